@@ -0,0 +1,270 @@
+//! # Host-call specification linting
+//!
+//! Every host call of the Wasm bindings is described by a pair of constants in
+//! `system_call_numbers.rs`: `ABI_*_NAME`, the name under which guests import
+//! the call, and `ABI_*_INDEX`, its host-call number.  Each call must also have
+//! an arm in the dispatcher of `runtime_state.rs`, and a signature checker in
+//! `type_checking.rs` that the resolver calls.  Nothing in the type system
+//! ties these together, so that a call may be given the name of another, or
+//! be resolved but never dispatched, and only fail once a guest uses it.
+//!
+//! This module lints the three sources against each other, checking that:
+//!
+//! - every `ABI_*_NAME` has a matching `ABI_*_INDEX`, and vice versa,
+//! - every name agrees with its constant, `ABI_FOO_BAR_NAME` naming
+//!   `__foo_bar`,
+//! - no two calls share a name, or a number,
+//! - the numbers of the stable and experimental calls are each dense, starting
+//!   from zero and from `ABI_EXPERIMENTAL_INDEX_LOWER_BOUND` respectively,
+//! - every number has an arm in the dispatcher,
+//! - every call has a signature checker, `check_*_signature`, which the
+//!   resolver calls.
+//!
+//! This module is included, with `#[path]`, by the build script of the
+//! `wasmi-bindings` crate, which fails the build, listing every omission, if
+//! any check fails.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::Path,
+};
+
+/// The path of the host-call numbers, relative to the manifest directory of
+/// the `wasmi-bindings` crate.
+pub const NUMBERS: &str = "src/system_call_numbers.rs";
+/// The path of the dispatcher and resolver, relative to the manifest
+/// directory of the `wasmi-bindings` crate.
+pub const DISPATCHER: &str = "src/runtime_state.rs";
+/// The path of the signature checkers, relative to the manifest directory of
+/// the `wasmi-bindings` crate.
+pub const CHECKERS: &str = "src/type_checking.rs";
+
+/// The constant holding the lower bound of the experimental host-call numbers.
+const EXPERIMENTAL_LOWER_BOUND: &str = "ABI_EXPERIMENTAL_INDEX_LOWER_BOUND";
+
+/// The `ABI_*_NAME` constants that name something other than a host call.
+const NOT_HOST_CALLS: &[&str] =
+    &["ABI_HOST_TABLE_NAME", "ABI_HOST_TABLE_VERSION_NAME"];
+
+/// Returns `true` iff `c` may appear in a Rust identifier.
+#[inline]
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Returns the `ABI_*` constants declared in `source`, along with the text of
+/// their values.
+fn constants(source: &str) -> Vec<(String, String)> {
+    let mut constants = Vec::new();
+
+    for declaration in source.split("const ").skip(1) {
+        let end = declaration
+            .find(|c| !is_identifier_char(c))
+            .unwrap_or(declaration.len());
+        let (identifier, rest) = declaration.split_at(end);
+
+        if !identifier.starts_with("ABI_") {
+            continue;
+        }
+
+        let value = rest
+            .split_once('=')
+            .and_then(|(_type, rest)| rest.split_once(';'))
+            .map(|(value, _rest)| value.trim().to_string())
+            .unwrap_or_default();
+
+        constants.push((identifier.to_string(), value));
+    }
+
+    constants
+}
+
+/// Returns `true` iff `identifier` appears in `source` as the pattern of a
+/// match arm, i.e. followed by `=>` or `|`, or preceded by `|`.
+fn has_arm(source: &str, identifier: &str) -> bool {
+    source
+        .match_indices(identifier)
+        .any(|(start, _identifier)| {
+            let before = &source[..start];
+            let after = &source[start + identifier.len()..];
+
+            if before.ends_with(is_identifier_char)
+                || after.starts_with(is_identifier_char)
+            {
+                return false;
+            }
+
+            let after = after.trim_start();
+
+            after.starts_with("=>")
+                || after.starts_with('|')
+                || before.trim_end().ends_with('|')
+        })
+}
+
+/// Lints the host-call numbers, `numbers`, against the dispatcher and
+/// resolver, `dispatcher`, and the signature checkers, `checkers`, returning
+/// a description of every problem found, in the order of the sources.
+pub fn lint(numbers: &str, dispatcher: &str, checkers: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut names: BTreeMap<String, String> = BTreeMap::new();
+    let mut indices: BTreeMap<String, usize> = BTreeMap::new();
+    let mut lower_bound = None;
+
+    for (constant, value) in constants(numbers) {
+        if constant == EXPERIMENTAL_LOWER_BOUND {
+            lower_bound = value.parse::<usize>().ok();
+        } else if let Some(stem) = constant.strip_suffix("_NAME") {
+            if NOT_HOST_CALLS.contains(&constant.as_str()) {
+                continue;
+            }
+
+            match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(name) => {
+                    names.insert(stem.to_string(), name.to_string());
+                }
+                None => problems
+                    .push(format!("`{}` is not a string literal", constant)),
+            }
+        } else if let Some(stem) = constant.strip_suffix("_INDEX") {
+            match value.parse::<usize>() {
+                Ok(index) => {
+                    indices.insert(stem.to_string(), index);
+                }
+                Err(_e) => problems
+                    .push(format!("`{}` is not an integer literal", constant)),
+            }
+        }
+    }
+
+    let lower_bound = match lower_bound {
+        Some(lower_bound) => lower_bound,
+        None => {
+            problems.push(format!("`{}` is missing", EXPERIMENTAL_LOWER_BOUND));
+            return problems;
+        }
+    };
+
+    /* 1. Names and numbers must be paired, and agree with their constants. */
+    for (stem, name) in &names {
+        if !indices.contains_key(stem) {
+            problems.push(format!("`{}_NAME` has no `{0}_INDEX`", stem));
+        }
+
+        let expected = format!("__{}", stem["ABI_".len()..].to_lowercase());
+
+        if *name != expected {
+            problems.push(format!(
+                "`{}_NAME` names `{}`, rather than `{}`",
+                stem, name, expected
+            ));
+        }
+    }
+
+    for stem in indices.keys() {
+        if !names.contains_key(stem) {
+            problems.push(format!("`{}_INDEX` has no `{0}_NAME`", stem));
+        }
+    }
+
+    /* 2. Names and numbers must be unique. */
+    let mut seen: HashMap<&str, &str> = HashMap::new();
+
+    for (stem, name) in &names {
+        if let Some(other) = seen.insert(name, stem) {
+            problems.push(format!(
+                "`{}_NAME` and `{}_NAME` both name `{}`",
+                other, stem, name
+            ));
+        }
+    }
+
+    let mut seen: HashMap<usize, &str> = HashMap::new();
+
+    for (stem, index) in &indices {
+        if let Some(other) = seen.insert(*index, stem) {
+            problems.push(format!(
+                "`{}_INDEX` and `{}_INDEX` are both numbered {}",
+                other, stem, index
+            ));
+        }
+    }
+
+    /* 3. Numbers must be dense within the stable and experimental ranges. */
+    let numbered: BTreeSet<usize> = indices.values().copied().collect();
+    let stable = numbered.iter().filter(|index| **index < lower_bound);
+    let experimental = numbered.iter().filter(|index| **index >= lower_bound);
+
+    for (start, range) in
+        [(0, stable.count()), (lower_bound, experimental.count())]
+    {
+        for index in start..start + range {
+            if !numbered.contains(&index) {
+                problems.push(format!("no host call is numbered {}", index));
+            }
+        }
+    }
+
+    /* 4. Every call must be dispatched, and have its signature checked. */
+    for stem in indices.keys() {
+        if !has_arm(dispatcher, &format!("{}_INDEX", stem)) {
+            problems.push(format!("`{}_INDEX` has no dispatcher arm", stem));
+        }
+
+        let checker =
+            format!("check_{}_signature", stem["ABI_".len()..].to_lowercase());
+
+        if !checkers.contains(&format!("fn {}(", checker)) {
+            problems.push(format!(
+                "`{}_INDEX` has no signature checker, `{}`",
+                stem, checker
+            ));
+        } else if !dispatcher.contains(&format!("{}(", checker)) {
+            problems.push(format!(
+                "`{}_INDEX` has a signature checker, `{}`, that is never \
+                 called",
+                stem, checker
+            ));
+        }
+    }
+
+    problems
+}
+
+/// Reads, and lints, the host-call specification of the crate whose manifest
+/// directory is `directory`.  Returns every problem found, one per line.
+pub fn load<P>(directory: P) -> Result<(), String>
+where
+    P: AsRef<Path>,
+{
+    let read = |path: &str| {
+        let path = directory.as_ref().join(path);
+
+        fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))
+    };
+
+    let problems = lint(&read(NUMBERS)?, &read(DISPATCHER)?, &read(CHECKERS)?);
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("\n"))
+    }
+}
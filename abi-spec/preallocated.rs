@@ -0,0 +1,219 @@
+//! # Pre-allocated handle generation
+//!
+//! Parses the description of the kernel's pre-allocated handles, in
+//! `preallocated-handles.txt`, and generates the `PREALLOCATED_HANDLE_*`
+//! constants from it.  This module is shared, with `#[path]`, by the build
+//! scripts of the `kernel` and `libsupervisionary` crates, so that both crates
+//! agree on every handle by construction.  Any malformed entry is reported as
+//! an error, which the build scripts turn into a failed build.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{collections::HashSet, fs, path::Path};
+
+/// The path of the description of the pre-allocated handles, relative to the
+/// manifest directory of either crate.
+pub const SPECIFICATION: &str = "../abi-spec/preallocated-handles.txt";
+
+/// The width to which generated documentation comments are wrapped.
+const DOCUMENTATION_WIDTH: usize = 80;
+
+/// The kinds of kernel object that may be pre-allocated, along with the prefix
+/// that the names of their handles must begin with.  Note that the prefix of
+/// type-formers must be checked before that of types.
+const KINDS: &[(&str, &str)] = &[
+    ("TypeFormer", "TYPE_FORMER_"),
+    ("Type", "TYPE_"),
+    ("Constant", "CONSTANT_"),
+    ("Term", "TERM_"),
+];
+
+/// A single pre-allocated handle.
+pub struct Entry {
+    /// The handle itself.
+    pub handle: usize,
+    /// The kind of kernel object pointed-to by the handle, as the name of its
+    /// tag.
+    pub kind: String,
+    /// The name of the constant, less its `PREALLOCATED_HANDLE_` prefix.
+    pub name: String,
+    /// A description of the kernel object pointed-to by the handle.
+    pub description: String,
+}
+
+/// Returns the kind whose prefix `name` begins with, if any.
+fn kind_of_name(name: &str) -> Option<&'static str> {
+    KINDS
+        .iter()
+        .find(|(_kind, prefix)| name.starts_with(prefix))
+        .map(|(kind, _prefix)| *kind)
+}
+
+/// Splits the first whitespace-separated field from `line`, returning it along
+/// with the remainder of the line, or `None` if `line` is blank.
+fn split_field(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let end = line.find(char::is_whitespace).unwrap_or(line.len());
+
+    if end == 0 {
+        None
+    } else {
+        Some(line.split_at(end))
+    }
+}
+
+/// Parses the description of the pre-allocated handles, `source`, checking
+/// that handles are allocated contiguously from zero, that every name is
+/// unique, and that every name agrees with the kind of its handle.
+pub fn parse(source: &str) -> Result<Vec<Entry>, String> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut names = HashSet::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let missing = || format!("line {}: missing field", number + 1);
+
+        let (handle, rest) = split_field(line).ok_or_else(missing)?;
+        let (kind, rest) = split_field(rest).ok_or_else(missing)?;
+        let (name, rest) = split_field(rest).ok_or_else(missing)?;
+        let description = rest.trim();
+
+        if description.is_empty() {
+            return Err(missing());
+        }
+
+        let handle: usize = handle.parse().map_err(|_e| {
+            format!("line {}: malformed handle `{}`", number + 1, handle)
+        })?;
+        let kind = kind.to_string();
+        let name = name.to_string();
+        let description = description.to_string();
+
+        if handle != entries.len() {
+            return Err(format!(
+                "line {}: expected handle {}, but found {}",
+                number + 1,
+                entries.len(),
+                handle
+            ));
+        }
+
+        if !KINDS.iter().any(|(k, _prefix)| *k == kind) {
+            return Err(format!(
+                "line {}: unknown kind `{}`",
+                number + 1,
+                kind
+            ));
+        }
+
+        if kind_of_name(&name) != Some(kind.as_str()) {
+            return Err(format!(
+                "line {}: name `{}` does not agree with kind `{}`",
+                number + 1,
+                name,
+                kind
+            ));
+        }
+
+        if !names.insert(name.clone()) {
+            return Err(format!(
+                "line {}: duplicate name `{}`",
+                number + 1,
+                name
+            ));
+        }
+
+        entries.push(Entry {
+            handle,
+            kind,
+            name,
+            description,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Reads, and parses, the description of the pre-allocated handles at `path`.
+pub fn load<P>(path: P) -> Result<Vec<Entry>, String>
+where
+    P: AsRef<Path>,
+{
+    let source = fs::read_to_string(path.as_ref()).map_err(|e| {
+        format!("failed to read {}: {}", path.as_ref().display(), e)
+    })?;
+
+    parse(&source)
+}
+
+/// Renders `text` as a documentation comment, wrapped to the documentation
+/// width.
+fn documentation(text: &str) -> String {
+    let mut rendered = String::new();
+    let mut line = String::from("///");
+
+    for word in text.split_whitespace() {
+        if line.len() + 1 + word.len() > DOCUMENTATION_WIDTH && line != "///" {
+            rendered.push_str(&line);
+            rendered.push('\n');
+            line = String::from("///");
+        }
+
+        line.push(' ');
+        line.push_str(word);
+    }
+
+    rendered.push_str(&line);
+    rendered.push('\n');
+    rendered
+}
+
+/// Generates a constant for every entry in `entries` of the kind `kind`, or of
+/// any kind if `kind` is `None`, rendering each handle with `constructor`.
+pub fn constants<F>(
+    entries: &[Entry],
+    kind: Option<&str>,
+    constructor: F,
+) -> String
+where
+    F: Fn(usize) -> String,
+{
+    let mut generated = String::new();
+
+    for entry in entries {
+        if kind.is_some_and(|kind| kind != entry.kind) {
+            continue;
+        }
+
+        generated.push_str(&documentation(&format!(
+            "A pre-allocated handle used to refer to {}",
+            entry.description
+        )));
+        generated.push_str(&format!(
+            "pub const PREALLOCATED_HANDLE_{}: Handle<tags::{}> = {};\n",
+            entry.name,
+            entry.kind,
+            constructor(entry.handle)
+        ));
+    }
+
+    generated
+}
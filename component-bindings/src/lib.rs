@@ -0,0 +1,1324 @@
+//! # Component-model bindings
+//!
+//! This module describes the kernel's system interface as a component-model
+//! world, `supervisionary:kernel/guest`, defined in the `wit` directory of this
+//! crate.  Guest programs built with `cargo component`, or with `wit-bindgen`
+//! for any of its target languages, can use the world to call the kernel
+//! through typed, versioned, interfaces rather than through the raw, numeric,
+//! host calls bound by `libsupervisionary`.
+//!
+//! Every function of the world corresponds to exactly one raw host call, and
+//! a host embedding the kernel in a component-model runtime routes each
+//! function to the kernel operation of its host call.  This module provides
+//! the correspondence, along with the correspondence between the cases of the
+//! world's `error-code` enumeration and the kernel's error codes.  The WASMI
+//! driver itself only executes core Wasm modules, and does not load
+//! components.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use kernel::error_code::{ErrorCode, ERRORCODE_ENCODING_UPPER_BOUND};
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// The world.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The WIT source of the kernel's component-model world.
+pub const WIT_SOURCE: &str = include_str!("../wit/supervisionary.wit");
+/// The name of the WIT package defining the kernel's interfaces.
+pub const WIT_PACKAGE_NAME: &str = "supervisionary:kernel";
+/// The version of the WIT package.  This changes whenever an interface does.
+pub const WIT_PACKAGE_VERSION: &str = "0.1.0";
+/// The name of the world that guest programs are built against.
+pub const WIT_WORLD_NAME: &str = "guest";
+
+/// Returns the name of the core Wasm module from which a guest, built against
+/// the world, imports the functions of the interface `interface`.
+pub fn import_module_name(interface: &str) -> String {
+    format!("{}/{}@{}", WIT_PACKAGE_NAME, interface, WIT_PACKAGE_VERSION)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Host calls.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A function of the world, and the raw host call that it corresponds to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HostCall {
+    /// The interface declaring the function.
+    pub interface: &'static str,
+    /// The name of the function within its interface.
+    pub function: &'static str,
+    /// The name under which guests import the corresponding raw host call.
+    pub raw: &'static str,
+}
+
+/// Every function of the world, in the order in which they are declared.
+pub const HOST_CALLS: &[HostCall] = &[
+    HostCall {
+        interface: "type-formers",
+        function: "resolve",
+        raw: "__type_former_resolve",
+    },
+    HostCall {
+        interface: "type-formers",
+        function: "is-registered",
+        raw: "__type_former_is_registered",
+    },
+    HostCall {
+        interface: "type-formers",
+        function: "register",
+        raw: "__type_former_register",
+    },
+    HostCall {
+        interface: "type-formers",
+        function: "register-with-metadata",
+        raw: "__type_former_register_with_metadata",
+    },
+    HostCall {
+        interface: "type-formers",
+        function: "metadata",
+        raw: "__type_former_metadata",
+    },
+    HostCall {
+        interface: "type-formers",
+        function: "lookup-by-name",
+        raw: "__type_former_lookup_by_name",
+    },
+    HostCall {
+        interface: "types",
+        function: "is-registered",
+        raw: "__type_is_registered",
+    },
+    HostCall {
+        interface: "types",
+        function: "register-variable",
+        raw: "__type_register_variable",
+    },
+    HostCall {
+        interface: "types",
+        function: "register-combination",
+        raw: "__type_register_combination",
+    },
+    HostCall {
+        interface: "types",
+        function: "register-function",
+        raw: "__type_register_function",
+    },
+    HostCall {
+        interface: "types",
+        function: "split-variable",
+        raw: "__type_split_variable",
+    },
+    HostCall {
+        interface: "types",
+        function: "split-combination",
+        raw: "__type_split_combination",
+    },
+    HostCall {
+        interface: "types",
+        function: "split-function",
+        raw: "__type_split_function",
+    },
+    HostCall {
+        interface: "types",
+        function: "test-variable",
+        raw: "__type_test_variable",
+    },
+    HostCall {
+        interface: "types",
+        function: "test-combination",
+        raw: "__type_test_combination",
+    },
+    HostCall {
+        interface: "types",
+        function: "test-function",
+        raw: "__type_test_function",
+    },
+    HostCall {
+        interface: "types",
+        function: "size",
+        raw: "__type_size",
+    },
+    HostCall {
+        interface: "types",
+        function: "variables",
+        raw: "__type_variables",
+    },
+    HostCall {
+        interface: "types",
+        function: "variables-count",
+        raw: "__type_variables_count",
+    },
+    HostCall {
+        interface: "types",
+        function: "variables-range",
+        raw: "__type_variables_range",
+    },
+    HostCall {
+        interface: "types",
+        function: "substitute",
+        raw: "__type_substitute",
+    },
+    HostCall {
+        interface: "types",
+        function: "generalise",
+        raw: "__type_generalise",
+    },
+    HostCall {
+        interface: "types",
+        function: "instantiate-fresh",
+        raw: "__type_instantiate_fresh",
+    },
+    HostCall {
+        interface: "types",
+        function: "define",
+        raw: "__type_define",
+    },
+    HostCall {
+        interface: "constants",
+        function: "resolve",
+        raw: "__constant_resolve",
+    },
+    HostCall {
+        interface: "constants",
+        function: "is-registered",
+        raw: "__constant_is_registered",
+    },
+    HostCall {
+        interface: "constants",
+        function: "register",
+        raw: "__constant_register",
+    },
+    HostCall {
+        interface: "constants",
+        function: "define",
+        raw: "__constant_define",
+    },
+    HostCall {
+        interface: "constants",
+        function: "register-named",
+        raw: "__constant_register_named",
+    },
+    HostCall {
+        interface: "constants",
+        function: "lookup-by-name",
+        raw: "__constant_lookup_by_name",
+    },
+    HostCall {
+        interface: "constants",
+        function: "mark-constructors",
+        raw: "__constant_mark_constructors",
+    },
+    HostCall {
+        interface: "constants",
+        function: "is-constructor",
+        raw: "__constant_is_constructor",
+    },
+    HostCall {
+        interface: "constants",
+        function: "constructor-siblings",
+        raw: "__constant_constructor_siblings",
+    },
+    HostCall {
+        interface: "terms",
+        function: "is-registered",
+        raw: "__term_is_registered",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-variable",
+        raw: "__term_register_variable",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-constant",
+        raw: "__term_register_constant",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-application",
+        raw: "__term_register_application",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-lambda",
+        raw: "__term_register_lambda",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-negation",
+        raw: "__term_register_negation",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-conjunction",
+        raw: "__term_register_conjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-disjunction",
+        raw: "__term_register_disjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-implication",
+        raw: "__term_register_implication",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-equality",
+        raw: "__term_register_equality",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-forall",
+        raw: "__term_register_forall",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-exists",
+        raw: "__term_register_exists",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-conditional",
+        raw: "__term_register_conditional",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-let",
+        raw: "__term_register_let",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-pair",
+        raw: "__term_register_pair",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-first",
+        raw: "__term_register_first",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-second",
+        raw: "__term_register_second",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-membership",
+        raw: "__term_register_membership",
+    },
+    HostCall {
+        interface: "terms",
+        function: "register-comprehension",
+        raw: "__term_register_comprehension",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-variable",
+        raw: "__term_split_variable",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-constant",
+        raw: "__term_split_constant",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-application",
+        raw: "__term_split_application",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-lambda",
+        raw: "__term_split_lambda",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-lambda-display",
+        raw: "__term_split_lambda_display",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-negation",
+        raw: "__term_split_negation",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-conjunction",
+        raw: "__term_split_conjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-disjunction",
+        raw: "__term_split_disjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-implication",
+        raw: "__term_split_implication",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-equality",
+        raw: "__term_split_equality",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-forall",
+        raw: "__term_split_forall",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-exists",
+        raw: "__term_split_exists",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-conditional",
+        raw: "__term_split_conditional",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-let",
+        raw: "__term_split_let",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-pair",
+        raw: "__term_split_pair",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-membership",
+        raw: "__term_split_membership",
+    },
+    HostCall {
+        interface: "terms",
+        function: "split-comprehension",
+        raw: "__term_split_comprehension",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-variable",
+        raw: "__term_test_variable",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-constant",
+        raw: "__term_test_constant",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-application",
+        raw: "__term_test_application",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-lambda",
+        raw: "__term_test_lambda",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-negation",
+        raw: "__term_test_negation",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-conjunction",
+        raw: "__term_test_conjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-disjunction",
+        raw: "__term_test_disjunction",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-implication",
+        raw: "__term_test_implication",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-equality",
+        raw: "__term_test_equality",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-forall",
+        raw: "__term_test_forall",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-exists",
+        raw: "__term_test_exists",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-conditional",
+        raw: "__term_test_conditional",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-let",
+        raw: "__term_test_let",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-pair",
+        raw: "__term_test_pair",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-membership",
+        raw: "__term_test_membership",
+    },
+    HostCall {
+        interface: "terms",
+        function: "test-comprehension",
+        raw: "__term_test_comprehension",
+    },
+    HostCall {
+        interface: "terms",
+        function: "free-variables",
+        raw: "__term_free_variables",
+    },
+    HostCall {
+        interface: "terms",
+        function: "free-variables-count",
+        raw: "__term_free_variables_count",
+    },
+    HostCall {
+        interface: "terms",
+        function: "free-variables-range",
+        raw: "__term_free_variables_range",
+    },
+    HostCall {
+        interface: "terms",
+        function: "substitute",
+        raw: "__term_substitute",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-variables",
+        raw: "__term_type_variables",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-variables-count",
+        raw: "__term_type_variables_count",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-variables-range",
+        raw: "__term_type_variables_range",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-substitute",
+        raw: "__term_type_substitute",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-infer",
+        raw: "__term_type_infer",
+    },
+    HostCall {
+        interface: "terms",
+        function: "type-is-proposition",
+        raw: "__term_type_is_proposition",
+    },
+    HostCall {
+        interface: "terms",
+        function: "diff",
+        raw: "__term_diff",
+    },
+    HostCall {
+        interface: "terms",
+        function: "subterm-at",
+        raw: "__term_subterm_at",
+    },
+    HostCall {
+        interface: "terms",
+        function: "replace-at",
+        raw: "__term_replace_at",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "is-registered",
+        raw: "__theorem_is_registered",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "is-tainted",
+        raw: "__theorem_is_tainted",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "trust-level",
+        raw: "__theorem_trust_level",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "alpha-equal",
+        raw: "__theorem_alpha_equal",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "exists-with-conclusion",
+        raw: "__theorem_exists_with_conclusion",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "find-by-conclusion",
+        raw: "__theorem_find_by_conclusion",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "find-matching",
+        raw: "__theorem_find_matching",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "split-hypotheses",
+        raw: "__theorem_split_hypotheses",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "hypotheses-count",
+        raw: "__theorem_hypotheses_count",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "split-hypotheses-range",
+        raw: "__theorem_split_hypotheses_range",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "split-conclusion",
+        raw: "__theorem_split_conclusion",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "statement-hash",
+        raw: "__theorem_statement_hash",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "statement-as-term",
+        raw: "__theorem_statement_as_term",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-assumption",
+        raw: "__theorem_register_assumption",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-weaken",
+        raw: "__theorem_register_weaken",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-reflexivity",
+        raw: "__theorem_register_reflexivity",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-symmetry",
+        raw: "__theorem_register_symmetry",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-transitivity",
+        raw: "__theorem_register_transitivity",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-beta",
+        raw: "__theorem_register_beta",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-eta",
+        raw: "__theorem_register_eta",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-application",
+        raw: "__theorem_register_application",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-lambda",
+        raw: "__theorem_register_lambda",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-substitute",
+        raw: "__theorem_register_substitute",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-type-substitute",
+        raw: "__theorem_register_type_substitute",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-truth-introduction",
+        raw: "__theorem_register_truth_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-falsity-elimination",
+        raw: "__theorem_register_falsity_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-conjunction-introduction",
+        raw: "__theorem_register_conjunction_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-conjunction-left-elimination",
+        raw: "__theorem_register_conjunction_left_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-conjunction-right-elimination",
+        raw: "__theorem_register_conjunction_right_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-disjunction-elimination",
+        raw: "__theorem_register_disjunction_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-disjunction-left-introduction",
+        raw: "__theorem_register_disjunction_left_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-disjunction-right-introduction",
+        raw: "__theorem_register_disjunction_right_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-implication-introduction",
+        raw: "__theorem_register_implication_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-implication-elimination",
+        raw: "__theorem_register_implication_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-iff-introduction",
+        raw: "__theorem_register_iff_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-iff-left-elimination",
+        raw: "__theorem_register_iff_left_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-negation-introduction",
+        raw: "__theorem_register_negation_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-negation-elimination",
+        raw: "__theorem_register_negation_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-forall-introduction",
+        raw: "__theorem_register_forall_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-forall-elimination",
+        raw: "__theorem_register_forall_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-exists-introduction",
+        raw: "__theorem_register_exists_introduction",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-exists-elimination",
+        raw: "__theorem_register_exists_elimination",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-conditional-congruence",
+        raw: "__theorem_register_conditional_congruence",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-let-congruence",
+        raw: "__theorem_register_let_congruence",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-forall-congruence",
+        raw: "__theorem_register_forall_congruence",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-exists-congruence",
+        raw: "__theorem_register_exists_congruence",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-first-projection",
+        raw: "__theorem_register_first_projection",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-second-projection",
+        raw: "__theorem_register_second_projection",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-surjective-pairing",
+        raw: "__theorem_register_surjective_pairing",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-membership-definition",
+        raw: "__theorem_register_membership_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-comprehension-definition",
+        raw: "__theorem_register_comprehension_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-empty-set-definition",
+        raw: "__theorem_register_empty_set_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-universal-set-definition",
+        raw: "__theorem_register_universal_set_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-union-definition",
+        raw: "__theorem_register_union_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-intersection-definition",
+        raw: "__theorem_register_intersection_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-subset-definition",
+        raw: "__theorem_register_subset_definition",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-unfold",
+        raw: "__theorem_register_unfold",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-rewrite-at",
+        raw: "__theorem_register_rewrite_at",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-rewrite-hypothesis",
+        raw: "__theorem_register_rewrite_hypothesis",
+    },
+    HostCall {
+        interface: "theorems",
+        function: "register-assumption-matching",
+        raw: "__theorem_register_assumption_matching",
+    },
+    HostCall {
+        interface: "hypotheses",
+        function: "intern",
+        raw: "__hypotheses_intern",
+    },
+    HostCall {
+        interface: "hypotheses",
+        function: "resolve",
+        raw: "__hypotheses_resolve",
+    },
+    HostCall {
+        interface: "proofs",
+        function: "theorem-proof",
+        raw: "__theorem_proof",
+    },
+    HostCall {
+        interface: "proofs",
+        function: "split",
+        raw: "__proof_split",
+    },
+    HostCall {
+        interface: "proofs",
+        function: "split-arguments",
+        raw: "__proof_split_arguments",
+    },
+    HostCall {
+        interface: "proofs",
+        function: "check",
+        raw: "__proof_check",
+    },
+    HostCall {
+        interface: "capabilities",
+        function: "challenge-current",
+        raw: "__challenge_current",
+    },
+    HostCall {
+        interface: "capabilities",
+        function: "challenge-discharge",
+        raw: "__challenge_discharge",
+    },
+    HostCall {
+        interface: "capabilities",
+        function: "present",
+        raw: "__capability_present",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "is-registered",
+        raw: "__sequent_is_registered",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "split-premisses",
+        raw: "__sequent_split_premisses",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "split-conclusions",
+        raw: "__sequent_split_conclusions",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-axiom",
+        raw: "__sequent_register_axiom",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-theorem",
+        raw: "__sequent_register_theorem",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-weaken-left",
+        raw: "__sequent_register_weaken_left",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-weaken-right",
+        raw: "__sequent_register_weaken_right",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-cut",
+        raw: "__sequent_register_cut",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-negation-left",
+        raw: "__sequent_register_negation_left",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-negation-right",
+        raw: "__sequent_register_negation_right",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-conjunction-left",
+        raw: "__sequent_register_conjunction_left",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-conjunction-right",
+        raw: "__sequent_register_conjunction_right",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-disjunction-left",
+        raw: "__sequent_register_disjunction_left",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-disjunction-right",
+        raw: "__sequent_register_disjunction_right",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-implication-left",
+        raw: "__sequent_register_implication_left",
+    },
+    HostCall {
+        interface: "sequents",
+        function: "register-implication-right",
+        raw: "__sequent_register_implication_right",
+    },
+    HostCall {
+        interface: "budgets",
+        function: "remaining",
+        raw: "__budget_remaining",
+    },
+    HostCall {
+        interface: "budgets",
+        function: "proposition",
+        raw: "__budget_resource",
+    },
+    HostCall {
+        interface: "linear-arith",
+        function: "signature",
+        raw: "__linear_arith_signature",
+    },
+    HostCall {
+        interface: "linear-arith",
+        function: "prove",
+        raw: "__theorem_register_linear_arith",
+    },
+    HostCall {
+        interface: "speculation",
+        function: "begin",
+        raw: "__speculate_begin",
+    },
+    HostCall {
+        interface: "speculation",
+        function: "end",
+        raw: "__speculate_end",
+    },
+    HostCall {
+        interface: "quotients",
+        function: "register",
+        raw: "__quotient_register",
+    },
+    HostCall {
+        interface: "quotients",
+        function: "split",
+        raw: "__quotient_split",
+    },
+    HostCall {
+        interface: "quotients",
+        function: "lift",
+        raw: "__quotient_lift",
+    },
+    HostCall {
+        interface: "quotients",
+        function: "transfer",
+        raw: "__quotient_transfer",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "register-type",
+        raw: "__substitution_register_type",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "register-term",
+        raw: "__substitution_register_term",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "compose",
+        raw: "__substitution_compose",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "type-substitute",
+        raw: "__type_substitute_with",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "term-substitute",
+        raw: "__term_substitute_with",
+    },
+    HostCall {
+        interface: "substitutions",
+        function: "term-type-substitute",
+        raw: "__term_type_substitute_with",
+    },
+    HostCall {
+        interface: "metadata",
+        function: "set",
+        raw: "__object_metadata_set",
+    },
+    HostCall {
+        interface: "metadata",
+        function: "get",
+        raw: "__object_metadata_get",
+    },
+    HostCall {
+        interface: "metadata",
+        function: "enumerate",
+        raw: "__object_metadata_enumerate",
+    },
+    HostCall {
+        interface: "objects",
+        function: "inc-ref",
+        raw: "__object_inc_ref",
+    },
+    HostCall {
+        interface: "objects",
+        function: "dec-ref",
+        raw: "__object_dec_ref",
+    },
+    HostCall {
+        interface: "objects",
+        function: "delete",
+        raw: "__object_delete",
+    },
+    HostCall {
+        interface: "scratch",
+        function: "put",
+        raw: "__scratch_put",
+    },
+    HostCall {
+        interface: "scratch",
+        function: "get",
+        raw: "__scratch_get",
+    },
+    HostCall {
+        interface: "scratch",
+        function: "delete",
+        raw: "__scratch_delete",
+    },
+    HostCall {
+        interface: "proof-objects",
+        function: "export-theorems",
+        raw: "__proof_object_export",
+    },
+    HostCall {
+        interface: "proof-objects",
+        function: "import-theorems",
+        raw: "__proof_object_import",
+    },
+    HostCall {
+        interface: "introspection",
+        function: "call-count",
+        raw: "__introspect_call_count",
+    },
+    HostCall {
+        interface: "introspection",
+        function: "fuel-consumed",
+        raw: "__introspect_fuel_consumed",
+    },
+    HostCall {
+        interface: "introspection",
+        function: "heap-size",
+        raw: "__introspect_heap_size",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "enumerate",
+        raw: "__kernel_enumerate",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "identify",
+        raw: "__kernel_identify",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "error-detail",
+        raw: "__kernel_error_detail",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "error-detail-last",
+        raw: "__error_detail_last",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "feature-supported",
+        raw: "__kernel_feature_supported",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "should-abort",
+        raw: "__should_abort",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "reset",
+        raw: "__kernel_reset",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "set-variable-order",
+        raw: "__kernel_set_variable_order",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "batch-execute",
+        raw: "__kernel_batch_execute",
+    },
+    HostCall {
+        interface: "kernel",
+        function: "export-proof",
+        raw: "__kernel_export_proof",
+    },
+];
+
+/// Returns the raw host call corresponding to the function `function` of the
+/// interface `interface`, if the world declares it.
+pub fn host_call(interface: &str, function: &str) -> Option<&'static HostCall> {
+    HOST_CALLS
+        .iter()
+        .find(|call| call.interface == interface && call.function == function)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Error codes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the index of the case of the world's `error-code` enumeration that
+/// corresponds to `code`, or `None` if `code` signals success, which the world
+/// signals with the `ok` case of a `result` instead.
+pub fn error_code_case(code: ErrorCode) -> Option<u32> {
+    let encoding: i32 = code.into();
+
+    (encoding as u32).checked_sub(1)
+}
+
+/// Returns the kernel error code corresponding to the case, with index `case`,
+/// of the world's `error-code` enumeration.
+pub fn error_code_from_case(case: u32) -> Option<ErrorCode> {
+    let encoding = case.checked_add(1)?;
+
+    if encoding as usize >= ERRORCODE_ENCODING_UPPER_BOUND {
+        return None;
+    }
+
+    ErrorCode::try_from(encoding as i32).ok()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests that the tables of this module agree with the WIT source.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code_case, error_code_from_case, host_call, HOST_CALLS,
+        WIT_SOURCE,
+    };
+    use kernel::error_code::{ErrorCode, ERRORCODE_ENCODING_UPPER_BOUND};
+    use std::collections::HashSet;
+
+    /// Returns the interface and name of every function declared in the WIT
+    /// source, in order.
+    fn declared_functions() -> Vec<(String, String)> {
+        let mut interface = "";
+        let mut functions = Vec::new();
+
+        for line in WIT_SOURCE.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("interface ") {
+                interface = name.trim_end_matches(" {");
+            } else if let Some((name, _signature)) = line.split_once(": func(")
+            {
+                functions.push((interface.to_string(), name.to_string()));
+            }
+        }
+
+        functions
+    }
+
+    /// Returns the cases of the `error-code` enumeration in the WIT source,
+    /// in order.
+    fn declared_error_codes() -> Vec<String> {
+        WIT_SOURCE
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| *line != "enum error-code {")
+            .skip(1)
+            .take_while(|line| *line != "}")
+            .map(|line| line.trim_end_matches(',').to_string())
+            .collect()
+    }
+
+    /// Converts the name of an error code into the name of a WIT case.
+    fn kebab_case(name: &str) -> String {
+        let mut case = String::new();
+
+        for c in name.chars() {
+            if c.is_ascii_uppercase() && !case.is_empty() {
+                case.push('-');
+            }
+            case.push(c.to_ascii_lowercase());
+        }
+
+        case
+    }
+
+    /// Tests that every function declared in the world has exactly one entry
+    /// in the host call table, in declaration order.
+    #[test]
+    pub fn component_bindings_test0() {
+        let declared = declared_functions();
+
+        assert_eq!(declared.len(), HOST_CALLS.len());
+
+        for ((interface, function), call) in declared.iter().zip(HOST_CALLS) {
+            assert_eq!(interface, call.interface);
+            assert_eq!(function, call.function);
+            assert_eq!(host_call(interface, function), Some(call));
+        }
+    }
+
+    /// Tests that no two functions of the world share a raw host call.
+    #[test]
+    pub fn component_bindings_test1() {
+        let raw: HashSet<&str> =
+            HOST_CALLS.iter().map(|call| call.raw).collect();
+
+        assert_eq!(raw.len(), HOST_CALLS.len());
+        assert!(host_call("terms", "fold").is_none());
+    }
+
+    /// Tests that the cases of the world's `error-code` enumeration follow the
+    /// encoding of the kernel's error codes.
+    #[test]
+    pub fn component_bindings_test2() {
+        let declared = declared_error_codes();
+
+        assert_eq!(declared.len(), ERRORCODE_ENCODING_UPPER_BOUND - 1);
+        assert_eq!(error_code_case(ErrorCode::Success), None);
+
+        for (case, name) in declared.iter().enumerate() {
+            let code = error_code_from_case(case as u32).unwrap();
+
+            assert_eq!(&kebab_case(&format!("{}", code)), name);
+            assert_eq!(error_code_case(code), Some(case as u32));
+        }
+
+        assert!(error_code_from_case(declared.len() as u32).is_none());
+    }
+}
@@ -0,0 +1,495 @@
+//! # Trace-to-test converter
+//!
+//! Converts a trace of host calls, recorded by passing `--record-trace` to the
+//! driver, into a standalone Rust guest program which replays every recorded
+//! host call, in order, and checks that each returns what it returned when the
+//! trace was recorded.  As the kernel issues handles deterministically, the
+//! program reproduces the recorded execution without the original guest, so
+//! a trace attached to a bug report can be turned into a test case under
+//! `tests/`.
+//!
+//! The program is written as a new crate, alongside the existing tests, which
+//! must then be added to the workspace.  Host calls that invoke guest callbacks,
+//! such as `Term.Fold`, cannot be replayed, and traces containing them are
+//! refused.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use clap::{App, Arg};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+    process::exit,
+};
+use wasmi::ValueType;
+use wasmi_bindings::trace::{
+    TraceArgument, TraceEvent, TraceResult, TraceValue,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An about message/header for the help menu of the converter.
+const ABOUT_MESSAGE: &str =
+    "Converts a recorded host-call trace into a standalone test program.";
+/// Name of the converter.
+const APPLICATION_NAME: &str = "Supervisionary trace-to-test converter.";
+/// Authors of the converter.
+const AUTHOR_LIST: &str =
+    "Dominic P. Mulligan and Nick Spinale, Arm Research, Cambridge.";
+/// The version number of the converter.
+const VERSION_NUMBER: &str = "0.1.0";
+/// The directory in which the test crate is written by default.
+const DEFAULT_OUTPUT_DIRECTORY: &str = "tests";
+/// The host calls that invoke guest callbacks, which cannot be replayed.
+const CALLBACK_HOST_CALLS: &[&str] = &["__progress_register", "__term_fold"];
+
+////////////////////////////////////////////////////////////////////////////////
+// Command-line parsing.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Captures the command line arguments passed to the program.
+struct CommandLineArguments {
+    /// The path of the trace to convert.
+    trace_path: PathBuf,
+    /// The name of the test crate to generate.
+    name: String,
+    /// The directory in which to write the test crate.
+    output_directory: PathBuf,
+}
+
+/// Parses the command line arguments of the program, exiting with an error code
+/// if this cannot be done successfully.
+fn parse_command_line_arguments() -> CommandLineArguments {
+    let matches = App::new(APPLICATION_NAME)
+        .about(ABOUT_MESSAGE)
+        .version(VERSION_NUMBER)
+        .author(AUTHOR_LIST)
+        .arg(
+            Arg::new("trace")
+                .required(true)
+                .short('t')
+                .long("trace")
+                .takes_value(true)
+                .help("Path to the trace recorded with --record-trace"),
+        )
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .short('n')
+                .long("name")
+                .takes_value(true)
+                .help("Name of the test crate to generate"),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .takes_value(true)
+                .default_value(DEFAULT_OUTPUT_DIRECTORY)
+                .help("Directory in which to write the test crate"),
+        )
+        .get_matches();
+
+    let name = matches.value_of("name").unwrap_or_default().to_string();
+
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        eprintln!("Malformed test crate name {}.", name);
+        exit(1)
+    }
+
+    CommandLineArguments {
+        trace_path: PathBuf::from(
+            matches.value_of("trace").unwrap_or_default(),
+        ),
+        name,
+        output_directory: PathBuf::from(
+            matches
+                .value_of("output")
+                .unwrap_or(DEFAULT_OUTPUT_DIRECTORY),
+        ),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reading traces.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reads the trace stored at `path`, exiting with an error code if it cannot
+/// be read or parsed, or if it contains host calls that cannot be replayed.
+fn read_trace(path: &Path) -> Vec<TraceEvent> {
+    let contents = read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Failed to read trace.  Error produced: {}.", e);
+        exit(1)
+    });
+
+    let mut events = Vec::new();
+
+    for (number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let event: TraceEvent = line.parse().unwrap_or_else(|e| {
+            eprintln!("Failed to parse line {} of trace: {}.", number + 1, e);
+            exit(1)
+        });
+
+        if CALLBACK_HOST_CALLS.contains(&event.name.as_str()) {
+            eprintln!(
+                "Line {} of trace calls {}, which invokes guest callbacks and cannot be replayed.",
+                number + 1,
+                event.name
+            );
+            exit(1)
+        }
+
+        events.push(event);
+    }
+
+    events
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Generating the test program.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The Rust type of a parameter of a host call, as declared by the generated
+/// program.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ParameterType {
+    /// A 32-bit value.
+    I32,
+    /// A 64-bit value.
+    I64,
+    /// A pointer to guest memory.
+    Pointer,
+}
+
+/// The declaration of a host call imported by the generated program.
+#[derive(Clone, Debug, Default)]
+struct Import {
+    /// The types of the host call's parameters.
+    parameters: Vec<Option<ParameterType>>,
+    /// The type of the host call's result, or `None` if it returns nothing.
+    result: Option<ValueType>,
+}
+
+/// Returns the declarations of every host call made in `events`, by name.
+/// Pointer arguments rejected by argument validation are recorded as plain
+/// values, so a parameter is declared as a pointer if any call passes memory
+/// through it.
+fn imports(events: &[TraceEvent]) -> BTreeMap<String, Import> {
+    let mut imports: BTreeMap<String, Import> = BTreeMap::new();
+
+    for event in events {
+        let import = imports.entry(event.name.clone()).or_default();
+
+        import.parameters.resize(event.arguments.len(), None);
+
+        for (parameter, argument) in
+            import.parameters.iter_mut().zip(event.arguments.iter())
+        {
+            *parameter = match (*parameter, argument) {
+                (_, TraceArgument::Memory { .. }) => {
+                    Some(ParameterType::Pointer)
+                }
+                (Some(ParameterType::Pointer), _) => {
+                    Some(ParameterType::Pointer)
+                }
+                (_, TraceArgument::Value(TraceValue::I32(_))) => {
+                    Some(ParameterType::I32)
+                }
+                (_, TraceArgument::Value(TraceValue::I64(_))) => {
+                    Some(ParameterType::I64)
+                }
+            };
+        }
+
+        import.result = match event.result {
+            TraceResult::Value(TraceValue::I32(_)) => Some(ValueType::I32),
+            TraceResult::Value(TraceValue::I64(_)) => Some(ValueType::I64),
+            TraceResult::None => None,
+            TraceResult::Trap(result) => result,
+        };
+    }
+
+    imports
+}
+
+/// Returns the Rust type corresponding to a Wasm value type.
+fn value_type_name(value_type: ValueType) -> &'static str {
+    match value_type {
+        ValueType::I64 => "i64",
+        _otherwise => "i32",
+    }
+}
+
+/// Returns a Rust literal for the plain value `value`.
+fn value_literal(value: &TraceValue) -> String {
+    match value {
+        TraceValue::I32(value) => format!("{}i32", value),
+        TraceValue::I64(value) => format!("{}i64", value),
+    }
+}
+
+/// Encodes `bytes` in hexadecimal, for decoding by the generated program.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Returns the source of the generated program replaying `events`, recorded
+/// in the trace at `trace_path`.
+fn generate_program(trace_path: &Path, events: &[TraceEvent]) -> String {
+    let mut program = String::new();
+    let imports = imports(events);
+
+    let _ = write!(
+        program,
+        r#"//! # Reproduction of a recorded host-call trace
+//!
+//! Generated by `trace2test` from the trace:
+//!
+//!     {}
+//!
+//! Replays each of the {} host calls recorded in the trace, in order, and
+//! checks that each returns what it returned when the trace was recorded.
+
+use libsupervisionary::raw::ErrorCode;
+use std::convert::TryFrom;
+
+extern "C" {{
+"#,
+        trace_path.display(),
+        events.len()
+    );
+
+    for (name, import) in imports.iter() {
+        let parameters: Vec<String> = import
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(position, parameter)| {
+                let parameter = match parameter {
+                    Some(ParameterType::Pointer) => "*mut u8",
+                    Some(ParameterType::I64) => "i64",
+                    _otherwise => "i32",
+                };
+
+                format!("a{}: {}", position, parameter)
+            })
+            .collect();
+
+        let result = match import.result {
+            Some(result) => format!(" -> {}", value_type_name(result)),
+            None => String::new(),
+        };
+
+        let _ = writeln!(
+            program,
+            "    fn {}({}){};",
+            name,
+            parameters.join(", "),
+            result
+        );
+    }
+
+    let _ = write!(
+        program,
+        r#"}}
+
+/// Decodes the hexadecimal encoding of a region of memory.
+fn decode(encoded: &str) -> Vec<u8> {{
+    (0..encoded.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&encoded[i..i + 2], 16).unwrap())
+        .collect()
+}}
+
+/// Describes a 32-bit result, which might be an error code.
+fn describe(result: i32) -> String {{
+    match ErrorCode::try_from(result) {{
+        Ok(code) => format!("{{}} ({{}})", result, code),
+        Err(_e) => format!("{{}}", result),
+    }}
+}}
+
+fn main() {{
+"#
+    );
+
+    for (call, event) in events.iter().enumerate() {
+        let import = &imports[&event.name];
+
+        if call != 0 {
+            let _ = writeln!(program);
+        }
+
+        let _ = writeln!(program, "    /* Call {}: {}. */", call, event.name);
+
+        let mut arguments = Vec::new();
+
+        for (position, argument) in event.arguments.iter().enumerate() {
+            match argument {
+                TraceArgument::Memory { before, .. } => {
+                    let _ = writeln!(
+                        program,
+                        "    let mut region_{}_{} = decode(\"{}\");",
+                        call,
+                        position,
+                        hex(before)
+                    );
+
+                    arguments.push(format!(
+                        "region_{}_{}.as_mut_ptr()",
+                        call, position
+                    ));
+                }
+                TraceArgument::Value(value) => {
+                    if import.parameters[position]
+                        == Some(ParameterType::Pointer)
+                    {
+                        arguments.push(format!(
+                            "{} as u32 as usize as *mut u8",
+                            value_literal(value)
+                        ));
+                    } else {
+                        arguments.push(value_literal(value));
+                    }
+                }
+            }
+        }
+
+        let invocation = format!("{}({})", event.name, arguments.join(", "));
+
+        match event.result {
+            TraceResult::Value(TraceValue::I32(expected)) => {
+                let _ = writeln!(
+                    program,
+                    "    let result = unsafe {{ {} }};\n    assert_eq!(result, {}i32, \"Call {} to {} returned {{}}, expected {{}}.\", describe(result), describe({}i32));",
+                    invocation, expected, call, event.name, expected
+                );
+            }
+            TraceResult::Value(TraceValue::I64(expected)) => {
+                let _ = writeln!(
+                    program,
+                    "    let result = unsafe {{ {} }};\n    assert_eq!(result, {}i64, \"Call {} to {} returned an unexpected result.\");",
+                    invocation, expected, call, event.name
+                );
+            }
+            TraceResult::None => {
+                let _ = writeln!(program, "    unsafe {{ {} }};", invocation);
+            }
+            TraceResult::Trap(_result) => {
+                let _ = writeln!(
+                    program,
+                    "    /* NB: this call trapped when the trace was recorded. */\n    unsafe {{ {} }};",
+                    invocation
+                );
+            }
+        }
+
+        for (position, argument) in event.arguments.iter().enumerate() {
+            if let TraceArgument::Memory {
+                after: Some(after), ..
+            } = argument
+            {
+                let _ = writeln!(
+                    program,
+                    "    assert_eq!(region_{}_{}, decode(\"{}\"), \"Call {} to {} wrote unexpected results through argument {}.\");",
+                    call, position, hex(after), call, event.name, position
+                );
+            }
+        }
+    }
+
+    program.push_str("}\n");
+    program
+}
+
+/// Returns the manifest of the generated test crate, `name`.
+fn generate_manifest(name: &str) -> String {
+    format!(
+        r#"[package]
+name        = "{}"
+version     = "0.1.0"
+authors     = ["The Supervisionary Development Team"]
+edition     = "2018"
+description = "Reproduction of a recorded host-call trace."
+
+[dependencies]
+libsupervisionary = {{path = "../../libsupervisionary"}}
+
+[profile.release]
+lto           = true
+opt-level     = 3
+codegen-units = 1
+"#,
+        name
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Entry point.
+////////////////////////////////////////////////////////////////////////////////
+
+fn main() {
+    let arguments = parse_command_line_arguments();
+    let events = read_trace(&arguments.trace_path);
+
+    let directory = arguments.output_directory.join(&arguments.name);
+    let source_directory = directory.join("src");
+
+    if directory.exists() {
+        eprintln!(
+            "Test crate directory {} already exists.",
+            directory.display()
+        );
+        exit(1)
+    }
+
+    let written = create_dir_all(&source_directory)
+        .and_then(|_| {
+            write(
+                directory.join("Cargo.toml"),
+                generate_manifest(&arguments.name),
+            )
+        })
+        .and_then(|_| {
+            write(
+                source_directory.join("main.rs"),
+                generate_program(&arguments.trace_path, &events),
+            )
+        });
+
+    if let Err(e) = written {
+        eprintln!("Failed to write test crate.  Error produced: {}.", e);
+        exit(1)
+    }
+
+    println!(
+        "Wrote test crate {} replaying {} host calls.  Add it to the workspace members to build it.",
+        directory.display(),
+        events.len()
+    );
+}
@@ -0,0 +1,100 @@
+//! # Wasm feature detection
+//!
+//! WASMI only accepts modules using the Wasm MVP.  Recent versions of LLVM
+//! enable several post-MVP proposals by default, and the standard library that
+//! recent Rust toolchains ship for `wasm32-unknown-unknown` is itself compiled
+//! with them, so a guest built with a current stable toolchain is often
+//! rejected when loaded, however it was compiled.  WASMI reports only the first
+//! opcode that it failed to decode, so modules are loaded here, and a failure
+//! to decode an opcode introduced by some proposal is reported as the use of an
+//! unsupported feature, naming the proposal, rather than as a malformed module.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::ExecutionError;
+use wasmi::{Error as WasmiError, Module};
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The prefix of the message with which WASMI reports an opcode that it failed
+/// to decode.
+const UNKNOWN_OPCODE_PREFIX: &str = "Unknown opcode ";
+/// The prefix of the message with which WASMI reports a `call_indirect` whose
+/// table index is not encoded as the single byte `0x00` of the MVP.  Linkers
+/// targeting the reference-types proposal may emit a padded encoding instead.
+const INVALID_TABLE_REFERENCE_PREFIX: &str = "Invalid table reference";
+/// The post-MVP proposal that permits table indices other than `0x00`.
+const REFERENCE_TYPES_FEATURE: &str = "reference types";
+
+/// The post-MVP proposals that WASMI does not support, paired with the range of
+/// opcodes, or opcode prefixes, that they introduce.
+const UNSUPPORTED_FEATURES: &[(u8, u8, &str)] = &[
+    (0xc0, 0xc4, "sign-extension operators"),
+    (
+        0xfc,
+        0xfc,
+        "bulk memory operations or non-trapping float-to-int conversions",
+    ),
+    (0xfd, 0xfd, "fixed-width SIMD"),
+    (0xfe, 0xfe, "threads and atomics"),
+];
+
+////////////////////////////////////////////////////////////////////////////////
+// Loading modules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the name of the unsupported Wasm proposal whose opcodes caused
+/// WASMI to fail with `error` when loading a module, if any.
+pub(crate) fn unsupported_feature(error: &WasmiError) -> Option<&'static str> {
+    let message = match error {
+        WasmiError::Validation(message) => message,
+        _otherwise => return None,
+    };
+
+    if message.starts_with(INVALID_TABLE_REFERENCE_PREFIX) {
+        return Some(REFERENCE_TYPES_FEATURE);
+    }
+
+    let opcode: u8 = message
+        .split(UNKNOWN_OPCODE_PREFIX)
+        .nth(1)?
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+
+    UNSUPPORTED_FEATURES
+        .iter()
+        .find(|(first, last, _name)| (*first..=*last).contains(&opcode))
+        .map(|(_first, _last, name)| *name)
+}
+
+/// Loads the Wasm binary `binary`.
+///
+/// # Errors
+///
+/// Returns `Err(ExecutionError::UnsupportedFeature)` if `binary` uses a Wasm
+/// proposal that WASMI does not support, or
+/// `Err(ExecutionError::MalformedModule)` if `binary` is otherwise not a valid
+/// Wasm module.
+pub(crate) fn load_module(binary: &[u8]) -> Result<Module, ExecutionError> {
+    Module::from_buffer(binary).map_err(|e| match unsupported_feature(&e) {
+        Some(feature) => ExecutionError::UnsupportedFeature(feature, e),
+        None => ExecutionError::MalformedModule(e),
+    })
+}
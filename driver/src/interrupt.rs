@@ -0,0 +1,84 @@
+//! # Interrupt handling
+//!
+//! Long-running proofs may need stopping part-way through, without losing the
+//! work done so far.  The first interrupt (`SIGINT`, usually from `Ctrl-C`)
+//! received by the driver therefore only asks the Wasm binary to stop, at its
+//! next host call, after which the driver reports what the Wasm binary managed
+//! to prove.  A second interrupt exits the driver immediately.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use log::{info, warn};
+use std::sync::{atomic::AtomicBool, Arc};
+
+/// The exit code of the driver when interrupted, following the shell
+/// convention of 128 plus the signal number.
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Arranges for the first interrupt received by the driver to set `flag`, and
+/// for the second to exit the driver.  Must be called before any other thread
+/// is spawned, so that the interrupt is delivered to the watching thread.
+#[cfg(unix)]
+pub fn install(flag: Arc<AtomicBool>) {
+    use std::{mem, process::exit, ptr, sync::atomic::Ordering, thread};
+
+    info!("Installing interrupt handler.");
+
+    /* NB: rather than handling the signal asynchronously, where almost
+     * nothing may safely be done, the signal is blocked and a dedicated
+     * thread waits for it to become pending.
+     */
+    let signals = unsafe {
+        let mut signals: libc::sigset_t = mem::zeroed();
+
+        libc::sigemptyset(&mut signals);
+        libc::sigaddset(&mut signals, libc::SIGINT);
+
+        if libc::pthread_sigmask(libc::SIG_BLOCK, &signals, ptr::null_mut())
+            != 0
+        {
+            warn!("Failed to block interrupts, so they cannot be handled.");
+            return;
+        }
+
+        signals
+    };
+
+    thread::spawn(move || loop {
+        let mut signal = 0;
+
+        if unsafe { libc::sigwait(&signals, &mut signal) } != 0 {
+            warn!("Failed to wait for interrupts, so they are now ignored.");
+            return;
+        }
+
+        if flag.swap(true, Ordering::SeqCst) {
+            eprintln!("Interrupted again, exiting immediately.");
+            exit(INTERRUPTED_EXIT_CODE);
+        }
+
+        eprintln!(
+            "Interrupted, stopping at the next host call.  Interrupt again to exit immediately."
+        );
+    });
+}
+
+/// Interrupts cannot be handled on this platform, so leaves the default
+/// behaviour, exiting the driver immediately, in place.
+#[cfg(not(unix))]
+pub fn install(_flag: Arc<AtomicBool>) {
+    warn!("Interrupts cannot be handled on this platform.");
+}
@@ -0,0 +1,394 @@
+//! # Driver library
+//!
+//! The part of the driver application that loads a Wasm binary and executes it
+//! under the Supervisionary kernel's supervision, exposed as a library so that
+//! other programs, such as the integration tests, can execute guests without
+//! going through the driver binary.  Configuring the kernel, and reporting on
+//! its state after execution, is left to the caller, which owns the runtime
+//! state that the guest executes against.  Guests built as dynamically-linked
+//! programs are linked against their side modules by the `linking` module, and
+//! the memories of untrusted guests may be bounded by the `limits` module.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+mod features;
+mod limits;
+mod linking;
+
+use limits::MemoryLimits;
+use log::info;
+use std::fmt::{Display, Error as DisplayError, Formatter};
+use wasmi::{
+    Error as WasmiError, ExternVal, ImportsBuilder, MemoryRef, ModuleInstance,
+    ModuleRef, RuntimeValue,
+};
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The default name of the Wasm module's heap.
+pub const LINEAR_MEMORY_NAME: &str = "memory";
+/// The name of the Wasm entry point.
+pub const WASM_ENTRY_POINT: &str = "main";
+/// The name of the Wasm module's function table, through which guest callbacks
+/// are invoked.
+const WASM_FUNCTION_TABLE_NAME: &str = "__indirect_function_table";
+/// The default name of the module resolved by the Wasmi imports resolver,
+/// unless renamed in the execution options.
+pub const DEFAULT_ENV_MODULE_NAME: &str = "env";
+/// The name of the dedicated module resolved by the Wasmi imports resolver,
+/// whatever the name of the env module, for guests built with
+/// `libsupervisionary`'s `supervisionary-imports` feature.
+const WASMI_SUPERVISIONARY_MODULE_IMPORTS_RESOLVER_NAME: &str =
+    "supervisionary";
+/// The name of the module resolved by the Wasmi imports resolver for guests
+/// built with `libsupervisionary`'s `wasi-imports` feature.
+const WASMI_WASI_MODULE_IMPORTS_RESOLVER_NAME: &str =
+    "supervisionary:kernel/abi";
+
+////////////////////////////////////////////////////////////////////////////////
+// Execution options.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A side module of a dynamically-linked guest, such as a `libsuper.so`-style
+/// module resolving the ABI for its clients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideModule {
+    /// The name of the side module, used when reporting on it.
+    pub name: String,
+    /// The side module's Wasm binary.
+    pub binary: Vec<u8>,
+}
+
+/// Options controlling how a Wasm binary is bound to the kernel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionOptions {
+    /// The name of the exported memory used as the default memory.
+    pub memory_name: String,
+    /// The name of the module from which host calls are resolved in place of
+    /// `env`, for toolchains that place imports of their own in `env`.  Host
+    /// calls are also always resolved from the `supervisionary` and
+    /// `supervisionary:kernel/abi` modules.
+    pub env_module: String,
+    /// Host calls, paired with the name of the exported memory that they should
+    /// read and write instead of the default memory.
+    pub call_memories: Vec<(String, String)>,
+    /// Side modules, loaded in order before the Wasm binary, which is then
+    /// dynamically linked against them.  If there are none, the Wasm binary is
+    /// not dynamically linked.  Otherwise, the memory shared by the modules, if
+    /// any, is used as the default memory in place of any memory exported by
+    /// the Wasm binary.
+    pub side_modules: Vec<SideModule>,
+    /// The number of pages that every memory of the Wasm binary, and of its
+    /// side modules, starts with, at least, if set.
+    pub initial_memory_pages: Option<u32>,
+    /// The number of pages beyond which no memory of the Wasm binary, or of
+    /// its side modules, may grow, if set.
+    pub max_memory_pages: Option<u32>,
+}
+
+impl Default for ExecutionOptions {
+    fn default() -> Self {
+        ExecutionOptions {
+            memory_name: LINEAR_MEMORY_NAME.to_string(),
+            env_module: DEFAULT_ENV_MODULE_NAME.to_string(),
+            call_memories: Vec::new(),
+            side_modules: Vec::new(),
+            initial_memory_pages: None,
+            max_memory_pages: None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Execution errors.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The ways in which executing a Wasm binary may fail.
+#[derive(Debug)]
+pub enum ExecutionError {
+    /// The binary is not a valid Wasm module.
+    MalformedModule(WasmiError),
+    /// The binary uses a Wasm proposal, named first, that the interpreter does
+    /// not support, for example because it was built with a toolchain that
+    /// enables post-MVP features by default.
+    UnsupportedFeature(&'static str, WasmiError),
+    /// The module's imports could not be resolved.
+    Instantiation(WasmiError),
+    /// The module contains a `start` function, which is not supported.
+    HasStart,
+    /// The module does not export a memory with the given name.
+    NoSuchMemory(String),
+    /// The module could not be dynamically linked against its side modules.
+    Linking(String),
+    /// The memory limits could not be imposed upon the module, for example
+    /// because it declares more initial pages than it may grow to.
+    MemoryLimit(String),
+    /// Invoking the module's entry point failed, for example because the guest
+    /// trapped.
+    Invocation(WasmiError),
+    /// Invoking the module's entry point failed once some memory of the guest
+    /// had grown to the limit of pages, given first, so that the guest most
+    /// likely failed to grow it further.
+    MemoryExhausted(u32, WasmiError),
+}
+
+impl Display for ExecutionError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            ExecutionError::MalformedModule(e) => {
+                write!(f, "Failed to load Wasm module.  Error produced: {}.", e)
+            }
+            ExecutionError::UnsupportedFeature(feature, e) => write!(
+                f,
+                "Wasm module uses {}, which are not supported: rebuild it for \
+                 the Wasm MVP, with a toolchain predating Rust 1.82.  Error \
+                 produced: {}.",
+                feature, e
+            ),
+            ExecutionError::Instantiation(e) => write!(
+                f,
+                "Failed to build module instance.  Error produced: {}.",
+                e
+            ),
+            ExecutionError::HasStart => {
+                write!(f, "Wasm module contains 'start' function.")
+            }
+            ExecutionError::NoSuchMemory(name) => write!(
+                f,
+                "Wasm module does not export any memory with name {}.",
+                name
+            ),
+            ExecutionError::Linking(e) => write!(
+                f,
+                "Failed to link Wasm modules.  Error produced: {}.",
+                e
+            ),
+            ExecutionError::MemoryLimit(e) => write!(
+                f,
+                "Failed to limit Wasm module memory.  Error produced: {}.",
+                e
+            ),
+            ExecutionError::Invocation(e) => write!(
+                f,
+                "Failed to invoke '{}' function.  Error produced: {}.",
+                WASM_ENTRY_POINT, e
+            ),
+            ExecutionError::MemoryExhausted(limit, e) => write!(
+                f,
+                "Wasm module exhausted its memory limit of {} pages whilst \
+                 executing '{}' function.  Error produced: {}.",
+                limit, WASM_ENTRY_POINT, e
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Execution.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the linear memory exported with name `name` by the WASM module,
+/// `module`.
+fn get_module_memory(
+    module: &ModuleRef,
+    name: &str,
+) -> Result<MemoryRef, ExecutionError> {
+    match module.export_by_name(name) {
+        Some(ExternVal::Memory(memory)) => Ok(memory),
+        _otherwise => Err(ExecutionError::NoSuchMemory(name.to_string())),
+    }
+}
+
+/// Loads the Wasm binary `binary`, binds its imports to the host calls of
+/// `runtime_state` as directed by `options`, and invokes its entry point,
+/// returning the value that the entry point returned, if any.
+pub fn execute(
+    binary: &[u8],
+    options: &ExecutionOptions,
+    runtime_state: &mut WasmiRuntimeState,
+) -> Result<Option<RuntimeValue>, ExecutionError> {
+    let mut loaded_module = features::load_module(binary)?;
+
+    info!("Wasm binary loaded.");
+
+    let limits = MemoryLimits {
+        initial_pages: options.initial_memory_pages,
+        max_pages: options.max_memory_pages,
+    };
+
+    /* NB: the limits are imposed upon the binary once it is known to be
+     * well-formed, and the binary then reloaded, so that a malformed binary is
+     * reported as such, rather than as one whose memories cannot be limited.
+     */
+    let limited;
+    let limited_side_modules;
+
+    let (binary, side_modules) = if limits.is_empty() {
+        (binary, &options.side_modules[..])
+    } else {
+        limited = limits::limit_memories(binary, &limits)
+            .map_err(ExecutionError::MemoryLimit)?;
+        loaded_module = features::load_module(&limited)?;
+
+        limited_side_modules = options
+            .side_modules
+            .iter()
+            .map(|side_module| {
+                let binary =
+                    limits::limit_memories(&side_module.binary, &limits)
+                        .map_err(|e| {
+                            ExecutionError::MemoryLimit(format!(
+                                "{}: {}",
+                                side_module.name, e
+                            ))
+                        })?;
+
+                Ok(SideModule {
+                    name: side_module.name.clone(),
+                    binary,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        info!("Wasm binary memories limited.");
+
+        (&limited[..], &limited_side_modules[..])
+    };
+
+    /* NB: host calls must be bound to their memories before the imports are
+     * resolved, so memory numbers are assigned here, in the order that the
+     * memories are registered once the module has been instantiated.
+     */
+    let mut memory_names = vec![options.memory_name.clone()];
+
+    for (call, name) in options.call_memories.iter() {
+        let memory = match memory_names.iter().position(|n| n == name) {
+            Some(memory) => memory,
+            None => {
+                memory_names.push(name.clone());
+                memory_names.len() - 1
+            }
+        };
+
+        runtime_state.bind_call_memory(call.clone(), memory);
+    }
+
+    /* NB: the instances of any side modules must outlive the invocation of the
+     * main module's entry point, below, as its imports refer to them weakly.
+     */
+    let (module_ref, shared_memory, _side_modules) =
+        if options.side_modules.is_empty() {
+            let not_started_module = {
+                let imports_resolver = ImportsBuilder::new()
+                    .with_resolver(&options.env_module, &*runtime_state)
+                    .with_resolver(
+                        WASMI_SUPERVISIONARY_MODULE_IMPORTS_RESOLVER_NAME,
+                        &*runtime_state,
+                    )
+                    .with_resolver(
+                        WASMI_WASI_MODULE_IMPORTS_RESOLVER_NAME,
+                        &*runtime_state,
+                    );
+
+                ModuleInstance::new(&loaded_module, &imports_resolver)
+                    .map_err(ExecutionError::Instantiation)?
+            };
+
+            if not_started_module.has_start() {
+                return Err(ExecutionError::HasStart);
+            }
+
+            (not_started_module.assert_no_start(), None, Vec::new())
+        } else {
+            let linked = linking::link(
+                &loaded_module,
+                binary,
+                side_modules,
+                &options.env_module,
+                runtime_state,
+            )?;
+
+            (linked.main, linked.memory, linked.side_modules)
+        };
+
+    info!("Wasmi environment resolver and module instance created.");
+
+    let mut memories = Vec::new();
+
+    for (number, name) in memory_names.iter().enumerate() {
+        /* NB: the default memory replaces any registered whilst linking. */
+        if number == 0 {
+            let memory = match &shared_memory {
+                Some(memory) => memory.clone(),
+                None => get_module_memory(&module_ref, name)?,
+            };
+
+            memories.push(memory.clone());
+            runtime_state.set_memory(memory);
+        } else {
+            let memory = get_module_memory(&module_ref, name)?;
+
+            memories.push(memory.clone());
+            runtime_state.register_memory(memory);
+        }
+
+        info!(
+            "Wasm module memory {} registered with Wasmi runtime state.",
+            name
+        );
+    }
+
+    if let Some(ExternVal::Table(table)) =
+        module_ref.export_by_name(WASM_FUNCTION_TABLE_NAME)
+    {
+        runtime_state.register_table(table);
+
+        info!(
+            "Wasm module function table registered with Wasmi runtime state."
+        );
+    }
+
+    info!("Invoking '{}'...", WASM_ENTRY_POINT);
+
+    /* TODO: scan the binary for 'main' and find if it actually expects
+     *       arguments, or not...
+     */
+    module_ref
+        .invoke_export(
+            WASM_ENTRY_POINT,
+            &[RuntimeValue::I32(0), RuntimeValue::I32(0)],
+            runtime_state,
+        )
+        .map_err(|e| {
+            /* NB: the interpreter reports a failure to grow a memory to the
+             * guest alone, which typically then traps, so a guest that fails
+             * with some memory at the limit is assumed to have exhausted it.
+             */
+            match options.max_memory_pages {
+                Some(limit)
+                    if memories.iter().any(|memory| {
+                        memory.current_size().0 >= limit as usize
+                    }) =>
+                {
+                    ExecutionError::MemoryExhausted(limit, e)
+                }
+                _otherwise => ExecutionError::Invocation(e),
+            }
+        })
+}
@@ -0,0 +1,300 @@
+//! # Memory limits
+//!
+//! An untrusted guest may grow its memory until the host runs out, as Wasm
+//! memories without a declared maximum may grow to 4GiB, and the interpreter
+//! honours whatever maximum the guest declares.  The embedder may therefore
+//! cap the number of pages of every memory of a guest, and may also set the
+//! number of pages that each memory starts with.  Both are enforced before the
+//! guest is loaded, by rewriting the limits of every memory that the guest
+//! defines, or imports, in its binary: the interpreter then refuses to grow a
+//! memory beyond the cap, and growing fails, within the guest, as it would
+//! have done had the guest declared the cap itself.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::linking::read_leb128;
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The identifier of the import section.
+const IMPORT_SECTION_ID: u8 = 2;
+/// The identifier of the memory section.
+const MEMORY_SECTION_ID: u8 = 5;
+/// The kind of an imported function.
+const IMPORT_KIND_FUNCTION: u8 = 0;
+/// The kind of an imported table.
+const IMPORT_KIND_TABLE: u8 = 1;
+/// The kind of an imported memory.
+const IMPORT_KIND_MEMORY: u8 = 2;
+/// The kind of an imported global.
+const IMPORT_KIND_GLOBAL: u8 = 3;
+/// The flags of limits with no maximum.
+const LIMITS_NO_MAXIMUM: u8 = 0;
+/// The flags of limits with a maximum.
+const LIMITS_MAXIMUM: u8 = 1;
+
+////////////////////////////////////////////////////////////////////////////////
+// Memory limits.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The limits imposed upon the memories of a guest, in pages.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct MemoryLimits {
+    /// The number of pages that every memory starts with, at least.
+    pub(crate) initial_pages: Option<u32>,
+    /// The number of pages beyond which no memory may grow.
+    pub(crate) max_pages: Option<u32>,
+}
+
+impl MemoryLimits {
+    /// Returns `true` iff no limits are imposed.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.initial_pages.is_none() && self.max_pages.is_none()
+    }
+
+    /// Returns the limits of a memory declared with `initial` and `maximum`
+    /// pages, once the limits are imposed.
+    fn impose(
+        &self,
+        initial: u32,
+        maximum: Option<u32>,
+    ) -> Result<(u32, Option<u32>), String> {
+        let initial = initial.max(self.initial_pages.unwrap_or(0));
+        let maximum = match (maximum, self.max_pages) {
+            (Some(maximum), Some(cap)) => Some(maximum.min(cap)),
+            (maximum, cap) => maximum.or(cap),
+        };
+
+        match maximum {
+            Some(maximum) if initial > maximum => Err(format!(
+                "memory needs {} initial pages, beyond its maximum of {}",
+                initial, maximum
+            )),
+            _otherwise => Ok((initial, maximum)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Rewriting binaries.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Appends `value` to `buffer`, encoded as an unsigned LEB128 integer.
+fn write_leb128(buffer: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+
+        value >>= 7;
+
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Reads the limits at `offset` in `section`, advancing `offset` past them.
+/// Returns the initial and maximum number of pages, or of slots for tables.
+fn read_limits(
+    section: &[u8],
+    offset: &mut usize,
+) -> Result<(u32, Option<u32>), String> {
+    let malformed = || String::from("malformed limits");
+
+    let flags = *section.get(*offset).ok_or_else(malformed)?;
+
+    *offset += 1;
+
+    let initial = read_leb128(section, offset).ok_or_else(malformed)?;
+    let maximum = match flags {
+        LIMITS_NO_MAXIMUM => None,
+        LIMITS_MAXIMUM => {
+            Some(read_leb128(section, offset).ok_or_else(malformed)?)
+        }
+        _otherwise => return Err(malformed()),
+    };
+
+    Ok((initial, maximum))
+}
+
+/// Appends the limits `initial` and `maximum` to `buffer`.
+fn write_limits(buffer: &mut Vec<u8>, initial: u32, maximum: Option<u32>) {
+    match maximum {
+        None => {
+            buffer.push(LIMITS_NO_MAXIMUM);
+            write_leb128(buffer, initial);
+        }
+        Some(maximum) => {
+            buffer.push(LIMITS_MAXIMUM);
+            write_leb128(buffer, initial);
+            write_leb128(buffer, maximum);
+        }
+    }
+}
+
+/// Reads the limits of a memory at `offset` in `section`, advancing `offset`
+/// past them, and appends them, with `limits` imposed, to `buffer`.
+fn rewrite_limits(
+    section: &[u8],
+    offset: &mut usize,
+    limits: &MemoryLimits,
+    buffer: &mut Vec<u8>,
+) -> Result<(), String> {
+    let (initial, maximum) = read_limits(section, offset)?;
+    let (initial, maximum) = limits.impose(initial, maximum)?;
+
+    write_limits(buffer, initial, maximum);
+
+    Ok(())
+}
+
+/// Copies the name at `offset` in `section` to `buffer`, advancing `offset`
+/// past it.
+fn copy_name(
+    section: &[u8],
+    offset: &mut usize,
+    buffer: &mut Vec<u8>,
+) -> Option<()> {
+    let start = *offset;
+    let length = read_leb128(section, offset)?;
+    let end = offset.checked_add(length as usize)?;
+
+    buffer.extend(section.get(start..end)?);
+    *offset = end;
+
+    Some(())
+}
+
+/// Rewrites the import section, `section`, imposing `limits` on every
+/// imported memory.
+fn rewrite_import_section(
+    section: &[u8],
+    limits: &MemoryLimits,
+) -> Result<Vec<u8>, String> {
+    let malformed = || String::from("malformed import section");
+
+    let mut offset = 0;
+    let mut buffer = Vec::new();
+    let count = read_leb128(section, &mut offset).ok_or_else(malformed)?;
+
+    write_leb128(&mut buffer, count);
+
+    for _i in 0..count {
+        copy_name(section, &mut offset, &mut buffer).ok_or_else(malformed)?;
+        copy_name(section, &mut offset, &mut buffer).ok_or_else(malformed)?;
+
+        let kind = *section.get(offset).ok_or_else(malformed)?;
+        let start = offset;
+
+        offset += 1;
+
+        /* NB: every kind of import but a memory is copied verbatim, once its
+         * end has been found.
+         */
+        match kind {
+            IMPORT_KIND_FUNCTION => {
+                read_leb128(section, &mut offset).ok_or_else(malformed)?;
+            }
+            IMPORT_KIND_TABLE => {
+                offset += 1;
+                read_limits(section, &mut offset)?;
+            }
+            IMPORT_KIND_MEMORY => {
+                buffer.push(kind);
+                rewrite_limits(section, &mut offset, limits, &mut buffer)?;
+                continue;
+            }
+            IMPORT_KIND_GLOBAL => offset += 2,
+            _otherwise => return Err(malformed()),
+        }
+
+        buffer.extend(section.get(start..offset).ok_or_else(malformed)?);
+    }
+
+    Ok(buffer)
+}
+
+/// Rewrites the memory section, `section`, imposing `limits` on every memory.
+fn rewrite_memory_section(
+    section: &[u8],
+    limits: &MemoryLimits,
+) -> Result<Vec<u8>, String> {
+    let malformed = || String::from("malformed memory section");
+
+    let mut offset = 0;
+    let mut buffer = Vec::new();
+    let count = read_leb128(section, &mut offset).ok_or_else(malformed)?;
+
+    write_leb128(&mut buffer, count);
+
+    for _i in 0..count {
+        rewrite_limits(section, &mut offset, limits, &mut buffer)?;
+    }
+
+    Ok(buffer)
+}
+
+/// Returns a copy of the Wasm binary, `binary`, with `limits` imposed upon
+/// every memory that it defines or imports.  The sections of `binary` are
+/// only parsed as far as is needed to find its memories, so a malformed
+/// `binary` may yield an equally malformed copy, which then fails to load.
+///
+/// # Errors
+///
+/// Returns `Err` if some memory must start with more pages than it may grow
+/// to, once `limits` are imposed, or if a section of `binary` that declares
+/// memories is malformed.
+pub(crate) fn limit_memories(
+    binary: &[u8],
+    limits: &MemoryLimits,
+) -> Result<Vec<u8>, String> {
+    let malformed = || String::from("malformed section");
+
+    /* NB: the magic number and version, which precede the sections, are
+     * copied verbatim.
+     */
+    let mut buffer = binary.get(..8).ok_or_else(malformed)?.to_vec();
+    let mut offset = 8;
+
+    while offset < binary.len() {
+        let id = binary[offset];
+
+        offset += 1;
+
+        let size = read_leb128(binary, &mut offset).ok_or_else(malformed)?;
+        let end = offset + size as usize;
+        let section = binary.get(offset..end).ok_or_else(malformed)?;
+
+        let section = match id {
+            IMPORT_SECTION_ID => rewrite_import_section(section, limits)?,
+            MEMORY_SECTION_ID => rewrite_memory_section(section, limits)?,
+            _otherwise => section.to_vec(),
+        };
+
+        buffer.push(id);
+        write_leb128(&mut buffer, section.len() as u32);
+        buffer.extend(section);
+
+        offset = end;
+    }
+
+    Ok(buffer)
+}
@@ -0,0 +1,739 @@
+//! # Dynamic linking
+//!
+//! Guests may be built as dynamically-linked, "shared everything", programs: a
+//! main module, and any number of side modules, in the style of `libsuper.so`,
+//! sharing a single linear memory and function table.  A side module will
+//! typically import the Supervisionary host calls and provide the ABI to its
+//! clients, either by exporting functions that its clients import, or through
+//! the host function table, which the runtime state fills in as the side
+//! module's imports are resolved, and through which its clients may then call
+//! the host calls indirectly.
+//!
+//! Side modules are loaded, in order, before the main module, and every module
+//! may import the functions exported by any side module loaded before it,
+//! which take precedence over the host calls of the same name, so that a side
+//! module may interpose on the ABI.  Following the WebAssembly tool
+//! conventions for dynamic linking, every module with a `dylink.0` section is
+//! given a region of the shared memory, and of the shared table, of its own,
+//! whose bases it imports as `__memory_base` and `__table_base`, and every
+//! module shares a single stack, whose pointer it imports as
+//! `__stack_pointer`.  The addresses of functions and data exported by earlier
+//! modules may be imported from the `GOT.func` and `GOT.mem` modules, whereas
+//! the addresses of a module's own functions and data may not: modules must be
+//! linked so as to refer to these relative to their own bases.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    features::load_module, ExecutionError, SideModule,
+    WASMI_SUPERVISIONARY_MODULE_IMPORTS_RESOLVER_NAME,
+    WASMI_WASI_MODULE_IMPORTS_RESOLVER_NAME, WASM_FUNCTION_TABLE_NAME,
+};
+use log::info;
+use std::cell::RefCell;
+use wasmi::{
+    memory_units::Pages, Error as WasmiError, ExternVal, FuncRef,
+    GlobalDescriptor, GlobalInstance, GlobalRef, ImportsBuilder,
+    MemoryDescriptor, MemoryInstance, MemoryRef, Module, ModuleImportResolver,
+    ModuleInstance, ModuleRef, NotStartedModuleRef, RuntimeValue, Signature,
+    TableDescriptor, TableInstance, TableRef,
+};
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the custom section recording a module's dynamic linking
+/// requirements.
+const DYLINK_SECTION_NAME: &str = "dylink.0";
+/// The type of the subsection of the `dylink.0` section recording a module's
+/// memory and table requirements.
+const DYLINK_MEMORY_INFO: u8 = 1;
+/// The address below which no module's memory region is placed, so that the
+/// null pointer never points into a module's data.
+const MEMORY_BASE: u32 = 1024;
+/// The slot below which no module's table region is placed, so that the null
+/// function pointer never points to a module's function.
+const TABLE_BASE: u32 = 1;
+/// The size, in bytes, of the stack shared by every module.
+const STACK_SIZE: u32 = 64 * 1024;
+/// The alignment, as a power of two, of the stack shared by every module.
+const STACK_ALIGNMENT: u32 = 4;
+/// The size, in bytes, of a page of memory.
+const PAGE_SIZE: u32 = 64 * 1024;
+/// The name of the shared memory.
+const MEMORY_NAME: &str = "memory";
+/// The name of the global through which a module imports the base of its
+/// memory region.
+const MEMORY_BASE_NAME: &str = "__memory_base";
+/// The name of the global through which a module imports the base of its
+/// table region.
+const TABLE_BASE_NAME: &str = "__table_base";
+/// The name of the global through which a module imports the shared stack
+/// pointer.
+const STACK_POINTER_NAME: &str = "__stack_pointer";
+/// The name of the module from which the addresses of functions are imported.
+const GOT_FUNC_MODULE_NAME: &str = "GOT.func";
+/// The name of the module from which the addresses of data are imported.
+const GOT_MEM_MODULE_NAME: &str = "GOT.mem";
+/// The function exported by a position-independent module to relocate its
+/// data once it has been placed.
+const APPLY_DATA_RELOCS_NAME: &str = "__wasm_apply_data_relocs";
+/// The function exported by a module to run its static constructors.
+const CALL_CTORS_NAME: &str = "__wasm_call_ctors";
+
+////////////////////////////////////////////////////////////////////////////////
+// Reading the `dylink.0` section.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The memory and table requirements of a module, as recorded in its
+/// `dylink.0` section.  Alignments are given as powers of two.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct MemoryInfo {
+    /// The size, in bytes, of the module's memory region.
+    memory_size: u32,
+    /// The alignment of the module's memory region.
+    memory_alignment: u32,
+    /// The size, in slots, of the module's table region.
+    table_size: u32,
+    /// The alignment of the module's table region.
+    table_alignment: u32,
+}
+
+/// Reads an unsigned LEB128-encoded integer from `bytes` at `offset`,
+/// advancing `offset` past it.  Returns `None` if the integer is truncated, or
+/// does not fit in 32 bits.
+pub(crate) fn read_leb128(bytes: &[u8], offset: &mut usize) -> Option<u32> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+
+    loop {
+        let byte = *bytes.get(*offset)?;
+
+        *offset += 1;
+
+        if shift >= 32 {
+            return None;
+        }
+
+        result |= u32::from(byte & 0x7f) << shift;
+
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+
+        shift += 7;
+    }
+}
+
+/// Reads the memory and table requirements of the Wasm binary, `binary`, from
+/// its `dylink.0` section, returning `Ok(None)` if it has no such section, and
+/// is therefore not position-independent.  Note that `binary` must already
+/// have been loaded, so that its sections are known to be well-formed.
+fn memory_info(binary: &[u8]) -> Result<Option<MemoryInfo>, String> {
+    let malformed = || format!("malformed {} section", DYLINK_SECTION_NAME);
+
+    /* NB: skip the magic number and version, which precede the sections. */
+    let mut offset = 8;
+
+    while offset < binary.len() {
+        let id = binary[offset];
+
+        offset += 1;
+
+        let size = read_leb128(binary, &mut offset).ok_or_else(malformed)?;
+        let end = offset + size as usize;
+
+        if id == 0 {
+            let mut cursor = offset;
+            let length = read_leb128(&binary[..end], &mut cursor)
+                .ok_or_else(malformed)?;
+            let name = binary
+                .get(cursor..cursor + length as usize)
+                .ok_or_else(malformed)?;
+
+            cursor += length as usize;
+
+            if name == DYLINK_SECTION_NAME.as_bytes() {
+                while cursor < end {
+                    let kind = binary[cursor];
+
+                    cursor += 1;
+
+                    let length = read_leb128(&binary[..end], &mut cursor)
+                        .ok_or_else(malformed)?;
+                    let next = cursor + length as usize;
+
+                    if kind == DYLINK_MEMORY_INFO {
+                        let section =
+                            binary.get(..next).ok_or_else(malformed)?;
+                        let mut field = || {
+                            read_leb128(section, &mut cursor)
+                                .ok_or_else(malformed)
+                        };
+
+                        return Ok(Some(MemoryInfo {
+                            memory_size: field()?,
+                            memory_alignment: field()?,
+                            table_size: field()?,
+                            table_alignment: field()?,
+                        }));
+                    }
+
+                    cursor = next;
+                }
+
+                return Ok(Some(MemoryInfo::default()));
+            }
+        }
+
+        offset = end;
+    }
+
+    Ok(None)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Layout.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The bases of a module's regions of the shared memory and table.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Placement {
+    /// The address of the module's memory region.
+    memory_base: u32,
+    /// The slot of the module's table region.
+    table_base: u32,
+}
+
+/// The layout of the shared memory and table, with a region of each for every
+/// module, followed by the shared stack.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Layout {
+    /// The placement of each module, in the order in which they are loaded.
+    placements: Vec<Placement>,
+    /// The number of pages of memory needed to hold every region.
+    memory_pages: u32,
+    /// The number of slots of the table needed to hold every region.
+    table_size: u32,
+    /// The initial value of the stack pointer, at the top of the stack.
+    stack_pointer: u32,
+}
+
+/// Rounds `value` up to a multiple of `2^alignment`, returning `None` on
+/// overflow.
+fn align(value: u32, alignment: u32) -> Option<u32> {
+    let mask = 1u32.checked_shl(alignment)?.wrapping_sub(1);
+
+    value.checked_add(mask).map(|value| value & !mask)
+}
+
+impl Layout {
+    /// Places a region of the shared memory and table for every module with
+    /// requirements, `infos`, one after the other, followed by the stack.
+    /// Modules without a `dylink.0` section are not position-independent, and
+    /// are given no region of their own.
+    fn new(infos: &[Option<MemoryInfo>]) -> Result<Self, String> {
+        let overflow = || String::from("modules do not fit in memory");
+
+        let mut memory = MEMORY_BASE;
+        let mut table = TABLE_BASE;
+        let mut placements = Vec::with_capacity(infos.len());
+
+        for info in infos {
+            let info = match info {
+                Some(info) => info,
+                None => {
+                    placements.push(Placement::default());
+                    continue;
+                }
+            };
+
+            let placement = Placement {
+                memory_base: align(memory, info.memory_alignment)
+                    .ok_or_else(overflow)?,
+                table_base: align(table, info.table_alignment)
+                    .ok_or_else(overflow)?,
+            };
+
+            memory = placement
+                .memory_base
+                .checked_add(info.memory_size)
+                .ok_or_else(overflow)?;
+            table = placement
+                .table_base
+                .checked_add(info.table_size)
+                .ok_or_else(overflow)?;
+
+            placements.push(placement);
+        }
+
+        let stack_pointer = align(memory, STACK_ALIGNMENT)
+            .and_then(|base| base.checked_add(STACK_SIZE))
+            .ok_or_else(overflow)?;
+
+        Ok(Layout {
+            placements,
+            memory_pages: stack_pointer.div_ceil(PAGE_SIZE),
+            table_size: table,
+            stack_pointer,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The linker, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A module that has been loaded, and linked.
+struct LinkedModule {
+    /// The name of the module.
+    name: String,
+    /// The instance of the module.
+    instance: ModuleRef,
+    /// The placement of the module's regions of the shared memory and table.
+    placement: Placement,
+}
+
+/// The state shared by every module of a dynamically-linked guest.  The
+/// shared memory and table are only allocated once some module imports them,
+/// so that their limits may be chosen to satisfy that module.
+struct Linker {
+    /// The layout of the shared memory and table.
+    layout: Layout,
+    /// The shared memory, if some module has imported it.
+    memory: RefCell<Option<MemoryRef>>,
+    /// The shared table, if some module has imported it.
+    table: RefCell<Option<TableRef>>,
+    /// The shared stack pointer.
+    stack_pointer: GlobalRef,
+    /// The side modules loaded so far, in the order in which they were loaded.
+    modules: Vec<LinkedModule>,
+}
+
+impl Linker {
+    /// Creates a new linker for modules placed by `layout`.
+    fn new(layout: Layout) -> Self {
+        let stack_pointer = GlobalInstance::alloc(
+            RuntimeValue::I32(layout.stack_pointer as i32),
+            true,
+        );
+
+        Linker {
+            layout,
+            memory: RefCell::new(None),
+            table: RefCell::new(None),
+            stack_pointer,
+            modules: Vec::new(),
+        }
+    }
+
+    /// Returns the shared memory, allocating it, or growing it, so that it
+    /// satisfies `descriptor`.
+    fn memory(
+        &self,
+        descriptor: &MemoryDescriptor,
+    ) -> Result<MemoryRef, WasmiError> {
+        let mut memory = self.memory.borrow_mut();
+
+        if let Some(memory) = &*memory {
+            let current = memory.current_size().0;
+            let initial = descriptor.initial() as usize;
+
+            if current < initial {
+                memory.grow(Pages(initial - current))?;
+            }
+
+            return Ok(memory.clone());
+        }
+
+        let initial = self.layout.memory_pages.max(descriptor.initial());
+        let allocated = MemoryInstance::alloc(
+            Pages(initial as usize),
+            descriptor.maximum().map(|maximum| Pages(maximum as usize)),
+        )?;
+
+        *memory = Some(allocated.clone());
+
+        Ok(allocated)
+    }
+
+    /// Returns the shared table, allocating it, or growing it, so that it
+    /// satisfies `descriptor`.
+    fn table(
+        &self,
+        descriptor: &TableDescriptor,
+    ) -> Result<TableRef, WasmiError> {
+        let mut table = self.table.borrow_mut();
+
+        if let Some(table) = &*table {
+            let current = table.current_size();
+
+            if current < descriptor.initial() {
+                table.grow(descriptor.initial() - current)?;
+            }
+
+            return Ok(table.clone());
+        }
+
+        let initial = self.layout.table_size.max(descriptor.initial());
+        let allocated = TableInstance::alloc(initial, descriptor.maximum())?;
+
+        *table = Some(allocated.clone());
+
+        Ok(allocated)
+    }
+
+    /// Returns the function exported as `name` by the earliest side module
+    /// that exports it, if any.
+    fn exported_function(&self, name: &str) -> Option<FuncRef> {
+        self.modules.iter().find_map(|module| {
+            match module.instance.export_by_name(name) {
+                Some(ExternVal::Func(function)) => Some(function),
+                _otherwise => None,
+            }
+        })
+    }
+
+    /// Returns the address of the data exported as `name` by the earliest side
+    /// module that exports it, if any.  Side modules export their data
+    /// relative to the base of their memory region.
+    fn exported_data(&self, name: &str) -> Option<u32> {
+        self.modules.iter().find_map(|module| {
+            match module.instance.export_by_name(name) {
+                Some(ExternVal::Global(global)) => match global.get() {
+                    RuntimeValue::I32(offset) => {
+                        Some(module.placement.memory_base + offset as u32)
+                    }
+                    _otherwise => None,
+                },
+                _otherwise => None,
+            }
+        })
+    }
+
+    /// Shares the shared memory and table, if they have been allocated, with
+    /// the runtime state, `runtime_state`, so that host calls read and write
+    /// the shared memory, and invoke callbacks through the shared table.
+    fn share(&self, runtime_state: &mut WasmiRuntimeState) {
+        if let Some(memory) = &*self.memory.borrow() {
+            runtime_state.set_memory(memory.clone());
+        }
+
+        if let Some(table) = &*self.table.borrow() {
+            runtime_state.register_table(table.clone());
+        }
+    }
+}
+
+/// Returns an error explaining that `name` could not be resolved.
+fn unresolved(name: &str) -> WasmiError {
+    WasmiError::Instantiation(format!("Export {} not found.", name))
+}
+
+/// Resolves the imports, from the env module, of a single module.
+struct ModuleResolver<'a> {
+    /// The state shared by every module.
+    linker: &'a Linker,
+    /// The runtime state providing the host calls.
+    runtime_state: &'a WasmiRuntimeState,
+    /// The placement of the module's regions of the shared memory and table.
+    placement: Placement,
+}
+
+impl<'a> ModuleImportResolver for ModuleResolver<'a> {
+    fn resolve_func(
+        &self,
+        field_name: &str,
+        signature: &Signature,
+    ) -> Result<FuncRef, WasmiError> {
+        match self.linker.exported_function(field_name) {
+            Some(function) => Ok(function),
+            None => self.runtime_state.resolve_func(field_name, signature),
+        }
+    }
+
+    fn resolve_global(
+        &self,
+        field_name: &str,
+        descriptor: &GlobalDescriptor,
+    ) -> Result<GlobalRef, WasmiError> {
+        match field_name {
+            MEMORY_BASE_NAME => Ok(GlobalInstance::alloc(
+                RuntimeValue::I32(self.placement.memory_base as i32),
+                false,
+            )),
+            TABLE_BASE_NAME => Ok(GlobalInstance::alloc(
+                RuntimeValue::I32(self.placement.table_base as i32),
+                false,
+            )),
+            STACK_POINTER_NAME => Ok(self.linker.stack_pointer.clone()),
+            _otherwise => {
+                self.runtime_state.resolve_global(field_name, descriptor)
+            }
+        }
+    }
+
+    fn resolve_memory(
+        &self,
+        field_name: &str,
+        descriptor: &MemoryDescriptor,
+    ) -> Result<MemoryRef, WasmiError> {
+        match field_name {
+            MEMORY_NAME => self.linker.memory(descriptor),
+            _otherwise => Err(unresolved(field_name)),
+        }
+    }
+
+    fn resolve_table(
+        &self,
+        field_name: &str,
+        descriptor: &TableDescriptor,
+    ) -> Result<TableRef, WasmiError> {
+        match field_name {
+            WASM_FUNCTION_TABLE_NAME => self.linker.table(descriptor),
+            _otherwise => {
+                self.runtime_state.resolve_table(field_name, descriptor)
+            }
+        }
+    }
+}
+
+/// Resolves the imports, from the `GOT.func` or `GOT.mem` modules, of the
+/// addresses of functions or data exported by side modules.
+struct GotResolver<'a> {
+    /// The state shared by every module.
+    linker: &'a Linker,
+    /// Whether addresses of functions, rather than of data, are resolved.
+    functions: bool,
+}
+
+impl<'a> ModuleImportResolver for GotResolver<'a> {
+    fn resolve_global(
+        &self,
+        field_name: &str,
+        descriptor: &GlobalDescriptor,
+    ) -> Result<GlobalRef, WasmiError> {
+        let address = if self.functions {
+            let function = self
+                .linker
+                .exported_function(field_name)
+                .ok_or_else(|| unresolved(field_name))?;
+
+            /* NB: the address of a function is its slot of the shared table,
+             * which is extended by a slot for every function whose address is
+             * imported.
+             */
+            let table = self
+                .linker
+                .table
+                .borrow()
+                .clone()
+                .ok_or_else(|| unresolved(WASM_FUNCTION_TABLE_NAME))?;
+            let slot = table.current_size();
+
+            table.grow(1)?;
+            table.set(slot, Some(function))?;
+
+            slot
+        } else {
+            self.linker
+                .exported_data(field_name)
+                .ok_or_else(|| unresolved(field_name))?
+        };
+
+        Ok(GlobalInstance::alloc(
+            RuntimeValue::I32(address as i32),
+            descriptor.is_mutable(),
+        ))
+    }
+}
+
+/// Instantiates the module, `module`, placed at `placement`, resolving its
+/// imports against the side modules already loaded by `linker`, and the host
+/// calls of `runtime_state`.  Imports from `env_module` may be resolved by
+/// either.
+fn instantiate<'m>(
+    module: &'m Module,
+    placement: Placement,
+    linker: &Linker,
+    env_module: &str,
+    runtime_state: &WasmiRuntimeState,
+) -> Result<NotStartedModuleRef<'m>, WasmiError> {
+    let resolver = ModuleResolver {
+        linker,
+        runtime_state,
+        placement,
+    };
+    let functions = GotResolver {
+        linker,
+        functions: true,
+    };
+    let data = GotResolver {
+        linker,
+        functions: false,
+    };
+
+    let imports_resolver = ImportsBuilder::new()
+        .with_resolver(env_module, &resolver)
+        .with_resolver(
+            WASMI_SUPERVISIONARY_MODULE_IMPORTS_RESOLVER_NAME,
+            runtime_state,
+        )
+        .with_resolver(WASMI_WASI_MODULE_IMPORTS_RESOLVER_NAME, runtime_state)
+        .with_resolver(GOT_FUNC_MODULE_NAME, &functions)
+        .with_resolver(GOT_MEM_MODULE_NAME, &data);
+
+    ModuleInstance::new(module, &imports_resolver)
+}
+
+/// Invokes the function exported as `name` by `module`, if it exports one.
+fn invoke_if_exported(
+    module: &ModuleRef,
+    name: &str,
+    runtime_state: &mut WasmiRuntimeState,
+) -> Result<(), WasmiError> {
+    if let Some(ExternVal::Func(_function)) = module.export_by_name(name) {
+        info!("Invoking '{}'...", name);
+
+        module.invoke_export(name, &[], runtime_state)?;
+    }
+
+    Ok(())
+}
+
+/// A dynamically-linked guest, ready for the entry point of its main module to
+/// be invoked.
+pub(crate) struct Linked {
+    /// The instance of the main module.
+    pub(crate) main: ModuleRef,
+    /// The shared memory, if any module imported it.
+    pub(crate) memory: Option<MemoryRef>,
+    /// The instances of the side modules.  Note that functions imported from a
+    /// side module refer to its instance only weakly, so these must be kept
+    /// alive for as long as the main module executes.
+    pub(crate) side_modules: Vec<ModuleRef>,
+}
+
+/// Loads the side modules, `side_modules`, in order, and then the main module,
+/// `main`, loaded from the Wasm binary `binary`, dynamically linking each
+/// against the modules loaded before it, and against the host calls of
+/// `runtime_state`, which shares the modules' memory and table.  Each module
+/// imports both from the env module, named `env_module`.
+pub(crate) fn link(
+    main: &Module,
+    binary: &[u8],
+    side_modules: &[SideModule],
+    env_module: &str,
+    runtime_state: &mut WasmiRuntimeState,
+) -> Result<Linked, ExecutionError> {
+    runtime_state.enable_host_table(true);
+
+    let mut loaded = Vec::with_capacity(side_modules.len());
+    let mut infos = Vec::with_capacity(side_modules.len() + 1);
+
+    for side_module in side_modules {
+        let module = load_module(&side_module.binary)?;
+        let info = memory_info(&side_module.binary).map_err(|e| {
+            ExecutionError::Linking(format!("{}: {}", side_module.name, e))
+        })?;
+
+        loaded.push(module);
+        infos.push(info);
+    }
+
+    infos.push(memory_info(binary).map_err(ExecutionError::Linking)?);
+
+    let layout = Layout::new(&infos).map_err(ExecutionError::Linking)?;
+    let mut linker = Linker::new(layout.clone());
+
+    for ((side_module, module), placement) in side_modules
+        .iter()
+        .zip(loaded.iter())
+        .zip(layout.placements.iter().cloned())
+    {
+        info!(
+            "Linking side module {} at memory base {:#x} and table base {}.",
+            side_module.name, placement.memory_base, placement.table_base
+        );
+
+        let linking_error = |e: WasmiError| {
+            ExecutionError::Linking(format!("{}: {}", side_module.name, e))
+        };
+
+        let not_started =
+            instantiate(module, placement, &linker, env_module, runtime_state)
+                .map_err(linking_error)?;
+
+        /* NB: the side module's start function, and its constructors, may
+         * already make host calls, so the shared memory and table must be
+         * shared with the runtime state beforehand.
+         */
+        linker.share(runtime_state);
+
+        let instance = not_started
+            .run_start(runtime_state)
+            .map_err(|t| linking_error(WasmiError::Trap(t)))?;
+
+        invoke_if_exported(&instance, APPLY_DATA_RELOCS_NAME, runtime_state)
+            .map_err(linking_error)?;
+        invoke_if_exported(&instance, CALL_CTORS_NAME, runtime_state)
+            .map_err(linking_error)?;
+
+        linker.modules.push(LinkedModule {
+            name: side_module.name.clone(),
+            instance,
+            placement,
+        });
+    }
+
+    let placement = layout.placements.last().cloned().unwrap_or_default();
+
+    info!(
+        "Linking main module at memory base {:#x} and table base {}, against side modules: {:?}.",
+        placement.memory_base,
+        placement.table_base,
+        linker.modules.iter().map(|m| m.name.as_str()).collect::<Vec<_>>()
+    );
+
+    let not_started =
+        instantiate(main, placement, &linker, env_module, runtime_state)
+            .map_err(ExecutionError::Instantiation)?;
+
+    if not_started.has_start() {
+        return Err(ExecutionError::HasStart);
+    }
+
+    linker.share(runtime_state);
+
+    let instance = not_started.assert_no_start();
+
+    invoke_if_exported(&instance, APPLY_DATA_RELOCS_NAME, runtime_state)
+        .map_err(ExecutionError::Invocation)?;
+
+    let memory = linker.memory.borrow().clone();
+
+    Ok(Linked {
+        main: instance,
+        memory,
+        side_modules: linker
+            .modules
+            .into_iter()
+            .map(|module| module.instance)
+            .collect(),
+    })
+}
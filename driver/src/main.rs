@@ -2,7 +2,67 @@
 //!
 //! This module defines a top-level "driver" application which takes a path to a
 //! Wasm binary, as a command-line argument, and executes the binary under the
-//! Supervisionary kernel's supervision.
+//! Supervisionary kernel's supervision.  Alternatively, the `selftest`
+//! subcommand exercises the kernel's inference rules directly, without loading
+//! any Wasm binary.  Passing `--repl` enters an interactive prompt once the
+//! Wasm binary has finished executing, for inspecting the theorems that it
+//! registered, and passing `--record-trace` writes a trace of every host call
+//! made by the Wasm binary to a file, which the `trace2test` tool can turn into
+//! a standalone test program.  Passing `--export-state` writes the kernel's
+//! state, once the Wasm binary has finished executing, to a file, and the
+//! `merge` subcommand merges two such files into one, so that theories
+//! developed separately can be combined.  The `run-many` subcommand executes
+//! several Wasm binaries in parallel, each against a kernel of its own,
+//! optionally overlaying a base theory that they share read-only, and reports
+//! what each proved.  The `minimise` subcommand executes a Wasm
+//! binary with proof recording enabled, and reports the lemmas and hypotheses
+//! that the derivation of one of its theorems actually uses, optionally
+//! writing a state pruned down to them.  Interrupting the driver stops the
+//! Wasm binary at its next host call, unless it polls `__should_abort` to stop
+//! itself, and reports what it managed to prove, still writing the kernel's
+//! state if `--export-state` was passed.  If the Wasm binary traps, the
+//! driver reports the last few host calls that it made, with their arguments,
+//! alongside the trap: `--trap-history` sets how many.  Passing
+//! `--side-module`, once for each side module, dynamically links the Wasm
+//! binary against side modules that provide the ABI to it.  Host calls are
+//! imported from the `env` module, or from the module named by `--env-module`
+//! for toolchains that place imports of their own in `env`, as well as from
+//! the dedicated `supervisionary` module.  Passing `--allow-introspection`,
+//! once for each fact, lets the Wasm binary inspect that fact about its own
+//! execution through the experimental `__introspect` host calls.  Passing
+//! `--rule-policy` restricts the Wasm binary to the inference rules named in a
+//! policy file, one per line, refusing every other rule with
+//! `PermissionDenied`, so that course assignments may insist on proofs being
+//! carried out with the rules of natural deduction alone, say.  Passing
+//! `--cost-report` reports the host calls that the Wasm binary made, with the
+//! bytes that each read and wrote, and passing `--simulate-batching` also
+//! estimates how many host calls proposed batched and compound host calls
+//! would have saved, to guide the design of the ABI.  Passing `--allow-reset`
+//! lets the Wasm binary reset the kernel, discarding everything registered so
+//! far, through the privileged `__kernel_reset` host call.  Passing
+//! `--max-memory-pages` caps the number of pages to which any memory of the
+//! Wasm binary may grow, so that an untrusted guest cannot exhaust the host's
+//! memory, and `--initial-memory-pages` sets how many pages each memory starts
+//! with, at least.  Passing `--randomise-handles` makes the kernel issue
+//! handles drawn pseudo-randomly, rather than sequentially, so that the Wasm
+//! binary cannot guess handles that it was never given; as the handles then
+//! differ from run to run, it cannot be combined with `--record-trace`.
+//! Passing `--save-snapshot` writes a snapshot of the kernel, once the Wasm
+//! binary has finished executing, to a file, and passing `--load-snapshot`
+//! restores the kernel from such a file before the Wasm binary starts, so that
+//! every handle issued in the earlier run remains valid.  Unlike the theorems
+//! of a state exported with `--export-state`, those of a snapshot are not
+//! re-proved, so snapshots must only be loaded from trusted files.  As a trace
+//! replays against a fresh kernel, `--load-snapshot` cannot be combined with
+//! `--record-trace`, nor, as the restored kernel issues handles sequentially,
+//! with `--randomise-handles`.  Passing `--only-kernel-proved` alongside
+//! `--export-state` leaves out of the exported state every theorem that
+//! depends upon an axiom postulated by the host, or upon an oracle such as the
+//! linear arithmetic decision procedure, for consumers that only trust the
+//! kernel's inference rules.  Passing `--export-proof` alongside
+//! `--record-proofs` writes the proof traces of the theorems that the Wasm
+//! binary registered, once it has finished executing, to a file as an
+//! OpenTheory article, so that they may be audited by an external checker.
 //!
 //! # Authors
 //!
@@ -19,19 +79,39 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
-use clap::{App, Arg};
+mod interrupt;
+mod minimise;
+mod portfolio;
+mod repl;
+mod selftest;
+
+use clap::{App, AppSettings, Arg, ArgMatches};
+use driver::{
+    execute, ExecutionError, ExecutionOptions, SideModule,
+    DEFAULT_ENV_MODULE_NAME, LINEAR_MEMORY_NAME,
+};
+use kernel::{
+    handle::Handle,
+    runtime_state::RuntimeState as KernelRuntimeState,
+    state_object::{state_merge, StateObjectError},
+    trust::TrustLevel,
+};
 use log::info;
+use portfolio::PortfolioOptions;
 use std::{
+    collections::hash_map::RandomState,
     fs::File,
-    io::Read,
+    hash::{BuildHasher, Hasher},
+    io::{Read, Write},
     path::{Path, PathBuf},
     process::exit,
+    sync::{atomic::AtomicBool, Arc},
+    thread,
 };
-use wasmi::{
-    ExternVal, ImportsBuilder, MemoryRef, Module, ModuleInstance, ModuleRef,
-    RuntimeValue,
+use wasmi_bindings::{
+    cost::CostReport, introspection::Introspection, rule_policy::RulePolicy,
+    runtime_state::WasmiRuntimeState, trace::DecodedTraceEvent,
 };
-use wasmi_bindings::runtime_state::WasmiRuntimeState;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Useful constants.
@@ -44,14 +124,34 @@ const APPLICATION_NAME: &str = "Supervisionary driver.";
 /// Authors of the driver application.
 const AUTHOR_LIST: &str =
     "Dominic P. Mulligan and Nick Spinale, Arm Research, Cambridge.";
-/// The name of the Wasm module's heap.
-const LINEAR_MEMORY_NAME: &str = "memory";
 /// The version number of the driver application.
 const VERSION_NUMBER: &str = "0.1.0";
-/// The name of the Wasm entry point.
-const WASM_ENTRY_POINT: &str = "main";
-/// The name of the module resolved by the Wasmi imports resolver.
-const WASMI_MODULE_IMPORTS_RESOLVER_NAME: &str = "env";
+/// An about message for the help menu of the merge subcommand.
+const MERGE_ABOUT_MESSAGE: &str =
+    "Merges two serialized kernel states, reporting any conflicts";
+/// The name of the merge subcommand.
+const MERGE_COMMAND_NAME: &str = "merge";
+/// An about message for the help menu of the minimise subcommand.
+const MINIMISE_ABOUT_MESSAGE: &str =
+    "Reports the lemmas and hypotheses that the proof of a theorem uses";
+/// The name of the minimise subcommand.
+const MINIMISE_COMMAND_NAME: &str = "minimise";
+/// An about message for the help menu of the run-many subcommand.
+const RUN_MANY_ABOUT_MESSAGE: &str =
+    "Executes several Wasm binaries in parallel, each against its own kernel";
+/// The name of the run-many subcommand.
+const RUN_MANY_COMMAND_NAME: &str = "run-many";
+/// An about message for the help menu of the self-test subcommand.
+const SELFTEST_ABOUT_MESSAGE: &str =
+    "Exercises every kernel inference rule, without loading a Wasm binary";
+/// The name of the self-test subcommand.
+const SELFTEST_COMMAND_NAME: &str = "selftest";
+/// The number of most recent host calls reported alongside a trap, unless
+/// overridden with `--trap-history`.
+const DEFAULT_TRAP_HISTORY: usize = 16;
+/// The number of proposed batched and compound host calls reported by
+/// `--simulate-batching`.
+const BATCHING_PROPOSALS: usize = 10;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Command-line parsing.
@@ -61,38 +161,714 @@ const WASMI_MODULE_IMPORTS_RESOLVER_NAME: &str = "env";
 struct CommandLineArguments {
     /// The path of the Wasm binary to load.
     wasm_binary_path: PathBuf,
+    /// The name of the exported memory used as the default memory.
+    memory_name: String,
+    /// The name of the module from which host calls are resolved in place of
+    /// `env`.
+    env_module: String,
+    /// Host calls, paired with the name of the exported memory that they should
+    /// read and write instead of the default memory.
+    call_memories: Vec<(String, String)>,
+    /// The paths of the side modules to dynamically link the Wasm binary
+    /// against, in the order in which they are loaded.
+    side_module_paths: Vec<PathBuf>,
+    /// The number of pages that each memory of the Wasm binary starts with, at
+    /// least, if set.
+    initial_memory_pages: Option<u32>,
+    /// The number of pages to which any memory of the Wasm binary may grow, if
+    /// set.
+    max_memory_pages: Option<u32>,
+    /// Whether the guest may import experimental host calls.
+    enable_experimental: bool,
+    /// Whether the kernel records a proof term for every theorem registered.
+    record_proofs: bool,
+    /// Whether the kernel warns of theorems duplicating earlier statements.
+    warn_duplicate_theorems: bool,
+    /// Whether the kernel defers type-checking terms until they are used.
+    lazy_validation: bool,
+    /// Whether the kernel issues randomised, rather than sequential, handles.
+    randomise_handles: bool,
+    /// The facts about its own execution that the guest may inspect.
+    introspection: Vec<Introspection>,
+    /// The inference rules that the guest may apply, or `None` if it may
+    /// apply every rule.
+    rule_policy: Option<RulePolicy>,
+    /// Whether the guest may reset the kernel.
+    allow_reset: bool,
+    /// Whether to report the costs of the host calls made.
+    cost_report: bool,
+    /// Whether to estimate the host calls that batched and compound host calls
+    /// would have saved.
+    simulate_batching: bool,
+    /// Whether to enter the REPL once the Wasm binary has finished executing.
+    repl: bool,
+    /// The path of the file to write a trace of every host call to, if any.
+    trace_path: Option<PathBuf>,
+    /// The number of most recent host calls reported alongside a trap.
+    trap_history: usize,
+    /// The path of the file to write the kernel's state to after execution, if
+    /// any.
+    state_path: Option<PathBuf>,
+    /// The least trusted theorems to write to the kernel's state.
+    export_trust: TrustLevel,
+    /// The path of the file to write the proof traces of the theorems
+    /// registered to after execution, if any.
+    proof_path: Option<PathBuf>,
+    /// The path of the file to restore the kernel from before execution, if
+    /// any.
+    load_snapshot_path: Option<PathBuf>,
+    /// The path of the file to write a snapshot of the kernel to after
+    /// execution, if any.
+    save_snapshot_path: Option<PathBuf>,
+}
+
+/// The commands that the driver application may be asked to perform.
+enum Command {
+    /// Load and execute a Wasm binary.
+    Execute(CommandLineArguments),
+    /// Run the kernel self-test.
+    SelfTest,
+    /// Merge two serialized kernel states.
+    Merge {
+        /// The path of the first state to merge.
+        left: PathBuf,
+        /// The path of the second state to merge.
+        right: PathBuf,
+        /// The path to write the merged state to.
+        output: PathBuf,
+    },
+    /// Execute a Wasm binary, and minimise one of the theorems it registered.
+    Minimise {
+        /// The path of the Wasm binary to load.
+        wasm_binary_path: PathBuf,
+        /// How the Wasm binary is bound to the kernel.
+        options: ExecutionOptions,
+        /// Whether the Wasm binary may import experimental host calls.
+        enable_experimental: bool,
+        /// The handle of the theorem to minimise.
+        theorem: usize,
+        /// The path to write the pruned state to, if any.
+        state_path: Option<PathBuf>,
+    },
+    /// Execute several Wasm binaries in parallel.
+    RunMany {
+        /// The paths of the Wasm binaries to load.
+        wasm_binary_paths: Vec<PathBuf>,
+        /// Options shared by every Wasm binary.
+        options: PortfolioOptions,
+        /// The path to write the merged states of the Wasm binaries that
+        /// executed successfully to, if any.
+        state_path: Option<PathBuf>,
+    },
 }
 
 /// Parses the command line arguments of the program, exiting with an error code
 /// if this cannot be done successfully.  Otherwise, packs the command line
-/// arguments into a `CommandLineArguments` value, which is returned.
-fn parse_command_line_arguments() -> CommandLineArguments {
+/// arguments into a `Command` value, which is returned.
+fn parse_command_line_arguments() -> Command {
     info!("Parsing command line arguments.");
 
     let matches = App::new(APPLICATION_NAME)
         .about(ABOUT_MESSAGE)
         .version(VERSION_NUMBER)
         .author(AUTHOR_LIST)
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(
+            App::new(SELFTEST_COMMAND_NAME).about(SELFTEST_ABOUT_MESSAGE),
+        )
+        .subcommand(
+            App::new(MERGE_COMMAND_NAME)
+                .about(MERGE_ABOUT_MESSAGE)
+                .arg(
+                    Arg::new("left")
+                        .required(true)
+                        .index(1)
+                        .help("Path to the first serialized state"),
+                )
+                .arg(
+                    Arg::new("right")
+                        .required(true)
+                        .index(2)
+                        .help("Path to the second serialized state"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .required(true)
+                        .short('o')
+                        .long("output")
+                        .takes_value(true)
+                        .help("Path to write the merged state to"),
+                ),
+        )
+        .subcommand(
+            App::new(MINIMISE_COMMAND_NAME)
+                .about(MINIMISE_ABOUT_MESSAGE)
+                .arg(
+                    Arg::new("wasm-binary-path")
+                        .required(true)
+                        .short('b')
+                        .long("binary")
+                        .takes_value(true)
+                        .help("Path to the Wasm binary to load"),
+                )
+                .arg(
+                    Arg::new("theorem")
+                        .required(true)
+                        .short('t')
+                        .long("theorem")
+                        .takes_value(true)
+                        .help("Handle of the theorem to minimise"),
+                )
+                .arg(
+                    Arg::new("memory-name")
+                        .short('m')
+                        .long("memory-name")
+                        .takes_value(true)
+                        .default_value(LINEAR_MEMORY_NAME)
+                        .help("Name of the exported memory used by host calls"),
+                )
+                .arg(
+                    Arg::new("env-module")
+                        .long("env-module")
+                        .takes_value(true)
+                        .default_value(DEFAULT_ENV_MODULE_NAME)
+                        .help("Name of the module to resolve host calls from in place of env"),
+                )
+                .arg(
+                    Arg::new("enable-experimental")
+                        .long("enable-experimental")
+                        .help("Allows the Wasm binary to import experimental host calls"),
+                )
+                .arg(
+                    Arg::new("export-state")
+                        .long("export-state")
+                        .takes_value(true)
+                        .help("Writes a state pruned down to the theorem and the lemmas it uses to a file"),
+                ),
+        )
+        .subcommand(
+            App::new(RUN_MANY_COMMAND_NAME)
+                .about(RUN_MANY_ABOUT_MESSAGE)
+                .arg(
+                    Arg::new("wasm-binary-path")
+                        .required(true)
+                        .short('b')
+                        .long("binary")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .help("Path to a Wasm binary to load"),
+                )
+                .arg(
+                    Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .takes_value(true)
+                        .help("Maximum number of Wasm binaries executing at once"),
+                )
+                .arg(
+                    Arg::new("memory-name")
+                        .short('m')
+                        .long("memory-name")
+                        .takes_value(true)
+                        .default_value(LINEAR_MEMORY_NAME)
+                        .help("Name of the exported memory used by host calls"),
+                )
+                .arg(
+                    Arg::new("env-module")
+                        .long("env-module")
+                        .takes_value(true)
+                        .default_value(DEFAULT_ENV_MODULE_NAME)
+                        .help("Name of the module to resolve host calls from in place of env"),
+                )
+                .arg(
+                    Arg::new("enable-experimental")
+                        .long("enable-experimental")
+                        .help("Allows the Wasm binaries to import experimental host calls"),
+                )
+                .arg(
+                    Arg::new("record-proofs")
+                        .long("record-proofs")
+                        .help("Records a proof term for every theorem registered"),
+                )
+                .arg(
+                    Arg::new("initial-memory-pages")
+                        .long("initial-memory-pages")
+                        .takes_value(true)
+                        .help("Sets how many pages each memory of the Wasm binaries starts with, at least"),
+                )
+                .arg(
+                    Arg::new("max-memory-pages")
+                        .long("max-memory-pages")
+                        .takes_value(true)
+                        .help("Caps the number of pages to which any memory of the Wasm binaries may grow"),
+                )
+                .arg(
+                    Arg::new("base")
+                        .long("base")
+                        .takes_value(true)
+                        .help("Starts every Wasm binary from a shared, read-only snapshot of a kernel"),
+                )
+                .arg(
+                    Arg::new("export-state")
+                        .long("export-state")
+                        .takes_value(true)
+                        .help("Writes the merged kernel states of the successful Wasm binaries to a file"),
+                ),
+        )
         .arg(
             Arg::new("wasm-binary-path")
                 .required(true)
                 .short('b')
                 .long("binary")
                 .takes_value(true)
-                .about("Path to the Wasm binary to load"),
+                .help("Path to the Wasm binary to load"),
+        )
+        .arg(
+            Arg::new("memory-name")
+                .short('m')
+                .long("memory-name")
+                .takes_value(true)
+                .default_value(LINEAR_MEMORY_NAME)
+                .help("Name of the exported memory used by host calls"),
+        )
+        .arg(
+            Arg::new("env-module")
+                .long("env-module")
+                .takes_value(true)
+                .default_value(DEFAULT_ENV_MODULE_NAME)
+                .help("Name of the module to resolve host calls from in place of env"),
+        )
+        .arg(
+            Arg::new("call-memory")
+                .long("call-memory")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .help("Binds a host call to another exported memory, as CALL=NAME"),
+        )
+        .arg(
+            Arg::new("side-module")
+                .long("side-module")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .help("Path to a side module to dynamically link the Wasm binary against"),
+        )
+        .arg(
+            Arg::new("initial-memory-pages")
+                .long("initial-memory-pages")
+                .takes_value(true)
+                .help("Sets how many pages each memory of the Wasm binary starts with, at least"),
+        )
+        .arg(
+            Arg::new("max-memory-pages")
+                .long("max-memory-pages")
+                .takes_value(true)
+                .help("Caps the number of pages to which any memory of the Wasm binary may grow"),
+        )
+        .arg(
+            Arg::new("enable-experimental")
+                .long("enable-experimental")
+                .help("Allows the Wasm binary to import experimental host calls"),
+        )
+        .arg(
+            Arg::new("record-proofs")
+                .long("record-proofs")
+                .help("Records a proof term for every theorem registered"),
+        )
+        .arg(
+            Arg::new("warn-duplicate-theorems")
+                .long("warn-duplicate-theorems")
+                .help("Warns when a theorem duplicates an earlier statement"),
+        )
+        .arg(
+            Arg::new("lazy-validation")
+                .long("lazy-validation")
+                .help("Defers type-checking terms until they are used in a theorem"),
+        )
+        .arg(
+            Arg::new("randomise-handles")
+                .long("randomise-handles")
+                .conflicts_with("record-trace")
+                .help("Issues handles drawn pseudo-randomly, rather than sequentially"),
+        )
+        .arg(
+            Arg::new("allow-introspection")
+                .long("allow-introspection")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .help("Lets the Wasm binary inspect call-counts, fuel or heap-size"),
+        )
+        .arg(
+            Arg::new("rule-policy")
+                .long("rule-policy")
+                .takes_value(true)
+                .help("Restricts the Wasm binary to the inference rules named in a policy file"),
+        )
+        .arg(
+            Arg::new("allow-reset")
+                .long("allow-reset")
+                .help("Lets the Wasm binary reset the kernel through __kernel_reset"),
+        )
+        .arg(
+            Arg::new("cost-report")
+                .long("cost-report")
+                .help("Reports the host calls made, and the bytes they read and wrote"),
+        )
+        .arg(
+            Arg::new("simulate-batching")
+                .long("simulate-batching")
+                .help("Estimates the host calls that batched and compound host calls would save"),
+        )
+        .arg(
+            Arg::new("repl")
+                .long("repl")
+                .help("Enters a REPL for inspecting theorems after execution"),
+        )
+        .arg(
+            Arg::new("record-trace")
+                .long("record-trace")
+                .takes_value(true)
+                .help("Writes a trace of every host call made to a file"),
+        )
+        .arg(
+            Arg::new("trap-history")
+                .long("trap-history")
+                .takes_value(true)
+                .help("Sets how many recent host calls are reported on a trap"),
+        )
+        .arg(
+            Arg::new("export-state")
+                .long("export-state")
+                .takes_value(true)
+                .help("Writes the kernel's state after execution to a file"),
+        )
+        .arg(
+            Arg::new("only-kernel-proved")
+                .long("only-kernel-proved")
+                .requires("export-state")
+                .help("Only exports theorems proved by the kernel's inference rules alone"),
+        )
+        .arg(
+            Arg::new("export-proof")
+                .long("export-proof")
+                .takes_value(true)
+                .requires("record-proofs")
+                .help("Writes the recorded proofs after execution to a file as an OpenTheory article"),
+        )
+        .arg(
+            Arg::new("load-snapshot")
+                .long("load-snapshot")
+                .takes_value(true)
+                .conflicts_with_all(&["randomise-handles", "record-trace"])
+                .help("Restores the kernel from a trusted snapshot file"),
+        )
+        .arg(
+            Arg::new("save-snapshot")
+                .long("save-snapshot")
+                .takes_value(true)
+                .help("Writes a snapshot of the kernel after execution to a file"),
         )
         .get_matches();
 
-    if let Some(path) = matches.value_of("wasm-binary-path") {
+    if matches.subcommand_matches(SELFTEST_COMMAND_NAME).is_some() {
         info!("Command line arguments successfully parsed.");
 
-        CommandLineArguments {
-            wasm_binary_path: PathBuf::from(path),
+        return Command::SelfTest;
+    }
+
+    if let Some(matches) = matches.subcommand_matches(MERGE_COMMAND_NAME) {
+        info!("Command line arguments successfully parsed.");
+
+        /* NB: all three arguments are required, so clap has already rejected
+         * any command line missing one of them.
+         */
+        return Command::Merge {
+            left: PathBuf::from(matches.value_of("left").unwrap_or_default()),
+            right: PathBuf::from(matches.value_of("right").unwrap_or_default()),
+            output: PathBuf::from(
+                matches.value_of("output").unwrap_or_default(),
+            ),
+        };
+    }
+
+    if let Some(matches) = matches.subcommand_matches(MINIMISE_COMMAND_NAME) {
+        /* NB: both the binary and the theorem are required, so clap has
+         * already rejected any command line missing one of them.
+         */
+        let theorem = matches.value_of("theorem").unwrap_or_default();
+        let theorem = theorem.parse::<usize>().unwrap_or_else(|_e| {
+            eprintln!(
+                "Malformed theorem handle {}, expected a number.",
+                theorem
+            );
+            exit(1)
+        });
+
+        info!("Command line arguments successfully parsed.");
+
+        return Command::Minimise {
+            wasm_binary_path: PathBuf::from(
+                matches.value_of("wasm-binary-path").unwrap_or_default(),
+            ),
+            options: ExecutionOptions {
+                memory_name: matches
+                    .value_of("memory-name")
+                    .unwrap_or(LINEAR_MEMORY_NAME)
+                    .to_string(),
+                env_module: matches
+                    .value_of("env-module")
+                    .unwrap_or(DEFAULT_ENV_MODULE_NAME)
+                    .to_string(),
+                call_memories: Vec::new(),
+                side_modules: Vec::new(),
+                initial_memory_pages: None,
+                max_memory_pages: None,
+            },
+            enable_experimental: matches.is_present("enable-experimental"),
+            theorem,
+            state_path: matches.value_of("export-state").map(PathBuf::from),
+        };
+    }
+
+    if let Some(matches) = matches.subcommand_matches(RUN_MANY_COMMAND_NAME) {
+        let jobs = match matches.value_of("jobs") {
+            Some(jobs) => match jobs.parse::<usize>() {
+                Ok(jobs) if jobs > 0 => jobs,
+                _otherwise => {
+                    eprintln!(
+                        "Malformed job count {}, expected a positive number.",
+                        jobs
+                    );
+                    exit(1)
+                }
+            },
+            None => thread::available_parallelism()
+                .map(|jobs| jobs.get())
+                .unwrap_or(1),
+        };
+
+        let (initial_memory_pages, max_memory_pages) =
+            parse_memory_limits(matches);
+
+        let base = matches
+            .value_of("base")
+            .map(|path| Arc::new(read_snapshot(path).freeze()));
+
+        let execution = ExecutionOptions {
+            memory_name: matches
+                .value_of("memory-name")
+                .unwrap_or(LINEAR_MEMORY_NAME)
+                .to_string(),
+            env_module: matches
+                .value_of("env-module")
+                .unwrap_or(DEFAULT_ENV_MODULE_NAME)
+                .to_string(),
+            call_memories: Vec::new(),
+            side_modules: Vec::new(),
+            initial_memory_pages,
+            max_memory_pages,
+        };
+
+        info!("Command line arguments successfully parsed.");
+
+        return Command::RunMany {
+            wasm_binary_paths: matches
+                .values_of("wasm-binary-path")
+                .map(|values| values.map(PathBuf::from).collect())
+                .unwrap_or_default(),
+            options: PortfolioOptions {
+                execution,
+                enable_experimental: matches.is_present("enable-experimental"),
+                record_proofs: matches.is_present("record-proofs"),
+                jobs,
+                abort: Arc::new(AtomicBool::new(false)),
+                base,
+            },
+            state_path: matches.value_of("export-state").map(PathBuf::from),
+        };
+    }
+
+    let path = match matches.value_of("wasm-binary-path") {
+        Some(path) => path,
+        None => {
+            eprintln!("No Wasm binary path provided as argument.");
+            exit(1)
         }
+    };
+
+    let memory_name = matches
+        .value_of("memory-name")
+        .unwrap_or(LINEAR_MEMORY_NAME)
+        .to_string();
+
+    let env_module = matches
+        .value_of("env-module")
+        .unwrap_or(DEFAULT_ENV_MODULE_NAME)
+        .to_string();
+
+    let call_memories = matches
+        .values_of("call-memory")
+        .map(|values| values.map(parse_call_memory).collect())
+        .unwrap_or_default();
+
+    let side_module_paths = matches
+        .values_of("side-module")
+        .map(|values| values.map(PathBuf::from).collect())
+        .unwrap_or_default();
+
+    let (initial_memory_pages, max_memory_pages) =
+        parse_memory_limits(&matches);
+
+    let enable_experimental = matches.is_present("enable-experimental");
+    let record_proofs = matches.is_present("record-proofs");
+    let warn_duplicate_theorems = matches.is_present("warn-duplicate-theorems");
+    let lazy_validation = matches.is_present("lazy-validation");
+    let randomise_handles = matches.is_present("randomise-handles");
+    let introspection = matches
+        .values_of("allow-introspection")
+        .map(|values| values.map(parse_introspection).collect())
+        .unwrap_or_default();
+    let rule_policy = matches.value_of("rule-policy").map(read_rule_policy);
+    let allow_reset = matches.is_present("allow-reset");
+    let cost_report = matches.is_present("cost-report");
+    let simulate_batching = matches.is_present("simulate-batching");
+    let repl = matches.is_present("repl");
+    let trace_path = matches.value_of("record-trace").map(PathBuf::from);
+    let trap_history = match matches.value_of("trap-history") {
+        Some(count) => count.parse::<usize>().unwrap_or_else(|_e| {
+            eprintln!(
+                "Malformed trap history length {}, expected a number.",
+                count
+            );
+            exit(1)
+        }),
+        None => DEFAULT_TRAP_HISTORY,
+    };
+    let state_path = matches.value_of("export-state").map(PathBuf::from);
+    let export_trust = if matches.is_present("only-kernel-proved") {
+        TrustLevel::KernelProved
     } else {
-        eprintln!("No Wasm binary path provided as argument.");
+        TrustLevel::OracleDependent
+    };
+    let proof_path = matches.value_of("export-proof").map(PathBuf::from);
+    let load_snapshot_path =
+        matches.value_of("load-snapshot").map(PathBuf::from);
+    let save_snapshot_path =
+        matches.value_of("save-snapshot").map(PathBuf::from);
+
+    info!("Command line arguments successfully parsed.");
+
+    Command::Execute(CommandLineArguments {
+        wasm_binary_path: PathBuf::from(path),
+        memory_name,
+        env_module,
+        call_memories,
+        side_module_paths,
+        initial_memory_pages,
+        max_memory_pages,
+        enable_experimental,
+        record_proofs,
+        warn_duplicate_theorems,
+        lazy_validation,
+        randomise_handles,
+        introspection,
+        rule_policy,
+        allow_reset,
+        cost_report,
+        simulate_batching,
+        repl,
+        trace_path,
+        trap_history,
+        state_path,
+        export_trust,
+        proof_path,
+        load_snapshot_path,
+        save_snapshot_path,
+    })
+}
+
+/// Parses the `--initial-memory-pages` and `--max-memory-pages` arguments, if
+/// present, exiting with an error code if either is malformed, or if the
+/// initial number of pages exceeds the maximum.
+fn parse_memory_limits(matches: &ArgMatches) -> (Option<u32>, Option<u32>) {
+    let parse = |name: &str| {
+        matches.value_of(name).map(|pages| {
+            pages.parse::<u32>().unwrap_or_else(|_e| {
+                eprintln!(
+                    "Malformed memory page count {}, expected a number.",
+                    pages
+                );
+                exit(1)
+            })
+        })
+    };
+
+    let initial = parse("initial-memory-pages");
+    let maximum = parse("max-memory-pages");
+
+    if let (Some(initial), Some(maximum)) = (initial, maximum) {
+        if initial > maximum {
+            eprintln!(
+                "Initial memory page count {} exceeds the maximum of {}.",
+                initial, maximum
+            );
+            exit(1)
+        }
+    }
+
+    (initial, maximum)
+}
+
+/// Parses a binding of a host call to an exported memory, of the form
+/// `CALL=NAME`, exiting with an error code if this cannot be done.
+fn parse_call_memory(binding: &str) -> (String, String) {
+    match binding.split_once('=') {
+        Some((call, name)) if !call.is_empty() && !name.is_empty() => {
+            (call.to_string(), name.to_string())
+        }
+        _otherwise => {
+            eprintln!(
+                "Malformed host call memory binding {}, expected CALL=NAME.",
+                binding
+            );
+            exit(1)
+        }
+    }
+}
+
+/// Parses the name of a fact that the guest may inspect about its own
+/// execution, exiting with an error code if this cannot be done.
+fn parse_introspection(fact: &str) -> Introspection {
+    fact.parse::<Introspection>().unwrap_or_else(|e| {
+        eprintln!("{}", e);
         exit(1)
+    })
+}
+
+/// Reads the rule policy stored at `path`, exiting with an error code if this
+/// cannot be done, or if the policy is malformed.
+fn read_rule_policy<P>(path: P) -> RulePolicy
+where
+    P: AsRef<Path>,
+{
+    info!("Reading rule policy {:?}.", path.as_ref());
+
+    let mut content = String::new();
+
+    if let Err(e) =
+        File::open(&path).and_then(|mut f| f.read_to_string(&mut content))
+    {
+        eprintln!(
+            "Failed to read rule policy {:?}.  Error produced: {}.",
+            path.as_ref(),
+            e
+        );
+        exit(1);
     }
+
+    content.parse::<RulePolicy>().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1)
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -124,21 +900,284 @@ where
     content
 }
 
-/// Finds the linear memory of the WASM module, `module`, and returns it,
-/// otherwise creates a fatal error.
-fn get_module_memory(module: &ModuleRef) -> MemoryRef {
-    match module.export_by_name(LINEAR_MEMORY_NAME) {
-        Some(ExternVal::Memory(memory)) => memory,
-        _otherwise => {
+////////////////////////////////////////////////////////////////////////////////
+// Tracing.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes the trace of host calls recorded by `runtime_state` to `path`, one
+/// host call per line, exiting with an error code if this cannot be done.
+fn write_trace<P>(runtime_state: &WasmiRuntimeState, path: P)
+where
+    P: AsRef<Path>,
+{
+    info!("Writing host-call trace to {:?}.", path.as_ref());
+
+    let mut file = File::create(path).unwrap_or_else(|e| {
+        eprintln!("Failed to create trace file.  Error produced: {}.", e);
+        exit(1);
+    });
+
+    for event in runtime_state.trace() {
+        if let Err(e) = writeln!(file, "{}", event) {
+            eprintln!("Failed to write trace file.  Error produced: {}.", e);
+            exit(1);
+        }
+    }
+
+    info!("Host-call trace written successfully.");
+}
+
+/// Reports the most recent host calls made before a trap, as retained by
+/// `runtime_state`, oldest first, if any were.
+fn report_history(runtime_state: &WasmiRuntimeState) {
+    let history = runtime_state.history();
+
+    if history.is_empty() {
+        return;
+    }
+
+    eprintln!("Most recent host calls made before the trap, oldest first:");
+
+    for event in history {
+        eprintln!("    {}", DecodedTraceEvent(event));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Host-call costs.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reports the costs of the host calls made, recorded in `report`, if `costs`
+/// is set, and the crossings that proposed batched and compound host calls
+/// would have saved, if `batching` is set.
+fn report_costs(report: &CostReport, costs: bool, batching: bool) {
+    if costs {
+        println!("{}", report);
+    }
+
+    if batching {
+        let total = report.total().crossings;
+
+        println!(
+            "Crossings that proposed host calls would have saved, of {}:",
+            total
+        );
+
+        for estimate in report.simulate_batching(BATCHING_PROPOSALS) {
+            println!("    {}.", estimate);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Serialized states.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reads the serialized state stored at `path`, exiting with an error code if
+/// this cannot be done.
+fn read_state<P>(path: P) -> Vec<u8>
+where
+    P: AsRef<Path>,
+{
+    info!("Reading serialized state {:?}.", path.as_ref());
+
+    let mut content = Vec::new();
+
+    if let Err(e) =
+        File::open(&path).and_then(|mut f| f.read_to_end(&mut content))
+    {
+        eprintln!(
+            "Failed to read state file {:?}.  Error produced: {}.",
+            path.as_ref(),
+            e
+        );
+        exit(1);
+    }
+
+    content
+}
+
+/// Restores a kernel from the snapshot stored at `path`, exiting with an error
+/// code if this cannot be done.
+fn read_snapshot<P>(path: P) -> KernelRuntimeState
+where
+    P: AsRef<Path>,
+{
+    KernelRuntimeState::snapshot_restore(&read_state(&path)).unwrap_or_else(
+        |e| {
             eprintln!(
-                "Wasm module does not export any memory with name {}.",
-                LINEAR_MEMORY_NAME
+                "Failed to restore snapshot {:?}.  Error produced: {}.",
+                path.as_ref(),
+                e
             );
             exit(1)
+        },
+    )
+}
+
+/// Writes the serialized state `state` to `path`, exiting with an error code if
+/// this cannot be done.
+fn write_state<P>(state: &[u8], path: P)
+where
+    P: AsRef<Path>,
+{
+    info!("Writing serialized state to {:?}.", path.as_ref());
+
+    if let Err(e) = File::create(path).and_then(|mut f| f.write_all(state)) {
+        eprintln!("Failed to write state file.  Error produced: {}.", e);
+        exit(1);
+    }
+
+    info!("Serialized state written successfully.");
+}
+
+/// Merges the serialized states stored at `left` and `right`, writing the
+/// merged state to `output`, and returns `true` iff this succeeded.  Conflicts
+/// between the states are reported one per line.
+fn merge(left: &Path, right: &Path, output: &Path) -> bool {
+    match state_merge(&read_state(left), &read_state(right)) {
+        Ok(merged) => {
+            write_state(&merged, output);
+            println!("States merged successfully.");
+            true
+        }
+        Err(StateObjectError::Conflicts(conflicts)) => {
+            for conflict in conflicts {
+                eprintln!("Conflict: {}.", conflict);
+            }
+
+            false
+        }
+        Err(StateObjectError::Kernel(code)) => {
+            eprintln!("Failed to merge states.  Error produced: {}.", code);
+            false
+        }
+    }
+}
+
+/// Executes the Wasm binary stored at `path`, as directed by `options`, with
+/// proof recording enabled, then minimises the theorem with handle `theorem`,
+/// reporting the lemmas and hypotheses that its derivation uses.  Returns
+/// `true` iff this succeeded.  If `state_path` is given, a state pruned down to
+/// the theorem and its lemmas is written to it.
+fn minimise(
+    path: &Path,
+    options: &ExecutionOptions,
+    enable_experimental: bool,
+    theorem: usize,
+    state_path: Option<&Path>,
+) -> bool {
+    let binary = load_binary(path);
+
+    let mut runtime_state = WasmiRuntimeState::new();
+
+    runtime_state.enable_experimental(enable_experimental);
+    runtime_state.enable_proof_recording(true);
+
+    if let Err(e) = execute(&binary, options, &mut runtime_state) {
+        eprintln!("{}", e);
+        return false;
+    }
+
+    runtime_state.with_kernel(|kernel| {
+        let minimisation = match kernel.theorem_minimise(Handle::from(theorem))
+        {
+            Ok(minimisation) => minimisation,
+            Err(e) => {
+                eprintln!(
+                    "Failed to minimise theorem {}.  Error produced: {}.",
+                    theorem, e
+                );
+                return false;
+            }
+        };
+
+        minimise::report(kernel, &minimisation);
+
+        if let Some(path) = state_path {
+            write_state(&kernel.state_export_minimised(&minimisation), path);
+        }
+
+        true
+    })
+}
+
+/// Executes the Wasm binaries stored at `paths` in parallel, as directed by
+/// `options`, reporting what each registered, and returns `true` iff every
+/// Wasm binary executed successfully.  If `state_path` is given, the kernel
+/// states of the Wasm binaries that executed successfully are merged and
+/// written to it, and `false` is returned if the states conflict.
+fn run_many(
+    paths: &[PathBuf],
+    options: &PortfolioOptions,
+    state_path: Option<&Path>,
+) -> bool {
+    let guests = paths
+        .iter()
+        .map(|path| (path.clone(), load_binary(path)))
+        .collect();
+
+    interrupt::install(options.abort.clone());
+
+    let outcomes = portfolio::run(guests, options);
+
+    portfolio::report(&outcomes);
+
+    let succeeded = outcomes.iter().all(|o| o.result.is_ok());
+
+    let path = match state_path {
+        Some(path) => path,
+        None => return succeeded,
+    };
+
+    match portfolio::merge(&outcomes) {
+        Ok(Some(merged)) => {
+            write_state(&merged, path);
+            succeeded
+        }
+        Ok(None) => {
+            eprintln!("No Wasm binary executed successfully, so no state was written.");
+            false
+        }
+        Err(StateObjectError::Conflicts(conflicts)) => {
+            for conflict in conflicts {
+                eprintln!("Conflict: {}.", conflict);
+            }
+
+            false
+        }
+        Err(StateObjectError::Kernel(code)) => {
+            eprintln!("Failed to merge states.  Error produced: {}.", code);
+            false
         }
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Interruption.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reports the partial results of a Wasm binary stopped by an interrupt: the
+/// number of theorems that it registered, and the challenges that remain open.
+fn report_interrupted(runtime_state: &WasmiRuntimeState) {
+    let (theorems, open) = runtime_state.with_kernel(|kernel| {
+        (
+            kernel.theorem_handles().len(),
+            kernel.challenge_open_services(),
+        )
+    });
+
+    println!(
+        "Wasm module interrupted.  {} theorems registered, {} challenges open.",
+        theorems,
+        open.len()
+    );
+
+    for service in open {
+        println!("Open challenge for service {}.", service);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Entry point.
 ////////////////////////////////////////////////////////////////////////////////
@@ -148,64 +1187,173 @@ fn main() {
 
     info!("Driver program initialized.");
 
-    let command_line_args = parse_command_line_arguments();
+    let command_line_args = match parse_command_line_arguments() {
+        Command::Execute(command_line_args) => command_line_args,
+        Command::SelfTest => {
+            if selftest::run() {
+                exit(0)
+            } else {
+                exit(1)
+            }
+        }
+        Command::Merge {
+            left,
+            right,
+            output,
+        } => {
+            if merge(&left, &right, &output) {
+                exit(0)
+            } else {
+                exit(1)
+            }
+        }
+        Command::Minimise {
+            wasm_binary_path,
+            options,
+            enable_experimental,
+            theorem,
+            state_path,
+        } => {
+            if minimise(
+                &wasm_binary_path,
+                &options,
+                enable_experimental,
+                theorem,
+                state_path.as_deref(),
+            ) {
+                exit(0)
+            } else {
+                exit(1)
+            }
+        }
+        Command::RunMany {
+            wasm_binary_paths,
+            options,
+            state_path,
+        } => {
+            if run_many(&wasm_binary_paths, &options, state_path.as_deref()) {
+                exit(0)
+            } else {
+                exit(1)
+            }
+        }
+    };
 
     let binary = load_binary(&command_line_args.wasm_binary_path);
 
-    let loaded_module = Module::from_buffer(&binary).unwrap_or_else(|e| {
-        eprintln!("Failed to load Wasm module.  Error produced: {}.", e);
-        exit(1);
-    });
+    /* NB: the seed is drawn from the randomly-keyed hasher of the standard
+     * library, which is seeded by the operating system.
+     */
+    let mut runtime_state = if command_line_args.randomise_handles {
+        WasmiRuntimeState::with_randomised_handles(
+            RandomState::new().build_hasher().finish(),
+        )
+    } else {
+        WasmiRuntimeState::new()
+    };
 
-    info!("Wasm binary loaded.");
+    /* NB: the kernel is replaced wholesale, so is restored before any of its
+     * settings are applied.
+     */
+    if let Some(path) = &command_line_args.load_snapshot_path {
+        let kernel = read_snapshot(path);
+        runtime_state.with_kernel(|state| *state = kernel);
+    }
 
-    let mut runtime_state = WasmiRuntimeState::new();
+    runtime_state.enable_experimental(command_line_args.enable_experimental);
+    runtime_state.enable_proof_recording(command_line_args.record_proofs);
+    runtime_state
+        .enable_duplicate_detection(command_line_args.warn_duplicate_theorems);
+    runtime_state.enable_lazy_validation(command_line_args.lazy_validation);
+    runtime_state.enable_tracing(command_line_args.trace_path.is_some());
+    runtime_state.enable_history(command_line_args.trap_history);
 
-    let imports_resolver = ImportsBuilder::new()
-        .with_resolver(WASMI_MODULE_IMPORTS_RESOLVER_NAME, &runtime_state);
+    for fact in &command_line_args.introspection {
+        runtime_state.enable_introspection(*fact, true);
+    }
 
-    let not_started_module = ModuleInstance::new(
-        &loaded_module,
-        &imports_resolver,
-    )
-    .unwrap_or_else(|e| {
-        eprintln!("Failed to build module instance.  Error produced: {}.", e);
-        exit(1);
-    });
+    runtime_state.set_rule_policy(command_line_args.rule_policy.clone());
+    runtime_state.enable_kernel_reset(command_line_args.allow_reset);
+    runtime_state.enable_cost_model(
+        command_line_args.cost_report || command_line_args.simulate_batching,
+    );
 
-    info!("Wasmi environment resolver and module instance created.");
+    interrupt::install(runtime_state.abort_flag());
 
-    if not_started_module.has_start() {
-        eprintln!("Wasm module contains 'start' function.");
-        exit(1);
+    let options = ExecutionOptions {
+        memory_name: command_line_args.memory_name.clone(),
+        env_module: command_line_args.env_module.clone(),
+        call_memories: command_line_args.call_memories.clone(),
+        side_modules: command_line_args
+            .side_module_paths
+            .iter()
+            .map(|path| SideModule {
+                name: path.display().to_string(),
+                binary: load_binary(path),
+            })
+            .collect(),
+        initial_memory_pages: command_line_args.initial_memory_pages,
+        max_memory_pages: command_line_args.max_memory_pages,
     };
 
-    let module_ref = not_started_module.assert_no_start();
+    let return_value = execute(&binary, &options, &mut runtime_state);
+
+    /* NB: the trace is written even if execution fails, as a trace of a
+     * failing execution is the most useful kind for reproducing a bug.
+     */
+    if let Some(path) = &command_line_args.trace_path {
+        write_trace(&runtime_state, path);
+    }
 
-    let memory = get_module_memory(&module_ref);
+    if let Some(report) = runtime_state.cost_report() {
+        report_costs(
+            &report,
+            command_line_args.cost_report,
+            command_line_args.simulate_batching,
+        );
+    }
 
-    runtime_state.set_memory(memory);
+    if let Err(
+        ExecutionError::Invocation(_) | ExecutionError::MemoryExhausted(..),
+    ) = &return_value
+    {
+        if runtime_state.abort_requested() {
+            report_interrupted(&runtime_state);
 
-    info!("Wasm module memory registered with Wasmi runtime state.");
+            if let Some(path) = &command_line_args.state_path {
+                let state = runtime_state.with_kernel(|kernel| {
+                    kernel.state_export_trusted(command_line_args.export_trust)
+                });
+                write_state(&state, path);
+            }
 
-    info!("Invoking 'main'...");
+            if let Some(path) = &command_line_args.proof_path {
+                let article = runtime_state
+                    .with_kernel(|kernel| kernel.proof_trace_export_all());
+                write_state(article.as_bytes(), path);
+            }
 
-    /* TODO: scan the binary for 'main' and find if it actually expects
-     *       arguments, or not...
-     */
-    let return_value = module_ref
-        .invoke_export(
-            WASM_ENTRY_POINT,
-            &[RuntimeValue::I32(0), RuntimeValue::I32(0)],
-            &mut runtime_state,
-        )
-        .unwrap_or_else(|e| {
-            eprintln!(
-                "Failed to invoke '{}' function.  Error produced: {}.",
-                WASM_ENTRY_POINT, e
-            );
-            exit(1)
-        });
+            if let Some(path) = &command_line_args.save_snapshot_path {
+                let snapshot = runtime_state
+                    .with_kernel(|kernel| kernel.snapshot_export());
+                write_state(&snapshot, path);
+            }
+
+            exit(interrupt::INTERRUPTED_EXIT_CODE);
+        }
+    }
+
+    let return_value = return_value.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+
+        if let ExecutionError::Invocation(_)
+        | ExecutionError::MemoryExhausted(..) = e
+        {
+            report_history(&runtime_state);
+        }
+
+        exit(1)
+    });
 
     match return_value {
         Some(value) => {
@@ -218,4 +1366,27 @@ fn main() {
             println!("Wasm module executed successfully.");
         }
     }
+
+    if let Some(path) = &command_line_args.state_path {
+        let state = runtime_state.with_kernel(|kernel| {
+            kernel.state_export_trusted(command_line_args.export_trust)
+        });
+        write_state(&state, path);
+    }
+
+    if let Some(path) = &command_line_args.proof_path {
+        let article =
+            runtime_state.with_kernel(|kernel| kernel.proof_trace_export_all());
+        write_state(article.as_bytes(), path);
+    }
+
+    if let Some(path) = &command_line_args.save_snapshot_path {
+        let snapshot =
+            runtime_state.with_kernel(|kernel| kernel.snapshot_export());
+        write_state(&snapshot, path);
+    }
+
+    if command_line_args.repl {
+        runtime_state.with_kernel(repl::run);
+    }
 }
@@ -0,0 +1,92 @@
+//! # Theorem minimisation reports
+//!
+//! Before a proof artefact is published, it is worth knowing what its main
+//! theorem actually depends on.  The `minimise` subcommand executes a Wasm
+//! binary with proof recording enabled, minimises one of the theorems that it
+//! registered, and reports the hypotheses of the theorem that its derivation
+//! uses, along with every lemma that the derivation uses, each with the
+//! hypotheses that it uses in turn.  Lemmas registered whilst proof recording
+//! was disabled, e.g. imported from elsewhere, are flagged, as nothing is
+//! known of their own derivations.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use kernel::{
+    handle::{tags, Handle},
+    minimise::Minimisation,
+    runtime_state::RuntimeState,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Reporting.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Prints the theorem `theorem`, and those of its hypotheses used by
+/// `minimisation`, one per line, indented by `indent`.
+fn print_theorem(
+    kernel: &RuntimeState,
+    minimisation: &Minimisation,
+    theorem: &Handle<tags::Theorem>,
+    indent: &str,
+) {
+    let statement = kernel
+        .theorem_pretty_print(theorem)
+        .unwrap_or_else(|e| format!("<{}>", e));
+    let total = kernel
+        .theorem_split_premisses(theorem)
+        .map(|premisses| premisses.len())
+        .unwrap_or_default();
+    let used = minimisation.hypotheses_of(theorem).unwrap_or_default();
+
+    println!("{}{}", indent, statement);
+    println!("{}Uses {} of its {} hypotheses:", indent, used.len(), total);
+
+    for hypothesis in used {
+        let hypothesis = kernel
+            .term_pretty_print(hypothesis)
+            .unwrap_or_else(|e| format!("<{}>", e));
+
+        println!("{}    {}", indent, hypothesis);
+    }
+}
+
+/// Prints a report of `minimisation`: the minimised theorem and the hypotheses
+/// that it uses, followed by every lemma used, in the order in which they are
+/// derived.
+pub fn report(kernel: &RuntimeState, minimisation: &Minimisation) {
+    println!("Theorem {}:", **minimisation.theorem());
+    print_theorem(kernel, minimisation, minimisation.theorem(), "    ");
+
+    let registered = kernel.theorem_handles().len();
+
+    println!(
+        "Uses {} of the {} other theorems registered, {} without a recorded proof.",
+        minimisation.lemmas().len(),
+        registered.saturating_sub(1),
+        minimisation.unrecorded().len()
+    );
+
+    for lemma in minimisation.lemmas() {
+        let recorded = if minimisation.unrecorded().contains(lemma) {
+            " (no proof recorded)"
+        } else {
+            ""
+        };
+
+        println!("Lemma {}{}:", **lemma, recorded);
+        print_theorem(kernel, minimisation, lemma, "    ");
+    }
+}
@@ -0,0 +1,227 @@
+//! # Portfolio execution
+//!
+//! Proof search is often embarrassingly parallel: several provers, or one
+//! prover configured several ways, race to prove the same goal.  The
+//! `run-many` subcommand executes each of a list of Wasm binaries in a thread
+//! of its own, against a kernel of its own, so that no guest can observe, or
+//! interfere with, the work of any other.  At most `--jobs` guests execute at
+//! once.  Guests may share a read-only base theory, restored once from a
+//! snapshot and frozen, rather than each restoring its own copy: each guest's
+//! kernel reads the base in place, and copies a table of it only when first
+//! registering something there, so that what one guest registers is never
+//! seen by another.  Once every guest has finished, the driver reports what each
+//! registered, and, if asked, merges the kernel states of the guests that
+//! executed successfully into one.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use driver::{execute, ExecutionOptions};
+use kernel::{
+    runtime_state::FrozenRuntimeState,
+    state_object::{state_merge, StateObjectError},
+};
+use log::info;
+use std::{
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+////////////////////////////////////////////////////////////////////////////////
+// Guests and their outcomes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Options shared by every guest in a portfolio.
+#[derive(Clone, Debug)]
+pub struct PortfolioOptions {
+    /// How each Wasm binary is bound to its kernel.
+    pub execution: ExecutionOptions,
+    /// Whether the guests may import experimental host calls.
+    pub enable_experimental: bool,
+    /// Whether the kernels record a proof term for every theorem registered.
+    pub record_proofs: bool,
+    /// The maximum number of guests executing at once.
+    pub jobs: usize,
+    /// The flag through which every guest is asked to stop.
+    pub abort: Arc<AtomicBool>,
+    /// The frozen base theory from which every guest's kernel starts, if any,
+    /// in place of an empty kernel.
+    pub base: Option<Arc<FrozenRuntimeState>>,
+}
+
+/// What became of a single guest of the portfolio.
+#[derive(Debug)]
+pub struct Outcome {
+    /// The path of the guest's Wasm binary.
+    pub path: PathBuf,
+    /// The value returned by the guest's entry point, rendered for display, or
+    /// a description of why execution failed.
+    pub result: Result<String, String>,
+    /// The number of theorems registered in the guest's kernel.
+    pub theorems: usize,
+    /// How long the guest took to execute.
+    pub elapsed: Duration,
+    /// The guest's kernel state after execution, serialized.
+    pub state: Vec<u8>,
+}
+
+/// Loads and executes the Wasm binary `binary`, stored at `path`, against a
+/// fresh kernel, or one overlaying the base theory, configured by `options`.
+fn run_one(
+    path: PathBuf,
+    binary: &[u8],
+    options: &PortfolioOptions,
+) -> Outcome {
+    info!("Executing portfolio guest {:?}.", path);
+
+    let mut runtime_state = match &options.base {
+        Some(base) => WasmiRuntimeState::from_frozen(base),
+        None => WasmiRuntimeState::new(),
+    };
+
+    runtime_state.enable_experimental(options.enable_experimental);
+    runtime_state.enable_proof_recording(options.record_proofs);
+    runtime_state.share_abort_flag(options.abort.clone());
+
+    let start = Instant::now();
+    let result = execute(binary, &options.execution, &mut runtime_state);
+    let elapsed = start.elapsed();
+
+    let result = match result {
+        Ok(Some(value)) => Ok(format!("{:?}", value)),
+        Ok(None) => Ok(String::from("nothing")),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let (theorems, state) = runtime_state.with_kernel(|kernel| {
+        (kernel.theorem_handles().len(), kernel.state_export())
+    });
+
+    info!("Portfolio guest {:?} finished in {:?}.", path, elapsed);
+
+    Outcome {
+        path,
+        result,
+        theorems,
+        elapsed,
+        state,
+    }
+}
+
+/// Executes every guest in `guests`, pairs of paths and Wasm binaries, each in
+/// a thread of its own and against a kernel of its own, with at most
+/// `options.jobs` guests executing at once.  Returns the outcome of each
+/// guest, in the order in which the guests were given.
+pub fn run(
+    guests: Vec<(PathBuf, Vec<u8>)>,
+    options: &PortfolioOptions,
+) -> Vec<Outcome> {
+    let count = guests.len();
+    let workers = options.jobs.clamp(1, count.max(1));
+
+    info!(
+        "Executing {} portfolio guests with {} workers.",
+        count, workers
+    );
+
+    /* NB: work is handed out from a shared queue, rather than partitioned up
+     * front, so that a worker finishing a quick guest moves straight on to the
+     * next, whilst another is still busy with a slow one.
+     */
+    let queue = Mutex::new(guests.into_iter().enumerate());
+    let outcomes = Mutex::new(Vec::with_capacity(count));
+
+    thread::scope(|scope| {
+        for _worker in 0..workers {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().next();
+
+                match next {
+                    Some((index, (path, binary))) => {
+                        let outcome = run_one(path, &binary, options);
+                        outcomes.lock().unwrap().push((index, outcome));
+                    }
+                    None => return,
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+
+    outcomes.sort_by_key(|(index, _outcome)| *index);
+    outcomes
+        .into_iter()
+        .map(|(_index, outcome)| outcome)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Aggregation.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Prints a summary of `outcomes`, one guest per line, followed by the totals
+/// over every guest.
+pub fn report(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(value) => println!(
+                "{:?}: succeeded in {:.3}s, returning {}.  {} theorems registered.",
+                outcome.path,
+                outcome.elapsed.as_secs_f64(),
+                value,
+                outcome.theorems
+            ),
+            Err(e) => println!(
+                "{:?}: failed after {:.3}s.  {} theorems registered.  {}",
+                outcome.path,
+                outcome.elapsed.as_secs_f64(),
+                outcome.theorems,
+                e
+            ),
+        }
+    }
+
+    let succeeded = outcomes.iter().filter(|o| o.result.is_ok()).count();
+    let theorems: usize = outcomes.iter().map(|o| o.theorems).sum();
+
+    println!(
+        "{} of {} guests succeeded.  {} theorems registered in total.",
+        succeeded,
+        outcomes.len(),
+        theorems
+    );
+}
+
+/// Merges the kernel states of the guests in `outcomes` that executed
+/// successfully into a single serialized state.  Returns `Ok(None)` if no
+/// guest executed successfully.
+pub fn merge(
+    outcomes: &[Outcome],
+) -> Result<Option<Vec<u8>>, StateObjectError> {
+    let mut merged: Option<Vec<u8>> = None;
+
+    for outcome in outcomes.iter().filter(|o| o.result.is_ok()) {
+        merged = Some(match merged {
+            None => outcome.state.clone(),
+            Some(merged) => state_merge(&merged, &outcome.state)?,
+        });
+    }
+
+    Ok(merged)
+}
@@ -0,0 +1,263 @@
+//! # Post-execution REPL
+//!
+//! An interactive prompt, entered once the Wasm binary has finished executing,
+//! for inspecting the theorems that it registered.  The `replay` command prints
+//! every inference step of the recorded derivation of a theorem, from the
+//! leaves upwards, and the `step` command does the same one step at a time.
+//! Derivations are only available for theorems registered whilst proof
+//! recording was enabled, i.e. when the driver is passed `--record-proofs`.
+//! The `reset` command discards everything registered, so that a fresh session
+//! may begin.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use kernel::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    proof::{ProofArgument, Rule},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{
+    collections::HashMap,
+    io::{stdin, stdout, BufRead, Write},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The prompt printed before reading each command.
+const PROMPT: &str = "supervisionary> ";
+/// The help message, listing the commands understood by the REPL.
+const HELP_MESSAGE: &str = "Commands:
+    replay <theorem>    Prints every step of the derivation of a theorem.
+    step <theorem>      As replay, but pauses after each step.  Press enter to
+                        continue, or enter q to stop.
+    reset               Discards every theorem, and every other kernel object,
+                        registered so far.
+    help                Prints this message.
+    quit                Leaves the REPL.";
+
+////////////////////////////////////////////////////////////////////////////////
+// Derivations.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single inference step of a derivation.
+struct Step {
+    /// The theorem derived by this step.
+    theorem: Handle<tags::Theorem>,
+    /// The inference rule applied, or `None` if no proof term was recorded for
+    /// the theorem.
+    rule: Option<Rule>,
+    /// The indices of the earlier steps deriving the premisses of the rule.
+    premisses: Vec<usize>,
+}
+
+/// Returns the steps of the recorded derivation of `theorem`, ordered so that
+/// every step follows the steps deriving its premisses, with the step deriving
+/// `theorem` itself last.  Theorems used more than once appear only once.
+fn derivation(
+    kernel: &RuntimeState,
+    theorem: Handle<tags::Theorem>,
+) -> Result<Vec<Step>, ErrorCode> {
+    if !kernel.theorem_is_registered(&theorem) {
+        return Err(ErrorCode::NoSuchTheoremRegistered);
+    }
+
+    let mut steps: Vec<Step> = Vec::new();
+    let mut indices: HashMap<Handle<tags::Theorem>, usize> = HashMap::new();
+
+    /* NB: an explicit work-list, rather than recursion, as the derivations
+     * produced by automation can be very deep.  Each theorem is visited twice:
+     * once to schedule its premisses, and again to emit its step, once every
+     * premiss has a step of its own.
+     */
+    let mut work = vec![(theorem, false)];
+
+    while let Some((theorem, expanded)) = work.pop() {
+        if indices.contains_key(&theorem) {
+            continue;
+        }
+
+        let proof = kernel.theorem_proof(&theorem).ok();
+        let (rule, premisses) = match &proof {
+            Some(proof) => {
+                let (rule, _theorem, arguments) = kernel.proof_split(proof)?;
+                let premisses: Vec<Handle<tags::Theorem>> = arguments
+                    .iter()
+                    .filter_map(|argument| match argument {
+                        ProofArgument::Theorem(handle) => Some(handle.clone()),
+                        _otherwise => None,
+                    })
+                    .collect();
+
+                (Some(rule), premisses)
+            }
+            None => (None, Vec::new()),
+        };
+
+        if expanded {
+            let premisses = premisses.iter().map(|p| indices[p]).collect();
+
+            indices.insert(theorem.clone(), steps.len());
+            steps.push(Step {
+                theorem,
+                rule,
+                premisses,
+            });
+        } else {
+            work.push((theorem, true));
+
+            for premiss in premisses.into_iter().rev() {
+                if !indices.contains_key(&premiss) {
+                    work.push((premiss, false));
+                }
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+/// Prints the step with index `index` of a derivation.
+fn print_step(kernel: &RuntimeState, index: usize, step: &Step) {
+    let statement = kernel
+        .theorem_pretty_print(&step.theorem)
+        .unwrap_or_else(|e| format!("<{}>", e));
+
+    let rule = match step.rule {
+        Some(rule) => format!("{:?}", rule),
+        None => String::from("(no proof recorded)"),
+    };
+
+    let premisses = if step.premisses.is_empty() {
+        String::new()
+    } else {
+        let premisses: Vec<String> = step
+            .premisses
+            .iter()
+            .map(|premiss| format!("[{}]", premiss))
+            .collect();
+
+        format!(" from {}", premisses.join(", "))
+    };
+
+    println!(
+        "[{}] {}{}, theorem {}:",
+        index, rule, premisses, *step.theorem
+    );
+    println!("    {}", statement);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Commands.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses the argument of a command as a theorem handle.
+fn parse_theorem(argument: Option<&str>) -> Option<Handle<tags::Theorem>> {
+    argument
+        .and_then(|argument| argument.parse::<usize>().ok())
+        .map(Handle::from)
+}
+
+/// Prints the derivation of `theorem`, pausing after every step if `pause` is
+/// `true` until a line is read from `input`.
+fn replay<B>(
+    kernel: &RuntimeState,
+    theorem: Handle<tags::Theorem>,
+    pause: bool,
+    input: &mut B,
+) where
+    B: BufRead,
+{
+    let steps = match derivation(kernel, theorem) {
+        Ok(steps) => steps,
+        Err(e) => {
+            println!("Cannot replay theorem: {}.", e);
+            return;
+        }
+    };
+
+    for (index, step) in steps.iter().enumerate() {
+        print_step(kernel, index, step);
+
+        if pause && index + 1 < steps.len() {
+            let mut line = String::new();
+
+            match input.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) if line.trim() == "q" => return,
+                Ok(_) => (),
+            }
+        }
+    }
+}
+
+/// Runs the REPL against the kernel's runtime state, `kernel`, reading commands
+/// from standard input until it is exhausted or the user quits.
+pub fn run(kernel: &mut RuntimeState) {
+    info!("Entering REPL.");
+
+    println!("Type help for a list of commands.");
+
+    let stdin = stdin();
+    let mut input = stdin.lock();
+
+    loop {
+        print!("{}", PROMPT);
+        let _ = stdout().flush();
+
+        let mut line = String::new();
+
+        match input.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => (),
+            Err(e) => {
+                eprintln!("Failed to read command.  Error produced: {}.", e);
+                break;
+            }
+        }
+
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            None => (),
+            Some(command) if command == "replay" || command == "step" => {
+                let pause = command == "step";
+
+                match parse_theorem(words.next()) {
+                    Some(theorem) => replay(kernel, theorem, pause, &mut input),
+                    None => println!("Expected a theorem handle."),
+                }
+            }
+            Some("reset") => {
+                kernel.reset();
+                println!("Kernel reset.");
+            }
+            Some("help") => println!("{}", HELP_MESSAGE),
+            Some("quit") | Some("exit") => break,
+            Some(command) => {
+                println!(
+                    "Unknown command {}.  Type help for a list of commands.",
+                    command
+                )
+            }
+        }
+    }
+
+    info!("Leaving REPL.");
+}
@@ -0,0 +1,1664 @@
+//! # Kernel consistency self-test
+//!
+//! Exercises each of the kernel's inference rules, and the derived rules
+//! provided by the kernel for efficiency, directly against a fresh runtime
+//! state, without loading any guest program.  Every rule is applied once to a
+//! canonical valid input, checking that the expected theorem is produced, and
+//! once to a canonical invalid input, checking that the expected error code is
+//! returned.  Proof recording is enabled throughout, and the proof term of
+//! every theorem produced is then re-checked by the kernel.  This is intended
+//! as a deployment-time sanity check for embedders shipping Supervisionary as
+//! part of a larger system.
+//!
+//! The definitional principles, for constants, types and recursive functions,
+//! the experimental linear arithmetic decision procedure, and axioms
+//! postulated by the host extend the theory rather than deriving theorems
+//! within it, and are not exercised.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use kernel::{
+    error_code::ErrorCode,
+    handle::{
+        tags, Handle, Kind, PREALLOCATED_HANDLE_TERM_FALSE,
+        PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TYPE_ALPHA,
+        PREALLOCATED_HANDLE_TYPE_BETA, PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    runtime_state::RuntimeState,
+    unfold::UnfoldMode,
+};
+use log::info;
+use std::{
+    any::Any,
+    fmt::Debug,
+    panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Checks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The result of a single check: `Err(reason)` describes why it failed.
+type CheckResult = Result<(), String>;
+
+/// A check of a single inference rule of the kernel.
+struct Check {
+    /// The name of the inference rule being checked.
+    rule: &'static str,
+    /// Runs the check against a freshly-created runtime state.
+    run: fn(&mut RuntimeState) -> CheckResult,
+}
+
+/// All checks performed by the self-test, one per inference rule.
+const CHECKS: &[Check] = &[
+    Check {
+        rule: "assumption",
+        run: check_assumption,
+    },
+    Check {
+        rule: "weaken",
+        run: check_weaken,
+    },
+    Check {
+        rule: "reflexivity",
+        run: check_reflexivity,
+    },
+    Check {
+        rule: "symmetry",
+        run: check_symmetry,
+    },
+    Check {
+        rule: "transitivity",
+        run: check_transitivity,
+    },
+    Check {
+        rule: "application",
+        run: check_application,
+    },
+    Check {
+        rule: "lambda",
+        run: check_lambda,
+    },
+    Check {
+        rule: "beta",
+        run: check_beta,
+    },
+    Check {
+        rule: "eta",
+        run: check_eta,
+    },
+    Check {
+        rule: "iff-introduction",
+        run: check_iff_introduction,
+    },
+    Check {
+        rule: "iff-left-elimination",
+        run: check_iff_left_elimination,
+    },
+    Check {
+        rule: "truth-introduction",
+        run: check_truth_introduction,
+    },
+    Check {
+        rule: "falsity-elimination",
+        run: check_falsity_elimination,
+    },
+    Check {
+        rule: "conjunction-introduction",
+        run: check_conjunction_introduction,
+    },
+    Check {
+        rule: "conjunction-left-elimination",
+        run: check_conjunction_left_elimination,
+    },
+    Check {
+        rule: "conjunction-right-elimination",
+        run: check_conjunction_right_elimination,
+    },
+    Check {
+        rule: "disjunction-left-introduction",
+        run: check_disjunction_left_introduction,
+    },
+    Check {
+        rule: "disjunction-right-introduction",
+        run: check_disjunction_right_introduction,
+    },
+    Check {
+        rule: "disjunction-elimination",
+        run: check_disjunction_elimination,
+    },
+    Check {
+        rule: "implication-introduction",
+        run: check_implication_introduction,
+    },
+    Check {
+        rule: "implication-elimination",
+        run: check_implication_elimination,
+    },
+    Check {
+        rule: "negation-introduction",
+        run: check_negation_introduction,
+    },
+    Check {
+        rule: "negation-elimination",
+        run: check_negation_elimination,
+    },
+    Check {
+        rule: "forall-introduction",
+        run: check_forall_introduction,
+    },
+    Check {
+        rule: "forall-elimination",
+        run: check_forall_elimination,
+    },
+    Check {
+        rule: "exists-introduction",
+        run: check_exists_introduction,
+    },
+    Check {
+        rule: "exists-elimination",
+        run: check_exists_elimination,
+    },
+    Check {
+        rule: "substitute",
+        run: check_substitute,
+    },
+    Check {
+        rule: "type-substitute",
+        run: check_type_substitute,
+    },
+    Check {
+        rule: "conditional-congruence",
+        run: check_conditional_congruence,
+    },
+    Check {
+        rule: "let-congruence",
+        run: check_let_congruence,
+    },
+    Check {
+        rule: "first-projection",
+        run: check_first_projection,
+    },
+    Check {
+        rule: "second-projection",
+        run: check_second_projection,
+    },
+    Check {
+        rule: "surjective-pairing",
+        run: check_surjective_pairing,
+    },
+    Check {
+        rule: "membership-definition",
+        run: check_membership_definition,
+    },
+    Check {
+        rule: "comprehension-definition",
+        run: check_comprehension_definition,
+    },
+    Check {
+        rule: "empty-set-definition",
+        run: check_empty_set_definition,
+    },
+    Check {
+        rule: "universal-set-definition",
+        run: check_universal_set_definition,
+    },
+    Check {
+        rule: "union-definition",
+        run: check_union_definition,
+    },
+    Check {
+        rule: "intersection-definition",
+        run: check_intersection_definition,
+    },
+    Check {
+        rule: "subset-definition",
+        run: check_subset_definition,
+    },
+    Check {
+        rule: "forall-congruence",
+        run: check_forall_congruence,
+    },
+    Check {
+        rule: "exists-congruence",
+        run: check_exists_congruence,
+    },
+    Check {
+        rule: "rewrite-hypothesis",
+        run: check_rewrite_hypothesis,
+    },
+    Check {
+        rule: "assumption-matching",
+        run: check_assumption_matching,
+    },
+    Check {
+        rule: "unfold",
+        run: check_unfold,
+    },
+    Check {
+        rule: "rewrite-at",
+        run: check_rewrite_at,
+    },
+];
+
+/// Inference rules that the self-test does not exercise, paired with the
+/// reason why.
+const SKIPPED: &[(&str, &str)] = &[];
+
+/// A handle that is never allocated in a freshly-created runtime state, used
+/// as a dangling argument to rules.
+const DANGLING_HANDLE: usize = usize::MAX;
+
+////////////////////////////////////////////////////////////////////////////////
+// Utilities.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Lifts a failure to construct the input of a check into a check failure.
+fn build<T>(result: Result<T, ErrorCode>) -> Result<T, String> {
+    result.map_err(|e| format!("failed to construct input: {}", e))
+}
+
+/// Describes the panic, with payload `payload`, raised by a check.
+fn panic_reason(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|m| m.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("no message"));
+
+    format!("the kernel panicked: {}", message)
+}
+
+/// Registers a fresh propositional variable with name `name`.
+fn proposition(
+    state: &mut RuntimeState,
+    name: u64,
+) -> Result<Handle<tags::Term>, String> {
+    build(state.term_register_variable(name, PREALLOCATED_HANDLE_TYPE_PROP))
+}
+
+/// Checks that `result` is a theorem with exactly the premisses `premisses`
+/// and the conclusion `conclusion`.
+fn expect_theorem(
+    state: &RuntimeState,
+    result: Result<Handle<tags::Theorem>, ErrorCode>,
+    mut premisses: Vec<Handle<tags::Term>>,
+    conclusion: Handle<tags::Term>,
+) -> CheckResult {
+    let handle =
+        result.map_err(|e| format!("valid input was rejected with {}", e))?;
+
+    let mut actual_premisses =
+        build(state.theorem_split_premisses(&handle))?.to_vec();
+    let actual_conclusion = build(state.theorem_split_conclusion(&handle))?;
+
+    premisses.sort();
+    actual_premisses.sort();
+
+    if actual_premisses != premisses {
+        return Err(format!(
+            "expected premisses {:?}, but found {:?}",
+            premisses, actual_premisses
+        ));
+    }
+
+    if actual_conclusion != conclusion {
+        return Err(format!(
+            "expected conclusion {}, but found {}",
+            conclusion, actual_conclusion
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks every proof term recorded in `state`.
+fn check_proofs(state: &mut RuntimeState) -> CheckResult {
+    let (proofs, _next) = state.kernel_enumerate(Kind::Proof, 0, usize::MAX);
+
+    for proof in proofs {
+        state.proof_check(Handle::from(proof)).map_err(|e| {
+            format!("recorded proof {} was rejected with {}", proof, e)
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Checks that `result` is the error `expected`.
+fn expect_error<T>(
+    result: Result<T, ErrorCode>,
+    expected: ErrorCode,
+) -> CheckResult
+where
+    T: Debug,
+{
+    match result {
+        Err(e) if e == expected => Ok(()),
+        Err(e) => Err(format!(
+            "invalid input was rejected with {}, expected {}",
+            e, expected
+        )),
+        Ok(value) => {
+            Err(format!("invalid input was accepted, producing {:?}", value))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Structural rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// `{p} ⊢ p`, rejecting non-propositions.
+fn check_assumption(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let x = build(
+        state.term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+
+    let thm = state.theorem_register_assumption(p.clone());
+    expect_theorem(state, thm, vec![p.clone()], p)?;
+
+    expect_error(
+        state.theorem_register_assumption(x),
+        ErrorCode::NotAProposition,
+    )
+}
+
+/// `{q} ⊢ T` from `{} ⊢ T`, rejecting non-propositions.
+fn check_weaken(state: &mut RuntimeState) -> CheckResult {
+    let q = proposition(state, 0)?;
+    let x = build(
+        state.term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let truth = build(state.theorem_register_truth_introduction())?;
+
+    let thm = state.theorem_register_weaken(q.clone(), truth.clone());
+    expect_theorem(state, thm, vec![q], PREALLOCATED_HANDLE_TERM_TRUE)?;
+
+    expect_error(
+        state.theorem_register_weaken(x, truth),
+        ErrorCode::NotAProposition,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Equality rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// `{} ⊢ p = p`, rejecting dangling terms.
+fn check_reflexivity(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let eq = build(state.term_register_equality(p.clone(), p.clone()))?;
+
+    let thm = state.theorem_register_reflexivity::<Handle<tags::Term>, _>(p);
+    expect_theorem(state, thm, vec![], eq)?;
+
+    expect_error(
+        state.theorem_register_reflexivity::<Handle<tags::Term>, _>(Handle::<
+            tags::Term,
+        >::from(
+            DANGLING_HANDLE,
+        )),
+        ErrorCode::NoSuchTermRegistered,
+    )
+}
+
+/// `{p = q} ⊢ q = p`, rejecting non-equalities.
+fn check_symmetry(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let qp = build(state.term_register_equality(q, p.clone()))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+    let other = build(state.theorem_register_assumption(p))?;
+
+    let thm = state.theorem_register_symmetry(hyp);
+    expect_theorem(state, thm, vec![pq], qp)?;
+
+    expect_error(
+        state.theorem_register_symmetry(other),
+        ErrorCode::NotAnEquality,
+    )
+}
+
+/// `{p = q, q = r} ⊢ p = r`, rejecting equalities that do not chain.
+fn check_transitivity(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let r = proposition(state, 2)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let qr = build(state.term_register_equality(q, r.clone()))?;
+    let pr = build(state.term_register_equality(p, r))?;
+    let left = build(state.theorem_register_assumption(pq.clone()))?;
+    let right = build(state.theorem_register_assumption(qr.clone()))?;
+
+    let thm = state.theorem_register_transitivity(&left, &right);
+    expect_theorem(state, thm, vec![pq, qr], pr)?;
+
+    expect_error(
+        state.theorem_register_transitivity(&right, &left),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{f = g, p = q} ⊢ f p = g q`, rejecting ill-typed applications.
+fn check_application(state: &mut RuntimeState) -> CheckResult {
+    let tau = build(state.type_register_function(
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    ))?;
+    let f = build(state.term_register_variable(0u64, tau.clone()))?;
+    let g = build(state.term_register_variable(1u64, tau))?;
+    let p = proposition(state, 2)?;
+    let q = proposition(state, 3)?;
+    let fg = build(state.term_register_equality(f.clone(), g.clone()))?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let fp = build(state.term_register_application(f, p))?;
+    let gq = build(state.term_register_application(g, q))?;
+    let conclusion = build(state.term_register_equality(fp, gq))?;
+    let left = build(state.theorem_register_assumption(fg.clone()))?;
+    let right = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_application(&left, &right);
+    expect_theorem(state, thm, vec![fg, pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_application(&right, &right),
+        ErrorCode::NotAFunctionType,
+    )
+}
+
+/// `{p = q} ⊢ λx:Prop. p = λx:Prop. q`, rejecting dangling types.
+fn check_lambda(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let lp = build(state.term_register_lambda(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        p,
+    ))?;
+    let lq = build(state.term_register_lambda(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        q,
+    ))?;
+    let conclusion = build(state.term_register_equality(lp, lq))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_lambda(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        &hyp,
+    );
+    expect_theorem(state, thm, vec![pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_lambda(
+            2u64,
+            Handle::<tags::Type>::from(DANGLING_HANDLE),
+            &hyp,
+        ),
+        ErrorCode::NoSuchTypeRegistered,
+    )
+}
+
+/// `{} ⊢ (λx:Prop. x ∧ p) T = T ∧ p`, rejecting applications of
+/// non-lambda-abstractions.
+fn check_beta(state: &mut RuntimeState) -> CheckResult {
+    let x = proposition(state, 0)?;
+    let p = proposition(state, 1)?;
+    let body = build(state.term_register_conjunction(x, p.clone()))?;
+    let lambda = build(state.term_register_lambda(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        body,
+    ))?;
+    let redex = build(
+        state.term_register_application(lambda, PREALLOCATED_HANDLE_TERM_TRUE),
+    )?;
+    let reduct =
+        build(state.term_register_conjunction(
+            PREALLOCATED_HANDLE_TERM_TRUE,
+            p.clone(),
+        ))?;
+    let conclusion =
+        build(state.term_register_equality(redex.clone(), reduct))?;
+
+    let thm = state.theorem_register_beta(redex);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    let tau = build(state.type_register_function(
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    ))?;
+    let f = build(state.term_register_variable(2u64, tau))?;
+    let fp = build(state.term_register_application(f, p))?;
+
+    expect_error(state.theorem_register_beta(fp), ErrorCode::NotALambda)
+}
+
+/// `{} ⊢ (λx:Prop. f x) = f`, rejecting lambda-abstractions of other shapes.
+fn check_eta(state: &mut RuntimeState) -> CheckResult {
+    let tau = build(state.type_register_function(
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    ))?;
+    let f = build(state.term_register_variable(0u64, tau))?;
+    let x = proposition(state, 1)?;
+    let fx = build(state.term_register_application(f.clone(), x.clone()))?;
+    let lambda = build(state.term_register_lambda(
+        1u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        fx,
+    ))?;
+    let conclusion = build(state.term_register_equality(lambda.clone(), f))?;
+    let constant = build(state.term_register_lambda(
+        1u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TERM_TRUE,
+    ))?;
+
+    let thm = state.theorem_register_eta(lambda);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_eta(constant),
+        ErrorCode::NotAnApplication,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Propositional rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// `{p ⇒ q, q ⇒ p} ⊢ p = q`, rejecting implications that do not converse.
+fn check_iff_introduction(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_implication(p.clone(), q.clone()))?;
+    let qp = build(state.term_register_implication(q.clone(), p.clone()))?;
+    let conclusion = build(state.term_register_equality(p, q))?;
+    let left = build(state.theorem_register_assumption(pq.clone()))?;
+    let right = build(state.theorem_register_assumption(qp.clone()))?;
+
+    let thm = state.theorem_register_iff_introduction(&left, &right);
+    expect_theorem(state, thm, vec![pq, qp], conclusion)?;
+
+    expect_error(
+        state.theorem_register_iff_introduction(&left, &left),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p = q} ⊢ p ⇒ q`, rejecting non-equalities.
+fn check_iff_left_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let conclusion = build(state.term_register_implication(p.clone(), q))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+    let other = build(state.theorem_register_assumption(p))?;
+
+    let thm = state.theorem_register_iff_left_elimination(hyp);
+    expect_theorem(state, thm, vec![pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_iff_left_elimination(other),
+        ErrorCode::NotAnEquality,
+    )
+}
+
+/// `{} ⊢ T`.  The rule takes no input, so has no invalid case.
+fn check_truth_introduction(state: &mut RuntimeState) -> CheckResult {
+    let thm = state.theorem_register_truth_introduction();
+
+    expect_theorem(state, thm, vec![], PREALLOCATED_HANDLE_TERM_TRUE)
+}
+
+/// `{F} ⊢ p` from `{F} ⊢ F`, rejecting proofs of anything but falsity.
+fn check_falsity_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let hyp = build(
+        state.theorem_register_assumption(PREALLOCATED_HANDLE_TERM_FALSE),
+    )?;
+    let other = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_falsity_elimination(hyp, p.clone());
+    expect_theorem(
+        state,
+        thm,
+        vec![PREALLOCATED_HANDLE_TERM_FALSE],
+        p.clone(),
+    )?;
+
+    expect_error(
+        state.theorem_register_falsity_elimination(other, p),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p, q} ⊢ p ∧ q`, rejecting dangling theorems.
+fn check_conjunction_introduction(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let conclusion =
+        build(state.term_register_conjunction(p.clone(), q.clone()))?;
+    let left = build(state.theorem_register_assumption(p.clone()))?;
+    let right = build(state.theorem_register_assumption(q.clone()))?;
+
+    let thm = state.theorem_register_conjunction_introduction(&left, &right);
+    expect_theorem(state, thm, vec![p, q], conclusion)?;
+
+    expect_error(
+        state.theorem_register_conjunction_introduction(
+            &left,
+            Handle::<tags::Theorem>::from(DANGLING_HANDLE),
+        ),
+        ErrorCode::NoSuchTheoremRegistered,
+    )
+}
+
+/// `{p ∧ q} ⊢ p`, rejecting non-conjunctions.
+fn check_conjunction_left_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_conjunction(p.clone(), q))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+    let other = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_conjunction_left_elimination(hyp);
+    expect_theorem(state, thm, vec![pq], p)?;
+
+    expect_error(
+        state.theorem_register_conjunction_left_elimination(other),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p ∧ q} ⊢ q`, rejecting non-conjunctions.
+fn check_conjunction_right_elimination(
+    state: &mut RuntimeState,
+) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_conjunction(p.clone(), q.clone()))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+    let other = build(state.theorem_register_assumption(p))?;
+
+    let thm = state.theorem_register_conjunction_right_elimination(hyp);
+    expect_theorem(state, thm, vec![pq], q)?;
+
+    expect_error(
+        state.theorem_register_conjunction_right_elimination(other),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p} ⊢ p ∨ q`, rejecting non-propositions.
+fn check_disjunction_left_introduction(
+    state: &mut RuntimeState,
+) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let x = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let conclusion =
+        build(state.term_register_disjunction(p.clone(), q.clone()))?;
+    let hyp = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_disjunction_left_introduction(&hyp, q);
+    expect_theorem(state, thm, vec![p], conclusion)?;
+
+    expect_error(
+        state.theorem_register_disjunction_left_introduction(&hyp, x),
+        ErrorCode::NotAProposition,
+    )
+}
+
+/// `{p} ⊢ q ∨ p`, rejecting non-propositions.
+fn check_disjunction_right_introduction(
+    state: &mut RuntimeState,
+) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let x = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let conclusion =
+        build(state.term_register_disjunction(q.clone(), p.clone()))?;
+    let hyp = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_disjunction_right_introduction(&hyp, q);
+    expect_theorem(state, thm, vec![p], conclusion)?;
+
+    expect_error(
+        state.theorem_register_disjunction_right_introduction(&hyp, x),
+        ErrorCode::NotAProposition,
+    )
+}
+
+/// `{p ∨ q} ⊢ T` from `{p ∨ q, p} ⊢ T` and `{p ∨ q, q} ⊢ T`, rejecting case
+/// analyses whose cases have different conclusions.
+fn check_disjunction_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_disjunction(p.clone(), q.clone()))?;
+    let disjunction = build(state.theorem_register_assumption(pq.clone()))?;
+    let truth = build(state.theorem_register_truth_introduction())?;
+    let left = build(state.theorem_register_weaken(pq.clone(), truth))?;
+    let left_case =
+        build(state.theorem_register_weaken(p.clone(), left.clone()))?;
+    let right_case = build(state.theorem_register_weaken(q, left))?;
+    let other = build(state.theorem_register_weaken(p, disjunction.clone()))?;
+
+    let thm = state.theorem_register_disjunction_elimination(
+        &disjunction,
+        &left_case,
+        &right_case,
+    );
+    expect_theorem(state, thm, vec![pq], PREALLOCATED_HANDLE_TERM_TRUE)?;
+
+    expect_error(
+        state.theorem_register_disjunction_elimination(
+            &disjunction,
+            &other,
+            &right_case,
+        ),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{} ⊢ p ⇒ p` from `{p} ⊢ p`, rejecting hypotheses that are not premisses.
+fn check_implication_introduction(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let conclusion =
+        build(state.term_register_implication(p.clone(), p.clone()))?;
+    let hyp = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_implication_introduction(&hyp, p);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_implication_introduction(&hyp, q),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p ⇒ q, p} ⊢ q`, rejecting mismatched antecedents.
+fn check_implication_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_implication(p.clone(), q.clone()))?;
+    let left = build(state.theorem_register_assumption(pq.clone()))?;
+    let right = build(state.theorem_register_assumption(p.clone()))?;
+    let other = build(state.theorem_register_assumption(q.clone()))?;
+
+    let thm = state.theorem_register_implication_elimination(&left, &right);
+    expect_theorem(state, thm, vec![pq, p], q)?;
+
+    expect_error(
+        state.theorem_register_implication_elimination(&left, &other),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{F} ⊢ ¬p` from `{F, p} ⊢ F`, rejecting theorems whose conclusion is not
+/// falsity.
+fn check_negation_introduction(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let conclusion = build(state.term_register_negation(p.clone()))?;
+    let hyp = build(
+        state.theorem_register_assumption(PREALLOCATED_HANDLE_TERM_FALSE),
+    )?;
+    let hyp = build(state.theorem_register_weaken(p.clone(), hyp))?;
+    let other = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_negation_introduction(&hyp, p.clone());
+    expect_theorem(
+        state,
+        thm,
+        vec![PREALLOCATED_HANDLE_TERM_FALSE],
+        conclusion,
+    )?;
+
+    expect_error(
+        state.theorem_register_negation_introduction(&other, p),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p, ¬p} ⊢ F`, rejecting negations of a different proposition.
+fn check_negation_elimination(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let np = build(state.term_register_negation(p.clone()))?;
+    let left = build(state.theorem_register_assumption(p.clone()))?;
+    let right = build(state.theorem_register_assumption(np.clone()))?;
+    let other = build(state.theorem_register_assumption(q))?;
+
+    let thm = state.theorem_register_negation_elimination(&left, &right);
+    expect_theorem(state, thm, vec![p, np], PREALLOCATED_HANDLE_TERM_FALSE)?;
+
+    expect_error(
+        state.theorem_register_negation_elimination(&other, &right),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p} ⊢ ∀x:Prop. p` from `{p} ⊢ p`, rejecting generalisation over a
+/// variable free in the premisses.
+fn check_forall_introduction(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 1)?;
+    let conclusion = build(state.term_register_forall(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        p.clone(),
+    ))?;
+    let hyp = build(state.theorem_register_assumption(p.clone()))?;
+
+    let thm = state.theorem_register_forall_introduction(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        &hyp,
+    );
+    expect_theorem(state, thm, vec![p], conclusion)?;
+
+    expect_error(
+        state.theorem_register_forall_introduction(
+            1u64,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+            &hyp,
+        ),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{x:Prop = x:Prop} ⊢ x:Prop = x:Prop` from `{x:A = x:A} ⊢ x:A = x:A`,
+/// `{∀x:Prop. x ∧ p} ⊢ T ∧ p`, rejecting instantiations of the wrong type.
+fn check_forall_elimination(state: &mut RuntimeState) -> CheckResult {
+    let x = proposition(state, 0)?;
+    let p = proposition(state, 1)?;
+    let y = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let body = build(state.term_register_conjunction(x, p.clone()))?;
+    let forall = build(state.term_register_forall(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        body,
+    ))?;
+    let conclusion = build(
+        state.term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, p),
+    )?;
+    let hyp = build(state.theorem_register_assumption(forall.clone()))?;
+
+    let thm = state.theorem_register_forall_elimination(
+        &hyp,
+        PREALLOCATED_HANDLE_TERM_TRUE,
+    );
+    expect_theorem(state, thm, vec![forall], conclusion)?;
+
+    expect_error(
+        state.theorem_register_forall_elimination(&hyp, y),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+/// `{T ∧ p} ⊢ ∃x:Prop. x ∧ p` with witness `T`, rejecting witnesses of the
+/// wrong type.
+fn check_exists_introduction(state: &mut RuntimeState) -> CheckResult {
+    let x = proposition(state, 0)?;
+    let p = proposition(state, 1)?;
+    let y = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let body = build(state.term_register_conjunction(x, p.clone()))?;
+    let exists = build(state.term_register_exists(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        body,
+    ))?;
+    let instance = build(
+        state.term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, p),
+    )?;
+    let hyp = build(state.theorem_register_assumption(instance.clone()))?;
+
+    let thm = state.theorem_register_exists_introduction(
+        &hyp,
+        exists.clone(),
+        PREALLOCATED_HANDLE_TERM_TRUE,
+    );
+    expect_theorem(state, thm, vec![instance], exists.clone())?;
+
+    expect_error(
+        state.theorem_register_exists_introduction(&hyp, exists, y),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+/// `{∃x:Prop. x ∧ p} ⊢ p` from `{v ∧ p} ⊢ p`, rejecting eigenvariables free
+/// in the conclusion.
+fn check_exists_elimination(state: &mut RuntimeState) -> CheckResult {
+    let x = proposition(state, 0)?;
+    let p = proposition(state, 1)?;
+    let v = proposition(state, 2)?;
+    let body = build(state.term_register_conjunction(x, p.clone()))?;
+    let exists = build(state.term_register_exists(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        body,
+    ))?;
+    let instance =
+        build(state.term_register_conjunction(v.clone(), p.clone()))?;
+    let left = build(state.theorem_register_assumption(exists.clone()))?;
+    let assumed = build(state.theorem_register_assumption(instance))?;
+    let right = build(
+        state.theorem_register_conjunction_right_elimination(assumed.clone()),
+    )?;
+
+    let thm =
+        state.theorem_register_exists_elimination(&left, v.clone(), &right);
+    expect_theorem(state, thm, vec![exists], p)?;
+
+    expect_error(
+        state.theorem_register_exists_elimination(&left, v, &assumed),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{T ∧ q} ⊢ T ∧ q` from `{p ∧ q} ⊢ p ∧ q`, rejecting ill-typed
+/// substitutions.
+fn check_substitute(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let y = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let pq = build(state.term_register_conjunction(p, q.clone()))?;
+    let expected = build(
+        state.term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, q),
+    )?;
+    let hyp = build(state.theorem_register_assumption(pq))?;
+
+    let thm = state.theorem_register_substitute(
+        &hyp,
+        vec![(
+            (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+            PREALLOCATED_HANDLE_TERM_TRUE,
+        )],
+    );
+    expect_theorem(state, thm, vec![expected.clone()], expected)?;
+
+    expect_error(
+        state.theorem_register_substitute(
+            &hyp,
+            vec![((0u64, PREALLOCATED_HANDLE_TYPE_PROP), y)],
+        ),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+/// rejecting dangling types.
+fn check_type_substitute(state: &mut RuntimeState) -> CheckResult {
+    let x = build(
+        state.term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA),
+    )?;
+    let eq = build(state.term_register_equality(x.clone(), x))?;
+    let hyp = build(state.theorem_register_assumption(eq))?;
+    let y = proposition(state, 0)?;
+    let expected = build(state.term_register_equality(y.clone(), y))?;
+
+    let thm = state.theorem_register_type_substitute(
+        &hyp,
+        vec![(0u64, PREALLOCATED_HANDLE_TYPE_PROP)],
+    );
+    expect_theorem(state, thm, vec![expected.clone()], expected)?;
+
+    expect_error(
+        state.theorem_register_type_substitute(
+            &hyp,
+            vec![(0u64, Handle::<tags::Type>::from(DANGLING_HANDLE))],
+        ),
+        ErrorCode::NoSuchTypeRegistered,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Rules of the bootstrap theory's derived constructs.
+////////////////////////////////////////////////////////////////////////////////
+
+/// `{c = d, p = q, r = s} ⊢ COND c p r = COND d q s`, rejecting non-equalities.
+fn check_conditional_congruence(state: &mut RuntimeState) -> CheckResult {
+    let mut equalities = Vec::new();
+    let mut theorems = Vec::new();
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for name in 0..3u64 {
+        let l = proposition(state, 2 * name)?;
+        let r = proposition(state, 2 * name + 1)?;
+        let eq = build(state.term_register_equality(l.clone(), r.clone()))?;
+
+        theorems.push(build(state.theorem_register_assumption(eq.clone()))?);
+        equalities.push(eq);
+        left.push(l);
+        right.push(r);
+    }
+
+    let lhs = build(state.term_register_conditional(
+        left[0].clone(),
+        left[1].clone(),
+        left[2].clone(),
+    ))?;
+    let rhs = build(state.term_register_conditional(
+        right[0].clone(),
+        right[1].clone(),
+        right[2].clone(),
+    ))?;
+    let conclusion = build(state.term_register_equality(lhs, rhs))?;
+    let truth = build(state.theorem_register_truth_introduction())?;
+
+    let thm = state.theorem_register_conditional_congruence(
+        &theorems[0],
+        &theorems[1],
+        &theorems[2],
+    );
+    expect_theorem(state, thm, equalities, conclusion)?;
+
+    expect_error(
+        state.theorem_register_conditional_congruence(
+            &truth,
+            &theorems[1],
+            &theorems[2],
+        ),
+        ErrorCode::NotAnEquality,
+    )
+}
+
+/// `{f = g, p = q} ⊢ LET f p = LET g q`, rejecting non-equalities.
+fn check_let_congruence(state: &mut RuntimeState) -> CheckResult {
+    let tau = build(state.type_register_function(
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    ))?;
+    let f = build(state.term_register_variable(0u64, tau.clone()))?;
+    let g = build(state.term_register_variable(1u64, tau))?;
+    let p = proposition(state, 2)?;
+    let q = proposition(state, 3)?;
+    let fg = build(state.term_register_equality(f.clone(), g.clone()))?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let lhs = build(state.term_register_let(f, p))?;
+    let rhs = build(state.term_register_let(g, q))?;
+    let conclusion = build(state.term_register_equality(lhs, rhs))?;
+    let function = build(state.theorem_register_assumption(fg.clone()))?;
+    let argument = build(state.theorem_register_assumption(pq.clone()))?;
+    let truth = build(state.theorem_register_truth_introduction())?;
+
+    let thm = state.theorem_register_let_congruence(&function, &argument);
+    expect_theorem(state, thm, vec![fg, pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_let_congruence(&function, &truth),
+        ErrorCode::NotAnEquality,
+    )
+}
+
+/// `{} ⊢ FST (PAIR x p) = x`, rejecting dangling terms.
+fn check_first_projection(state: &mut RuntimeState) -> CheckResult {
+    let x = build(
+        state.term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let p = proposition(state, 1)?;
+    let pair = build(state.term_register_pair(x.clone(), p.clone()))?;
+    let first = build(state.term_register_first(pair))?;
+    let conclusion = build(state.term_register_equality(first, x.clone()))?;
+
+    let thm = state.theorem_register_first_projection(x, p.clone());
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_first_projection(
+            Handle::<tags::Term>::from(DANGLING_HANDLE),
+            p,
+        ),
+        ErrorCode::NoSuchTermRegistered,
+    )
+}
+
+/// `{} ⊢ SND (PAIR x p) = p`, rejecting dangling terms.
+fn check_second_projection(state: &mut RuntimeState) -> CheckResult {
+    let x = build(
+        state.term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let p = proposition(state, 1)?;
+    let pair = build(state.term_register_pair(x.clone(), p.clone()))?;
+    let second = build(state.term_register_second(pair))?;
+    let conclusion = build(state.term_register_equality(second, p.clone()))?;
+
+    let thm = state.theorem_register_second_projection(x.clone(), p);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_second_projection(
+            x,
+            Handle::<tags::Term>::from(DANGLING_HANDLE),
+        ),
+        ErrorCode::NoSuchTermRegistered,
+    )
+}
+
+/// `{} ⊢ PAIR (FST z) (SND z) = z`, rejecting terms not of product type.
+fn check_surjective_pairing(state: &mut RuntimeState) -> CheckResult {
+    let x = build(
+        state.term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let p = proposition(state, 1)?;
+    let pair = build(state.term_register_pair(x, p.clone()))?;
+    let tau = build(state.term_type_infer(&pair))?;
+    let z = build(state.term_register_variable(2u64, tau))?;
+    let first = build(state.term_register_first(z.clone()))?;
+    let second = build(state.term_register_second(z.clone()))?;
+    let rebuilt = build(state.term_register_pair(first, second))?;
+    let conclusion = build(state.term_register_equality(rebuilt, z.clone()))?;
+
+    let thm = state.theorem_register_surjective_pairing(z);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_surjective_pairing(p),
+        ErrorCode::NotAProductType,
+    )
+}
+
+/// Registers a pair of distinct sets, of type `B -> Prop`, for use by the set
+/// definition checks.
+fn sets(
+    state: &mut RuntimeState,
+) -> Result<(Handle<tags::Term>, Handle<tags::Term>), String> {
+    let tau = build(state.type_register_function(
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    ))?;
+    let s = build(state.term_register_variable(0u64, tau.clone()))?;
+    let t = build(state.term_register_variable(1u64, tau))?;
+
+    Ok((s, t))
+}
+
+/// `{} ⊢ IN x s = s x`, rejecting elements of the wrong type.
+fn check_membership_definition(state: &mut RuntimeState) -> CheckResult {
+    let (s, _t) = sets(state)?;
+    let x = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let membership =
+        build(state.term_register_membership(x.clone(), s.clone()))?;
+    let application =
+        build(state.term_register_application(s.clone(), x.clone()))?;
+    let conclusion =
+        build(state.term_register_equality(membership, application))?;
+
+    let thm = state.theorem_register_membership_definition(x, s.clone());
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_membership_definition(
+            PREALLOCATED_HANDLE_TERM_TRUE,
+            s,
+        ),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+/// `{} ⊢ SETSPEC s = s`, rejecting terms not of set type.
+fn check_comprehension_definition(state: &mut RuntimeState) -> CheckResult {
+    let (s, _t) = sets(state)?;
+    let x = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+
+    let thm = state.theorem_register_comprehension_definition(s.clone());
+    let conclusion = build(
+        thm.clone()
+            .and_then(|thm| state.theorem_split_conclusion(&thm)),
+    )?;
+
+    /* NB: the left-hand side is built by the rule itself, as the
+     * comprehension constant is only exposed applied to an abstraction.
+     */
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &s => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                s, conclusion
+            ))
+        }
+    }
+
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_comprehension_definition(x),
+        ErrorCode::NotASetType,
+    )
+}
+
+/// `{} ⊢ EMPTY = λx:B. F`, rejecting dangling types.
+fn check_empty_set_definition(state: &mut RuntimeState) -> CheckResult {
+    let thm =
+        build(state.theorem_register_empty_set_definition(
+            PREALLOCATED_HANDLE_TYPE_BETA,
+        ))?;
+    let conclusion = build(state.theorem_split_conclusion(&thm))?;
+    let lambda = build(state.term_register_lambda(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        PREALLOCATED_HANDLE_TERM_FALSE,
+    ))?;
+
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &lambda => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                lambda, conclusion
+            ))
+        }
+    }
+
+    expect_error(
+        state.theorem_register_empty_set_definition(
+            Handle::<tags::Type>::from(DANGLING_HANDLE),
+        ),
+        ErrorCode::NoSuchTypeRegistered,
+    )
+}
+
+/// `{} ⊢ UNIV = λx:B. T`, rejecting dangling types.
+fn check_universal_set_definition(state: &mut RuntimeState) -> CheckResult {
+    let thm = build(state.theorem_register_universal_set_definition(
+        PREALLOCATED_HANDLE_TYPE_BETA,
+    ))?;
+    let conclusion = build(state.theorem_split_conclusion(&thm))?;
+    let lambda = build(state.term_register_lambda(
+        0u64,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        PREALLOCATED_HANDLE_TERM_TRUE,
+    ))?;
+
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &lambda => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                lambda, conclusion
+            ))
+        }
+    }
+
+    expect_error(
+        state.theorem_register_universal_set_definition(
+            Handle::<tags::Type>::from(DANGLING_HANDLE),
+        ),
+        ErrorCode::NoSuchTypeRegistered,
+    )
+}
+
+/// A function registering a binary connective applied to two terms.
+type Connective = fn(
+    &mut RuntimeState,
+    Handle<tags::Term>,
+    Handle<tags::Term>,
+) -> Result<Handle<tags::Term>, ErrorCode>;
+
+/// Builds `λx:B. s x ⊕ t x`, for `⊕` the binary connective registered by
+/// `connective`, where `x` is the name chosen fresh for the sets `s` and `t`.
+fn pointwise(
+    state: &mut RuntimeState,
+    s: &Handle<tags::Term>,
+    t: &Handle<tags::Term>,
+    connective: Connective,
+) -> Result<Handle<tags::Term>, String> {
+    // NB: `s` and `t` are named `0` and `1`, so `2` is the first fresh name.
+    let x = build(
+        state.term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA),
+    )?;
+    let sx = build(state.term_register_application(s.clone(), x.clone()))?;
+    let tx = build(state.term_register_application(t.clone(), x))?;
+
+    build(connective(state, sx, tx))
+}
+
+/// `{} ⊢ UNION s t = λx:B. s x ∨ t x`, rejecting sets of different types.
+fn check_union_definition(state: &mut RuntimeState) -> CheckResult {
+    let (s, t) = sets(state)?;
+    let body = pointwise(state, &s, &t, |state, l, r| {
+        state.term_register_disjunction(l, r)
+    })?;
+    let lambda = build(state.term_register_lambda(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        body,
+    ))?;
+
+    let thm = build(state.theorem_register_union_definition(s.clone(), t))?;
+    let conclusion = build(state.theorem_split_conclusion(&thm))?;
+
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &lambda => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                lambda, conclusion
+            ))
+        }
+    }
+
+    expect_error(
+        state.theorem_register_union_definition(
+            s,
+            PREALLOCATED_HANDLE_TERM_TRUE,
+        ),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+/// `{} ⊢ INTER s t = λx:B. s x ∧ t x`, rejecting terms not of set type.
+fn check_intersection_definition(state: &mut RuntimeState) -> CheckResult {
+    let (s, t) = sets(state)?;
+    let body = pointwise(state, &s, &t, |state, l, r| {
+        state.term_register_conjunction(l, r)
+    })?;
+    let lambda = build(state.term_register_lambda(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        body,
+    ))?;
+
+    let thm =
+        build(state.theorem_register_intersection_definition(s, t.clone()))?;
+    let conclusion = build(state.theorem_split_conclusion(&thm))?;
+
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &lambda => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                lambda, conclusion
+            ))
+        }
+    }
+
+    expect_error(
+        state.theorem_register_intersection_definition(
+            PREALLOCATED_HANDLE_TERM_TRUE,
+            t,
+        ),
+        ErrorCode::NotASetType,
+    )
+}
+
+/// `{} ⊢ SUBSET s t = ∀x:B. s x ⇒ t x`, rejecting sets of different types.
+fn check_subset_definition(state: &mut RuntimeState) -> CheckResult {
+    let (s, t) = sets(state)?;
+    let body = pointwise(state, &s, &t, |state, l, r| {
+        state.term_register_implication(l, r)
+    })?;
+    let forall = build(state.term_register_forall(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        body,
+    ))?;
+
+    let thm = build(state.theorem_register_subset_definition(s.clone(), t))?;
+    let conclusion = build(state.theorem_split_conclusion(&thm))?;
+
+    match state.term_split_equality(&conclusion) {
+        Ok((_left, right)) if right == &forall => (),
+        _otherwise => {
+            return Err(format!(
+                "expected an equality with right-hand side {}, but found {}",
+                forall, conclusion
+            ))
+        }
+    }
+
+    expect_error(
+        state.theorem_register_subset_definition(
+            s,
+            PREALLOCATED_HANDLE_TERM_TRUE,
+        ),
+        ErrorCode::DomainTypeMismatch,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Derived rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// `{p = q} ⊢ (∀x:Prop. p) = (∀x:Prop. q)`, rejecting variables free in the
+/// premisses.
+fn check_forall_congruence(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let left = build(state.term_register_forall(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        p,
+    ))?;
+    let right = build(state.term_register_forall(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        q,
+    ))?;
+    let conclusion = build(state.term_register_equality(left, right))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_forall_congruence(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        &hyp,
+    );
+    expect_theorem(state, thm, vec![pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_forall_congruence(
+            0u64,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+            &hyp,
+        ),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p = q} ⊢ (∃x:Prop. p) = (∃x:Prop. q)`, rejecting variables free in the
+/// premisses.
+fn check_exists_congruence(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let left = build(state.term_register_exists(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        p,
+    ))?;
+    let right = build(state.term_register_exists(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        q,
+    ))?;
+    let conclusion = build(state.term_register_equality(left, right))?;
+    let hyp = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_exists_congruence(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+        &hyp,
+    );
+    expect_theorem(state, thm, vec![pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_exists_congruence(
+            0u64,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+            &hyp,
+        ),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+/// `{p = q, q ∧ r} ⊢ p ∧ r` from `{p ∧ r} ⊢ p ∧ r`, rejecting premisses that
+/// do not exist.
+fn check_rewrite_hypothesis(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let r = proposition(state, 2)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let pr = build(state.term_register_conjunction(p, r.clone()))?;
+    let qr = build(state.term_register_conjunction(q, r))?;
+    let hyp = build(state.theorem_register_assumption(pr.clone()))?;
+    let equation = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_rewrite_hypothesis(&hyp, 0, &equation);
+    expect_theorem(state, thm, vec![pq, qr], pr)?;
+
+    expect_error(
+        state.theorem_register_rewrite_hypothesis(&hyp, 1, &equation),
+        ErrorCode::NoSuchPremiss,
+    )
+}
+
+/// `{p, q} ⊢ q` from the pattern `q`, rejecting patterns that match no
+/// hypothesis.
+fn check_assumption_matching(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let r = proposition(state, 2)?;
+
+    let thm = state
+        .theorem_register_assumption_matching(vec![p.clone(), q.clone()], &q);
+    expect_theorem(state, thm, vec![p.clone(), q.clone()], q.clone())?;
+
+    expect_error(
+        state.theorem_register_assumption_matching(vec![p, q], &r),
+        ErrorCode::NoMatchingHypothesis,
+    )
+}
+
+/// `{} ⊢ (c ∧ p) = (T ∧ p)` from the definition `{} ⊢ c = T`, rejecting
+/// definitions that are not equations.
+fn check_unfold(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let (c, defn) =
+        build(state.register_new_definition(PREALLOCATED_HANDLE_TERM_TRUE))?;
+    let term = build(state.term_register_conjunction(c, p.clone()))?;
+    let unfolded = build(
+        state.term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, p),
+    )?;
+    let conclusion =
+        build(state.term_register_equality(term.clone(), unfolded))?;
+    let truth = build(state.theorem_register_truth_introduction())?;
+
+    let thm = state.theorem_register_unfold(&defn, &term, &UnfoldMode::Once);
+    expect_theorem(state, thm, vec![], conclusion)?;
+
+    expect_error(
+        state.theorem_register_unfold(&truth, &term, &UnfoldMode::Once),
+        ErrorCode::NotAnEquality,
+    )
+}
+
+/// `{p = q} ⊢ (p ∧ r) = (q ∧ r)` rewriting at the position of `p`, rejecting
+/// positions that do not address the left-hand side.
+fn check_rewrite_at(state: &mut RuntimeState) -> CheckResult {
+    let p = proposition(state, 0)?;
+    let q = proposition(state, 1)?;
+    let r = proposition(state, 2)?;
+    let pq = build(state.term_register_equality(p.clone(), q.clone()))?;
+    let pr = build(state.term_register_conjunction(p, r.clone()))?;
+    let qr = build(state.term_register_conjunction(q, r))?;
+    let conclusion = build(state.term_register_equality(pr.clone(), qr))?;
+    let equation = build(state.theorem_register_assumption(pq.clone()))?;
+
+    let thm = state.theorem_register_rewrite_at(&equation, &pr, &[0, 1]);
+    expect_theorem(state, thm, vec![pq], conclusion)?;
+
+    expect_error(
+        state.theorem_register_rewrite_at(&equation, &pr, &[1]),
+        ErrorCode::ShapeMismatch,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Entry point.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Runs every check of the self-test, each against a freshly-created runtime
+/// state, printing one line per inference rule.  A kernel panic during a check
+/// is reported as a failure of that check, as otherwise-valid input should
+/// never cause the kernel to panic.  Returns `true` iff every check
+/// passed.
+pub fn run() -> bool {
+    info!("Running kernel self-test.");
+
+    let mut failures = 0;
+
+    /* NB: kernel panics are reported as check failures, below, so the default
+     * panic message is suppressed whilst the checks run.
+     */
+    let hook = take_hook();
+    set_hook(Box::new(|_info| ()));
+
+    for check in CHECKS {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            (check.run)(&mut state)?;
+            check_proofs(&mut state)
+        }))
+        .unwrap_or_else(|payload| Err(panic_reason(payload)));
+
+        match result {
+            Ok(()) => println!("ok      {}", check.rule),
+            Err(reason) => {
+                failures += 1;
+                println!("FAILED  {}: {}", check.rule, reason);
+            }
+        }
+    }
+
+    set_hook(hook);
+
+    for (rule, reason) in SKIPPED {
+        println!("skipped {}: {}", rule, reason);
+    }
+
+    println!(
+        "Self-test finished: {} passed, {} failed, {} skipped.",
+        CHECKS.len() - failures,
+        failures,
+        SKIPPED.len()
+    );
+
+    failures == 0
+}
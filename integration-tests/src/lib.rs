@@ -0,0 +1,245 @@
+//! # End-to-end test support
+//!
+//! Support for the end-to-end tests, which build every guest program under the
+//! `tests` directory of the *Supervisionary* root directory for Wasm, execute
+//! it under the kernel through the driver library, and check that it ran to
+//! completion.  The guests exercise the `libsupervisionary` bindings, the WASMI
+//! bindings and the kernel together.
+//!
+//! Guests are built with `cargo`, into a target directory of their own so as not
+//! to contend with the build running the tests.  The toolchain used to build
+//! them can be chosen by setting `SUPERVISIONARY_GUEST_TOOLCHAIN`, e.g. to
+//! `1.81`, which is passed to `cargo` as `+1.81`.  `libsupervisionary` builds
+//! on a stable compiler, so any toolchain with the Wasm target will do.
+//!
+//! WASMI only accepts Wasm MVP modules.  Guests are compiled for the MVP, but
+//! recent toolchains ship a standard library for `wasm32-unknown-unknown` that
+//! was itself compiled with later Wasm features enabled, so guests built with
+//! them are rejected when loaded.  A toolchain from before Rust 1.82, such as
+//! `1.81` or `nightly-2024-07-01`, is needed.  Guests are also built with the
+//! current stable toolchain, and its default target features, and must then
+//! either run to completion or be reported by the driver as using a Wasm
+//! proposal that WASMI does not support, rather than as malformed.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use driver::{execute, ExecutionError, ExecutionOptions};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use wasmi::RuntimeValue;
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The target that guests are built for.
+pub const GUEST_TARGET: &str = "wasm32-unknown-unknown";
+/// The environment variable naming the toolchain that guests are built with.
+const GUEST_TOOLCHAIN_VARIABLE: &str = "SUPERVISIONARY_GUEST_TOOLCHAIN";
+/// The environment variable through which flags are passed to the compiler
+/// when building for `GUEST_TARGET`.
+const GUEST_RUSTFLAGS_VARIABLE: &str =
+    "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUSTFLAGS";
+/// The flags that guests are built with.  Host calls are left undefined by the
+/// guest, and the linker must turn them into imports from the `env` module.
+const GUEST_RUSTFLAGS: &str = "-C target-cpu=mvp -C link-arg=--allow-undefined";
+/// The toolchain that guests are built with when checking how the driver copes
+/// with current compilers.
+const STABLE_TOOLCHAIN: &str = "stable";
+/// The flags that guests are built with by `STABLE_TOOLCHAIN`, which leave the
+/// compiler's default target features enabled.
+const STABLE_GUEST_RUSTFLAGS: &str = "-C link-arg=--allow-undefined";
+
+/// Returns the *Supervisionary* root directory.
+fn root_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Test crate is not within the Supervisionary root directory.")
+        .to_path_buf()
+}
+
+/// Returns the `+toolchain` argument selecting the toolchain that guests are
+/// built with, if one was chosen.
+fn toolchain_argument() -> Option<String> {
+    std::env::var(GUEST_TOOLCHAIN_VARIABLE)
+        .ok()
+        .filter(|toolchain| !toolchain.is_empty())
+        .map(|toolchain| format!("+{}", toolchain))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Guests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A guest program under the `tests` directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Guest {
+    /// The name of the guest's package, which is also the name of its binary.
+    pub name: String,
+    /// The path of the guest's manifest.
+    pub manifest: PathBuf,
+}
+
+/// Returns the package name declared by the manifest `manifest`, if any.
+fn package_name(manifest: &str) -> Option<String> {
+    manifest
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _value)| key.trim() == "name")
+        .map(|(_key, value)| value.trim().trim_matches('"').to_string())
+}
+
+/// Returns every guest under the `tests` directory, in order of name.
+pub fn guests() -> Vec<Guest> {
+    let directory = root_directory().join("tests");
+    let mut guests = Vec::new();
+
+    let entries = fs::read_dir(&directory).unwrap_or_else(|e| {
+        panic!("Failed to read {:?}.  Error produced: {}.", directory, e)
+    });
+
+    for entry in entries.flatten() {
+        let manifest = entry.path().join("Cargo.toml");
+
+        if let Some(name) = fs::read_to_string(&manifest)
+            .ok()
+            .and_then(|contents| package_name(&contents))
+        {
+            guests.push(Guest { name, manifest });
+        }
+    }
+
+    guests.sort_by(|left, right| left.name.cmp(&right.name));
+    guests
+}
+
+/// Returns `true` iff the toolchain selected by the `+toolchain` argument
+/// `toolchain`, or the default toolchain if `None`, can target `GUEST_TARGET`.
+fn target_installed(toolchain: Option<String>) -> bool {
+    let mut command = Command::new("rustc");
+
+    if let Some(toolchain) = toolchain {
+        command.arg(toolchain);
+    }
+
+    let sysroot = match command.args(["--print", "sysroot"]).output() {
+        Ok(output) if output.status.success() => output.stdout,
+        _otherwise => return false,
+    };
+
+    let sysroot = String::from_utf8_lossy(&sysroot);
+
+    Path::new(sysroot.trim())
+        .join("lib")
+        .join("rustlib")
+        .join(GUEST_TARGET)
+        .is_dir()
+}
+
+/// Returns `true` iff the toolchain that guests are built with can target
+/// `GUEST_TARGET`.
+#[inline]
+pub fn guest_target_installed() -> bool {
+    target_installed(toolchain_argument())
+}
+
+/// Returns `true` iff the current stable toolchain can target `GUEST_TARGET`.
+#[inline]
+pub fn stable_target_installed() -> bool {
+    target_installed(Some(format!("+{}", STABLE_TOOLCHAIN)))
+}
+
+/// Builds `guest` for `GUEST_TARGET` with the toolchain selected by the
+/// `+toolchain` argument `toolchain` and the compiler flags `rustflags`, into
+/// the target directory named `directory`.  Returns the path of the resulting
+/// Wasm binary, or the compiler's diagnostics if the build failed.
+fn build_with(
+    guest: &Guest,
+    toolchain: Option<String>,
+    rustflags: &str,
+    directory: &str,
+) -> Result<PathBuf, String> {
+    let target_directory = root_directory().join("target").join(directory);
+    let mut command = Command::new("cargo");
+
+    if let Some(toolchain) = toolchain {
+        command.arg(toolchain);
+    }
+
+    let output = command
+        .arg("build")
+        .arg("--release")
+        .args(["--target", GUEST_TARGET])
+        .arg("--manifest-path")
+        .arg(&guest.manifest)
+        .arg("--target-dir")
+        .arg(&target_directory)
+        .env(GUEST_RUSTFLAGS_VARIABLE, rustflags)
+        .output()
+        .map_err(|e| format!("Failed to run cargo.  Error produced: {}.", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    Ok(target_directory
+        .join(GUEST_TARGET)
+        .join("release")
+        .join(format!("{}.wasm", guest.name)))
+}
+
+/// Builds `guest` for `GUEST_TARGET`, returning the path of the resulting Wasm
+/// binary, or the compiler's diagnostics if the build failed.
+#[inline]
+pub fn build(guest: &Guest) -> Result<PathBuf, String> {
+    build_with(guest, toolchain_argument(), GUEST_RUSTFLAGS, "guests")
+}
+
+/// Builds `guest` for `GUEST_TARGET` with the current stable toolchain and its
+/// default target features, returning the path of the resulting Wasm binary,
+/// or the compiler's diagnostics if the build failed.
+#[inline]
+pub fn build_stable(guest: &Guest) -> Result<PathBuf, String> {
+    build_with(
+        guest,
+        Some(format!("+{}", STABLE_TOOLCHAIN)),
+        STABLE_GUEST_RUSTFLAGS,
+        "stable-guests",
+    )
+}
+
+/// Executes the Wasm binary at `path` under a fresh kernel, returning the value
+/// returned by its entry point, if any.
+pub fn run<P>(path: P) -> Result<Option<RuntimeValue>, ExecutionError>
+where
+    P: AsRef<Path>,
+{
+    let binary = fs::read(path.as_ref()).unwrap_or_else(|e| {
+        panic!(
+            "Failed to read {:?}.  Error produced: {}.",
+            path.as_ref(),
+            e
+        )
+    });
+
+    let mut runtime_state = WasmiRuntimeState::new();
+
+    execute(&binary, &ExecutionOptions::default(), &mut runtime_state)
+}
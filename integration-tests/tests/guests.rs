@@ -0,0 +1,100 @@
+//! # End-to-end tests
+//!
+//! Builds and executes every guest program under the `tests` directory of the
+//! *Supervisionary* root directory, failing if any guest fails to build, or
+//! fails to run to completion.  Every guest is also built with the current
+//! stable toolchain, and must either run to completion or be rejected as using
+//! an unsupported Wasm feature.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use driver::ExecutionError;
+use integration_tests::{
+    build, build_stable, guest_target_installed, guests, run,
+    stable_target_installed, GUEST_TARGET,
+};
+
+/// Tests that every guest builds, and runs to completion under the kernel.
+///
+/// Ignored by default, as it needs the `GUEST_TARGET` target installed for a
+/// toolchain predating Rust 1.82, named by `SUPERVISIONARY_GUEST_TOOLCHAIN`.
+/// Run with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+pub fn guests_test0() {
+    assert!(
+        guest_target_installed(),
+        "The {} target is not installed for the guest toolchain.",
+        GUEST_TARGET
+    );
+
+    let guests = guests();
+    let mut failures = Vec::new();
+
+    assert!(!guests.is_empty());
+
+    for guest in guests.iter() {
+        match build(guest).map(run) {
+            Err(diagnostics) => failures.push(format!(
+                "{} failed to build:\n{}",
+                guest.name, diagnostics
+            )),
+            Ok(Err(e)) => {
+                failures.push(format!("{} failed: {}", guest.name, e))
+            }
+            Ok(Ok(_value)) => eprintln!("{} ran to completion.", guest.name),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Tests that every guest built with the current stable toolchain, and its
+/// default target features, either runs to completion under the kernel, or is
+/// rejected as using a Wasm feature that the interpreter does not support.
+///
+/// Ignored by default, as it needs the `GUEST_TARGET` target installed for the
+/// stable toolchain.  Run with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+pub fn guests_test1() {
+    assert!(
+        stable_target_installed(),
+        "The {} target is not installed for the stable toolchain.",
+        GUEST_TARGET
+    );
+
+    let guests = guests();
+    let mut failures = Vec::new();
+
+    for guest in guests.iter() {
+        match build_stable(guest).map(run) {
+            Err(diagnostics) => failures.push(format!(
+                "{} failed to build:\n{}",
+                guest.name, diagnostics
+            )),
+            Ok(Err(ExecutionError::UnsupportedFeature(feature, _e))) => {
+                eprintln!("{} rejected, as it uses {}.", guest.name, feature)
+            }
+            Ok(Err(e)) => {
+                failures.push(format!("{} failed: {}", guest.name, e))
+            }
+            Ok(Ok(_value)) => eprintln!("{} ran to completion.", guest.name),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
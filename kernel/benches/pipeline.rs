@@ -0,0 +1,162 @@
+//! # Benchmarks of the parallel proof object pipelines
+//!
+//! Measures the throughput, in theorems per second, of exporting and importing
+//! a library of several thousand theorems, both as a single proof object and
+//! with the parallel pipelines, for varying numbers of workers.  The library
+//! consists of many independent families of theorems, each a reflexivity
+//! theorem over a moderately-sized term together with the theorem derived from
+//! it by symmetry, so that the parallel export has independent groups of
+//! theorems to distribute between its workers.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion,
+    Throughput,
+};
+use kernel::{
+    handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+    runtime_state::RuntimeState,
+};
+
+/// The number of independent families of theorems in the library.
+const FAMILIES: usize = 2048;
+/// The depth of the conjunction spine in the statement of each family.
+const DEPTH: usize = 8;
+/// The numbers of workers benchmarked.
+const WORKERS: &[usize] = &[1, 2, 4, 8];
+
+////////////////////////////////////////////////////////////////////////////////
+// A representative library.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the library, with proof recording enabled, returning its
+/// theorems.
+fn library(state: &mut RuntimeState) -> Vec<Handle<tags::Theorem>> {
+    state.set_proof_recording(true);
+
+    let mut theorems = Vec::new();
+
+    for family in 0..FAMILIES {
+        let mut trm = state
+            .term_register_variable(
+                family as u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+
+        for depth in 0..DEPTH {
+            let p = state
+                .term_register_variable(
+                    (FAMILIES + depth) as u64,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                )
+                .unwrap();
+
+            trm = state.term_register_conjunction(p, trm).unwrap();
+        }
+
+        let thm = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(trm)
+            .unwrap();
+
+        theorems.push(thm.clone());
+        theorems.push(state.theorem_register_symmetry(thm).unwrap());
+    }
+
+    theorems
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Benchmarks exporting the library, sequentially and in parallel.
+fn pipeline_export(c: &mut Criterion) {
+    let mut state = RuntimeState::new();
+    let theorems = library(&mut state);
+
+    let mut group = c.benchmark_group("pipeline/export");
+    group.throughput(Throughput::Elements(theorems.len() as u64));
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| state.theorem_export(&theorems).unwrap())
+    });
+
+    for workers in WORKERS {
+        group.bench_with_input(
+            BenchmarkId::new("parallel", workers),
+            workers,
+            |b, workers| {
+                b.iter(|| {
+                    state.theorem_export_parallel(&theorems, *workers).unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmarks importing the library into a fresh runtime state, sequentially
+/// and in parallel.
+fn pipeline_import(c: &mut Criterion) {
+    let mut state = RuntimeState::new();
+    let theorems = library(&mut state);
+
+    let mut group = c.benchmark_group("pipeline/import");
+    group.throughput(Throughput::Elements(theorems.len() as u64));
+
+    let bytes = state.theorem_export(&theorems).unwrap();
+
+    group.bench_function("sequential", |b| {
+        b.iter_batched(
+            RuntimeState::new,
+            |mut state| state.theorem_import(&bytes).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+
+    for workers in WORKERS {
+        let chunks: Vec<Vec<u8>> = state
+            .theorem_export_parallel(&theorems, *workers)
+            .unwrap()
+            .into_iter()
+            .map(|chunk| chunk.bytes)
+            .collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("parallel", workers),
+            workers,
+            |b, workers| {
+                b.iter_batched(
+                    RuntimeState::new,
+                    |mut state| {
+                        state
+                            .theorem_import_parallel(&chunks, *workers)
+                            .unwrap()
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, pipeline_export, pipeline_import);
+criterion_main!(benches);
@@ -0,0 +1,114 @@
+//! # Benchmarks of term registration
+//!
+//! Measures the cost of registering terms bottom-up, as guests do, on deep
+//! application spines.  Every registration looks up the types recorded for its
+//! immediate subterms, rather than inferring them again, so no registration
+//! traverses the spine beneath it.  Two shapes of spine are used: left-nested
+//! spines, `f p ... p`, applying a variable to many arguments in turn, and
+//! right-nested spines, `¬...¬p`, nesting many applications in argument
+//! position.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion,
+};
+use kernel::{
+    handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+    runtime_state::RuntimeState,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Representative spines.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the left-nested spine `f p ... p` of `depth` applications, where
+/// `f` is a variable taking `depth` propositions to a proposition.
+fn left_spine(state: &mut RuntimeState, depth: usize) -> Handle<tags::Term> {
+    let mut tau = PREALLOCATED_HANDLE_TYPE_PROP;
+
+    for _i in 0..depth {
+        tau = state
+            .type_register_function(PREALLOCATED_HANDLE_TYPE_PROP, tau)
+            .unwrap();
+    }
+
+    let p = state
+        .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+    let mut term = state.term_register_variable(1u64, tau).unwrap();
+
+    for _i in 0..depth {
+        term = state.term_register_application(term, p.clone()).unwrap();
+    }
+
+    term
+}
+
+/// Registers the right-nested spine `¬...¬p` of `depth` negations.
+fn right_spine(state: &mut RuntimeState, depth: usize) -> Handle<tags::Term> {
+    let mut term = state
+        .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+
+    for _i in 0..depth {
+        term = state.term_register_negation(term).unwrap();
+    }
+
+    term
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Benchmarks registering the spines built by `build`, for each of the given
+/// `depths`, in a fresh runtime state.
+fn register(
+    c: &mut Criterion,
+    group: &str,
+    build: fn(&mut RuntimeState, usize) -> Handle<tags::Term>,
+    depths: &[usize],
+) {
+    let mut group = c.benchmark_group(group);
+
+    for depth in depths {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(depth),
+            depth,
+            |b, depth| {
+                b.iter_batched(
+                    RuntimeState::new,
+                    |mut state| build(&mut state, *depth),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn registration_left_spine(c: &mut Criterion) {
+    register(c, "registration/left-spine", left_spine, &[16, 64, 256]);
+}
+
+fn registration_right_spine(c: &mut Criterion) {
+    register(c, "registration/right-spine", right_spine, &[16, 64, 256]);
+}
+
+criterion_group!(benches, registration_left_spine, registration_right_spine);
+criterion_main!(benches);
@@ -0,0 +1,307 @@
+//! # Benchmarks of term substitution
+//!
+//! Compares the kernel's substitution, which works directly on handles with an
+//! explicit work-list and memoises its results per shared subterm, against a
+//! naive recursive substitution written against the kernel's public interface,
+//! which rebuilds and re-registers every subterm that it visits.  Both are run
+//! on representative nested terms: long chains of conjunctions, where there is
+//! little sharing, and balanced conjunctions whose two halves are shared, where
+//! the term is exponentially larger than the graph of handles representing it.
+//!
+//! Also compares composing substitutions of types for type-variables lazily,
+//! with the kernel's triangular compositions, against composing them eagerly,
+//! by applying each new substitution to the range of everything composed so
+//! far, on the long runs of single-variable substitutions that an elaborator's
+//! unification steps produce.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion,
+};
+use kernel::{
+    handle::{
+        tags, Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    name::Name,
+    runtime_state::RuntimeState,
+    substitution::Substitution,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Representative terms.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the chain `x ∧ (y ∧ (x ∧ ...))` of `depth` conjunctions.
+fn chain(state: &mut RuntimeState, depth: usize) -> Handle<tags::Term> {
+    let x = state
+        .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+    let y = state
+        .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+
+    let mut term = y.clone();
+
+    for i in 0..depth {
+        let leaf = if i % 2 == 0 { x.clone() } else { y.clone() };
+        term = state.term_register_conjunction(leaf, term).unwrap();
+    }
+
+    term
+}
+
+/// Registers the balanced conjunction of `x` and `y` of the given `depth`,
+/// wherein both halves of every conjunction are the same term.
+fn balanced(state: &mut RuntimeState, depth: usize) -> Handle<tags::Term> {
+    let x = state
+        .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+    let y = state
+        .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        .unwrap();
+
+    let mut term = state.term_register_conjunction(x, y).unwrap();
+
+    for _i in 0..depth {
+        term = state.term_register_conjunction(term.clone(), term).unwrap();
+    }
+
+    term
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Naive substitution.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Substitutes the closed term `range` for the variable `x : Prop` in `term`,
+/// visiting and re-registering every subterm.  As `range` is closed, no
+/// renaming of bound variables is ever necessary.
+fn naive(
+    state: &mut RuntimeState,
+    term: &Handle<tags::Term>,
+    range: &Handle<tags::Term>,
+) -> Handle<tags::Term> {
+    if let Ok((name, tau)) = state.term_split_variable(term) {
+        if *name == 0 && *tau == PREALLOCATED_HANDLE_TYPE_PROP {
+            return range.clone();
+        }
+
+        return term.clone();
+    }
+
+    if let Ok((left, right)) = state.term_split_application(term) {
+        let (left, right) = (left.clone(), right.clone());
+        let left = naive(state, &left, range);
+        let right = naive(state, &right, range);
+
+        return state.term_register_application(left, right).unwrap();
+    }
+
+    if let Ok((name, tau, body)) = state.term_split_lambda(term) {
+        let (name, tau, body) = (*name, tau.clone(), body.clone());
+
+        if name == 0 && tau == PREALLOCATED_HANDLE_TYPE_PROP {
+            return term.clone();
+        }
+
+        let body = naive(state, &body, range);
+
+        return state.term_register_lambda(name, tau, body).unwrap();
+    }
+
+    term.clone()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Elaboration.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the substitutions of `steps` unification steps, the `i`th
+/// solving the type-variable `i` as `(i + 1) → Prop`.
+fn unification(
+    state: &mut RuntimeState,
+    steps: usize,
+) -> Vec<Handle<tags::Substitution>> {
+    (0..steps as u64)
+        .map(|i| {
+            let next = state.type_register_variable(i + 1);
+            let range = state
+                .type_register_function(next, PREALLOCATED_HANDLE_TYPE_PROP)
+                .unwrap();
+
+            state.substitution_register_type(vec![(i, range)]).unwrap()
+        })
+        .collect()
+}
+
+/// Composes the single-variable substitutions `steps` eagerly, applying each
+/// to the range of everything composed before it, and then returns the image
+/// of the type-variable `0` under the composition.
+fn eager(
+    state: &mut RuntimeState,
+    steps: &[Handle<tags::Substitution>],
+) -> Handle<tags::Type> {
+    let mut composed: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+
+    for step in steps {
+        for (_name, range) in composed.iter_mut() {
+            *range = state.type_substitute_with(&*range, step).unwrap();
+        }
+
+        if let Substitution::Type(bindings) =
+            state.substitution_resolve(step).unwrap().clone()
+        {
+            composed.extend(bindings);
+        }
+    }
+
+    /* NB: the ranges of the composition are already fully substituted, so
+     * the image of the type-variable is just its binding.
+     */
+    composed
+        .into_iter()
+        .find(|(name, _range)| *name == 0)
+        .map(|(_name, range)| range)
+        .unwrap()
+}
+
+/// Composes the substitutions `steps` lazily, with the kernel, and then
+/// applies the composition to the type-variable `0`.
+fn lazy(
+    state: &mut RuntimeState,
+    steps: &[Handle<tags::Substitution>],
+) -> Handle<tags::Type> {
+    let mut composed = steps[0].clone();
+
+    for step in &steps[1..] {
+        composed = state.substitution_compose(&composed, step).unwrap();
+    }
+
+    let root = state.type_register_variable(0u64);
+
+    state.type_substitute_with(root, composed).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Benchmarks both substitutions on terms built by `build`, for each of the
+/// given `depths`.
+fn compare(
+    c: &mut Criterion,
+    group: &str,
+    build: fn(&mut RuntimeState, usize) -> Handle<tags::Term>,
+    depths: &[usize],
+) {
+    let mut group = c.benchmark_group(group);
+
+    for depth in depths {
+        let mut state = RuntimeState::new();
+        let term = build(&mut state, *depth);
+
+        group.bench_with_input(
+            BenchmarkId::new("kernel", depth),
+            &term,
+            |b, term| {
+                b.iter_batched(
+                    || state.clone(),
+                    |mut state| {
+                        state
+                            .substitution(
+                                term.clone(),
+                                vec![(
+                                    (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                                    PREALLOCATED_HANDLE_TERM_TRUE,
+                                )],
+                            )
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("naive", depth),
+            &term,
+            |b, term| {
+                b.iter_batched(
+                    || state.clone(),
+                    |mut state| {
+                        naive(&mut state, term, &PREALLOCATED_HANDLE_TERM_TRUE)
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn substitution_chain(c: &mut Criterion) {
+    compare(c, "substitution/chain", chain, &[16, 64, 256]);
+}
+
+fn substitution_balanced(c: &mut Criterion) {
+    compare(c, "substitution/balanced", balanced, &[4, 8, 12]);
+}
+
+fn substitution_composition(c: &mut Criterion) {
+    let mut group = c.benchmark_group("substitution/composition");
+
+    for steps in &[16, 32, 64] {
+        let mut state = RuntimeState::new();
+        let substitutions = unification(&mut state, *steps);
+
+        group.bench_with_input(
+            BenchmarkId::new("lazy", steps),
+            &substitutions,
+            |b, substitutions| {
+                b.iter_batched(
+                    || state.clone(),
+                    |mut state| lazy(&mut state, substitutions),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("eager", steps),
+            &substitutions,
+            |b, substitutions| {
+                b.iter_batched(
+                    || state.clone(),
+                    |mut state| eager(&mut state, substitutions),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    substitution_chain,
+    substitution_balanced,
+    substitution_composition
+);
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+//! # Kernel build script
+//!
+//! Records the revision of the source tree that the kernel is built from, for
+//! use in kernel identification.  The revision can be overridden by setting
+//! the `SUPERVISIONARY_BUILD_HASH` environment variable, for example when
+//! building outside of a git checkout.
+//!
+//! Also generates the kernel's `PREALLOCATED_HANDLE_*` constants from the
+//! description in `abi-spec/preallocated-handles.txt`, which is shared with
+//! `libsupervisionary`, failing the build if the description is malformed.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+#[path = "../abi-spec/preallocated.rs"]
+mod preallocated;
+
+use std::{env, fs, path::Path, process::Command};
+
+/// The environment variable through which the build hash is passed to the
+/// kernel, and through which it may be overridden.
+const BUILD_HASH_VARIABLE: &str = "SUPERVISIONARY_BUILD_HASH";
+
+/// Returns the revision of the source tree, as reported by git, if any.
+fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|revision| revision.trim().to_string())
+}
+
+/// Generates the pre-allocated handle constants, along with their upper-bound,
+/// into `preallocated.rs` in the build's output directory.
+fn generate_preallocated() {
+    let entries = preallocated::load(preallocated::SPECIFICATION)
+        .unwrap_or_else(|e| panic!("malformed pre-allocated handles: {}", e));
+
+    let mut generated = format!(
+        "/// The upper-bound (exclusive) of the preallocated handles.\n\
+         pub const PREALLOCATED_HANDLE_UPPER_BOUND: usize = {};\n\n",
+        entries.len()
+    );
+
+    generated.push_str(&preallocated::constants(&entries, None, |handle| {
+        format!("Handle::new({}, PhantomData)", handle)
+    }));
+
+    /* NB: the kind of every pre-allocated handle is also recorded, so that the
+     * tests can check that each is registered, at its kind, in a fresh kernel.
+     */
+    generated.push_str(
+        "\n/// The kind of kernel object pointed-to by each pre-allocated handle.\n\
+         #[cfg(test)]\n\
+         pub(crate) const PREALLOCATED_HANDLE_KINDS: &[Kind] = &[\n",
+    );
+
+    for entry in &entries {
+        generated.push_str(&format!("    Kind::{},\n", entry.kind));
+    }
+
+    generated.push_str("];\n");
+
+    let out = env::var("OUT_DIR").expect("OUT_DIR is not set");
+
+    fs::write(Path::new(&out).join("preallocated.rs"), generated)
+        .expect("failed to write the pre-allocated handles");
+
+    println!("cargo:rerun-if-changed={}", preallocated::SPECIFICATION);
+    println!("cargo:rerun-if-changed=../abi-spec/preallocated.rs");
+}
+
+fn main() {
+    generate_preallocated();
+
+    let hash = env::var(BUILD_HASH_VARIABLE)
+        .ok()
+        .or_else(git_revision)
+        .unwrap_or_else(|| String::from("unknown"));
+
+    println!("cargo:rustc-env={}={}", BUILD_HASH_VARIABLE, hash);
+    println!("cargo:rerun-if-env-changed={}", BUILD_HASH_VARIABLE);
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+    println!("cargo:rerun-if-changed=../.git/refs");
+}
@@ -3,7 +3,7 @@
 //! HOL uses simple-types with top-level polymorphism.  The grammar of types
 //! is recursively defined as follows:
 //!
-//! ```
+//! ```text
 //!     τ, τʹ, ... ::= ⍺ | F(τ₁, ..., τₙ)
 //! ```
 //!
@@ -36,9 +36,18 @@
 use crate::{
     handle::{
         tags, Handle, PREALLOCATED_HANDLE_TYPE_ALPHA,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+        PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR,
         PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
-        PREALLOCATED_HANDLE_TYPE_FORMER_PROP, PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+        PREALLOCATED_HANDLE_TYPE_FUNCTION,
+        PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR,
+        PREALLOCATED_HANDLE_TYPE_PRODUCT, PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+        PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
         PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
+        PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR,
         PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
     },
     name::Name,
@@ -244,6 +253,137 @@ lazy_static! {
             PREALLOCATED_HANDLE_TYPE_PROP,
         ],
     };
+
+    /// The type of polymorphic unary operators, `A -> A`.
+    pub static ref TYPE_POLYMORPHIC_UNARY_OPERATOR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+        ],
+    };
+
+    /// The type of polymorphic binary operators, `A -> (A -> A)`.
+    pub static ref TYPE_POLYMORPHIC_BINARY_OPERATOR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR,
+        ],
+    };
+
+    /// The type of the polymorphic conditional, `Prop -> (A -> (A -> A))`.
+    pub static ref TYPE_POLYMORPHIC_CONDITIONAL: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_PROP,
+            PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR,
+        ],
+    };
+
+    /// The type of polymorphic functions, `A -> B`.
+    pub static ref TYPE_POLYMORPHIC_FUNCTION: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_BETA,
+        ],
+    };
+
+    /// The type of the polymorphic local definition, `(A -> B) -> (A -> B)`.
+    pub static ref TYPE_POLYMORPHIC_LET: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_FUNCTION,
+            PREALLOCATED_HANDLE_TYPE_FUNCTION,
+        ],
+    };
+
+    /// The polymorphic product type, `A * B`.
+    pub static ref TYPE_POLYMORPHIC_PRODUCT: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_BETA,
+        ],
+    };
+
+    /// The type of the polymorphic pairing constant applied to its first
+    /// argument, `B -> (A * B)`.
+    pub static ref TYPE_POLYMORPHIC_PARTIAL_PAIR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_BETA,
+            PREALLOCATED_HANDLE_TYPE_PRODUCT,
+        ],
+    };
+
+    /// The type of the polymorphic pairing constant, `A -> (B -> (A * B))`.
+    pub static ref TYPE_POLYMORPHIC_PAIR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR,
+        ],
+    };
+
+    /// The type of the polymorphic first projection, `(A * B) -> A`.
+    pub static ref TYPE_POLYMORPHIC_FIRST: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_PRODUCT,
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+        ],
+    };
+
+    /// The type of the polymorphic second projection, `(A * B) -> B`.
+    pub static ref TYPE_POLYMORPHIC_SECOND: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_PRODUCT,
+            PREALLOCATED_HANDLE_TYPE_BETA,
+        ],
+    };
+
+    /// The type of the polymorphic set membership constant,
+    /// `A -> ((A -> Prop) -> Prop)`.
+    pub static ref TYPE_POLYMORPHIC_MEMBERSHIP: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+        ],
+    };
+
+    /// The type of polymorphic operators on sets,
+    /// `(A -> Prop) -> (A -> Prop)`.
+    pub static ref TYPE_POLYMORPHIC_SET_OPERATOR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+        ],
+    };
+
+    /// The type of polymorphic binary operators on sets,
+    /// `(A -> Prop) -> ((A -> Prop) -> (A -> Prop))`.
+    pub static ref TYPE_POLYMORPHIC_BINARY_SET_OPERATOR: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+        ],
+    };
+
+    /// The type of polymorphic binary relations on sets,
+    /// `(A -> Prop) -> ((A -> Prop) -> Prop)`.
+    pub static ref TYPE_POLYMORPHIC_SET_RELATION: Type = Type::Combination {
+        former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        arguments: vec![
+            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+        ],
+    };
 }
 
 ////////////////////////////////////////////////////////////////////////////////
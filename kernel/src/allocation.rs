@@ -0,0 +1,267 @@
+//! # Handle allocation
+//!
+//! By default the kernel issues handles sequentially, so that a guest may
+//! guess the handle of an object that it never received, e.g. one registered
+//! by another component of a prover, simply by counting.  As a hardening
+//! option, the kernel may instead draw handles pseudo-randomly from a sparse
+//! space, so that a guessed handle almost never points-to any kernel object.
+//!
+//! Either way, every handle issued is assigned a *sequence number*, counting
+//! up from the first handle after the preallocated ones, and the kernel orders
+//! handles by their sequence numbers wherever the order of issue matters, e.g.
+//! when discarding the objects registered within a speculative branch.  For
+//! sequential handles the sequence number is the handle itself, whereas for
+//! randomised handles it is recorded in a validity map, so that looking it up
+//! stays O(1).  Randomised handles are still never reissued.
+//!
+//! Randomised handles are drawn from a generator seeded by the embedder, so a
+//! session is only reproducible, e.g. when replaying a recorded trace, given
+//! the same seed.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::handle::PREALLOCATED_HANDLE_UPPER_BOUND;
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The exclusive upper bound of the space that randomised handles are drawn
+/// from.  Guests compiled for 32-bit Wasm hold handles in a `usize`, so every
+/// handle must fit in 32 bits.
+const RANDOMISED_HANDLE_UPPER_BOUND: u64 = 1 << 32;
+/// The inverse of the fraction of the space that randomised handles are drawn
+/// from that may be issued, beyond which handles are deemed exhausted.  Keeping
+/// the space sparse bounds both the chance of guessing a handle, and the number
+/// of draws needed to find an unused one.
+const RANDOMISED_HANDLE_OCCUPANCY: u64 = 16;
+
+////////////////////////////////////////////////////////////////////////////////
+// Handle allocators.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The state of a randomised handle allocator.
+#[derive(Clone, Debug)]
+struct Randomised {
+    /// The state of the xorshift64 generator that handles are drawn from.
+    state: u64,
+    /// The sequence number of every handle issued, indexed by the handle.
+    sequence: HashMap<usize, usize>,
+}
+
+impl Randomised {
+    /// Returns the next pseudo-random handle, which may already be issued.
+    fn draw(&mut self) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        let first = PREALLOCATED_HANDLE_UPPER_BOUND as u64;
+
+        (first + self.state % (RANDOMISED_HANDLE_UPPER_BOUND - first)) as usize
+    }
+}
+
+/// Issues fresh handles, either sequentially or pseudo-randomly, and records
+/// the order in which they were issued.
+#[derive(Clone, Debug)]
+pub(crate) struct HandleAllocator {
+    /// The sequence number of the next handle to issue.
+    next: usize,
+    /// The state of the allocator, if handles are randomised.
+    randomised: Option<Randomised>,
+}
+
+impl HandleAllocator {
+    /// Returns an allocator issuing handles sequentially, from the first
+    /// handle after the preallocated ones.
+    #[inline]
+    pub(crate) fn sequential() -> Self {
+        HandleAllocator::sequential_from(PREALLOCATED_HANDLE_UPPER_BOUND)
+    }
+
+    /// Returns an allocator issuing handles sequentially, from `first`, which
+    /// must be no smaller than the first handle after the preallocated ones.
+    #[inline]
+    pub(crate) fn sequential_from(first: usize) -> Self {
+        HandleAllocator {
+            next: first,
+            randomised: None,
+        }
+    }
+
+    /// Returns an allocator issuing handles pseudo-randomly, from a generator
+    /// seeded with `seed`.
+    pub(crate) fn randomised(seed: u64) -> Self {
+        /* NB: the xorshift64 generator is stuck at zero, so a zero seed is
+         * replaced with an arbitrary non-zero one.
+         */
+        let state = if seed == 0 { 0x9e3779b97f4a7c15 } else { seed };
+
+        HandleAllocator {
+            next: PREALLOCATED_HANDLE_UPPER_BOUND,
+            randomised: Some(Randomised {
+                state,
+                sequence: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Returns `true` iff handles are issued pseudo-randomly.
+    #[inline]
+    pub(crate) fn is_randomised(&self) -> bool {
+        self.randomised.is_some()
+    }
+
+    /// Returns the sequence number that the next handle issued will have.
+    /// Every handle issued so far has a lower sequence number.
+    #[inline]
+    pub(crate) fn next_sequence(&self) -> usize {
+        self.next
+    }
+
+    /// Returns the sequence number of `handle`, or `None` if `handle` is
+    /// neither preallocated nor has been issued.  Sequential allocators
+    /// cannot tell whether `handle` has been issued, so return `handle`
+    /// itself.
+    pub(crate) fn sequence(&self, handle: usize) -> Option<usize> {
+        match &self.randomised {
+            Some(randomised) if handle >= PREALLOCATED_HANDLE_UPPER_BOUND => {
+                randomised.sequence.get(&handle).cloned()
+            }
+            _otherwise => Some(handle),
+        }
+    }
+
+    /// Returns `true` iff `handle` is preallocated, or was issued before the
+    /// handle with sequence number `mark`.
+    #[inline]
+    pub(crate) fn issued_before(&self, handle: usize, mark: usize) -> bool {
+        self.sequence(handle)
+            .is_some_and(|sequence| sequence < mark)
+    }
+
+    /// Issues a fresh handle, or returns `None` if handles are exhausted.
+    pub(crate) fn issue(&mut self) -> Option<usize> {
+        let sequence = self.next;
+        let next = self.next.checked_add(1)?;
+
+        let handle = match &mut self.randomised {
+            None => sequence,
+            Some(randomised) => {
+                if randomised.sequence.len() as u64
+                    * RANDOMISED_HANDLE_OCCUPANCY
+                    >= RANDOMISED_HANDLE_UPPER_BOUND
+                {
+                    return None;
+                }
+
+                let mut handle = randomised.draw();
+
+                while randomised.sequence.contains_key(&handle) {
+                    handle = randomised.draw();
+                }
+
+                randomised.sequence.insert(handle, sequence);
+                handle
+            }
+        };
+
+        self.next = next;
+
+        Some(handle)
+    }
+}
+
+impl Default for HandleAllocator {
+    #[inline]
+    fn default() -> Self {
+        HandleAllocator::sequential()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        allocation::{HandleAllocator, RANDOMISED_HANDLE_UPPER_BOUND},
+        handle::PREALLOCATED_HANDLE_UPPER_BOUND,
+    };
+
+    /// Tests that sequential handles are their own sequence numbers.
+    #[test]
+    pub fn allocation0() {
+        let mut allocator = HandleAllocator::sequential();
+
+        assert_eq!(allocator.issue(), Some(PREALLOCATED_HANDLE_UPPER_BOUND));
+        assert_eq!(
+            allocator.issue(),
+            Some(PREALLOCATED_HANDLE_UPPER_BOUND + 1)
+        );
+        assert_eq!(
+            allocator.next_sequence(),
+            PREALLOCATED_HANDLE_UPPER_BOUND + 2
+        );
+        assert!(allocator.issued_before(
+            PREALLOCATED_HANDLE_UPPER_BOUND,
+            PREALLOCATED_HANDLE_UPPER_BOUND + 1
+        ));
+    }
+
+    /// Tests that randomised handles are distinct, within range, and are
+    /// ordered by their sequence numbers, and that handles never issued have
+    /// none.
+    #[test]
+    pub fn allocation1() {
+        let mut allocator = HandleAllocator::randomised(0);
+        let mut handles = Vec::new();
+
+        for _i in 0..100 {
+            handles.push(allocator.issue().unwrap());
+        }
+
+        let mut distinct = handles.clone();
+
+        distinct.sort_unstable();
+        distinct.dedup();
+
+        assert_eq!(distinct.len(), handles.len());
+        assert!(handles.iter().all(|handle| {
+            (PREALLOCATED_HANDLE_UPPER_BOUND as u64
+                ..RANDOMISED_HANDLE_UPPER_BOUND)
+                .contains(&(*handle as u64))
+        }));
+        assert_ne!(handles[1], handles[0] + 1);
+
+        for (index, handle) in handles.iter().enumerate() {
+            assert_eq!(
+                allocator.sequence(*handle),
+                Some(PREALLOCATED_HANDLE_UPPER_BOUND + index)
+            );
+        }
+
+        let mark = PREALLOCATED_HANDLE_UPPER_BOUND + 50;
+
+        assert!(allocator.issued_before(handles[49], mark));
+        assert!(!allocator.issued_before(handles[50], mark));
+        assert!(allocator.issued_before(0, mark));
+        assert_eq!(allocator.sequence(distinct[0] + 1), None);
+    }
+}
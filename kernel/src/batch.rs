@@ -0,0 +1,586 @@
+//! # Batched registration
+//!
+//! Building a large term from prover-space takes one host call per node of the
+//! term, and every host call crosses the ABI boundary, so the cost of crossing
+//! soon dominates the cost of registering anything.  A *batch* instead encodes
+//! a sequence of the kernel's registration and splitting calls as a compact
+//! command buffer, which is decoded, and then executed, in a single crossing.
+//!
+//! A command buffer is a sequence of commands, read until the buffer is
+//! exhausted, where all integers are unsigned LEB128 varints:
+//!
+//! ```text
+//! command : opcode, operands...
+//! operand : 2 * value, for a literal handle or name `value`, or
+//!           2 * index + 1, for the result with index `index`
+//! ```
+//!
+//! Every command yields one or more results, each a handle or a name, which
+//! are numbered in order from zero across the whole batch, so that a later
+//! command may refer to the results of an earlier one, e.g. an application may
+//! refer to the terms registered by the two commands before it.  The operands
+//! of each opcode are listed on `Opcode`.  Two opcodes take a variable number
+//! of operands, and are followed by a count before the variable part: a
+//! type-combination by the number of its arguments, and a constant by the
+//! number of pairs in its type-substitution.
+//!
+//! A batch is decoded in full, and every reference to a result checked, before
+//! anything is executed.  Execution stops at the first command that fails,
+//! whose error is returned, though the objects registered by the commands
+//! before it stay registered.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    name::Name,
+    proof_object::Reader,
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Opcodes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The opcodes of a command buffer, each mirroring a registration or splitting
+/// call of the kernel.  The operands and results of each are listed in order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Opcode {
+    /// `name`, yielding a type-variable.
+    TypeRegisterVariable,
+    /// `former, count, arguments...`, yielding a type-combination.
+    TypeRegisterCombination,
+    /// `domain, range`, yielding a function type.
+    TypeRegisterFunction,
+    /// `name, type`, yielding a variable.
+    TermRegisterVariable,
+    /// `constant, count, (name, type)...`, yielding a constant at an instance
+    /// of its type.
+    TermRegisterConstant,
+    /// `left, right`, yielding an application.
+    TermRegisterApplication,
+    /// `name, type, body`, yielding a λ-abstraction.
+    TermRegisterLambda,
+    /// `body`, yielding a negation.
+    TermRegisterNegation,
+    /// `left, right`, yielding a conjunction.
+    TermRegisterConjunction,
+    /// `left, right`, yielding a disjunction.
+    TermRegisterDisjunction,
+    /// `left, right`, yielding an implication.
+    TermRegisterImplication,
+    /// `left, right`, yielding an equality.
+    TermRegisterEquality,
+    /// `name, type, body`, yielding a universal quantification.
+    TermRegisterForall,
+    /// `name, type, body`, yielding an existential quantification.
+    TermRegisterExists,
+    /// `type`, yielding its domain and range.
+    TypeSplitFunction,
+    /// `term`, yielding its name and type.
+    TermSplitVariable,
+    /// `term`, yielding its constant and type.
+    TermSplitConstant,
+    /// `term`, yielding its left and right subterms.
+    TermSplitApplication,
+    /// `term`, yielding its name, type and body.
+    TermSplitLambda,
+    /// `term`, yielding its type.
+    TermTypeInfer,
+}
+
+impl Opcode {
+    /// Returns the number of operands taken by the opcode, not counting the
+    /// variable part of the operands of those taking a variable number.
+    fn operands(self) -> usize {
+        match self {
+            Opcode::TypeRegisterVariable
+            | Opcode::TypeRegisterCombination
+            | Opcode::TermRegisterConstant
+            | Opcode::TermRegisterNegation
+            | Opcode::TypeSplitFunction
+            | Opcode::TermSplitVariable
+            | Opcode::TermSplitConstant
+            | Opcode::TermSplitApplication
+            | Opcode::TermSplitLambda
+            | Opcode::TermTypeInfer => 1,
+            Opcode::TypeRegisterFunction
+            | Opcode::TermRegisterVariable
+            | Opcode::TermRegisterApplication
+            | Opcode::TermRegisterConjunction
+            | Opcode::TermRegisterDisjunction
+            | Opcode::TermRegisterImplication
+            | Opcode::TermRegisterEquality => 2,
+            Opcode::TermRegisterLambda
+            | Opcode::TermRegisterForall
+            | Opcode::TermRegisterExists => 3,
+        }
+    }
+
+    /// Returns the number of operands in each element of the variable part of
+    /// the operands of the opcode, or `0` if it takes a fixed number.
+    fn variable_operands(self) -> usize {
+        match self {
+            Opcode::TypeRegisterCombination => 1,
+            Opcode::TermRegisterConstant => 2,
+            _otherwise => 0,
+        }
+    }
+
+    /// Returns the number of results yielded by the opcode.
+    fn results(self) -> usize {
+        match self {
+            Opcode::TypeSplitFunction
+            | Opcode::TermSplitVariable
+            | Opcode::TermSplitConstant
+            | Opcode::TermSplitApplication => 2,
+            Opcode::TermSplitLambda => 3,
+            _otherwise => 1,
+        }
+    }
+}
+
+/// Conversion from an opcode into a `u64`, for encoding command buffers.
+impl From<Opcode> for u64 {
+    fn from(opcode: Opcode) -> u64 {
+        match opcode {
+            Opcode::TypeRegisterVariable => 0,
+            Opcode::TypeRegisterCombination => 1,
+            Opcode::TypeRegisterFunction => 2,
+            Opcode::TermRegisterVariable => 3,
+            Opcode::TermRegisterConstant => 4,
+            Opcode::TermRegisterApplication => 5,
+            Opcode::TermRegisterLambda => 6,
+            Opcode::TermRegisterNegation => 7,
+            Opcode::TermRegisterConjunction => 8,
+            Opcode::TermRegisterDisjunction => 9,
+            Opcode::TermRegisterImplication => 10,
+            Opcode::TermRegisterEquality => 11,
+            Opcode::TermRegisterForall => 12,
+            Opcode::TermRegisterExists => 13,
+            Opcode::TypeSplitFunction => 14,
+            Opcode::TermSplitVariable => 15,
+            Opcode::TermSplitConstant => 16,
+            Opcode::TermSplitApplication => 17,
+            Opcode::TermSplitLambda => 18,
+            Opcode::TermTypeInfer => 19,
+        }
+    }
+}
+
+/// Conversion from a `u64`, read from a command buffer, into an opcode.
+impl TryFrom<u64> for Opcode {
+    type Error = ErrorCode;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Opcode::TypeRegisterVariable),
+            1 => Ok(Opcode::TypeRegisterCombination),
+            2 => Ok(Opcode::TypeRegisterFunction),
+            3 => Ok(Opcode::TermRegisterVariable),
+            4 => Ok(Opcode::TermRegisterConstant),
+            5 => Ok(Opcode::TermRegisterApplication),
+            6 => Ok(Opcode::TermRegisterLambda),
+            7 => Ok(Opcode::TermRegisterNegation),
+            8 => Ok(Opcode::TermRegisterConjunction),
+            9 => Ok(Opcode::TermRegisterDisjunction),
+            10 => Ok(Opcode::TermRegisterImplication),
+            11 => Ok(Opcode::TermRegisterEquality),
+            12 => Ok(Opcode::TermRegisterForall),
+            13 => Ok(Opcode::TermRegisterExists),
+            14 => Ok(Opcode::TypeSplitFunction),
+            15 => Ok(Opcode::TermSplitVariable),
+            16 => Ok(Opcode::TermSplitConstant),
+            17 => Ok(Opcode::TermSplitApplication),
+            18 => Ok(Opcode::TermSplitLambda),
+            19 => Ok(Opcode::TermTypeInfer),
+            _otherwise => Err(ErrorCode::MalformedBatch),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Batches.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An operand of a command: either a literal handle or name, or the result of
+/// an earlier command of the batch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    /// A literal handle or name.
+    Literal(u64),
+    /// The result with the given index.
+    Result(usize),
+}
+
+/// A decoded command buffer, every reference to a result of which has been
+/// checked to refer to the result of an earlier command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Batch {
+    /// The commands of the batch, in order, each with its operands.
+    commands: Vec<(Opcode, Vec<Operand>)>,
+    /// The number of results yielded by the commands of the batch.
+    results: usize,
+}
+
+impl Batch {
+    /// Decodes the command buffer `bytes` into a batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::MalformedBatch)` if `bytes` is not a
+    /// well-formed command buffer, or if any operand refers to a result that
+    /// is not yielded by an earlier command.
+    pub fn decode(bytes: &[u8]) -> Result<Self, ErrorCode> {
+        let mut reader = Reader::new(bytes);
+        let mut commands = Vec::new();
+        let mut results = 0;
+
+        while !reader.is_exhausted() {
+            let opcode = Opcode::try_from(read_varint(&mut reader)?)?;
+            let mut operands = Vec::new();
+
+            for _i in 0..opcode.operands() {
+                operands.push(read_operand(&mut reader, results)?);
+            }
+
+            if opcode.variable_operands() != 0 {
+                /* NB: as with proof objects, the count is untrusted, so the
+                 * operands are grown as they are actually read.
+                 */
+                for _i in 0..read_varint(&mut reader)? {
+                    for _j in 0..opcode.variable_operands() {
+                        operands.push(read_operand(&mut reader, results)?);
+                    }
+                }
+            }
+
+            results += opcode.results();
+            commands.push((opcode, operands));
+        }
+
+        Ok(Batch { commands, results })
+    }
+
+    /// Returns the number of results yielded by the commands of the batch.
+    #[inline]
+    pub fn results(&self) -> usize {
+        self.results
+    }
+}
+
+/// Reads a varint from a command buffer.
+fn read_varint(reader: &mut Reader) -> Result<u64, ErrorCode> {
+    reader.read_varint().map_err(|_e| ErrorCode::MalformedBatch)
+}
+
+/// Reads an operand from a command buffer, where `results` is the number of
+/// results yielded by the commands read so far.
+fn read_operand(
+    reader: &mut Reader,
+    results: usize,
+) -> Result<Operand, ErrorCode> {
+    let operand = read_varint(reader)?;
+
+    if operand % 2 == 0 {
+        Ok(Operand::Literal(operand / 2))
+    } else if operand / 2 < results as u64 {
+        Ok(Operand::Result((operand / 2) as usize))
+    } else {
+        Err(ErrorCode::MalformedBatch)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Executing batches.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Executes the commands of `batch` in order, returning the results
+    /// yielded by every command, in order, with handles and names alike as
+    /// `u64`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first command that fails, as for the kernel
+    /// call that the command mirrors, after which no further command is
+    /// executed.  Objects registered by earlier commands remain registered.
+    pub fn batch_execute(
+        &mut self,
+        batch: &Batch,
+    ) -> Result<Vec<u64>, ErrorCode> {
+        info!("Executing batch of {} commands.", batch.commands.len());
+
+        let mut results = Vec::with_capacity(batch.results);
+
+        for (opcode, operands) in batch.commands.iter() {
+            let operands: Vec<u64> = operands
+                .iter()
+                .map(|operand| match operand {
+                    Operand::Literal(value) => *value,
+                    Operand::Result(index) => results[*index],
+                })
+                .collect();
+
+            self.batch_step(*opcode, &operands, &mut results)?;
+        }
+
+        Ok(results)
+    }
+
+    /// Executes a single command, with opcode `opcode` and operands
+    /// `operands`, pushing its results onto `results`.
+    fn batch_step(
+        &mut self,
+        opcode: Opcode,
+        operands: &[u64],
+        results: &mut Vec<u64>,
+    ) -> Result<(), ErrorCode> {
+        let name = |index: usize| -> Name { operands[index] };
+        let tau = |index: usize| -> Handle<tags::Type> {
+            Handle::from(operands[index] as usize)
+        };
+        let term = |index: usize| -> Handle<tags::Term> {
+            Handle::from(operands[index] as usize)
+        };
+
+        match opcode {
+            Opcode::TypeRegisterVariable => {
+                results.push(*self.type_register_variable(name(0)) as u64);
+            }
+            Opcode::TypeRegisterCombination => {
+                let former: Handle<tags::TypeFormer> =
+                    Handle::from(operands[0] as usize);
+                let arguments = (1..operands.len()).map(tau).collect();
+                let result =
+                    self.type_register_combination(former, arguments)?;
+                results.push(*result as u64);
+            }
+            Opcode::TypeRegisterFunction => {
+                let result = self.type_register_function(tau(0), tau(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterVariable => {
+                let result = self.term_register_variable(name(0), tau(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterConstant => {
+                let constant: Handle<tags::Constant> =
+                    Handle::from(operands[0] as usize);
+                let substitution = (1..operands.len())
+                    .step_by(2)
+                    .map(|index| (name(index), tau(index + 1)))
+                    .collect();
+                let result =
+                    self.term_register_constant(constant, substitution)?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterApplication => {
+                let result =
+                    self.term_register_application(term(0), term(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterLambda => {
+                let result =
+                    self.term_register_lambda(name(0), tau(1), term(2))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterNegation => {
+                let result = self.term_register_negation(term(0))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterConjunction => {
+                let result =
+                    self.term_register_conjunction(term(0), term(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterDisjunction => {
+                let result =
+                    self.term_register_disjunction(term(0), term(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterImplication => {
+                let result =
+                    self.term_register_implication(term(0), term(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterEquality => {
+                let result = self.term_register_equality(term(0), term(1))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterForall => {
+                let result =
+                    self.term_register_forall(name(0), tau(1), term(2))?;
+                results.push(*result as u64);
+            }
+            Opcode::TermRegisterExists => {
+                let result =
+                    self.term_register_exists(name(0), tau(1), term(2))?;
+                results.push(*result as u64);
+            }
+            Opcode::TypeSplitFunction => {
+                let (domain, range) = self.type_split_function(tau(0))?;
+                results.extend_from_slice(&[**domain as u64, **range as u64]);
+            }
+            Opcode::TermSplitVariable => {
+                let (name, tau) = self.term_split_variable(term(0))?;
+                results.extend_from_slice(&[*name, **tau as u64]);
+            }
+            Opcode::TermSplitConstant => {
+                let (constant, tau) = self.term_split_constant(term(0))?;
+                results.extend_from_slice(&[**constant as u64, **tau as u64]);
+            }
+            Opcode::TermSplitApplication => {
+                let (left, right) = self.term_split_application(term(0))?;
+                results.extend_from_slice(&[**left as u64, **right as u64]);
+            }
+            Opcode::TermSplitLambda => {
+                let (name, tau, body) = self.term_split_lambda(term(0))?;
+                results.extend_from_slice(&[
+                    *name,
+                    **tau as u64,
+                    **body as u64,
+                ]);
+            }
+            Opcode::TermTypeInfer => {
+                let result = self.term_type_infer(term(0))?;
+                results.push(*result as u64);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for batched registration.
+#[cfg(test)]
+mod test {
+    use crate::{
+        batch::{Batch, Opcode},
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        proof_object::write_varint,
+        runtime_state::RuntimeState,
+    };
+
+    /// Encodes a command buffer from a sequence of opcodes and their already
+    /// encoded operands.
+    fn encode(commands: &[(Opcode, &[u64])]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        for (opcode, operands) in commands {
+            write_varint(&mut buffer, u64::from(*opcode));
+
+            for operand in operands.iter() {
+                write_varint(&mut buffer, *operand);
+            }
+        }
+
+        buffer
+    }
+
+    /// Tests that a batch registers the same objects as the kernel calls it
+    /// mirrors, with later commands referring to the results of earlier ones.
+    #[test]
+    pub fn batch0() {
+        let mut state = RuntimeState::new();
+
+        let prop = *PREALLOCATED_HANDLE_TYPE_PROP as u64;
+        let truth = *PREALLOCATED_HANDLE_TERM_TRUE as u64;
+
+        let bytes = encode(&[
+            (Opcode::TermRegisterVariable, &[0, 2 * prop]),
+            (Opcode::TermRegisterLambda, &[0, 2 * prop, 1]),
+            (Opcode::TermRegisterApplication, &[3, 2 * truth]),
+            (Opcode::TermSplitApplication, &[5]),
+            (Opcode::TermTypeInfer, &[5]),
+        ]);
+        let batch = Batch::decode(&bytes).unwrap();
+
+        assert_eq!(batch.results(), 6);
+
+        let results = state.batch_execute(&batch).unwrap();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                x.clone(),
+            )
+            .unwrap();
+        let application = state
+            .term_register_application(
+                lambda.clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                *x as u64,
+                *lambda as u64,
+                *application as u64,
+                *lambda as u64,
+                truth,
+                prop
+            ]
+        );
+    }
+
+    /// Tests that malformed command buffers are rejected before anything is
+    /// executed, and that execution stops at the first failing command.
+    #[test]
+    pub fn batch1() {
+        let mut state = RuntimeState::new();
+
+        let prop = *PREALLOCATED_HANDLE_TYPE_PROP as u64;
+
+        assert_eq!(Batch::decode(&[20]), Err(ErrorCode::MalformedBatch));
+        assert_eq!(
+            Batch::decode(&encode(&[(Opcode::TermRegisterVariable, &[0])])),
+            Err(ErrorCode::MalformedBatch)
+        );
+        assert_eq!(
+            Batch::decode(&encode(&[(Opcode::TermRegisterNegation, &[1])])),
+            Err(ErrorCode::MalformedBatch)
+        );
+
+        let bytes = encode(&[
+            (Opcode::TermRegisterVariable, &[0, 2 * prop]),
+            (Opcode::TermSplitApplication, &[1]),
+            (Opcode::TermRegisterNegation, &[1]),
+        ]);
+        let batch = Batch::decode(&bytes).unwrap();
+
+        assert_eq!(
+            state.batch_execute(&batch),
+            Err(ErrorCode::NotAnApplication)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! # Bloom filters
+//!
+//! A Bloom filter is a compact, probabilistic, set: asking whether a key is a
+//! member may return a false positive, but never a false negative.  The kernel
+//! keeps one over the conclusions of its registered theorems, so that the
+//! common case of an existence query from automation, "has this already been
+//! proved?", whose answer is usually "no", is answered without consulting the
+//! kernel's tables.  Keys are never removed, so a filter must be rebuilt to
+//! forget a key, e.g. when a speculative branch is discarded.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+////////////////////////////////////////////////////////////////////////////////
+// Useful constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of bits of the filter per key that it is sized for, which, with
+/// `HASHES` hashes per key, gives a false positive rate of around 2% at
+/// capacity.
+const BITS_PER_KEY: usize = 8;
+/// The number of bits set in the filter per key.
+const HASHES: u64 = 4;
+/// The number of keys that a filter is sized for, initially.
+const INITIAL_CAPACITY: usize = 1024;
+
+////////////////////////////////////////////////////////////////////////////////
+// Hashing.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Mixes the bits of `key`, with the finaliser of the SplitMix64 generator.
+fn mix(mut key: u64) -> u64 {
+    key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+    key ^ (key >> 31)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Bloom filters, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A Bloom filter over `u64` keys, which doubles in size whenever the number
+/// of keys inserted exceeds the number that it is sized for, so that its false
+/// positive rate stays bounded.
+#[derive(Clone, Debug)]
+pub(crate) struct BloomFilter {
+    /// The bits of the filter, packed into words.
+    words: Vec<u64>,
+    /// The keys inserted, kept so that the filter may be rebuilt when grown.
+    keys: Vec<u64>,
+}
+
+impl BloomFilter {
+    /// Returns a new, empty, filter sized for `capacity` keys.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let bits = capacity.max(1) * BITS_PER_KEY;
+
+        BloomFilter {
+            words: vec![0; bits.div_ceil(64)],
+            keys: Vec::new(),
+        }
+    }
+
+    /// Returns the number of bits in the filter.
+    #[inline]
+    fn bits(&self) -> u64 {
+        self.words.len() as u64 * 64
+    }
+
+    /// Returns the positions of the bits set for `key`, derived from two
+    /// hashes of the key by double hashing.
+    fn positions(&self, key: u64) -> impl Iterator<Item = u64> {
+        let first = mix(key);
+        let second = mix(first) | 1;
+        let bits = self.bits();
+
+        (0..HASHES)
+            .map(move |i| first.wrapping_add(i.wrapping_mul(second)) % bits)
+    }
+
+    /// Inserts `key` into the filter, growing the filter if it now holds more
+    /// keys than it is sized for.
+    pub(crate) fn insert(&mut self, key: u64) {
+        if self.keys.len() >= self.words.len() * 64 / BITS_PER_KEY {
+            let mut grown = BloomFilter::with_capacity(self.keys.len() * 2);
+
+            for key in self.keys.drain(..) {
+                grown.insert(key);
+            }
+
+            *self = grown;
+        }
+
+        for position in self.positions(key).collect::<Vec<_>>() {
+            self.words[(position / 64) as usize] |= 1 << (position % 64);
+        }
+
+        self.keys.push(key);
+    }
+
+    /// Returns `false` if `key` has definitely not been inserted into the
+    /// filter, and `true` if it may have been.
+    pub(crate) fn may_contain(&self, key: u64) -> bool {
+        self.positions(key).all(|position| {
+            self.words[(position / 64) as usize] & (1 << (position % 64)) != 0
+        })
+    }
+}
+
+impl Default for BloomFilter {
+    #[inline]
+    fn default() -> Self {
+        BloomFilter::with_capacity(INITIAL_CAPACITY)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::bloom::BloomFilter;
+
+    /// Tests that a filter never reports a false negative, including across
+    /// growth, and rarely reports a false positive.
+    #[test]
+    pub fn bloom0() {
+        let mut filter = BloomFilter::with_capacity(16);
+
+        for key in (0..4096).map(|key| key * 3) {
+            filter.insert(key);
+        }
+
+        assert!((0..4096).all(|key| filter.may_contain(key * 3)));
+
+        let false_positives = (0..4096)
+            .map(|key| key * 3 + 1)
+            .filter(|key| filter.may_contain(*key))
+            .count();
+
+        assert!(false_positives < 4096 / 10);
+    }
+}
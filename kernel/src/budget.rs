@@ -0,0 +1,291 @@
+//! # Resource budgets
+//!
+//! Challenges and capabilities, from the `capability` module, decide *whether*
+//! a guest may use a guarded service.  Budgets decide *how often*.  A budget
+//! is a linear resource assertion, `Rⁿ`: `n` copies of a resource
+//! proposition, `R`, chosen by the host, that are consumed, one at a time, as
+//! the service that they are granted for is used.  Unlike theorems, resource
+//! assertions cannot be duplicated: once every copy has been consumed, the
+//! service may not be used again until the host grants more, so that a policy
+//! such as "you may open at most `n` files" can be stated, and enforced, in the
+//! kernel.
+//!
+//! Budgets are only compiled into the kernel with the experimental `budgets`
+//! cargo feature.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    capability::Service,
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+
+////////////////////////////////////////////////////////////////////////////////
+// Budgets, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Budget objects record the resource proposition asserted by the budget,
+/// along with the number of copies of the assertion that remain to be
+/// consumed.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Budget {
+    /// A handle to the resource proposition, `R`.
+    resource: Handle<tags::Term>,
+    /// The number of copies of `R` that remain.
+    remaining: u64,
+}
+
+impl Budget {
+    /// Creates a new budget asserting `remaining` copies of the resource
+    /// proposition pointed-to by `resource`.
+    #[inline]
+    pub fn new<T>(resource: T, remaining: u64) -> Self
+    where
+        T: Into<Handle<tags::Term>>,
+    {
+        Budget {
+            resource: resource.into(),
+            remaining,
+        }
+    }
+
+    /// Returns a handle to the resource proposition asserted by the budget.
+    #[inline]
+    pub fn resource(&self) -> &Handle<tags::Term> {
+        &self.resource
+    }
+
+    /// Returns the number of copies of the resource assertion that remain to
+    /// be consumed.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns `true` iff every copy of the resource assertion has been
+    /// consumed.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Consumes a single copy of the resource assertion, returning `false`,
+    /// and leaving the budget untouched, if the budget is already exhausted.
+    pub(crate) fn consume(&mut self) -> bool {
+        if self.is_exhausted() {
+            return false;
+        }
+
+        self.remaining -= 1;
+        true
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Budget-related material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Grants `amount` copies of the resource proposition pointed-to by
+    /// `resource` to the service `service`, so that the service may be used at
+    /// most `amount` more times.  Replaces any existing budget for `service`,
+    /// along with whatever remained of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `resource` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `resource` does not
+    /// point-to a term with propositional type.
+    pub fn budget_grant<T>(
+        &mut self,
+        service: Service,
+        resource: T,
+        amount: u64,
+    ) -> Result<(), ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Granting budget of {} for service {} with resource handle: {}.",
+            amount,
+            service,
+            resource.clone().into()
+        );
+
+        let resource = resource.into();
+
+        if !self.term_type_is_proposition(&resource)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        self.budgets.insert(service, Budget::new(resource, amount));
+
+        Ok(())
+    }
+
+    /// Returns `Ok(budget)` if the host has granted the budget, `budget`, to
+    /// the service `service`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchBudgetRegistered)` if no budget has been
+    /// granted to `service`.
+    #[inline]
+    pub fn budget_resolve(
+        &self,
+        service: Service,
+    ) -> Result<&Budget, ErrorCode> {
+        info!("Resolving budget for service {}.", service);
+
+        self.budgets
+            .get(&service)
+            .ok_or(ErrorCode::NoSuchBudgetRegistered)
+    }
+
+    /// Consumes a single copy of the resource assertion granted to the
+    /// service `service`, returning `Ok(())` if a copy remained to be
+    /// consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchBudgetRegistered)` if no budget has been
+    /// granted to `service`.
+    ///
+    /// Returns `Err(ErrorCode::BudgetExhausted)` if every copy of the resource
+    /// assertion granted to `service` has already been consumed.
+    pub fn budget_consume(
+        &mut self,
+        service: Service,
+    ) -> Result<(), ErrorCode> {
+        info!("Consuming budget for service {}.", service);
+
+        let budget = self
+            .budgets
+            .get_mut(&service)
+            .ok_or(ErrorCode::NoSuchBudgetRegistered)?;
+
+        if budget.consume() {
+            Ok(())
+        } else {
+            Err(ErrorCode::BudgetExhausted)
+        }
+    }
+
+    /// Charges a single use of the service `service` against its budget.
+    /// Unlike `budget_consume()`, services that have been granted no budget
+    /// are unlimited, and may always be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::BudgetExhausted)` if `service` has been granted
+    /// a budget, and every copy of its resource assertion has already been
+    /// consumed.
+    pub fn budget_charge(&mut self, service: Service) -> Result<(), ErrorCode> {
+        match self.budget_consume(service) {
+            Err(ErrorCode::NoSuchBudgetRegistered) => Ok(()),
+            otherwise => otherwise,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for budget-related functionality.
+#[cfg(test)]
+mod test {
+    use crate::{
+        budget::Budget,
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TYPE_BETA,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that a budget may be consumed exactly as many times as it was
+    /// granted for, and not once more.
+    #[test]
+    pub fn budget_test0() {
+        let mut budget = Budget::new(PREALLOCATED_HANDLE_TERM_TRUE, 2);
+
+        assert!(!budget.is_exhausted());
+        assert!(budget.consume());
+        assert_eq!(budget.remaining(), 1);
+        assert!(budget.consume());
+        assert!(budget.is_exhausted());
+        assert!(!budget.consume());
+        assert_eq!(budget.remaining(), 0);
+        assert_eq!(budget.resource(), &PREALLOCATED_HANDLE_TERM_TRUE);
+    }
+
+    /// Tests that granted budgets are consumed by uses of their service, and
+    /// that services without a budget are unlimited when charged.
+    #[test]
+    pub fn budget_test1() {
+        let mut state = RuntimeState::new();
+
+        assert_eq!(
+            state.budget_resolve(0),
+            Err(ErrorCode::NoSuchBudgetRegistered)
+        );
+        assert_eq!(
+            state.budget_consume(0),
+            Err(ErrorCode::NoSuchBudgetRegistered)
+        );
+        assert_eq!(state.budget_charge(0), Ok(()));
+
+        state
+            .budget_grant(0, PREALLOCATED_HANDLE_TERM_TRUE, 1)
+            .unwrap();
+
+        assert_eq!(state.budget_resolve(0).unwrap().remaining(), 1);
+        assert_eq!(state.budget_charge(0), Ok(()));
+        assert_eq!(state.budget_charge(0), Err(ErrorCode::BudgetExhausted));
+        assert_eq!(state.budget_consume(0), Err(ErrorCode::BudgetExhausted));
+
+        state
+            .budget_grant(0, PREALLOCATED_HANDLE_TERM_TRUE, 3)
+            .unwrap();
+
+        assert_eq!(state.budget_resolve(0).unwrap().remaining(), 3);
+    }
+
+    /// Tests that budgets may only assert propositions.
+    #[test]
+    pub fn budget_test2() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+
+        assert_eq!(
+            state.budget_grant(0, x, 1),
+            Err(ErrorCode::NotAProposition)
+        );
+        assert_eq!(
+            state.budget_resolve(0),
+            Err(ErrorCode::NoSuchBudgetRegistered)
+        );
+    }
+}
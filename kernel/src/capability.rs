@@ -0,0 +1,175 @@
+//! # Capabilities
+//!
+//! Supervisionary guards privileged host services behind *challenges*: each
+//! guarded service is associated with a proposition, registered by the host,
+//! which prover-space must prove before the service may be used.  Once a guest
+//! discharges a challenge, by presenting a theorem with no premisses whose
+//! conclusion is the challenge proposition, the kernel mints a *capability*
+//! for the service.  The capability may then be presented to subsequent calls
+//! of the guarded service, without the guest needing to re-prove the
+//! challenge, until its expiry policy lapses.
+//!
+//! Note that, like other kernel objects, capabilities are merely bookkeeping,
+//! here: the runtime state is responsible for checking that challenges are
+//! discharged and that capabilities are presented before they expire.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Services and expiry policies.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Guarded host services are identified by a machine word, chosen by the host.
+pub type Service = u64;
+
+/// Expiry policies, describing how long a capability remains valid for after
+/// it has been minted.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Expiry {
+    /// The capability may be presented at most this many times.
+    Uses(u64),
+    /// The capability may be presented any number of times, until the host
+    /// signals that the machine state, from which challenges are constructed,
+    /// has changed.
+    UntilStateChange,
+}
+
+/// Conversion from a pair of `u64` values, received across the ABI boundary,
+/// into an expiry policy.  The first value encodes the kind of policy, and the
+/// second its parameter, which is ignored by policies without a parameter.
+impl TryFrom<(u64, u64)> for Expiry {
+    type Error = ();
+
+    fn try_from(value: (u64, u64)) -> Result<Self, Self::Error> {
+        match value {
+            (0, uses) => Ok(Expiry::Uses(uses)),
+            (1, _parameter) => Ok(Expiry::UntilStateChange),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Capabilities, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Capability objects record the service that they grant access to, along
+/// with what remains of their expiry policy.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Capability {
+    /// The service that the capability grants access to.
+    service: Service,
+    /// The expiry policy of the capability.  For `Expiry::Uses`, this records
+    /// the number of uses remaining.
+    expiry: Expiry,
+    /// The number of machine state changes that the host had signalled when
+    /// the capability was minted.
+    epoch: u64,
+}
+
+impl Capability {
+    /// Creates a new capability for `service`, minted at the machine state
+    /// epoch `epoch` with expiry policy `expiry`.
+    #[inline]
+    pub fn new(service: Service, expiry: Expiry, epoch: u64) -> Self {
+        Capability {
+            service,
+            expiry,
+            epoch,
+        }
+    }
+
+    /// Returns the service that the capability grants access to.
+    #[inline]
+    pub fn service(&self) -> Service {
+        self.service
+    }
+
+    /// Returns what remains of the capability's expiry policy.
+    #[inline]
+    pub fn expiry(&self) -> Expiry {
+        self.expiry
+    }
+
+    /// Returns `true` iff the capability has expired, given that the host has
+    /// signalled `epoch` machine state changes.
+    pub fn is_expired(&self, epoch: u64) -> bool {
+        match self.expiry {
+            Expiry::Uses(uses) => uses == 0,
+            Expiry::UntilStateChange => epoch != self.epoch,
+        }
+    }
+
+    /// Revokes the capability, so that it is expired from now on.
+    #[inline]
+    pub(crate) fn revoke(&mut self) {
+        self.expiry = Expiry::Uses(0);
+    }
+
+    /// Records a single use of the capability.  Callers are expected to check
+    /// that the capability has not expired beforehand.
+    pub(crate) fn consume(&mut self) {
+        if let Expiry::Uses(uses) = self.expiry {
+            self.expiry = Expiry::Uses(uses.saturating_sub(1));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for capability-related functionality.
+#[cfg(test)]
+mod test {
+    use crate::capability::{Capability, Expiry};
+    use std::convert::TryFrom;
+
+    /// Tests that capabilities limited to a number of uses expire once they
+    /// are used up, and not before.
+    #[test]
+    pub fn capability_test0() {
+        let mut capability = Capability::new(0, Expiry::Uses(2), 0);
+
+        assert!(!capability.is_expired(0));
+        capability.consume();
+        assert!(!capability.is_expired(1));
+        capability.consume();
+        assert!(capability.is_expired(0));
+        assert_eq!(capability.expiry(), Expiry::Uses(0));
+    }
+
+    /// Tests that capabilities valid until the machine state changes expire
+    /// once it does, however often they are used.
+    #[test]
+    pub fn capability_test1() {
+        let mut capability = Capability::new(0, Expiry::UntilStateChange, 3);
+
+        capability.consume();
+        assert!(!capability.is_expired(3));
+        assert!(capability.is_expired(4));
+    }
+
+    /// Tests the decoding of expiry policies received across the ABI boundary.
+    #[test]
+    pub fn capability_test2() {
+        assert_eq!(Expiry::try_from((0, 5)), Ok(Expiry::Uses(5)));
+        assert_eq!(Expiry::try_from((1, 5)), Ok(Expiry::UntilStateChange));
+        assert_eq!(Expiry::try_from((2, 0)), Err(()));
+    }
+}
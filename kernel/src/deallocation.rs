@@ -0,0 +1,296 @@
+//! # Reference counting and deallocation
+//!
+//! Kernel objects are registered for the lifetime of a session, so a
+//! long-running guest that registers many short-lived types, terms, and
+//! theorems, e.g. whilst searching for a proof, slowly leaks memory in kernel
+//! space.  Guests may instead free the kernel objects that they no longer need,
+//! either explicitly, by *deleting* them, or by *retaining* and later
+//! *releasing* references to them, whereupon an object is freed once the last
+//! reference to it is released.
+//!
+//! Reference counts only record the references held by the guest: a kernel
+//! object starts out with none, and is never freed implicitly unless the guest
+//! retained and then released it.  Whatever its reference count, a kernel
+//! object is never freed whilst another registered kernel object refers to
+//! it, e.g. a term that is the conclusion of a registered theorem, or the body
+//! of a registered λ-abstraction, so freeing an object never leaves a dangling
+//! handle within the kernel.  Freeing an object frees none of the objects that
+//! it refers to, which must be freed in turn.  In particular, the premisses of
+//! a theorem are interned as a set of hypotheses, whose handle is found by
+//! interning them again, and which must be freed before its terms may be.
+//!
+//! Only types, terms, theorems, and interned sets of hypotheses are ever
+//! freed, and never those built into the kernel, nor any registered before
+//! the innermost speculative branch in progress began, as discarding the
+//! branch could not then restore them.  Handles are never reissued, so the
+//! handle of a freed object reports that no object of its kind is registered.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{Kind, PREALLOCATED_HANDLE_UPPER_BOUND},
+    runtime_state::RuntimeState,
+};
+use log::info;
+
+////////////////////////////////////////////////////////////////////////////////
+// Reference counting-related material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns `Ok(())` iff the kernel object pointed-to by `handle` may be
+    /// freed once the guest holds no references to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    ///
+    /// Returns `Err(ErrorCode::ObjectInUse)` if the object is of a kind that is
+    /// never freed, is built into the kernel, was registered before the
+    /// innermost speculative branch in progress began, or if another
+    /// registered kernel object refers to it.
+    fn object_check_freeable(&self, handle: usize) -> Result<(), ErrorCode> {
+        self.object_check_registered(handle)?;
+
+        let kind = self.object_kind(handle);
+
+        if !matches!(
+            kind,
+            Some(Kind::Type | Kind::Term | Kind::Theorem | Kind::Hypotheses)
+        ) || handle < PREALLOCATED_HANDLE_UPPER_BOUND
+            || self.object_predates_speculation(handle)
+            || self.object_is_referenced(handle)
+        {
+            return Err(ErrorCode::ObjectInUse);
+        }
+
+        Ok(())
+    }
+
+    /// Records a reference, held by the guest, to the kernel object pointed-to
+    /// by `handle`, which is not freed whilst the guest holds any references to
+    /// it.  Objects of any kind may be retained, though only some are ever
+    /// freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    pub fn object_inc_ref(&mut self, handle: usize) -> Result<(), ErrorCode> {
+        info!("Retaining kernel object with handle: {}.", handle);
+
+        self.object_check_registered(handle)?;
+
+        let count = self.references.entry(handle).or_insert(0);
+
+        *count = count.saturating_add(1);
+
+        Ok(())
+    }
+
+    /// Releases a reference, held by the guest, to the kernel object pointed-to
+    /// by `handle`.  If that was the last reference held by the guest, then the
+    /// object is freed, unless it may not be, e.g. because another registered
+    /// kernel object refers to it, in which case it remains registered without
+    /// error.  Returns `Ok(true)` iff the object was freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    ///
+    /// Returns `Err(ErrorCode::ObjectNotRetained)` if the guest holds no
+    /// references to the object.
+    pub fn object_dec_ref(&mut self, handle: usize) -> Result<bool, ErrorCode> {
+        info!("Releasing kernel object with handle: {}.", handle);
+
+        self.object_check_registered(handle)?;
+
+        let count = self
+            .references
+            .get_mut(&handle)
+            .ok_or(ErrorCode::ObjectNotRetained)?;
+
+        *count -= 1;
+
+        if *count > 0 {
+            return Ok(false);
+        }
+
+        self.references.remove(&handle);
+
+        if self.object_check_freeable(handle).is_err() {
+            return Ok(false);
+        }
+
+        self.object_free(handle);
+
+        Ok(true)
+    }
+
+    /// Frees the kernel object pointed-to by `handle`, to which the guest must
+    /// hold no references.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    ///
+    /// Returns `Err(ErrorCode::ObjectInUse)` if the guest holds references to
+    /// the object, or if the object may not be freed, e.g. because another
+    /// registered kernel object refers to it.
+    pub fn object_delete(&mut self, handle: usize) -> Result<(), ErrorCode> {
+        info!("Deleting kernel object with handle: {}.", handle);
+
+        self.object_check_freeable(handle)?;
+
+        if self.references.contains_key(&handle) {
+            return Err(ErrorCode::ObjectInUse);
+        }
+
+        self.object_free(handle);
+
+        Ok(())
+    }
+
+    /// Returns the number of references held by the guest to the kernel object
+    /// pointed-to by `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    pub fn object_reference_count(
+        &self,
+        handle: usize,
+    ) -> Result<usize, ErrorCode> {
+        self.object_check_registered(handle)?;
+
+        Ok(self.references.get(&handle).cloned().unwrap_or(0))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for reference counting-related functionality.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that objects referred to by other objects are never freed, and
+    /// that freeing them in turn frees everything.
+    #[test]
+    pub fn deallocation_test0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let assume = state.theorem_register_assumption(p.clone()).unwrap();
+        let hyps = state.hypotheses_intern(vec![p.clone()]).unwrap();
+
+        assert_eq!(state.object_delete(*p), Err(ErrorCode::ObjectInUse));
+        assert_eq!(state.object_delete(*hyps), Err(ErrorCode::ObjectInUse));
+        assert_eq!(
+            state.object_delete(*PREALLOCATED_HANDLE_TERM_TRUE),
+            Err(ErrorCode::ObjectInUse)
+        );
+
+        assert_eq!(state.object_delete(*assume), Ok(()));
+        assert!(!state.theorem_is_registered(&assume));
+        assert_eq!(state.object_delete(*p), Err(ErrorCode::ObjectInUse));
+        assert_eq!(state.object_delete(*hyps), Ok(()));
+        assert_eq!(state.object_delete(*p), Ok(()));
+        assert!(!state.term_is_registered(&p));
+        assert_eq!(
+            state.object_delete(*p),
+            Err(ErrorCode::NoSuchObjectRegistered)
+        );
+
+        let q = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_ne!(q, p);
+    }
+
+    /// Tests that retained objects are only freed once the last reference to
+    /// them is released.
+    #[test]
+    pub fn deallocation_test1() {
+        let mut state = RuntimeState::new();
+
+        let alpha = state.type_register_variable(7u64);
+        let tau = state.type_register_function(alpha.clone(), alpha).unwrap();
+
+        assert_eq!(state.object_inc_ref(*tau), Ok(()));
+        assert_eq!(state.object_inc_ref(*tau), Ok(()));
+        assert_eq!(state.object_reference_count(*tau), Ok(2));
+        assert_eq!(state.object_delete(*tau), Err(ErrorCode::ObjectInUse));
+        assert_eq!(state.object_dec_ref(*tau), Ok(false));
+        assert_eq!(state.object_dec_ref(*tau), Ok(true));
+        assert!(!state.type_is_registered(&tau));
+        assert_eq!(
+            state.object_dec_ref(*tau),
+            Err(ErrorCode::NoSuchObjectRegistered)
+        );
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(state.object_dec_ref(*p), Err(ErrorCode::ObjectNotRetained));
+    }
+
+    /// Tests that objects registered before a speculative branch began are not
+    /// freed within it, and that references released within a discarded
+    /// branch are restored.
+    #[test]
+    pub fn deallocation_test2() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        state.object_inc_ref(*p).unwrap();
+        state.speculate_begin();
+
+        assert_eq!(state.object_dec_ref(*p), Ok(false));
+        assert_eq!(state.object_delete(*p), Err(ErrorCode::ObjectInUse));
+
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(state.object_delete(*q), Ok(()));
+
+        state.speculate_end(false).unwrap();
+
+        assert_eq!(state.object_reference_count(*p), Ok(1));
+        assert_eq!(state.object_dec_ref(*p), Ok(true));
+    }
+}
@@ -0,0 +1,143 @@
+//! # Derived rules
+//!
+//! Derived inference rules shared by the kernel's definitional packages, each
+//! implemented by chaining together the kernel's primitive inference rules.
+//! Nothing in this module extends the kernel's trusted base: every theorem
+//! registered is recorded, and replayed, as the sequence of primitive rules
+//! that derived it.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    name::Name,
+    runtime_state::RuntimeState,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Derived rules.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Derives `Γ ∪ Δ ⊢ ψ` from `Γ ⊢ ɸ = ψ`, pointed-to by `equality`, and
+    /// `Δ ⊢ ɸ`, pointed-to by `handle`.
+    pub(crate) fn derived_equality_mp(
+        &mut self,
+        equality: &Handle<tags::Theorem>,
+        handle: &Handle<tags::Theorem>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let implication =
+            self.theorem_register_iff_left_elimination(equality)?;
+
+        self.theorem_register_implication_elimination(implication, handle)
+    }
+
+    /// Derives `Γ ⊢ s a₁ … aₙ = t[x₁ ↦ a₁, …, xₙ ↦ aₙ]` from the theorem
+    /// `Γ ⊢ s = λx₁ … xₙ. t`, pointed-to by `handle`, for the terms
+    /// `arguments`, by β-reducing each argument in turn.  Used to unfold a
+    /// definition applied to arguments, and, from reflexivity, to reduce an
+    /// abstraction applied to arguments.
+    pub(crate) fn derived_unfold(
+        &mut self,
+        handle: &Handle<tags::Theorem>,
+        arguments: &[Handle<tags::Term>],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle.clone();
+
+        for argument in arguments {
+            let reflexivity = self
+                .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                    argument.clone(),
+                )?;
+            let congruence =
+                self.theorem_register_application(thm, reflexivity)?;
+
+            let (_left, right) = self.term_split_equality(
+                self.theorem_split_conclusion(&congruence)?,
+            )?;
+            let right = right.clone();
+
+            let beta = self.theorem_register_beta(right)?;
+
+            thm = self.theorem_register_transitivity(congruence, beta)?;
+        }
+
+        Ok(thm)
+    }
+
+    /// Instantiates the outermost universal quantifiers of the theorem
+    /// pointed-to by `handle` with the variables `binders`, in order.
+    pub(crate) fn derived_specialise(
+        &mut self,
+        handle: Handle<tags::Theorem>,
+        binders: &[(Name, Handle<tags::Type>)],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle;
+
+        for (name, tau) in binders {
+            let variable = self.term_register_variable(*name, tau.clone())?;
+
+            thm = self.theorem_register_forall_elimination(thm, variable)?;
+        }
+
+        Ok(thm)
+    }
+
+    /// Universally quantifies the conclusion of the theorem pointed-to by
+    /// `handle` over the variables `binders`, so that the first binder is
+    /// outermost.
+    pub(crate) fn derived_generalise(
+        &mut self,
+        handle: Handle<tags::Theorem>,
+        binders: &[(Name, Handle<tags::Type>)],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle;
+
+        for (name, tau) in binders.iter().rev() {
+            thm = self.theorem_register_forall_introduction(
+                *name,
+                tau.clone(),
+                thm,
+            )?;
+        }
+
+        Ok(thm)
+    }
+
+    /// Discharges the premisses `hypotheses` from the theorem pointed-to by
+    /// `handle`, so that the first premiss is outermost.  Premisses that the
+    /// theorem does not depend upon are discharged all the same.
+    pub(crate) fn derived_discharge(
+        &mut self,
+        handle: Handle<tags::Theorem>,
+        hypotheses: &[Handle<tags::Term>],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle;
+
+        for hypothesis in hypotheses.iter().rev() {
+            let weakened =
+                self.theorem_register_weaken(hypothesis.clone(), thm)?;
+
+            thm = self.theorem_register_implication_introduction(
+                weakened,
+                hypothesis.clone(),
+            )?;
+        }
+
+        Ok(thm)
+    }
+}
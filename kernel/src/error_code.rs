@@ -37,7 +37,7 @@ use std::{
 use wasmi::HostError;
 
 /// The upper limit (exclusive) on the encoding space of the `ErrorCode` type.
-pub const ERRORCODE_ENCODING_UPPER_BOUND: usize = 28;
+pub const ERRORCODE_ENCODING_UPPER_BOUND: usize = 80;
 
 /// Error codes, used for passing back information on why a kernel operation
 /// failed to prover-space.  These codes are intra-convertible between the `i32`
@@ -51,8 +51,15 @@ pub enum ErrorCode {
     /// exist.
     NoSuchFunction,
     /* Dangling objects. */
+    /// A handle was supplied that did not reference a registered capability.
+    NoSuchCapabilityRegistered,
     /// A handle was supplied that did not reference a registered constant.
     NoSuchConstantRegistered,
+    /// A handle was supplied that did not reference a registered set of
+    /// hypotheses.
+    NoSuchHypothesesRegistered,
+    /// A handle was supplied that did not reference a registered substitution.
+    NoSuchSubstitutionRegistered,
     /// A handle was supplied that did not reference a registered term.
     NoSuchTermRegistered,
     /// A handle was supplied that did not reference a registered theorem.
@@ -70,13 +77,33 @@ pub enum ErrorCode {
     NoSuchTypeRegistered,
     /// A type was expected to be a functional type, but it was not.
     NotAFunctionType,
+    /// A type was expected to be a product type, but it was not.
+    NotAProductType,
+    /// A type was expected to be a set type, i.e. a predicate type of the form
+    /// `A -> Prop`, but it was not.
+    NotASetType,
     /// A type was expected to be a type-combination, but it was not.
     NotATypeCombination,
     /// A type was expected to be a type-variable, but it was not.
     NotATypeVariable,
     /// A type passed to a function as an argument was not well-formed.
     TypeNotWellformed,
+    /// A type was expected to be a quotient type, registered with the
+    /// quotient type package, but it was not.
+    NotAQuotientType,
     /* -- Constant related errors. */
+    /// A constant was expected to be a constructor of a datatype, but it was not.
+    NotAConstructor,
+    /// The constructors of a datatype were marked more than once, or a constant
+    /// was marked as a constructor of more than one datatype.
+    ConstructorsAlreadyMarked,
+    /* -- Substitution related errors. */
+    /// A substitution was expected to be a substitution of types for
+    /// type-variables, but it was not.
+    NotATypeSubstitution,
+    /// A substitution was expected to be a substitution of terms for
+    /// variables, but it was not.
+    NotATermSubstitution,
     /* -- Term related errors. */
     NotAConjunction,
     /// A term passed to a function was expected to be a constant but it was
@@ -94,6 +121,9 @@ pub enum ErrorCode {
     /// A term passed to a function was expected to be an application but it was
     /// not.
     NotAnApplication,
+    /// A term passed to a function was expected to be a conditional but it was
+    /// not.
+    NotAConditional,
     /// A term passed to a function was expected to be an equality but it was
     /// not.
     NotAnEquality,
@@ -106,9 +136,20 @@ pub enum ErrorCode {
     /// A term passed to a function was expected to be a negation but it was
     /// not.
     NotANegation,
+    /// A term passed to a function was expected to be a pair but it was not.
+    NotAPair,
+    /// A term passed to a function was expected to be a set membership but it
+    /// was not.
+    NotAMembership,
+    /// A term passed to a function was expected to be a set comprehension but
+    /// it was not.
+    NotAComprehension,
     /// A term passed to a function as an argument did not have propositional
     /// type.
     NotAProposition,
+    /// A term passed to a function was expected to be a local definition but it
+    /// was not.
+    NotALet,
     /// A term passed to a function was expected to be a variable but it was
     /// not.
     NotAVariable,
@@ -120,6 +161,133 @@ pub enum ErrorCode {
     ShapeMismatch,
     /// A theorem passed to a function as an argument was not well-formed.
     TheoremNotWellformed,
+    /* -- Serialization related errors. */
+    /// A serialized proof object passed to the kernel for import was
+    /// malformed, truncated, or referenced kernel objects that do not exist.
+    MalformedProofObject,
+    /* -- Enumeration related errors. */
+    /// A value passed to a function did not encode a kind of kernel object.
+    NoSuchKind,
+    /* -- Capability related errors. */
+    /// A service was named that is not guarded by any registered challenge.
+    NoSuchChallengeRegistered,
+    /// A theorem presented to discharge a challenge did not prove the challenge
+    /// proposition, without premisses.
+    ChallengeNotDischarged,
+    /// A capability was presented after its expiry policy had lapsed.
+    CapabilityExpired,
+    /// A capability was presented to a service other than the one it was minted
+    /// for.
+    CapabilityServiceMismatch,
+    /// A value passed to a function did not encode an expiry policy.
+    NoSuchExpiryPolicy,
+    /* -- Budget related errors. */
+    /// A service was named for which the host has granted no budget.
+    NoSuchBudgetRegistered,
+    /// A service was used after every copy of its resource assertion had been
+    /// consumed.
+    BudgetExhausted,
+    /* -- Proof-term related errors. */
+    /// A handle was supplied to a function that does not point to any registered
+    /// proof term, or a theorem for which no proof term was recorded.
+    NoSuchProofRegistered,
+    /// Re-applying a recorded inference rule to its recorded arguments did not
+    /// derive the recorded theorem.
+    ProofCheckFailed,
+    /* -- Sequent-related errors. */
+    /// A handle was supplied to a function that does not point to any registered
+    /// sequent.
+    NoSuchSequentRegistered,
+    /// A formula that an inference rule acts upon does not appear on the expected
+    /// side of a sequent.
+    FormulaNotInSequent,
+    /* -- Speculation-related errors. */
+    /// An attempt was made to end a speculative branch when none was in
+    /// progress.
+    NoSpeculationInProgress,
+    /// A handle was supplied that was issued within a speculative branch that
+    /// has since been discarded, and so no longer references any kernel
+    /// object.
+    StaleHandle,
+    /* -- Host-call argument errors. */
+    /// A pointer passed to a host call was null, or pointed outside of the memory
+    /// that the host call reads or writes.
+    InvalidPointer,
+    /// A length passed to a host call, or read by it from memory, described a
+    /// buffer extending outside of memory, or was zero where a non-empty buffer
+    /// is required.
+    InvalidLength,
+    /// A string passed to a host call was not valid UTF-8.
+    InvalidString,
+    /// An integer passed to a host call, such as a handle or a length, could
+    /// not be represented on the host.
+    ArgumentOutOfRange,
+    /* -- Metadata-related errors. */
+    /// A handle was supplied that does not point-to a registered kernel object
+    /// of any kind.
+    NoSuchObjectRegistered,
+    /// No metadata is attached to a kernel object under the key supplied.
+    NoSuchMetadataKey,
+    /* -- Linear arithmetic-related errors. */
+    /// A goal was supplied to the linear arithmetic decision procedure that is
+    /// not in the fragment of arithmetic that it decides.
+    NotInLinearArithFragment,
+    /// The linear arithmetic decision procedure failed to prove a goal, either
+    /// because the goal does not hold of every natural number, or because
+    /// deciding it exceeded the procedure's limits.
+    LinearArithGoalNotProved,
+    /* -- Introspection-related errors. */
+    /// The guest asked to inspect a fact about its own machine state that the
+    /// host's introspection policy does not reveal, or that does not exist.
+    IntrospectionRefused,
+    /* -- Rule policy-related errors. */
+    /// The guest applied an inference rule that the host's rule policy does not
+    /// permit in this session.
+    PermissionDenied,
+    /* -- Retraction-related errors. */
+    /// The theorem has been retracted, along with the axiom or definition that
+    /// it depends upon, and may no longer be used.
+    TheoremRetracted,
+    /* -- Position-related errors. */
+    /// A position did not address a subterm of a term: some index along it was
+    /// not that of a child of the subterm reached so far.
+    NoSuchPosition,
+    /* -- Scratch store-related errors. */
+    /// Nothing is stored in the scratch store under the key supplied.
+    NoSuchScratchKey,
+    /* -- Error detail-related errors. */
+    /// No host call has failed in this session, since it began, so there is no
+    /// failure to report.
+    NoFailureRecorded,
+    /* -- Reference counting-related errors. */
+    /// An attempt was made to free a kernel object that is still in use: the
+    /// guest still holds a reference to it, another registered kernel object
+    /// refers to it, or it is of a kind that is never freed.
+    ObjectInUse,
+    /// An attempt was made to release a reference to a kernel object that the
+    /// guest holds no reference to.
+    ObjectNotRetained,
+    /* -- Type definition-related errors. */
+    /// A type-former was expected to be that of a type defined with the kernel's
+    /// type-definition principle, but is not.
+    NotADefinedType,
+    /* -- Premiss-related errors. */
+    /// An index did not address a premiss of a theorem, as it was not less than
+    /// the number of premisses.
+    NoSuchPremiss,
+    /* -- Symbol table-related errors. */
+    /// A name was supplied that is already registered in the kernel's symbol
+    /// table.
+    NameAlreadyRegistered,
+    /// A name was supplied that is not registered in the kernel's symbol table.
+    NoSuchNameRegistered,
+    /* -- Batch-related errors. */
+    /// A command buffer passed to `Kernel.BatchExecute` was malformed, e.g. as
+    /// it used an unknown opcode, or referred to a result not yet computed.
+    MalformedBatch,
+    /* -- Hypothesis-related errors. */
+    /// No hypothesis in a set of hypotheses matched a pattern.
+    NoMatchingHypothesis,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -170,6 +338,100 @@ impl Display for ErrorCode {
             ErrorCode::TheoremNotWellformed => {
                 write!(f, "TheoremNotWellformed")
             }
+            ErrorCode::MalformedProofObject => {
+                write!(f, "MalformedProofObject")
+            }
+            ErrorCode::NoSuchKind => write!(f, "NoSuchKind"),
+            ErrorCode::NoSuchHypothesesRegistered => {
+                write!(f, "NoSuchHypothesesRegistered")
+            }
+            ErrorCode::NotAConditional => write!(f, "NotAConditional"),
+            ErrorCode::NotALet => write!(f, "NotALet"),
+            ErrorCode::NotAProductType => write!(f, "NotAProductType"),
+            ErrorCode::NotAPair => write!(f, "NotAPair"),
+            ErrorCode::NotASetType => write!(f, "NotASetType"),
+            ErrorCode::NotAMembership => write!(f, "NotAMembership"),
+            ErrorCode::NotAComprehension => write!(f, "NotAComprehension"),
+            ErrorCode::NoSuchCapabilityRegistered => {
+                write!(f, "NoSuchCapabilityRegistered")
+            }
+            ErrorCode::NoSuchChallengeRegistered => {
+                write!(f, "NoSuchChallengeRegistered")
+            }
+            ErrorCode::ChallengeNotDischarged => {
+                write!(f, "ChallengeNotDischarged")
+            }
+            ErrorCode::CapabilityExpired => write!(f, "CapabilityExpired"),
+            ErrorCode::CapabilityServiceMismatch => {
+                write!(f, "CapabilityServiceMismatch")
+            }
+            ErrorCode::NoSuchExpiryPolicy => write!(f, "NoSuchExpiryPolicy"),
+            ErrorCode::NoSuchProofRegistered => {
+                write!(f, "NoSuchProofRegistered")
+            }
+            ErrorCode::ProofCheckFailed => write!(f, "ProofCheckFailed"),
+            ErrorCode::NoSuchSequentRegistered => {
+                write!(f, "NoSuchSequentRegistered")
+            }
+            ErrorCode::FormulaNotInSequent => write!(f, "FormulaNotInSequent"),
+            ErrorCode::NoSpeculationInProgress => {
+                write!(f, "NoSpeculationInProgress")
+            }
+            ErrorCode::InvalidPointer => write!(f, "InvalidPointer"),
+            ErrorCode::InvalidLength => write!(f, "InvalidLength"),
+            ErrorCode::InvalidString => write!(f, "InvalidString"),
+            ErrorCode::NotAConstructor => write!(f, "NotAConstructor"),
+            ErrorCode::ConstructorsAlreadyMarked => {
+                write!(f, "ConstructorsAlreadyMarked")
+            }
+            ErrorCode::NotAQuotientType => write!(f, "NotAQuotientType"),
+            ErrorCode::StaleHandle => write!(f, "StaleHandle"),
+            ErrorCode::NoSuchSubstitutionRegistered => {
+                write!(f, "NoSuchSubstitutionRegistered")
+            }
+            ErrorCode::NotATypeSubstitution => {
+                write!(f, "NotATypeSubstitution")
+            }
+            ErrorCode::NotATermSubstitution => {
+                write!(f, "NotATermSubstitution")
+            }
+            ErrorCode::NoSuchBudgetRegistered => {
+                write!(f, "NoSuchBudgetRegistered")
+            }
+            ErrorCode::BudgetExhausted => write!(f, "BudgetExhausted"),
+            ErrorCode::ArgumentOutOfRange => write!(f, "ArgumentOutOfRange"),
+            ErrorCode::NoSuchObjectRegistered => {
+                write!(f, "NoSuchObjectRegistered")
+            }
+            ErrorCode::NoSuchMetadataKey => write!(f, "NoSuchMetadataKey"),
+            ErrorCode::NotInLinearArithFragment => {
+                write!(f, "NotInLinearArithFragment")
+            }
+            ErrorCode::LinearArithGoalNotProved => {
+                write!(f, "LinearArithGoalNotProved")
+            }
+            ErrorCode::IntrospectionRefused => {
+                write!(f, "IntrospectionRefused")
+            }
+            ErrorCode::PermissionDenied => write!(f, "PermissionDenied"),
+            ErrorCode::TheoremRetracted => write!(f, "TheoremRetracted"),
+            ErrorCode::NoSuchPosition => write!(f, "NoSuchPosition"),
+            ErrorCode::NoSuchScratchKey => write!(f, "NoSuchScratchKey"),
+            ErrorCode::NoFailureRecorded => write!(f, "NoFailureRecorded"),
+            ErrorCode::ObjectInUse => write!(f, "ObjectInUse"),
+            ErrorCode::ObjectNotRetained => write!(f, "ObjectNotRetained"),
+            ErrorCode::NotADefinedType => write!(f, "NotADefinedType"),
+            ErrorCode::NoSuchPremiss => write!(f, "NoSuchPremiss"),
+            ErrorCode::NameAlreadyRegistered => {
+                write!(f, "NameAlreadyRegistered")
+            }
+            ErrorCode::NoSuchNameRegistered => {
+                write!(f, "NoSuchNameRegistered")
+            }
+            ErrorCode::MalformedBatch => write!(f, "MalformedBatch"),
+            ErrorCode::NoMatchingHypothesis => {
+                write!(f, "NoMatchingHypothesis")
+            }
         }
     }
 }
@@ -215,6 +477,58 @@ impl Into<i32> for ErrorCode {
             ErrorCode::TermNotWellformed => 25,
             ErrorCode::ShapeMismatch => 26,
             ErrorCode::TheoremNotWellformed => 27,
+            ErrorCode::MalformedProofObject => 28,
+            ErrorCode::NoSuchKind => 29,
+            ErrorCode::NoSuchHypothesesRegistered => 30,
+            ErrorCode::NotAConditional => 31,
+            ErrorCode::NotALet => 32,
+            ErrorCode::NotAProductType => 33,
+            ErrorCode::NotAPair => 34,
+            ErrorCode::NotASetType => 35,
+            ErrorCode::NotAMembership => 36,
+            ErrorCode::NotAComprehension => 37,
+            ErrorCode::NoSuchCapabilityRegistered => 38,
+            ErrorCode::NoSuchChallengeRegistered => 39,
+            ErrorCode::ChallengeNotDischarged => 40,
+            ErrorCode::CapabilityExpired => 41,
+            ErrorCode::CapabilityServiceMismatch => 42,
+            ErrorCode::NoSuchExpiryPolicy => 43,
+            ErrorCode::NoSuchProofRegistered => 44,
+            ErrorCode::ProofCheckFailed => 45,
+            ErrorCode::NoSuchSequentRegistered => 46,
+            ErrorCode::FormulaNotInSequent => 47,
+            ErrorCode::NoSpeculationInProgress => 48,
+            ErrorCode::InvalidPointer => 49,
+            ErrorCode::InvalidLength => 50,
+            ErrorCode::InvalidString => 51,
+            ErrorCode::NotAConstructor => 52,
+            ErrorCode::ConstructorsAlreadyMarked => 53,
+            ErrorCode::NotAQuotientType => 54,
+            ErrorCode::StaleHandle => 55,
+            ErrorCode::NoSuchSubstitutionRegistered => 56,
+            ErrorCode::NotATypeSubstitution => 57,
+            ErrorCode::NotATermSubstitution => 58,
+            ErrorCode::NoSuchBudgetRegistered => 59,
+            ErrorCode::BudgetExhausted => 60,
+            ErrorCode::ArgumentOutOfRange => 61,
+            ErrorCode::NoSuchObjectRegistered => 62,
+            ErrorCode::NoSuchMetadataKey => 63,
+            ErrorCode::NotInLinearArithFragment => 64,
+            ErrorCode::LinearArithGoalNotProved => 65,
+            ErrorCode::IntrospectionRefused => 66,
+            ErrorCode::PermissionDenied => 67,
+            ErrorCode::TheoremRetracted => 68,
+            ErrorCode::NoSuchPosition => 69,
+            ErrorCode::NoSuchScratchKey => 70,
+            ErrorCode::NoFailureRecorded => 71,
+            ErrorCode::ObjectInUse => 72,
+            ErrorCode::ObjectNotRetained => 73,
+            ErrorCode::NotADefinedType => 74,
+            ErrorCode::NoSuchPremiss => 75,
+            ErrorCode::NameAlreadyRegistered => 76,
+            ErrorCode::NoSuchNameRegistered => 77,
+            ErrorCode::MalformedBatch => 78,
+            ErrorCode::NoMatchingHypothesis => 79,
         }
     }
 }
@@ -252,6 +566,58 @@ impl TryFrom<i32> for ErrorCode {
             25 => Ok(ErrorCode::TermNotWellformed),
             26 => Ok(ErrorCode::ShapeMismatch),
             27 => Ok(ErrorCode::TheoremNotWellformed),
+            28 => Ok(ErrorCode::MalformedProofObject),
+            29 => Ok(ErrorCode::NoSuchKind),
+            30 => Ok(ErrorCode::NoSuchHypothesesRegistered),
+            31 => Ok(ErrorCode::NotAConditional),
+            32 => Ok(ErrorCode::NotALet),
+            33 => Ok(ErrorCode::NotAProductType),
+            34 => Ok(ErrorCode::NotAPair),
+            35 => Ok(ErrorCode::NotASetType),
+            36 => Ok(ErrorCode::NotAMembership),
+            37 => Ok(ErrorCode::NotAComprehension),
+            38 => Ok(ErrorCode::NoSuchCapabilityRegistered),
+            39 => Ok(ErrorCode::NoSuchChallengeRegistered),
+            40 => Ok(ErrorCode::ChallengeNotDischarged),
+            41 => Ok(ErrorCode::CapabilityExpired),
+            42 => Ok(ErrorCode::CapabilityServiceMismatch),
+            43 => Ok(ErrorCode::NoSuchExpiryPolicy),
+            44 => Ok(ErrorCode::NoSuchProofRegistered),
+            45 => Ok(ErrorCode::ProofCheckFailed),
+            46 => Ok(ErrorCode::NoSuchSequentRegistered),
+            47 => Ok(ErrorCode::FormulaNotInSequent),
+            48 => Ok(ErrorCode::NoSpeculationInProgress),
+            49 => Ok(ErrorCode::InvalidPointer),
+            50 => Ok(ErrorCode::InvalidLength),
+            51 => Ok(ErrorCode::InvalidString),
+            52 => Ok(ErrorCode::NotAConstructor),
+            53 => Ok(ErrorCode::ConstructorsAlreadyMarked),
+            54 => Ok(ErrorCode::NotAQuotientType),
+            55 => Ok(ErrorCode::StaleHandle),
+            56 => Ok(ErrorCode::NoSuchSubstitutionRegistered),
+            57 => Ok(ErrorCode::NotATypeSubstitution),
+            58 => Ok(ErrorCode::NotATermSubstitution),
+            59 => Ok(ErrorCode::NoSuchBudgetRegistered),
+            60 => Ok(ErrorCode::BudgetExhausted),
+            61 => Ok(ErrorCode::ArgumentOutOfRange),
+            62 => Ok(ErrorCode::NoSuchObjectRegistered),
+            63 => Ok(ErrorCode::NoSuchMetadataKey),
+            64 => Ok(ErrorCode::NotInLinearArithFragment),
+            65 => Ok(ErrorCode::LinearArithGoalNotProved),
+            66 => Ok(ErrorCode::IntrospectionRefused),
+            67 => Ok(ErrorCode::PermissionDenied),
+            68 => Ok(ErrorCode::TheoremRetracted),
+            69 => Ok(ErrorCode::NoSuchPosition),
+            70 => Ok(ErrorCode::NoSuchScratchKey),
+            71 => Ok(ErrorCode::NoFailureRecorded),
+            72 => Ok(ErrorCode::ObjectInUse),
+            73 => Ok(ErrorCode::ObjectNotRetained),
+            74 => Ok(ErrorCode::NotADefinedType),
+            75 => Ok(ErrorCode::NoSuchPremiss),
+            76 => Ok(ErrorCode::NameAlreadyRegistered),
+            77 => Ok(ErrorCode::NoSuchNameRegistered),
+            78 => Ok(ErrorCode::MalformedBatch),
+            79 => Ok(ErrorCode::NoMatchingHypothesis),
             _otherwise => Err(()),
         }
     }
@@ -271,7 +637,7 @@ mod test {
     /// started.
     #[test]
     pub fn errorcode_test0() {
-        for i in 0..27 {
+        for i in 0..ERRORCODE_ENCODING_UPPER_BOUND as i32 {
             assert_eq!(ErrorCode::try_from(i).unwrap().try_into(), Ok(i));
         }
     }
@@ -543,4 +909,472 @@ mod test {
         let e: ErrorCode = ErrorCode::try_from(i).unwrap();
         assert_eq!(e, ErrorCode::TheoremNotWellformed);
     }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test32() {
+        let i: i32 = ErrorCode::into(ErrorCode::MalformedProofObject);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::MalformedProofObject);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test33() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchKind);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchKind);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test34() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchHypothesesRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchHypothesesRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test35() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAConditional);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAConditional);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test36() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotALet);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotALet);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test37() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAProductType);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAProductType);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test38() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAPair);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAPair);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test39() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotASetType);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotASetType);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test40() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAMembership);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAMembership);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test41() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAComprehension);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAComprehension);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test42() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchCapabilityRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchCapabilityRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test43() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchChallengeRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchChallengeRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test44() {
+        let i: i32 = ErrorCode::into(ErrorCode::ChallengeNotDischarged);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ChallengeNotDischarged);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test45() {
+        let i: i32 = ErrorCode::into(ErrorCode::CapabilityExpired);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::CapabilityExpired);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test46() {
+        let i: i32 = ErrorCode::into(ErrorCode::CapabilityServiceMismatch);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::CapabilityServiceMismatch);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test47() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchExpiryPolicy);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchExpiryPolicy);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test48() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchProofRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchProofRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test49() {
+        let i: i32 = ErrorCode::into(ErrorCode::ProofCheckFailed);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ProofCheckFailed);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test50() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchSequentRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchSequentRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test51() {
+        let i: i32 = ErrorCode::into(ErrorCode::FormulaNotInSequent);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::FormulaNotInSequent);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test52() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSpeculationInProgress);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSpeculationInProgress);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test53() {
+        let i: i32 = ErrorCode::into(ErrorCode::InvalidPointer);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::InvalidPointer);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test54() {
+        let i: i32 = ErrorCode::into(ErrorCode::InvalidLength);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::InvalidLength);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test55() {
+        let i: i32 = ErrorCode::into(ErrorCode::InvalidString);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::InvalidString);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test56() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAConstructor);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAConstructor);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test57() {
+        let i: i32 = ErrorCode::into(ErrorCode::ConstructorsAlreadyMarked);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ConstructorsAlreadyMarked);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test58() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotAQuotientType);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotAQuotientType);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test59() {
+        let i: i32 = ErrorCode::into(ErrorCode::StaleHandle);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::StaleHandle);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test60() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchSubstitutionRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchSubstitutionRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test61() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotATypeSubstitution);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotATypeSubstitution);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test62() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotATermSubstitution);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotATermSubstitution);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test63() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchBudgetRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchBudgetRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test64() {
+        let i: i32 = ErrorCode::into(ErrorCode::BudgetExhausted);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::BudgetExhausted);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test65() {
+        let i: i32 = ErrorCode::into(ErrorCode::ArgumentOutOfRange);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ArgumentOutOfRange);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test66() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchObjectRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchObjectRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test67() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchMetadataKey);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchMetadataKey);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test68() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotInLinearArithFragment);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotInLinearArithFragment);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test69() {
+        let i: i32 = ErrorCode::into(ErrorCode::LinearArithGoalNotProved);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::LinearArithGoalNotProved);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test70() {
+        let i: i32 = ErrorCode::into(ErrorCode::IntrospectionRefused);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::IntrospectionRefused);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test71() {
+        let i: i32 = ErrorCode::into(ErrorCode::PermissionDenied);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::PermissionDenied);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test72() {
+        let i: i32 = ErrorCode::into(ErrorCode::TheoremRetracted);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::TheoremRetracted);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test73() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchPosition);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchPosition);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test74() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchScratchKey);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchScratchKey);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test75() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoFailureRecorded);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoFailureRecorded);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test76() {
+        let i: i32 = ErrorCode::into(ErrorCode::ObjectInUse);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ObjectInUse);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test77() {
+        let i: i32 = ErrorCode::into(ErrorCode::ObjectNotRetained);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::ObjectNotRetained);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test78() {
+        let i: i32 = ErrorCode::into(ErrorCode::NotADefinedType);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NotADefinedType);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test79() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchPremiss);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchPremiss);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test80() {
+        let i: i32 = ErrorCode::into(ErrorCode::NameAlreadyRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NameAlreadyRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test81() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoSuchNameRegistered);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoSuchNameRegistered);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test82() {
+        let i: i32 = ErrorCode::into(ErrorCode::MalformedBatch);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::MalformedBatch);
+    }
+
+    /// Pointwise test that conversion to an `i32` and back again gets you back
+    /// to where you started.
+    #[test]
+    pub fn errorcode_test83() {
+        let i: i32 = ErrorCode::into(ErrorCode::NoMatchingHypothesis);
+        let e: ErrorCode = ErrorCode::try_from(i).unwrap();
+        assert_eq!(e, ErrorCode::NoMatchingHypothesis);
+    }
 }
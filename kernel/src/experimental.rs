@@ -0,0 +1,875 @@
+//! # Experimental multi-conclusion sequents
+//!
+//! For research into classical sequent calculi, this module extends the kernel
+//! with *sequents*, `Γ ⊢ Δ`, carrying a set of premisses, `Γ`, and a set of
+//! conclusions, `Δ`, read classically as "if every premiss holds then some
+//! conclusion holds".  Sequents are derived with the rules of the propositional
+//! fragment of a Gentzen-style sequent calculus, in which contraction is
+//! implicit, as both sides of a sequent are sets.
+//!
+//! Sequents are kept strictly apart from theorems: a theorem may be lifted into
+//! a single-conclusion sequent, but no sequent is ever lowered back into a
+//! theorem, so nothing in this module extends the kernel's trusted base.  The
+//! module is only compiled with the `sequents` cargo feature, and guests detect
+//! whether it is available by feature negotiation, with `Feature::Sequents`.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Sequents.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A multi-conclusion sequent, consisting of a set of premisses and a set of
+/// conclusions, both of which are kept sorted and free of duplicates.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Sequent {
+    /// The premisses of the sequent.
+    premisses: Vec<Handle<tags::Term>>,
+    /// The conclusions of the sequent.
+    conclusions: Vec<Handle<tags::Term>>,
+}
+
+impl Sequent {
+    /// Creates a new sequent from its premisses and conclusions.  Duplicate
+    /// premisses, and duplicate conclusions, are removed.
+    pub fn new<T, U>(premisses: T, conclusions: U) -> Self
+    where
+        T: IntoIterator<Item = Handle<tags::Term>>,
+        U: IntoIterator<Item = Handle<tags::Term>>,
+    {
+        let mut premisses: Vec<_> = premisses.into_iter().collect();
+        let mut conclusions: Vec<_> = conclusions.into_iter().collect();
+
+        premisses.sort();
+        premisses.dedup();
+        conclusions.sort();
+        conclusions.dedup();
+
+        Sequent {
+            premisses,
+            conclusions,
+        }
+    }
+
+    /// Returns the premisses of the sequent, in ascending order.
+    #[inline]
+    pub fn premisses(&self) -> &[Handle<tags::Term>] {
+        &self.premisses
+    }
+
+    /// Returns the conclusions of the sequent, in ascending order.
+    #[inline]
+    pub fn conclusions(&self) -> &[Handle<tags::Term>] {
+        &self.conclusions
+    }
+}
+
+/// Returns the elements of `formulae` other than `removed`, failing with
+/// `ErrorCode::FormulaNotInSequent` if `removed` is not among them.
+fn remove_formula(
+    formulae: &[Handle<tags::Term>],
+    removed: &Handle<tags::Term>,
+) -> Result<Vec<Handle<tags::Term>>, ErrorCode> {
+    if formulae.binary_search(removed).is_err() {
+        return Err(ErrorCode::FormulaNotInSequent);
+    }
+
+    Ok(formulae.iter().filter(|f| *f != removed).cloned().collect())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Sequent-related material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Registers `sequent` in the runtime state's sequent-table, returning the
+    /// newly-issued handle pointing-to it.  As for theorems, every formula of
+    /// `sequent` is validated first, as it may have been registered whilst
+    /// lazy validation was enabled.
+    fn admit_sequent(
+        &mut self,
+        sequent: Sequent,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode> {
+        for formula in sequent.premisses.iter().chain(&sequent.conclusions) {
+            self.term_validate(formula)?;
+        }
+
+        let fresh = self.issue_handle();
+        self.sequents.insert(fresh.clone(), sequent);
+
+        Ok(fresh)
+    }
+
+    /// Returns `Ok(sequent)` iff `handle` points-to a registered sequent in
+    /// the runtime state's sequent-table, and otherwise the error to report
+    /// for `handle`.
+    #[inline]
+    fn resolve_sequent_handle<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Sequent, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        self.sequents.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(handle.borrow(), ErrorCode::NoSuchSequentRegistered)
+        })
+    }
+
+    /// Resolves the sequent pointed-to by `handle`, cloning it so that the
+    /// runtime state may be modified whilst it is in use.
+    fn sequent_of<T>(&self, handle: T) -> Result<Sequent, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        self.resolve_sequent_handle(handle).cloned()
+    }
+
+    /// Checks that `handle` points-to a registered term of propositional type.
+    fn check_proposition(
+        &mut self,
+        handle: &Handle<tags::Term>,
+    ) -> Result<(), ErrorCode> {
+        if self.term_type_is_proposition(handle)? {
+            Ok(())
+        } else {
+            Err(ErrorCode::NotAProposition)
+        }
+    }
+
+    /// Returns `true` iff `handle` points-to a registered sequent in the
+    /// runtime state's sequent-table.
+    pub fn sequent_is_registered<T>(&self, handle: T) -> bool
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        info!(
+            "Checking sequent with handle {} is registered.",
+            handle.borrow()
+        );
+
+        self.resolve_sequent_handle(handle).is_ok()
+    }
+
+    /// Returns the premisses of the sequent pointed-to by `handle`, in
+    /// ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `handle` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    pub fn sequent_split_premisses<T>(
+        &self,
+        handle: T,
+    ) -> Result<&[Handle<tags::Term>], ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        info!(
+            "Splitting premisses of sequent with handle {}.",
+            handle.borrow()
+        );
+
+        self.resolve_sequent_handle(handle).map(Sequent::premisses)
+    }
+
+    /// Returns the conclusions of the sequent pointed-to by `handle`, in
+    /// ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `handle` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    pub fn sequent_split_conclusions<T>(
+        &self,
+        handle: T,
+    ) -> Result<&[Handle<tags::Term>], ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        info!(
+            "Splitting conclusions of sequent with handle {}.",
+            handle.borrow()
+        );
+
+        self.resolve_sequent_handle(handle)
+            .map(Sequent::conclusions)
+    }
+
+    /// Derives the axiom `p ⊢ p`, where `p` is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `term` does not have
+    /// propositional type.
+    pub fn sequent_register_axiom<T>(
+        &mut self,
+        term: T,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!("Registering sequent axiom with term handle {}.", term);
+
+        self.check_proposition(&term)?;
+
+        self.admit_sequent(Sequent::new(vec![term.clone()], vec![term]))
+    }
+
+    /// Lifts the theorem `Γ ⊢ φ`, pointed-to by `theorem`, into the
+    /// single-conclusion sequent `Γ ⊢ φ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `theorem` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    pub fn sequent_register_theorem<T>(
+        &mut self,
+        theorem: T,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Registering sequent from theorem with handle {}.",
+            theorem.borrow()
+        );
+
+        let premisses =
+            self.theorem_split_premisses(theorem.borrow())?.to_vec();
+        let conclusion = self.theorem_split_conclusion(theorem)?;
+
+        self.admit_sequent(Sequent::new(premisses, vec![conclusion]))
+    }
+
+    /// Derives `p, Γ ⊢ Δ` from `Γ ⊢ Δ`, pointed-to by `sequent`, where `p` is
+    /// the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `term` does not have
+    /// propositional type.
+    pub fn sequent_register_weaken_left<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering left weakening of sequent with handle {} by term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        self.check_proposition(&term)?;
+
+        let premisses = sequent.premisses.into_iter().chain(Some(term));
+
+        self.admit_sequent(Sequent::new(premisses, sequent.conclusions))
+    }
+
+    /// Derives `Γ ⊢ Δ, p` from `Γ ⊢ Δ`, pointed-to by `sequent`, where `p` is
+    /// the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `term` does not have
+    /// propositional type.
+    pub fn sequent_register_weaken_right<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering right weakening of sequent with handle {} by term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        self.check_proposition(&term)?;
+
+        let conclusions = sequent.conclusions.into_iter().chain(Some(term));
+
+        self.admit_sequent(Sequent::new(sequent.premisses, conclusions))
+    }
+
+    /// Derives `Γ, Γ' ⊢ Δ, Δ'` from `Γ ⊢ Δ, p`, pointed-to by `left`, and
+    /// `p, Γ' ⊢ Δ'`, pointed-to by `right`, where `p` is the term pointed-to by
+    /// `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `left` or `right`
+    /// do not point-to registered sequents in the runtime state's
+    /// sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `term` is not a
+    /// conclusion of `left`, or is not a premiss of `right`.
+    pub fn sequent_register_cut<T, U, V>(
+        &mut self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering cut of sequents with handles {} and {} on term with handle {}.",
+            left.borrow(),
+            right.borrow(),
+            term
+        );
+
+        let left = self.sequent_of(left)?;
+        let right = self.sequent_of(right)?;
+
+        let conclusions = remove_formula(&left.conclusions, &term)?;
+        let premisses = remove_formula(&right.premisses, &term)?;
+
+        self.admit_sequent(Sequent::new(
+            left.premisses.into_iter().chain(premisses),
+            conclusions.into_iter().chain(right.conclusions),
+        ))
+    }
+
+    /// Derives `¬p, Γ ⊢ Δ` from `Γ ⊢ Δ, p`, pointed-to by `sequent`, where `p`
+    /// is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `term` is not a
+    /// conclusion of `sequent`.
+    pub fn sequent_register_negation_left<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering left negation of sequent with handle {} on term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        let conclusions = remove_formula(&sequent.conclusions, &term)?;
+        let negation = self.term_register_negation(term)?;
+
+        let premisses = sequent.premisses.into_iter().chain(Some(negation));
+
+        self.admit_sequent(Sequent::new(premisses, conclusions))
+    }
+
+    /// Derives `Γ ⊢ Δ, ¬p` from `p, Γ ⊢ Δ`, pointed-to by `sequent`, where `p`
+    /// is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `term` is not a
+    /// premiss of `sequent`.
+    pub fn sequent_register_negation_right<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering right negation of sequent with handle {} on term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        let premisses = remove_formula(&sequent.premisses, &term)?;
+        let negation = self.term_register_negation(term)?;
+
+        let conclusions = sequent.conclusions.into_iter().chain(Some(negation));
+
+        self.admit_sequent(Sequent::new(premisses, conclusions))
+    }
+
+    /// Derives `p ∧ q, Γ ⊢ Δ` from `p, q, Γ ⊢ Δ`, pointed-to by `sequent`,
+    /// where `p ∧ q` is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAConjunction)` if `term` is not a
+    /// conjunction.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if either conjunct is not
+    /// a premiss of `sequent`.
+    pub fn sequent_register_conjunction_left<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering left conjunction of sequent with handle {} on term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        let (p, q) = self.term_split_conjunction(&term)?;
+
+        let premisses = remove_formula(&sequent.premisses, p)?;
+        let premisses = remove_formula(&premisses, q)?;
+
+        self.admit_sequent(Sequent::new(
+            premisses.into_iter().chain(Some(term)),
+            sequent.conclusions,
+        ))
+    }
+
+    /// Derives `Γ, Γ' ⊢ Δ, Δ', p ∧ q` from `Γ ⊢ Δ, p`, pointed-to by `left`,
+    /// and `Γ' ⊢ Δ', q`, pointed-to by `right`, where `p ∧ q` is the term
+    /// pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `left` or `right`
+    /// do not point-to registered sequents in the runtime state's
+    /// sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAConjunction)` if `term` is not a
+    /// conjunction.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `p` is not a conclusion
+    /// of `left`, or `q` is not a conclusion of `right`.
+    pub fn sequent_register_conjunction_right<T, U, V>(
+        &mut self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering right conjunction of sequents with handles {} and {} on term with handle {}.",
+            left.borrow(),
+            right.borrow(),
+            term
+        );
+
+        let left = self.sequent_of(left)?;
+        let right = self.sequent_of(right)?;
+        let (p, q) = self.term_split_conjunction(&term)?;
+
+        let left_conclusions = remove_formula(&left.conclusions, p)?;
+        let right_conclusions = remove_formula(&right.conclusions, q)?;
+
+        self.admit_sequent(Sequent::new(
+            left.premisses.into_iter().chain(right.premisses),
+            left_conclusions
+                .into_iter()
+                .chain(right_conclusions)
+                .chain(Some(term)),
+        ))
+    }
+
+    /// Derives `p ∨ q, Γ, Γ' ⊢ Δ, Δ'` from `p, Γ ⊢ Δ`, pointed-to by `left`,
+    /// and `q, Γ' ⊢ Δ'`, pointed-to by `right`, where `p ∨ q` is the term
+    /// pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `left` or `right`
+    /// do not point-to registered sequents in the runtime state's
+    /// sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotADisjunction)` if `term` is not a
+    /// disjunction.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `p` is not a premiss of
+    /// `left`, or `q` is not a premiss of `right`.
+    pub fn sequent_register_disjunction_left<T, U, V>(
+        &mut self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering left disjunction of sequents with handles {} and {} on term with handle {}.",
+            left.borrow(),
+            right.borrow(),
+            term
+        );
+
+        let left = self.sequent_of(left)?;
+        let right = self.sequent_of(right)?;
+        let (p, q) = self.term_split_disjunction(&term)?;
+
+        let left_premisses = remove_formula(&left.premisses, p)?;
+        let right_premisses = remove_formula(&right.premisses, q)?;
+
+        self.admit_sequent(Sequent::new(
+            left_premisses
+                .into_iter()
+                .chain(right_premisses)
+                .chain(Some(term)),
+            left.conclusions.into_iter().chain(right.conclusions),
+        ))
+    }
+
+    /// Derives `Γ ⊢ Δ, p ∨ q` from `Γ ⊢ Δ, p, q`, pointed-to by `sequent`,
+    /// where `p ∨ q` is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotADisjunction)` if `term` is not a
+    /// disjunction.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if either disjunct is not
+    /// a conclusion of `sequent`.
+    pub fn sequent_register_disjunction_right<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering right disjunction of sequent with handle {} on term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        let (p, q) = self.term_split_disjunction(&term)?;
+
+        let conclusions = remove_formula(&sequent.conclusions, p)?;
+        let conclusions = remove_formula(&conclusions, q)?;
+
+        self.admit_sequent(Sequent::new(
+            sequent.premisses,
+            conclusions.into_iter().chain(Some(term)),
+        ))
+    }
+
+    /// Derives `p ⇒ q, Γ, Γ' ⊢ Δ, Δ'` from `Γ ⊢ Δ, p`, pointed-to by `left`,
+    /// and `q, Γ' ⊢ Δ'`, pointed-to by `right`, where `p ⇒ q` is the term
+    /// pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `left` or `right`
+    /// do not point-to registered sequents in the runtime state's
+    /// sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnImplication)` if `term` is not an
+    /// implication.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `p` is not a conclusion
+    /// of `left`, or `q` is not a premiss of `right`.
+    pub fn sequent_register_implication_left<T, U, V>(
+        &mut self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering left implication of sequents with handles {} and {} on term with handle {}.",
+            left.borrow(),
+            right.borrow(),
+            term
+        );
+
+        let left = self.sequent_of(left)?;
+        let right = self.sequent_of(right)?;
+        let (p, q) = self.term_split_implication(&term)?;
+
+        let conclusions = remove_formula(&left.conclusions, p)?;
+        let premisses = remove_formula(&right.premisses, q)?;
+
+        self.admit_sequent(Sequent::new(
+            left.premisses
+                .into_iter()
+                .chain(premisses)
+                .chain(Some(term)),
+            conclusions.into_iter().chain(right.conclusions),
+        ))
+    }
+
+    /// Derives `Γ ⊢ Δ, p ⇒ q` from `p, Γ ⊢ Δ, q`, pointed-to by `sequent`,
+    /// where `p ⇒ q` is the term pointed-to by `term`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSequentRegistered)` if `sequent` does not
+    /// point-to a registered sequent in the runtime state's sequent-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnImplication)` if `term` is not an
+    /// implication.
+    ///
+    /// Returns `Err(ErrorCode::FormulaNotInSequent)` if `p` is not a premiss,
+    /// or `q` is not a conclusion, of `sequent`.
+    pub fn sequent_register_implication_right<T, U>(
+        &mut self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        let term = term.into();
+
+        info!(
+            "Registering right implication of sequent with handle {} on term with handle {}.",
+            sequent.borrow(),
+            term
+        );
+
+        let sequent = self.sequent_of(sequent)?;
+        let (p, q) = self.term_split_implication(&term)?;
+
+        let premisses = remove_formula(&sequent.premisses, p)?;
+        let conclusions = remove_formula(&sequent.conclusions, q)?;
+
+        self.admit_sequent(Sequent::new(
+            premisses,
+            conclusions.into_iter().chain(Some(term)),
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that the law of the excluded middle, `⊢ p ∨ ¬p`, is derivable,
+    /// with multiple conclusions appearing along the way.
+    #[test]
+    pub fn sequent0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let not_p = state.term_register_negation(p.clone()).unwrap();
+        let lem = state
+            .term_register_disjunction(p.clone(), not_p.clone())
+            .unwrap();
+
+        let axiom = state.sequent_register_axiom(p.clone()).unwrap();
+        let both = state
+            .sequent_register_negation_right(&axiom, p.clone())
+            .unwrap();
+
+        let mut conclusions = vec![p, not_p];
+        conclusions.sort();
+
+        assert!(state.sequent_split_premisses(&both).unwrap().is_empty());
+        assert_eq!(
+            state.sequent_split_conclusions(&both).unwrap(),
+            conclusions.as_slice()
+        );
+
+        let result = state
+            .sequent_register_disjunction_right(&both, lem.clone())
+            .unwrap();
+
+        assert!(state.sequent_split_premisses(&result).unwrap().is_empty());
+        assert_eq!(state.sequent_split_conclusions(&result).unwrap(), &[lem]);
+    }
+
+    /// Tests that theorems lift to single-conclusion sequents, and that cut
+    /// checks for the formula being cut.
+    #[test]
+    pub fn sequent1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let truth = state.theorem_register_truth_introduction().unwrap();
+        let lifted = state.sequent_register_theorem(&truth).unwrap();
+
+        assert!(state.sequent_split_premisses(&lifted).unwrap().is_empty());
+        assert_eq!(
+            state.sequent_split_conclusions(&lifted).unwrap(),
+            &[PREALLOCATED_HANDLE_TERM_TRUE]
+        );
+
+        let axiom = state.sequent_register_axiom(p.clone()).unwrap();
+
+        assert_eq!(
+            state.sequent_register_cut(&lifted, &axiom, p.clone()),
+            Err(ErrorCode::FormulaNotInSequent)
+        );
+
+        let weakened = state
+            .sequent_register_weaken_left(&axiom, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let cut = state
+            .sequent_register_cut(
+                &lifted,
+                &weakened,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.sequent_split_premisses(&cut).unwrap(),
+            state.sequent_split_conclusions(&cut).unwrap()
+        );
+        assert_eq!(state.sequent_split_conclusions(&cut).unwrap(), &[p]);
+    }
+
+    /// Tests that sequent functions reject dangling handles.
+    #[test]
+    pub fn sequent2() {
+        let mut state = RuntimeState::new();
+        let dangling: Handle<tags::Sequent> = Handle::from(0);
+
+        assert!(!state.sequent_is_registered(&dangling));
+        assert_eq!(
+            state.sequent_split_premisses(&dangling),
+            Err(ErrorCode::NoSuchSequentRegistered)
+        );
+        assert_eq!(
+            state.sequent_register_weaken_right(
+                &dangling,
+                PREALLOCATED_HANDLE_TERM_TRUE
+            ),
+            Err(ErrorCode::NoSuchSequentRegistered)
+        );
+    }
+}
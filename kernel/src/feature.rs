@@ -0,0 +1,120 @@
+//! # Optional kernel features
+//!
+//! Some kernel functionality is experimental, and is only compiled into the
+//! kernel when the corresponding cargo feature is enabled.  Guests cannot know
+//! how the kernel that they are executing against was built, and so negotiate
+//! the features that they rely upon, asking the kernel whether each is
+//! supported before using it.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Optional features.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The optional features of the kernel, which may be negotiated by guests.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Feature {
+    /// Multi-conclusion sequents, and the rules of the sequent calculus, from
+    /// the `experimental` module.  Compiled in with the `sequents` cargo
+    /// feature.
+    Sequents,
+    /// Linear resource budgets on the use of host services, from the `budget`
+    /// module.  Compiled in with the `budgets` cargo feature.
+    Budgets,
+    /// The linear arithmetic decision procedure, from the `linear_arith`
+    /// module, which enlarges the kernel's trusted base.  Compiled in with the
+    /// `linear-arith` cargo feature.
+    LinearArith,
+}
+
+impl Feature {
+    /// Returns `true` iff the feature was compiled into this kernel.
+    #[inline]
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Feature::Sequents => cfg!(feature = "sequents"),
+            Feature::Budgets => cfg!(feature = "budgets"),
+            Feature::LinearArith => cfg!(feature = "linear-arith"),
+        }
+    }
+}
+
+/// Conversion from a feature into a `u64`, for ABI transport.
+impl From<Feature> for u64 {
+    #[inline]
+    fn from(feature: Feature) -> u64 {
+        match feature {
+            Feature::Sequents => 0,
+            Feature::Budgets => 1,
+            Feature::LinearArith => 2,
+        }
+    }
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into a feature.
+impl TryFrom<u64> for Feature {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Feature::Sequents),
+            1 => Ok(Feature::Budgets),
+            2 => Ok(Feature::LinearArith),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::feature::Feature;
+    use std::convert::TryFrom;
+
+    /// Tests that the encoding of features round-trips, and that unknown
+    /// encodings are rejected.
+    #[test]
+    pub fn feature0() {
+        assert_eq!(
+            Feature::try_from(u64::from(Feature::Sequents)),
+            Ok(Feature::Sequents)
+        );
+        assert_eq!(
+            Feature::try_from(u64::from(Feature::Budgets)),
+            Ok(Feature::Budgets)
+        );
+        assert_eq!(
+            Feature::try_from(u64::from(Feature::LinearArith)),
+            Ok(Feature::LinearArith)
+        );
+        assert_eq!(Feature::try_from(3u64), Err(()));
+        assert_eq!(
+            Feature::Sequents.is_supported(),
+            cfg!(feature = "sequents")
+        );
+        assert_eq!(Feature::Budgets.is_supported(), cfg!(feature = "budgets"));
+        assert_eq!(
+            Feature::LinearArith.is_supported(),
+            cfg!(feature = "linear-arith")
+        );
+    }
+}
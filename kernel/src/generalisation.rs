@@ -0,0 +1,255 @@
+//! # Type generalisation and fresh instantiation
+//!
+//! Elaborators implementing let-polymorphism on top of the kernel treat the
+//! type of a polymorphic definition as a *type scheme*: a type, some of whose
+//! type-variables are generalised, and so may be instantiated differently at
+//! every use of the definition.  HOL has no quantifiers over types, so the
+//! kernel represents a scheme as a type alone, alongside the names of its
+//! generalised type-variables.
+//!
+//! Two operations suffice.  *Generalising* a type, given the names of the
+//! type-variables in use elsewhere, e.g. in the typing context, renames every
+//! other type-variable of the type to the least names not in use, so that two
+//! schemes differing only in the names of their generalised type-variables
+//! yield the same type.  *Instantiating* a type *freshly* renames every one of
+//! its type-variables apart from a set of names in use, and from each other,
+//! so that the instance can be unified with other types without capture.
+//! Either is a sequence of type registrations that prover-space code could
+//! make itself, but doing so costs a host call per type-variable.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type,
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    name::{fresh, Name},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Renaming type-variables.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A renaming of type-variables, pairing the name of each type-variable with
+/// its new name.
+pub type Renaming = Vec<(Name, Name)>;
+
+/// Returns `count` distinct names, none of which appear in `avoid`, in
+/// ascending order.
+fn fresh_names(count: usize, avoid: &[Name]) -> Vec<Name> {
+    let mut names: Vec<Name> = Vec::with_capacity(count);
+
+    for _i in 0..count {
+        let name = fresh(avoid.iter().chain(names.iter()).cloned());
+        names.push(name);
+    }
+
+    names
+}
+
+impl RuntimeState {
+    /// Renames the type-variables of the type pointed-to by `handle`
+    /// simultaneously, as directed by `renaming`, which pairs the name of each
+    /// type-variable with its new name.  Type-variables that `renaming` does
+    /// not mention are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a type in the runtime state's type-table.
+    fn type_rename<T>(
+        &mut self,
+        handle: T,
+        renaming: &[(Name, Name)],
+    ) -> Result<Handle<tags::Type>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        match self.resolve_type_handle(handle)?.clone() {
+            Type::Variable { name } => {
+                let name = renaming
+                    .iter()
+                    .find(|(old, _new)| *old == name)
+                    .map_or(name, |(_old, new)| *new);
+
+                Ok(self.type_register_variable(name))
+            }
+            Type::Combination { former, arguments } => {
+                let mut renamed = Vec::with_capacity(arguments.len());
+
+                for argument in arguments.iter() {
+                    renamed.push(self.type_rename(argument, renaming)?);
+                }
+
+                self.type_register_combination(former, renamed)
+            }
+        }
+    }
+
+    /// Generalises the type pointed-to by `handle` over every type-variable
+    /// whose name does not appear in `avoid`.  Returns `Ok((tau, names))`,
+    /// where `tau` is the type with those type-variables renamed to the least
+    /// names not appearing in `avoid`, assigned in the runtime state's variable
+    /// order, and `names` are their new names, in ascending order.  Under the
+    /// default variable order, two types that differ only in the names of the
+    /// type-variables generalised yield the same `tau`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a type in the runtime state's type-table.
+    pub fn type_generalise<T>(
+        &mut self,
+        handle: T,
+        avoid: &[Name],
+    ) -> Result<(Handle<tags::Type>, Vec<Name>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        let handle = handle.borrow();
+
+        info!("Generalising type {} avoiding {:?}.", handle, avoid);
+
+        let generalised: Vec<Name> = self
+            .type_variables(handle)?
+            .into_iter()
+            .filter(|name| !avoid.contains(name))
+            .cloned()
+            .collect();
+
+        let names = fresh_names(generalised.len(), avoid);
+        let renaming: Renaming =
+            generalised.into_iter().zip(names.iter().cloned()).collect();
+
+        Ok((self.type_rename(handle, &renaming)?, names))
+    }
+
+    /// Renames every type-variable of the type pointed-to by `handle` to a
+    /// fresh name, appearing neither in `avoid` nor in the type itself.
+    /// Returns `Ok((tau, renaming))`, where `tau` is the renamed type, and
+    /// `renaming` pairs the name of each type-variable of the type, in the
+    /// runtime state's variable order, with its fresh name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a type in the runtime state's type-table.
+    pub fn type_instantiate_fresh<T>(
+        &mut self,
+        handle: T,
+        avoid: &[Name],
+    ) -> Result<(Handle<tags::Type>, Renaming), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        let handle = handle.borrow();
+
+        info!(
+            "Freshly instantiating type {} avoiding {:?}.",
+            handle, avoid
+        );
+
+        let variables: Vec<Name> =
+            self.type_variables(handle)?.into_iter().cloned().collect();
+
+        let mut used = avoid.to_vec();
+        used.extend(variables.iter().cloned());
+
+        let renaming: Renaming = variables
+            .iter()
+            .cloned()
+            .zip(fresh_names(variables.len(), &used))
+            .collect();
+
+        Ok((self.type_rename(handle, &renaming)?, renaming))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that generalising renames only the type-variables not in use, to
+    /// the least names available, so that schemes equal up to renaming yield
+    /// the same type.
+    #[test]
+    pub fn type_generalise0() {
+        let mut kernel = RuntimeState::new();
+
+        let a = kernel.type_register_variable(5u64);
+        let b = kernel.type_register_variable(7u64);
+        let c = kernel.type_register_variable(3u64);
+
+        let ab = kernel.type_register_function(a.clone(), b.clone()).unwrap();
+        let tau = kernel.type_register_function(ab, a).unwrap();
+        let cb = kernel.type_register_function(c.clone(), b.clone()).unwrap();
+        let sigma = kernel.type_register_function(cb, c).unwrap();
+
+        let (generalised, names) = kernel.type_generalise(&tau, &[7]).unwrap();
+
+        assert_eq!(names, vec![0]);
+        assert_eq!(
+            kernel.type_generalise(&sigma, &[7]).unwrap().0,
+            generalised
+        );
+
+        let zero = kernel.type_register_variable(0u64);
+        let zb = kernel.type_register_function(zero.clone(), b).unwrap();
+        let expected = kernel.type_register_function(zb, zero).unwrap();
+
+        assert_eq!(generalised, expected);
+        assert_eq!(
+            kernel.type_generalise(PREALLOCATED_HANDLE_TYPE_PROP, &[]),
+            Ok((PREALLOCATED_HANDLE_TYPE_PROP, Vec::new()))
+        );
+    }
+
+    /// Tests that fresh instantiation renames every type-variable apart from
+    /// the names in use and from the type itself.
+    #[test]
+    pub fn type_instantiate_fresh0() {
+        let mut kernel = RuntimeState::new();
+
+        let a = kernel.type_register_variable(1u64);
+        let b = kernel.type_register_variable(2u64);
+        let tau = kernel.type_register_function(a, b).unwrap();
+
+        let (instance, renaming) =
+            kernel.type_instantiate_fresh(&tau, &[0, 3]).unwrap();
+
+        assert_eq!(renaming, vec![(1, 4), (2, 5)]);
+
+        let c = kernel.type_register_variable(4u64);
+        let d = kernel.type_register_variable(5u64);
+
+        assert_eq!(instance, kernel.type_register_function(c, d).unwrap());
+        assert_eq!(
+            kernel.type_instantiate_fresh(Handle::from(usize::MAX), &[]),
+            Err(ErrorCode::NoSuchTypeRegistered)
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! # Kernel identification
+//!
+//! Proof certificates and exported proof objects are only meaningful relative
+//! to the kernel that validated them.  This module collects together the
+//! information needed to identify a kernel exactly: the version of the kernel
+//! crate, the revision of the source tree it was built from, the logic it
+//! implements, and a fingerprint of the bootstrap theory that every runtime
+//! state starts with.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::runtime_state::RuntimeState;
+use std::fmt::{Display, Error as DisplayError, Formatter};
+
+////////////////////////////////////////////////////////////////////////////////
+// Identifying constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The version of the kernel crate.
+pub const KERNEL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The revision of the source tree that the kernel was built from, or
+/// `unknown` if this could not be determined at build time.  Set by the
+/// kernel's build script.
+pub const KERNEL_BUILD_HASH: &str = env!("SUPERVISIONARY_BUILD_HASH");
+
+/// An identifier for the logic implemented by the kernel.  Note that the
+/// kernel provides neither a choice operator nor the law of the excluded
+/// middle as primitives.
+#[cfg(not(feature = "linear-arith"))]
+pub const KERNEL_LOGIC_IDENTIFIER: &str = "HOL-intuitionistic";
+
+/// An identifier for the logic implemented by the kernel.  Note that the
+/// kernel provides neither a choice operator nor the law of the excluded
+/// middle as primitives, and that the linear arithmetic decision procedure
+/// enlarges the kernel's trusted base, so is part of the logic.
+#[cfg(feature = "linear-arith")]
+pub const KERNEL_LOGIC_IDENTIFIER: &str = "HOL-intuitionistic+linear-arith";
+
+////////////////////////////////////////////////////////////////////////////////
+// Kernel identities.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A complete identification of a kernel.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Identity {
+    /// The version of the kernel crate.
+    pub version: String,
+    /// The revision of the source tree that the kernel was built from.
+    pub build_hash: String,
+    /// An identifier for the logic implemented by the kernel.
+    pub logic: String,
+    /// A fingerprint of the kernel's bootstrap theory, i.e. of the kernel
+    /// objects registered in a freshly-created runtime state.
+    pub bootstrap_hash: u64,
+}
+
+impl Identity {
+    /// Returns the identity of this kernel.
+    pub fn new() -> Self {
+        Identity {
+            version: KERNEL_VERSION.to_string(),
+            build_hash: KERNEL_BUILD_HASH.to_string(),
+            logic: KERNEL_LOGIC_IDENTIFIER.to_string(),
+            bootstrap_hash: RuntimeState::new().bootstrap_fingerprint(),
+        }
+    }
+}
+
+impl Default for Identity {
+    #[inline]
+    fn default() -> Self {
+        Identity::new()
+    }
+}
+
+/// Pretty-printing for kernel identities, as a series of `key=value` lines.
+/// This is also the format in which identities are passed to prover-space.
+impl Display for Identity {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        writeln!(f, "version={}", self.version)?;
+        writeln!(f, "build={}", self.build_hash)?;
+        writeln!(f, "logic={}", self.logic)?;
+        writeln!(f, "bootstrap={:016x}", self.bootstrap_hash)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for kernel identification.
+#[cfg(test)]
+mod test {
+    use crate::{
+        handle::PREALLOCATED_HANDLE_TERM_TRUE,
+        identity::{Identity, KERNEL_LOGIC_IDENTIFIER, KERNEL_VERSION},
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that the bootstrap fingerprint is unaffected by kernel objects
+    /// registered after the bootstrap theory.
+    #[test]
+    pub fn identity_test0() {
+        let mut state = RuntimeState::new();
+        let before = state.bootstrap_fingerprint();
+
+        state.type_register_variable(100u64);
+        state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(before, Identity::new().bootstrap_hash);
+        assert_eq!(before, state.bootstrap_fingerprint());
+    }
+
+    /// Tests that identities are rendered as `key=value` lines.
+    #[test]
+    pub fn identity_test1() {
+        let identity = Identity::new();
+        let rendered = identity.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], format!("version={}", KERNEL_VERSION));
+        assert_eq!(lines[2], format!("logic={}", KERNEL_LOGIC_IDENTIFIER));
+        assert!(lines[3].starts_with("bootstrap="));
+    }
+}
@@ -0,0 +1,1098 @@
+//! # Inductive predicates
+//!
+//! A definitional package for inductive predicates, in the style of the
+//! `inductive` command of other HOL-family systems.  Given a list of
+//! introduction rules for a predicate, the package defines the predicate as
+//! the least predicate closed under the rules, using the impredicative
+//! encoding:
+//!
+//! ```text
+//! R = λx. ∀r. ρ₁ ∧ … ∧ ρₖ ⟶ r x
+//! ```
+//!
+//! where each `ρᵢ` is an introduction rule, mentioning the predicate variable
+//! `r`.  From this definition the package derives the introduction rules
+//! themselves, for `R`, an induction rule and a case-analysis rule.
+//!
+//! Nothing in this module extends the kernel's trusted base: the predicate is
+//! introduced with an ordinary definition, and every theorem is derived with
+//! the kernel's inference rules, so is recorded, and replayed, like any other.
+//! Registration is all-or-nothing: if any step fails then every kernel object
+//! registered along the way is discarded.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{
+        tags, Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    name::{fresh, Name},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::slice;
+
+////////////////////////////////////////////////////////////////////////////////
+// Inductive predicates.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An inductive predicate registered with the kernel, along with the theorems
+/// derived for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InductivePredicate {
+    /// The constant naming the predicate.
+    constant: Handle<tags::Term>,
+    /// The definitional theorem of the predicate.
+    definition: Handle<tags::Theorem>,
+    /// The introduction rules of the predicate, in the order the rules were
+    /// supplied.
+    introductions: Vec<Handle<tags::Theorem>>,
+    /// The induction rule of the predicate.
+    induction: Handle<tags::Theorem>,
+    /// The case-analysis rule of the predicate.
+    cases: Handle<tags::Theorem>,
+}
+
+impl InductivePredicate {
+    /// Returns the constant, `R`, naming the predicate.
+    #[inline]
+    pub fn constant(&self) -> &Handle<tags::Term> {
+        &self.constant
+    }
+
+    /// Returns the definitional theorem of the predicate,
+    /// `⊢ R = λx. ∀r. ρ₁ ∧ … ∧ ρₖ ⟶ r x`.
+    #[inline]
+    pub fn definition(&self) -> &Handle<tags::Theorem> {
+        &self.definition
+    }
+
+    /// Returns the introduction rules of the predicate, `⊢ ρᵢ[r ↦ R]`, one for
+    /// each rule supplied at registration, and in the same order.
+    #[inline]
+    pub fn introductions(&self) -> &[Handle<tags::Theorem>] {
+        &self.introductions
+    }
+
+    /// Returns the induction rule of the predicate,
+    /// `⊢ ∀r. ρ₁ ∧ … ∧ ρₖ ⟶ (∀x. R x ⟶ r x)`.
+    #[inline]
+    pub fn induction(&self) -> &Handle<tags::Theorem> {
+        &self.induction
+    }
+
+    /// Returns the case-analysis rule of the predicate, stated as an
+    /// elimination rule, `⊢ ∀x. R x ⟶ (∀P. C₁ ⟶ … ⟶ Cₖ ⟶ P)`, where the case
+    /// `Cᵢ` for the rule `∀xs. H₁ ⟶ … ⟶ Hₘ ⟶ r t` is
+    /// `∀xs. x = t ⟶ H₁[r ↦ R] ⟶ … ⟶ Hₘ[r ↦ R] ⟶ P`.
+    #[inline]
+    pub fn cases(&self) -> &Handle<tags::Theorem> {
+        &self.cases
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Introduction rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A premiss of an introduction rule.
+#[derive(Clone, Debug)]
+enum Premiss {
+    /// A side-condition, in which the predicate variable does not appear.
+    Side(Handle<tags::Term>),
+    /// A recursive premiss, `r s`, with the argument `s`.
+    Recursive(Handle<tags::Term>),
+}
+
+/// An introduction rule, `∀xs. H₁ ⟶ … ⟶ Hₘ ⟶ r t`, split into its parts.
+#[derive(Clone, Debug)]
+struct IntroductionRule {
+    /// The rule itself.
+    rule: Handle<tags::Term>,
+    /// The universally-quantified variables, `xs`, outermost first.
+    binders: Vec<(Name, Handle<tags::Type>)>,
+    /// The premisses, `H₁` to `Hₘ`.
+    premisses: Vec<Premiss>,
+    /// The argument, `t`, of the predicate variable in the conclusion.
+    argument: Handle<tags::Term>,
+}
+
+/// The objects shared by the derivations of the theorems about a predicate,
+/// `R`, being defined.
+#[derive(Clone, Debug)]
+struct Encoding {
+    /// The predicate variable, `r`.
+    r: (Name, Handle<tags::Type>),
+    /// The predicate variable, `r`, as a term.
+    predicate: Handle<tags::Term>,
+    /// The variable, `x`, bound by the definition of `R`.
+    x: (Name, Handle<tags::Type>),
+    /// The variable, `x`, as a term.
+    xvar: Handle<tags::Term>,
+    /// The variable, `P`, concluding the case-analysis rule.
+    p: (Name, Handle<tags::Type>),
+    /// The variable, `P`, as a term.
+    pvar: Handle<tags::Term>,
+    /// The conjunction of the rules, `Φ`.
+    phi: Handle<tags::Term>,
+    /// The constant, `R`.
+    constant: Handle<tags::Term>,
+    /// The definitional theorem, `⊢ R = λx. ∀r. Φ ⟶ r x`.
+    definition: Handle<tags::Theorem>,
+    /// The assumption `{Φ} ⊢ Φ`.
+    assumption: Handle<tags::Theorem>,
+}
+
+impl RuntimeState {
+    /// Returns `true` iff the predicate variable, `r`, appears free in the
+    /// term pointed-to by `handle`.
+    fn inductive_mentions(
+        &self,
+        handle: &Handle<tags::Term>,
+        r: &(Name, Handle<tags::Type>),
+    ) -> Result<bool, ErrorCode> {
+        Ok(self.term_free_variables(handle)?.contains(&(&r.0, &r.1)))
+    }
+
+    /// Splits the rule pointed-to by `handle` into its parts, checking that it
+    /// has the shape `∀xs. H₁ ⟶ … ⟶ Hₘ ⟶ r t`, where the predicate variable,
+    /// `r`, is the only free variable of the rule, and appears in each premiss
+    /// either not at all or only as `r s`, with `r` not free in `s`.
+    fn inductive_split_rule(
+        &mut self,
+        handle: &Handle<tags::Term>,
+        r: &(Name, Handle<tags::Type>),
+        predicate: &Handle<tags::Term>,
+    ) -> Result<IntroductionRule, ErrorCode> {
+        if !self.term_type_is_proposition(handle)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        if self
+            .term_free_variables(handle)?
+            .iter()
+            .any(|(name, tau)| **name != r.0 || **tau != r.1)
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let mut binders = Vec::new();
+        let mut body = handle.clone();
+
+        while let Ok((name, tau, inner)) = self.term_split_forall(&body) {
+            /* NB: binders are stored with canonical names, so a binder can
+             * only be named after a predicate variable which itself has a
+             * canonical name, and could then shadow it, so such binders are
+             * rejected outright.
+             */
+            if *name == r.0 {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+
+            binders.push((*name, tau.clone()));
+            body = inner.clone();
+        }
+
+        let mut premisses = Vec::new();
+
+        while let Ok((premiss, inner)) = self.term_split_implication(&body) {
+            let premiss = premiss.clone();
+            let inner = inner.clone();
+
+            premisses
+                .push(self.inductive_split_premiss(&premiss, r, predicate)?);
+            body = inner;
+        }
+
+        let (left, argument) = self
+            .term_split_application(&body)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+
+        if left != predicate || self.inductive_mentions(argument, r)? {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        Ok(IntroductionRule {
+            rule: handle.clone(),
+            binders,
+            premisses,
+            argument: argument.clone(),
+        })
+    }
+
+    /// Classifies the premiss pointed-to by `handle` as either a side-condition
+    /// or a recursive premiss.
+    fn inductive_split_premiss(
+        &self,
+        handle: &Handle<tags::Term>,
+        r: &(Name, Handle<tags::Type>),
+        predicate: &Handle<tags::Term>,
+    ) -> Result<Premiss, ErrorCode> {
+        if !self.inductive_mentions(handle, r)? {
+            return Ok(Premiss::Side(handle.clone()));
+        }
+
+        match self.term_split_application(handle) {
+            Ok((left, argument))
+                if left == predicate
+                    && !self.inductive_mentions(argument, r)? =>
+            {
+                Ok(Premiss::Recursive(argument.clone()))
+            }
+            _otherwise => Err(ErrorCode::ShapeMismatch),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Derived rules.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Derives `{Φ} ⊢ ρᵢ` from the assumption `{Φ} ⊢ Φ`, pointed-to by
+    /// `handle`, where `Φ = ρ₁ ∧ … ∧ ρₖ` is nested to the right.
+    fn inductive_project(
+        &mut self,
+        handle: &Handle<tags::Theorem>,
+        index: usize,
+        count: usize,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle.clone();
+
+        for _ in 0..index {
+            thm = self.theorem_register_conjunction_right_elimination(thm)?;
+        }
+
+        if index + 1 < count {
+            thm = self.theorem_register_conjunction_left_elimination(thm)?;
+        }
+
+        Ok(thm)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Registering inductive predicates.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Defines a new inductive predicate over the type pointed-to by `tau`,
+    /// the least predicate closed under the introduction rules pointed-to by
+    /// `rules`, and derives its introduction, induction and case-analysis
+    /// rules.  Returns `Ok(predicate)` if this process is successful.
+    ///
+    /// Each rule must have the shape `∀xs. H₁ ⟶ … ⟶ Hₘ ⟶ r t`, where `r` is
+    /// the *predicate variable*, named `name` and of type `τ → Prop`, standing
+    /// for the predicate being defined.  The predicate variable must be the
+    /// only free variable of each rule, and may appear in a premiss, `Hⱼ`,
+    /// only as an application `r s`, with `r` not free in `s`.  Predicates of
+    /// several arguments can be defined over a pair type.
+    ///
+    /// Either every kernel object is registered, or, if an error is returned,
+    /// none are.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if any of `rules` does
+    /// not point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if any of `rules` does not
+    /// point-to a proposition.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if any rule does not have the
+    /// shape described above, has a free variable other than the predicate
+    /// variable, or binds a variable named `name`.  Also returns
+    /// `Err(ErrorCode::ShapeMismatch)` if the rules mention a type-variable
+    /// not appearing in `tau`, as the defined predicate would otherwise be
+    /// unsound.
+    pub fn inductive_register<T, U>(
+        &mut self,
+        name: T,
+        tau: U,
+        rules: Vec<Handle<tags::Term>>,
+    ) -> Result<InductivePredicate, ErrorCode>
+    where
+        T: Into<Name>,
+        U: Into<Handle<tags::Type>>,
+    {
+        let name = name.into();
+        let tau = tau.into();
+
+        info!(
+            "Registering inductive predicate over type {} with {} rules.",
+            tau,
+            rules.len()
+        );
+
+        self.speculate_begin();
+
+        let result = self.inductive_define(name, tau, rules);
+
+        self.speculate_end(result.is_ok())?;
+
+        result
+    }
+
+    /// Performs the work of `inductive_register`, leaving the caller to discard
+    /// the objects registered if this fails.
+    fn inductive_define(
+        &mut self,
+        name: Name,
+        tau: Handle<tags::Type>,
+        rules: Vec<Handle<tags::Term>>,
+    ) -> Result<InductivePredicate, ErrorCode> {
+        self.resolve_type_handle(&tau)?;
+
+        /* 1. Split the rules, and pick names for the bound variables that occur
+         *    in none of them.
+         */
+        let rtau = self.type_register_function(
+            tau.clone(),
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        )?;
+        let r = (name, rtau.clone());
+        let predicate = self.term_register_variable(name, rtau)?;
+
+        let mut split = Vec::new();
+        let mut avoid = vec![name];
+
+        for rule in rules.iter() {
+            split.push(self.inductive_split_rule(rule, &r, &predicate)?);
+            avoid.append(&mut self.term_names(rule)?);
+        }
+
+        let x = (fresh(avoid.iter().cloned()), tau.clone());
+        avoid.push(x.0);
+        let p = (fresh(avoid.iter().cloned()), PREALLOCATED_HANDLE_TYPE_PROP);
+
+        let xvar = self.term_register_variable(x.0, x.1.clone())?;
+        let pvar = self.term_register_variable(p.0, p.1.clone())?;
+
+        /* 2. Define `R = λx. ∀r. Φ ⟶ r x`. */
+        let phi = self.inductive_conjoin(&rules)?;
+        let rx =
+            self.term_register_application(predicate.clone(), xvar.clone())?;
+        let body = self.term_register_implication(phi.clone(), rx)?;
+        let body = self.term_register_forall(r.0, r.1.clone(), body)?;
+        let defn = self.term_register_lambda(x.0, x.1.clone(), body)?;
+
+        {
+            let allowed = self.type_variables(&tau)?;
+
+            if self
+                .term_type_variables(&defn)?
+                .iter()
+                .any(|v| !allowed.contains(v))
+            {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+        }
+
+        let (constant, definition) = self.register_new_definition(defn)?;
+        let assumption = self.theorem_register_assumption(phi.clone())?;
+
+        let encoding = Encoding {
+            r,
+            predicate,
+            x,
+            xvar,
+            p,
+            pvar,
+            phi,
+            constant,
+            definition,
+            assumption,
+        };
+
+        /* 3. Derive the theorems about the predicate. */
+        let mut introductions = Vec::new();
+
+        for (index, rule) in split.iter().enumerate() {
+            introductions.push(self.inductive_introduction(
+                &encoding,
+                rule,
+                index,
+                split.len(),
+            )?);
+        }
+
+        let induction = self.inductive_induction(&encoding)?;
+        let cases = self.inductive_cases(
+            &encoding,
+            &split,
+            &introductions,
+            &induction,
+        )?;
+
+        Ok(InductivePredicate {
+            constant: encoding.constant,
+            definition: encoding.definition,
+            introductions,
+            induction,
+            cases,
+        })
+    }
+
+    /// Returns the conjunction `ρ₁ ∧ … ∧ ρₖ` of `rules`, nested to the right,
+    /// or truth if there are no rules.
+    fn inductive_conjoin(
+        &mut self,
+        rules: &[Handle<tags::Term>],
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        match rules.split_last() {
+            None => Ok(PREALLOCATED_HANDLE_TERM_TRUE),
+            Some((last, rest)) => {
+                let mut phi = last.clone();
+
+                for rule in rest.iter().rev() {
+                    phi = self.term_register_conjunction(rule.clone(), phi)?;
+                }
+
+                Ok(phi)
+            }
+        }
+    }
+
+    /// Derives the introduction rule `⊢ ∀xs. H₁ᴿ ⟶ … ⟶ Hₘᴿ ⟶ R t` for the
+    /// `index`th of `count` rules, `rule`, where `Hᴿ` is `H[r ↦ R]`.
+    fn inductive_introduction(
+        &mut self,
+        encoding: &Encoding,
+        rule: &IntroductionRule,
+        index: usize,
+        count: usize,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm =
+            self.inductive_project(&encoding.assumption, index, count)?;
+        thm = self.derived_specialise(thm, &rule.binders)?;
+
+        /* NB: from `{Φ} ⊢ H₁ ⟶ … ⟶ Hₘ ⟶ r t`, each premiss is discharged by
+         * assumption, with recursive premisses, `r s`, following from `R s`
+         * and `Φ`, by unfolding the definition of `R`.
+         */
+        let mut hypotheses = Vec::new();
+
+        for premiss in rule.premisses.iter() {
+            let (hypothesis, proof) = match premiss {
+                Premiss::Side(h) => {
+                    (h.clone(), self.theorem_register_assumption(h.clone())?)
+                }
+                Premiss::Recursive(s) => {
+                    let rs = self.term_register_application(
+                        encoding.constant.clone(),
+                        s.clone(),
+                    )?;
+                    let proof = self.theorem_register_assumption(rs.clone())?;
+                    let unfold = self.derived_unfold(
+                        &encoding.definition,
+                        slice::from_ref(s),
+                    )?;
+                    let proof = self.derived_equality_mp(&unfold, &proof)?;
+                    let proof = self.theorem_register_forall_elimination(
+                        proof,
+                        encoding.predicate.clone(),
+                    )?;
+                    let proof = self.theorem_register_implication_elimination(
+                        proof,
+                        &encoding.assumption,
+                    )?;
+
+                    (rs, proof)
+                }
+            };
+
+            hypotheses.push(hypothesis);
+            thm = self.theorem_register_implication_elimination(thm, proof)?;
+        }
+
+        thm = self.derived_discharge(thm, slice::from_ref(&encoding.phi))?;
+        thm = self.derived_generalise(thm, slice::from_ref(&encoding.r))?;
+
+        let unfold = self.derived_unfold(
+            &encoding.definition,
+            slice::from_ref(&rule.argument),
+        )?;
+        let fold = self.theorem_register_symmetry(unfold)?;
+        thm = self.derived_equality_mp(&fold, &thm)?;
+        thm = self.derived_discharge(thm, &hypotheses)?;
+
+        self.derived_generalise(thm, &rule.binders)
+    }
+
+    /// Derives the induction rule `⊢ ∀r. Φ ⟶ (∀x. R x ⟶ r x)`.
+    fn inductive_induction(
+        &mut self,
+        encoding: &Encoding,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let rx = self.term_register_application(
+            encoding.constant.clone(),
+            encoding.xvar.clone(),
+        )?;
+
+        let thm = self.theorem_register_assumption(rx.clone())?;
+        let unfold = self.derived_unfold(
+            &encoding.definition,
+            slice::from_ref(&encoding.xvar),
+        )?;
+        let thm = self.derived_equality_mp(&unfold, &thm)?;
+        let thm = self.theorem_register_forall_elimination(
+            thm,
+            encoding.predicate.clone(),
+        )?;
+        let thm = self.theorem_register_implication_elimination(
+            thm,
+            &encoding.assumption,
+        )?;
+        let thm = self.derived_discharge(thm, &[rx])?;
+        let thm = self.derived_generalise(thm, slice::from_ref(&encoding.x))?;
+        let thm =
+            self.derived_discharge(thm, slice::from_ref(&encoding.phi))?;
+
+        self.derived_generalise(thm, slice::from_ref(&encoding.r))
+    }
+
+    /// Derives the case-analysis rule `⊢ ∀x. R x ⟶ K`, where
+    /// `K = ∀P. C₁ ⟶ … ⟶ Cₖ ⟶ P`, by induction with the predicate
+    /// `Q = λx. R x ∧ K`.
+    fn inductive_cases(
+        &mut self,
+        encoding: &Encoding,
+        rules: &[IntroductionRule],
+        introductions: &[Handle<tags::Theorem>],
+        induction: &Handle<tags::Theorem>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        /* 1. State the cases, `Cᵢ = ∀xs. x = t ⟶ H₁ᴿ ⟶ … ⟶ Hₘᴿ ⟶ P`. */
+        let mut cases = Vec::new();
+
+        for rule in rules.iter() {
+            let mut case = encoding.pvar.clone();
+
+            for premiss in rule.premisses.iter().rev() {
+                let premiss = match premiss {
+                    Premiss::Side(h) => h.clone(),
+                    Premiss::Recursive(s) => self.term_register_application(
+                        encoding.constant.clone(),
+                        s.clone(),
+                    )?,
+                };
+
+                case = self.term_register_implication(premiss, case)?;
+            }
+
+            let eq = self.term_register_equality(
+                encoding.xvar.clone(),
+                rule.argument.clone(),
+            )?;
+            case = self.term_register_implication(eq, case)?;
+
+            for (name, tau) in rule.binders.iter().rev() {
+                case = self.term_register_forall(*name, tau.clone(), case)?;
+            }
+
+            cases.push(case);
+        }
+
+        let mut k = encoding.pvar.clone();
+
+        for case in cases.iter().rev() {
+            k = self.term_register_implication(case.clone(), k)?;
+        }
+
+        let rx = self.term_register_application(
+            encoding.constant.clone(),
+            encoding.xvar.clone(),
+        )?;
+        let k =
+            self.term_register_forall(encoding.p.0, encoding.p.1.clone(), k)?;
+        let q = self.term_register_conjunction(rx.clone(), k)?;
+        let q =
+            self.term_register_lambda(encoding.x.0, encoding.x.1.clone(), q)?;
+
+        /* 2. Show that `Q` is closed under the rules, `⊢ Φ[r ↦ Q]`. */
+        let mut closure = Vec::new();
+
+        for (index, rule) in rules.iter().enumerate() {
+            closure.push(self.inductive_closure(
+                encoding,
+                rule,
+                index,
+                &cases,
+                &introductions[index],
+                &q,
+            )?);
+        }
+
+        let closed = match closure.split_last() {
+            None => self.theorem_register_truth_introduction()?,
+            Some((last, rest)) => {
+                let mut thm = last.clone();
+
+                for proof in rest.iter().rev() {
+                    thm = self.theorem_register_conjunction_introduction(
+                        proof, thm,
+                    )?;
+                }
+
+                thm
+            }
+        };
+
+        /* 3. Conclude `R x ⟶ K` from `R x ⟶ Q x`, by induction. */
+        let thm =
+            self.theorem_register_forall_elimination(induction, q.clone())?;
+        let thm = self.theorem_register_implication_elimination(thm, closed)?;
+        let thm = self.derived_specialise(thm, slice::from_ref(&encoding.x))?;
+        let assumption = self.theorem_register_assumption(rx.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption)?;
+        let qx = self.term_register_application(q, encoding.xvar.clone())?;
+        let beta = self.theorem_register_beta(qx)?;
+        let thm = self.derived_equality_mp(&beta, &thm)?;
+        let thm = self.theorem_register_conjunction_right_elimination(thm)?;
+        let thm = self.derived_discharge(thm, &[rx])?;
+
+        self.derived_generalise(thm, slice::from_ref(&encoding.x))
+    }
+
+    /// Derives `⊢ ρᵢ[r ↦ Q]`, showing that the predicate `Q = λx. R x ∧ K`,
+    /// pointed-to by `q`, is closed under the `index`th rule, `rule`.  Here
+    /// `cases` are the cases `C₁` to `Cₖ`, mentioning `x` free, and
+    /// `introduction` is the introduction rule derived for `rule`.
+    fn inductive_closure(
+        &mut self,
+        encoding: &Encoding,
+        rule: &IntroductionRule,
+        index: usize,
+        cases: &[Handle<tags::Term>],
+        introduction: &Handle<tags::Theorem>,
+        q: &Handle<tags::Term>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        /* NB: the premisses are assumed in their `Q` form, `Q s` for recursive
+         * premisses, from which `R s` follows, for use with both the
+         * introduction rule and the `index`th case.
+         */
+        let mut hypotheses = Vec::new();
+        let mut proofs = Vec::new();
+
+        for premiss in rule.premisses.iter() {
+            match premiss {
+                Premiss::Side(h) => {
+                    hypotheses.push(h.clone());
+                    proofs.push(self.theorem_register_assumption(h.clone())?);
+                }
+                Premiss::Recursive(s) => {
+                    let qs =
+                        self.term_register_application(q.clone(), s.clone())?;
+                    let proof = self.theorem_register_assumption(qs.clone())?;
+                    let beta = self.theorem_register_beta(qs.clone())?;
+                    let proof = self.derived_equality_mp(&beta, &proof)?;
+
+                    hypotheses.push(qs);
+                    proofs.push(
+                        self.theorem_register_conjunction_left_elimination(
+                            proof,
+                        )?,
+                    );
+                }
+            }
+        }
+
+        /* `R t`, by the introduction rule. */
+        let mut rt =
+            self.derived_specialise(introduction.clone(), &rule.binders)?;
+
+        for proof in proofs.iter() {
+            rt = self.theorem_register_implication_elimination(rt, proof)?;
+        }
+
+        /* `K[x ↦ t]`, by the `index`th case, instantiated with `t = t`. */
+        let sigma = vec![(encoding.x.clone(), rule.argument.clone())];
+        let mut instances = Vec::new();
+
+        for case in cases.iter() {
+            instances.push(self.substitution(case.clone(), sigma.clone())?);
+        }
+
+        let mut kt =
+            self.theorem_register_assumption(instances[index].clone())?;
+        kt = self.derived_specialise(kt, &rule.binders)?;
+
+        let reflexivity = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                rule.argument.clone(),
+            )?;
+        kt = self.theorem_register_implication_elimination(kt, reflexivity)?;
+
+        for proof in proofs.iter() {
+            kt = self.theorem_register_implication_elimination(kt, proof)?;
+        }
+
+        kt = self.derived_discharge(kt, &instances)?;
+        kt = self.derived_generalise(kt, slice::from_ref(&encoding.p))?;
+
+        /* `Q t`, folding `R t ∧ K[x ↦ t]`. */
+        let both = self.theorem_register_conjunction_introduction(rt, kt)?;
+        let qt =
+            self.term_register_application(q.clone(), rule.argument.clone())?;
+        let beta = self.theorem_register_beta(qt)?;
+        let fold = self.theorem_register_symmetry(beta)?;
+        let thm = self.derived_equality_mp(&fold, &both)?;
+        let thm = self.derived_discharge(thm, &hypotheses)?;
+        let thm = self.derived_generalise(thm, &rule.binders)?;
+
+        /* NB: the result is the rule with `Q` substituted for `r` only up to
+         * ⍺-equivalence, which the kernel identifies, so it matches the
+         * instantiated induction rule without further work.
+         */
+        debug_assert_eq!(
+            self.theorem_split_conclusion(&thm),
+            self.substitution(
+                rule.rule.clone(),
+                vec![(encoding.r.clone(), q.clone())]
+            )
+        );
+
+        Ok(thm)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, Kind, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Checks the proof of the theorem pointed-to by `handle`.
+    fn check(state: &mut RuntimeState, handle: &Handle<tags::Theorem>) {
+        let proof = state.theorem_proof(handle).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+    }
+
+    /// Tests the theorems derived for the propositions reachable from truth by
+    /// negation, and by conjunction with arbitrary propositions.
+    #[test]
+    pub fn inductive0() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let rtau = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let r = state.term_register_variable(0u64, rtau.clone()).unwrap();
+        let p = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let np = state.term_register_negation(p.clone()).unwrap();
+        let pq = state
+            .term_register_conjunction(p.clone(), q.clone())
+            .unwrap();
+
+        /* `r T`. */
+        let base = state
+            .term_register_application(r.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        /* `∀p. r p ⟶ r ¬p`. */
+        let rp = state
+            .term_register_application(r.clone(), p.clone())
+            .unwrap();
+        let rnp = state
+            .term_register_application(r.clone(), np.clone())
+            .unwrap();
+        let step = state.term_register_implication(rp.clone(), rnp).unwrap();
+        let step = state
+            .term_register_forall(1u64, PREALLOCATED_HANDLE_TYPE_PROP, step)
+            .unwrap();
+
+        /* `∀p q. q ⟶ r p ⟶ r (p ∧ q)`. */
+        let rpq = state
+            .term_register_application(r.clone(), pq.clone())
+            .unwrap();
+        let side = state.term_register_implication(rp, rpq).unwrap();
+        let side = state.term_register_implication(q.clone(), side).unwrap();
+        let side = state
+            .term_register_forall(2u64, PREALLOCATED_HANDLE_TYPE_PROP, side)
+            .unwrap();
+        let side = state
+            .term_register_forall(1u64, PREALLOCATED_HANDLE_TYPE_PROP, side)
+            .unwrap();
+
+        let rules = vec![base.clone(), step.clone(), side.clone()];
+        let predicate = state
+            .inductive_register(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                rules.clone(),
+            )
+            .unwrap();
+        let constant = predicate.constant().clone();
+
+        assert_eq!(predicate.introductions().len(), 3);
+
+        for (rule, intro) in rules.iter().zip(predicate.introductions()) {
+            let expected = state
+                .substitution(
+                    rule.clone(),
+                    vec![((0u64, rtau.clone()), constant.clone())],
+                )
+                .unwrap();
+
+            assert_eq!(state.theorem_split_conclusion(intro), Ok(expected));
+            assert_eq!(state.theorem_split_premisses(intro), Ok(&[][..]));
+        }
+
+        /* `∀r. Φ ⟶ (∀x. R x ⟶ r x)`. */
+        let x = state
+            .term_register_variable(3u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let rx = state.term_register_application(r, x.clone()).unwrap();
+        let cx = state
+            .term_register_application(constant.clone(), x.clone())
+            .unwrap();
+        let phi = state.term_register_conjunction(step, side).unwrap();
+        let phi = state.term_register_conjunction(base, phi).unwrap();
+        let expected = state.term_register_implication(cx.clone(), rx).unwrap();
+        let expected = state
+            .term_register_forall(3u64, PREALLOCATED_HANDLE_TYPE_PROP, expected)
+            .unwrap();
+        let expected = state.term_register_implication(phi, expected).unwrap();
+        let expected =
+            state.term_register_forall(0u64, rtau, expected).unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(predicate.induction()),
+            Ok(expected)
+        );
+
+        /* `∀x. R x ⟶ (∀P. (x = T ⟶ P) ⟶ (∀p. x = ¬p ⟶ R p ⟶ P) ⟶
+         *  (∀p q. x = p ∧ q ⟶ q ⟶ R p ⟶ P) ⟶ P)`.
+         */
+        let big_p = state
+            .term_register_variable(4u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let cp = state
+            .term_register_application(constant.clone(), p.clone())
+            .unwrap();
+        let cp_p = state
+            .term_register_implication(cp.clone(), big_p.clone())
+            .unwrap();
+
+        let case0 = state
+            .term_register_equality(x.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let case0 = state
+            .term_register_implication(case0, big_p.clone())
+            .unwrap();
+
+        let case1 = state.term_register_equality(x.clone(), np).unwrap();
+        let case1 = state
+            .term_register_implication(case1, cp_p.clone())
+            .unwrap();
+        let case1 = state
+            .term_register_forall(1u64, PREALLOCATED_HANDLE_TYPE_PROP, case1)
+            .unwrap();
+
+        let case2 = state.term_register_implication(q, cp_p).unwrap();
+        let eq = state.term_register_equality(x, pq).unwrap();
+        let case2 = state.term_register_implication(eq, case2).unwrap();
+        let case2 = state
+            .term_register_forall(2u64, PREALLOCATED_HANDLE_TYPE_PROP, case2)
+            .unwrap();
+        let case2 = state
+            .term_register_forall(1u64, PREALLOCATED_HANDLE_TYPE_PROP, case2)
+            .unwrap();
+
+        let expected = state.term_register_implication(case2, big_p).unwrap();
+        let expected =
+            state.term_register_implication(case1, expected).unwrap();
+        let expected =
+            state.term_register_implication(case0, expected).unwrap();
+        let expected = state
+            .term_register_forall(4u64, PREALLOCATED_HANDLE_TYPE_PROP, expected)
+            .unwrap();
+        let expected = state.term_register_implication(cx, expected).unwrap();
+        let expected = state
+            .term_register_forall(3u64, PREALLOCATED_HANDLE_TYPE_PROP, expected)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(predicate.cases()),
+            Ok(expected)
+        );
+
+        for intro in predicate.introductions().to_vec().iter() {
+            check(&mut state, intro);
+        }
+
+        check(&mut state, &predicate.induction().clone());
+        check(&mut state, &predicate.cases().clone());
+    }
+
+    /// Tests that a definition by the empty set of rules, over a type-variable,
+    /// is accepted, and that the derived theorems are stated correctly.
+    #[test]
+    pub fn inductive1() {
+        let mut state = RuntimeState::new();
+
+        let predicate = state
+            .inductive_register(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, vec![])
+            .unwrap();
+
+        let x = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let p = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let cx = state
+            .term_register_application(predicate.constant().clone(), x)
+            .unwrap();
+        let all_p = state
+            .term_register_forall(2u64, PREALLOCATED_HANDLE_TYPE_PROP, p)
+            .unwrap();
+        let expected = state.term_register_implication(cx, all_p).unwrap();
+        let expected = state
+            .term_register_forall(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+
+        assert!(predicate.introductions().is_empty());
+        assert_eq!(
+            state.theorem_split_conclusion(predicate.cases()),
+            Ok(expected)
+        );
+    }
+
+    /// Tests that malformed rules are rejected, and that nothing is registered
+    /// when they are.
+    #[test]
+    pub fn inductive2() {
+        let mut state = RuntimeState::new();
+
+        let rtau = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let r = state.term_register_variable(0u64, rtau).unwrap();
+        let p = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let rt = state
+            .term_register_application(r.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let rp = state.term_register_application(r, p.clone()).unwrap();
+
+        /* `λp. p`, which is not a proposition. */
+        let lambda = state
+            .term_register_lambda(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        /* `p ⟶ r T`, with `p` free. */
+        let open = state
+            .term_register_implication(p.clone(), rt.clone())
+            .unwrap();
+        /* `∀p. ¬(r p) ⟶ r p`, with a negative occurrence of `r`. */
+        let negative = state.term_register_negation(rp.clone()).unwrap();
+        let negative = state
+            .term_register_implication(negative, rp.clone())
+            .unwrap();
+        let negative = state
+            .term_register_forall(1u64, PREALLOCATED_HANDLE_TYPE_PROP, negative)
+            .unwrap();
+        /* `∀p. r p ⟶ p`, not concluding with `r`. */
+        let conclusion =
+            state.term_register_implication(rp, p.clone()).unwrap();
+        let conclusion = state
+            .term_register_forall(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                conclusion,
+            )
+            .unwrap();
+        /* `∀y:α. y = y ⟶ r T`, mentioning a type-variable absent from `Prop`. */
+        let eq = state.term_register_equality(y.clone(), y).unwrap();
+        let polymorphic =
+            state.term_register_implication(eq, rt.clone()).unwrap();
+        let polymorphic = state
+            .term_register_forall(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                polymorphic,
+            )
+            .unwrap();
+
+        let terms = state.kernel_enumerate(Kind::Term, 0, 1000).0.len();
+        let theorems = state.theorem_handles().len();
+
+        for (rule, error) in [
+            (lambda, ErrorCode::NotAProposition),
+            (open, ErrorCode::ShapeMismatch),
+            (negative, ErrorCode::ShapeMismatch),
+            (conclusion, ErrorCode::ShapeMismatch),
+            (polymorphic, ErrorCode::ShapeMismatch),
+            (Handle::from(0), ErrorCode::NoSuchTermRegistered),
+        ] {
+            assert_eq!(
+                state.inductive_register(
+                    0u64,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    vec![rt.clone(), rule]
+                ),
+                Err(error)
+            );
+        }
+
+        assert_eq!(
+            state.inductive_register(0u64, Handle::from(0), vec![rt]),
+            Err(ErrorCode::NoSuchTypeRegistered)
+        );
+        assert_eq!(state.kernel_enumerate(Kind::Term, 0, 1000).0.len(), terms);
+        assert_eq!(state.theorem_handles().len(), theorems);
+        assert_eq!(state.speculation_depth(), 0);
+    }
+}
@@ -55,3 +55,8 @@ pub const DANGLING_HANDLE_ERROR: &str =
 /// build a kernel primitive.
 pub const PRIMITIVE_CONSTRUCTION_ERROR: &str =
     "Kernel invariant failed: failed to construct a kernel primitive.";
+
+/// Error message produced during a kernel panic due to the compressor failing
+/// to frame a well-formed proof object held in memory.
+pub const PROOF_OBJECT_FRAMING_ERROR: &str =
+    "Kernel invariant failed: failed to frame a proof object.";
@@ -34,10 +34,50 @@
 //! [Arm Research]: http://www.arm.com/research
 
 pub mod _type;
+mod allocation;
+pub mod batch;
+mod bloom;
+#[cfg(feature = "budgets")]
+pub mod budget;
+pub mod capability;
+pub mod deallocation;
+mod derived;
 pub mod error_code;
+#[cfg(feature = "sequents")]
+pub mod experimental;
+pub mod feature;
+pub mod generalisation;
 pub mod handle;
+pub mod identity;
+pub mod inductive;
 pub mod kernel_panic;
+#[cfg(feature = "linear-arith")]
+pub mod linear_arith;
+pub mod metadata;
+pub mod minimise;
 pub mod name;
+pub mod observer;
+pub mod pipeline;
+pub mod position;
+pub mod proof;
+pub mod proof_object;
+pub mod proof_trace;
+pub mod quotient;
+pub mod recursion;
+pub mod reference;
+#[cfg(feature = "retraction")]
+pub mod retraction;
 pub mod runtime_state;
+pub mod scratch;
+pub mod search;
+pub mod serialize;
+mod shared;
+pub mod state_object;
+pub mod substitution;
+pub mod symbol;
 pub mod term;
+pub mod term_view;
 pub mod theorem;
+pub mod trust;
+pub mod type_definition;
+pub mod unfold;
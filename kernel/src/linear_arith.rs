@@ -0,0 +1,1265 @@
+//! # A decision procedure for linear arithmetic
+//!
+//! A decision procedure for linear arithmetic over the natural numbers,
+//! registering theorems for goals that it finds to be true.  Unlike the
+//! derived rules and definitional packages elsewhere in the kernel, the
+//! procedure does not derive its theorems from the kernel's inference rules:
+//! it is a *trusted extension*, and every theorem that it registers is only as
+//! sound as the procedure's implementation, in this module, and the
+//! arithmetic that it performs.  The procedure is therefore only compiled into
+//! the kernel with the experimental `linear-arith` cargo feature, which also
+//! changes the kernel's logic identifier, and guests must negotiate
+//! `Feature::LinearArith` before using it.
+//!
+//! To let consumers of theorems decide whether to accept this enlarged trusted
+//! base, the kernel tracks *taint*: every theorem registered by the procedure
+//! is tainted, as is every theorem derived from a tainted theorem by any other
+//! inference rule, and `RuntimeState::theorem_is_tainted` reports whether a
+//! theorem is tainted.  Tainted theorems have trust level
+//! `TrustLevel::OracleDependent`, and theorems that are not tainted depend
+//! only on the kernel's inference rules and any axioms postulated by the
+//! host, exactly as in a kernel built without the feature.
+//!
+//! # The signature
+//!
+//! The kernel knows nothing of the natural numbers otherwise, so when the
+//! feature is enabled a signature for them is registered in the runtime
+//! state when first requested: a nullary type-former, named `num`, along with
+//! constants for zero, the successor function, addition, and the `≤` and `<`
+//! relations.  Zero and the successor function are marked as the constructors
+//! of `num`.  None of these constants has a definition, and the procedure
+//! interprets them as the standard natural numbers: as the constants are
+//! otherwise uninterpreted, the standard model of HOL extended with these
+//! interpretations is a model of every theorem that the procedure registers,
+//! so the extension is consistent if HOL is.
+//!
+//! # The fragment
+//!
+//! The procedure decides goals of the form `∀x₁ ... xₙ. ɸ`, where every bound
+//! variable has type `num` and `ɸ` is a quantifier-free formula built with
+//! truth, falsity, negation, conjunction, disjunction, implication, and
+//! equality between propositions, from atoms that are equalities, or `≤` and
+//! `<` relations, between terms of type `num`.  These terms are built from
+//! zero, the successor function, and addition, and any other term of type
+//! `num`, such as a variable, is treated as an opaque atom standing for an
+//! arbitrary natural number.  Free variables of the goal are implicitly
+//! universally quantified, as usual.  Goals outside of the fragment are
+//! rejected with `ErrorCode::NotInLinearArithFragment`.
+//!
+//! # The procedure
+//!
+//! A goal holds iff its negation has no solution in the natural numbers.  The
+//! negation is put into negation normal form, with every atom a linear
+//! constraint over the integers, and its disjunctions are split, lazily, into
+//! conjunctions of constraints, each of which, along with the constraint that
+//! every atom is non-negative, is decided with the Omega test of Pugh.  The
+//! Omega test is complete, so the procedure fails with
+//! `ErrorCode::LinearArithGoalNotProved` only if the goal does not hold, or if
+//! deciding it would exceed a fixed bound on the work done, or on the size of
+//! the integers involved.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{
+        tags, Handle, PREALLOCATED_HANDLE_TERM_FALSE,
+        PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    kernel_panic::{DANGLING_HANDLE_ERROR, PRIMITIVE_CONSTRUCTION_ERROR},
+    metadata::TypeFormerMetadata,
+    proof::{ProofArgument, Rule},
+    runtime_state::RuntimeState,
+    term::Term,
+    theorem::Theorem,
+};
+use log::info;
+use std::collections::{BTreeMap, HashMap};
+
+////////////////////////////////////////////////////////////////////////////////
+// Limits.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the type-former of the natural numbers.
+pub const LINEAR_ARITH_TYPE_FORMER_NAME: &str = "num";
+
+/// The documentation attached to the type-former of the natural numbers.
+const LINEAR_ARITH_TYPE_FORMER_DOCUMENTATION: &str =
+    "The natural numbers, as decided by the linear arithmetic procedure.";
+
+/// The maximum number of case splits and Omega test problems that the
+/// procedure examines whilst deciding a single goal.
+const LINEAR_ARITH_STEP_LIMIT: usize = 1 << 16;
+
+////////////////////////////////////////////////////////////////////////////////
+// The signature.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The kernel objects making up the signature of the natural numbers, as
+/// interpreted by the decision procedure.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LinearArithSignature {
+    /// The nullary type-former of the natural numbers.
+    former: Handle<tags::TypeFormer>,
+    /// The type of the natural numbers, `num`.
+    num: Handle<tags::Type>,
+    /// Zero, of type `num`.
+    zero: Handle<tags::Constant>,
+    /// The successor function, of type `num → num`.
+    successor: Handle<tags::Constant>,
+    /// Addition, of type `num → num → num`.
+    addition: Handle<tags::Constant>,
+    /// The `≤` relation, of type `num → num → Prop`.
+    less_equal: Handle<tags::Constant>,
+    /// The `<` relation, of type `num → num → Prop`.
+    less_than: Handle<tags::Constant>,
+}
+
+impl LinearArithSignature {
+    /// Returns the type-former of the natural numbers.
+    #[inline]
+    pub fn former(&self) -> &Handle<tags::TypeFormer> {
+        &self.former
+    }
+
+    /// Returns the type of the natural numbers.
+    #[inline]
+    pub fn num(&self) -> &Handle<tags::Type> {
+        &self.num
+    }
+
+    /// Returns the constant for zero.
+    #[inline]
+    pub fn zero(&self) -> &Handle<tags::Constant> {
+        &self.zero
+    }
+
+    /// Returns the constant for the successor function.
+    #[inline]
+    pub fn successor(&self) -> &Handle<tags::Constant> {
+        &self.successor
+    }
+
+    /// Returns the constant for addition.
+    #[inline]
+    pub fn addition(&self) -> &Handle<tags::Constant> {
+        &self.addition
+    }
+
+    /// Returns the constant for the `≤` relation.
+    #[inline]
+    pub fn less_equal(&self) -> &Handle<tags::Constant> {
+        &self.less_equal
+    }
+
+    /// Returns the constant for the `<` relation.
+    #[inline]
+    pub fn less_than(&self) -> &Handle<tags::Constant> {
+        &self.less_than
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Linear constraints.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A linear expression, `c + a₁x₁ + ... + aₙxₙ`, over the atoms of a goal,
+/// which are identified by their index.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Linear {
+    /// The constant term, `c`.
+    constant: i128,
+    /// The non-zero coefficients, `aᵢ`, indexed by atom.
+    coefficients: BTreeMap<usize, i128>,
+}
+
+impl Linear {
+    /// Returns the expression `self - other + offset`.
+    fn difference(&self, other: &Linear, offset: i128) -> Linear {
+        let mut result = self.clone();
+        result.constant += offset - other.constant;
+
+        for (atom, coefficient) in &other.coefficients {
+            *result.coefficients.entry(*atom).or_insert(0) -= coefficient;
+        }
+
+        result
+            .coefficients
+            .retain(|_atom, coefficient| *coefficient != 0);
+        result
+    }
+
+    /// Returns the expression as a dense vector of `width` entries: the
+    /// constant term, followed by the coefficient of every atom, in order.
+    fn dense(&self, width: usize) -> Vec<i128> {
+        let mut result = vec![0; width];
+        result[0] = self.constant;
+
+        for (atom, coefficient) in &self.coefficients {
+            result[atom + 1] = *coefficient;
+        }
+
+        result
+    }
+}
+
+/// A formula over linear constraints, in negation normal form.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Formula {
+    /// The constraint `e ≥ 0`.
+    NonNegative(Linear),
+    /// The constraint `e = 0`.
+    Zero(Linear),
+    /// The conjunction of the formulae, which is true if there are none.
+    Conjunction(Vec<Formula>),
+    /// The disjunction of the formulae, which is false if there are none.
+    Disjunction(Vec<Formula>),
+}
+
+impl Formula {
+    /// Returns the conjunction of `left` and `right` if `positive`, and their
+    /// disjunction otherwise.
+    fn both(positive: bool, left: Formula, right: Formula) -> Formula {
+        if positive {
+            Formula::Conjunction(vec![left, right])
+        } else {
+            Formula::Disjunction(vec![left, right])
+        }
+    }
+
+    /// Returns truth if `positive`, and falsity otherwise.
+    fn truth(positive: bool) -> Formula {
+        if positive {
+            Formula::Conjunction(Vec::new())
+        } else {
+            Formula::Disjunction(Vec::new())
+        }
+    }
+
+    /// Returns the disjunction of `left` and `right` if `positive`, and their
+    /// conjunction otherwise.
+    fn either(positive: bool, left: Formula, right: Formula) -> Formula {
+        Formula::both(!positive, left, right)
+    }
+}
+
+/// The work remaining before the procedure gives up on a goal.
+struct Budget(usize);
+
+impl Budget {
+    /// Records a step of work, failing if no work remains.
+    fn step(&mut self) -> Result<(), ErrorCode> {
+        if self.0 == 0 {
+            return Err(ErrorCode::LinearArithGoalNotProved);
+        }
+
+        self.0 -= 1;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The Omega test.
+////////////////////////////////////////////////////////////////////////////////
+
+/* NB: constraints are dense vectors, `[c, a₁, ..., aₙ]`, standing for
+ * `c + a₁x₁ + ... + aₙxₙ ≥ 0` or `... = 0`, over integer variables `xᵢ`.  The
+ * arithmetic is checked, and overflow treated as exceeding the procedure's
+ * limits.
+ */
+
+/// The result of normalising a constraint.
+enum Normalised {
+    /// The constraint holds of every assignment, so may be dropped.
+    Trivial,
+    /// The constraint holds of no assignment.
+    Infeasible,
+    /// The normalised constraint.
+    Constraint(Vec<i128>),
+}
+
+/// Returns the greatest common divisor of the coefficients of `constraint`,
+/// which is zero if every coefficient is zero.
+fn coefficient_gcd(constraint: &[i128]) -> i128 {
+    constraint[1..].iter().fold(0, |mut a, b| {
+        let mut b = b.abs();
+
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+
+        a
+    })
+}
+
+/// Normalises the equality `constraint`, dividing through by the greatest
+/// common divisor of its coefficients.
+fn normalise_equality(mut constraint: Vec<i128>) -> Normalised {
+    let g = coefficient_gcd(&constraint);
+
+    if g == 0 {
+        return if constraint[0] == 0 {
+            Normalised::Trivial
+        } else {
+            Normalised::Infeasible
+        };
+    }
+
+    if constraint[0] % g != 0 {
+        return Normalised::Infeasible;
+    }
+
+    constraint.iter_mut().for_each(|a| *a /= g);
+    Normalised::Constraint(constraint)
+}
+
+/// Normalises the inequality `constraint`, dividing through by the greatest
+/// common divisor of its coefficients and tightening its constant term.
+fn normalise_inequality(mut constraint: Vec<i128>) -> Normalised {
+    let g = coefficient_gcd(&constraint);
+
+    if g == 0 {
+        return if constraint[0] >= 0 {
+            Normalised::Trivial
+        } else {
+            Normalised::Infeasible
+        };
+    }
+
+    constraint[0] = constraint[0].div_euclid(g);
+    constraint[1..].iter_mut().for_each(|a| *a /= g);
+    Normalised::Constraint(constraint)
+}
+
+/// Returns `a·left + b·right`, failing if the arithmetic overflows.
+fn combine(
+    a: i128,
+    left: &[i128],
+    b: i128,
+    right: &[i128],
+) -> Result<Vec<i128>, ErrorCode> {
+    left.iter()
+        .zip(right)
+        .map(|(l, r)| {
+            a.checked_mul(*l)
+                .and_then(|l| b.checked_mul(*r).and_then(|r| l.checked_add(r)))
+                .ok_or(ErrorCode::LinearArithGoalNotProved)
+        })
+        .collect()
+}
+
+/// Eliminates the variable `k` from `target`, using the equality `equality`,
+/// in which the coefficient of `k` is `±1`.
+fn substitute(
+    target: &mut Vec<i128>,
+    equality: &[i128],
+    k: usize,
+) -> Result<(), ErrorCode> {
+    if target[k] != 0 {
+        *target = combine(1, target, -target[k] * equality[k], equality)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the symmetric residue of `a` modulo `m`, in the range `[-m/2,
+/// m/2)`.
+fn symmetric_residue(a: i128, m: i128) -> i128 {
+    let r = a.rem_euclid(m);
+
+    if 2 * r >= m {
+        r - m
+    } else {
+        r
+    }
+}
+
+/// Returns `true` iff the equalities `equalities` and inequalities
+/// `inequalities` have a common solution in the integers.
+fn omega(
+    mut equalities: Vec<Vec<i128>>,
+    inequalities: Vec<Vec<i128>>,
+    budget: &mut Budget,
+) -> Result<bool, ErrorCode> {
+    budget.step()?;
+
+    let mut inequalities = inequalities;
+
+    /* 1. Solve the equalities, eliminating a variable with each. */
+    while let Some(equality) = equalities.pop() {
+        let mut equality = match normalise_equality(equality) {
+            Normalised::Trivial => continue,
+            Normalised::Infeasible => return Ok(false),
+            Normalised::Constraint(equality) => equality,
+        };
+
+        if let Some(k) = (1..equality.len()).find(|k| equality[*k].abs() == 1) {
+            for target in equalities.iter_mut().chain(inequalities.iter_mut()) {
+                substitute(target, &equality, k)?;
+            }
+
+            continue;
+        }
+
+        /* NB: no coefficient is a unit, so Pugh's method is used: a fresh
+         * variable, σ, is introduced with an equality in which the variable
+         * with the smallest coefficient has a unit coefficient, and that
+         * variable is eliminated in favour of σ, shrinking the coefficients of
+         * the original equality, which is then solved again.  Normalisation
+         * leaves at least one non-zero coefficient.
+         */
+        let k = match (1..equality.len())
+            .filter(|k| equality[*k] != 0)
+            .min_by_key(|k| equality[*k].abs())
+        {
+            None => continue,
+            Some(k) => k,
+        };
+        let m = equality[k].abs() + 1;
+
+        for target in equalities.iter_mut().chain(inequalities.iter_mut()) {
+            target.push(0);
+        }
+        equality.push(0);
+
+        let mut fresh: Vec<i128> =
+            equality.iter().map(|a| symmetric_residue(*a, m)).collect();
+        let sigma = fresh.len() - 1;
+        fresh[sigma] = -m;
+
+        for target in equalities.iter_mut().chain(inequalities.iter_mut()) {
+            substitute(target, &fresh, k)?;
+        }
+        substitute(&mut equality, &fresh, k)?;
+
+        equalities.push(equality);
+    }
+
+    /* 2. Normalise the inequalities, keeping only the tightest of those that
+     * differ only in their constant terms, and turning opposing pairs that
+     * meet into equalities.
+     */
+    let mut tightest: BTreeMap<Vec<i128>, i128> = BTreeMap::new();
+
+    for inequality in inequalities {
+        match normalise_inequality(inequality) {
+            Normalised::Trivial => {}
+            Normalised::Infeasible => return Ok(false),
+            Normalised::Constraint(inequality) => {
+                let constant = tightest
+                    .entry(inequality[1..].to_vec())
+                    .or_insert(inequality[0]);
+                *constant = (*constant).min(inequality[0]);
+            }
+        }
+    }
+
+    let inequalities: Vec<Vec<i128>> = tightest
+        .iter()
+        .map(|(coefficients, constant)| {
+            let mut inequality = vec![*constant];
+            inequality.extend_from_slice(coefficients);
+            inequality
+        })
+        .collect();
+
+    for inequality in &inequalities {
+        let opposite: Vec<i128> = inequality[1..].iter().map(|a| -a).collect();
+
+        if let Some(constant) = tightest.get(&opposite) {
+            if inequality[0] + constant < 0 {
+                return Ok(false);
+            }
+
+            if inequality[0] + constant == 0 {
+                return omega(
+                    vec![inequality.clone()],
+                    inequalities.clone(),
+                    budget,
+                );
+            }
+        }
+    }
+
+    if inequalities.is_empty() {
+        return Ok(true);
+    }
+
+    /* 3. Choose a variable to eliminate: one that is unbounded in some
+     * direction if possible, as every constraint mentioning it may then be
+     * dropped, and otherwise one whose elimination is exact, and otherwise
+     * any, in each case preferring the fewest new constraints.
+     */
+    let width = inequalities[0].len();
+    let mut choice: Option<(usize, bool, usize)> = None;
+
+    for k in 1..width {
+        let lower: Vec<i128> = inequalities
+            .iter()
+            .map(|i| i[k])
+            .filter(|a| *a > 0)
+            .collect();
+        let upper: Vec<i128> = inequalities
+            .iter()
+            .map(|i| i[k])
+            .filter(|a| *a < 0)
+            .collect();
+
+        if lower.is_empty() && upper.is_empty() {
+            continue;
+        }
+
+        if lower.is_empty() || upper.is_empty() {
+            let remaining =
+                inequalities.into_iter().filter(|i| i[k] == 0).collect();
+
+            return omega(Vec::new(), remaining, budget);
+        }
+
+        let exact =
+            lower.iter().all(|b| *b == 1) || upper.iter().all(|a| *a == -1);
+        let cost = lower.len() * upper.len();
+
+        let better = match choice {
+            None => true,
+            Some((_k, chosen_exact, chosen_cost)) => {
+                (exact, usize::MAX - cost)
+                    > (chosen_exact, usize::MAX - chosen_cost)
+            }
+        };
+
+        if better {
+            choice = Some((k, exact, cost));
+        }
+    }
+
+    let (k, exact, _cost) = match choice {
+        None => return Ok(true),
+        Some(choice) => choice,
+    };
+
+    /* 4. Eliminate the variable, combining every lower bound, `b·x ≥ β`, with
+     * every upper bound, `a·x ≤ α`.  The real shadow, `a·β ≤ b·α`, is exact
+     * if either coefficient is one in every pair.  Otherwise, the integer
+     * solutions lie in the dark shadow, `b·α - a·β ≥ (a - 1)(b - 1)`, or
+     * close enough to a lower bound to be found by enumerating the splinters
+     * `b·x = β + i`.
+     */
+    let (bounds, rest): (Vec<Vec<i128>>, Vec<Vec<i128>>) =
+        inequalities.iter().cloned().partition(|i| i[k] != 0);
+    let (lower, upper): (Vec<Vec<i128>>, Vec<Vec<i128>>) =
+        bounds.into_iter().partition(|i| i[k] > 0);
+
+    let mut real = rest.clone();
+    let mut dark = rest;
+
+    for l in &lower {
+        for u in &upper {
+            let (a, b) = (-u[k], l[k]);
+            let shadow = combine(a, l, b, u)?;
+
+            let mut darkened = shadow.clone();
+            darkened[0] = (a - 1)
+                .checked_mul(b - 1)
+                .and_then(|slack| darkened[0].checked_sub(slack))
+                .ok_or(ErrorCode::LinearArithGoalNotProved)?;
+
+            real.push(shadow);
+            dark.push(darkened);
+        }
+    }
+
+    if exact {
+        return omega(Vec::new(), real, budget);
+    }
+
+    if !omega(Vec::new(), real, budget)? {
+        return Ok(false);
+    }
+
+    if omega(Vec::new(), dark, budget)? {
+        return Ok(true);
+    }
+
+    let largest = upper.iter().map(|u| -u[k]).max().unwrap_or(1);
+
+    for l in &lower {
+        let splinters = largest
+            .checked_mul(l[k])
+            .and_then(|p| p.checked_sub(largest + l[k]))
+            .ok_or(ErrorCode::LinearArithGoalNotProved)?
+            .div_euclid(largest);
+
+        for i in 0..=splinters {
+            let mut splinter = l.clone();
+            splinter[0] -= i;
+
+            if omega(vec![splinter], inequalities.clone(), budget)? {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns `true` iff `pending`, along with the equalities `equalities` and
+/// inequalities `inequalities`, has a solution in the integers, splitting the
+/// disjunctions of `pending` into cases.
+fn satisfiable(
+    width: usize,
+    mut equalities: Vec<Vec<i128>>,
+    mut inequalities: Vec<Vec<i128>>,
+    mut pending: Vec<&Formula>,
+    budget: &mut Budget,
+) -> Result<bool, ErrorCode> {
+    while let Some(formula) = pending.pop() {
+        match formula {
+            Formula::NonNegative(e) => inequalities.push(e.dense(width)),
+            Formula::Zero(e) => equalities.push(e.dense(width)),
+            Formula::Conjunction(conjuncts) => pending.extend(conjuncts),
+            Formula::Disjunction(disjuncts) => {
+                for disjunct in disjuncts {
+                    budget.step()?;
+
+                    let mut case = pending.clone();
+                    case.push(disjunct);
+
+                    if satisfiable(
+                        width,
+                        equalities.clone(),
+                        inequalities.clone(),
+                        case,
+                        budget,
+                    )? {
+                        return Ok(true);
+                    }
+                }
+
+                return Ok(false);
+            }
+        }
+    }
+
+    omega(equalities, inequalities, budget)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The decision procedure.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Registers the signature of the natural numbers.
+    fn linear_arith_bootstrap(&mut self) -> LinearArithSignature {
+        let former = self.type_former_register_with_metadata(
+            0usize,
+            TypeFormerMetadata::new(
+                LINEAR_ARITH_TYPE_FORMER_NAME,
+                LINEAR_ARITH_TYPE_FORMER_DOCUMENTATION,
+            ),
+        );
+
+        let num = self
+            .type_register_combination(
+                former.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let operator = self
+            .type_register_function(num.clone(), num.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let binary_operator = self
+            .type_register_function(num.clone(), operator.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let predicate = self
+            .type_register_function(num.clone(), PREALLOCATED_HANDLE_TYPE_PROP)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let relation = self
+            .type_register_function(num.clone(), predicate)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let mut constant = |tau: &Handle<tags::Type>| {
+            self.constant_register(tau.clone())
+                .expect(PRIMITIVE_CONSTRUCTION_ERROR)
+        };
+
+        let signature = LinearArithSignature {
+            former: former.clone(),
+            num: num.clone(),
+            zero: constant(&num),
+            successor: constant(&operator),
+            addition: constant(&binary_operator),
+            less_equal: constant(&relation),
+            less_than: constant(&relation),
+        };
+
+        self.constant_mark_constructors(
+            former,
+            vec![signature.zero.clone(), signature.successor.clone()],
+        )
+        .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        signature
+    }
+
+    /// Returns the signature of the natural numbers decided by the linear
+    /// arithmetic decision procedure, registering it first if it has not yet
+    /// been registered.
+    pub fn linear_arith_signature(&mut self) -> LinearArithSignature {
+        if let Some(signature) = &self.linear_arith {
+            return signature.clone();
+        }
+
+        let signature = self.linear_arith_bootstrap();
+        self.linear_arith = Some(signature.clone());
+        signature
+    }
+
+    /// Returns the linear expression denoted by the term of type `num`
+    /// pointed-to by `term`, allocating indices in `atoms` to the opaque terms
+    /// that it mentions.
+    fn linear_arith_term(
+        &self,
+        signature: &LinearArithSignature,
+        term: &Handle<tags::Term>,
+        atoms: &mut HashMap<Handle<tags::Term>, usize>,
+    ) -> Linear {
+        let mut result = Linear::default();
+        let mut work = vec![term.clone()];
+
+        /* NB: long numerals are chains of successors, so the term is walked
+         * with an explicit work-list rather than by recursion.
+         */
+        while let Some(term) = work.pop() {
+            let trm = self
+                .resolve_term_handle(&term)
+                .expect(DANGLING_HANDLE_ERROR);
+
+            let constant = |handle: &Handle<tags::Term>| match self
+                .resolve_term_handle(handle)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                Term::Constant { constant, .. } => Some(constant.clone()),
+                _otherwise => None,
+            };
+
+            match trm {
+                Term::Constant { constant, .. }
+                    if constant == &signature.zero =>
+                {
+                    continue
+                }
+                Term::Application { left, right }
+                    if constant(left).as_ref()
+                        == Some(&signature.successor) =>
+                {
+                    result.constant += 1;
+                    work.push(right.clone());
+                    continue;
+                }
+                Term::Application { left, right } => {
+                    if let Term::Application {
+                        left: operator,
+                        right: operand,
+                    } = self
+                        .resolve_term_handle(left)
+                        .expect(DANGLING_HANDLE_ERROR)
+                    {
+                        if constant(operator).as_ref()
+                            == Some(&signature.addition)
+                        {
+                            work.push(operand.clone());
+                            work.push(right.clone());
+                            continue;
+                        }
+                    }
+                }
+                _otherwise => {}
+            }
+
+            let fresh = atoms.len();
+            let atom = *atoms.entry(term).or_insert(fresh);
+
+            *result.coefficients.entry(atom).or_insert(0) += 1;
+        }
+
+        result
+            .coefficients
+            .retain(|_atom, coefficient| *coefficient != 0);
+        result
+    }
+
+    /// Returns the formula asserting the proposition pointed-to by `formula`
+    /// if `positive`, and its negation otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotInLinearArithFragment)` if the proposition
+    /// is not in the fragment decided by the procedure.
+    fn linear_arith_formula(
+        &mut self,
+        signature: &LinearArithSignature,
+        formula: &Handle<tags::Term>,
+        positive: bool,
+        atoms: &mut HashMap<Handle<tags::Term>, usize>,
+    ) -> Result<Formula, ErrorCode> {
+        if formula == &PREALLOCATED_HANDLE_TERM_TRUE {
+            return Ok(Formula::truth(positive));
+        }
+
+        if formula == &PREALLOCATED_HANDLE_TERM_FALSE {
+            return Ok(Formula::truth(!positive));
+        }
+
+        if let Ok(body) = self.term_split_negation(formula) {
+            let body = body.clone();
+
+            return self
+                .linear_arith_formula(signature, &body, !positive, atoms);
+        }
+
+        if let Ok((left, right)) = self.term_split_conjunction(formula) {
+            let (left, right) = (left.clone(), right.clone());
+
+            let left =
+                self.linear_arith_formula(signature, &left, positive, atoms)?;
+            let right =
+                self.linear_arith_formula(signature, &right, positive, atoms)?;
+
+            return Ok(Formula::both(positive, left, right));
+        }
+
+        if let Ok((left, right)) = self.term_split_disjunction(formula) {
+            let (left, right) = (left.clone(), right.clone());
+
+            let left =
+                self.linear_arith_formula(signature, &left, positive, atoms)?;
+            let right =
+                self.linear_arith_formula(signature, &right, positive, atoms)?;
+
+            return Ok(Formula::either(positive, left, right));
+        }
+
+        if let Ok((left, right)) = self.term_split_implication(formula) {
+            let (left, right) = (left.clone(), right.clone());
+
+            let left =
+                self.linear_arith_formula(signature, &left, !positive, atoms)?;
+            let right =
+                self.linear_arith_formula(signature, &right, positive, atoms)?;
+
+            return Ok(Formula::either(positive, left, right));
+        }
+
+        if let Ok((left, right)) = self.term_split_equality(formula) {
+            let (left, right) = (left.clone(), right.clone());
+            let tau = self.term_type_infer(&left)?;
+
+            if tau == PREALLOCATED_HANDLE_TYPE_PROP {
+                /* NB: `ɸ = ψ` is `(ɸ ⟶ ψ) ∧ (ψ ⟶ ɸ)`, and its negation is
+                 * `(ɸ ∧ ¬ψ) ∨ (ψ ∧ ¬ɸ)`.
+                 */
+                let forward = Formula::either(
+                    positive,
+                    self.linear_arith_formula(
+                        signature, &left, !positive, atoms,
+                    )?,
+                    self.linear_arith_formula(
+                        signature, &right, positive, atoms,
+                    )?,
+                );
+                let backward = Formula::either(
+                    positive,
+                    self.linear_arith_formula(
+                        signature, &right, !positive, atoms,
+                    )?,
+                    self.linear_arith_formula(
+                        signature, &left, positive, atoms,
+                    )?,
+                );
+
+                return Ok(Formula::both(positive, forward, backward));
+            }
+
+            if tau != signature.num {
+                return Err(ErrorCode::NotInLinearArithFragment);
+            }
+
+            let left = self.linear_arith_term(signature, &left, atoms);
+            let right = self.linear_arith_term(signature, &right, atoms);
+
+            return if positive {
+                Ok(Formula::Zero(left.difference(&right, 0)))
+            } else {
+                Ok(Formula::Disjunction(vec![
+                    Formula::NonNegative(left.difference(&right, -1)),
+                    Formula::NonNegative(right.difference(&left, -1)),
+                ]))
+            };
+        }
+
+        /* NB: what remains are the relations, `R a b`. */
+        let relation = self
+            .term_split_application(formula)
+            .ok()
+            .and_then(|(head, b)| {
+                self.term_split_application(head)
+                    .ok()
+                    .map(|(head, a)| (head.clone(), a.clone(), b.clone()))
+            })
+            .and_then(|(head, a, b)| {
+                self.term_split_constant(&head)
+                    .ok()
+                    .map(|(constant, _tau)| (constant.clone(), a, b))
+            });
+
+        let (relation, a, b) =
+            relation.ok_or(ErrorCode::NotInLinearArithFragment)?;
+
+        let a = self.linear_arith_term(signature, &a, atoms);
+        let b = self.linear_arith_term(signature, &b, atoms);
+
+        if relation == signature.less_equal {
+            if positive {
+                Ok(Formula::NonNegative(b.difference(&a, 0)))
+            } else {
+                Ok(Formula::NonNegative(a.difference(&b, -1)))
+            }
+        } else if relation == signature.less_than {
+            if positive {
+                Ok(Formula::NonNegative(b.difference(&a, -1)))
+            } else {
+                Ok(Formula::NonNegative(a.difference(&b, 0)))
+            }
+        } else {
+            Err(ErrorCode::NotInLinearArithFragment)
+        }
+    }
+
+    /// Registers a new theorem object, `⊢ ɸ`, in the kernel's theorem-table iff
+    /// `goal` points-to the formula `ɸ` in the kernel's term-table, `ɸ` is in
+    /// the fragment of linear arithmetic decided by the procedure, and `ɸ`
+    /// holds of every natural number.  The new theorem is tainted.  Returns
+    /// `Ok(handle)` if this process is successful, where `handle` is the
+    /// newly-allocated handle pointing-to the new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `goal` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `goal` does not point-to a
+    /// term with propositional type.
+    ///
+    /// Returns `Err(ErrorCode::NotInLinearArithFragment)` if `ɸ` is not in the
+    /// fragment decided by the procedure.
+    ///
+    /// Returns `Err(ErrorCode::LinearArithGoalNotProved)` if `ɸ` does not hold
+    /// of every natural number, or if deciding it exceeds the procedure's
+    /// limits.
+    pub fn theorem_register_linear_arith<T>(
+        &mut self,
+        goal: T,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![ProofArgument::Term(goal.clone().into())];
+
+        let goal = goal.into();
+        info!(
+            "Registering 'linear arithmetic' theorem with handle {}.",
+            goal
+        );
+
+        if !self.term_type_is_proposition(&goal)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        let signature = self.linear_arith_signature();
+
+        let mut body = goal.clone();
+
+        while let Ok((_name, tau, inner)) = self.term_split_forall(&body) {
+            if tau != &signature.num {
+                return Err(ErrorCode::NotInLinearArithFragment);
+            }
+
+            body = inner.clone();
+        }
+
+        let mut atoms = HashMap::new();
+        let negation =
+            self.linear_arith_formula(&signature, &body, false, &mut atoms)?;
+
+        let width = atoms.len() + 1;
+        let natural: Vec<Vec<i128>> = (1..width)
+            .map(|k| {
+                let mut inequality = vec![0; width];
+                inequality[k] = 1;
+                inequality
+            })
+            .collect();
+
+        let mut budget = Budget(LINEAR_ARITH_STEP_LIMIT);
+
+        if satisfiable(
+            width,
+            Vec::new(),
+            natural,
+            vec![&negation],
+            &mut budget,
+        )? {
+            return Err(ErrorCode::LinearArithGoalNotProved);
+        }
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::LinearArith,
+            arguments,
+            Theorem::new(premisses, goal),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+        runtime_state::RuntimeState,
+    };
+
+    /// Registers the numeral `n`, built from zero and the successor function.
+    fn numeral(state: &mut RuntimeState, n: usize) -> Handle<tags::Term> {
+        let signature = state.linear_arith_signature();
+        let no_substitution: Vec<(u64, Handle<tags::Type>)> = Vec::new();
+
+        let mut result = state
+            .term_register_constant(
+                signature.zero().clone(),
+                no_substitution.clone(),
+            )
+            .unwrap();
+        let successor = state
+            .term_register_constant(
+                signature.successor().clone(),
+                no_substitution,
+            )
+            .unwrap();
+
+        for _i in 0..n {
+            result = state
+                .term_register_application(successor.clone(), result)
+                .unwrap();
+        }
+
+        result
+    }
+
+    /// Registers the binary application of `constant` to `a` and `b`.
+    fn binary(
+        state: &mut RuntimeState,
+        constant: &Handle<tags::Constant>,
+        a: Handle<tags::Term>,
+        b: Handle<tags::Term>,
+    ) -> Handle<tags::Term> {
+        let no_substitution: Vec<(u64, Handle<tags::Type>)> = Vec::new();
+
+        let constant = state
+            .term_register_constant(constant.clone(), no_substitution)
+            .unwrap();
+        let partial = state.term_register_application(constant, a).unwrap();
+
+        state.term_register_application(partial, b).unwrap()
+    }
+
+    /// Registers the sum of `k` copies of `t`, plus `n`.
+    fn multiple(
+        state: &mut RuntimeState,
+        k: usize,
+        t: &Handle<tags::Term>,
+        n: usize,
+    ) -> Handle<tags::Term> {
+        let addition = state.linear_arith_signature().addition().clone();
+        let mut result = numeral(state, n);
+
+        for _i in 0..k {
+            result = binary(state, &addition, t.clone(), result);
+        }
+
+        result
+    }
+
+    /// Registers `∀x y. body(x, y)` over the natural numbers.
+    fn forall2<F>(state: &mut RuntimeState, body: F) -> Handle<tags::Term>
+    where
+        F: FnOnce(
+            &mut RuntimeState,
+            Handle<tags::Term>,
+            Handle<tags::Term>,
+        ) -> Handle<tags::Term>,
+    {
+        let num = state.linear_arith_signature().num().clone();
+
+        let x = state.term_register_variable(0u64, num.clone()).unwrap();
+        let y = state.term_register_variable(1u64, num.clone()).unwrap();
+
+        let body = body(state, x, y);
+        let inner =
+            state.term_register_forall(1u64, num.clone(), body).unwrap();
+
+        state.term_register_forall(0u64, num, inner).unwrap()
+    }
+
+    /// Tests that commutativity of addition and `x < y ⟶ SUC x ≤ y` are proved,
+    /// and that the theorems are tainted, as are theorems derived from them.
+    #[test]
+    pub fn linear_arith_test0() {
+        let mut state = RuntimeState::new();
+        let signature = state.linear_arith_signature();
+
+        let commutativity = forall2(&mut state, |state, x, y| {
+            let left =
+                binary(state, signature.addition(), x.clone(), y.clone());
+            let right = binary(state, signature.addition(), y, x);
+
+            state.term_register_equality(left, right).unwrap()
+        });
+
+        let theorem =
+            state.theorem_register_linear_arith(commutativity).unwrap();
+
+        assert!(state.theorem_is_tainted(&theorem).unwrap());
+
+        let monotone = forall2(&mut state, |state, x, y| {
+            let successor = multiple(state, 1, &x, 1);
+            let less = binary(state, signature.less_than(), x, y.clone());
+            let less_equal =
+                binary(state, signature.less_equal(), successor, y);
+
+            state.term_register_implication(less, less_equal).unwrap()
+        });
+
+        assert!(state.theorem_register_linear_arith(monotone).is_ok());
+
+        let weaken = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let weakened = state
+            .theorem_register_weaken(weaken.clone(), theorem)
+            .unwrap();
+
+        assert!(state.theorem_is_tainted(&weakened).unwrap());
+
+        let assumption = state.theorem_register_assumption(weaken).unwrap();
+
+        assert!(!state.theorem_is_tainted(&assumption).unwrap());
+    }
+
+    /// Tests that goals requiring integer, rather than real, reasoning are
+    /// proved: that no even number is odd, and Pugh's example, whose real
+    /// shadow has solutions but which has none in the integers.
+    #[test]
+    pub fn linear_arith_test1() {
+        let mut state = RuntimeState::new();
+        let signature = state.linear_arith_signature();
+
+        let parity = forall2(&mut state, |state, x, y| {
+            let even = multiple(state, 2, &x, 0);
+            let odd = multiple(state, 2, &y, 1);
+            let equality = state.term_register_equality(even, odd).unwrap();
+
+            state.term_register_negation(equality).unwrap()
+        });
+
+        assert!(state.theorem_register_linear_arith(parity).is_ok());
+
+        /* NB: 27 ≤ 11x + 13y ≤ 45 and -10 ≤ 7x - 9y ≤ 4. */
+        let pugh = forall2(&mut state, |state, x, y| {
+            let a = multiple(state, 11, &x, 0);
+            let sum = {
+                let b = multiple(state, 13, &y, 0);
+                binary(state, signature.addition(), a, b)
+            };
+            let low = numeral(state, 27);
+            let high = numeral(state, 45);
+            let seven_x = multiple(state, 7, &x, 0);
+            let nine_y = multiple(state, 9, &y, 0);
+            let seven_x_ten = multiple(state, 7, &x, 10);
+            let nine_y_four = multiple(state, 9, &y, 4);
+
+            let constraints = [
+                binary(state, signature.less_equal(), low, sum.clone()),
+                binary(state, signature.less_equal(), sum, high),
+                binary(state, signature.less_equal(), nine_y, seven_x_ten),
+                binary(state, signature.less_equal(), seven_x, nine_y_four),
+            ];
+
+            let mut conjunction = constraints[0].clone();
+
+            for constraint in &constraints[1..] {
+                conjunction = state
+                    .term_register_conjunction(conjunction, constraint.clone())
+                    .unwrap();
+            }
+
+            state.term_register_negation(conjunction).unwrap()
+        });
+
+        assert!(state.theorem_register_linear_arith(pugh).is_ok());
+    }
+
+    /// Tests that false goals, and goals outside of the fragment, are rejected.
+    #[test]
+    pub fn linear_arith_test2() {
+        let mut state = RuntimeState::new();
+        let signature = state.linear_arith_signature();
+
+        let irreflexive = forall2(&mut state, |state, x, _y| {
+            binary(state, signature.less_than(), x.clone(), x)
+        });
+
+        assert_eq!(
+            state.theorem_register_linear_arith(irreflexive),
+            Err(ErrorCode::LinearArithGoalNotProved)
+        );
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let excluded = state
+            .term_register_forall(0u64, PREALLOCATED_HANDLE_TYPE_PROP, p)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_linear_arith(excluded),
+            Err(ErrorCode::NotInLinearArithFragment)
+        );
+
+        let x = state
+            .term_register_variable(0u64, signature.num().clone())
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_linear_arith(x),
+            Err(ErrorCode::NotAProposition)
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! # Metadata on kernel objects
+//!
+//! Metadata attached to kernel objects for the benefit of pretty-printing and
+//! other tooling: type-formers may be registered with a name and
+//! documentation, and prover-space code may attach arbitrary key-value data to
+//! any kernel object.  Metadata plays no part in the logic: the kernel never
+//! consults it when checking an inference, and two kernel objects differing
+//! only in their metadata are indistinguishable to the inference rules.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    proof_object::Sequent,
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::collections::BTreeMap;
+
+////////////////////////////////////////////////////////////////////////////////
+// Type-former metadata.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Metadata optionally supplied when registering a type-former: a
+/// human-readable name, e.g. `list`, and a documentation string.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeFormerMetadata {
+    /// The name of the type-former.
+    name: String,
+    /// The documentation string of the type-former.
+    documentation: String,
+}
+
+impl TypeFormerMetadata {
+    /// Creates new type-former metadata from a `name` and a `documentation`
+    /// string.
+    #[inline]
+    pub fn new<T, U>(name: T, documentation: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        TypeFormerMetadata {
+            name: name.into(),
+            documentation: documentation.into(),
+        }
+    }
+
+    /// Returns the name of the type-former.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the documentation string of the type-former.
+    #[inline]
+    pub fn documentation(&self) -> &str {
+        &self.documentation
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Object metadata.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Auxiliary data attached to a kernel object by prover-space code, e.g. the
+/// priority of a simplification rule, a tag, or a source position, as a map
+/// from keys to uninterpreted bytes.  As the kernel maximally shares types and
+/// terms, metadata attached to a type or a term is shared by every occurrence
+/// of it.
+pub type ObjectMetadata = BTreeMap<String, Vec<u8>>;
+
+impl RuntimeState {
+    /// Returns `Ok(())` iff `handle` points-to a registered kernel object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object, or `Err(ErrorCode::StaleHandle)`
+    /// if it was issued within a discarded speculative branch.
+    pub(crate) fn object_check_registered(
+        &self,
+        handle: usize,
+    ) -> Result<(), ErrorCode> {
+        if self.object_kind(handle).is_some() {
+            return Ok(());
+        }
+
+        /* NB: whether a handle is stale does not depend on its tag. */
+        Err(self.unresolved(
+            &Handle::<tags::Term>::from(handle),
+            ErrorCode::NoSuchObjectRegistered,
+        ))
+    }
+
+    /// Attaches `value` to the kernel object pointed-to by `handle` under
+    /// `key`, replacing any value previously attached under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    pub fn object_metadata_set<T, U>(
+        &mut self,
+        handle: usize,
+        key: T,
+        value: U,
+    ) -> Result<(), ErrorCode>
+    where
+        T: Into<String>,
+        U: Into<Vec<u8>>,
+    {
+        let key = key.into();
+
+        info!(
+            "Setting metadata key {:?} of kernel object with handle: {}.",
+            key, handle
+        );
+
+        self.object_check_registered(handle)?;
+
+        self.object_metadata
+            .entry(handle)
+            .or_default()
+            .insert(key, value.into());
+
+        Ok(())
+    }
+
+    /// Returns the value attached to the kernel object pointed-to by `handle`
+    /// under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchMetadataKey)` if nothing is attached to
+    /// the object under `key`.
+    pub fn object_metadata_get(
+        &self,
+        handle: usize,
+        key: &str,
+    ) -> Result<&[u8], ErrorCode> {
+        info!(
+            "Getting metadata key {:?} of kernel object with handle: {}.",
+            key, handle
+        );
+
+        self.object_check_registered(handle)?;
+
+        self.object_metadata
+            .get(&handle)
+            .and_then(|metadata| metadata.get(key))
+            .map(|value| value.as_slice())
+            .ok_or(ErrorCode::NoSuchMetadataKey)
+    }
+
+    /// Returns the keys under which values are attached to the kernel object
+    /// pointed-to by `handle`, in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchObjectRegistered)` if `handle` does not
+    /// point-to any registered kernel object.
+    pub fn object_metadata_keys(
+        &self,
+        handle: usize,
+    ) -> Result<Vec<&str>, ErrorCode> {
+        info!(
+            "Enumerating metadata keys of kernel object with handle: {}.",
+            handle
+        );
+
+        self.object_check_registered(handle)?;
+
+        Ok(self
+            .object_metadata
+            .get(&handle)
+            .map(|metadata| metadata.keys().map(|k| k.as_str()).collect())
+            .unwrap_or_default())
+    }
+
+    /// Returns the metadata attached to the kernel object pointed-to by
+    /// `handle`, if any.
+    #[inline]
+    pub(crate) fn object_metadata(
+        &self,
+        handle: usize,
+    ) -> Option<&ObjectMetadata> {
+        self.object_metadata.get(&handle)
+    }
+
+    /// Attaches the metadata of any imported theorem with the same statement
+    /// as the newly-registered theorem pointed-to by `handle` to it.
+    pub(crate) fn attach_pending_metadata(
+        &mut self,
+        handle: &Handle<tags::Theorem>,
+    ) {
+        let statement = Sequent {
+            premisses: self
+                .theorem_split_premisses(handle)
+                .expect(DANGLING_HANDLE_ERROR)
+                .to_vec(),
+            conclusion: self
+                .theorem_split_conclusion(handle)
+                .expect(DANGLING_HANDLE_ERROR),
+        };
+
+        if let Some(metadata) = self.pending_theorem_metadata.get(&statement) {
+            info!(
+                "Attaching imported metadata to theorem with handle: {}.",
+                handle
+            );
+
+            self.object_metadata
+                .entry(**handle)
+                .or_default()
+                .extend(metadata.clone());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for metadata on kernel objects.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that metadata can be attached to, read from, and enumerated on,
+    /// kernel objects of different kinds, and that setting a key again
+    /// replaces its value.
+    #[test]
+    pub fn metadata_test0() {
+        let mut kernel = RuntimeState::new();
+
+        let t = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let thm = kernel.theorem_register_assumption(t.clone()).unwrap();
+
+        assert_eq!(kernel.object_metadata_keys(*thm), Ok(Vec::new()));
+
+        kernel
+            .object_metadata_set(*thm, "priority", vec![1])
+            .unwrap();
+        kernel
+            .object_metadata_set(*thm, "line", vec![4, 2])
+            .unwrap();
+        kernel
+            .object_metadata_set(*thm, "priority", vec![3])
+            .unwrap();
+        kernel.object_metadata_set(*t, "priority", vec![5]).unwrap();
+
+        assert_eq!(kernel.object_metadata_get(*thm, "priority"), Ok(&[3][..]));
+        assert_eq!(kernel.object_metadata_get(*t, "priority"), Ok(&[5][..]));
+        assert_eq!(
+            kernel.object_metadata_keys(*thm),
+            Ok(vec!["line", "priority"])
+        );
+        assert_eq!(
+            kernel.object_metadata_get(*t, "line"),
+            Err(ErrorCode::NoSuchMetadataKey)
+        );
+    }
+
+    /// Tests that metadata cannot be attached to unregistered or stale
+    /// handles, and that metadata attached within a discarded speculative
+    /// branch is forgotten.
+    #[test]
+    pub fn metadata_test1() {
+        let mut kernel = RuntimeState::new();
+
+        assert_eq!(
+            kernel.object_metadata_set(usize::MAX, "tag", vec![]),
+            Err(ErrorCode::NoSuchObjectRegistered)
+        );
+
+        let t: Handle<tags::Term> = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        kernel.speculate_begin();
+
+        kernel.object_metadata_set(*t, "tag", vec![1]).unwrap();
+        let thm = kernel.theorem_register_assumption(t.clone()).unwrap();
+        kernel.object_metadata_set(*thm, "tag", vec![2]).unwrap();
+
+        kernel.speculate_end(false).unwrap();
+
+        assert_eq!(
+            kernel.object_metadata_get(*t, "tag"),
+            Err(ErrorCode::NoSuchMetadataKey)
+        );
+        assert_eq!(
+            kernel.object_metadata_keys(*thm),
+            Err(ErrorCode::StaleHandle)
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! # Theorem minimisation
+//!
+//! A proof artefact is often developed piecemeal, and by the time that its
+//! main theorem is proved the runtime state is cluttered with lemmas that the
+//! final proof never used, and the theorem itself may carry hypotheses that
+//! were only ever introduced by weakening.  Minimisation walks the recorded
+//! derivation of a target theorem, computing the lemmas that the derivation
+//! actually uses and, for each of these and the target itself, the subset of
+//! its hypotheses that the derivation actually depends on, i.e. those that
+//! can be traced back to an assumption rather than a weakening.
+//!
+//! The analysis relies on the proof terms recorded by the kernel, and so is
+//! only exact for theorems registered whilst proof recording was enabled.
+//! Lemmas without a recorded proof term are treated as opaque, and every one
+//! of their hypotheses is assumed to be used.  The substitution rules rename
+//! the hypotheses of their premiss, so a theorem derived by substitution is
+//! conservatively assumed to use every one of its hypotheses unless its
+//! premiss uses none of its own.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    proof::{ProofArgument, Rule},
+    proof_object::Sequent,
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, collections::HashMap};
+
+////////////////////////////////////////////////////////////////////////////////
+// Minimisations.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The result of minimising a theorem: the lemmas used by its recorded
+/// derivation, and the hypotheses of each theorem in the derivation that the
+/// derivation depends upon.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Minimisation {
+    /// The theorem that was minimised.
+    theorem: Handle<tags::Theorem>,
+    /// The lemmas used by the derivation of the theorem, ordered so that every
+    /// lemma follows the lemmas used to derive it.
+    lemmas: Vec<Handle<tags::Theorem>>,
+    /// The lemmas that have no recorded proof term.
+    unrecorded: Vec<Handle<tags::Theorem>>,
+    /// The hypotheses used by each theorem in the derivation, including the
+    /// theorem that was minimised, in the order of the theorem's hypotheses.
+    used: HashMap<Handle<tags::Theorem>, Vec<Handle<tags::Term>>>,
+}
+
+impl Minimisation {
+    /// Returns the theorem that was minimised.
+    #[inline]
+    pub fn theorem(&self) -> &Handle<tags::Theorem> {
+        &self.theorem
+    }
+
+    /// Returns the lemmas used by the derivation of the theorem, not including
+    /// the theorem itself, ordered so that every lemma follows the lemmas used
+    /// to derive it.
+    #[inline]
+    pub fn lemmas(&self) -> &[Handle<tags::Theorem>] {
+        &self.lemmas
+    }
+
+    /// Returns the lemmas used by the derivation of the theorem that have no
+    /// recorded proof term, and whose own derivations are therefore unknown.
+    #[inline]
+    pub fn unrecorded(&self) -> &[Handle<tags::Theorem>] {
+        &self.unrecorded
+    }
+
+    /// Returns the hypotheses of the theorem that its derivation uses.
+    #[inline]
+    pub fn hypotheses(&self) -> &[Handle<tags::Term>] {
+        self.used.get(&self.theorem).expect(DANGLING_HANDLE_ERROR)
+    }
+
+    /// Returns the hypotheses of `theorem` that its derivation uses, or `None`
+    /// if `theorem` is neither the theorem that was minimised nor one of its
+    /// lemmas.
+    #[inline]
+    pub fn hypotheses_of<T>(&self, theorem: T) -> Option<&[Handle<tags::Term>]>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.used.get(theorem.borrow()).map(|used| used.as_slice())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Minimising theorems.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Minimises the theorem pointed-to by `handle`, returning the lemmas that
+    /// its recorded derivation uses, and the hypotheses of the theorem and of
+    /// each lemma that the derivation depends upon.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchProofRegistered)` if no proof term was
+    /// recorded for the theorem, as it was registered whilst proof recording
+    /// was disabled.
+    pub fn theorem_minimise<T>(
+        &self,
+        handle: T,
+    ) -> Result<Minimisation, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Minimising theorem with handle {}.", handle.borrow());
+
+        let theorem = handle.borrow().clone();
+
+        self.theorem_proof(&theorem)?;
+
+        let mut used: HashMap<Handle<tags::Theorem>, Vec<Handle<tags::Term>>> =
+            HashMap::new();
+        let mut order = Vec::new();
+        let mut unrecorded = Vec::new();
+
+        /* NB: an explicit work-list, as derivations produced by automation can
+         * be very deep.  Each theorem is visited twice: once to schedule its
+         * premisses, and again to compute its used hypotheses, once those of
+         * every premiss are known.
+         */
+        let mut work = vec![(theorem.clone(), false)];
+
+        while let Some((current, expanded)) = work.pop() {
+            if used.contains_key(&current) {
+                continue;
+            }
+
+            let hypotheses = self.theorem_split_premisses(&current)?;
+
+            let (rule, premisses) = match self.theorem_proof(&current) {
+                Ok(proof) => {
+                    let (rule, _theorem, arguments) =
+                        self.proof_split(&proof)?;
+                    let premisses: Vec<Handle<tags::Theorem>> = arguments
+                        .iter()
+                        .filter_map(|argument| match argument {
+                            ProofArgument::Theorem(handle) => {
+                                Some(handle.clone())
+                            }
+                            _otherwise => None,
+                        })
+                        .collect();
+
+                    (rule, premisses)
+                }
+                Err(_e) => {
+                    unrecorded.push(current.clone());
+                    used.insert(current.clone(), hypotheses.to_vec());
+                    order.push(current);
+                    continue;
+                }
+            };
+
+            if !expanded {
+                work.push((current, true));
+
+                for premiss in premisses.into_iter().rev() {
+                    if !used.contains_key(&premiss) {
+                        work.push((premiss, false));
+                    }
+                }
+
+                continue;
+            }
+
+            let uses = match rule {
+                _rule if premisses.is_empty() => hypotheses.to_vec(),
+                Rule::Substitute | Rule::TypeSubstitute => {
+                    if used[&premisses[0]].is_empty() {
+                        Vec::new()
+                    } else {
+                        hypotheses.to_vec()
+                    }
+                }
+                _otherwise => hypotheses
+                    .iter()
+                    .filter(|h| premisses.iter().any(|p| used[p].contains(h)))
+                    .cloned()
+                    .collect(),
+            };
+
+            used.insert(current.clone(), uses);
+            order.push(current);
+        }
+
+        /* NB: the minimised theorem is always the last to be computed, as it
+         * follows every one of its premisses.
+         */
+        order.pop();
+
+        info!(
+            "Theorem minimised: {} lemmas, {} of {} hypotheses used.",
+            order.len(),
+            used[&theorem].len(),
+            self.theorem_split_premisses(&theorem)?.len()
+        );
+
+        Ok(Minimisation {
+            theorem,
+            lemmas: order,
+            unrecorded,
+            used,
+        })
+    }
+
+    /// Serializes the signature of the runtime state, and the sequents of the
+    /// lemmas and theorem of `minimisation`, into a state object.  Each
+    /// sequent only carries the hypotheses that its derivation uses, and the
+    /// lemmas precede the theorem.  The signature is exported whole, as for
+    /// `state_export`.
+    pub fn state_export_minimised(
+        &self,
+        minimisation: &Minimisation,
+    ) -> Vec<u8> {
+        info!(
+            "Exporting minimised theorem with handle {} as a state object.",
+            minimisation.theorem()
+        );
+
+        let sequents: Vec<Sequent> = minimisation
+            .lemmas()
+            .iter()
+            .chain(std::iter::once(minimisation.theorem()))
+            .map(|t| Sequent {
+                premisses: minimisation
+                    .hypotheses_of(t)
+                    .expect(DANGLING_HANDLE_ERROR)
+                    .to_vec(),
+                conclusion: self
+                    .theorem_split_conclusion(t)
+                    .expect(DANGLING_HANDLE_ERROR),
+            })
+            .collect();
+
+        self.encode_state(&sequents)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for theorem minimisation.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_TRUE,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that hypotheses introduced only by weakening are not used, and
+    /// that lemmas not used by the derivation are not reported.
+    #[test]
+    pub fn minimise_test0() {
+        let mut kernel = RuntimeState::new();
+
+        kernel.set_proof_recording(true);
+
+        let assumed = kernel
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let unused = kernel.theorem_register_truth_introduction().unwrap();
+        let weakened = kernel
+            .theorem_register_weaken(
+                PREALLOCATED_HANDLE_TERM_FALSE,
+                assumed.clone(),
+            )
+            .unwrap();
+        let target = kernel
+            .theorem_register_conjunction_introduction(&weakened, &assumed)
+            .unwrap();
+
+        let minimisation = kernel.theorem_minimise(&target).unwrap();
+
+        assert_eq!(minimisation.theorem(), &target);
+        assert_eq!(minimisation.lemmas(), &[assumed.clone(), weakened]);
+        assert!(minimisation.unrecorded().is_empty());
+        assert_eq!(minimisation.hypotheses(), &[PREALLOCATED_HANDLE_TERM_TRUE]);
+        assert_eq!(kernel.theorem_split_premisses(&target).unwrap().len(), 2);
+        assert_eq!(minimisation.hypotheses_of(&unused), None);
+    }
+
+    /// Tests that discharged hypotheses are not used, and that lemmas without
+    /// a recorded proof term are reported, with every hypothesis used.
+    #[test]
+    pub fn minimise_test1() {
+        let mut kernel = RuntimeState::new();
+
+        let assumed = kernel
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        kernel.set_proof_recording(true);
+
+        let target = kernel
+            .theorem_register_implication_introduction(
+                &assumed,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        let minimisation = kernel.theorem_minimise(&target).unwrap();
+
+        assert_eq!(minimisation.unrecorded().len(), 1);
+        assert_eq!(minimisation.unrecorded()[0], assumed);
+        assert_eq!(
+            minimisation.hypotheses_of(&assumed),
+            Some(&[PREALLOCATED_HANDLE_TERM_TRUE][..])
+        );
+        assert!(minimisation.hypotheses().is_empty());
+
+        assert_eq!(
+            kernel.theorem_minimise(&assumed),
+            Err(ErrorCode::NoSuchProofRegistered)
+        );
+    }
+
+    /// Tests that a minimised state object carries only the lemmas used, each
+    /// with only the hypotheses used.
+    #[test]
+    pub fn minimise_test2() {
+        let mut kernel = RuntimeState::new();
+
+        kernel.set_proof_recording(true);
+
+        let truth = kernel.theorem_register_truth_introduction().unwrap();
+        kernel
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+        let target = kernel
+            .theorem_register_weaken(
+                PREALLOCATED_HANDLE_TERM_FALSE,
+                truth.clone(),
+            )
+            .unwrap();
+
+        let minimisation = kernel.theorem_minimise(&target).unwrap();
+        let bytes = kernel.state_export_minimised(&minimisation);
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.state_import(&bytes).unwrap();
+
+        assert_eq!(sequents.len(), 2);
+        assert!(sequents.iter().all(|s| s.premisses.is_empty()));
+        assert!(sequents
+            .iter()
+            .all(|s| s.conclusion == PREALLOCATED_HANDLE_TERM_TRUE));
+    }
+}
@@ -8,6 +8,23 @@
 //! for example when performing a capture-avoiding substitution.  This module
 //! implements that functionality.
 //!
+//! Names are also reserved for the kernel's own use.  The kernel stores every
+//! λ-abstraction in an ⍺-normal form, in which the bound variable is renamed to
+//! a *canonical name* determined by the abstraction alone, so that two terms
+//! are ⍺-equivalent exactly when they are structurally equal.  The canonical
+//! names are those with the top bit set.  Nothing prevents a guest from giving
+//! a variable a canonical name, and splitting a λ-abstraction also yields a
+//! free variable with one, so ordinary and canonical names may collide: the
+//! canonical name chosen for a bound variable is therefore one that no free
+//! variable of the same type in the abstraction's body already carries.  The
+//! name originally chosen for a bound variable is kept as its *display name*.
+//!
+//! Proof objects and state snapshots produced by kernels predating ⍺-normal
+//! forms carry the names chosen by their authors, and need no conversion: the
+//! kernel rebuilds their terms through the usual registration functions when
+//! importing them, which normalise every λ-abstraction and remember its
+//! original name as its display name.
+//!
 //! # Authors
 //!
 //! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
@@ -29,6 +46,7 @@
 
 use crate::kernel_panic::FRESH_NAME_GENERATION_FAILED;
 use log::info;
+use std::convert::TryFrom;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Names and related material.
@@ -37,17 +55,43 @@ use log::info;
 /// We use `u64` values to represent variable names.
 pub type Name = u64;
 
+/// The smallest canonical name.  The `i`th canonical name is
+/// `CANONICAL_NAME_BASE + i`.
+pub const CANONICAL_NAME_BASE: Name = 1 << 63;
+
+/// Returns the `index`th canonical name.
+#[inline]
+pub fn canonical_name(index: u64) -> Name {
+    CANONICAL_NAME_BASE | index
+}
+
+/// Returns `Some(index)` iff `name` is the `index`th canonical name, and
+/// `None` if `name` is an ordinary name.
+#[inline]
+pub fn canonical_index(name: Name) -> Option<u64> {
+    if name >= CANONICAL_NAME_BASE {
+        Some(name - CANONICAL_NAME_BASE)
+    } else {
+        None
+    }
+}
+
 /// Fresh name generation, for e.g. implementing the capture-avoiding
 /// substitution action.  Finds a name that is not contained in the `avoid` set
 /// of names.
-fn fresh<T>(mut avoid: T) -> Name
+pub(crate) fn fresh<T>(avoid: T) -> Name
 where
     T: Iterator<Item = Name>,
 {
+    /* NB: collect the names to avoid up-front, as searching the iterator
+     * directly would consume it, so later candidates would only be checked
+     * against what remains of the `avoid` set.
+     */
+    let avoid: Vec<Name> = avoid.collect();
     let mut counter = 0;
 
     loop {
-        if avoid.any(|x| x == counter) {
+        if avoid.contains(&counter) {
             if let Some(next) = counter.checked_add(1) {
                 counter = next;
             } else {
@@ -60,6 +104,42 @@ where
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Variable orders.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The order in which the kernel lists the variables of a type or term, when
+/// enumerating them.  Either way, every variable is listed exactly once, and
+/// the order depends only on the type or term, never on the order in which
+/// kernel objects were registered, nor on the kernel's internal tables.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VariableOrder {
+    /// The order in which the variables first occur, reading the type or term
+    /// from left to right: the arguments of a type-former from first to last,
+    /// the function of an application before its argument, and the type of a
+    /// bound variable before the body of its λ-abstraction.  The variables of
+    /// the type of a variable or constant occur at that variable or constant.
+    FirstOccurrence,
+    /// Ascending order of name, with variables sharing a name, which may occur
+    /// amongst the free variables of a term, in the order in which they first
+    /// occur.
+    ByName,
+}
+
+/// Conversion from a `u64` value, received across the ABI boundary, into a
+/// variable order.
+impl TryFrom<u64> for VariableOrder {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VariableOrder::FirstOccurrence),
+            1 => Ok(VariableOrder::ByName),
+            _otherwise => Err(()),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests.
 ////////////////////////////////////////////////////////////////////////////////
@@ -67,7 +147,7 @@ where
 /// Tests for fresh name generation-related functionality.
 #[cfg(test)]
 mod test {
-    use crate::name::fresh;
+    use crate::name::{canonical_index, canonical_name, fresh};
 
     /// Tests that fresh-name generation is indeed fresh.
     #[test]
@@ -76,4 +156,22 @@ mod test {
 
         assert!(!(0..100).contains(&n));
     }
+
+    /// Tests that fresh-name generation avoids every name, however the names
+    /// to avoid are ordered.
+    #[test]
+    pub fn name_test1() {
+        let n = fresh((0..100).rev());
+
+        assert!(!(0..100).contains(&n));
+    }
+
+    /// Tests that canonical names are distinct from ordinary names, and that
+    /// their indices are recovered.
+    #[test]
+    pub fn name_test2() {
+        assert_eq!(canonical_index(canonical_name(3)), Some(3));
+        assert_eq!(canonical_index(fresh(0..100)), None);
+        assert_ne!(canonical_name(0), 0);
+    }
 }
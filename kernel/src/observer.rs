@@ -0,0 +1,427 @@
+//! # Kernel event observers
+//!
+//! Embedders of the kernel, such as Veracruz, may need to account for, cache,
+//! or monitor what a guest does with the kernel, without forking the kernel to
+//! do so.  Observers are callbacks, registered with the runtime state by the
+//! embedder, that are notified of kernel events as they happen: the allocation
+//! of every kernel object, the registration of every theorem, and the progress
+//! of long-running operations, such as checking a large proof or importing a
+//! large proof object, so that an interactive embedder can show how far along
+//! the operation is.
+//!
+//! Observers are handed a description of each event, and never the runtime
+//! state itself, so that they cannot affect the outcome of any kernel
+//! operation.  Note that objects allocated within a speculative branch are
+//! reported as they are allocated, even if the branch is later discarded, and
+//! that clones of the runtime state share the observers of the original.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    handle::{tags, Handle, Kind},
+    runtime_state::RuntimeState,
+    theorem::Theorem,
+};
+use log::info;
+use std::{
+    fmt::{Debug, Error as DisplayError, Formatter},
+    mem::take,
+    rc::Rc,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Observers, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An observer of the allocation of kernel objects, called with the kind of
+/// each object allocated along with its handle.
+pub type ObjectObserver = Rc<dyn Fn(Kind, usize)>;
+
+/// An observer of the registration of theorems, called with the handle of each
+/// theorem registered along with the theorem itself.
+pub type TheoremObserver = Rc<dyn Fn(&Handle<tags::Theorem>, &Theorem)>;
+
+/// The long-running kernel operations whose progress is reported to progress
+/// observers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LongOperation {
+    /// Checking a proof term, with `proof_check`.
+    ProofCheck,
+    /// Importing proof objects, with `theorem_import` or
+    /// `theorem_import_parallel`.
+    ProofImport,
+    /// Importing a state object, with `state_import`.
+    StateImport,
+}
+
+/// An observer of the progress of long-running kernel operations, called with
+/// the operation in progress and the percentage of it completed, from `0` to
+/// `100`, once as the operation begins and then each time that percentage
+/// increases.  An operation that fails stops being reported without reaching
+/// `100`.
+pub type ProgressObserver = Rc<dyn Fn(LongOperation, u8)>;
+
+/// The observers registered with the runtime state, in the order in which they
+/// were registered.
+#[derive(Clone, Default)]
+pub(crate) struct Observers {
+    /// The observers of the allocation of kernel objects.
+    object_allocated: Vec<ObjectObserver>,
+    /// The observers of the registration of theorems.
+    theorem_registered: Vec<TheoremObserver>,
+    /// The observers of the progress of long-running operations.
+    progress: Vec<ProgressObserver>,
+    /// The long-running operation in progress, if any, and the percentage of
+    /// it last reported as completed.
+    in_progress: Option<(LongOperation, u8)>,
+}
+
+impl Debug for Observers {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        f.debug_struct("Observers")
+            .field("object_allocated", &self.object_allocated.len())
+            .field("theorem_registered", &self.theorem_registered.len())
+            .field("progress", &self.progress.len())
+            .field("in_progress", &self.in_progress)
+            .finish()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Observer-related material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Registers `observer`, to be called with the kind and handle of every
+    /// kernel object allocated from now on, as its handle is issued.
+    pub fn on_object_allocated<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(Kind, usize) + 'static,
+    {
+        info!("Registering object allocation observer.");
+
+        self.observers.object_allocated.push(Rc::new(observer));
+        self
+    }
+
+    /// Registers `observer`, to be called with the handle of every theorem
+    /// registered from now on, along with the theorem itself, once it has been
+    /// admitted into the runtime state's theorem table.
+    pub fn on_theorem_registered<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(&Handle<tags::Theorem>, &Theorem) + 'static,
+    {
+        info!("Registering theorem registration observer.");
+
+        self.observers.theorem_registered.push(Rc::new(observer));
+        self
+    }
+
+    /// Registers `observer`, to be called with the progress of every
+    /// long-running operation from now on.  See `ProgressObserver`.
+    pub fn on_progress<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: Fn(LongOperation, u8) + 'static,
+    {
+        info!("Registering progress observer.");
+
+        self.observers.progress.push(Rc::new(observer));
+        self
+    }
+
+    /// Removes every observer registered with the runtime state.
+    pub fn clear_observers(&mut self) -> &mut Self {
+        info!("Removing every observer.");
+
+        self.observers = Observers::default();
+        self
+    }
+
+    /// Notifies every observer of the allocation of kernel objects that the
+    /// object with kind `kind` and handle `handle` has been allocated.
+    #[inline]
+    pub(crate) fn notify_object_allocated(&self, kind: Kind, handle: usize) {
+        for observer in self.observers.object_allocated.iter() {
+            observer(kind, handle);
+        }
+    }
+
+    /// Notifies every observer of the registration of theorems that the
+    /// theorem `theorem`, with handle `handle`, has been registered.
+    #[inline]
+    pub(crate) fn notify_theorem_registered(
+        &self,
+        handle: &Handle<tags::Theorem>,
+        theorem: &Theorem,
+    ) {
+        for observer in self.observers.theorem_registered.iter() {
+            observer(handle, theorem);
+        }
+    }
+
+    /// Runs `body` without notifying the observers of the allocation of
+    /// kernel objects, or of the registration of theorems, of anything that it
+    /// does, e.g. when the objects that it registers are discarded afterwards.
+    /// Progress observers are still notified.
+    pub(crate) fn unobserved<F, R>(&mut self, body: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let object_allocated = take(&mut self.observers.object_allocated);
+        let theorem_registered = take(&mut self.observers.theorem_registered);
+
+        let result = body(self);
+
+        self.observers.object_allocated = object_allocated;
+        self.observers.theorem_registered = theorem_registered;
+
+        result
+    }
+
+    /// Runs `body` as the long-running operation `operation`, reporting its
+    /// beginning and, if it succeeds, its completion to every progress
+    /// observer.  Progress within `body` is reported with `progress_report`.
+    /// An operation run within another reports nothing of its own, as its
+    /// progress is part of the progress of the enclosing operation.
+    pub(crate) fn with_progress<F, R, E>(
+        &mut self,
+        operation: LongOperation,
+        body: F,
+    ) -> Result<R, E>
+    where
+        F: FnOnce(&mut Self) -> Result<R, E>,
+    {
+        if self.observers.progress.is_empty()
+            || self.observers.in_progress.is_some()
+        {
+            return body(self);
+        }
+
+        self.observers.in_progress = Some((operation, 0));
+        self.notify_progress(operation, 0);
+
+        let result = body(self);
+
+        if result.is_ok() {
+            self.progress_report(1, 1);
+        }
+
+        self.observers.in_progress = None;
+
+        result
+    }
+
+    /// Reports that `done` out of `total` units of work of the long-running
+    /// operation in progress have been completed, notifying every progress
+    /// observer if the percentage completed has increased since it was last
+    /// reported.  Does nothing if no operation is in progress.
+    pub(crate) fn progress_report(&mut self, done: usize, total: usize) {
+        if let Some((operation, reported)) = self.observers.in_progress {
+            let percent =
+                (done.min(total) as u128 * 100 / total.max(1) as u128) as u8;
+
+            if percent > reported {
+                self.observers.in_progress = Some((operation, percent));
+                self.notify_progress(operation, percent);
+            }
+        }
+    }
+
+    /// Returns `true` iff the progress of a long-running operation is being
+    /// reported.
+    #[inline]
+    pub(crate) fn progress_observed(&self) -> bool {
+        self.observers.in_progress.is_some()
+    }
+
+    /// Notifies every progress observer that `percent` of the long-running
+    /// operation `operation` has been completed.
+    #[inline]
+    fn notify_progress(&self, operation: LongOperation, percent: u8) {
+        for observer in self.observers.progress.iter() {
+            observer(operation, percent);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for observer-related functionality.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, Kind, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        observer::LongOperation,
+        runtime_state::RuntimeState,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Tests that object allocation observers are notified of the kind and
+    /// handle of every fresh object, and not of objects already registered.
+    #[test]
+    pub fn observer_test0() {
+        let mut state = RuntimeState::new();
+        let allocated = Rc::new(RefCell::new(Vec::new()));
+
+        let log = allocated.clone();
+        state.on_object_allocated(move |kind, handle| {
+            log.borrow_mut().push((kind, handle))
+        });
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(x, y);
+        assert_eq!(*allocated.borrow(), vec![(Kind::Term, *x)]);
+    }
+
+    /// Tests that theorem registration observers are notified of every
+    /// theorem registered, with its handle, and that observers may be removed.
+    #[test]
+    pub fn observer_test1() {
+        let mut state = RuntimeState::new();
+        let registered: Rc<RefCell<Vec<Handle<tags::Theorem>>>> =
+            Rc::new(RefCell::new(Vec::new()));
+
+        let log = registered.clone();
+        state.on_theorem_registered(move |handle, theorem| {
+            assert_eq!(theorem.conclusion(), &PREALLOCATED_HANDLE_TERM_TRUE);
+            log.borrow_mut().push(handle.clone())
+        });
+
+        let thm = state.theorem_register_truth_introduction().unwrap();
+
+        assert_eq!(*registered.borrow(), vec![thm]);
+
+        state.clear_observers();
+        state.theorem_register_truth_introduction().unwrap();
+
+        assert_eq!(registered.borrow().len(), 1);
+    }
+
+    /// Tests that clones of the runtime state share its observers.
+    #[test]
+    pub fn observer_test2() {
+        let mut state = RuntimeState::new();
+        let count = Rc::new(RefCell::new(0));
+
+        let log = count.clone();
+        state.on_object_allocated(move |_kind, _handle| *log.borrow_mut() += 1);
+
+        let mut clone = state.clone();
+
+        state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        clone
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(*count.borrow(), 2);
+    }
+
+    /// Builds a chain of theorems, each derived by symmetry from the last,
+    /// with proof recording enabled, returning the last.
+    fn symmetry_chain(
+        state: &mut RuntimeState,
+        length: usize,
+    ) -> Handle<tags::Theorem> {
+        state.set_proof_recording(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let mut thm = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(p)
+            .unwrap();
+
+        for _i in 0..length {
+            thm = state.theorem_register_symmetry(thm).unwrap();
+        }
+
+        thm
+    }
+
+    /// Tests that the progress of checking a proof and of importing a proof
+    /// object is reported as it increases, from `0` to `100`.
+    #[test]
+    pub fn observer_test3() {
+        let mut state = RuntimeState::new();
+        let thm = symmetry_chain(&mut state, 200);
+        let reports = Rc::new(RefCell::new(Vec::new()));
+
+        let log = reports.clone();
+        state.on_progress(move |operation, percent| {
+            log.borrow_mut().push((operation, percent))
+        });
+
+        let proof = state.theorem_proof(&thm).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+
+        let bytes = state.theorem_export(&[thm]).unwrap();
+
+        assert!(state.theorem_import(&bytes).is_ok());
+
+        for operation in [LongOperation::ProofCheck, LongOperation::ProofImport]
+        {
+            let percents: Vec<u8> = reports
+                .borrow()
+                .iter()
+                .filter(|(o, _p)| *o == operation)
+                .map(|(_o, p)| *p)
+                .collect();
+
+            assert!(percents.len() > 2);
+            assert_eq!(percents.first(), Some(&0));
+            assert_eq!(percents.last(), Some(&100));
+            assert!(percents.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    /// Tests that a failed operation is not reported as completed, and that
+    /// nothing is reported without progress observers.
+    #[test]
+    pub fn observer_test4() {
+        let mut state = RuntimeState::new();
+        let reports = Rc::new(RefCell::new(Vec::new()));
+
+        assert!(state.theorem_import(b"SPVO").is_err());
+        assert!(!state.progress_observed());
+
+        let log = reports.clone();
+        state.on_progress(move |operation, percent| {
+            log.borrow_mut().push((operation, percent))
+        });
+
+        assert_eq!(
+            state.theorem_import(b"SPVO"),
+            Err(ErrorCode::MalformedProofObject)
+        );
+        assert_eq!(*reports.borrow(), vec![(LongOperation::ProofImport, 0)]);
+        assert!(!state.progress_observed());
+    }
+}
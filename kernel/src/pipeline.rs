@@ -0,0 +1,700 @@
+//! # Parallel proof object pipelines
+//!
+//! Exporting or importing a large library as a single proof object is a
+//! sequential walk over every type, term and sequent of the library.  The
+//! pipelines in this module instead split a library into several proof
+//! objects, or *chunks*, which are processed by worker threads on the host.
+//!
+//! On export, the theorems are partitioned along their recorded dependencies:
+//! two theorems land in the same chunk whenever one was derived, directly or
+//! transitively, from the other, or both from a common lemma, so that the
+//! types and terms that related theorems share are serialized once.  Theorems
+//! without a recorded proof term depend on nothing.  The independent groups of
+//! theorems so obtained are dealt out to at most one chunk per worker, and the
+//! chunks are then serialized concurrently.  Every chunk is an ordinary proof
+//! object, and may also be imported with `theorem_import`, or framed with
+//! `proof_object_frame` when the kernel is built with the `zstd` feature.
+//!
+//! On import, the chunks are decoded concurrently into a table of entries,
+//! checking their structure but registering nothing, and are then committed
+//! into the kernel one at a time, in the order given.  Registration itself is
+//! sequential, as the kernel's tables are not shared between threads.  The
+//! commitment is all-or-nothing: if any chunk fails to decode or to register,
+//! no kernel object from any chunk is kept.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    name::Name,
+    observer::LongOperation,
+    proof::ProofArgument,
+    proof_object::{
+        encode_sequents, read_handles, unframe, Encoder, Reader, Sequent,
+        FLAG_HANDLES, TAG_TERM_APPLICATION, TAG_TERM_CONSTANT, TAG_TERM_LAMBDA,
+        TAG_TERM_VARIABLE, TAG_TYPE_COMBINATION, TAG_TYPE_VARIABLE,
+    },
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, collections::HashMap, thread};
+
+////////////////////////////////////////////////////////////////////////////////
+// Chunks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A chunk of a library exported by a parallel pipeline: a proof object, and
+/// the theorems whose sequents it contains, in the order in which they appear
+/// in the proof object.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ProofObjectChunk {
+    /// The theorems exported in the chunk.
+    pub theorems: Vec<Handle<tags::Theorem>>,
+    /// The serialized proof object.
+    pub bytes: Vec<u8>,
+}
+
+/// A type-table entry of a decoded, but not yet committed, proof object.
+/// References to other entries are indices into the decoded tables.
+enum TypeEntry {
+    /// A type-variable.
+    Variable(Name),
+    /// A combination of a raw type-former handle and argument types.
+    Combination(usize, Vec<usize>),
+}
+
+/// A term-table entry of a decoded, but not yet committed, proof object.
+/// References to other entries are indices into the decoded tables.
+enum TermEntry {
+    /// A variable, with its name and type.
+    Variable(Name, usize),
+    /// A raw constant handle, at a type.
+    Constant(usize, usize),
+    /// An application of a term to another.
+    Application(usize, usize),
+    /// A λ-abstraction, with the name and type of its bound variable, and its
+    /// body.
+    Lambda(Name, usize, usize),
+}
+
+/// A proof object that has been decoded, and whose structure has been checked,
+/// but none of whose entries have yet been registered in the kernel.
+struct DecodedChunk {
+    /// The decoded type-table.
+    types: Vec<TypeEntry>,
+    /// The decoded term-table.
+    terms: Vec<TermEntry>,
+    /// The decoded sequents, as premisses and conclusion indices into the
+    /// term-table.
+    sequents: Vec<(Vec<usize>, usize)>,
+}
+
+/// Decodes the proof object `bytes`, without registering anything.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::MalformedProofObject)` under the same conditions as
+/// `theorem_import`.
+fn decode_chunk(bytes: &[u8]) -> Result<DecodedChunk, ErrorCode> {
+    let (flags, body) = unframe(bytes)?;
+    let mut reader = Reader::new(&body);
+
+    /* NB: as in `theorem_import`, table sizes are untrusted, so tables are
+     * grown as entries are actually read.
+     */
+    let mut types = Vec::new();
+
+    for _i in 0..reader.read_varint()? {
+        let entry = match reader.read_u8()? {
+            TAG_TYPE_VARIABLE => TypeEntry::Variable(reader.read_varint()?),
+            TAG_TYPE_COMBINATION => {
+                let former = reader.read_varint()? as usize;
+                let mut arguments = Vec::new();
+
+                for _j in 0..reader.read_varint()? {
+                    arguments.push(reader.read_reference(types.len())?);
+                }
+
+                TypeEntry::Combination(former, arguments)
+            }
+            _otherwise => return Err(ErrorCode::MalformedProofObject),
+        };
+
+        types.push(entry);
+    }
+
+    let mut terms = Vec::new();
+
+    for _i in 0..reader.read_varint()? {
+        let entry = match reader.read_u8()? {
+            TAG_TERM_VARIABLE => TermEntry::Variable(
+                reader.read_varint()?,
+                reader.read_reference(types.len())?,
+            ),
+            TAG_TERM_CONSTANT => TermEntry::Constant(
+                reader.read_varint()? as usize,
+                reader.read_reference(types.len())?,
+            ),
+            TAG_TERM_APPLICATION => TermEntry::Application(
+                reader.read_reference(terms.len())?,
+                reader.read_reference(terms.len())?,
+            ),
+            TAG_TERM_LAMBDA => TermEntry::Lambda(
+                reader.read_varint()?,
+                reader.read_reference(types.len())?,
+                reader.read_reference(terms.len())?,
+            ),
+            _otherwise => return Err(ErrorCode::MalformedProofObject),
+        };
+
+        terms.push(entry);
+    }
+
+    let mut sequents = Vec::new();
+
+    for _i in 0..reader.read_varint()? {
+        let mut premisses = Vec::new();
+
+        for _j in 0..reader.read_varint()? {
+            premisses.push(reader.read_reference(terms.len())?);
+        }
+
+        sequents.push((premisses, reader.read_reference(terms.len())?));
+    }
+
+    /* NB: as in `theorem_import`, any handle section is ignored. */
+    if flags & FLAG_HANDLES != 0 {
+        read_handles(&mut reader, types.len() + terms.len() + sequents.len())?;
+    }
+
+    if !reader.is_exhausted() {
+        return Err(ErrorCode::MalformedProofObject);
+    }
+
+    Ok(DecodedChunk {
+        types,
+        terms,
+        sequents,
+    })
+}
+
+/// Returns the number of workers to use for `jobs` jobs, given that at most
+/// `workers` were requested.
+#[inline]
+fn worker_count(workers: usize, jobs: usize) -> usize {
+    workers.max(1).min(jobs.max(1))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Dependency groups.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the representative of the group containing `handle`, compressing
+/// the path to it in `parents`.
+fn find(
+    parents: &mut HashMap<Handle<tags::Theorem>, Handle<tags::Theorem>>,
+    handle: &Handle<tags::Theorem>,
+) -> Handle<tags::Theorem> {
+    let mut root = handle.clone();
+
+    while let Some(parent) = parents.get(&root) {
+        if *parent == root {
+            break;
+        }
+
+        root = parent.clone();
+    }
+
+    let mut current = handle.clone();
+
+    while current != root {
+        let next = parents
+            .insert(current, root.clone())
+            .unwrap_or_else(|| root.clone());
+        current = next;
+    }
+
+    root
+}
+
+impl RuntimeState {
+    /// Partitions the theorems pointed-to by `handles` into groups which are
+    /// independent with respect to their recorded derivations, returning the
+    /// groups in order of their first theorem in `handles`, and the theorems
+    /// of each group in the order given.
+    fn theorem_dependency_groups(
+        &self,
+        handles: &[Handle<tags::Theorem>],
+    ) -> Vec<Vec<Handle<tags::Theorem>>> {
+        let mut parents = HashMap::new();
+
+        for handle in handles {
+            if parents.contains_key(handle) {
+                continue;
+            }
+
+            parents.insert(handle.clone(), handle.clone());
+
+            let mut work = vec![handle.clone()];
+
+            /* NB: every theorem in a recorded derivation is merged into the
+             * group of the theorem derived, and a theorem already visited is
+             * not walked a second time, so the whole pass is linear in the
+             * size of the derivations.
+             */
+            while let Some(next) = work.pop() {
+                let premisses = match self.theorem_proof(&next) {
+                    Ok(proof) => match self.proof_split(proof) {
+                        Ok((_rule, _theorem, arguments)) => arguments,
+                        Err(_) => continue,
+                    },
+                    Err(_) => continue,
+                };
+
+                for argument in premisses {
+                    if let ProofArgument::Theorem(premiss) = argument {
+                        let visited = parents.contains_key(premiss);
+
+                        if !visited {
+                            parents.insert(premiss.clone(), premiss.clone());
+                        }
+
+                        let left = find(&mut parents, handle);
+                        let right = find(&mut parents, premiss);
+
+                        if left != right {
+                            parents.insert(right, left);
+                        }
+
+                        if !visited {
+                            work.push(premiss.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut indices = HashMap::new();
+        let mut groups: Vec<Vec<Handle<tags::Theorem>>> = Vec::new();
+
+        for handle in handles {
+            let root = find(&mut parents, handle);
+            let index = *indices.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+
+            groups[index].push(handle.clone());
+        }
+
+        groups
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Parallel exporting and importing.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Serializes the theorems pointed-to by `handles` into at most `workers`
+    /// proof objects, using up to `workers` threads.  Theorems related by
+    /// their recorded derivations are always exported in the same chunk, and
+    /// the theorems of each chunk appear in the order given.  A `workers` of
+    /// zero is treated as one.
+    ///
+    /// The chunks produced depend only on `handles`, `workers` and the runtime
+    /// state, and not on the scheduling of the threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any handle in
+    /// `handles` does not point-to a registered theorem in the runtime state's
+    /// theorem-table.
+    pub fn theorem_export_parallel<T>(
+        &self,
+        handles: &[T],
+        workers: usize,
+    ) -> Result<Vec<ProofObjectChunk>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Exporting {} theorems as proof objects with {} workers.",
+            handles.len(),
+            workers
+        );
+
+        let handles: Vec<Handle<tags::Theorem>> =
+            handles.iter().map(|h| h.borrow().clone()).collect();
+
+        let mut sequents = Vec::new();
+
+        for handle in handles.iter() {
+            sequents.push(Sequent {
+                premisses: self.theorem_split_premisses(handle)?.to_vec(),
+                conclusion: self.theorem_split_conclusion(handle)?,
+            });
+        }
+
+        let groups = self.theorem_dependency_groups(&handles);
+
+        /* NB: each group is dealt to the chunk with the fewest theorems so
+         * far, preferring earlier chunks, and each chunk then keeps its
+         * theorems in the order given.
+         */
+        let workers = worker_count(workers, groups.len());
+        let mut sizes = vec![0usize; workers];
+        let mut assignment = HashMap::new();
+
+        for group in groups.iter() {
+            let (chunk, _size) = sizes
+                .iter()
+                .enumerate()
+                .min_by_key(|(index, size)| (**size, *index))
+                .expect("At least one worker is always used.");
+
+            sizes[chunk] += group.len();
+
+            for handle in group {
+                assignment.insert(handle.clone(), chunk);
+            }
+        }
+
+        let mut batches = vec![(Vec::new(), Vec::new()); workers];
+
+        for (handle, sequent) in handles.into_iter().zip(sequents) {
+            let chunk = assignment[&handle];
+
+            batches[chunk].0.push(handle);
+            batches[chunk].1.push(sequent);
+        }
+
+        batches.retain(|(theorems, _sequents)| !theorems.is_empty());
+
+        let (types, terms) = (self.type_table(), self.term_table());
+
+        let chunks = thread::scope(|scope| {
+            let jobs: Vec<_> = batches
+                .into_iter()
+                .map(|(theorems, sequents)| {
+                    scope.spawn(move || {
+                        let encoder = Encoder::from_tables(types, terms);
+                        let bytes = encode_sequents(encoder, &sequents, None);
+
+                        ProofObjectChunk { theorems, bytes }
+                    })
+                })
+                .collect();
+
+            jobs.into_iter()
+                .map(|job| job.join().expect("Export worker panicked."))
+                .collect::<Vec<_>>()
+        });
+
+        info!("Proof objects exported: {} chunks.", chunks.len());
+
+        Ok(chunks)
+    }
+
+    /// Decodes the proof objects `chunks`, using up to `workers` threads, and
+    /// then registers every type and term that they contain in the runtime
+    /// state, one chunk at a time and in the order given.  Returns the
+    /// sequents of the exported theorems of every chunk, in order.  No
+    /// theorems are registered, and if an error is returned then no kernel
+    /// object from any chunk is kept.  A `workers` of zero is treated as one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that `theorem_import` would return for the first chunk
+    /// that cannot be imported.
+    pub fn theorem_import_parallel<T>(
+        &mut self,
+        chunks: &[T],
+        workers: usize,
+    ) -> Result<Vec<Sequent>, ErrorCode>
+    where
+        T: AsRef<[u8]> + Sync,
+    {
+        info!(
+            "Importing {} proof objects with {} workers.",
+            chunks.len(),
+            workers
+        );
+
+        let workers = worker_count(workers, chunks.len());
+        let stride = chunks.len().div_ceil(workers);
+
+        let decoded = thread::scope(|scope| {
+            let jobs: Vec<_> = chunks
+                .chunks(stride.max(1))
+                .map(|batch| {
+                    scope.spawn(move || {
+                        batch
+                            .iter()
+                            .map(|chunk| decode_chunk(chunk.as_ref()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            jobs.into_iter()
+                .flat_map(|job| job.join().expect("Import worker panicked."))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        self.speculate_begin();
+
+        /* NB: decoding happens away from the runtime state, so only the
+         * registration of each chunk is reported as progress.
+         */
+        let result = self.with_progress(LongOperation::ProofImport, |state| {
+            decoded
+                .iter()
+                .enumerate()
+                .map(|(index, chunk)| {
+                    let sequents = state.commit_chunk(chunk);
+                    state.progress_report(index + 1, decoded.len());
+                    sequents
+                })
+                .collect::<Result<Vec<_>, _>>()
+        });
+
+        self.speculate_end(result.is_ok())?;
+
+        let sequents: Vec<Sequent> = result?.into_iter().flatten().collect();
+
+        info!("Proof objects imported: {} sequents.", sequents.len());
+
+        Ok(sequents)
+    }
+
+    /// Registers every type and term of the decoded proof object `chunk`,
+    /// returning its sequents.
+    fn commit_chunk(
+        &mut self,
+        chunk: &DecodedChunk,
+    ) -> Result<Vec<Sequent>, ErrorCode> {
+        let mut types: Vec<Handle<tags::Type>> = Vec::new();
+
+        for entry in chunk.types.iter() {
+            let handle = match entry {
+                TypeEntry::Variable(name) => self.type_register_variable(*name),
+                TypeEntry::Combination(former, arguments) => {
+                    let arguments =
+                        arguments.iter().map(|a| types[*a].clone()).collect();
+
+                    self.type_register_combination(
+                        Handle::from(*former),
+                        arguments,
+                    )?
+                }
+            };
+
+            types.push(handle);
+        }
+
+        let mut terms: Vec<Handle<tags::Term>> = Vec::new();
+
+        for entry in chunk.terms.iter() {
+            let handle = match entry {
+                TermEntry::Variable(name, tau) => {
+                    self.term_register_variable(*name, types[*tau].clone())?
+                }
+                TermEntry::Constant(constant, tau) => self
+                    .term_register_constant_at_type(
+                        Handle::from(*constant),
+                        types[*tau].clone(),
+                    )?,
+                TermEntry::Application(left, right) => self
+                    .term_register_application(
+                        terms[*left].clone(),
+                        terms[*right].clone(),
+                    )?,
+                TermEntry::Lambda(name, tau, body) => self
+                    .term_register_lambda(
+                        *name,
+                        types[*tau].clone(),
+                        terms[*body].clone(),
+                    )?,
+            };
+
+            terms.push(handle);
+        }
+
+        Ok(chunk
+            .sequents
+            .iter()
+            .map(|(premisses, conclusion)| Sequent {
+                premisses: premisses
+                    .iter()
+                    .map(|p| terms[*p].clone())
+                    .collect(),
+                conclusion: terms[*conclusion].clone(),
+            })
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for the parallel proof object pipelines.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{tags, Handle, Kind, PREALLOCATED_HANDLE_TYPE_PROP},
+        proof_object::{Sequent, PROOF_OBJECT_MAGIC},
+        runtime_state::RuntimeState,
+    };
+
+    /// Registers `count` unrelated theorems `⊢ pᵢ = pᵢ`, and, for each, the
+    /// theorem derived from it by symmetry.
+    fn library(
+        kernel: &mut RuntimeState,
+        count: usize,
+    ) -> Vec<Handle<tags::Theorem>> {
+        kernel.set_proof_recording(true);
+
+        let mut theorems = Vec::new();
+
+        for i in 0..count {
+            let p = kernel
+                .term_register_variable(i as u64, PREALLOCATED_HANDLE_TYPE_PROP)
+                .unwrap();
+            let thm = kernel
+                .theorem_register_reflexivity::<Handle<tags::Term>, _>(p)
+                .unwrap();
+
+            theorems.push(thm.clone());
+            theorems.push(kernel.theorem_register_symmetry(thm).unwrap());
+        }
+
+        theorems
+    }
+
+    /// Returns the sequent of the theorem pointed-to by `handle`.
+    fn sequent(
+        kernel: &RuntimeState,
+        handle: &Handle<tags::Theorem>,
+    ) -> Sequent {
+        Sequent {
+            premisses: kernel.theorem_split_premisses(handle).unwrap().to_vec(),
+            conclusion: kernel.theorem_split_conclusion(handle).unwrap(),
+        }
+    }
+
+    /// Tests that a parallel export partitions the theorems, keeping each
+    /// theorem with those that it was derived from, and that importing the
+    /// chunks yields back the theorems' sequents, in chunk order.
+    #[test]
+    pub fn pipeline_test0() {
+        let mut kernel = RuntimeState::new();
+
+        let theorems = library(&mut kernel, 8);
+        let chunks = kernel.theorem_export_parallel(&theorems, 3).unwrap();
+
+        assert_eq!(chunks.len(), 3);
+
+        let mut exported: Vec<_> =
+            chunks.iter().flat_map(|c| c.theorems.clone()).collect();
+        exported.sort();
+
+        let mut expected = theorems.clone();
+        expected.sort();
+
+        assert_eq!(exported, expected);
+
+        for pair in theorems.chunks(2) {
+            assert!(chunks.iter().any(|c| c.theorems.contains(&pair[0])
+                && c.theorems.contains(&pair[1])));
+        }
+
+        let bytes: Vec<_> = chunks.iter().map(|c| c.bytes.clone()).collect();
+        let sequents = kernel.theorem_import_parallel(&bytes, 3).unwrap();
+
+        let expected: Vec<_> = chunks
+            .iter()
+            .flat_map(|c| c.theorems.iter().map(|t| sequent(&kernel, t)))
+            .collect();
+
+        assert_eq!(sequents, expected);
+    }
+
+    /// Tests that the chunks of a parallel export are ordinary proof objects,
+    /// and that the export does not depend on the scheduling of the workers.
+    #[test]
+    pub fn pipeline_test1() {
+        let mut kernel = RuntimeState::new();
+
+        let theorems = library(&mut kernel, 16);
+        let chunks = kernel.theorem_export_parallel(&theorems, 4).unwrap();
+
+        assert_eq!(
+            kernel.theorem_export_parallel(&theorems, 4),
+            Ok(chunks.clone())
+        );
+
+        for chunk in chunks.iter() {
+            let expected: Vec<_> =
+                chunk.theorems.iter().map(|t| sequent(&kernel, t)).collect();
+
+            assert_eq!(kernel.theorem_import(&chunk.bytes), Ok(expected));
+        }
+
+        let single = kernel.theorem_export_parallel(&theorems, 0).unwrap();
+
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].theorems, theorems);
+    }
+
+    /// Tests that a parallel import is all-or-nothing: if a chunk is malformed,
+    /// or fails to register, then no term from any chunk is kept.
+    #[test]
+    pub fn pipeline_test2() {
+        let mut source = RuntimeState::new();
+
+        let theorems = library(&mut source, 4);
+        let chunks = source.theorem_export_parallel(&theorems, 2).unwrap();
+        let mut bytes: Vec<_> =
+            chunks.iter().map(|c| c.bytes.clone()).collect();
+
+        let mut kernel = RuntimeState::new();
+        let terms = kernel.kernel_enumerate(Kind::Term, 0, usize::MAX);
+
+        let mut truncated = bytes[1].clone();
+        truncated.pop();
+
+        assert_eq!(
+            kernel.theorem_import_parallel(&[bytes[0].clone(), truncated], 2),
+            Err(ErrorCode::MalformedProofObject)
+        );
+        assert_eq!(kernel.kernel_enumerate(Kind::Term, 0, usize::MAX), terms);
+
+        /* A type-combination over a type-former that is not registered. */
+        let mut unregistered = PROOF_OBJECT_MAGIC.to_vec();
+        unregistered.extend(vec![1, 0, 1, 1, 0x7f, 0, 0, 0]);
+        bytes.push(unregistered);
+
+        assert_eq!(
+            kernel.theorem_import_parallel(&bytes, 2),
+            Err(ErrorCode::NoSuchTypeFormerRegistered)
+        );
+        assert_eq!(kernel.kernel_enumerate(Kind::Term, 0, usize::MAX), terms);
+    }
+}
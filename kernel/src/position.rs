@@ -0,0 +1,571 @@
+//! # Positions of subterms
+//!
+//! A *position* addresses a subterm of a term by the sequence of the indices
+//! of the children taken on the way down from the root: the function of an
+//! application `f a` is its child `0`, and its argument, `a`, its child `1`,
+//! whilst the body of a λ-abstraction is its child `0`.  Variables and
+//! constants have no children.  The empty position addresses the term itself.
+//!
+//! Positions let a guest address a subterm beneath a binder, and rewrite it, in
+//! a single call, rather than splitting the term apart, and rebuilding it, one
+//! layer at a time.  As terms are stored in ⍺-normal form, a subterm beneath a
+//! binder mentions the bound variable under its canonical name, which a
+//! replacement may also mention, so that it is captured by the binder.
+//!
+//! Positions also underpin the rewriting of a premiss of a theorem, rather than
+//! its conclusion, with an equation: every occurrence of the left-hand side of
+//! the equation in the premiss is found, and rewritten in turn, and the
+//! rewritten premiss is then swapped in for the original by discharging it and
+//! re-establishing it from the rewritten premiss.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+    term::Term,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Positions, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns the child of the term pointed-to by `handle` with index
+    /// `index`.
+    fn term_child(
+        &self,
+        handle: &Handle<tags::Term>,
+        index: usize,
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        match (self.resolve_term_handle(handle)?, index) {
+            (Term::Application { left, .. }, 0) => Ok(left.clone()),
+            (Term::Application { right, .. }, 1) => Ok(right.clone()),
+            (Term::Lambda { body, .. }, 0) => Ok(body.clone()),
+            _otherwise => Err(ErrorCode::NoSuchPosition),
+        }
+    }
+
+    /// Returns a handle to the subterm of the term pointed-to by `handle` at
+    /// `position`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchPosition)` if `position` does not address
+    /// a subterm of the term pointed-to by `handle`.
+    pub fn term_subterm_at<T>(
+        &self,
+        handle: T,
+        position: &[usize],
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Finding subterm of term with handle {} at position {:?}.",
+            handle.borrow(),
+            position
+        );
+
+        let mut subterm = handle.borrow().clone();
+
+        self.resolve_term_handle(&subterm)?;
+
+        for index in position {
+            subterm = self.term_child(&subterm, *index)?;
+        }
+
+        Ok(subterm)
+    }
+
+    /// Registers the term obtained from the term pointed-to by `handle` by
+    /// replacing its subterm at `position` with the term pointed-to by
+    /// `replacement`.  Returns `Ok(handle)`, where `handle` points-to the new
+    /// term.  Variables of `replacement` that are bound by a λ-abstraction
+    /// enclosing `position` are captured by it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` or
+    /// `replacement` does not point-to any term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchPosition)` if `position` does not address
+    /// a subterm of the term pointed-to by `handle`.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)`, or
+    /// `Err(ErrorCode::NotAFunctionType)`, if the new term is not well-typed,
+    /// as `replacement` has a different type from the subterm that it
+    /// replaces.
+    pub fn term_replace_at<T, U>(
+        &mut self,
+        handle: T,
+        position: &[usize],
+        replacement: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Replacing subterm of term with handle {} at position {:?} with \
+             term with handle {}.",
+            handle.borrow(),
+            position,
+            replacement.borrow()
+        );
+
+        self.resolve_term_handle(replacement.borrow())?;
+
+        let (index, rest) = match position.split_first() {
+            None => {
+                self.resolve_term_handle(handle.borrow())?;
+
+                return Ok(replacement.borrow().clone());
+            }
+            Some(split) => split,
+        };
+
+        let child = self.term_child(handle.borrow(), *index)?;
+        let child = self.term_replace_at(child, rest, replacement)?;
+
+        match self.resolve_term_handle(handle.borrow())?.clone() {
+            Term::Application { right, .. } if *index == 0 => {
+                self.term_register_application(child, right)
+            }
+            Term::Application { left, .. } => {
+                self.term_register_application(left, child)
+            }
+            Term::Lambda { name, tau, .. } => {
+                self.term_register_lambda(name, tau, child)
+            }
+            _otherwise => unreachable!("term has a child, so it has children"),
+        }
+    }
+
+    /// Registers a new theorem object, `Γ ⊢ t = t[p ↦ r]`, from the equation
+    /// `Γ ⊢ l = r`, pointed-to by `equation`, where `t` is the term
+    /// pointed-to by `handle`, whose subterm at the position `p`, `position`,
+    /// is `l`, and `t[p ↦ r]` is `t` with that subterm replaced by `r`.
+    /// Returns `Ok(handle)`, where `handle` points-to the new theorem.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `equation` does
+    /// not point-to any theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the
+    /// theorem pointed-to by `equation` is not an equality.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchPosition)` if `position` does not address
+    /// a subterm of the term pointed-to by `handle`.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the subterm at `position` is
+    /// not `l`.
+    pub fn theorem_register_rewrite_at<T, U>(
+        &mut self,
+        equation: T,
+        handle: U,
+        position: &[usize],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Rewriting term with handle {} at position {:?} with equation \
+             with handle {}.",
+            handle.borrow(),
+            position,
+            equation.borrow()
+        );
+
+        let conclusion = self.theorem_split_conclusion(equation.borrow())?;
+        let (left, _right) = self.term_split_equality(conclusion)?;
+        let left = left.clone();
+
+        let subterm = self.term_subterm_at(handle.borrow(), position)?;
+
+        if subterm != left {
+            return Err(self.shape_mismatch_terms(
+                "the subterm at the position is not the left-hand side of \
+                 the equation.",
+                &left,
+                &subterm,
+            ));
+        }
+
+        self.derived_congruence_at(
+            handle.borrow(),
+            position,
+            equation.borrow().clone(),
+        )
+    }
+
+    /// Registers a new theorem object, `Γ ∪ Δ ∪ {ψ[l ↦ r]} ⊢ ɸ`, from the
+    /// theorem `Γ ∪ {ψ} ⊢ ɸ`, pointed-to by `handle`, and the equation
+    /// `Δ ⊢ l = r`, pointed-to by `equation`, where `ψ` is the premiss of the
+    /// theorem with index `index`, counting premisses in the order returned by
+    /// `theorem_split_premisses`, and `ψ[l ↦ r]` is `ψ` with every occurrence
+    /// of `l` replaced by `r`.  Returns `Ok(handle)`, where `handle` points-to
+    /// the new theorem.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` or
+    /// `equation` does not point-to any theorem in the runtime state's
+    /// theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchPremiss)` if `index` is not less than the
+    /// number of premisses of the theorem pointed-to by `handle`.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the
+    /// theorem pointed-to by `equation` is not an equality.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `l` does not occur in `ψ`, or
+    /// if an occurrence of `l` mentions a variable bound by a λ-abstraction
+    /// enclosing it that is free in `Δ`.
+    pub fn theorem_register_rewrite_hypothesis<T, U>(
+        &mut self,
+        handle: T,
+        index: usize,
+        equation: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Rewriting premiss {} of theorem with handle {} with equation \
+             with handle {}.",
+            index,
+            handle.borrow(),
+            equation.borrow()
+        );
+
+        let hypothesis = self
+            .theorem_split_premisses(handle.borrow())?
+            .get(index)
+            .cloned()
+            .ok_or(ErrorCode::NoSuchPremiss)?;
+
+        let conclusion = self.theorem_split_conclusion(equation.borrow())?;
+        let (left, _right) = self.term_split_equality(conclusion)?;
+        let left = left.clone();
+
+        let mut positions = Vec::new();
+
+        self.term_occurrences(
+            &hypothesis,
+            &left,
+            &mut Vec::new(),
+            &mut positions,
+        )?;
+
+        if positions.is_empty() {
+            return Err(self.shape_mismatch_terms(
+                "the left-hand side of the equation does not occur in the \
+                 premiss.",
+                &left,
+                &hypothesis,
+            ));
+        }
+
+        /* 1. Derive `Δ ⊢ ψ = ψ[l ↦ r]`, rewriting one occurrence at a time.
+         *    Occurrences are never nested, so rewriting one leaves the
+         *    positions of the others intact.
+         */
+        let mut rewritten = hypothesis.clone();
+        let mut rewrite: Option<Handle<tags::Theorem>> = None;
+
+        for position in &positions {
+            let step = self.derived_congruence_at(
+                &rewritten,
+                position,
+                equation.borrow().clone(),
+            )?;
+
+            let (_left, right) = self
+                .term_split_equality(self.theorem_split_conclusion(&step)?)?;
+            rewritten = right.clone();
+
+            rewrite = Some(match rewrite {
+                None => step,
+                Some(rewrite) => {
+                    self.theorem_register_transitivity(rewrite, step)?
+                }
+            });
+        }
+
+        let rewrite = rewrite.expect("a premiss with an occurrence was found");
+
+        /* 2. Re-establish `Δ ∪ {ψ[l ↦ r]} ⊢ ψ` from the rewritten premiss. */
+        let symmetry = self.theorem_register_symmetry(rewrite)?;
+        let assumption = self.theorem_register_assumption(rewritten)?;
+        let recovered = self.derived_equality_mp(&symmetry, &assumption)?;
+
+        /* 3. Discharge `ψ`, and cut the re-established `ψ` in for it. */
+        let discharged = self.theorem_register_implication_introduction(
+            handle.borrow(),
+            hypothesis,
+        )?;
+
+        self.theorem_register_implication_elimination(discharged, recovered)
+    }
+
+    /// Collects the position of every occurrence of the term `subterm` within
+    /// the term `term` into `positions`, where `prefix` is the position of
+    /// `term` itself.  Occurrences within an occurrence are not collected.
+    fn term_occurrences(
+        &self,
+        term: &Handle<tags::Term>,
+        subterm: &Handle<tags::Term>,
+        prefix: &mut Vec<usize>,
+        positions: &mut Vec<Vec<usize>>,
+    ) -> Result<(), ErrorCode> {
+        if term == subterm {
+            positions.push(prefix.clone());
+
+            return Ok(());
+        }
+
+        let children = match self.resolve_term_handle(term)? {
+            Term::Application { left, right, .. } => {
+                vec![left.clone(), right.clone()]
+            }
+            Term::Lambda { body, .. } => vec![body.clone()],
+            _otherwise => Vec::new(),
+        };
+
+        for (index, child) in children.iter().enumerate() {
+            prefix.push(index);
+            self.term_occurrences(child, subterm, prefix, positions)?;
+            prefix.pop();
+        }
+
+        Ok(())
+    }
+
+    /// Derives `Γ ⊢ t = t[p ↦ s']` from `Γ ⊢ s = s'`, pointed-to by `handle`,
+    /// where `s` is the subterm of the term `t`, pointed-to by `term`, at the
+    /// position `p`, `position`, by a congruence for each step of `position`.
+    pub(crate) fn derived_congruence_at(
+        &mut self,
+        term: &Handle<tags::Term>,
+        position: &[usize],
+        handle: Handle<tags::Theorem>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let (index, rest) = match position.split_first() {
+            None => return Ok(handle),
+            Some(split) => split,
+        };
+
+        let child = self.term_child(term, *index)?;
+        let thm = self.derived_congruence_at(&child, rest, handle)?;
+
+        match self.resolve_term_handle(term)?.clone() {
+            Term::Application { right, .. } if *index == 0 => {
+                let reflexivity = self
+                    .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                        right,
+                    )?;
+
+                self.theorem_register_application(thm, reflexivity)
+            }
+            Term::Application { left, .. } => {
+                let reflexivity = self
+                    .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                        left,
+                    )?;
+
+                self.theorem_register_application(reflexivity, thm)
+            }
+            Term::Lambda { name, tau, .. } => {
+                self.theorem_register_lambda(name, tau, thm)
+            }
+            _otherwise => unreachable!("term has a child, so it has children"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests finding, and replacing, a subterm beneath a binder.
+    #[test]
+    pub fn position0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let body = state
+            .term_register_conjunction(x.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let term = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        assert_eq!(
+            state.term_subterm_at(&term, &[0, 1]),
+            Ok(PREALLOCATED_HANDLE_TERM_TRUE)
+        );
+        assert_eq!(state.term_subterm_at(&term, &[]), Ok(term.clone()));
+        assert_eq!(
+            state.term_subterm_at(&term, &[1]),
+            Err(ErrorCode::NoSuchPosition)
+        );
+        assert_eq!(
+            state.term_subterm_at(&term, &[0, 1, 0]),
+            Err(ErrorCode::NoSuchPosition)
+        );
+
+        /* NB: the bound variable, found beneath the binder, is captured by it
+         * when it replaces another subterm beneath the binder.
+         */
+        let bound = state.term_subterm_at(&term, &[0, 0, 1]).unwrap();
+        let replaced = state.term_replace_at(&term, &[0, 1], &bound).unwrap();
+
+        let body = state.term_register_conjunction(x.clone(), x).unwrap();
+        let expected = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        assert_eq!(replaced, expected);
+        assert_eq!(
+            state.term_replace_at(
+                &term,
+                &[0, 0],
+                PREALLOCATED_HANDLE_TERM_TRUE
+            ),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    /// Tests rewriting beneath a binder with an equation.
+    #[test]
+    pub fn position1() {
+        let mut state = RuntimeState::new();
+
+        let (c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let body = state.term_register_conjunction(x.clone(), c).unwrap();
+        let term = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        let thm = state
+            .theorem_register_rewrite_at(&defn, &term, &[0, 1])
+            .unwrap();
+        let conclusion = state.theorem_split_conclusion(&thm).unwrap();
+        let (left, right) = state.term_split_equality(conclusion).unwrap();
+        let left = left.clone();
+        let right = right.clone();
+
+        let body = state
+            .term_register_conjunction(x, PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+        let expected = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        assert_eq!(left, term);
+        assert_eq!(right, expected);
+        assert_eq!(
+            state.theorem_register_rewrite_at(&defn, &term, &[0, 0, 1]),
+            Err(ErrorCode::ShapeMismatch)
+        );
+    }
+
+    /// Tests rewriting every occurrence of a defined constant in a premiss,
+    /// leaving the conclusion untouched.
+    #[test]
+    pub fn position2() {
+        let mut state = RuntimeState::new();
+
+        let (c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+
+        let q = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let right = state
+            .term_register_conjunction(q.clone(), c.clone())
+            .unwrap();
+        let hypothesis = state.term_register_conjunction(c, right).unwrap();
+
+        let thm = state
+            .theorem_register_assumption(hypothesis.clone())
+            .unwrap();
+        let rewritten = state
+            .theorem_register_rewrite_hypothesis(&thm, 0, &defn)
+            .unwrap();
+
+        let right = state
+            .term_register_conjunction(
+                q.clone(),
+                PREALLOCATED_HANDLE_TERM_FALSE,
+            )
+            .unwrap();
+        let expected = state
+            .term_register_conjunction(PREALLOCATED_HANDLE_TERM_FALSE, right)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_premisses(&rewritten),
+            Ok(&[expected][..])
+        );
+        assert_eq!(state.theorem_split_conclusion(&rewritten), Ok(hypothesis));
+
+        let other = state.theorem_register_assumption(q).unwrap();
+
+        assert_eq!(
+            state.theorem_register_rewrite_hypothesis(&thm, 1, &defn),
+            Err(ErrorCode::NoSuchPremiss)
+        );
+        assert_eq!(
+            state.theorem_register_rewrite_hypothesis(&other, 0, &defn),
+            Err(ErrorCode::ShapeMismatch)
+        );
+    }
+}
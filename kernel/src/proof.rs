@@ -0,0 +1,429 @@
+//! # Proof terms
+//!
+//! Optionally, the kernel records an LCF-style *proof term* alongside every
+//! theorem that it registers, describing how the theorem was derived: the
+//! inference rule that was applied, and the arguments that were passed to it.
+//! Premisses are referred to by the handles of the theorems that they were
+//! derived as, so the proof terms of a theorem and of its premisses form a
+//! graph with shared subproofs, mirroring the sharing of the theorems
+//! themselves.
+//!
+//! Proof terms are not trusted: the kernel's `proof_check` function
+//! re-validates a proof term by re-applying every recorded rule to its recorded
+//! arguments, and comparing the results against the recorded theorems.  Proof
+//! terms are therefore the foundation for exporting, replaying, and
+//! independently checking proofs.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    handle::{tags, Handle},
+    name::Name,
+};
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Inference rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The kernel's inference rules, each corresponding to one of the kernel's
+/// theorem registration functions.  The arguments recorded for each rule are
+/// listed in the order in which they are passed to that function.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    /// The assumption rule, with the assumed term as argument.
+    Assumption,
+    /// The weakening rule, with the added term and the theorem as arguments.
+    Weaken,
+    /// The reflexivity rule, with the term as argument.
+    Reflexivity,
+    /// The symmetry rule, with the theorem as argument.
+    Symmetry,
+    /// The transitivity rule, with the two theorems as arguments.
+    Transitivity,
+    /// The application congruence rule, with the two theorems as arguments.
+    Application,
+    /// The conditional congruence rule, with the three theorems as arguments.
+    ConditionalCongruence,
+    /// The let congruence rule, with the two theorems as arguments.
+    LetCongruence,
+    /// The first projection axiom, with the two components as arguments.
+    FirstProjection,
+    /// The second projection axiom, with the two components as arguments.
+    SecondProjection,
+    /// The surjective pairing axiom, with the pair as argument.
+    SurjectivePairing,
+    /// The definition of set membership, with the element and set as
+    /// arguments.
+    MembershipDefinition,
+    /// The definition of set comprehension, with the set as argument.
+    ComprehensionDefinition,
+    /// The definition of the empty set, with the element type as argument.
+    EmptySetDefinition,
+    /// The definition of the universal set, with the element type as argument.
+    UniversalSetDefinition,
+    /// The definition of set union, with the two sets as arguments.
+    UnionDefinition,
+    /// The definition of set intersection, with the two sets as arguments.
+    IntersectionDefinition,
+    /// The definition of the subset relation, with the two sets as arguments.
+    SubsetDefinition,
+    /// The lambda congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    Lambda,
+    /// The beta-reduction axiom, with the application as argument.
+    Beta,
+    /// The eta-reduction axiom, with the lambda-abstraction as argument.
+    Eta,
+    /// The iff introduction rule, with the two theorems as arguments.
+    IffIntroduction,
+    /// The iff elimination rule, with the theorem as argument.
+    IffLeftElimination,
+    /// The truth introduction rule, without arguments.
+    TruthIntroduction,
+    /// The falsity elimination rule, with the theorem and the conclusion as
+    /// arguments.
+    FalsityElimination,
+    /// The conjunction introduction rule, with the two theorems as arguments.
+    ConjunctionIntroduction,
+    /// The left conjunction elimination rule, with the theorem as argument.
+    ConjunctionLeftElimination,
+    /// The right conjunction elimination rule, with the theorem as argument.
+    ConjunctionRightElimination,
+    /// The left disjunction introduction rule, with the theorem and the
+    /// introduced disjunct as arguments.
+    DisjunctionLeftIntroduction,
+    /// The right disjunction introduction rule, with the theorem and the
+    /// introduced disjunct as arguments.
+    DisjunctionRightIntroduction,
+    /// The disjunction elimination rule, with the three theorems as arguments.
+    DisjunctionElimination,
+    /// The implication introduction rule, with the theorem and the discharged
+    /// premiss as arguments.
+    ImplicationIntroduction,
+    /// The implication elimination rule, with the two theorems as arguments.
+    ImplicationElimination,
+    /// The substitution rule, with the theorem followed by the name, type, and
+    /// replacement term of every binding of the substitution as arguments.
+    Substitute,
+    /// The type substitution rule, with the theorem followed by the name and
+    /// replacement type of every binding of the substitution as arguments.
+    TypeSubstitute,
+    /// The negation introduction rule, with the theorem and the negated term
+    /// as arguments.
+    NegationIntroduction,
+    /// The negation elimination rule, with the two theorems as arguments.
+    NegationElimination,
+    /// The universal introduction rule, with the name and type of the
+    /// generalised variable and the theorem as arguments.
+    ForallIntroduction,
+    /// The universal elimination rule, with the theorem and the instantiating
+    /// term as arguments.
+    ForallElimination,
+    /// A definitional theorem, with the defined constant, lifted into a term,
+    /// and the definition as arguments.
+    Definition,
+    /// A theorem characterising the abstraction and representation functions
+    /// of a defined type, with the statement of the theorem as argument.
+    TypeDefinition,
+    /// The defining equation of a function defined by well-founded recursion,
+    /// with the statement of the equation as argument.
+    RecursiveDefinition,
+    /// The universal congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    ForallCongruence,
+    /// The existential congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    ExistsCongruence,
+    /// The existential introduction rule, with the theorem, the existential
+    /// and the witness as arguments.
+    ExistsIntroduction,
+    /// The existential elimination rule, with the existential theorem, the
+    /// eigenvariable and the theorem discharging the instance as arguments.
+    ExistsElimination,
+    /// A goal proved by the linear arithmetic decision procedure, from the
+    /// experimental `linear-arith` feature, with the goal as argument.
+    LinearArith,
+    /// An axiom postulated by the host, with the statement of the axiom as
+    /// argument.
+    Axiom,
+}
+
+/// Conversion from an inference rule into a `u64`, for ABI transport.
+impl From<Rule> for u64 {
+    fn from(rule: Rule) -> u64 {
+        match rule {
+            Rule::Assumption => 0,
+            Rule::Weaken => 1,
+            Rule::Reflexivity => 2,
+            Rule::Symmetry => 3,
+            Rule::Transitivity => 4,
+            Rule::Application => 5,
+            Rule::ConditionalCongruence => 6,
+            Rule::LetCongruence => 7,
+            Rule::FirstProjection => 8,
+            Rule::SecondProjection => 9,
+            Rule::SurjectivePairing => 10,
+            Rule::MembershipDefinition => 11,
+            Rule::ComprehensionDefinition => 12,
+            Rule::EmptySetDefinition => 13,
+            Rule::UniversalSetDefinition => 14,
+            Rule::UnionDefinition => 15,
+            Rule::IntersectionDefinition => 16,
+            Rule::SubsetDefinition => 17,
+            Rule::Lambda => 18,
+            Rule::Beta => 19,
+            Rule::Eta => 20,
+            Rule::IffIntroduction => 21,
+            Rule::IffLeftElimination => 22,
+            Rule::TruthIntroduction => 23,
+            Rule::FalsityElimination => 24,
+            Rule::ConjunctionIntroduction => 25,
+            Rule::ConjunctionLeftElimination => 26,
+            Rule::ConjunctionRightElimination => 27,
+            Rule::DisjunctionLeftIntroduction => 28,
+            Rule::DisjunctionRightIntroduction => 29,
+            Rule::DisjunctionElimination => 30,
+            Rule::ImplicationIntroduction => 31,
+            Rule::ImplicationElimination => 32,
+            Rule::Substitute => 33,
+            Rule::TypeSubstitute => 34,
+            Rule::NegationIntroduction => 35,
+            Rule::NegationElimination => 36,
+            Rule::ForallElimination => 37,
+            Rule::Definition => 38,
+            Rule::ForallIntroduction => 39,
+            Rule::TypeDefinition => 40,
+            Rule::RecursiveDefinition => 41,
+            Rule::ForallCongruence => 42,
+            Rule::ExistsCongruence => 43,
+            Rule::LinearArith => 44,
+            Rule::Axiom => 45,
+            Rule::ExistsIntroduction => 46,
+            Rule::ExistsElimination => 47,
+        }
+    }
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into an
+/// inference rule.
+impl TryFrom<u64> for Rule {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Rule::Assumption),
+            1 => Ok(Rule::Weaken),
+            2 => Ok(Rule::Reflexivity),
+            3 => Ok(Rule::Symmetry),
+            4 => Ok(Rule::Transitivity),
+            5 => Ok(Rule::Application),
+            6 => Ok(Rule::ConditionalCongruence),
+            7 => Ok(Rule::LetCongruence),
+            8 => Ok(Rule::FirstProjection),
+            9 => Ok(Rule::SecondProjection),
+            10 => Ok(Rule::SurjectivePairing),
+            11 => Ok(Rule::MembershipDefinition),
+            12 => Ok(Rule::ComprehensionDefinition),
+            13 => Ok(Rule::EmptySetDefinition),
+            14 => Ok(Rule::UniversalSetDefinition),
+            15 => Ok(Rule::UnionDefinition),
+            16 => Ok(Rule::IntersectionDefinition),
+            17 => Ok(Rule::SubsetDefinition),
+            18 => Ok(Rule::Lambda),
+            19 => Ok(Rule::Beta),
+            20 => Ok(Rule::Eta),
+            21 => Ok(Rule::IffIntroduction),
+            22 => Ok(Rule::IffLeftElimination),
+            23 => Ok(Rule::TruthIntroduction),
+            24 => Ok(Rule::FalsityElimination),
+            25 => Ok(Rule::ConjunctionIntroduction),
+            26 => Ok(Rule::ConjunctionLeftElimination),
+            27 => Ok(Rule::ConjunctionRightElimination),
+            28 => Ok(Rule::DisjunctionLeftIntroduction),
+            29 => Ok(Rule::DisjunctionRightIntroduction),
+            30 => Ok(Rule::DisjunctionElimination),
+            31 => Ok(Rule::ImplicationIntroduction),
+            32 => Ok(Rule::ImplicationElimination),
+            33 => Ok(Rule::Substitute),
+            34 => Ok(Rule::TypeSubstitute),
+            35 => Ok(Rule::NegationIntroduction),
+            36 => Ok(Rule::NegationElimination),
+            37 => Ok(Rule::ForallElimination),
+            38 => Ok(Rule::Definition),
+            39 => Ok(Rule::ForallIntroduction),
+            40 => Ok(Rule::TypeDefinition),
+            41 => Ok(Rule::RecursiveDefinition),
+            42 => Ok(Rule::ForallCongruence),
+            43 => Ok(Rule::ExistsCongruence),
+            44 => Ok(Rule::LinearArith),
+            45 => Ok(Rule::Axiom),
+            46 => Ok(Rule::ExistsIntroduction),
+            47 => Ok(Rule::ExistsElimination),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Proof terms, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The arguments passed to an inference rule.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ProofArgument {
+    /// A name, for example of a bound variable.
+    Name(Name),
+    /// A handle to a type.
+    Type(Handle<tags::Type>),
+    /// A handle to a term.
+    Term(Handle<tags::Term>),
+    /// A handle to a theorem, used as a premiss of the rule.
+    Theorem(Handle<tags::Theorem>),
+}
+
+impl ProofArgument {
+    /// Returns the argument as a raw machine word, for ABI transport: either
+    /// the name, or the handle.  The kind of each argument is determined by
+    /// the inference rule that it is passed to.
+    pub fn raw(&self) -> u64 {
+        match self {
+            ProofArgument::Name(name) => *name,
+            ProofArgument::Type(handle) => **handle as u64,
+            ProofArgument::Term(handle) => **handle as u64,
+            ProofArgument::Theorem(handle) => **handle as u64,
+        }
+    }
+}
+
+/// Proof terms record the inference rule, and the arguments passed to it, that
+/// derived a theorem.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Proof {
+    /// The inference rule that was applied.
+    rule: Rule,
+    /// The arguments passed to the inference rule.
+    arguments: Vec<ProofArgument>,
+    /// A handle to the theorem that was derived.
+    theorem: Handle<tags::Theorem>,
+}
+
+impl Proof {
+    /// Creates a new proof term, recording that `theorem` was derived by
+    /// applying `rule` to `arguments`.
+    #[inline]
+    pub fn new(
+        rule: Rule,
+        arguments: Vec<ProofArgument>,
+        theorem: Handle<tags::Theorem>,
+    ) -> Self {
+        Proof {
+            rule,
+            arguments,
+            theorem,
+        }
+    }
+
+    /// Returns the inference rule that was applied.
+    #[inline]
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns the arguments passed to the inference rule.
+    #[inline]
+    pub fn arguments(&self) -> &[ProofArgument] {
+        &self.arguments
+    }
+
+    /// Returns a handle to the theorem that was derived.
+    #[inline]
+    pub fn theorem(&self) -> &Handle<tags::Theorem> {
+        &self.theorem
+    }
+
+    /// Returns the `index`th argument passed to the inference rule, if it is a
+    /// name.
+    pub fn argument_name(&self, index: usize) -> Option<Name> {
+        match self.arguments.get(index) {
+            Some(ProofArgument::Name(name)) => Some(*name),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the `index`th argument passed to the inference rule, if it is a
+    /// handle to a type.
+    pub fn argument_type(&self, index: usize) -> Option<Handle<tags::Type>> {
+        match self.arguments.get(index) {
+            Some(ProofArgument::Type(handle)) => Some(handle.clone()),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the `index`th argument passed to the inference rule, if it is a
+    /// handle to a term.
+    pub fn argument_term(&self, index: usize) -> Option<Handle<tags::Term>> {
+        match self.arguments.get(index) {
+            Some(ProofArgument::Term(handle)) => Some(handle.clone()),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the `index`th argument passed to the inference rule, if it is a
+    /// handle to a theorem.
+    pub fn argument_theorem(
+        &self,
+        index: usize,
+    ) -> Option<Handle<tags::Theorem>> {
+        match self.arguments.get(index) {
+            Some(ProofArgument::Theorem(handle)) => Some(handle.clone()),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the handles of the theorems used as premisses by the rule.
+    pub fn premisses(&self) -> impl Iterator<Item = &Handle<tags::Theorem>> {
+        self.arguments.iter().filter_map(|argument| match argument {
+            ProofArgument::Theorem(handle) => Some(handle),
+            _otherwise => None,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for proof-term related functionality.
+#[cfg(test)]
+mod test {
+    use crate::proof::Rule;
+    use std::convert::TryFrom;
+
+    /// Tests that every inference rule survives conversion to a `u64` and
+    /// back again.
+    #[test]
+    pub fn rule0() {
+        let mut count = 0;
+
+        while let Ok(rule) = Rule::try_from(count) {
+            assert_eq!(u64::from(rule), count);
+            count += 1;
+        }
+
+        assert_eq!(count, 48);
+    }
+}
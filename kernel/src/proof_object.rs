@@ -0,0 +1,1323 @@
+//! # Serialized proof objects
+//!
+//! Theorems are exported from the kernel as *proof objects*: a compact binary
+//! encoding of the sequents of a collection of theorems, together with every
+//! type and term that those sequents mention.  The kernel maximally shares
+//! types and terms, and the encoding preserves this sharing: every distinct
+//! type and term is serialized exactly once, in a table, and every later
+//! occurrence is written as a back-reference into that table.  As a result,
+//! the size of a proof object is proportional to the number of *distinct*
+//! subterms of the exported theorems, rather than to the size of their
+//! unfolded syntax trees, and large proofs with repeated structure shrink by
+//! orders of magnitude over a naive tree encoding.
+//!
+//! The format is as follows, where all integers are unsigned LEB128 varints:
+//!
+//! ```text
+//! magic    : "SPVO"
+//! version  : u8
+//! flags    : u8
+//! types    : count, then `count` type entries
+//! terms    : count, then `count` term entries
+//! theorems : count, then `count` theorem entries
+//! handles  : a handle per type, term and theorem entry, iff bit 0 of `flags`
+//!            is set
+//! ```
+//!
+//! A type entry is either `0, name` (a type-variable) or `1, former, arity,
+//! arguments...` (a type-combination).  A term entry is one of `0, name, type`
+//! (a variable), `1, constant, type` (a constant), `2, left, right` (an
+//! application), or `3, name, type, body` (a λ-abstraction).  A theorem entry
+//! is `count, premisses..., conclusion`.  Type-former and constant handles are
+//! written as raw kernel handles.  All other references are indices into the
+//! type or term table, and must point *backwards*, to an entry that has already
+//! been read, so that decoding is a single linear pass.
+//!
+//! Bit 0 of the `flags` byte marks a proof object carrying a *handle section*:
+//! the handles that every type, term and theorem had in the exporting runtime
+//! state, in table order.  Handles are only meaningful within the runtime
+//! state that issued them, so importing such a proof object into another, or
+//! into the same runtime state after a reset, yields a *remapping table* from
+//! the exported handles to the imported objects, with which prover-space code
+//! can update the handles that it retained.
+//!
+//! Bit 1 of the `flags` byte marks a *framed* proof object, in which everything
+//! after the flags byte is compressed with zstd.  Framing is only supported
+//! when the kernel is built with the `zstd` feature: `proof_object_frame`
+//! frames an exported proof object, and importing unframes it transparently.
+//! Without the feature, framed proof objects are rejected, as are proof objects
+//! setting any other bit of the `flags` byte.
+//!
+//! Importing a proof object re-registers its types and terms through the
+//! kernel's usual, checked, registration functions and returns the imported
+//! *sequents*.  It never registers theorems: a serialized sequent is only a
+//! claim, and must be re-proved before it may be used as a theorem.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type,
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    observer::LongOperation,
+    runtime_state::RuntimeState,
+    term::Term,
+};
+use log::info;
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{BTreeMap, HashMap},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Format constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The magic bytes with which every serialized proof object begins.
+pub const PROOF_OBJECT_MAGIC: &[u8; 4] = b"SPVO";
+/// The version of the proof object format produced by this kernel.
+pub const PROOF_OBJECT_VERSION: u8 = 1;
+
+/// Flag of a proof object carrying a handle section.
+pub(crate) const FLAG_HANDLES: u8 = 1;
+/// Flag of a proof object framed with zstd.
+#[cfg(feature = "zstd")]
+pub(crate) const FLAG_ZSTD: u8 = 2;
+
+/// The flags that a proof object accepted by this kernel may set.
+#[cfg(feature = "zstd")]
+const FLAGS_PERMITTED: u8 = FLAG_HANDLES | FLAG_ZSTD;
+/// The flags that a proof object accepted by this kernel may set.
+#[cfg(not(feature = "zstd"))]
+const FLAGS_PERMITTED: u8 = FLAG_HANDLES;
+
+/// The maximum size, in bytes, of the body of a framed proof object once
+/// unframed, bounding the memory that a small, malicious, proof object can
+/// make the kernel allocate.
+#[cfg(feature = "zstd")]
+pub const PROOF_OBJECT_MAXIMUM_UNFRAMED_SIZE: u64 = 1 << 28;
+
+/// Tag of a type-variable entry in the type-table.
+pub(crate) const TAG_TYPE_VARIABLE: u8 = 0;
+/// Tag of a type-combination entry in the type-table.
+pub(crate) const TAG_TYPE_COMBINATION: u8 = 1;
+/// Tag of a variable entry in the term-table.
+pub(crate) const TAG_TERM_VARIABLE: u8 = 0;
+/// Tag of a constant entry in the term-table.
+pub(crate) const TAG_TERM_CONSTANT: u8 = 1;
+/// Tag of an application entry in the term-table.
+pub(crate) const TAG_TERM_APPLICATION: u8 = 2;
+/// Tag of a λ-abstraction entry in the term-table.
+pub(crate) const TAG_TERM_LAMBDA: u8 = 3;
+
+////////////////////////////////////////////////////////////////////////////////
+// Sequents.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The statement of a theorem, as recovered from a proof object: a list of
+/// premisses and a conclusion, all of which are registered terms.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Sequent {
+    /// The premisses of the sequent.
+    pub premisses: Vec<Handle<tags::Term>>,
+    /// The conclusion of the sequent.
+    pub conclusion: Handle<tags::Term>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Remapping tables.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The correspondence between the handles of a proof object's objects in the
+/// runtime state that exported it, and their counterparts in the runtime
+/// state that imported it.  Types and terms map to the handles of the imported
+/// types and terms, whereas theorems, which importing never registers, map to
+/// the position of their sequent amongst those imported.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Remapping {
+    /// The imported handle of every type and term, indexed by its exported
+    /// handle.  Handles are never issued twice, whatever the kind of object,
+    /// so the two kinds share a table.
+    objects: BTreeMap<usize, usize>,
+    /// The position of the sequent of every theorem, indexed by its exported
+    /// handle.
+    theorems: BTreeMap<usize, usize>,
+}
+
+impl Remapping {
+    /// Returns the handle, in the importing runtime state, of the type or term
+    /// with handle `handle` in the exporting runtime state, or `None` if the
+    /// proof object carried no such type or term.
+    #[inline]
+    pub fn remap<T>(&self, handle: &Handle<T>) -> Option<Handle<T>>
+    where
+        T: tags::IsTag,
+    {
+        self.objects.get(&**handle).map(|h| Handle::from(*h))
+    }
+
+    /// Returns the position, amongst the imported sequents, of the sequent of
+    /// the theorem with handle `handle` in the exporting runtime state, or
+    /// `None` if the proof object carried no such theorem.
+    #[inline]
+    pub fn theorem<T>(&self, handle: T) -> Option<usize>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.theorems.get(&**handle.borrow()).cloned()
+    }
+
+    /// Returns every pair of an exported handle of a type or term, and its
+    /// imported handle, in ascending order of exported handle.
+    #[inline]
+    pub fn objects(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.objects.iter().map(|(old, new)| (*old, *new))
+    }
+
+    /// Returns every pair of an exported handle of a theorem, and the position
+    /// of its sequent, in ascending order of exported handle.
+    #[inline]
+    pub fn theorems(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.theorems.iter().map(|(old, index)| (*old, *index))
+    }
+
+    /// Returns `true` iff the remapping table is empty, as it is for proof
+    /// objects without a handle section.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty() && self.theorems.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Varints.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Appends the unsigned LEB128 encoding of `value` to `buffer`.
+pub(crate) fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// A cursor over the bytes of a proof object being decoded.
+pub(crate) struct Reader<'a> {
+    /// The raw bytes of the proof object.
+    bytes: &'a [u8],
+    /// The offset of the next byte to be read.
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Returns a new reader positioned at the first byte of `bytes`.
+    #[inline]
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, offset: 0 }
+    }
+
+    /// Reads a single byte.
+    pub(crate) fn read_u8(&mut self) -> Result<u8, ErrorCode> {
+        let byte = *self
+            .bytes
+            .get(self.offset)
+            .ok_or(ErrorCode::MalformedProofObject)?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    /// Reads an unsigned LEB128 varint, rejecting encodings which overflow a
+    /// `u64`.
+    pub(crate) fn read_varint(&mut self) -> Result<u64, ErrorCode> {
+        let mut value: u64 = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.read_u8()?;
+
+            if shift == 63 && byte > 1 {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift += 7;
+
+            if shift > 63 {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+        }
+    }
+
+    /// Reads a back-reference, which must be strictly less than `bound`, the
+    /// number of entries of the referenced table read so far.
+    pub(crate) fn read_reference(
+        &mut self,
+        bound: usize,
+    ) -> Result<usize, ErrorCode> {
+        let index = self.read_varint()?;
+
+        if index < bound as u64 {
+            Ok(index as usize)
+        } else {
+            Err(ErrorCode::MalformedProofObject)
+        }
+    }
+
+    /// Reads the header of a serialized object, failing unless it begins with
+    /// `magic`, followed by `version` and a flags byte setting no flags
+    /// outside of `permitted`.  Returns the flags byte.
+    pub(crate) fn read_header_with_flags(
+        &mut self,
+        magic: &[u8; 4],
+        version: u8,
+        permitted: u8,
+    ) -> Result<u8, ErrorCode> {
+        for m in magic.iter() {
+            if self.read_u8()? != *m {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+        }
+
+        if self.read_u8()? != version {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        let flags = self.read_u8()?;
+
+        if flags & !permitted != 0 {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        Ok(flags)
+    }
+
+    /// Returns the bytes of the proof object that have not yet been consumed.
+    #[inline]
+    pub(crate) fn remainder(&self) -> &'a [u8] {
+        &self.bytes[self.offset..]
+    }
+
+    /// Returns `true` iff every byte of the proof object has been consumed.
+    #[inline]
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.offset == self.bytes.len()
+    }
+
+    /// Returns the number of bytes read so far, and the total number of bytes,
+    /// for reporting progress.
+    #[inline]
+    pub(crate) fn progress(&self) -> (usize, usize) {
+        (self.offset, self.bytes.len())
+    }
+}
+
+/// Reads the header of the proof object `bytes`, returning its flags byte and
+/// its body: everything after the header, decompressed if the proof object is
+/// framed.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::MalformedProofObject)` if `bytes` has the wrong
+/// magic bytes, version or flags, or if the proof object is framed and its
+/// body is not a zstd frame, or decompresses to more than
+/// `PROOF_OBJECT_MAXIMUM_UNFRAMED_SIZE` bytes.
+pub(crate) fn unframe(bytes: &[u8]) -> Result<(u8, Cow<'_, [u8]>), ErrorCode> {
+    let mut reader = Reader::new(bytes);
+
+    let flags = reader.read_header_with_flags(
+        PROOF_OBJECT_MAGIC,
+        PROOF_OBJECT_VERSION,
+        FLAGS_PERMITTED,
+    )?;
+
+    #[cfg(feature = "zstd")]
+    if flags & FLAG_ZSTD != 0 {
+        use std::io::Read;
+
+        let mut body = Vec::new();
+
+        zstd::stream::read::Decoder::new(reader.remainder())
+            .and_then(|decoder| {
+                decoder
+                    .take(PROOF_OBJECT_MAXIMUM_UNFRAMED_SIZE + 1)
+                    .read_to_end(&mut body)
+            })
+            .map_err(|_e| ErrorCode::MalformedProofObject)?;
+
+        if body.len() as u64 > PROOF_OBJECT_MAXIMUM_UNFRAMED_SIZE {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        return Ok((flags, Cow::Owned(body)));
+    }
+
+    Ok((flags, Cow::Borrowed(reader.remainder())))
+}
+
+/// Frames the proof object `bytes`, as produced by `theorem_export`,
+/// `theorem_export_remappable` or `theorem_export_parallel`, compressing
+/// everything after its header with zstd at compression level `level`.  The
+/// framed proof object is imported exactly as the original.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::MalformedProofObject)` if `bytes` has the wrong
+/// magic bytes, version or flags, including if it is already framed.
+///
+/// # Panics
+///
+/// Panics if the compressor fails, which it does not for in-memory buffers.
+#[cfg(feature = "zstd")]
+pub fn proof_object_frame(
+    bytes: &[u8],
+    level: i32,
+) -> Result<Vec<u8>, ErrorCode> {
+    let mut reader = Reader::new(bytes);
+
+    let flags = reader.read_header_with_flags(
+        PROOF_OBJECT_MAGIC,
+        PROOF_OBJECT_VERSION,
+        FLAG_HANDLES,
+    )?;
+
+    let mut buffer = PROOF_OBJECT_MAGIC.to_vec();
+
+    buffer.push(PROOF_OBJECT_VERSION);
+    buffer.push(flags | FLAG_ZSTD);
+
+    zstd::stream::copy_encode(reader.remainder(), &mut buffer, level)
+        .expect(crate::kernel_panic::PROOF_OBJECT_FRAMING_ERROR);
+
+    info!(
+        "Proof object framed: {} bytes, from {} bytes.",
+        buffer.len(),
+        bytes.len()
+    );
+
+    Ok(buffer)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Encoding.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Accumulates the type- and term-tables of a proof object as it is being
+/// built, remembering the table index assigned to every kernel object that
+/// has already been serialized.
+pub(crate) struct Encoder<'a> {
+    /// The type-table from which types are being exported.
+    types: &'a HashMap<Handle<tags::Type>, Type>,
+    /// The term-table from which terms are being exported.
+    terms: &'a HashMap<Handle<tags::Term>, Term>,
+    /// Table indices of already-serialized types.
+    type_indices: HashMap<Handle<tags::Type>, u64>,
+    /// The serialized type-table.
+    type_table: Vec<u8>,
+    /// Table indices of already-serialized terms.
+    term_indices: HashMap<Handle<tags::Term>, u64>,
+    /// The serialized term-table.
+    term_table: Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+    /// Returns a new encoder, with empty tables, of objects from `kernel`.
+    #[inline]
+    pub(crate) fn new(kernel: &'a RuntimeState) -> Self {
+        Encoder::from_tables(kernel.type_table(), kernel.term_table())
+    }
+
+    /// Returns a new encoder, with empty tables, of objects from the type- and
+    /// term-tables `types` and `terms`.  Unlike the runtime state itself, the
+    /// tables may be shared between threads.
+    pub(crate) fn from_tables(
+        types: &'a HashMap<Handle<tags::Type>, Type>,
+        terms: &'a HashMap<Handle<tags::Term>, Term>,
+    ) -> Self {
+        Encoder {
+            types,
+            terms,
+            type_indices: HashMap::new(),
+            type_table: Vec::new(),
+            term_indices: HashMap::new(),
+            term_table: Vec::new(),
+        }
+    }
+
+    /// Serializes the type pointed-to by `handle`, and all of its subtypes, if
+    /// this has not already been done, returning its index in the type-table.
+    pub(crate) fn encode_type(&mut self, handle: &Handle<tags::Type>) -> u64 {
+        if let Some(index) = self.type_indices.get(handle) {
+            return *index;
+        }
+
+        // Appease the borrow-checker gods...
+        let types = self.types;
+
+        let entry = match types.get(handle).expect(DANGLING_HANDLE_ERROR) {
+            Type::Variable { name } => {
+                let mut entry = vec![TAG_TYPE_VARIABLE];
+                write_varint(&mut entry, *name);
+                entry
+            }
+            Type::Combination { former, arguments } => {
+                let arguments: Vec<u64> =
+                    arguments.iter().map(|a| self.encode_type(a)).collect();
+
+                let mut entry = vec![TAG_TYPE_COMBINATION];
+                write_varint(&mut entry, **former as u64);
+                write_varint(&mut entry, arguments.len() as u64);
+
+                for a in arguments {
+                    write_varint(&mut entry, a);
+                }
+
+                entry
+            }
+        };
+
+        let index = self.type_indices.len() as u64;
+        self.type_table.extend(entry);
+        self.type_indices.insert(handle.clone(), index);
+
+        index
+    }
+
+    /// Serializes the term pointed-to by `handle`, and all of its subterms and
+    /// types, if this has not already been done, returning its index in the
+    /// term-table.
+    pub(crate) fn encode_term(&mut self, handle: &Handle<tags::Term>) -> u64 {
+        if let Some(index) = self.term_indices.get(handle) {
+            return *index;
+        }
+
+        // Appease the borrow-checker gods...
+        let terms = self.terms;
+
+        let entry = match terms.get(handle).expect(DANGLING_HANDLE_ERROR) {
+            Term::Variable { name, tau } => {
+                let tau = self.encode_type(tau);
+
+                let mut entry = vec![TAG_TERM_VARIABLE];
+                write_varint(&mut entry, *name);
+                write_varint(&mut entry, tau);
+                entry
+            }
+            Term::Constant { constant, tau } => {
+                let tau = self.encode_type(tau);
+
+                let mut entry = vec![TAG_TERM_CONSTANT];
+                write_varint(&mut entry, **constant as u64);
+                write_varint(&mut entry, tau);
+                entry
+            }
+            Term::Application { left, right } => {
+                let left = self.encode_term(left);
+                let right = self.encode_term(right);
+
+                let mut entry = vec![TAG_TERM_APPLICATION];
+                write_varint(&mut entry, left);
+                write_varint(&mut entry, right);
+                entry
+            }
+            Term::Lambda { name, tau, body } => {
+                let tau = self.encode_type(tau);
+                let body = self.encode_term(body);
+
+                let mut entry = vec![TAG_TERM_LAMBDA];
+                write_varint(&mut entry, *name);
+                write_varint(&mut entry, tau);
+                write_varint(&mut entry, body);
+                entry
+            }
+        };
+
+        let index = self.term_indices.len() as u64;
+        self.term_table.extend(entry);
+        self.term_indices.insert(handle.clone(), index);
+
+        index
+    }
+
+    /// Returns the index in the type-table of the type pointed-to by `handle`,
+    /// if it has already been serialized.
+    #[inline]
+    pub(crate) fn type_index(
+        &self,
+        handle: &Handle<tags::Type>,
+    ) -> Option<u64> {
+        self.type_indices.get(handle).cloned()
+    }
+
+    /// Returns the index in the term-table of the term pointed-to by `handle`,
+    /// if it has already been serialized.
+    #[inline]
+    pub(crate) fn term_index(
+        &self,
+        handle: &Handle<tags::Term>,
+    ) -> Option<u64> {
+        self.term_indices.get(handle).cloned()
+    }
+
+    /// Appends the type-table, preceded by its length, to `buffer`.
+    pub(crate) fn write_type_table(&self, buffer: &mut Vec<u8>) {
+        write_varint(buffer, self.type_indices.len() as u64);
+        buffer.extend(&self.type_table);
+    }
+
+    /// Appends the term-table, preceded by its length, to `buffer`.
+    pub(crate) fn write_term_table(&self, buffer: &mut Vec<u8>) {
+        write_varint(buffer, self.term_indices.len() as u64);
+        buffer.extend(&self.term_table);
+    }
+
+    /// Appends the handle section, for the serialized types and terms and the
+    /// theorems `theorems`, to `buffer`.
+    pub(crate) fn write_handle_section(
+        &self,
+        buffer: &mut Vec<u8>,
+        theorems: &[Handle<tags::Theorem>],
+    ) {
+        let mut types: Vec<(&u64, usize)> =
+            self.type_indices.iter().map(|(h, i)| (i, **h)).collect();
+        let mut terms: Vec<(&u64, usize)> =
+            self.term_indices.iter().map(|(h, i)| (i, **h)).collect();
+
+        types.sort_unstable();
+        terms.sort_unstable();
+
+        for (_index, handle) in types.into_iter().chain(terms) {
+            write_varint(buffer, handle as u64);
+        }
+
+        for theorem in theorems {
+            write_varint(buffer, **theorem as u64);
+        }
+    }
+}
+
+/// Serializes `sequents` into a proof object, using `encoder`, which must not
+/// yet have serialized anything, to build its type- and term-tables.  If
+/// `theorems`, the handles of the theorems whose sequents are `sequents`, is
+/// supplied then the proof object carries a handle section.
+pub(crate) fn encode_sequents(
+    mut encoder: Encoder,
+    sequents: &[Sequent],
+    theorems: Option<&[Handle<tags::Theorem>]>,
+) -> Vec<u8> {
+    let mut theorem_table = Vec::new();
+
+    for sequent in sequents {
+        write_varint(&mut theorem_table, sequent.premisses.len() as u64);
+
+        for p in sequent.premisses.iter() {
+            let p = encoder.encode_term(p);
+            write_varint(&mut theorem_table, p);
+        }
+
+        let conclusion = encoder.encode_term(&sequent.conclusion);
+        write_varint(&mut theorem_table, conclusion);
+    }
+
+    let mut buffer = Vec::new();
+
+    buffer.extend(PROOF_OBJECT_MAGIC);
+    buffer.push(PROOF_OBJECT_VERSION);
+    buffer.push(if theorems.is_some() { FLAG_HANDLES } else { 0 });
+
+    encoder.write_type_table(&mut buffer);
+    encoder.write_term_table(&mut buffer);
+    write_varint(&mut buffer, sequents.len() as u64);
+    buffer.extend(theorem_table);
+
+    if let Some(theorems) = theorems {
+        encoder.write_handle_section(&mut buffer, theorems);
+    }
+
+    buffer
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Decoding.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Decodes a type-table from `reader`, registering every type in it, and
+    /// returns the handles of the registered types, in table order.  The raw
+    /// type-former handles written in the table are translated by `former`.
+    pub(crate) fn decode_type_table<F>(
+        &mut self,
+        reader: &mut Reader,
+        former: F,
+    ) -> Result<Vec<Handle<tags::Type>>, ErrorCode>
+    where
+        F: Fn(usize) -> Result<Handle<tags::TypeFormer>, ErrorCode>,
+    {
+        /* NB: table sizes are untrusted, so tables are grown as entries are
+         * actually read rather than being allocated up-front.
+         */
+        let mut types: Vec<Handle<tags::Type>> = Vec::new();
+
+        for _i in 0..reader.read_varint()? {
+            let handle = match reader.read_u8()? {
+                TAG_TYPE_VARIABLE => {
+                    self.type_register_variable(reader.read_varint()?)
+                }
+                TAG_TYPE_COMBINATION => {
+                    let former = former(reader.read_varint()? as usize)?;
+                    let mut arguments = Vec::new();
+
+                    for _j in 0..reader.read_varint()? {
+                        let a = reader.read_reference(types.len())?;
+                        arguments.push(types[a].clone());
+                    }
+
+                    self.type_register_combination(former, arguments)?
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            types.push(handle);
+
+            let (done, total) = reader.progress();
+            self.progress_report(done, total);
+        }
+
+        Ok(types)
+    }
+
+    /// Decodes a term-table from `reader`, whose entries refer to the types
+    /// `types`, registering every term in it, and returns the handles of the
+    /// registered terms, in table order.  The raw constant handles written in
+    /// the table are translated by `constant`.
+    pub(crate) fn decode_term_table<F>(
+        &mut self,
+        reader: &mut Reader,
+        types: &[Handle<tags::Type>],
+        constant: F,
+    ) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+    where
+        F: Fn(usize) -> Result<Handle<tags::Constant>, ErrorCode>,
+    {
+        let mut terms: Vec<Handle<tags::Term>> = Vec::new();
+
+        for _i in 0..reader.read_varint()? {
+            let handle = match reader.read_u8()? {
+                TAG_TERM_VARIABLE => {
+                    let name = reader.read_varint()?;
+                    let tau = reader.read_reference(types.len())?;
+
+                    self.term_register_variable(name, types[tau].clone())?
+                }
+                TAG_TERM_CONSTANT => {
+                    let constant = constant(reader.read_varint()? as usize)?;
+                    let tau = reader.read_reference(types.len())?;
+
+                    self.term_register_constant_at_type(
+                        constant,
+                        types[tau].clone(),
+                    )?
+                }
+                TAG_TERM_APPLICATION => {
+                    let left = reader.read_reference(terms.len())?;
+                    let right = reader.read_reference(terms.len())?;
+
+                    self.term_register_application(
+                        terms[left].clone(),
+                        terms[right].clone(),
+                    )?
+                }
+                TAG_TERM_LAMBDA => {
+                    let name = reader.read_varint()?;
+                    let tau = reader.read_reference(types.len())?;
+                    let body = reader.read_reference(terms.len())?;
+
+                    self.term_register_lambda(
+                        name,
+                        types[tau].clone(),
+                        terms[body].clone(),
+                    )?
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            terms.push(handle);
+
+            let (done, total) = reader.progress();
+            self.progress_report(done, total);
+        }
+
+        Ok(terms)
+    }
+}
+
+/// Reads `count` handles of a handle section from `reader`.
+pub(crate) fn read_handles(
+    reader: &mut Reader,
+    count: usize,
+) -> Result<Vec<usize>, ErrorCode> {
+    let mut handles = Vec::new();
+
+    for _i in 0..count {
+        handles.push(reader.read_varint()? as usize);
+    }
+
+    Ok(handles)
+}
+
+/// Decodes a table of sequents from `reader`, whose entries refer to the terms
+/// `terms`.
+pub(crate) fn decode_sequent_table(
+    reader: &mut Reader,
+    terms: &[Handle<tags::Term>],
+) -> Result<Vec<Sequent>, ErrorCode> {
+    let mut sequents = Vec::new();
+
+    for _i in 0..reader.read_varint()? {
+        let mut premisses = Vec::new();
+
+        for _j in 0..reader.read_varint()? {
+            let p = reader.read_reference(terms.len())?;
+            premisses.push(terms[p].clone());
+        }
+
+        let conclusion = reader.read_reference(terms.len())?;
+
+        sequents.push(Sequent {
+            premisses,
+            conclusion: terms[conclusion].clone(),
+        });
+    }
+
+    Ok(sequents)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Exporting and importing proof objects.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Serializes the theorems pointed-to by `handles` into a proof object,
+    /// sharing every type and term common to the theorems' sequents.  The
+    /// theorems appear in the proof object in the order given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any handle in
+    /// `handles` does not point-to a registered theorem in the runtime state's
+    /// theorem-table.
+    pub fn theorem_export<T>(&self, handles: &[T]) -> Result<Vec<u8>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Exporting {} theorems as a proof object.", handles.len());
+
+        let sequents = self.theorem_sequents(handles)?;
+        let buffer = encode_sequents(Encoder::new(self), &sequents, None);
+
+        info!("Proof object exported: {} bytes.", buffer.len());
+
+        Ok(buffer)
+    }
+
+    /// Serializes the theorems pointed-to by `handles` into a proof object, as
+    /// `theorem_export`, along with a handle section, so that importing the
+    /// proof object with `theorem_import_remapped` yields a remapping table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any handle in
+    /// `handles` does not point-to a registered theorem in the runtime state's
+    /// theorem-table.
+    pub fn theorem_export_remappable<T>(
+        &self,
+        handles: &[T],
+    ) -> Result<Vec<u8>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Exporting {} theorems as a remappable proof object.",
+            handles.len()
+        );
+
+        let sequents = self.theorem_sequents(handles)?;
+        let theorems: Vec<Handle<tags::Theorem>> =
+            handles.iter().map(|h| h.borrow().clone()).collect();
+        let buffer =
+            encode_sequents(Encoder::new(self), &sequents, Some(&theorems));
+
+        info!("Proof object exported: {} bytes.", buffer.len());
+
+        Ok(buffer)
+    }
+
+    /// Returns the sequents of the theorems pointed-to by `handles`, in order.
+    fn theorem_sequents<T>(
+        &self,
+        handles: &[T],
+    ) -> Result<Vec<Sequent>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        let mut sequents = Vec::new();
+
+        for handle in handles {
+            sequents.push(Sequent {
+                premisses: self
+                    .theorem_split_premisses(handle.borrow())?
+                    .to_vec(),
+                conclusion: self.theorem_split_conclusion(handle.borrow())?,
+            });
+        }
+
+        Ok(sequents)
+    }
+
+    /// Decodes the proof object `bytes`, registering every type and term that
+    /// it contains in the runtime state, and returns the sequents of the
+    /// exported theorems, in order.  No theorems are registered.  Any handle
+    /// section is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::MalformedProofObject)` if `bytes` is truncated,
+    /// has trailing data, has the wrong magic bytes, version or flags, is
+    /// framed but cannot be unframed, or contains an unknown tag or a reference
+    /// that does not point backwards into its table.
+    ///
+    /// Otherwise, returns whatever error the kernel's registration functions
+    /// return if an entry in the proof object does not describe a well-formed
+    /// type or term in the runtime state, for example
+    /// `Err(ErrorCode::NoSuchConstantRegistered)` if a term refers to a
+    /// constant that has not been registered, or
+    /// `Err(ErrorCode::DomainTypeMismatch)` if an application is not typeable.
+    #[inline]
+    pub fn theorem_import(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<Vec<Sequent>, ErrorCode> {
+        self.theorem_import_remapped(bytes)
+            .map(|(sequents, _remapping)| sequents)
+    }
+
+    /// Decodes the proof object `bytes`, as `theorem_import`, returning the
+    /// sequents of the exported theorems along with a remapping table built
+    /// from its handle section.  The remapping table is empty if the proof
+    /// object has no handle section.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors that `theorem_import` does, and
+    /// `Err(ErrorCode::MalformedProofObject)` if the handle section is
+    /// truncated.
+    pub fn theorem_import_remapped(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(Vec<Sequent>, Remapping), ErrorCode> {
+        info!("Importing proof object of {} bytes.", bytes.len());
+
+        let (sequents, remapping) =
+            self.with_progress(LongOperation::ProofImport, |state| {
+                let (flags, body) = unframe(bytes)?;
+                let mut reader = Reader::new(&body);
+
+                let types = state
+                    .decode_type_table(&mut reader, |h| Ok(Handle::from(h)))?;
+                let terms =
+                    state.decode_term_table(&mut reader, &types, |h| {
+                        Ok(Handle::from(h))
+                    })?;
+                let sequents = decode_sequent_table(&mut reader, &terms)?;
+
+                let mut remapping = Remapping::default();
+
+                if flags & FLAG_HANDLES != 0 {
+                    let imported = types.iter().map(|h| **h);
+                    let imported = imported.chain(terms.iter().map(|h| **h));
+                    let exported =
+                        read_handles(&mut reader, types.len() + terms.len())?;
+
+                    remapping
+                        .objects
+                        .extend(exported.into_iter().zip(imported));
+
+                    let exported = read_handles(&mut reader, sequents.len())?;
+
+                    remapping.theorems.extend(
+                        exported.into_iter().enumerate().map(|(i, h)| (h, i)),
+                    );
+                }
+
+                if !reader.is_exhausted() {
+                    return Err(ErrorCode::MalformedProofObject);
+                }
+
+                Ok((sequents, remapping))
+            })?;
+
+        info!("Proof object imported: {} sequents.", sequents.len());
+
+        Ok((sequents, remapping))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for proof object serialization.
+#[cfg(test)]
+mod test {
+    use crate::{
+        _type::Type,
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        proof_object::{write_varint, PROOF_OBJECT_MAGIC},
+        runtime_state::RuntimeState,
+        term::Term,
+    };
+
+    #[cfg(feature = "zstd")]
+    use crate::proof_object::proof_object_frame;
+
+    /// Serializes a term as a naive syntax tree, with every shared subterm and
+    /// type written out in full, for comparing against proof objects.
+    fn naive_term_size(
+        kernel: &RuntimeState,
+        handle: &Handle<tags::Term>,
+    ) -> usize {
+        fn type_size(
+            kernel: &RuntimeState,
+            handle: &Handle<tags::Type>,
+        ) -> usize {
+            let mut buffer = Vec::new();
+
+            match kernel.resolve_type_handle(handle).unwrap() {
+                Type::Variable { name } => {
+                    write_varint(&mut buffer, *name);
+                    1 + buffer.len()
+                }
+                Type::Combination { former, arguments } => {
+                    write_varint(&mut buffer, **former as u64);
+                    write_varint(&mut buffer, arguments.len() as u64);
+                    1 + buffer.len()
+                        + arguments
+                            .iter()
+                            .map(|a| type_size(kernel, a))
+                            .sum::<usize>()
+                }
+            }
+        }
+
+        let mut buffer = Vec::new();
+
+        match kernel.resolve_term_handle(handle).unwrap() {
+            Term::Variable { name, tau } => {
+                write_varint(&mut buffer, *name);
+                1 + buffer.len() + type_size(kernel, tau)
+            }
+            Term::Constant { constant, tau } => {
+                write_varint(&mut buffer, **constant as u64);
+                1 + buffer.len() + type_size(kernel, tau)
+            }
+            Term::Application { left, right } => {
+                1 + naive_term_size(kernel, left)
+                    + naive_term_size(kernel, right)
+            }
+            Term::Lambda { name, tau, body } => {
+                write_varint(&mut buffer, *name);
+                1 + buffer.len()
+                    + type_size(kernel, tau)
+                    + naive_term_size(kernel, body)
+            }
+        }
+    }
+
+    /// Builds the theorem `⊢ t = t`, where `t` is a balanced tree of
+    /// conjunctions of depth `depth` over a single propositional variable.
+    fn large_theorem(
+        kernel: &mut RuntimeState,
+        depth: usize,
+    ) -> Handle<tags::Theorem> {
+        let mut trm = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        for _i in 0..depth {
+            trm = kernel
+                .term_register_conjunction(trm.clone(), trm.clone())
+                .unwrap();
+        }
+
+        let eq = kernel.term_register_equality(trm.clone(), trm).unwrap();
+
+        kernel.theorem_register_assumption(eq).unwrap()
+    }
+
+    /// Tests that exporting and importing a theorem, in the same runtime state,
+    /// yields back the theorem's sequent.
+    #[test]
+    pub fn proof_object_test0() {
+        let mut kernel = RuntimeState::new();
+
+        let thm = large_theorem(&mut kernel, 4);
+        let bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        let sequents = kernel.theorem_import(&bytes).unwrap();
+
+        assert_eq!(sequents.len(), 1);
+        assert_eq!(
+            sequents[0].premisses,
+            kernel.theorem_split_premisses(&thm).unwrap()
+        );
+        assert_eq!(
+            sequents[0].conclusion,
+            kernel.theorem_split_conclusion(&thm).unwrap()
+        );
+    }
+
+    /// Tests that a proof object can be imported into a fresh runtime state,
+    /// and that re-exporting the imported sequent yields the same bytes.
+    #[test]
+    pub fn proof_object_test1() {
+        let mut kernel = RuntimeState::new();
+
+        let thm = large_theorem(&mut kernel, 6);
+        let bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.theorem_import(&bytes).unwrap();
+
+        let conclusion = sequents[0].conclusion.clone();
+        let thm = fresh.theorem_register_assumption(conclusion).unwrap();
+        let reexported = fresh.theorem_export(&[&thm]).unwrap();
+
+        assert_eq!(bytes, reexported);
+    }
+
+    /// Tests that proof objects are at least an order of magnitude smaller
+    /// than a naive tree serialization of a large theorem with lots of shared
+    /// structure.
+    #[test]
+    pub fn proof_object_test2() {
+        let mut kernel = RuntimeState::new();
+
+        let thm = large_theorem(&mut kernel, 12);
+        let bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        let mut sequent =
+            kernel.theorem_split_premisses(&thm).unwrap().to_vec();
+        sequent.push(kernel.theorem_split_conclusion(&thm).unwrap());
+
+        let naive: usize =
+            sequent.iter().map(|t| naive_term_size(&kernel, t)).sum();
+
+        assert!(bytes.len() * 10 < naive);
+    }
+
+    /// Tests that polymorphic constants are re-imported at their instantiated
+    /// type.
+    #[test]
+    pub fn proof_object_test3() {
+        let mut kernel = RuntimeState::new();
+
+        let a = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let eq = kernel.term_register_equality(a.clone(), a).unwrap();
+        let thm = kernel.theorem_register_assumption(eq.clone()).unwrap();
+
+        let bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.theorem_import(&bytes).unwrap();
+        let (left, _right) =
+            fresh.term_split_equality(&sequents[0].conclusion).unwrap();
+        let left = left.clone();
+
+        assert_eq!(
+            fresh.term_type_infer(&left).unwrap(),
+            PREALLOCATED_HANDLE_TYPE_ALPHA
+        );
+        assert!(fresh
+            .term_register_constant_at_type(
+                PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+                PREALLOCATED_HANDLE_TYPE_PROP
+            )
+            .is_err());
+    }
+
+    /// Tests that truncated proof objects, and proof objects with trailing
+    /// junk, are rejected.
+    #[test]
+    pub fn proof_object_test4() {
+        let mut kernel = RuntimeState::new();
+
+        let thm = large_theorem(&mut kernel, 2);
+        let mut bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        for i in 0..bytes.len() {
+            assert_eq!(
+                kernel.theorem_import(&bytes[0..i]),
+                Err(ErrorCode::MalformedProofObject)
+            );
+        }
+
+        bytes.push(0);
+
+        assert_eq!(
+            kernel.theorem_import(&bytes),
+            Err(ErrorCode::MalformedProofObject)
+        );
+    }
+
+    /// Tests that forward references are rejected.
+    #[test]
+    pub fn proof_object_test5() {
+        let mut kernel = RuntimeState::new();
+
+        let mut bytes = PROOF_OBJECT_MAGIC.to_vec();
+        bytes.extend(vec![1, 0]);
+        /* One type, an arrow whose arguments refer to the type itself. */
+        bytes.extend(vec![1, 1, 1, 2, 0, 0]);
+        bytes.extend(vec![0, 0]);
+
+        assert_eq!(
+            kernel.theorem_import(&bytes),
+            Err(ErrorCode::MalformedProofObject)
+        );
+    }
+
+    /// Tests that importing a remappable proof object into a non-empty runtime
+    /// state yields a remapping table from the exported handles to the
+    /// imported objects, and that plain proof objects yield an empty one.
+    #[test]
+    pub fn proof_object_test6() {
+        let mut exporter = RuntimeState::new();
+        let mut importer = RuntimeState::new();
+
+        /* Occupy the importer's handles, so that the two runtime states clash. */
+        large_theorem(&mut importer, 3);
+
+        let first = large_theorem(&mut exporter, 2);
+        let second = large_theorem(&mut exporter, 4);
+        let bytes = exporter
+            .theorem_export_remappable(&[&second, &first])
+            .unwrap();
+
+        let (sequents, remapping) =
+            importer.theorem_import_remapped(&bytes).unwrap();
+
+        let conclusion = exporter.theorem_split_conclusion(&first).unwrap();
+        let remapped = remapping.remap(&conclusion).unwrap();
+
+        assert_ne!(remapped, conclusion);
+        assert_eq!(remapping.theorem(&first), Some(1));
+        assert_eq!(remapping.theorem(&second), Some(0));
+        assert_eq!(sequents[1].conclusion, remapped);
+        assert_eq!(
+            remapping.remap(&PREALLOCATED_HANDLE_TYPE_PROP),
+            Some(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
+
+        let bytes = exporter.theorem_export(&[&first]).unwrap();
+        let (_sequents, remapping) =
+            importer.theorem_import_remapped(&bytes).unwrap();
+
+        assert!(remapping.is_empty());
+        assert_eq!(importer.theorem_import(&bytes).unwrap().len(), 1);
+    }
+
+    /// Tests that proof objects setting the framing flag are rejected unless
+    /// their body is a zstd frame, and that, without the `zstd` feature, they
+    /// are rejected outright.
+    #[test]
+    pub fn proof_object_test7() {
+        let mut kernel = RuntimeState::new();
+
+        let thm = large_theorem(&mut kernel, 2);
+        let mut bytes = kernel.theorem_export(&[&thm]).unwrap();
+
+        bytes[5] |= 2;
+
+        assert_eq!(
+            kernel.theorem_import(&bytes),
+            Err(ErrorCode::MalformedProofObject)
+        );
+    }
+
+    /// Tests that framed proof objects, remappable or not, import as the
+    /// proof objects that they frame, are smaller than them on large proofs,
+    /// and cannot be framed twice.
+    #[cfg(feature = "zstd")]
+    #[test]
+    pub fn proof_object_test8() {
+        let mut exporter = RuntimeState::new();
+        let mut importer = RuntimeState::new();
+
+        let thms: Vec<_> = (1..12)
+            .map(|depth| large_theorem(&mut exporter, depth))
+            .collect();
+
+        let bytes = exporter.theorem_export_remappable(&thms).unwrap();
+        let framed = proof_object_frame(&bytes, 19).unwrap();
+
+        assert!(framed.len() < bytes.len());
+        assert_eq!(
+            proof_object_frame(&framed, 19),
+            Err(ErrorCode::MalformedProofObject)
+        );
+
+        let (sequents, remapping) =
+            importer.theorem_import_remapped(&framed).unwrap();
+
+        assert_eq!(
+            importer.theorem_import_remapped(&bytes).unwrap(),
+            (sequents, remapping)
+        );
+
+        let bytes = exporter.theorem_export(&thms).unwrap();
+        let framed = proof_object_frame(&bytes, 1).unwrap();
+
+        assert_eq!(
+            importer.theorem_import(&framed).unwrap(),
+            importer.theorem_import(&bytes).unwrap()
+        );
+
+        for i in 0..framed.len() {
+            assert_eq!(
+                importer.theorem_import(&framed[0..i]),
+                Err(ErrorCode::MalformedProofObject)
+            );
+        }
+    }
+}
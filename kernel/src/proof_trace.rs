@@ -0,0 +1,700 @@
+//! # Exporting proof traces
+//!
+//! Whilst proof recording is enabled, with
+//! `RuntimeState::set_proof_recording`, the kernel records a proof term for
+//! every theorem registered through its ABI, noting the inference rule, and
+//! the arguments, that derived it.  The recorded proof terms of a theorem, and
+//! of every theorem that it was transitively derived from, together form a
+//! *proof trace* of that theorem, which this module exports as an
+//! [OpenTheory] article, so that an external checker can independently audit
+//! what was proved under Supervisionary.
+//!
+//! Articles are written in version 6 of the OpenTheory article format, one
+//! command per line.  Every type, term and theorem is built on the article's
+//! stack exactly once, and stored in its dictionary, with every later
+//! occurrence recalled from there, so that the article preserves the sharing
+//! of the kernel's tables.  Each theorem requested is then exported with the
+//! article's `thm` command.
+//!
+//! The kernel's inference rules with a counterpart amongst OpenTheory's
+//! primitive rules, namely assumption, weakening, reflexivity, symmetry,
+//! transitivity, application and λ-abstraction congruence, and β-conversion,
+//! are exported as applications of those rules.  Every other theorem in the
+//! trace, whether derived with a richer rule of the kernel, postulated by the
+//! host, or registered whilst proof recording was disabled, is exported as an
+//! *axiom*, which an external checker reports as an assumption of the article,
+//! so an audit is only as thorough as the primitive fragment of the trace.
+//!
+//! The constants and type-formers of the bootstrap theory are exported under
+//! their names in the OpenTheory standard library, the constants of the symbol
+//! table under their registered names, and type-formers under the name in
+//! their metadata.  Any other constant, or type-former, is exported as `c`, or
+//! `F`, followed by its handle.
+//!
+//! [OpenTheory]: https://www.gilith.com/opentheory/article.html
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type,
+    error_code::ErrorCode,
+    handle::{
+        tags, Handle, PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+        PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+        PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
+        PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
+        PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
+        PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+        PREALLOCATED_HANDLE_CONSTANT_EXISTS,
+        PREALLOCATED_HANDLE_CONSTANT_FALSE, PREALLOCATED_HANDLE_CONSTANT_FIRST,
+        PREALLOCATED_HANDLE_CONSTANT_FORALL,
+        PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
+        PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+        PREALLOCATED_HANDLE_CONSTANT_LET,
+        PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
+        PREALLOCATED_HANDLE_CONSTANT_NEGATION,
+        PREALLOCATED_HANDLE_CONSTANT_PAIR, PREALLOCATED_HANDLE_CONSTANT_SECOND,
+        PREALLOCATED_HANDLE_CONSTANT_SUBSET, PREALLOCATED_HANDLE_CONSTANT_TRUE,
+        PREALLOCATED_HANDLE_CONSTANT_UNION,
+        PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
+        PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+    },
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    name::{canonical_index, Name},
+    proof::{ProofArgument, Rule},
+    runtime_state::RuntimeState,
+    term::Term,
+};
+use log::info;
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Articles.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The version of the OpenTheory article format that proof traces are
+/// exported in.
+const ARTICLE_VERSION: u64 = 6;
+
+/// An OpenTheory article under construction, along with the dictionary keys of
+/// the types, terms and theorems that it has already built.
+struct Article<'a> {
+    /// The runtime state whose objects are exported.
+    kernel: &'a RuntimeState,
+    /// The names of the constants of the symbol table.
+    constant_names: HashMap<Handle<tags::Constant>, &'a str>,
+    /// The commands of the article written so far.
+    text: String,
+    /// The dictionary key to store the next object under.
+    next_key: u64,
+    /// The dictionary keys of the types built so far.
+    types: HashMap<Handle<tags::Type>, u64>,
+    /// The dictionary keys of the terms built so far.
+    terms: HashMap<Handle<tags::Term>, u64>,
+    /// The dictionary keys of the theorems derived so far.
+    theorems: HashMap<Handle<tags::Theorem>, u64>,
+}
+
+impl<'a> Article<'a> {
+    /// Starts a new article exporting objects of `kernel`.
+    fn new(kernel: &'a RuntimeState) -> Self {
+        let mut article = Article {
+            kernel,
+            constant_names: kernel.constant_names_by_handle(),
+            text: String::new(),
+            next_key: 0,
+            types: HashMap::new(),
+            terms: HashMap::new(),
+            theorems: HashMap::new(),
+        };
+
+        article.number(ARTICLE_VERSION);
+        article.command("version");
+
+        article
+    }
+
+    /// Writes the command `command`.
+    fn command(&mut self, command: &str) {
+        self.text.push_str(command);
+        self.text.push('\n');
+    }
+
+    /// Writes a command pushing the number `number`.
+    fn number(&mut self, number: u64) {
+        self.command(&number.to_string());
+    }
+
+    /// Writes a command pushing the name `name`, escaping the characters that
+    /// may not appear unescaped in a quoted name.
+    fn name(&mut self, name: &str) {
+        let mut quoted = String::from("\"");
+
+        for c in name.chars() {
+            if c == '"' || c == '\\' {
+                quoted.push('\\');
+            }
+
+            quoted.push(c);
+        }
+
+        quoted.push('"');
+
+        self.command(&quoted);
+    }
+
+    /// Stores the object on top of the stack in the dictionary, leaving it on
+    /// the stack, and returns its key.
+    fn store(&mut self) -> u64 {
+        let key = self.next_key;
+
+        self.next_key += 1;
+        self.number(key);
+        self.command("def");
+
+        key
+    }
+
+    /// Pushes the object stored in the dictionary under `key`.
+    fn recall(&mut self, key: u64) {
+        self.number(key);
+        self.command("ref");
+    }
+
+    /// Builds a list of the objects pushed by `push` for each of `elements`.
+    fn list<T, F>(&mut self, elements: &[T], mut push: F)
+    where
+        F: FnMut(&mut Self, &T),
+    {
+        for element in elements {
+            push(self, element);
+        }
+
+        self.command("nil");
+
+        for _element in elements {
+            self.command("cons");
+        }
+    }
+
+    /// Returns the name that the type-former pointed-to by `former` is
+    /// exported under.
+    fn type_former_name(&self, former: &Handle<tags::TypeFormer>) -> String {
+        match former {
+            f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_PROP => {
+                String::from("bool")
+            }
+            f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_ARROW => {
+                String::from("->")
+            }
+            f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT => {
+                String::from("Data.Pair.*")
+            }
+            _otherwise => match self.kernel.type_former_metadata(former) {
+                Ok(Some(metadata)) if !metadata.name().is_empty() => {
+                    metadata.name().to_string()
+                }
+                _otherwise => format!("F{}", former),
+            },
+        }
+    }
+
+    /// Returns the name that the constant pointed-to by `constant` is exported
+    /// under.
+    fn constant_name(&self, constant: &Handle<tags::Constant>) -> String {
+        let name = match constant {
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_TRUE => "Data.Bool.T",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_FALSE => "Data.Bool.F",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_NEGATION => "Data.Bool.~",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION => {
+                "Data.Bool./\\"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION => {
+                "Data.Bool.\\/"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_IMPLICATION => {
+                "Data.Bool.==>"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_EQUALITY => "=",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_FORALL => "Data.Bool.!",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_EXISTS => "Data.Bool.?",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL => {
+                "Data.Bool.cond"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_LET => "Data.Bool.let",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_PAIR => "Data.Pair.,",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_FIRST => "Data.Pair.fst",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_SECOND => "Data.Pair.snd",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP => "Set.member",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION => {
+                "Set.fromPredicate"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET => "Set.empty",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET => {
+                "Set.universe"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_UNION => "Set.union",
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_INTERSECTION => {
+                "Set.intersect"
+            }
+            c if c == &PREALLOCATED_HANDLE_CONSTANT_SUBSET => "Set.subset",
+            _otherwise => match self.constant_names.get(constant) {
+                Some(name) => name,
+                None => return format!("c{}", constant),
+            },
+        };
+
+        name.to_string()
+    }
+
+    /// Pushes the type pointed-to by `handle`, which must not dangle.
+    fn push_type(&mut self, handle: &Handle<tags::Type>) {
+        if let Some(key) = self.types.get(handle) {
+            return self.recall(*key);
+        }
+
+        match self
+            .kernel
+            .resolve_type_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Type::Variable { name } => {
+                self.name(&format!("a{}", name));
+                self.command("varType");
+            }
+            Type::Combination { former, arguments } => {
+                let name = self.type_former_name(former);
+
+                self.name(&name);
+                self.command("typeOp");
+                self.list(arguments, Self::push_type);
+                self.command("opType");
+            }
+        }
+
+        let key = self.store();
+
+        self.types.insert(handle.clone(), key);
+    }
+
+    /// Pushes the variable named `name` of the type pointed-to by `tau`.
+    /// Canonical names, which are bound, are written with their index.
+    fn push_variable(&mut self, name: Name, tau: &Handle<tags::Type>) {
+        match canonical_index(name) {
+            Some(index) => self.name(&format!("b{}", index)),
+            None => self.name(&format!("x{}", name)),
+        }
+
+        self.push_type(tau);
+        self.command("var");
+    }
+
+    /// Pushes the term pointed-to by `handle`, which must not dangle.
+    fn push_term(&mut self, handle: &Handle<tags::Term>) {
+        if let Some(key) = self.terms.get(handle) {
+            return self.recall(*key);
+        }
+
+        match self
+            .kernel
+            .resolve_term_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Term::Variable { name, tau } => {
+                self.push_variable(*name, tau);
+                self.command("varTerm");
+            }
+            Term::Constant { constant, tau } => {
+                let name = self.constant_name(constant);
+
+                self.name(&name);
+                self.command("const");
+                self.push_type(tau);
+                self.command("constTerm");
+            }
+            Term::Application { left, right } => {
+                self.push_term(left);
+                self.push_term(right);
+                self.command("appTerm");
+            }
+            Term::Lambda { name, tau, body } => {
+                self.push_variable(*name, tau);
+                self.push_term(body);
+                self.command("absTerm");
+            }
+        }
+
+        let key = self.store();
+
+        self.terms.insert(handle.clone(), key);
+    }
+
+    /// Pushes the hypotheses, as a list, and then the conclusion, of the
+    /// theorem pointed-to by `handle`, which must not dangle.
+    fn push_sequent(&mut self, handle: &Handle<tags::Theorem>) {
+        let theorem = self
+            .kernel
+            .resolve_theorem_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        self.list(theorem.premisses(), Self::push_term);
+        self.push_term(theorem.conclusion());
+    }
+
+    /// Pushes the theorem pointed-to by `handle`, which must already have been
+    /// derived.
+    fn push_theorem(&mut self, handle: &Handle<tags::Theorem>) {
+        self.recall(self.theorems[handle]);
+    }
+
+    /// Derives the theorem pointed-to by `handle`, whose premisses in the
+    /// trace must all have been derived already, and stores it in the
+    /// dictionary.
+    fn derive(&mut self, handle: &Handle<tags::Theorem>) {
+        use ProofArgument::{Name, Term, Theorem, Type};
+
+        match self.kernel.proof_trace_step(handle) {
+            Some((Rule::Assumption, [Term(trm)])) => {
+                self.push_term(trm);
+                self.command("assume");
+            }
+            Some((Rule::Weaken, [Term(trm), Theorem(thm)])) => {
+                self.push_term(trm);
+                self.command("assume");
+                self.push_theorem(thm);
+                self.command("proveHyp");
+            }
+            Some((Rule::Reflexivity, [Term(trm)])) => {
+                self.push_term(trm);
+                self.command("refl");
+            }
+            Some((Rule::Symmetry, [Theorem(thm)])) => {
+                self.push_theorem(thm);
+                self.command("sym");
+            }
+            Some((Rule::Transitivity, [Theorem(left), Theorem(right)])) => {
+                self.push_theorem(left);
+                self.push_theorem(right);
+                self.command("trans");
+            }
+            Some((Rule::Application, [Theorem(left), Theorem(right)])) => {
+                self.push_theorem(left);
+                self.push_theorem(right);
+                self.command("appThm");
+            }
+            Some((Rule::Lambda, [Name(name), Type(tau), Theorem(thm)])) => {
+                self.push_variable(*name, tau);
+                self.push_theorem(thm);
+                self.command("absThm");
+            }
+            Some((Rule::Beta, [Term(application)])) => {
+                self.push_term(application);
+                self.command("betaConv");
+            }
+            _otherwise => {
+                self.push_sequent(handle);
+                self.command("axiom");
+            }
+        }
+
+        let key = self.store();
+
+        self.command("pop");
+        self.theorems.insert(handle.clone(), key);
+    }
+
+    /// Exports the theorem pointed-to by `handle`, which must already have
+    /// been derived.
+    fn export(&mut self, handle: &Handle<tags::Theorem>) {
+        self.push_theorem(handle);
+        self.push_sequent(handle);
+        self.command("thm");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Exporting proof traces.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns the inference rule, and the arguments, that derived the theorem
+    /// pointed-to by `handle`, iff the rule has a counterpart amongst the
+    /// primitive rules of OpenTheory and every premiss passed to it is still
+    /// registered, and `None` if the theorem must instead be exported as an
+    /// axiom.
+    fn proof_trace_step(
+        &self,
+        handle: &Handle<tags::Theorem>,
+    ) -> Option<(Rule, &[ProofArgument])> {
+        let proof = self.theorem_proof(handle).ok()?;
+        let (rule, _theorem, arguments) =
+            self.proof_split(proof).expect(DANGLING_HANDLE_ERROR);
+
+        let primitive = matches!(
+            rule,
+            Rule::Assumption
+                | Rule::Weaken
+                | Rule::Reflexivity
+                | Rule::Symmetry
+                | Rule::Transitivity
+                | Rule::Application
+                | Rule::Lambda
+                | Rule::Beta
+        );
+
+        /* NB: a premiss freed, or retracted, since the theorem was derived no
+         * longer has a sequent to export, so the theorem is exported as an
+         * axiom instead.
+         */
+        let premisses = arguments.iter().all(|argument| match argument {
+            ProofArgument::Theorem(premiss) => {
+                self.resolve_theorem_handle(premiss).is_ok()
+            }
+            _otherwise => true,
+        });
+
+        if primitive && premisses {
+            Some((rule, arguments))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the theorems of the proof traces of the theorems pointed-to by
+    /// `handles`, each after every premiss that it was derived from.
+    fn proof_trace_order(
+        &self,
+        handles: &[Handle<tags::Theorem>],
+    ) -> Vec<Handle<tags::Theorem>> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack: Vec<(Handle<tags::Theorem>, bool)> =
+            handles.iter().rev().map(|h| (h.clone(), false)).collect();
+
+        /* NB: traces may be arbitrarily deep, e.g. long chains of
+         * transitivity, so are walked with an explicit stack.  Each theorem is
+         * pushed a second time, marked as expanded, beneath its premisses, so
+         * that it is only ordered once all of them have been.
+         */
+        while let Some((handle, expanded)) = stack.pop() {
+            if expanded {
+                order.push(handle);
+                continue;
+            }
+
+            if !visited.insert(handle.clone()) {
+                continue;
+            }
+
+            stack.push((handle.clone(), true));
+
+            if let Some((_rule, arguments)) = self.proof_trace_step(&handle) {
+                for argument in arguments.iter().rev() {
+                    if let ProofArgument::Theorem(premiss) = argument {
+                        stack.push((premiss.clone(), false));
+                    }
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Writes the proof traces of the theorems pointed-to by `handles`, which
+    /// must all be registered, as an OpenTheory article exporting each of
+    /// them.
+    fn proof_trace_article(&self, handles: &[Handle<tags::Theorem>]) -> String {
+        let mut article = Article::new(self);
+
+        for handle in self.proof_trace_order(handles) {
+            article.derive(&handle);
+        }
+
+        for handle in handles {
+            article.export(handle);
+        }
+
+        article.text
+    }
+
+    /// Returns the proof traces of the theorems pointed-to by `handles`, as an
+    /// OpenTheory article exporting each of them, in the order given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any handle in
+    /// `handles` does not point-to a registered theorem in the runtime state's
+    /// theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchProofRegistered)` if no proof term was
+    /// recorded for any of the theorems, as it was registered whilst proof
+    /// recording was disabled.
+    pub fn proof_trace_export<T>(
+        &self,
+        handles: &[T],
+    ) -> Result<String, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Exporting proof traces of {} theorems.", handles.len());
+
+        for handle in handles {
+            self.theorem_proof(handle.borrow())?;
+        }
+
+        let handles: Vec<Handle<tags::Theorem>> =
+            handles.iter().map(|h| h.borrow().clone()).collect();
+
+        Ok(self.proof_trace_article(&handles))
+    }
+
+    /// Returns the proof traces of every registered theorem with a recorded
+    /// proof term, as an OpenTheory article exporting each of them, in
+    /// ascending order of handle.  The article exports nothing if proof
+    /// recording was never enabled.
+    pub fn proof_trace_export_all(&self) -> String {
+        let handles: Vec<Handle<tags::Theorem>> = self
+            .theorem_handles()
+            .into_iter()
+            .filter(|h| self.theorem_proof(h).is_ok())
+            .collect();
+
+        info!("Exporting proof traces of all {} theorems.", handles.len());
+
+        self.proof_trace_article(&handles)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for exporting proof traces.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Returns the commands of `article`, after its version header.
+    fn commands(article: &str) -> Vec<&str> {
+        let lines: Vec<&str> = article.lines().collect();
+
+        assert_eq!(&lines[0..2], &["6", "version"]);
+
+        lines[2..].to_vec()
+    }
+
+    /// Tests that an assumption is exported with the `assume` rule, with its
+    /// type and term stored once and recalled thereafter.
+    #[test]
+    pub fn proof_trace0() {
+        let mut kernel = RuntimeState::new();
+
+        kernel.set_proof_recording(true);
+
+        let p = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let thm = kernel.theorem_register_assumption(p).unwrap();
+
+        let article = kernel.proof_trace_export(&[&thm]).unwrap();
+
+        assert_eq!(
+            commands(&article),
+            vec![
+                "\"x0\"", "\"bool\"", "typeOp", "nil", "opType", "0", "def",
+                "var", "varTerm", "1", "def", "assume", "2", "def", "pop", "2",
+                "ref", "1", "ref", "nil", "cons", "1", "ref", "thm",
+            ]
+        );
+    }
+
+    /// Tests that equational reasoning is exported with OpenTheory's
+    /// primitive rules, and that a premiss is derived once, before the theorems
+    /// derived from it.
+    #[test]
+    pub fn proof_trace1() {
+        let mut kernel = RuntimeState::new();
+
+        kernel.set_proof_recording(true);
+
+        let x = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let refl = kernel
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x)
+            .unwrap();
+        let sym = kernel.theorem_register_symmetry(&refl).unwrap();
+        let trans = kernel.theorem_register_transitivity(&refl, &sym).unwrap();
+
+        let article = kernel.proof_trace_export(&[&trans]).unwrap();
+        let commands = commands(&article);
+
+        let position =
+            |command| commands.iter().position(|c| c == &command).unwrap();
+
+        assert_eq!(commands.iter().filter(|c| **c == "refl").count(), 1);
+        assert!(position("refl") < position("sym"));
+        assert!(position("sym") < position("trans"));
+        assert!(position("trans") < position("thm"));
+        assert!(!commands.contains(&"axiom"));
+    }
+
+    /// Tests that a premiss registered whilst proof recording was disabled is
+    /// exported as an axiom, and that weakening is exported with `proveHyp`.
+    #[test]
+    pub fn proof_trace2() {
+        let mut kernel = RuntimeState::new();
+
+        let p = kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = kernel
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let thm = kernel.theorem_register_assumption(p).unwrap();
+
+        assert_eq!(
+            kernel.proof_trace_export(&[&thm]),
+            Err(ErrorCode::NoSuchProofRegistered)
+        );
+
+        kernel.set_proof_recording(true);
+
+        let weakened = kernel.theorem_register_weaken(q, thm.clone()).unwrap();
+
+        let article = kernel.proof_trace_export(&[&weakened]).unwrap();
+        let commands = commands(&article);
+
+        assert!(commands.contains(&"axiom"));
+        assert!(commands.contains(&"proveHyp"));
+        assert_eq!(commands.last(), Some(&"thm"));
+        assert_eq!(commands.iter().filter(|c| **c == "thm").count(), 1);
+
+        assert_eq!(kernel.proof_trace_export_all(), article);
+    }
+}
@@ -0,0 +1,1962 @@
+//! # Quotient types
+//!
+//! A definitional package for quotient types, in the style of the
+//! `define_quotient_type` command of other HOL-family systems.  Given an
+//! equivalence relation, `R : σ → σ → Prop`, the package defines a new type,
+//! `σ/R`, in bijection with the equivalence classes of `R`, using the kernel's
+//! type-definition principle with the predicate:
+//!
+//! ```text
+//! P = λc:σ → Prop. ∃x. c = R x
+//! ```
+//!
+//! The package then defines the function, `[·] : σ → σ/R`, mapping each
+//! element to its equivalence class, and derives the theorems needed to reason
+//! about the new type through its representatives: that two elements have the
+//! same class iff they are related, and an induction rule, stating that every
+//! element of the quotient is the class of some representative.
+//!
+//! Functions on `σ` that respect `R` can then be lifted to functions on `σ/R`,
+//! with a theorem equating the lifted function applied to classes with the
+//! class of the original function applied to their representatives, and
+//! theorems relating representatives can be transferred to theorems equating
+//! their classes.  The kernel provides no choice operator, so lifted functions
+//! are defined on classes directly, as the class of the image of a class, and
+//! only functions into the carrier can be lifted.
+//!
+//! The kernel's existential quantifier has no inference rules, so the
+//! existential quantifiers above, and those in the definitions of lifted
+//! functions, are encoded impredicatively: `∃x. ɸ` is `∀q. (∀x. ɸ ⟶ q) ⟶ q`.
+//!
+//! Other than through the type-definition principle, nothing in this module
+//! extends the kernel's trusted base: every theorem is derived with the
+//! kernel's inference rules, so is recorded, and replayed, like any other.
+//! Registration is all-or-nothing: if any step fails then every kernel object
+//! registered along the way is discarded.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+    name::{fresh, Name},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, slice};
+
+////////////////////////////////////////////////////////////////////////////////
+// Quotient types.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A quotient type registered with the kernel, along with the theorems derived
+/// for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quotient {
+    /// The equivalence relation, `R`.
+    relation: Handle<tags::Term>,
+    /// The type, `σ`, related by the equivalence relation.
+    carrier: Handle<tags::Type>,
+    /// The quotient type, `σ/R`.
+    tau: Handle<tags::Type>,
+    /// The abstraction function of the type definition.
+    abs: Handle<tags::Term>,
+    /// The representation function of the type definition.
+    rep: Handle<tags::Term>,
+    /// The theorem `⊢ ∀a. abs (rep a) = a`.
+    abs_rep: Handle<tags::Theorem>,
+    /// The theorem `⊢ ∀c. P c = (rep (abs c) = c)`.
+    rep_abs: Handle<tags::Theorem>,
+    /// The constant, `[·]`, mapping elements to their classes.
+    class: Handle<tags::Term>,
+    /// The definitional theorem of the class function.
+    class_definition: Handle<tags::Theorem>,
+    /// The theorem `⊢ ∀x. rep [x] = R x`.
+    representation: Handle<tags::Theorem>,
+    /// The theorem `⊢ ∀x y. R x y = ([x] = [y])`.
+    class_equality: Handle<tags::Theorem>,
+    /// The induction rule of the quotient.
+    induction: Handle<tags::Theorem>,
+    /// The reflexivity theorem of the relation, `⊢ ∀x. R x x`.
+    reflexivity: Handle<tags::Theorem>,
+    /// The transitivity theorem of the relation,
+    /// `⊢ ∀x y z. R x y ⟶ R y z ⟶ R x z`.
+    transitivity: Handle<tags::Theorem>,
+}
+
+impl Quotient {
+    /// Returns the equivalence relation, `R`, of the quotient.
+    #[inline]
+    pub fn relation(&self) -> &Handle<tags::Term> {
+        &self.relation
+    }
+
+    /// Returns the quotient type, `σ/R`.
+    #[inline]
+    pub fn tau(&self) -> &Handle<tags::Type> {
+        &self.tau
+    }
+
+    /// Returns the abstraction function, `abs : (σ → Prop) → σ/R`, of the
+    /// type definition.
+    #[inline]
+    pub fn abs(&self) -> &Handle<tags::Term> {
+        &self.abs
+    }
+
+    /// Returns the representation function, `rep : σ/R → (σ → Prop)`, of the
+    /// type definition.
+    #[inline]
+    pub fn rep(&self) -> &Handle<tags::Term> {
+        &self.rep
+    }
+
+    /// Returns the theorem `⊢ ∀a. abs (rep a) = a` of the type definition.
+    #[inline]
+    pub fn abs_rep(&self) -> &Handle<tags::Theorem> {
+        &self.abs_rep
+    }
+
+    /// Returns the theorem `⊢ ∀c. P c = (rep (abs c) = c)` of the type
+    /// definition.
+    #[inline]
+    pub fn rep_abs(&self) -> &Handle<tags::Theorem> {
+        &self.rep_abs
+    }
+
+    /// Returns the constant, `[·] : σ → σ/R`, mapping each element to its
+    /// equivalence class.
+    #[inline]
+    pub fn class(&self) -> &Handle<tags::Term> {
+        &self.class
+    }
+
+    /// Returns the definitional theorem of the class function,
+    /// `⊢ [·] = λx. abs (R x)`.
+    #[inline]
+    pub fn class_definition(&self) -> &Handle<tags::Theorem> {
+        &self.class_definition
+    }
+
+    /// Returns the theorem `⊢ ∀x y. R x y = ([x] = [y])`, stating that two
+    /// elements have the same class iff they are related.
+    #[inline]
+    pub fn class_equality(&self) -> &Handle<tags::Theorem> {
+        &self.class_equality
+    }
+
+    /// Returns the induction rule of the quotient,
+    /// `⊢ ∀Q. (∀x. Q [x]) ⟶ (∀a. Q a)`.
+    #[inline]
+    pub fn induction(&self) -> &Handle<tags::Theorem> {
+        &self.induction
+    }
+
+    /// Returns `true` iff the quotient mentions the kernel object with handle
+    /// `handle`, of any kind.
+    pub(crate) fn mentions(&self, handle: usize) -> bool {
+        [
+            *self.relation,
+            *self.carrier,
+            *self.tau,
+            *self.abs,
+            *self.rep,
+            *self.abs_rep,
+            *self.rep_abs,
+            *self.class,
+            *self.class_definition,
+            *self.representation,
+            *self.class_equality,
+            *self.induction,
+            *self.reflexivity,
+            *self.transitivity,
+        ]
+        .contains(&handle)
+    }
+}
+
+/// A function lifted to a quotient type, along with the theorems derived for
+/// it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiftedFunction {
+    /// The constant naming the lifted function.
+    constant: Handle<tags::Term>,
+    /// The definitional theorem of the lifted function.
+    definition: Handle<tags::Theorem>,
+    /// The theorem relating the lifted function to the original function.
+    equation: Handle<tags::Theorem>,
+}
+
+impl LiftedFunction {
+    /// Returns the constant, `f'`, naming the lifted function.
+    #[inline]
+    pub fn constant(&self) -> &Handle<tags::Term> {
+        &self.constant
+    }
+
+    /// Returns the definitional theorem of the lifted function.
+    #[inline]
+    pub fn definition(&self) -> &Handle<tags::Theorem> {
+        &self.definition
+    }
+
+    /// Returns the theorem `⊢ ∀x₁ … xₙ. f' [x₁] … [xₙ] = [f x₁ … xₙ]`,
+    /// relating the lifted function, `f'`, to the original function, `f`.
+    #[inline]
+    pub fn equation(&self) -> &Handle<tags::Theorem> {
+        &self.equation
+    }
+}
+
+/// Returns a name appearing in none of `avoid`, and adds it to `avoid`.
+fn fresh_name(avoid: &mut Vec<Name>) -> Name {
+    let name = fresh(avoid.iter().cloned());
+    avoid.push(name);
+    name
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Supporting material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns the term `R s t`, for the relation `relation`.
+    fn quotient_relate(
+        &mut self,
+        relation: &Handle<tags::Term>,
+        left: &Handle<tags::Term>,
+        right: &Handle<tags::Term>,
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        let partial =
+            self.term_register_application(relation.clone(), left.clone())?;
+
+        self.term_register_application(partial, right.clone())
+    }
+
+    /// Returns the term `f a₁ … aₙ`, for the function `function` and the
+    /// terms `arguments`.
+    fn quotient_apply(
+        &mut self,
+        function: &Handle<tags::Term>,
+        arguments: &[Handle<tags::Term>],
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        let mut result = function.clone();
+
+        for argument in arguments {
+            result =
+                self.term_register_application(result, argument.clone())?;
+        }
+
+        Ok(result)
+    }
+
+    /// Registers the variables `binders` as terms.
+    fn quotient_variables(
+        &mut self,
+        binders: &[(Name, Handle<tags::Type>)],
+    ) -> Result<Vec<Handle<tags::Term>>, ErrorCode> {
+        binders
+            .iter()
+            .map(|(name, tau)| self.term_register_variable(*name, tau.clone()))
+            .collect()
+    }
+
+    /// Instantiates the outermost universal quantifiers of the theorem
+    /// pointed-to by `handle` with the terms `terms`, in order.
+    fn quotient_instantiate(
+        &mut self,
+        handle: &Handle<tags::Theorem>,
+        terms: &[Handle<tags::Term>],
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let mut thm = handle.clone();
+
+        for trm in terms {
+            thm = self.theorem_register_forall_elimination(thm, trm.clone())?;
+        }
+
+        Ok(thm)
+    }
+
+    /// Checks that the theorem pointed-to by `handle` has no premisses and has
+    /// `statement` as its conclusion, up to ⍺-equivalence.
+    fn quotient_check_statement(
+        &self,
+        handle: &Handle<tags::Theorem>,
+        statement: &Handle<tags::Term>,
+    ) -> Result<(), ErrorCode> {
+        if !self.theorem_split_premisses(handle)?.is_empty()
+            || &self.theorem_split_conclusion(handle)? != statement
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Ok(())` iff every type-variable of the term pointed-to by
+    /// `handle` also appears in the relation of `quotient`, so that defining a
+    /// constant from the term is sound.
+    fn quotient_check_type_variables(
+        &self,
+        quotient: &Quotient,
+        handle: &Handle<tags::Term>,
+    ) -> Result<(), ErrorCode> {
+        let allowed = self.term_type_variables(&quotient.relation)?;
+
+        if self
+            .term_type_variables(handle)?
+            .iter()
+            .any(|v| !allowed.contains(v))
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Ok(quotient)` iff the type pointed-to by `tau` is a quotient
+    /// type, registered with `quotient_register`, where `quotient` describes
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAQuotientType)` if the type pointed-to by
+    /// `tau` is not a quotient type.
+    pub fn quotient_resolve<T>(&self, tau: T) -> Result<&Quotient, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        info!("Resolving quotient type with handle: {}.", tau.borrow());
+
+        self.resolve_type_handle(tau.borrow())?;
+
+        self.quotients
+            .get(tau.borrow())
+            .ok_or(ErrorCode::NotAQuotientType)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Registering quotient types.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Defines a new quotient type, of the type `σ` by the equivalence
+    /// relation, `R : σ → σ → Prop`, pointed-to by `relation`, and derives the
+    /// theorems needed to reason about it.  Returns `Ok(quotient)` if this
+    /// process is successful.
+    ///
+    /// The relation must be closed, and `reflexivity`, `symmetry` and
+    /// `transitivity` must point-to theorems, without premisses, stating that
+    /// the relation is an equivalence:
+    ///
+    /// ```text
+    /// ⊢ ∀x. R x x
+    /// ⊢ ∀x y. R x y ⟶ R y x
+    /// ⊢ ∀x y z. R x y ⟶ R y z ⟶ R x z
+    /// ```
+    ///
+    /// The quotient type takes one argument for each type-variable of the
+    /// relation.  Either every kernel object is registered, or, if an error is
+    /// returned, none are.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `relation` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `reflexivity`,
+    /// `symmetry` or `transitivity` do not point-to registered theorems in the
+    /// runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` if the relation does not
+    /// have a function type.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the relation has free
+    /// variables, or does not have a type of the form `σ → σ → Prop`, or if
+    /// any of the theorems does not have the statement described above.
+    pub fn quotient_register<T, U, V, W>(
+        &mut self,
+        relation: T,
+        reflexivity: U,
+        symmetry: V,
+        transitivity: W,
+    ) -> Result<Quotient, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Theorem>>,
+        V: Borrow<Handle<tags::Theorem>>,
+        W: Borrow<Handle<tags::Theorem>>,
+    {
+        let relation = relation.into();
+
+        info!("Registering quotient type of relation {}.", relation);
+
+        self.speculate_begin();
+
+        let result = self.quotient_define(
+            relation,
+            reflexivity.borrow(),
+            symmetry.borrow(),
+            transitivity.borrow(),
+        );
+
+        self.speculate_end(result.is_ok())?;
+
+        result
+    }
+
+    /// Performs the work of `quotient_register`, leaving the caller to discard
+    /// the objects registered if this fails.
+    fn quotient_define(
+        &mut self,
+        relation: Handle<tags::Term>,
+        reflexivity: &Handle<tags::Theorem>,
+        symmetry: &Handle<tags::Theorem>,
+        transitivity: &Handle<tags::Theorem>,
+    ) -> Result<Quotient, ErrorCode> {
+        /* 1. Check the relation, and the statements of the theorems. */
+        let rtype = self.term_type_infer(&relation)?;
+
+        if !self.term_free_variables(&relation)?.is_empty() {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let (carrier, range) = self.type_split_function(&rtype)?;
+        let carrier = carrier.clone();
+        let class_type = range.clone();
+
+        match self.type_split_function(&class_type) {
+            Ok((domain, range))
+                if domain == &carrier
+                    && range == &PREALLOCATED_HANDLE_TYPE_PROP => {}
+            _otherwise => return Err(ErrorCode::ShapeMismatch),
+        }
+
+        let mut avoid = self.term_names(&relation)?;
+
+        let x = (fresh_name(&mut avoid), carrier.clone());
+        let y = (fresh_name(&mut avoid), carrier.clone());
+        let z = (fresh_name(&mut avoid), carrier.clone());
+        let c = (fresh_name(&mut avoid), class_type.clone());
+        let q = (fresh_name(&mut avoid), PREALLOCATED_HANDLE_TYPE_PROP);
+
+        let xv = self.term_register_variable(x.0, x.1.clone())?;
+        let yv = self.term_register_variable(y.0, y.1.clone())?;
+        let zv = self.term_register_variable(z.0, z.1.clone())?;
+        let cv = self.term_register_variable(c.0, c.1.clone())?;
+        let qv = self.term_register_variable(q.0, q.1.clone())?;
+
+        let rxx = self.quotient_relate(&relation, &xv, &xv)?;
+        let rxy = self.quotient_relate(&relation, &xv, &yv)?;
+        let ryx = self.quotient_relate(&relation, &yv, &xv)?;
+        let ryz = self.quotient_relate(&relation, &yv, &zv)?;
+        let rxz = self.quotient_relate(&relation, &xv, &zv)?;
+
+        let statement = self.term_register_forall(x.0, x.1.clone(), rxx)?;
+        self.quotient_check_statement(reflexivity, &statement)?;
+
+        let statement = self.term_register_implication(rxy.clone(), ryx)?;
+        let statement =
+            self.term_register_forall(y.0, y.1.clone(), statement)?;
+        let statement =
+            self.term_register_forall(x.0, x.1.clone(), statement)?;
+        self.quotient_check_statement(symmetry, &statement)?;
+
+        let statement = self.term_register_implication(ryz, rxz)?;
+        let statement = self.term_register_implication(rxy, statement)?;
+        let statement =
+            self.term_register_forall(z.0, z.1.clone(), statement)?;
+        let statement =
+            self.term_register_forall(y.0, y.1.clone(), statement)?;
+        let statement =
+            self.term_register_forall(x.0, x.1.clone(), statement)?;
+        self.quotient_check_statement(transitivity, &statement)?;
+
+        /* 2. Define the type of the classes, `P = λc. ∃x. c = R x`, witnessed
+         *    by the class of `x` itself.
+         */
+        let ry =
+            self.term_register_application(relation.clone(), yv.clone())?;
+        let body = self.term_register_equality(cv, ry)?;
+        let body = self.term_register_implication(body, qv.clone())?;
+        let body = self.term_register_forall(y.0, y.1.clone(), body)?;
+        let body = self.term_register_implication(body, qv.clone())?;
+        let body = self.term_register_forall(q.0, q.1.clone(), body)?;
+        let predicate = self.term_register_lambda(c.0, c.1.clone(), body)?;
+
+        let rx =
+            self.term_register_application(relation.clone(), xv.clone())?;
+        let witness = self.quotient_witness(&predicate, &rx, &y, &q)?;
+
+        let (tau, abs, rep, abs_rep, rep_abs) =
+            self.register_new_type_definition(&witness)?;
+
+        /* 3. Define the class function, `[·] = λx. abs (R x)`, and show that
+         *    `rep [x] = R x`.
+         */
+        let defn = self.term_register_application(abs.clone(), rx.clone())?;
+        let defn = self.term_register_lambda(x.0, x.1.clone(), defn)?;
+
+        let (class, class_definition) = self.register_new_definition(defn)?;
+
+        let rep_abs_rx =
+            self.theorem_register_forall_elimination(&rep_abs, rx.clone())?;
+        let rep_abs_rx = self.derived_equality_mp(&rep_abs_rx, &witness)?;
+
+        let unfold =
+            self.derived_unfold(&class_definition, slice::from_ref(&xv))?;
+        let reflexivity_rep = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                rep.clone(),
+            )?;
+        let representation =
+            self.theorem_register_application(reflexivity_rep, unfold)?;
+        let representation =
+            self.theorem_register_transitivity(representation, rep_abs_rx)?;
+        let representation =
+            self.derived_generalise(representation, slice::from_ref(&x))?;
+
+        let mut quotient = Quotient {
+            relation,
+            carrier,
+            tau: tau.clone(),
+            abs,
+            rep,
+            abs_rep,
+            rep_abs,
+            class,
+            class_definition,
+            representation,
+            class_equality: witness.clone(),
+            induction: witness,
+            reflexivity: reflexivity.clone(),
+            transitivity: transitivity.clone(),
+        };
+
+        /* 4. Derive the theorems about the quotient. */
+        quotient.class_equality =
+            self.quotient_class_equality(&quotient, symmetry, &x, &y, &z)?;
+        quotient.induction = self.quotient_induction(&quotient, &mut avoid)?;
+
+        self.quotients.insert(tau, quotient.clone());
+
+        Ok(quotient)
+    }
+
+    /// Derives `⊢ P (R x)`, where `P`, pointed-to by `predicate`, is the
+    /// predicate `λc. ∀q. (∀y. c = R y ⟶ q) ⟶ q` picking out the classes, for
+    /// the term `R x` pointed-to by `class`.
+    fn quotient_witness(
+        &mut self,
+        predicate: &Handle<tags::Term>,
+        class: &Handle<tags::Term>,
+        y: &(Name, Handle<tags::Type>),
+        q: &(Name, Handle<tags::Type>),
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let qv = self.term_register_variable(q.0, q.1.clone())?;
+
+        /* From `∀y. R x = R y ⟶ q`, instantiated at `x`, conclude `q`. */
+        let representative = self.term_split_application(class)?.1.clone();
+        let hypothesis = {
+            let yv = self.term_register_variable(y.0, y.1.clone())?;
+            let relation = self.term_split_application(class)?.0.clone();
+            let ry = self.term_register_application(relation, yv)?;
+            let body = self.term_register_equality(class.clone(), ry)?;
+            let body = self.term_register_implication(body, qv)?;
+
+            self.term_register_forall(y.0, y.1.clone(), body)?
+        };
+
+        let thm = self.theorem_register_assumption(hypothesis.clone())?;
+        let thm =
+            self.theorem_register_forall_elimination(thm, representative)?;
+        let reflexivity = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                class.clone(),
+            )?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, reflexivity)?;
+        let thm = self.derived_discharge(thm, &[hypothesis])?;
+        let thm = self.derived_generalise(thm, slice::from_ref(q))?;
+
+        /* Fold the definition of `P`. */
+        let application =
+            self.term_register_application(predicate.clone(), class.clone())?;
+        let beta = self.theorem_register_beta(application)?;
+        let fold = self.theorem_register_symmetry(beta)?;
+
+        self.derived_equality_mp(&fold, &thm)
+    }
+
+    /// Derives `⊢ ∀x y. R x y = ([x] = [y])` for `quotient`, using the
+    /// symmetry theorem of the relation, pointed-to by `symmetry`.
+    fn quotient_class_equality(
+        &mut self,
+        quotient: &Quotient,
+        symmetry: &Handle<tags::Theorem>,
+        x: &(Name, Handle<tags::Type>),
+        y: &(Name, Handle<tags::Type>),
+        z: &(Name, Handle<tags::Type>),
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let relation = quotient.relation.clone();
+        let xv = self.term_register_variable(x.0, x.1.clone())?;
+        let yv = self.term_register_variable(y.0, y.1.clone())?;
+        let zv = self.term_register_variable(z.0, z.1.clone())?;
+
+        let rxy = self.quotient_relate(&relation, &xv, &yv)?;
+        let rxz = self.quotient_relate(&relation, &xv, &zv)?;
+        let ryz = self.quotient_relate(&relation, &yv, &zv)?;
+
+        /* 1. From `R x y`, conclude `R x z = R y z` for every `z`, and hence
+         *    `R x = R y`, by extensionality.
+         */
+        let assumption = self.theorem_register_assumption(rxy.clone())?;
+
+        let thm =
+            self.derived_specialise(symmetry.clone(), &[x.clone(), y.clone()])?;
+        let ryx =
+            self.theorem_register_implication_elimination(thm, &assumption)?;
+        let thm = self.derived_specialise(
+            quotient.transitivity.clone(),
+            &[y.clone(), x.clone(), z.clone()],
+        )?;
+        let thm = self.theorem_register_implication_elimination(thm, ryx)?;
+        let assumption_xz = self.theorem_register_assumption(rxz.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption_xz)?;
+        let forward = self.derived_discharge(thm, slice::from_ref(&rxz))?;
+
+        let thm = self.derived_specialise(
+            quotient.transitivity.clone(),
+            &[x.clone(), y.clone(), z.clone()],
+        )?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, &assumption)?;
+        let assumption_yz = self.theorem_register_assumption(ryz.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption_yz)?;
+        let backward = self.derived_discharge(thm, slice::from_ref(&ryz))?;
+
+        let pointwise =
+            self.theorem_register_iff_introduction(forward, backward)?;
+        let lambda =
+            self.theorem_register_lambda(z.0, z.1.clone(), pointwise)?;
+
+        let eta_x = self.term_register_lambda(z.0, z.1.clone(), rxz)?;
+        let eta_x = self.theorem_register_eta(eta_x)?;
+        let eta_y = self.term_register_lambda(z.0, z.1.clone(), ryz)?;
+        let eta_y = self.theorem_register_eta(eta_y)?;
+
+        let eta_x = self.theorem_register_symmetry(eta_x)?;
+        let thm = self.theorem_register_transitivity(lambda, eta_y)?;
+        let classes = self.theorem_register_transitivity(eta_x, thm)?;
+
+        /* 2. Hence `abs (R x) = abs (R y)`, and so `[x] = [y]`. */
+        let reflexivity_abs = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                quotient.abs.clone(),
+            )?;
+        let thm =
+            self.theorem_register_application(reflexivity_abs, classes)?;
+
+        let unfold_x = self
+            .derived_unfold(&quotient.class_definition, slice::from_ref(&xv))?;
+        let unfold_y = self
+            .derived_unfold(&quotient.class_definition, slice::from_ref(&yv))?;
+        let fold_y = self.theorem_register_symmetry(unfold_y)?;
+
+        let thm = self.theorem_register_transitivity(thm, fold_y)?;
+        let thm = self.theorem_register_transitivity(unfold_x, thm)?;
+        let forward = self.derived_discharge(thm, slice::from_ref(&rxy))?;
+
+        /* 3. Conversely, from `[x] = [y]` conclude `rep [x] = rep [y]`, hence
+         *    `R x = R y`, and so `R x y` from `R y y`.
+         */
+        let class_x =
+            self.term_register_application(quotient.class.clone(), xv.clone())?;
+        let class_y =
+            self.term_register_application(quotient.class.clone(), yv.clone())?;
+        let equality = self.term_register_equality(class_x, class_y)?;
+
+        let assumption = self.theorem_register_assumption(equality.clone())?;
+        let reflexivity_rep = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                quotient.rep.clone(),
+            )?;
+        let thm =
+            self.theorem_register_application(reflexivity_rep, assumption)?;
+
+        let representation_x = self.theorem_register_forall_elimination(
+            &quotient.representation,
+            xv,
+        )?;
+        let representation_x =
+            self.theorem_register_symmetry(representation_x)?;
+        let representation_y = self.theorem_register_forall_elimination(
+            &quotient.representation,
+            yv.clone(),
+        )?;
+
+        let thm = self.theorem_register_transitivity(thm, representation_y)?;
+        let classes =
+            self.theorem_register_transitivity(representation_x, thm)?;
+
+        let reflexivity_y = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                yv.clone(),
+            )?;
+        let thm = self.theorem_register_application(classes, reflexivity_y)?;
+        let thm = self.theorem_register_symmetry(thm)?;
+        let ryy = self
+            .theorem_register_forall_elimination(&quotient.reflexivity, yv)?;
+        let thm = self.derived_equality_mp(&thm, &ryy)?;
+        let backward =
+            self.derived_discharge(thm, slice::from_ref(&equality))?;
+
+        let thm = self.theorem_register_iff_introduction(forward, backward)?;
+
+        self.derived_generalise(thm, &[x.clone(), y.clone()])
+    }
+
+    /// Derives the induction rule `⊢ ∀Q. (∀x. Q [x]) ⟶ (∀a. Q a)` for
+    /// `quotient`, choosing names for the bound variables that appear in none
+    /// of `avoid`.
+    fn quotient_induction(
+        &mut self,
+        quotient: &Quotient,
+        avoid: &mut Vec<Name>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let ptype = self.type_register_function(
+            quotient.tau.clone(),
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        )?;
+
+        let p = (fresh_name(avoid), ptype);
+        let a = (fresh_name(avoid), quotient.tau.clone());
+        let x = (fresh_name(avoid), quotient.carrier.clone());
+
+        let pv = self.term_register_variable(p.0, p.1.clone())?;
+        let av = self.term_register_variable(a.0, a.1.clone())?;
+        let xv = self.term_register_variable(x.0, x.1.clone())?;
+
+        /* 1. The hypothesis, `∀x. Q [x]`. */
+        let class_x =
+            self.term_register_application(quotient.class.clone(), xv.clone())?;
+        let hypothesis = self.term_register_application(pv.clone(), class_x)?;
+        let hypothesis =
+            self.term_register_forall(x.0, x.1.clone(), hypothesis)?;
+
+        /* 2. `P (rep a)`, as `rep (abs (rep a)) = rep a`, and unfolded. */
+        let rep_a =
+            self.term_register_application(quotient.rep.clone(), av.clone())?;
+
+        let rep_abs = self.theorem_register_forall_elimination(
+            &quotient.rep_abs,
+            rep_a.clone(),
+        )?;
+        let abs_rep = self.theorem_register_forall_elimination(
+            &quotient.abs_rep,
+            av.clone(),
+        )?;
+
+        let reflexivity_rep = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                quotient.rep.clone(),
+            )?;
+        let thm =
+            self.theorem_register_application(reflexivity_rep, &abs_rep)?;
+        let fold = self.theorem_register_symmetry(rep_abs.clone())?;
+        let thm = self.derived_equality_mp(&fold, &thm)?;
+
+        let (predicate, _right) =
+            self.term_split_equality(self.theorem_split_conclusion(&rep_abs)?)?;
+        let predicate = predicate.clone();
+        let beta = self.theorem_register_beta(predicate)?;
+        let thm = self.derived_equality_mp(&beta, &thm)?;
+
+        let qa = self.term_register_application(pv.clone(), av.clone())?;
+        let thm = self.theorem_register_forall_elimination(thm, qa.clone())?;
+
+        /* 3. Every class, `R x`, representing `a` yields `Q a`, as then
+         *    `a = abs (R x) = [x]`.
+         */
+        let rx = self
+            .term_register_application(quotient.relation.clone(), xv.clone())?;
+        let represents = self.term_register_equality(rep_a, rx)?;
+
+        let assumption =
+            self.theorem_register_assumption(represents.clone())?;
+        let reflexivity_abs = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                quotient.abs.clone(),
+            )?;
+        let step =
+            self.theorem_register_application(reflexivity_abs, assumption)?;
+
+        let unfold = self
+            .derived_unfold(&quotient.class_definition, slice::from_ref(&xv))?;
+        let fold = self.theorem_register_symmetry(unfold)?;
+        let step = self.theorem_register_transitivity(step, fold)?;
+        let abs_rep = self.theorem_register_symmetry(abs_rep)?;
+        let step = self.theorem_register_transitivity(abs_rep, step)?;
+
+        let reflexivity_p =
+            self.theorem_register_reflexivity::<Handle<tags::Term>, _>(pv)?;
+        let step = self.theorem_register_application(reflexivity_p, step)?;
+        let step = self.theorem_register_symmetry(step)?;
+
+        let instance = self.theorem_register_assumption(hypothesis.clone())?;
+        let instance =
+            self.theorem_register_forall_elimination(instance, xv)?;
+        let step = self.derived_equality_mp(&step, &instance)?;
+        let step =
+            self.derived_discharge(step, slice::from_ref(&represents))?;
+        let step = self.derived_generalise(step, slice::from_ref(&x))?;
+
+        /* 4. Conclude `Q a`, and generalise. */
+        let thm = self.theorem_register_implication_elimination(thm, step)?;
+        let thm = self.derived_generalise(thm, slice::from_ref(&a))?;
+        let thm = self.derived_discharge(thm, slice::from_ref(&hypothesis))?;
+
+        self.derived_generalise(thm, slice::from_ref(&p))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Lifting functions.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The variables bound in lifting a function of `n` arguments to a quotient.
+struct LiftBinders {
+    /// The representatives, `x₁` to `xₙ`, of the arguments of the function.
+    xs: Vec<(Name, Handle<tags::Type>)>,
+    /// The representatives, `y₁` to `yₙ`, quantified over in the image of the
+    /// arguments.
+    ys: Vec<(Name, Handle<tags::Type>)>,
+    /// The element, `z`, of the image of the arguments.
+    z: (Name, Handle<tags::Type>),
+    /// The proposition, `q`, quantified over in the encoding of `∃`.
+    q: (Name, Handle<tags::Type>),
+}
+
+impl RuntimeState {
+    /// Lifts the function `f : σ → … → σ → σ`, of `n` arguments and pointed-to
+    /// by `function`, to a function `f' : σ/R → … → σ/R → σ/R` on the quotient
+    /// type pointed-to by `tau`, and derives the theorem
+    /// `⊢ ∀x₁ … xₙ. f' [x₁] … [xₙ] = [f x₁ … xₙ]`.  Returns `Ok(lifted)` if
+    /// this process is successful.
+    ///
+    /// The function must be closed, and `respect` must point-to a theorem,
+    /// without premisses, stating that the function respects the relation:
+    ///
+    /// ```text
+    /// ⊢ ∀x₁ … xₙ y₁ … yₙ. R x₁ y₁ ⟶ … ⟶ R xₙ yₙ ⟶ R (f x₁ … xₙ) (f y₁ … yₙ)
+    /// ```
+    ///
+    /// The lifted function maps classes to the class of their image:
+    /// `f' a₁ … aₙ = abs (λz. ∃y₁ … yₙ. rep a₁ y₁ ∧ … ∧ rep aₙ yₙ ∧
+    /// R (f y₁ … yₙ) z)`.  Either every kernel object is registered, or, if an
+    /// error is returned, none are.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAQuotientType)` if the type pointed-to by
+    /// `tau` is not a quotient type.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `function` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `respect` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the function has free
+    /// variables, or type-variables not appearing in the relation, or does not
+    /// have a type of the form described above, or if the theorem pointed-to
+    /// by `respect` does not have the statement described above.
+    pub fn quotient_lift<T, U, V>(
+        &mut self,
+        tau: T,
+        function: U,
+        respect: V,
+    ) -> Result<LiftedFunction, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Into<Handle<tags::Term>>,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        let function = function.into();
+
+        info!(
+            "Lifting function {} to quotient type {}.",
+            function,
+            tau.borrow()
+        );
+
+        let quotient = self.quotient_resolve(tau)?.clone();
+
+        self.speculate_begin();
+
+        let result =
+            self.quotient_define_lift(&quotient, function, respect.borrow());
+
+        self.speculate_end(result.is_ok())?;
+
+        result
+    }
+
+    /// Performs the work of `quotient_lift`, leaving the caller to discard the
+    /// objects registered if this fails.
+    fn quotient_define_lift(
+        &mut self,
+        quotient: &Quotient,
+        function: Handle<tags::Term>,
+        respect: &Handle<tags::Theorem>,
+    ) -> Result<LiftedFunction, ErrorCode> {
+        /* 1. Check the function, and find its number of arguments. */
+        let mut range = self.term_type_infer(&function)?;
+        let mut arity = 0;
+
+        while range != quotient.carrier {
+            let (domain, inner) = self
+                .type_split_function(&range)
+                .map_err(|_e| ErrorCode::ShapeMismatch)?;
+
+            if domain != &quotient.carrier {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+
+            range = inner.clone();
+            arity += 1;
+        }
+
+        if !self.term_free_variables(&function)?.is_empty() {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        self.quotient_check_type_variables(quotient, &function)?;
+
+        /* 2. Check the statement of the respectfulness theorem. */
+        let mut avoid = self.term_names(&quotient.relation)?;
+        avoid.append(&mut self.term_names(&function)?);
+
+        let class_type = self.type_register_function(
+            quotient.carrier.clone(),
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        )?;
+
+        let mut binders = LiftBinders {
+            xs: Vec::new(),
+            ys: Vec::new(),
+            z: (fresh_name(&mut avoid), quotient.carrier.clone()),
+            q: (fresh_name(&mut avoid), PREALLOCATED_HANDLE_TYPE_PROP),
+        };
+        let mut cs = Vec::new();
+        let mut r#as = Vec::new();
+
+        for _i in 0..arity {
+            binders
+                .xs
+                .push((fresh_name(&mut avoid), quotient.carrier.clone()));
+            binders
+                .ys
+                .push((fresh_name(&mut avoid), quotient.carrier.clone()));
+            cs.push((fresh_name(&mut avoid), class_type.clone()));
+            r#as.push((fresh_name(&mut avoid), quotient.tau.clone()));
+        }
+
+        let xvs = self.quotient_variables(&binders.xs)?;
+        let yvs = self.quotient_variables(&binders.ys)?;
+
+        let fx = self.quotient_apply(&function, &xvs)?;
+        let fy = self.quotient_apply(&function, &yvs)?;
+
+        let mut statement =
+            self.quotient_relate(&quotient.relation, &fx, &fy)?;
+
+        for (xv, yv) in xvs.iter().zip(yvs.iter()).rev() {
+            let rxy = self.quotient_relate(&quotient.relation, xv, yv)?;
+
+            statement = self.term_register_implication(rxy, statement)?;
+        }
+
+        for (name, tau) in binders.xs.iter().chain(binders.ys.iter()).rev() {
+            statement =
+                self.term_register_forall(*name, tau.clone(), statement)?;
+        }
+
+        self.quotient_check_statement(respect, &statement)?;
+
+        /* 3. Define `f' = λa₁ … aₙ. abs (L (rep a₁) … (rep aₙ))`, where `L`
+         *    maps classes to the class of their image.
+         */
+        let mut reps = Vec::new();
+
+        for av in self.quotient_variables(&r#as)? {
+            reps.push(
+                self.term_register_application(quotient.rep.clone(), av)?,
+            );
+        }
+
+        let image =
+            self.quotient_image(quotient, &function, &reps, &binders)?;
+        let mut defn =
+            self.term_register_application(quotient.abs.clone(), image)?;
+
+        for (name, tau) in r#as.iter().rev() {
+            defn = self.term_register_lambda(*name, tau.clone(), defn)?;
+        }
+
+        let (constant, definition) = self.register_new_definition(defn)?;
+
+        /* 4. Unfold `f' [x₁] … [xₙ]` to `abs (L (rep [x₁]) … (rep [xₙ]))`. */
+        let mut classes = Vec::new();
+
+        for xv in xvs.iter() {
+            classes.push(self.term_register_application(
+                quotient.class.clone(),
+                xv.clone(),
+            )?);
+        }
+
+        let unfold = self.derived_unfold(&definition, &classes)?;
+
+        /* 5. Rewrite each `rep [xᵢ]` to `R xᵢ`, as the arguments of
+         *    `G = λc₁ … cₙ. abs (L c₁ … cₙ)`.
+         */
+        let cvs = self.quotient_variables(&cs)?;
+        let image = self.quotient_image(quotient, &function, &cvs, &binders)?;
+        let mut g =
+            self.term_register_application(quotient.abs.clone(), image)?;
+
+        for (name, tau) in cs.iter().rev() {
+            g = self.term_register_lambda(*name, tau.clone(), g)?;
+        }
+
+        let reflexivity_g =
+            self.theorem_register_reflexivity::<Handle<tags::Term>, _>(g)?;
+
+        let mut representatives = Vec::new();
+        let mut relations = Vec::new();
+        let mut rewrite = reflexivity_g.clone();
+
+        for xv in xvs.iter() {
+            let representation = self.theorem_register_forall_elimination(
+                &quotient.representation,
+                xv.clone(),
+            )?;
+            let (left, right) = self.term_split_equality(
+                self.theorem_split_conclusion(&representation)?,
+            )?;
+
+            representatives.push(left.clone());
+            relations.push(right.clone());
+
+            rewrite =
+                self.theorem_register_application(rewrite, representation)?;
+        }
+
+        let beta_left =
+            self.derived_unfold(&reflexivity_g, &representatives)?;
+        let beta_left = self.theorem_register_symmetry(beta_left)?;
+        let beta_right = self.derived_unfold(&reflexivity_g, &relations)?;
+
+        let rewrite =
+            self.theorem_register_transitivity(rewrite, beta_right)?;
+        let rewrite = self.theorem_register_transitivity(beta_left, rewrite)?;
+
+        /* 6. The image of the classes is the class of `f x₁ … xₙ`. */
+        let image =
+            self.quotient_lift_image(quotient, &function, respect, &binders)?;
+        let reflexivity_abs = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                quotient.abs.clone(),
+            )?;
+        let image =
+            self.theorem_register_application(reflexivity_abs, image)?;
+
+        let fold = self
+            .derived_unfold(&quotient.class_definition, slice::from_ref(&fx))?;
+        let fold = self.theorem_register_symmetry(fold)?;
+
+        let thm = self.theorem_register_transitivity(image, fold)?;
+        let thm = self.theorem_register_transitivity(rewrite, thm)?;
+        let thm = self.theorem_register_transitivity(unfold, thm)?;
+        let equation = self.derived_generalise(thm, &binders.xs)?;
+
+        Ok(LiftedFunction {
+            constant,
+            definition,
+            equation,
+        })
+    }
+
+    /// Returns `∀y₁ … yₙ. k₁ y₁ ⟶ … ⟶ kₙ yₙ ⟶ R (f y₁ … yₙ) z ⟶ q`, for the
+    /// classes, `k₁` to `kₙ`, pointed-to by `classes` and the function, `f`,
+    /// pointed-to by `function`.
+    fn quotient_image_premiss(
+        &mut self,
+        quotient: &Quotient,
+        function: &Handle<tags::Term>,
+        classes: &[Handle<tags::Term>],
+        binders: &LiftBinders,
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        let yvs = self.quotient_variables(&binders.ys)?;
+        let zv =
+            self.term_register_variable(binders.z.0, binders.z.1.clone())?;
+        let qv =
+            self.term_register_variable(binders.q.0, binders.q.1.clone())?;
+
+        let fy = self.quotient_apply(function, &yvs)?;
+        let rfz = self.quotient_relate(&quotient.relation, &fy, &zv)?;
+        let mut body = self.term_register_implication(rfz, qv)?;
+
+        for (class, yv) in classes.iter().zip(yvs.iter()).rev() {
+            let member =
+                self.term_register_application(class.clone(), yv.clone())?;
+
+            body = self.term_register_implication(member, body)?;
+        }
+
+        for (name, tau) in binders.ys.iter().rev() {
+            body = self.term_register_forall(*name, tau.clone(), body)?;
+        }
+
+        Ok(body)
+    }
+
+    /// Returns `λz. ∀q. (∀y₁ … yₙ. k₁ y₁ ⟶ … ⟶ kₙ yₙ ⟶ R (f y₁ … yₙ) z ⟶ q)
+    /// ⟶ q`, the image of the classes, `k₁` to `kₙ`, pointed-to by `classes`
+    /// under the function, `f`, pointed-to by `function`.
+    fn quotient_image(
+        &mut self,
+        quotient: &Quotient,
+        function: &Handle<tags::Term>,
+        classes: &[Handle<tags::Term>],
+        binders: &LiftBinders,
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        let qv =
+            self.term_register_variable(binders.q.0, binders.q.1.clone())?;
+
+        let body =
+            self.quotient_image_premiss(quotient, function, classes, binders)?;
+        let body = self.term_register_implication(body, qv)?;
+        let body =
+            self.term_register_forall(binders.q.0, binders.q.1.clone(), body)?;
+
+        self.term_register_lambda(binders.z.0, binders.z.1.clone(), body)
+    }
+
+    /// Derives `⊢ L (R x₁) … (R xₙ) = R (f x₁ … xₙ)`, where `L` is the image
+    /// of classes under the function, `f`, pointed-to by `function`, using the
+    /// respectfulness theorem of the function, pointed-to by `respect`.
+    fn quotient_lift_image(
+        &mut self,
+        quotient: &Quotient,
+        function: &Handle<tags::Term>,
+        respect: &Handle<tags::Theorem>,
+        binders: &LiftBinders,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let xvs = self.quotient_variables(&binders.xs)?;
+        let yvs = self.quotient_variables(&binders.ys)?;
+        let zv =
+            self.term_register_variable(binders.z.0, binders.z.1.clone())?;
+        let qv =
+            self.term_register_variable(binders.q.0, binders.q.1.clone())?;
+
+        let mut classes = Vec::new();
+
+        for xv in xvs.iter() {
+            classes.push(self.term_register_application(
+                quotient.relation.clone(),
+                xv.clone(),
+            )?);
+        }
+
+        let fx = self.quotient_apply(function, &xvs)?;
+        let fy = self.quotient_apply(function, &yvs)?;
+        let rfxz = self.quotient_relate(&quotient.relation, &fx, &zv)?;
+        let rfyz = self.quotient_relate(&quotient.relation, &fy, &zv)?;
+
+        let premiss =
+            self.quotient_image_premiss(quotient, function, &classes, binders)?;
+        let body = self.term_register_implication(premiss.clone(), qv)?;
+        let body =
+            self.term_register_forall(binders.q.0, binders.q.1.clone(), body)?;
+
+        /* 1. If `z` is in the image then `R (f x₁ … xₙ) z`, taking `q` to be
+         *    `R (f x₁ … xₙ) z`, as the function respects the relation.
+         */
+        let mut related = Vec::new();
+        let mut thm = self.quotient_instantiate(respect, &xvs)?;
+        thm = self.quotient_instantiate(&thm, &yvs)?;
+
+        for (xv, yv) in xvs.iter().zip(yvs.iter()) {
+            let rxy = self.quotient_relate(&quotient.relation, xv, yv)?;
+            let assumption = self.theorem_register_assumption(rxy.clone())?;
+
+            thm =
+                self.theorem_register_implication_elimination(thm, assumption)?;
+            related.push(rxy);
+        }
+
+        let transitivity =
+            self.quotient_instantiate(&quotient.transitivity, &[fx, fy, zv])?;
+        let thm =
+            self.theorem_register_implication_elimination(transitivity, thm)?;
+        let assumption = self.theorem_register_assumption(rfyz.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption)?;
+
+        related.push(rfyz);
+
+        let thm = self.derived_discharge(thm, &related)?;
+        let antecedent = self.derived_generalise(thm, &binders.ys)?;
+
+        let assumption = self.theorem_register_assumption(body.clone())?;
+        let instance =
+            self.theorem_register_forall_elimination(assumption, rfxz.clone())?;
+        let thm = self
+            .theorem_register_implication_elimination(instance, antecedent)?;
+        let forward = self.derived_discharge(thm, slice::from_ref(&body))?;
+
+        /* 2. Conversely, `z` is in the image if `R (f x₁ … xₙ) z`, taking each
+         *    `yᵢ` to be `xᵢ`.
+         */
+        let assumption = self.theorem_register_assumption(premiss.clone())?;
+        let mut thm = self.quotient_instantiate(&assumption, &xvs)?;
+
+        for xv in xvs {
+            let reflexivity = self.theorem_register_forall_elimination(
+                &quotient.reflexivity,
+                xv,
+            )?;
+
+            thm = self
+                .theorem_register_implication_elimination(thm, reflexivity)?;
+        }
+
+        let assumption = self.theorem_register_assumption(rfxz.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption)?;
+        let thm = self.derived_discharge(thm, slice::from_ref(&premiss))?;
+        let thm = self.derived_generalise(thm, slice::from_ref(&binders.q))?;
+        let backward = self.derived_discharge(thm, slice::from_ref(&rfxz))?;
+
+        /* 3. Hence the image, and `R (f x₁ … xₙ)`, are extensionally equal. */
+        let pointwise =
+            self.theorem_register_iff_introduction(forward, backward)?;
+        let lambda = self.theorem_register_lambda(
+            binders.z.0,
+            binders.z.1.clone(),
+            pointwise,
+        )?;
+        let eta =
+            self.term_register_lambda(binders.z.0, binders.z.1.clone(), rfxz)?;
+        let eta = self.theorem_register_eta(eta)?;
+
+        self.theorem_register_transitivity(lambda, eta)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Transferring theorems.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Transfers the theorem `Γ ⊢ R s t`, pointed-to by `handle`, relating two
+    /// elements by the relation of the quotient type pointed-to by `tau`, to
+    /// the theorem `Γ ⊢ [s] = [t]`, equating their classes.  Returns
+    /// `Ok(handle)` if this process is successful, where `handle` points-to
+    /// the new theorem.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAQuotientType)` if the type pointed-to by
+    /// `tau` is not a quotient type.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the conclusion of the
+    /// theorem is not an application of the relation of the quotient type to
+    /// two arguments.
+    pub fn quotient_transfer<T, U>(
+        &mut self,
+        tau: T,
+        handle: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Transferring theorem {} to quotient type {}.",
+            handle.borrow(),
+            tau.borrow()
+        );
+
+        let quotient = self.quotient_resolve(tau)?;
+        let relation = quotient.relation.clone();
+        let class_equality = quotient.class_equality.clone();
+
+        let conclusion = self.theorem_split_conclusion(handle.borrow())?;
+
+        let (partial, right) = self
+            .term_split_application(&conclusion)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let (head, left) = self
+            .term_split_application(partial)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+
+        if head != &relation {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let arguments = [left.clone(), right.clone()];
+        let equality =
+            self.quotient_instantiate(&class_equality, &arguments)?;
+
+        self.derived_equality_mp(&equality, handle.borrow())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+    use std::slice;
+
+    /// Checks the proof of the theorem pointed-to by `handle`.
+    fn check(state: &mut RuntimeState, handle: &Handle<tags::Theorem>) {
+        let proof = state.theorem_proof(handle).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+    }
+
+    /// Returns `R a b`.
+    fn relate(
+        state: &mut RuntimeState,
+        relation: &Handle<tags::Term>,
+        a: &Handle<tags::Term>,
+        b: &Handle<tags::Term>,
+    ) -> Handle<tags::Term> {
+        let partial = state
+            .term_register_application(relation.clone(), a.clone())
+            .unwrap();
+
+        state.term_register_application(partial, b.clone()).unwrap()
+    }
+
+    /// Returns the equality relation, `R = λx y. x = y`, on `tau`, along with
+    /// its reflexivity, symmetry and transitivity theorems.
+    fn equality(
+        state: &mut RuntimeState,
+        tau: Handle<tags::Type>,
+    ) -> (
+        Handle<tags::Term>,
+        Handle<tags::Theorem>,
+        Handle<tags::Theorem>,
+        Handle<tags::Theorem>,
+    ) {
+        let x = state.term_register_variable(0u64, tau.clone()).unwrap();
+        let y = state.term_register_variable(1u64, tau.clone()).unwrap();
+        let z = state.term_register_variable(2u64, tau.clone()).unwrap();
+
+        let relation =
+            state.term_register_equality(x.clone(), y.clone()).unwrap();
+        let relation = state
+            .term_register_lambda(1u64, tau.clone(), relation)
+            .unwrap();
+        let relation = state
+            .term_register_lambda(0u64, tau.clone(), relation)
+            .unwrap();
+        let defn = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                relation.clone(),
+            )
+            .unwrap();
+
+        let x_ = (0u64, tau.clone());
+        let y_ = (1u64, tau.clone());
+        let z_ = (2u64, tau);
+
+        /* `∀x. R x x`. */
+        let unfold = state
+            .derived_unfold(&defn, &[x.clone(), x.clone()])
+            .unwrap();
+        let fold = state.theorem_register_symmetry(unfold).unwrap();
+        let thm = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x.clone())
+            .unwrap();
+        let thm = state.derived_equality_mp(&fold, &thm).unwrap();
+        let reflexivity =
+            state.derived_generalise(thm, slice::from_ref(&x_)).unwrap();
+
+        /* `∀x y. R x y ⟶ R y x`. */
+        let rxy = relate(state, &relation, &x, &y);
+        let assumption =
+            state.theorem_register_assumption(rxy.clone()).unwrap();
+        let unfold = state
+            .derived_unfold(&defn, &[x.clone(), y.clone()])
+            .unwrap();
+        let thm = state.derived_equality_mp(&unfold, &assumption).unwrap();
+        let thm = state.theorem_register_symmetry(thm).unwrap();
+        let unfold = state
+            .derived_unfold(&defn, &[y.clone(), x.clone()])
+            .unwrap();
+        let fold = state.theorem_register_symmetry(unfold).unwrap();
+        let thm = state.derived_equality_mp(&fold, &thm).unwrap();
+        let thm = state.derived_discharge(thm, slice::from_ref(&rxy)).unwrap();
+        let symmetry = state
+            .derived_generalise(thm, &[x_.clone(), y_.clone()])
+            .unwrap();
+
+        /* `∀x y z. R x y ⟶ R y z ⟶ R x z`. */
+        let ryz = relate(state, &relation, &y, &z);
+        let left = state.theorem_register_assumption(rxy.clone()).unwrap();
+        let right = state.theorem_register_assumption(ryz.clone()).unwrap();
+        let unfold = state
+            .derived_unfold(&defn, &[x.clone(), y.clone()])
+            .unwrap();
+        let left = state.derived_equality_mp(&unfold, &left).unwrap();
+        let unfold = state.derived_unfold(&defn, &[y, z.clone()]).unwrap();
+        let right = state.derived_equality_mp(&unfold, &right).unwrap();
+        let thm = state.theorem_register_transitivity(left, right).unwrap();
+        let unfold = state.derived_unfold(&defn, &[x, z]).unwrap();
+        let fold = state.theorem_register_symmetry(unfold).unwrap();
+        let thm = state.derived_equality_mp(&fold, &thm).unwrap();
+        let thm = state.derived_discharge(thm, &[rxy, ryz]).unwrap();
+        let transitivity =
+            state.derived_generalise(thm, &[x_, y_, z_]).unwrap();
+
+        (relation, reflexivity, symmetry, transitivity)
+    }
+
+    /// Tests the theorems derived for the quotient of a type by equality.
+    #[test]
+    pub fn quotient0() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let quotient = state
+            .quotient_register(
+                relation.clone(),
+                &reflexivity,
+                &symmetry,
+                &transitivity,
+            )
+            .unwrap();
+        let tau = quotient.tau().clone();
+
+        assert_eq!(quotient.relation(), &relation);
+        assert_eq!(state.quotient_resolve(&tau), Ok(&quotient));
+        assert_eq!(
+            state.term_type_infer(quotient.class()),
+            state.type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                tau.clone()
+            )
+        );
+
+        /* `∀x y. R x y = ([x] = [y])`. */
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let rxy = relate(&mut state, &relation, &x, &y);
+        let cx = state
+            .term_register_application(quotient.class().clone(), x.clone())
+            .unwrap();
+        let cy = state
+            .term_register_application(quotient.class().clone(), y)
+            .unwrap();
+        let expected = state.term_register_equality(cx, cy).unwrap();
+        let expected = state.term_register_equality(rxy, expected).unwrap();
+        let expected = state
+            .term_register_forall(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+        let expected = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(quotient.class_equality()),
+            Ok(expected)
+        );
+        assert_eq!(
+            state.theorem_split_premisses(quotient.class_equality()),
+            Ok(&[][..])
+        );
+
+        /* `∀Q. (∀x. Q [x]) ⟶ (∀a. Q a)`. */
+        let qtau = state
+            .type_register_function(tau.clone(), PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state.term_register_variable(5u64, qtau.clone()).unwrap();
+        let a = state.term_register_variable(6u64, tau.clone()).unwrap();
+        let cx = state
+            .term_register_application(quotient.class().clone(), x)
+            .unwrap();
+        let qcx = state.term_register_application(q.clone(), cx).unwrap();
+        let qa = state.term_register_application(q, a).unwrap();
+        let hypothesis = state
+            .term_register_forall(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, qcx)
+            .unwrap();
+        let conclusion = state.term_register_forall(6u64, tau, qa).unwrap();
+        let expected = state
+            .term_register_implication(hypothesis, conclusion)
+            .unwrap();
+        let expected =
+            state.term_register_forall(5u64, qtau, expected).unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(quotient.induction()),
+            Ok(expected)
+        );
+        assert_eq!(
+            state.theorem_split_premisses(quotient.induction()),
+            Ok(&[][..])
+        );
+
+        check(&mut state, quotient.abs_rep());
+        check(&mut state, quotient.rep_abs());
+        check(&mut state, quotient.class_definition());
+        check(&mut state, quotient.class_equality());
+        check(&mut state, quotient.induction());
+    }
+
+    /// Tests lifting the identity function, of one argument, to the quotient of
+    /// a type by equality.
+    #[test]
+    pub fn quotient1() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let quotient = state
+            .quotient_register(
+                relation.clone(),
+                &reflexivity,
+                &symmetry,
+                &transitivity,
+            )
+            .unwrap();
+
+        /* `f = λx. x`, and `∀x y. R x y ⟶ R (f x) (f y)`. */
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let function = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                x.clone(),
+            )
+            .unwrap();
+        let fx = state
+            .term_register_application(function.clone(), x.clone())
+            .unwrap();
+        let fy = state
+            .term_register_application(function.clone(), y.clone())
+            .unwrap();
+
+        let defn = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                relation.clone(),
+            )
+            .unwrap();
+        let identity = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                function.clone(),
+            )
+            .unwrap();
+
+        let rxy = relate(&mut state, &relation, &x, &y);
+        let assumption =
+            state.theorem_register_assumption(rxy.clone()).unwrap();
+        let unfold = state
+            .derived_unfold(&defn, &[x.clone(), y.clone()])
+            .unwrap();
+        let thm = state.derived_equality_mp(&unfold, &assumption).unwrap();
+        let left = state
+            .derived_unfold(&identity, slice::from_ref(&x))
+            .unwrap();
+        let right = state.derived_unfold(&identity, &[y]).unwrap();
+        let right = state.theorem_register_symmetry(right).unwrap();
+        let thm = state.theorem_register_transitivity(thm, right).unwrap();
+        let thm = state.theorem_register_transitivity(left, thm).unwrap();
+        let unfold = state.derived_unfold(&defn, &[fx.clone(), fy]).unwrap();
+        let fold = state.theorem_register_symmetry(unfold).unwrap();
+        let thm = state.derived_equality_mp(&fold, &thm).unwrap();
+        let thm = state.derived_discharge(thm, &[rxy]).unwrap();
+        let respect = state
+            .derived_generalise(
+                thm,
+                &[
+                    (0u64, PREALLOCATED_HANDLE_TYPE_ALPHA),
+                    (1u64, PREALLOCATED_HANDLE_TYPE_ALPHA),
+                ],
+            )
+            .unwrap();
+
+        let lifted = state
+            .quotient_lift(quotient.tau(), function, &respect)
+            .unwrap();
+
+        /* `∀x. f' [x] = [f x]`. */
+        let cx = state
+            .term_register_application(quotient.class().clone(), x)
+            .unwrap();
+        let left = state
+            .term_register_application(lifted.constant().clone(), cx)
+            .unwrap();
+        let right = state
+            .term_register_application(quotient.class().clone(), fx)
+            .unwrap();
+        let expected = state.term_register_equality(left, right).unwrap();
+        let expected = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(lifted.equation()),
+            Ok(expected)
+        );
+        assert_eq!(
+            state.theorem_split_premisses(lifted.equation()),
+            Ok(&[][..])
+        );
+
+        check(&mut state, lifted.definition());
+        check(&mut state, lifted.equation());
+    }
+
+    /// Tests lifting a constant, a function of no arguments, to the quotient of
+    /// `Prop` by equality.
+    #[test]
+    pub fn quotient2() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_PROP);
+        let quotient = state
+            .quotient_register(
+                relation.clone(),
+                &reflexivity,
+                &symmetry,
+                &transitivity,
+            )
+            .unwrap();
+
+        /* `⊢ R T T`. */
+        let respect = state
+            .theorem_register_forall_elimination(
+                &reflexivity,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        let lifted = state
+            .quotient_lift(
+                quotient.tau(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                &respect,
+            )
+            .unwrap();
+
+        /* `f' = [T]`. */
+        let right = state
+            .term_register_application(
+                quotient.class().clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let expected = state
+            .term_register_equality(lifted.constant().clone(), right)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(lifted.equation()),
+            Ok(expected)
+        );
+
+        check(&mut state, lifted.equation());
+
+        /* Functions must respect the relation. */
+        assert_eq!(
+            state.quotient_lift(
+                quotient.tau(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                &reflexivity
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+    }
+
+    /// Tests transferring theorems relating representatives to theorems
+    /// equating their classes.
+    #[test]
+    pub fn quotient3() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let quotient = state
+            .quotient_register(
+                relation.clone(),
+                &reflexivity,
+                &symmetry,
+                &transitivity,
+            )
+            .unwrap();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let rxy = relate(&mut state, &relation, &x, &y);
+        let assumption =
+            state.theorem_register_assumption(rxy.clone()).unwrap();
+
+        let thm = state
+            .quotient_transfer(quotient.tau(), &assumption)
+            .unwrap();
+
+        let cx = state
+            .term_register_application(quotient.class().clone(), x.clone())
+            .unwrap();
+        let cy = state
+            .term_register_application(quotient.class().clone(), y)
+            .unwrap();
+        let expected = state.term_register_equality(cx, cy).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(expected));
+        assert_eq!(state.theorem_split_premisses(&thm), Ok(&[rxy][..]));
+
+        check(&mut state, &thm);
+
+        let thm = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x)
+            .unwrap();
+
+        assert_eq!(
+            state.quotient_transfer(quotient.tau(), &thm),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state
+                .quotient_transfer(PREALLOCATED_HANDLE_TYPE_ALPHA, &assumption),
+            Err(ErrorCode::NotAQuotientType)
+        );
+        assert_eq!(
+            state.quotient_transfer(Handle::from(0), &assumption),
+            Err(ErrorCode::NoSuchTypeRegistered)
+        );
+    }
+
+    /// Tests that relations which are not closed, or are not homogeneous, or
+    /// are not shown to be equivalences, are rejected, and that nothing is
+    /// registered when they are.
+    #[test]
+    pub fn quotient4() {
+        let mut state = RuntimeState::new();
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_ALPHA);
+
+        /* The theorems must be given in order. */
+        assert_eq!(
+            state.quotient_register(
+                relation.clone(),
+                &symmetry,
+                &reflexivity,
+                &transitivity
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* `λx y. x = w` is not closed. */
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let w = state
+            .term_register_variable(3u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let open = state.term_register_equality(x, w).unwrap();
+        let open = state
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA, open)
+            .unwrap();
+        let open = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, open)
+            .unwrap();
+
+        assert_eq!(
+            state.quotient_register(
+                open,
+                &reflexivity,
+                &symmetry,
+                &transitivity
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* `λx:α y:β. T` is not homogeneous. */
+        let mixed = state
+            .term_register_lambda(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let mixed = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, mixed)
+            .unwrap();
+
+        assert_eq!(
+            state.quotient_register(
+                mixed,
+                &reflexivity,
+                &symmetry,
+                &transitivity
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.quotient_register(
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                &reflexivity,
+                &symmetry,
+                &transitivity
+            ),
+            Err(ErrorCode::NotAFunctionType)
+        );
+
+        assert_eq!(state.speculation_depth(), 0);
+        assert!(state.quotients.is_empty());
+    }
+
+    /// Tests that functions whose types do not match the quotient, or which
+    /// mention type-variables not appearing in the relation, are not lifted.
+    #[test]
+    pub fn quotient5() {
+        let mut state = RuntimeState::new();
+
+        let (relation, reflexivity, symmetry, transitivity) =
+            equality(&mut state, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let quotient = state
+            .quotient_register(relation, &reflexivity, &symmetry, &transitivity)
+            .unwrap();
+
+        /* `λx. T` does not have a type of the form `α → α`. */
+        let predicate = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.quotient_lift(quotient.tau(), predicate, &reflexivity),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* `λx. (λp. x) ((λz:β. z) = (λz:β. z))` mentions `β`. */
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let z = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let identity = state
+            .term_register_lambda(2u64, PREALLOCATED_HANDLE_TYPE_BETA, z)
+            .unwrap();
+        let argument = state
+            .term_register_equality(identity.clone(), identity)
+            .unwrap();
+        let function = state
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_PROP, x)
+            .unwrap();
+        let function =
+            state.term_register_application(function, argument).unwrap();
+        let function = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                function,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.quotient_lift(quotient.tau(), function, &reflexivity),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.quotient_lift(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                &reflexivity
+            ),
+            Err(ErrorCode::NotAQuotientType)
+        );
+        assert_eq!(state.speculation_depth(), 0);
+    }
+}
@@ -0,0 +1,909 @@
+//! # Recursive functions
+//!
+//! A definitional package for functions defined by well-founded recursion.
+//! The user provides the recursive equation of the function, `∀x. f x = t`,
+//! where the function, `f`, is the only free variable of the right-hand side,
+//! `t`, along with a relation, `R`, below which every recursive call is made.
+//! Before the function is defined the user must discharge two obligations,
+//! stating that `R` is well-founded and that the value of `t` depends only on
+//! the values of `f` at elements `R`-below `x`:
+//!
+//! ```text
+//! ∀P. (∀x. (∀y. R y x ⟶ P y) ⟶ P x) ⟶ (∀x. P x)
+//! ∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ t = t[f ↦ g]
+//! ```
+//!
+//! Given proofs of both, the package defines a new constant, `c`, with the
+//! kernel's recursive definition principle, and derives the recursive
+//! equation, `⊢ ∀x. c x = t[f ↦ c]`, as a theorem.
+//!
+//! The principle itself, `register_new_recursive_definition`, is stated in
+//! terms of the functional, `F = λf x. t`, and so forms part of the kernel's
+//! trusted base, much like the type-definition principle.  Everything in this
+//! module is derived from it with the kernel's inference rules, inside a
+//! speculative branch, so registration is all-or-nothing.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    name::{fresh, Name},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, slice};
+
+////////////////////////////////////////////////////////////////////////////////
+// Recursive functions.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A function defined by well-founded recursion, along with the theorems
+/// derived for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecursiveFunction {
+    /// The constant naming the function.
+    constant: Handle<tags::Term>,
+    /// The defining equation of the function, stated with the functional.
+    definition: Handle<tags::Theorem>,
+    /// The recursive equation of the function.
+    equation: Handle<tags::Theorem>,
+}
+
+impl RecursiveFunction {
+    /// Returns the constant, `c`, naming the function.
+    #[inline]
+    pub fn constant(&self) -> &Handle<tags::Term> {
+        &self.constant
+    }
+
+    /// Returns the defining equation, `⊢ ∀x. c x = F c x`, of the function,
+    /// where `F = λf x. t` is the functional of the recursive equation.
+    #[inline]
+    pub fn definition(&self) -> &Handle<tags::Theorem> {
+        &self.definition
+    }
+
+    /// Returns the recursive equation, `⊢ ∀x. c x = t[f ↦ c]`, of the
+    /// function.
+    #[inline]
+    pub fn equation(&self) -> &Handle<tags::Theorem> {
+        &self.equation
+    }
+}
+
+/// A recursive equation, `∀x. f x = t`, split into its parts.
+struct Equation {
+    /// The function, `f`, being defined.
+    function: (Name, Handle<tags::Type>),
+    /// The argument, `x`, of the function.
+    argument: (Name, Handle<tags::Type>),
+    /// The right-hand side, `t`, of the equation.
+    body: Handle<tags::Term>,
+    /// The functional, `F = λf x. t`, of the equation.
+    functional: Handle<tags::Term>,
+}
+
+impl RuntimeState {
+    /// Splits the recursive equation, `∀x. f x = t`, pointed-to by `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the term pointed-to by
+    /// `handle` does not have the form described above, or has a free
+    /// variable other than `f`, or if `f` and `x` share a name.
+    fn recursion_split_equation(
+        &mut self,
+        handle: &Handle<tags::Term>,
+    ) -> Result<Equation, ErrorCode> {
+        let (name, tau, equality) = self
+            .term_split_forall(handle)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let argument = (*name, tau.clone());
+
+        let (left, body) = self
+            .term_split_equality(equality)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let (left, body) = (left.clone(), body.clone());
+
+        let (function, x) = self
+            .term_split_application(&left)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let x = x.clone();
+
+        let (name, tau) = self
+            .term_split_variable(function)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let function = (*name, tau.clone());
+
+        if function.0 == argument.0
+            || self.term_split_variable(&x) != Ok((&argument.0, &argument.1))
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        if self
+            .term_free_variables(handle)?
+            .iter()
+            .any(|(name, tau)| **name != function.0 || **tau != function.1)
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let functional = self.term_register_lambda(
+            argument.0,
+            argument.1.clone(),
+            body.clone(),
+        )?;
+        let functional = self.term_register_lambda(
+            function.0,
+            function.1.clone(),
+            functional,
+        )?;
+
+        Ok(Equation {
+            function,
+            argument,
+            body,
+            functional,
+        })
+    }
+
+    /// Returns the termination obligation,
+    /// `∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ t = t[f ↦ g]`, of `equation`, for
+    /// the relation pointed-to by `relation`, along with the names of `g` and
+    /// `y`.
+    fn recursion_termination(
+        &mut self,
+        equation: &Equation,
+        relation: &Handle<tags::Term>,
+    ) -> Result<(Handle<tags::Term>, Name, Name), ErrorCode> {
+        let mut avoid = self.term_names(&equation.functional)?;
+        avoid.append(&mut self.term_names(relation)?);
+
+        let g = fresh(avoid.iter().cloned());
+        avoid.push(g);
+        let y = fresh(avoid.iter().cloned());
+
+        let (f, tau) = equation.function.clone();
+        let (x, alpha) = equation.argument.clone();
+
+        let fv = self.term_register_variable(f, tau.clone())?;
+        let gv = self.term_register_variable(g, tau.clone())?;
+        let xv = self.term_register_variable(x, alpha.clone())?;
+        let yv = self.term_register_variable(y, alpha.clone())?;
+
+        let ry =
+            self.term_register_application(relation.clone(), yv.clone())?;
+        let ryx = self.term_register_application(ry, xv)?;
+        let fy = self.term_register_application(fv, yv.clone())?;
+        let gy = self.term_register_application(gv.clone(), yv)?;
+        let agree = self.term_register_equality(fy, gy)?;
+        let agree = self.term_register_implication(ryx, agree)?;
+        let agree = self.term_register_forall(y, alpha.clone(), agree)?;
+
+        let substituted = self.substitution(
+            equation.body.clone(),
+            vec![((f, tau.clone()), gv)],
+        )?;
+        let mut obligation =
+            self.term_register_equality(equation.body.clone(), substituted)?;
+        obligation = self.term_register_implication(agree, obligation)?;
+
+        for (name, tau) in [(x, alpha), (g, tau.clone()), (f, tau)] {
+            obligation = self.term_register_forall(name, tau, obligation)?;
+        }
+
+        Ok((obligation, g, y))
+    }
+
+    /// Returns `Ok((well_founded, termination))`, the obligations that must be
+    /// proved before defining a function with the recursive equation,
+    /// `∀x. f x = t`, pointed-to by `equation`, with `recursion_register`.
+    /// These state that the relation, `R`, pointed-to by `relation` is
+    /// well-founded, and that every recursive call is made `R`-below `x`:
+    ///
+    /// ```text
+    /// ∀P. (∀x. (∀y. R y x ⟶ P y) ⟶ P x) ⟶ (∀x. P x)
+    /// ∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ t = t[f ↦ g]
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `equation` or
+    /// `relation` do not point-to registered terms in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `equation` does not point-to
+    /// a recursive equation of the form described above, whose only free
+    /// variable is `f`, or if the relation is not closed, or does not relate
+    /// arguments of the function, or if the equation or relation mention a
+    /// type-variable not appearing in the type of the function.
+    pub fn recursion_obligations<T, U>(
+        &mut self,
+        equation: T,
+        relation: U,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Computing obligations of recursive equation {} with relation {}.",
+            equation.borrow(),
+            relation.borrow()
+        );
+
+        let equation = self.recursion_split_equation(equation.borrow())?;
+        let (well_founded, _congruence) = self
+            .recursive_definition_obligations(
+                &equation.functional,
+                relation.borrow(),
+            )?;
+        let (termination, _g, _y) =
+            self.recursion_termination(&equation, relation.borrow())?;
+
+        Ok((well_founded, termination))
+    }
+
+    /// Defines a new function by well-founded recursion, with the recursive
+    /// equation, `∀x. f x = t`, pointed-to by `equation`, and derives the
+    /// theorem `⊢ ∀x. c x = t[f ↦ c]` for the new constant, `c`.  The theorems
+    /// pointed-to by `well_founded` and `termination` must have no premisses,
+    /// and must prove the two obligations returned by `recursion_obligations`
+    /// for the relation pointed-to by `relation`, in order.  Returns
+    /// `Ok(function)` if this process is successful.
+    ///
+    /// Either every kernel object is registered, or, if an error is returned,
+    /// none are.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `equation` or
+    /// `relation` do not point-to registered terms in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `well_founded` or
+    /// `termination` do not point-to registered theorems in the runtime
+    /// state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the equation or relation are
+    /// rejected by `recursion_obligations`, or if either theorem has premisses
+    /// or does not prove its obligation.
+    pub fn recursion_register<T, U, V, W>(
+        &mut self,
+        equation: T,
+        relation: U,
+        well_founded: V,
+        termination: W,
+    ) -> Result<RecursiveFunction, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+        V: Borrow<Handle<tags::Theorem>>,
+        W: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Registering recursive function with equation {}.",
+            equation.borrow()
+        );
+
+        self.speculate_begin();
+
+        let result = self.recursion_define(
+            equation.borrow(),
+            relation.borrow(),
+            well_founded.borrow(),
+            termination.borrow(),
+        );
+
+        self.speculate_end(result.is_ok())?;
+
+        result
+    }
+
+    /// Performs the work of `recursion_register`, leaving the caller to
+    /// discard the objects registered if this fails.
+    fn recursion_define(
+        &mut self,
+        equation: &Handle<tags::Term>,
+        relation: &Handle<tags::Term>,
+        well_founded: &Handle<tags::Theorem>,
+        termination: &Handle<tags::Theorem>,
+    ) -> Result<RecursiveFunction, ErrorCode> {
+        /* 1. Check the termination theorem proves its obligation.  The
+         *    well-foundedness theorem is checked by the definition principle.
+         */
+        let equation = self.recursion_split_equation(equation)?;
+        let (obligation, g, y) =
+            self.recursion_termination(&equation, relation)?;
+
+        if !self.theorem_split_premisses(termination)?.is_empty()
+            || self.theorem_split_conclusion(termination)? != obligation
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        /* 2. Restate the termination theorem with the functional, as
+         *    `∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ F f x = F g x`.
+         */
+        let (f, tau) = equation.function.clone();
+        let (x, alpha) = equation.argument.clone();
+
+        let binders = [(f, tau.clone()), (g, tau), (x, alpha.clone())];
+
+        let fv = self.term_register_variable(f, binders[0].1.clone())?;
+        let gv = self.term_register_variable(g, binders[1].1.clone())?;
+        let xv = self.term_register_variable(x, alpha.clone())?;
+        let yv = self.term_register_variable(y, alpha)?;
+
+        let ry =
+            self.term_register_application(relation.clone(), yv.clone())?;
+        let ryx = self.term_register_application(ry, xv.clone())?;
+        let fy = self.term_register_application(fv.clone(), yv.clone())?;
+        let gy = self.term_register_application(gv.clone(), yv)?;
+        let agree = self.term_register_equality(fy, gy)?;
+        let agree = self.term_register_implication(ryx, agree)?;
+        let agree =
+            self.term_register_forall(y, binders[2].1.clone(), agree)?;
+
+        let thm = self.derived_specialise(termination.clone(), &binders)?;
+        let assumption = self.theorem_register_assumption(agree.clone())?;
+        let thm =
+            self.theorem_register_implication_elimination(thm, assumption)?;
+
+        let reflexivity = self
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                equation.functional.clone(),
+            )?;
+        let unfold_f = self.derived_unfold(&reflexivity, &[fv, xv.clone()])?;
+        let unfold_g = self.derived_unfold(&reflexivity, &[gv, xv.clone()])?;
+        let fold_g = self.theorem_register_symmetry(unfold_g)?;
+
+        let thm = self.theorem_register_transitivity(thm, fold_g)?;
+        let thm = self.theorem_register_transitivity(unfold_f, thm)?;
+        let thm = self.derived_discharge(thm, slice::from_ref(&agree))?;
+        let congruence = self.derived_generalise(thm, &binders)?;
+
+        /* 3. Define the function, and derive its recursive equation. */
+        let (constant, definition) = self.register_new_recursive_definition(
+            &equation.functional,
+            relation,
+            well_founded,
+            &congruence,
+        )?;
+
+        let thm = self.derived_specialise(
+            definition.clone(),
+            slice::from_ref(&binders[2]),
+        )?;
+        let unfold =
+            self.derived_unfold(&reflexivity, &[constant.clone(), xv])?;
+        let thm = self.theorem_register_transitivity(thm, unfold)?;
+        let equation =
+            self.derived_generalise(thm, slice::from_ref(&binders[2]))?;
+
+        Ok(RecursiveFunction {
+            constant,
+            definition,
+            equation,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TERM_FALSE,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        name::Name,
+        runtime_state::RuntimeState,
+    };
+    use std::slice;
+
+    /// Checks the proof of the theorem pointed-to by `handle`.
+    fn check(state: &mut RuntimeState, handle: &Handle<tags::Theorem>) {
+        let proof = state.theorem_proof(handle).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+    }
+
+    /// Returns `R a b`.
+    fn relate(
+        state: &mut RuntimeState,
+        relation: &Handle<tags::Term>,
+        a: &Handle<tags::Term>,
+        b: &Handle<tags::Term>,
+    ) -> Handle<tags::Term> {
+        let partial = state
+            .term_register_application(relation.clone(), a.clone())
+            .unwrap();
+
+        state.term_register_application(partial, b.clone()).unwrap()
+    }
+
+    /// Returns the empty relation, `R = λa b. F`, on `α`, along with the
+    /// theorem stating that it is well-founded.
+    fn empty(
+        state: &mut RuntimeState,
+    ) -> (Handle<tags::Term>, Handle<tags::Theorem>) {
+        let relation = state
+            .term_register_lambda(
+                10u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TERM_FALSE,
+            )
+            .unwrap();
+        let relation = state
+            .term_register_lambda(
+                11u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                relation,
+            )
+            .unwrap();
+
+        let pty = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let p = state.term_register_variable(20u64, pty.clone()).unwrap();
+        let x = state
+            .term_register_variable(21u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(22u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+
+        let ryx = relate(state, &relation, &y, &x);
+        let px = state
+            .term_register_application(p.clone(), x.clone())
+            .unwrap();
+        let py = state.term_register_application(p, y.clone()).unwrap();
+
+        let below = state
+            .term_register_implication(ryx.clone(), py.clone())
+            .unwrap();
+        let below = state
+            .term_register_forall(22u64, PREALLOCATED_HANDLE_TYPE_ALPHA, below)
+            .unwrap();
+        let step = state.term_register_implication(below, px).unwrap();
+        let step = state
+            .term_register_forall(21u64, PREALLOCATED_HANDLE_TYPE_ALPHA, step)
+            .unwrap();
+
+        /* From `R y x ⊢ F` conclude `⊢ ∀y. R y x ⟶ P y`, and so `P x`. */
+        let reflexivity = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                relation.clone(),
+            )
+            .unwrap();
+        let unfold = state.derived_unfold(&reflexivity, &[y, x]).unwrap();
+        let falsity = state.theorem_register_assumption(ryx.clone()).unwrap();
+        let falsity = state.derived_equality_mp(&unfold, &falsity).unwrap();
+        let below = state
+            .theorem_register_falsity_elimination(falsity, py)
+            .unwrap();
+        let below = state
+            .derived_discharge(below, slice::from_ref(&ryx))
+            .unwrap();
+        let below = state
+            .derived_generalise(
+                below,
+                &[(22u64, PREALLOCATED_HANDLE_TYPE_ALPHA)],
+            )
+            .unwrap();
+
+        let thm = state.theorem_register_assumption(step.clone()).unwrap();
+        let thm = state
+            .derived_specialise(thm, &[(21u64, PREALLOCATED_HANDLE_TYPE_ALPHA)])
+            .unwrap();
+        let thm = state
+            .theorem_register_implication_elimination(thm, below)
+            .unwrap();
+        let thm = state
+            .derived_generalise(thm, &[(21u64, PREALLOCATED_HANDLE_TYPE_ALPHA)])
+            .unwrap();
+        let thm = state
+            .derived_discharge(thm, slice::from_ref(&step))
+            .unwrap();
+        let thm = state.derived_generalise(thm, &[(20u64, pty)]).unwrap();
+
+        (relation, thm)
+    }
+
+    /// Proves the obligation, `∀x̄. H ⟶ C`, pointed-to by `obligation`, given a
+    /// function proving its consequent, `C`.
+    fn prove<F>(
+        state: &mut RuntimeState,
+        obligation: &Handle<tags::Term>,
+        consequent: F,
+    ) -> Handle<tags::Theorem>
+    where
+        F: FnOnce(
+            &mut RuntimeState,
+            &Handle<tags::Term>,
+        ) -> Handle<tags::Theorem>,
+    {
+        let mut binders: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+        let mut body = obligation.clone();
+
+        while let Ok((name, tau, inner)) = state.term_split_forall(&body) {
+            binders.push((*name, tau.clone()));
+            body = inner.clone();
+        }
+
+        let (antecedent, conclusion) =
+            state.term_split_implication(&body).unwrap();
+        let (antecedent, conclusion) = (antecedent.clone(), conclusion.clone());
+
+        let thm = consequent(state, &conclusion);
+        let thm = state
+            .derived_discharge(thm, slice::from_ref(&antecedent))
+            .unwrap();
+
+        state.derived_generalise(thm, &binders).unwrap()
+    }
+
+    /// Defines the function `c x = x` by recursion over the empty relation,
+    /// and checks its theorems.
+    #[test]
+    pub fn recursion0() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let fty = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+            )
+            .unwrap();
+        let f = state.term_register_variable(1u64, fty.clone()).unwrap();
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let fx = state.term_register_application(f, x.clone()).unwrap();
+        let equation = state.term_register_equality(fx, x.clone()).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        let (relation, well_founded) = empty(&mut state);
+
+        let (wf, termination) =
+            state.recursion_obligations(&equation, &relation).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&well_founded), Ok(wf));
+
+        let termination =
+            prove(&mut state, &termination, |state, conclusion| {
+                let (left, _right) =
+                    state.term_split_equality(conclusion).unwrap();
+                let left = left.clone();
+
+                state
+                    .theorem_register_reflexivity::<Handle<tags::Term>, _>(left)
+                    .unwrap()
+            });
+
+        let function = state
+            .recursion_register(
+                &equation,
+                &relation,
+                &well_founded,
+                &termination,
+            )
+            .unwrap();
+
+        assert_eq!(state.term_type_infer(function.constant()), Ok(fty));
+
+        let cx = state
+            .term_register_application(function.constant().clone(), x.clone())
+            .unwrap();
+        let expected = state.term_register_equality(cx, x).unwrap();
+        let expected = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+
+        assert!(state
+            .theorem_split_premisses(function.equation())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            state.theorem_split_conclusion(function.equation()),
+            Ok(expected)
+        );
+
+        check(&mut state, function.definition());
+        check(&mut state, function.equation());
+    }
+
+    /// Defines the function `c x = (c x = c x)`, whose right-hand side
+    /// mentions the function, and checks its recursive equation.
+    #[test]
+    pub fn recursion1() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let fty = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let f = state.term_register_variable(1u64, fty.clone()).unwrap();
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let fx = state.term_register_application(f, x.clone()).unwrap();
+        let body = state
+            .term_register_equality(fx.clone(), fx.clone())
+            .unwrap();
+        let equation = state.term_register_equality(fx, body).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        let (relation, well_founded) = empty(&mut state);
+
+        let (_wf, termination) =
+            state.recursion_obligations(&equation, &relation).unwrap();
+
+        /* Both sides of `(f x = f x) = (g x = g x)` are provable. */
+        let termination =
+            prove(&mut state, &termination, |state, conclusion| {
+                let (left, right) =
+                    state.term_split_equality(conclusion).unwrap();
+                let (left, right) = (left.clone(), right.clone());
+                let (fx, _fx) = state.term_split_equality(&left).unwrap();
+                let fx = fx.clone();
+                let (gx, _gx) = state.term_split_equality(&right).unwrap();
+                let gx = gx.clone();
+
+                let refl_f = state
+                    .theorem_register_reflexivity::<Handle<tags::Term>, _>(fx)
+                    .unwrap();
+                let refl_g = state
+                    .theorem_register_reflexivity::<Handle<tags::Term>, _>(gx)
+                    .unwrap();
+                let forward = state
+                    .derived_discharge(refl_g, slice::from_ref(&left))
+                    .unwrap();
+                let backward = state
+                    .derived_discharge(refl_f, slice::from_ref(&right))
+                    .unwrap();
+
+                state
+                    .theorem_register_iff_introduction(forward, backward)
+                    .unwrap()
+            });
+
+        let function = state
+            .recursion_register(
+                &equation,
+                &relation,
+                &well_founded,
+                &termination,
+            )
+            .unwrap();
+
+        let cx = state
+            .term_register_application(function.constant().clone(), x)
+            .unwrap();
+        let expected = state
+            .term_register_equality(cx.clone(), cx.clone())
+            .unwrap();
+        let expected = state.term_register_equality(cx, expected).unwrap();
+        let expected = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                expected,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(function.equation()),
+            Ok(expected)
+        );
+
+        check(&mut state, function.equation());
+    }
+
+    /// Checks that malformed recursive equations and relations are rejected.
+    #[test]
+    pub fn recursion2() {
+        let mut state = RuntimeState::new();
+
+        let (relation, _well_founded) = empty(&mut state);
+
+        let fty = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+            )
+            .unwrap();
+        let f = state.term_register_variable(1u64, fty).unwrap();
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let z = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+
+        /* The left-hand side is not an application of the function. */
+        let equation =
+            state.term_register_equality(x.clone(), x.clone()).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.recursion_obligations(&equation, &relation),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* The right-hand side has a free variable other than the function. */
+        let fx = state
+            .term_register_application(f.clone(), x.clone())
+            .unwrap();
+        let equation =
+            state.term_register_equality(fx.clone(), z.clone()).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.recursion_obligations(&equation, &relation),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* The function is not applied to the bound argument. */
+        let fz = state.term_register_application(f, z).unwrap();
+        let equation = state.term_register_equality(fz, x.clone()).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.recursion_obligations(&equation, &relation),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* The relation does not relate arguments of the function. */
+        let equation = state.term_register_equality(fx, x).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+        let unrelated = state
+            .term_register_lambda(
+                10u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                PREALLOCATED_HANDLE_TERM_FALSE,
+            )
+            .unwrap();
+        let unrelated = state
+            .term_register_lambda(
+                11u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                unrelated,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.recursion_obligations(&equation, &unrelated),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert!(state.recursion_obligations(&equation, &relation).is_ok());
+    }
+
+    /// Checks that theorems not proving the obligations, or proving them only
+    /// under premisses, are rejected.
+    #[test]
+    pub fn recursion3() {
+        let mut state = RuntimeState::new();
+
+        let fty = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+            )
+            .unwrap();
+        let f = state.term_register_variable(1u64, fty).unwrap();
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let fx = state.term_register_application(f, x.clone()).unwrap();
+        let equation = state.term_register_equality(fx, x).unwrap();
+        let equation = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                equation,
+            )
+            .unwrap();
+
+        let (relation, well_founded) = empty(&mut state);
+
+        let (_wf, termination) =
+            state.recursion_obligations(&equation, &relation).unwrap();
+        let assumed = state.theorem_register_assumption(termination).unwrap();
+
+        assert_eq!(
+            state.recursion_register(
+                &equation,
+                &relation,
+                &well_founded,
+                &well_founded
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.recursion_register(
+                &equation,
+                &relation,
+                &well_founded,
+                &assumed
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.recursion_register(&equation, &relation, &assumed, &assumed),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(state.speculation_depth(), 0);
+    }
+}
@@ -0,0 +1,1142 @@
+//! # Reference implementation
+//!
+//! A small, slow, and obviously-correct model of the kernel's types, terms,
+//! and the core theorems about them, used as an oracle when testing the
+//! runtime state.  Unlike the runtime state, the model has no tables and no
+//! handles to types, terms, or theorems: these are plain trees, built afresh by
+//! every operation, with no sharing, no interning, and no caching.  Bound
+//! variables are only ever compared after translating terms into a nameless
+//! representation, and substitution renames a bound variable whenever it could
+//! possibly capture.
+//!
+//! Type-formers and constants are the exception, as these are named by their
+//! handles: the model records the arity of each type-former, and the type of
+//! each constant, registered with it under the handle that the runtime state
+//! issued for it, so that objects of the model can be compared with objects
+//! read back from the runtime state using the `reflect_*` functions.
+//!
+//! Every operation returns the same error code as its counterpart in the
+//! runtime state, where that counterpart can fail, so that performance-motivated
+//! changes to the runtime state can be checked against the model by running
+//! the same sequence of operations against both, and comparing the results.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type as KernelType,
+    error_code::ErrorCode,
+    handle::{
+        tags, Handle, PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+        PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+    },
+    name::Name,
+    runtime_state::RuntimeState,
+    term::Term as KernelTerm,
+};
+use std::{borrow::Borrow, collections::HashMap};
+
+////////////////////////////////////////////////////////////////////////////////
+// Types, terms, and theorems of the model.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Types of the model: the same as the kernel's types, but with arguments held
+/// directly, rather than as handles into a type-table.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Type {
+    /// A type-variable with a name.
+    Variable(Name),
+    /// A type-former, named by its handle, applied to a list of arguments.
+    Combination(Handle<tags::TypeFormer>, Vec<Type>),
+}
+
+impl Type {
+    /// Returns the type of propositions.
+    #[inline]
+    pub fn prop() -> Self {
+        Type::Combination(PREALLOCATED_HANDLE_TYPE_FORMER_PROP, Vec::new())
+    }
+
+    /// Returns the type of functions from `domain` to `range`.
+    #[inline]
+    pub fn function(domain: Type, range: Type) -> Self {
+        Type::Combination(
+            PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+            vec![domain, range],
+        )
+    }
+
+    /// Returns `Some((domain, range))` iff the type is a function type from
+    /// `domain` to `range`.
+    pub fn split_function(&self) -> Option<(&Type, &Type)> {
+        match self {
+            Type::Combination(former, arguments)
+                if former == &PREALLOCATED_HANDLE_TYPE_FORMER_ARROW
+                    && arguments.len() == 2 =>
+            {
+                Some((&arguments[0], &arguments[1]))
+            }
+            _otherwise => None,
+        }
+    }
+
+    /// Replaces every type-variable in the type bound by `sigma` with the type
+    /// that it is bound to.  Where a type-variable is bound more than once,
+    /// the first binding is used.
+    pub fn substitute(&self, sigma: &[(Name, Type)]) -> Self {
+        match self {
+            Type::Variable(name) => sigma
+                .iter()
+                .find(|(n, _tau)| n == name)
+                .map(|(_n, tau)| tau.clone())
+                .unwrap_or_else(|| self.clone()),
+            Type::Combination(former, arguments) => Type::Combination(
+                former.clone(),
+                arguments.iter().map(|a| a.substitute(sigma)).collect(),
+            ),
+        }
+    }
+}
+
+/// Terms of the model: the same as the kernel's terms, but with subterms and
+/// types held directly, rather than as handles into the kernel's tables.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Term {
+    /// A variable with a name and a type.
+    Variable(Name, Type),
+    /// A constant, named by its handle, at a type.
+    Constant(Handle<tags::Constant>, Type),
+    /// An application of one term to another.
+    Application(Box<Term>, Box<Term>),
+    /// A λ-abstraction of a variable, with a name and type, over a body.
+    Lambda(Name, Type, Box<Term>),
+}
+
+/// Terms of the model with bound variables replaced by their de Bruijn
+/// indices, so that ⍺-equivalent terms are syntactically equal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Nameless {
+    /// A variable not bound within the term.
+    Free(Name, Type),
+    /// A variable bound by the λ-abstraction with the given de Bruijn index.
+    Bound(usize),
+    /// A constant, at a type.
+    Constant(Handle<tags::Constant>, Type),
+    /// An application of one term to another.
+    Application(Box<Nameless>, Box<Nameless>),
+    /// A λ-abstraction over a variable of the given type.
+    Lambda(Type, Box<Nameless>),
+}
+
+impl Term {
+    /// Returns `Some((left, right))` iff the term is an equality between
+    /// `left` and `right`.
+    pub fn split_equality(&self) -> Option<(&Term, &Term)> {
+        if let Term::Application(inner, right) = self {
+            if let Term::Application(constant, left) = inner.as_ref() {
+                if let Term::Constant(constant, _tau) = constant.as_ref() {
+                    if constant == &PREALLOCATED_HANDLE_CONSTANT_EQUALITY {
+                        return Some((left, right));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the free variables of the term, without duplicates, in
+    /// ascending order.
+    pub fn free_variables(&self) -> Vec<(Name, Type)> {
+        let mut variables = Vec::new();
+
+        self.free_variables_under(&mut Vec::new(), &mut variables);
+
+        variables.sort();
+        variables.dedup();
+        variables
+    }
+
+    /// Pushes each variable of the term to `variables`, other than those in
+    /// `bound` and those bound within the term.
+    fn free_variables_under(
+        &self,
+        bound: &mut Vec<(Name, Type)>,
+        variables: &mut Vec<(Name, Type)>,
+    ) {
+        match self {
+            Term::Variable(name, tau) => {
+                let variable = (*name, tau.clone());
+
+                if !bound.contains(&variable) {
+                    variables.push(variable);
+                }
+            }
+            Term::Constant(_constant, _tau) => (),
+            Term::Application(left, right) => {
+                left.free_variables_under(bound, variables);
+                right.free_variables_under(bound, variables);
+            }
+            Term::Lambda(name, tau, body) => {
+                bound.push((*name, tau.clone()));
+                body.free_variables_under(bound, variables);
+                bound.pop();
+            }
+        }
+    }
+
+    /// Returns every name appearing in the term, whether free or bound.
+    fn names(&self) -> Vec<Name> {
+        match self {
+            Term::Variable(name, _tau) => vec![*name],
+            Term::Constant(_constant, _tau) => Vec::new(),
+            Term::Application(left, right) => {
+                let mut names = left.names();
+                names.append(&mut right.names());
+                names
+            }
+            Term::Lambda(name, _tau, body) => {
+                let mut names = body.names();
+                names.push(*name);
+                names
+            }
+        }
+    }
+
+    /// Translates the term into its nameless representation, under the
+    /// variables `bound` by enclosing λ-abstractions, innermost last.
+    fn nameless(&self, bound: &mut Vec<(Name, Type)>) -> Nameless {
+        match self {
+            Term::Variable(name, tau) => {
+                match bound
+                    .iter()
+                    .rev()
+                    .position(|(n, t)| n == name && t == tau)
+                {
+                    Some(index) => Nameless::Bound(index),
+                    None => Nameless::Free(*name, tau.clone()),
+                }
+            }
+            Term::Constant(constant, tau) => {
+                Nameless::Constant(constant.clone(), tau.clone())
+            }
+            Term::Application(left, right) => Nameless::Application(
+                Box::new(left.nameless(bound)),
+                Box::new(right.nameless(bound)),
+            ),
+            Term::Lambda(name, tau, body) => {
+                bound.push((*name, tau.clone()));
+                let body = body.nameless(bound);
+                bound.pop();
+
+                Nameless::Lambda(tau.clone(), Box::new(body))
+            }
+        }
+    }
+
+    /// Returns `true` iff the term is ⍺-equivalent to `other`, that is, equal
+    /// up-to a renaming of bound variables.
+    pub fn alpha_equivalent(&self, other: &Term) -> bool {
+        self.nameless(&mut Vec::new()) == other.nameless(&mut Vec::new())
+    }
+
+    /// Performs a simultaneous, capture-avoiding substitution of terms for the
+    /// free variables of the term bound by `sigma`.  Where a variable is bound
+    /// more than once, the first binding is used.  Bound variables are renamed
+    /// whenever a free variable of a replacing term shares their name and
+    /// type, whether or not it would actually be captured.
+    pub fn substitute(&self, sigma: &[((Name, Type), Term)]) -> Term {
+        match self {
+            Term::Variable(name, tau) => sigma
+                .iter()
+                .find(|((n, t), _range)| n == name && t == tau)
+                .map(|(_domain, range)| range.clone())
+                .unwrap_or_else(|| self.clone()),
+            Term::Constant(_constant, _tau) => self.clone(),
+            Term::Application(left, right) => Term::Application(
+                Box::new(left.substitute(sigma)),
+                Box::new(right.substitute(sigma)),
+            ),
+            Term::Lambda(name, tau, body) => {
+                let sigma: Vec<((Name, Type), Term)> = sigma
+                    .iter()
+                    .filter(|((n, t), _range)| !(n == name && t == tau))
+                    .cloned()
+                    .collect();
+
+                let captures = sigma.iter().any(|(_domain, range)| {
+                    range.free_variables().contains(&(*name, tau.clone()))
+                });
+
+                if !captures {
+                    return Term::Lambda(
+                        *name,
+                        tau.clone(),
+                        Box::new(body.substitute(&sigma)),
+                    );
+                }
+
+                let mut avoid = body.names();
+                avoid.push(*name);
+
+                for ((n, _t), range) in sigma.iter() {
+                    avoid.push(*n);
+                    avoid.append(&mut range.names());
+                }
+
+                let fresh = (0..)
+                    .find(|n| !avoid.contains(n))
+                    .expect("Fresh name generation failed.");
+                let renamed = body.substitute(&[(
+                    (*name, tau.clone()),
+                    Term::Variable(fresh, tau.clone()),
+                )]);
+
+                Term::Lambda(
+                    fresh,
+                    tau.clone(),
+                    Box::new(renamed.substitute(&sigma)),
+                )
+            }
+        }
+    }
+}
+
+/// Theorems of the model: a list of premisses, and a conclusion.
+#[derive(Clone, Debug)]
+pub struct Theorem {
+    /// The premisses of the theorem, without duplicates up-to
+    /// ⍺-equivalence.
+    premisses: Vec<Term>,
+    /// The conclusion of the theorem.
+    conclusion: Term,
+}
+
+impl Theorem {
+    /// Creates a new theorem from its premisses, discarding any premiss that
+    /// is ⍺-equivalent to an earlier one, and its conclusion.
+    pub fn new(premisses: Vec<Term>, conclusion: Term) -> Self {
+        let mut distinct: Vec<Term> = Vec::new();
+
+        for premiss in premisses {
+            if !distinct.iter().any(|p| p.alpha_equivalent(&premiss)) {
+                distinct.push(premiss);
+            }
+        }
+
+        Theorem {
+            premisses: distinct,
+            conclusion,
+        }
+    }
+
+    /// Returns the premisses of the theorem.
+    #[inline]
+    pub fn premisses(&self) -> &[Term] {
+        &self.premisses
+    }
+
+    /// Returns the conclusion of the theorem.
+    #[inline]
+    pub fn conclusion(&self) -> &Term {
+        &self.conclusion
+    }
+
+    /// Returns `true` iff the theorem has the same premisses as `other`, in any
+    /// order, and the same conclusion, each up-to ⍺-equivalence.
+    pub fn alpha_equivalent(&self, other: &Theorem) -> bool {
+        let contains = |premisses: &[Term], premiss: &Term| {
+            premisses.iter().any(|p| p.alpha_equivalent(premiss))
+        };
+
+        self.premisses.len() == other.premisses.len()
+            && self.premisses.iter().all(|p| contains(&other.premisses, p))
+            && self.conclusion.alpha_equivalent(&other.conclusion)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The model.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The model of the kernel, recording the type-formers and constants
+/// registered with it.  The model starts out knowing only of the type-formers
+/// and constants needed to state the theorems it supports: functions,
+/// propositions, and equality.
+#[derive(Clone, Debug)]
+pub struct Reference {
+    /// The arity of each type-former registered with the model.
+    type_formers: HashMap<Handle<tags::TypeFormer>, usize>,
+    /// The type of each constant registered with the model.
+    constants: HashMap<Handle<tags::Constant>, Type>,
+}
+
+impl Default for Reference {
+    fn default() -> Self {
+        let alpha = Type::Variable(0);
+        let equality =
+            Type::function(alpha.clone(), Type::function(alpha, Type::prop()));
+
+        let mut type_formers = HashMap::new();
+        type_formers.insert(PREALLOCATED_HANDLE_TYPE_FORMER_ARROW, 2);
+        type_formers.insert(PREALLOCATED_HANDLE_TYPE_FORMER_PROP, 0);
+
+        let mut constants = HashMap::new();
+        constants.insert(PREALLOCATED_HANDLE_CONSTANT_EQUALITY, equality);
+
+        Reference {
+            type_formers,
+            constants,
+        }
+    }
+}
+
+impl Reference {
+    /// Returns a new model, knowing only of functions, propositions, and
+    /// equality.
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers a type-former, named by `handle`, with an arity.
+    #[inline]
+    pub fn type_former_register(
+        &mut self,
+        handle: Handle<tags::TypeFormer>,
+        arity: usize,
+    ) {
+        self.type_formers.insert(handle, arity);
+    }
+
+    /// Registers a constant, named by `handle`, with a type.
+    #[inline]
+    pub fn constant_register(
+        &mut self,
+        handle: Handle<tags::Constant>,
+        tau: Type,
+    ) {
+        self.constants.insert(handle, tau);
+    }
+
+    /// Applies the type-former `former` to `arguments`, as
+    /// `RuntimeState::type_register_combination`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeFormerRegistered)` if `former` has not
+    /// been registered with the model.
+    ///
+    /// Returns `Err(ErrorCode::MismatchedArity)` if the length of `arguments`
+    /// does not match the arity of `former`.
+    pub fn type_combination(
+        &self,
+        former: Handle<tags::TypeFormer>,
+        arguments: Vec<Type>,
+    ) -> Result<Type, ErrorCode> {
+        let arity = self
+            .type_formers
+            .get(&former)
+            .ok_or(ErrorCode::NoSuchTypeFormerRegistered)?;
+
+        if arguments.len() != *arity {
+            return Err(ErrorCode::MismatchedArity);
+        }
+
+        Ok(Type::Combination(former, arguments))
+    }
+
+    /// Lifts the constant `constant` into a term at its registered type,
+    /// instantiated with `sigma`, as `RuntimeState::term_register_constant`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchConstantRegistered)` if `constant` has not
+    /// been registered with the model.
+    pub fn term_constant(
+        &self,
+        constant: Handle<tags::Constant>,
+        sigma: &[(Name, Type)],
+    ) -> Result<Term, ErrorCode> {
+        let tau = self
+            .constants
+            .get(&constant)
+            .ok_or(ErrorCode::NoSuchConstantRegistered)?;
+
+        Ok(Term::Constant(constant, tau.substitute(sigma)))
+    }
+
+    /// Returns the type of `term`, as `RuntimeState::term_type_infer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` if the left-hand term of an
+    /// application does not have a function type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the right-hand term of
+    /// an application does not have the domain type of the left-hand term.
+    pub fn type_infer(&self, term: &Term) -> Result<Type, ErrorCode> {
+        match term {
+            Term::Variable(_name, tau) => Ok(tau.clone()),
+            Term::Constant(_constant, tau) => Ok(tau.clone()),
+            Term::Application(left, right) => {
+                let ltau = self.type_infer(left)?;
+                let rtau = self.type_infer(right)?;
+
+                let (domain, range) =
+                    ltau.split_function().ok_or(ErrorCode::NotAFunctionType)?;
+
+                if domain == &rtau {
+                    Ok(range.clone())
+                } else {
+                    Err(ErrorCode::DomainTypeMismatch)
+                }
+            }
+            Term::Lambda(_name, tau, body) => {
+                Ok(Type::function(tau.clone(), self.type_infer(body)?))
+            }
+        }
+    }
+
+    /// Applies `left` to `right`, as `RuntimeState::term_register_application`.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`, applied to the resulting application.
+    pub fn term_application(
+        &self,
+        left: Term,
+        right: Term,
+    ) -> Result<Term, ErrorCode> {
+        let application = Term::Application(Box::new(left), Box::new(right));
+
+        self.type_infer(&application)?;
+
+        Ok(application)
+    }
+
+    /// Forms the equality between `left` and `right`, as
+    /// `RuntimeState::term_register_equality`.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`, applied to either term.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if `left` and `right` do
+    /// not have the same type.
+    pub fn term_equality(
+        &self,
+        left: Term,
+        right: Term,
+    ) -> Result<Term, ErrorCode> {
+        let ltau = self.type_infer(&left)?;
+        let rtau = self.type_infer(&right)?;
+
+        if ltau != rtau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        let equality = self.term_constant(
+            PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+            &[(0, ltau)],
+        )?;
+        let inner = self.term_application(equality, left)?;
+
+        self.term_application(inner, right)
+    }
+
+    /// Substitutes terms for free variables in `term`, as
+    /// `RuntimeState::substitution`.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`, applied to any term in the range of `sigma`.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if any term in the range of
+    /// `sigma` does not have the type of the variable that it replaces.
+    pub fn term_substitute(
+        &self,
+        term: &Term,
+        sigma: &[((Name, Type), Term)],
+    ) -> Result<Term, ErrorCode> {
+        for ((_name, tau), range) in sigma {
+            if &self.type_infer(range)? != tau {
+                return Err(ErrorCode::DomainTypeMismatch);
+            }
+        }
+
+        Ok(term.substitute(sigma))
+    }
+
+    /// Returns `Ok(true)` iff `term` is a proposition.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`.
+    #[inline]
+    pub fn term_is_proposition(&self, term: &Term) -> Result<bool, ErrorCode> {
+        Ok(self.type_infer(term)? == Type::prop())
+    }
+
+    /// Returns the theorem `{ɸ} ⊢ ɸ`, as
+    /// `RuntimeState::theorem_register_assumption`.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `term` is not a
+    /// proposition.
+    pub fn theorem_assumption(&self, term: Term) -> Result<Theorem, ErrorCode> {
+        if !self.term_is_proposition(&term)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        Ok(Theorem::new(vec![term.clone()], term))
+    }
+
+    /// Returns the theorem `{} ⊢ r = r`, as
+    /// `RuntimeState::theorem_register_reflexivity`.
+    ///
+    /// # Errors
+    ///
+    /// As for `type_infer`.
+    pub fn theorem_reflexivity(
+        &self,
+        term: Term,
+    ) -> Result<Theorem, ErrorCode> {
+        let conclusion = self.term_equality(term.clone(), term)?;
+
+        Ok(Theorem::new(Vec::new(), conclusion))
+    }
+
+    /// Returns the theorem `Γ ⊢ s = r` from the theorem `Γ ⊢ r = s`, as
+    /// `RuntimeState::theorem_register_symmetry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of `theorem`
+    /// is not an equality.
+    pub fn theorem_symmetry(
+        &self,
+        theorem: &Theorem,
+    ) -> Result<Theorem, ErrorCode> {
+        let (left, right) = theorem
+            .conclusion()
+            .split_equality()
+            .ok_or(ErrorCode::NotAnEquality)?;
+
+        let conclusion = self.term_equality(right.clone(), left.clone())?;
+
+        Ok(Theorem::new(theorem.premisses().to_vec(), conclusion))
+    }
+
+    /// Returns the theorem `Γ ∪ Δ ⊢ r = t` from the theorems `Γ ⊢ r = s` and
+    /// `Δ ⊢ s = t`, as `RuntimeState::theorem_register_transitivity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of either
+    /// theorem is not an equality.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the right-hand side of the
+    /// first equality is not ⍺-equivalent to the left-hand side of the second.
+    pub fn theorem_transitivity(
+        &self,
+        left: &Theorem,
+        right: &Theorem,
+    ) -> Result<Theorem, ErrorCode> {
+        let (l, mid0) = left
+            .conclusion()
+            .split_equality()
+            .ok_or(ErrorCode::NotAnEquality)?;
+        let (mid1, r) = right
+            .conclusion()
+            .split_equality()
+            .ok_or(ErrorCode::NotAnEquality)?;
+
+        if !mid0.alpha_equivalent(mid1) {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let mut premisses = left.premisses().to_vec();
+        premisses.extend_from_slice(right.premisses());
+
+        let conclusion = self.term_equality(l.clone(), r.clone())?;
+
+        Ok(Theorem::new(premisses, conclusion))
+    }
+
+    /// Returns the theorem `{} ⊢ (λx:τ. r)t = r[x:τ ↦ t]`, as
+    /// `RuntimeState::theorem_register_beta`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotAnApplication)` if `term` is not an
+    /// application.
+    ///
+    /// Returns `Err(ErrorCode::NotALambda)` if `term` is not an application of
+    /// a λ-abstraction.
+    pub fn theorem_beta(&self, term: Term) -> Result<Theorem, ErrorCode> {
+        let (left, right) = match &term {
+            Term::Application(left, right) => (left, right),
+            _otherwise => return Err(ErrorCode::NotAnApplication),
+        };
+
+        let reduct = match left.as_ref() {
+            Term::Lambda(name, tau, body) => {
+                body.substitute(&[((*name, tau.clone()), *right.clone())])
+            }
+            _otherwise => return Err(ErrorCode::NotALambda),
+        };
+
+        let conclusion = self.term_equality(term.clone(), reduct)?;
+
+        Ok(Theorem::new(Vec::new(), conclusion))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reflection of kernel objects.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reads back the type pointed-to by `handle` in the runtime state's
+/// type-table as a type of the model.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle`, or any handle
+/// within the type that it points-to, does not point-to a registered type.
+pub fn reflect_type<T>(
+    state: &RuntimeState,
+    handle: T,
+) -> Result<Type, ErrorCode>
+where
+    T: Borrow<Handle<tags::Type>>,
+{
+    match state.resolve_type_handle(handle)? {
+        KernelType::Variable { name } => Ok(Type::Variable(*name)),
+        KernelType::Combination { former, arguments } => {
+            let arguments = arguments
+                .iter()
+                .map(|a| reflect_type(state, a))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Type::Combination(former.clone(), arguments))
+        }
+    }
+}
+
+/// Reads back the term pointed-to by `handle` in the runtime state's
+/// term-table as a term of the model.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle`, or any handle
+/// within the term that it points-to, does not point-to a registered term.
+///
+/// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if any type within the term
+/// does not point-to a registered type.
+pub fn reflect_term<T>(
+    state: &RuntimeState,
+    handle: T,
+) -> Result<Term, ErrorCode>
+where
+    T: Borrow<Handle<tags::Term>>,
+{
+    match state.resolve_term_handle(handle)? {
+        KernelTerm::Variable { name, tau } => {
+            Ok(Term::Variable(*name, reflect_type(state, tau)?))
+        }
+        KernelTerm::Constant { constant, tau } => {
+            Ok(Term::Constant(constant.clone(), reflect_type(state, tau)?))
+        }
+        KernelTerm::Application { left, right } => Ok(Term::Application(
+            Box::new(reflect_term(state, left)?),
+            Box::new(reflect_term(state, right)?),
+        )),
+        KernelTerm::Lambda { name, tau, body } => Ok(Term::Lambda(
+            *name,
+            reflect_type(state, tau)?,
+            Box::new(reflect_term(state, body)?),
+        )),
+    }
+}
+
+/// Reads back the theorem pointed-to by `handle` in the runtime state's
+/// theorem-table as a theorem of the model.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+/// point-to a registered theorem.
+///
+/// Otherwise, as for `reflect_term`, applied to the premisses and conclusion
+/// of the theorem.
+pub fn reflect_theorem<T>(
+    state: &RuntimeState,
+    handle: T,
+) -> Result<Theorem, ErrorCode>
+where
+    T: Borrow<Handle<tags::Theorem>>,
+{
+    let handle = handle.borrow();
+
+    let premisses = state
+        .theorem_split_premisses(handle)?
+        .iter()
+        .map(|p| reflect_term(state, p))
+        .collect::<Result<_, _>>()?;
+    let conclusion =
+        reflect_term(state, state.theorem_split_conclusion(handle)?)?;
+
+    Ok(Theorem::new(premisses, conclusion))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Differential tests, running random sequences of operations against both the
+/// runtime state and the model, and comparing their results.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+            PREALLOCATED_HANDLE_TERM_EQUALITY, PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        reference::{
+            reflect_term, reflect_theorem, reflect_type, Reference, Term,
+            Theorem, Type,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// A small, deterministic, pseudo-random number generator (xorshift64),
+    /// so that failing sequences of operations can be replayed.
+    struct Random(u64);
+
+    impl Random {
+        /// Returns the next pseudo-random number below `bound`.
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            (self.0 % bound as u64) as usize
+        }
+
+        /// Returns a pseudo-randomly chosen element of `items`.
+        fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+            &items[self.below(items.len())]
+        }
+    }
+
+    /// The objects registered so far, each paired with its counterpart in the
+    /// model.
+    struct Pools {
+        type_formers: Vec<Handle<tags::TypeFormer>>,
+        constants: Vec<Handle<tags::Constant>>,
+        types: Vec<(Handle<tags::Type>, Type)>,
+        terms: Vec<(Handle<tags::Term>, Term)>,
+        theorems: Vec<(Handle<tags::Theorem>, Theorem)>,
+    }
+
+    /// Checks that the runtime state and the model agree on the outcome of an
+    /// operation: either both fail with the same error, or both succeed and
+    /// `agree` holds of their results.
+    fn compare<K, R, F>(
+        kernel: &Result<K, ErrorCode>,
+        model: &Result<R, ErrorCode>,
+        agree: F,
+    ) where
+        K: std::fmt::Debug,
+        R: std::fmt::Debug,
+        F: FnOnce(&K, &R) -> bool,
+    {
+        match (kernel, model) {
+            (Ok(k), Ok(r)) => assert!(agree(k, r), "{:?} vs. {:?}", k, r),
+            (Err(k), Err(r)) => assert_eq!(k, r),
+            _otherwise => panic!("{:?} vs. {:?}", kernel, model),
+        }
+    }
+
+    /// Performs a single pseudo-randomly chosen operation against both the
+    /// runtime state and the model, checking that they agree.
+    fn step(
+        random: &mut Random,
+        kernel: &mut RuntimeState,
+        model: &mut Reference,
+        pools: &mut Pools,
+    ) {
+        match random.below(16) {
+            0 => {
+                let name = random.below(3) as u64;
+                let handle = kernel.type_register_variable(name);
+
+                assert_eq!(
+                    reflect_type(kernel, &handle),
+                    Ok(Type::Variable(name))
+                );
+                pools.types.push((handle, Type::Variable(name)));
+            }
+            1 => {
+                let arity = random.below(3);
+                let handle = kernel.type_former_register(arity);
+
+                model.type_former_register(handle.clone(), arity);
+                pools.type_formers.push(handle);
+            }
+            2 => {
+                let former = random.choose(&pools.type_formers).clone();
+                let (handles, types): (Vec<_>, Vec<_>) = (0..random.below(3))
+                    .map(|_i| random.choose(&pools.types).clone())
+                    .unzip();
+
+                let k =
+                    kernel.type_register_combination(former.clone(), handles);
+                let r = model.type_combination(former, types);
+
+                compare(&k, &r, |k, r| {
+                    reflect_type(kernel, k).as_ref() == Ok(r)
+                });
+
+                if let (Ok(k), Ok(r)) = (k, r) {
+                    pools.types.push((k, r));
+                }
+            }
+            3 => {
+                let (dh, dt) = random.choose(&pools.types).clone();
+                let (rh, rt) = random.choose(&pools.types).clone();
+
+                let handle = kernel.type_register_function(dh, rh).unwrap();
+                let tau = Type::function(dt, rt);
+
+                assert_eq!(reflect_type(kernel, &handle).as_ref(), Ok(&tau));
+                pools.types.push((handle, tau));
+            }
+            4 => {
+                let (handle, tau) = random.choose(&pools.types).clone();
+                let constant = kernel.constant_register(handle).unwrap();
+
+                model.constant_register(constant.clone(), tau);
+                pools.constants.push(constant);
+            }
+            5 => {
+                let name = random.below(3) as u64;
+                let (handle, tau) = random.choose(&pools.types).clone();
+
+                let k = kernel.term_register_variable(name, handle).unwrap();
+
+                pools.terms.push((k, Term::Variable(name, tau)));
+            }
+            6 => {
+                let constant = random.choose(&pools.constants).clone();
+                let (handle, tau) = random.choose(&pools.types).clone();
+
+                let k = kernel.term_register_constant(
+                    constant.clone(),
+                    vec![(0u64, handle)],
+                );
+                let r = model.term_constant(constant, &[(0, tau)]);
+
+                compare(&k, &r, |k, r| {
+                    reflect_term(kernel, k).unwrap().alpha_equivalent(r)
+                });
+
+                if let (Ok(k), Ok(r)) = (k, r) {
+                    pools.terms.push((k, r));
+                }
+            }
+            7 => {
+                let (lh, lt) = random.choose(&pools.terms).clone();
+                let (rh, rt) = random.choose(&pools.terms).clone();
+
+                let k = kernel.term_register_application(lh, rh);
+                let r = model.term_application(lt, rt);
+
+                compare(&k, &r, |k, r| {
+                    reflect_term(kernel, k).unwrap().alpha_equivalent(r)
+                });
+
+                if let (Ok(k), Ok(r)) = (k, r) {
+                    pools.terms.push((k, r));
+                }
+            }
+            8 => {
+                let name = random.below(3) as u64;
+                let (th, tt) = random.choose(&pools.types).clone();
+                let (bh, bt) = random.choose(&pools.terms).clone();
+
+                let k = kernel.term_register_lambda(name, th, bh).unwrap();
+                let r = Term::Lambda(name, tt, Box::new(bt));
+
+                assert!(reflect_term(kernel, &k).unwrap().alpha_equivalent(&r));
+                pools.terms.push((k, r));
+            }
+            9 => {
+                let (handle, term) = random.choose(&pools.terms).clone();
+
+                let k = kernel.term_type_infer(&handle);
+                let r = model.type_infer(&term);
+
+                compare(&k, &r, |k, r| {
+                    reflect_type(kernel, k).as_ref() == Ok(r)
+                });
+
+                let k: Vec<(u64, Type)> = kernel
+                    .term_free_variables(&handle)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(n, t)| (*n, reflect_type(kernel, t).unwrap()))
+                    .collect();
+                let mut k = k;
+                k.sort();
+                k.dedup();
+
+                assert_eq!(k, term.free_variables());
+            }
+            10 => {
+                let (handle, term) = random.choose(&pools.terms).clone();
+                let (rh, rt) = random.choose(&pools.terms).clone();
+                let name = random.below(3) as u64;
+                let (th, tt) = random.choose(&pools.types).clone();
+
+                let k = kernel.substitution(handle, vec![((name, th), rh)]);
+                let r = model.term_substitute(&term, &[((name, tt), rt)]);
+
+                compare(&k, &r, |k, r| {
+                    reflect_term(kernel, k).unwrap().alpha_equivalent(r)
+                });
+
+                if let (Ok(k), Ok(r)) = (k, r) {
+                    pools.terms.push((k, r));
+                }
+            }
+            _otherwise => {
+                let (k, r) = match random.below(5) {
+                    0 => {
+                        let (h, t) = random.choose(&pools.terms).clone();
+                        (
+                            kernel.theorem_register_assumption(h),
+                            model.theorem_assumption(t),
+                        )
+                    }
+                    1 => {
+                        let (h, t) = random.choose(&pools.terms).clone();
+                        (
+                            kernel.theorem_register_reflexivity::<
+                                Handle<tags::Term>,
+                                _,
+                            >(h),
+                            model.theorem_reflexivity(t),
+                        )
+                    }
+                    2 => {
+                        let (h, t) = random.choose(&pools.terms).clone();
+                        (kernel.theorem_register_beta(h), model.theorem_beta(t))
+                    }
+                    3 if !pools.theorems.is_empty() => {
+                        let (h, t) = random.choose(&pools.theorems).clone();
+                        (
+                            kernel.theorem_register_symmetry(h),
+                            model.theorem_symmetry(&t),
+                        )
+                    }
+                    4 if !pools.theorems.is_empty() => {
+                        let (lh, lt) = random.choose(&pools.theorems).clone();
+                        let (rh, rt) = random.choose(&pools.theorems).clone();
+                        (
+                            kernel.theorem_register_transitivity(lh, rh),
+                            model.theorem_transitivity(&lt, &rt),
+                        )
+                    }
+                    _otherwise => return,
+                };
+
+                compare(&k, &r, |k, r| {
+                    reflect_theorem(kernel, k).unwrap().alpha_equivalent(r)
+                });
+
+                if let (Ok(k), Ok(r)) = (k, r) {
+                    pools.theorems.push((k, r));
+                }
+            }
+        }
+    }
+
+    /// Runs `steps` pseudo-random operations, from the given `seed`, against a
+    /// fresh runtime state and model.
+    fn differential(seed: u64, steps: usize) {
+        let mut random = Random(seed);
+        let mut kernel = RuntimeState::new();
+        let mut model = Reference::new();
+
+        let alpha = kernel.type_register_variable(0u64);
+        let prop = PREALLOCATED_HANDLE_TYPE_PROP;
+
+        let mut pools = Pools {
+            type_formers: vec![
+                kernel.type_former_register(0usize),
+                kernel.type_former_register(1usize),
+            ],
+            constants: Vec::new(),
+            types: vec![(alpha, Type::Variable(0)), (prop, Type::prop())],
+            terms: Vec::new(),
+            theorems: Vec::new(),
+        };
+
+        model.type_former_register(pools.type_formers[0].clone(), 0);
+        model.type_former_register(pools.type_formers[1].clone(), 1);
+
+        pools.terms.push((
+            PREALLOCATED_HANDLE_TERM_EQUALITY,
+            reflect_term(&kernel, PREALLOCATED_HANDLE_TERM_EQUALITY).unwrap(),
+        ));
+        pools.constants.push(PREALLOCATED_HANDLE_CONSTANT_EQUALITY);
+
+        for _step in 0..steps {
+            step(&mut random, &mut kernel, &mut model, &mut pools);
+        }
+    }
+
+    /// Tests that the runtime state and the model agree on a handful of
+    /// pseudo-random sequences of operations.
+    #[test]
+    pub fn reference_test0() {
+        for seed in 1..=16 {
+            differential(seed, 200);
+        }
+    }
+
+    /// Tests that capture-avoiding substitution in the model renames a bound
+    /// variable that would otherwise capture a free variable of the replacing
+    /// term.
+    #[test]
+    pub fn reference_test1() {
+        let alpha = Type::Variable(0);
+        let x = Term::Variable(0, alpha.clone());
+        let y = Term::Variable(1, alpha.clone());
+
+        let lambda = Term::Lambda(0, alpha.clone(), Box::new(y.clone()));
+        let result = lambda.substitute(&[((1, alpha.clone()), x.clone())]);
+
+        assert_eq!(result.free_variables(), vec![(0, alpha.clone())]);
+        assert!(!result.alpha_equivalent(&Term::Lambda(0, alpha, Box::new(x))));
+    }
+}
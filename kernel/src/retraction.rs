@@ -0,0 +1,197 @@
+//! # Theorem retraction
+//!
+//! In exploratory developments, axioms and definitions are often adjusted
+//! iteratively: a definition turns out to be subtly wrong, and must be
+//! replaced, after a number of theorems have already been proved from it.  As
+//! kernel objects are never freed, the kernel cannot forget the definition, but
+//! it may *retract* its definitional theorem, marking it, and every theorem
+//! that depends upon it, as unusable.  Retracted theorems remain registered,
+//! but any attempt to use one, whether as the premiss of an inference rule or
+//! otherwise, fails with `ErrorCode::TheoremRetracted`.
+//!
+//! The theorems depending upon a theorem are found by following the
+//! provenance graph, recording the premisses from which each theorem was
+//! derived, which the kernel maintains whether or not proof recording is
+//! enabled.  The constants and type-formers introduced by a retracted
+//! definition remain registered, but nothing more is known of them, so a
+//! corrected definition is registered afresh.
+//!
+//! Retraction is a research tool, only compiled into the kernel with the
+//! experimental `retraction` cargo feature, and is not exposed to guests.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Retraction-related material.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Retracts the theorem pointed-to by `handle`, typically an axiom or the
+    /// definitional theorem of a definition, along with every theorem that
+    /// depends upon it, however indirectly.  Returns the handles of every
+    /// theorem retracted, including `handle`, in ascending order.
+    ///
+    /// Retractions made within a speculative branch are undone if the branch
+    /// is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to any theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::TheoremRetracted)` if the theorem pointed-to by
+    /// `handle` has already been retracted.
+    pub fn theorem_retract<T>(
+        &mut self,
+        handle: T,
+    ) -> Result<Vec<Handle<tags::Theorem>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Retracting theorem with handle {}.", handle.borrow());
+
+        if self.theorem_is_retracted(handle.borrow())? {
+            return Err(ErrorCode::TheoremRetracted);
+        }
+
+        let mut affected = Vec::new();
+        let mut work = vec![handle.borrow().clone()];
+
+        while let Some(handle) = work.pop() {
+            if !self.retracted.insert(handle.clone()) {
+                continue;
+            }
+
+            if let Some(dependents) = self.dependents.get(&handle) {
+                work.extend(dependents.iter().cloned());
+            }
+
+            affected.push(handle);
+        }
+
+        affected.sort();
+
+        Ok(affected)
+    }
+
+    /// Returns `Ok(true)` iff the theorem pointed-to by `handle` has been
+    /// retracted, either directly or because it depends upon a theorem that
+    /// was.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to any theorem in the runtime state's theorem-table.
+    pub fn theorem_is_retracted<T>(&self, handle: T) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Checking if theorem with handle {} is retracted.",
+            handle.borrow()
+        );
+
+        if !self.theorems.contains_key(handle.borrow()) {
+            return Err(self.unresolved(
+                handle.borrow(),
+                ErrorCode::NoSuchTheoremRegistered,
+            ));
+        }
+
+        Ok(self.retracted.contains(handle.borrow()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for retraction-related functionality.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that retracting a definition retracts exactly the theorems
+    /// derived from it, which may then no longer be used.
+    #[test]
+    pub fn retraction_test0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let (_c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let sym = state.theorem_register_symmetry(&defn).unwrap();
+        let refl = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(p)
+            .unwrap();
+        let trans = state.theorem_register_transitivity(&defn, &sym).unwrap();
+
+        assert_eq!(
+            state.theorem_retract(&defn),
+            Ok(vec![defn.clone(), sym.clone(), trans.clone()])
+        );
+
+        assert_eq!(state.theorem_is_retracted(&sym), Ok(true));
+        assert_eq!(state.theorem_is_retracted(&refl), Ok(false));
+        assert!(!state.theorem_is_registered(&trans));
+        assert_eq!(
+            state.theorem_register_symmetry(&sym),
+            Err(ErrorCode::TheoremRetracted)
+        );
+        assert_eq!(
+            state.theorem_retract(&sym),
+            Err(ErrorCode::TheoremRetracted)
+        );
+        assert!(state.theorem_register_symmetry(&refl).is_ok());
+    }
+
+    /// Tests that retractions made within a discarded speculative branch are
+    /// undone.
+    #[test]
+    pub fn retraction_test1() {
+        let mut state = RuntimeState::new();
+
+        let (_c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        state.speculate_begin();
+        let sym = state.theorem_register_symmetry(&defn).unwrap();
+        assert_eq!(state.theorem_retract(&defn), Ok(vec![defn.clone(), sym]));
+        state.speculate_end(false).unwrap();
+
+        assert_eq!(state.theorem_is_retracted(&defn), Ok(false));
+        assert!(state.theorem_register_symmetry(&defn).is_ok());
+    }
+}
@@ -23,60 +23,432 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+#[cfg(feature = "budgets")]
+use crate::budget::Budget;
+#[cfg(feature = "sequents")]
+use crate::experimental::Sequent;
+#[cfg(feature = "linear-arith")]
+use crate::linear_arith::LinearArithSignature;
 use crate::{
     _type::{
         Type, TYPE_ALPHA, TYPE_BETA, TYPE_BINARY_CONNECTIVE,
-        TYPE_POLYMORPHIC_BINARY_PREDICATE, TYPE_POLYMORPHIC_QUANTIFIER,
+        TYPE_POLYMORPHIC_BINARY_OPERATOR, TYPE_POLYMORPHIC_BINARY_PREDICATE,
+        TYPE_POLYMORPHIC_BINARY_SET_OPERATOR, TYPE_POLYMORPHIC_CONDITIONAL,
+        TYPE_POLYMORPHIC_FIRST, TYPE_POLYMORPHIC_FUNCTION,
+        TYPE_POLYMORPHIC_LET, TYPE_POLYMORPHIC_MEMBERSHIP,
+        TYPE_POLYMORPHIC_PAIR, TYPE_POLYMORPHIC_PARTIAL_PAIR,
+        TYPE_POLYMORPHIC_PRODUCT, TYPE_POLYMORPHIC_QUANTIFIER,
+        TYPE_POLYMORPHIC_SECOND, TYPE_POLYMORPHIC_SET_OPERATOR,
+        TYPE_POLYMORPHIC_SET_RELATION, TYPE_POLYMORPHIC_UNARY_OPERATOR,
         TYPE_POLYMORPHIC_UNARY_PREDICATE, TYPE_PROP, TYPE_UNARY_CONNECTIVE,
     },
+    allocation::HandleAllocator,
+    bloom::BloomFilter,
+    capability::{Capability, Expiry, Service},
     error_code::ErrorCode,
     handle::{
-        tags, Handle, PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
+        is_preallocated, tags, Handle, Kind,
+        PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+        PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+        PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
         PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
+        PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
         PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
         PREALLOCATED_HANDLE_CONSTANT_EXISTS,
-        PREALLOCATED_HANDLE_CONSTANT_FALSE,
+        PREALLOCATED_HANDLE_CONSTANT_FALSE, PREALLOCATED_HANDLE_CONSTANT_FIRST,
         PREALLOCATED_HANDLE_CONSTANT_FORALL,
         PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
+        PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+        PREALLOCATED_HANDLE_CONSTANT_LET,
+        PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
         PREALLOCATED_HANDLE_CONSTANT_NEGATION,
-        PREALLOCATED_HANDLE_CONSTANT_TRUE,
+        PREALLOCATED_HANDLE_CONSTANT_PAIR, PREALLOCATED_HANDLE_CONSTANT_SECOND,
+        PREALLOCATED_HANDLE_CONSTANT_SUBSET, PREALLOCATED_HANDLE_CONSTANT_TRUE,
+        PREALLOCATED_HANDLE_CONSTANT_UNION,
+        PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
+        PREALLOCATED_HANDLE_TERM_COMPREHENSION,
+        PREALLOCATED_HANDLE_TERM_CONDITIONAL,
         PREALLOCATED_HANDLE_TERM_CONJUNCTION,
         PREALLOCATED_HANDLE_TERM_DISJUNCTION,
-        PREALLOCATED_HANDLE_TERM_EQUALITY, PREALLOCATED_HANDLE_TERM_EXISTS,
-        PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_FORALL,
+        PREALLOCATED_HANDLE_TERM_EMPTY_SET, PREALLOCATED_HANDLE_TERM_EQUALITY,
+        PREALLOCATED_HANDLE_TERM_EXISTS, PREALLOCATED_HANDLE_TERM_FALSE,
+        PREALLOCATED_HANDLE_TERM_FIRST, PREALLOCATED_HANDLE_TERM_FORALL,
         PREALLOCATED_HANDLE_TERM_IMPLICATION,
-        PREALLOCATED_HANDLE_TERM_NEGATION, PREALLOCATED_HANDLE_TERM_TRUE,
+        PREALLOCATED_HANDLE_TERM_INTERSECTION, PREALLOCATED_HANDLE_TERM_LET,
+        PREALLOCATED_HANDLE_TERM_MEMBERSHIP, PREALLOCATED_HANDLE_TERM_NEGATION,
+        PREALLOCATED_HANDLE_TERM_PAIR, PREALLOCATED_HANDLE_TERM_SECOND,
+        PREALLOCATED_HANDLE_TERM_SUBSET, PREALLOCATED_HANDLE_TERM_TRUE,
+        PREALLOCATED_HANDLE_TERM_UNION, PREALLOCATED_HANDLE_TERM_UNIVERSAL_SET,
         PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
         PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+        PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR,
         PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+        PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+        PREALLOCATED_HANDLE_TYPE_CONDITIONAL, PREALLOCATED_HANDLE_TYPE_FIRST,
         PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
-        PREALLOCATED_HANDLE_TYPE_FORMER_PROP, PREALLOCATED_HANDLE_TYPE_PROP,
-        PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+        PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+        PREALLOCATED_HANDLE_TYPE_FUNCTION, PREALLOCATED_HANDLE_TYPE_LET,
+        PREALLOCATED_HANDLE_TYPE_MEMBERSHIP, PREALLOCATED_HANDLE_TYPE_PAIR,
+        PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR,
+        PREALLOCATED_HANDLE_TYPE_PRODUCT, PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_QUANTIFIER, PREALLOCATED_HANDLE_TYPE_SECOND,
+        PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+        PREALLOCATED_HANDLE_TYPE_SET_RELATION,
         PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
+        PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR,
         PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
-        PREALLOCATED_HANDLE_UPPER_BOUND,
     },
     kernel_panic::{
         DANGLING_HANDLE_ERROR, HANDLE_EXHAUST_ERROR,
         PRIMITIVE_CONSTRUCTION_ERROR,
     },
-    name::Name,
+    metadata::{ObjectMetadata, TypeFormerMetadata},
+    name::{canonical_index, canonical_name, fresh, Name, VariableOrder},
+    observer::{LongOperation, Observers},
+    proof::{Proof, ProofArgument, Rule},
+    quotient::Quotient,
+    shared::Shared,
+    substitution::Substitution,
     term::{
-        Term, TERM_CONJUNCTION_CONSTANT, TERM_DISJUNCTION_CONSTANT,
-        TERM_EQUALITY_CONSTANT, TERM_EXISTS_CONSTANT, TERM_FALSE_CONSTANT,
-        TERM_FORALL_CONSTANT, TERM_IMPLICATION_CONSTANT,
-        TERM_NEGATION_CONSTANT, TERM_TRUE_CONSTANT,
+        Term, TERM_COMPREHENSION_CONSTANT, TERM_CONDITIONAL_CONSTANT,
+        TERM_CONJUNCTION_CONSTANT, TERM_DISJUNCTION_CONSTANT,
+        TERM_EMPTY_SET_CONSTANT, TERM_EQUALITY_CONSTANT, TERM_EXISTS_CONSTANT,
+        TERM_FALSE_CONSTANT, TERM_FIRST_CONSTANT, TERM_FORALL_CONSTANT,
+        TERM_IMPLICATION_CONSTANT, TERM_INTERSECTION_CONSTANT,
+        TERM_LET_CONSTANT, TERM_MEMBERSHIP_CONSTANT, TERM_NEGATION_CONSTANT,
+        TERM_PAIR_CONSTANT, TERM_SECOND_CONSTANT, TERM_SUBSET_CONSTANT,
+        TERM_TRUE_CONSTANT, TERM_UNION_CONSTANT, TERM_UNIVERSAL_SET_CONSTANT,
     },
     theorem::Theorem,
+    trust::TrustLevel,
+    type_definition::DefinedType,
 };
-use log::info;
+use log::{info, warn};
+use smallvec::SmallVec;
 use std::{
     borrow::Borrow,
-    collections::HashMap,
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::{Debug, Display},
-    iter::FromIterator,
+    iter::{once, FromIterator},
+    mem::take,
+    ops::Range,
+    sync::Arc,
 };
 
+////////////////////////////////////////////////////////////////////////////////
+// Substitution work-lists.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The bindings of a single substitution, associating typed variables with the
+/// terms that replace them.  Substitutions arising from inference rules almost
+/// always bind a single variable, so bindings are stored inline.
+pub(crate) type Bindings =
+    SmallVec<[(Name, Handle<tags::Type>, Handle<tags::Term>); 4]>;
+
+/// A set of bindings encountered during substitution, alongside the free
+/// variables of its range, used to detect variable capture.
+type SubstitutionContext = (Bindings, Vec<(Name, Handle<tags::Type>)>);
+
+/// Items of the explicit work-list used by the iterative substitution
+/// algorithm, so that substituting into deeply-nested terms does not exhaust
+/// the native stack.
+enum SubstitutionFrame {
+    /// Substitute into the term pointed-to by the handle, under the bindings
+    /// with the given index.
+    Visit(Handle<tags::Term>, usize),
+    /// Rebuild the application pointed-to by the handle from the two most
+    /// recent results.
+    Application(Handle<tags::Term>),
+    /// Rebuild the λ-abstraction pointed-to by the handle from the most recent
+    /// result, binding the given name.
+    Lambda(Handle<tags::Term>, Name),
+    /// Record the most recent result as the result of substituting into the
+    /// term pointed-to by the handle, under the bindings with the given index.
+    Memoise(Handle<tags::Term>, usize),
+}
+
+/// The objects registered by a type definition: the new type, the abstraction
+/// and representation functions, and the two theorems characterising them.
+pub type TypeDefinition = (
+    Handle<tags::Type>,
+    Handle<tags::Term>,
+    Handle<tags::Term>,
+    Handle<tags::Theorem>,
+    Handle<tags::Theorem>,
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// Hashing.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The initial value of a 64-bit FNV-1a hash.
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// The prime by which a 64-bit FNV-1a hash is multiplied after each byte.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Extends the 64-bit FNV-1a hash `hash` with `bytes`.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
+
+/// Extends the 64-bit FNV-1a hash `hash` with each of `words`, in
+/// little-endian byte order.
+pub(crate) fn fnv1a_words(hash: u64, words: &[u64]) -> u64 {
+    words
+        .iter()
+        .fold(hash, |hash, word| fnv1a(hash, &word.to_le_bytes()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Interned hypotheses.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An interned set of hypotheses, shared between the table of interned
+/// hypotheses and every theorem with those premisses.
+type Premisses = Arc<Vec<Handle<tags::Term>>>;
+
+////////////////////////////////////////////////////////////////////////////////
+// Speculation.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The parts of the runtime state needed to discard a speculative branch.
+/// Kernel objects are only ever added to the tables of the runtime state, so
+/// discarding a branch amounts to removing every object whose handle was
+/// issued after the branch began.  Capabilities are the exception, as
+/// presenting a capability records a use, so the capability-table is saved in
+/// full, as are the constructor markings, which may refer to constants
+/// registered before the branch began.
+#[derive(Clone, Debug)]
+struct Speculation {
+    /// The sequence number of the next handle to be issued when the branch
+    /// began.
+    mark: usize,
+    /// The capability-table when the branch began.
+    capabilities: HashMap<Handle<tags::Capability>, Capability>,
+    /// The constructors of each datatype when the branch began.
+    datatype_constructors:
+        HashMap<Handle<tags::TypeFormer>, Vec<Handle<tags::Constant>>>,
+    /// The datatype of each constructor when the branch began.
+    constructor_datatypes:
+        HashMap<Handle<tags::Constant>, Handle<tags::TypeFormer>>,
+    /// The metadata attached to kernel objects when the branch began.
+    object_metadata: HashMap<usize, ObjectMetadata>,
+    /// The references held by the guest to kernel objects when the branch
+    /// began.
+    references: HashMap<usize, usize>,
+    /// The metadata awaiting theorems when the branch began.
+    pending_theorem_metadata:
+        HashMap<crate::proof_object::Sequent, ObjectMetadata>,
+    /// The retracted theorems when the branch began, from the experimental
+    /// `retraction` feature.
+    #[cfg(feature = "retraction")]
+    retracted: HashSet<Handle<tags::Theorem>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Frozen runtime states.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A runtime state frozen for sharing as the immutable base theory of many
+/// runtime states, e.g. one for each guest of a pool executing in parallel.
+/// Each runtime state instantiated from the base shares its tables until it
+/// first writes to one, whereupon that table alone is copied, so that an
+/// instance registering a handful of objects over a large base theory holds
+/// little more than the objects that it registered.  Unlike a `RuntimeState`,
+/// a frozen runtime state may be shared between threads.
+///
+/// A frozen runtime state carries every kernel object of the state that it
+/// was frozen from, along with its configuration, but neither its observers,
+/// which belong to the embedder of that state, nor its error detail, nor any
+/// speculative branch in progress, whose objects are frozen as if kept, nor the
+/// references held by its guest.
+#[derive(Clone, Debug)]
+pub struct FrozenRuntimeState {
+    /// The allocator of the base, from which every instance continues to issue
+    /// handles.  Instances issue the same handles independently.
+    handles: HandleAllocator,
+    /// The table of registered type-formers.
+    type_formers: Shared<HashMap<Handle<tags::TypeFormer>, usize>>,
+    /// The metadata supplied when registering type-formers.
+    type_former_metadata: HashMap<Handle<tags::TypeFormer>, TypeFormerMetadata>,
+    /// The symbol table of named type-formers.
+    type_former_names: HashMap<String, Handle<tags::TypeFormer>>,
+    /// The table of types.
+    types: Shared<HashMap<Handle<tags::Type>, Type>>,
+    /// The table of constants.
+    constants: Shared<HashMap<Handle<tags::Constant>, Handle<tags::Type>>>,
+    /// The symbol table of named constants.
+    constant_names: HashMap<String, Handle<tags::Constant>>,
+    /// The table of terms.
+    terms: Shared<HashMap<Handle<tags::Term>, Term>>,
+    /// The reverse of the term-table.
+    terms_index: Shared<HashMap<Term, Handle<tags::Term>>>,
+    /// The heights of registered terms.
+    term_heights: Shared<HashMap<Handle<tags::Term>, (u64, u64)>>,
+    /// The names of bound variables chosen when λ-abstractions were first
+    /// registered.
+    lambda_display_names: Shared<HashMap<Handle<tags::Term>, Name>>,
+    /// The types of registered terms.
+    term_types: Shared<HashMap<Handle<tags::Term>, Handle<tags::Type>>>,
+    /// The table of theorems.
+    theorems: Shared<HashMap<Handle<tags::Theorem>, Theorem>>,
+    /// The table of interned sets of hypotheses.
+    hypotheses: Shared<HashMap<Handle<tags::Hypotheses>, Premisses>>,
+    /// The reverse of the table of interned hypotheses.
+    hypotheses_index: Shared<HashMap<Premisses, Handle<tags::Hypotheses>>>,
+    /// The table of registered substitutions.
+    substitutions:
+        Shared<HashMap<Handle<tags::Substitution>, Arc<Substitution>>>,
+    /// The reverse of the table of registered substitutions.
+    substitutions_index:
+        Shared<HashMap<Arc<Substitution>, Handle<tags::Substitution>>>,
+    /// The table of challenges.
+    challenges: HashMap<Service, Handle<tags::Term>>,
+    /// The table of capabilities.
+    capabilities: HashMap<Handle<tags::Capability>, Capability>,
+    /// The number of machine state changes signalled by the host.
+    epoch: u64,
+    /// The table of budgets, from the experimental `budgets` feature.
+    #[cfg(feature = "budgets")]
+    budgets: HashMap<Service, Budget>,
+    /// The table of proof terms.
+    proofs: Shared<HashMap<Handle<tags::Proof>, Proof>>,
+    /// The proof term recorded for each theorem, if any.
+    theorem_proofs: Shared<HashMap<Handle<tags::Theorem>, Handle<tags::Proof>>>,
+    /// Whether a proof term is recorded for every theorem registered.
+    record_proofs: bool,
+    /// The first theorem registered with each statement hash.
+    statement_hashes: Shared<HashMap<u64, Handle<tags::Theorem>>>,
+    /// Whether duplicate theorem statements are detected.
+    detect_duplicates: bool,
+    /// Whether terms are registered without checking that they are well-typed.
+    lazy_validation: bool,
+    /// The constructors of each datatype.
+    datatype_constructors:
+        HashMap<Handle<tags::TypeFormer>, Vec<Handle<tags::Constant>>>,
+    /// The datatype of each constructor.
+    constructor_datatypes:
+        HashMap<Handle<tags::Constant>, Handle<tags::TypeFormer>>,
+    /// The table of quotient types.
+    quotients: HashMap<Handle<tags::Type>, Quotient>,
+    /// The table of defined types.
+    defined_types: HashMap<Handle<tags::TypeFormer>, DefinedType>,
+    /// The table of multi-conclusion sequents, from the experimental
+    /// `sequents` feature.
+    #[cfg(feature = "sequents")]
+    sequents: HashMap<Handle<tags::Sequent>, Sequent>,
+    /// The ranges of the sequence numbers of retired handles.
+    retired: Vec<Range<usize>>,
+    /// The metadata attached to kernel objects.
+    object_metadata: HashMap<usize, ObjectMetadata>,
+    /// The metadata of theorems imported from state objects.
+    pending_theorem_metadata:
+        HashMap<crate::proof_object::Sequent, ObjectMetadata>,
+    /// The trust level of every theorem not proved by the kernel alone.
+    trust: Shared<HashMap<Handle<tags::Theorem>, TrustLevel>>,
+    /// The theorems registered with each conclusion.
+    conclusions:
+        Shared<HashMap<Handle<tags::Term>, Vec<Handle<tags::Theorem>>>>,
+    /// A Bloom filter over the conclusions of registered theorems.
+    conclusion_filter: BloomFilter,
+    /// The conclusions of registered theorems, indexed by shape.
+    conclusion_shapes: Shared<HashMap<u64, Vec<Handle<tags::Term>>>>,
+    /// The scratch store.
+    scratch: BTreeMap<String, Vec<u8>>,
+    /// Whether the scratch store is exported with state objects.
+    persist_scratch: bool,
+    /// The order in which the variables of types and terms are enumerated.
+    variable_order: VariableOrder,
+    /// The signature of the natural numbers, from the experimental
+    /// `linear-arith` feature.
+    #[cfg(feature = "linear-arith")]
+    linear_arith: Option<LinearArithSignature>,
+    /// The theorems derived directly from each theorem, from the experimental
+    /// `retraction` feature.
+    #[cfg(feature = "retraction")]
+    dependents: HashMap<Handle<tags::Theorem>, Vec<Handle<tags::Theorem>>>,
+    /// The theorems that have been retracted, from the experimental
+    /// `retraction` feature.
+    #[cfg(feature = "retraction")]
+    retracted: HashSet<Handle<tags::Theorem>>,
+}
+
+impl FrozenRuntimeState {
+    /// Returns a new `RuntimeState` whose kernel objects are those of the
+    /// base, sharing the tables of the base until it first writes to them.
+    /// The new runtime state has no observers, and no speculative branch in
+    /// progress.  Instantiating takes time proportional to the number of
+    /// tables, rather than to the number of kernel objects, in the base.
+    pub fn instantiate(&self) -> RuntimeState {
+        info!("Instantiating runtime state from frozen base.");
+
+        RuntimeState {
+            handles: self.handles.clone(),
+            type_formers: self.type_formers.clone(),
+            type_former_metadata: self.type_former_metadata.clone(),
+            type_former_names: self.type_former_names.clone(),
+            types: self.types.clone(),
+            constants: self.constants.clone(),
+            constant_names: self.constant_names.clone(),
+            terms: self.terms.clone(),
+            terms_index: self.terms_index.clone(),
+            term_heights: self.term_heights.clone(),
+            lambda_display_names: self.lambda_display_names.clone(),
+            term_types: self.term_types.clone(),
+            theorems: self.theorems.clone(),
+            hypotheses: self.hypotheses.clone(),
+            hypotheses_index: self.hypotheses_index.clone(),
+            substitutions: self.substitutions.clone(),
+            substitutions_index: self.substitutions_index.clone(),
+            challenges: self.challenges.clone(),
+            capabilities: self.capabilities.clone(),
+            epoch: self.epoch,
+            #[cfg(feature = "budgets")]
+            budgets: self.budgets.clone(),
+            proofs: self.proofs.clone(),
+            theorem_proofs: self.theorem_proofs.clone(),
+            record_proofs: self.record_proofs,
+            statement_hashes: self.statement_hashes.clone(),
+            detect_duplicates: self.detect_duplicates,
+            lazy_validation: self.lazy_validation,
+            datatype_constructors: self.datatype_constructors.clone(),
+            constructor_datatypes: self.constructor_datatypes.clone(),
+            quotients: self.quotients.clone(),
+            defined_types: self.defined_types.clone(),
+            #[cfg(feature = "sequents")]
+            sequents: self.sequents.clone(),
+            speculations: Vec::new(),
+            retired: self.retired.clone(),
+            error_detail: RefCell::new(None),
+            observers: Observers::default(),
+            object_metadata: self.object_metadata.clone(),
+            references: HashMap::new(),
+            pending_theorem_metadata: self.pending_theorem_metadata.clone(),
+            trust: self.trust.clone(),
+            conclusions: self.conclusions.clone(),
+            conclusion_filter: self.conclusion_filter.clone(),
+            conclusion_shapes: self.conclusion_shapes.clone(),
+            scratch: self.scratch.clone(),
+            persist_scratch: self.persist_scratch,
+            variable_order: self.variable_order,
+            #[cfg(feature = "linear-arith")]
+            linear_arith: self.linear_arith.clone(),
+            #[cfg(feature = "retraction")]
+            dependents: self.dependents.clone(),
+            #[cfg(feature = "retraction")]
+            retracted: self.retracted.clone(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // The runtime state.
 ////////////////////////////////////////////////////////////////////////////////
@@ -84,35 +456,224 @@ use std::{
 /// The runtime state of the kernel, containing the various tables of kernel
 /// objects, indexed by handles.  The Wasm host interface manipulates this
 /// state via system calls.  The runtime state also keeps track of the next
-/// kernel handle to issue.  At present, kernel handles are only ever issued in
-/// a monotonically-increasing order, and once issued are never invalidated or
-/// freed.  Note that untrusted, "prover-space", code should not rely on that
-/// behaviour, however.
+/// kernel handle to issue.  By default, kernel handles are issued in a
+/// monotonically-increasing order, unless the runtime state was created with
+/// randomised handles, and once issued are never reissued.  Note that
+/// untrusted, "prover-space", code should not rely on that behaviour, however.
+///
+/// # Examples
+///
+/// Embedders drive the kernel directly, registering objects and applying
+/// inference rules, each of which returns the handle of the new object:
+///
+/// ```
+/// use kernel::{
+///     handle::PREALLOCATED_HANDLE_TYPE_PROP, runtime_state::RuntimeState,
+/// };
+///
+/// let mut state = RuntimeState::new();
+///
+/// let p = state
+///     .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+///     .unwrap();
+/// let thm = state.theorem_register_assumption(p.clone()).unwrap();
+///
+/// assert_eq!(state.theorem_split_conclusion(&thm), Ok(p.clone()));
+/// assert_eq!(state.theorem_split_premisses(&thm).unwrap(), &vec![p]);
+/// ```
 #[derive(Clone, Debug)]
 pub struct RuntimeState {
-    /// The next handle to issue by the runtime state when a new kernel object
-    /// is registered.
-    next_handle: usize,
+    /// Issues the handles of new kernel objects, and records the order in
+    /// which they were issued.
+    pub(crate) handles: HandleAllocator,
     /// The table of registered type-formers.  Handles are essentially names for
     /// type-formers.
-    type_formers: HashMap<Handle<tags::TypeFormer>, usize>,
+    type_formers: Shared<HashMap<Handle<tags::TypeFormer>, usize>>,
+    /// The metadata supplied when registering type-formers, if any.
+    type_former_metadata: HashMap<Handle<tags::TypeFormer>, TypeFormerMetadata>,
+    /// The symbol table of named type-formers, associating each name with the
+    /// first type-former registered with it.
+    pub(crate) type_former_names: HashMap<String, Handle<tags::TypeFormer>>,
     /// The table of types.  The kernel enforces maximal sharing, wherein any
     /// attempt to register a previously-registered type means that the handle
     /// pointing to the registered type is returned.
-    types: HashMap<Handle<tags::Type>, Type>,
+    types: Shared<HashMap<Handle<tags::Type>, Type>>,
     /// The table of constants, associating handles for constants to handles for
     /// types.  Handles are essentially names for constants.
-    constants: HashMap<Handle<tags::Constant>, Handle<tags::Type>>,
+    constants: Shared<HashMap<Handle<tags::Constant>, Handle<tags::Type>>>,
+    /// The symbol table of named constants, associating each name with the
+    /// constant registered with it.
+    pub(crate) constant_names: HashMap<String, Handle<tags::Constant>>,
     /// The table of terms.  The kernel enforces maximal sharing, wherein any
     /// attempt to register a previously-registered term (up-to
     /// alpha-equivalence) means that the handle pointing to the registered term
-    /// is returned.
-    terms: HashMap<Handle<tags::Term>, Term>,
+    /// is returned.  Terms are stored in ⍺-normal form, with every bound
+    /// variable renamed to a canonical name, so two terms are ⍺-equivalent iff
+    /// they are structurally equal.
+    terms: Shared<HashMap<Handle<tags::Term>, Term>>,
+    /// The reverse of the term-table, used to find the handle of a term that
+    /// has already been registered.
+    terms_index: Shared<HashMap<Term, Handle<tags::Term>>>,
+    /// The heights of registered terms, used to choose canonical names: for
+    /// each term, one more than the largest index of a canonical name bound
+    /// within it, and one more than the largest index of a canonical name of
+    /// a variable occurring within it.  Terms with no entry have neither.
+    term_heights: Shared<HashMap<Handle<tags::Term>, (u64, u64)>>,
+    /// The names of bound variables chosen when λ-abstractions were first
+    /// registered, before they were renamed to canonical names.
+    lambda_display_names: Shared<HashMap<Handle<tags::Term>, Name>>,
+    /// The types of registered terms.  Terms registered through the
+    /// `term_register_*` functions are annotated with their type as they are
+    /// admitted, and the types of any other terms are recorded the first time
+    /// that they are inferred, so that registering a term over registered
+    /// subterms never infers their types again.
+    term_types: Shared<HashMap<Handle<tags::Term>, Handle<tags::Type>>>,
     /// The table of theorems.  The kernel enforces maximal sharing, wherein any
     /// attempt to register a previously-registered theorem (up-to
     /// alpha-equivalence of the conclusion and hypotheses) means that the
     /// handle pointing to the registered theorem is returned.
-    theorems: HashMap<Handle<tags::Theorem>, Theorem>,
+    pub(crate) theorems: Shared<HashMap<Handle<tags::Theorem>, Theorem>>,
+    /// The table of interned sets of hypotheses.  Every theorem admitted into
+    /// the theorem-table shares its premisses with the matching entry in this
+    /// table, so that theorems with the same premisses do not each hold their
+    /// own copy.  The kernel enforces maximal sharing, so two sets of
+    /// hypotheses are equal iff their handles are equal.
+    hypotheses: Shared<HashMap<Handle<tags::Hypotheses>, Premisses>>,
+    /// The reverse of the table of interned hypotheses, used to find the handle
+    /// of a set of hypotheses that has already been interned.
+    hypotheses_index: Shared<HashMap<Premisses, Handle<tags::Hypotheses>>>,
+    /// The table of registered substitutions, so that a substitution applied
+    /// to many terms or types need only be supplied and checked once.  The
+    /// kernel enforces maximal sharing, wherein any attempt to register a
+    /// previously-registered substitution means that the handle pointing to
+    /// the registered substitution is returned.
+    pub(crate) substitutions:
+        Shared<HashMap<Handle<tags::Substitution>, Arc<Substitution>>>,
+    /// The reverse of the table of registered substitutions, used to find the
+    /// handle of a substitution that has already been registered.
+    pub(crate) substitutions_index:
+        Shared<HashMap<Arc<Substitution>, Handle<tags::Substitution>>>,
+    /// The table of challenges, associating each guarded service with a handle
+    /// to the proposition that must be proved before the service may be used.
+    challenges: HashMap<Service, Handle<tags::Term>>,
+    /// The table of capabilities minted by discharging challenges.  Like other
+    /// kernel objects, capabilities are never freed, even once expired.
+    capabilities: HashMap<Handle<tags::Capability>, Capability>,
+    /// The number of machine state changes signalled by the host, used to
+    /// expire capabilities that are only valid until the state changes.
+    epoch: u64,
+    /// The table of budgets, from the experimental `budgets` feature,
+    /// associating each budgeted service with the resource assertion granted
+    /// to it.  Budgets are not restored when a speculative branch is
+    /// discarded, as the uses that consumed them have already happened.
+    #[cfg(feature = "budgets")]
+    pub(crate) budgets: HashMap<Service, Budget>,
+    /// The table of proof terms, recorded for theorems registered whilst proof
+    /// recording was enabled.
+    proofs: Shared<HashMap<Handle<tags::Proof>, Proof>>,
+    /// The proof term recorded for each theorem, if any.
+    theorem_proofs: Shared<HashMap<Handle<tags::Theorem>, Handle<tags::Proof>>>,
+    /// Whether a proof term is recorded for every theorem registered.
+    record_proofs: bool,
+    /// The first theorem registered with each statement hash, whilst duplicate
+    /// detection was enabled.
+    statement_hashes: Shared<HashMap<u64, Handle<tags::Theorem>>>,
+    /// Whether a warning is logged for every theorem registered whose
+    /// statement hash duplicates that of an earlier theorem.
+    detect_duplicates: bool,
+    /// Whether terms are registered without checking that they are well-typed,
+    /// deferring the check until they are used in a theorem.
+    lazy_validation: bool,
+    /// The constructors of each datatype, identified by its type-former, in
+    /// the order in which they were marked.
+    datatype_constructors:
+        HashMap<Handle<tags::TypeFormer>, Vec<Handle<tags::Constant>>>,
+    /// The datatype, identified by its type-former, of each constructor.
+    constructor_datatypes:
+        HashMap<Handle<tags::Constant>, Handle<tags::TypeFormer>>,
+    /// The table of quotient types, registered with the quotient type package,
+    /// indexed by the quotient type.
+    pub(crate) quotients: HashMap<Handle<tags::Type>, Quotient>,
+    /// The table of types defined with the type-definition principle, along
+    /// with their abstraction and representation functions and the theorems
+    /// characterising them, indexed by the type-former of the defined type.
+    pub(crate) defined_types: HashMap<Handle<tags::TypeFormer>, DefinedType>,
+    /// The table of multi-conclusion sequents, from the experimental
+    /// `sequents` feature.
+    #[cfg(feature = "sequents")]
+    pub(crate) sequents: HashMap<Handle<tags::Sequent>, Sequent>,
+    /// The stack of speculative branches currently in progress, innermost
+    /// last.
+    speculations: Vec<Speculation>,
+    /// The ranges of the sequence numbers of handles issued within
+    /// speculative branches that have since been discarded, disjoint and in
+    /// ascending order.
+    retired: Vec<Range<usize>>,
+    /// An explanation of the most recent failure that recorded one, for
+    /// failures whose error code alone says little, e.g. `ShapeMismatch`.  The
+    /// checks of inference rules only borrow the runtime state, hence the
+    /// interior mutability.
+    error_detail: RefCell<Option<String>>,
+    /// The observers registered by the embedder, notified of kernel events as
+    /// they happen.
+    pub(crate) observers: Observers,
+    /// The metadata attached to kernel objects by prover-space code, indexed
+    /// by the handle of the object.  Handles are never reissued, so the handle
+    /// alone identifies an object, whatever its kind.
+    pub(crate) object_metadata: HashMap<usize, ObjectMetadata>,
+    /// The number of references held by the guest to each kernel object,
+    /// indexed by the handle of the object.  Objects with no entry have none,
+    /// and may be freed once nothing else refers to them.
+    pub(crate) references: HashMap<usize, usize>,
+    /// The metadata of theorems imported from state objects, indexed by their
+    /// statements.  As importing registers no theorems, the metadata is only
+    /// attached once a theorem with the same statement is registered.
+    pub(crate) pending_theorem_metadata:
+        HashMap<crate::proof_object::Sequent, ObjectMetadata>,
+    /// The trust level of every theorem that depends upon more than the
+    /// kernel's inference rules, either because it is an axiom or was proved
+    /// by the linear arithmetic decision procedure, or because it was derived
+    /// from a theorem that was.  Unlike every other theorem, these are only
+    /// sound if the axioms and the procedure are, so are tracked for the
+    /// benefit of consumers that do not wish to trust them.  Theorems with no
+    /// entry are proved by the kernel alone.
+    pub(crate) trust: Shared<HashMap<Handle<tags::Theorem>, TrustLevel>>,
+    /// The theorems registered with each conclusion.  As terms are maximally
+    /// shared, and kept in alpha-normal form, the handle of a conclusion
+    /// identifies it up to alpha-equivalence.
+    pub(crate) conclusions:
+        Shared<HashMap<Handle<tags::Term>, Vec<Handle<tags::Theorem>>>>,
+    /// A Bloom filter over the conclusions of registered theorems, so that
+    /// most queries for a conclusion that no theorem has are answered without
+    /// consulting `conclusions`.
+    conclusion_filter: BloomFilter,
+    /// The conclusions of registered theorems, indexed by their shape, a hash
+    /// of their structure with every type erased, so that the conclusions
+    /// matching a pattern may be found without matching every conclusion.
+    /// See the `search` module.
+    pub(crate) conclusion_shapes: Shared<HashMap<u64, Vec<Handle<tags::Term>>>>,
+    /// The scratch store of the session, in which prover-space code may stash
+    /// arbitrary data under string keys.  Like metadata, the scratch store
+    /// plays no part in the logic, and is untouched by speculative branches.
+    pub(crate) scratch: BTreeMap<String, Vec<u8>>,
+    /// Whether the scratch store is exported with state objects.
+    pub(crate) persist_scratch: bool,
+    /// The order in which the variables of types and terms are enumerated.
+    variable_order: VariableOrder,
+    /// The signature of the natural numbers, from the experimental
+    /// `linear-arith` feature, registered when first requested.
+    #[cfg(feature = "linear-arith")]
+    pub(crate) linear_arith: Option<LinearArithSignature>,
+    /// The theorems derived directly from each theorem, from the experimental
+    /// `retraction` feature, so that the theorems depending upon a retracted
+    /// theorem may be found.
+    #[cfg(feature = "retraction")]
+    pub(crate) dependents:
+        HashMap<Handle<tags::Theorem>, Vec<Handle<tags::Theorem>>>,
+    /// The theorems that have been retracted, from the experimental
+    /// `retraction` feature, which may no longer be used.
+    #[cfg(feature = "retraction")]
+    pub(crate) retracted: HashSet<Handle<tags::Theorem>>,
 }
 
 impl RuntimeState {
@@ -123,23 +684,87 @@ impl RuntimeState {
         Default::default()
     }
 
+    /// Returns a new `RuntimeState`, as `new`, that issues handles drawn
+    /// pseudo-randomly from a sparse space, by a generator seeded with `seed`,
+    /// rather than sequentially, so that prover-space code cannot guess the
+    /// handles of kernel objects that it was never given.  Handles issued by
+    /// two runtime states are then only the same if they were seeded alike, so
+    /// sessions relying on handles being issued deterministically, e.g. when
+    /// replaying a recorded trace, must reuse the seed.
+    #[inline]
+    pub fn with_randomised_handles(seed: u64) -> Self {
+        RuntimeState {
+            handles: HandleAllocator::randomised(seed),
+            ..RuntimeState::new()
+        }
+    }
+
+    /// Returns `true` iff the runtime state issues randomised handles.
+    #[inline]
+    pub fn has_randomised_handles(&self) -> bool {
+        self.handles.is_randomised()
+    }
+
+    /// Resets the runtime state, discarding every kernel object registered
+    /// since it was created, so that a fresh session may begin without
+    /// creating a new runtime state.  Only the preallocated kernel objects
+    /// remain.  Any speculative branches in progress are abandoned, and the
+    /// challenges guarding services, and the budgets granted to them, are
+    /// discarded along with everything else, so must be registered anew, and
+    /// the scratch store is emptied.  The settings of the runtime state, such
+    /// as whether proofs are recorded, are kept, as are the observers
+    /// registered by the embedder.
+    ///
+    /// As when a speculative branch is discarded, handles are never reissued:
+    /// every handle issued before the reset is retired, so stale handles
+    /// retained by the caller are reported with `ErrorCode::StaleHandle`.
+    pub fn reset(&mut self) {
+        info!("Resetting runtime state.");
+
+        let fresh = RuntimeState::new();
+
+        /* NB: every range retired so far is subsumed by the range retired by
+         * the reset.
+         */
+        let mut retired = Vec::new();
+
+        let first = fresh.handles.next_sequence();
+
+        if first < self.handles.next_sequence() {
+            retired.push(first..self.handles.next_sequence());
+        }
+
+        *self = RuntimeState {
+            handles: self.handles.clone(),
+            epoch: self.epoch,
+            record_proofs: self.record_proofs,
+            detect_duplicates: self.detect_duplicates,
+            lazy_validation: self.lazy_validation,
+            persist_scratch: self.persist_scratch,
+            variable_order: self.variable_order,
+            retired,
+            observers: take(&mut self.observers),
+            ..fresh
+        };
+    }
+
     /// Issues a fresh handle.  Callers should not rely on this returning
     /// consecutive handles.
     ///
     /// Will **panic** if issued handles are exhausted.
-    fn issue_handle<T>(&mut self) -> Handle<T>
+    pub(crate) fn issue_handle<T>(&mut self) -> Handle<T>
     where
         T: tags::IsTag,
     {
-        let next = self.next_handle;
-
-        match self.next_handle.checked_add(1) {
-            None => panic!("{}", HANDLE_EXHAUST_ERROR),
-            Some(next) => self.next_handle = next,
-        }
+        let next = self
+            .handles
+            .issue()
+            .unwrap_or_else(|| panic!("{}", HANDLE_EXHAUST_ERROR));
 
         info!("Generating fresh handle: {}.", next);
 
+        self.notify_object_allocated(T::KIND, next);
+
         Handle::from(next)
     }
 
@@ -192,6 +817,72 @@ impl RuntimeState {
         result
     }
 
+    /// Registers a new type-former with a declared arity with the runtime
+    /// state, as `type_former_register`, attaching `metadata` to it.  Returns
+    /// the handle to the newly-registered type-former.  A non-empty name in
+    /// `metadata` is also entered into the kernel's symbol table, unless
+    /// another type-former was registered with the same name first.
+    pub fn type_former_register_with_metadata<T>(
+        &mut self,
+        arity: T,
+        metadata: TypeFormerMetadata,
+    ) -> Handle<tags::TypeFormer>
+    where
+        T: Into<usize> + Clone,
+    {
+        info!("Registering new type-former with metadata: {:?}.", metadata);
+
+        let handle = self.type_former_register(arity);
+        self.type_former_name(&handle, metadata.name());
+        self.type_former_metadata.insert(handle.clone(), metadata);
+        handle
+    }
+
+    /// Returns `Ok(Some(metadata))` if the type-former pointed-to by `handle`
+    /// was registered with metadata `metadata`, and `Ok(None)` if it was
+    /// registered without any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeFormerRegistered)` if `handle` does
+    /// not point-to a registered type-former.
+    pub fn type_former_metadata<T>(
+        &self,
+        handle: T,
+    ) -> Result<Option<&TypeFormerMetadata>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        info!(
+            "Resolving metadata of type-former with handle: {}.",
+            handle.borrow()
+        );
+
+        if !self.type_former_is_registered(handle.borrow()) {
+            return Err(self.unresolved(
+                handle.borrow(),
+                ErrorCode::NoSuchTypeFormerRegistered,
+            ));
+        }
+
+        Ok(self.type_former_metadata.get(handle.borrow()))
+    }
+
+    /// Returns the handles of every type-former registered in the runtime
+    /// state, other than the kernel's preallocated type-formers, in ascending
+    /// order.
+    pub(crate) fn type_former_handles(&self) -> Vec<Handle<tags::TypeFormer>> {
+        let mut handles: Vec<_> = self
+            .type_formers
+            .keys()
+            .filter(|h| !is_preallocated((*h).clone()))
+            .cloned()
+            .collect();
+
+        handles.sort();
+        handles
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Type related material.
     ////////////////////////////////////////////////////////////////////////////
@@ -202,7 +893,7 @@ impl RuntimeState {
     ///
     /// Functions calling this should ensure that the type argument, `tau`, is
     /// well-formed before calling.
-    fn admit_type(&mut self, tau: Type) -> Handle<tags::Type> {
+    pub(crate) fn admit_type(&mut self, tau: Type) -> Handle<tags::Type> {
         info!("Admitting type: {:?}.", tau);
 
         for (handle, registered) in self.types.iter() {
@@ -220,16 +911,26 @@ impl RuntimeState {
         handle
     }
 
-    /// Returns `Some(tau)` iff the handle points to a type, `tau` in the
+    /// Returns `Ok(tau)` iff the handle points to a type, `tau` in the
     /// runtime state's type-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to any registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::StaleHandle)` if `handle` was issued within a
+    /// speculative branch that has since been discarded.
     #[inline]
-    pub fn resolve_type_handle<T>(&self, handle: T) -> Option<&Type>
+    pub fn resolve_type_handle<T>(&self, handle: T) -> Result<&Type, ErrorCode>
     where
         T: Borrow<Handle<tags::Type>>,
     {
         info!("Resolving type with handle: {}.", handle.borrow());
 
-        self.types.get(handle.borrow())
+        self.types.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(handle.borrow(), ErrorCode::NoSuchTypeRegistered)
+        })
     }
 
     /// Returns `true` iff the handle points to a type, `tau`, in the runtime
@@ -241,7 +942,7 @@ impl RuntimeState {
     {
         info!("Checking type {} is registered.", handle.borrow());
 
-        let result = self.resolve_type_handle(handle).is_some();
+        let result = self.resolve_type_handle(handle).is_ok();
 
         info!("Result: {}.", result);
 
@@ -294,15 +995,13 @@ impl RuntimeState {
 
         let former = former.into();
 
-        let arity = self
-            .type_former_resolve(former.clone())
-            .ok_or(ErrorCode::NoSuchTypeFormerRegistered)?;
+        let arity =
+            self.type_former_resolve(former.clone()).ok_or_else(|| {
+                self.unresolved(&former, ErrorCode::NoSuchTypeFormerRegistered)
+            })?;
 
-        if !arguments
-            .iter()
-            .all(|a| self.type_is_registered(a.clone().into()))
-        {
-            return Err(ErrorCode::NoSuchTypeRegistered);
+        for argument in arguments.iter() {
+            self.resolve_type_handle(argument.clone().into())?;
         }
 
         if arguments.len() != *arity {
@@ -323,6 +1022,38 @@ impl RuntimeState {
     ///
     /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if either `domain` or
     /// `range` do not point-to a type in the runtime state's type-table.
+    ///
+    /// # Examples
+    ///
+    /// Types are shared maximally, so registering the same function type twice
+    /// yields the same handle:
+    ///
+    /// ```
+    /// use kernel::{
+    ///     handle::{
+    ///         PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP,
+    ///     },
+    ///     runtime_state::RuntimeState,
+    /// };
+    ///
+    /// let mut state = RuntimeState::new();
+    ///
+    /// let predicate = state
+    ///     .type_register_function(
+    ///         PREALLOCATED_HANDLE_TYPE_ALPHA,
+    ///         PREALLOCATED_HANDLE_TYPE_PROP,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert!(state.type_test_function(&predicate).unwrap());
+    /// assert_eq!(
+    ///     state.type_register_function(
+    ///         PREALLOCATED_HANDLE_TYPE_ALPHA,
+    ///         PREALLOCATED_HANDLE_TYPE_PROP,
+    ///     ),
+    ///     Ok(predicate)
+    /// );
+    /// ```
     pub fn type_register_function<T>(
         &mut self,
         domain: T,
@@ -339,13 +1070,9 @@ impl RuntimeState {
         let domain = domain.into();
         let range = range.into();
 
-        if !self.type_is_registered(&domain) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(&domain)?;
 
-        if !self.type_is_registered(&range) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(&range)?;
 
         Ok(self.admit_type(Type::Combination {
             former: PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
@@ -369,11 +1096,9 @@ impl RuntimeState {
     {
         info!("Splitting handle {} into type variable.", handle.borrow());
 
-        if let Some(tau) = self.resolve_type_handle(handle) {
-            tau.split_variable().ok_or(ErrorCode::NotATypeVariable)
-        } else {
-            Err(ErrorCode::NoSuchTypeRegistered)
-        }
+        self.resolve_type_handle(handle)?
+            .split_variable()
+            .ok_or(ErrorCode::NotATypeVariable)
     }
 
     /// Returns `Ok((former, args))` iff the type pointed-to by `handle` in the
@@ -400,12 +1125,9 @@ impl RuntimeState {
             handle.borrow()
         );
 
-        if let Some(tau) = self.resolve_type_handle(handle) {
-            tau.split_combination()
-                .ok_or(ErrorCode::NotATypeCombination)
-        } else {
-            Err(ErrorCode::NoSuchTypeRegistered)
-        }
+        self.resolve_type_handle(handle)?
+            .split_combination()
+            .ok_or(ErrorCode::NotATypeCombination)
     }
 
     /// Returns `Ok((domain, range))` iff the type pointed-to by `handle` in the
@@ -427,10 +1149,76 @@ impl RuntimeState {
     {
         info!("Splitting handle {} into function type.", handle.borrow());
 
-        if let Some(tau) = self.resolve_type_handle(handle) {
-            tau.split_function().ok_or(ErrorCode::NotAFunctionType)
+        self.resolve_type_handle(handle)?
+            .split_function()
+            .ok_or(ErrorCode::NotAFunctionType)
+    }
+
+    /// Returns `Ok((left, right))` iff the type pointed-to by `handle` in the
+    /// runtime state's type-table is a product type of `left` and `right`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProductType)` if the type pointed-to by
+    /// `handle` in the runtime state's type-table is not a product type.
+    fn type_split_product<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Handle<tags::Type>, &Handle<tags::Type>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        info!("Splitting handle {} into product type.", handle.borrow());
+
+        let (former, arguments) =
+            self.type_split_combination(handle).map_err(|e| match e {
+                ErrorCode::NotATypeCombination => ErrorCode::NotAProductType,
+                otherwise => otherwise,
+            })?;
+
+        if former != &PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT {
+            return Err(ErrorCode::NotAProductType);
+        }
+
+        match arguments.as_slice() {
+            [left, right] => Ok((left, right)),
+            _otherwise => panic!("{}", PRIMITIVE_CONSTRUCTION_ERROR),
+        }
+    }
+
+    /// Returns `Ok(element)` iff the type pointed-to by `handle` in the
+    /// runtime state's type-table is a set type, `element -> Prop`, of sets
+    /// with elements of type `element`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the type pointed-to by
+    /// `handle` in the runtime state's type-table is not a set type.
+    fn type_split_set<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Handle<tags::Type>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        info!("Splitting handle {} into set type.", handle.borrow());
+
+        let (element, range) =
+            self.type_split_function(handle).map_err(|e| match e {
+                ErrorCode::NotAFunctionType => ErrorCode::NotASetType,
+                otherwise => otherwise,
+            })?;
+
+        if range == &PREALLOCATED_HANDLE_TYPE_PROP {
+            Ok(element)
         } else {
-            Err(ErrorCode::NoSuchTypeRegistered)
+            Err(ErrorCode::NotASetType)
         }
     }
 
@@ -448,11 +1236,7 @@ impl RuntimeState {
     {
         info!("Testing handle {} as type-variable.", handle.borrow());
 
-        Ok(self
-            .resolve_type_handle(handle)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?
-            .split_variable()
-            .is_some())
+        Ok(self.resolve_type_handle(handle)?.split_variable().is_some())
     }
 
     /// Returns `Ok(true)` iff the type pointed-to by `handle` in the runtime
@@ -470,8 +1254,7 @@ impl RuntimeState {
         info!("Testing handle {} as type combination.", handle.borrow());
 
         Ok(self
-            .resolve_type_handle(handle)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?
+            .resolve_type_handle(handle)?
             .split_combination()
             .is_some())
     }
@@ -490,19 +1273,17 @@ impl RuntimeState {
     {
         info!("Testing handle {} as function type.", handle.borrow());
 
-        Ok(self
-            .resolve_type_handle(handle)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?
-            .split_function()
-            .is_some())
+        Ok(self.resolve_type_handle(handle)?.split_function().is_some())
     }
 
     /// Returns `Ok(size)` where `size` is the size of the type pointed-to by
     /// `handle`.  Here, size is defined recursively on the structure of types
     /// by:
     ///
-    ///     size(Variable(n)) = 1
-    ///     size(Combination(f, a_1, ..., a_n) = 1 + size(a_1) + ... + size(a_n)
+    /// ```text
+    /// size(Variable(n)) = 1
+    /// size(Combination(f, a_1, ..., a_n) = 1 + size(a_1) + ... + size(a_n)
+    /// ```
     ///
     /// # Errors
     ///
@@ -517,9 +1298,7 @@ impl RuntimeState {
     {
         info!("Computing size of type {}.", handle.borrow());
 
-        let tau = self
-            .resolve_type_handle(handle)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?;
+        let tau = self.resolve_type_handle(handle)?;
 
         let mut size = 0u64;
         let mut work_list = vec![tau];
@@ -531,7 +1310,7 @@ impl RuntimeState {
                     let mut arguments = arguments
                         .iter()
                         .map(|a| {
-                            self.resolve_type_handle(a).unwrap_or_else(|| {
+                            self.resolve_type_handle(a).unwrap_or_else(|_e| {
                                 panic!("{}", DANGLING_HANDLE_ERROR)
                             })
                         })
@@ -546,7 +1325,8 @@ impl RuntimeState {
     }
 
     /// Returns `Ok(vs)` where `vs` is the set of variables appearing in the
-    /// type pointed-to by `handle` in the runtime state's type-table.
+    /// type pointed-to by `handle` in the runtime state's type-table, each
+    /// exactly once, in the runtime state's variable order.
     ///
     /// # Errors
     ///
@@ -561,34 +1341,46 @@ impl RuntimeState {
     {
         info!("Computing variables of type {}.", handle.borrow());
 
-        let tau = self
-            .resolve_type_handle(handle)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?;
+        let tau = self.resolve_type_handle(handle)?;
 
         let mut ftv = Vec::new();
         let mut work_list = vec![tau];
 
+        /* NB: arguments are pushed in reverse, so that they are popped from
+         * first to last, and variables are found in order of first occurrence.
+         */
         while let Some(tau) = work_list.pop() {
             match tau {
                 Type::Variable { name } => ftv.push(name),
                 Type::Combination { arguments, .. } => {
-                    let mut arguments = arguments
-                        .iter()
-                        .map(|a| {
-                            self.resolve_type_handle(a).unwrap_or_else(|| {
-                                panic!("{}", DANGLING_HANDLE_ERROR)
-                            })
+                    let arguments = arguments.iter().rev().map(|a| {
+                        self.resolve_type_handle(a).unwrap_or_else(|_e| {
+                            panic!("{}", DANGLING_HANDLE_ERROR)
                         })
-                        .collect();
-                    work_list.append(&mut arguments);
+                    });
+                    work_list.extend(arguments);
                 }
             }
         }
 
-        ftv.sort();
-        ftv.dedup();
+        Ok(self.order_variables(ftv))
+    }
+
+    /// Removes every repeated name from `names`, listed in order of first
+    /// occurrence, keeping the first, and sorts the result if the runtime
+    /// state's variable order demands it.
+    fn order_variables<'a>(&self, names: Vec<&'a Name>) -> Vec<&'a Name> {
+        let mut seen = HashSet::new();
+        let mut names: Vec<&Name> = names
+            .into_iter()
+            .filter(|name| seen.insert(*name))
+            .collect();
+
+        if self.variable_order == VariableOrder::ByName {
+            names.sort();
+        }
 
-        Ok(ftv)
+        names
     }
 
     /// Instantiates a type pointed-to by the handle `tau`, using the type
@@ -616,15 +1408,10 @@ impl RuntimeState {
     {
         info!("Substituting {:?} in type {}.", sigma, tau.borrow());
 
-        let mut tau = self
-            .resolve_type_handle(tau)
-            .ok_or(ErrorCode::NoSuchTypeRegistered)?
-            .clone();
+        let mut tau = self.resolve_type_handle(tau)?.clone();
 
         for (domain, range) in sigma.clone() {
-            let range = self
-                .resolve_type_handle(&range.into())
-                .ok_or(ErrorCode::NoSuchTypeRegistered)?;
+            let range = self.resolve_type_handle(&range.into())?;
 
             match tau {
                 Type::Variable { ref name } => {
@@ -652,6 +1439,55 @@ impl RuntimeState {
         Ok(self.admit_type(tau))
     }
 
+    /// Returns `true` iff the type pointed-to by `target` is a substitutive
+    /// instance of the type pointed-to by `pattern`, extending `sigma` with the
+    /// bindings for the type-variables of `pattern` needed to witness this.
+    ///
+    /// Will raise a kernel panic if either type contains a dangling handle.
+    pub(crate) fn type_match(
+        &self,
+        pattern: &Handle<tags::Type>,
+        target: &Handle<tags::Type>,
+        sigma: &mut Vec<(Name, Handle<tags::Type>)>,
+    ) -> bool {
+        let pattern = self
+            .resolve_type_handle(pattern)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        match pattern {
+            Type::Variable { name } => {
+                if let Some((_name, bound)) =
+                    sigma.iter().find(|(n, _)| n == name)
+                {
+                    bound == target
+                } else {
+                    sigma.push((*name, target.clone()));
+                    true
+                }
+            }
+            Type::Combination { former, arguments } => {
+                let target = self
+                    .resolve_type_handle(target)
+                    .expect(DANGLING_HANDLE_ERROR);
+
+                if let Type::Combination {
+                    former: tformer,
+                    arguments: targuments,
+                } = target
+                {
+                    former == tformer
+                        && arguments.len() == targuments.len()
+                        && arguments
+                            .iter()
+                            .zip(targuments.iter())
+                            .all(|(p, t)| self.type_match(p, t, sigma))
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Constant related material.
     ////////////////////////////////////////////////////////////////////////////
@@ -676,9 +1512,7 @@ impl RuntimeState {
             handle.clone().into()
         );
 
-        if !self.type_is_registered(handle.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(handle.clone().into())?;
 
         let fresh = self.issue_handle();
         self.constants.insert(fresh.clone(), handle.into());
@@ -702,9 +1536,12 @@ impl RuntimeState {
     {
         info!("Resolving constant with handle: {}.", handle.borrow());
 
-        self.constants
-            .get(handle.borrow())
-            .ok_or(ErrorCode::NoSuchConstantRegistered)
+        self.constants.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(
+                handle.borrow(),
+                ErrorCode::NoSuchConstantRegistered,
+            )
+        })
     }
 
     /// Returns `true` iff `handle` points-to a registered constant in the
@@ -723,57 +1560,508 @@ impl RuntimeState {
         result
     }
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Term related material.
-    ////////////////////////////////////////////////////////////////////////////
+    /// Marks the constants pointed-to by `constructors` as the free
+    /// constructors of the datatype whose type-former is pointed-to by
+    /// `former`, in order.  The type of every constructor must be of the form
+    /// `τ1 -> ... -> τn -> F(σ1, ..., σm)`, with `n` possibly zero, where `F`
+    /// is the type-former of the datatype.  Markings are only metadata, and
+    /// are not consulted by any inference rule, but they allow the datatype
+    /// package to find the constructors of a datatype, and those of a
+    /// constructor's siblings, when deriving distinctness and injectivity
+    /// theorems or case splits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeFormerRegistered)` if `former` does
+    /// not point-to a registered type-former.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchConstantRegistered)` if any handle in
+    /// `constructors` does not point-to a registered constant.
+    ///
+    /// Returns `Err(ErrorCode::ConstructorsAlreadyMarked)` if the constructors
+    /// of the datatype have already been marked, if `former` is from the
+    /// bootstrap theory, or if any constant is already marked as a
+    /// constructor, or appears in `constructors` more than once.
+    ///
+    /// Returns `Err(ErrorCode::NotAConstructor)` if `constructors` is empty,
+    /// if any constant is from the bootstrap theory, or if the type of any
+    /// constant does not construct values of the datatype.
+    pub fn constant_mark_constructors<T, U>(
+        &mut self,
+        former: T,
+        constructors: Vec<U>,
+    ) -> Result<(), ErrorCode>
+    where
+        T: Into<Handle<tags::TypeFormer>> + Clone,
+        U: Into<Handle<tags::Constant>> + Clone,
+    {
+        let former = former.into();
+        let constructors: Vec<Handle<tags::Constant>> =
+            constructors.into_iter().map(|c| c.into()).collect();
 
-    /// Admits a new term into the runtime state's term-table.  If any term
-    /// exists in the runtime state's term-table that is alpha-equivalent to
-    /// `trm` then the handle for that existing term is returned.  Otherwise, a
-    /// fresh handle is generated and the term `trm` is admitted.  It is
-    /// expected that `trm` has been checked for well-formedness before this
-    /// function is called.
-    fn admit_term(&mut self, trm: Term) -> Handle<tags::Term> {
-        for (handle, registered) in self.terms.clone().iter() {
-            if self
-                .alpha_equivalent_inner(&trm, registered)
-                .expect(DANGLING_HANDLE_ERROR)
+        info!(
+            "Marking constants {:?} as constructors of type-former {}.",
+            constructors, former
+        );
+
+        if !self.type_former_is_registered(&former) {
+            return Err(
+                self.unresolved(&former, ErrorCode::NoSuchTypeFormerRegistered)
+            );
+        }
+
+        if is_preallocated(former.clone())
+            || self.datatype_constructors.contains_key(&former)
+        {
+            return Err(ErrorCode::ConstructorsAlreadyMarked);
+        }
+
+        if constructors.is_empty() {
+            return Err(ErrorCode::NotAConstructor);
+        }
+
+        for (index, constructor) in constructors.iter().enumerate() {
+            let mut tau = self.constant_resolve(constructor)?;
+
+            if self.constructor_datatypes.contains_key(constructor)
+                || constructors[..index].contains(constructor)
             {
-                return handle.clone();
+                return Err(ErrorCode::ConstructorsAlreadyMarked);
+            }
+
+            if is_preallocated(constructor.clone()) {
+                return Err(ErrorCode::NotAConstructor);
+            }
+
+            /* NB: the type-former of the datatype is checked before the
+             * arrow, so that the range of a constructor is found even if it
+             * is itself a function type.
+             */
+            loop {
+                match self.type_split_combination(tau) {
+                    Ok((f, _arguments)) if f == &former => break,
+                    _otherwise => match self.type_split_function(tau) {
+                        Ok((_domain, range)) => tau = range,
+                        Err(_e) => return Err(ErrorCode::NotAConstructor),
+                    },
+                }
             }
         }
 
-        let fresh = self.issue_handle();
-        self.terms.insert(fresh.clone(), trm);
-        fresh
+        for constructor in constructors.iter() {
+            self.constructor_datatypes
+                .insert(constructor.clone(), former.clone());
+        }
+
+        self.datatype_constructors.insert(former, constructors);
+
+        Ok(())
     }
 
-    /// Returns `true` iff the `handle` points to an allocated term in the
-    /// kernel's term heap.
-    pub fn term_is_registered<T>(&self, handle: T) -> bool
+    /// Returns `Ok(true)` iff the constant pointed-to by `handle` has been
+    /// marked as a constructor of a datatype.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchConstantRegistered)` if `handle` does not
+    /// point-to a registered constant.
+    pub fn constant_is_constructor<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, ErrorCode>
     where
-        T: Borrow<Handle<tags::Term>>,
+        T: Borrow<Handle<tags::Constant>>,
     {
-        let handle = handle.borrow();
-
-        info!("Checking term with handle {} is registered.", handle);
-
-        let result = self.terms.get(handle).is_some();
+        info!("Checking constant {} is a constructor.", handle.borrow());
 
-        info!("Result: {}.", result);
+        self.constant_resolve(handle.borrow())?;
 
-        result
+        Ok(self.constructor_datatypes.contains_key(handle.borrow()))
     }
 
-    /// Registers a new term variable, with name `name` and with the type
-    /// pointed-to by handle in the runtime state's type-table.
+    /// Returns `Ok((former, constructors))` iff the constant pointed-to by
+    /// `handle` has been marked as a constructor of the datatype with
+    /// type-former `former`, whose constructors, including the constant
+    /// itself, are `constructors`, in the order in which they were marked.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
-    /// point to any type in the runtime state's type-table.
-    pub fn term_register_variable<T, U>(
-        &mut self,
+    /// Returns `Err(ErrorCode::NoSuchConstantRegistered)` if `handle` does not
+    /// point-to a registered constant.
+    ///
+    /// Returns `Err(ErrorCode::NotAConstructor)` if the constant pointed-to by
+    /// `handle` has not been marked as a constructor.
+    pub fn constant_constructor_siblings<T>(
+        &self,
+        handle: T,
+    ) -> Result<
+        (&Handle<tags::TypeFormer>, &Vec<Handle<tags::Constant>>),
+        ErrorCode,
+    >
+    where
+        T: Borrow<Handle<tags::Constant>>,
+    {
+        info!(
+            "Resolving sibling constructors of constant {}.",
+            handle.borrow()
+        );
+
+        self.constant_resolve(handle.borrow())?;
+
+        let former = self
+            .constructor_datatypes
+            .get(handle.borrow())
+            .ok_or(ErrorCode::NotAConstructor)?;
+
+        let constructors = self
+            .datatype_constructors
+            .get(former)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        Ok((former, constructors))
+    }
+
+    /// Returns the constructors marked for the datatype with type-former
+    /// `former`, in the order in which they were marked, if any have been.
+    pub(crate) fn type_former_constructors<T>(
+        &self,
+        former: T,
+    ) -> Option<&Vec<Handle<tags::Constant>>>
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        self.datatype_constructors.get(former.borrow())
+    }
+
+    /// Returns the handles of every constant registered in the runtime state,
+    /// other than the kernel's preallocated constants, in ascending order.
+    pub(crate) fn constant_handles(&self) -> Vec<Handle<tags::Constant>> {
+        let mut handles: Vec<_> = self
+            .constants
+            .keys()
+            .filter(|h| !is_preallocated((*h).clone()))
+            .cloned()
+            .collect();
+
+        handles.sort();
+        handles
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Term related material.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Admits a new term into the runtime state's term-table.  If any term
+    /// exists in the runtime state's term-table that is alpha-equivalent to
+    /// `trm` then the handle for that existing term is returned.  Otherwise, a
+    /// fresh handle is generated and the term `trm` is admitted.  It is
+    /// expected that `trm` has been checked for well-formedness before this
+    /// function is called.
+    ///
+    /// A λ-abstraction is first put into ⍺-normal form, and the name of its
+    /// bound variable is remembered as its display name, unless the
+    /// abstraction already has one.
+    pub(crate) fn admit_term(&mut self, trm: Term) -> Handle<tags::Term> {
+        let (trm, display) = match trm {
+            Term::Lambda { name, tau, body } => {
+                let display =
+                    canonical_index(name).map_or(Some(name), |_| None);
+
+                (self.canonical_lambda(name, tau, body), display)
+            }
+            otherwise => (otherwise, None),
+        };
+
+        /* NB: terms are stored in ⍺-normal form, so an ⍺-equivalent term has
+         * already been registered iff an equal term has.
+         */
+        let handle = match self.terms_index.get(&trm) {
+            Some(handle) => handle.clone(),
+            None => {
+                let heights = self.heights_of(&trm);
+                let fresh = self.issue_handle();
+
+                self.terms_index.insert(trm.clone(), fresh.clone());
+                self.terms.insert(fresh.clone(), trm);
+
+                if heights != (0, 0) {
+                    self.term_heights.insert(fresh.clone(), heights);
+                }
+
+                fresh
+            }
+        };
+
+        if let Some(display) = display {
+            self.lambda_display_names
+                .entry(handle.clone())
+                .or_insert(display);
+        }
+
+        handle
+    }
+
+    /// Returns the heights, as recorded in `term_heights`, of the registered
+    /// term pointed-to by `handle`.
+    #[inline]
+    fn term_height(&self, handle: &Handle<tags::Term>) -> (u64, u64) {
+        self.term_heights.get(handle).cloned().unwrap_or((0, 0))
+    }
+
+    /// Computes the heights, as recorded in `term_heights`, of `trm`, whose
+    /// immediate subterms are registered.
+    fn heights_of(&self, trm: &Term) -> (u64, u64) {
+        match trm {
+            Term::Variable { name, .. } => {
+                (0, canonical_index(*name).map_or(0, |i| i + 1))
+            }
+            Term::Constant { .. } => (0, 0),
+            Term::Application { left, right } => {
+                let left = self.term_height(left);
+                let right = self.term_height(right);
+
+                (left.0.max(right.0), left.1.max(right.1))
+            }
+            Term::Lambda { name, body, .. } => {
+                let body = self.term_height(body);
+                let bound = canonical_index(*name).map_or(0, |i| i + 1);
+
+                (body.0.max(bound), body.1)
+            }
+        }
+    }
+
+    /// Returns the λ-abstraction binding the variable with name `name` and
+    /// type `tau` in the registered term `body`, in ⍺-normal form.
+    ///
+    /// The canonical name of the bound variable is the smallest whose index
+    /// is at least the height of `body`, so that no binder within `body` binds
+    /// the same name, and which is not the name of some other variable of the
+    /// same type occurring free in `body`, so that renaming never captures a
+    /// variable.  Free variables with canonical names arise from splitting a
+    /// λ-abstraction, and every binder within `body` is chosen in the same way,
+    /// so that the name chosen depends only on the ⍺-equivalence class of the
+    /// abstraction.
+    fn canonical_lambda(
+        &mut self,
+        name: Name,
+        tau: Handle<tags::Type>,
+        body: Handle<tags::Term>,
+    ) -> Term {
+        /* NB: a canonical name smaller than the height of `body` may have
+         * been avoided by the binders within `body`, which would then not be
+         * in ⍺-normal form once the bound variable is renamed, so the bound
+         * variable is first renamed to an ordinary name.
+         */
+        let (name, body) = match canonical_index(name) {
+            Some(index) if index < self.term_height(&body).0 => {
+                let avoid =
+                    self.term_names(&body).expect(DANGLING_HANDLE_ERROR);
+                let ordinary = fresh(avoid.into_iter());
+
+                (ordinary, self.rename_free(&body, name, &tau, ordinary))
+            }
+            _otherwise => (name, body),
+        };
+
+        let (bound, variables) = self.term_height(&body);
+
+        let mut index = bound;
+
+        /* NB: no binder within `body` binds a canonical name whose index is
+         * at least `bound`, so every variable with such a name is free.
+         */
+        if variables > bound {
+            let mut occupied = HashSet::new();
+
+            for subterm in
+                self.term_postorder(&body).expect(DANGLING_HANDLE_ERROR)
+            {
+                if let Term::Variable { name: n, tau: t } = self
+                    .resolve_term_handle(&subterm)
+                    .expect(DANGLING_HANDLE_ERROR)
+                {
+                    match canonical_index(*n) {
+                        Some(i) if i >= bound && *t == tau && *n != name => {
+                            occupied.insert(i);
+                        }
+                        _otherwise => (),
+                    }
+                }
+            }
+
+            while occupied.contains(&index) {
+                index += 1;
+            }
+        }
+
+        let canonical = canonical_name(index);
+
+        if canonical == name {
+            return Term::lambda(name, tau, body);
+        }
+
+        let body = self.rename_free(&body, name, &tau, canonical);
+
+        Term::lambda(canonical, tau, body)
+    }
+
+    /// Renames the free variable with name `name` and type `tau` in the
+    /// registered term `body` to `renamed`, which must not occur free in
+    /// `body`, returning the renamed term.
+    fn rename_free(
+        &mut self,
+        body: &Handle<tags::Term>,
+        name: Name,
+        tau: &Handle<tags::Type>,
+        renamed: Name,
+    ) -> Handle<tags::Term> {
+        let variable = self.admit_term(Term::variable(renamed, tau.clone()));
+
+        let mut bindings = Bindings::new();
+        bindings.push((name, tau.clone(), variable));
+
+        self.substitution_inner(body.clone(), bindings)
+    }
+
+    /// Admits a new term into the runtime state's term-table, as
+    /// `admit_term`, annotating it with its type, `tau`.  It is expected that
+    /// `tau` is the type of `trm`.
+    fn admit_typed_term(
+        &mut self,
+        trm: Term,
+        tau: Handle<tags::Type>,
+    ) -> Handle<tags::Term> {
+        let handle = self.admit_term(trm);
+        self.term_types.entry(handle.clone()).or_insert(tau);
+        handle
+    }
+
+    /// Enables, or disables, lazy validation.  Whilst lazy validation is
+    /// enabled, applications, lambda-abstractions, and the logical connectives
+    /// and quantifiers are registered without checking that they are
+    /// well-typed, which speeds up automation that constructs many candidate
+    /// terms that it never uses.  Terms that must be typed to be constructed
+    /// at all, such as equalities and pairs, are still checked eagerly.  Lazy
+    /// validation is disabled by default.
+    ///
+    /// Lazily-registered terms are validated when they are first used in a
+    /// theorem, or sequent, or have their type inferred, so lazy validation
+    /// never admits a theorem mentioning an ill-typed term.
+    #[inline]
+    pub fn set_lazy_validation(&mut self, enabled: bool) -> &mut Self {
+        info!("Setting lazy validation to {}.", enabled);
+
+        self.lazy_validation = enabled;
+        self
+    }
+
+    /// Returns `true` iff lazy validation is enabled.
+    #[inline]
+    pub fn is_validating_lazily(&self) -> bool {
+        self.lazy_validation
+    }
+
+    /// Sets the order in which `type_variables`, `term_type_variables` and
+    /// `term_free_variables` list the variables that they find, which is
+    /// `VariableOrder::FirstOccurrence` by default.  See `VariableOrder` for
+    /// the orders.
+    #[inline]
+    pub fn set_variable_order(&mut self, order: VariableOrder) -> &mut Self {
+        info!("Setting variable order to {:?}.", order);
+
+        self.variable_order = order;
+        self
+    }
+
+    /// Returns the order in which the variables of types and terms are
+    /// enumerated.
+    #[inline]
+    pub fn variable_order(&self) -> VariableOrder {
+        self.variable_order
+    }
+
+    /// Checks that the term pointed-to by `handle` is well-typed, returning
+    /// `Ok(())` if so.  Terms registered whilst lazy validation was disabled
+    /// are always well-typed, as are terms already validated, so this is cheap
+    /// for all but lazily-registered terms, which are validated once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` or
+    /// `Err(ErrorCode::DomainTypeMismatch)` if the term is not well-typed.
+    #[inline]
+    pub fn term_validate<T>(&mut self, handle: T) -> Result<(), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.term_type_infer(handle).map(|_tau| ())
+    }
+
+    /// Checks that every term in `arguments`, the arguments of an inference
+    /// rule, is well-typed.  Rules call this before building any term from
+    /// their arguments, as those may have been registered lazily.
+    fn validate_proof_arguments(
+        &mut self,
+        arguments: &[ProofArgument],
+    ) -> Result<(), ErrorCode> {
+        for argument in arguments {
+            if let ProofArgument::Term(term) = argument {
+                self.term_validate(term)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the term pointed-to by `handle` is a proposition, whilst
+    /// registering a term built from it.  Whilst lazy validation is enabled,
+    /// only checks that the term is registered, deferring the rest.
+    fn term_check_proposition<T>(&mut self, handle: T) -> Result<(), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        if self.lazy_validation {
+            self.resolve_term_handle(handle)?;
+            return Ok(());
+        }
+
+        if !self.term_type_is_proposition(handle)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` iff the `handle` points to an allocated term in the
+    /// kernel's term heap.
+    pub fn term_is_registered<T>(&self, handle: T) -> bool
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        let handle = handle.borrow();
+
+        info!("Checking term with handle {} is registered.", handle);
+
+        let result = self.terms.get(handle).is_some();
+
+        info!("Result: {}.", result);
+
+        result
+    }
+
+    /// Registers a new term variable, with name `name` and with the type
+    /// pointed-to by handle in the runtime state's type-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point to any type in the runtime state's type-table.
+    pub fn term_register_variable<T, U>(
+        &mut self,
         name: T,
         handle: U,
     ) -> Result<Handle<tags::Term>, ErrorCode>
@@ -787,11 +2075,12 @@ impl RuntimeState {
             handle.clone().into()
         );
 
-        if !self.type_is_registered(handle.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(handle.clone().into())?;
 
-        Ok(self.admit_term(Term::variable(name, handle)))
+        Ok(self.admit_typed_term(
+            Term::variable(name, handle.clone()),
+            handle.into(),
+        ))
     }
 
     /// Registers a new term constant, lifting the handle pointing-to a
@@ -826,7 +2115,53 @@ impl RuntimeState {
 
         let tau = self.type_substitute(cnst, type_substitution)?;
 
-        Ok(self.admit_term(Term::constant(handle, tau)))
+        Ok(self.admit_typed_term(Term::constant(handle, tau.clone()), tau))
+    }
+
+    /// Registers a new term constant, lifting the handle pointing-to a
+    /// registered constant in the runtime state's constant-table into a term
+    /// with the type pointed-to by `tau`.  Unlike `term_register_constant`,
+    /// the caller supplies the instantiated type directly, and the kernel
+    /// checks that it is a substitutive instance of the constant's registered
+    /// type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchConstantRegistered)` if `handle` does not
+    /// point-to a registered constant in the runtime state's constant-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::TypeNotWellformed)` if the type pointed-to by
+    /// `tau` is not an instance of the constant's registered type.
+    pub fn term_register_constant_at_type<T, U>(
+        &mut self,
+        handle: T,
+        tau: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Constant>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+    {
+        info!(
+            "Registering constant with handle: {} at type with handle: {}.",
+            handle.clone().into(),
+            tau.clone().into()
+        );
+
+        let cnst = self.constant_resolve(handle.clone().into())?.clone();
+        let tau = tau.into();
+
+        self.resolve_type_handle(&tau)?;
+
+        let mut sigma = Vec::new();
+
+        if !self.type_match(&cnst, &tau, &mut sigma) {
+            return Err(ErrorCode::TypeNotWellformed);
+        }
+
+        Ok(self.admit_typed_term(Term::constant(handle, tau.clone()), tau))
     }
 
     /// Registers a new application of the term pointed-to by `left` to the term
@@ -858,24 +2193,28 @@ impl RuntimeState {
             right.clone().into()
         );
 
-        if !self.is_term_registered(left.clone().into()) {
-            return Err(ErrorCode::NoSuchTermRegistered);
-        }
+        if self.lazy_validation {
+            self.resolve_term_handle(left.clone().into())?;
+            self.resolve_term_handle(right.clone().into())?;
 
-        if !self.is_term_registered(right.clone().into()) {
-            return Err(ErrorCode::NoSuchTermRegistered);
+            return Ok(self.admit_term(Term::application(left, right)));
         }
 
+        /* NB: the types of `left` and `right` were recorded when they were
+         * registered, so are looked up rather than inferred again.
+         */
         let ltau = self.term_type_infer(left.clone().into())?;
         let rtau = self.term_type_infer(right.clone().into())?;
 
-        let (dom, _rng) = self.type_split_function(&ltau)?;
+        let (dom, rng) = self.type_split_function(&ltau)?;
 
         if dom != &rtau {
             return Err(ErrorCode::DomainTypeMismatch);
         }
 
-        Ok(self.admit_term(Term::application(left, right)))
+        let rng = rng.clone();
+
+        Ok(self.admit_typed_term(Term::application(left, right), rng))
     }
 
     /// Registers a new lambda-abstraction into the runtime state's term-table
@@ -907,15 +2246,18 @@ impl RuntimeState {
             body.clone().into()
         );
 
-        if !self.type_is_registered(tau.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(tau.clone().into())?;
 
-        if !self.is_term_registered(body.clone().into()) {
-            return Err(ErrorCode::NoSuchTermRegistered);
+        if self.lazy_validation {
+            self.resolve_term_handle(body.clone().into())?;
+
+            return Ok(self.admit_term(Term::lambda(name, tau, body)));
         }
 
-        Ok(self.admit_term(Term::lambda(name, tau, body)))
+        let btau = self.term_type_infer(body.clone().into())?;
+        let ftau = self.admit_type(Type::function(tau.clone().into(), btau));
+
+        Ok(self.admit_typed_term(Term::lambda(name, tau, body), ftau))
     }
 
     /// Registers a new negation of the term pointed-to by `term` in the runtime
@@ -937,9 +2279,7 @@ impl RuntimeState {
     {
         info!("Registering negation with handle: {}.", term.clone().into());
 
-        if !self.term_type_is_proposition(term.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(term.clone().into())?;
 
         self.term_register_application(PREALLOCATED_HANDLE_TERM_NEGATION, term)
     }
@@ -1014,13 +2354,9 @@ impl RuntimeState {
             right.clone().into()
         );
 
-        if !self.term_type_is_proposition(left.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(left.clone().into())?;
 
-        if !self.term_type_is_proposition(right.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(right.clone().into())?;
 
         let inner = self.term_register_application(
             PREALLOCATED_HANDLE_TERM_DISJUNCTION,
@@ -1056,13 +2392,9 @@ impl RuntimeState {
             right.clone().into()
         );
 
-        if !self.term_type_is_proposition(left.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(left.clone().into())?;
 
-        if !self.term_type_is_proposition(right.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(right.clone().into())?;
 
         let inner = self.term_register_application(
             PREALLOCATED_HANDLE_TERM_CONJUNCTION,
@@ -1083,6 +2415,39 @@ impl RuntimeState {
     ///
     /// Returns `Err(ErrorCode::NotAProposition)` if either of the terms
     /// pointed-to by `left` or `right` are not propositions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kernel::{
+    ///     error_code::ErrorCode,
+    ///     handle::{
+    ///         PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP,
+    ///     },
+    ///     runtime_state::RuntimeState,
+    /// };
+    ///
+    /// let mut state = RuntimeState::new();
+    ///
+    /// let p = state
+    ///     .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+    ///     .unwrap();
+    /// let q = state
+    ///     .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+    ///     .unwrap();
+    /// let x = state
+    ///     .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+    ///     .unwrap();
+    ///
+    /// let implication =
+    ///     state.term_register_implication(p.clone(), q.clone()).unwrap();
+    ///
+    /// assert_eq!(state.term_split_implication(&implication), Ok((&p, &q)));
+    /// assert_eq!(
+    ///     state.term_register_implication(p, x),
+    ///     Err(ErrorCode::NotAProposition)
+    /// );
+    /// ```
     pub fn term_register_implication<T, U>(
         &mut self,
         left: T,
@@ -1098,13 +2463,9 @@ impl RuntimeState {
             right.clone().into()
         );
 
-        if !self.term_type_is_proposition(left.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(left.clone().into())?;
 
-        if !self.term_type_is_proposition(right.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(right.clone().into())?;
 
         let inner = self.term_register_application(
             PREALLOCATED_HANDLE_TERM_IMPLICATION,
@@ -1145,13 +2506,9 @@ impl RuntimeState {
             body.clone().into()
         );
 
-        if !self.type_is_registered(tau.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(tau.clone().into())?;
 
-        if !self.term_type_is_proposition(body.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(body.clone().into())?;
 
         let lambda = self
             .term_register_lambda(name, tau.clone(), body)
@@ -1198,13 +2555,9 @@ impl RuntimeState {
             body.clone().into()
         );
 
-        if !self.type_is_registered(tau.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
+        self.resolve_type_handle(tau.clone().into())?;
 
-        if !self.term_type_is_proposition(body.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.term_check_proposition(body.clone().into())?;
 
         let lambda = self
             .term_register_lambda(name, tau.clone(), body)
@@ -1220,23 +2573,396 @@ impl RuntimeState {
         self.term_register_application(univ, lambda)
     }
 
-    /// Returns `Ok(trm)` iff `handle` points-to the term `trm` in the runtime
-    /// state's term-table.
+    /// Registers a new conditional, `COND condition left right`, choosing
+    /// between the terms pointed-to by `left` and `right` depending on the
+    /// proposition pointed-to by `condition`.  Correctly instantiates the
+    /// polymorphic conditional constant as part of term construction.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
-    /// point-to any registered term in the runtime state's term-table.
-    #[inline]
-    pub fn resolve_term_handle<T>(&self, handle: T) -> Result<&Term, ErrorCode>
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if any of `condition`,
+    /// `left`, or `right` don't point-to any registered term in the runtime
+    /// state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if the term pointed-to by
+    /// `condition` is not a proposition.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the types of the terms
+    /// pointed-to by `left` and `right` are not equal.
+    pub fn term_register_conditional<T, U, V>(
+        &mut self,
+        condition: T,
+        left: U,
+        right: V,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Term>>,
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
     {
-        info!("Resolving term with handle: {}.", handle.borrow());
+        info!(
+            "Registering conditional with condition-handle: {}, left-handle: {} and right-handle: {}.",
+            condition.clone().into(),
+            left.clone().into(),
+            right.clone().into()
+        );
 
-        self.terms
-            .get(handle.borrow())
-            .ok_or(ErrorCode::NoSuchTermRegistered)
+        self.term_check_proposition(condition.clone().into())?;
+
+        let ltau = self.term_type_infer(left.clone().into())?;
+        let rtau = self.term_type_infer(right.clone().into())?;
+
+        if ltau != rtau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        let spec = self.term_type_substitute(
+            PREALLOCATED_HANDLE_TERM_CONDITIONAL,
+            vec![(0_u64, ltau)],
+        )?;
+
+        let inner = self.term_register_application(spec, condition)?;
+        let inner = self.term_register_application(inner, left)?;
+
+        self.term_register_application(inner, right)
+    }
+
+    /// Registers a new local definition, `LET function argument`, binding the
+    /// term pointed-to by `argument` in the body of the function pointed-to by
+    /// `function`.  Correctly instantiates the polymorphic local definition
+    /// constant as part of term construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `function` or
+    /// `argument` don't point-to any registered term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` if the term pointed-to by
+    /// `function` does not have a functional type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the type of the term
+    /// pointed-to by `argument` does not match the domain type of the term
+    /// pointed-to by `function`.
+    pub fn term_register_let<T, U>(
+        &mut self,
+        function: T,
+        argument: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering local definition with function-handle: {} and argument-handle: {}.",
+            function.clone().into(),
+            argument.clone().into()
+        );
+
+        let ftau = self.term_type_infer(function.clone().into())?;
+        let atau = self.term_type_infer(argument.clone().into())?;
+
+        let (dom, _rng) = self.type_split_function(&ftau)?;
+
+        if dom != &atau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        /* NB: the local definition constant is polymorphic in two
+         * type-variables, so we instantiate it by matching against its type at
+         * the point of use rather than by performing a type-substitution.
+         */
+        let tau = self
+            .type_register_function(ftau.clone(), ftau)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
+
+        let spec = self
+            .term_register_constant_at_type(
+                PREALLOCATED_HANDLE_CONSTANT_LET,
+                tau,
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let inner = self.term_register_application(spec, function)?;
+
+        self.term_register_application(inner, argument)
+    }
+
+    /// Registers a new pair of the terms pointed-to by `left` and `right`.
+    /// Correctly instantiates the polymorphic pairing constant as part of term
+    /// construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` don't point-to any registered term in the runtime state's
+    /// term-table.
+    pub fn term_register_pair<T, U>(
+        &mut self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering pair with left-handle: {} and right-handle: {}.",
+            left.clone().into(),
+            right.clone().into()
+        );
+
+        let ltau = self.term_type_infer(left.clone().into())?;
+        let rtau = self.term_type_infer(right.clone().into())?;
+
+        /* NB: as with local definitions, the pairing constant is polymorphic
+         * in two type-variables, so we instantiate it by matching against its
+         * type at the point of use.  None of this can fail, as the types of
+         * both components are registered.
+         */
+        let product = self
+            .type_register_combination(
+                PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+                vec![ltau.clone(), rtau.clone()],
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let partial = self
+            .type_register_function(rtau, product)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let tau = self
+            .type_register_function(ltau, partial)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let spec = self
+            .term_register_constant_at_type(
+                PREALLOCATED_HANDLE_CONSTANT_PAIR,
+                tau,
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let inner = self.term_register_application(spec, left)?;
+
+        self.term_register_application(inner, right)
+    }
+
+    /// Registers a new first projection, `FST pair`, of the term pointed-to by
+    /// `pair`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `pair` does not
+    /// point-to any registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProductType)` if the term pointed-to by
+    /// `pair` does not have a product type.
+    #[inline]
+    pub fn term_register_first<T>(
+        &mut self,
+        pair: T,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering first projection of handle: {}.",
+            pair.clone().into()
+        );
+
+        self.term_register_projection(PREALLOCATED_HANDLE_CONSTANT_FIRST, pair)
+    }
+
+    /// Registers a new second projection, `SND pair`, of the term pointed-to
+    /// by `pair`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `pair` does not
+    /// point-to any registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProductType)` if the term pointed-to by
+    /// `pair` does not have a product type.
+    #[inline]
+    pub fn term_register_second<T>(
+        &mut self,
+        pair: T,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering second projection of handle: {}.",
+            pair.clone().into()
+        );
+
+        self.term_register_projection(PREALLOCATED_HANDLE_CONSTANT_SECOND, pair)
+    }
+
+    /// Applies the projection constant pointed-to by `projection`, which must
+    /// be either the first or second projection constant, to the term
+    /// pointed-to by `pair`, instantiating the constant at the type of `pair`.
+    fn term_register_projection<T>(
+        &mut self,
+        projection: Handle<tags::Constant>,
+        pair: T,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        let ptau = self.term_type_infer(pair.clone().into())?;
+        let (left, right) = self.type_split_product(&ptau)?;
+
+        let range = if projection == PREALLOCATED_HANDLE_CONSTANT_FIRST {
+            left.clone()
+        } else {
+            right.clone()
+        };
+
+        let tau = self
+            .type_register_function(ptau, range)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let spec = self
+            .term_register_constant_at_type(projection, tau)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        self.term_register_application(spec, pair)
+    }
+
+    /// Registers a new set membership, `IN element set`, asserting that the
+    /// term pointed-to by `element` is a member of the set pointed-to by `set`.
+    /// Sets are represented as predicates, so `set` must have a type of the
+    /// form `A -> Prop`.  Correctly instantiates the polymorphic membership
+    /// constant as part of term construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `element` or
+    /// `set` don't point-to any registered term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `set`
+    /// does not have a set type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the type of the term
+    /// pointed-to by `element` is not the element type of the set pointed-to
+    /// by `set`.
+    pub fn term_register_membership<T, U>(
+        &mut self,
+        element: T,
+        set: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering set membership with element-handle: {} and set-handle: {}.",
+            element.clone().into(),
+            set.clone().into()
+        );
+
+        let etau = self.term_type_infer(element.clone().into())?;
+        let stau = self.term_type_infer(set.clone().into())?;
+
+        if self.type_split_set(&stau)? != &etau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        let spec = self.term_type_substitute(
+            PREALLOCATED_HANDLE_TERM_MEMBERSHIP,
+            vec![(0_u64, etau)],
+        )?;
+
+        let inner = self.term_register_application(spec, element)?;
+
+        self.term_register_application(inner, set)
+    }
+
+    /// Registers a new set comprehension, `SETSPEC (λname:tau. body)`, of the
+    /// elements of type `tau` satisfying the proposition pointed-to by `body`.
+    /// Correctly instantiates the polymorphic comprehension constant as part
+    /// of term construction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `body` does not
+    /// point-to any registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if the term pointed-to by
+    /// `body` is not a proposition.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to any registered type in the runtime state's type-table.
+    pub fn term_register_comprehension<T, U, V>(
+        &mut self,
+        name: T,
+        tau: U,
+        body: V,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering set comprehension with name: {}, type handle: {} and body handle: {}.",
+            name.clone().into(),
+            tau.clone().into(),
+            body.clone().into()
+        );
+
+        self.resolve_type_handle(tau.clone().into())?;
+
+        self.term_check_proposition(body.clone().into())?;
+
+        let lambda = self
+            .term_register_lambda(name, tau.clone(), body)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
+
+        let spec = self
+            .term_type_substitute(
+                PREALLOCATED_HANDLE_TERM_COMPREHENSION,
+                vec![(0_u64, tau)],
+            )
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
+
+        self.term_register_application(spec, lambda)
+    }
+
+    /// Returns `Ok(trm)` iff `handle` points-to the term `trm` in the runtime
+    /// state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::StaleHandle)` if `handle` was issued within a
+    /// speculative branch that has since been discarded.
+    #[inline]
+    pub fn resolve_term_handle<T>(&self, handle: T) -> Result<&Term, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Resolving term with handle: {}.", handle.borrow());
+
+        self.terms.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(handle.borrow(), ErrorCode::NoSuchTermRegistered)
+        })
+    }
+
+    /// Returns the runtime state's type-table, which, unlike the runtime state
+    /// itself, may be shared between threads.
+    #[inline]
+    pub(crate) fn type_table(&self) -> &HashMap<Handle<tags::Type>, Type> {
+        &self.types
+    }
+
+    /// Returns the runtime state's term-table, which, unlike the runtime state
+    /// itself, may be shared between threads.
+    #[inline]
+    pub(crate) fn term_table(&self) -> &HashMap<Handle<tags::Term>, Term> {
+        &self.terms
     }
 
     /// Returns `true` iff `handle` points-to a registered term in the runtime
@@ -1383,62 +3109,139 @@ impl RuntimeState {
         }
     }
 
-    /// Returns `Some(body)` if `handle` points-to a negated term, `body`,
-    /// in the runtime state's term-table.
+    /// Returns `Ok(name)` if `handle` points-to a lambda-abstraction in the
+    /// runtime state's term-table, where `name` is the name chosen for its
+    /// bound variable when it was first registered.  Lambda-abstractions are
+    /// stored with a canonical name for their bound variable, which is what
+    /// `term_split_lambda` returns, and so this is only useful for displaying
+    /// terms.  Lambda-abstractions only ever registered with a canonical name
+    /// have that name as their display name.
     ///
     /// # Errors
     ///
     /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
     /// point-to any term in the runtime state's term-table.
     ///
-    /// Returns `Err(ErrorCode::NotANegation)` if the term pointed-to by
-    /// `handle` is not a negated term.
-    pub fn term_split_negation<T>(
+    /// Returns `Err(ErrorCode::NotALambda)` if the term pointed-to by `handle`
+    /// is not a lambda-abstraction.
+    pub fn term_lambda_display_name<T>(
         &self,
         handle: T,
-    ) -> Result<&Handle<tags::Term>, ErrorCode>
+    ) -> Result<Name, ErrorCode>
     where
         T: Borrow<Handle<tags::Term>>,
     {
-        info!("Splitting negation with handle: {}.", handle.borrow());
-
-        let (left, right) = self
-            .resolve_term_handle(handle)?
-            .split_application()
-            .ok_or(ErrorCode::NotANegation)?;
-
-        let (constant, _tau) = self
-            .term_split_constant(left)
-            .map_err(|_e| ErrorCode::NotANegation)?;
+        info!(
+            "Finding display name of lambda-abstraction with handle: {}.",
+            handle.borrow()
+        );
 
-        if constant != &PREALLOCATED_HANDLE_CONSTANT_NEGATION {
-            return Err(ErrorCode::NotANegation);
-        }
+        let (name, _tau, _body) = self.term_split_lambda(handle.borrow())?;
 
-        Ok(right)
+        Ok(self
+            .lambda_display_names
+            .get(handle.borrow())
+            .cloned()
+            .unwrap_or(*name))
     }
 
-    /// Returns `Some((left, right))` if `handle` points-to an equality
-    /// between terms `left` and `right` in the runtime state's term-table.
+    /// Returns `Ok((name, tau, body))` if `handle` points-to a
+    /// lambda-abstraction in the runtime state's term-table, as
+    /// `term_split_lambda`, except that `name` is the display name of the
+    /// bound variable, and `body` is the body with the bound variable renamed
+    /// to match.  This is a compatibility call for guests which relied on
+    /// finding the names that they chose for bound variables when splitting
+    /// terms, before terms were stored in ⍺-normal form.  The renamed body is
+    /// registered if necessary.
     ///
     /// # Errors
     ///
     /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
     /// point-to any term in the runtime state's term-table.
     ///
-    /// Returns `Err(ErrorCode::NotAnEquality)` if the term pointed-to by
-    /// `handle` is not an equality between terms.
-    pub fn term_split_equality<T>(
-        &self,
+    /// Returns `Err(ErrorCode::NotALambda)` if the term pointed-to by `handle`
+    /// is not a lambda-abstraction.
+    pub fn term_split_lambda_display<T>(
+        &mut self,
         handle: T,
-    ) -> Result<(&Handle<tags::Term>, &Handle<tags::Term>), ErrorCode>
+    ) -> Result<(Name, Handle<tags::Type>, Handle<tags::Term>), ErrorCode>
     where
         T: Borrow<Handle<tags::Term>>,
     {
-        info!("Splitting equality with handle: {}.", handle.borrow());
+        let display = self.term_lambda_display_name(handle.borrow())?;
+        let (name, tau, body) = self
+            .term_split_lambda(handle.borrow())
+            .map(|(n, t, b)| (*n, t.clone(), b.clone()))?;
 
-        let (left, right) = self
-            .resolve_term_handle(handle)?
+        if display == name {
+            return Ok((name, tau, body));
+        }
+
+        /* NB: the display name was bound by the λ-abstraction when it was
+         * registered, so it does not occur free in the body, and renaming the
+         * bound variable back cannot capture anything.
+         */
+        let body = self.rename_free(&body, name, &tau, display);
+
+        Ok((display, tau, body))
+    }
+
+    /// Returns `Some(body)` if `handle` points-to a negated term, `body`,
+    /// in the runtime state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotANegation)` if the term pointed-to by
+    /// `handle` is not a negated term.
+    pub fn term_split_negation<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Handle<tags::Term>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Splitting negation with handle: {}.", handle.borrow());
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotANegation)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotANegation)?;
+
+        if constant != &PREALLOCATED_HANDLE_CONSTANT_NEGATION {
+            return Err(ErrorCode::NotANegation);
+        }
+
+        Ok(right)
+    }
+
+    /// Returns `Some((left, right))` if `handle` points-to an equality
+    /// between terms `left` and `right` in the runtime state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the term pointed-to by
+    /// `handle` is not an equality between terms.
+    pub fn term_split_equality<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Handle<tags::Term>, &Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Splitting equality with handle: {}.", handle.borrow());
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
             .split_application()
             .ok_or(ErrorCode::NotAnEquality)?;
 
@@ -1670,6 +3473,232 @@ impl RuntimeState {
         }
     }
 
+    /// Returns `Some((condition, left, right))` if `handle` points-to a
+    /// conditional, `COND condition left right`, in the runtime state's
+    /// term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAConditional)` if the term pointed-to by
+    /// `handle` is not a conditional.
+    #[allow(clippy::type_complexity)]
+    pub fn term_split_conditional<T>(
+        &self,
+        handle: T,
+    ) -> Result<
+        (
+            &Handle<tags::Term>,
+            &Handle<tags::Term>,
+            &Handle<tags::Term>,
+        ),
+        ErrorCode,
+    >
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Splitting conditional with handle: {}.", handle.borrow());
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotAConditional)?;
+
+        let (left, mid) = self
+            .resolve_term_handle(left)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+            .split_application()
+            .ok_or(ErrorCode::NotAConditional)?;
+
+        let (left, condition) = self
+            .resolve_term_handle(left)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+            .split_application()
+            .ok_or(ErrorCode::NotAConditional)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotAConditional)?;
+
+        if constant == &PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL {
+            Ok((condition, mid, right))
+        } else {
+            Err(ErrorCode::NotAConditional)
+        }
+    }
+
+    /// Returns `Some((function, argument))` if `handle` points-to a local
+    /// definition, `LET function argument`, in the runtime state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotALet)` if the term pointed-to by `handle` is
+    /// not a local definition.
+    pub fn term_split_let<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Handle<tags::Term>, &Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Splitting local definition with handle: {}.",
+            handle.borrow()
+        );
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotALet)?;
+
+        let (left, mid) = self
+            .resolve_term_handle(left)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+            .split_application()
+            .ok_or(ErrorCode::NotALet)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotALet)?;
+
+        if constant == &PREALLOCATED_HANDLE_CONSTANT_LET {
+            Ok((mid, right))
+        } else {
+            Err(ErrorCode::NotALet)
+        }
+    }
+
+    /// Returns `Some((left, right))` if `handle` points-to a pair of terms
+    /// `left` and `right` in the runtime state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAPair)` if the term pointed-to by `handle` is
+    /// not a pair.
+    pub fn term_split_pair<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Handle<tags::Term>, &Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Splitting pair with handle: {}.", handle.borrow());
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotAPair)?;
+
+        let (left, mid) = self
+            .resolve_term_handle(left)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+            .split_application()
+            .ok_or(ErrorCode::NotAPair)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotAPair)?;
+
+        if constant == &PREALLOCATED_HANDLE_CONSTANT_PAIR {
+            Ok((mid, right))
+        } else {
+            Err(ErrorCode::NotAPair)
+        }
+    }
+
+    /// Returns `Some((element, set))` if `handle` points-to a set membership,
+    /// `IN element set`, in the runtime state's term-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAMembership)` if the term pointed-to by
+    /// `handle` is not a set membership.
+    pub fn term_split_membership<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Handle<tags::Term>, &Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Splitting set membership with handle: {}.", handle.borrow());
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotAMembership)?;
+
+        let (left, mid) = self
+            .resolve_term_handle(left)
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+            .split_application()
+            .ok_or(ErrorCode::NotAMembership)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotAMembership)?;
+
+        if constant == &PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP {
+            Ok((mid, right))
+        } else {
+            Err(ErrorCode::NotAMembership)
+        }
+    }
+
+    /// Returns `Some((name, tau, body))` if `handle` points-to a set
+    /// comprehension in the runtime state's term-table, consisting of a name,
+    /// `name`, a handle pointing-to the element type, `tau`, and a handle
+    /// pointing-to a body term, `body`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAComprehension)` if the term pointed-to by
+    /// `handle` is not a set comprehension.
+    pub fn term_split_comprehension<T>(
+        &self,
+        handle: T,
+    ) -> Result<(&Name, &Handle<tags::Type>, &Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Splitting set comprehension with handle: {}.",
+            handle.borrow()
+        );
+
+        let (left, right) = self
+            .resolve_term_handle(handle)?
+            .split_application()
+            .ok_or(ErrorCode::NotAComprehension)?;
+
+        let (constant, _tau) = self
+            .term_split_constant(left)
+            .map_err(|_e| ErrorCode::NotAComprehension)?;
+
+        let (name, _type, body) = self
+            .term_split_lambda(right)
+            .map_err(|_e| ErrorCode::NotAComprehension)?;
+
+        if constant == &PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION {
+            Ok((name, _type, body))
+        } else {
+            Err(ErrorCode::NotAComprehension)
+        }
+    }
+
     /// Returns `Ok(true)` if `handle` points-to a term variable.
     ///
     /// # Error
@@ -1900,58 +3929,140 @@ impl RuntimeState {
         Ok(self.term_split_exists(handle).is_ok())
     }
 
-    /// Computes the *free type-variables* of the term pointed-to by the handle
-    /// `handle` in the runtime state's term-table.
+    /// Returns `Ok(true)` if `handle` points-to a conditional.
     ///
-    /// # Errors
+    /// # Error
     ///
     /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
     /// point-to any term in the runtime state's term-table.
-    pub fn term_type_variables<T>(
-        &self,
-        handle: T,
-    ) -> Result<Vec<&Name>, ErrorCode>
+    #[inline]
+    pub fn term_test_conditional<T>(&self, handle: T) -> Result<bool, ErrorCode>
     where
         T: Borrow<Handle<tags::Term>>,
     {
-        info!(
-            "Computing type-variables of term with handle: {}.",
-            handle.borrow()
-        );
+        info!("Testing conditional with handle: {}.", handle.borrow());
 
-        let trm = self.resolve_term_handle(handle)?;
+        Ok(self.term_split_conditional(handle).is_ok())
+    }
 
-        let mut work_list = vec![trm];
-        let mut ftv = vec![];
+    /// Returns `Ok(true)` if `handle` points-to a local definition.
+    ///
+    /// # Error
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    #[inline]
+    pub fn term_test_let<T>(&self, handle: T) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Testing local definition with handle: {}.", handle.borrow());
 
-        while let Some(next) = work_list.pop() {
-            match next {
-                Term::Variable { tau: _type, .. } => {
-                    let mut fvs =
-                        self.type_variables(_type).unwrap_or_else(|_e| {
-                            panic!("{}", DANGLING_HANDLE_ERROR)
-                        });
-                    ftv.append(&mut fvs);
-                }
-                Term::Constant { tau: _type, .. } => {
-                    let mut fvs =
-                        self.type_variables(_type).unwrap_or_else(|_e| {
-                            panic!("{}", DANGLING_HANDLE_ERROR)
-                        });
-                    ftv.append(&mut fvs);
-                }
-                Term::Application { left, right } => {
-                    let left =
-                        self.resolve_term_handle(left).unwrap_or_else(|_e| {
-                            panic!("{}", DANGLING_HANDLE_ERROR)
+        Ok(self.term_split_let(handle).is_ok())
+    }
+
+    /// Returns `Ok(true)` if `handle` points-to a pair.
+    ///
+    /// # Error
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    #[inline]
+    pub fn term_test_pair<T>(&self, handle: T) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Testing pair with handle: {}.", handle.borrow());
+
+        Ok(self.term_split_pair(handle).is_ok())
+    }
+
+    /// Returns `Ok(true)` if `handle` points-to a set membership.
+    ///
+    /// # Error
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    #[inline]
+    pub fn term_test_membership<T>(&self, handle: T) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Testing set membership with handle: {}.", handle.borrow());
+
+        Ok(self.term_split_membership(handle).is_ok())
+    }
+
+    /// Returns `Ok(true)` if `handle` points-to a set comprehension.
+    ///
+    /// # Error
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    #[inline]
+    pub fn term_test_comprehension<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Testing set comprehension with handle: {}.",
+            handle.borrow()
+        );
+
+        Ok(self.term_split_comprehension(handle).is_ok())
+    }
+
+    /// Computes the *free type-variables* of the term pointed-to by the handle
+    /// `handle` in the runtime state's term-table, each exactly once, in the
+    /// runtime state's variable order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    pub fn term_type_variables<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<&Name>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Computing type-variables of term with handle: {}.",
+            handle.borrow()
+        );
+
+        let trm = self.resolve_term_handle(handle)?;
+
+        let mut work_list = vec![trm];
+        let mut ftv = vec![];
+
+        /* NB: the argument of an application is pushed before its function,
+         * so that the function is popped, and its type-variables found, first.
+         */
+        while let Some(next) = work_list.pop() {
+            match next {
+                Term::Variable { tau: _type, .. }
+                | Term::Constant { tau: _type, .. } => {
+                    ftv.extend(self.type_variables(_type).unwrap_or_else(
+                        |_e| panic!("{}", DANGLING_HANDLE_ERROR),
+                    ));
+                }
+                Term::Application { left, right } => {
+                    let left =
+                        self.resolve_term_handle(left).unwrap_or_else(|_e| {
+                            panic!("{}", DANGLING_HANDLE_ERROR)
                         });
                     let right =
                         self.resolve_term_handle(right).unwrap_or_else(|_e| {
                             panic!("{}", DANGLING_HANDLE_ERROR)
                         });
 
-                    work_list.push(left);
                     work_list.push(right);
+                    work_list.push(left);
                 }
                 Term::Lambda {
                     tau: _type, body, ..
@@ -1960,22 +4071,110 @@ impl RuntimeState {
                         self.resolve_term_handle(body).unwrap_or_else(|_e| {
                             panic!("{}", DANGLING_HANDLE_ERROR)
                         });
-                    let mut fvs = self.type_variables(_type)?;
 
-                    ftv.append(&mut fvs);
+                    ftv.extend(self.type_variables(_type)?);
                     work_list.push(body);
                 }
             }
         }
 
-        ftv.sort();
-        ftv.dedup();
+        Ok(self.order_variables(ftv))
+    }
+
+    /// Returns the distinct subterms of the term pointed-to by `handle` in the
+    /// runtime state's term-table, including the term itself, each exactly
+    /// once.  Every subterm is preceded by the subterms that it is built from,
+    /// so that a traversal of the result in order visits the children of every
+    /// node before the node itself, and visits shared subterms only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    pub fn term_postorder<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        let handle = handle.borrow();
+
+        info!("Computing postorder of term with handle: {}.", handle);
+
+        self.resolve_term_handle(handle)?;
+
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+
+        /* NB: each handle on the work-list is paired with whether its subterms
+         * have already been pushed, in which case it is ready to be emitted.
+         */
+        let mut work = vec![(handle.clone(), false)];
+
+        while let Some((handle, expanded)) = work.pop() {
+            if expanded {
+                order.push(handle);
+                continue;
+            }
+
+            if !visited.insert(handle.clone()) {
+                continue;
+            }
+
+            work.push((handle.clone(), true));
+
+            match self
+                .resolve_term_handle(&handle)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                Term::Application { left, right } => {
+                    work.push((right.clone(), false));
+                    work.push((left.clone(), false));
+                }
+                Term::Lambda { body, .. } => work.push((body.clone(), false)),
+                _otherwise => (),
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Returns every name, free or bound, appearing in the term pointed-to by
+    /// `handle`, for choosing names that clash with none of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    pub(crate) fn term_names<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<Name>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        let mut names = Vec::new();
+
+        for subterm in self.term_postorder(handle)? {
+            match self
+                .resolve_term_handle(subterm)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                Term::Variable { name, .. } | Term::Lambda { name, .. } => {
+                    names.push(*name)
+                }
+                _otherwise => (),
+            }
+        }
 
-        Ok(ftv)
+        Ok(names)
     }
 
     /// Computes the *free variables* of the term pointed-to by the handle
-    /// `handle` in the runtime state's term-table.
+    /// `handle` in the runtime state's term-table, each exactly once, in the
+    /// runtime state's variable order.  Variables are identified by both their
+    /// name and their type, so two free variables may share a name.
     ///
     /// # Errors
     ///
@@ -1993,6 +4192,24 @@ impl RuntimeState {
             handle.borrow()
         );
 
+        let mut fvs = self.term_free_variables_occurring(handle)?;
+
+        if self.variable_order == VariableOrder::ByName {
+            fvs.sort_by_key(|(name, _type)| **name);
+        }
+
+        Ok(fvs)
+    }
+
+    /// Computes the free variables of the term pointed-to by `handle`, as
+    /// `term_free_variables`, in order of first occurrence.
+    fn term_free_variables_occurring<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<(&Name, &Handle<tags::Type>)>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
         let term = self.resolve_term_handle(handle)?;
 
         match term {
@@ -2000,13 +4217,17 @@ impl RuntimeState {
             Term::Constant { .. } => Ok(vec![]),
             Term::Application { left, right } => {
                 let mut left = self
-                    .term_free_variables(left)
+                    .term_free_variables_occurring(left)
                     .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
-                let mut right = self
-                    .term_free_variables(right)
+                let right = self
+                    .term_free_variables_occurring(right)
                     .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
 
-                left.append(&mut right);
+                for v in right {
+                    if !left.contains(&v) {
+                        left.push(v);
+                    }
+                }
 
                 Ok(left)
             }
@@ -2016,7 +4237,7 @@ impl RuntimeState {
                 body,
             } => {
                 let body = self
-                    .term_free_variables(body)
+                    .term_free_variables_occurring(body)
                     .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
 
                 Ok(body
@@ -2028,10 +4249,33 @@ impl RuntimeState {
         }
     }
 
+    /// Performs a simultaneous, capture-avoiding substitution of terms for
+    /// free variables in the term pointed-to by `handle`.  Each entry of
+    /// `sigma` pairs a variable, given by its name and type, with the term that
+    /// replaces its free occurrences.  Bound variables are renamed, where
+    /// necessary, to avoid capturing free variables of the replacing terms.
+    ///
+    /// Substitution works directly on handles: the term is traversed using an
+    /// explicit work-list, results are memoised per shared subterm, and
+    /// subterms unaffected by the substitution are returned as-is, rather than
+    /// being rebuilt and re-registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle`, or any term
+    /// in the range of `sigma`, does not point-to any term in the runtime
+    /// state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if any type in the domain
+    /// of `sigma` does not point-to any type in the runtime state's
+    /// type-table.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if any term in the range of
+    /// `sigma` does not have the type of the variable that it replaces.
     pub fn substitution<T, N, U, V>(
         &mut self,
-        _handle: T,
-        _sigma: Vec<((N, U), V)>,
+        handle: T,
+        sigma: Vec<((N, U), V)>,
     ) -> Result<Handle<tags::Term>, ErrorCode>
     where
         T: Into<Handle<tags::Term>>,
@@ -2039,7 +4283,267 @@ impl RuntimeState {
         U: Into<Handle<tags::Type>> + Clone,
         V: Into<Handle<tags::Term>> + Clone,
     {
-        unimplemented!()
+        let handle = handle.into();
+
+        info!("Substituting terms in term with handle {}.", handle);
+
+        self.resolve_term_handle(&handle)?;
+
+        let bindings = self.substitution_bindings(sigma)?;
+
+        Ok(self.substitution_inner(handle, bindings))
+    }
+
+    /// Checks the substitution `sigma`, as described in the documentation of
+    /// `substitution`, returning its bindings with shadowed and trivial
+    /// bindings discarded.
+    ///
+    /// # Errors
+    ///
+    /// As for `substitution`, other than for the term being substituted into.
+    pub(crate) fn substitution_bindings<N, U, V>(
+        &mut self,
+        sigma: Vec<((N, U), V)>,
+    ) -> Result<Bindings, ErrorCode>
+    where
+        N: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        let mut bindings = Bindings::new();
+
+        for ((name, tau), range) in sigma {
+            let name = name.into();
+            let tau = tau.into();
+            let range = range.into();
+
+            self.resolve_type_handle(&tau)?;
+
+            if self.term_type_infer(&range)? != tau {
+                return Err(ErrorCode::DomainTypeMismatch);
+            }
+
+            /* NB: later bindings of the same variable are shadowed by earlier
+             * ones, and trivial bindings are discarded.
+             */
+            let bound =
+                bindings.iter().any(|(n, t, _)| *n == name && *t == tau);
+
+            if !bound
+                && self.resolve_term_handle(&range)?.split_variable()
+                    != Some((&name, &tau))
+            {
+                bindings.push((name, tau, range));
+            }
+        }
+
+        Ok(bindings)
+    }
+
+    /// Performs the substitution described by `bindings` in the term
+    /// pointed-to by `handle`, as described in the documentation of
+    /// `substitution`.  Assumes that all handles are registered and that the
+    /// bindings are well-typed.
+    ///
+    /// Will **panic** if any handle dangles.
+    pub(crate) fn substitution_inner(
+        &mut self,
+        handle: Handle<tags::Term>,
+        bindings: Bindings,
+    ) -> Handle<tags::Term> {
+        /* NB: distinct bindings, arising from going under binders, are
+         * interned so that the results of substituting into shared subterms
+         * can be memoised.
+         */
+        let mut contexts: Vec<SubstitutionContext> = Vec::new();
+        let mut context_index: HashMap<Bindings, usize> = HashMap::new();
+        let mut memo: HashMap<(Handle<tags::Term>, usize), Handle<tags::Term>> =
+            HashMap::new();
+
+        let root = self.substitution_context(
+            &mut contexts,
+            &mut context_index,
+            bindings,
+        );
+
+        let mut work = vec![SubstitutionFrame::Visit(handle, root)];
+        let mut results: Vec<Handle<tags::Term>> = Vec::new();
+
+        while let Some(frame) = work.pop() {
+            match frame {
+                SubstitutionFrame::Visit(handle, context) => {
+                    if contexts[context].0.is_empty() {
+                        results.push(handle);
+                        continue;
+                    }
+
+                    if let Some(result) = memo.get(&(handle.clone(), context)) {
+                        results.push(result.clone());
+                        continue;
+                    }
+
+                    match self
+                        .resolve_term_handle(&handle)
+                        .expect(DANGLING_HANDLE_ERROR)
+                        .clone()
+                    {
+                        Term::Variable { name, tau } => {
+                            let result = contexts[context]
+                                .0
+                                .iter()
+                                .find(|(n, t, _)| *n == name && *t == tau)
+                                .map(|(_, _, range)| range.clone())
+                                .unwrap_or(handle);
+
+                            results.push(result);
+                        }
+                        Term::Constant { .. } => results.push(handle),
+                        Term::Application { left, right } => {
+                            work.push(SubstitutionFrame::Memoise(
+                                handle.clone(),
+                                context,
+                            ));
+                            work.push(SubstitutionFrame::Application(handle));
+                            work.push(SubstitutionFrame::Visit(right, context));
+                            work.push(SubstitutionFrame::Visit(left, context));
+                        }
+                        Term::Lambda { name, tau, body } => {
+                            let (outer, captured) = &contexts[context];
+
+                            let mut inner: Bindings = outer
+                                .iter()
+                                .filter(|(n, t, _)| !(*n == name && *t == tau))
+                                .cloned()
+                                .collect();
+
+                            /* NB: if the bound variable appears free in the
+                             * range of the substitution then rename it to a
+                             * fresh name, by extending the substitution
+                             * under the binder.
+                             */
+                            let binder = if !inner.is_empty()
+                                && captured.contains(&(name, tau.clone()))
+                            {
+                                let avoid: Vec<Name> = self
+                                    .term_free_variables(&body)
+                                    .expect(DANGLING_HANDLE_ERROR)
+                                    .iter()
+                                    .map(|(n, _)| **n)
+                                    .chain(captured.iter().map(|(n, _)| *n))
+                                    .collect();
+                                let binder = fresh(avoid.into_iter());
+                                let variable = self.admit_term(Term::variable(
+                                    binder,
+                                    tau.clone(),
+                                ));
+
+                                inner.push((name, tau, variable));
+
+                                binder
+                            } else {
+                                name
+                            };
+
+                            let inner = self.substitution_context(
+                                &mut contexts,
+                                &mut context_index,
+                                inner,
+                            );
+
+                            work.push(SubstitutionFrame::Memoise(
+                                handle.clone(),
+                                context,
+                            ));
+                            work.push(SubstitutionFrame::Lambda(
+                                handle, binder,
+                            ));
+                            work.push(SubstitutionFrame::Visit(body, inner));
+                        }
+                    }
+                }
+                SubstitutionFrame::Application(handle) => {
+                    let right =
+                        results.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+                    let left =
+                        results.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+                    let unchanged = self
+                        .resolve_term_handle(&handle)
+                        .expect(DANGLING_HANDLE_ERROR)
+                        .split_application()
+                        == Some((&left, &right));
+
+                    if unchanged {
+                        results.push(handle);
+                    } else {
+                        results.push(
+                            self.admit_term(Term::application(left, right)),
+                        );
+                    }
+                }
+                SubstitutionFrame::Lambda(handle, binder) => {
+                    let body =
+                        results.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+                    let (name, tau, original) = self
+                        .resolve_term_handle(&handle)
+                        .expect(DANGLING_HANDLE_ERROR)
+                        .split_lambda()
+                        .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+                    if *name == binder && *original == body {
+                        results.push(handle);
+                    } else {
+                        // Appease the borrow-checker gods...
+                        let tau = tau.clone();
+
+                        results.push(
+                            self.admit_term(Term::lambda(binder, tau, body)),
+                        );
+                    }
+                }
+                SubstitutionFrame::Memoise(handle, context) => {
+                    let result =
+                        results.last().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+                    memo.insert((handle, context), result.clone());
+                }
+            }
+        }
+
+        results.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR)
+    }
+
+    /// Interns `bindings` in the table of substitution contexts used by
+    /// `substitution_inner`, returning its index.
+    fn substitution_context(
+        &self,
+        contexts: &mut Vec<SubstitutionContext>,
+        index: &mut HashMap<Bindings, usize>,
+        bindings: Bindings,
+    ) -> usize {
+        if let Some(context) = index.get(&bindings) {
+            return *context;
+        }
+
+        let mut captured = Vec::new();
+
+        for (_name, _type, range) in bindings.iter() {
+            for (n, t) in self
+                .term_free_variables(range)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                if !captured.contains(&(*n, t.clone())) {
+                    captured.push((*n, t.clone()));
+                }
+            }
+        }
+
+        let context = contexts.len();
+
+        index.insert(bindings.clone(), context);
+        contexts.push((bindings, captured));
+
+        context
     }
 
     pub fn term_type_substitute<T, U, V>(
@@ -2120,13 +4624,17 @@ impl RuntimeState {
     {
         info!("Inferring type of term with handle: {}.", handle.borrow());
 
-        let trm = self.resolve_term_handle(handle)?;
+        if let Some(tau) = self.term_types.get(handle.borrow()) {
+            return Ok(tau.clone());
+        }
+
+        let trm = self.resolve_term_handle(handle.borrow())?;
 
         let trm = trm.clone();
 
-        match trm {
-            Term::Variable { tau: _type, .. } => Ok(_type),
-            Term::Constant { tau: _type, .. } => Ok(_type),
+        let tau = match trm {
+            Term::Variable { tau: _type, .. } => _type,
+            Term::Constant { tau: _type, .. } => _type,
             Term::Application { left, right } => {
                 let ltau = self.term_type_infer(&left)?;
                 let rtau = self.term_type_infer(&right)?;
@@ -2136,18 +4644,22 @@ impl RuntimeState {
                     .map_err(|_e| ErrorCode::NotAFunctionType)?;
 
                 if dom == &rtau {
-                    Ok(rng.clone())
+                    rng.clone()
                 } else {
-                    Err(ErrorCode::DomainTypeMismatch)
+                    return Err(ErrorCode::DomainTypeMismatch);
                 }
             }
             Term::Lambda {
                 tau: _type, body, ..
             } => {
                 let btau = self.term_type_infer(&body)?;
-                Ok(self.admit_type(Type::function(_type, btau)))
+                self.admit_type(Type::function(_type, btau))
             }
-        }
+        };
+
+        self.term_types.insert(handle.borrow().clone(), tau.clone());
+
+        Ok(tau)
     }
 
     /// Returns `Ok(true)` iff the type of the term pointed-to by `handle` in
@@ -2176,189 +4688,40 @@ impl RuntimeState {
         Ok(self.term_type_infer(handle)? == PREALLOCATED_HANDLE_TYPE_PROP)
     }
 
-    /// Permutes the variable `a` and `b` throughout the term pointed-to by
-    /// `handle` in the kernel's term-table.  Leaves all other names fixed.
+    /// Returns `Ok(true)` iff the terms pointed-to by `left` and `right` in the
+    /// kernel's term-table are ⍺-equivalent, that is, equal up-to a permutative
+    /// renaming of their bound variables.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
-    /// point-to a term in the runtime state's term-table.
-    fn swap<T, U, V, Q, R>(
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a term in the runtime state's term-table.
+    #[inline]
+    pub fn is_alpha_equivalent<T>(
         &mut self,
-        handle: T,
-        a: U,
-        atau: V,
-        b: Q,
-        btau: R,
-    ) -> Result<Handle<tags::Term>, ErrorCode>
+        left: T,
+        right: T,
+    ) -> Result<bool, ErrorCode>
     where
-        T: Borrow<Handle<tags::Term>> + Clone,
-        U: Into<Name> + Clone,
-        V: Borrow<Handle<tags::Type>>,
-        Q: Into<Name> + Clone,
-        R: Borrow<Handle<tags::Type>>,
+        T: Borrow<Handle<tags::Term>>,
     {
         info!(
-            "Swapping name: {} for name: {} in term with handle: {}.",
-            a.clone().into(),
-            b.clone().into(),
-            handle.borrow()
+            "Testing terms with handles: {} and: {} for alpha-equivalence.",
+            left.borrow(),
+            right.borrow()
         );
 
-        let trm = self.resolve_term_handle(handle.clone())?.clone();
-        let atau = atau.borrow();
-        let btau = btau.borrow();
+        let left = left.borrow();
+        let right = right.borrow();
 
-        match trm {
-            Term::Variable { name, tau: _type } => {
-                if name == a.clone().into() {
-                    Ok(self.admit_term(Term::variable(b, _type)))
-                } else if name == b.into() {
-                    Ok(self.admit_term(Term::variable(a, _type)))
-                } else {
-                    Ok(handle.borrow().clone())
-                }
-            }
-            Term::Constant { .. } => Ok(handle.borrow().clone()),
-            Term::Application { left, right } => {
-                let left = self
-                    .swap(&left, a.clone(), atau, b.clone(), btau)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
-                let right = self
-                    .swap(&right, a, atau, b, btau)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
+        self.resolve_term_handle(left)?;
+        self.resolve_term_handle(right)?;
 
-                Ok(self.admit_term(Term::application(left, right)))
-            }
-            Term::Lambda {
-                name,
-                tau: _type,
-                body,
-            } => {
-                let body = self
-                    .swap(&body, a.clone(), atau, b.clone(), btau)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
-                if name == a.clone().into() && &_type == atau {
-                    Ok(self.admit_term(Term::lambda(b, _type, body)))
-                } else if name == b.into() && &_type == btau {
-                    Ok(self.admit_term(Term::lambda(a, _type, body)))
-                } else {
-                    Ok(self.admit_term(Term::lambda(name, _type, body)))
-                }
-            }
-        }
-    }
-
-    fn alpha_equivalent_inner(
-        &mut self,
-        left: &Term,
-        right: &Term,
-    ) -> Result<bool, ErrorCode> {
-        match (left, right) {
-            (
-                Term::Variable {
-                    name: name0,
-                    tau: _type0,
-                },
-                Term::Variable {
-                    name: name1,
-                    tau: _type1,
-                },
-            ) => Ok(name0 == name1 && _type0 == _type1),
-            (
-                Term::Constant {
-                    constant: handle0,
-                    tau: _type0,
-                },
-                Term::Constant {
-                    constant: handle1,
-                    tau: _type1,
-                },
-            ) => Ok(handle0 == handle1 && _type0 == _type1),
-            (
-                Term::Application {
-                    left: left0,
-                    right: right0,
-                },
-                Term::Application {
-                    left: left1,
-                    right: right1,
-                },
-            ) => {
-                let left = self
-                    .is_alpha_equivalent(left0, left1)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
-                let right = self
-                    .is_alpha_equivalent(right0, right1)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR));
-                Ok(left && right)
-            }
-            (
-                Term::Lambda {
-                    name: name0,
-                    tau: _type0,
-                    body: body0,
-                },
-                Term::Lambda {
-                    name: name1,
-                    tau: _type1,
-                    body: body1,
-                },
-            ) => {
-                if name0 == name1 && _type0 == _type1 {
-                    let body = self.is_alpha_equivalent(body0, body1)?;
-                    Ok(body)
-                } else if self
-                    .term_free_variables(body1)
-                    .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
-                    .contains(&(name0, _type0))
-                {
-                    Ok(false)
-                } else {
-                    let body1 = self
-                        .swap(body1, *name0, _type0, *name1, _type1)
-                        .unwrap_or_else(|_e| {
-                            panic!("{}", DANGLING_HANDLE_ERROR)
-                        });
-                    let body =
-                        self.is_alpha_equivalent(body0, &body1).unwrap_or_else(
-                            |_e| panic!("{}", DANGLING_HANDLE_ERROR),
-                        );
-
-                    Ok(body && _type0 == _type1)
-                }
-            }
-            _otherwise => Ok(false),
-        }
-    }
-
-    /// Returns `Ok(true)` iff the terms pointed-to by `left` and `right` in the
-    /// kernel's term-table are ⍺-equivalent, that is, equal up-to a permutative
-    /// renaming of their bound variables.
-    ///
-    /// # Errors
-    ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
-    /// `right` do not point-to a term in the runtime state's term-table.
-    #[inline]
-    pub fn is_alpha_equivalent<T>(
-        &mut self,
-        left: T,
-        right: T,
-    ) -> Result<bool, ErrorCode>
-    where
-        T: Borrow<Handle<tags::Term>>,
-    {
-        info!(
-            "Testing terms with handles: {} and: {} for alpha-equivalence.",
-            left.borrow(),
-            right.borrow()
-        );
-
-        let left = self.resolve_term_handle(left)?.clone();
-        let right = self.resolve_term_handle(right)?.clone();
-
-        self.alpha_equivalent_inner(&left, &right)
+        /* NB: the kernel maintains maximal sharing of terms, up-to
+         * ⍺-equivalence, so two registered terms are ⍺-equivalent iff their
+         * handles are equal.
+         */
+        Ok(left == right)
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -2375,20 +4738,119 @@ impl RuntimeState {
     /// 1. Ensure that `thm` is well-formed before calling this function,
     /// 2. The hypotheses of the theorem `thm` should be sorted prior to calling
     /// this function, so that theorems can be compared for structural equality.
-    fn admit_theorem(&mut self, thm: Theorem) -> Handle<tags::Theorem> {
+    ///
+    /// If proof recording is enabled, also records a proof term for `thm`,
+    /// noting that it was derived by applying the inference rule `rule` to
+    /// `arguments`.  The trust level of the theorem is that of `rule`, or that
+    /// of the least trusted theorem in `arguments`, whichever is lower.
+    ///
+    /// Every term in `arguments`, and every term in `thm`, is validated before
+    /// `thm` is admitted, as any of them may have been registered whilst lazy
+    /// validation was enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` or
+    /// `Err(ErrorCode::DomainTypeMismatch)` if any of these terms is not
+    /// well-typed.
+    pub(crate) fn admit_theorem(
+        &mut self,
+        rule: Rule,
+        arguments: Vec<ProofArgument>,
+        mut thm: Theorem,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        self.validate_proof_arguments(&arguments)?;
+        self.term_validate(thm.conclusion())?;
+
+        for premiss in thm.premisses() {
+            self.term_validate(premiss)?;
+        }
+
+        let (_handle, premisses) =
+            self.admit_hypotheses(thm.shared_premisses().clone());
+        thm.share_premisses(premisses);
+
+        let trust = arguments
+            .iter()
+            .filter_map(|argument| match argument {
+                ProofArgument::Theorem(premiss) => self.trust.get(premiss),
+                _otherwise => None,
+            })
+            .fold(TrustLevel::of_rule(rule), |trust, premiss| {
+                trust.max(*premiss)
+            });
+
         let fresh = self.issue_handle();
+        let conclusion = thm.conclusion().clone();
         self.theorems.insert(fresh.clone(), thm);
-        fresh
+
+        self.conclusion_filter.insert(*conclusion as u64);
+        self.index_conclusion(&conclusion);
+        self.conclusions
+            .entry(conclusion)
+            .or_default()
+            .push(fresh.clone());
+
+        if trust != TrustLevel::KernelProved {
+            self.trust.insert(fresh.clone(), trust);
+        }
+
+        #[cfg(feature = "retraction")]
+        for argument in &arguments {
+            if let ProofArgument::Theorem(premiss) = argument {
+                self.dependents
+                    .entry(premiss.clone())
+                    .or_default()
+                    .push(fresh.clone());
+            }
+        }
+
+        if self.record_proofs {
+            let proof = self.issue_handle();
+
+            self.proofs.insert(
+                proof.clone(),
+                Proof::new(rule, arguments, fresh.clone()),
+            );
+            self.theorem_proofs.insert(fresh.clone(), proof);
+        }
+
+        if self.detect_duplicates {
+            self.detect_duplicate(&fresh);
+        }
+
+        if !self.pending_theorem_metadata.is_empty() {
+            self.attach_pending_metadata(&fresh);
+        }
+
+        if let Some(theorem) = self.theorems.get(&fresh) {
+            self.notify_theorem_registered(&fresh, theorem);
+        }
+
+        Ok(fresh)
     }
 
-    /// Returns `Some(thm)` iff `handle` points-to a registered theorem in the
-    /// runtime state's theorem table.
+    /// Returns `Ok(thm)` iff `handle` points-to a registered theorem in the
+    /// runtime state's theorem table, and otherwise the error to report for
+    /// `handle`.
     #[inline]
-    fn resolve_theorem_handle<T>(&self, handle: T) -> Option<&Theorem>
+    pub(crate) fn resolve_theorem_handle<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Theorem, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
     {
-        self.theorems.get(handle.borrow())
+        let theorem = self.theorems.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(handle.borrow(), ErrorCode::NoSuchTheoremRegistered)
+        })?;
+
+        #[cfg(feature = "retraction")]
+        if self.retracted.contains(handle.borrow()) {
+            return Err(ErrorCode::TheoremRetracted);
+        }
+
+        Ok(theorem)
     }
 
     /// Returns `true` iff `handle` points to a registered theorem in the
@@ -2403,7 +4865,126 @@ impl RuntimeState {
             handle.borrow()
         );
 
-        self.resolve_theorem_handle(handle).is_some()
+        self.resolve_theorem_handle(handle).is_ok()
+    }
+
+    /// Returns `Ok(true)` iff the theorem pointed-to by `handle` depends upon
+    /// the linear arithmetic decision procedure, having been proved by it, or
+    /// derived from a theorem that was.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to any theorem in the runtime state's theorem-table.
+    pub fn theorem_is_tainted<T>(&self, handle: T) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Checking if theorem with handle {} is tainted.",
+            handle.borrow()
+        );
+
+        self.resolve_theorem_handle(handle.borrow())?;
+
+        Ok(self.trust.get(handle.borrow())
+            == Some(&TrustLevel::OracleDependent))
+    }
+
+    /// Returns `Ok(true)` iff the theorems pointed-to by `left` and `right`
+    /// have the same statement up to ⍺-equivalence: ⍺-equivalent conclusions,
+    /// and sets of hypotheses that are ⍺-equivalent element-wise.  The
+    /// theorems' handles, proofs and taint are not compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if either `left` or
+    /// `right` do not point-to a theorem in the runtime state's theorem-table.
+    pub fn theorem_alpha_equal<T>(
+        &self,
+        left: T,
+        right: T,
+    ) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Testing theorems with handles: {} and: {} for alpha-equivalence.",
+            left.borrow(),
+            right.borrow()
+        );
+
+        let left = self.resolve_theorem_handle(left)?;
+        let right = self.resolve_theorem_handle(right)?;
+
+        /* NB: terms are shared up-to ⍺-equivalence, so comparing handles
+         * suffices, and premisses are kept sorted, so comparing them after
+         * removing repeats compares them as sets.
+         */
+        if left.conclusion() != right.conclusion() {
+            return Ok(false);
+        }
+
+        let mut lefts = left.premisses().iter().collect::<Vec<_>>();
+        let mut rights = right.premisses().iter().collect::<Vec<_>>();
+
+        lefts.dedup();
+        rights.dedup();
+
+        Ok(lefts == rights)
+    }
+
+    /// Returns `Ok(true)` iff some theorem registered in the runtime state has
+    /// the term pointed-to by `handle`, or one alpha-equivalent to it, as its
+    /// conclusion, whatever its hypotheses.  Intended for automation, to check
+    /// whether a goal has already been proved before searching for a proof, so
+    /// queries for conclusions that no theorem has are answered quickly, with
+    /// a Bloom filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a registered term in the runtime state's term-table.
+    pub fn theorem_exists_with_conclusion<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Checking if a theorem exists with conclusion with handle {}.",
+            handle.borrow()
+        );
+
+        self.resolve_term_handle(handle.borrow())?;
+
+        if !self.conclusion_filter.may_contain(**handle.borrow() as u64) {
+            return Ok(false);
+        }
+
+        Ok(self
+            .conclusions
+            .get(handle.borrow())
+            .is_some_and(|theorems| {
+                theorems
+                    .iter()
+                    .any(|theorem| self.resolve_theorem_handle(theorem).is_ok())
+            }))
+    }
+
+    /// Returns the handles of every theorem registered in the runtime state,
+    /// other than the kernel's preallocated axioms, in ascending order.
+    pub fn theorem_handles(&self) -> Vec<Handle<tags::Theorem>> {
+        let mut handles: Vec<_> = self
+            .theorems
+            .keys()
+            .filter(|h| !is_preallocated((*h).clone()))
+            .cloned()
+            .collect();
+
+        handles.sort();
+        handles
     }
 
     /// Returns `Ok(conclusion)` if `handle` points-to a theorem object
@@ -2427,11 +5008,7 @@ impl RuntimeState {
             handle.borrow()
         );
 
-        Ok(self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .conclusion()
-            .clone())
+        Ok(self.resolve_theorem_handle(handle)?.conclusion().clone())
     }
 
     /// Returns `Ok(premisses)` if `handle` points-to a theorem object
@@ -2446,7 +5023,7 @@ impl RuntimeState {
     pub fn theorem_split_premisses<T>(
         &self,
         handle: T,
-    ) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+    ) -> Result<&[Handle<tags::Term>], ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
     {
@@ -2455,11 +5032,7 @@ impl RuntimeState {
             handle.borrow()
         );
 
-        Ok(self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .premisses()
-            .to_vec())
+        Ok(self.resolve_theorem_handle(handle)?.premisses())
     }
 
     /// Registers a new theorem object, `{ɸ} ⊢ ɸ` in the kernel's theorem-table
@@ -2481,6 +5054,10 @@ impl RuntimeState {
     where
         T: Into<Handle<tags::Term>> + Clone,
     {
+        let arguments = vec![ProofArgument::Term(trm.clone().into())];
+
+        self.validate_proof_arguments(&arguments)?;
+
         let trm = trm.into();
         info!("Registering 'assumption' theorem with handle {}.", trm);
 
@@ -2488,7 +5065,11 @@ impl RuntimeState {
             return Err(ErrorCode::NotAProposition);
         }
 
-        Ok(self.admit_theorem(Theorem::new(vec![trm.clone()], trm)))
+        self.admit_theorem(
+            Rule::Assumption,
+            arguments,
+            Theorem::new(vec![trm.clone()], trm),
+        )
     }
 
     /// Registers a new theorem object, `Γ ∪ {ψ} ⊢ ɸ` in the kernel's
@@ -2516,6 +5097,13 @@ impl RuntimeState {
         T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Theorem>> + Clone,
     {
+        let arguments = vec![
+            ProofArgument::Term(trm.clone().into()),
+            ProofArgument::Theorem(handle.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
         let trm = trm.into();
         let handle = handle.into();
 
@@ -2528,18 +5116,18 @@ impl RuntimeState {
             return Err(ErrorCode::NotAProposition);
         }
 
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let thm = self.resolve_theorem_handle(handle)?.clone();
 
         let mut premisses = thm.premisses().clone();
         premisses.push(trm);
         premisses.sort();
         premisses.dedup();
 
-        Ok(self
-            .admit_theorem(Theorem::new(premisses, thm.conclusion().clone())))
+        self.admit_theorem(
+            Rule::Weaken,
+            arguments,
+            Theorem::new(premisses, thm.conclusion().clone()),
+        )
     }
 
     /// Registers a new theorem object, `{} ⊢ r = r` in the kernel's
@@ -2560,13 +5148,15 @@ impl RuntimeState {
         T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Term>> + Clone,
     {
+        let arguments = vec![ProofArgument::Term(trm.clone().into())];
+
+        self.validate_proof_arguments(&arguments)?;
+
         let trm = trm.into();
 
         info!("Registering 'reflexivity' theorem with handle {}.", trm);
 
-        if !self.term_type_is_proposition(&trm)? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.resolve_term_handle(&trm)?;
 
         // NB: this should never fail as `trm` has been type-checked at this
         // point, and every term has the same type as itself.  If it does fail,
@@ -2576,7 +5166,11 @@ impl RuntimeState {
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
         let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Reflexivity,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
     /// Registers a new theorem object, `Γ ⊢ s = r` in the kernel's
@@ -2600,10 +5194,9 @@ impl RuntimeState {
     where
         T: Borrow<Handle<tags::Theorem>>,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![ProofArgument::Theorem(handle.borrow().clone())];
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
 
         let (left, right) = self.term_split_equality(thm.conclusion())?;
 
@@ -2620,7 +5213,11 @@ impl RuntimeState {
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
         let premisses = thm.premisses().clone();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Symmetry,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
     /// Registers a new theorem object, `Γ ∪ Δ ⊢ r = t` in the kernel's
@@ -2651,14 +5248,13 @@ impl RuntimeState {
         T: Borrow<Handle<tags::Theorem>>,
         U: Borrow<Handle<tags::Theorem>>,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
 
         let mut premisses = left.premisses().clone();
         premisses.append(&mut right.premisses().clone());
@@ -2669,7 +5265,12 @@ impl RuntimeState {
         let (mid1, right) = self.term_split_equality(right.conclusion())?;
 
         if mid0 != mid1 {
-            return Err(ErrorCode::ShapeMismatch);
+            return Err(self.shape_mismatch_terms(
+                "the right-hand side of the first equality is not the \
+                 left-hand side of the second.",
+                mid0,
+                mid1,
+            ));
         }
 
         // Appease the borrow-checker gods...
@@ -2685,7 +5286,11 @@ impl RuntimeState {
             .term_register_equality(left, right)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Transitivity,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
     /// Registers a new theorem object, `Γ ∪ Δ ⊢ f r = g s` in the kernel's
@@ -2718,14 +5323,13 @@ impl RuntimeState {
         T: Borrow<Handle<tags::Theorem>>,
         U: Borrow<Handle<tags::Theorem>>,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
 
         let mut premisses = left.premisses().clone();
         premisses.append(&mut right.premisses().clone());
@@ -2748,748 +5352,1161 @@ impl RuntimeState {
         let right = self.term_register_application(fun_right, arg_right)?;
         let conclusion = self.term_register_equality(left, right)?;
 
-        Ok(self.admit_theorem(Theorem::new(premisses.clone(), conclusion)))
+        self.admit_theorem(
+            Rule::Application,
+            arguments,
+            Theorem::new(premisses.clone(), conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `Γ ⊢ λx:τ. r = λx:τ. s` in the kernel's
-    /// theorem-table iff `handle` points-to the theorem `Γ ⊢ r = s` in the
-    /// kernel's theorem-table, and `tau` points-to a type in the kernel's
-    /// type-table.  Returns `Ok(handle)` if this process is successful, where
-    /// `handle` is the newly-allocated handle pointing-to the new theorem
-    /// object.
+    /// Registers a new theorem object, `Γ ∪ Δ ∪ Θ ⊢ COND c r s = COND d t u`
+    /// in the kernel's theorem-table iff `condition` points-to the theorem
+    /// `Γ ⊢ c = d`, `left` points-to the theorem `Δ ⊢ r = t`, and `right`
+    /// points-to the theorem `Θ ⊢ s = u` in the kernel's theorem-table.
+    /// Returns `Ok(handle)` if this process is successful, where `handle` is
+    /// the newly-allocated handle pointing-to the new theorem object.
+    ///
+    /// **Note**: this is a derived rule, obtainable from repeated applications
+    /// of `theorem_register_application()`, provided for efficiency.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
-    /// point-to a registered theorem in the runtime state's theorem-table.
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any of
+    /// `condition`, `left`, or `right` do not point-to a registered theorem in
+    /// the runtime state's theorem-table.
     ///
-    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
-    /// point-to a registered type in the runtime state's type-table.
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of any of the
+    /// theorems pointed-to by `condition`, `left`, or `right` in the runtime
+    /// state's theorem-table is not an equality between terms.
     ///
-    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the theorem
-    /// pointed-to by `handle` in the runtime state's theorem-table is not an
-    /// equality between terms.
-    pub fn theorem_register_lambda<T, U, V>(
+    /// Returns `Err(ErrorCode::NotAProposition)` if terms `c` (and `d`) are not
+    /// propositions.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if terms `r` (and `t`) do
+    /// not have the same type as terms `s` (and `u`).
+    pub fn theorem_register_conditional_congruence<T, U, V>(
         &mut self,
-        name: T,
-        tau: U,
-        handle: V,
+        condition: T,
+        left: U,
+        right: V,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Into<Name> + Clone,
-        U: Into<Handle<tags::Type>> + Clone,
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
         V: Borrow<Handle<tags::Theorem>>,
     {
-        if !self.type_is_registered(tau.clone().into()) {
-            return Err(ErrorCode::NoSuchTypeRegistered);
-        }
-
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Theorem(condition.borrow().clone()),
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let condition = self.resolve_theorem_handle(condition)?.clone();
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
+
+        let mut premisses = condition.premisses().clone();
+        premisses.append(&mut left.premisses().clone());
+        premisses.append(&mut right.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
 
-        let (left, right) = self.term_split_equality(thm.conclusion())?;
+        let (cond_left, cond_right) =
+            self.term_split_equality(condition.conclusion())?;
+        let (then_left, then_right) =
+            self.term_split_equality(left.conclusion())?;
+        let (else_left, else_right) =
+            self.term_split_equality(right.conclusion())?;
 
         // Appease the borrow-checker gods...
-        let left = left.clone();
-        let right = right.clone();
+        let cond_left = cond_left.clone();
+        let cond_right = cond_right.clone();
+        let then_left = then_left.clone();
+        let then_right = then_right.clone();
+        let else_left = else_left.clone();
+        let else_right = else_right.clone();
 
-        // NB: none of these can fail as we obtained the terms from the
-        // conclusion of a theorem, which is an equality, and we therefore know
-        // that everything is well-typed, and that both side of the equality
-        // have the same type.
-        let lhandle = self
-            .term_register_lambda(name.clone(), tau.clone(), left)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
-        let rhandle = self
-            .term_register_lambda(name, tau, right)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
-        let conclusion = self
-            .term_register_equality(lhandle, rhandle)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        // NB: these *can* fail due to a type-mismatch in the equalities.
+        let left =
+            self.term_register_conditional(cond_left, then_left, else_left)?;
+        let right =
+            self.term_register_conditional(cond_right, then_right, else_right)?;
+        let conclusion = self.term_register_equality(left, right)?;
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        self.admit_theorem(
+            Rule::ConditionalCongruence,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `{} ⊢ (λx:τ. r)t = r[x:τ ↦ t]` in the
-    /// kernel's theorem-table iff `application` points-to the term `(λx:τ. r)t`
-    /// in the kernel's term-table.  Returns `Ok(handle)` if this process is
-    /// successful, where `handle` is the newly-allocated handle pointing-to the
-    /// new theorem object.
+    /// Registers a new theorem object, `Γ ∪ Δ ⊢ LET f r = LET g s` in the
+    /// kernel's theorem-table iff `function` points-to the theorem
+    /// `Γ ⊢ f = g` and `argument` points-to the theorem `Δ ⊢ r = s` in the
+    /// kernel's theorem-table.  Returns `Ok(handle)` if this process is
+    /// successful, where `handle` is the newly-allocated handle pointing-to
+    /// the new theorem object.
+    ///
+    /// **Note**: this is a derived rule, obtainable from repeated applications
+    /// of `theorem_register_application()`, provided for efficiency.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `application` does not
-    /// point-to a registered term in the runtime state's term-table.
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `function` or
+    /// `argument` do not point-to a registered theorem in the runtime state's
+    /// theorem-table.
     ///
-    /// Returns `Err(ErrorCode::NotAnApplication)` if `application` does not
-    /// point-to a term application in the runtime state's term-table.
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the theorem
+    /// pointed-to by either `function` or `argument` in the runtime state's
+    /// theorem-table is not an equality between terms.
     ///
-    /// Returns `Err(ErrorCode::NotALambda)` if `application` does not
-    /// point-to a term application between a λ-abstraction and an argument.
-    pub fn theorem_register_beta<T>(
+    /// Returns `Err(ErrorCode::NotAFunctionType)` if terms `f` (and `g`) do not
+    /// have a functional type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if terms `r` (and `s`) do
+    /// not have a type that matches the domain type of terms `f` and `g`.
+    pub fn theorem_register_let_congruence<T, U>(
         &mut self,
-        application: T,
+        function: T,
+        argument: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Into<Handle<tags::Term>> + Clone,
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
     {
-        let (lhs, rhs) =
-            self.term_split_application(application.clone().into())?;
+        let arguments = vec![
+            ProofArgument::Theorem(function.borrow().clone()),
+            ProofArgument::Theorem(argument.borrow().clone()),
+        ];
 
-        let (name, _type, body) = self.term_split_lambda(lhs)?;
+        let function = self.resolve_theorem_handle(function)?.clone();
+        let argument = self.resolve_theorem_handle(argument)?.clone();
 
-        // Appease the borrow-checker gods...
-        let body = body.clone();
-        let name = *name;
-        let _type = _type.clone();
-        let rhs = rhs.clone();
+        let mut premisses = function.premisses().clone();
+        premisses.append(&mut argument.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
 
-        // NB: these should never fail as `body` originates from a well-typed
-        // term obtained from the kernel's term-table.  Moreover, a meta-theorem
-        // of HOL asserts that the construction of the equality, below, is
-        // well-typed.
-        let subst = self
-            .substitution(body, vec![((name, _type), rhs)])
-            .expect(DANGLING_HANDLE_ERROR);
-        let conclusion = self
-            .term_register_equality(application, subst)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let (fun_left, fun_right) =
+            self.term_split_equality(function.conclusion())?;
+        let (arg_left, arg_right) =
+            self.term_split_equality(argument.conclusion())?;
 
-        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+        // Appease the borrow-checker gods...
+        let fun_left = fun_left.clone();
+        let fun_right = fun_right.clone();
+        let arg_left = arg_left.clone();
+        let arg_right = arg_right.clone();
+
+        // NB: these *can* fail due to a type-mismatch in the equalities.
+        let left = self.term_register_let(fun_left, arg_left)?;
+        let right = self.term_register_let(fun_right, arg_right)?;
+        let conclusion = self.term_register_equality(left, right)?;
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::LetCongruence,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `{} ⊢ λx:τ. f x = f` in the
-    /// kernel's theorem-table iff `lambda` points-to the term `λx:τ. f x`
-    /// in the kernel's term-table and `x ∉ fv f`.  Returns `Ok(handle)` if this
-    /// process is successful, where `handle` is the newly-allocated handle
-    /// pointing-to the new theorem object.
+    /// Registers a new theorem object, `Γ ⊢ (∀x:τ. ɸ) = (∀x:τ. ψ)` in the
+    /// kernel's theorem-table iff `handle` points-to the theorem `Γ ⊢ ɸ = ψ`
+    /// in the kernel's theorem-table, and the variable `x:τ`, named `name`,
+    /// does not appear free in any premiss, `Γ`.  Returns `Ok(handle)` if
+    /// this process is successful, where `handle` is the newly-allocated
+    /// handle pointing-to the new theorem object.
+    ///
+    /// **Note**: this is a derived rule, obtainable from
+    /// `theorem_register_lambda()` and `theorem_register_application()`,
+    /// provided for efficiency.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `lambda` does not
-    /// point-to a registered term in the runtime state's term-table.
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
     ///
-    /// Returns `Err(ErrorCode::NotALambda)` if `lambda` does not
-    /// point-to a λ-abstraction in the runtime state's term-table.
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
     ///
-    /// Returns `Err(ErrorCode::NotAnApplication)` if `lambda` does not
-    /// point-to a λ-abstraction with a term application as a body, in the
-    /// runtime state's term-table.
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the theorem
+    /// pointed-to by `handle` in the runtime state's theorem-table is not an
+    /// equality between terms.
     ///
-    /// Returns `Err(ErrorCode::ShapeMismatch)` if `lambda` does not point-to
-    /// λ-abstraction with a term application between an argument term and the
-    /// bound variable in the runtime state's term-table.  Alternatively, if
-    /// the bound variable appears in the free-variables of the functional term.
-    pub fn theorem_register_eta<T>(
+    /// Returns `Err(ErrorCode::NotAProposition)` if terms `ɸ` (and `ψ`) are not
+    /// propositions.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the variable `x:τ` appears
+    /// free in some premiss of the theorem pointed-to by `handle`.
+    pub fn theorem_register_forall_congruence<T, U, V>(
         &mut self,
-        lambda: T,
+        name: T,
+        tau: U,
+        handle: V,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Into<Handle<tags::Term>> + Clone,
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
     {
-        let (name0, _type, body) =
-            self.term_split_lambda(lambda.clone().into())?;
-        let (func, var) = self.term_split_application(body)?;
-        let (name1, _type) = self.term_split_variable(var)?;
+        self.quantifier_congruence(
+            Rule::ForallCongruence,
+            name.into(),
+            tau.into(),
+            handle.borrow(),
+        )
+    }
 
-        if name0 != name1 {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+    /// Registers a new theorem object, `Γ ⊢ (∃x:τ. ɸ) = (∃x:τ. ψ)` in the
+    /// kernel's theorem-table iff `handle` points-to the theorem `Γ ⊢ ɸ = ψ`
+    /// in the kernel's theorem-table, and the variable `x:τ`, named `name`,
+    /// does not appear free in any premiss, `Γ`.  Returns `Ok(handle)` if
+    /// this process is successful, where `handle` is the newly-allocated
+    /// handle pointing-to the new theorem object.
+    ///
+    /// **Note**: this is a derived rule, obtainable from
+    /// `theorem_register_lambda()` and `theorem_register_application()`,
+    /// provided for efficiency.
+    ///
+    /// # Errors
+    ///
+    /// As for `theorem_register_forall_congruence()`.
+    pub fn theorem_register_exists_congruence<T, U, V>(
+        &mut self,
+        name: T,
+        tau: U,
+        handle: V,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        self.quantifier_congruence(
+            Rule::ExistsCongruence,
+            name.into(),
+            tau.into(),
+            handle.borrow(),
+        )
+    }
 
-        // NB: these steps should never fail, as we should know by this point
-        // that `func` does not dangle (due to it originating from deep inside
-        // a registered term) and also that the type of the resulting lambda is
-        // correct.
-        if self
-            .term_free_variables(func)
-            .expect(DANGLING_HANDLE_ERROR)
-            .contains(&(name1, _type))
-        {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+    /// The common implementation of `theorem_register_forall_congruence()`
+    /// and `theorem_register_exists_congruence()`, binding `name:tau` on both
+    /// sides of the equality concluded by the theorem pointed-to by `handle`
+    /// with the quantifier introduced by `rule`.
+    fn quantifier_congruence(
+        &mut self,
+        rule: Rule,
+        name: Name,
+        tau: Handle<tags::Type>,
+        handle: &Handle<tags::Theorem>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let arguments = vec![
+            ProofArgument::Name(name),
+            ProofArgument::Type(tau.clone()),
+            ProofArgument::Theorem(handle.clone()),
+        ];
+
+        self.resolve_type_handle(&tau)?;
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        let (left, right) = self.term_split_equality(thm.conclusion())?;
 
         // Appease the borrow-checker gods...
-        let body = body.clone();
+        let left = left.clone();
+        let right = right.clone();
+
+        for premiss in thm.premisses().iter() {
+            if self
+                .term_free_variables(premiss)
+                .expect(DANGLING_HANDLE_ERROR)
+                .contains(&(&name, &tau))
+            {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+        }
+
+        // NB: these *can* fail if the sides of the equality are not
+        // propositions.
+        let (left, right) = if rule == Rule::ForallCongruence {
+            (
+                self.term_register_forall(name, tau.clone(), left)?,
+                self.term_register_forall(name, tau, right)?,
+            )
+        } else {
+            (
+                self.term_register_exists(name, tau.clone(), left)?,
+                self.term_register_exists(name, tau, right)?,
+            )
+        };
 
+        /* NB: this cannot fail, as both sides are propositions. */
         let conclusion = self
-            .term_register_equality(lambda, body)
+            .term_register_equality(left, right)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
-        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            rule,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `Γ ∪ Δ ⊢ ɸ = ψ` in the kernel's
-    /// theorem-table iff `left` points-to the theorem `Γ ⊢ ɸ ⟶ ψ` and `right`
-    /// points-to the theorem `Δ ⊢ ψ ⟶ ɸ` in the kernel's theorem-table.
-    /// Returns `Ok(handle)` if this process is successful, where `handle` is
-    /// the newly-allocated handle pointing-to the new theorem object.
+    /// Registers a new theorem object, `{} ⊢ FST (PAIR r s) = r` in the
+    /// kernel's theorem-table iff `left` and `right` point-to the terms `r`
+    /// and `s`, respectively, in the kernel's term-table.  Returns
+    /// `Ok(handle)` if this process is successful, where `handle` is the
+    /// newly-allocated handle pointing-to the new theorem object.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `left` or `right` do
-    /// not point-to a registered term in the runtime state's term-table.
-    ///
-    /// Returns `Err(ErrorCode::NotAnImplication)` if `left` or `right` are not
-    /// implicational theorems registered in the runtime state's term-table.
-    ///
-    /// Returns `Err(ErrorCode::ShapeMismatch)` if `left` and `right` are not
-    /// implications between the same two terms (up-to ⍺-equivalence).
-    pub fn theorem_register_iff_introduction<T, U>(
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a registered term in the runtime state's
+    /// term-table.
+    pub fn theorem_register_first_projection<T, U>(
         &mut self,
         left: T,
         right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-
-        let (left0, right0) = self.term_split_implication(left.conclusion())?;
-        let (left1, right1) =
-            self.term_split_implication(right.conclusion())?;
-
-        if left0 != right1 || left1 != right0 {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+        let arguments = vec![
+            ProofArgument::Term(left.clone().into()),
+            ProofArgument::Term(right.clone().into()),
+        ];
 
-        let mut premisses = left.premisses().clone();
-        premisses.append(&mut right.premisses().clone());
-        premisses.sort();
-        premisses.dedup();
+        self.validate_proof_arguments(&arguments)?;
 
-        // Appease the borrow-checker gods...
-        let left0 = left0.clone();
-        let right1 = right1.clone();
+        let pair = self.term_register_pair(left.clone(), right)?;
 
-        // NB: this shouldn't fail as we know that everything is now a proposition.
+        // NB: these should never fail as the pair is well-typed, and its first
+        // projection has the same type as its first component.
+        let first = self
+            .term_register_first(pair)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
         let conclusion = self
-            .term_register_equality(left0, right1)
+            .term_register_equality(first, left)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::FirstProjection,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `Γ ⊢ ɸ ⟶ ψ` in the kernel's
-    /// theorem-table iff `handle` points-to the theorem `Γ ⊢ ɸ = ψ` in the
-    /// kernel's theorem-table.  Returns `Ok(handle)` if this process is
-    /// successful, where `handle` is the newly-allocated handle pointing-to the
-    /// new theorem object.
+    /// Registers a new theorem object, `{} ⊢ SND (PAIR r s) = s` in the
+    /// kernel's theorem-table iff `left` and `right` point-to the terms `r`
+    /// and `s`, respectively, in the kernel's term-table.  Returns
+    /// `Ok(handle)` if this process is successful, where `handle` is the
+    /// newly-allocated handle pointing-to the new theorem object.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does
-    /// not point-to a registered theorem in the runtime state's theorem-table.
-    ///
-    /// Returns `Err(ErrorCode::NotAnEquality)` if `handle` does not point-to an
-    /// equational theorem registered in the runtime state's theorem-table.
-    ///
-    /// Returns `Err(ErrorCode::NotAProposition)` if `handle` does not point-to
-    /// an equational theorem between formulae, registered in the runtime
-    /// state's theorem-table.
-    pub fn theorem_register_iff_left_elimination<T>(
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a registered term in the runtime state's
+    /// term-table.
+    pub fn theorem_register_second_projection<T, U>(
         &mut self,
-        handle: T,
+        left: T,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-
-        let (left, right) = self.term_split_equality(thm.conclusion())?;
+        let arguments = vec![
+            ProofArgument::Term(left.clone().into()),
+            ProofArgument::Term(right.clone().into()),
+        ];
 
-        // Appease the borrow-checker gods...
-        let left = left.clone();
-        let right = right.clone();
+        self.validate_proof_arguments(&arguments)?;
 
-        if !self
-            .term_type_is_proposition(left.clone())
-            .expect(DANGLING_HANDLE_ERROR)
-        {
-            return Err(ErrorCode::NotAProposition);
-        }
+        let pair = self.term_register_pair(left, right.clone())?;
 
-        // NB: this should never fail as we've already checked that the equality
-        // is an equality between two formulae.
+        // NB: these should never fail as the pair is well-typed, and its second
+        // projection has the same type as its second component.
+        let second = self
+            .term_register_second(pair)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
         let conclusion = self
-            .term_register_implication(left, right)
+            .term_register_equality(second, right)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::SecondProjection,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `{} ⊢ True` in the kernel's
-    /// theorem-table.  Returns `Ok(handle)` where `handle` is the
-    /// newly-allocated handle pointing-to the new theorem object.
+    /// Registers a new theorem object, `{} ⊢ PAIR (FST p) (SND p) = p`, the
+    /// surjectivity of pairing, in the kernel's theorem-table iff `pair`
+    /// points-to a term `p` of product type in the kernel's term-table.
+    /// Returns `Ok(handle)` if this process is successful, where `handle` is
+    /// the newly-allocated handle pointing-to the new theorem object.
     ///
     /// # Errors
     ///
-    /// Does not error: returns `Result<Handle<tags::Theorem>, ErrorCode>` for
-    /// uniformity with other axioms/rules.
-    pub fn theorem_register_truth_introduction<T>(
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `pair` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProductType)` if the term pointed-to by
+    /// `pair` does not have a product type.
+    pub fn theorem_register_surjective_pairing<T>(
         &mut self,
+        pair: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Into<Handle<tags::Term>> + Clone,
     {
-        let identity: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+        let arguments = vec![ProofArgument::Term(pair.clone().into())];
 
-        // NB: this should never fail if the initial theory is properly
-        // initialized.
+        self.validate_proof_arguments(&arguments)?;
+
+        let first = self.term_register_first(pair.clone())?;
+
+        // NB: these should never fail as `pair` has been checked to have a
+        // product type, above.
+        let second = self
+            .term_register_second(pair.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rebuilt = self
+            .term_register_pair(first, second)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
         let conclusion = self
-            .term_register_constant(PREALLOCATED_HANDLE_CONSTANT_TRUE, identity)
+            .term_register_equality(rebuilt, pair)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
         let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::SurjectivePairing,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    /// Registers a new theorem object, `Γ ⊢ ɸ` in the kernel's theorem-table
-    /// iff `thm` points-to the theorem `Γ ⊢ False` in the kernel's
-    /// theorem-table and `conclusion` points-to the formula `ɸ` in the kernel's
-    /// term-table.  Returns `Ok(handle)` if this process is successful,
-    /// where `handle` is the newly-allocated handle pointing-to the new theorem
-    /// object.
+    /// Registers a new theorem object, `{} ⊢ IN r s = s r` in the kernel's
+    /// theorem-table iff `element` and `set` point-to the terms `r` and `s`,
+    /// respectively, in the kernel's term-table.  Returns `Ok(handle)` if this
+    /// process is successful, where `handle` is the newly-allocated handle
+    /// pointing-to the new theorem object.
     ///
     /// # Errors
     ///
-    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `thm` does
-    /// not point-to a registered theorem in the runtime state's theorem-table.
-    ///
-    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `conclusion` does
-    /// not point-to a registered term in the runtime state's term-table.
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `element` or
+    /// `set` do not point-to a registered term in the runtime state's
+    /// term-table.
     ///
-    /// Returns `Err(ErrorCode::NotAProposition)` if `conclusion` does not
-    /// point-to a formula, registered in the runtime state's theorem-table.
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `set`
+    /// does not have a set type.
     ///
-    /// Returns `Err(ErrorCode::ShapeMismatch)` if `thm` does not point-to a
-    /// proof of falsity.
-    pub fn theorem_register_falsity_elimination<T, U>(
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the type of the term
+    /// pointed-to by `element` is not the element type of the set pointed-to
+    /// by `set`.
+    pub fn theorem_register_membership_definition<T, U>(
         &mut self,
-        thm: T,
-        conclusion: U,
+        element: T,
+        set: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Term>> + Clone,
     {
-        let conclusion = conclusion.into();
-        let thm = thm.borrow();
+        let arguments = vec![
+            ProofArgument::Term(element.clone().into()),
+            ProofArgument::Term(set.clone().into()),
+        ];
 
-        let thm = self
-            .resolve_theorem_handle(thm)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        self.validate_proof_arguments(&arguments)?;
 
-        if !self.is_term_registered(&conclusion) {
-            return Err(ErrorCode::NoSuchTermRegistered);
-        }
+        let membership =
+            self.term_register_membership(element.clone(), set.clone())?;
 
-        if !self
-            .term_type_is_proposition(&conclusion)
-            .expect(DANGLING_HANDLE_ERROR)
-        {
-            return Err(ErrorCode::NotAProposition);
-        }
+        // NB: these should never fail as the membership is well-typed.
+        let application = self
+            .term_register_application(set, element)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let conclusion = self
+            .term_register_equality(membership, application)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        if !self
-            .is_false(thm.conclusion())
-            .expect(DANGLING_HANDLE_ERROR)
-        {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        self.admit_theorem(
+            Rule::MembershipDefinition,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_conjunction_introduction<T, U>(
+    /// Registers a new theorem object, `{} ⊢ SETSPEC s = s` in the kernel's
+    /// theorem-table iff `set` points-to the term `s` in the kernel's
+    /// term-table.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `set` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `set`
+    /// does not have a set type.
+    pub fn theorem_register_comprehension_definition<T>(
         &mut self,
-        left: T,
-        right: U,
+        set: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![ProofArgument::Term(set.clone().into())];
 
-        let conclusion = self.term_register_conjunction(
-            left.conclusion().clone(),
-            right.conclusion().clone(),
-        )?;
+        self.validate_proof_arguments(&arguments)?;
 
-        let mut premisses = left.premisses().clone();
-        premisses.append(&mut right.premisses().clone());
-        premisses.sort();
-        premisses.dedup();
+        let stau = self.term_type_infer(set.clone().into())?;
+        let element = self.type_split_set(&stau)?.clone();
+
+        // NB: these should never fail as the set is well-typed.
+        let spec = self
+            .term_type_substitute(
+                PREALLOCATED_HANDLE_TERM_COMPREHENSION,
+                vec![(0_u64, element)],
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let comprehension = self
+            .term_register_application(spec, set.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let conclusion = self
+            .term_register_equality(comprehension, set)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::ComprehensionDefinition,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_conjunction_left_elimination<T>(
+    /// Registers a new theorem object, `{} ⊢ EMPTY = λx:τ. F` in the kernel's
+    /// theorem-table iff `tau` points-to the type `τ` in the kernel's
+    /// type-table.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    #[inline]
+    pub fn theorem_register_empty_set_definition<T>(
         &mut self,
-        handle: T,
+        tau: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Type>> + Clone + Debug,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-
-        let (left, _right) = self
-            .term_split_conjunction(thm.conclusion())
-            .map_err(|_e| ErrorCode::ShapeMismatch)?;
-
-        // Appease the borrow-checker gods...
-        let left = left.clone();
+        self.theorem_register_constant_set_definition(
+            PREALLOCATED_HANDLE_TERM_EMPTY_SET,
+            PREALLOCATED_HANDLE_TERM_FALSE,
+            tau,
+            Rule::EmptySetDefinition,
+        )
+    }
 
-        Ok(self.admit_theorem(Theorem::new(thm.premisses().clone(), left)))
+    /// Registers a new theorem object, `{} ⊢ UNIV = λx:τ. T` in the kernel's
+    /// theorem-table iff `tau` points-to the type `τ` in the kernel's
+    /// type-table.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    #[inline]
+    pub fn theorem_register_universal_set_definition<T>(
+        &mut self,
+        tau: T,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        self.theorem_register_constant_set_definition(
+            PREALLOCATED_HANDLE_TERM_UNIVERSAL_SET,
+            PREALLOCATED_HANDLE_TERM_TRUE,
+            tau,
+            Rule::UniversalSetDefinition,
+        )
     }
 
-    pub fn theorem_register_conjunction_right_elimination<T>(
+    /// Registers a new theorem object, `{} ⊢ S = λx:τ. P`, where `S` is the
+    /// set constant pointed-to by `set` instantiated at `tau`, and `P` is the
+    /// closed proposition pointed-to by `body`, recording it as derived by the
+    /// inference rule `rule`.
+    fn theorem_register_constant_set_definition<T>(
         &mut self,
-        handle: T,
+        set: Handle<tags::Term>,
+        body: Handle<tags::Term>,
+        tau: T,
+        rule: Rule,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Type>> + Clone + Debug,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![ProofArgument::Type(tau.clone().into())];
 
-        let (_left, right) = self
-            .term_split_conjunction(thm.conclusion())
-            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        self.resolve_type_handle(tau.clone().into())?;
 
-        // Appease the borrow-checker gods...
-        let conclusion = right.clone();
+        // NB: these should never fail as `tau` is registered.
+        let spec = self
+            .term_type_substitute(set, vec![(0_u64, tau.clone())])
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let lambda = self
+            .term_register_lambda(0_u64, tau, body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let conclusion = self
+            .term_register_equality(spec, lambda)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(rule, arguments, Theorem::new(premisses, conclusion))
     }
 
-    pub fn theorem_register_disjunction_left_introduction<T, U>(
+    /// Registers a new theorem object, `{} ⊢ UNION s t = λx:τ. s x ∨ t x` in
+    /// the kernel's theorem-table iff `left` and `right` point-to the terms `s`
+    /// and `t`, respectively, in the kernel's term-table, both sets with
+    /// elements of type `τ`.  The bound variable `x` is chosen fresh for `s`
+    /// and `t`.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a registered term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `left`
+    /// does not have a set type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the terms pointed-to by
+    /// `left` and `right` do not have the same type.
+    pub fn theorem_register_union_definition<T, U>(
         &mut self,
-        handle: T,
-        term: U,
+        left: T,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Term(left.clone().into()),
+            ProofArgument::Term(right.clone().into()),
+        ];
 
-        if !self.term_type_is_proposition(term.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.validate_proof_arguments(&arguments)?;
 
-        let conclusion =
-            self.term_register_disjunction(thm.conclusion().clone(), term)?;
+        let (element, name, left_body, right_body) =
+            self.set_pointwise_operands(left.clone(), right.clone())?;
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        // NB: none of these can fail, as the operands are well-typed sets.
+        let body = self
+            .term_register_disjunction(left_body, right_body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let lambda = self
+            .term_register_lambda(name, element.clone(), body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let union = self.set_operator_application(
+            PREALLOCATED_HANDLE_TERM_UNION,
+            element,
+            left,
+            right,
+        );
+        let conclusion = self
+            .term_register_equality(union, lambda)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::UnionDefinition,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_disjunction_right_introduction<T, U>(
+    /// Registers a new theorem object, `{} ⊢ INTER s t = λx:τ. s x ∧ t x` in
+    /// the kernel's theorem-table iff `left` and `right` point-to the terms `s`
+    /// and `t`, respectively, in the kernel's term-table, both sets with
+    /// elements of type `τ`.  The bound variable `x` is chosen fresh for `s`
+    /// and `t`.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a registered term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `left`
+    /// does not have a set type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the terms pointed-to by
+    /// `left` and `right` do not have the same type.
+    pub fn theorem_register_intersection_definition<T, U>(
         &mut self,
-        handle: T,
-        term: U,
+        left: T,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Term(left.clone().into()),
+            ProofArgument::Term(right.clone().into()),
+        ];
 
-        if !self.term_type_is_proposition(term.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
-        }
+        self.validate_proof_arguments(&arguments)?;
 
-        let conclusion =
-            self.term_register_disjunction(term, thm.conclusion().clone())?;
+        let (element, name, left_body, right_body) =
+            self.set_pointwise_operands(left.clone(), right.clone())?;
 
-        Ok(self
-            .admit_theorem(Theorem::new(thm.premisses().clone(), conclusion)))
+        // NB: none of these can fail, as the operands are well-typed sets.
+        let body = self
+            .term_register_conjunction(left_body, right_body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let lambda = self
+            .term_register_lambda(name, element.clone(), body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let intersection = self.set_operator_application(
+            PREALLOCATED_HANDLE_TERM_INTERSECTION,
+            element,
+            left,
+            right,
+        );
+        let conclusion = self
+            .term_register_equality(intersection, lambda)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::IntersectionDefinition,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_disjunction_elimination<T, U, V>(
+    /// Registers a new theorem object, `{} ⊢ SUBSET s t = ∀x:τ. s x ⇒ t x` in
+    /// the kernel's theorem-table iff `left` and `right` point-to the terms `s`
+    /// and `t`, respectively, in the kernel's term-table, both sets with
+    /// elements of type `τ`.  The bound variable `x` is chosen fresh for `s`
+    /// and `t`.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to a registered term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotASetType)` if the term pointed-to by `left`
+    /// does not have a set type.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the terms pointed-to by
+    /// `left` and `right` do not have the same type.
+    pub fn theorem_register_subset_definition<T, U>(
         &mut self,
         left: T,
-        mid: U,
-        right: V,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
-        V: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let mid = self
-            .resolve_theorem_handle(mid)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Term(left.clone().into()),
+            ProofArgument::Term(right.clone().into()),
+        ];
 
-        let (phi, psi) = self.term_split_disjunction(left.conclusion())?;
+        self.validate_proof_arguments(&arguments)?;
 
-        if mid.conclusion() != right.conclusion() {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+        let (element, name, left_body, right_body) =
+            self.set_pointwise_operands(left.clone(), right.clone())?;
 
-        if !mid.premisses().contains(phi) || !right.premisses().contains(psi) {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+        // NB: none of these can fail, as the operands are well-typed sets.
+        let body = self
+            .term_register_implication(left_body, right_body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let forall = self
+            .term_register_forall(name, element.clone(), body)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let subset = self.set_operator_application(
+            PREALLOCATED_HANDLE_TERM_SUBSET,
+            element,
+            left,
+            right,
+        );
+        let conclusion = self
+            .term_register_equality(subset, forall)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        if left.premisses().clone()
-            != mid
-                .premisses()
-                .iter()
-                .filter(|h| *h != phi)
-                .cloned()
-                .collect::<Vec<_>>()
-            || left.premisses().clone()
-                != right
-                    .premisses()
-                    .iter()
-                    .filter(|h| *h != psi)
-                    .cloned()
-                    .collect::<Vec<_>>()
-        {
-            return Err(ErrorCode::ShapeMismatch);
-        }
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(
-            left.premisses().clone(),
-            right.conclusion().clone(),
-        )))
+        self.admit_theorem(
+            Rule::SubsetDefinition,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_implication_introduction<T, U>(
+    /// Checks that the terms pointed-to by `left` and `right`, `s` and `t`, are
+    /// sets of the same type, with elements of type `τ`, and returns `τ`
+    /// alongside a name, `x`, fresh for both `s` and `t` and handles pointing
+    /// to the applications `s x` and `t x`.
+    #[allow(clippy::type_complexity)]
+    fn set_pointwise_operands<T, U>(
         &mut self,
-        handle: T,
-        intro: U,
-    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+        left: T,
+        right: U,
+    ) -> Result<
+        (
+            Handle<tags::Type>,
+            Name,
+            Handle<tags::Term>,
+            Handle<tags::Term>,
+        ),
+        ErrorCode,
+    >
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
         U: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let ltau = self.term_type_infer(left.clone().into())?;
+        let rtau = self.term_type_infer(right.clone().into())?;
 
-        if !self.term_type_is_proposition(intro.clone().into())? {
-            return Err(ErrorCode::NotAProposition);
+        let element = self.type_split_set(&ltau)?.clone();
+
+        if ltau != rtau {
+            return Err(ErrorCode::DomainTypeMismatch);
         }
 
-        if !thm.premisses().contains(&intro.clone().into()) {
-            return Err(ErrorCode::ShapeMismatch);
-        }
-
-        let conclusion = self.term_register_implication(
-            intro.clone(),
-            thm.conclusion().clone(),
-        )?;
-        let premisses = thm
-            .premisses()
+        let mut avoid: Vec<Name> = self
+            .term_free_variables(left.clone().into())
+            .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
             .iter()
-            .filter(|h| **h != intro.clone().into())
-            .cloned()
+            .map(|(name, _tau)| **name)
             .collect();
+        avoid.extend(
+            self.term_free_variables(right.clone().into())
+                .unwrap_or_else(|_e| panic!("{}", DANGLING_HANDLE_ERROR))
+                .iter()
+                .map(|(name, _tau)| **name),
+        );
+
+        let name = fresh(avoid.into_iter());
+
+        let variable = self
+            .term_register_variable(name, element.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let left = self
+            .term_register_application(left, variable.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let right = self
+            .term_register_application(right, variable)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        Ok((element, name, left, right))
     }
 
-    pub fn theorem_register_implication_elimination<T, U>(
+    /// Applies the binary set constant pointed-to by `operator`, instantiated
+    /// at the element type `element`, to the sets pointed-to by `left` and
+    /// `right`.  The operands must already have been checked to be sets with
+    /// elements of type `element`.
+    fn set_operator_application<T, U>(
         &mut self,
+        operator: Handle<tags::Term>,
+        element: Handle<tags::Type>,
         left: T,
         right: U,
-    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    ) -> Handle<tags::Term>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
     {
-        let left = self
-            .resolve_theorem_handle(left)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-
-        let (hyp, conc) = self
-            .term_split_implication(left.conclusion())
-            .map_err(|_e| ErrorCode::ShapeMismatch)?;
-
-        if hyp != right.conclusion() {
-            return Err(ErrorCode::ShapeMismatch);
-        }
-
-        // Appease the borrow-checker gods...
-        let conc = conc.clone();
-
-        let mut premisses = left.premisses().clone();
-        premisses.append(&mut right.premisses().clone());
-        premisses.sort();
-        premisses.dedup();
+        let spec = self
+            .term_type_substitute(operator, vec![(0_u64, element)])
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let inner = self
+            .term_register_application(spec, left)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conc)))
+        self.term_register_application(inner, right)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR)
     }
 
-    pub fn theorem_register_substitute<T, U, V>(
+    /// Registers a new theorem object, `Γ ⊢ λx:τ. r = λx:τ. s` in the kernel's
+    /// theorem-table iff `handle` points-to the theorem `Γ ⊢ r = s` in the
+    /// kernel's theorem-table, and `tau` points-to a type in the kernel's
+    /// type-table.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the theorem
+    /// pointed-to by `handle` in the runtime state's theorem-table is not an
+    /// equality between terms.
+    pub fn theorem_register_lambda<T, U, V>(
         &mut self,
-        handle: T,
-        sigma: Vec<((Name, U), V)>,
+        name: T,
+        tau: U,
+        handle: V,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
+        T: Into<Name> + Clone,
         U: Into<Handle<tags::Type>> + Clone,
-        V: Into<Handle<tags::Term>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Name(name.clone().into()),
+            ProofArgument::Type(tau.clone().into()),
+            ProofArgument::Theorem(handle.borrow().clone()),
+        ];
 
-        let conclusion =
-            self.substitution(thm.conclusion().clone(), sigma.clone())?;
-        let mut premisses = vec![];
+        self.resolve_type_handle(tau.clone().into())?;
 
-        for h in thm.premisses().iter().cloned() {
-            premisses.push(self.substitution(h, sigma.clone())?);
-        }
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        let (left, right) = self.term_split_equality(thm.conclusion())?;
+
+        // Appease the borrow-checker gods...
+        let left = left.clone();
+        let right = right.clone();
+
+        // NB: none of these can fail as we obtained the terms from the
+        // conclusion of a theorem, which is an equality, and we therefore know
+        // that everything is well-typed, and that both side of the equality
+        // have the same type.
+        let lhandle = self
+            .term_register_lambda(name.clone(), tau.clone(), left)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rhandle = self
+            .term_register_lambda(name, tau, right)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let conclusion = self
+            .term_register_equality(lhandle, rhandle)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Lambda,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
     }
 
-    pub fn theorem_register_type_substitute<T, U>(
+    /// Registers a new theorem object, `{} ⊢ (λx:τ. r)t = r[x:τ ↦ t]` in the
+    /// kernel's theorem-table iff `application` points-to the term `(λx:τ. r)t`
+    /// in the kernel's term-table.  Returns `Ok(handle)` if this process is
+    /// successful, where `handle` is the newly-allocated handle pointing-to the
+    /// new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `application` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnApplication)` if `application` does not
+    /// point-to a term application in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotALambda)` if `application` does not
+    /// point-to a term application between a λ-abstraction and an argument.
+    pub fn theorem_register_beta<T>(
         &mut self,
-        handle: T,
-        sigma: Vec<(Name, U)>,
+        application: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Type>> + Clone + Debug,
+        T: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![ProofArgument::Term(application.clone().into())];
 
-        let conclusion =
-            self.term_type_substitute(thm.conclusion().clone(), sigma.clone())?;
-        let mut premisses = Vec::new();
+        self.validate_proof_arguments(&arguments)?;
 
-        for h in thm.premisses().iter().cloned() {
-            premisses.push(self.term_type_substitute(h, sigma.clone())?);
-        }
+        let (lhs, rhs) =
+            self.term_split_application(application.clone().into())?;
+
+        let (name, _type, body) = self.term_split_lambda(lhs)?;
+
+        // Appease the borrow-checker gods...
+        let body = body.clone();
+        let name = *name;
+        let _type = _type.clone();
+        let rhs = rhs.clone();
+
+        // NB: these should never fail as `body` originates from a well-typed
+        // term obtained from the kernel's term-table.  Moreover, a meta-theorem
+        // of HOL asserts that the construction of the equality, below, is
+        // well-typed.
+        let subst = self
+            .substitution(body, vec![((name, _type), rhs)])
+            .expect(DANGLING_HANDLE_ERROR);
+        let conclusion = self
+            .term_register_equality(application, subst)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Beta,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_negation_introduction<T, U>(
+    /// Registers a new theorem object, `{} ⊢ λx:τ. f x = f` in the
+    /// kernel's theorem-table iff `lambda` points-to the term `λx:τ. f x`
+    /// in the kernel's term-table and `x ∉ fv f`.  Returns `Ok(handle)` if this
+    /// process is successful, where `handle` is the newly-allocated handle
+    /// pointing-to the new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `lambda` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotALambda)` if `lambda` does not
+    /// point-to a λ-abstraction in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnApplication)` if `lambda` does not
+    /// point-to a λ-abstraction with a term application as a body, in the
+    /// runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `lambda` does not point-to
+    /// λ-abstraction with a term application between an argument term and the
+    /// bound variable in the runtime state's term-table.  Alternatively, if
+    /// the bound variable appears in the free-variables of the functional term.
+    pub fn theorem_register_eta<T>(
         &mut self,
-        thm: T,
-        trm: U,
+        lambda: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Term>>,
+        T: Into<Handle<tags::Term>> + Clone,
     {
-        let thm = thm.borrow();
-        let trm = trm.into();
+        let arguments = vec![ProofArgument::Term(lambda.clone().into())];
 
-        let thm = self
-            .resolve_theorem_handle(thm)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        self.validate_proof_arguments(&arguments)?;
 
-        if !self.term_type_is_proposition(&trm)? {
-            return Err(ErrorCode::NotAProposition);
+        let (name0, _type, body) =
+            self.term_split_lambda(lambda.clone().into())?;
+        let (func, var) = self.term_split_application(body)?;
+        let (name1, _type) = self.term_split_variable(var)?;
+
+        if name0 != name1 {
+            return Err(ErrorCode::ShapeMismatch);
         }
 
-        if !thm.premisses().contains(&trm) {
+        // NB: these steps should never fail, as we should know by this point
+        // that `func` does not dangle (due to it originating from deep inside
+        // a registered term) and also that the type of the resulting lambda is
+        // correct.
+        if self
+            .term_free_variables(func)
+            .expect(DANGLING_HANDLE_ERROR)
+            .contains(&(name1, _type))
+        {
             return Err(ErrorCode::ShapeMismatch);
         }
 
-        let premisses = thm
-            .premisses()
-            .iter()
-            .filter(|e| *e != &trm)
-            .cloned()
-            .collect();
+        // Appease the borrow-checker gods...
+        let func = func.clone();
+
         let conclusion = self
-            .term_register_negation(trm)
+            .term_register_equality(lambda, func)
             .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::Eta,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_negation_elimination<T, U>(
+    /// Registers a new theorem object, `Γ ∪ Δ ⊢ ɸ = ψ` in the kernel's
+    /// theorem-table iff `left` points-to the theorem `Γ ⊢ ɸ ⟶ ψ` and `right`
+    /// points-to the theorem `Δ ⊢ ψ ⟶ ɸ` in the kernel's theorem-table.
+    /// Returns `Ok(handle)` if this process is successful, where `handle` is
+    /// the newly-allocated handle pointing-to the new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `left` or `right` do
+    /// not point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnImplication)` if `left` or `right` are not
+    /// implicational theorems registered in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `left` and `right` are not
+    /// implications between the same two terms (up-to ⍺-equivalence).
+    pub fn theorem_register_iff_introduction<T, U>(
         &mut self,
-        left_handle: T,
-        right_handle: U,
+        left: T,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
         U: Borrow<Handle<tags::Theorem>>,
     {
-        let left = self
-            .resolve_theorem_handle(left_handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
-        let right = self
-            .resolve_theorem_handle(right_handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?
-            .clone();
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
 
-        let right_concl = self
-            .term_split_negation(right.conclusion())
-            .map_err(|_| ErrorCode::ShapeMismatch)?;
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
 
-        if left.conclusion() == right_concl {
-            return Err(ErrorCode::ShapeMismatch);
+        let (left0, right0) = self.term_split_implication(left.conclusion())?;
+        let (left1, right1) =
+            self.term_split_implication(right.conclusion())?;
+
+        if left0 != right1 {
+            return Err(self.shape_mismatch_terms(
+                "the antecedent of the first implication is not the \
+                 consequent of the second.",
+                left0,
+                right1,
+            ));
+        }
+
+        if left1 != right0 {
+            return Err(self.shape_mismatch_terms(
+                "the antecedent of the second implication is not the \
+                 consequent of the first.",
+                left1,
+                right0,
+            ));
         }
 
         let mut premisses = left.premisses().clone();
@@ -3497,598 +6514,7812 @@ impl RuntimeState {
         premisses.sort();
         premisses.dedup();
 
-        Ok(self.admit_theorem(Theorem::new(
-            premisses,
-            PREALLOCATED_HANDLE_TERM_FALSE,
-        )))
+        // Appease the borrow-checker gods...
+        let left0 = left0.clone();
+        let right0 = right0.clone();
+
+        // NB: this shouldn't fail as we know that everything is now a proposition.
+        let conclusion = self
+            .term_register_equality(left0, right0)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        self.admit_theorem(
+            Rule::IffIntroduction,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_forall_elimination<T, U>(
+    /// Registers a new theorem object, `Γ ⊢ ɸ ⟶ ψ` in the kernel's
+    /// theorem-table iff `handle` points-to the theorem `Γ ⊢ ɸ = ψ` in the
+    /// kernel's theorem-table.  Returns `Ok(handle)` if this process is
+    /// successful, where `handle` is the newly-allocated handle pointing-to the
+    /// new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does
+    /// not point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if `handle` does not point-to an
+    /// equational theorem registered in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `handle` does not point-to
+    /// an equational theorem between formulae, registered in the runtime
+    /// state's theorem-table.
+    pub fn theorem_register_iff_left_elimination<T>(
         &mut self,
         handle: T,
-        trm: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Term>>,
     {
-        let thm = self
-            .resolve_theorem_handle(handle)
-            .ok_or(ErrorCode::NoSuchTheoremRegistered)?;
-        let trm = trm.into();
-        let thm = thm.clone();
+        let arguments = vec![ProofArgument::Theorem(handle.borrow().clone())];
 
-        let typ = self.term_type_infer(&trm)?;
-        let (name, tau, body) = self
-            .term_split_forall(thm.conclusion())
-            .map_err(|_| ErrorCode::ShapeMismatch)?;
+        let thm = self.resolve_theorem_handle(handle)?.clone();
 
-        if tau != &typ {
-            return Err(ErrorCode::DomainTypeMismatch);
-        }
+        let (left, right) = self.term_split_equality(thm.conclusion())?;
 
         // Appease the borrow-checker gods...
-        let body = body.clone();
-        let name = *name;
-        let trm = trm.clone();
-        let premisses = thm.premisses().clone();
+        let left = left.clone();
+        let right = right.clone();
 
-        /* NB: this should never fail, as everything has either been checked at
-         * this point, or derives from a pre-existing kernel object which should
-         * not contain dangling handles.
-         */
+        if !self
+            .term_type_is_proposition(left.clone())
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        // NB: this should never fail as we've already checked that the equality
+        // is an equality between two formulae.
         let conclusion = self
-            .substitution(body, vec![((name, typ), trm)])
-            .expect(DANGLING_HANDLE_ERROR);
-        let premisses = thm.premisses().clone();
+            .term_register_implication(left, right)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        self.admit_theorem(
+            Rule::IffLeftElimination,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
+    }
+
+    /// Registers a new theorem object, `{} ⊢ True` in the kernel's
+    /// theorem-table.  Returns `Ok(handle)` where `handle` is the
+    /// newly-allocated handle pointing-to the new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Does not error: returns `Result<Handle<tags::Theorem>, ErrorCode>` for
+    /// uniformity with other axioms/rules.
+    pub fn theorem_register_truth_introduction(
+        &mut self,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let arguments = vec![];
+
+        let identity: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+
+        // NB: this should never fail if the initial theory is properly
+        // initialized.
+        let conclusion = self
+            .term_register_constant(PREALLOCATED_HANDLE_CONSTANT_TRUE, identity)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
 
-        Ok(self.admit_theorem(Theorem::new(premisses, conclusion)))
+        self.admit_theorem(
+            Rule::TruthIntroduction,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_forall_introduction<T, U>(
+    /// Registers a new theorem object, `Γ ⊢ ɸ` in the kernel's theorem-table
+    /// iff `thm` points-to the theorem `Γ ⊢ False` in the kernel's
+    /// theorem-table and `conclusion` points-to the formula `ɸ` in the kernel's
+    /// term-table.  Returns `Ok(handle)` if this process is successful,
+    /// where `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `thm` does
+    /// not point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `conclusion` does
+    /// not point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `conclusion` does not
+    /// point-to a formula, registered in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `thm` does not point-to a
+    /// proof of falsity.
+    pub fn theorem_register_falsity_elimination<T, U>(
         &mut self,
-        _handle: T,
-        _name: U,
+        thm: T,
+        conclusion: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Name>,
+        U: Into<Handle<tags::Term>> + Clone,
     {
-        unimplemented!()
+        let arguments = vec![
+            ProofArgument::Theorem(thm.borrow().clone()),
+            ProofArgument::Term(conclusion.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let conclusion = conclusion.into();
+        let thm = thm.borrow();
+
+        let thm = self.resolve_theorem_handle(thm)?.clone();
+
+        self.resolve_term_handle(&conclusion)?;
+
+        if !self
+            .term_type_is_proposition(&conclusion)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        if self.is_false(thm.conclusion()) != Ok(true) {
+            return Err(self.shape_mismatch_terms(
+                "the conclusion of the theorem is not falsity.",
+                &PREALLOCATED_HANDLE_TERM_FALSE,
+                thm.conclusion(),
+            ));
+        }
+
+        self.admit_theorem(
+            Rule::FalsityElimination,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
     }
 
-    pub fn theorem_register_exists_introduction<T, U>(
+    pub fn theorem_register_conjunction_introduction<T, U>(
         &mut self,
-        _handle: T,
-        _trm: U,
+        left: T,
+        right: U,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Theorem>>,
     {
-        unimplemented!()
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
+
+        let conclusion = self.term_register_conjunction(
+            left.conclusion().clone(),
+            right.conclusion().clone(),
+        )?;
+
+        let mut premisses = left.premisses().clone();
+        premisses.append(&mut right.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
+
+        self.admit_theorem(
+            Rule::ConjunctionIntroduction,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
     }
 
-    pub fn theorem_register_exists_elimination<T, U>(
+    pub fn theorem_register_conjunction_left_elimination<T>(
         &mut self,
-        _left_handle: T,
-        _right_handle: U,
+        handle: T,
     ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
     {
-        unimplemented!()
-    }
+        let arguments = vec![ProofArgument::Theorem(handle.borrow().clone())];
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Modifying the global theory.
-    ////////////////////////////////////////////////////////////////////////////
+        let thm = self.resolve_theorem_handle(handle)?.clone();
 
-    pub fn register_new_definition<T>(
+        let (left, _right) = self
+            .term_split_conjunction(thm.conclusion())
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+
+        // Appease the borrow-checker gods...
+        let left = left.clone();
+
+        self.admit_theorem(
+            Rule::ConjunctionLeftElimination,
+            arguments,
+            Theorem::new(thm.premisses().clone(), left),
+        )
+    }
+
+    pub fn theorem_register_conjunction_right_elimination<T>(
         &mut self,
-        defn: T,
-    ) -> Result<(Handle<tags::Term>, Handle<tags::Theorem>), ErrorCode>
+        handle: T,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
     where
-        T: Into<Handle<tags::Term>> + Clone,
+        T: Borrow<Handle<tags::Theorem>>,
     {
-        /* 1. Check the body of the definition exists, and it has a type. */
-        let tau = self.term_type_infer(defn.clone().into())?;
+        let arguments = vec![ProofArgument::Theorem(handle.borrow().clone())];
 
-        /* 2. Add the new constant, giving it the type inferred previously. */
-        let cnst_handle = self.issue_handle();
-        self.constants.insert(cnst_handle.clone(), tau);
+        let thm = self.resolve_theorem_handle(handle)?.clone();
 
-        let empty: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+        let (_left, right) = self
+            .term_split_conjunction(thm.conclusion())
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
 
-        /* 3. Lift the registered constant into a term. */
-        let cnst = self
-            .term_register_constant(cnst_handle, empty)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        // Appease the borrow-checker gods...
+        let conclusion = right.clone();
 
-        /* 4. Construct the definitional theorem. */
-        let stmt = self
-            .term_register_equality(cnst.clone(), defn)
-            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        self.admit_theorem(
+            Rule::ConjunctionRightElimination,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
+    }
 
-        /* 5. Register the definitional theorem. */
-        let empty: Vec<Handle<tags::Term>> = Vec::new();
-        let thm = self.admit_theorem(Theorem::new(empty, stmt));
+    pub fn theorem_register_disjunction_left_introduction<T, U>(
+        &mut self,
+        handle: T,
+        term: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(handle.borrow().clone()),
+            ProofArgument::Term(term.clone().into()),
+        ];
 
-        /* 6. Return the handle to the new constant and definitional theorem. */
+        self.validate_proof_arguments(&arguments)?;
 
-        Ok((cnst, thm))
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        if !self.term_type_is_proposition(term.clone().into())? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        let conclusion =
+            self.term_register_disjunction(thm.conclusion().clone(), term)?;
+
+        self.admit_theorem(
+            Rule::DisjunctionLeftIntroduction,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
     }
-}
 
-/// Creates a default, new instance of the `RuntimeState` containing empty
-/// heaps/tables of kernel objects and seeding the next handle counter
-/// appropriately.
-impl Default for RuntimeState {
-    /// Creates a new, default runtime state with all primitive kernel objects
-    /// registered and the next handle counter suitably initialized.
-    fn default() -> RuntimeState {
-        let type_formers = HashMap::from_iter(vec![
-            (PREALLOCATED_HANDLE_TYPE_FORMER_PROP, 0),
-            (PREALLOCATED_HANDLE_TYPE_FORMER_ARROW, 2),
-        ]);
+    pub fn theorem_register_disjunction_right_introduction<T, U>(
+        &mut self,
+        handle: T,
+        term: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(handle.borrow().clone()),
+            ProofArgument::Term(term.clone().into()),
+        ];
 
-        let types = HashMap::from_iter(vec![
-            (PREALLOCATED_HANDLE_TYPE_ALPHA, TYPE_ALPHA.clone()),
-            (PREALLOCATED_HANDLE_TYPE_BETA, TYPE_BETA.clone()),
-            (PREALLOCATED_HANDLE_TYPE_PROP, TYPE_PROP.clone()),
-            (
-                PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
-                TYPE_UNARY_CONNECTIVE.clone(),
-            ),
-            (
-                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
-                TYPE_BINARY_CONNECTIVE.clone(),
-            ),
-            (
-                PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
-                TYPE_POLYMORPHIC_UNARY_PREDICATE.clone(),
-            ),
-            (
-                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
-                TYPE_POLYMORPHIC_BINARY_PREDICATE.clone(),
-            ),
-            (
-                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
-                TYPE_POLYMORPHIC_QUANTIFIER.clone(),
-            ),
-        ]);
+        self.validate_proof_arguments(&arguments)?;
 
-        let constants = HashMap::from_iter(vec![
-            (
-                PREALLOCATED_HANDLE_CONSTANT_TRUE,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-            ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_FALSE,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-            ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_NEGATION,
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        if !self.term_type_is_proposition(term.clone().into())? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        let conclusion =
+            self.term_register_disjunction(term, thm.conclusion().clone())?;
+
+        self.admit_theorem(
+            Rule::DisjunctionRightIntroduction,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
+    }
+
+    pub fn theorem_register_disjunction_elimination<T, U, V>(
+        &mut self,
+        left: T,
+        mid: U,
+        right: V,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(mid.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let mid = self.resolve_theorem_handle(mid)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
+
+        let (phi, psi) = self.term_split_disjunction(left.conclusion())?;
+
+        if mid.conclusion() != right.conclusion() {
+            return Err(self.shape_mismatch_terms(
+                "the conclusions of the second and third theorems differ.",
+                mid.conclusion(),
+                right.conclusion(),
+            ));
+        }
+
+        if !mid.premisses().contains(phi) {
+            return Err(self.shape_mismatch_missing_premiss(
+                "the left disjunct is not a premiss of the second theorem.",
+                phi,
+                mid.premisses(),
+            ));
+        }
+
+        if !right.premisses().contains(psi) {
+            return Err(self.shape_mismatch_missing_premiss(
+                "the right disjunct is not a premiss of the third theorem.",
+                psi,
+                right.premisses(),
+            ));
+        }
+
+        let mid_premisses = mid
+            .premisses()
+            .iter()
+            .filter(|h| *h != phi)
+            .cloned()
+            .collect::<Vec<_>>();
+        let right_premisses = right
+            .premisses()
+            .iter()
+            .filter(|h| *h != psi)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if left.premisses() != &mid_premisses {
+            return Err(self.shape_mismatch_premisses(
+                "the premisses of the second theorem, less the left \
+                 disjunct, are not those of the first.",
+                left.premisses(),
+                &mid_premisses,
+            ));
+        }
+
+        if left.premisses() != &right_premisses {
+            return Err(self.shape_mismatch_premisses(
+                "the premisses of the third theorem, less the right \
+                 disjunct, are not those of the first.",
+                left.premisses(),
+                &right_premisses,
+            ));
+        }
+
+        self.admit_theorem(
+            Rule::DisjunctionElimination,
+            arguments,
+            Theorem::new(left.premisses().clone(), right.conclusion().clone()),
+        )
+    }
+
+    pub fn theorem_register_implication_introduction<T, U>(
+        &mut self,
+        handle: T,
+        intro: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(handle.borrow().clone()),
+            ProofArgument::Term(intro.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        if !self.term_type_is_proposition(intro.clone().into())? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        if !thm.premisses().contains(&intro.clone().into()) {
+            return Err(self.shape_mismatch_missing_premiss(
+                "the proposition to discharge is not a premiss of the \
+                 theorem.",
+                &intro.into(),
+                thm.premisses(),
+            ));
+        }
+
+        let conclusion = self.term_register_implication(
+            intro.clone(),
+            thm.conclusion().clone(),
+        )?;
+        let premisses = thm
+            .premisses()
+            .iter()
+            .filter(|h| **h != intro.clone().into())
+            .cloned()
+            .collect();
+
+        self.admit_theorem(
+            Rule::ImplicationIntroduction,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    /// Applies modus ponens to the theorems pointed-to by `left`, concluding an
+    /// implication `p ⇒ q`, and `right`, concluding `p`, registering a theorem
+    /// concluding `q` whose premisses are those of both.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if either `left` or
+    /// `right` does not point-to a registered theorem.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the conclusion of the theorem
+    /// pointed-to by `left` is not an implication, or its antecedent is not
+    /// the conclusion of the theorem pointed-to by `right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kernel::{
+    ///     handle::PREALLOCATED_HANDLE_TYPE_PROP, runtime_state::RuntimeState,
+    /// };
+    ///
+    /// let mut state = RuntimeState::new();
+    ///
+    /// let p = state
+    ///     .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+    ///     .unwrap();
+    /// let q = state
+    ///     .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+    ///     .unwrap();
+    /// let p_implies_q =
+    ///     state.term_register_implication(p.clone(), q.clone()).unwrap();
+    ///
+    /// let major = state.theorem_register_assumption(p_implies_q).unwrap();
+    /// let minor = state.theorem_register_assumption(p).unwrap();
+    /// let thm = state
+    ///     .theorem_register_implication_elimination(&major, &minor)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(state.theorem_split_conclusion(&thm), Ok(q));
+    /// assert_eq!(state.theorem_split_premisses(&thm).unwrap().len(), 2);
+    /// ```
+    pub fn theorem_register_implication_elimination<T, U>(
+        &mut self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(left.borrow().clone()),
+            ProofArgument::Theorem(right.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left)?.clone();
+        let right = self.resolve_theorem_handle(right)?.clone();
+
+        let (hyp, conc) = self
+            .term_split_implication(left.conclusion())
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+
+        if hyp != right.conclusion() {
+            return Err(self.shape_mismatch_terms(
+                "the antecedent of the implication is not the conclusion of \
+                 the second theorem.",
+                hyp,
+                right.conclusion(),
+            ));
+        }
+
+        // Appease the borrow-checker gods...
+        let conc = conc.clone();
+
+        let mut premisses = left.premisses().clone();
+        premisses.append(&mut right.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
+
+        self.admit_theorem(
+            Rule::ImplicationElimination,
+            arguments,
+            Theorem::new(premisses, conc),
+        )
+    }
+
+    pub fn theorem_register_substitute<T, U, V>(
+        &mut self,
+        handle: T,
+        sigma: Vec<((Name, U), V)>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        let mut arguments =
+            vec![ProofArgument::Theorem(handle.borrow().clone())];
+
+        for ((name, tau), trm) in sigma.iter() {
+            arguments.push(ProofArgument::Name(*name));
+            arguments.push(ProofArgument::Type(tau.clone().into()));
+            arguments.push(ProofArgument::Term(trm.clone().into()));
+        }
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        let conclusion =
+            self.substitution(thm.conclusion().clone(), sigma.clone())?;
+        let mut premisses = vec![];
+
+        for h in thm.premisses().iter().cloned() {
+            premisses.push(self.substitution(h, sigma.clone())?);
+        }
+
+        self.admit_theorem(
+            Rule::Substitute,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    pub fn theorem_register_type_substitute<T, U>(
+        &mut self,
+        handle: T,
+        sigma: Vec<(Name, U)>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        let mut arguments =
+            vec![ProofArgument::Theorem(handle.borrow().clone())];
+
+        for (name, tau) in sigma.iter() {
+            arguments.push(ProofArgument::Name(*name));
+            arguments.push(ProofArgument::Type(tau.clone().into()));
+        }
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        let conclusion =
+            self.term_type_substitute(thm.conclusion().clone(), sigma.clone())?;
+        let mut premisses = Vec::new();
+
+        for h in thm.premisses().iter().cloned() {
+            premisses.push(self.term_type_substitute(h, sigma.clone())?);
+        }
+
+        self.admit_theorem(
+            Rule::TypeSubstitute,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    pub fn theorem_register_negation_introduction<T, U>(
+        &mut self,
+        thm: T,
+        trm: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(thm.borrow().clone()),
+            ProofArgument::Term(trm.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let thm = thm.borrow();
+        let trm = trm.into();
+
+        let thm = self.resolve_theorem_handle(thm)?.clone();
+
+        if !self.term_type_is_proposition(&trm)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        if !thm.premisses().contains(&trm) {
+            return Err(self.shape_mismatch_missing_premiss(
+                "the proposition to discharge is not a premiss of the \
+                 theorem.",
+                &trm,
+                thm.premisses(),
+            ));
+        }
+
+        if self.is_false(thm.conclusion()) != Ok(true) {
+            return Err(self.shape_mismatch_terms(
+                "the conclusion of the theorem is not falsity.",
+                &PREALLOCATED_HANDLE_TERM_FALSE,
+                thm.conclusion(),
+            ));
+        }
+
+        let premisses = thm
+            .premisses()
+            .iter()
+            .filter(|e| *e != &trm)
+            .cloned()
+            .collect();
+        let conclusion = self
+            .term_register_negation(trm)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        self.admit_theorem(
+            Rule::NegationIntroduction,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    pub fn theorem_register_negation_elimination<T, U>(
+        &mut self,
+        left_handle: T,
+        right_handle: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(left_handle.borrow().clone()),
+            ProofArgument::Theorem(right_handle.borrow().clone()),
+        ];
+
+        let left = self.resolve_theorem_handle(left_handle)?.clone();
+        let right = self.resolve_theorem_handle(right_handle)?.clone();
+
+        let right_concl = self
+            .term_split_negation(right.conclusion())
+            .map_err(|_| ErrorCode::ShapeMismatch)?;
+
+        if left.conclusion() != right_concl {
+            return Err(self.shape_mismatch_terms(
+                "the conclusion of the first theorem is not the negated \
+                 proposition of the second.",
+                right_concl,
+                left.conclusion(),
+            ));
+        }
+
+        let mut premisses = left.premisses().clone();
+        premisses.append(&mut right.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
+
+        self.admit_theorem(
+            Rule::NegationElimination,
+            arguments,
+            Theorem::new(premisses, PREALLOCATED_HANDLE_TERM_FALSE),
+        )
+    }
+
+    pub fn theorem_register_forall_elimination<T, U>(
+        &mut self,
+        handle: T,
+        trm: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(handle.borrow().clone()),
+            ProofArgument::Term(trm.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let thm = self.resolve_theorem_handle(handle)?;
+        let trm = trm.into();
+        let thm = thm.clone();
+
+        let typ = self.term_type_infer(&trm)?;
+        let (name, tau, body) = self
+            .term_split_forall(thm.conclusion())
+            .map_err(|_| ErrorCode::ShapeMismatch)?;
+
+        if tau != &typ {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        // Appease the borrow-checker gods...
+        let body = body.clone();
+        let name = *name;
+        let trm = trm.clone();
+
+        /* NB: this should never fail, as everything has either been checked at
+         * this point, or derives from a pre-existing kernel object which should
+         * not contain dangling handles.
+         */
+        let conclusion = self
+            .substitution(body, vec![((name, typ), trm)])
+            .expect(DANGLING_HANDLE_ERROR);
+        let premisses = thm.premisses().clone();
+
+        self.admit_theorem(
+            Rule::ForallElimination,
+            arguments,
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    /// Registers a new theorem object, `Γ ⊢ ∀x:τ. ɸ` in the kernel's
+    /// theorem-table iff `handle` points-to the theorem `Γ ⊢ ɸ` in the kernel's
+    /// theorem-table, and the variable `x:τ`, named `name`, does not appear
+    /// free in any premiss, `Γ`.  Returns `Ok(handle)` if this process is
+    /// successful, where `handle` is the newly-allocated handle pointing-to the
+    /// new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the variable `x:τ` appears
+    /// free in some premiss of the theorem pointed-to by `handle`.
+    pub fn theorem_register_forall_introduction<T, U, V>(
+        &mut self,
+        name: T,
+        tau: U,
+        handle: V,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        let arguments = vec![
+            ProofArgument::Name(name.clone().into()),
+            ProofArgument::Type(tau.clone().into()),
+            ProofArgument::Theorem(handle.borrow().clone()),
+        ];
+
+        self.resolve_type_handle(tau.clone().into())?;
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+
+        let name = name.into();
+        let tau = tau.into();
+
+        for premiss in thm.premisses().iter() {
+            if self
+                .term_free_variables(premiss)
+                .expect(DANGLING_HANDLE_ERROR)
+                .contains(&(&name, &tau))
+            {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+        }
+
+        /* NB: this cannot fail, as the conclusion of a theorem is always a
+         * proposition.
+         */
+        let conclusion = self
+            .term_register_forall(name, tau, thm.conclusion().clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        self.admit_theorem(
+            Rule::ForallIntroduction,
+            arguments,
+            Theorem::new(thm.premisses().clone(), conclusion),
+        )
+    }
+
+    /// Registers a new theorem object, `Γ ⊢ ∃x:τ. ɸ`, in the kernel's
+    /// theorem-table iff `trm` points-to the existential `∃x:τ. ɸ`, `witness`
+    /// points-to a term `t` of type `τ`, and `handle` points-to the theorem
+    /// `Γ ⊢ ɸ[t/x]` in the kernel's theorem-table.  Returns `Ok(handle)` if
+    /// this process is successful, where `handle` is the newly-allocated handle
+    /// pointing-to the new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `trm` or `witness`
+    /// does not point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `trm` is not an existential,
+    /// or if the conclusion of the theorem pointed-to by `handle` is not the
+    /// body of the existential with the witness substituted for the bound
+    /// variable.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the type of the witness
+    /// is not the type of the bound variable.
+    pub fn theorem_register_exists_introduction<T, U, V>(
+        &mut self,
+        handle: T,
+        trm: U,
+        witness: V,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(handle.borrow().clone()),
+            ProofArgument::Term(trm.clone().into()),
+            ProofArgument::Term(witness.clone().into()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let thm = self.resolve_theorem_handle(handle)?.clone();
+        let trm = trm.into();
+        let witness = witness.into();
+
+        let (name, tau, body) = self
+            .term_split_exists(&trm)
+            .map_err(|_| ErrorCode::ShapeMismatch)?;
+
+        // Appease the borrow-checker gods...
+        let (name, tau, body) = (*name, tau.clone(), body.clone());
+
+        if self.term_type_infer(&witness)? != tau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        /* NB: this should never fail, as everything has either been checked at
+         * this point, or derives from a pre-existing kernel object which should
+         * not contain dangling handles.
+         */
+        let instance = self
+            .substitution(body, vec![((name, tau), witness)])
+            .expect(DANGLING_HANDLE_ERROR);
+
+        if thm.conclusion() != &instance {
+            return Err(self.shape_mismatch_terms(
+                "the conclusion of the theorem is not the body of the \
+                 existential instantiated at the witness.",
+                &instance,
+                thm.conclusion(),
+            ));
+        }
+
+        self.admit_theorem(
+            Rule::ExistsIntroduction,
+            arguments,
+            Theorem::new(thm.premisses().clone(), trm),
+        )
+    }
+
+    /// Registers a new theorem object, `Γ ∪ Δ ⊢ ψ`, in the kernel's
+    /// theorem-table iff `left_handle` points-to the theorem `Γ ⊢ ∃x:τ. ɸ`,
+    /// `variable` points-to a variable `v` of type `τ`, and `right_handle`
+    /// points-to the theorem `Δ ∪ {ɸ[v/x]} ⊢ ψ` in the kernel's
+    /// theorem-table, and `v` appears free in neither `∃x:τ. ɸ`, nor `ψ`, nor
+    /// any premiss in `Γ` or `Δ`.  Returns `Ok(handle)` if this process is
+    /// successful, where `handle` is the newly-allocated handle pointing-to the
+    /// new theorem object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `left_handle` or
+    /// `right_handle` does not point-to a registered theorem in the runtime
+    /// state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `variable` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the conclusion of the theorem
+    /// pointed-to by `left_handle` is not an existential, if `variable` is not
+    /// a variable, if `ɸ[v/x]` is not a premiss of the theorem pointed-to by
+    /// `right_handle`, or if `v` appears free where it must not.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if the type of `v` is not
+    /// the type of the bound variable.
+    pub fn theorem_register_exists_elimination<T, U, V>(
+        &mut self,
+        left_handle: T,
+        variable: U,
+        right_handle: V,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        let arguments = vec![
+            ProofArgument::Theorem(left_handle.borrow().clone()),
+            ProofArgument::Term(variable.clone().into()),
+            ProofArgument::Theorem(right_handle.borrow().clone()),
+        ];
+
+        self.validate_proof_arguments(&arguments)?;
+
+        let left = self.resolve_theorem_handle(left_handle)?.clone();
+        let right = self.resolve_theorem_handle(right_handle)?.clone();
+        let variable = variable.into();
+
+        let (name, tau, body) = self
+            .term_split_exists(left.conclusion())
+            .map_err(|_| ErrorCode::ShapeMismatch)?;
+        let (name, tau, body) = (*name, tau.clone(), body.clone());
+
+        let (v, sigma) = self
+            .term_split_variable(&variable)
+            .map_err(|_| ErrorCode::ShapeMismatch)?;
+        let (v, sigma) = (*v, sigma.clone());
+
+        if sigma != tau {
+            return Err(ErrorCode::DomainTypeMismatch);
+        }
+
+        /* NB: as above, this should never fail. */
+        let instance = self
+            .substitution(body, vec![((name, tau), variable)])
+            .expect(DANGLING_HANDLE_ERROR);
+
+        if !right.premisses().contains(&instance) {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let mut premisses: Vec<Handle<tags::Term>> = right
+            .premisses()
+            .iter()
+            .filter(|premiss| *premiss != &instance)
+            .cloned()
+            .collect();
+
+        /* NB: the eigenvariable condition. */
+        for trm in premisses
+            .iter()
+            .chain(left.premisses().iter())
+            .chain([left.conclusion(), right.conclusion()])
+        {
+            if self
+                .term_free_variables(trm)
+                .expect(DANGLING_HANDLE_ERROR)
+                .contains(&(&v, &sigma))
+            {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+        }
+
+        premisses.append(&mut left.premisses().clone());
+        premisses.sort();
+        premisses.dedup();
+
+        self.admit_theorem(
+            Rule::ExistsElimination,
+            arguments,
+            Theorem::new(premisses, right.conclusion().clone()),
+        )
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Hypotheses related material.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Admits a set of hypotheses, `hyps`, into the runtime state's table of
+    /// interned hypotheses, returning its handle along with the interned list
+    /// itself.  If an equal set of hypotheses has already been interned then
+    /// the existing handle and list are returned, otherwise `hyps` itself is
+    /// interned under a fresh handle.
+    ///
+    /// Callers are expected to ensure that `hyps` is sorted and free of
+    /// duplicates before calling this function.
+    fn admit_hypotheses(
+        &mut self,
+        hyps: Arc<Vec<Handle<tags::Term>>>,
+    ) -> (Handle<tags::Hypotheses>, Arc<Vec<Handle<tags::Term>>>) {
+        if let Some((interned, handle)) =
+            self.hypotheses_index.get_key_value(hyps.as_ref())
+        {
+            return (handle.clone(), interned.clone());
+        }
+
+        let fresh = self.issue_handle();
+
+        self.hypotheses.insert(fresh.clone(), hyps.clone());
+        self.hypotheses_index.insert(hyps.clone(), fresh.clone());
+
+        (fresh, hyps)
+    }
+
+    /// Returns `Ok(hyps)` iff `handle` points-to an interned set of
+    /// hypotheses in the runtime state's table of hypotheses, and otherwise
+    /// the error to report for `handle`.
+    #[inline]
+    fn resolve_hypotheses_handle<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Vec<Handle<tags::Term>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Hypotheses>>,
+    {
+        self.hypotheses
+            .get(handle.borrow())
+            .map(|h| h.as_ref())
+            .ok_or_else(|| {
+                self.unresolved(
+                    handle.borrow(),
+                    ErrorCode::NoSuchHypothesesRegistered,
+                )
+            })
+    }
+
+    /// Interns the set of hypotheses, `hyps`, in the runtime state's table of
+    /// hypotheses, returning `Ok(handle)` where `handle` points-to the interned
+    /// set.  The order of, and any duplicates in, `hyps` are insignificant:
+    /// two calls with the same set of hypotheses return the same handle, so
+    /// that sets of hypotheses can be compared for equality by comparing their
+    /// handles.  Note that any set of hypotheses already used as the premisses
+    /// of a registered theorem is already interned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if any handle in `hyps`
+    /// does not point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if any handle in `hyps` does
+    /// not point-to a term with propositional type.
+    pub fn hypotheses_intern<T>(
+        &mut self,
+        hyps: Vec<T>,
+    ) -> Result<Handle<tags::Hypotheses>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        let mut hyps: Vec<Handle<tags::Term>> =
+            hyps.iter().cloned().map(|h| h.into()).collect();
+
+        info!("Interning hypotheses with handles {:?}.", hyps);
+
+        for hyp in hyps.iter() {
+            if !self.term_type_is_proposition(hyp)? {
+                return Err(ErrorCode::NotAProposition);
+            }
+        }
+
+        hyps.sort();
+        hyps.dedup();
+
+        Ok(self.admit_hypotheses(Arc::new(hyps)).0)
+    }
+
+    /// Returns `Ok(hyps)` if `handle` points-to an interned set of
+    /// hypotheses, `hyps`, in the runtime state's table of hypotheses.  The
+    /// handles in `hyps` are in ascending sorted order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchHypothesesRegistered)` if `handle` does
+    /// not point-to an interned set of hypotheses in the runtime state's table
+    /// of hypotheses.
+    #[inline]
+    pub fn hypotheses_resolve<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Vec<Handle<tags::Term>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Hypotheses>>,
+    {
+        info!("Resolving hypotheses with handle {}.", handle.borrow());
+
+        self.resolve_hypotheses_handle(handle)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Challenges and capabilities.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Guards the service `service` with the challenge `proposition`, so that
+    /// the service may only be used by presenting a capability minted by
+    /// proving `proposition`.  Replaces any existing challenge for `service`,
+    /// in which case every capability previously minted for `service` is
+    /// revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `proposition` does not
+    /// point-to a registered term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if `proposition` does not
+    /// point-to a term with propositional type.
+    pub fn challenge_register<T>(
+        &mut self,
+        service: Service,
+        proposition: T,
+    ) -> Result<(), ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering challenge for service {} with handle: {}.",
+            service,
+            proposition.clone().into()
+        );
+
+        let proposition = proposition.into();
+
+        if !self.term_type_is_proposition(&proposition)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        if self.challenges.insert(service, proposition).is_some() {
+            for capability in self.capabilities.values_mut() {
+                if capability.service() == service {
+                    capability.revoke();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Ok(proposition)` if the service `service` is guarded by the
+    /// challenge `proposition`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchChallengeRegistered)` if `service` is not
+    /// guarded by any challenge.
+    #[inline]
+    pub fn challenge_resolve(
+        &self,
+        service: Service,
+    ) -> Result<&Handle<tags::Term>, ErrorCode> {
+        info!("Resolving challenge for service {}.", service);
+
+        self.challenges
+            .get(&service)
+            .ok_or(ErrorCode::NoSuchChallengeRegistered)
+    }
+
+    /// Returns every service guarded by a challenge for which no unexpired
+    /// capability is held, i.e. whose challenge remains to be discharged, in
+    /// ascending order.
+    pub fn challenge_open_services(&self) -> Vec<Service> {
+        info!("Finding services with open challenges.");
+
+        let mut services: Vec<Service> = self
+            .challenges
+            .keys()
+            .filter(|service| {
+                !self.capabilities.values().any(|c| {
+                    c.service() == **service && !c.is_expired(self.epoch)
+                })
+            })
+            .cloned()
+            .collect();
+
+        services.sort_unstable();
+        services
+    }
+
+    /// Discharges the challenge guarding the service `service` with the
+    /// theorem pointed-to by `theorem`, minting a new capability for `service`
+    /// with expiry policy `expiry`.  Returns `Ok(handle)`, where `handle` is
+    /// the newly-allocated handle pointing-to the new capability.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchChallengeRegistered)` if `service` is not
+    /// guarded by any challenge.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `theorem` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ChallengeNotDischarged)` if `theorem` has any
+    /// premisses, or if its conclusion is not the challenge proposition (up-to
+    /// ⍺-equivalence).
+    pub fn challenge_discharge<T>(
+        &mut self,
+        service: Service,
+        theorem: T,
+        expiry: Expiry,
+    ) -> Result<Handle<tags::Capability>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Discharging challenge for service {} with theorem: {}.",
+            service,
+            theorem.borrow()
+        );
+
+        let proposition = self.challenge_resolve(service)?.clone();
+        let theorem = self.resolve_theorem_handle(theorem)?.clone();
+
+        if !theorem.premisses().is_empty() {
+            return Err(ErrorCode::ChallengeNotDischarged);
+        }
+
+        // NB: this should never fail, as both terms are already registered.
+        if !self
+            .is_alpha_equivalent(theorem.conclusion(), &proposition)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            return Err(ErrorCode::ChallengeNotDischarged);
+        }
+
+        let fresh = self.issue_handle();
+
+        self.capabilities.insert(
+            fresh.clone(),
+            Capability::new(service, expiry, self.epoch),
+        );
+
+        Ok(fresh)
+    }
+
+    /// Presents the capability pointed-to by `capability` to the service
+    /// `service`, returning `Ok(())` if the capability grants access to the
+    /// service.  Records a use of the capability in this case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchCapabilityRegistered)` if `capability`
+    /// does not point-to a registered capability in the runtime state's
+    /// capability-table.
+    ///
+    /// Returns `Err(ErrorCode::CapabilityServiceMismatch)` if `capability` was
+    /// minted for a service other than `service`.
+    ///
+    /// Returns `Err(ErrorCode::CapabilityExpired)` if the expiry policy of
+    /// `capability` has lapsed, or if it has been revoked.
+    pub fn capability_present<T>(
+        &mut self,
+        capability: T,
+        service: Service,
+    ) -> Result<(), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Capability>>,
+    {
+        info!(
+            "Presenting capability with handle {} to service {}.",
+            capability.borrow(),
+            service
+        );
+
+        let epoch = self.epoch;
+        let unresolved = self.unresolved(
+            capability.borrow(),
+            ErrorCode::NoSuchCapabilityRegistered,
+        );
+        let capability = self
+            .capabilities
+            .get_mut(capability.borrow())
+            .ok_or(unresolved)?;
+
+        if capability.service() != service {
+            return Err(ErrorCode::CapabilityServiceMismatch);
+        }
+
+        if capability.is_expired(epoch) {
+            return Err(ErrorCode::CapabilityExpired);
+        }
+
+        capability.consume();
+
+        Ok(())
+    }
+
+    /// Returns `Ok(capability)` if `handle` points-to the capability,
+    /// `capability`, in the runtime state's capability-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchCapabilityRegistered)` if `handle` does
+    /// not point-to a registered capability in the runtime state's
+    /// capability-table.
+    #[inline]
+    pub fn capability_resolve<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Capability, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Capability>>,
+    {
+        info!("Resolving capability with handle {}.", handle.borrow());
+
+        self.capabilities.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(
+                handle.borrow(),
+                ErrorCode::NoSuchCapabilityRegistered,
+            )
+        })
+    }
+
+    /// Signals that the machine state, from which the host constructs
+    /// challenges, has changed.  Expires every capability minted with the
+    /// `Expiry::UntilStateChange` policy.
+    #[inline]
+    pub fn signal_state_change(&mut self) {
+        info!("Signalling machine state change.");
+
+        self.epoch += 1;
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Proof-term related material.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Enables, or disables, proof recording.  Whilst proof recording is
+    /// enabled, a proof term is recorded for every theorem registered, noting
+    /// the inference rule, and arguments, that derived it.  Proof recording is
+    /// disabled by default.
+    #[inline]
+    pub fn set_proof_recording(&mut self, enabled: bool) -> &mut Self {
+        info!("Setting proof recording to {}.", enabled);
+
+        self.record_proofs = enabled;
+        self
+    }
+
+    /// Returns `true` iff proof recording is enabled.
+    #[inline]
+    pub fn is_recording_proofs(&self) -> bool {
+        self.record_proofs
+    }
+
+    /// Returns `Ok(proof)` iff `handle` points-to a recorded proof term in
+    /// the runtime state's proof-table, and otherwise the error to report for
+    /// `handle`.
+    #[inline]
+    fn resolve_proof_handle<T>(&self, handle: T) -> Result<&Proof, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        self.proofs.get(handle.borrow()).ok_or_else(|| {
+            self.unresolved(handle.borrow(), ErrorCode::NoSuchProofRegistered)
+        })
+    }
+
+    /// Returns `true` iff `handle` points to a recorded proof term in the
+    /// runtime state's proof-table.
+    #[inline]
+    pub fn proof_is_registered<T>(&self, handle: T) -> bool
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        info!(
+            "Checking if proof with handle {} is registered.",
+            handle.borrow()
+        );
+
+        self.resolve_proof_handle(handle).is_ok()
+    }
+
+    /// Returns `Ok(proof)`, where `proof` is a handle to the proof term that
+    /// was recorded for the theorem pointed-to by `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchProofRegistered)` if no proof term was
+    /// recorded for the theorem, as it was registered whilst proof recording
+    /// was disabled.
+    pub fn theorem_proof<T>(
+        &self,
+        handle: T,
+    ) -> Result<Handle<tags::Proof>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Finding proof of theorem with handle {}.", handle.borrow());
+
+        self.resolve_theorem_handle(handle.borrow())?;
+
+        self.theorem_proofs
+            .get(handle.borrow())
+            .cloned()
+            .ok_or(ErrorCode::NoSuchProofRegistered)
+    }
+
+    /// Returns `Ok((rule, theorem, arguments))` iff `handle` points-to a proof
+    /// term recording that the theorem pointed-to by `theorem` was derived by
+    /// applying the inference rule `rule` to `arguments`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchProofRegistered)` if `handle` does not
+    /// point-to a recorded proof term in the runtime state's proof-table.
+    pub fn proof_split<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Rule, &Handle<tags::Theorem>, &[ProofArgument]), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        info!("Splitting proof with handle {}.", handle.borrow());
+
+        let proof = self.resolve_proof_handle(handle)?;
+
+        Ok((proof.rule(), proof.theorem(), proof.arguments()))
+    }
+
+    /// Re-applies the inference rule recorded in `proof` to the arguments
+    /// recorded alongside it, returning a handle to the theorem derived.
+    /// Definitional theorems are re-derived from their recorded definitions,
+    /// as the defined constant cannot be defined a second time.
+    fn proof_replay(
+        &mut self,
+        proof: &Proof,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode> {
+        let name = |index| {
+            proof
+                .argument_name(index)
+                .ok_or(ErrorCode::ProofCheckFailed)
+        };
+        let tau = |index| {
+            proof
+                .argument_type(index)
+                .ok_or(ErrorCode::ProofCheckFailed)
+        };
+        let term = |index| {
+            proof
+                .argument_term(index)
+                .ok_or(ErrorCode::ProofCheckFailed)
+        };
+        let theorem = |index| {
+            proof
+                .argument_theorem(index)
+                .ok_or(ErrorCode::ProofCheckFailed)
+        };
+
+        match proof.rule() {
+            Rule::Assumption => self.theorem_register_assumption(term(0)?),
+            Rule::Weaken => self.theorem_register_weaken(term(0)?, theorem(1)?),
+            Rule::Reflexivity => self
+                .theorem_register_reflexivity::<Handle<tags::Term>, _>(term(
+                    0,
+                )?),
+            Rule::Symmetry => self.theorem_register_symmetry(theorem(0)?),
+            Rule::Transitivity => {
+                self.theorem_register_transitivity(theorem(0)?, theorem(1)?)
+            }
+            Rule::Application => {
+                self.theorem_register_application(theorem(0)?, theorem(1)?)
+            }
+            Rule::ConditionalCongruence => self
+                .theorem_register_conditional_congruence(
+                    theorem(0)?,
+                    theorem(1)?,
+                    theorem(2)?,
+                ),
+            Rule::LetCongruence => {
+                self.theorem_register_let_congruence(theorem(0)?, theorem(1)?)
+            }
+            Rule::FirstProjection => {
+                self.theorem_register_first_projection(term(0)?, term(1)?)
+            }
+            Rule::SecondProjection => {
+                self.theorem_register_second_projection(term(0)?, term(1)?)
+            }
+            Rule::SurjectivePairing => {
+                self.theorem_register_surjective_pairing(term(0)?)
+            }
+            Rule::MembershipDefinition => {
+                self.theorem_register_membership_definition(term(0)?, term(1)?)
+            }
+            Rule::ComprehensionDefinition => {
+                self.theorem_register_comprehension_definition(term(0)?)
+            }
+            Rule::EmptySetDefinition => {
+                self.theorem_register_empty_set_definition(tau(0)?)
+            }
+            Rule::UniversalSetDefinition => {
+                self.theorem_register_universal_set_definition(tau(0)?)
+            }
+            Rule::UnionDefinition => {
+                self.theorem_register_union_definition(term(0)?, term(1)?)
+            }
+            Rule::IntersectionDefinition => self
+                .theorem_register_intersection_definition(term(0)?, term(1)?),
+            Rule::SubsetDefinition => {
+                self.theorem_register_subset_definition(term(0)?, term(1)?)
+            }
+            Rule::Lambda => {
+                self.theorem_register_lambda(name(0)?, tau(1)?, theorem(2)?)
+            }
+            Rule::ForallCongruence => self.theorem_register_forall_congruence(
+                name(0)?,
+                tau(1)?,
+                theorem(2)?,
+            ),
+            Rule::ExistsCongruence => self.theorem_register_exists_congruence(
+                name(0)?,
+                tau(1)?,
+                theorem(2)?,
+            ),
+            Rule::Beta => self.theorem_register_beta(term(0)?),
+            Rule::Eta => self.theorem_register_eta(term(0)?),
+            Rule::IffIntroduction => {
+                self.theorem_register_iff_introduction(theorem(0)?, theorem(1)?)
+            }
+            Rule::IffLeftElimination => {
+                self.theorem_register_iff_left_elimination(theorem(0)?)
+            }
+            Rule::TruthIntroduction => {
+                self.theorem_register_truth_introduction()
+            }
+            Rule::FalsityElimination => {
+                self.theorem_register_falsity_elimination(theorem(0)?, term(1)?)
+            }
+            Rule::ConjunctionIntroduction => self
+                .theorem_register_conjunction_introduction(
+                    theorem(0)?,
+                    theorem(1)?,
+                ),
+            Rule::ConjunctionLeftElimination => {
+                self.theorem_register_conjunction_left_elimination(theorem(0)?)
+            }
+            Rule::ConjunctionRightElimination => {
+                self.theorem_register_conjunction_right_elimination(theorem(0)?)
+            }
+            Rule::DisjunctionLeftIntroduction => self
+                .theorem_register_disjunction_left_introduction(
+                    theorem(0)?,
+                    term(1)?,
+                ),
+            Rule::DisjunctionRightIntroduction => self
+                .theorem_register_disjunction_right_introduction(
+                    theorem(0)?,
+                    term(1)?,
+                ),
+            Rule::DisjunctionElimination => self
+                .theorem_register_disjunction_elimination(
+                    theorem(0)?,
+                    theorem(1)?,
+                    theorem(2)?,
+                ),
+            Rule::ImplicationIntroduction => self
+                .theorem_register_implication_introduction(
+                    theorem(0)?,
+                    term(1)?,
+                ),
+            Rule::ImplicationElimination => self
+                .theorem_register_implication_elimination(
+                    theorem(0)?,
+                    theorem(1)?,
+                ),
+            Rule::Substitute => {
+                let mut sigma = Vec::new();
+
+                for index in (1..proof.arguments().len()).step_by(3) {
+                    sigma.push((
+                        (name(index)?, tau(index + 1)?),
+                        term(index + 2)?,
+                    ));
+                }
+
+                self.theorem_register_substitute(theorem(0)?, sigma)
+            }
+            Rule::TypeSubstitute => {
+                let mut sigma = Vec::new();
+
+                for index in (1..proof.arguments().len()).step_by(2) {
+                    sigma.push((name(index)?, tau(index + 1)?));
+                }
+
+                self.theorem_register_type_substitute(theorem(0)?, sigma)
+            }
+            Rule::NegationIntroduction => self
+                .theorem_register_negation_introduction(theorem(0)?, term(1)?),
+            Rule::NegationElimination => self
+                .theorem_register_negation_elimination(
+                    theorem(0)?,
+                    theorem(1)?,
+                ),
+            Rule::ForallIntroduction => self
+                .theorem_register_forall_introduction(
+                    name(0)?,
+                    tau(1)?,
+                    theorem(2)?,
+                ),
+            Rule::ForallElimination => {
+                self.theorem_register_forall_elimination(theorem(0)?, term(1)?)
+            }
+            Rule::ExistsIntroduction => self
+                .theorem_register_exists_introduction(
+                    theorem(0)?,
+                    term(1)?,
+                    term(2)?,
+                ),
+            Rule::ExistsElimination => self
+                .theorem_register_exists_elimination(
+                    theorem(0)?,
+                    term(1)?,
+                    theorem(2)?,
+                ),
+            Rule::Definition => {
+                let conclusion =
+                    self.term_register_equality(term(0)?, term(1)?)?;
+                let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+                self.admit_theorem(
+                    Rule::Definition,
+                    proof.arguments().to_vec(),
+                    Theorem::new(premisses, conclusion),
+                )
+            }
+            Rule::TypeDefinition => {
+                let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+                self.admit_theorem(
+                    Rule::TypeDefinition,
+                    proof.arguments().to_vec(),
+                    Theorem::new(premisses, term(0)?),
+                )
+            }
+            Rule::RecursiveDefinition => {
+                let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+                self.admit_theorem(
+                    Rule::RecursiveDefinition,
+                    proof.arguments().to_vec(),
+                    Theorem::new(premisses, term(0)?),
+                )
+            }
+            #[cfg(feature = "linear-arith")]
+            Rule::LinearArith => self.theorem_register_linear_arith(term(0)?),
+            #[cfg(not(feature = "linear-arith"))]
+            Rule::LinearArith => Err(ErrorCode::NoSuchFunction),
+            Rule::Axiom => self.theorem_register_axiom(term(0)?),
+        }
+    }
+
+    /// Checks the proof term pointed-to by `handle`, and the proof terms of
+    /// every premiss that it depends upon, by re-applying each recorded
+    /// inference rule to its recorded arguments and checking that the theorem
+    /// so derived has the same premisses and conclusion as the recorded
+    /// theorem.  Each distinct proof term is checked once.  The theorems
+    /// derived whilst checking are discarded, along with every kernel object
+    /// registered alongside them, are never recorded, and are not reported to
+    /// observers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchProofRegistered)` if `handle` does not
+    /// point-to a recorded proof term in the runtime state's proof-table, or
+    /// if some premiss of the proof was registered whilst proof recording was
+    /// disabled, and so has no proof term of its own.
+    ///
+    /// Returns `Err(ErrorCode::ProofCheckFailed)` if re-applying a recorded
+    /// inference rule fails, or derives a different theorem.
+    pub fn proof_check<T>(&mut self, handle: T) -> Result<(), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        info!("Checking proof with handle {}.", handle.borrow());
+
+        self.resolve_proof_handle(handle.borrow())?;
+
+        /* NB: theorems are re-derived whilst checking within a speculative
+         * branch, discarded afterwards along with everything registered
+         * alongside them, and unseen by observers.  They would all duplicate
+         * theorems already registered.
+         */
+        let recording = self.record_proofs;
+        let detecting = self.detect_duplicates;
+
+        self.record_proofs = false;
+        self.detect_duplicates = false;
+        self.speculate_begin();
+        let result = self.unobserved(|state| {
+            state.with_progress(LongOperation::ProofCheck, |state| {
+                state.proof_check_inner(handle.borrow().clone())
+            })
+        });
+        self.record_proofs = recording;
+        self.detect_duplicates = detecting;
+        self.speculate_end(false)?;
+
+        result
+    }
+
+    /// Checks the proof term pointed-to by `handle`, and those of its
+    /// premisses, in post-order, with an explicit work-list.  See
+    /// `proof_check`.
+    fn proof_check_inner(
+        &mut self,
+        handle: Handle<tags::Proof>,
+    ) -> Result<(), ErrorCode> {
+        /* NB: counting the proof terms to check costs a traversal of its own,
+         * so is only done when progress is being reported.
+         */
+        let total = if self.progress_observed() {
+            self.proof_count(&handle)
+        } else {
+            0
+        };
+
+        let mut checked: HashSet<Handle<tags::Proof>> = HashSet::new();
+        let mut work = vec![(handle, false)];
+
+        while let Some((handle, expanded)) = work.pop() {
+            if checked.contains(&handle) {
+                continue;
+            }
+
+            let proof = self
+                .resolve_proof_handle(&handle)
+                .expect(DANGLING_HANDLE_ERROR)
+                .clone();
+
+            if !expanded {
+                work.push((handle, true));
+
+                for premiss in proof.premisses() {
+                    let premiss = self
+                        .theorem_proofs
+                        .get(premiss)
+                        .ok_or(ErrorCode::NoSuchProofRegistered)?;
+
+                    work.push((premiss.clone(), false));
+                }
+
+                continue;
+            }
+
+            let derived = self
+                .proof_replay(&proof)
+                .map_err(|_e| ErrorCode::ProofCheckFailed)?;
+
+            let matches = {
+                let derived = self
+                    .resolve_theorem_handle(&derived)
+                    .expect(DANGLING_HANDLE_ERROR);
+                let recorded = self
+                    .resolve_theorem_handle(proof.theorem())
+                    .expect(DANGLING_HANDLE_ERROR);
+
+                derived.premisses() == recorded.premisses()
+                    && derived.conclusion() == recorded.conclusion()
+            };
+
+            if !matches {
+                return Err(ErrorCode::ProofCheckFailed);
+            }
+
+            checked.insert(handle);
+            self.progress_report(checked.len(), total);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of distinct proof terms that checking the proof term
+    /// pointed-to by `handle` would check, ignoring premisses that have no
+    /// proof term of their own.
+    fn proof_count(&self, handle: &Handle<tags::Proof>) -> usize {
+        let mut seen: HashSet<Handle<tags::Proof>> = HashSet::new();
+        let mut work = vec![handle.clone()];
+
+        while let Some(handle) = work.pop() {
+            if !seen.insert(handle.clone()) {
+                continue;
+            }
+
+            if let Ok(proof) = self.resolve_proof_handle(&handle) {
+                for premiss in proof.premisses() {
+                    if let Some(premiss) = self.theorem_proofs.get(premiss) {
+                        work.push(premiss.clone());
+                    }
+                }
+            }
+        }
+
+        seen.len()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Modifying the global theory.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Defines a new constant, `c`, equal to the closed term pointed-to by
+    /// `defn`, `t`.  Registers `c`, giving it the type of `t`, and the
+    /// definitional theorem:
+    ///
+    /// ```text
+    /// ⊢ c = t
+    /// ```
+    ///
+    /// Returns `Ok((c, thm))` if this process is successful, where `c` is the
+    /// new constant lifted into a term, and `thm` is the definitional theorem.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `defn` does not
+    /// point-to a term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::TermNotWellformed)` if the term pointed-to by
+    /// `defn` is not well-typed.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the term pointed-to by `defn`
+    /// has free variables, or mentions a type-variable that its type does not,
+    /// as instantiating `c` could not then instantiate that type-variable.
+    pub fn register_new_definition<T>(
+        &mut self,
+        defn: T,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Theorem>), ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        info!(
+            "Registering new definition of term {}.",
+            defn.clone().into()
+        );
+
+        /* 1. Check the body of the definition exists, and it has a type. */
+        let tau = self.term_type_infer(defn.clone().into())?;
+
+        /* 2. Check the body of the definition is closed, and that its type
+         *    mentions every type-variable that it does.
+         */
+        if !self
+            .term_free_variables(defn.clone().into())
+            .expect(DANGLING_HANDLE_ERROR)
+            .is_empty()
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let type_variables =
+            self.type_variables(&tau).expect(DANGLING_HANDLE_ERROR);
+
+        if self
+            .term_type_variables(defn.clone().into())
+            .expect(DANGLING_HANDLE_ERROR)
+            .iter()
+            .any(|v| !type_variables.contains(v))
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        /* 3. Add the new constant, giving it the type inferred previously. */
+        let cnst_handle = self.issue_handle();
+        self.constants.insert(cnst_handle.clone(), tau);
+
+        let empty: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+
+        /* 4. Lift the registered constant into a term. */
+        let cnst = self
+            .term_register_constant(cnst_handle, empty)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 5. Construct the definitional theorem. */
+        let stmt = self
+            .term_register_equality(cnst.clone(), defn.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 6. Register the definitional theorem. */
+        let arguments = vec![
+            ProofArgument::Term(cnst.clone()),
+            ProofArgument::Term(defn.into()),
+        ];
+        let empty: Vec<Handle<tags::Term>> = Vec::new();
+        let thm = self.admit_theorem(
+            Rule::Definition,
+            arguments,
+            Theorem::new(empty, stmt),
+        )?;
+
+        /* 7. Return the handle to the new constant and definitional theorem. */
+
+        Ok((cnst, thm))
+    }
+
+    /// Defines a new type, in bijection with the non-empty subset of an
+    /// existing type, `ρ`, picked out by a predicate, `P : ρ → Prop`.  The
+    /// theorem pointed-to by `witness` must have the form `{} ⊢ P t`, for a
+    /// closed predicate `P`, witnessing that the subset is non-empty.
+    ///
+    /// Registers a new type-former, with one argument for each type-variable
+    /// of `P` in ascending order of name, and the type, `τ`, obtained by
+    /// applying it to those type-variables.  Also registers two new constants:
+    /// the abstraction function, `abs : ρ → τ`, and the representation
+    /// function, `rep : τ → ρ`, along with the two theorems characterising
+    /// them:
+    ///
+    /// ```text
+    /// ⊢ ∀a:τ. abs (rep a) = a
+    /// ⊢ ∀r:ρ. P r = (rep (abs r) = r)
+    /// ```
+    ///
+    /// The definition is recorded against the new type-former, and may be
+    /// recovered with `type_definition_resolve`.  Returns `Ok((tau, abs, rep,
+    /// abs_rep, rep_abs))` if this process is successful, where `abs` and
+    /// `rep` are the constants lifted into terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `witness` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnApplication)` if the conclusion of the
+    /// theorem pointed-to by `witness` is not an application.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the theorem pointed-to by
+    /// `witness` has premisses, or if the predicate has free variables.
+    pub fn register_new_type_definition<T>(
+        &mut self,
+        witness: T,
+    ) -> Result<TypeDefinition, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Registering new type definition with witness {}.",
+            witness.borrow()
+        );
+
+        /* 1. Check the witness has the form `{} ⊢ P t`, with `P` closed. */
+        let thm = self.resolve_theorem_handle(witness.borrow())?.clone();
+
+        if !thm.premisses().is_empty() {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let (predicate, witness) =
+            self.term_split_application(thm.conclusion())?;
+
+        // Appease the borrow-checker gods...
+        let predicate = predicate.clone();
+        let witness = witness.clone();
+
+        if !self
+            .term_free_variables(&predicate)
+            .expect(DANGLING_HANDLE_ERROR)
+            .is_empty()
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        /* 2. Register the new type, quantified over every type-variable of the
+         *    predicate, lest the type depend on a type-variable that it does
+         *    not mention.
+         */
+        let rho = self.term_type_infer(&witness).expect(DANGLING_HANDLE_ERROR);
+
+        let mut variables: Vec<Name> = self
+            .term_type_variables(&predicate)
+            .expect(DANGLING_HANDLE_ERROR)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        variables.sort_unstable();
+        variables.dedup();
+
+        let former = self.type_former_register(variables.len());
+        let arguments: Vec<Handle<tags::Type>> = variables
+            .into_iter()
+            .map(|v| self.type_register_variable(v))
+            .collect();
+
+        // NB: none of these can fail, as everything is known to be registered
+        // and the arity of the type-former matches its arguments.
+        let tau = self
+            .type_register_combination(former.clone(), arguments)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_type = self
+            .type_register_function(rho.clone(), tau.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_type = self
+            .type_register_function(tau.clone(), rho.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 3. Register the abstraction and representation functions. */
+        let empty: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+
+        let abs = self
+            .constant_register(abs_type)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs = self
+            .term_register_constant(abs, empty.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep = self
+            .constant_register(rep_type)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep = self
+            .term_register_constant(rep, empty)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 4. Construct the characterising theorems.  As the predicate is
+         *    closed, any name may be used for the bound variables without
+         *    danger of capture.
+         */
+        let name: Name = 0;
+
+        let a = self
+            .term_register_variable(name, tau.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_a = self
+            .term_register_application(rep.clone(), a.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_rep_a = self
+            .term_register_application(abs.clone(), rep_a)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_rep = self
+            .term_register_equality(abs_rep_a, a)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_rep = self
+            .term_register_forall(name, tau.clone(), abs_rep)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let r = self
+            .term_register_variable(name, rho.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let p_r = self
+            .term_register_application(predicate.clone(), r.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_r = self
+            .term_register_application(abs.clone(), r.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_abs_r = self
+            .term_register_application(rep.clone(), abs_r)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_abs = self
+            .term_register_equality(rep_abs_r, r)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_abs = self
+            .term_register_equality(p_r, rep_abs)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let rep_abs = self
+            .term_register_forall(name, rho.clone(), rep_abs)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 5. Register the characterising theorems. */
+        let mut theorems = Vec::new();
+
+        for stmt in [abs_rep, rep_abs] {
+            let arguments = vec![ProofArgument::Term(stmt.clone())];
+            let empty: Vec<Handle<tags::Term>> = Vec::new();
+
+            theorems.push(self.admit_theorem(
+                Rule::TypeDefinition,
+                arguments,
+                Theorem::new(empty, stmt),
+            )?);
+        }
+
+        let rep_abs = theorems.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let abs_rep = theorems.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 6. Record the definition, so that it may be resolved later. */
+        self.defined_types.insert(
+            former.clone(),
+            DefinedType {
+                former,
+                predicate,
+                carrier: rho,
+                tau: tau.clone(),
+                abs: abs.clone(),
+                rep: rep.clone(),
+                abs_rep: abs_rep.clone(),
+                rep_abs: rep_abs.clone(),
+            },
+        );
+
+        Ok((tau, abs, rep, abs_rep, rep_abs))
+    }
+
+    /// Returns `Ok((well_founded, congruence))`, the propositions that must be
+    /// proved before defining a function by well-founded recursion, with
+    /// `register_new_recursive_definition`, from the functional,
+    /// `F : (α → β) → α → β`, pointed-to by `functional` and the relation,
+    /// `R : α → α → Prop`, pointed-to by `relation`.  These state that `R` is
+    /// well-founded, and that `F f x` depends only on the values of `f` at
+    /// elements `R`-below `x`:
+    ///
+    /// ```text
+    /// ∀P. (∀x. (∀y. R y x ⟶ P y) ⟶ P x) ⟶ (∀x. P x)
+    /// ∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ F f x = F g x
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `functional` or
+    /// `relation` do not point-to registered terms in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the functional or relation
+    /// have free variables, or do not have types of the forms described
+    /// above, or if either mentions a type-variable not appearing in `α → β`.
+    pub fn recursive_definition_obligations<T, U>(
+        &mut self,
+        functional: T,
+        relation: U,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        let functional = functional.borrow();
+        let relation = relation.borrow();
+
+        info!(
+            "Computing recursive definition obligations of functional {} and relation {}.",
+            functional, relation
+        );
+
+        /* 1. Check the types of the functional and relation. */
+        let ftype = self.term_type_infer(functional)?;
+        let rtype = self.term_type_infer(relation)?;
+
+        let (tau, range) = self
+            .type_split_function(&ftype)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let tau = tau.clone();
+
+        if range != &tau {
+            return Err(self.shape_mismatch_types(
+                "the functional does not map functions to functions of the \
+                 same type.",
+                &tau,
+                range,
+            ));
+        }
+
+        let (alpha, _beta) = self
+            .type_split_function(&tau)
+            .map_err(|_e| ErrorCode::ShapeMismatch)?;
+        let alpha = alpha.clone();
+
+        let ptype = self
+            .type_register_function(
+                alpha.clone(),
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let expected = self
+            .type_register_function(alpha.clone(), ptype.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        if rtype != expected {
+            return Err(self.shape_mismatch_types(
+                "the relation is not a binary relation on the domain of the \
+                 function.",
+                &expected,
+                &rtype,
+            ));
+        }
+
+        /* 2. Check the functional and relation are closed, and mention no
+         *    type-variable that the type of the function does not, lest the
+         *    defining equation fix the function at some type that it does not
+         *    depend on.
+         */
+        if !self.term_free_variables(functional)?.is_empty()
+            || !self.term_free_variables(relation)?.is_empty()
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let allowed = self.type_variables(&tau).expect(DANGLING_HANDLE_ERROR);
+
+        for handle in [functional, relation] {
+            if self
+                .term_type_variables(handle)?
+                .iter()
+                .any(|v| !allowed.contains(v))
+            {
+                return Err(ErrorCode::ShapeMismatch);
+            }
+        }
+
+        /* 3. Construct the obligations, with bound variables named apart from
+         *    every name in the functional and relation.
+         */
+        let mut avoid = self.term_names(functional)?;
+        avoid.append(&mut self.term_names(relation)?);
+
+        let mut names = Vec::new();
+
+        for _i in 0..5 {
+            let name = fresh(avoid.iter().cloned());
+
+            avoid.push(name);
+            names.push(name);
+        }
+
+        let (p, x, y, f, g) =
+            (names[0], names[1], names[2], names[3], names[4]);
+
+        let pv = self
+            .term_register_variable(p, ptype.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let xv = self
+            .term_register_variable(x, alpha.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let yv = self
+            .term_register_variable(y, alpha.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let fv = self
+            .term_register_variable(f, tau.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let gv = self
+            .term_register_variable(g, tau.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let ry = self
+            .term_register_application(relation.clone(), yv.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let ryx = self
+            .term_register_application(ry, xv.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* `∀P. (∀x. (∀y. R y x ⟶ P y) ⟶ P x) ⟶ (∀x. P x)`. */
+        let py = self
+            .term_register_application(pv.clone(), yv.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let px = self
+            .term_register_application(pv, xv.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let below = self
+            .term_register_implication(ryx.clone(), py)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let below = self
+            .term_register_forall(y, alpha.clone(), below)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let step = self
+            .term_register_implication(below, px.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let step = self
+            .term_register_forall(x, alpha.clone(), step)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let all = self
+            .term_register_forall(x, alpha.clone(), px)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let well_founded = self
+            .term_register_implication(step, all)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let well_founded = self
+            .term_register_forall(p, ptype, well_founded)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* `∀f g x. (∀y. R y x ⟶ f y = g y) ⟶ F f x = F g x`. */
+        let fy = self
+            .term_register_application(fv.clone(), yv.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let gy = self
+            .term_register_application(gv.clone(), yv)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let agree = self
+            .term_register_equality(fy, gy)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let agree = self
+            .term_register_implication(ryx, agree)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let agree = self
+            .term_register_forall(y, alpha.clone(), agree)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let mut sides = Vec::new();
+
+        for v in [fv, gv] {
+            let side = self
+                .term_register_application(functional.clone(), v)
+                .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+            sides.push(
+                self.term_register_application(side, xv.clone())
+                    .expect(PRIMITIVE_CONSTRUCTION_ERROR),
+            );
+        }
+
+        let right = sides.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let left = sides.pop().expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let congruence = self
+            .term_register_equality(left, right)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let mut congruence = self
+            .term_register_implication(agree, congruence)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        for (name, tau) in [(x, alpha), (g, tau.clone()), (f, tau)] {
+            congruence = self
+                .term_register_forall(name, tau, congruence)
+                .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        }
+
+        Ok((well_founded, congruence))
+    }
+
+    /// Defines a new function, `f : α → β`, by well-founded recursion, from
+    /// the functional, `F : (α → β) → α → β`, pointed-to by `functional` and
+    /// the relation, `R : α → α → Prop`, pointed-to by `relation`.  The
+    /// theorems pointed-to by `well_founded` and `congruence` must have no
+    /// premisses, and must prove the two obligations returned by
+    /// `recursive_definition_obligations`, in order.  By the recursion
+    /// theorem there is then exactly one function satisfying the defining
+    /// equation:
+    ///
+    /// ```text
+    /// ⊢ ∀x. f x = F f x
+    /// ```
+    ///
+    /// Returns `Ok((constant, equation))` if this process is successful, where
+    /// `constant` is the new constant lifted into a term.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `functional` or
+    /// `relation` do not point-to registered terms in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `well_founded` or
+    /// `congruence` do not point-to registered theorems in the runtime state's
+    /// theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if the functional or relation
+    /// are rejected by `recursive_definition_obligations`, or if either
+    /// theorem has premisses or does not prove its obligation.
+    pub fn register_new_recursive_definition<T, U, V, W>(
+        &mut self,
+        functional: T,
+        relation: U,
+        well_founded: V,
+        congruence: W,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Theorem>), ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+        V: Borrow<Handle<tags::Theorem>>,
+        W: Borrow<Handle<tags::Theorem>>,
+    {
+        let functional = functional.borrow();
+
+        info!(
+            "Registering new recursive definition with functional {}.",
+            functional
+        );
+
+        /* 1. Check the theorems prove the obligations. */
+        let obligations = self
+            .recursive_definition_obligations(functional, relation.borrow())?;
+
+        for (handle, obligation) in [
+            (well_founded.borrow(), obligations.0),
+            (congruence.borrow(), obligations.1),
+        ] {
+            let thm = self.resolve_theorem_handle(handle)?;
+
+            if !thm.premisses().is_empty() {
+                return Err(self.shape_mismatch_premisses(
+                    "the proof of the obligation has premisses.",
+                    &[],
+                    thm.premisses(),
+                ));
+            }
+
+            if thm.conclusion() != &obligation {
+                return Err(self.shape_mismatch_terms(
+                    "the theorem does not prove the obligation.",
+                    &obligation,
+                    thm.conclusion(),
+                ));
+            }
+        }
+
+        /* 2. Register the new constant. */
+        let ftype = self
+            .term_type_infer(functional)
+            .expect(DANGLING_HANDLE_ERROR);
+        let (tau, _range) = self
+            .type_split_function(&ftype)
+            .expect(DANGLING_HANDLE_ERROR);
+        let tau = tau.clone();
+        let (alpha, _beta) =
+            self.type_split_function(&tau).expect(DANGLING_HANDLE_ERROR);
+        let alpha = alpha.clone();
+
+        let empty: Vec<(Name, Handle<tags::Type>)> = Vec::new();
+
+        let cnst = self
+            .constant_register(tau)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let cnst = self
+            .term_register_constant(cnst, empty)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        /* 3. Construct and register the defining equation.  As the functional
+         *    is closed, any name may be used for the bound variable without
+         *    danger of capture.
+         */
+        let name: Name = 0;
+
+        let x = self
+            .term_register_variable(name, alpha.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let left = self
+            .term_register_application(cnst.clone(), x.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let right = self
+            .term_register_application(functional.clone(), cnst.clone())
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let right = self
+            .term_register_application(right, x)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let stmt = self
+            .term_register_equality(left, right)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+        let stmt = self
+            .term_register_forall(name, alpha, stmt)
+            .expect(PRIMITIVE_CONSTRUCTION_ERROR);
+
+        let arguments = vec![ProofArgument::Term(stmt.clone())];
+        let empty: Vec<Handle<tags::Term>> = Vec::new();
+        let thm = self.admit_theorem(
+            Rule::RecursiveDefinition,
+            arguments,
+            Theorem::new(empty, stmt),
+        )?;
+
+        Ok((cnst, thm))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Speculative proof search.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Begins a new speculative branch, nested within any branch already in
+    /// progress.  Kernel objects may be registered freely within the branch,
+    /// and are either kept or discarded, all at once, when the branch ends.
+    pub fn speculate_begin(&mut self) {
+        info!(
+            "Beginning speculative branch at depth {}.",
+            self.speculations.len()
+        );
+
+        self.speculations.push(Speculation {
+            mark: self.handles.next_sequence(),
+            capabilities: self.capabilities.clone(),
+            datatype_constructors: self.datatype_constructors.clone(),
+            constructor_datatypes: self.constructor_datatypes.clone(),
+            object_metadata: self.object_metadata.clone(),
+            references: self.references.clone(),
+            pending_theorem_metadata: self.pending_theorem_metadata.clone(),
+            #[cfg(feature = "retraction")]
+            retracted: self.retracted.clone(),
+        });
+    }
+
+    /// Ends the innermost speculative branch in progress.  If `keep` is `true`
+    /// then every kernel object registered within the branch is kept, becoming
+    /// part of the enclosing branch, if any.  Otherwise, every kernel object
+    /// registered within the branch is discarded, the uses of any
+    /// capabilities presented within the branch are forgotten, and any
+    /// theorems retracted within the branch are reinstated.
+    ///
+    /// Handles issued within a discarded branch are never reissued: the range
+    /// of handles issued within the branch is retired, so stale handles
+    /// retained by the caller are reported with `ErrorCode::StaleHandle`
+    /// rather than silently pointing to some other kernel object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSpeculationInProgress)` if no speculative
+    /// branch is in progress.
+    pub fn speculate_end(&mut self, keep: bool) -> Result<(), ErrorCode> {
+        info!(
+            "Ending speculative branch at depth {}, keeping: {}.",
+            self.speculations.len().saturating_sub(1),
+            keep
+        );
+
+        let speculation = self
+            .speculations
+            .pop()
+            .ok_or(ErrorCode::NoSpeculationInProgress)?;
+
+        if keep {
+            return Ok(());
+        }
+
+        let mark = speculation.mark;
+
+        /* NB: the ranges retired by branches nested within this one are
+         * subsumed by the range retired by this one.
+         */
+        self.retired.retain(|range| range.start < mark);
+
+        if mark < self.handles.next_sequence() {
+            self.retired.push(mark..self.handles.next_sequence());
+        }
+
+        let handles = &self.handles;
+        let kept = |handle: usize| handles.issued_before(handle, mark);
+
+        self.type_formers.retain(|handle, _| kept(**handle));
+        self.type_former_metadata.retain(|handle, _| kept(**handle));
+        self.type_former_names.retain(|_, handle| kept(**handle));
+        self.types.retain(|handle, _| kept(**handle));
+        self.constants.retain(|handle, _| kept(**handle));
+        self.constant_names.retain(|_, handle| kept(**handle));
+        self.terms.retain(|handle, _| kept(**handle));
+        self.terms_index.retain(|_, handle| kept(**handle));
+        self.term_heights.retain(|handle, _| kept(**handle));
+        self.lambda_display_names.retain(|handle, _| kept(**handle));
+        self.term_types.retain(|handle, _| kept(**handle));
+        self.theorems.retain(|handle, _| kept(**handle));
+        self.trust.retain(|handle, _| kept(**handle));
+        self.conclusions.retain(|_, theorems| {
+            theorems.retain(|handle| kept(**handle));
+            !theorems.is_empty()
+        });
+        self.conclusion_shapes.retain(|_, conclusions| {
+            conclusions.retain(|handle| kept(**handle));
+            !conclusions.is_empty()
+        });
+
+        /* NB: keys cannot be removed from a Bloom filter, so the filter is
+         * rebuilt from the conclusions that remain.
+         */
+        self.conclusion_filter = BloomFilter::default();
+
+        for conclusion in self.conclusions.keys() {
+            self.conclusion_filter.insert(**conclusion as u64);
+        }
+
+        #[cfg(feature = "linear-arith")]
+        if let Some(signature) = &self.linear_arith {
+            if !kept(**signature.former()) {
+                self.linear_arith = None;
+            }
+        }
+        self.hypotheses.retain(|handle, _| kept(**handle));
+        self.hypotheses_index.retain(|_, handle| kept(**handle));
+        self.substitutions.retain(|handle, _| kept(**handle));
+        self.substitutions_index.retain(|_, handle| kept(**handle));
+        self.proofs.retain(|handle, _| kept(**handle));
+        self.theorem_proofs.retain(|handle, _| kept(**handle));
+        self.statement_hashes.retain(|_, handle| kept(**handle));
+        self.quotients.retain(|handle, _| kept(**handle));
+        self.defined_types.retain(|handle, _| kept(**handle));
+        #[cfg(feature = "sequents")]
+        self.sequents.retain(|handle, _| kept(**handle));
+        #[cfg(feature = "retraction")]
+        {
+            self.dependents.retain(|handle, _| kept(**handle));
+
+            for dependents in self.dependents.values_mut() {
+                dependents.retain(|handle| kept(**handle));
+            }
+        }
+
+        self.capabilities = speculation.capabilities;
+        self.datatype_constructors = speculation.datatype_constructors;
+        self.constructor_datatypes = speculation.constructor_datatypes;
+        self.object_metadata = speculation.object_metadata;
+        self.references = speculation.references;
+        self.pending_theorem_metadata = speculation.pending_theorem_metadata;
+        #[cfg(feature = "retraction")]
+        {
+            self.retracted = speculation.retracted;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of speculative branches currently in progress.
+    #[inline]
+    pub fn speculation_depth(&self) -> usize {
+        self.speculations.len()
+    }
+
+    /// Returns `true` iff `handle` was issued within a speculative branch
+    /// that has since been discarded, and so no longer points-to any kernel
+    /// object.
+    pub fn handle_is_stale<T>(&self, handle: &Handle<T>) -> bool
+    where
+        T: tags::IsTag,
+    {
+        let handle = match self.handles.sequence(**handle) {
+            Some(sequence) => sequence,
+            None => return false,
+        };
+
+        self.retired
+            .binary_search_by(|range| {
+                if range.end <= handle {
+                    Ordering::Less
+                } else if range.start > handle {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns the error to report for `handle`, which does not point-to a
+    /// registered kernel object: `ErrorCode::StaleHandle` if `handle` is
+    /// stale, and `code` otherwise.
+    pub(crate) fn unresolved<T>(
+        &self,
+        handle: &Handle<T>,
+        code: ErrorCode,
+    ) -> ErrorCode
+    where
+        T: tags::IsTag,
+    {
+        if self.handle_is_stale(handle) {
+            ErrorCode::StaleHandle
+        } else {
+            code
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Hashing theorem statements.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a structural hash of the type pointed-to by `handle`, which
+    /// depends only on the names of type-variables and on the handles of
+    /// type-formers.
+    fn type_structural_hash(&self, handle: &Handle<tags::Type>) -> u64 {
+        match self
+            .resolve_type_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Type::Variable { name } => {
+                fnv1a_words(FNV_OFFSET_BASIS, &[0, *name])
+            }
+            Type::Combination { former, arguments } => {
+                let mut words =
+                    vec![1, **former as u64, arguments.len() as u64];
+
+                for argument in arguments.iter() {
+                    words.push(self.type_structural_hash(argument));
+                }
+
+                fnv1a_words(FNV_OFFSET_BASIS, &words)
+            }
+        }
+    }
+
+    /// Returns a structural hash of the term pointed-to by `handle`, under the
+    /// λ-bound variables `bound`, innermost last.  Bound variables are hashed
+    /// by their de Bruijn index, so the hash does not depend on the names of
+    /// bound variables, nor on the handles of subterms.
+    fn term_structural_hash(
+        &self,
+        handle: &Handle<tags::Term>,
+        bound: &mut Vec<(Name, Handle<tags::Type>)>,
+    ) -> u64 {
+        match self
+            .resolve_term_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Term::Variable { name, tau } => {
+                let index =
+                    bound.iter().rev().position(|(n, t)| n == name && t == tau);
+
+                match index {
+                    Some(index) => {
+                        fnv1a_words(FNV_OFFSET_BASIS, &[4, index as u64])
+                    }
+                    None => fnv1a_words(
+                        FNV_OFFSET_BASIS,
+                        &[0, *name, self.type_structural_hash(tau)],
+                    ),
+                }
+            }
+            Term::Constant { constant, tau } => fnv1a_words(
+                FNV_OFFSET_BASIS,
+                &[1, **constant as u64, self.type_structural_hash(tau)],
+            ),
+            Term::Application { left, right } => {
+                let left = self.term_structural_hash(left, bound);
+                let right = self.term_structural_hash(right, bound);
+
+                fnv1a_words(FNV_OFFSET_BASIS, &[2, left, right])
+            }
+            Term::Lambda { name, tau, body } => {
+                let tau_hash = self.type_structural_hash(tau);
+
+                bound.push((*name, tau.clone()));
+                let body = self.term_structural_hash(body, bound);
+                bound.pop();
+
+                fnv1a_words(FNV_OFFSET_BASIS, &[3, tau_hash, body])
+            }
+        }
+    }
+
+    /// Returns a structural hash of the statement of `thm`, i.e. of its
+    /// premisses and conclusion.
+    fn statement_hash(&self, thm: &Theorem) -> u64 {
+        let mut bound = Vec::new();
+
+        let mut words: Vec<u64> = thm
+            .premisses()
+            .iter()
+            .map(|premiss| self.term_structural_hash(premiss, &mut bound))
+            .collect();
+
+        words.sort_unstable();
+        words.push(words.len() as u64);
+        words.push(self.term_structural_hash(thm.conclusion(), &mut bound));
+
+        fnv1a_words(FNV_OFFSET_BASIS, &words)
+    }
+
+    /// Returns `Ok(hash)`, where `hash` is a 64-bit hash of the statement of
+    /// the theorem pointed-to by `handle`: its premisses and its conclusion.
+    /// The hash depends only on the structure of the statement, up to
+    /// ⍺-equivalence, and not on the handles of the terms and types within it,
+    /// so theorems with the same statement share a hash across runs, provided
+    /// that their free variables, type-variables, constants and type-formers
+    /// are named identically.  It is computed with 64-bit FNV-1a.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    pub fn theorem_statement_hash<T>(&self, handle: T) -> Result<u64, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Hashing statement of theorem with handle {}.",
+            handle.borrow()
+        );
+
+        let thm = self.resolve_theorem_handle(handle)?;
+
+        Ok(self.statement_hash(thm))
+    }
+
+    /// Enables, or disables, duplicate detection.  Whilst duplicate detection
+    /// is enabled, the kernel logs a warning whenever a newly registered
+    /// theorem has the same statement hash as a theorem registered earlier,
+    /// whilst duplicate detection was also enabled, helping to spot redundant
+    /// work in large automation runs.  Duplicate detection is disabled by
+    /// default.
+    #[inline]
+    pub fn set_duplicate_detection(&mut self, enabled: bool) -> &mut Self {
+        info!("Setting duplicate detection to {}.", enabled);
+
+        self.detect_duplicates = enabled;
+        self
+    }
+
+    /// Records the statement hash of the theorem pointed-to by `handle`,
+    /// warning if a theorem with the same statement hash has been recorded
+    /// previously.
+    fn detect_duplicate(&mut self, handle: &Handle<tags::Theorem>) {
+        let thm = self
+            .resolve_theorem_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR);
+        let hash = self.statement_hash(thm);
+
+        match self.statement_hashes.get(&hash) {
+            Some(original) => warn!(
+                "Theorem {} duplicates the statement of theorem {} (statement hash {:#018x}).",
+                handle, original, hash
+            ),
+            None => {
+                self.statement_hashes.insert(hash, handle.clone());
+            }
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Quoting theorem statements.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `Ok(trm)`, where `trm` is a handle to a proposition equivalent to
+    /// the statement of the theorem pointed-to by `handle`, allowing statements
+    /// to be reasoned about, and stored, as ordinary terms.  A theorem with no
+    /// premisses is quoted as its conclusion, and a theorem `p₁, ..., pₙ ⊢ q`
+    /// as the implication `p₁ ∧ (... ∧ pₙ) ⇒ q`, with the premisses taken in
+    /// the order in which they are stored in the theorem.  Note that the
+    /// quoted statement is just a term: quoting a theorem does not register any
+    /// new theorem.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    pub fn theorem_statement_as_term<T>(
+        &mut self,
+        handle: T,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Quoting statement of theorem with handle {} as a term.",
+            handle.borrow()
+        );
+
+        let (premisses, conclusion) = {
+            let thm = self.resolve_theorem_handle(handle)?;
+
+            (thm.premisses().clone(), thm.conclusion().clone())
+        };
+
+        let mut premisses = premisses.into_iter().rev();
+
+        let hypothesis = match premisses.next() {
+            None => return Ok(conclusion),
+            Some(last) => premisses.try_fold(last, |right, left| {
+                self.term_register_conjunction(left, right)
+            })?,
+        };
+
+        self.term_register_implication(hypothesis, conclusion)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Pretty-printing.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the name used when pretty-printing the constant pointed-to by
+    /// `handle`.  Constants of the bootstrap theory are printed by name, and
+    /// all others by handle.
+    fn constant_name(handle: &Handle<tags::Constant>) -> String {
+        let name = match handle {
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_TRUE => "true",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_FALSE => "false",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_NEGATION => "~",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION => "/\\",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION => "\\/",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_IMPLICATION => "==>",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_EQUALITY => "=",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_FORALL => "forall",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_EXISTS => "exists",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL => "if",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_LET => "let",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_PAIR => "pair",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_FIRST => "fst",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_SECOND => "snd",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP => "mem",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION => "comp",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET => "empty",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET => "univ",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_UNION => "union",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_INTERSECTION => "inter",
+            h if h == &PREALLOCATED_HANDLE_CONSTANT_SUBSET => "subset",
+            _otherwise => return format!("c{}", handle),
+        };
+
+        name.to_string()
+    }
+
+    /// Pretty-prints the type pointed-to by `handle`, which must not dangle.
+    fn pretty_type(&self, handle: &Handle<tags::Type>) -> String {
+        match self
+            .resolve_type_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Type::Variable { name } => format!("a{}", name),
+            Type::Combination { former, arguments } => {
+                let arguments: Vec<String> =
+                    arguments.iter().map(|a| self.pretty_type(a)).collect();
+
+                match former {
+                    f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_PROP => {
+                        String::from("Prop")
+                    }
+                    f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_ARROW => {
+                        format!("({} -> {})", arguments[0], arguments[1])
+                    }
+                    f if f == &PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT => {
+                        format!("({} * {})", arguments[0], arguments[1])
+                    }
+                    _otherwise => {
+                        let name = match self.type_former_metadata.get(former) {
+                            Some(metadata) if !metadata.name().is_empty() => {
+                                metadata.name().to_string()
+                            }
+                            _otherwise => format!("F{}", former),
+                        };
+
+                        if arguments.is_empty() {
+                            name
+                        } else {
+                            format!("{}({})", name, arguments.join(", "))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Pretty-prints a variable name, printing canonical names, which are
+    /// bound, by their index.
+    fn pretty_name(name: Name) -> String {
+        match canonical_index(name) {
+            Some(index) => format!("b{}", index),
+            None => format!("x{}", name),
+        }
+    }
+
+    /// Pretty-prints the term pointed-to by `handle`, which must not dangle.
+    /// Negations, binary connectives, equalities and quantifiers are printed
+    /// using their usual notation, and everything else is fully parenthesised.
+    fn pretty_term(&self, handle: &Handle<tags::Term>) -> String {
+        match self
+            .resolve_term_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Term::Variable { name, .. } => Self::pretty_name(*name),
+            Term::Constant { constant, .. } => Self::constant_name(constant),
+            Term::Lambda { name, tau, body } => format!(
+                "(\\{} : {}. {})",
+                Self::pretty_name(*name),
+                self.pretty_type(tau),
+                self.pretty_term(body)
+            ),
+            Term::Application { left, right } => {
+                if let Ok(body) = self.term_split_negation(handle) {
+                    return format!("~{}", self.pretty_term(body));
+                }
+
+                if let Ok((name, tau, body)) = self
+                    .term_split_forall(handle)
+                    .or_else(|_e| self.term_split_exists(handle))
+                {
+                    let (quantifier, _tau) = self
+                        .term_split_constant(left)
+                        .expect(DANGLING_HANDLE_ERROR);
+
+                    return format!(
+                        "({} {} : {}. {})",
+                        Self::constant_name(quantifier),
+                        Self::pretty_name(*name),
+                        self.pretty_type(tau),
+                        self.pretty_term(body)
+                    );
+                }
+
+                if let Ok((operator, lhs)) = self.term_split_application(left) {
+                    if let Ok((constant, _tau)) =
+                        self.term_split_constant(operator)
+                    {
+                        if constant == &PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION
+                            || constant
+                                == &PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION
+                            || constant
+                                == &PREALLOCATED_HANDLE_CONSTANT_IMPLICATION
+                            || constant
+                                == &PREALLOCATED_HANDLE_CONSTANT_EQUALITY
+                        {
+                            return format!(
+                                "({} {} {})",
+                                self.pretty_term(lhs),
+                                Self::constant_name(constant),
+                                self.pretty_term(right)
+                            );
+                        }
+                    }
+                }
+
+                format!(
+                    "({} {})",
+                    self.pretty_term(left),
+                    self.pretty_term(right)
+                )
+            }
+        }
+    }
+
+    /// Returns `Ok(text)`, where `text` is a human-readable rendering of the
+    /// type pointed-to by `handle`.  Type-variables are printed as `a` followed
+    /// by their name, and type-formers outside of the bootstrap theory by the
+    /// name given in their metadata, or otherwise as `F` followed by their
+    /// handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `handle` does not
+    /// point-to a registered type in the runtime state's type-table.
+    pub fn type_pretty_print<T>(&self, handle: T) -> Result<String, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        info!("Pretty-printing type with handle {}.", handle.borrow());
+
+        self.resolve_type_handle(handle.borrow())?;
+
+        Ok(self.pretty_type(handle.borrow()))
+    }
+
+    /// Returns `Ok(text)`, where `text` is a human-readable rendering of the
+    /// term pointed-to by `handle`.  Variables are printed as `x` followed by
+    /// their name, and constants outside of the bootstrap theory as `c`
+    /// followed by their handle.  The rendering is intended for diagnostics,
+    /// and is not guaranteed to be stable, or parseable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a registered term in the runtime state's term-table.
+    pub fn term_pretty_print<T>(&self, handle: T) -> Result<String, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Pretty-printing term with handle {}.", handle.borrow());
+
+        self.resolve_term_handle(handle.borrow())?;
+
+        Ok(self.pretty_term(handle.borrow()))
+    }
+
+    /// Appends the path `step` to `path`, separating steps with a dot.
+    fn diff_path(path: &str, step: &str) -> String {
+        if path.is_empty() {
+            step.to_string()
+        } else {
+            format!("{}.{}", path, step)
+        }
+    }
+
+    /// Records, in `differences`, a line for each of the outermost positions
+    /// at which the types pointed-to by `left` and `right`, which must not
+    /// dangle, differ.  Positions are reached from `path` by the indices of
+    /// type-former arguments.
+    fn type_differences(
+        &self,
+        left: &Handle<tags::Type>,
+        right: &Handle<tags::Type>,
+        path: &str,
+        differences: &mut Vec<String>,
+    ) {
+        if left == right {
+            return;
+        }
+
+        let left_type =
+            self.resolve_type_handle(left).expect(DANGLING_HANDLE_ERROR);
+        let right_type = self
+            .resolve_type_handle(right)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        if let (
+            Type::Combination {
+                former: left_former,
+                arguments: left_arguments,
+            },
+            Type::Combination {
+                former: right_former,
+                arguments: right_arguments,
+            },
+        ) = (left_type, right_type)
+        {
+            if left_former == right_former
+                && left_arguments.len() == right_arguments.len()
+            {
+                for (index, (l, r)) in
+                    left_arguments.iter().zip(right_arguments).enumerate()
+                {
+                    let path = Self::diff_path(path, &index.to_string());
+
+                    self.type_differences(l, r, &path, differences);
+                }
+
+                return;
+            }
+        }
+
+        differences.push(format!(
+            "{}: {} vs {}",
+            path,
+            self.pretty_type(left),
+            self.pretty_type(right)
+        ));
+    }
+
+    /// Records, in `differences`, a line for each of the outermost positions
+    /// at which the terms pointed-to by `left` and `right`, which must not
+    /// dangle, differ.  Positions are reached from `path` by the steps `fun`
+    /// and `arg` into applications, `type` and `body` into λ-abstractions, and
+    /// `type` into variables and constants.
+    fn term_differences(
+        &self,
+        left: &Handle<tags::Term>,
+        right: &Handle<tags::Term>,
+        path: &str,
+        differences: &mut Vec<String>,
+    ) {
+        /* NB: as terms are stored in alpha-normal form, and shared, equal
+         * subterms have equal handles, so comparing handles suffices to prune
+         * the search.
+         */
+        if left == right {
+            return;
+        }
+
+        let left_term =
+            self.resolve_term_handle(left).expect(DANGLING_HANDLE_ERROR);
+        let right_term = self
+            .resolve_term_handle(right)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        match (left_term, right_term) {
+            (
+                Term::Variable {
+                    name: left_name,
+                    tau: left_tau,
+                },
+                Term::Variable {
+                    name: right_name,
+                    tau: right_tau,
+                },
+            ) if left_name == right_name => {
+                let path = Self::diff_path(path, "type");
+
+                self.type_differences(left_tau, right_tau, &path, differences)
+            }
+            (
+                Term::Constant {
+                    constant: left_constant,
+                    tau: left_tau,
+                },
+                Term::Constant {
+                    constant: right_constant,
+                    tau: right_tau,
+                },
+            ) if left_constant == right_constant => {
+                let path = Self::diff_path(path, "type");
+
+                self.type_differences(left_tau, right_tau, &path, differences)
+            }
+            (
+                Term::Application {
+                    left: left_function,
+                    right: left_argument,
+                },
+                Term::Application {
+                    left: right_function,
+                    right: right_argument,
+                },
+            ) => {
+                self.term_differences(
+                    left_function,
+                    right_function,
+                    &Self::diff_path(path, "fun"),
+                    differences,
+                );
+                self.term_differences(
+                    left_argument,
+                    right_argument,
+                    &Self::diff_path(path, "arg"),
+                    differences,
+                );
+            }
+            (
+                Term::Lambda {
+                    name: left_name,
+                    tau: left_tau,
+                    body: left_body,
+                },
+                Term::Lambda {
+                    name: right_name,
+                    tau: right_tau,
+                    body: right_body,
+                },
+            ) if left_name == right_name => {
+                self.type_differences(
+                    left_tau,
+                    right_tau,
+                    &Self::diff_path(path, "type"),
+                    differences,
+                );
+                self.term_differences(
+                    left_body,
+                    right_body,
+                    &Self::diff_path(path, "body"),
+                    differences,
+                );
+            }
+            _otherwise => differences.push(format!(
+                "{}: {} vs {}",
+                if path.is_empty() { "root" } else { path },
+                self.pretty_term(left),
+                self.pretty_term(right)
+            )),
+        }
+    }
+
+    /// Returns `Ok(text)`, where `text` describes the outermost positions at
+    /// which the terms pointed-to by `left` and `right` differ, one per line,
+    /// or is empty if the two terms are alpha-equivalent.  Each line gives
+    /// the path to a position, as dot-separated steps `fun` and `arg` into
+    /// applications, `type` and `body` into λ-abstractions, `type` into
+    /// variables and constants, and argument indices into types, followed by
+    /// the pretty-printed subterms, or subtypes, at that position.  The
+    /// description is intended for diagnostics, and is not guaranteed to be
+    /// stable, or parseable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if either `left` or
+    /// `right` do not point-to registered terms in the runtime state's
+    /// term-table.
+    pub fn term_diff<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<String, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Computing difference between terms with handles {} and {}.",
+            left.borrow(),
+            right.borrow()
+        );
+
+        self.resolve_term_handle(left.borrow())?;
+        self.resolve_term_handle(right.borrow())?;
+
+        let mut differences = Vec::new();
+
+        self.term_differences(
+            left.borrow(),
+            right.borrow(),
+            "",
+            &mut differences,
+        );
+
+        Ok(differences.join("\n"))
+    }
+
+    /// Returns `Ok(text)`, where `text` is a human-readable rendering of the
+    /// theorem pointed-to by `handle`, as its comma-separated premisses and
+    /// its conclusion separated by a turnstile, `|-`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to a registered theorem in the runtime state's theorem-table.
+    pub fn theorem_pretty_print<T>(
+        &self,
+        handle: T,
+    ) -> Result<String, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!("Pretty-printing theorem with handle {}.", handle.borrow());
+
+        let thm = self.resolve_theorem_handle(handle)?;
+
+        let premisses: Vec<String> = thm
+            .premisses()
+            .iter()
+            .map(|p| self.pretty_term(p))
+            .collect();
+        let conclusion = self.pretty_term(thm.conclusion());
+
+        if premisses.is_empty() {
+            Ok(format!("|- {}", conclusion))
+        } else {
+            Ok(format!("{} |- {}", premisses.join(", "), conclusion))
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Explaining failures.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the explanation recorded by the most recent failure that
+    /// recorded one, if any, and if it has not since been cleared.  The
+    /// explanation is intended for diagnostics, and is not guaranteed to be
+    /// stable, or parseable.
+    pub fn kernel_error_detail(&self) -> Option<String> {
+        info!("Retrieving error detail.");
+
+        self.error_detail.borrow().clone()
+    }
+
+    /// Discards the explanation recorded by the most recent failure, if any,
+    /// so that a later failure that records none is not confused with it.
+    pub fn kernel_clear_error_detail(&mut self) {
+        info!("Clearing error detail.");
+
+        *self.error_detail.get_mut() = None;
+    }
+
+    /// Records `detail`, explaining why `ShapeMismatch` is about to be
+    /// returned, given the pretty-printed `expected` and `actual` objects.
+    /// Returns `ErrorCode::ShapeMismatch`, for the caller to return.
+    fn shape_mismatch(
+        &self,
+        detail: &str,
+        expected: &str,
+        actual: &str,
+    ) -> ErrorCode {
+        /* NB: objects that differ in structure also differ in their rendering,
+         * unless they differ only in types, which are not printed within
+         * terms, so the first differing character locates the divergence.
+         */
+        let divergence = expected
+            .chars()
+            .zip(actual.chars())
+            .position(|(e, a)| e != a)
+            .unwrap_or_else(|| {
+                expected.chars().count().min(actual.chars().count())
+            });
+
+        let mut explanation = format!(
+            "ShapeMismatch: {}\nexpected: {}\n  actual: {}\n",
+            detail, expected, actual
+        );
+
+        if expected == actual {
+            explanation.push_str(
+                "(the two differ only in the types of their variables, or \
+                 constants)\n",
+            );
+        } else {
+            explanation
+                .push_str(&format!("          {}^\n", " ".repeat(divergence)));
+        }
+
+        *self.error_detail.borrow_mut() = Some(explanation);
+
+        ErrorCode::ShapeMismatch
+    }
+
+    /// As `shape_mismatch()`, for the terms pointed-to by `expected` and
+    /// `actual`, which must not dangle.
+    pub(crate) fn shape_mismatch_terms(
+        &self,
+        detail: &str,
+        expected: &Handle<tags::Term>,
+        actual: &Handle<tags::Term>,
+    ) -> ErrorCode {
+        self.shape_mismatch(
+            detail,
+            &self.pretty_term(expected),
+            &self.pretty_term(actual),
+        )
+    }
+
+    /// As `shape_mismatch()`, for the types pointed-to by `expected` and
+    /// `actual`, which must not dangle.
+    fn shape_mismatch_types(
+        &self,
+        detail: &str,
+        expected: &Handle<tags::Type>,
+        actual: &Handle<tags::Type>,
+    ) -> ErrorCode {
+        self.shape_mismatch(
+            detail,
+            &self.pretty_type(expected),
+            &self.pretty_type(actual),
+        )
+    }
+
+    /// As `shape_mismatch()`, for the sets of premisses `expected` and
+    /// `actual`, none of which may dangle.
+    fn shape_mismatch_premisses(
+        &self,
+        detail: &str,
+        expected: &[Handle<tags::Term>],
+        actual: &[Handle<tags::Term>],
+    ) -> ErrorCode {
+        let render = |premisses: &[Handle<tags::Term>]| {
+            let premisses: Vec<String> =
+                premisses.iter().map(|p| self.pretty_term(p)).collect();
+
+            format!("{{{}}}", premisses.join(", "))
+        };
+
+        self.shape_mismatch(detail, &render(expected), &render(actual))
+    }
+
+    /// As `shape_mismatch_premisses()`, where the premisses `actual` lack the
+    /// premiss `missing`, which must not dangle.
+    fn shape_mismatch_missing_premiss(
+        &self,
+        detail: &str,
+        missing: &Handle<tags::Term>,
+        actual: &[Handle<tags::Term>],
+    ) -> ErrorCode {
+        let mut expected = actual.to_vec();
+
+        expected.push(missing.clone());
+        expected.sort();
+
+        self.shape_mismatch_premisses(detail, &expected, actual)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Identifying the bootstrap theory.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a fingerprint of the bootstrap theory, i.e. of the kernel
+    /// objects registered under preallocated handles.  As these kernel objects
+    /// are fixed when the runtime state is created, every runtime state of the
+    /// same kernel has the same fingerprint.  The fingerprint is a 64-bit
+    /// FNV-1a hash of the preallocated objects, in handle order.
+    pub fn bootstrap_fingerprint(&self) -> u64 {
+        let mut objects: Vec<(usize, String)> = Vec::new();
+
+        objects.extend(
+            self.type_formers
+                .iter()
+                .filter(|(h, _a)| is_preallocated((*h).clone()))
+                .map(|(h, a)| (**h, format!("{:?}", a))),
+        );
+        objects.extend(
+            self.types
+                .iter()
+                .filter(|(h, _t)| is_preallocated((*h).clone()))
+                .map(|(h, t)| (**h, format!("{:?}", t))),
+        );
+        objects.extend(
+            self.constants
+                .iter()
+                .filter(|(h, _t)| is_preallocated((*h).clone()))
+                .map(|(h, t)| (**h, format!("{:?}", t))),
+        );
+        objects.extend(
+            self.terms
+                .iter()
+                .filter(|(h, _t)| is_preallocated((*h).clone()))
+                .map(|(h, t)| (**h, format!("{:?}", t))),
+        );
+
+        objects.sort();
+
+        objects
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |hash, (handle, object)| {
+                let hash = fnv1a(hash, &(*handle as u64).to_le_bytes());
+                fnv1a(hash, object.as_bytes())
+            })
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Enumerating kernel objects.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Enumerates the handles of registered kernel objects of kind `kind`, in
+    /// ascending order, starting from (and including) the handle `cursor` and
+    /// returning at most `limit` handles.  Also returns `Some(next)` if further
+    /// objects of kind `kind` remain to be enumerated, where `next` is the
+    /// cursor to pass to the next call, and `None` if the enumeration is
+    /// complete.
+    ///
+    /// Handles are returned untagged, as raw machine words, as the kind of
+    /// object they point-to is only known at runtime.  Note that the
+    /// enumeration reflects the state of the kernel's tables at the time of
+    /// each call: objects registered between calls with handles above the
+    /// cursor will also be enumerated.
+    pub fn kernel_enumerate(
+        &self,
+        kind: Kind,
+        cursor: usize,
+        limit: usize,
+    ) -> (Vec<usize>, Option<usize>) {
+        info!(
+            "Enumerating {:?} objects from handle {} (at most {}).",
+            kind, cursor, limit
+        );
+
+        let mut handles: Vec<usize> = match kind {
+            Kind::TypeFormer => self.type_formers.keys().map(|h| **h).collect(),
+            Kind::Type => self.types.keys().map(|h| **h).collect(),
+            Kind::Constant => self.constants.keys().map(|h| **h).collect(),
+            Kind::Term => self.terms.keys().map(|h| **h).collect(),
+            Kind::Theorem => self.theorems.keys().map(|h| **h).collect(),
+            Kind::Hypotheses => self.hypotheses.keys().map(|h| **h).collect(),
+            Kind::Capability => self.capabilities.keys().map(|h| **h).collect(),
+            Kind::Proof => self.proofs.keys().map(|h| **h).collect(),
+            #[cfg(feature = "sequents")]
+            Kind::Sequent => self.sequents.keys().map(|h| **h).collect(),
+            #[cfg(not(feature = "sequents"))]
+            Kind::Sequent => Vec::new(),
+            Kind::Substitution => {
+                self.substitutions.keys().map(|h| **h).collect()
+            }
+        };
+
+        handles.retain(|h| *h >= cursor);
+        handles.sort_unstable();
+        if handles.len() > limit {
+            let next = handles[limit];
+            handles.truncate(limit);
+
+            (handles, Some(next))
+        } else {
+            (handles, None)
+        }
+    }
+
+    /// Returns `Some(kind)` iff `handle` points-to a registered kernel object
+    /// of kind `kind`, and `None` if it points-to no kernel object at all.
+    /// Handles are never reissued, so at most one table contains `handle`.
+    pub fn object_kind(&self, handle: usize) -> Option<Kind> {
+        if self.type_formers.contains_key(&Handle::from(handle)) {
+            Some(Kind::TypeFormer)
+        } else if self.types.contains_key(&Handle::from(handle)) {
+            Some(Kind::Type)
+        } else if self.constants.contains_key(&Handle::from(handle)) {
+            Some(Kind::Constant)
+        } else if self.terms.contains_key(&Handle::from(handle)) {
+            Some(Kind::Term)
+        } else if self.theorems.contains_key(&Handle::from(handle)) {
+            Some(Kind::Theorem)
+        } else if self.hypotheses.contains_key(&Handle::from(handle)) {
+            Some(Kind::Hypotheses)
+        } else if self.capabilities.contains_key(&Handle::from(handle)) {
+            Some(Kind::Capability)
+        } else if self.proofs.contains_key(&Handle::from(handle)) {
+            Some(Kind::Proof)
+        } else if self.substitutions.contains_key(&Handle::from(handle)) {
+            Some(Kind::Substitution)
+        } else {
+            self.sequent_kind(handle)
+        }
+    }
+
+    /// Returns `Some(Kind::Sequent)` iff `handle` points-to a registered
+    /// multi-conclusion sequent.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_kind(&self, handle: usize) -> Option<Kind> {
+        if self.sequents.contains_key(&Handle::from(handle)) {
+            Some(Kind::Sequent)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `None`, as multi-conclusion sequents are only registered with
+    /// the experimental `sequents` feature.
+    #[cfg(not(feature = "sequents"))]
+    #[inline]
+    fn sequent_kind(&self, _handle: usize) -> Option<Kind> {
+        None
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Freeing kernel objects.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` iff some registered kernel object refers to the kernel
+    /// object with handle `handle`, of any kind, so that freeing it would
+    /// leave a dangling handle behind.  An interned set of hypotheses is
+    /// referred to by every theorem with those premisses.  Recorded proof
+    /// terms are never freed, so neither is anything that they mention.
+    ///
+    /// Every table of the runtime state is scanned, so this takes time linear
+    /// in the number of registered kernel objects.
+    pub(crate) fn object_is_referenced(&self, handle: usize) -> bool {
+        let mentions_type = |tau: &Type| match tau {
+            Type::Variable { .. } => false,
+            Type::Combination { arguments, .. } => {
+                arguments.iter().any(|argument| **argument == handle)
+            }
+        };
+        let mentions_term = |trm: &Term| match trm {
+            Term::Variable { tau, .. } | Term::Constant { tau, .. } => {
+                **tau == handle
+            }
+            Term::Application { left, right } => {
+                **left == handle || **right == handle
+            }
+            Term::Lambda { tau, body, .. } => {
+                **tau == handle || **body == handle
+            }
+        };
+        let mentions_theorem = |thm: &Theorem| {
+            **thm.conclusion() == handle
+                || thm.premisses().iter().any(|premiss| **premiss == handle)
+        };
+        let mentions_hypotheses =
+            |interned: &Handle<tags::Hypotheses>,
+             hyps: &Arc<Vec<Handle<tags::Term>>>| {
+                hyps.iter().any(|hyp| **hyp == handle)
+                    || (**interned == handle
+                        && self
+                            .theorems
+                            .values()
+                            .any(|thm| thm.premisses() == hyps.as_ref()))
+            };
+        let mentions_substitution = |sigma: &Arc<Substitution>| match sigma
+            .as_ref()
+        {
+            Substitution::Type(bindings) => {
+                bindings.iter().any(|(_name, tau)| **tau == handle)
+            }
+            Substitution::Term(bindings) => bindings
+                .iter()
+                .any(|(_name, tau, trm)| **tau == handle || **trm == handle),
+            Substitution::Composition(first, second) => {
+                **first == handle || **second == handle
+            }
+        };
+        let mentions_proof = |proof: &Proof| {
+            **proof.theorem() == handle
+                || proof.arguments().iter().any(|argument| match argument {
+                    ProofArgument::Name(_name) => false,
+                    ProofArgument::Type(tau) => **tau == handle,
+                    ProofArgument::Term(trm) => **trm == handle,
+                    ProofArgument::Theorem(thm) => **thm == handle,
+                })
+        };
+
+        if self.types.values().any(mentions_type)
+            || self.constants.values().any(|tau| **tau == handle)
+            || self.terms.values().any(mentions_term)
+            || self.theorems.values().any(mentions_theorem)
+            || self
+                .hypotheses
+                .iter()
+                .any(|(interned, hyps)| mentions_hypotheses(interned, hyps))
+            || self.substitutions.values().any(mentions_substitution)
+            || self.challenges.values().any(|trm| **trm == handle)
+            || self.proofs.values().any(mentions_proof)
+            || self.quotients.iter().any(|(tau, quotient)| {
+                **tau == handle || quotient.mentions(handle)
+            })
+            || self
+                .defined_types
+                .values()
+                .any(|defined| defined.mentions(handle))
+        {
+            return true;
+        }
+
+        #[cfg(feature = "budgets")]
+        if self
+            .budgets
+            .values()
+            .any(|budget| **budget.resource() == handle)
+        {
+            return true;
+        }
+
+        #[cfg(feature = "sequents")]
+        if self.sequents.values().any(|sequent| {
+            sequent
+                .premisses()
+                .iter()
+                .chain(sequent.conclusions())
+                .any(|trm| **trm == handle)
+        }) {
+            return true;
+        }
+
+        #[cfg(feature = "linear-arith")]
+        if let Some(signature) = &self.linear_arith {
+            if **signature.num() == handle {
+                return true;
+            }
+        }
+
+        /* NB: the theorems derived from a theorem are only found through it,
+         * so freeing it would leave them unreachable when retracting.
+         */
+        #[cfg(feature = "retraction")]
+        if self
+            .dependents
+            .get(&Handle::from(handle))
+            .is_some_and(|dependents| !dependents.is_empty())
+        {
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns `true` iff the kernel object with handle `handle` was
+    /// registered before the innermost speculative branch in progress began,
+    /// so that freeing it could not be undone were the branch discarded.
+    #[inline]
+    pub(crate) fn object_predates_speculation(&self, handle: usize) -> bool {
+        self.speculations.last().is_some_and(|speculation| {
+            self.handles.issued_before(handle, speculation.mark)
+        })
+    }
+
+    /// Removes the type, term, theorem, or interned set of hypotheses with
+    /// handle `handle` from the runtime state, along with everything that the
+    /// runtime state records about it.  Nothing may refer to the object, as
+    /// checked with `object_is_referenced`.  As handles are never reissued,
+    /// `handle` dangles forever after.
+    pub(crate) fn object_free(&mut self, handle: usize) {
+        let trm = Handle::<tags::Term>::from(handle);
+        let thm = Handle::<tags::Theorem>::from(handle);
+
+        self.types.remove(&Handle::from(handle));
+
+        if let Some(term) = self.terms.remove(&trm) {
+            self.terms_index.remove(&term);
+        }
+
+        self.term_heights.remove(&trm);
+        self.lambda_display_names.remove(&trm);
+        self.term_types.remove(&trm);
+
+        if let Some(theorem) = self.theorems.remove(&thm) {
+            if let Some(theorems) =
+                self.conclusions.get_mut(theorem.conclusion())
+            {
+                theorems.retain(|registered| *registered != thm);
+
+                /* NB: keys cannot be removed from a Bloom filter, but a
+                 * conclusion left in the filter only costs a lookup.
+                 */
+                if theorems.is_empty() {
+                    self.conclusions.remove(theorem.conclusion());
+                }
+            }
+        }
+
+        self.trust.remove(&thm);
+        self.theorem_proofs.remove(&thm);
+        self.statement_hashes
+            .retain(|_hash, registered| *registered != thm);
+
+        #[cfg(feature = "retraction")]
+        {
+            self.dependents.remove(&thm);
+
+            for dependents in self.dependents.values_mut() {
+                dependents.retain(|dependent| *dependent != thm);
+            }
+
+            self.retracted.remove(&thm);
+        }
+
+        if let Some(hyps) = self.hypotheses.remove(&Handle::from(handle)) {
+            self.hypotheses_index.remove(&hyps);
+        }
+
+        self.object_metadata.remove(&handle);
+        self.references.remove(&handle);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Freezing.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Freezes the runtime state, returning a base theory from which many
+    /// runtime states may be instantiated, possibly on different threads, as
+    /// `FrozenRuntimeState::instantiate`.  The tables of the runtime state are
+    /// shared with the base, rather than copied, so freezing is cheap, and the
+    /// runtime state may continue to be used: any later write to a table
+    /// copies it, leaving the base untouched.
+    pub fn freeze(&self) -> FrozenRuntimeState {
+        info!("Freezing runtime state.");
+
+        FrozenRuntimeState {
+            handles: self.handles.clone(),
+            type_formers: self.type_formers.clone(),
+            type_former_metadata: self.type_former_metadata.clone(),
+            type_former_names: self.type_former_names.clone(),
+            types: self.types.clone(),
+            constants: self.constants.clone(),
+            constant_names: self.constant_names.clone(),
+            terms: self.terms.clone(),
+            terms_index: self.terms_index.clone(),
+            term_heights: self.term_heights.clone(),
+            lambda_display_names: self.lambda_display_names.clone(),
+            term_types: self.term_types.clone(),
+            theorems: self.theorems.clone(),
+            hypotheses: self.hypotheses.clone(),
+            hypotheses_index: self.hypotheses_index.clone(),
+            substitutions: self.substitutions.clone(),
+            substitutions_index: self.substitutions_index.clone(),
+            challenges: self.challenges.clone(),
+            capabilities: self.capabilities.clone(),
+            epoch: self.epoch,
+            #[cfg(feature = "budgets")]
+            budgets: self.budgets.clone(),
+            proofs: self.proofs.clone(),
+            theorem_proofs: self.theorem_proofs.clone(),
+            record_proofs: self.record_proofs,
+            statement_hashes: self.statement_hashes.clone(),
+            detect_duplicates: self.detect_duplicates,
+            lazy_validation: self.lazy_validation,
+            datatype_constructors: self.datatype_constructors.clone(),
+            constructor_datatypes: self.constructor_datatypes.clone(),
+            quotients: self.quotients.clone(),
+            defined_types: self.defined_types.clone(),
+            #[cfg(feature = "sequents")]
+            sequents: self.sequents.clone(),
+            retired: self.retired.clone(),
+            object_metadata: self.object_metadata.clone(),
+            pending_theorem_metadata: self.pending_theorem_metadata.clone(),
+            trust: self.trust.clone(),
+            conclusions: self.conclusions.clone(),
+            conclusion_filter: self.conclusion_filter.clone(),
+            conclusion_shapes: self.conclusion_shapes.clone(),
+            scratch: self.scratch.clone(),
+            persist_scratch: self.persist_scratch,
+            variable_order: self.variable_order,
+            #[cfg(feature = "linear-arith")]
+            linear_arith: self.linear_arith.clone(),
+            #[cfg(feature = "retraction")]
+            dependents: self.dependents.clone(),
+            #[cfg(feature = "retraction")]
+            retracted: self.retracted.clone(),
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Restoring snapshots.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a new `RuntimeState`, as `new`, that issues handles
+    /// sequentially from `bound`, so that no handle below `bound` is ever
+    /// issued afresh, and may instead be restored from a snapshot.
+    #[inline]
+    pub(crate) fn with_handles_from(bound: usize) -> Self {
+        RuntimeState {
+            handles: HandleAllocator::sequential_from(bound),
+            ..RuntimeState::new()
+        }
+    }
+
+    /// Restores the type-former with handle `handle` and arity `arity`, and
+    /// with metadata `metadata`, if any, from a snapshot.
+    pub(crate) fn restore_type_former(
+        &mut self,
+        handle: Handle<tags::TypeFormer>,
+        arity: usize,
+        metadata: Option<TypeFormerMetadata>,
+    ) {
+        if let Some(metadata) = metadata {
+            self.type_former_name(&handle, metadata.name());
+            self.type_former_metadata.insert(handle.clone(), metadata);
+        }
+
+        self.type_formers.insert(handle, arity);
+    }
+
+    /// Restores the type `tau`, with handle `handle`, from a snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeFormerRegistered)` or
+    /// `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` mentions a type-former
+    /// or type that has not been restored, and
+    /// `Err(ErrorCode::MismatchedArity)` if `tau` applies a type-former to the
+    /// wrong number of arguments, as for `type_register_combination`.
+    ///
+    /// Returns `Err(ErrorCode::MalformedProofObject)` if `tau` has already been
+    /// restored under another handle, as the kernel shares types maximally.
+    pub(crate) fn restore_type(
+        &mut self,
+        handle: Handle<tags::Type>,
+        tau: Type,
+    ) -> Result<(), ErrorCode> {
+        if let Type::Combination { former, arguments } = &tau {
+            let arity = *self
+                .type_former_resolve(former)
+                .ok_or(ErrorCode::NoSuchTypeFormerRegistered)?;
+
+            if arity != arguments.len() {
+                return Err(ErrorCode::MismatchedArity);
+            }
+
+            for argument in arguments.iter() {
+                self.resolve_type_handle(argument)?;
+            }
+        }
+
+        if self.types.values().any(|registered| registered == &tau) {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        self.types.insert(handle, tau);
+
+        Ok(())
+    }
+
+    /// Restores the constant with handle `handle` and type `tau` from a
+    /// snapshot, entering it into the symbol table under `name`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` has not been
+    /// restored.
+    ///
+    /// Returns `Err(ErrorCode::MalformedProofObject)` if another constant has
+    /// already been restored under `name`, as names of constants are unique.
+    pub(crate) fn restore_constant(
+        &mut self,
+        handle: Handle<tags::Constant>,
+        tau: Handle<tags::Type>,
+        name: Option<String>,
+    ) -> Result<(), ErrorCode> {
+        self.resolve_type_handle(&tau)?;
+
+        if let Some(name) = name {
+            if self.constant_names.contains_key(&name) {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+
+            self.constant_names.insert(name, handle.clone());
+        }
+
+        self.constants.insert(handle, tau);
+
+        Ok(())
+    }
+
+    /// Restores the term `trm`, with handle `handle`, from a snapshot.  Like a
+    /// term registered whilst lazy validation is enabled, `trm` is only
+    /// type-checked once it is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)`,
+    /// `Err(ErrorCode::NoSuchConstantRegistered)` or
+    /// `Err(ErrorCode::NoSuchTermRegistered)` if `trm` mentions a type,
+    /// constant or term that has not been restored, and
+    /// `Err(ErrorCode::TypeNotWellformed)` if `trm` is a constant at a type
+    /// which is not an instance of the constant's type.
+    ///
+    /// Returns `Err(ErrorCode::MalformedProofObject)` if `trm` is a
+    /// λ-abstraction not in ⍺-normal form, or has already been restored under
+    /// another handle, as the kernel shares terms maximally.
+    pub(crate) fn restore_term(
+        &mut self,
+        handle: Handle<tags::Term>,
+        trm: Term,
+    ) -> Result<(), ErrorCode> {
+        match &trm {
+            Term::Variable { tau, .. } => {
+                self.resolve_type_handle(tau)?;
+            }
+            Term::Constant { constant, tau } => {
+                let cnst = self.constant_resolve(constant)?.clone();
+
+                self.resolve_type_handle(tau)?;
+
+                if !self.type_match(&cnst, tau, &mut Vec::new()) {
+                    return Err(ErrorCode::TypeNotWellformed);
+                }
+            }
+            Term::Application { left, right } => {
+                self.resolve_term_handle(left)?;
+                self.resolve_term_handle(right)?;
+            }
+            Term::Lambda { name, tau, body } => {
+                self.resolve_type_handle(tau)?;
+                self.resolve_term_handle(body)?;
+
+                if self.canonical_lambda(*name, tau.clone(), body.clone())
+                    != trm
+                {
+                    return Err(ErrorCode::MalformedProofObject);
+                }
+            }
+        }
+
+        if self.terms_index.contains_key(&trm) {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        let heights = self.heights_of(&trm);
+
+        self.terms_index.insert(trm.clone(), handle.clone());
+        self.terms.insert(handle.clone(), trm);
+
+        if heights != (0, 0) {
+            self.term_heights.insert(handle, heights);
+        }
+
+        Ok(())
+    }
+
+    /// Restores the theorem with handle `handle`, premisses `premisses`, and
+    /// conclusion `conclusion` from a snapshot, with trust level `trust`.  The
+    /// theorem is not re-proved, and no proof of it is recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if any of the premisses
+    /// or the conclusion has not been restored, and
+    /// `Err(ErrorCode::NotAProposition)` if any of them is not a proposition.
+    ///
+    /// Returns `Err(ErrorCode::NotAFunctionType)` or
+    /// `Err(ErrorCode::DomainTypeMismatch)` if any of them is not well-typed.
+    pub(crate) fn restore_theorem(
+        &mut self,
+        handle: Handle<tags::Theorem>,
+        premisses: Vec<Handle<tags::Term>>,
+        conclusion: Handle<tags::Term>,
+        trust: TrustLevel,
+    ) -> Result<(), ErrorCode> {
+        for trm in premisses.iter().chain(once(&conclusion)) {
+            if !self.term_type_is_proposition(trm)? {
+                return Err(ErrorCode::NotAProposition);
+            }
+        }
+
+        let mut thm = Theorem::new(premisses, conclusion.clone());
+
+        let (_handle, premisses) =
+            self.admit_hypotheses(thm.shared_premisses().clone());
+        thm.share_premisses(premisses);
+
+        self.theorems.insert(handle.clone(), thm);
+
+        self.conclusion_filter.insert(*conclusion as u64);
+        self.index_conclusion(&conclusion);
+        self.conclusions
+            .entry(conclusion)
+            .or_default()
+            .push(handle.clone());
+
+        if trust != TrustLevel::KernelProved {
+            self.trust.insert(handle, trust);
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a default, new instance of the `RuntimeState` containing empty
+/// heaps/tables of kernel objects and seeding the next handle counter
+/// appropriately.
+impl Default for RuntimeState {
+    /// Creates a new, default runtime state with all primitive kernel objects
+    /// registered and the next handle counter suitably initialized.
+    fn default() -> RuntimeState {
+        let type_formers = HashMap::from_iter(vec![
+            (PREALLOCATED_HANDLE_TYPE_FORMER_PROP, 0),
+            (PREALLOCATED_HANDLE_TYPE_FORMER_ARROW, 2),
+            (PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT, 2),
+        ]);
+
+        let types = HashMap::from_iter(vec![
+            (PREALLOCATED_HANDLE_TYPE_ALPHA, TYPE_ALPHA.clone()),
+            (PREALLOCATED_HANDLE_TYPE_BETA, TYPE_BETA.clone()),
+            (PREALLOCATED_HANDLE_TYPE_PROP, TYPE_PROP.clone()),
+            (
+                PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
+                TYPE_UNARY_CONNECTIVE.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+                TYPE_BINARY_CONNECTIVE.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+                TYPE_POLYMORPHIC_UNARY_PREDICATE.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+                TYPE_POLYMORPHIC_BINARY_PREDICATE.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+                TYPE_POLYMORPHIC_QUANTIFIER.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR,
+                TYPE_POLYMORPHIC_UNARY_OPERATOR.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR,
+                TYPE_POLYMORPHIC_BINARY_OPERATOR.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_CONDITIONAL,
+                TYPE_POLYMORPHIC_CONDITIONAL.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_FUNCTION,
+                TYPE_POLYMORPHIC_FUNCTION.clone(),
+            ),
+            (PREALLOCATED_HANDLE_TYPE_LET, TYPE_POLYMORPHIC_LET.clone()),
+            (
+                PREALLOCATED_HANDLE_TYPE_PRODUCT,
+                TYPE_POLYMORPHIC_PRODUCT.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR,
+                TYPE_POLYMORPHIC_PARTIAL_PAIR.clone(),
+            ),
+            (PREALLOCATED_HANDLE_TYPE_PAIR, TYPE_POLYMORPHIC_PAIR.clone()),
+            (
+                PREALLOCATED_HANDLE_TYPE_FIRST,
+                TYPE_POLYMORPHIC_FIRST.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_SECOND,
+                TYPE_POLYMORPHIC_SECOND.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_MEMBERSHIP,
+                TYPE_POLYMORPHIC_MEMBERSHIP.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+                TYPE_POLYMORPHIC_SET_OPERATOR.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+                TYPE_POLYMORPHIC_BINARY_SET_OPERATOR.clone(),
+            ),
+            (
+                PREALLOCATED_HANDLE_TYPE_SET_RELATION,
+                TYPE_POLYMORPHIC_SET_RELATION.clone(),
+            ),
+        ]);
+
+        let constants = HashMap::from_iter(vec![
+            (
+                PREALLOCATED_HANDLE_CONSTANT_TRUE,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_FALSE,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_NEGATION,
                 PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
-                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            (
+                PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
+                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
+                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
+                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_FORALL,
+                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_EXISTS,
+                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+                PREALLOCATED_HANDLE_TYPE_CONDITIONAL,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_LET,
+                PREALLOCATED_HANDLE_TYPE_LET,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_PAIR,
+                PREALLOCATED_HANDLE_TYPE_PAIR,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_FIRST,
+                PREALLOCATED_HANDLE_TYPE_FIRST,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_SECOND,
+                PREALLOCATED_HANDLE_TYPE_SECOND,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
+                PREALLOCATED_HANDLE_TYPE_MEMBERSHIP,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+                PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
+                PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
+                PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_UNION,
+                PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+                PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+            ),
+            (
+                PREALLOCATED_HANDLE_CONSTANT_SUBSET,
+                PREALLOCATED_HANDLE_TYPE_SET_RELATION,
+            ),
+        ]);
+
+        let terms = HashMap::from_iter(vec![
+            (PREALLOCATED_HANDLE_TERM_TRUE, TERM_TRUE_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_FALSE, TERM_FALSE_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_FORALL, TERM_FORALL_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_EXISTS, TERM_EXISTS_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_NEGATION, TERM_NEGATION_CONSTANT),
+            (
+                PREALLOCATED_HANDLE_TERM_IMPLICATION,
+                TERM_IMPLICATION_CONSTANT,
+            ),
+            (
+                PREALLOCATED_HANDLE_TERM_CONJUNCTION,
+                TERM_CONJUNCTION_CONSTANT,
+            ),
+            (
+                PREALLOCATED_HANDLE_TERM_DISJUNCTION,
+                TERM_DISJUNCTION_CONSTANT,
+            ),
+            (PREALLOCATED_HANDLE_TERM_EQUALITY, TERM_EQUALITY_CONSTANT),
+            (
+                PREALLOCATED_HANDLE_TERM_CONDITIONAL,
+                TERM_CONDITIONAL_CONSTANT,
+            ),
+            (PREALLOCATED_HANDLE_TERM_LET, TERM_LET_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_PAIR, TERM_PAIR_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_FIRST, TERM_FIRST_CONSTANT),
+            (PREALLOCATED_HANDLE_TERM_SECOND, TERM_SECOND_CONSTANT),
+            (
+                PREALLOCATED_HANDLE_TERM_MEMBERSHIP,
+                TERM_MEMBERSHIP_CONSTANT,
+            ),
+            (
+                PREALLOCATED_HANDLE_TERM_COMPREHENSION,
+                TERM_COMPREHENSION_CONSTANT,
+            ),
+            (PREALLOCATED_HANDLE_TERM_EMPTY_SET, TERM_EMPTY_SET_CONSTANT),
+            (
+                PREALLOCATED_HANDLE_TERM_UNIVERSAL_SET,
+                TERM_UNIVERSAL_SET_CONSTANT,
+            ),
+            (PREALLOCATED_HANDLE_TERM_UNION, TERM_UNION_CONSTANT),
+            (
+                PREALLOCATED_HANDLE_TERM_INTERSECTION,
+                TERM_INTERSECTION_CONSTANT,
+            ),
+            (PREALLOCATED_HANDLE_TERM_SUBSET, TERM_SUBSET_CONSTANT),
+        ]);
+
+        let theorems = HashMap::from_iter(vec![]);
+        let hypotheses = HashMap::from_iter(vec![]);
+        let hypotheses_index = HashMap::from_iter(vec![]);
+        let challenges = HashMap::from_iter(vec![]);
+        let capabilities = HashMap::from_iter(vec![]);
+
+        RuntimeState {
+            handles: HandleAllocator::sequential(),
+            type_formers: type_formers.into(),
+            type_former_metadata: HashMap::new(),
+            type_former_names: HashMap::new(),
+            types: types.into(),
+            constants: constants.into(),
+            constant_names: HashMap::new(),
+            terms_index: Shared::from(
+                terms
+                    .iter()
+                    .map(|(handle, trm)| (trm.clone(), handle.clone()))
+                    .collect::<HashMap<_, _>>(),
+            ),
+            terms: terms.into(),
+            term_heights: Shared::default(),
+            lambda_display_names: Shared::default(),
+            term_types: Shared::default(),
+            theorems: theorems.into(),
+            hypotheses: hypotheses.into(),
+            hypotheses_index: hypotheses_index.into(),
+            substitutions: Shared::default(),
+            substitutions_index: Shared::default(),
+            challenges,
+            capabilities,
+            epoch: 0,
+            #[cfg(feature = "budgets")]
+            budgets: HashMap::new(),
+            proofs: Shared::default(),
+            theorem_proofs: Shared::default(),
+            record_proofs: false,
+            statement_hashes: Shared::default(),
+            detect_duplicates: false,
+            lazy_validation: false,
+            datatype_constructors: HashMap::new(),
+            constructor_datatypes: HashMap::new(),
+            quotients: HashMap::new(),
+            defined_types: HashMap::new(),
+            #[cfg(feature = "sequents")]
+            sequents: HashMap::new(),
+            speculations: Vec::new(),
+            retired: Vec::new(),
+            error_detail: RefCell::new(None),
+            observers: Observers::default(),
+            object_metadata: HashMap::new(),
+            references: HashMap::new(),
+            pending_theorem_metadata: HashMap::new(),
+            trust: Shared::default(),
+            conclusions: Shared::default(),
+            conclusion_filter: BloomFilter::default(),
+            conclusion_shapes: Shared::default(),
+            scratch: BTreeMap::new(),
+            persist_scratch: false,
+            variable_order: VariableOrder::FirstOccurrence,
+            #[cfg(feature = "linear-arith")]
+            linear_arith: None,
+            #[cfg(feature = "retraction")]
+            dependents: HashMap::new(),
+            #[cfg(feature = "retraction")]
+            retracted: HashSet::new(),
+        }
+    }
+}
+
+/// Module containing theorem-related tests.
+#[cfg(test)]
+mod test {
+    use crate::{
+        capability::Expiry,
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, Kind, PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+            PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+            PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
+            PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
+            PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
+            PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
+            PREALLOCATED_HANDLE_CONSTANT_EXISTS,
+            PREALLOCATED_HANDLE_CONSTANT_FALSE,
+            PREALLOCATED_HANDLE_CONSTANT_FIRST,
+            PREALLOCATED_HANDLE_CONSTANT_FORALL,
+            PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
+            PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+            PREALLOCATED_HANDLE_CONSTANT_LET,
+            PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
+            PREALLOCATED_HANDLE_CONSTANT_NEGATION,
+            PREALLOCATED_HANDLE_CONSTANT_PAIR,
+            PREALLOCATED_HANDLE_CONSTANT_SECOND,
+            PREALLOCATED_HANDLE_CONSTANT_SUBSET,
+            PREALLOCATED_HANDLE_CONSTANT_TRUE,
+            PREALLOCATED_HANDLE_CONSTANT_UNION,
+            PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
+            PREALLOCATED_HANDLE_KINDS, PREALLOCATED_HANDLE_TERM_COMPREHENSION,
+            PREALLOCATED_HANDLE_TERM_CONDITIONAL,
+            PREALLOCATED_HANDLE_TERM_CONJUNCTION,
+            PREALLOCATED_HANDLE_TERM_DISJUNCTION,
+            PREALLOCATED_HANDLE_TERM_EMPTY_SET,
+            PREALLOCATED_HANDLE_TERM_EQUALITY, PREALLOCATED_HANDLE_TERM_EXISTS,
+            PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_FIRST,
+            PREALLOCATED_HANDLE_TERM_FORALL,
+            PREALLOCATED_HANDLE_TERM_IMPLICATION,
+            PREALLOCATED_HANDLE_TERM_INTERSECTION,
+            PREALLOCATED_HANDLE_TERM_LET, PREALLOCATED_HANDLE_TERM_MEMBERSHIP,
+            PREALLOCATED_HANDLE_TERM_NEGATION, PREALLOCATED_HANDLE_TERM_PAIR,
+            PREALLOCATED_HANDLE_TERM_SECOND, PREALLOCATED_HANDLE_TERM_SUBSET,
+            PREALLOCATED_HANDLE_TERM_TRUE, PREALLOCATED_HANDLE_TERM_UNION,
+            PREALLOCATED_HANDLE_TERM_UNIVERSAL_SET,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+            PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR,
+            PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+            PREALLOCATED_HANDLE_TYPE_CONDITIONAL,
+            PREALLOCATED_HANDLE_TYPE_FIRST,
+            PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+            PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+            PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+            PREALLOCATED_HANDLE_TYPE_FUNCTION, PREALLOCATED_HANDLE_TYPE_LET,
+            PREALLOCATED_HANDLE_TYPE_MEMBERSHIP, PREALLOCATED_HANDLE_TYPE_PAIR,
+            PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR,
+            PREALLOCATED_HANDLE_TYPE_PRODUCT, PREALLOCATED_HANDLE_TYPE_PROP,
+            PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+            PREALLOCATED_HANDLE_TYPE_SECOND,
+            PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+            PREALLOCATED_HANDLE_TYPE_SET_RELATION,
+            PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
+            PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR,
+            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+            PREALLOCATED_HANDLE_UPPER_BOUND,
+        },
+        metadata::TypeFormerMetadata,
+        name::{canonical_index, canonical_name, VariableOrder},
+        proof::{ProofArgument, Rule},
+        runtime_state::{FrozenRuntimeState, RuntimeState},
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Initial theory tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests all primitive type-formers are registered in the initial theory.
+    #[test]
+    pub fn initial_theory0() {
+        let state = RuntimeState::new();
+
+        assert!(state
+            .type_former_resolve(&PREALLOCATED_HANDLE_TYPE_FORMER_PROP)
+            .is_some());
+        assert!(state
+            .type_former_resolve(&PREALLOCATED_HANDLE_TYPE_FORMER_ARROW)
+            .is_some());
+        assert!(state
+            .type_former_resolve(&PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT)
+            .is_some());
+    }
+
+    /// Tests all primitive constants are registered in the initial theory.
+    #[test]
+    pub fn initial_theory1() {
+        let state = RuntimeState::new();
+
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_EXISTS)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_FORALL)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_IMPLICATION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_TRUE)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_FALSE)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_EQUALITY)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_NEGATION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_LET)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_PAIR)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_FIRST)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_SECOND)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_UNION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_INTERSECTION)
+            .is_ok());
+        assert!(state
+            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_SUBSET)
+            .is_ok());
+    }
+
+    /// Tests all primitive types are registered in the initial theory.
+    #[test]
+    pub fn initial_theory2() {
+        let state = RuntimeState::new();
+
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_PROP)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_QUANTIFIER)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BETA)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_UNARY_OPERATOR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_OPERATOR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_CONDITIONAL)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_FUNCTION)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_LET)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_PRODUCT)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_PARTIAL_PAIR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_PAIR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_FIRST)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_SECOND)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_MEMBERSHIP)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_SET_OPERATOR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR)
+            .is_ok());
+        assert!(state
+            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_SET_RELATION)
+            .is_ok());
+    }
+
+    /// Tests all primitive terms are registered in the initial theory.
+    #[test]
+    pub fn initial_theory3() {
+        let state = RuntimeState::new();
+
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_EXISTS)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_FORALL)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_IMPLICATION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_CONJUNCTION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_DISJUNCTION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_TRUE)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_FALSE)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_EQUALITY)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_NEGATION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_CONDITIONAL)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_LET)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_PAIR)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_FIRST)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_SECOND)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_MEMBERSHIP)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_COMPREHENSION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_EMPTY_SET)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_UNIVERSAL_SET)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_UNION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_INTERSECTION)
+            .is_ok());
+        assert!(state
+            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_SUBSET)
+            .is_ok());
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Free-variable tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    pub fn free_variables0() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let fvs = state.term_free_variables(&v).unwrap();
+
+        assert_eq!(fvs, vec![(&0_u64, &PREALLOCATED_HANDLE_TYPE_PROP)]);
+    }
+
+    #[test]
+    pub fn free_variables1() {
+        let state = RuntimeState::new();
+
+        let fvs = state
+            .term_free_variables(&PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert!(fvs.is_empty());
+    }
+
+    #[test]
+    pub fn free_variables2() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let l = state
+            .term_register_lambda(0_u64, PREALLOCATED_HANDLE_TYPE_PROP, v)
+            .unwrap();
+
+        let fvs = state.term_free_variables(&l).unwrap();
+
+        assert!(fvs.is_empty());
+    }
+
+    #[test]
+    pub fn free_variables3() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            )
+            .unwrap();
+        let l = state
+            .term_register_lambda(0_u64, PREALLOCATED_HANDLE_TYPE_PROP, v)
+            .unwrap();
+
+        let fvs = state.term_free_variables(&l).unwrap();
+
+        assert_eq!(
+            fvs,
+            vec![(&0_u64, &PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE)]
+        );
+    }
+
+    #[test]
+    pub fn free_variables4() {
+        let mut state = RuntimeState::new();
+
+        let l = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        let fvs = state.term_free_variables(&l).unwrap();
+
+        assert!(fvs.is_empty())
+    }
+
+    #[test]
+    pub fn free_variables5() {
+        let mut state = RuntimeState::new();
+
+        let l = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let v = state
+            .term_register_variable(5_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let t = state.term_register_application(l, v).unwrap();
+
+        let fvs = state.term_free_variables(&t).unwrap();
+
+        assert_eq!(fvs, vec![(&5_u64, &PREALLOCATED_HANDLE_TYPE_PROP)])
+    }
+
+    /// Tests that free variables are listed once each, in order of first
+    /// occurrence, or by name, with variables sharing a name in order of first
+    /// occurrence, if the runtime state's variable order demands it.
+    #[test]
+    pub fn free_variables6() {
+        let mut state = RuntimeState::new();
+
+        let prop = PREALLOCATED_HANDLE_TYPE_PROP;
+        let alpha = state.type_register_variable(3_u64);
+        let predicate = state
+            .type_register_function(prop.clone(), prop.clone())
+            .unwrap();
+        let relation = state
+            .type_register_function(prop.clone(), predicate)
+            .unwrap();
+        let alpha_predicate = state
+            .type_register_function(alpha.clone(), prop.clone())
+            .unwrap();
+
+        let f = state
+            .term_register_variable(7_u64, relation.clone())
+            .unwrap();
+        let x = state.term_register_variable(9_u64, prop.clone()).unwrap();
+        let y = state.term_register_variable(2_u64, prop.clone()).unwrap();
+        let k = state
+            .term_register_variable(4_u64, alpha_predicate.clone())
+            .unwrap();
+        let z = state.term_register_variable(2_u64, alpha.clone()).unwrap();
+
+        let fy = state
+            .term_register_application(f.clone(), y.clone())
+            .unwrap();
+        let fyx = state.term_register_application(fy, x.clone()).unwrap();
+        let fx = state.term_register_application(f, x).unwrap();
+        let fxy = state.term_register_application(fx, y).unwrap();
+        let kz = state.term_register_application(k, z).unwrap();
+        let left = state.term_register_conjunction(fyx, fxy).unwrap();
+        let t = state.term_register_conjunction(left, kz).unwrap();
+
+        assert_eq!(
+            state.term_free_variables(&t).unwrap(),
+            vec![
+                (&7_u64, &relation),
+                (&2_u64, &prop),
+                (&9_u64, &prop),
+                (&4_u64, &alpha_predicate),
+                (&2_u64, &alpha),
+            ]
+        );
+
+        state.set_variable_order(VariableOrder::ByName);
+
+        assert_eq!(
+            state.term_free_variables(&t).unwrap(),
+            vec![
+                (&2_u64, &prop),
+                (&2_u64, &alpha),
+                (&4_u64, &alpha_predicate),
+                (&7_u64, &relation),
+                (&9_u64, &prop),
+            ]
+        );
+    }
+
+    /// Tests that the type-variables of types and terms are listed once each,
+    /// in order of first occurrence, or by name if the runtime state's
+    /// variable order demands it, and that the order survives a reset.
+    #[test]
+    pub fn free_variables7() {
+        let mut state = RuntimeState::new();
+
+        let alpha = state.type_register_variable(3_u64);
+        let beta = state.type_register_variable(1_u64);
+        let gamma = state.type_register_variable(2_u64);
+
+        let left = state
+            .type_register_function(alpha.clone(), beta.clone())
+            .unwrap();
+        let right = state.type_register_function(gamma, beta.clone()).unwrap();
+        let tau = state.type_register_function(left, right).unwrap();
+
+        assert_eq!(
+            state.type_variables(&tau).unwrap(),
+            vec![&3_u64, &1_u64, &2_u64]
+        );
+
+        let prop = PREALLOCATED_HANDLE_TYPE_PROP;
+        let alpha_predicate = state
+            .type_register_function(alpha.clone(), prop.clone())
+            .unwrap();
+        let beta_predicate =
+            state.type_register_function(beta.clone(), prop).unwrap();
+
+        let k = state
+            .term_register_variable(4_u64, alpha_predicate)
+            .unwrap();
+        let z = state.term_register_variable(2_u64, alpha).unwrap();
+        let m = state.term_register_variable(6_u64, beta_predicate).unwrap();
+        let w = state.term_register_variable(8_u64, beta).unwrap();
+
+        let kz = state.term_register_application(k, z).unwrap();
+        let mw = state.term_register_application(m, w).unwrap();
+        let t = state.term_register_conjunction(kz, mw).unwrap();
+
+        assert_eq!(
+            state.term_type_variables(&t).unwrap(),
+            vec![&3_u64, &1_u64]
+        );
+
+        state.set_variable_order(VariableOrder::ByName);
+
+        assert_eq!(
+            state.type_variables(&tau).unwrap(),
+            vec![&1_u64, &2_u64, &3_u64]
+        );
+        assert_eq!(
+            state.term_type_variables(&t).unwrap(),
+            vec![&1_u64, &3_u64]
+        );
+
+        state.reset();
+
+        assert_eq!(state.variable_order(), VariableOrder::ByName);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Alpha-equivalence tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    pub fn alpha_equivalence0() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert!(state.is_alpha_equivalent(&v, &v).unwrap());
+    }
+
+    #[test]
+    pub fn alpha_equivalence1() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert!(!state.is_alpha_equivalent(&v, &q).unwrap());
+    }
+
+    #[test]
+    pub fn alpha_equivalence2() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            )
+            .unwrap();
+
+        assert!(!state.is_alpha_equivalent(&v, &q).unwrap());
+    }
+
+    #[test]
+    pub fn alpha_equivalence3() {
+        let mut state = RuntimeState::new();
+
+        let v = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let l = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                v.clone(),
+            )
+            .unwrap();
+        let c = state.term_register_application(l, v).unwrap();
+
+        assert!(state.is_alpha_equivalent(&c, &c).unwrap());
+    }
+
+    #[test]
+    pub fn alpha_equivalence4() {
+        let mut state = RuntimeState::new();
+
+        let v0 = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let l0 = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                v0.clone(),
+            )
+            .unwrap();
+        let c0 = state.term_register_application(l0, v0.clone()).unwrap();
+
+        let v1 = state
+            .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let l1 = state
+            .term_register_lambda(
+                1_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                v1.clone(),
+            )
+            .unwrap();
+        let c1 = state.term_register_application(l1, v0.clone()).unwrap();
+
+        assert!(state.is_alpha_equivalent(&c0, &c1).unwrap());
+    }
+
+    /// Tests that nested binders are paired up correctly, including where
+    /// they swap names or shadow each other, and that binders of different
+    /// types are never identified.
+    #[test]
+    pub fn alpha_equivalence5() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let xy = state
+            .term_register_conjunction(x.clone(), y.clone())
+            .unwrap();
+        let yx = state
+            .term_register_conjunction(y.clone(), x.clone())
+            .unwrap();
+
+        let lambdas = |state: &mut RuntimeState, outer, inner, body| {
+            let body = state
+                .term_register_lambda(
+                    inner,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    body,
+                )
+                .unwrap();
+
+            state
+                .term_register_lambda(
+                    outer,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    body,
+                )
+                .unwrap()
+        };
+
+        /* `λx y. x ∧ y`, `λy x. y ∧ x` and `λx y. y ∧ x`. */
+        let l0 = lambdas(&mut state, 0_u64, 1_u64, xy);
+        let l1 = lambdas(&mut state, 1_u64, 0_u64, yx.clone());
+        let l2 = lambdas(&mut state, 0_u64, 1_u64, yx);
+
+        assert_eq!(l0, l1);
+        assert_ne!(l0, l2);
+
+        /* `λx x. x`, `λx y. y` and `λx y. x`. */
+        let l3 = lambdas(&mut state, 0_u64, 0_u64, x.clone());
+        let l4 = lambdas(&mut state, 0_u64, 1_u64, y);
+        let l5 = lambdas(&mut state, 0_u64, 1_u64, x);
+
+        assert_eq!(l3, l4);
+        assert_ne!(l3, l5);
+
+        let l6 = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let l7 = state
+            .term_register_lambda(
+                0_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        assert_ne!(l6, l7);
+    }
+
+    /// Tests that λ-abstractions remember the names first chosen for their
+    /// bound variables, and that splitting them with their display names
+    /// recovers the terms as registered.
+    #[test]
+    pub fn alpha_equivalence6() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(3_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(4_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let nx = state.term_register_negation(x).unwrap();
+        let ny = state.term_register_negation(y).unwrap();
+
+        let l0 = state
+            .term_register_lambda(
+                3_u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                nx.clone(),
+            )
+            .unwrap();
+        let l1 = state
+            .term_register_lambda(4_u64, PREALLOCATED_HANDLE_TYPE_PROP, ny)
+            .unwrap();
+
+        assert_eq!(l0, l1);
+        assert_eq!(
+            state.term_split_lambda(&l0).map(|(n, _t, _b)| *n),
+            Ok(canonical_name(0))
+        );
+        assert_eq!(state.term_lambda_display_name(&l0), Ok(3_u64));
+        assert_eq!(
+            state.term_split_lambda_display(&l0),
+            Ok((3_u64, PREALLOCATED_HANDLE_TYPE_PROP, nx))
+        );
+        assert_eq!(
+            state.term_lambda_display_name(PREALLOCATED_HANDLE_TERM_TRUE),
+            Err(ErrorCode::NotALambda)
+        );
+    }
+
+    /// Tests that binding a variable never captures a free variable with a
+    /// canonical name, and that the canonical name chosen does not depend on
+    /// the names of bound variables.
+    #[test]
+    pub fn alpha_equivalence7() {
+        let mut state = RuntimeState::new();
+
+        let c0 = state
+            .term_register_variable(
+                canonical_name(0),
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let c1 = state
+            .term_register_variable(
+                canonical_name(1),
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let x = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        /* `λx. x ∧ c₀`, where `c₀` is free. */
+        let body = state.term_register_conjunction(x, c0.clone()).unwrap();
+        let l0 = state
+            .term_register_lambda(0_u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        assert_eq!(
+            state.term_free_variables(&l0),
+            Ok(vec![(&canonical_name(0), &PREALLOCATED_HANDLE_TYPE_PROP)])
+        );
+
+        /* `λc₁. λy. y ∧ c₀ ∧ c₁` and `λx. λy. y ∧ c₀ ∧ x`. */
+        let lambdas = |state: &mut RuntimeState, outer, outer_var| {
+            let y = state
+                .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+                .unwrap();
+            let inner = state
+                .term_register_conjunction(c0.clone(), outer_var)
+                .unwrap();
+            let inner = state.term_register_conjunction(y, inner).unwrap();
+            let inner = state
+                .term_register_lambda(
+                    1_u64,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    inner,
+                )
+                .unwrap();
+
+            state
+                .term_register_lambda(
+                    outer,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    inner,
+                )
+                .unwrap()
+        };
+
+        let x = state
+            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let l1 = lambdas(&mut state, canonical_name(1), c1);
+        let l2 = lambdas(&mut state, 0_u64, x);
+
+        assert_eq!(l1, l2);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Substitution tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Type-checking tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Inference tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that terms are annotated with their types as they are registered,
+    /// including every level of a deep spine, and that the annotations agree
+    /// with the types inferred.
+    #[test]
+    pub fn inference0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let mut spine = vec![p.clone()];
+
+        for _i in 0..64 {
+            let inner = spine.last().unwrap().clone();
+            spine.push(state.term_register_negation(inner).unwrap());
+        }
+
+        for level in &spine {
+            assert_eq!(
+                state.term_types.get(level),
+                Some(&PREALLOCATED_HANDLE_TYPE_PROP)
+            );
+        }
+
+        let lambda = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                spine.last().unwrap().clone(),
+            )
+            .unwrap();
+        let tau = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+
+        assert_eq!(state.term_types.get(&lambda), Some(&tau));
+        assert_eq!(state.term_type_infer(&lambda), Ok(tau));
+
+        let application =
+            state.term_register_application(lambda, p.clone()).unwrap();
+
+        assert_eq!(
+            state.term_type_infer(&application),
+            Ok(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
+        assert_eq!(
+            state.term_register_application(p.clone(), p),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    /// Tests that the type annotations of terms registered in a discarded
+    /// speculative branch are discarded with them.
+    #[test]
+    pub fn inference1() {
+        let mut state = RuntimeState::new();
+
+        state.speculate_begin();
+
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(
+            state.term_type_infer(&q),
+            Ok(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
+        assert_eq!(state.speculate_end(false), Ok(()));
+
+        assert!(!state.term_types.contains_key(&q));
+        assert_eq!(state.term_type_infer(&q), Err(ErrorCode::StaleHandle));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Lazy validation tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that an ill-typed term may be registered whilst lazy validation is
+    /// enabled, but is rejected when validated or used to form a theorem.
+    #[test]
+    pub fn lazy_validation0() {
+        let mut state = RuntimeState::new();
+
+        state.set_lazy_validation(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let application =
+            state.term_register_application(p.clone(), p).unwrap();
+        let negation =
+            state.term_register_negation(application.clone()).unwrap();
+
+        assert_eq!(
+            state.term_validate(&application),
+            Err(ErrorCode::NotAFunctionType)
+        );
+        assert_eq!(
+            state.theorem_register_assumption(negation),
+            Err(ErrorCode::NotAFunctionType)
+        );
+        assert_eq!(
+            state.theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                application
+            ),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    /// Tests that well-typed terms registered whilst lazy validation is enabled
+    /// may be used to form theorems, and that disabling lazy validation
+    /// restores eager rejection of ill-typed terms.
+    #[test]
+    pub fn lazy_validation1() {
+        let mut state = RuntimeState::new();
+
+        assert!(state.set_lazy_validation(true).is_validating_lazily());
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        let application =
+            state.term_register_application(lambda, p.clone()).unwrap();
+
+        assert!(!state.term_types.contains_key(&application));
+        assert_eq!(state.term_validate(&application), Ok(()));
+        assert!(state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(application)
+            .is_ok());
+
+        state.set_lazy_validation(false);
+
+        assert!(!state.is_validating_lazily());
+        assert_eq!(
+            state.term_register_application(p.clone(), p),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Enumeration tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that the initial theory's type-formers are enumerated in order.
+    #[test]
+    pub fn enumerate0() {
+        let state = RuntimeState::new();
+
+        assert_eq!(
+            state.kernel_enumerate(Kind::TypeFormer, 0, 10),
+            (
+                vec![
+                    *PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+                    *PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
+                    *PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT
+                ],
+                None
+            )
+        );
+    }
+
+    /// Tests that paging through the terms of the initial theory visits every
+    /// term exactly once.
+    #[test]
+    pub fn enumerate1() {
+        let state = RuntimeState::new();
+
+        let mut cursor = 0;
+        let mut visited = Vec::new();
+
+        loop {
+            let (page, next) = state.kernel_enumerate(Kind::Term, cursor, 2);
+
+            assert!(page.len() <= 2);
+            visited.extend(page);
+
+            match next {
+                None => break,
+                Some(next) => cursor = next,
+            }
+        }
+
+        assert_eq!(
+            visited,
+            (19..28)
+                .chain(35..37)
+                .chain(46..49)
+                .chain(60..PREALLOCATED_HANDLE_UPPER_BOUND)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// Tests that newly-registered theorems are enumerated, and that a cursor
+    /// above every registered handle yields nothing.
+    #[test]
+    pub fn enumerate2() {
+        let mut state = RuntimeState::new();
+
+        assert_eq!(
+            state.kernel_enumerate(Kind::Theorem, 0, 10),
+            (vec![], None)
+        );
+
+        let thm = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(
+            state.kernel_enumerate(Kind::Theorem, 0, 10),
+            (vec![*thm], None)
+        );
+        assert_eq!(
+            state.kernel_enumerate(Kind::Theorem, *thm + 1, 10),
+            (vec![], None)
+        );
+    }
+
+    /// Tests that every pre-allocated handle generated from the ABI
+    /// specification is registered, at its kind, in a fresh kernel, and that no
+    /// other handle below the upper-bound is.
+    #[test]
+    pub fn enumerate3() {
+        let state = RuntimeState::new();
+
+        assert_eq!(
+            PREALLOCATED_HANDLE_KINDS.len(),
+            PREALLOCATED_HANDLE_UPPER_BOUND
+        );
+
+        for kind in [Kind::TypeFormer, Kind::Type, Kind::Constant, Kind::Term] {
+            let (registered, _next) = state.kernel_enumerate(
+                kind,
+                0,
+                PREALLOCATED_HANDLE_UPPER_BOUND,
+            );
+
+            let expected: Vec<usize> = PREALLOCATED_HANDLE_KINDS
+                .iter()
+                .enumerate()
+                .filter(|(_handle, k)| **k == kind)
+                .map(|(handle, _k)| handle)
+                .collect();
+
+            assert_eq!(registered, expected);
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Hypotheses tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that interning is insensitive to the order of, and duplicates in,
+    /// the hypotheses, and that resolving gets you back the sorted set.
+    #[test]
+    pub fn hypotheses0() {
+        let mut state = RuntimeState::new();
+
+        let left = state
+            .hypotheses_intern(vec![
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TERM_FALSE,
+            ])
+            .unwrap();
+        let right = state
+            .hypotheses_intern(vec![
+                PREALLOCATED_HANDLE_TERM_FALSE,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TERM_FALSE,
+            ])
+            .unwrap();
+
+        assert_eq!(left, right);
+        assert_eq!(
+            state.hypotheses_resolve(&left),
+            Ok(&vec![
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TERM_FALSE
+            ])
+        );
+    }
+
+    /// Tests that theorems with the same premisses share a single interned
+    /// list, and that the list is interned under the same handle as the one
+    /// returned when interning the premisses directly.
+    #[test]
+    pub fn hypotheses1() {
+        let mut state = RuntimeState::new();
+
+        let left = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let right = state.theorem_register_truth_introduction().unwrap();
+        let right = state
+            .theorem_register_weaken(PREALLOCATED_HANDLE_TERM_TRUE, right)
+            .unwrap();
+
+        let left = state.resolve_theorem_handle(&left).unwrap();
+        let right = state.resolve_theorem_handle(&right).unwrap();
+
+        assert!(std::ptr::eq(left.premisses(), right.premisses()));
+
+        let hyps = state.hypotheses.len();
+        let handle = state
+            .hypotheses_intern(vec![PREALLOCATED_HANDLE_TERM_TRUE])
+            .unwrap();
+
+        assert_eq!(state.hypotheses.len(), hyps);
+        assert_eq!(
+            state.hypotheses_resolve(handle),
+            Ok(&vec![PREALLOCATED_HANDLE_TERM_TRUE])
+        );
+    }
+
+    /// Tests that only registered propositions can be interned, and that
+    /// dangling handles cannot be resolved.
+    #[test]
+    pub fn hypotheses2() {
+        let mut state = RuntimeState::new();
+
+        assert_eq!(
+            state.hypotheses_intern(vec![PREALLOCATED_HANDLE_TERM_NEGATION]),
+            Err(ErrorCode::NotAProposition)
+        );
+        assert_eq!(
+            state.hypotheses_intern(vec![Handle::<tags::Term>::from(
+                PREALLOCATED_HANDLE_UPPER_BOUND + 100
+            )]),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+        assert_eq!(
+            state.hypotheses_resolve(Handle::<tags::Hypotheses>::from(
+                PREALLOCATED_HANDLE_UPPER_BOUND + 100
+            )),
+            Err(ErrorCode::NoSuchHypothesesRegistered)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Conditional and local definition tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that a registered conditional splits back into its components,
+    /// has the type of its branches, and is not mistaken for a local
+    /// definition.
+    #[test]
+    pub fn conditional0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let cond = state
+            .term_register_conditional(
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                x.clone(),
+                y.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.term_split_conditional(&cond),
+            Ok((&PREALLOCATED_HANDLE_TERM_TRUE, &x, &y))
+        );
+        assert_eq!(
+            state.term_type_infer(&cond),
+            Ok(PREALLOCATED_HANDLE_TYPE_BETA)
+        );
+        assert_eq!(state.term_test_conditional(&cond), Ok(true));
+        assert_eq!(state.term_test_let(&cond), Ok(false));
+        assert_eq!(state.term_test_conditional(&x), Ok(false));
+    }
+
+    /// Tests that conditionals are only registered for propositional
+    /// conditions and branches of the same type.
+    #[test]
+    pub fn conditional1() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+
+        assert_eq!(
+            state.term_register_conditional(
+                x.clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TERM_FALSE
+            ),
+            Err(ErrorCode::NotAProposition)
+        );
+        assert_eq!(
+            state.term_register_conditional(
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                x.clone(),
+                PREALLOCATED_HANDLE_TERM_FALSE
+            ),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+    }
+
+    /// Tests that a registered local definition splits back into its
+    /// components and has the range type of its function.
+    #[test]
+    pub fn let0() {
+        let mut state = RuntimeState::new();
+
+        let body = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let fun = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                body.clone(),
+            )
+            .unwrap();
+        let arg = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let trm = state.term_register_let(fun.clone(), arg.clone()).unwrap();
+
+        assert_eq!(state.term_split_let(&trm), Ok((&fun, &arg)));
+        assert_eq!(
+            state.term_type_infer(&trm),
+            Ok(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
+        assert_eq!(state.term_test_let(&trm), Ok(true));
+        assert_eq!(state.term_test_conditional(&trm), Ok(false));
+        assert_eq!(
+            state.term_register_let(fun.clone(), PREALLOCATED_HANDLE_TERM_TRUE),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+        assert_eq!(
+            state.term_register_let(arg.clone(), arg.clone()),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    /// Tests the congruence rules for conditionals and local definitions.
+    #[test]
+    pub fn congruence0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let eq = state.term_register_equality(p.clone(), q.clone()).unwrap();
+        let hyp = state.theorem_register_assumption(eq.clone()).unwrap();
+
+        let thm = state
+            .theorem_register_conditional_congruence(&hyp, &hyp, &hyp)
+            .unwrap();
+
+        let left = state
+            .term_register_conditional(p.clone(), p.clone(), p.clone())
+            .unwrap();
+        let right = state
+            .term_register_conditional(q.clone(), q.clone(), q.clone())
+            .unwrap();
+        let conclusion = state.term_register_equality(left, right).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+        assert_eq!(state.theorem_split_premisses(&thm), Ok(&[eq.clone()][..]));
+
+        let fun = state
+            .term_register_lambda(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        let refl = state
+            .term_register_equality(fun.clone(), fun.clone())
+            .unwrap();
+        let refl = state.theorem_register_assumption(refl.clone()).unwrap();
+
+        let thm = state.theorem_register_let_congruence(&refl, &hyp).unwrap();
+
+        let left = state.term_register_let(fun.clone(), p.clone()).unwrap();
+        let right = state.term_register_let(fun.clone(), q.clone()).unwrap();
+        let conclusion = state.term_register_equality(left, right).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+        assert_eq!(
+            state.theorem_register_let_congruence(&hyp, &refl),
+            Err(ErrorCode::NotAFunctionType)
+        );
+    }
+
+    /// Tests that the quantifier congruence rules bind both sides of an
+    /// equality, and that their proofs check.
+    #[test]
+    pub fn congruence1() {
+        let mut state = RuntimeState::new();
+
+        state.set_proof_recording(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let eq = state.term_register_equality(p.clone(), q.clone()).unwrap();
+        let hyp = state.theorem_register_assumption(eq.clone()).unwrap();
+
+        let refl = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(p.clone())
+            .unwrap();
+
+        let thm = state
+            .theorem_register_forall_congruence(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &refl,
+            )
+            .unwrap();
+
+        let forall = state
+            .term_register_forall(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        let conclusion = state
+            .term_register_equality(forall.clone(), forall)
+            .unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+
+        let thm = state
+            .theorem_register_exists_congruence(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &hyp,
+            )
+            .unwrap();
+
+        let left = state
+            .term_register_exists(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        let right = state
+            .term_register_exists(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                q.clone(),
+            )
+            .unwrap();
+        let conclusion = state.term_register_equality(left, right).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+        assert_eq!(state.theorem_split_premisses(&thm), Ok(&[eq][..]));
+
+        let proof = state.theorem_proof(&thm).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+    }
+
+    /// Tests that the quantifier congruence rules refuse to bind a variable
+    /// free in a premiss, or to quantify over non-propositions.
+    #[test]
+    pub fn congruence2() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let eq = state.term_register_equality(p, q).unwrap();
+        let hyp = state.theorem_register_assumption(eq).unwrap();
+
+        assert_eq!(
+            state.theorem_register_forall_congruence(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &hyp
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let refl = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_exists_congruence(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &refl
+            ),
+            Err(ErrorCode::NotAProposition)
+        );
+
+        let truth = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_forall_congruence(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &truth
+            ),
+            Err(ErrorCode::NotAnEquality)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Pair tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that a registered pair splits back into its components, has a
+    /// product type, and has projections of the component types.
+    #[test]
+    pub fn pair0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let pair = state
+            .term_register_pair(x.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(
+            state.term_split_pair(&pair),
+            Ok((&x, &PREALLOCATED_HANDLE_TERM_TRUE))
+        );
+        assert_eq!(state.term_test_pair(&pair), Ok(true));
+        assert_eq!(state.term_test_pair(&x), Ok(false));
+
+        let tau = state.term_type_infer(&pair).unwrap();
+        let product = state
+            .type_register_combination(
+                PREALLOCATED_HANDLE_TYPE_FORMER_PRODUCT,
+                vec![
+                    PREALLOCATED_HANDLE_TYPE_BETA,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(tau, product);
+
+        let first = state.term_register_first(pair.clone()).unwrap();
+        let second = state.term_register_second(pair).unwrap();
+
+        assert_eq!(
+            state.term_type_infer(&first),
+            Ok(PREALLOCATED_HANDLE_TYPE_BETA)
+        );
+        assert_eq!(
+            state.term_type_infer(&second),
+            Ok(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
+        assert_eq!(
+            state.term_register_first(x),
+            Err(ErrorCode::NotAProductType)
+        );
+    }
+
+    /// Tests the characteristic theorems of pairs.
+    #[test]
+    pub fn pair1() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let pair = state
+            .term_register_pair(x.clone(), PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        let thm = state
+            .theorem_register_first_projection(
+                x.clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let first = state.term_register_first(pair.clone()).unwrap();
+        let conclusion =
+            state.term_register_equality(first, x.clone()).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+        assert!(state.theorem_split_premisses(&thm).unwrap().is_empty());
+
+        let thm = state
+            .theorem_register_second_projection(
+                x.clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let second = state.term_register_second(pair.clone()).unwrap();
+        let conclusion = state
+            .term_register_equality(second, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+
+        let tau = state.term_type_infer(&pair).unwrap();
+        let p = state.term_register_variable(1u64, tau).unwrap();
+        let thm = state
+            .theorem_register_surjective_pairing(p.clone())
+            .unwrap();
+        let (left, right) = state
+            .term_split_equality(state.theorem_split_conclusion(&thm).unwrap())
+            .map(|(l, r)| (l.clone(), r.clone()))
+            .unwrap();
+
+        assert_eq!(right, p);
+        assert_eq!(state.term_test_pair(&left), Ok(true));
+        assert_eq!(
+            state.theorem_register_surjective_pairing(x),
+            Err(ErrorCode::NotAProductType)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Set tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that registered set memberships and comprehensions split back
+    /// into their components, and that memberships are type-checked.
+    #[test]
+    pub fn set0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let set = state
+            .term_register_comprehension(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.term_split_comprehension(&set),
+            Ok((
+                &canonical_name(0),
+                &PREALLOCATED_HANDLE_TYPE_BETA,
+                &PREALLOCATED_HANDLE_TERM_TRUE
+            ))
+        );
+        assert_eq!(state.term_test_comprehension(&set), Ok(true));
+        assert_eq!(state.term_test_comprehension(&x), Ok(false));
+
+        let membership = state
+            .term_register_membership(x.clone(), set.clone())
+            .unwrap();
+
+        assert_eq!(state.term_split_membership(&membership), Ok((&x, &set)));
+        assert_eq!(state.term_test_membership(&membership), Ok(true));
+        assert_eq!(state.term_test_membership(&set), Ok(false));
+        assert_eq!(state.term_type_is_proposition(&membership), Ok(true));
+
+        assert_eq!(
+            state.term_register_membership(x.clone(), x.clone()),
+            Err(ErrorCode::NotASetType)
+        );
+        assert_eq!(
+            state.term_register_membership(PREALLOCATED_HANDLE_TERM_TRUE, set),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+        assert_eq!(
+            state.term_register_comprehension(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                x
+            ),
+            Err(ErrorCode::NotAProposition)
+        );
+    }
+
+    /// Tests the defining theorems of the set constants, and that the bound
+    /// variables they introduce avoid the free variables of their operands.
+    #[test]
+    pub fn set1() {
+        let mut state = RuntimeState::new();
+
+        let tau = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_BETA,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let s = state.term_register_variable(0u64, tau.clone()).unwrap();
+        let t = state.term_register_variable(1u64, tau).unwrap();
+
+        let thm = state
+            .theorem_register_membership_definition(x.clone(), s.clone())
+            .unwrap();
+        let membership = state
+            .term_register_membership(x.clone(), s.clone())
+            .unwrap();
+        let application =
+            state.term_register_application(s.clone(), x).unwrap();
+        let conclusion = state
+            .term_register_equality(membership, application)
+            .unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(conclusion));
+        assert!(state.theorem_split_premisses(&thm).unwrap().is_empty());
+
+        let thm = state
+            .theorem_register_union_definition(s.clone(), t.clone())
+            .unwrap();
+        let (_left, right) = state
+            .term_split_equality(state.theorem_split_conclusion(&thm).unwrap())
+            .map(|(l, r)| (l.clone(), r.clone()))
+            .unwrap();
+        let (name, _tau, body) = state
+            .term_split_lambda(&right)
+            .map(|(n, t, b)| (*n, t.clone(), b.clone()))
+            .unwrap();
+
+        assert_eq!(canonical_index(name), Some(0));
+        assert_eq!(state.term_test_disjunction(&body), Ok(true));
+
+        let thm = state
+            .theorem_register_subset_definition(s.clone(), t.clone())
+            .unwrap();
+        let (_left, right) = state
+            .term_split_equality(state.theorem_split_conclusion(&thm).unwrap())
+            .map(|(l, r)| (l.clone(), r.clone()))
+            .unwrap();
+
+        assert_eq!(state.term_test_forall(&right), Ok(true));
+
+        let thm = state
+            .theorem_register_empty_set_definition(
+                PREALLOCATED_HANDLE_TYPE_BETA,
+            )
+            .unwrap();
+        let (_left, right) = state
+            .term_split_equality(state.theorem_split_conclusion(&thm).unwrap())
+            .map(|(l, r)| (l.clone(), r.clone()))
+            .unwrap();
+
+        assert_eq!(
+            state.term_split_lambda(&right),
+            Ok((
+                &canonical_name(0),
+                &PREALLOCATED_HANDLE_TYPE_BETA,
+                &PREALLOCATED_HANDLE_TERM_FALSE
+            ))
+        );
+        assert_eq!(
+            state.theorem_register_intersection_definition(
+                s,
+                PREALLOCATED_HANDLE_TERM_TRUE
+            ),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Inference rule tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests the conclusions of eta-conversion, iff-introduction and negation
+    /// introduction and elimination, and that the latter two reject theorems
+    /// of the wrong shape.
+    #[test]
+    pub fn rules0() {
+        let mut state = RuntimeState::new();
+
+        let tau = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let f = state.term_register_variable(0u64, tau).unwrap();
+        let p = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let fp = state
+            .term_register_application(f.clone(), p.clone())
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_PROP, fp)
+            .unwrap();
+        let thm = state.theorem_register_eta(lambda.clone()).unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            state.term_register_equality(lambda, f)
+        );
+
+        let pq = state
+            .term_register_implication(p.clone(), q.clone())
+            .unwrap();
+        let qp = state
+            .term_register_implication(q.clone(), p.clone())
+            .unwrap();
+        let left = state.theorem_register_assumption(pq).unwrap();
+        let right = state.theorem_register_assumption(qp).unwrap();
+        let thm = state
+            .theorem_register_iff_introduction(&left, &right)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            state.term_register_equality(p.clone(), q.clone())
+        );
+
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+
+        assert_eq!(
+            state
+                .theorem_register_negation_introduction(&assumption, p.clone()),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        let np = state.term_register_negation(p.clone()).unwrap();
+        let nq = state.term_register_negation(q).unwrap();
+        let right = state.theorem_register_assumption(np).unwrap();
+        let other = state.theorem_register_assumption(nq).unwrap();
+        let thm = state
+            .theorem_register_negation_elimination(&assumption, &right)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            Ok(PREALLOCATED_HANDLE_TERM_FALSE)
+        );
+        assert_eq!(
+            state.theorem_register_negation_elimination(&assumption, &other),
+            Err(ErrorCode::ShapeMismatch)
+        );
+    }
+
+    /// Tests that universal introduction generalises over variables not free
+    /// in the premisses, and rejects variables that are.
+    #[test]
+    pub fn rules1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+        let weakened = state
+            .theorem_register_weaken(q.clone(), assumption.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_forall_introduction(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &assumption
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        let reflexivity = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(p.clone())
+            .unwrap();
+        let thm = state
+            .theorem_register_forall_introduction(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                &reflexivity,
+            )
+            .unwrap();
+        let pp = state.term_register_equality(p.clone(), p.clone()).unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            state.term_register_forall(0u64, PREALLOCATED_HANDLE_TYPE_PROP, pp)
+        );
+
+        /* NB: only the variable of the given type is generalised over, so a
+         * variable with the same name but a different type is not captured
+         * by the eigenvariable condition.
+         */
+        let thm = state
+            .theorem_register_forall_introduction(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                &weakened,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_premisses(&thm).map(|p| p.to_vec()),
+            Ok(vec![p.clone(), q])
+        );
+        assert_eq!(
+            state.theorem_register_forall_introduction(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                Handle::from(0)
+            ),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
+    }
+
+    /// Tests that reflexivity holds at every type, not only at propositions.
+    #[test]
+    pub fn rules2() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let thm = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            state.term_register_equality(x.clone(), x)
+        );
+        assert_eq!(
+            state.theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                Handle::from(0)
+            ),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Challenge and capability tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that challenges are only discharged by theorems proving the
+    /// challenge proposition outright, and that the capabilities minted expire
+    /// per their expiry policies.
+    #[test]
+    pub fn challenge0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let truth = state.theorem_register_truth_introduction().unwrap();
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+
+        assert_eq!(
+            state.challenge_discharge(0, &truth, Expiry::Uses(1)),
+            Err(ErrorCode::NoSuchChallengeRegistered)
+        );
+
+        state
+            .challenge_register(0, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        state.challenge_register(1, p.clone()).unwrap();
+
+        assert_eq!(
+            state.challenge_discharge(1, &assumption, Expiry::Uses(1)),
+            Err(ErrorCode::ChallengeNotDischarged)
+        );
+        assert_eq!(
+            state.challenge_discharge(1, &truth, Expiry::Uses(1)),
+            Err(ErrorCode::ChallengeNotDischarged)
+        );
+
+        let once = state
+            .challenge_discharge(0, &truth, Expiry::Uses(1))
+            .unwrap();
+        let epoch = state
+            .challenge_discharge(0, &truth, Expiry::UntilStateChange)
+            .unwrap();
+
+        assert_eq!(
+            state.capability_present(&once, 1),
+            Err(ErrorCode::CapabilityServiceMismatch)
+        );
+        assert_eq!(state.capability_present(&once, 0), Ok(()));
+        assert_eq!(
+            state.capability_present(&once, 0),
+            Err(ErrorCode::CapabilityExpired)
+        );
+
+        assert_eq!(state.capability_present(&epoch, 0), Ok(()));
+        assert_eq!(state.capability_present(&epoch, 0), Ok(()));
+        state.signal_state_change();
+        assert_eq!(
+            state.capability_present(&epoch, 0),
+            Err(ErrorCode::CapabilityExpired)
+        );
+
+        let replaced = state
+            .challenge_discharge(0, &truth, Expiry::Uses(5))
+            .unwrap();
+
+        state
+            .challenge_register(0, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(
+            state.capability_present(&replaced, 0),
+            Err(ErrorCode::CapabilityExpired)
+        );
+        assert_eq!(
+            state.kernel_enumerate(Kind::Capability, 0, 10).0,
+            vec![*once, *epoch, *replaced]
+        );
+    }
+
+    /// Tests that a challenge is open until a capability is minted for its
+    /// service, and again once every such capability has expired.
+    #[test]
+    pub fn challenge1() {
+        let mut state = RuntimeState::new();
+
+        let truth = state.theorem_register_truth_introduction().unwrap();
+
+        state
+            .challenge_register(0, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        state
+            .challenge_register(1, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert_eq!(state.challenge_open_services(), vec![0, 1]);
+
+        let once = state
+            .challenge_discharge(1, &truth, Expiry::Uses(1))
+            .unwrap();
+
+        assert_eq!(state.challenge_open_services(), vec![0]);
+
+        state.capability_present(&once, 1).unwrap();
+
+        assert_eq!(state.challenge_open_services(), vec![0, 1]);
+    }
+
+    /// Tests that substitution replaces free occurrences of variables, leaves
+    /// unaffected subterms untouched, and rejects ill-typed substitutions.
+    #[test]
+    pub fn substitution0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let conjunction = state
+            .term_register_conjunction(x.clone(), y.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.substitution(
+                x.clone(),
+                vec![((0u64, PREALLOCATED_HANDLE_TYPE_PROP), y.clone())]
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
-                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            Ok(y.clone())
+        );
+        assert_eq!(
+            state.substitution(
+                conjunction.clone(),
+                vec![((2u64, PREALLOCATED_HANDLE_TYPE_PROP), y.clone())]
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
-                PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
+            Ok(conjunction.clone())
+        );
+        assert_eq!(
+            state.substitution(
+                conjunction.clone(),
+                vec![(
+                    (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                    PREALLOCATED_HANDLE_TERM_TRUE
+                )]
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
-                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            state.term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, y)
+        );
+        assert_eq!(
+            state.substitution(
+                conjunction.clone(),
+                vec![(
+                    (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                    PREALLOCATED_HANDLE_TERM_CONJUNCTION
+                )]
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_FORALL,
-                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+        assert_eq!(
+            state.substitution(
+                conjunction,
+                vec![((0u64, PREALLOCATED_HANDLE_TYPE_PROP), Handle::from(0))]
             ),
-            (
-                PREALLOCATED_HANDLE_CONSTANT_EXISTS,
-                PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+    }
+
+    /// Tests that substitution is simultaneous, and that it does not replace
+    /// bound occurrences of variables.
+    #[test]
+    pub fn substitution1() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let xy = state
+            .term_register_conjunction(x.clone(), y.clone())
+            .unwrap();
+        let yx = state
+            .term_register_conjunction(y.clone(), x.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.substitution(
+                xy.clone(),
+                vec![
+                    ((0u64, PREALLOCATED_HANDLE_TYPE_PROP), y.clone()),
+                    ((1u64, PREALLOCATED_HANDLE_TYPE_PROP), x.clone())
+                ]
             ),
-        ]);
+            Ok(yx)
+        );
 
-        let terms = HashMap::from_iter(vec![
-            (PREALLOCATED_HANDLE_TERM_TRUE, TERM_TRUE_CONSTANT),
-            (PREALLOCATED_HANDLE_TERM_FALSE, TERM_FALSE_CONSTANT),
-            (PREALLOCATED_HANDLE_TERM_FORALL, TERM_FORALL_CONSTANT),
-            (PREALLOCATED_HANDLE_TERM_EXISTS, TERM_EXISTS_CONSTANT),
-            (PREALLOCATED_HANDLE_TERM_NEGATION, TERM_NEGATION_CONSTANT),
-            (
-                PREALLOCATED_HANDLE_TERM_IMPLICATION,
-                TERM_IMPLICATION_CONSTANT,
+        let lambda = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, xy)
+            .unwrap();
+
+        assert_eq!(
+            state.substitution(
+                lambda.clone(),
+                vec![(
+                    (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                    PREALLOCATED_HANDLE_TERM_TRUE
+                )]
             ),
-            (
+            Ok(lambda)
+        );
+    }
+
+    /// Tests that substitution renames bound variables to avoid capturing free
+    /// variables of the substituted terms.
+    #[test]
+    pub fn substitution2() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let z = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let xy = state
+            .term_register_conjunction(x.clone(), y.clone())
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_PROP, xy)
+            .unwrap();
+
+        let yz = state.term_register_conjunction(y.clone(), z).unwrap();
+        let expected = state
+            .term_register_lambda(2u64, PREALLOCATED_HANDLE_TYPE_PROP, yz)
+            .unwrap();
+        let yy = state
+            .term_register_conjunction(y.clone(), y.clone())
+            .unwrap();
+        let captured = state
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_PROP, yy)
+            .unwrap();
+
+        let result = state
+            .substitution(
+                lambda,
+                vec![((0u64, PREALLOCATED_HANDLE_TYPE_PROP), y)],
+            )
+            .unwrap();
+
+        assert_eq!(result, expected);
+        assert_ne!(result, captured);
+    }
+
+    /// Tests that β-reduction, which is implemented using substitution,
+    /// produces the expected theorem.
+    #[test]
+    pub fn substitution3() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let body = state
+            .term_register_conjunction(x.clone(), x.clone())
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+        let redex = state
+            .term_register_application(lambda, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        let beta = state.theorem_register_beta(redex.clone()).unwrap();
+        let reduct = state
+            .term_register_conjunction(
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+        let expected = state.term_register_equality(redex, reduct).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&beta), Ok(expected));
+    }
+
+    /// Tests that the postorder of a term lists each distinct subterm once,
+    /// after its own subterms.
+    #[test]
+    pub fn term_postorder0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let conjunction = state
+            .term_register_conjunction(x.clone(), x.clone())
+            .unwrap();
+        let (partial, _right) = state
+            .term_split_application(&conjunction)
+            .map(|(l, r)| (l.clone(), r.clone()))
+            .unwrap();
+
+        assert_eq!(
+            state.term_postorder(&conjunction),
+            Ok(vec![
                 PREALLOCATED_HANDLE_TERM_CONJUNCTION,
-                TERM_CONJUNCTION_CONSTANT,
-            ),
-            (
-                PREALLOCATED_HANDLE_TERM_DISJUNCTION,
-                TERM_DISJUNCTION_CONSTANT,
-            ),
-            (PREALLOCATED_HANDLE_TERM_EQUALITY, TERM_EQUALITY_CONSTANT),
-        ]);
+                x,
+                partial,
+                conjunction
+            ])
+        );
+        assert_eq!(
+            state.term_postorder(Handle::from(0)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+    }
+
+    /// Tests that no proof terms are recorded unless proof recording is
+    /// enabled.
+    #[test]
+    pub fn proof0() {
+        let mut state = RuntimeState::new();
+
+        let thm = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        assert!(!state.is_recording_proofs());
+        assert_eq!(
+            state.theorem_proof(&thm),
+            Err(ErrorCode::NoSuchProofRegistered)
+        );
+        assert_eq!(
+            state.theorem_proof(Handle::from(0)),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
+        assert_eq!(
+            state.proof_check(Handle::from(0)),
+            Err(ErrorCode::NoSuchProofRegistered)
+        );
+    }
+
+    /// Tests that recorded proof terms can be split, and are checked without
+    /// leaving any theorems behind.
+    #[test]
+    pub fn proof1() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+        let implication = state
+            .theorem_register_implication_introduction(&assumption, p.clone())
+            .unwrap();
+        let substitute = state
+            .theorem_register_substitute(
+                &implication,
+                vec![(
+                    (0u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                    PREALLOCATED_HANDLE_TERM_TRUE,
+                )],
+            )
+            .unwrap();
+
+        let proof = state.theorem_proof(&implication).unwrap();
+
+        assert_eq!(
+            state.proof_split(&proof),
+            Ok((
+                Rule::ImplicationIntroduction,
+                &implication,
+                &[ProofArgument::Theorem(assumption), ProofArgument::Term(p)][..]
+            ))
+        );
+
+        let theorems = state.kernel_enumerate(Kind::Theorem, 0, 100).0.len();
+        let proof = state.theorem_proof(&substitute).unwrap();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+        assert_eq!(
+            state.kernel_enumerate(Kind::Theorem, 0, 100).0.len(),
+            theorems
+        );
+        assert!(state.is_recording_proofs());
+    }
+
+    /// Tests that proof terms depending on theorems registered whilst proof
+    /// recording was disabled cannot be checked.
+    #[test]
+    pub fn proof2() {
+        let mut state = RuntimeState::new();
+
+        let assumption = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        state.set_proof_recording(true);
+
+        let weakened = state
+            .theorem_register_weaken(PREALLOCATED_HANDLE_TERM_FALSE, assumption)
+            .unwrap();
+        let proof = state.theorem_proof(&weakened).unwrap();
+
+        assert_eq!(
+            state.proof_check(&proof),
+            Err(ErrorCode::NoSuchProofRegistered)
+        );
+    }
+
+    /// Tests that checking a proof term notifies no observers of the theorems
+    /// that it re-derives, and leaves no trace of them in the side tables.
+    #[test]
+    pub fn proof3() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+        let implication = state
+            .theorem_register_implication_introduction(&assumption, p)
+            .unwrap();
+        let proof = state.theorem_proof(&implication).unwrap();
+
+        let notified = Rc::new(Cell::new(0));
+
+        let count = notified.clone();
+        state.on_object_allocated(move |_kind, _handle| {
+            count.set(count.get() + 1)
+        });
+        let count = notified.clone();
+        state.on_theorem_registered(move |_handle, _theorem| {
+            count.set(count.get() + 1)
+        });
+
+        let theorems = state.theorems.len();
+        let conclusions: usize = state.conclusions.values().map(Vec::len).sum();
+        let hypotheses = state.hypotheses.len();
+        let trust = state.trust.len();
+        #[cfg(feature = "retraction")]
+        let dependents: usize = state.dependents.values().map(Vec::len).sum();
+
+        assert_eq!(state.proof_check(&proof), Ok(()));
+        assert_eq!(notified.get(), 0);
+        assert_eq!(state.theorems.len(), theorems);
+        assert_eq!(
+            state.conclusions.values().map(Vec::len).sum::<usize>(),
+            conclusions
+        );
+        assert_eq!(state.hypotheses.len(), hypotheses);
+        assert_eq!(state.trust.len(), trust);
+        #[cfg(feature = "retraction")]
+        assert_eq!(
+            state.dependents.values().map(Vec::len).sum::<usize>(),
+            dependents
+        );
+        assert!(state.theorem_is_registered(&implication));
+        assert!(state.is_recording_proofs());
+    }
+
+    /// Tests that ⍺-equivalent statements have the same statement hash,
+    /// independently of the handles of the terms within them.
+    #[test]
+    pub fn theorem_statement_hash0() {
+        let mut first = RuntimeState::new();
+        let mut second = RuntimeState::new();
+
+        second
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let x = first
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let id = first
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, x)
+            .unwrap();
+        let id = first
+            .term_register_application(id, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let thm = first.theorem_register_assumption(id).unwrap();
+
+        let y = second
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let id = second
+            .term_register_lambda(1u64, PREALLOCATED_HANDLE_TYPE_PROP, y)
+            .unwrap();
+        let id = second
+            .term_register_application(id, PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let other = second.theorem_register_assumption(id).unwrap();
+
+        assert_ne!(thm, other);
+        assert_eq!(
+            first.theorem_statement_hash(&thm),
+            second.theorem_statement_hash(&other)
+        );
+    }
+
+    /// Tests that statements differing in their premisses, or in their free
+    /// variables, have different statement hashes.
+    #[test]
+    pub fn theorem_statement_hash1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let assume_p = state.theorem_register_assumption(p.clone()).unwrap();
+        let assume_q = state.theorem_register_assumption(q).unwrap();
+        let refl_p = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(p)
+            .unwrap();
+
+        let hash = state.theorem_statement_hash(&assume_p).unwrap();
+
+        assert_ne!(state.theorem_statement_hash(&assume_q), Ok(hash));
+        assert_ne!(state.theorem_statement_hash(&refl_p), Ok(hash));
+        assert_eq!(
+            state.theorem_statement_hash(Handle::from(0)),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
+    }
+
+    /// Tests that duplicate detection records the first theorem registered
+    /// with each statement, and is not triggered by proof checking.
+    #[test]
+    pub fn theorem_statement_hash2() {
+        let mut state = RuntimeState::new();
+        state
+            .set_duplicate_detection(true)
+            .set_proof_recording(true);
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let first = state.theorem_register_assumption(p.clone()).unwrap();
+        let second = state.theorem_register_assumption(p).unwrap();
+
+        assert_eq!(state.statement_hashes.len(), 1);
+
+        let hash = state.theorem_statement_hash(&second).unwrap();
+        assert_eq!(state.statement_hashes.get(&hash), Some(&first));
+
+        let proof = state.theorem_proof(&second).unwrap().clone();
+        assert!(state.proof_check(&proof).is_ok());
+        assert_eq!(state.statement_hashes.len(), 1);
+    }
+
+    /// Tests that theorems registered separately, with ⍺-equivalent statements,
+    /// are ⍺-equal, and that theorems differing in their hypotheses are not.
+    #[test]
+    pub fn theorem_alpha_equal0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let left = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, x)
+            .unwrap();
+        let right = state
+            .term_register_lambda(
+                1u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                y.clone(),
+            )
+            .unwrap();
+
+        let first = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(left)
+            .unwrap();
+        let second = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(right)
+            .unwrap();
+        let weakened =
+            state.theorem_register_weaken(y, second.clone()).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(state.theorem_alpha_equal(&first, &second), Ok(true));
+        assert_eq!(state.theorem_alpha_equal(&first, &weakened), Ok(false));
+        assert_eq!(
+            state.theorem_alpha_equal(&first, &Handle::from(0)),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Theorem quoting tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that a theorem with no premisses is quoted as its conclusion.
+    #[test]
+    pub fn theorem_statement_as_term0() {
+        let mut state = RuntimeState::new();
+
+        let truth = state.theorem_register_truth_introduction().unwrap();
+
+        assert_eq!(
+            state.theorem_statement_as_term(&truth),
+            Ok(PREALLOCATED_HANDLE_TERM_TRUE)
+        );
+        assert_eq!(
+            state.theorem_statement_as_term(Handle::from(0)),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
+    }
+
+    /// Tests that a theorem with premisses is quoted as an implication from
+    /// the conjunction of its premisses to its conclusion.
+    #[test]
+    pub fn theorem_statement_as_term1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let r = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
 
-        let theorems = HashMap::from_iter(vec![]);
+        let assume_p = state.theorem_register_assumption(p.clone()).unwrap();
 
-        RuntimeState {
-            next_handle: PREALLOCATED_HANDLE_UPPER_BOUND,
-            type_formers,
-            types,
-            constants,
-            terms,
-            theorems,
-        }
-    }
-}
+        let quoted = state.theorem_statement_as_term(&assume_p).unwrap();
+        let expected = state
+            .term_register_implication(p.clone(), p.clone())
+            .unwrap();
 
-/// Module containing theorem-related tests.
-#[cfg(test)]
-mod test {
-    use crate::{
-        handle::{
-            PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
-            PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
-            PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
-            PREALLOCATED_HANDLE_CONSTANT_EXISTS,
-            PREALLOCATED_HANDLE_CONSTANT_FALSE,
-            PREALLOCATED_HANDLE_CONSTANT_FORALL,
-            PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
-            PREALLOCATED_HANDLE_CONSTANT_NEGATION,
-            PREALLOCATED_HANDLE_CONSTANT_TRUE,
-            PREALLOCATED_HANDLE_TERM_CONJUNCTION,
-            PREALLOCATED_HANDLE_TERM_DISJUNCTION,
-            PREALLOCATED_HANDLE_TERM_EQUALITY, PREALLOCATED_HANDLE_TERM_EXISTS,
-            PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_FORALL,
-            PREALLOCATED_HANDLE_TERM_IMPLICATION,
-            PREALLOCATED_HANDLE_TERM_NEGATION, PREALLOCATED_HANDLE_TERM_TRUE,
-            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
-            PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
-            PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
-            PREALLOCATED_HANDLE_TYPE_FORMER_ARROW,
-            PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
-            PREALLOCATED_HANDLE_TYPE_PROP, PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
-            PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
-            PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
-        },
-        runtime_state::RuntimeState,
-    };
+        assert_eq!(quoted, expected);
+
+        let weakened =
+            state.theorem_register_weaken(q.clone(), assume_p).unwrap();
+        let weakened =
+            state.theorem_register_weaken(r.clone(), weakened).unwrap();
+
+        let quoted = state.theorem_statement_as_term(&weakened).unwrap();
+        let qr = state.term_register_conjunction(q, r).unwrap();
+        let pqr = state.term_register_conjunction(p.clone(), qr).unwrap();
+        let expected = state.term_register_implication(pqr, p).unwrap();
+
+        assert_eq!(quoted, expected);
+    }
 
     ////////////////////////////////////////////////////////////////////////////
-    // Initial theory tests.
+    // Pretty-printing tests.
     ////////////////////////////////////////////////////////////////////////////
 
-    /// Tests all primitive type-formers are registered in the initial theory.
+    /// Tests that connectives and quantifiers are printed using their usual
+    /// notation, and that theorems are printed with a turnstile.
     #[test]
-    pub fn initial_theory0() {
-        let state = RuntimeState::new();
+    pub fn pretty_print0() {
+        let mut state = RuntimeState::new();
 
-        assert!(state
-            .type_former_resolve(&PREALLOCATED_HANDLE_TYPE_FORMER_PROP)
-            .is_some());
-        assert!(state
-            .type_former_resolve(&PREALLOCATED_HANDLE_TYPE_FORMER_ARROW)
-            .is_some());
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let not_p = state.term_register_negation(p.clone()).unwrap();
+        let both = state
+            .term_register_conjunction(p.clone(), not_p.clone())
+            .unwrap();
+        let absurd = state
+            .term_register_implication(both, PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+        let all = state
+            .term_register_forall(0u64, PREALLOCATED_HANDLE_TYPE_PROP, absurd)
+            .unwrap();
+
+        assert_eq!(
+            state.term_pretty_print(&all),
+            Ok(String::from("(forall b0 : Prop. ((b0 /\\ ~b0) ==> false))"))
+        );
+        assert_eq!(
+            state.type_pretty_print(PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE),
+            Ok(String::from("(Prop -> Prop)"))
+        );
+
+        let assumption = state.theorem_register_assumption(not_p).unwrap();
+
+        assert_eq!(
+            state.theorem_pretty_print(&assumption),
+            Ok(String::from("~x0 |- ~x0"))
+        );
+        assert_eq!(
+            state.term_pretty_print(Handle::from(0)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
     }
 
-    /// Tests all primitive constants are registered in the initial theory.
+    /// Tests that term differences are reported at the outermost differing
+    /// positions, including positions within types.
     #[test]
-    pub fn initial_theory1() {
-        let state = RuntimeState::new();
+    pub fn term_diff0() {
+        let mut state = RuntimeState::new();
 
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_EXISTS)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_FORALL)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_IMPLICATION)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_TRUE)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_FALSE)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_EQUALITY)
-            .is_ok());
-        assert!(state
-            .constant_resolve(&PREALLOCATED_HANDLE_CONSTANT_NEGATION)
-            .is_ok());
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let r = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let left = state.term_register_conjunction(p.clone(), q).unwrap();
+        let right = state.term_register_conjunction(p.clone(), r).unwrap();
+
+        assert_eq!(
+            state.term_diff(&left, &right),
+            Ok(String::from("arg: x1 vs x2"))
+        );
+        assert_eq!(state.term_diff(&left, &left), Ok(String::new()));
+        assert_eq!(
+            state.term_diff(&left, &p),
+            Ok(String::from("root: (x0 /\\ x1) vs x0"))
+        );
+
+        let a = state.type_register_variable(0u64);
+        let prop_id = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, p)
+            .unwrap();
+        let x = state.term_register_variable(0u64, a.clone()).unwrap();
+        let a_id = state.term_register_lambda(0u64, a, x).unwrap();
+
+        assert_eq!(
+            state.term_diff(&prop_id, &a_id),
+            Ok(String::from("type: Prop vs a0\nbody.type: Prop vs a0"))
+        );
+        assert_eq!(
+            state.term_diff(&left, Handle::from(0)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
     }
 
-    /// Tests all primitive types are registered in the initial theory.
+    /// Tests that metadata is stored with a type-former, and that its name is
+    /// used when pretty-printing types built from it.
     #[test]
-    pub fn initial_theory2() {
-        let state = RuntimeState::new();
+    pub fn type_former_metadata0() {
+        let mut state = RuntimeState::new();
 
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_PROP)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_QUANTIFIER)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_BETA)
-            .is_some());
-        assert!(state
-            .resolve_type_handle(&PREALLOCATED_HANDLE_TYPE_ALPHA)
-            .is_some());
+        let list = state.type_former_register_with_metadata(
+            1usize,
+            TypeFormerMetadata::new("list", "Finite lists."),
+        );
+        let anonymous = state.type_former_register(0usize);
+
+        let metadata = state.type_former_metadata(&list).unwrap().unwrap();
+
+        assert_eq!(metadata.name(), "list");
+        assert_eq!(metadata.documentation(), "Finite lists.");
+        assert_eq!(state.type_former_metadata(&anonymous), Ok(None));
+        assert_eq!(
+            state.type_former_metadata(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTypeFormerRegistered)
+        );
+
+        let tau = state
+            .type_register_combination(
+                list,
+                vec![PREALLOCATED_HANDLE_TYPE_PROP],
+            )
+            .unwrap();
+        let sigma = state
+            .type_register_combination(
+                anonymous.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.type_pretty_print(&tau),
+            Ok(String::from("list(Prop)"))
+        );
+        assert_eq!(
+            state.type_pretty_print(&sigma),
+            Ok(format!("F{}", anonymous))
+        );
     }
 
-    /// Tests all primitive terms are registered in the initial theory.
+    /// Tests that the constructors of a datatype can be marked once, and that
+    /// a constructor's siblings can then be found.
     #[test]
-    pub fn initial_theory3() {
-        let state = RuntimeState::new();
+    pub fn constant_mark_constructors0() {
+        let mut state = RuntimeState::new();
+
+        let list = state.type_former_register(1usize);
+        let list_alpha = state
+            .type_register_combination(
+                list.clone(),
+                vec![PREALLOCATED_HANDLE_TYPE_ALPHA],
+            )
+            .unwrap();
+        let tail = state
+            .type_register_function(list_alpha.clone(), list_alpha.clone())
+            .unwrap();
+        let cons_type = state
+            .type_register_function(PREALLOCATED_HANDLE_TYPE_ALPHA, tail)
+            .unwrap();
+
+        let nil = state.constant_register(list_alpha).unwrap();
+        let cons = state.constant_register(cons_type).unwrap();
+        let other = state
+            .constant_register(PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert_eq!(
+            state.constant_mark_constructors(list.clone(), vec![other.clone()]),
+            Err(ErrorCode::NotAConstructor)
+        );
+        assert_eq!(
+            state.constant_mark_constructors(
+                list.clone(),
+                vec![nil.clone(), nil.clone()]
+            ),
+            Err(ErrorCode::ConstructorsAlreadyMarked)
+        );
+        assert_eq!(state.constant_is_constructor(&nil), Ok(false));
 
         assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_EXISTS)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_FORALL)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_IMPLICATION)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_CONJUNCTION)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_DISJUNCTION)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_TRUE)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_FALSE)
-            .is_ok());
-        assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_EQUALITY)
+            .constant_mark_constructors(
+                list.clone(),
+                vec![nil.clone(), cons.clone()]
+            )
             .is_ok());
+
+        assert_eq!(state.constant_is_constructor(&cons), Ok(true));
+        assert_eq!(state.constant_is_constructor(&other), Ok(false));
+        assert_eq!(
+            state.constant_constructor_siblings(&cons),
+            Ok((&list, &vec![nil.clone(), cons.clone()]))
+        );
+        assert_eq!(
+            state.constant_constructor_siblings(&other),
+            Err(ErrorCode::NotAConstructor)
+        );
+        assert_eq!(
+            state.constant_mark_constructors(list, vec![nil]),
+            Err(ErrorCode::ConstructorsAlreadyMarked)
+        );
+    }
+
+    /// Tests that marking constructors within a discarded speculative branch
+    /// is undone, even for constants registered before the branch began.
+    #[test]
+    pub fn constant_mark_constructors1() {
+        let mut state = RuntimeState::new();
+
+        let unit = state.type_former_register(0usize);
+        let unit_type = state
+            .type_register_combination(
+                unit.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .unwrap();
+        let star = state.constant_register(unit_type).unwrap();
+
+        state.speculate_begin();
         assert!(state
-            .resolve_term_handle(&PREALLOCATED_HANDLE_TERM_NEGATION)
+            .constant_mark_constructors(unit.clone(), vec![star.clone()])
             .is_ok());
+        assert!(state.speculate_end(false).is_ok());
+
+        assert_eq!(state.constant_is_constructor(&star), Ok(false));
+        assert!(state.constant_mark_constructors(unit, vec![star]).is_ok());
     }
 
     ////////////////////////////////////////////////////////////////////////////
-    // Free-variable tests.
+    // Error detail tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that a failed implication elimination explains which terms
+    /// mismatched, and where they first diverge.
+    #[test]
+    pub fn error_detail0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let p_q = state.term_register_implication(p, q.clone()).unwrap();
+
+        let left = state.theorem_register_assumption(p_q).unwrap();
+        let right = state.theorem_register_assumption(q).unwrap();
+
+        assert_eq!(state.kernel_error_detail(), None);
+        assert_eq!(
+            state.theorem_register_implication_elimination(left, right),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.kernel_error_detail(),
+            Some(String::from(
+                "ShapeMismatch: the antecedent of the implication is not the \
+                 conclusion of the second theorem.\n\
+                 expected: x0\n  \
+                 actual: x1\n           ^\n"
+            ))
+        );
+
+        state.kernel_clear_error_detail();
+
+        assert_eq!(state.kernel_error_detail(), None);
+    }
+
+    /// Tests that a failed disjunction elimination explains which premiss is
+    /// missing.
+    #[test]
+    pub fn error_detail1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let p_or_q = state.term_register_disjunction(p, q.clone()).unwrap();
+
+        let left = state.theorem_register_assumption(p_or_q).unwrap();
+        let mid = state
+            .theorem_register_assumption(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let right = state.theorem_register_assumption(q).unwrap();
+
+        assert_eq!(
+            state.theorem_register_disjunction_elimination(left, mid, right),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        let detail = state.kernel_error_detail().unwrap();
+
+        assert!(detail.starts_with(
+            "ShapeMismatch: the conclusions of the second and third theorems \
+             differ."
+        ));
+        assert!(detail.contains("expected: true\n  actual: x1\n"));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Speculation tests.
     ////////////////////////////////////////////////////////////////////////////
 
+    /// Tests that discarding a speculative branch removes every kernel object
+    /// registered within it, without reissuing their handles, whilst keeping
+    /// Tests that a runtime state with randomised handles issues handles that
+    /// are not consecutive, resolves them, and reports handles issued within a
+    /// discarded speculative branch as stale.
+    #[test]
+    pub fn randomised_handles0() {
+        let mut state = RuntimeState::with_randomised_handles(7);
+
+        assert!(state.has_randomised_handles());
+        assert!(!RuntimeState::new().has_randomised_handles());
+
+        let alpha = state.type_register_variable(10u64);
+        let beta = state.type_register_variable(11u64);
+
+        assert_ne!(*beta, *alpha + 1);
+        assert!(state.type_is_registered(&alpha));
+
+        state.speculate_begin();
+
+        let gamma = state.type_register_variable(12u64);
+
+        assert_eq!(state.speculate_end(false), Ok(()));
+        assert!(state.handle_is_stale(&gamma));
+        assert!(!state.handle_is_stale(&beta));
+        assert!(state.type_is_registered(&beta));
+        assert!(!state.type_is_registered(&gamma));
+        assert!(!state.handle_is_stale(&Handle::<tags::Type>::from(*gamma + 1)));
+    }
+
+    /// a branch retains them.
     #[test]
-    pub fn free_variables0() {
+    pub fn speculation0() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
 
-        let fvs = state.term_free_variables(&v).unwrap();
+        state.speculate_begin();
 
-        assert_eq!(fvs, vec![(&0_u64, &PREALLOCATED_HANDLE_TYPE_PROP)]);
-    }
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let assume_q = state.theorem_register_assumption(q.clone()).unwrap();
+        let shared = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
 
-    #[test]
-    pub fn free_variables1() {
-        let state = RuntimeState::new();
+        assert_eq!(shared, p);
+        assert_eq!(state.speculate_end(false), Ok(()));
 
-        let fvs = state
-            .term_free_variables(&PREALLOCATED_HANDLE_TERM_TRUE)
+        assert!(state.term_is_registered(&p));
+        assert!(!state.term_is_registered(&q));
+        assert!(!state.theorem_is_registered(&assume_q));
+
+        let r = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
 
-        assert!(fvs.is_empty());
+        assert!(*r > *assume_q);
+
+        state.speculate_begin();
+
+        let assume_r = state.theorem_register_assumption(r).unwrap();
+
+        assert_eq!(state.speculate_end(true), Ok(()));
+        assert!(state.theorem_is_registered(&assume_r));
     }
 
+    /// Tests that speculative branches nest, with discarding an outer branch
+    /// also discarding the objects kept by the inner branches.
     #[test]
-    pub fn free_variables2() {
+    pub fn speculation1() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        state.speculate_begin();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
-        let l = state
-            .term_register_lambda(0_u64, PREALLOCATED_HANDLE_TYPE_PROP, v)
+
+        state.speculate_begin();
+
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
 
-        let fvs = state.term_free_variables(&l).unwrap();
+        assert_eq!(state.speculation_depth(), 2);
+        assert_eq!(state.speculate_end(true), Ok(()));
+        assert!(state.term_is_registered(&q));
 
-        assert!(fvs.is_empty());
+        assert_eq!(state.speculation_depth(), 1);
+        assert_eq!(state.speculate_end(false), Ok(()));
+        assert!(!state.term_is_registered(&p));
+        assert!(!state.term_is_registered(&q));
+
+        assert_eq!(state.speculation_depth(), 0);
+        assert_eq!(
+            state.speculate_end(true),
+            Err(ErrorCode::NoSpeculationInProgress)
+        );
     }
 
+    /// Tests that discarding a speculative branch forgets the uses of the
+    /// capabilities presented within it.
     #[test]
-    pub fn free_variables3() {
+    pub fn speculation2() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(
-                0_u64,
-                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
-            )
+        let truth = state.theorem_register_truth_introduction().unwrap();
+
+        state
+            .challenge_register(0, PREALLOCATED_HANDLE_TERM_TRUE)
             .unwrap();
-        let l = state
-            .term_register_lambda(0_u64, PREALLOCATED_HANDLE_TYPE_PROP, v)
+
+        let once = state
+            .challenge_discharge(0, &truth, Expiry::Uses(1))
             .unwrap();
 
-        let fvs = state.term_free_variables(&l).unwrap();
+        state.speculate_begin();
+
+        let minted = state
+            .challenge_discharge(0, &truth, Expiry::Uses(1))
+            .unwrap();
+
+        assert_eq!(state.capability_present(&once, 0), Ok(()));
+        assert_eq!(state.speculate_end(false), Ok(()));
 
+        assert_eq!(state.capability_present(&once, 0), Ok(()));
         assert_eq!(
-            fvs,
-            vec![(&0_u64, &PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE)]
+            state.capability_present(&minted, 0),
+            Err(ErrorCode::StaleHandle)
         );
     }
 
+    /// Tests that handles issued within a discarded speculative branch are
+    /// reported as stale, whichever table they are resolved against, whilst
+    /// handles that were never issued are reported as unregistered.
     #[test]
-    pub fn free_variables4() {
+    pub fn speculation3() {
         let mut state = RuntimeState::new();
 
-        let l = state
-            .term_register_lambda(
-                0_u64,
+        state.speculate_begin();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let tau = state
+            .type_register_function(
                 PREALLOCATED_HANDLE_TYPE_PROP,
-                PREALLOCATED_HANDLE_TERM_TRUE,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
             )
             .unwrap();
+        let assume_p = state.theorem_register_assumption(p.clone()).unwrap();
 
-        let fvs = state.term_free_variables(&l).unwrap();
+        assert_eq!(state.speculate_end(false), Ok(()));
 
-        assert!(fvs.is_empty())
+        assert!(state.handle_is_stale(&p));
+        assert!(state.handle_is_stale(&tau));
+        assert!(state.handle_is_stale(&assume_p));
+        assert_eq!(state.term_type_infer(&p), Err(ErrorCode::StaleHandle));
+        assert_eq!(state.type_size(&tau), Err(ErrorCode::StaleHandle));
+        assert_eq!(
+            state.theorem_split_conclusion(&assume_p),
+            Err(ErrorCode::StaleHandle)
+        );
+        assert_eq!(
+            state.theorem_register_assumption(p),
+            Err(ErrorCode::StaleHandle)
+        );
+
+        let unissued: Handle<tags::Term> = Handle::from(usize::MAX);
+
+        assert!(!state.handle_is_stale(&unissued));
+        assert_eq!(
+            state.term_type_infer(&unissued),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+        assert!(!state.handle_is_stale(&PREALLOCATED_HANDLE_TERM_TRUE));
     }
 
+    /// Tests that discarding a branch retires the handles issued within the
+    /// branches nested inside it, including those that were kept, but not
+    /// those issued after it ended.
     #[test]
-    pub fn free_variables5() {
+    pub fn speculation4() {
         let mut state = RuntimeState::new();
 
-        let l = state
-            .term_register_lambda(
-                0_u64,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-                PREALLOCATED_HANDLE_TERM_TRUE,
-            )
+        state.speculate_begin();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
-        let v = state
-            .term_register_variable(5_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+
+        state.speculate_begin();
+
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
-        let t = state.term_register_application(l, v).unwrap();
 
-        let fvs = state.term_free_variables(&t).unwrap();
+        assert_eq!(state.speculate_end(false), Ok(()));
 
-        assert_eq!(fvs, vec![(&5_u64, &PREALLOCATED_HANDLE_TYPE_PROP)])
-    }
+        state.speculate_begin();
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Alpha-equivalence tests.
-    ////////////////////////////////////////////////////////////////////////////
+        let r = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
 
-    #[test]
-    pub fn alpha_equivalence0() {
-        let mut state = RuntimeState::new();
+        assert_eq!(state.speculate_end(true), Ok(()));
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        assert!(!state.handle_is_stale(&p));
+        assert!(state.handle_is_stale(&q));
+        assert!(!state.handle_is_stale(&r));
+
+        assert_eq!(state.speculate_end(false), Ok(()));
+
+        let s = state
+            .term_register_variable(3u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
 
-        assert!(state.is_alpha_equivalent(&v, &v).unwrap());
+        assert!(state.handle_is_stale(&p));
+        assert!(state.handle_is_stale(&q));
+        assert!(state.handle_is_stale(&r));
+        assert!(!state.handle_is_stale(&s));
+        assert!(state.is_term_registered(&s));
     }
 
+    /// Tests that resetting discards every registered kernel object, retiring
+    /// its handle, but keeps the preallocated kernel objects and the settings
+    /// of the runtime state.
     #[test]
-    pub fn alpha_equivalence1() {
+    pub fn reset0() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        state.set_proof_recording(true);
+        state.speculate_begin();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
+        let assume_p = state.theorem_register_assumption(p.clone()).unwrap();
+
+        state.reset();
+
+        assert!(state.handle_is_stale(&p));
+        assert!(state.handle_is_stale(&assume_p));
+        assert_eq!(state.term_type_infer(&p), Err(ErrorCode::StaleHandle));
+        assert_eq!(state.speculation_depth(), 0);
+        assert!(state.is_recording_proofs());
+        assert!(state.is_term_registered(&PREALLOCATED_HANDLE_TERM_TRUE));
+
         let q = state
-            .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
 
-        assert!(!state.is_alpha_equivalent(&v, &q).unwrap());
+        assert!(*q > *assume_p);
+        assert!(!state.handle_is_stale(&q));
+        assert_eq!(
+            state.term_type_infer(&q),
+            Ok(PREALLOCATED_HANDLE_TYPE_PROP)
+        );
     }
 
+    /// Tests that existence queries find theorems by conclusion, up to
+    /// alpha-equivalence and whatever their hypotheses, and forget theorems
+    /// registered within a discarded speculative branch.
     #[test]
-    pub fn alpha_equivalence2() {
+    pub fn theorem_exists_with_conclusion0() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
             .unwrap();
         let q = state
-            .term_register_variable(
-                0_u64,
-                PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let lam_x = state
+            .term_register_lambda(
+                2u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
+            )
+            .unwrap();
+        let lam_y = state
+            .term_register_lambda(
+                3u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                p.clone(),
             )
             .unwrap();
+        let eq = state
+            .term_register_equality(lam_x.clone(), lam_x.clone())
+            .unwrap();
+        let eq_alpha = state
+            .term_register_equality(lam_y.clone(), lam_y.clone())
+            .unwrap();
 
-        assert!(!state.is_alpha_equivalent(&v, &q).unwrap());
+        assert_eq!(state.theorem_exists_with_conclusion(&p), Ok(false));
+
+        state.theorem_register_assumption(p.clone()).unwrap();
+        state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(lam_x)
+            .unwrap();
+
+        assert_eq!(state.theorem_exists_with_conclusion(&p), Ok(true));
+        assert_eq!(state.theorem_exists_with_conclusion(&eq), Ok(true));
+        assert_eq!(state.theorem_exists_with_conclusion(&eq_alpha), Ok(true));
+
+        state.speculate_begin();
+        state.theorem_register_assumption(q.clone()).unwrap();
+        assert_eq!(state.theorem_exists_with_conclusion(&q), Ok(true));
+        state.speculate_end(false).unwrap();
+
+        assert_eq!(state.theorem_exists_with_conclusion(&q), Ok(false));
+        assert_eq!(
+            state.theorem_exists_with_conclusion(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
     }
 
+    ////////////////////////////////////////////////////////////////////////////
+    // Definition tests.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Tests that defining a constant registers its definitional theorem, and
+    /// that only closed terms mentioning no type-variable absent from their
+    /// type may be defined.
     #[test]
-    pub fn alpha_equivalence3() {
+    pub fn definition0() {
         let mut state = RuntimeState::new();
 
-        let v = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        let alpha = state.type_register_variable(0u64);
+        let x = state.term_register_variable(0u64, alpha.clone()).unwrap();
+        let id = state
+            .term_register_lambda(0u64, alpha.clone(), x.clone())
             .unwrap();
-        let l = state
-            .term_register_lambda(
-                0_u64,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-                v.clone(),
-            )
+
+        let (c, defn) = state.register_new_definition(id.clone()).unwrap();
+        let stmt = state.term_register_equality(c.clone(), id).unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&defn), Ok(stmt));
+        assert!(state.term_split_constant(&c).is_ok());
+
+        assert_eq!(
+            state.register_new_definition(x.clone()),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        let refl = state.term_register_equality(x.clone(), x).unwrap();
+        let all = state.term_register_forall(0u64, alpha, refl).unwrap();
+
+        assert_eq!(
+            state.register_new_definition(all),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.register_new_definition(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+    }
+
+    /// Tests that existential introduction generalises a theorem about a
+    /// witness, checking the witness against the bound variable, and that the
+    /// derivation replays.
+    #[test]
+    pub fn exists0() {
+        let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let p = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let xx = state.term_register_equality(x.clone(), x.clone()).unwrap();
+        let ex = state
+            .term_register_exists(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, xx)
             .unwrap();
-        let c = state.term_register_application(l, v).unwrap();
 
-        assert!(state.is_alpha_equivalent(&c, &c).unwrap());
+        let yy = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(y.clone())
+            .unwrap();
+        let thm = state
+            .theorem_register_exists_introduction(&yy, ex.clone(), y.clone())
+            .unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&thm), Ok(ex.clone()));
+        assert_eq!(state.theorem_split_premisses(&thm).map(|p| p.len()), Ok(0));
+        assert_eq!(
+            state.proof_check(state.theorem_proof(&thm).unwrap()),
+            Ok(())
+        );
+
+        assert_eq!(
+            state.theorem_register_exists_introduction(&yy, ex.clone(), p),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_introduction(&yy, ex.clone(), x),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_introduction(
+                &yy,
+                state.theorem_split_conclusion(&yy).unwrap(),
+                y.clone()
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_introduction(Handle::from(0), ex, y),
+            Err(ErrorCode::NoSuchTheoremRegistered)
+        );
     }
 
+    /// Tests that existential elimination discharges the instance of the
+    /// existential at the eigenvariable, enforcing the eigenvariable condition,
+    /// and that the derivation replays.
     #[test]
-    pub fn alpha_equivalence4() {
+    pub fn exists1() {
         let mut state = RuntimeState::new();
+        state.set_proof_recording(true);
 
-        let v0 = state
-            .term_register_variable(0_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
             .unwrap();
-        let l0 = state
-            .term_register_lambda(
-                0_u64,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-                v0.clone(),
-            )
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let p = state
+            .term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(3u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let xx = state.term_register_equality(x.clone(), x.clone()).unwrap();
+        let yy = state.term_register_equality(y.clone(), y.clone()).unwrap();
+        let yx = state.term_register_equality(y.clone(), x.clone()).unwrap();
+        let ex = state
+            .term_register_exists(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA, xx)
             .unwrap();
-        let c0 = state.term_register_application(l0, v0.clone()).unwrap();
 
-        let v1 = state
-            .term_register_variable(1_u64, PREALLOCATED_HANDLE_TYPE_PROP)
+        let refl = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(x.clone())
             .unwrap();
-        let l1 = state
-            .term_register_lambda(
-                1_u64,
-                PREALLOCATED_HANDLE_TYPE_PROP,
-                v1.clone(),
-            )
+        let exists = state
+            .theorem_register_exists_introduction(&refl, ex, x.clone())
             .unwrap();
-        let c1 = state.term_register_application(l1, v0.clone()).unwrap();
+        let truth = state.theorem_register_truth_introduction().unwrap();
+        let right = state
+            .theorem_register_weaken(yy.clone(), truth.clone())
+            .unwrap();
+        let right = state.theorem_register_weaken(q.clone(), right).unwrap();
 
-        assert!(state.is_alpha_equivalent(&c0, &c1).unwrap());
+        let thm = state
+            .theorem_register_exists_elimination(&exists, y.clone(), &right)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&thm),
+            Ok(PREALLOCATED_HANDLE_TERM_TRUE)
+        );
+        assert_eq!(
+            state.theorem_split_premisses(&thm).map(|p| p.to_vec()),
+            Ok(vec![q])
+        );
+        assert_eq!(
+            state.proof_check(state.theorem_proof(&thm).unwrap()),
+            Ok(())
+        );
+
+        /* NB: the eigenvariable is free in the conclusion. */
+        let assumption = state.theorem_register_assumption(yy.clone()).unwrap();
+
+        assert_eq!(
+            state.theorem_register_exists_elimination(
+                &exists,
+                y.clone(),
+                &assumption
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* NB: the eigenvariable is free in another premiss. */
+        let free = state
+            .theorem_register_weaken(yx.clone(), right.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_exists_elimination(
+                &exists,
+                y.clone(),
+                &free
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        /* NB: the eigenvariable is free in a premiss of the existential. */
+        let weakened =
+            state.theorem_register_weaken(yx, exists.clone()).unwrap();
+
+        assert_eq!(
+            state.theorem_register_exists_elimination(
+                &weakened,
+                y.clone(),
+                &right
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+
+        assert_eq!(
+            state.theorem_register_exists_elimination(&exists, p, &right),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_elimination(&exists, yy, &right),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_elimination(
+                &exists,
+                y.clone(),
+                &truth
+            ),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_exists_elimination(&right, y, &right),
+            Err(ErrorCode::ShapeMismatch)
+        );
     }
 
     ////////////////////////////////////////////////////////////////////////////
-    // Substitution tests.
+    // Freezing tests.
     ////////////////////////////////////////////////////////////////////////////
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Type-checking tests.
-    ////////////////////////////////////////////////////////////////////////////
+    /// Tests that runtime states instantiated from a frozen base share its
+    /// kernel objects, whilst objects registered by one instance are private
+    /// to it, and that a frozen base may be shared between threads.
+    #[test]
+    pub fn freeze0() {
+        fn assert_sync<T: Send + Sync>() {}
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Inference tests.
-    ////////////////////////////////////////////////////////////////////////////
+        assert_sync::<FrozenRuntimeState>();
+
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let assume_p = state.theorem_register_assumption(p.clone()).unwrap();
+
+        let base = state.freeze();
+
+        let mut left = base.instantiate();
+        let right = base.instantiate();
+
+        assert!(left.terms.is_shared_with(&right.terms));
+        assert!(left.theorem_is_registered(&assume_p));
+
+        let q = left
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        assert!(!left.terms.is_shared_with(&right.terms));
+        assert!(left.theorems.is_shared_with(&right.theorems));
+        assert!(left.term_is_registered(&q));
+        assert!(!right.term_is_registered(&q));
+        assert!(!state.term_is_registered(&q));
+        assert!(!base.instantiate().term_is_registered(&q));
+
+        let thread_base = base.clone();
+        let r = std::thread::spawn(move || {
+            let mut instance = thread_base.instantiate();
+
+            instance
+                .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+                .unwrap()
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(r, q);
+    }
 }
@@ -0,0 +1,151 @@
+//! # The scratch store
+//!
+//! A small key-value store, scoped to a session of the runtime state, in which
+//! prover-space code may stash intermediate data between the phases of a
+//! tactic, e.g. an encoded goal or a cache of lemmas, without attaching it as
+//! metadata to some unrelated kernel object, or keeping it in the globals of a
+//! guest module that may be reloaded.  Keys are strings, and values arbitrary
+//! bytes that the kernel never interprets.
+//!
+//! The scratch store plays no part in the logic.  It is untouched by
+//! speculative branches, is emptied when the runtime state is reset, and is
+//! exported with state objects only if the runtime state has been asked to
+//! persist it, by `set_scratch_persistence`.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{error_code::ErrorCode, runtime_state::RuntimeState};
+use log::info;
+
+////////////////////////////////////////////////////////////////////////////////
+// Scratch store operations.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Stores `value` in the scratch store under `key`, replacing any value
+    /// previously stored under `key`.
+    pub fn scratch_put<T, U>(&mut self, key: T, value: U)
+    where
+        T: Into<String>,
+        U: Into<Vec<u8>>,
+    {
+        let key = key.into();
+
+        info!("Storing scratch key {:?}.", key);
+
+        self.scratch.insert(key, value.into());
+    }
+
+    /// Returns the value stored in the scratch store under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchScratchKey)` if nothing is stored under
+    /// `key`.
+    pub fn scratch_get(&self, key: &str) -> Result<&[u8], ErrorCode> {
+        info!("Getting scratch key {:?}.", key);
+
+        self.scratch
+            .get(key)
+            .map(|value| value.as_slice())
+            .ok_or(ErrorCode::NoSuchScratchKey)
+    }
+
+    /// Removes the value stored in the scratch store under `key`, returning
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchScratchKey)` if nothing is stored under
+    /// `key`.
+    pub fn scratch_delete(&mut self, key: &str) -> Result<Vec<u8>, ErrorCode> {
+        info!("Deleting scratch key {:?}.", key);
+
+        self.scratch.remove(key).ok_or(ErrorCode::NoSuchScratchKey)
+    }
+
+    /// Returns the keys under which values are stored in the scratch store, in
+    /// ascending order.
+    #[inline]
+    pub fn scratch_keys(&self) -> Vec<&str> {
+        self.scratch.keys().map(|key| key.as_str()).collect()
+    }
+
+    /// Enables, or disables, the persistence of the scratch store.  Whilst
+    /// persistence is enabled, the scratch store is exported with every state
+    /// object, and restored when the state object is imported.  Persistence is
+    /// disabled by default.
+    #[inline]
+    pub fn set_scratch_persistence(&mut self, enabled: bool) -> &mut Self {
+        info!("Setting scratch persistence to {}.", enabled);
+
+        self.persist_scratch = enabled;
+        self
+    }
+
+    /// Returns `true` iff the scratch store is exported with state objects.
+    #[inline]
+    pub fn is_persisting_scratch(&self) -> bool {
+        self.persist_scratch
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{error_code::ErrorCode, runtime_state::RuntimeState};
+
+    /// Tests that values are stored, replaced and deleted, that the scratch
+    /// store is untouched by a discarded speculative branch, and that it is
+    /// emptied by a reset.
+    #[test]
+    pub fn scratch0() {
+        let mut kernel = RuntimeState::new();
+
+        assert_eq!(
+            kernel.scratch_get("goal"),
+            Err(ErrorCode::NoSuchScratchKey)
+        );
+
+        kernel.scratch_put("goal", vec![1, 2, 3]);
+        kernel.scratch_put("lemmas", "cached");
+        kernel.scratch_put("goal", vec![4]);
+
+        assert_eq!(kernel.scratch_get("goal"), Ok(&[4][..]));
+        assert_eq!(kernel.scratch_keys(), vec!["goal", "lemmas"]);
+
+        kernel.speculate_begin();
+        kernel.scratch_put("phase", vec![2]);
+        kernel.speculate_end(false).unwrap();
+
+        assert_eq!(kernel.scratch_get("phase"), Ok(&[2][..]));
+
+        assert_eq!(kernel.scratch_delete("goal"), Ok(vec![4]));
+        assert_eq!(
+            kernel.scratch_delete("goal"),
+            Err(ErrorCode::NoSuchScratchKey)
+        );
+
+        kernel.set_scratch_persistence(true);
+        kernel.reset();
+
+        assert!(kernel.scratch_keys().is_empty());
+        assert!(kernel.is_persisting_scratch());
+    }
+}
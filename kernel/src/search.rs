@@ -0,0 +1,642 @@
+//! # The theorem index
+//!
+//! Automation running in prover-space frequently needs to know whether, and
+//! how, something has already been proved, and would otherwise have to keep
+//! its own shadow copy of every theorem that it has derived to find out.  The
+//! kernel therefore indexes its registered theorems by their conclusions, and
+//! answers two kinds of query against the index:
+//!
+//! - `RuntimeState::theorem_find_by_conclusion` finds the theorems with a
+//!   given conclusion, up to ⍺-equivalence,
+//! - `RuntimeState::theorem_find_matching` finds the theorems whose conclusion
+//!   is an instance of a given pattern, in which every type-variable acts as a
+//!   wildcard, standing for any type, though a type-variable occurring more
+//!   than once must stand for the same type throughout.
+//!
+//! The former is answered directly from the table of theorems registered with
+//! each conclusion.  For the latter, every conclusion is also indexed by its
+//! *shape*, a hash of its structure with every type erased, which a pattern
+//! shares with each of its instances, so that only the conclusions with the
+//! same shape as the pattern need be matched against it.
+//!
+//! Patterns are also used to close goals by assumption, with
+//! `RuntimeState::theorem_register_assumption_matching`, which finds the
+//! hypothesis matching a pattern amongst a set of hypotheses and derives the
+//! theorem concluding it, without the hypotheses crossing the ABI one at a
+//! time.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    name::Name,
+    runtime_state::{fnv1a_words, RuntimeState, FNV_OFFSET_BASIS},
+    term::Term,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Shapes of terms.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns the shape of the term pointed-to by `handle`, under the
+    /// λ-bound variables named `bound`, innermost last: a structural hash of
+    /// the term, as `term_structural_hash`, but with every type erased.  Bound
+    /// variables are hashed by their de Bruijn index.
+    ///
+    /// Will raise a kernel panic if the term contains a dangling handle.
+    fn term_shape(
+        &self,
+        handle: &Handle<tags::Term>,
+        bound: &mut Vec<Name>,
+    ) -> u64 {
+        match self
+            .resolve_term_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+        {
+            Term::Variable { name, .. } => {
+                match bound.iter().rev().position(|n| n == name) {
+                    Some(index) => {
+                        fnv1a_words(FNV_OFFSET_BASIS, &[4, index as u64])
+                    }
+                    None => fnv1a_words(FNV_OFFSET_BASIS, &[0, *name]),
+                }
+            }
+            Term::Constant { constant, .. } => {
+                fnv1a_words(FNV_OFFSET_BASIS, &[1, **constant as u64])
+            }
+            Term::Application { left, right } => {
+                let left = self.term_shape(left, bound);
+                let right = self.term_shape(right, bound);
+
+                fnv1a_words(FNV_OFFSET_BASIS, &[2, left, right])
+            }
+            Term::Lambda { name, body, .. } => {
+                bound.push(*name);
+                let body = self.term_shape(body, bound);
+                bound.pop();
+
+                fnv1a_words(FNV_OFFSET_BASIS, &[3, body])
+            }
+        }
+    }
+
+    /// Enters `conclusion`, the conclusion of a theorem just admitted into the
+    /// theorem-table, into the index of conclusions by shape, unless it has
+    /// been entered already.
+    ///
+    /// Will raise a kernel panic if the term contains a dangling handle.
+    pub(crate) fn index_conclusion(&mut self, conclusion: &Handle<tags::Term>) {
+        let shape = self.term_shape(conclusion, &mut Vec::new());
+        let conclusions = self.conclusion_shapes.entry(shape).or_default();
+
+        if !conclusions.contains(conclusion) {
+            conclusions.push(conclusion.clone());
+        }
+    }
+
+    /// Returns `true` iff the term pointed-to by `target` is an instance of
+    /// the term pointed-to by `pattern`, obtained by substituting for the
+    /// type-variables of `pattern`, extending `sigma` with the bindings for
+    /// the type-variables needed to witness this.  The names of the λ-bound
+    /// variables of both terms, innermost last, are `bound`, and bound
+    /// variables are matched by their de Bruijn index.
+    ///
+    /// Will raise a kernel panic if either term contains a dangling handle.
+    fn term_match(
+        &self,
+        pattern: &Handle<tags::Term>,
+        target: &Handle<tags::Term>,
+        bound: &mut Vec<(Name, Name)>,
+        sigma: &mut Vec<(Name, Handle<tags::Type>)>,
+    ) -> bool {
+        let pattern = self
+            .resolve_term_handle(pattern)
+            .expect(DANGLING_HANDLE_ERROR);
+        let target = self
+            .resolve_term_handle(target)
+            .expect(DANGLING_HANDLE_ERROR);
+
+        match (pattern, target) {
+            (
+                Term::Variable { name, tau },
+                Term::Variable {
+                    name: tname,
+                    tau: ttau,
+                },
+            ) => {
+                let index = bound.iter().rev().position(|(n, _)| n == name);
+                let tindex = bound.iter().rev().position(|(_, n)| n == tname);
+
+                match (index, tindex) {
+                    (Some(index), Some(tindex)) => index == tindex,
+                    (None, None) => {
+                        name == tname && self.type_match(tau, ttau, sigma)
+                    }
+                    _otherwise => false,
+                }
+            }
+            (
+                Term::Constant { constant, tau },
+                Term::Constant {
+                    constant: tconstant,
+                    tau: ttau,
+                },
+            ) => constant == tconstant && self.type_match(tau, ttau, sigma),
+            (
+                Term::Application { left, right },
+                Term::Application {
+                    left: tleft,
+                    right: tright,
+                },
+            ) => {
+                self.term_match(left, tleft, bound, sigma)
+                    && self.term_match(right, tright, bound, sigma)
+            }
+            (
+                Term::Lambda { name, tau, body },
+                Term::Lambda {
+                    name: tname,
+                    tau: ttau,
+                    body: tbody,
+                },
+            ) => {
+                if !self.type_match(tau, ttau, sigma) {
+                    return false;
+                }
+
+                bound.push((*name, *tname));
+                let matches = self.term_match(body, tbody, bound, sigma);
+                bound.pop();
+
+                matches
+            }
+            _otherwise => false,
+        }
+    }
+
+    /// Returns the handles of the theorems, still registered, that have been
+    /// registered with the conclusion pointed-to by `conclusion`.
+    fn theorems_concluding(
+        &self,
+        conclusion: &Handle<tags::Term>,
+    ) -> impl Iterator<Item = &Handle<tags::Theorem>> {
+        self.conclusions
+            .get(conclusion)
+            .into_iter()
+            .flatten()
+            .filter(move |theorem| {
+                self.resolve_theorem_handle(*theorem).is_ok()
+            })
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Queries.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the handles of every registered theorem whose conclusion is the
+    /// term pointed-to by `handle`, up to ⍺-equivalence, whatever their
+    /// hypotheses, in ascending order.  Retracted theorems are not returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a registered term in the runtime state's term-table.
+    pub fn theorem_find_by_conclusion<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<Handle<tags::Theorem>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Finding theorems with conclusion with handle {}.",
+            handle.borrow()
+        );
+
+        self.resolve_term_handle(handle.borrow())?;
+
+        let mut theorems: Vec<_> =
+            self.theorems_concluding(handle.borrow()).cloned().collect();
+
+        theorems.sort();
+
+        Ok(theorems)
+    }
+
+    /// Returns the handles of every registered theorem whose conclusion is an
+    /// instance of the term pointed-to by `pattern`, up to ⍺-equivalence,
+    /// whatever their hypotheses, in ascending order.  A conclusion is an
+    /// instance of `pattern` if it is obtained by substituting types for the
+    /// type-variables of `pattern`, so that, e.g., the pattern `x = x`, with
+    /// `x` of type `α`, matches `x = x` at every type, but `x` must be named
+    /// identically in both.  Retracted theorems are not returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `pattern` does not
+    /// point-to a registered term in the runtime state's term-table.
+    pub fn theorem_find_matching<T>(
+        &self,
+        pattern: T,
+    ) -> Result<Vec<Handle<tags::Theorem>>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Finding theorems with conclusion matching pattern with handle {}.",
+            pattern.borrow()
+        );
+
+        self.resolve_term_handle(pattern.borrow())?;
+
+        let shape = self.term_shape(pattern.borrow(), &mut Vec::new());
+
+        /* NB: the index of conclusions by shape is never pruned when a
+         * theorem is freed, so conclusions with no theorems still registered
+         * are skipped before they are matched, as their terms may since have
+         * been freed too.
+         */
+        let mut theorems = Vec::new();
+
+        for conclusion in
+            self.conclusion_shapes.get(&shape).into_iter().flatten()
+        {
+            let mut concluding =
+                self.theorems_concluding(conclusion).peekable();
+
+            if concluding.peek().is_some()
+                && self.term_match(
+                    pattern.borrow(),
+                    conclusion,
+                    &mut Vec::new(),
+                    &mut Vec::new(),
+                )
+            {
+                theorems.extend(concluding.cloned());
+            }
+        }
+
+        theorems.sort();
+
+        Ok(theorems)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Closing goals by assumption.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Registers a new theorem object, `Γ ⊢ ψ`, in the kernel's theorem-table,
+    /// where `Γ` is the set of hypotheses `hypotheses`, and `ψ` is the first of
+    /// them that is ⍺-equivalent to the term pointed-to by `pattern`, or if
+    /// there is none the first of them that is an instance of `pattern`, as for
+    /// `theorem_find_matching`.  Closing a goal by assumption is the most
+    /// common step taken by tactics, so this finds the assumption and derives
+    /// the theorem, with the assumption rule followed by weakening, in one
+    /// call.  Returns `Ok(handle)` if this process is successful, where
+    /// `handle` is the newly-allocated handle pointing-to the new theorem
+    /// object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `pattern`, or any
+    /// handle in `hypotheses`, does not point-to a registered term in the
+    /// runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAProposition)` if any handle in `hypotheses`
+    /// does not point-to a term with propositional type.
+    ///
+    /// Returns `Err(ErrorCode::NoMatchingHypothesis)` if no hypothesis in
+    /// `hypotheses` matches `pattern`.
+    pub fn theorem_register_assumption_matching<T, U>(
+        &mut self,
+        hypotheses: Vec<T>,
+        pattern: U,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        let hypotheses: Vec<Handle<tags::Term>> =
+            hypotheses.into_iter().map(|h| h.into()).collect();
+        let pattern = pattern.borrow();
+
+        info!(
+            "Registering 'assumption' theorem matching pattern with handle {} \
+             amongst hypotheses with handles {:?}.",
+            pattern, hypotheses
+        );
+
+        self.resolve_term_handle(pattern)?;
+
+        for hypothesis in &hypotheses {
+            if !self.term_type_is_proposition(hypothesis)? {
+                return Err(ErrorCode::NotAProposition);
+            }
+        }
+
+        let assumption = hypotheses
+            .iter()
+            .find(|hypothesis| *hypothesis == pattern)
+            .or_else(|| {
+                hypotheses.iter().find(|hypothesis| {
+                    self.term_match(
+                        pattern,
+                        hypothesis,
+                        &mut Vec::new(),
+                        &mut Vec::new(),
+                    )
+                })
+            })
+            .ok_or(ErrorCode::NoMatchingHypothesis)?
+            .clone();
+
+        let mut theorem =
+            self.theorem_register_assumption(assumption.clone())?;
+
+        for hypothesis in hypotheses {
+            if hypothesis != assumption {
+                theorem = self.theorem_register_weaken(hypothesis, theorem)?;
+            }
+        }
+
+        Ok(theorem)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for the theorem index.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that theorems are found by their conclusion, up to
+    /// ⍺-equivalence, and only by their conclusion, and that theorems
+    /// registered within a discarded speculative branch are forgotten.
+    #[test]
+    pub fn search0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let body = state
+            .term_register_implication(p.clone(), p.clone())
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                body.clone(),
+            )
+            .unwrap();
+        let lambda_alpha = {
+            let body = state
+                .term_register_implication(q.clone(), q.clone())
+                .unwrap();
+            state
+                .term_register_lambda(
+                    1u64,
+                    PREALLOCATED_HANDLE_TYPE_PROP,
+                    body.clone(),
+                )
+                .unwrap()
+        };
+        let eq = state
+            .term_register_equality(lambda.clone(), lambda.clone())
+            .unwrap();
+        let eq_alpha = state
+            .term_register_equality(lambda_alpha.clone(), lambda_alpha.clone())
+            .unwrap();
+
+        assert_eq!(state.theorem_find_by_conclusion(&p), Ok(vec![]));
+
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+        let reflexivity = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                lambda.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(state.theorem_find_by_conclusion(&p), Ok(vec![assumption]));
+        assert_eq!(
+            state.theorem_find_by_conclusion(&eq_alpha),
+            Ok(vec![reflexivity.clone()])
+        );
+        assert_eq!(
+            state.theorem_find_by_conclusion(&eq),
+            Ok(vec![reflexivity])
+        );
+        assert_eq!(state.theorem_find_by_conclusion(&q), Ok(vec![]));
+
+        state.speculate_begin();
+        let speculative = state.theorem_register_assumption(q.clone()).unwrap();
+        assert_eq!(state.theorem_find_matching(&q), Ok(vec![speculative]));
+        state.speculate_end(false).unwrap();
+
+        assert_eq!(state.theorem_find_matching(&q), Ok(vec![]));
+        assert_eq!(
+            state.theorem_find_by_conclusion(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+    }
+
+    /// Tests that the type-variables of a pattern match any type, but
+    /// consistently, and that nothing else in a pattern is a wildcard.
+    #[test]
+    pub fn search1() {
+        let mut state = RuntimeState::new();
+
+        let alpha_to_alpha = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+                PREALLOCATED_HANDLE_TYPE_ALPHA,
+            )
+            .unwrap();
+        let prop_to_prop = state
+            .type_register_function(
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+
+        let variable = |state: &mut RuntimeState, name: u64, tau| {
+            state.term_register_variable(name, tau).unwrap()
+        };
+
+        let x_alpha = variable(&mut state, 0, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let x_prop = variable(&mut state, 0, PREALLOCATED_HANDLE_TYPE_PROP);
+        let y_prop = variable(&mut state, 1, PREALLOCATED_HANDLE_TYPE_PROP);
+        let f_alpha = variable(&mut state, 2, alpha_to_alpha);
+        let f_prop = variable(&mut state, 2, prop_to_prop.clone());
+
+        let pattern = state
+            .term_register_equality(x_alpha.clone(), x_alpha.clone())
+            .unwrap();
+        let refl_x = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                x_prop.clone(),
+            )
+            .unwrap();
+        let refl_y = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                y_prop.clone(),
+            )
+            .unwrap();
+        let refl_y_conclusion =
+            state.theorem_split_conclusion(&refl_y).unwrap();
+
+        assert_eq!(
+            state.theorem_find_matching(&pattern),
+            Ok(vec![refl_x.clone()])
+        );
+
+        let fx_alpha = state
+            .term_register_application(f_alpha.clone(), x_alpha.clone())
+            .unwrap();
+        let fx_prop = state
+            .term_register_application(f_prop.clone(), x_prop.clone())
+            .unwrap();
+        let pattern = state
+            .term_register_equality(fx_alpha.clone(), fx_alpha.clone())
+            .unwrap();
+        let refl_fx = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                fx_prop.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(state.theorem_find_matching(&pattern), Ok(vec![refl_fx]));
+        assert_eq!(state.theorem_find_matching(&x_alpha), Ok(vec![]));
+
+        let y_alpha = variable(&mut state, 1, PREALLOCATED_HANDLE_TYPE_ALPHA);
+        let y_fun = variable(&mut state, 1, prop_to_prop);
+
+        let conjunction = |state: &mut RuntimeState,
+                           x: &Handle<tags::Term>,
+                           y: &Handle<tags::Term>| {
+            let left =
+                state.term_register_equality(x.clone(), x.clone()).unwrap();
+            let right =
+                state.term_register_equality(y.clone(), y.clone()).unwrap();
+
+            state
+                .term_register_conjunction(left.clone(), right.clone())
+                .unwrap()
+        };
+
+        let pattern = conjunction(&mut state, &x_alpha, &y_alpha);
+        let consistent = conjunction(&mut state, &x_prop, &y_prop);
+        let inconsistent = conjunction(&mut state, &x_prop, &y_fun);
+
+        let consistent = state
+            .theorem_register_assumption(consistent.clone())
+            .unwrap();
+        state
+            .theorem_register_assumption(inconsistent.clone())
+            .unwrap();
+
+        assert_eq!(state.theorem_find_matching(&pattern), Ok(vec![consistent]));
+        assert_eq!(
+            state.theorem_find_matching(&refl_y_conclusion),
+            Ok(vec![refl_y])
+        );
+    }
+
+    /// Tests that closing a goal by assumption prefers an ⍺-equivalent
+    /// hypothesis to a mere instance of the pattern, and derives a theorem
+    /// with every hypothesis supplied.
+    #[test]
+    pub fn search2() {
+        let mut state = RuntimeState::new();
+
+        let p_alpha = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let p_prop = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let pattern = state
+            .term_register_equality(p_alpha.clone(), p_alpha.clone())
+            .unwrap();
+        let instance = state
+            .term_register_equality(p_prop.clone(), p_prop.clone())
+            .unwrap();
+
+        let theorem = state
+            .theorem_register_assumption_matching(
+                vec![q.clone(), instance.clone()],
+                &pattern,
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_split_conclusion(&theorem),
+            Ok(instance.clone())
+        );
+
+        let mut premisses = vec![q.clone(), instance.clone()];
+        premisses.sort();
+
+        assert_eq!(
+            state.theorem_split_premisses(&theorem),
+            Ok(premisses.as_slice())
+        );
+
+        let theorem = state
+            .theorem_register_assumption_matching(
+                vec![instance.clone(), q.clone()],
+                &q,
+            )
+            .unwrap();
+
+        assert_eq!(state.theorem_split_conclusion(&theorem), Ok(q.clone()));
+        assert_eq!(
+            state.theorem_register_assumption_matching(
+                vec![q.clone()],
+                &pattern
+            ),
+            Err(ErrorCode::NoMatchingHypothesis)
+        );
+        assert_eq!(
+            state.theorem_register_assumption_matching(
+                vec![q.clone(), p_alpha.clone()],
+                &q
+            ),
+            Err(ErrorCode::NotAProposition)
+        );
+    }
+}
@@ -0,0 +1,726 @@
+//! # Runtime state snapshots
+//!
+//! A state object carries the theory developed in a runtime state in a form
+//! that can be imported into any other, at the cost of re-registering every
+//! object, under fresh handles, and of re-proving every theorem.  A *snapshot*
+//! instead captures the runtime state itself: every type-former, type,
+//! constant, term and theorem registered in it, under the handle that it was
+//! issued, so that the handle graph linking them survives.  Restoring a
+//! snapshot yields a fresh runtime state in which every handle valid in the
+//! snapshotted runtime state points-to the same object, so that a theory
+//! developed in one run of the driver may be persisted and picked up by the
+//! next, and a pre-built theory image shipped to guests, without replaying
+//! every registration call that built it.
+//!
+//! The format is as follows, where all integers are unsigned LEB128 varints
+//! and strings are a length followed by that many bytes of UTF-8:
+//!
+//! ```text
+//! magic     : "SPVK"
+//! version   : u8
+//! flags     : u8
+//! bound     : an exclusive upper bound on the handles of the runtime state
+//! formers   : count, then `count` type-former entries
+//! types     : count, then `count` type entries
+//! constants : count, then `count` constant entries
+//! datatypes : count, then `count` datatype entries
+//! terms     : count, then `count` term entries
+//! theorems  : count, then `count` theorem entries
+//! ```
+//!
+//! Every entry begins with the handle of its object.  A type-former entry then
+//! continues as in a state object, with `arity, 0` or `arity, 1, name,
+//! documentation`, and type and term entries as in a proof object, except that
+//! the types and terms that they mention are written as handles, rather than
+//! as indices into a table.  A constant entry continues with the handle of its
+//! type, then `0`, or `1, name` for a constant named in the symbol table, and a
+//! theorem entry with `trust, count, premisses..., conclusion`,
+//! where `trust` is 1 for a theorem depending upon the linear arithmetic
+//! decision procedure, 2 for a theorem depending upon an axiom but not upon
+//! the procedure, and 0 otherwise.  A datatype entry is `former, count,
+//! constructors...`, listing the constructors of the datatype with type-former
+//! `former` in order.  Within each table, objects appear in the order in which
+//! they were issued, so every handle written in an entry points-to an object
+//! that is preallocated, or restored by an earlier entry.  No flags are
+//! defined as yet.
+//!
+//! Restoring a snapshot checks that every type and term is well-formed, and
+//! that the premisses and conclusion of every theorem are propositions, but
+//! does **not** re-prove any theorem: a snapshot is as trusted as the kernel
+//! that took it, so must only ever be restored from storage that only the
+//! host can write to, and never from bytes supplied by prover-space code,
+//! which should use state objects instead.  Everything else is session state,
+//! and is not captured: the kernel's settings, recorded proofs, metadata
+//! attached to objects other than type-formers, the scratch store, challenges
+//! and capabilities, the records of defined and quotient types, and any
+//! objects registered by experimental features.  The
+//! restored runtime state issues handles sequentially, from the bound, so
+//! handles issued after restoring a snapshot of a runtime state that issued
+//! them sequentially continue where it left off.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type,
+    error_code::ErrorCode,
+    handle::{
+        is_preallocated, tags, Handle, Kind, PREALLOCATED_HANDLE_UPPER_BOUND,
+    },
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    metadata::TypeFormerMetadata,
+    proof_object::{
+        write_varint, Reader, TAG_TERM_APPLICATION, TAG_TERM_CONSTANT,
+        TAG_TERM_LAMBDA, TAG_TERM_VARIABLE, TAG_TYPE_COMBINATION,
+        TAG_TYPE_VARIABLE,
+    },
+    runtime_state::RuntimeState,
+    state_object::{
+        read_string, write_string, TAG_FORMER_ANONYMOUS, TAG_FORMER_NAMED,
+    },
+    term::Term,
+    trust::TrustLevel,
+};
+use log::info;
+use std::collections::HashSet;
+
+////////////////////////////////////////////////////////////////////////////////
+// Format constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The magic bytes with which every snapshot begins.
+pub const SNAPSHOT_MAGIC: &[u8; 4] = b"SPVK";
+/// The version of the snapshot format produced by this kernel.
+pub const SNAPSHOT_VERSION: u8 = 2;
+
+/// Tag of a constant entry for a constant not named in the symbol table.
+const TAG_CONSTANT_ANONYMOUS: u8 = 0;
+/// Tag of a constant entry for a constant named in the symbol table.
+const TAG_CONSTANT_NAMED: u8 = 1;
+
+/// Tag of a theorem entry for a theorem independent of the linear arithmetic
+/// decision procedure.
+const TAG_THEOREM_UNTAINTED: u8 = 0;
+/// Tag of a theorem entry for a theorem depending upon the linear arithmetic
+/// decision procedure.
+const TAG_THEOREM_TAINTED: u8 = 1;
+/// Tag of a theorem entry for a theorem depending upon an axiom, but
+/// independent of the linear arithmetic decision procedure.
+const TAG_THEOREM_AXIOM_DEPENDENT: u8 = 2;
+
+////////////////////////////////////////////////////////////////////////////////
+// Taking and restoring snapshots.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Reads a handle written in a snapshot with exclusive upper bound `bound`,
+/// failing if it is out of bounds.
+fn read_handle<T>(
+    reader: &mut Reader,
+    bound: usize,
+) -> Result<Handle<T>, ErrorCode>
+where
+    T: tags::IsTag,
+{
+    let handle = reader.read_varint()?;
+
+    if handle < bound as u64 {
+        Ok(Handle::from(handle as usize))
+    } else {
+        Err(ErrorCode::MalformedProofObject)
+    }
+}
+
+/// Reads the handle of the object of a snapshot entry, as `read_handle`,
+/// failing if it is preallocated or has already been read, as recorded in
+/// `restored`.
+fn read_fresh_handle<T>(
+    reader: &mut Reader,
+    bound: usize,
+    restored: &mut HashSet<usize>,
+) -> Result<Handle<T>, ErrorCode>
+where
+    T: tags::IsTag,
+{
+    let handle: Handle<T> = read_handle(reader, bound)?;
+
+    if is_preallocated(Handle::<T>::from(*handle)) || !restored.insert(*handle)
+    {
+        return Err(ErrorCode::MalformedProofObject);
+    }
+
+    Ok(handle)
+}
+
+impl RuntimeState {
+    /// Returns the handles of every object of kind `kind` registered in the
+    /// runtime state, other than the kernel's preallocated objects, in the
+    /// order in which they were issued.
+    fn snapshot_handles<T>(&self, kind: Kind) -> Vec<Handle<T>>
+    where
+        T: tags::IsTag,
+    {
+        let (mut handles, _next) = self.kernel_enumerate(kind, 0, usize::MAX);
+
+        handles.retain(|h| !is_preallocated(Handle::<T>::from(*h)));
+        handles.sort_by_key(|h| self.handles.sequence(*h));
+        handles.into_iter().map(Handle::from).collect()
+    }
+
+    /// Serializes the runtime state into a snapshot, which
+    /// `RuntimeState::snapshot_restore` restores.
+    pub fn snapshot_export(&self) -> Vec<u8> {
+        info!("Taking snapshot of runtime state.");
+
+        let formers: Vec<Handle<tags::TypeFormer>> =
+            self.snapshot_handles(Kind::TypeFormer);
+        let types: Vec<Handle<tags::Type>> = self.snapshot_handles(Kind::Type);
+        let constants: Vec<Handle<tags::Constant>> =
+            self.snapshot_handles(Kind::Constant);
+        let terms: Vec<Handle<tags::Term>> = self.snapshot_handles(Kind::Term);
+        let theorems: Vec<Handle<tags::Theorem>> =
+            self.snapshot_handles(Kind::Theorem);
+
+        /* NB: a runtime state issuing handles sequentially has issued every
+         * handle below its next, not just those of the objects captured, and
+         * the restored runtime state must not reissue any of them.
+         */
+        let issued = formers
+            .iter()
+            .map(|h| **h)
+            .chain(types.iter().map(|h| **h))
+            .chain(constants.iter().map(|h| **h))
+            .chain(terms.iter().map(|h| **h))
+            .chain(theorems.iter().map(|h| **h))
+            .max()
+            .map_or(0, |h| h + 1);
+
+        let bound = if self.handles.is_randomised() {
+            issued
+        } else {
+            issued.max(self.handles.next_sequence())
+        };
+
+        let mut buffer = SNAPSHOT_MAGIC.to_vec();
+
+        buffer.push(SNAPSHOT_VERSION);
+        buffer.push(0);
+        write_varint(&mut buffer, bound as u64);
+
+        write_varint(&mut buffer, formers.len() as u64);
+
+        for former in formers.iter() {
+            let arity = self
+                .type_former_resolve(former)
+                .expect(DANGLING_HANDLE_ERROR);
+
+            write_varint(&mut buffer, **former as u64);
+            write_varint(&mut buffer, *arity as u64);
+
+            match self
+                .type_former_metadata(former)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                None => buffer.push(TAG_FORMER_ANONYMOUS),
+                Some(metadata) => {
+                    buffer.push(TAG_FORMER_NAMED);
+                    write_string(&mut buffer, metadata.name());
+                    write_string(&mut buffer, metadata.documentation());
+                }
+            }
+        }
+
+        write_varint(&mut buffer, types.len() as u64);
+
+        for tau in types.iter() {
+            write_varint(&mut buffer, **tau as u64);
+
+            match self.resolve_type_handle(tau).expect(DANGLING_HANDLE_ERROR) {
+                Type::Variable { name } => {
+                    buffer.push(TAG_TYPE_VARIABLE);
+                    write_varint(&mut buffer, *name);
+                }
+                Type::Combination { former, arguments } => {
+                    buffer.push(TAG_TYPE_COMBINATION);
+                    write_varint(&mut buffer, **former as u64);
+                    write_varint(&mut buffer, arguments.len() as u64);
+
+                    for argument in arguments.iter() {
+                        write_varint(&mut buffer, **argument as u64);
+                    }
+                }
+            }
+        }
+
+        write_varint(&mut buffer, constants.len() as u64);
+
+        let names = self.constant_names_by_handle();
+
+        for constant in constants.iter() {
+            let tau = self
+                .constant_resolve(constant)
+                .expect(DANGLING_HANDLE_ERROR);
+
+            write_varint(&mut buffer, **constant as u64);
+            write_varint(&mut buffer, **tau as u64);
+
+            match names.get(constant) {
+                None => buffer.push(TAG_CONSTANT_ANONYMOUS),
+                Some(name) => {
+                    buffer.push(TAG_CONSTANT_NAMED);
+                    write_string(&mut buffer, name);
+                }
+            }
+        }
+
+        let datatypes: Vec<_> = formers
+            .iter()
+            .filter_map(|former| {
+                self.type_former_constructors(former)
+                    .map(|constructors| (former, constructors))
+            })
+            .collect();
+
+        write_varint(&mut buffer, datatypes.len() as u64);
+
+        for (former, constructors) in datatypes {
+            write_varint(&mut buffer, **former as u64);
+            write_varint(&mut buffer, constructors.len() as u64);
+
+            for constructor in constructors.iter() {
+                write_varint(&mut buffer, **constructor as u64);
+            }
+        }
+
+        write_varint(&mut buffer, terms.len() as u64);
+
+        for trm in terms.iter() {
+            write_varint(&mut buffer, **trm as u64);
+
+            match self.resolve_term_handle(trm).expect(DANGLING_HANDLE_ERROR) {
+                Term::Variable { name, tau } => {
+                    buffer.push(TAG_TERM_VARIABLE);
+                    write_varint(&mut buffer, *name);
+                    write_varint(&mut buffer, **tau as u64);
+                }
+                Term::Constant { constant, tau } => {
+                    buffer.push(TAG_TERM_CONSTANT);
+                    write_varint(&mut buffer, **constant as u64);
+                    write_varint(&mut buffer, **tau as u64);
+                }
+                Term::Application { left, right } => {
+                    buffer.push(TAG_TERM_APPLICATION);
+                    write_varint(&mut buffer, **left as u64);
+                    write_varint(&mut buffer, **right as u64);
+                }
+                Term::Lambda { name, tau, body } => {
+                    buffer.push(TAG_TERM_LAMBDA);
+                    write_varint(&mut buffer, *name);
+                    write_varint(&mut buffer, **tau as u64);
+                    write_varint(&mut buffer, **body as u64);
+                }
+            }
+        }
+
+        write_varint(&mut buffer, theorems.len() as u64);
+
+        for thm in theorems.iter() {
+            let premisses = self
+                .theorem_split_premisses(thm)
+                .expect(DANGLING_HANDLE_ERROR);
+            let conclusion = self
+                .theorem_split_conclusion(thm)
+                .expect(DANGLING_HANDLE_ERROR);
+
+            write_varint(&mut buffer, **thm as u64);
+
+            buffer.push(
+                match self
+                    .theorem_trust_level(thm)
+                    .expect(DANGLING_HANDLE_ERROR)
+                {
+                    TrustLevel::KernelProved => TAG_THEOREM_UNTAINTED,
+                    TrustLevel::AxiomDependent => TAG_THEOREM_AXIOM_DEPENDENT,
+                    TrustLevel::OracleDependent => TAG_THEOREM_TAINTED,
+                },
+            );
+
+            write_varint(&mut buffer, premisses.len() as u64);
+
+            for premiss in premisses.iter() {
+                write_varint(&mut buffer, **premiss as u64);
+            }
+
+            write_varint(&mut buffer, *conclusion as u64);
+        }
+
+        info!(
+            "Snapshot taken: {} bytes, {} theorems.",
+            buffer.len(),
+            theorems.len()
+        );
+
+        buffer
+    }
+
+    /// Restores the snapshot `bytes` into a fresh runtime state, in which
+    /// every object of the snapshot is registered under its original handle.
+    /// The theorems of the snapshot are registered without being re-proved,
+    /// so `bytes` must be a snapshot taken by `snapshot_export`, and kept out
+    /// of reach of prover-space code since.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::MalformedProofObject)` if `bytes` is truncated,
+    /// has trailing bytes, has the wrong magic bytes, version or flags, or
+    /// contains an unknown tag, a string which is not valid UTF-8, a handle
+    /// which is preallocated, out of bounds, or restored twice, or a type or
+    /// term which is restored twice, or is not in the form in which the kernel
+    /// stores it.
+    ///
+    /// Otherwise, returns whatever error the kernel's registration functions
+    /// return if an entry does not describe a well-formed kernel object, e.g.
+    /// `Err(ErrorCode::NoSuchTermRegistered)` if it mentions a term that has
+    /// not been restored, or `Err(ErrorCode::NotAProposition)` if a theorem
+    /// does not relate propositions.
+    pub fn snapshot_restore(bytes: &[u8]) -> Result<Self, ErrorCode> {
+        info!("Restoring snapshot of {} bytes.", bytes.len());
+
+        let mut reader = Reader::new(bytes);
+
+        reader.read_header_with_flags(SNAPSHOT_MAGIC, SNAPSHOT_VERSION, 0)?;
+
+        let bound = reader.read_varint()? as usize;
+
+        let mut state = RuntimeState::with_handles_from(
+            bound.max(PREALLOCATED_HANDLE_UPPER_BOUND),
+        );
+        let mut restored = HashSet::new();
+
+        for _i in 0..reader.read_varint()? {
+            let handle = read_fresh_handle(&mut reader, bound, &mut restored)?;
+            let arity = reader.read_varint()? as usize;
+
+            let metadata = match reader.read_u8()? {
+                TAG_FORMER_ANONYMOUS => None,
+                TAG_FORMER_NAMED => {
+                    let name = read_string(&mut reader)?;
+                    let documentation = read_string(&mut reader)?;
+
+                    Some(TypeFormerMetadata::new(name, documentation))
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            state.restore_type_former(handle, arity, metadata);
+        }
+
+        for _i in 0..reader.read_varint()? {
+            let handle = read_fresh_handle(&mut reader, bound, &mut restored)?;
+
+            let tau = match reader.read_u8()? {
+                TAG_TYPE_VARIABLE => Type::Variable {
+                    name: reader.read_varint()?,
+                },
+                TAG_TYPE_COMBINATION => {
+                    let former = read_handle(&mut reader, bound)?;
+
+                    /* NB: as with other tables, the count is untrusted, so
+                     * the arguments are grown as they are actually read.
+                     */
+                    let mut arguments = Vec::new();
+
+                    for _j in 0..reader.read_varint()? {
+                        arguments.push(read_handle(&mut reader, bound)?);
+                    }
+
+                    Type::Combination { former, arguments }
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            state.restore_type(handle, tau)?;
+        }
+
+        for _i in 0..reader.read_varint()? {
+            let handle = read_fresh_handle(&mut reader, bound, &mut restored)?;
+            let tau = read_handle(&mut reader, bound)?;
+
+            let name = match reader.read_u8()? {
+                TAG_CONSTANT_ANONYMOUS => None,
+                TAG_CONSTANT_NAMED => Some(read_string(&mut reader)?),
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            state.restore_constant(handle, tau, name)?;
+        }
+
+        for _i in 0..reader.read_varint()? {
+            let former: Handle<tags::TypeFormer> =
+                read_handle(&mut reader, bound)?;
+            let mut constructors: Vec<Handle<tags::Constant>> = Vec::new();
+
+            for _j in 0..reader.read_varint()? {
+                constructors.push(read_handle(&mut reader, bound)?);
+            }
+
+            state.constant_mark_constructors(former, constructors)?;
+        }
+
+        for _i in 0..reader.read_varint()? {
+            let handle = read_fresh_handle(&mut reader, bound, &mut restored)?;
+
+            let trm = match reader.read_u8()? {
+                TAG_TERM_VARIABLE => Term::Variable {
+                    name: reader.read_varint()?,
+                    tau: read_handle(&mut reader, bound)?,
+                },
+                TAG_TERM_CONSTANT => Term::Constant {
+                    constant: read_handle(&mut reader, bound)?,
+                    tau: read_handle(&mut reader, bound)?,
+                },
+                TAG_TERM_APPLICATION => Term::Application {
+                    left: read_handle(&mut reader, bound)?,
+                    right: read_handle(&mut reader, bound)?,
+                },
+                TAG_TERM_LAMBDA => Term::Lambda {
+                    name: reader.read_varint()?,
+                    tau: read_handle(&mut reader, bound)?,
+                    body: read_handle(&mut reader, bound)?,
+                },
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            state.restore_term(handle, trm)?;
+        }
+
+        let count = reader.read_varint()?;
+
+        for _i in 0..count {
+            let handle = read_fresh_handle(&mut reader, bound, &mut restored)?;
+
+            let trust = match reader.read_u8()? {
+                TAG_THEOREM_UNTAINTED => TrustLevel::KernelProved,
+                TAG_THEOREM_AXIOM_DEPENDENT => TrustLevel::AxiomDependent,
+                TAG_THEOREM_TAINTED => TrustLevel::OracleDependent,
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            let mut premisses = Vec::new();
+
+            for _j in 0..reader.read_varint()? {
+                premisses.push(read_handle(&mut reader, bound)?);
+            }
+
+            let conclusion = read_handle(&mut reader, bound)?;
+
+            state.restore_theorem(handle, premisses, conclusion, trust)?;
+        }
+
+        if !reader.is_exhausted() {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        info!("Snapshot restored: {} theorems.", count);
+
+        Ok(state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_PROP, PREALLOCATED_HANDLE_UPPER_BOUND,
+        },
+        metadata::TypeFormerMetadata,
+        proof_object::{write_varint, TAG_TERM_VARIABLE},
+        runtime_state::RuntimeState,
+        serialize::{SNAPSHOT_MAGIC, SNAPSHOT_VERSION, TAG_THEOREM_UNTAINTED},
+    };
+
+    /// Tests that restoring a snapshot registers every object under its
+    /// original handle, and that handles issued afterwards are fresh.
+    #[test]
+    pub fn snapshot0() {
+        let mut kernel = RuntimeState::new();
+
+        let nat = kernel.type_former_register_with_metadata(
+            0usize,
+            TypeFormerMetadata::new("nat", "The natural numbers."),
+        );
+        let nat_type = kernel
+            .type_register_combination(
+                nat.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .unwrap();
+        let succ_type = kernel
+            .type_register_function(nat_type.clone(), nat_type.clone())
+            .unwrap();
+
+        let zero = kernel
+            .constant_register_named(nat_type.clone(), "nat.zero")
+            .unwrap();
+        let succ = kernel.constant_register(succ_type).unwrap();
+
+        kernel
+            .constant_mark_constructors(
+                nat.clone(),
+                vec![zero.clone(), succ.clone()],
+            )
+            .unwrap();
+
+        let x = kernel
+            .term_register_variable(0u64, nat_type.clone())
+            .unwrap();
+        let succ_term = kernel
+            .term_register_constant(succ, Vec::<(u64, Handle<_>)>::new())
+            .unwrap();
+        let body = kernel.term_register_application(succ_term, x).unwrap();
+        let lambda = kernel
+            .term_register_lambda(0u64, nat_type.clone(), body)
+            .unwrap();
+        let p = kernel
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let reflexivity = kernel
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(lambda)
+            .unwrap();
+        let assumption = kernel.theorem_register_assumption(p).unwrap();
+
+        let bytes = kernel.snapshot_export();
+        let mut restored = RuntimeState::snapshot_restore(&bytes).unwrap();
+
+        /* NB: restoring interns the premisses of theorems afresh, so that
+         * only the bound, a single byte here, differs on re-export.
+         */
+        assert_eq!(restored.snapshot_export()[7..], bytes[7..]);
+        assert_eq!(
+            restored.type_former_metadata(&nat).unwrap().unwrap().name(),
+            "nat"
+        );
+        assert_eq!(restored.type_former_lookup_by_name("nat"), Ok(nat.clone()));
+        assert_eq!(restored.constant_lookup_by_name("nat.zero"), Ok(zero));
+        assert_eq!(
+            restored.type_former_constructors(&nat),
+            kernel.type_former_constructors(&nat)
+        );
+
+        for thm in [reflexivity, assumption].iter() {
+            assert_eq!(
+                restored.theorem_split_premisses(thm),
+                kernel.theorem_split_premisses(thm)
+            );
+            assert_eq!(
+                restored.theorem_split_conclusion(thm),
+                kernel.theorem_split_conclusion(thm)
+            );
+        }
+
+        assert_eq!(
+            restored.type_register_combination(
+                nat,
+                Vec::<Handle<tags::Type>>::new()
+            ),
+            Ok(nat_type)
+        );
+
+        let fresh = restored.type_register_variable(5u64);
+
+        assert!(*fresh >= bytes[6] as usize);
+        assert_eq!(kernel.object_kind(*fresh), None);
+    }
+
+    /// Tests that malformed snapshots are rejected.
+    #[test]
+    pub fn snapshot1() {
+        let mut kernel = RuntimeState::new();
+
+        kernel
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let bytes = kernel.snapshot_export();
+
+        assert_eq!(
+            RuntimeState::snapshot_restore(&bytes[..bytes.len() - 1])
+                .map(|_state| ()),
+            Err(ErrorCode::MalformedProofObject)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+
+        assert_eq!(
+            RuntimeState::snapshot_restore(&trailing).map(|_state| ()),
+            Err(ErrorCode::MalformedProofObject)
+        );
+        assert_eq!(
+            RuntimeState::snapshot_restore(&kernel.state_export())
+                .map(|_state| ()),
+            Err(ErrorCode::MalformedProofObject)
+        );
+    }
+
+    /// Tests that a snapshot claiming a theorem whose conclusion is not a
+    /// proposition is rejected, as is one restoring a handle twice.
+    #[test]
+    pub fn snapshot2() {
+        let variable = PREALLOCATED_HANDLE_UPPER_BOUND as u64;
+        let theorem = variable + 1;
+
+        let snapshot = |theorem: u64| {
+            let mut bytes = SNAPSHOT_MAGIC.to_vec();
+
+            bytes.push(SNAPSHOT_VERSION);
+            bytes.push(0);
+            write_varint(&mut bytes, variable + 2);
+
+            for _table in 0..4 {
+                write_varint(&mut bytes, 0);
+            }
+
+            write_varint(&mut bytes, 1);
+            write_varint(&mut bytes, variable);
+            bytes.push(TAG_TERM_VARIABLE);
+            write_varint(&mut bytes, 0);
+            write_varint(&mut bytes, *PREALLOCATED_HANDLE_TYPE_ALPHA as u64);
+
+            write_varint(&mut bytes, 1);
+            write_varint(&mut bytes, theorem);
+            bytes.push(TAG_THEOREM_UNTAINTED);
+            write_varint(&mut bytes, 0);
+            write_varint(&mut bytes, variable);
+
+            bytes
+        };
+
+        assert_eq!(
+            RuntimeState::snapshot_restore(&snapshot(theorem)).map(|_s| ()),
+            Err(ErrorCode::NotAProposition)
+        );
+        assert_eq!(
+            RuntimeState::snapshot_restore(&snapshot(variable)).map(|_s| ()),
+            Err(ErrorCode::MalformedProofObject)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! # Copy-on-write tables
+//!
+//! The tables of a runtime state may be shared between many runtime states,
+//! e.g. every instance of a pool of guests instantiated from one frozen base
+//! theory, so that each instance need not hold its own copy of the base.  A
+//! shared table is copied the first time that a runtime state sharing it
+//! writes to it, so that the write is private to that runtime state, and is
+//! thereafter owned by it outright.  Reading a shared table never copies it.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Shared tables.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A table that may be shared with other runtime states, and which is copied
+/// when first written to whilst shared.  Cloning a shared table shares it,
+/// rather than copying it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Shared<T>(Arc<T>);
+
+impl<T> Shared<T> {
+    /// Returns `true` iff `self` and `other` share the same table, so that
+    /// neither has been written to since one was cloned from the other.
+    #[cfg(test)]
+    #[inline]
+    pub(crate) fn is_shared_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> From<T> for Shared<T> {
+    #[inline]
+    fn from(table: T) -> Self {
+        Shared(Arc::new(table))
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Shared<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::shared::Shared;
+
+    /// Tests that writing to a shared table copies it, leaving the tables that
+    /// it was shared with untouched.
+    #[test]
+    pub fn shared0() {
+        let mut base: Shared<Vec<u64>> = Shared::default();
+
+        base.push(0);
+
+        let mut overlay = base.clone();
+
+        assert!(overlay.is_shared_with(&base));
+
+        overlay.push(1);
+
+        assert!(!overlay.is_shared_with(&base));
+        assert_eq!(*base, vec![0]);
+        assert_eq!(*overlay, vec![0, 1]);
+    }
+}
@@ -0,0 +1,1191 @@
+//! # Serialized runtime states
+//!
+//! Whereas a proof object carries the sequents of a handful of theorems, and
+//! refers to the type-formers and constants that they mention by raw handle, a
+//! *state object* carries the whole signature of a runtime state: every
+//! type-former and constant registered in it, besides the kernel's own, along
+//! with the sequents of every theorem registered in it.  State objects are
+//! self-contained, so can be imported into any runtime state, and two state
+//! objects can be merged into one, allowing theories developed separately to
+//! be combined.
+//!
+//! The format reuses the type-, term- and theorem-table encodings of proof
+//! objects, and is as follows, where all integers are unsigned LEB128 varints
+//! and strings are a length followed by that many bytes of UTF-8:
+//!
+//! ```text
+//! magic     : "SPVS"
+//! version   : u8
+//! flags     : u8
+//! formers   : count, then `count` type-former entries
+//! types     : count, then `count` type entries
+//! constants : count, then `count` constant entries
+//! terms     : count, then `count` term entries
+//! theorems  : count, then `count` theorem entries
+//! metadata  : count, then `count` metadata entries
+//! scratch   : count, then `count` scratch entries, iff bit 0 of `flags` is set
+//! ```
+//!
+//! A type-former entry is `handle, arity, 0` for a type-former registered
+//! without metadata, or `handle, arity, 1, name, documentation` otherwise.  A
+//! constant entry is `handle, type, 0` for a plain constant, or `handle, type,
+//! 1, former, index` for the `index`th constructor of the datatype with
+//! type-former `former`.  The handle of every type-former and constant is the
+//! handle that it had in the exporting runtime state, and the handles written
+//! in type- and term-entries refer to these, or to the kernel's preallocated
+//! type-formers and constants.
+//!
+//! A metadata entry is `kind, index, count`, followed by `count` pairs of a
+//! string key and a value, written as a length followed by that many bytes.
+//! It carries the metadata attached by prover-space code to the type-former or
+//! constant with handle `index`, for a `kind` of 0 or 1 respectively, or to the
+//! type, term, or theorem at position `index` in its table, for a `kind` of 2,
+//! 3 or 4.  Metadata attached to objects of any other kind, or to types and
+//! terms that the state object does not otherwise mention, is not exported.
+//!
+//! A scratch entry is a string key followed by a value, written as a length
+//! followed by that many bytes.  The scratch store of a runtime state is only
+//! exported if the runtime state has been asked to persist it, whereas merging
+//! state objects keeps the scratch entries of both.
+//!
+//! Importing a state object registers fresh copies of its type-formers and
+//! constants, except that a named type-former is identified with the first
+//! type-former already registered under the same name, as are the
+//! constructors of the two datatypes.  Everything else is deduplicated
+//! structurally, by the kernel's maximal sharing of types and terms.  Names
+//! are the only identity that type-formers and constants carry across runtime
+//! states, so an unnamed type-former, or a constant which is not a
+//! constructor, is never identified with an existing one.  As with proof
+//! objects, no theorems are registered: the imported sequents are only claims,
+//! and must be re-proved before they may be used as theorems.  The imported
+//! metadata of a theorem is therefore attached to every theorem with the same
+//! statement registered after the import, whereas that of other objects is
+//! attached to their imported copies straightaway, replacing any values
+//! already attached under the same keys.  Likewise, imported scratch entries
+//! replace any already stored under the same keys.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{is_preallocated, tags, Handle, Kind},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    metadata::{ObjectMetadata, TypeFormerMetadata},
+    observer::LongOperation,
+    proof_object::{
+        decode_sequent_table, write_varint, Encoder, Reader, Sequent,
+    },
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Display, Error as DisplayError, Formatter},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Format constants.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The magic bytes with which every serialized state object begins.
+pub const STATE_OBJECT_MAGIC: &[u8; 4] = b"SPVS";
+/// The version of the state object format produced by this kernel.
+pub const STATE_OBJECT_VERSION: u8 = 2;
+
+/// Flag of a state object carrying the scratch store of the runtime state that
+/// exported it.
+const FLAG_SCRATCH: u8 = 1;
+
+/// Tag of a type-former entry without metadata.
+pub(crate) const TAG_FORMER_ANONYMOUS: u8 = 0;
+/// Tag of a type-former entry with metadata.
+pub(crate) const TAG_FORMER_NAMED: u8 = 1;
+/// Tag of a constant entry for a plain constant.
+const TAG_CONSTANT_PLAIN: u8 = 0;
+/// Tag of a constant entry for a constructor of a datatype.
+const TAG_CONSTANT_CONSTRUCTOR: u8 = 1;
+/// Tag of a metadata entry for a type-former.
+const TAG_METADATA_FORMER: u8 = 0;
+/// Tag of a metadata entry for a constant.
+const TAG_METADATA_CONSTANT: u8 = 1;
+/// Tag of a metadata entry for a type.
+const TAG_METADATA_TYPE: u8 = 2;
+/// Tag of a metadata entry for a term.
+const TAG_METADATA_TERM: u8 = 3;
+/// Tag of a metadata entry for a theorem.
+const TAG_METADATA_THEOREM: u8 = 4;
+
+////////////////////////////////////////////////////////////////////////////////
+// Errors.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An incompatibility between a named object of an imported state object and
+/// the object already registered under the same name.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MergeConflict {
+    /// The type-former registered, and the type-former imported, under `name`
+    /// have different arities.
+    TypeFormerArity {
+        /// The name shared by the type-formers.
+        name: String,
+        /// The arity of the type-former already registered.
+        existing: usize,
+        /// The arity of the type-former being imported.
+        imported: usize,
+    },
+    /// The datatype registered, and the datatype imported, under `name` have
+    /// different constructors, either in number or in type.
+    Constructors {
+        /// The name shared by the type-formers of the datatypes.
+        name: String,
+    },
+}
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            MergeConflict::TypeFormerArity {
+                name,
+                existing,
+                imported,
+            } => write!(
+                f,
+                "type-former {} has arity {}, but is imported with arity {}",
+                name, existing, imported
+            ),
+            MergeConflict::Constructors { name } => write!(
+                f,
+                "datatype {} is imported with different constructors",
+                name
+            ),
+        }
+    }
+}
+
+/// The ways in which importing, or merging, state objects can fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StateObjectError {
+    /// The state object was malformed, or did not describe well-formed kernel
+    /// objects.
+    Kernel(ErrorCode),
+    /// The state object was well-formed, but its named objects conflict with
+    /// those already registered.  Every conflict found is reported.
+    Conflicts(Vec<MergeConflict>),
+}
+
+impl From<ErrorCode> for StateObjectError {
+    #[inline]
+    fn from(code: ErrorCode) -> Self {
+        StateObjectError::Kernel(code)
+    }
+}
+
+impl Display for StateObjectError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            StateObjectError::Kernel(code) => write!(f, "{}", code),
+            StateObjectError::Conflicts(conflicts) => {
+                for (index, conflict) in conflicts.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{}", conflict)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Strings.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Appends `bytes`, preceded by their length, to `buffer`.
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    write_varint(buffer, bytes.len() as u64);
+    buffer.extend(bytes);
+}
+
+/// Appends `string`, preceded by its length in bytes, to `buffer`.
+#[inline]
+pub(crate) fn write_string(buffer: &mut Vec<u8>, string: &str) {
+    write_bytes(buffer, string.as_bytes());
+}
+
+/// Reads bytes written by `write_bytes`.
+fn read_bytes(reader: &mut Reader) -> Result<Vec<u8>, ErrorCode> {
+    /* NB: as with tables, the length is untrusted, so the bytes are grown as
+     * they are actually read.
+     */
+    let mut bytes = Vec::new();
+
+    for _i in 0..reader.read_varint()? {
+        bytes.push(reader.read_u8()?);
+    }
+
+    Ok(bytes)
+}
+
+/// Reads a string written by `write_string`, rejecting invalid UTF-8.
+#[inline]
+pub(crate) fn read_string(reader: &mut Reader) -> Result<String, ErrorCode> {
+    String::from_utf8(read_bytes(reader)?)
+        .map_err(|_e| ErrorCode::MalformedProofObject)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Exporting and importing state objects.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Serializes the signature of the runtime state, and the sequents of every
+    /// theorem registered in it, into a state object.
+    pub fn state_export(&self) -> Vec<u8> {
+        info!("Exporting runtime state as a state object.");
+
+        let sequents: Vec<Sequent> = self
+            .theorem_handles()
+            .iter()
+            .map(|t| Sequent {
+                premisses: self
+                    .theorem_split_premisses(t)
+                    .expect(DANGLING_HANDLE_ERROR)
+                    .to_vec(),
+                conclusion: self
+                    .theorem_split_conclusion(t)
+                    .expect(DANGLING_HANDLE_ERROR),
+            })
+            .collect();
+
+        self.encode_state(&sequents)
+    }
+
+    /// Serializes the signature of the runtime state, and `sequents`, whose
+    /// terms must be registered in the runtime state, into a state object.
+    pub(crate) fn encode_state(&self, sequents: &[Sequent]) -> Vec<u8> {
+        let mut encoder = Encoder::new(self);
+
+        let formers = self.type_former_handles();
+        let mut former_table = Vec::new();
+
+        for former in formers.iter() {
+            let arity = self
+                .type_former_resolve(former)
+                .expect(DANGLING_HANDLE_ERROR);
+
+            write_varint(&mut former_table, **former as u64);
+            write_varint(&mut former_table, *arity as u64);
+
+            match self
+                .type_former_metadata(former)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                None => former_table.push(TAG_FORMER_ANONYMOUS),
+                Some(metadata) => {
+                    former_table.push(TAG_FORMER_NAMED);
+                    write_string(&mut former_table, metadata.name());
+                    write_string(&mut former_table, metadata.documentation());
+                }
+            }
+        }
+
+        let constants = self.constant_handles();
+        let mut constant_table = Vec::new();
+
+        for constant in constants.iter() {
+            let tau = self
+                .constant_resolve(constant)
+                .expect(DANGLING_HANDLE_ERROR);
+            let tau = encoder.encode_type(tau);
+
+            write_varint(&mut constant_table, **constant as u64);
+            write_varint(&mut constant_table, tau);
+
+            match self.constant_constructor_siblings(constant) {
+                Err(_e) => constant_table.push(TAG_CONSTANT_PLAIN),
+                Ok((former, constructors)) => {
+                    let index = constructors
+                        .iter()
+                        .position(|c| c == constant)
+                        .expect(DANGLING_HANDLE_ERROR);
+
+                    constant_table.push(TAG_CONSTANT_CONSTRUCTOR);
+                    write_varint(&mut constant_table, **former as u64);
+                    write_varint(&mut constant_table, index as u64);
+                }
+            }
+        }
+
+        let mut theorem_table = Vec::new();
+
+        for sequent in sequents {
+            write_varint(&mut theorem_table, sequent.premisses.len() as u64);
+
+            for p in sequent.premisses.iter() {
+                let p = encoder.encode_term(p);
+                write_varint(&mut theorem_table, p);
+            }
+
+            let conclusion = encoder.encode_term(&sequent.conclusion);
+            write_varint(&mut theorem_table, conclusion);
+        }
+
+        let mut metadata_table = Vec::new();
+        let metadata_count = self.encode_metadata(
+            &encoder,
+            &formers,
+            &constants,
+            sequents,
+            &mut metadata_table,
+        );
+
+        let mut buffer = Vec::new();
+
+        buffer.extend(STATE_OBJECT_MAGIC);
+        buffer.push(STATE_OBJECT_VERSION);
+        buffer.push(if self.persist_scratch {
+            FLAG_SCRATCH
+        } else {
+            0
+        });
+
+        write_varint(&mut buffer, formers.len() as u64);
+        buffer.extend(former_table);
+        encoder.write_type_table(&mut buffer);
+        write_varint(&mut buffer, constants.len() as u64);
+        buffer.extend(constant_table);
+        encoder.write_term_table(&mut buffer);
+        write_varint(&mut buffer, sequents.len() as u64);
+        buffer.extend(theorem_table);
+        write_varint(&mut buffer, metadata_count);
+        buffer.extend(metadata_table);
+
+        if self.persist_scratch {
+            write_varint(&mut buffer, self.scratch.len() as u64);
+
+            for (key, value) in self.scratch.iter() {
+                write_string(&mut buffer, key);
+                write_bytes(&mut buffer, value);
+            }
+        }
+
+        info!("State object exported: {} bytes.", buffer.len());
+
+        buffer
+    }
+
+    /// Appends a metadata entry for every type-former in `formers`, constant in
+    /// `constants`, and type and term serialized by `encoder`, with metadata
+    /// attached, and for every statement in `sequents` with metadata attached
+    /// to a theorem with that statement, or awaiting one, to `buffer`.
+    /// Returns the number of entries appended.
+    fn encode_metadata(
+        &self,
+        encoder: &Encoder,
+        formers: &[Handle<tags::TypeFormer>],
+        constants: &[Handle<tags::Constant>],
+        sequents: &[Sequent],
+        buffer: &mut Vec<u8>,
+    ) -> u64 {
+        let mut handles: Vec<usize> =
+            self.object_metadata.keys().cloned().collect();
+        handles.sort_unstable();
+
+        let mut entries = Vec::new();
+        let mut statements = self.pending_theorem_metadata.clone();
+
+        for handle in handles {
+            let metadata =
+                self.object_metadata(handle).expect(DANGLING_HANDLE_ERROR);
+
+            let entry = match self.object_kind(handle) {
+                Some(Kind::TypeFormer)
+                    if formers.binary_search(&Handle::from(handle)).is_ok() =>
+                {
+                    Some((TAG_METADATA_FORMER, handle as u64))
+                }
+                Some(Kind::Constant)
+                    if constants
+                        .binary_search(&Handle::from(handle))
+                        .is_ok() =>
+                {
+                    Some((TAG_METADATA_CONSTANT, handle as u64))
+                }
+                Some(Kind::Type) => encoder
+                    .type_index(&Handle::from(handle))
+                    .map(|index| (TAG_METADATA_TYPE, index)),
+                Some(Kind::Term) => encoder
+                    .term_index(&Handle::from(handle))
+                    .map(|index| (TAG_METADATA_TERM, index)),
+                Some(Kind::Theorem) => {
+                    let theorem = Handle::from(handle);
+                    let statement = Sequent {
+                        premisses: self
+                            .theorem_split_premisses(&theorem)
+                            .expect(DANGLING_HANDLE_ERROR)
+                            .to_vec(),
+                        conclusion: self
+                            .theorem_split_conclusion(&theorem)
+                            .expect(DANGLING_HANDLE_ERROR),
+                    };
+
+                    statements
+                        .entry(statement)
+                        .or_default()
+                        .extend(metadata.clone());
+
+                    None
+                }
+                _otherwise => None,
+            };
+
+            if let Some(entry) = entry {
+                entries.push((entry, metadata));
+            }
+        }
+
+        for (index, sequent) in sequents.iter().enumerate() {
+            if let Some(metadata) = statements.get(sequent) {
+                entries.push(((TAG_METADATA_THEOREM, index as u64), metadata));
+            }
+        }
+
+        for ((tag, index), metadata) in entries.iter() {
+            buffer.push(*tag);
+            write_varint(buffer, *index);
+            write_varint(buffer, metadata.len() as u64);
+
+            for (key, value) in metadata.iter() {
+                write_string(buffer, key);
+                write_bytes(buffer, value);
+            }
+        }
+
+        entries.len() as u64
+    }
+
+    /// Decodes the state object `bytes`, registering its type-formers,
+    /// constants, types and terms in the runtime state, and returns the
+    /// sequents of its theorems, in order.  No theorems are registered.  Named
+    /// type-formers, and the constructors of their datatypes, are identified
+    /// with those already registered under the same name, if any.  The import
+    /// is all-or-nothing: if it fails, nothing is registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(StateObjectError::Conflicts(conflicts))` if any named
+    /// type-former of the state object has a different arity to the
+    /// type-former already registered under its name, or if both have been
+    /// marked with constructors, and the constructors differ in number or in
+    /// type.
+    ///
+    /// Returns `Err(StateObjectError::Kernel(ErrorCode::MalformedProofObject))`
+    /// if `bytes` is truncated, has trailing data, has the wrong magic bytes,
+    /// version or flags, contains an unknown tag, a string which is not valid
+    /// UTF-8, a reference that does not point backwards into its table, or a
+    /// handle that is neither preallocated nor registered earlier in the state
+    /// object.
+    ///
+    /// Otherwise, returns whatever error the kernel's registration functions
+    /// return if an entry in the state object does not describe a well-formed
+    /// kernel object, as for proof objects.
+    pub fn state_import(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<Vec<Sequent>, StateObjectError> {
+        info!("Importing state object of {} bytes.", bytes.len());
+
+        let mut conflicts = Vec::new();
+        let mut scratch = Vec::new();
+
+        self.speculate_begin();
+
+        let result = self.with_progress(LongOperation::StateImport, |state| {
+            state.decode_state(bytes, &mut conflicts, &mut scratch)
+        });
+
+        self.speculate_end(result.is_ok() && conflicts.is_empty())?;
+
+        let sequents = result?;
+
+        if !conflicts.is_empty() {
+            return Err(StateObjectError::Conflicts(conflicts));
+        }
+
+        /* NB: the scratch store is untouched by speculative branches, so its
+         * entries are only stored once the import is known to succeed.
+         */
+        self.scratch.extend(scratch);
+
+        info!("State object imported: {} sequents.", sequents.len());
+
+        Ok(sequents)
+    }
+
+    /// Decodes the state object `bytes`, as `state_import`, pushing every
+    /// conflict found to `conflicts` rather than stopping at the first, and
+    /// every scratch entry to `scratch`.
+    fn decode_state(
+        &mut self,
+        bytes: &[u8],
+        conflicts: &mut Vec<MergeConflict>,
+        scratch: &mut Vec<(String, Vec<u8>)>,
+    ) -> Result<Vec<Sequent>, ErrorCode> {
+        let mut reader = Reader::new(bytes);
+
+        let flags = reader.read_header_with_flags(
+            STATE_OBJECT_MAGIC,
+            STATE_OBJECT_VERSION,
+            FLAG_SCRATCH,
+        )?;
+
+        /* NB: only type-formers registered before the import are candidates
+         * for identification, so that a state object naming two type-formers
+         * alike round-trips faithfully.
+         */
+        let mut named = HashMap::new();
+
+        for former in self.type_former_handles() {
+            if let Some(metadata) = self.type_former_metadata(&former)? {
+                if !metadata.name().is_empty() {
+                    named.entry(metadata.name().to_string()).or_insert(former);
+                }
+            }
+        }
+
+        let mut formers: HashMap<usize, Handle<tags::TypeFormer>> =
+            HashMap::new();
+        let mut identified: HashMap<usize, String> = HashMap::new();
+
+        for _i in 0..reader.read_varint()? {
+            let original = reader.read_varint()? as usize;
+            let arity = reader.read_varint()? as usize;
+
+            let metadata = match reader.read_u8()? {
+                TAG_FORMER_ANONYMOUS => None,
+                TAG_FORMER_NAMED => {
+                    let name = read_string(&mut reader)?;
+                    let documentation = read_string(&mut reader)?;
+
+                    Some(TypeFormerMetadata::new(name, documentation))
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            let existing =
+                metadata.as_ref().and_then(|m| named.get(m.name())).cloned();
+
+            let handle = match (existing, metadata) {
+                (Some(existing), Some(metadata)) => {
+                    let existing_arity = *self
+                        .type_former_resolve(&existing)
+                        .expect(DANGLING_HANDLE_ERROR);
+
+                    if existing_arity == arity {
+                        identified
+                            .insert(original, metadata.name().to_string());
+                        existing
+                    } else {
+                        conflicts.push(MergeConflict::TypeFormerArity {
+                            name: metadata.name().to_string(),
+                            existing: existing_arity,
+                            imported: arity,
+                        });
+                        self.type_former_register_with_metadata(arity, metadata)
+                    }
+                }
+                (_existing, Some(metadata)) => {
+                    self.type_former_register_with_metadata(arity, metadata)
+                }
+                (_existing, None) => self.type_former_register(arity),
+            };
+
+            if is_preallocated(Handle::<tags::TypeFormer>::from(original))
+                || formers.insert(original, handle).is_some()
+            {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+        }
+
+        let types =
+            self.decode_type_table(&mut reader, |h| match formers.get(&h) {
+                Some(former) => Ok(former.clone()),
+                None if is_preallocated(Handle::<tags::TypeFormer>::from(
+                    h,
+                )) =>
+                {
+                    Ok(Handle::from(h))
+                }
+                None => Err(ErrorCode::MalformedProofObject),
+            })?;
+
+        let mut constants: HashMap<usize, Handle<tags::Constant>> =
+            HashMap::new();
+        let mut matched: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut markings: BTreeMap<
+            usize,
+            Vec<(usize, Handle<tags::Constant>)>,
+        > = BTreeMap::new();
+
+        for _i in 0..reader.read_varint()? {
+            let original = reader.read_varint()? as usize;
+            let tau = reader.read_reference(types.len())?;
+            let tau = types[tau].clone();
+
+            let handle = match reader.read_u8()? {
+                TAG_CONSTANT_PLAIN => self.constant_register(tau)?,
+                TAG_CONSTANT_CONSTRUCTOR => {
+                    let former = reader.read_varint()? as usize;
+                    let index = reader.read_varint()? as usize;
+
+                    let handle = formers
+                        .get(&former)
+                        .ok_or(ErrorCode::MalformedProofObject)?;
+
+                    match (
+                        identified.get(&former),
+                        self.type_former_constructors(handle).cloned(),
+                    ) {
+                        (Some(name), Some(existing)) => {
+                            matched.entry(former).or_default().push(index);
+
+                            match existing.get(index) {
+                                Some(constructor)
+                                    if self
+                                        .constant_resolve(constructor)?
+                                        == &tau =>
+                                {
+                                    constructor.clone()
+                                }
+                                _otherwise => {
+                                    let conflict =
+                                        MergeConflict::Constructors {
+                                            name: name.clone(),
+                                        };
+
+                                    if !conflicts.contains(&conflict) {
+                                        conflicts.push(conflict);
+                                    }
+
+                                    self.constant_register(tau)?
+                                }
+                            }
+                        }
+                        _otherwise => {
+                            let constant = self.constant_register(tau)?;
+
+                            markings
+                                .entry(former)
+                                .or_default()
+                                .push((index, constant.clone()));
+
+                            constant
+                        }
+                    }
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            };
+
+            if is_preallocated(Handle::<tags::Constant>::from(original))
+                || constants.insert(original, handle).is_some()
+            {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+        }
+
+        for (former, mut indices) in matched {
+            indices.sort_unstable();
+
+            if indices.windows(2).any(|w| w[0] == w[1]) {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+
+            let name = identified.get(&former).expect(DANGLING_HANDLE_ERROR);
+            let existing = self
+                .type_former_constructors(&formers[&former])
+                .expect(DANGLING_HANDLE_ERROR);
+            let conflict = MergeConflict::Constructors { name: name.clone() };
+
+            if indices.len() != existing.len() && !conflicts.contains(&conflict)
+            {
+                conflicts.push(conflict);
+            }
+        }
+
+        for (former, mut constructors) in markings {
+            constructors.sort();
+
+            if constructors
+                .iter()
+                .enumerate()
+                .any(|(i, (index, _c))| i != *index)
+            {
+                return Err(ErrorCode::MalformedProofObject);
+            }
+
+            self.constant_mark_constructors(
+                formers[&former].clone(),
+                constructors.into_iter().map(|(_index, c)| c).collect(),
+            )?;
+        }
+
+        let terms = self.decode_term_table(&mut reader, &types, |h| {
+            match constants.get(&h) {
+                Some(constant) => Ok(constant.clone()),
+                None if is_preallocated(Handle::<tags::Constant>::from(h)) => {
+                    Ok(Handle::from(h))
+                }
+                None => Err(ErrorCode::MalformedProofObject),
+            }
+        })?;
+
+        let sequents = decode_sequent_table(&mut reader, &terms)?;
+
+        for _i in 0..reader.read_varint()? {
+            let tag = reader.read_u8()?;
+            let index = reader.read_varint()? as usize;
+
+            let mut metadata = ObjectMetadata::new();
+
+            for _j in 0..reader.read_varint()? {
+                let key = read_string(&mut reader)?;
+                let value = read_bytes(&mut reader)?;
+
+                metadata.insert(key, value);
+            }
+
+            let handle = match tag {
+                TAG_METADATA_FORMER => formers.get(&index).map(|h| **h),
+                TAG_METADATA_CONSTANT => constants.get(&index).map(|h| **h),
+                TAG_METADATA_TYPE => types.get(index).map(|h| **h),
+                TAG_METADATA_TERM => terms.get(index).map(|h| **h),
+                TAG_METADATA_THEOREM => {
+                    let statement = sequents
+                        .get(index)
+                        .ok_or(ErrorCode::MalformedProofObject)?;
+
+                    self.pending_theorem_metadata
+                        .entry(statement.clone())
+                        .or_default()
+                        .extend(metadata);
+
+                    continue;
+                }
+                _otherwise => return Err(ErrorCode::MalformedProofObject),
+            }
+            .ok_or(ErrorCode::MalformedProofObject)?;
+
+            self.object_metadata
+                .entry(handle)
+                .or_default()
+                .extend(metadata);
+        }
+
+        if flags & FLAG_SCRATCH != 0 {
+            for _i in 0..reader.read_varint()? {
+                let key = read_string(&mut reader)?;
+                let value = read_bytes(&mut reader)?;
+
+                scratch.push((key, value));
+            }
+        }
+
+        if !reader.is_exhausted() {
+            return Err(ErrorCode::MalformedProofObject);
+        }
+
+        Ok(sequents)
+    }
+}
+
+/// Merges the state objects `left` and `right` into a single state object,
+/// containing the union of their signatures and theorems.  Named type-formers
+/// of `right`, and the constructors of their datatypes, are identified with
+/// those of `left`, and theorems with the same sequent appear only once.  The
+/// scratch entries of `right` replace those of `left` stored under the same
+/// keys.
+///
+/// # Errors
+///
+/// Returns any error that importing `left`, and then `right`, into an empty
+/// runtime state does, including every conflict between the named objects of
+/// `right` and those of `left`.
+pub fn state_merge(
+    left: &[u8],
+    right: &[u8],
+) -> Result<Vec<u8>, StateObjectError> {
+    info!(
+        "Merging state objects of {} and {} bytes.",
+        left.len(),
+        right.len()
+    );
+
+    let mut kernel = RuntimeState::new();
+    let mut sequents = kernel.state_import(left)?;
+
+    for sequent in kernel.state_import(right)? {
+        if !sequents.contains(&sequent) {
+            sequents.push(sequent);
+        }
+    }
+
+    kernel.persist_scratch = !kernel.scratch.is_empty();
+
+    Ok(kernel.encode_state(&sequents))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for state object serialization and merging.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{tags, Handle},
+        metadata::TypeFormerMetadata,
+        runtime_state::RuntimeState,
+        state_object::{state_merge, MergeConflict, StateObjectError},
+    };
+
+    /// Registers the datatype of natural numbers, named `nat`, with
+    /// constructors `zero` and `succ`, and the theorem
+    /// `zero = zero ⊢ zero = zero`, returning the constructors.
+    fn natural_numbers(
+        kernel: &mut RuntimeState,
+    ) -> Vec<Handle<tags::Constant>> {
+        let nat = kernel.type_former_register_with_metadata(
+            0usize,
+            TypeFormerMetadata::new("nat", "The natural numbers."),
+        );
+        let nat_type = kernel
+            .type_register_combination(
+                nat.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .unwrap();
+        let succ_type = kernel
+            .type_register_function(nat_type.clone(), nat_type.clone())
+            .unwrap();
+
+        let zero = kernel.constant_register(nat_type).unwrap();
+        let succ = kernel.constant_register(succ_type).unwrap();
+
+        kernel
+            .constant_mark_constructors(nat, vec![zero.clone(), succ.clone()])
+            .unwrap();
+
+        let zero_term = kernel
+            .term_register_constant(
+                zero.clone(),
+                Vec::<(u64, Handle<_>)>::new(),
+            )
+            .unwrap();
+
+        let eq = kernel
+            .term_register_equality(zero_term.clone(), zero_term)
+            .unwrap();
+
+        kernel.theorem_register_assumption(eq).unwrap();
+
+        vec![zero, succ]
+    }
+
+    /// Tests that a state object can be imported into a fresh runtime state,
+    /// recovering its named type-former, constructors and sequents, and that
+    /// importing the same state object twice yields the same state.
+    #[test]
+    pub fn state_object_test0() {
+        let mut kernel = RuntimeState::new();
+
+        natural_numbers(&mut kernel);
+
+        let bytes = kernel.state_export();
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.state_import(&bytes).unwrap();
+
+        assert_eq!(sequents.len(), 1);
+        assert_eq!(sequents[0].premisses, vec![sequents[0].conclusion.clone()]);
+
+        let formers = fresh.type_former_handles();
+
+        assert_eq!(formers.len(), 1);
+        assert_eq!(
+            fresh
+                .type_former_metadata(&formers[0])
+                .unwrap()
+                .unwrap()
+                .name(),
+            "nat"
+        );
+        assert_eq!(
+            fresh.type_former_constructors(&formers[0]).unwrap().len(),
+            2
+        );
+
+        let mut again = RuntimeState::new();
+        let again_sequents = again.state_import(&bytes).unwrap();
+
+        assert_eq!(
+            again.encode_state(&again_sequents),
+            fresh.encode_state(&sequents)
+        );
+    }
+
+    /// Tests that merging a state object with itself identifies its named
+    /// type-former and constructors, and deduplicates its sequents, so that the
+    /// merge adds nothing to importing the state object once.
+    #[test]
+    pub fn state_object_test1() {
+        let mut kernel = RuntimeState::new();
+
+        natural_numbers(&mut kernel);
+
+        let bytes = kernel.state_export();
+        let merged = state_merge(&bytes, &bytes).unwrap();
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.state_import(&bytes).unwrap();
+
+        assert_eq!(merged, fresh.encode_state(&sequents));
+    }
+
+    /// Tests that merging adds the objects of both state objects, and that
+    /// the sequents about identified constructors are shared.
+    #[test]
+    pub fn state_object_test2() {
+        let mut left = RuntimeState::new();
+        natural_numbers(&mut left);
+
+        let mut right = RuntimeState::new();
+        natural_numbers(&mut right);
+
+        let pair = right.type_former_register(2usize);
+        let alpha = right.type_register_variable(0u64);
+        let beta = right.type_register_variable(1u64);
+        let other_type = right
+            .type_register_combination(pair, vec![alpha, beta])
+            .unwrap();
+        right.constant_register(other_type).unwrap();
+
+        let merged =
+            state_merge(&left.state_export(), &right.state_export()).unwrap();
+
+        let mut kernel = RuntimeState::new();
+        let sequents = kernel.state_import(&merged).unwrap();
+
+        assert_eq!(sequents.len(), 1);
+        assert_eq!(kernel.type_former_handles().len(), 2);
+        assert_eq!(kernel.constant_handles().len(), 3);
+    }
+
+    /// Tests that type-formers sharing a name but not an arity are reported as
+    /// conflicting, and that a conflicting import registers nothing.
+    #[test]
+    pub fn state_object_test3() {
+        let mut left = RuntimeState::new();
+        natural_numbers(&mut left);
+
+        let mut right = RuntimeState::new();
+        right.type_former_register_with_metadata(
+            1usize,
+            TypeFormerMetadata::new("nat", ""),
+        );
+
+        let bytes = right.state_export();
+        let formers = left.type_former_handles();
+        let constants = left.constant_handles();
+
+        assert_eq!(
+            left.state_import(&bytes),
+            Err(StateObjectError::Conflicts(vec![
+                MergeConflict::TypeFormerArity {
+                    name: String::from("nat"),
+                    existing: 0,
+                    imported: 1,
+                }
+            ]))
+        );
+        assert_eq!(left.type_former_handles(), formers);
+        assert_eq!(left.constant_handles(), constants);
+    }
+
+    /// Tests that datatypes sharing a name but not their constructors are
+    /// reported as conflicting.
+    #[test]
+    pub fn state_object_test4() {
+        let mut left = RuntimeState::new();
+        natural_numbers(&mut left);
+
+        let mut right = RuntimeState::new();
+        let nat = right.type_former_register_with_metadata(
+            0usize,
+            TypeFormerMetadata::new("nat", ""),
+        );
+        let nat_type = right
+            .type_register_combination(
+                nat.clone(),
+                Vec::<Handle<tags::Type>>::new(),
+            )
+            .unwrap();
+        let zero = right.constant_register(nat_type).unwrap();
+        right.constant_mark_constructors(nat, vec![zero]).unwrap();
+
+        assert_eq!(
+            state_merge(&left.state_export(), &right.state_export()),
+            Err(StateObjectError::Conflicts(vec![
+                MergeConflict::Constructors {
+                    name: String::from("nat"),
+                }
+            ]))
+        );
+    }
+
+    /// Tests that malformed state objects are rejected.
+    #[test]
+    pub fn state_object_test5() {
+        let mut kernel = RuntimeState::new();
+        natural_numbers(&mut kernel);
+
+        let bytes = kernel.state_export();
+
+        let mut fresh = RuntimeState::new();
+
+        assert_eq!(
+            fresh.state_import(&bytes[..bytes.len() - 1]),
+            Err(StateObjectError::Kernel(ErrorCode::MalformedProofObject))
+        );
+        assert_eq!(
+            fresh.state_import(
+                &kernel.theorem_export::<&Handle<_>>(&[]).unwrap()
+            ),
+            Err(StateObjectError::Kernel(ErrorCode::MalformedProofObject))
+        );
+        assert!(fresh.type_former_handles().is_empty());
+    }
+
+    /// Tests that metadata attached to type-formers, constants, terms and
+    /// theorems survives exporting and importing a state object, the latter
+    /// once the theorem is re-proved.
+    #[test]
+    pub fn state_object_test6() {
+        let mut kernel = RuntimeState::new();
+        let constructors = natural_numbers(&mut kernel);
+
+        let nat = kernel.type_former_handles()[0].clone();
+        let theorem = kernel.theorem_handles()[0].clone();
+        let conclusion = kernel.theorem_split_conclusion(&theorem).unwrap();
+
+        kernel.object_metadata_set(*nat, "tag", "datatype").unwrap();
+        kernel
+            .object_metadata_set(*constructors[1], "position", vec![1, 2])
+            .unwrap();
+        kernel
+            .object_metadata_set(*conclusion, "printing", "infix")
+            .unwrap();
+        kernel
+            .object_metadata_set(*theorem, "priority", vec![7])
+            .unwrap();
+
+        let bytes = kernel.state_export();
+
+        let mut fresh = RuntimeState::new();
+        let sequents = fresh.state_import(&bytes).unwrap();
+
+        let nat = fresh.type_former_handles()[0].clone();
+        let succ = fresh.type_former_constructors(&nat).unwrap()[1].clone();
+
+        assert_eq!(
+            fresh.object_metadata_get(*nat, "tag"),
+            Ok(&b"datatype"[..])
+        );
+        assert_eq!(
+            fresh.object_metadata_get(*succ, "position"),
+            Ok(&[1, 2][..])
+        );
+        assert_eq!(
+            fresh.object_metadata_get(*sequents[0].conclusion, "printing"),
+            Ok(&b"infix"[..])
+        );
+
+        let theorem = fresh
+            .theorem_register_assumption(sequents[0].conclusion.clone())
+            .unwrap();
+
+        assert_eq!(
+            fresh.object_metadata_get(*theorem, "priority"),
+            Ok(&[7][..])
+        );
+    }
+
+    /// Tests that the scratch store is exported only whilst persistence is
+    /// enabled, that importing restores it, and that merging keeps the
+    /// entries of both state objects, preferring those of the right.
+    #[test]
+    pub fn state_object_test7() {
+        let mut kernel = RuntimeState::new();
+        natural_numbers(&mut kernel);
+
+        kernel.scratch_put("goal", vec![1, 2]);
+        kernel.scratch_put("phase", vec![1]);
+
+        let transient = kernel.state_export();
+
+        kernel.set_scratch_persistence(true);
+
+        let persistent = kernel.state_export();
+
+        let mut fresh = RuntimeState::new();
+        fresh.state_import(&transient).unwrap();
+
+        assert!(fresh.scratch_keys().is_empty());
+
+        fresh.scratch_put("phase", vec![0]);
+        fresh.scratch_put("other", vec![3]);
+        fresh.state_import(&persistent).unwrap();
+
+        assert_eq!(fresh.scratch_keys(), vec!["goal", "other", "phase"]);
+        assert_eq!(fresh.scratch_get("phase"), Ok(&[1][..]));
+
+        let mut other = RuntimeState::new();
+        other.set_scratch_persistence(true);
+        other.scratch_put("phase", vec![2]);
+
+        let merged = state_merge(&persistent, &other.state_export()).unwrap();
+
+        let mut fresh = RuntimeState::new();
+        fresh.state_import(&merged).unwrap();
+
+        assert_eq!(fresh.scratch_get("goal"), Ok(&[1, 2][..]));
+        assert_eq!(fresh.scratch_get("phase"), Ok(&[2][..]));
+
+        let mut truncated = persistent.clone();
+        truncated.pop();
+
+        let mut fresh = RuntimeState::new();
+
+        assert_eq!(
+            fresh.state_import(&truncated),
+            Err(StateObjectError::Kernel(ErrorCode::MalformedProofObject))
+        );
+        assert!(fresh.scratch_keys().is_empty());
+    }
+}
@@ -0,0 +1,918 @@
+//! # Registered substitutions
+//!
+//! Prover-space code often applies the same substitution to many terms, or
+//! types, for example when instantiating every theorem of a theory at once.
+//! Rather than supplying, and having the kernel check, the substitution anew
+//! each time, a substitution may be registered once, returning a handle, and
+//! then applied by handle as often as needed.
+//!
+//! Substitutions come in two flavours: substitutions of types for
+//! type-variables, applied to types and to the types within terms, and
+//! substitutions of terms for variables, applied to terms.  A registered
+//! substitution behaves exactly as the same substitution supplied directly to
+//! `type_substitute`, `term_type_substitute`, or `substitution`.
+//!
+//! Elaborators build up a substitution of types for type-variables from many
+//! small ones, one per unification step.  Composing these eagerly, by applying
+//! each to the range of everything composed so far, is quadratic in the number
+//! of steps, so registered substitutions of types for type-variables may
+//! instead be composed lazily.  A composition is kept in *triangular* form, as
+//! the sequence of substitutions that it composes, so composing is
+//! constant-time, and is only resolved when applied.  Resolution finds the
+//! image of each type-variable in the manner of union-find: the next
+//! substitution binding a type-variable is found by binary search, and the
+//! image of every type-variable at every step is computed at most once.  Each
+//! substitution of a composition is applied simultaneously.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    _type::Type,
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    name::Name,
+    runtime_state::{Bindings, RuntimeState},
+    term::Term,
+};
+use log::info;
+use std::{borrow::Borrow, collections::HashMap, fmt::Debug, sync::Arc};
+
+////////////////////////////////////////////////////////////////////////////////
+// Substitutions.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A substitution registered with the kernel.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Substitution {
+    /// A substitution of types for type-variables, pairing the name of each
+    /// type-variable with the type that replaces it.
+    Type(Vec<(Name, Handle<tags::Type>)>),
+    /// A substitution of terms for variables, associating each variable, given
+    /// by its name and type, with the term that replaces its free occurrences.
+    /// Shadowed and trivial bindings are discarded when the substitution is
+    /// registered.
+    Term(Vec<(Name, Handle<tags::Type>, Handle<tags::Term>)>),
+    /// The composition of two substitutions of types for type-variables,
+    /// applying the first and then the second.
+    Composition(Handle<tags::Substitution>, Handle<tags::Substitution>),
+}
+
+/// A composition of substitutions of types for type-variables, resolved into
+/// its triangular form: the sequence of substitutions that it composes, in
+/// the order in which they are applied.
+struct Triangular {
+    /// The bindings of each substitution of the sequence.
+    steps: Vec<HashMap<Name, Handle<tags::Type>>>,
+    /// The steps of the sequence binding each type-variable, in ascending
+    /// order.
+    index: HashMap<Name, Vec<usize>>,
+    /// The image of each type-variable bound at each step, under that step
+    /// and every later one.
+    images: HashMap<(Name, usize), Handle<tags::Type>>,
+}
+
+impl Triangular {
+    /// Returns the first step of the sequence, no earlier than `from`, that
+    /// binds the type-variable `name`, if any.
+    fn binding(&self, name: Name, from: usize) -> Option<usize> {
+        let steps = self.index.get(&name)?;
+
+        steps
+            .get(steps.partition_point(|step| *step < from))
+            .cloned()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Registering substitutions.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Admits a substitution, `sigma`, into the runtime state's table of
+    /// substitutions, returning its handle.  If an equal substitution has
+    /// already been registered then the existing handle is returned.
+    fn admit_substitution(
+        &mut self,
+        sigma: Substitution,
+    ) -> Handle<tags::Substitution> {
+        if let Some(handle) = self.substitutions_index.get(&sigma) {
+            return handle.clone();
+        }
+
+        let sigma = Arc::new(sigma);
+        let fresh = self.issue_handle();
+
+        self.substitutions.insert(fresh.clone(), sigma.clone());
+        self.substitutions_index.insert(sigma, fresh.clone());
+
+        fresh
+    }
+
+    /// Registers the substitution of types for type-variables, `sigma`, with
+    /// the runtime state, returning `Ok(handle)` where `handle` points-to the
+    /// registered substitution.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if any of the handles
+    /// contained in `sigma` do not point to a type in the runtime state's
+    /// type-table.
+    pub fn substitution_register_type<U, V>(
+        &mut self,
+        sigma: Vec<(U, V)>,
+    ) -> Result<Handle<tags::Substitution>, ErrorCode>
+    where
+        U: Into<Name> + Clone + Debug,
+        V: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        info!("Registering type substitution {:?}.", sigma);
+
+        let mut bindings = Vec::with_capacity(sigma.len());
+
+        for (domain, range) in sigma {
+            let range = range.into();
+
+            self.resolve_type_handle(&range)?;
+
+            bindings.push((domain.into(), range));
+        }
+
+        Ok(self.admit_substitution(Substitution::Type(bindings)))
+    }
+
+    /// Registers the substitution of terms for variables, `sigma`, with the
+    /// runtime state, returning `Ok(handle)` where `handle` points-to the
+    /// registered substitution.  As for `substitution`, `sigma` pairs a
+    /// variable, given by its name and type, with the term that replaces its
+    /// free occurrences.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if any term in the range
+    /// of `sigma` does not point-to any term in the runtime state's
+    /// term-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if any type in the domain
+    /// of `sigma` does not point-to any type in the runtime state's
+    /// type-table.
+    ///
+    /// Returns `Err(ErrorCode::DomainTypeMismatch)` if any term in the range of
+    /// `sigma` does not have the type of the variable that it replaces.
+    pub fn substitution_register_term<N, U, V>(
+        &mut self,
+        sigma: Vec<((N, U), V)>,
+    ) -> Result<Handle<tags::Substitution>, ErrorCode>
+    where
+        N: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        info!("Registering term substitution.");
+
+        let bindings = self.substitution_bindings(sigma)?;
+
+        Ok(self.admit_substitution(Substitution::Term(bindings.into_vec())))
+    }
+
+    /// Returns `Ok(sigma)` iff `handle` points-to the registered substitution
+    /// `sigma`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSubstitutionRegistered)` if `handle` does
+    /// not point-to a registered substitution in the runtime state's table of
+    /// substitutions.
+    pub fn substitution_resolve<T>(
+        &self,
+        handle: T,
+    ) -> Result<&Substitution, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Substitution>>,
+    {
+        info!("Resolving substitution with handle {}.", handle.borrow());
+
+        self.substitutions
+            .get(handle.borrow())
+            .map(|sigma| sigma.as_ref())
+            .ok_or_else(|| {
+                self.unresolved(
+                    handle.borrow(),
+                    ErrorCode::NoSuchSubstitutionRegistered,
+                )
+            })
+    }
+
+    /// Returns `Ok(sigma)` iff `handle` points-to a registered substitution of
+    /// types for type-variables, `sigma`.
+    fn resolve_type_substitution<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<(Name, Handle<tags::Type>)>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Substitution>>,
+    {
+        match self.substitution_resolve(handle)? {
+            Substitution::Type(sigma) => Ok(sigma.clone()),
+            Substitution::Term(..) | Substitution::Composition(..) => {
+                Err(ErrorCode::NotATypeSubstitution)
+            }
+        }
+    }
+
+    /// Returns `Ok(true)` iff `handle` points-to a registered composition of
+    /// substitutions.
+    fn substitution_is_composition<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Substitution>>,
+    {
+        Ok(matches!(
+            self.substitution_resolve(handle)?,
+            Substitution::Composition(..)
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Composing substitutions.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Registers the composition of the substitutions of types for
+    /// type-variables pointed-to by `first` and `second`, returning
+    /// `Ok(handle)` where `handle` points-to the registered composition.
+    /// Applying the composition is equivalent to applying `first`, and then
+    /// applying `second` to the result.  Either may itself be a composition.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSubstitutionRegistered)` if either `first`
+    /// or `second` does not point-to a registered substitution.
+    ///
+    /// Returns `Err(ErrorCode::NotATypeSubstitution)` if either `first` or
+    /// `second` points-to a substitution of terms for variables.
+    pub fn substitution_compose<T, U>(
+        &mut self,
+        first: T,
+        second: U,
+    ) -> Result<Handle<tags::Substitution>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Substitution>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        info!(
+            "Composing substitutions {} and {}.",
+            first.borrow(),
+            second.borrow()
+        );
+
+        for sigma in [first.borrow(), second.borrow()] {
+            if let Substitution::Term(_bindings) =
+                self.substitution_resolve(sigma)?
+            {
+                return Err(ErrorCode::NotATypeSubstitution);
+            }
+        }
+
+        Ok(self.admit_substitution(Substitution::Composition(
+            first.borrow().clone(),
+            second.borrow().clone(),
+        )))
+    }
+
+    /// Resolves the composition pointed-to by `handle` into its triangular
+    /// form.
+    ///
+    /// Will **panic** if `handle`, or any substitution that it composes, does
+    /// not point-to a registered substitution.
+    fn substitution_triangular(
+        &self,
+        handle: &Handle<tags::Substitution>,
+    ) -> Triangular {
+        let mut steps = Vec::new();
+        let mut index: HashMap<Name, Vec<usize>> = HashMap::new();
+        let mut work = vec![handle];
+
+        while let Some(handle) = work.pop() {
+            match self
+                .substitution_resolve(handle)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                Substitution::Type(bindings) => {
+                    let mut step = HashMap::new();
+
+                    /* NB: as for `type_substitute`, the first binding of a
+                     * type-variable takes precedence.
+                     */
+                    for (name, tau) in bindings {
+                        step.entry(*name).or_insert_with(|| tau.clone());
+                    }
+
+                    for name in step.keys() {
+                        index.entry(*name).or_default().push(steps.len());
+                    }
+
+                    steps.push(step);
+                }
+                Substitution::Composition(first, second) => {
+                    work.push(second);
+                    work.push(first);
+                }
+                Substitution::Term(_bindings) => {
+                    unreachable!("only type substitutions are composed")
+                }
+            }
+        }
+
+        Triangular {
+            steps,
+            index,
+            images: HashMap::new(),
+        }
+    }
+
+    /// Returns the image of the type-variable `name` under the steps of
+    /// `triangular` from `from` onwards, or `None` if no such step binds it.
+    /// Images are computed with an explicit work-list, rather than by
+    /// recursion, as sequences of steps may be very long.
+    fn triangular_image(
+        &mut self,
+        triangular: &mut Triangular,
+        name: Name,
+        from: usize,
+    ) -> Option<Handle<tags::Type>> {
+        let root = (name, triangular.binding(name, from)?);
+        let mut work = vec![root];
+
+        while let Some((name, step)) = work.last().cloned() {
+            if triangular.images.contains_key(&(name, step)) {
+                work.pop();
+                continue;
+            }
+
+            let range = triangular.steps[step][&name].clone();
+            let mut images = HashMap::new();
+            let mut pending = false;
+
+            for v in self.type_variables(&range).expect(DANGLING_HANDLE_ERROR) {
+                if let Some(later) = triangular.binding(*v, step + 1) {
+                    match triangular.images.get(&(*v, later)) {
+                        Some(image) => {
+                            images.insert(*v, image.clone());
+                        }
+                        None => {
+                            work.push((*v, later));
+                            pending = true;
+                        }
+                    }
+                }
+            }
+
+            if pending {
+                continue;
+            }
+
+            work.pop();
+
+            let image = self.type_instantiate(&range, &images);
+
+            triangular.images.insert((name, step), image);
+        }
+
+        triangular.images.get(&root).cloned()
+    }
+
+    /// Returns the image of each type-variable in `variables` under the
+    /// composition pointed-to by `handle`, omitting those that it leaves
+    /// unchanged.
+    fn composition_images(
+        &mut self,
+        handle: &Handle<tags::Substitution>,
+        variables: Vec<Name>,
+    ) -> HashMap<Name, Handle<tags::Type>> {
+        let mut triangular = self.substitution_triangular(handle);
+        let mut images = HashMap::new();
+
+        for v in variables {
+            if let Some(image) = self.triangular_image(&mut triangular, v, 0) {
+                images.insert(v, image);
+            }
+        }
+
+        images
+    }
+
+    /// Simultaneously replaces each type-variable in the type pointed-to by
+    /// `tau` with its image in `images`, if any.
+    ///
+    /// Will **panic** if `tau` dangles.
+    fn type_instantiate(
+        &mut self,
+        tau: &Handle<tags::Type>,
+        images: &HashMap<Name, Handle<tags::Type>>,
+    ) -> Handle<tags::Type> {
+        if images.is_empty() {
+            return tau.clone();
+        }
+
+        match self
+            .resolve_type_handle(tau)
+            .expect(DANGLING_HANDLE_ERROR)
+            .clone()
+        {
+            Type::Variable { name } => {
+                images.get(&name).cloned().unwrap_or_else(|| tau.clone())
+            }
+            Type::Combination { former, arguments } => {
+                let instantiated: Vec<Handle<tags::Type>> = arguments
+                    .iter()
+                    .map(|argument| self.type_instantiate(argument, images))
+                    .collect();
+
+                if instantiated == arguments {
+                    tau.clone()
+                } else {
+                    self.admit_type(Type::combination(former, instantiated))
+                }
+            }
+        }
+    }
+
+    /// Simultaneously replaces each type-variable in the types within the term
+    /// pointed-to by `handle` with its image in `images`, if any.
+    ///
+    /// Will **panic** if `handle` dangles.
+    fn term_type_instantiate(
+        &mut self,
+        handle: &Handle<tags::Term>,
+        images: &HashMap<Name, Handle<tags::Type>>,
+        memo: &mut HashMap<Handle<tags::Term>, Handle<tags::Term>>,
+    ) -> Handle<tags::Term> {
+        if images.is_empty() {
+            return handle.clone();
+        }
+
+        if let Some(result) = memo.get(handle) {
+            return result.clone();
+        }
+
+        let result = match self
+            .resolve_term_handle(handle)
+            .expect(DANGLING_HANDLE_ERROR)
+            .clone()
+        {
+            Term::Variable { name, tau } => {
+                let tau = self.type_instantiate(&tau, images);
+                self.admit_term(Term::variable(name, tau))
+            }
+            Term::Constant { constant, tau } => {
+                let tau = self.type_instantiate(&tau, images);
+                self.admit_term(Term::constant(constant, tau))
+            }
+            Term::Application { left, right } => {
+                let left = self.term_type_instantiate(&left, images, memo);
+                let right = self.term_type_instantiate(&right, images, memo);
+                self.admit_term(Term::application(left, right))
+            }
+            Term::Lambda { name, tau, body } => {
+                let tau = self.type_instantiate(&tau, images);
+                let body = self.term_type_instantiate(&body, images, memo);
+                self.admit_term(Term::lambda(name, tau, body))
+            }
+        };
+
+        memo.insert(handle.clone(), result.clone());
+
+        result
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Applying substitutions.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Instantiates the type pointed-to by `tau` with the registered
+    /// substitution pointed-to by `sigma`, as `type_substitute`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSubstitutionRegistered)` if `sigma` does
+    /// not point-to a registered substitution.
+    ///
+    /// Returns `Err(ErrorCode::NotATypeSubstitution)` if `sigma` points-to a
+    /// substitution of terms for variables.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not point
+    /// to a type in the runtime state's type-table.
+    pub fn type_substitute_with<T, U>(
+        &mut self,
+        tau: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Type>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        info!("Substituting {} in type {}.", sigma.borrow(), tau.borrow());
+
+        if self.substitution_is_composition(sigma.borrow())? {
+            let variables = self
+                .type_variables(tau.borrow())?
+                .into_iter()
+                .cloned()
+                .collect();
+            let images = self.composition_images(sigma.borrow(), variables);
+
+            return Ok(self.type_instantiate(tau.borrow(), &images));
+        }
+
+        let sigma = self.resolve_type_substitution(sigma)?;
+
+        self.type_substitute(tau, sigma)
+    }
+
+    /// Instantiates the types within the term pointed-to by `handle` with the
+    /// registered substitution pointed-to by `sigma`, as
+    /// `term_type_substitute`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSubstitutionRegistered)` if `sigma` does
+    /// not point-to a registered substitution.
+    ///
+    /// Returns `Err(ErrorCode::NotATypeSubstitution)` if `sigma` points-to a
+    /// substitution of terms for variables.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a term in the runtime state's term-table.
+    pub fn term_type_substitute_with<T, U>(
+        &mut self,
+        handle: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        let handle = handle.into();
+
+        info!(
+            "Substituting {} in types of term with handle {}.",
+            sigma.borrow(),
+            handle
+        );
+
+        if self.substitution_is_composition(sigma.borrow())? {
+            let variables = self
+                .term_type_variables(&handle)?
+                .into_iter()
+                .cloned()
+                .collect();
+            let images = self.composition_images(sigma.borrow(), variables);
+
+            return Ok(self.term_type_instantiate(
+                &handle,
+                &images,
+                &mut HashMap::new(),
+            ));
+        }
+
+        let sigma = self.resolve_type_substitution(sigma)?;
+
+        self.term_type_substitute(handle, sigma)
+    }
+
+    /// Substitutes terms for variables in the term pointed-to by `handle` with
+    /// the registered substitution pointed-to by `sigma`, as `substitution`.
+    /// The substitution was checked when registered, so is not checked again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchSubstitutionRegistered)` if `sigma` does
+    /// not point-to a registered substitution.
+    ///
+    /// Returns `Err(ErrorCode::NotATermSubstitution)` if `sigma` points-to a
+    /// substitution of types for type-variables.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to a term in the runtime state's term-table.
+    pub fn substitution_with<T, U>(
+        &mut self,
+        handle: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Term>, ErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        let handle = handle.into();
+
+        info!(
+            "Substituting {} in term with handle {}.",
+            sigma.borrow(),
+            handle
+        );
+
+        let bindings = match self.substitution_resolve(sigma)? {
+            Substitution::Term(bindings) => Bindings::from(bindings.as_slice()),
+            Substitution::Type(..) | Substitution::Composition(..) => {
+                return Err(ErrorCode::NotATermSubstitution)
+            }
+        };
+
+        self.resolve_term_handle(&handle)?;
+
+        Ok(self.substitution_inner(handle, bindings))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, Kind, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that registering equal substitutions returns the same handle, and
+    /// that only registered substitutions are enumerated.
+    #[test]
+    pub fn substitution_register0() {
+        let mut state = RuntimeState::new();
+
+        let sigma = state
+            .substitution_register_type(vec![(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )])
+            .unwrap();
+        let tau = state
+            .substitution_register_type(vec![(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )])
+            .unwrap();
+
+        assert_eq!(sigma, tau);
+        assert_eq!(
+            state.kernel_enumerate(Kind::Substitution, 0, 10).0,
+            vec![*sigma]
+        );
+        assert_eq!(
+            state.substitution_register_type(vec![(
+                0u64,
+                Handle::<tags::Type>::from(usize::MAX)
+            )]),
+            Err(ErrorCode::NoSuchTypeRegistered)
+        );
+        assert_eq!(
+            state.substitution_resolve(Handle::<tags::Substitution>::from(
+                usize::MAX
+            )),
+            Err(ErrorCode::NoSuchSubstitutionRegistered)
+        );
+    }
+
+    /// Tests that applying a registered substitution agrees with applying the
+    /// same substitution directly.
+    #[test]
+    pub fn substitution_register1() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+        let pair = state.term_register_pair(x.clone(), y.clone()).unwrap();
+
+        let types = vec![(0u64, PREALLOCATED_HANDLE_TYPE_PROP)];
+        let sigma = state.substitution_register_type(types.clone()).unwrap();
+
+        assert_eq!(
+            state.type_substitute_with(PREALLOCATED_HANDLE_TYPE_ALPHA, &sigma),
+            state
+                .type_substitute(PREALLOCATED_HANDLE_TYPE_ALPHA, types.clone())
+        );
+        assert_eq!(
+            state.term_type_substitute_with(pair.clone(), &sigma),
+            state.term_type_substitute(pair.clone(), types)
+        );
+
+        let terms = vec![((0u64, PREALLOCATED_HANDLE_TYPE_ALPHA), x.clone())];
+        let trivial = state.substitution_register_term(terms).unwrap();
+
+        assert_eq!(state.substitution_with(pair.clone(), &trivial), Ok(pair));
+    }
+
+    /// Tests that each flavour of registered substitution is rejected where
+    /// the other is expected, and that ill-typed substitutions of terms cannot
+    /// be registered.
+    #[test]
+    pub fn substitution_register2() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+        let y = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_BETA)
+            .unwrap();
+
+        let types = state
+            .substitution_register_type(vec![(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )])
+            .unwrap();
+        let terms = state
+            .substitution_register_term(vec![(
+                (0u64, PREALLOCATED_HANDLE_TYPE_ALPHA),
+                x.clone(),
+            )])
+            .unwrap();
+
+        assert_ne!(types, terms);
+        assert_eq!(
+            state.substitution_with(x.clone(), &types),
+            Err(ErrorCode::NotATermSubstitution)
+        );
+        assert_eq!(
+            state.term_type_substitute_with(x.clone(), &terms),
+            Err(ErrorCode::NotATypeSubstitution)
+        );
+        assert_eq!(
+            state.substitution_register_term(vec![(
+                (0u64, PREALLOCATED_HANDLE_TYPE_ALPHA),
+                y
+            )]),
+            Err(ErrorCode::DomainTypeMismatch)
+        );
+    }
+
+    /// Tests that substitutions registered within a discarded speculative
+    /// branch are discarded with it.
+    #[test]
+    pub fn substitution_register3() {
+        let mut state = RuntimeState::new();
+
+        state.speculate_begin();
+
+        let sigma = state
+            .substitution_register_type(vec![(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )])
+            .unwrap();
+
+        state.speculate_end(false).unwrap();
+
+        assert_eq!(
+            state.substitution_resolve(&sigma),
+            Err(ErrorCode::StaleHandle)
+        );
+        assert!(state
+            .kernel_enumerate(Kind::Substitution, 0, 10)
+            .0
+            .is_empty());
+
+        let tau = state
+            .substitution_register_type(vec![(
+                0u64,
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )])
+            .unwrap();
+
+        assert_ne!(sigma, tau);
+        assert!(state.substitution_resolve(&tau).is_ok());
+    }
+
+    /// Tests that applying a composition agrees with applying the
+    /// substitutions that it composes in turn, and that only substitutions of
+    /// types for type-variables may be composed.
+    #[test]
+    pub fn substitution_compose0() {
+        let mut state = RuntimeState::new();
+
+        let a = state.type_register_variable(10u64);
+        let b = state.type_register_variable(11u64);
+        let c = state.type_register_variable(12u64);
+        let ab = state.type_register_function(a.clone(), b.clone()).unwrap();
+        let cc = state.type_register_function(c.clone(), c.clone()).unwrap();
+        let aa = state.type_register_function(a.clone(), a.clone()).unwrap();
+
+        let first = state
+            .substitution_register_type(vec![(10u64, b.clone())])
+            .unwrap();
+        let second = state
+            .substitution_register_type(vec![(11u64, c.clone())])
+            .unwrap();
+        let back = state
+            .substitution_register_type(vec![(11u64, a.clone())])
+            .unwrap();
+
+        let sigma = state.substitution_compose(&first, &second).unwrap();
+        let swap = state.substitution_compose(&first, &back).unwrap();
+
+        assert_eq!(state.type_substitute_with(&a, &sigma), Ok(c.clone()));
+        assert_eq!(state.type_substitute_with(&ab, &sigma), Ok(cc));
+        assert_eq!(state.type_substitute_with(&ab, &swap), Ok(aa));
+        assert_eq!(
+            state.substitution_compose(&first, &second),
+            Ok(sigma.clone())
+        );
+
+        let x = state.term_register_variable(0u64, a).unwrap();
+        let y = state.term_register_variable(0u64, c).unwrap();
+
+        assert_eq!(state.term_type_substitute_with(x.clone(), &sigma), Ok(y));
+
+        let terms = state
+            .substitution_register_term(vec![(
+                (1u64, PREALLOCATED_HANDLE_TYPE_PROP),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )])
+            .unwrap();
+
+        assert_eq!(
+            state.substitution_compose(&sigma, &terms),
+            Err(ErrorCode::NotATypeSubstitution)
+        );
+        assert_eq!(
+            state.substitution_with(x, &sigma),
+            Err(ErrorCode::NotATermSubstitution)
+        );
+        assert_eq!(
+            state.substitution_compose(
+                &sigma,
+                Handle::<tags::Substitution>::from(usize::MAX)
+            ),
+            Err(ErrorCode::NoSuchSubstitutionRegistered)
+        );
+    }
+
+    /// Tests that long compositions, however they are bracketed, agree with
+    /// applying the substitutions that they compose in turn.
+    #[test]
+    pub fn substitution_compose1() {
+        let mut state = RuntimeState::new();
+
+        let steps: Vec<_> = (0u64..64)
+            .map(|i| {
+                let next = state.type_register_variable(i + 1);
+                let range = state
+                    .type_register_function(next, PREALLOCATED_HANDLE_TYPE_PROP)
+                    .unwrap();
+
+                state.substitution_register_type(vec![(i, range)]).unwrap()
+            })
+            .collect();
+
+        let root = state.type_register_variable(0u64);
+        let mut expected = root.clone();
+
+        for step in steps.iter() {
+            expected = state.type_substitute_with(&expected, step).unwrap();
+        }
+
+        let mut left = steps[0].clone();
+        let mut right = steps[63].clone();
+
+        for i in 1..64 {
+            left = state.substitution_compose(&left, &steps[i]).unwrap();
+            right = state.substitution_compose(&steps[63 - i], &right).unwrap();
+        }
+
+        assert_eq!(
+            state.type_substitute_with(&root, &left),
+            Ok(expected.clone())
+        );
+        assert_eq!(state.type_substitute_with(&root, &right), Ok(expected));
+    }
+}
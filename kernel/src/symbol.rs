@@ -0,0 +1,216 @@
+//! # The symbol table
+//!
+//! Kernel objects are named by their handles, which are issued afresh in every
+//! session, so separately compiled prover-space libraries have no way of
+//! agreeing on which constant is which from handles alone.  The kernel
+//! therefore also keeps a *symbol table*, associating UTF-8 names with
+//! constants and type-formers, so that a library may register a constant
+//! under a well-known name, and another may look it up by that name later.
+//!
+//! A constant is named when it is registered, with
+//! `RuntimeState::constant_register_named`, and names are unique: a name may
+//! only ever be associated with one constant.  A type-former is named by the
+//! name in the metadata that it is registered with, and a name registered with
+//! more than one type-former resolves to the first of them.  Like other
+//! metadata, names play no part in the logic, but names survive snapshots,
+//! along with the objects that they name.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, collections::HashMap};
+
+////////////////////////////////////////////////////////////////////////////////
+// Named constants.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Registers a new constant, with a type pointed-to by `tau`, as
+    /// `constant_register`, entering it into the kernel's symbol table under
+    /// `name`.  Returns the handle to the newly-registered constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeRegistered)` if `tau` does not
+    /// point-to a registered type in the runtime state's type-table.
+    ///
+    /// Returns `Err(ErrorCode::NameAlreadyRegistered)` if a constant has
+    /// already been registered under `name`, in which case no constant is
+    /// registered.
+    pub fn constant_register_named<T, U>(
+        &mut self,
+        tau: T,
+        name: U,
+    ) -> Result<Handle<tags::Constant>, ErrorCode>
+    where
+        T: Into<Handle<tags::Type>> + Clone,
+        U: Into<String>,
+    {
+        let name = name.into();
+
+        info!("Registering constant with name: {}.", name);
+
+        if self.constant_names.contains_key(&name) {
+            return Err(ErrorCode::NameAlreadyRegistered);
+        }
+
+        let handle = self.constant_register(tau)?;
+        self.constant_names.insert(name, handle.clone());
+
+        Ok(handle)
+    }
+
+    /// Returns `Ok(handle)` iff `handle` points-to the constant registered
+    /// under `name` in the kernel's symbol table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchNameRegistered)` if no constant is
+    /// registered under `name`.
+    pub fn constant_lookup_by_name<T>(
+        &self,
+        name: T,
+    ) -> Result<Handle<tags::Constant>, ErrorCode>
+    where
+        T: AsRef<str>,
+    {
+        info!("Looking up constant with name: {}.", name.as_ref());
+
+        self.constant_names
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(ErrorCode::NoSuchNameRegistered)
+    }
+
+    /// Returns the names of every named constant, keyed by the handle of the
+    /// constant, for writing alongside the constants in a snapshot.
+    pub(crate) fn constant_names_by_handle(
+        &self,
+    ) -> HashMap<Handle<tags::Constant>, &str> {
+        self.constant_names
+            .iter()
+            .map(|(name, handle)| (handle.clone(), name.as_str()))
+            .collect()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Named type-formers.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `Ok(handle)` iff `handle` points-to the first type-former
+    /// registered with `name` in its metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchNameRegistered)` if no type-former is
+    /// registered with `name`.
+    pub fn type_former_lookup_by_name<T>(
+        &self,
+        name: T,
+    ) -> Result<Handle<tags::TypeFormer>, ErrorCode>
+    where
+        T: AsRef<str>,
+    {
+        info!("Looking up type-former with name: {}.", name.as_ref());
+
+        self.type_former_names
+            .get(name.as_ref())
+            .cloned()
+            .ok_or(ErrorCode::NoSuchNameRegistered)
+    }
+
+    /// Enters the type-former pointed-to by `handle` into the kernel's symbol
+    /// table under `name`, unless `name` is empty, or another type-former has
+    /// already been entered under it.
+    pub(crate) fn type_former_name<T>(
+        &mut self,
+        handle: &Handle<tags::TypeFormer>,
+        name: T,
+    ) where
+        T: Borrow<str>,
+    {
+        if !name.borrow().is_empty() {
+            self.type_former_names
+                .entry(name.borrow().to_string())
+                .or_insert_with(|| handle.clone());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for the symbol table.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode, handle::PREALLOCATED_HANDLE_TYPE_PROP,
+        metadata::TypeFormerMetadata, runtime_state::RuntimeState,
+    };
+
+    /// Tests that named constants may be looked up by name, and that names of
+    /// constants are unique.
+    #[test]
+    pub fn symbol0() {
+        let mut state = RuntimeState::new();
+
+        let c = state
+            .constant_register_named(PREALLOCATED_HANDLE_TYPE_PROP, "c")
+            .unwrap();
+
+        assert_eq!(state.constant_lookup_by_name("c"), Ok(c));
+        assert_eq!(
+            state.constant_register_named(PREALLOCATED_HANDLE_TYPE_PROP, "c"),
+            Err(ErrorCode::NameAlreadyRegistered)
+        );
+        assert_eq!(
+            state.constant_lookup_by_name("d"),
+            Err(ErrorCode::NoSuchNameRegistered)
+        );
+    }
+
+    /// Tests that a type-former is named by its metadata, and that a name
+    /// shared by many type-formers resolves to the first of them.
+    #[test]
+    pub fn symbol1() {
+        let mut state = RuntimeState::new();
+
+        let list = state.type_former_register_with_metadata(
+            1usize,
+            TypeFormerMetadata::new("list", "Finite lists."),
+        );
+        state.type_former_register_with_metadata(
+            1usize,
+            TypeFormerMetadata::new("list", "Lists, again."),
+        );
+        state.type_former_register_with_metadata(
+            0usize,
+            TypeFormerMetadata::new("", "Anonymous."),
+        );
+
+        assert_eq!(state.type_former_lookup_by_name("list"), Ok(list));
+        assert_eq!(
+            state.type_former_lookup_by_name(""),
+            Err(ErrorCode::NoSuchNameRegistered)
+        );
+    }
+}
@@ -3,7 +3,7 @@
 //! HOL's terms are the terms of the explicitly-typed λ-calculus, extended with
 //! constants.  The grammar is recursively-defined, as follows:
 //!
-//! ```
+//! ```text
 //!     r,s,t ::= x:τ | C:τ | rs | λx:τ. r
 //! ```
 //!
@@ -60,19 +60,35 @@
 
 use crate::{
     handle::{
-        tags, Handle, PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
+        tags, Handle, PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+        PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+        PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION,
         PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION,
+        PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
         PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
         PREALLOCATED_HANDLE_CONSTANT_EXISTS,
-        PREALLOCATED_HANDLE_CONSTANT_FALSE,
+        PREALLOCATED_HANDLE_CONSTANT_FALSE, PREALLOCATED_HANDLE_CONSTANT_FIRST,
         PREALLOCATED_HANDLE_CONSTANT_FORALL,
         PREALLOCATED_HANDLE_CONSTANT_IMPLICATION,
+        PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+        PREALLOCATED_HANDLE_CONSTANT_LET,
+        PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
         PREALLOCATED_HANDLE_CONSTANT_NEGATION,
-        PREALLOCATED_HANDLE_CONSTANT_TRUE,
+        PREALLOCATED_HANDLE_CONSTANT_PAIR, PREALLOCATED_HANDLE_CONSTANT_SECOND,
+        PREALLOCATED_HANDLE_CONSTANT_SUBSET, PREALLOCATED_HANDLE_CONSTANT_TRUE,
+        PREALLOCATED_HANDLE_CONSTANT_UNION,
+        PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
         PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE,
         PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
-        PREALLOCATED_HANDLE_TYPE_PROP, PREALLOCATED_HANDLE_TYPE_QUANTIFIER,
+        PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+        PREALLOCATED_HANDLE_TYPE_CONDITIONAL, PREALLOCATED_HANDLE_TYPE_FIRST,
+        PREALLOCATED_HANDLE_TYPE_LET, PREALLOCATED_HANDLE_TYPE_MEMBERSHIP,
+        PREALLOCATED_HANDLE_TYPE_PAIR, PREALLOCATED_HANDLE_TYPE_PROP,
+        PREALLOCATED_HANDLE_TYPE_QUANTIFIER, PREALLOCATED_HANDLE_TYPE_SECOND,
+        PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+        PREALLOCATED_HANDLE_TYPE_SET_RELATION,
         PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE,
+        PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
     },
     name::Name,
 };
@@ -83,7 +99,7 @@ use crate::{
 
 /// HOL terms.  These are either variables, constants, applications, or
 /// λ-abstractions.
-#[derive(Clone, Debug, Hash)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Term {
     /// Variables of the simply-typed lambda-calculus.  All variables are
     /// explicitly typed.  Not that two variables are equal when their names and
@@ -340,3 +356,75 @@ pub const TERM_EQUALITY_CONSTANT: Term = Term::Constant {
     constant: PREALLOCATED_HANDLE_CONSTANT_EQUALITY,
     tau: PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE,
 };
+
+/// The conditional constant, at polymorphic type, lifted into a term.
+pub const TERM_CONDITIONAL_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_CONDITIONAL,
+    tau: PREALLOCATED_HANDLE_TYPE_CONDITIONAL,
+};
+
+/// The local definition constant, at polymorphic type, lifted into a term.
+pub const TERM_LET_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_LET,
+    tau: PREALLOCATED_HANDLE_TYPE_LET,
+};
+
+/// The pairing constant, at polymorphic type, lifted into a term.
+pub const TERM_PAIR_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_PAIR,
+    tau: PREALLOCATED_HANDLE_TYPE_PAIR,
+};
+
+/// The first projection constant, at polymorphic type, lifted into a term.
+pub const TERM_FIRST_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_FIRST,
+    tau: PREALLOCATED_HANDLE_TYPE_FIRST,
+};
+
+/// The second projection constant, at polymorphic type, lifted into a term.
+pub const TERM_SECOND_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_SECOND,
+    tau: PREALLOCATED_HANDLE_TYPE_SECOND,
+};
+
+/// The set membership constant, at polymorphic type, lifted into a term.
+pub const TERM_MEMBERSHIP_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_MEMBERSHIP,
+    tau: PREALLOCATED_HANDLE_TYPE_MEMBERSHIP,
+};
+
+/// The set comprehension constant, at polymorphic type, lifted into a term.
+pub const TERM_COMPREHENSION_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_COMPREHENSION,
+    tau: PREALLOCATED_HANDLE_TYPE_SET_OPERATOR,
+};
+
+/// The empty set constant, at polymorphic type, lifted into a term.
+pub const TERM_EMPTY_SET_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_EMPTY_SET,
+    tau: PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+};
+
+/// The universal set constant, at polymorphic type, lifted into a term.
+pub const TERM_UNIVERSAL_SET_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_UNIVERSAL_SET,
+    tau: PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE,
+};
+
+/// The set union constant, at polymorphic type, lifted into a term.
+pub const TERM_UNION_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_UNION,
+    tau: PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+};
+
+/// The set intersection constant, at polymorphic type, lifted into a term.
+pub const TERM_INTERSECTION_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_INTERSECTION,
+    tau: PREALLOCATED_HANDLE_TYPE_BINARY_SET_OPERATOR,
+};
+
+/// The subset constant, at polymorphic type, lifted into a term.
+pub const TERM_SUBSET_CONSTANT: Term = Term::Constant {
+    constant: PREALLOCATED_HANDLE_CONSTANT_SUBSET,
+    tau: PREALLOCATED_HANDLE_TYPE_SET_RELATION,
+};
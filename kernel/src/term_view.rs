@@ -0,0 +1,287 @@
+//! # Read-only views of terms
+//!
+//! A term view is a self-contained, serialized copy of a term's DAG, laid out
+//! so that prover-space code can traverse the term in its own memory, without
+//! making a host call per node.  The view lists each distinct subterm exactly
+//! once, in postorder, so that the children of every node precede it and the
+//! term itself is the last node.  Views are copies: the kernel never reads a
+//! view back, and modifying one has no effect on the kernel's state.
+//!
+//! A view is a sequence of little-endian 64-bit words.  The first is the
+//! number of nodes, `n`, and it is followed by `n` nodes of
+//! `TERM_VIEW_NODE_WORDS` words each: a tag, the handle of the subterm, and
+//! three fields whose meaning depends on the tag:
+//!
+//! - `TERM_VIEW_TAG_VARIABLE`: the variable's name, and a handle to its type,
+//! - `TERM_VIEW_TAG_CONSTANT`: a handle to the constant, and a handle to its
+//!   type,
+//! - `TERM_VIEW_TAG_APPLICATION`: the indices, within the view, of the nodes
+//!   of the function and of its argument,
+//! - `TERM_VIEW_TAG_LAMBDA`: the name of the bound variable, a handle to its
+//!   type, and the index, within the view, of the node of the body.
+//!
+//! Unused fields are zero.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    runtime_state::RuntimeState,
+    term::Term,
+};
+use log::info;
+use std::{borrow::Borrow, collections::HashMap, convert::TryInto};
+
+////////////////////////////////////////////////////////////////////////////////
+// Layout.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tag of a node describing a variable.
+pub const TERM_VIEW_TAG_VARIABLE: u64 = 0;
+/// The tag of a node describing a constant.
+pub const TERM_VIEW_TAG_CONSTANT: u64 = 1;
+/// The tag of a node describing an application.
+pub const TERM_VIEW_TAG_APPLICATION: u64 = 2;
+/// The tag of a node describing a lambda-abstraction.
+pub const TERM_VIEW_TAG_LAMBDA: u64 = 3;
+
+/// The number of 64-bit words making up each node of a view.
+pub const TERM_VIEW_NODE_WORDS: usize = 5;
+
+/// A node of a decoded term view.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TermViewNode {
+    /// One of the `TERM_VIEW_TAG_*` tags.
+    pub tag: u64,
+    /// The handle of the subterm described by the node.
+    pub handle: u64,
+    /// The three fields of the node, as described by its tag.
+    pub fields: [u64; 3],
+}
+
+/// Decodes the term view `bytes` into its nodes, returning `None` if `bytes`
+/// is truncated, has trailing bytes, or refers to a node that does not
+/// precede the node referring to it.
+pub fn term_view_decode(bytes: &[u8]) -> Option<Vec<TermViewNode>> {
+    let words: Vec<u64> = bytes
+        .chunks(8)
+        .map(|w| w.try_into().ok().map(u64::from_le_bytes))
+        .collect::<Option<_>>()?;
+
+    let (count, body) = words.split_first()?;
+
+    if body.len() as u64 != count.checked_mul(TERM_VIEW_NODE_WORDS as u64)? {
+        return None;
+    }
+
+    let mut nodes = Vec::with_capacity(body.len() / TERM_VIEW_NODE_WORDS);
+
+    for (index, node) in body.chunks(TERM_VIEW_NODE_WORDS).enumerate() {
+        let fields = [node[2], node[3], node[4]];
+
+        let children: &[u64] = match node[0] {
+            TERM_VIEW_TAG_VARIABLE | TERM_VIEW_TAG_CONSTANT => &[],
+            TERM_VIEW_TAG_APPLICATION => &fields[0..2],
+            TERM_VIEW_TAG_LAMBDA => &fields[2..3],
+            _otherwise => return None,
+        };
+
+        if children.iter().any(|c| *c >= index as u64) {
+            return None;
+        }
+
+        nodes.push(TermViewNode {
+            tag: node[0],
+            handle: node[1],
+            fields,
+        });
+    }
+
+    Some(nodes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Producing views.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns a read-only view of the term pointed-to by `handle` in the
+    /// runtime state's term-table, laid out as described in the module
+    /// documentation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `handle` does not
+    /// point-to any term in the runtime state's term-table.
+    pub fn term_view<T>(&self, handle: T) -> Result<Vec<u8>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!("Producing view of term with handle: {}.", handle.borrow());
+
+        let order = self.term_postorder(handle)?;
+        let mut indices: HashMap<&Handle<tags::Term>, u64> = HashMap::new();
+
+        let mut words =
+            Vec::with_capacity(1 + order.len() * TERM_VIEW_NODE_WORDS);
+        words.push(order.len() as u64);
+
+        for (index, node) in order.iter().enumerate() {
+            let (tag, fields) = match self
+                .resolve_term_handle(node)
+                .expect(DANGLING_HANDLE_ERROR)
+            {
+                Term::Variable { name, tau } => {
+                    (TERM_VIEW_TAG_VARIABLE, [*name, **tau as u64, 0])
+                }
+                Term::Constant { constant, tau } => (
+                    TERM_VIEW_TAG_CONSTANT,
+                    [**constant as u64, **tau as u64, 0],
+                ),
+                Term::Application { left, right } => (
+                    TERM_VIEW_TAG_APPLICATION,
+                    [indices[left], indices[right], 0],
+                ),
+                Term::Lambda { name, tau, body } => {
+                    (TERM_VIEW_TAG_LAMBDA, [*name, **tau as u64, indices[body]])
+                }
+            };
+
+            words.push(tag);
+            words.push(**node as u64);
+            words.extend_from_slice(&fields);
+
+            indices.insert(node, index as u64);
+        }
+
+        Ok(words.iter().flat_map(|w| w.to_le_bytes()).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            Handle, PREALLOCATED_HANDLE_TERM_CONJUNCTION,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        name::canonical_name,
+        runtime_state::RuntimeState,
+        term_view::{
+            term_view_decode, TERM_VIEW_TAG_APPLICATION,
+            TERM_VIEW_TAG_CONSTANT, TERM_VIEW_TAG_LAMBDA,
+            TERM_VIEW_TAG_VARIABLE,
+        },
+    };
+
+    /// Tests that the view of `λx. x ∧ x` lists the shared variable once, and
+    /// that every node refers to the nodes of its subterms by index.
+    #[test]
+    pub fn term_view_test0() {
+        let mut state = RuntimeState::new();
+
+        /* NB: the bound variable is given its canonical name, so that the
+         * body is stored as registered.
+         */
+        let x = state
+            .term_register_variable(
+                canonical_name(0),
+                PREALLOCATED_HANDLE_TYPE_PROP,
+            )
+            .unwrap();
+        let conjunction = state
+            .term_register_conjunction(x.clone(), x.clone())
+            .unwrap();
+        let lambda = state
+            .term_register_lambda(
+                canonical_name(0),
+                PREALLOCATED_HANDLE_TYPE_PROP,
+                conjunction.clone(),
+            )
+            .unwrap();
+
+        let nodes = term_view_decode(&state.term_view(&lambda).unwrap())
+            .expect("a well-formed view");
+
+        assert_eq!(nodes.len(), 5);
+
+        let index = |h: &Handle<_>| {
+            nodes.iter().position(|n| n.handle == **h as u64).unwrap() as u64
+        };
+
+        let root = nodes.last().unwrap();
+
+        assert_eq!(root.tag, TERM_VIEW_TAG_LAMBDA);
+        assert_eq!(root.handle, *lambda as u64);
+        assert_eq!(root.fields[0], canonical_name(0));
+        assert_eq!(root.fields[1], *PREALLOCATED_HANDLE_TYPE_PROP as u64);
+        assert_eq!(root.fields[2], index(&conjunction));
+
+        let variable = &nodes[index(&x) as usize];
+
+        assert_eq!(variable.tag, TERM_VIEW_TAG_VARIABLE);
+        assert_eq!(
+            variable.fields,
+            [canonical_name(0), *PREALLOCATED_HANDLE_TYPE_PROP as u64, 0]
+        );
+
+        let connective =
+            &nodes[index(&PREALLOCATED_HANDLE_TERM_CONJUNCTION) as usize];
+
+        assert_eq!(connective.tag, TERM_VIEW_TAG_CONSTANT);
+
+        let body = &nodes[index(&conjunction) as usize];
+
+        assert_eq!(body.tag, TERM_VIEW_TAG_APPLICATION);
+        assert_eq!(body.fields[1], index(&x));
+    }
+
+    /// Tests that views of unregistered terms are rejected, and that malformed
+    /// views are not decoded.
+    #[test]
+    pub fn term_view_test1() {
+        let state = RuntimeState::new();
+
+        assert_eq!(
+            state.term_view(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTermRegistered)
+        );
+
+        let view = state
+            .term_view(PREALLOCATED_HANDLE_TERM_CONJUNCTION)
+            .unwrap();
+
+        assert!(term_view_decode(&view).is_some());
+        assert!(term_view_decode(&view[..view.len() - 8]).is_none());
+        assert!(term_view_decode(&view[..view.len() - 1]).is_none());
+
+        /* An application node referring to itself. */
+        let mut cyclic = 1u64.to_le_bytes().to_vec();
+
+        for word in &[TERM_VIEW_TAG_APPLICATION, 0, 0, 0, 0] {
+            cyclic.extend_from_slice(&word.to_le_bytes());
+        }
+
+        assert!(term_view_decode(&cyclic).is_none());
+    }
+}
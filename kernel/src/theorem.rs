@@ -34,6 +34,7 @@
 //! [Arm Research]: http://www.arm.com/research
 
 use crate::handle::{tags, Handle};
+use std::sync::Arc;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Theorems, proper.
@@ -46,8 +47,11 @@ pub struct Theorem {
     /// The premisses of the theorem, i.e. the set of propositions that must
     /// hold for the conclusion to also hold.  All elements of this list should
     /// be handles pointing-to propositions in the runtime state's term-table.
-    /// Handles should be stored in ascending sorted order.
-    premisses: Vec<Handle<tags::Term>>,
+    /// Handles should be stored in ascending sorted order.  Once a theorem is
+    /// admitted into the runtime state the list is shared with the state's
+    /// table of interned hypotheses, and with every other theorem with the same
+    /// premisses.
+    premisses: Arc<Vec<Handle<tags::Term>>>,
     /// The conclusion of the theorem, which must be a handle pointing-to a
     /// proposition in the runtime state's term-table.
     conclusion: Handle<tags::Term>,
@@ -70,11 +74,24 @@ impl Theorem {
         premisses.dedup();
 
         Self {
-            premisses,
+            premisses: Arc::new(premisses),
             conclusion: conclusion.into(),
         }
     }
 
+    /// Replaces the premisses of the theorem with `premisses`, an interned list
+    /// that is equal to the existing premisses of the theorem, so that the two
+    /// lists can be shared.
+    #[inline]
+    pub(crate) fn share_premisses(
+        &mut self,
+        premisses: Arc<Vec<Handle<tags::Term>>>,
+    ) {
+        debug_assert_eq!(self.premisses, premisses);
+
+        self.premisses = premisses;
+    }
+
     /// Returns the handle to the theorem's conclusion.
     #[inline]
     pub fn conclusion(&self) -> &Handle<tags::Term> {
@@ -86,6 +103,12 @@ impl Theorem {
     pub fn premisses(&self) -> &Vec<Handle<tags::Term>> {
         &self.premisses
     }
+
+    /// Returns the premisses of the theorem, as a shareable list.
+    #[inline]
+    pub(crate) fn shared_premisses(&self) -> &Arc<Vec<Handle<tags::Term>>> {
+        &self.premisses
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,351 @@
+//! # Trust levels
+//!
+//! Every theorem registered by the kernel is only as sound as whatever it was
+//! derived from.  Most theorems are derived by the kernel's inference rules
+//! alone, but a theorem may also depend upon an *axiom*, postulated by the
+//! host without proof, or upon an *oracle*, such as the linear arithmetic
+//! decision procedure of the experimental `linear-arith` feature, which is
+//! trusted to register only true theorems.  The kernel tracks this
+//! automatically, extending its tracking of taint: every theorem has a *trust
+//! level*, which is that of the inference rule that registered it, or that of
+//! the least trusted premiss that the rule was applied to, whichever is
+//! lower, so that a consumer of a proof artefact may enforce a trust policy
+//! of its own, e.g. accepting only theorems proved by the kernel alone.
+//!
+//! Axioms are only ever postulated by the host, with
+//! `RuntimeState::theorem_register_axiom`, and never by prover-space code: a
+//! host may postulate the axioms of a theory that it does not wish to develop
+//! definitionally, say, but axioms are not checked for consistency, and a
+//! single inconsistent axiom lets every proposition be derived.  Theorems
+//! restored from a snapshot keep the trust level that they had when the
+//! snapshot was taken, whereas the sequents of a state object are only
+//! claims, so have no trust level until re-proved.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    kernel_panic::DANGLING_HANDLE_ERROR,
+    proof::{ProofArgument, Rule},
+    proof_object::Sequent,
+    runtime_state::RuntimeState,
+    theorem::Theorem,
+};
+use log::info;
+use std::{borrow::Borrow, convert::TryFrom};
+
+////////////////////////////////////////////////////////////////////////////////
+// Trust levels.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The trust level of a theorem, recording what the theorem depends upon
+/// besides the kernel's inference rules.  Trust levels are ordered from most to
+/// least trusted.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TrustLevel {
+    /// The theorem was derived by the kernel's inference rules alone.
+    KernelProved,
+    /// The theorem depends upon an axiom postulated by the host, but upon no
+    /// oracle.
+    AxiomDependent,
+    /// The theorem depends upon an oracle, i.e. is tainted, and possibly also
+    /// upon axioms.
+    OracleDependent,
+}
+
+impl TrustLevel {
+    /// Returns the trust level of theorems registered by the inference rule
+    /// `rule`, before taking the trust levels of its premisses into account.
+    #[inline]
+    pub(crate) fn of_rule(rule: Rule) -> Self {
+        match rule {
+            Rule::Axiom => TrustLevel::AxiomDependent,
+            Rule::LinearArith => TrustLevel::OracleDependent,
+            _otherwise => TrustLevel::KernelProved,
+        }
+    }
+}
+
+/// Conversion from a trust level into a `u64`, for ABI transport.
+impl From<TrustLevel> for u64 {
+    #[inline]
+    fn from(trust: TrustLevel) -> u64 {
+        match trust {
+            TrustLevel::KernelProved => 0,
+            TrustLevel::AxiomDependent => 1,
+            TrustLevel::OracleDependent => 2,
+        }
+    }
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into a trust
+/// level.
+impl TryFrom<u64> for TrustLevel {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TrustLevel::KernelProved),
+            1 => Ok(TrustLevel::AxiomDependent),
+            2 => Ok(TrustLevel::OracleDependent),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Axioms, and querying and filtering by trust level.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Postulates the proposition pointed-to by `conclusion` as an axiom,
+    /// registering the theorem `⊢ ɸ` without proof, where `ɸ` is the
+    /// proposition.  The new theorem, and every theorem derived from it, is at
+    /// least `TrustLevel::AxiomDependent`.  This is only ever called by the
+    /// host, and is never exposed to prover-space code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `conclusion` does not
+    /// point-to a registered term, and `Err(ErrorCode::NotAProposition)` if the
+    /// term is not a proposition.
+    pub fn theorem_register_axiom<T>(
+        &mut self,
+        conclusion: T,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Postulating term with handle {} as an axiom.",
+            conclusion.borrow()
+        );
+
+        let conclusion = conclusion.borrow().clone();
+
+        if !self.term_type_is_proposition(&conclusion)? {
+            return Err(ErrorCode::NotAProposition);
+        }
+
+        let premisses: Vec<Handle<tags::Term>> = Vec::new();
+
+        self.admit_theorem(
+            Rule::Axiom,
+            vec![ProofArgument::Term(conclusion.clone())],
+            Theorem::new(premisses, conclusion),
+        )
+    }
+
+    /// Returns the trust level of the theorem pointed-to by `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `handle` does not
+    /// point-to any theorem in the runtime state's theorem-table.
+    pub fn theorem_trust_level<T>(
+        &self,
+        handle: T,
+    ) -> Result<TrustLevel, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        info!(
+            "Computing trust level of theorem with handle {}.",
+            handle.borrow()
+        );
+
+        self.theorem_split_conclusion(handle.borrow())?;
+
+        Ok(self
+            .trust
+            .get(handle.borrow())
+            .cloned()
+            .unwrap_or(TrustLevel::KernelProved))
+    }
+
+    /// Returns the handles of every theorem registered in the runtime state
+    /// that is at least as trusted as `trust`, in ascending order.
+    pub fn theorem_handles_trusted(
+        &self,
+        trust: TrustLevel,
+    ) -> Vec<Handle<tags::Theorem>> {
+        self.theorem_handles()
+            .into_iter()
+            .filter(|handle| {
+                self.trust
+                    .get(handle)
+                    .cloned()
+                    .unwrap_or(TrustLevel::KernelProved)
+                    <= trust
+            })
+            .collect()
+    }
+
+    /// Serializes the signature of the runtime state, and the sequents of every
+    /// theorem registered in it that is at least as trusted as `trust`, into a
+    /// state object.  The signature is exported whole, as for `state_export`,
+    /// which exports the sequents of every theorem whatever its trust level.
+    pub fn state_export_trusted(&self, trust: TrustLevel) -> Vec<u8> {
+        info!(
+            "Exporting runtime state as a state object, keeping theorems at trust level {:?} or above.",
+            trust
+        );
+
+        let sequents: Vec<Sequent> = self
+            .theorem_handles_trusted(trust)
+            .iter()
+            .map(|t| Sequent {
+                premisses: self
+                    .theorem_split_premisses(t)
+                    .expect(DANGLING_HANDLE_ERROR)
+                    .to_vec(),
+                conclusion: self
+                    .theorem_split_conclusion(t)
+                    .expect(DANGLING_HANDLE_ERROR),
+            })
+            .collect();
+
+        self.encode_state(&sequents)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for trust levels.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TYPE_ALPHA,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+        trust::TrustLevel,
+    };
+    use std::convert::TryFrom;
+
+    /// Tests that axioms, and theorems derived from them, are axiom-dependent,
+    /// and that the encoding of trust levels round-trips.
+    #[test]
+    pub fn trust0() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let x = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_ALPHA)
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_axiom(&x),
+            Err(ErrorCode::NotAProposition)
+        );
+
+        let axiom = state.theorem_register_axiom(&p).unwrap();
+        let assumption = state.theorem_register_assumption(p.clone()).unwrap();
+        let weakened = state
+            .theorem_register_weaken(
+                PREALLOCATED_HANDLE_TERM_FALSE,
+                axiom.clone(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_trust_level(&axiom),
+            Ok(TrustLevel::AxiomDependent)
+        );
+        assert_eq!(
+            state.theorem_trust_level(&assumption),
+            Ok(TrustLevel::KernelProved)
+        );
+        assert_eq!(
+            state.theorem_trust_level(&weakened),
+            Ok(TrustLevel::AxiomDependent)
+        );
+        assert!(!state.theorem_is_tainted(&axiom).unwrap());
+
+        for trust in [
+            TrustLevel::KernelProved,
+            TrustLevel::AxiomDependent,
+            TrustLevel::OracleDependent,
+        ] {
+            assert_eq!(TrustLevel::try_from(u64::from(trust)), Ok(trust));
+        }
+
+        assert_eq!(TrustLevel::try_from(3u64), Err(()));
+    }
+
+    /// Tests that filtering by trust level keeps exactly the theorems that are
+    /// trusted enough, both when querying and when exporting.
+    #[test]
+    pub fn trust1() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let q = state
+            .term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+
+        let axiom = state.theorem_register_axiom(&p).unwrap();
+        let assumption = state.theorem_register_assumption(q).unwrap();
+
+        assert_eq!(
+            state.theorem_handles_trusted(TrustLevel::KernelProved),
+            vec![assumption.clone()]
+        );
+        assert_eq!(
+            state.theorem_handles_trusted(TrustLevel::AxiomDependent),
+            vec![axiom, assumption]
+        );
+
+        let exported = state.state_export_trusted(TrustLevel::KernelProved);
+        let imported = RuntimeState::new().state_import(&exported).unwrap();
+
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].premisses.len(), 1);
+    }
+
+    /// Tests that trust levels survive taking and restoring a snapshot.
+    #[test]
+    pub fn trust2() {
+        let mut state = RuntimeState::new();
+
+        let p = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let axiom = state.theorem_register_axiom(&p).unwrap();
+        let assumption = state.theorem_register_assumption(p).unwrap();
+
+        let restored =
+            RuntimeState::snapshot_restore(&state.snapshot_export()).unwrap();
+
+        assert_eq!(
+            restored.theorem_trust_level(&axiom),
+            Ok(TrustLevel::AxiomDependent)
+        );
+        assert_eq!(
+            restored.theorem_trust_level(&assumption),
+            Ok(TrustLevel::KernelProved)
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! # Defined types
+//!
+//! The kernel's type-definition principle, in the style of the
+//! `new_type_definition` command of other HOL-family systems, defines a new
+//! type in bijection with the non-empty subset of an existing type picked out
+//! by a predicate, given a theorem witnessing that the subset is non-empty.
+//! Unlike the definition of a constant, a type definition is not an equation,
+//! so cannot be unfolded: the new type is instead *axiomatised* by two
+//! theorems relating its abstraction and representation functions, and these
+//! are the only means of reasoning about it.
+//!
+//! The kernel records every type definition against the type-former of the new
+//! type, along with the predicate and witness type that it was defined from
+//! and the constants and theorems axiomatising it, so that the definition may
+//! be recovered later from the type-former alone, e.g. by a package deriving
+//! further theorems about the type, or by a consumer auditing what a theory
+//! defines.  The quotient types of the `quotient` module are defined with the
+//! same principle, so are recorded here, too.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::borrow::Borrow;
+
+////////////////////////////////////////////////////////////////////////////////
+// Defined types.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A type defined with the kernel's type-definition principle, along with the
+/// constants and theorems axiomatising it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinedType {
+    /// The type-former of the defined type.
+    pub(crate) former: Handle<tags::TypeFormer>,
+    /// The predicate, `P : ρ → Prop`, picking out the subset of `ρ` that the
+    /// defined type is in bijection with.
+    pub(crate) predicate: Handle<tags::Term>,
+    /// The type, `ρ`, of which the defined type is a subset.
+    pub(crate) carrier: Handle<tags::Type>,
+    /// The defined type, `τ`, applying its type-former to the type-variables
+    /// of the predicate.
+    pub(crate) tau: Handle<tags::Type>,
+    /// The abstraction function, `abs : ρ → τ`.
+    pub(crate) abs: Handle<tags::Term>,
+    /// The representation function, `rep : τ → ρ`.
+    pub(crate) rep: Handle<tags::Term>,
+    /// The theorem `⊢ ∀a:τ. abs (rep a) = a`.
+    pub(crate) abs_rep: Handle<tags::Theorem>,
+    /// The theorem `⊢ ∀r:ρ. P r = (rep (abs r) = r)`.
+    pub(crate) rep_abs: Handle<tags::Theorem>,
+}
+
+impl DefinedType {
+    /// Returns the type-former of the defined type.
+    #[inline]
+    pub fn former(&self) -> &Handle<tags::TypeFormer> {
+        &self.former
+    }
+
+    /// Returns the predicate, `P : ρ → Prop`, that the type was defined from.
+    #[inline]
+    pub fn predicate(&self) -> &Handle<tags::Term> {
+        &self.predicate
+    }
+
+    /// Returns the type, `ρ`, of which the defined type is a subset.
+    #[inline]
+    pub fn carrier(&self) -> &Handle<tags::Type> {
+        &self.carrier
+    }
+
+    /// Returns the defined type, `τ`.
+    #[inline]
+    pub fn tau(&self) -> &Handle<tags::Type> {
+        &self.tau
+    }
+
+    /// Returns the abstraction function, `abs : ρ → τ`.
+    #[inline]
+    pub fn abs(&self) -> &Handle<tags::Term> {
+        &self.abs
+    }
+
+    /// Returns the representation function, `rep : τ → ρ`.
+    #[inline]
+    pub fn rep(&self) -> &Handle<tags::Term> {
+        &self.rep
+    }
+
+    /// Returns the theorem `⊢ ∀a:τ. abs (rep a) = a`.
+    #[inline]
+    pub fn abs_rep(&self) -> &Handle<tags::Theorem> {
+        &self.abs_rep
+    }
+
+    /// Returns the theorem `⊢ ∀r:ρ. P r = (rep (abs r) = r)`.
+    #[inline]
+    pub fn rep_abs(&self) -> &Handle<tags::Theorem> {
+        &self.rep_abs
+    }
+
+    /// Returns `true` iff the definition mentions the kernel object with handle
+    /// `handle`, of any kind.
+    pub(crate) fn mentions(&self, handle: usize) -> bool {
+        [
+            *self.predicate,
+            *self.carrier,
+            *self.tau,
+            *self.abs,
+            *self.rep,
+            *self.abs_rep,
+            *self.rep_abs,
+        ]
+        .contains(&handle)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Resolving defined types.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Returns `Ok(defined)` iff the type-former pointed-to by `former` is that
+    /// of a type defined with `register_new_type_definition`, where `defined`
+    /// describes the definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTypeFormerRegistered)` if `former` does
+    /// not point-to a registered type-former in the runtime state's
+    /// type-former table.
+    ///
+    /// Returns `Err(ErrorCode::NotADefinedType)` if the type-former pointed-to
+    /// by `former` is not that of a defined type.
+    pub fn type_definition_resolve<T>(
+        &self,
+        former: T,
+    ) -> Result<&DefinedType, ErrorCode>
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        info!(
+            "Resolving defined type with type-former: {}.",
+            former.borrow()
+        );
+
+        if !self.type_former_is_registered(former.borrow()) {
+            return Err(ErrorCode::NoSuchTypeFormerRegistered);
+        }
+
+        self.defined_types
+            .get(former.borrow())
+            .ok_or(ErrorCode::NotADefinedType)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests for defined types.
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            Handle, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_FORMER_PROP,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+    };
+
+    /// Tests that a type definition is recorded against its type-former, and
+    /// may be resolved from it, along with the objects axiomatising it.
+    #[test]
+    pub fn type_definition0() {
+        let mut state = RuntimeState::new();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let predicate = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, x)
+            .unwrap();
+        let application = state
+            .term_register_application(
+                predicate.clone(),
+                PREALLOCATED_HANDLE_TERM_TRUE,
+            )
+            .unwrap();
+
+        let beta = state.theorem_register_beta(application).unwrap();
+        let beta = state.theorem_register_symmetry(beta).unwrap();
+        let truth = state.theorem_register_truth_introduction().unwrap();
+        let witness = state.derived_equality_mp(&beta, &truth).unwrap();
+
+        let (tau, abs, rep, abs_rep, rep_abs) =
+            state.register_new_type_definition(&witness).unwrap();
+        let (former, arguments) = state.type_split_combination(&tau).unwrap();
+        let former = former.clone();
+
+        assert!(arguments.is_empty());
+
+        let defined = state.type_definition_resolve(&former).unwrap();
+
+        assert_eq!(defined.former(), &former);
+        assert_eq!(defined.predicate(), &predicate);
+        assert_eq!(defined.carrier(), &PREALLOCATED_HANDLE_TYPE_PROP);
+        assert_eq!(defined.tau(), &tau);
+        assert_eq!(defined.abs(), &abs);
+        assert_eq!(defined.rep(), &rep);
+        assert_eq!(defined.abs_rep(), &abs_rep);
+        assert_eq!(defined.rep_abs(), &rep_abs);
+
+        assert_eq!(state.object_delete(*abs_rep), Err(ErrorCode::ObjectInUse));
+        assert_eq!(
+            state.type_definition_resolve(PREALLOCATED_HANDLE_TYPE_FORMER_PROP),
+            Err(ErrorCode::NotADefinedType)
+        );
+        assert_eq!(
+            state.type_definition_resolve(Handle::from(usize::MAX)),
+            Err(ErrorCode::NoSuchTypeFormerRegistered)
+        );
+    }
+}
@@ -0,0 +1,424 @@
+//! # Controlled unfolding of definitions
+//!
+//! A definition, `⊢ c = t`, is unfolded by rewriting occurrences of the
+//! constant `c` with its definiens, `t`.  Definitional reasoning is often
+//! sensitive to how much is unfolded: unfolding every occurrence of a
+//! constant may swamp a goal with detail that is only needed in one place, so
+//! the kernel offers several *modes* of unfolding, each producing the theorem
+//! `Γ ⊢ s = s'`, where `s'` is `s` with some occurrences of `c` unfolded:
+//!
+//! - `UnfoldMode::Once` unfolds only the leftmost-outermost occurrence,
+//! - `UnfoldMode::All` unfolds every occurrence, to a fixpoint,
+//! - `UnfoldMode::Within(position)` unfolds every occurrence, to a fixpoint,
+//!   but only within the subterm at `position`.
+//!
+//! Each mode saves the guest from taking the term apart, and rebuilding the
+//! theorem by congruence, itself.  As for the `derived` module, nothing here
+//! extends the kernel's trusted base: the theorem is derived by the kernel's
+//! primitive congruence rules, and recorded as such.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::{
+    error_code::ErrorCode,
+    handle::{tags, Handle},
+    runtime_state::RuntimeState,
+};
+use log::info;
+use std::{borrow::Borrow, convert::TryFrom};
+
+////////////////////////////////////////////////////////////////////////////////
+// Unfolding modes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Which occurrences of a defined constant are unfolded.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum UnfoldMode {
+    /// Only the leftmost-outermost occurrence.
+    Once,
+    /// Every occurrence, to a fixpoint.
+    All,
+    /// Every occurrence, to a fixpoint, within the subterm at the position, as
+    /// described in the `position` module.
+    Within(Vec<usize>),
+}
+
+/// Conversion from a pair of a `u64` value and a position, received across the
+/// ABI boundary, into an unfolding mode.  The value encodes the kind of mode,
+/// and the position is that of a `UnfoldMode::Within` mode, which is ignored by
+/// the other modes.
+impl TryFrom<(u64, Vec<usize>)> for UnfoldMode {
+    type Error = ();
+
+    fn try_from(value: (u64, Vec<usize>)) -> Result<Self, Self::Error> {
+        match value {
+            (0, _position) => Ok(UnfoldMode::Once),
+            (1, _position) => Ok(UnfoldMode::All),
+            (2, position) => Ok(UnfoldMode::Within(position)),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Unfolding, proper.
+////////////////////////////////////////////////////////////////////////////////
+
+impl RuntimeState {
+    /// Unfolds the definition `Γ ⊢ c = t`, pointed-to by `definition`, within
+    /// the term pointed-to by `term`, `s`, deriving and registering the
+    /// theorem `Γ ⊢ s = s'`, where `s'` is `s` with the occurrences of the
+    /// constant `c` selected by `mode` replaced by `t`.  Returns `Ok(handle)`,
+    /// where `handle` points-to the new theorem.  If no occurrence is
+    /// selected, the theorem is `⊢ s = s`.
+    ///
+    /// Occurrences of `c` are matched exactly, including their type, so that
+    /// an instance of a polymorphic constant at another type is not unfolded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `definition` does
+    /// not point-to any theorem in the runtime state's theorem-table.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchTermRegistered)` if `term` does not
+    /// point-to any term in the runtime state's term-table.
+    ///
+    /// Returns `Err(ErrorCode::NotAnEquality)` if the conclusion of the
+    /// theorem pointed-to by `definition` is not an equality, and
+    /// `Err(ErrorCode::NotAConstant)` if its left-hand side is not a constant.
+    ///
+    /// Returns `Err(ErrorCode::ShapeMismatch)` if `mode` unfolds to a
+    /// fixpoint, but `t` mentions `c`, so that there is none.
+    ///
+    /// Returns `Err(ErrorCode::NoSuchPosition)` if the position of a
+    /// `UnfoldMode::Within` mode does not address a subterm of `s`.
+    pub fn theorem_register_unfold<T, U>(
+        &mut self,
+        definition: T,
+        term: U,
+        mode: &UnfoldMode,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        info!(
+            "Unfolding definition with handle {} within term with handle {}, \
+             in mode {:?}.",
+            definition.borrow(),
+            term.borrow(),
+            mode
+        );
+
+        let definition = definition.borrow();
+        let term = term.borrow();
+
+        let conclusion = self.theorem_split_conclusion(definition)?;
+        let (constant, definiens) = self.term_split_equality(conclusion)?;
+
+        // Appease the borrow-checker gods...
+        let constant = constant.clone();
+        let definiens = definiens.clone();
+
+        self.term_split_constant(&constant)?;
+        self.resolve_term_handle(term)?;
+
+        if *mode != UnfoldMode::Once
+            && self.term_postorder(&definiens)?.contains(&constant)
+        {
+            return Err(ErrorCode::ShapeMismatch);
+        }
+
+        let unfolded = match mode {
+            UnfoldMode::Once => self.unfold_occurrences(
+                definition,
+                &constant,
+                term,
+                &mut Some(1),
+            )?,
+            UnfoldMode::All => {
+                self.unfold_occurrences(definition, &constant, term, &mut None)?
+            }
+            UnfoldMode::Within(position) => {
+                let subterm = self.term_subterm_at(term, position)?;
+
+                match self.unfold_occurrences(
+                    definition, &constant, &subterm, &mut None,
+                )? {
+                    None => None,
+                    Some(thm) => {
+                        Some(self.derived_congruence_at(term, position, thm)?)
+                    }
+                }
+            }
+        };
+
+        match unfolded {
+            Some(thm) => Ok(thm),
+            None => self.theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                term.clone(),
+            ),
+        }
+    }
+
+    /// Unfolds occurrences of `constant`, defined by `definition`, within
+    /// `term`, leftmost-outermost first.  At most `remaining` occurrences are
+    /// unfolded, and `remaining` is decremented for each, unless it is `None`,
+    /// in which case every occurrence is.  Returns `Ok(None)` if nothing was
+    /// unfolded.
+    fn unfold_occurrences(
+        &mut self,
+        definition: &Handle<tags::Theorem>,
+        constant: &Handle<tags::Term>,
+        term: &Handle<tags::Term>,
+        remaining: &mut Option<usize>,
+    ) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+        match *remaining {
+            Some(0) => return Ok(None),
+            Some(count) if term == constant => {
+                *remaining = Some(count - 1);
+                return Ok(Some(definition.clone()));
+            }
+            None if term == constant => return Ok(Some(definition.clone())),
+            _otherwise => (),
+        }
+
+        if let Ok((left, right)) = self.term_split_application(term) {
+            let left = left.clone();
+            let right = right.clone();
+
+            let function = self
+                .unfold_occurrences(definition, constant, &left, remaining)?;
+            let argument = self
+                .unfold_occurrences(definition, constant, &right, remaining)?;
+
+            return self.unfold_congruence(&left, function, &right, argument);
+        }
+
+        if let Ok((name, tau, body)) = self.term_split_lambda(term) {
+            let name = *name;
+            let tau = tau.clone();
+            let body = body.clone();
+
+            return match self
+                .unfold_occurrences(definition, constant, &body, remaining)?
+            {
+                None => Ok(None),
+                Some(thm) => {
+                    self.theorem_register_lambda(name, tau, thm).map(Some)
+                }
+            };
+        }
+
+        Ok(None)
+    }
+
+    /// Derives `f a = g b` from the unfoldings of the function, `f`, and
+    /// argument, `a`, of an application, either of which may be missing, and
+    /// is then replaced by reflexivity.  Returns `Ok(None)` if both are.
+    fn unfold_congruence(
+        &mut self,
+        left: &Handle<tags::Term>,
+        function: Option<Handle<tags::Theorem>>,
+        right: &Handle<tags::Term>,
+        argument: Option<Handle<tags::Theorem>>,
+    ) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+        if function.is_none() && argument.is_none() {
+            return Ok(None);
+        }
+
+        let function = match function {
+            Some(thm) => thm,
+            None => self
+                .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                    left.clone(),
+                )?,
+        };
+        let argument = match argument {
+            Some(thm) => thm,
+            None => self
+                .theorem_register_reflexivity::<Handle<tags::Term>, _>(
+                    right.clone(),
+                )?,
+        };
+
+        self.theorem_register_application(function, argument)
+            .map(Some)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error_code::ErrorCode,
+        handle::{
+            tags, Handle, PREALLOCATED_HANDLE_TERM_FALSE,
+            PREALLOCATED_HANDLE_TERM_NEGATION, PREALLOCATED_HANDLE_TERM_TRUE,
+            PREALLOCATED_HANDLE_TYPE_PROP,
+        },
+        runtime_state::RuntimeState,
+        unfold::UnfoldMode,
+    };
+    use std::convert::TryFrom;
+
+    /// Returns the right-hand side of the conclusion of the theorem pointed-to
+    /// by `handle`, after checking that its left-hand side is `term`.
+    fn unfolded(
+        state: &RuntimeState,
+        handle: &Handle<tags::Theorem>,
+        term: &Handle<tags::Term>,
+    ) -> Handle<tags::Term> {
+        let conclusion = state.theorem_split_conclusion(handle).unwrap();
+        let (left, right) = state.term_split_equality(conclusion).unwrap();
+
+        assert_eq!(left, term);
+
+        right.clone()
+    }
+
+    /// Tests each mode on `c (¬ c)`, where `c` is defined to be `true`.
+    #[test]
+    pub fn unfold0() {
+        let mut state = RuntimeState::new();
+
+        let (c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+
+        let not_c = state
+            .term_register_application(
+                PREALLOCATED_HANDLE_TERM_NEGATION,
+                c.clone(),
+            )
+            .unwrap();
+        let term = state
+            .term_register_conjunction(c.clone(), not_c.clone())
+            .unwrap();
+
+        let not_t = state
+            .term_register_negation(PREALLOCATED_HANDLE_TERM_TRUE)
+            .unwrap();
+        let once = state
+            .term_register_conjunction(PREALLOCATED_HANDLE_TERM_TRUE, not_c)
+            .unwrap();
+        let all = state
+            .term_register_conjunction(
+                PREALLOCATED_HANDLE_TERM_TRUE,
+                not_t.clone(),
+            )
+            .unwrap();
+        let within = state.term_register_conjunction(c, not_t).unwrap();
+
+        let thm = state
+            .theorem_register_unfold(&defn, &term, &UnfoldMode::Once)
+            .unwrap();
+        assert_eq!(unfolded(&state, &thm, &term), once);
+
+        let thm = state
+            .theorem_register_unfold(&defn, &term, &UnfoldMode::All)
+            .unwrap();
+        assert_eq!(unfolded(&state, &thm, &term), all);
+
+        let thm = state
+            .theorem_register_unfold(&defn, &term, &UnfoldMode::Within(vec![1]))
+            .unwrap();
+        assert_eq!(unfolded(&state, &thm, &term), within);
+
+        let thm = state
+            .theorem_register_unfold(
+                &defn,
+                PREALLOCATED_HANDLE_TERM_FALSE,
+                &UnfoldMode::All,
+            )
+            .unwrap();
+        assert_eq!(
+            unfolded(&state, &thm, &PREALLOCATED_HANDLE_TERM_FALSE),
+            PREALLOCATED_HANDLE_TERM_FALSE
+        );
+
+        assert_eq!(
+            state.theorem_register_unfold(
+                &defn,
+                &term,
+                &UnfoldMode::Within(vec![0, 0, 0]),
+            ),
+            Err(ErrorCode::NoSuchPosition)
+        );
+    }
+
+    /// Tests unfolding beneath a binder, and that a definiens mentioning the
+    /// constant being unfolded has no fixpoint.
+    #[test]
+    pub fn unfold1() {
+        let mut state = RuntimeState::new();
+
+        let (c, defn) = state
+            .register_new_definition(PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+
+        let x = state
+            .term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP)
+            .unwrap();
+        let body = state
+            .term_register_conjunction(x.clone(), c.clone())
+            .unwrap();
+        let term = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        let body = state
+            .term_register_conjunction(x, PREALLOCATED_HANDLE_TERM_FALSE)
+            .unwrap();
+        let expected = state
+            .term_register_lambda(0u64, PREALLOCATED_HANDLE_TYPE_PROP, body)
+            .unwrap();
+
+        let thm = state
+            .theorem_register_unfold(
+                &defn,
+                &term,
+                &UnfoldMode::Within(vec![0, 1]),
+            )
+            .unwrap();
+        assert_eq!(unfolded(&state, &thm, &term), expected);
+
+        let refl = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(c)
+            .unwrap();
+        let refl_term = state
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(term.clone())
+            .unwrap();
+
+        assert_eq!(
+            state.theorem_register_unfold(&refl, &term, &UnfoldMode::All),
+            Err(ErrorCode::ShapeMismatch)
+        );
+        assert_eq!(
+            state.theorem_register_unfold(&refl_term, &term, &UnfoldMode::Once),
+            Err(ErrorCode::NotAConstant)
+        );
+        assert_eq!(
+            UnfoldMode::try_from((2, vec![0, 1])),
+            Ok(UnfoldMode::Within(vec![0, 1]))
+        );
+        assert_eq!(UnfoldMode::try_from((1, vec![0])), Ok(UnfoldMode::All));
+        assert_eq!(UnfoldMode::try_from((3, Vec::new())), Err(()));
+    }
+}
@@ -0,0 +1,278 @@
+//! # The boolean theory
+//!
+//! Basic lemmas about the boolean connectives, derived in prover-space from
+//! the kernel's natural deduction rules.  Every lemma is stated over the
+//! propositional variables `p`, `q` and `r`, and may be instantiated with
+//! `theorem_register_substitute`.  The remaining standard theories build on
+//! the helpers exported here.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use libsupervisionary::raw::{
+    _type::PREALLOCATED_HANDLE_TYPE_PROP,
+    metadata::object_metadata_set,
+    tags,
+    term::{
+        term_register_conjunction, term_register_disjunction,
+        term_register_implication, term_register_negation,
+        term_register_variable, PREALLOCATED_HANDLE_TERM_FALSE,
+    },
+    theorem::{
+        theorem_implication_introduction, theorem_negation_introduction,
+        theorem_register_assumption, theorem_register_conjunction_introduction,
+        theorem_register_conjunction_left_elimination,
+        theorem_register_conjunction_right_elimination,
+        theorem_register_disjunction_elimination,
+        theorem_register_disjunction_left_introduction,
+        theorem_register_disjunction_right_introduction,
+        theorem_register_falsity_elimination,
+        theorem_register_iff_introduction,
+        theorem_register_implication_elimination,
+        theorem_register_negation_elimination,
+        theorem_register_truth_introduction, theorem_register_weaken,
+    },
+    ErrorCode, Handle, Name,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Variables and naming.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the propositional variable `p`.
+pub const NAME_P: Name = 0;
+/// The name of the propositional variable `q`.
+pub const NAME_Q: Name = 1;
+/// The name of the propositional variable `r`.
+pub const NAME_R: Name = 2;
+
+/// The metadata key under which the name of a lemma is recorded.
+pub const METADATA_KEY_NAME: &str = "name";
+
+/// Registers the propositional variable `name`.
+pub fn proposition<N>(name: N) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    N: Into<Name>,
+{
+    term_register_variable(name, PREALLOCATED_HANDLE_TYPE_PROP)
+}
+
+/// Attaches `name` to the theorem pointed-to by `handle`, so that the lemma
+/// can be found by name once the kernel's state is exported and re-imported.
+pub fn name_theorem<H>(handle: H, name: &str) -> Result<(), ErrorCode>
+where
+    H: AsRef<Handle<tags::Theorem>>,
+{
+    object_metadata_set(handle, METADATA_KEY_NAME, name)
+}
+
+/// Attaches its name to every lemma in `lemmas`.
+pub fn name_theorems(
+    lemmas: &[(&str, Handle<tags::Theorem>)],
+) -> Result<(), ErrorCode> {
+    for (name, handle) in lemmas {
+        name_theorem(handle, name)?;
+    }
+
+    Ok(())
+}
+
+/// Discharges the premisses `hypotheses` of the theorem pointed-to by
+/// `handle`, last-to-first, so that the derived theorem is a chain of
+/// implications whose antecedents appear in the order of `hypotheses`.
+pub fn discharge<T>(
+    handle: Handle<tags::Theorem>,
+    hypotheses: &[T],
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>> + Clone,
+{
+    hypotheses
+        .iter()
+        .rev()
+        .try_fold(handle, |handle, hypothesis| {
+            theorem_implication_introduction(handle, hypothesis.clone())
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Lemmas.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Derives `⊢ ⊤`.
+pub fn truth() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    theorem_register_truth_introduction()
+}
+
+/// Derives `⊢ p → p`.
+pub fn implication_reflexivity() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let assumed = theorem_register_assumption(p.clone())?;
+
+    discharge(assumed, &[p])
+}
+
+/// Derives `⊢ (p → q) → (q → r) → p → r`.
+pub fn implication_transitivity() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let q = proposition(NAME_Q)?;
+    let r = proposition(NAME_R)?;
+
+    let pq = term_register_implication(p.clone(), q.clone())?;
+    let qr = term_register_implication(q, r)?;
+
+    let q = theorem_register_implication_elimination(
+        theorem_register_assumption(pq.clone())?,
+        theorem_register_assumption(p.clone())?,
+    )?;
+    let r = theorem_register_implication_elimination(
+        theorem_register_assumption(qr.clone())?,
+        q,
+    )?;
+
+    discharge(r, &[pq, qr, p])
+}
+
+/// Derives `⊢ p ∧ q → q ∧ p`.
+pub fn conjunction_commutativity() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let q = proposition(NAME_Q)?;
+    let pq = term_register_conjunction(p, q)?;
+
+    let assumed = theorem_register_assumption(pq.clone())?;
+    let left = theorem_register_conjunction_left_elimination(assumed.clone())?;
+    let right = theorem_register_conjunction_right_elimination(assumed)?;
+
+    let swapped = theorem_register_conjunction_introduction(right, left)?;
+
+    discharge(swapped, &[pq])
+}
+
+/// Derives `⊢ (p ∧ p) = p`.
+pub fn conjunction_idempotence() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let pp = term_register_conjunction(p.clone(), p.clone())?;
+
+    let forward = discharge(
+        theorem_register_conjunction_left_elimination(
+            theorem_register_assumption(pp.clone())?,
+        )?,
+        &[pp],
+    )?;
+
+    let assumed = theorem_register_assumption(p.clone())?;
+    let backward = discharge(
+        theorem_register_conjunction_introduction(assumed.clone(), assumed)?,
+        &[p],
+    )?;
+
+    theorem_register_iff_introduction(forward, backward)
+}
+
+/// Derives `⊢ p ∨ q → q ∨ p`.
+pub fn disjunction_commutativity() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let q = proposition(NAME_Q)?;
+    let pq = term_register_disjunction(p.clone(), q.clone())?;
+
+    /* The premisses of both cases must be exactly those of the disjunction,
+     * less the disjunct discharged by each case.
+     */
+    let left = theorem_register_weaken(
+        pq.clone(),
+        theorem_register_disjunction_right_introduction(
+            theorem_register_assumption(p.clone())?,
+            q.clone(),
+        )?,
+    )?;
+    let right = theorem_register_weaken(
+        pq.clone(),
+        theorem_register_disjunction_left_introduction(
+            theorem_register_assumption(q)?,
+            p,
+        )?,
+    )?;
+
+    let swapped = theorem_register_disjunction_elimination(
+        theorem_register_assumption(pq.clone())?,
+        left,
+        right,
+    )?;
+
+    discharge(swapped, &[pq])
+}
+
+/// Derives `⊢ p → ¬¬p`.
+pub fn double_negation_introduction() -> Result<Handle<tags::Theorem>, ErrorCode>
+{
+    let p = proposition(NAME_P)?;
+    let np = term_register_negation(p.clone())?;
+
+    let absurd = theorem_register_negation_elimination(
+        theorem_register_assumption(p.clone())?,
+        theorem_register_assumption(np.clone())?,
+    )?;
+    let nnp = theorem_negation_introduction(absurd, np)?;
+
+    discharge(nnp, &[p])
+}
+
+/// Derives `⊢ (p → q) → ¬q → ¬p`.
+pub fn contraposition() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+    let q = proposition(NAME_Q)?;
+    let pq = term_register_implication(p.clone(), q.clone())?;
+    let nq = term_register_negation(q)?;
+
+    let q = theorem_register_implication_elimination(
+        theorem_register_assumption(pq.clone())?,
+        theorem_register_assumption(p.clone())?,
+    )?;
+    let absurd = theorem_register_negation_elimination(
+        q,
+        theorem_register_assumption(nq.clone())?,
+    )?;
+    let np = theorem_negation_introduction(absurd, p)?;
+
+    discharge(np, &[pq, nq])
+}
+
+/// Derives `⊢ ⊥ → p`.
+pub fn falsity_elimination() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = proposition(NAME_P)?;
+
+    let p = theorem_register_falsity_elimination(
+        theorem_register_assumption(PREALLOCATED_HANDLE_TERM_FALSE)?,
+        p,
+    )?;
+
+    discharge(p, &[PREALLOCATED_HANDLE_TERM_FALSE])
+}
+
+/// Derives every lemma of the theory, paired with its name.
+pub fn lemmas() -> Result<Vec<(&'static str, Handle<tags::Theorem>)>, ErrorCode>
+{
+    Ok(vec![
+        ("TRUTH", truth()?),
+        ("IMP_REFL", implication_reflexivity()?),
+        ("IMP_TRANS", implication_transitivity()?),
+        ("CONJ_COMM", conjunction_commutativity()?),
+        ("CONJ_IDEM", conjunction_idempotence()?),
+        ("DISJ_COMM", disjunction_commutativity()?),
+        ("NOT_NOT_INTRO", double_negation_introduction()?),
+        ("CONTRAPOS", contraposition()?),
+        ("FALSITY_ELIM", falsity_elimination()?),
+    ])
+}
@@ -0,0 +1,221 @@
+//! # The combinator theory
+//!
+//! The `I`, `K`, `S` and `B` combinators, defined as λ-abstractions, and the
+//! lemmas stating how each reduces when applied to all of its arguments.  The
+//! reduction lemmas are derived by `reduce`, which β-reduces the head of an
+//! application spine one argument at a time.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use libsupervisionary::raw::{
+    _type::{
+        type_register_function, type_register_variable,
+        PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+    },
+    tags,
+    term::{
+        term_register_application, term_register_lambda,
+        term_register_variable, term_split_equality,
+    },
+    theorem::{
+        theorem_register_application, theorem_register_beta,
+        theorem_register_reflexivity, theorem_register_transitivity,
+        theorem_split_conclusion,
+    },
+    ErrorCode, Handle, Name,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Variables.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the type-variable `γ`, following the pre-allocated `⍺` and `β`.
+pub const NAME_GAMMA: Name = 2;
+
+/// The name of the first variable bound by a combinator.
+pub const NAME_X: Name = 0;
+/// The name of the second variable bound by a combinator.
+pub const NAME_Y: Name = 1;
+/// The name of the third variable bound by a combinator.
+pub const NAME_Z: Name = 2;
+
+/// Registers the type-variable `γ`.
+pub fn type_gamma() -> Handle<tags::Type> {
+    type_register_variable(NAME_GAMMA)
+}
+
+/// The types of the three variables bound by the `S` combinator,
+/// `⍺ → β → γ`, `⍺ → β` and `⍺`.
+fn s_types() -> Result<[Handle<tags::Type>; 3], ErrorCode> {
+    let bc =
+        type_register_function(PREALLOCATED_HANDLE_TYPE_BETA, type_gamma())?;
+    let abc = type_register_function(PREALLOCATED_HANDLE_TYPE_ALPHA, bc)?;
+    let ab = type_register_function(
+        PREALLOCATED_HANDLE_TYPE_ALPHA,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+    )?;
+
+    Ok([abc, ab, PREALLOCATED_HANDLE_TYPE_ALPHA])
+}
+
+/// The types of the three variables bound by the `B` combinator, `β → γ`,
+/// `⍺ → β` and `⍺`.
+fn b_types() -> Result<[Handle<tags::Type>; 3], ErrorCode> {
+    let bc =
+        type_register_function(PREALLOCATED_HANDLE_TYPE_BETA, type_gamma())?;
+    let ab = type_register_function(
+        PREALLOCATED_HANDLE_TYPE_ALPHA,
+        PREALLOCATED_HANDLE_TYPE_BETA,
+    )?;
+
+    Ok([bc, ab, PREALLOCATED_HANDLE_TYPE_ALPHA])
+}
+
+/// Registers the variables `x`, `y` and `z`, at `types`.
+fn variables(
+    types: &[Handle<tags::Type>; 3],
+) -> Result<[Handle<tags::Term>; 3], ErrorCode> {
+    Ok([
+        term_register_variable(NAME_X, types[0].clone())?,
+        term_register_variable(NAME_Y, types[1].clone())?,
+        term_register_variable(NAME_Z, types[2].clone())?,
+    ])
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Combinators.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the identity combinator, `I = λx:⍺. x`.
+pub fn combinator_i() -> Result<Handle<tags::Term>, ErrorCode> {
+    let x = term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+
+    term_register_lambda(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA, x)
+}
+
+/// Registers the constant combinator, `K = λx:⍺. λy:β. x`.
+pub fn combinator_k() -> Result<Handle<tags::Term>, ErrorCode> {
+    let x = term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+    let body = term_register_lambda(NAME_Y, PREALLOCATED_HANDLE_TYPE_BETA, x)?;
+
+    term_register_lambda(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA, body)
+}
+
+/// Registers the substitution combinator,
+/// `S = λx:⍺ → β → γ. λy:⍺ → β. λz:⍺. x z (y z)`.
+pub fn combinator_s() -> Result<Handle<tags::Term>, ErrorCode> {
+    let types = s_types()?;
+    let [x, y, z] = variables(&types)?;
+
+    let body = term_register_application(
+        term_register_application(x, z.clone())?,
+        term_register_application(y, z)?,
+    )?;
+
+    abstract_over(&types, body)
+}
+
+/// Registers the composition combinator,
+/// `B = λx:β → γ. λy:⍺ → β. λz:⍺. x (y z)`.
+pub fn combinator_b() -> Result<Handle<tags::Term>, ErrorCode> {
+    let types = b_types()?;
+    let [x, y, z] = variables(&types)?;
+
+    let body = term_register_application(x, term_register_application(y, z)?)?;
+
+    abstract_over(&types, body)
+}
+
+/// Abstracts `body` over `x`, `y` and `z`, at `types`.
+fn abstract_over(
+    types: &[Handle<tags::Type>; 3],
+    body: Handle<tags::Term>,
+) -> Result<Handle<tags::Term>, ErrorCode> {
+    let body = term_register_lambda(NAME_Z, types[2].clone(), body)?;
+    let body = term_register_lambda(NAME_Y, types[1].clone(), body)?;
+
+    term_register_lambda(NAME_X, types[0].clone(), body)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reduction.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Derives `⊢ head a₁ ... aₙ = r`, where `r` is obtained by β-reducing the
+/// head of the application spine once per argument in `arguments`.  Every
+/// partial application of `head` must reduce to a λ-abstraction while
+/// arguments remain, as is the case for a combinator applied to no more
+/// arguments than it binds.
+pub fn reduce(
+    head: Handle<tags::Term>,
+    arguments: &[Handle<tags::Term>],
+) -> Result<Handle<tags::Theorem>, ErrorCode> {
+    arguments.iter().try_fold(
+        theorem_register_reflexivity(head)?,
+        |reduced, argument| {
+            let (_left, right) = term_split_equality(
+                theorem_split_conclusion(reduced.clone())?,
+            )?;
+
+            let applied = theorem_register_application(
+                reduced,
+                theorem_register_reflexivity(argument.clone())?,
+            )?;
+            let beta = theorem_register_beta(term_register_application(
+                right,
+                argument.clone(),
+            )?)?;
+
+            theorem_register_transitivity(applied, beta)
+        },
+    )
+}
+
+/// Derives `⊢ I x = x`.
+pub fn i_reduction() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let x = term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+
+    reduce(combinator_i()?, &[x])
+}
+
+/// Derives `⊢ K x y = x`.
+pub fn k_reduction() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let x = term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+    let y = term_register_variable(NAME_Y, PREALLOCATED_HANDLE_TYPE_BETA)?;
+
+    reduce(combinator_k()?, &[x, y])
+}
+
+/// Derives `⊢ S x y z = x z (y z)`.
+pub fn s_reduction() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    reduce(combinator_s()?, &variables(&s_types()?)?)
+}
+
+/// Derives `⊢ B x y z = x (y z)`.
+pub fn b_reduction() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    reduce(combinator_b()?, &variables(&b_types()?)?)
+}
+
+/// Derives every lemma of the theory, paired with its name.
+pub fn lemmas() -> Result<Vec<(&'static str, Handle<tags::Theorem>)>, ErrorCode>
+{
+    Ok(vec![
+        ("I_THM", i_reduction()?),
+        ("K_THM", k_reduction()?),
+        ("S_THM", s_reduction()?),
+        ("B_THM", b_reduction()?),
+    ])
+}
@@ -0,0 +1,149 @@
+//! # The list theory
+//!
+//! The polymorphic list type, `⍺ list`, with its constructors `NIL` and
+//! `CONS`, which are marked as the constructors of the datatype.  Only the
+//! congruence of `CONS` is derived for now: structural induction and the
+//! distinctness and injectivity of the constructors need the kernel's support
+//! for inductive definitions, and will be added to this theory once the ABI
+//! exposes it.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use libsupervisionary::raw::{
+    _type::{
+        type_register_combination, type_register_function,
+        PREALLOCATED_HANDLE_TYPE_ALPHA,
+    },
+    constant::{constant_mark_constructors, constant_register},
+    metadata::object_metadata_set,
+    tags,
+    term::{
+        term_register_constant, term_register_equality, term_register_variable,
+    },
+    theorem::{
+        theorem_register_application, theorem_register_assumption,
+        theorem_register_reflexivity,
+    },
+    type_former::type_former_register_with_metadata,
+    ErrorCode, Handle, Name,
+};
+use theory_bool::{discharge, METADATA_KEY_NAME};
+
+////////////////////////////////////////////////////////////////////////////////
+// The list datatype.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the list type-former.
+pub const LIST_NAME: &str = "list";
+/// The documentation attached to the list type-former.
+pub const LIST_DOCUMENTATION: &str = "Finite lists, built from NIL and CONS.";
+
+/// The name of the variable `x`, of type `⍺`.
+pub const NAME_X: Name = 0;
+/// The name of the variable `xs`, of type `⍺ list`.
+pub const NAME_XS: Name = 1;
+/// The name of the variable `x'`, of type `⍺`.
+pub const NAME_X_PRIME: Name = 2;
+/// The name of the variable `xs'`, of type `⍺ list`.
+pub const NAME_XS_PRIME: Name = 3;
+
+/// The kernel objects making up the list datatype.
+#[derive(Clone, Debug)]
+pub struct ListTheory {
+    /// The list type-former, of arity one.
+    pub former: Handle<tags::TypeFormer>,
+    /// The type `⍺ list`.
+    pub list_type: Handle<tags::Type>,
+    /// The empty list, `NIL : ⍺ list`.
+    pub nil: Handle<tags::Constant>,
+    /// The list constructor, `CONS : ⍺ → ⍺ list → ⍺ list`.
+    pub cons: Handle<tags::Constant>,
+}
+
+/// Registers the list type-former and its constructors, marks the
+/// constructors as such, and names each of them.
+pub fn register() -> Result<ListTheory, ErrorCode> {
+    let former = type_former_register_with_metadata(
+        1u64,
+        LIST_NAME,
+        LIST_DOCUMENTATION,
+    )?;
+    let list_type = type_register_combination(
+        former.clone(),
+        vec![PREALLOCATED_HANDLE_TYPE_ALPHA],
+    )?;
+
+    let nil = constant_register(list_type.clone())?;
+    let cons = constant_register(type_register_function(
+        PREALLOCATED_HANDLE_TYPE_ALPHA,
+        type_register_function(list_type.clone(), list_type.clone())?,
+    )?)?;
+
+    constant_mark_constructors(
+        former.clone(),
+        vec![nil.clone(), cons.clone()],
+    )?;
+
+    object_metadata_set(&nil, METADATA_KEY_NAME, "NIL")?;
+    object_metadata_set(&cons, METADATA_KEY_NAME, "CONS")?;
+
+    Ok(ListTheory {
+        former,
+        list_type,
+        nil,
+        cons,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Lemmas.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Derives `⊢ x = x' → xs = xs' → CONS x xs = CONS x' xs'`.
+pub fn cons_congruence(
+    theory: &ListTheory,
+) -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let x = term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+    let xp =
+        term_register_variable(NAME_X_PRIME, PREALLOCATED_HANDLE_TYPE_ALPHA)?;
+    let xs = term_register_variable(NAME_XS, theory.list_type.clone())?;
+    let xsp = term_register_variable(NAME_XS_PRIME, theory.list_type.clone())?;
+
+    let cons = term_register_constant(
+        theory.cons.clone(),
+        Vec::<(Name, Handle<tags::Type>)>::new(),
+    )?;
+
+    let head = term_register_equality(x, xp)?;
+    let tail = term_register_equality(xs, xsp)?;
+
+    let congruence = theorem_register_application(
+        theorem_register_application(
+            theorem_register_reflexivity(cons)?,
+            theorem_register_assumption(head.clone())?,
+        )?,
+        theorem_register_assumption(tail.clone())?,
+    )?;
+
+    discharge(congruence, &[head, tail])
+}
+
+/// Derives every lemma of the theory, paired with its name.
+pub fn lemmas(
+    theory: &ListTheory,
+) -> Result<Vec<(&'static str, Handle<tags::Theorem>)>, ErrorCode> {
+    Ok(vec![("CONS_CONG", cons_congruence(theory)?)])
+}
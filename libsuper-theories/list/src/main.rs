@@ -0,0 +1,30 @@
+//! # The list theory
+//!
+//! Registers the list datatype, derives every lemma of the list theory and
+//! names it, so that running this binary under the driver with
+//! `--export-state` produces the theory's serialized artifact.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use theory_bool::name_theorems;
+use theory_list::{lemmas, register};
+
+fn main() {
+    let theory = register().expect("Failed to register the list datatype.");
+    let lemmas = lemmas(&theory).expect("Failed to derive the list theory.");
+
+    name_theorems(&lemmas).expect("Failed to name the list theory.");
+}
@@ -0,0 +1,195 @@
+//! # The pair theory
+//!
+//! Lemmas about the kernel's polymorphic pairs, `PAIR`, and their projections,
+//! `FST` and `SND`: the projection and surjective pairing axioms, restated as
+//! lemmas over variables, together with the congruence and injectivity of
+//! pairing.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use libsupervisionary::raw::{
+    _type::{
+        PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_BETA,
+        PREALLOCATED_HANDLE_TYPE_PRODUCT,
+    },
+    tags,
+    term::{
+        term_register_equality, term_register_first, term_register_pair,
+        term_register_second, term_register_variable, term_split_application,
+    },
+    theorem::{
+        theorem_register_application, theorem_register_assumption,
+        theorem_register_first_projection, theorem_register_reflexivity,
+        theorem_register_second_projection,
+        theorem_register_surjective_pairing, theorem_register_symmetry,
+        theorem_register_transitivity,
+    },
+    ErrorCode, Handle, Name,
+};
+use theory_bool::discharge;
+
+////////////////////////////////////////////////////////////////////////////////
+// Variables.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the variable `x`, of type `⍺`.
+pub const NAME_X: Name = 0;
+/// The name of the variable `y`, of type `β`.
+pub const NAME_Y: Name = 1;
+/// The name of the variable `x'`, of type `⍺`.
+pub const NAME_X_PRIME: Name = 2;
+/// The name of the variable `y'`, of type `β`.
+pub const NAME_Y_PRIME: Name = 3;
+/// The name of the variable `p`, of type `⍺ * β`.
+pub const NAME_P: Name = 4;
+
+/// Registers the variables `x` and `y`.
+fn components() -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode> {
+    Ok((
+        term_register_variable(NAME_X, PREALLOCATED_HANDLE_TYPE_ALPHA)?,
+        term_register_variable(NAME_Y, PREALLOCATED_HANDLE_TYPE_BETA)?,
+    ))
+}
+
+/// Registers the variables `x'` and `y'`.
+fn primed_components(
+) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode> {
+    Ok((
+        term_register_variable(NAME_X_PRIME, PREALLOCATED_HANDLE_TYPE_ALPHA)?,
+        term_register_variable(NAME_Y_PRIME, PREALLOCATED_HANDLE_TYPE_BETA)?,
+    ))
+}
+
+/// Returns the head of the application `term`, which is applied to a single
+/// argument.
+fn head(term: Handle<tags::Term>) -> Result<Handle<tags::Term>, ErrorCode> {
+    Ok(term_split_application(term)?.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Lemmas.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Derives `⊢ FST (x, y) = x`.
+pub fn first_projection() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let (x, y) = components()?;
+
+    theorem_register_first_projection(x, y)
+}
+
+/// Derives `⊢ SND (x, y) = y`.
+pub fn second_projection() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let (x, y) = components()?;
+
+    theorem_register_second_projection(x, y)
+}
+
+/// Derives `⊢ (FST p, SND p) = p`.
+pub fn surjective_pairing() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let p = term_register_variable(NAME_P, PREALLOCATED_HANDLE_TYPE_PRODUCT)?;
+
+    theorem_register_surjective_pairing(p)
+}
+
+/// Derives `⊢ x = x' → y = y' → (x, y) = (x', y')`.
+pub fn pair_congruence() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let (x, y) = components()?;
+    let (xp, yp) = primed_components()?;
+
+    let pair = head(head(term_register_pair(x.clone(), y.clone())?)?)?;
+
+    let left = term_register_equality(x, xp)?;
+    let right = term_register_equality(y, yp)?;
+
+    let congruence = theorem_register_application(
+        theorem_register_application(
+            theorem_register_reflexivity(pair)?,
+            theorem_register_assumption(left.clone())?,
+        )?,
+        theorem_register_assumption(right.clone())?,
+    )?;
+
+    discharge(congruence, &[left, right])
+}
+
+/// Derives `⊢ (x, y) = (x', y') → x = x'`.
+pub fn pair_injectivity_first() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let (x, y) = components()?;
+    let (xp, yp) = primed_components()?;
+
+    let pair = term_register_pair(x.clone(), y.clone())?;
+    let primed = term_register_pair(xp.clone(), yp.clone())?;
+    let equal = term_register_equality(pair.clone(), primed)?;
+
+    let first = head(term_register_first(pair)?)?;
+
+    let congruence = theorem_register_application(
+        theorem_register_reflexivity(first)?,
+        theorem_register_assumption(equal.clone())?,
+    )?;
+    let injective = theorem_register_transitivity(
+        theorem_register_transitivity(
+            theorem_register_symmetry(theorem_register_first_projection(
+                x, y,
+            )?)?,
+            congruence,
+        )?,
+        theorem_register_first_projection(xp, yp)?,
+    )?;
+
+    discharge(injective, &[equal])
+}
+
+/// Derives `⊢ (x, y) = (x', y') → y = y'`.
+pub fn pair_injectivity_second() -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let (x, y) = components()?;
+    let (xp, yp) = primed_components()?;
+
+    let pair = term_register_pair(x.clone(), y.clone())?;
+    let primed = term_register_pair(xp.clone(), yp.clone())?;
+    let equal = term_register_equality(pair.clone(), primed)?;
+
+    let second = head(term_register_second(pair)?)?;
+
+    let congruence = theorem_register_application(
+        theorem_register_reflexivity(second)?,
+        theorem_register_assumption(equal.clone())?,
+    )?;
+    let injective = theorem_register_transitivity(
+        theorem_register_transitivity(
+            theorem_register_symmetry(theorem_register_second_projection(
+                x, y,
+            )?)?,
+            congruence,
+        )?,
+        theorem_register_second_projection(xp, yp)?,
+    )?;
+
+    discharge(injective, &[equal])
+}
+
+/// Derives every lemma of the theory, paired with its name.
+pub fn lemmas() -> Result<Vec<(&'static str, Handle<tags::Theorem>)>, ErrorCode>
+{
+    Ok(vec![
+        ("FST", first_projection()?),
+        ("SND", second_projection()?),
+        ("PAIR", surjective_pairing()?),
+        ("PAIR_CONG", pair_congruence()?),
+        ("PAIR_INJ_FST", pair_injectivity_first()?),
+        ("PAIR_INJ_SND", pair_injectivity_second()?),
+    ])
+}
@@ -0,0 +1,29 @@
+//! # The pair theory
+//!
+//! Derives every lemma of the pair theory and names it, so that running
+//! this binary under the driver with `--export-state` produces the theory's
+//! serialized artifact.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use theory_bool::name_theorems;
+use theory_pair::lemmas;
+
+fn main() {
+    let lemmas = lemmas().expect("Failed to derive the pair theory.");
+
+    name_theorems(&lemmas).expect("Failed to name the pair theory.");
+}
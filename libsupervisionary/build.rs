@@ -0,0 +1,58 @@
+//! # libsupervisionary build script
+//!
+//! Generates the `PREALLOCATED_HANDLE_*` constants of the raw bindings from
+//! the description in `abi-spec/preallocated-handles.txt`, which is shared
+//! with the kernel, so that prover-space and the kernel agree on every
+//! pre-allocated handle.  The build fails if the description is malformed.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+#[path = "../abi-spec/preallocated.rs"]
+mod preallocated;
+
+use std::{env, fs, path::Path};
+
+/// The kinds of pre-allocated handle, paired with the suffix of the generated
+/// file that the raw module binding that kind of kernel object includes.
+const OUTPUTS: &[(&str, &str)] = &[
+    ("TypeFormer", "type_former"),
+    ("Type", "type"),
+    ("Constant", "constant"),
+    ("Term", "term"),
+];
+
+fn main() {
+    let entries = preallocated::load(preallocated::SPECIFICATION)
+        .unwrap_or_else(|e| panic!("malformed pre-allocated handles: {}", e));
+
+    let out = env::var("OUT_DIR").expect("OUT_DIR is not set");
+
+    for (kind, suffix) in OUTPUTS {
+        let generated =
+            preallocated::constants(&entries, Some(kind), |handle| {
+                format!("Handle::new({}usize, PhantomData)", handle)
+            });
+
+        fs::write(
+            Path::new(&out).join(format!("preallocated_{}.rs", suffix)),
+            generated,
+        )
+        .expect("failed to write the pre-allocated handles");
+    }
+
+    println!("cargo:rerun-if-changed={}", preallocated::SPECIFICATION);
+    println!("cargo:rerun-if-changed=../abi-spec/preallocated.rs");
+}
@@ -0,0 +1,188 @@
+//! # Pooled buffers for ABI results
+//!
+//! Many of the raw ABI wrappers pass the kernel a buffer of machine words to
+//! write their results into, for example an array of handles or a list of
+//! variables, copy the results out, and then immediately discard the buffer.
+//! In the inner loops of tactics this churns the Wasm allocator, so this module
+//! keeps a small pool of released buffers for reuse instead.
+//!
+//! Buffers are taken from the pool with `buffer`, and are returned to it when
+//! they are dropped.  Outside of any scope only a few, modestly-sized, buffers
+//! are retained.  Opening a `Scope`, with `scope`, around a loop retains every
+//! buffer released within it, however large, so that each iteration reuses
+//! the buffers of the last; the excess is released when the outermost scope
+//! closes.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// The buffer pool.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of released buffers retained outside of any scope.
+const RETAINED_BUFFERS: usize = 8;
+/// The capacity, in words, above which released buffers are not retained
+/// outside of any scope.
+const RETAINED_CAPACITY: usize = 4096;
+
+/// A pool of released buffers, awaiting reuse.
+struct Pool {
+    /// The released buffers, all of which are empty.
+    free: Vec<Vec<u64>>,
+    /// The number of scopes currently open.
+    scopes: usize,
+}
+
+impl Pool {
+    /// Takes a buffer from the pool, or allocates a fresh one if the pool is
+    /// empty, resized to `length` zeroed words.
+    fn take(&mut self, length: usize) -> Vec<u64> {
+        let mut words = self.free.pop().unwrap_or_default();
+        words.resize(length, 0u64);
+        words
+    }
+
+    /// Returns `words` to the pool, unless no scope is open and the pool is
+    /// already full, or `words` is too large to be worth retaining.
+    fn release(&mut self, mut words: Vec<u64>) {
+        if self.scopes == 0
+            && (self.free.len() >= RETAINED_BUFFERS
+                || words.capacity() > RETAINED_CAPACITY)
+        {
+            return;
+        }
+
+        words.clear();
+        self.free.push(words);
+    }
+
+    /// Releases buffers back to the allocator until the pool is within the
+    /// bounds that apply outside of any scope.
+    fn trim(&mut self) {
+        self.free
+            .retain(|words| words.capacity() <= RETAINED_CAPACITY);
+        self.free.truncate(RETAINED_BUFFERS);
+    }
+}
+
+thread_local! {
+    /// The buffer pool.  Wasm guests are single-threaded, so in practice there
+    /// is exactly one.
+    static POOL: RefCell<Pool> = const {
+        RefCell::new(Pool {
+            free: Vec::new(),
+            scopes: 0,
+        })
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Buffers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A buffer of machine words taken from the pool, which is returned to the
+/// pool when dropped.  Dereferences to the underlying vector.
+#[derive(Debug)]
+pub struct Buffer {
+    /// The words of the buffer.
+    words: Vec<u64>,
+}
+
+/// Returns a buffer of `length` zeroed words, reusing a released buffer from
+/// the pool if possible.
+pub fn buffer(length: usize) -> Buffer {
+    let words = POOL
+        .try_with(|pool| pool.borrow_mut().take(length))
+        .unwrap_or_else(|_| vec![0u64; length]);
+
+    Buffer { words }
+}
+
+impl Deref for Buffer {
+    type Target = Vec<u64>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.words
+    }
+}
+
+impl DerefMut for Buffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.words
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        let words = mem::take(&mut self.words);
+
+        /* NB: the pool may already have been destroyed if the buffer is dropped
+         * during thread teardown, in which case the words are simply freed.
+         */
+        let _ = POOL.try_with(|pool| pool.borrow_mut().release(words));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Scopes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An open scope, within which every released buffer is retained by the pool.
+/// Closing the outermost open scope, by dropping it, releases the buffers in
+/// excess of those retained outside of any scope back to the allocator.
+#[derive(Debug)]
+pub struct Scope {
+    /// Scopes refer to the pool of the thread that opened them, and so must not
+    /// be sent to other threads.
+    marker: PhantomData<*const ()>,
+}
+
+/// Opens a new scope, which stays open until the returned value is dropped.
+pub fn scope() -> Scope {
+    POOL.with(|pool| pool.borrow_mut().scopes += 1);
+
+    Scope {
+        marker: PhantomData,
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let _ = POOL.try_with(|pool| {
+            let mut pool = pool.borrow_mut();
+
+            pool.scopes -= 1;
+
+            if pool.scopes == 0 {
+                pool.trim();
+            }
+        });
+    }
+}
+
+/// Returns the number of released buffers currently retained by the pool.
+pub fn retained_buffers() -> usize {
+    POOL.with(|pool| pool.borrow().free.len())
+}
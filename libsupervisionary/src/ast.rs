@@ -0,0 +1,350 @@
+//! # Compiling terms from an abstract syntax tree
+//!
+//! Building even a modest formula through the raw ABI wrappers means issuing a
+//! host call for every one of its subterms, and threading their handles through
+//! by hand.  This module instead lets prover-space code describe a type or term
+//! as an ordinary Rust value, an abstract syntax tree, and then *compile* it,
+//! registering every subterm with the kernel in turn and returning the handle of
+//! the whole.
+//!
+//! A `Compiler` remembers the handle of every type and term that it has
+//! registered, so that a subterm occurring many times, within one tree or
+//! across many trees compiled with the same `Compiler`, is registered only
+//! once.  The kernel shares structurally-equal objects anyway, so this saves
+//! host calls rather than kernel memory.  Objects registered by other means may
+//! be embedded in a tree by their handle.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{
+    _type::{
+        type_register_combination, type_register_function,
+        type_register_variable, PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    tags,
+    term::{
+        term_register_application, term_register_conjunction,
+        term_register_constant, term_register_disjunction,
+        term_register_equality, term_register_exists, term_register_forall,
+        term_register_implication, term_register_lambda,
+        term_register_negation, term_register_variable,
+        PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_TRUE,
+    },
+    ErrorCode, Handle, Name,
+};
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////
+// Abstract syntax.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The abstract syntax of a type.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Type {
+    /// A type already registered with the kernel.
+    Registered(Handle<tags::Type>),
+    /// The type of propositions.
+    Prop,
+    /// A type-variable.
+    Variable(Name),
+    /// A type-former applied to a list of argument types.
+    Combination(Handle<tags::TypeFormer>, Vec<Type>),
+    /// A function type, from its domain to its range.
+    Function(Box<Type>, Box<Type>),
+}
+
+impl Type {
+    /// Returns the function type from `domain` to `range`.
+    #[inline]
+    pub fn function(domain: Type, range: Type) -> Self {
+        Type::Function(Box::new(domain), Box::new(range))
+    }
+}
+
+/// The abstract syntax of a term.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Term {
+    /// A term already registered with the kernel.
+    Registered(Handle<tags::Term>),
+    /// Truth.
+    True,
+    /// Falsity.
+    False,
+    /// A variable, of the given name and type.
+    Variable(Name, Type),
+    /// A constant, with the given types substituted for the type-variables of
+    /// its type.
+    Constant(Handle<tags::Constant>, Vec<(Name, Type)>),
+    /// The application of a function to an argument.
+    Application(Box<Term>, Box<Term>),
+    /// A lambda-abstraction, binding a variable of the given name and type.
+    Lambda(Name, Type, Box<Term>),
+    /// The negation of a proposition.
+    Negation(Box<Term>),
+    /// The conjunction of two propositions.
+    Conjunction(Box<Term>, Box<Term>),
+    /// The disjunction of two propositions.
+    Disjunction(Box<Term>, Box<Term>),
+    /// The implication of the second proposition by the first.
+    Implication(Box<Term>, Box<Term>),
+    /// The equality of two terms of the same type.
+    Equality(Box<Term>, Box<Term>),
+    /// A universal quantification, binding a variable of the given name and
+    /// type, over a proposition.
+    Forall(Name, Type, Box<Term>),
+    /// An existential quantification, binding a variable of the given name and
+    /// type, over a proposition.
+    Exists(Name, Type, Box<Term>),
+}
+
+impl Term {
+    /// Returns the variable `name` of type `tau`.
+    #[inline]
+    pub fn variable(name: Name, tau: Type) -> Self {
+        Term::Variable(name, tau)
+    }
+
+    /// Returns the application of `left` to `right`.
+    #[inline]
+    pub fn application(left: Term, right: Term) -> Self {
+        Term::Application(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the lambda-abstraction of `name`, of type `tau`, over `body`.
+    #[inline]
+    pub fn lambda(name: Name, tau: Type, body: Term) -> Self {
+        Term::Lambda(name, tau, Box::new(body))
+    }
+
+    /// Returns the negation of `body`.
+    #[inline]
+    pub fn negation(body: Term) -> Self {
+        Term::Negation(Box::new(body))
+    }
+
+    /// Returns the conjunction of `left` and `right`.
+    #[inline]
+    pub fn conjunction(left: Term, right: Term) -> Self {
+        Term::Conjunction(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the disjunction of `left` and `right`.
+    #[inline]
+    pub fn disjunction(left: Term, right: Term) -> Self {
+        Term::Disjunction(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the implication of `right` by `left`.
+    #[inline]
+    pub fn implication(left: Term, right: Term) -> Self {
+        Term::Implication(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the equality of `left` and `right`.
+    #[inline]
+    pub fn equality(left: Term, right: Term) -> Self {
+        Term::Equality(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the universal quantification of `name`, of type `tau`, over
+    /// `body`.
+    #[inline]
+    pub fn forall(name: Name, tau: Type, body: Term) -> Self {
+        Term::Forall(name, tau, Box::new(body))
+    }
+
+    /// Returns the existential quantification of `name`, of type `tau`, over
+    /// `body`.
+    #[inline]
+    pub fn exists(name: Name, tau: Type, body: Term) -> Self {
+        Term::Exists(name, tau, Box::new(body))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Compilation.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compiles types and terms, remembering the handle of everything that it has
+/// registered.
+#[derive(Clone, Debug, Default)]
+pub struct Compiler {
+    /// The handles of the types registered so far.
+    types: HashMap<Type, Handle<tags::Type>>,
+    /// The handles of the terms registered so far.
+    terms: HashMap<Term, Handle<tags::Term>>,
+}
+
+impl Compiler {
+    /// Returns a new compiler, which has registered nothing.
+    #[inline]
+    pub fn new() -> Self {
+        Compiler::default()
+    }
+
+    /// Registers the type `tau`, and each of its subtypes not already
+    /// registered by this compiler, returning its handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the kernel fails to register a subtype with, e.g.
+    /// `ErrorCode::MismatchedArity` if a type-former is applied to the wrong
+    /// number of arguments.
+    pub fn compile_type(
+        &mut self,
+        tau: &Type,
+    ) -> Result<Handle<tags::Type>, ErrorCode> {
+        if let Some(handle) = self.types.get(tau) {
+            return Ok(handle.clone());
+        }
+
+        let handle = match tau {
+            Type::Registered(handle) => handle.clone(),
+            Type::Prop => PREALLOCATED_HANDLE_TYPE_PROP,
+            Type::Variable(name) => type_register_variable(*name),
+            Type::Combination(former, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.compile_type(argument))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                type_register_combination(former.clone(), arguments)?
+            }
+            Type::Function(domain, range) => {
+                let domain = self.compile_type(domain)?;
+                let range = self.compile_type(range)?;
+
+                type_register_function(domain, range)?
+            }
+        };
+
+        self.types.insert(tau.clone(), handle.clone());
+
+        Ok(handle)
+    }
+
+    /// Registers the term `term`, and each of its subterms not already
+    /// registered by this compiler, returning its handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the kernel fails to register a type or subterm
+    /// with, e.g. `ErrorCode::DomainTypeMismatch` if a function is applied to
+    /// an argument of the wrong type, or `ErrorCode::NotAProposition` if a
+    /// connective is applied to a term that is not a proposition.
+    pub fn compile(
+        &mut self,
+        term: &Term,
+    ) -> Result<Handle<tags::Term>, ErrorCode> {
+        if let Some(handle) = self.terms.get(term) {
+            return Ok(handle.clone());
+        }
+
+        let handle = match term {
+            Term::Registered(handle) => handle.clone(),
+            Term::True => PREALLOCATED_HANDLE_TERM_TRUE,
+            Term::False => PREALLOCATED_HANDLE_TERM_FALSE,
+            Term::Variable(name, tau) => {
+                term_register_variable(*name, self.compile_type(tau)?)?
+            }
+            Term::Constant(constant, substitution) => {
+                let substitution = substitution
+                    .iter()
+                    .map(|(name, tau)| Ok((*name, self.compile_type(tau)?)))
+                    .collect::<Result<Vec<_>, ErrorCode>>()?;
+
+                term_register_constant(constant.clone(), substitution)?
+            }
+            Term::Application(left, right) => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+
+                term_register_application(left, right)?
+            }
+            Term::Lambda(name, tau, body) => {
+                let tau = self.compile_type(tau)?;
+                let body = self.compile(body)?;
+
+                term_register_lambda(*name, tau, body)?
+            }
+            Term::Negation(body) => {
+                term_register_negation(self.compile(body)?)?
+            }
+            Term::Conjunction(left, right) => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+
+                term_register_conjunction(left, right)?
+            }
+            Term::Disjunction(left, right) => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+
+                term_register_disjunction(left, right)?
+            }
+            Term::Implication(left, right) => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+
+                term_register_implication(left, right)?
+            }
+            Term::Equality(left, right) => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+
+                term_register_equality(left, right)?
+            }
+            Term::Forall(name, tau, body) => {
+                let tau = self.compile_type(tau)?;
+                let body = self.compile(body)?;
+
+                term_register_forall(*name, tau, body)?
+            }
+            Term::Exists(name, tau, body) => {
+                let tau = self.compile_type(tau)?;
+                let body = self.compile(body)?;
+
+                term_register_exists(*name, tau, body)?
+            }
+        };
+
+        self.terms.insert(term.clone(), handle.clone());
+
+        Ok(handle)
+    }
+
+    /// Forgets the handles of everything registered so far, e.g. after the
+    /// kernel has been reset, or a speculative branch discarded, so that the
+    /// handles may no longer be valid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.types.clear();
+        self.terms.clear();
+    }
+}
+
+/// Registers the type `tau`, as `Compiler::compile_type`, with a new compiler.
+#[inline]
+pub fn compile_type(tau: &Type) -> Result<Handle<tags::Type>, ErrorCode> {
+    Compiler::new().compile_type(tau)
+}
+
+/// Registers the term `term`, as `Compiler::compile`, with a new compiler.
+/// Subterms occurring many times within `term` are still registered only once.
+#[inline]
+pub fn compile(term: &Term) -> Result<Handle<tags::Term>, ErrorCode> {
+    Compiler::new().compile(term)
+}
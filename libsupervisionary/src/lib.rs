@@ -19,7 +19,10 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
-#![feature(const_fn_trait_bound)]
-
+pub mod alloc;
+pub mod ast;
 pub mod build;
 pub mod raw;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod tactic;
@@ -15,45 +15,46 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+use crate::alloc;
 use crate::raw::{tags, ErrorCode, Handle, Name, RawHandle};
-use std::{
-    collections::HashSet, convert::TryFrom, iter::FromIterator,
-    marker::PhantomData,
-};
+use std::{collections::HashSet, convert::TryFrom, marker::PhantomData};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Pre-allocated type-related handles.
 ////////////////////////////////////////////////////////////////////////////////
 
-/// A pre-allocated handle used to refer to the type-variable `A`.
-pub const PREALLOCATED_HANDLE_TYPE_ALPHA: Handle<tags::Type> =
-    Handle::new(2usize, PhantomData);
-/// A pre-allocated handle used to refer to the type-variable `B`.
-pub const PREALLOCATED_HANDLE_TYPE_BETA: Handle<tags::Type> =
-    Handle::new(3usize, PhantomData);
-/// A pre-allocated handle used to refer to the `Prop` type.
-pub const PREALLOCATED_HANDLE_TYPE_PROP: Handle<tags::Type> =
-    Handle::new(4usize, PhantomData);
-/// A pre-allocated handle used to refer to the type of unary predicates.
-pub const PREALLOCATED_HANDLE_TYPE_UNARY_PREDICATE: Handle<tags::Type> =
-    Handle::new(5usize, PhantomData);
-/// A pre-allocated handle used to refer to the type of binary predicates.
-pub const PREALLOCATED_HANDLE_TYPE_BINARY_PREDICATE: Handle<tags::Type> =
-    Handle::new(6usize, PhantomData);
-/// A pre-allocated handle used to refer to the type of unary connectives.
-pub const PREALLOCATED_HANDLE_TYPE_UNARY_CONNECTIVE: Handle<tags::Type> =
-    Handle::new(7usize, PhantomData);
-/// A pre-allocated handle used to refer to the type of binary connectives.
-pub const PREALLOCATED_HANDLE_TYPE_BINARY_CONNECTIVE: Handle<tags::Type> =
-    Handle::new(8usize, PhantomData);
-/// A pre-allocated handle used to refer to the type of polymorphic quantifiers.
-pub const PREALLOCATED_HANDLE_TYPE_QUANTIFIER: Handle<tags::Type> =
-    Handle::new(9usize, PhantomData);
+/* NB: generated by the build script from `abi-spec/preallocated-handles.txt`,
+ * which is shared with the kernel.
+ */
+include!(concat!(env!("OUT_DIR"), "/preallocated_type.rs"));
+
+/// The objects registered by a type definition: the type-former of the new
+/// type, the abstraction and representation constants, and the two theorems
+/// characterising them.
+pub type TypeDefinition = (
+    Handle<tags::TypeFormer>,
+    Handle<tags::Constant>,
+    Handle<tags::Constant>,
+    Handle<tags::Theorem>,
+    Handle<tags::Theorem>,
+);
+
+/// A freshly-instantiated type, along with the renaming of its type-variables
+/// that produced it.
+pub type FreshInstance = (Handle<tags::Type>, Vec<(Name, Name)>);
 
 ////////////////////////////////////////////////////////////////////////////////
 // ABI bindings.
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
 extern "C" {
     /// Raw ABI binding to the `Type.IsRegistered` function.
     fn __type_is_registered(handle: RawHandle) -> bool;
@@ -101,6 +102,15 @@ extern "C" {
         result_base: *mut Name,
         result_length: *mut u64,
     ) -> i32;
+    /// Raw ABI binding to the `Type.Variables.Count` function.
+    fn __type_variables_count(handle: RawHandle, result: *mut u64) -> i32;
+    /// Raw ABI binding to the `Type.Variables.Range` function.
+    fn __type_variables_range(
+        handle: RawHandle,
+        offset: u64,
+        result_base: *mut Name,
+        result_length: *mut u64,
+    ) -> i32;
     /// Raw ABI binding to the `Type.Substitute` function.
     fn __type_substitute(
         handle: RawHandle,
@@ -110,6 +120,33 @@ extern "C" {
         range_length: u64,
         result: *mut RawHandle,
     ) -> i32;
+    /// Raw ABI binding to the `Type.Generalise` function.
+    fn __type_generalise(
+        handle: RawHandle,
+        avoid_base: *const Name,
+        avoid_length: u64,
+        result: *mut RawHandle,
+        names_base: *mut Name,
+        names_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Type.InstantiateFresh` function.
+    fn __type_instantiate_fresh(
+        handle: RawHandle,
+        avoid_base: *const Name,
+        avoid_length: u64,
+        result: *mut RawHandle,
+        renaming_base: *mut Name,
+        renaming_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Type.Define` function.
+    fn __type_define(
+        witness_handle: RawHandle,
+        former: *mut RawHandle,
+        abs: *mut RawHandle,
+        rep: *mut RawHandle,
+        abs_rep: *mut RawHandle,
+        rep_abs: *mut RawHandle,
+    ) -> i32;
 }
 
 /// Returns `true` iff `handle` points-to a registered type in the kernel's
@@ -285,7 +322,7 @@ where
     let size = type_size(&handle)?;
 
     let mut type_former: u64 = 0;
-    let mut arguments = vec![0u64; size];
+    let mut arguments = alloc::buffer(size);
     let mut argument_length: u64 = 0;
 
     let status = unsafe {
@@ -441,7 +478,7 @@ where
 {
     let size = type_size(&handle)?;
 
-    let mut variables = vec![0u64; size];
+    let mut variables = alloc::buffer(size);
     let mut variables_length: u64 = 0;
 
     let status = unsafe {
@@ -455,7 +492,64 @@ where
     if status == 0 {
         variables.truncate(variables_length as usize);
 
-        Ok(HashSet::from_iter(variables))
+        Ok(variables.iter().copied().collect())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the number of type-variables of the type pointed-to by `handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_variables_count<H>(handle: H) -> Result<usize, ErrorCode>
+where
+    H: AsRef<Handle<tags::Type>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __type_variables_count(
+            *handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result as usize)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns at most `limit` type-variables of the type pointed-to by `handle`,
+/// starting from the type-variable at `offset`, in the order in which the
+/// kernel lists them, set by `kernel_set_variable_order`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_variables_range<H>(
+    handle: H,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<Name>, ErrorCode>
+where
+    H: AsRef<Handle<tags::Type>>,
+{
+    let mut variables = alloc::buffer(limit);
+    let mut variables_length: u64 = limit as u64;
+
+    let status = unsafe {
+        __type_variables_range(
+            *handle.as_ref().clone() as u64,
+            offset as u64,
+            variables.as_mut_ptr(),
+            &mut variables_length as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        variables.truncate(variables_length as usize);
+
+        Ok(variables.to_vec())
     } else {
         Err(ErrorCode::try_from(status).unwrap())
     }
@@ -469,6 +563,8 @@ where
 /// Returns `ErrorCode::NoSuchTypeRegistered` if `handle`, or any of the types
 /// appearing in the range of `substitution`, do not point-to an allocated type
 /// in the kernel's heaps.
+///
+/// Returns `ErrorCode::InvalidLength` if `substitution` is empty.
 pub fn type_substitute<H, N, T>(
     handle: H,
     substitution: Vec<(N, T)>,
@@ -502,3 +598,165 @@ where
         Err(ErrorCode::try_from(status).unwrap())
     }
 }
+
+/// Generalises the type pointed-to by `handle` over every type-variable not
+/// named in `avoid`, typically the type-variables of a typing context.  Returns
+/// the type with those type-variables renamed to the least names not in
+/// `avoid`, along with their new names, in ascending order.  Two types that
+/// differ only in the names of the type-variables generalised yield the same
+/// type, under the kernel's default variable order.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoSuchTypeRegistered` if `handle` does not point-to an
+/// allocated type in the kernel's heaps.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_generalise<H>(
+    handle: H,
+    avoid: &[Name],
+) -> Result<(Handle<tags::Type>, Vec<Name>), ErrorCode>
+where
+    H: AsRef<Handle<tags::Type>>,
+{
+    let mut names: Vec<Name> = Vec::new();
+
+    /* NB: as with `proof_object_import`, the first call may only discover the
+     * number of names, and the second call fills a buffer of the right size.
+     */
+    loop {
+        let capacity = names.len() as u64;
+        let mut length: u64 = capacity;
+        let mut result: u64 = 0;
+
+        let status = unsafe {
+            __type_generalise(
+                *handle.as_ref().clone() as u64,
+                avoid.as_ptr(),
+                avoid.len() as u64,
+                &mut result as *mut u64,
+                names.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            names.truncate(length as usize);
+
+            return Ok((Handle::new(result as usize, PhantomData), names));
+        }
+
+        names.resize(length as usize, 0);
+    }
+}
+
+/// Renames every type-variable of the type pointed-to by `handle` to a fresh
+/// name, appearing neither in `avoid` nor in the type itself, e.g. to
+/// instantiate a type scheme at a use of a polymorphic definition.  Returns
+/// the renamed type, along with the name of each type-variable of the type
+/// paired with its fresh name.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoSuchTypeRegistered` if `handle` does not point-to an
+/// allocated type in the kernel's heaps.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_instantiate_fresh<H>(
+    handle: H,
+    avoid: &[Name],
+) -> Result<FreshInstance, ErrorCode>
+where
+    H: AsRef<Handle<tags::Type>>,
+{
+    let mut words: Vec<Name> = Vec::new();
+
+    loop {
+        let capacity = words.len() as u64;
+        let mut length: u64 = capacity;
+        let mut result: u64 = 0;
+
+        let status = unsafe {
+            __type_instantiate_fresh(
+                *handle.as_ref().clone() as u64,
+                avoid.as_ptr(),
+                avoid.len() as u64,
+                &mut result as *mut u64,
+                words.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            let renaming = words[..length as usize]
+                .chunks(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect();
+
+            return Ok((Handle::new(result as usize, PhantomData), renaming));
+        }
+
+        words.resize(length as usize, 0);
+    }
+}
+
+/// Defines a new type, `τ`, in bijection with the non-empty subset of a type,
+/// `ρ`, picked out by a closed predicate, `P : ρ → Prop`, where the theorem
+/// pointed-to by `witness_handle` has the form `{} ⊢ P t`.  Returns the
+/// type-former of `τ`, the abstraction and representation constants, `abs` and
+/// `rep`, and the theorems `⊢ ∀a:τ. abs (rep a) = a` and
+/// `⊢ ∀r:ρ. P r = (rep (abs r) = r)` characterising them, in that order.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoSuchTheoremRegistered` if `witness_handle` does not
+/// point-to an allocated theorem in the kernel's heaps.
+///
+/// Returns `ErrorCode::NotAnApplication` if the conclusion of the theorem is
+/// not an application.
+///
+/// Returns `ErrorCode::ShapeMismatch` if the theorem has premisses, or if the
+/// predicate has free variables.
+pub fn type_define<H>(witness_handle: H) -> Result<TypeDefinition, ErrorCode>
+where
+    H: AsRef<Handle<tags::Theorem>>,
+{
+    let mut former: u64 = 0;
+    let mut abs: u64 = 0;
+    let mut rep: u64 = 0;
+    let mut abs_rep: u64 = 0;
+    let mut rep_abs: u64 = 0;
+
+    let status = unsafe {
+        __type_define(
+            *witness_handle.as_ref().clone() as u64,
+            &mut former as *mut u64,
+            &mut abs as *mut u64,
+            &mut rep as *mut u64,
+            &mut abs_rep as *mut u64,
+            &mut rep_abs as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(former as usize, PhantomData),
+            Handle::new(abs as usize, PhantomData),
+            Handle::new(rep as usize, PhantomData),
+            Handle::new(abs_rep as usize, PhantomData),
+            Handle::new(rep_abs as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
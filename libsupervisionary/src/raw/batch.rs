@@ -0,0 +1,277 @@
+//! # Bindings to Supervisionary's batched registration ABI
+//!
+//! A batch encodes a sequence of the kernel's registration and splitting calls
+//! as a command buffer, executed by the kernel in a single host call, so that
+//! building a large term does not cross the ABI boundary once per node.  Each
+//! command may refer to the results of earlier commands in the same batch.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::alloc;
+use crate::raw::{ErrorCode, RawHandle};
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Opcodes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The opcodes of a batch, each mirroring a registration or splitting call of
+/// the kernel.  The operands and results of each are listed in order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Opcode {
+    /// `name`, yielding a type-variable.
+    TypeRegisterVariable,
+    /// `former, arguments...`, yielding a type-combination.
+    TypeRegisterCombination,
+    /// `domain, range`, yielding a function type.
+    TypeRegisterFunction,
+    /// `name, type`, yielding a variable.
+    TermRegisterVariable,
+    /// `constant, (name, type)...`, yielding a constant at an instance of its
+    /// type.
+    TermRegisterConstant,
+    /// `left, right`, yielding an application.
+    TermRegisterApplication,
+    /// `name, type, body`, yielding a λ-abstraction.
+    TermRegisterLambda,
+    /// `body`, yielding a negation.
+    TermRegisterNegation,
+    /// `left, right`, yielding a conjunction.
+    TermRegisterConjunction,
+    /// `left, right`, yielding a disjunction.
+    TermRegisterDisjunction,
+    /// `left, right`, yielding an implication.
+    TermRegisterImplication,
+    /// `left, right`, yielding an equality.
+    TermRegisterEquality,
+    /// `name, type, body`, yielding a universal quantification.
+    TermRegisterForall,
+    /// `name, type, body`, yielding an existential quantification.
+    TermRegisterExists,
+    /// `type`, yielding its domain and range.
+    TypeSplitFunction,
+    /// `term`, yielding its name and type.
+    TermSplitVariable,
+    /// `term`, yielding its constant and type.
+    TermSplitConstant,
+    /// `term`, yielding its left and right subterms.
+    TermSplitApplication,
+    /// `term`, yielding its name, type and body.
+    TermSplitLambda,
+    /// `term`, yielding its type.
+    TermTypeInfer,
+}
+
+impl Opcode {
+    /// Returns the number of operands that precede the variable part of the
+    /// operands of the opcode, or `None` if it takes a fixed number.
+    fn variable_after(self) -> Option<usize> {
+        match self {
+            Opcode::TypeRegisterCombination | Opcode::TermRegisterConstant => {
+                Some(1)
+            }
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the number of results yielded by the opcode.
+    fn results(self) -> usize {
+        match self {
+            Opcode::TypeSplitFunction
+            | Opcode::TermSplitVariable
+            | Opcode::TermSplitConstant
+            | Opcode::TermSplitApplication => 2,
+            Opcode::TermSplitLambda => 3,
+            _otherwise => 1,
+        }
+    }
+}
+
+impl From<Opcode> for u64 {
+    fn from(opcode: Opcode) -> u64 {
+        match opcode {
+            Opcode::TypeRegisterVariable => 0,
+            Opcode::TypeRegisterCombination => 1,
+            Opcode::TypeRegisterFunction => 2,
+            Opcode::TermRegisterVariable => 3,
+            Opcode::TermRegisterConstant => 4,
+            Opcode::TermRegisterApplication => 5,
+            Opcode::TermRegisterLambda => 6,
+            Opcode::TermRegisterNegation => 7,
+            Opcode::TermRegisterConjunction => 8,
+            Opcode::TermRegisterDisjunction => 9,
+            Opcode::TermRegisterImplication => 10,
+            Opcode::TermRegisterEquality => 11,
+            Opcode::TermRegisterForall => 12,
+            Opcode::TermRegisterExists => 13,
+            Opcode::TypeSplitFunction => 14,
+            Opcode::TermSplitVariable => 15,
+            Opcode::TermSplitConstant => 16,
+            Opcode::TermSplitApplication => 17,
+            Opcode::TermSplitLambda => 18,
+            Opcode::TermTypeInfer => 19,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Building batches.
+////////////////////////////////////////////////////////////////////////////////
+
+/// An operand of a command: either a literal handle or name, or the result of
+/// an earlier command of the same batch, numbered from zero across the batch.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operand {
+    /// A literal handle or name.
+    Literal(u64),
+    /// The result with the given index.
+    Result(usize),
+}
+
+/// A batch of commands under construction, encoded as a command buffer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Batch {
+    /// The encoded commands.
+    bytes: Vec<u8>,
+    /// The number of results yielded by the commands so far.
+    results: usize,
+}
+
+impl Batch {
+    /// Returns a new, empty, batch.
+    #[inline]
+    pub fn new() -> Self {
+        Batch::default()
+    }
+
+    /// Appends a command to the batch, with opcode `opcode` and operands
+    /// `operands`, and returns the index of its first result, with any further
+    /// results following it.  For a type-combination or constant, `operands`
+    /// is the type-former or constant followed by its arguments or the pairs
+    /// of its type-substitution, respectively, without a count.
+    ///
+    /// The kernel rejects the whole batch with `ErrorCode::MalformedBatch` if
+    /// `operands` has the wrong length for `opcode`, or if it refers to a
+    /// result not yielded by an earlier command.
+    pub fn push(&mut self, opcode: Opcode, operands: &[Operand]) -> usize {
+        write_varint(&mut self.bytes, u64::from(opcode));
+
+        for (index, operand) in operands.iter().enumerate() {
+            if opcode.variable_after() == Some(index) {
+                let count = operands.len() - index;
+                let count = if opcode == Opcode::TermRegisterConstant {
+                    count / 2
+                } else {
+                    count
+                };
+
+                write_varint(&mut self.bytes, count as u64);
+            }
+
+            let encoded = match operand {
+                Operand::Literal(value) => 2 * value,
+                Operand::Result(index) => 2 * *index as u64 + 1,
+            };
+
+            write_varint(&mut self.bytes, encoded);
+        }
+
+        if opcode.variable_after() == Some(operands.len()) {
+            write_varint(&mut self.bytes, 0);
+        }
+
+        let first = self.results;
+
+        self.results += opcode.results();
+
+        first
+    }
+
+    /// Returns the number of results yielded by the commands of the batch.
+    #[inline]
+    pub fn results(&self) -> usize {
+        self.results
+    }
+}
+
+/// Appends `value` to `buffer` as an unsigned LEB128 varint.
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+
+    buffer.push(value as u8);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `__kernel_batch_execute` function.
+    fn __kernel_batch_execute(
+        commands_base: *const u8,
+        commands_length: u64,
+        result_base: *mut RawHandle,
+        result_length: *mut u64,
+    ) -> i32;
+}
+
+/// Executes the commands of `batch` in order, in a single host call, and
+/// returns the results yielded by every command, in order.  Results are
+/// handles or names, depending on the command, and are returned raw.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::MalformedBatch)` if the batch is malformed, in
+/// which case nothing is executed.  Otherwise, returns the error of the first
+/// command that fails, after which no further command is executed, though the
+/// objects registered by earlier commands remain registered.
+pub fn kernel_batch_execute(batch: &Batch) -> Result<Vec<u64>, ErrorCode> {
+    let mut results = alloc::buffer(batch.results());
+    let mut length: u64 = results.len() as u64;
+
+    /* NB: the result buffer is sized from the batch itself, so always fits,
+     * and the kernel never asks for a larger one.
+     */
+    let status = unsafe {
+        __kernel_batch_execute(
+            batch.bytes.as_ptr(),
+            batch.bytes.len() as u64,
+            results.as_mut_ptr() as *mut RawHandle,
+            &mut length as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        results.truncate(length as usize);
+
+        Ok(results.to_vec())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,80 @@
+//! # Bindings to Supervisionary's resource budget ABI
+//!
+//! The host may budget the use of a service, granting a number of copies of a
+//! resource proposition that are consumed, one at a time, as the service is
+//! used.  Budgets on the kernel's own host calls use the index of the host call
+//! as their service.  These bindings are only compiled with the `budgets`
+//! feature, and the host must support `Feature::Budgets`, which should be
+//! checked with `kernel_feature_supported` before use.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{capability::Service, tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Budget.Remaining` function.
+    fn __budget_remaining(service: u64, result: *mut u64) -> i32;
+    /// Raw ABI binding to the `Budget.Resource` function.
+    fn __budget_resource(service: u64, result: *mut RawHandle) -> i32;
+}
+
+/// Returns the number of further uses of the service `service` that remain in
+/// the budget granted to it by the host.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn budget_remaining(service: Service) -> Result<u64, ErrorCode> {
+    let mut result: u64 = 0;
+
+    let status =
+        unsafe { __budget_remaining(service, &mut result as *mut u64) };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns a handle to the resource proposition asserted by the budget granted
+/// to the service `service` by the host.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn budget_resource(
+    service: Service,
+) -> Result<Handle<tags::Term>, ErrorCode> {
+    let mut result: u64 = 0;
+
+    let status = unsafe { __budget_resource(service, &mut result as *mut u64) };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
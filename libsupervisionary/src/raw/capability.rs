@@ -0,0 +1,158 @@
+//! # Bindings to Supervisionary's challenge and capability ABI
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// Services and expiry policies.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Guarded host services are identified by a machine word, chosen by the host.
+pub type Service = u64;
+
+/// Expiry policies, describing how long a capability remains valid for after
+/// it has been minted.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Expiry {
+    /// The capability may be presented at most this many times.
+    Uses(u64),
+    /// The capability may be presented any number of times, until the host
+    /// signals that the machine state has changed.
+    UntilStateChange,
+}
+
+impl From<Expiry> for (u64, u64) {
+    fn from(expiry: Expiry) -> (u64, u64) {
+        match expiry {
+            Expiry::Uses(uses) => (0, uses),
+            Expiry::UntilStateChange => (1, 0),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Challenge.Current` function.
+    fn __challenge_current(service: u64, result: *mut RawHandle) -> i32;
+    /// Raw ABI binding to the `Challenge.Discharge` function.
+    fn __challenge_discharge(
+        service: u64,
+        theorem_handle: RawHandle,
+        expiry: u64,
+        uses: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Capability.Present` function.
+    fn __capability_present(capability_handle: RawHandle, service: u64) -> i32;
+}
+
+/// Returns a handle to the challenge proposition guarding the service
+/// `service`, as constructed by the host for the current machine state.  A
+/// theorem proving it without premisses may then be presented to
+/// `challenge_discharge`.  The proposition may change whenever the machine
+/// state does, so it should be fetched afresh before every attempt.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchChallengeRegistered)` if `service` is not
+/// guarded by any challenge.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn challenge_current(
+    service: Service,
+) -> Result<Handle<tags::Term>, ErrorCode> {
+    let mut result: u64 = 0;
+
+    let status =
+        unsafe { __challenge_current(service, &mut result as *mut u64) };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Discharges the challenge guarding the service `service` with the theorem
+/// pointed-to by `theorem`, which must prove the challenge proposition without
+/// premisses.  Returns a handle to a new capability for `service`, which
+/// remains valid per the expiry policy `expiry`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn challenge_discharge<T>(
+    service: Service,
+    theorem: T,
+    expiry: Expiry,
+) -> Result<Handle<tags::Capability>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let theorem = *theorem.as_ref().clone() as u64;
+    let (expiry, uses) = expiry.into();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __challenge_discharge(
+            service,
+            theorem,
+            expiry,
+            uses,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Presents the capability pointed-to by `capability` to the service
+/// `service`, succeeding iff the capability grants access to the service.
+/// Uses up one use of the capability, if it is limited to a number of uses.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn capability_present<T>(
+    capability: T,
+    service: Service,
+) -> Result<(), ErrorCode>
+where
+    T: AsRef<Handle<tags::Capability>>,
+{
+    let capability = *capability.as_ref().clone() as u64;
+
+    let status = unsafe { __capability_present(capability, service) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
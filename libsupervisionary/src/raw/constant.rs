@@ -15,6 +15,7 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+use crate::alloc;
 use crate::raw::{tags, ErrorCode, Handle, RawHandle};
 use std::{convert::TryFrom, marker::PhantomData};
 
@@ -22,47 +23,38 @@ use std::{convert::TryFrom, marker::PhantomData};
 // Pre-allocated constant-related handles.
 ////////////////////////////////////////////////////////////////////////////////
 
-/// A pre-allocated handle used to refer to the truth constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_TRUE: Handle<tags::Constant> =
-    Handle::new(10usize, PhantomData);
-/// A pre-allocated handle used to refer to the falsity constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_FALSE: Handle<tags::Constant> =
-    Handle::new(11usize, PhantomData);
-/// A pre-allocated handle used to refer to the negation constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_NEGATION: Handle<tags::Constant> =
-    Handle::new(12usize, PhantomData);
-/// A pre-allocated handle used to refer to the binary conjunction connective.
-pub const PREALLOCATED_HANDLE_CONSTANT_CONJUNCTION: Handle<tags::Constant> =
-    Handle::new(13usize, PhantomData);
-/// A pre-allocated handle used to refer to the binary disjunction connective.
-pub const PREALLOCATED_HANDLE_CONSTANT_DISJUNCTION: Handle<tags::Constant> =
-    Handle::new(14usize, PhantomData);
-/// A pre-allocated handle used to refer to the binary implication connective.
-pub const PREALLOCATED_HANDLE_CONSTANT_IMPLICATION: Handle<tags::Constant> =
-    Handle::new(15usize, PhantomData);
-/// A pre-allocated handle used to refer to the universal quantifier constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_FORALL: Handle<tags::Constant> =
-    Handle::new(16usize, PhantomData);
-/// A pre-allocated handle used to refer to the existential quantifier constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_EXISTS: Handle<tags::Constant> =
-    Handle::new(17usize, PhantomData);
-/// A pre-allocated handle used to refer to the equality constant.
-pub const PREALLOCATED_HANDLE_CONSTANT_EQUALITY: Handle<tags::Constant> =
-    Handle::new(18usize, PhantomData);
+/* NB: generated by the build script from `abi-spec/preallocated-handles.txt`,
+ * which is shared with the kernel.
+ */
+include!(concat!(env!("OUT_DIR"), "/preallocated_constant.rs"));
 
 ////////////////////////////////////////////////////////////////////////////////
 // ABI bindings.
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
 extern "C" {
     /// Raw ABI binding to the `__constant_is_registered` function.
     fn __constant_is_registered(handle: RawHandle) -> i32;
-    /// Raw ABI binding to the `__constant_resolve` function.
-    fn __constant_resolve(handle: RawHandle, result: *mut RawHandle) -> i32;
-    /// Raw ABI binding to the `__constant_register` function.
-    fn __constant_register(
-        type_handle: RawHandle,
-        result: *mut RawHandle,
+    /// Raw ABI binding to the `__constant_mark_constructors` function.
+    fn __constant_mark_constructors(
+        former_handle: RawHandle,
+        constructor_base: *const RawHandle,
+        constructor_length: u64,
+    ) -> i32;
+    /// Raw ABI binding to the `__constant_constructor_siblings` function.
+    fn __constant_constructor_siblings(
+        handle: RawHandle,
+        former: *mut RawHandle,
+        constructor_base: *mut RawHandle,
+        constructor_length: *mut u64,
     ) -> i32;
 }
 
@@ -79,55 +71,180 @@ where
     result == 0
 }
 
-/// Returns the registered type of the constant pointed-to by `handle`, if any,
-/// in the kernel's heaps.
+declare_hostcall! {
+    /// Returns the registered type of the constant pointed-to by `handle`, if
+    /// any, in the kernel's heaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchConstantRegistered` if `handle` does not
+    /// point-to any allocated constant in the kernel's heaps.
+    pub fn constant_resolve(handle: &Constant) -> Type = __constant_resolve;
+
+    /// Allocates a new constant in the kernel's heap with a registered type
+    /// pointed-to by `type_handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchTypeRegistered` if `type_handle` does not
+    /// point-to an allocated type in the kernel's heaps.
+    pub fn constant_register(
+        type_handle: Type,
+    ) -> Constant = __constant_register;
+
+    /// Allocates a new constant in the kernel's heap with a registered type
+    /// pointed-to by `type_handle`, as `constant_register`, entering it into
+    /// the kernel's symbol table under `name`, so that separately compiled code
+    /// may find it with `constant_lookup_by_name`.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchTypeRegistered` if `type_handle` does not
+    /// point-to an allocated type in the kernel's heaps.
+    ///
+    /// Returns `ErrorCode::NameAlreadyRegistered` if a constant is already
+    /// registered under `name`.
+    pub fn constant_register_named(
+        type_handle: Type,
+        name: &str,
+    ) -> Constant = __constant_register_named;
+
+    /// Returns the handle of the constant registered under `name` in the
+    /// kernel's symbol table.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchNameRegistered` if no constant is registered
+    /// under `name`.
+    pub fn constant_lookup_by_name(
+        name: &str,
+    ) -> Constant = __constant_lookup_by_name;
+
+    /// Defines a new constant, `c`, equal to the closed term, `t`, pointed-to
+    /// by `term_handle`, returning `c` and the definitional theorem `⊢ c = t`.
+    /// The constant is given the type of `t`, which must mention every
+    /// type-variable that `t` does.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchTermRegistered` if `term_handle` does not
+    /// point-to an allocated term in the kernel's heaps.
+    ///
+    /// Returns `ErrorCode::TermNotWellformed` if the term is not well-typed.
+    ///
+    /// Returns `ErrorCode::ShapeMismatch` if the term has free variables, or
+    /// mentions a type-variable that its type does not.
+    pub fn constant_define(
+        term_handle: Term,
+    ) -> (Constant, Theorem) = __constant_define;
+
+    /// Returns `true` iff the constant pointed-to by `handle` has been marked
+    /// as a constructor of a datatype.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn constant_is_constructor(
+        handle: &Constant,
+    ) -> bool = __constant_is_constructor;
+}
+
+/// Marks the constants `constructors` as the free constructors, in order, of
+/// the datatype whose type-former is pointed-to by `former_handle`.  Intended
+/// for use by the datatype package.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
 ///
 /// # Errors
 ///
-/// Returns `ErrorCode::NoSuchConstantRegistered` if `handle` does not point-to
-/// any allocated constant in the kernel's heaps.
-pub fn constant_resolve<H>(handle: H) -> Result<Handle<tags::Type>, ErrorCode>
+/// Returns `ErrorCode::ConstructorsAlreadyMarked` if the constructors of the
+/// datatype, or any of the constants, have already been marked.
+///
+/// Returns `ErrorCode::NotAConstructor` if the type of any constant does not
+/// construct values of the datatype.
+pub fn constant_mark_constructors<H, T>(
+    former_handle: H,
+    constructors: Vec<T>,
+) -> Result<(), ErrorCode>
 where
-    H: AsRef<Handle<tags::Constant>>,
+    H: Into<Handle<tags::TypeFormer>>,
+    T: Into<Handle<tags::Constant>>,
 {
-    let mut result: u64 = 0;
+    let constructors: Vec<u64> =
+        constructors.into_iter().map(|c| *c.into() as u64).collect();
 
     let status = unsafe {
-        __constant_resolve(
-            *handle.as_ref().clone() as u64,
-            &mut result as *mut u64,
+        __constant_mark_constructors(
+            *former_handle.into() as u64,
+            constructors.as_ptr(),
+            constructors.len() as u64,
         )
     };
 
     if status == 0 {
-        Ok(Handle::new(result as usize, PhantomData))
+        Ok(())
     } else {
         Err(ErrorCode::try_from(status).unwrap())
     }
 }
 
-/// Allocates a new constant in the kernel's heap with a registered type
-/// pointed-to by `type_handle`.
+/// Returns the type-former of the datatype constructed by the constant
+/// pointed-to by `handle`, along with every constructor of that datatype,
+/// including the constant itself, in the order in which they were marked.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
 ///
 /// # Errors
 ///
-/// Returns `ErrorCode::NoSuchTypeRegistered` if `type_handle` does not point-to
-/// an allocated type in the kernel's heaps.
-pub fn constant_register<H>(
-    type_handle: H,
-) -> Result<Handle<tags::Constant>, ErrorCode>
+/// Returns `ErrorCode::NotAConstructor` if the constant has not been marked as
+/// a constructor.
+pub fn constant_constructor_siblings<H>(
+    handle: H,
+) -> Result<(Handle<tags::TypeFormer>, Vec<Handle<tags::Constant>>), ErrorCode>
 where
-    H: Into<Handle<tags::Type>>,
+    H: AsRef<Handle<tags::Constant>>,
 {
-    let mut result: u64 = 0;
+    let handle = *handle.as_ref().clone() as u64;
+    let mut former: u64 = 0;
+    let mut constructors = alloc::buffer(0);
 
-    let status = unsafe {
-        __constant_register(*type_handle.into() as u64, &mut result as *mut u64)
-    };
+    /* NB: as with `hypotheses_resolve`, the first call only discovers the
+     * number of constructors, and the second call fills a buffer of the right
+     * size.
+     */
+    loop {
+        let capacity = constructors.len() as u64;
+        let mut length: u64 = capacity;
 
-    if status == 0 {
-        Ok(Handle::new(result as usize, PhantomData))
-    } else {
-        Err(ErrorCode::try_from(status).unwrap())
+        let status = unsafe {
+            __constant_constructor_siblings(
+                handle,
+                &mut former as *mut u64,
+                constructors.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            constructors.truncate(length as usize);
+
+            return Ok((
+                Handle::new(former as usize, PhantomData),
+                constructors
+                    .iter()
+                    .map(|c| Handle::new(*c as usize, PhantomData))
+                    .collect(),
+            ));
+        }
+
+        constructors.resize(length as usize, 0u64);
     }
 }
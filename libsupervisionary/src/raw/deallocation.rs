@@ -0,0 +1,109 @@
+//! # Bindings to Supervisionary's reference counting ABI
+//!
+//! Kernel objects are otherwise registered for the lifetime of a session, so
+//! long-running prover-space code may free the types, terms, theorems and sets
+//! of hypotheses that it no longer needs.  An object may be deleted outright,
+//! or retained and later released, whereupon it is freed once the last
+//! reference to it is released.  The kernel never frees an object that another
+//! registered object refers to, so freeing an object is always safe, though
+//! its handle dangles forever after.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Object.IncRef` function.
+    fn __object_inc_ref(handle: RawHandle) -> i32;
+    /// Raw ABI binding to the `Object.DecRef` function.
+    fn __object_dec_ref(handle: RawHandle) -> i32;
+    /// Raw ABI binding to the `Object.Delete` function.
+    fn __object_delete(handle: RawHandle) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Records a reference to the kernel object pointed-to by `handle`, which is
+/// not freed whilst any such reference remains.  Handles of any kind of kernel
+/// object may be passed.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_inc_ref<H, T>(handle: H) -> Result<(), ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+{
+    let status = unsafe { __object_inc_ref(**handle.as_ref() as u64) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Releases a reference to the kernel object pointed-to by `handle`, freeing
+/// the object if that was the last reference and nothing else refers to it.
+/// Returns `Err(ErrorCode::ObjectNotRetained)` if no reference is held.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_dec_ref<H, T>(handle: H) -> Result<(), ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+{
+    let status = unsafe { __object_dec_ref(**handle.as_ref() as u64) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Frees the kernel object pointed-to by `handle`, or returns
+/// `Err(ErrorCode::ObjectInUse)` if any reference to it is held, or anything
+/// else refers to it.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_delete<H, T>(handle: H) -> Result<(), ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+{
+    let status = unsafe { __object_delete(**handle.as_ref() as u64) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
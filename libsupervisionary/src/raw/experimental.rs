@@ -0,0 +1,575 @@
+//! # Bindings to Supervisionary's experimental sequent ABI
+//!
+//! Multi-conclusion sequents, `Γ ⊢ Δ`, derived with the rules of a classical
+//! sequent calculus.  These bindings are only compiled with the `sequents`
+//! cargo feature, and are only usable if the kernel supports
+//! `Feature::Sequents`, which should be checked with `kernel_feature_supported`
+//! before any of them is called.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::alloc;
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Sequent.IsRegistered` function.
+    fn __sequent_is_registered(sequent_handle: RawHandle) -> bool;
+    /// Raw ABI binding to the `Sequent.Split.Premisses` function.
+    fn __sequent_split_premisses(
+        sequent_handle: RawHandle,
+        premisses_base: *mut RawHandle,
+        premisses_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Split.Conclusions` function.
+    fn __sequent_split_conclusions(
+        sequent_handle: RawHandle,
+        conclusions_base: *mut RawHandle,
+        conclusions_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.Axiom` function.
+    fn __sequent_register_axiom(
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.Theorem` function.
+    fn __sequent_register_theorem(
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.WeakenLeft` function.
+    fn __sequent_register_weaken_left(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.WeakenRight` function.
+    fn __sequent_register_weaken_right(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.Cut` function.
+    fn __sequent_register_cut(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.NegationLeft` function.
+    fn __sequent_register_negation_left(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.NegationRight` function.
+    fn __sequent_register_negation_right(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.ConjunctionLeft` function.
+    fn __sequent_register_conjunction_left(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.ConjunctionRight` function.
+    fn __sequent_register_conjunction_right(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.DisjunctionLeft` function.
+    fn __sequent_register_disjunction_left(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.DisjunctionRight` function.
+    fn __sequent_register_disjunction_right(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.ImplicationLeft` function.
+    fn __sequent_register_implication_left(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Sequent.Register.ImplicationRight` function.
+    fn __sequent_register_implication_right(
+        sequent_handle: RawHandle,
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+}
+
+/// Returns `true` iff `sequent_handle` points-to a registered sequent.
+#[inline]
+pub fn sequent_is_registered<T>(sequent_handle: T) -> bool
+where
+    T: Into<Handle<tags::Sequent>>,
+{
+    unsafe { __sequent_is_registered(*sequent_handle.into() as u64) }
+}
+
+/// Returns the premisses of the sequent pointed-to by `sequent_handle`, in ascending
+/// order.
+pub fn sequent_split_premisses<T>(
+    sequent_handle: T,
+) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+where
+    T: Into<Handle<tags::Sequent>>,
+{
+    let handle = *sequent_handle.into() as u64;
+    let mut premisses = alloc::buffer(0);
+
+    /* NB: as for `hypotheses_resolve`, the first call only discovers the number
+     * of premisses, and the second call fills a buffer of the right size.
+     */
+    loop {
+        let capacity = premisses.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __sequent_split_premisses(
+                handle,
+                premisses.as_mut_ptr() as *mut u64,
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            premisses.truncate(length as usize);
+
+            return Ok(premisses
+                .iter()
+                .map(|h| Handle::new(*h as usize, PhantomData))
+                .collect());
+        }
+
+        premisses.resize(length as usize, 0u64);
+    }
+}
+
+/// Returns the conclusions of the sequent pointed-to by `sequent_handle`, in ascending
+/// order.
+pub fn sequent_split_conclusions<T>(
+    sequent_handle: T,
+) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+where
+    T: Into<Handle<tags::Sequent>>,
+{
+    let handle = *sequent_handle.into() as u64;
+    let mut conclusions = alloc::buffer(0);
+
+    /* NB: as for `hypotheses_resolve`, the first call only discovers the number
+     * of conclusions, and the second call fills a buffer of the right size.
+     */
+    loop {
+        let capacity = conclusions.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __sequent_split_conclusions(
+                handle,
+                conclusions.as_mut_ptr() as *mut u64,
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            conclusions.truncate(length as usize);
+
+            return Ok(conclusions
+                .iter()
+                .map(|h| Handle::new(*h as usize, PhantomData))
+                .collect());
+        }
+
+        conclusions.resize(length as usize, 0u64);
+    }
+}
+
+/// Derives the axiom `p ⊢ p`, where `p` is `term_handle`.
+pub fn sequent_register_axiom<T>(
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_axiom(
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Lifts the theorem `Γ ⊢ φ`, pointed-to by `theorem_handle`, into the
+/// single-conclusion sequent `Γ ⊢ φ`.
+pub fn sequent_register_theorem<T>(
+    theorem_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    T: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_theorem(
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `p, Γ ⊢ Δ` from `Γ ⊢ Δ`, where `p` is `term_handle`.
+pub fn sequent_register_weaken_left<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_weaken_left(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ ⊢ Δ, p` from `Γ ⊢ Δ`, where `p` is `term_handle`.
+pub fn sequent_register_weaken_right<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_weaken_right(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ, Γ' ⊢ Δ, Δ'` from `Γ ⊢ Δ, p`, `left_handle`, and `p, Γ' ⊢ Δ'`, `right_handle`, where `p` is `term_handle`.
+pub fn sequent_register_cut<L, R, T>(
+    left_handle: L,
+    right_handle: R,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    L: Into<Handle<tags::Sequent>>,
+    R: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_cut(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `¬p, Γ ⊢ Δ` from `Γ ⊢ Δ, p`, where `p` is `term_handle`.
+pub fn sequent_register_negation_left<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_negation_left(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ ⊢ Δ, ¬p` from `p, Γ ⊢ Δ`, where `p` is `term_handle`.
+pub fn sequent_register_negation_right<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_negation_right(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `p ∧ q, Γ ⊢ Δ` from `p, q, Γ ⊢ Δ`, where `p ∧ q` is `term_handle`.
+pub fn sequent_register_conjunction_left<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_conjunction_left(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ, Γ' ⊢ Δ, Δ', p ∧ q` from `Γ ⊢ Δ, p`, `left_handle`, and `Γ' ⊢ Δ', q`, `right_handle`, where `p ∧ q` is `term_handle`.
+pub fn sequent_register_conjunction_right<L, R, T>(
+    left_handle: L,
+    right_handle: R,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    L: Into<Handle<tags::Sequent>>,
+    R: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_conjunction_right(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `p ∨ q, Γ, Γ' ⊢ Δ, Δ'` from `p, Γ ⊢ Δ`, `left_handle`, and `q, Γ' ⊢ Δ'`, `right_handle`, where `p ∨ q` is `term_handle`.
+pub fn sequent_register_disjunction_left<L, R, T>(
+    left_handle: L,
+    right_handle: R,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    L: Into<Handle<tags::Sequent>>,
+    R: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_disjunction_left(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ ⊢ Δ, p ∨ q` from `Γ ⊢ Δ, p, q`, where `p ∨ q` is `term_handle`.
+pub fn sequent_register_disjunction_right<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_disjunction_right(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `p ⇒ q, Γ, Γ' ⊢ Δ, Δ'` from `Γ ⊢ Δ, p`, `left_handle`, and `q, Γ' ⊢ Δ'`, `right_handle`, where `p ⇒ q` is `term_handle`.
+pub fn sequent_register_implication_left<L, R, T>(
+    left_handle: L,
+    right_handle: R,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    L: Into<Handle<tags::Sequent>>,
+    R: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_implication_left(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ ⊢ Δ, p ⇒ q` from `p, Γ ⊢ Δ, q`, where `p ⇒ q` is `term_handle`.
+pub fn sequent_register_implication_right<S, T>(
+    sequent_handle: S,
+    term_handle: T,
+) -> Result<Handle<tags::Sequent>, ErrorCode>
+where
+    S: Into<Handle<tags::Sequent>>,
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __sequent_register_implication_right(
+            *sequent_handle.into() as u64,
+            *term_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
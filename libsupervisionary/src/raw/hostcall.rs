@@ -0,0 +1,314 @@
+//! # Declaring host calls
+//!
+//! Most bindings to the kernel's ABI have one of a handful of shapes: handles,
+//! and perhaps a string, are passed in by value, the kernel writes its results
+//! through output pointers, and a non-zero status is decoded into an
+//! `ErrorCode`.  Rather than writing out the raw `extern` declaration, the
+//! pointer setup, and the error decoding by hand for each of them, a binding of
+//! such a shape is declared with `declare_hostcall!`, from which all of these
+//! are generated.  Bindings with a shape of their own, such as those that pass
+//! a buffer of handles in, are still written by hand.
+//!
+//! A binding is declared as a function, with the raw host call that it wraps
+//! named after an `=`:
+//!
+//! ```ignore
+//! declare_hostcall! {
+//!     /// Returns the registered type of the constant pointed-to by `handle`.
+//!     pub fn constant_resolve(handle: &Constant) -> Type = __constant_resolve;
+//! }
+//! ```
+//!
+//! Each argument is one of:
+//!
+//! - `name: &str`, passed to the kernel as a pointer and a length,
+//! - `handle: &Tag`, any `AsRef<Handle<tags::Tag>>`, passed as a raw handle,
+//! - `handle: Tag`, any `Into<Handle<tags::Tag>>`, passed as a raw handle,
+//!
+//! and the result, which the function returns wrapped in a `Result` along with
+//! the decoded error code, is one of:
+//!
+//! - `()`, where the kernel writes nothing,
+//! - `bool`, written by the kernel through a `u32` pointer,
+//! - `Tag`, a handle written through a `u64` pointer,
+//! - `(Tag, Tag)`, a pair of handles written through two `u64` pointers,
+//! - `[Tag]`, a vector of handles, written into a buffer through a base pointer
+//!   and a length pointer.  As with every such call, the length pointer is read
+//!   by the kernel for the capacity of the buffer and overwritten with the
+//!   number of handles, and if they do not fit then the call is repeated with a
+//!   buffer of the right size.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+/// Declares bindings to host calls, generating the raw `extern` declaration and
+/// a safe wrapper around it from the description of each.  See the module
+/// documentation for the shapes of binding that may be declared.
+macro_rules! declare_hostcall {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($args:tt)*) -> $ret:tt = $raw:ident;
+    )*) => {
+        $(
+            declare_hostcall!(
+                @arguments [$(#[$meta])*] [$vis] $name $raw $ret [] [] []
+                $($args)*
+            );
+        )*
+    };
+
+    /* NB: the arguments are munched one at a time, accumulating the parameters
+     * of the wrapper, the parameters of the raw declaration, and the arguments
+     * passed to the raw call, as a string argument becomes two of each of the
+     * latter.
+     */
+    (@arguments $meta:tt $vis:tt $name:ident $raw:ident $ret:tt
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+        $argument:ident: &str $(, $($rest:tt)*)?
+    ) => {
+        declare_hostcall!(
+            @arguments $meta $vis $name $raw $ret
+            [$($signature)* $argument: &str,]
+            [$($parameters)* _: *const u8, _: u64,]
+            [$($arguments)* $argument.as_ptr(), $argument.len() as u64,]
+            $($($rest)*)?
+        );
+    };
+    (@arguments $meta:tt $vis:tt $name:ident $raw:ident $ret:tt
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+        $argument:ident: &$tag:ident $(, $($rest:tt)*)?
+    ) => {
+        declare_hostcall!(
+            @arguments $meta $vis $name $raw $ret
+            [
+                $($signature)*
+                $argument: impl AsRef<
+                    $crate::raw::Handle<$crate::raw::tags::$tag>
+                >,
+            ]
+            [$($parameters)* _: $crate::raw::RawHandle,]
+            [$($arguments)* *$argument.as_ref().clone() as u64,]
+            $($($rest)*)?
+        );
+    };
+    (@arguments $meta:tt $vis:tt $name:ident $raw:ident $ret:tt
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+        $argument:ident: $tag:ident $(, $($rest:tt)*)?
+    ) => {
+        declare_hostcall!(
+            @arguments $meta $vis $name $raw $ret
+            [
+                $($signature)*
+                $argument: impl Into<
+                    $crate::raw::Handle<$crate::raw::tags::$tag>
+                >,
+            ]
+            [$($parameters)* _: $crate::raw::RawHandle,]
+            [$($arguments)* *$argument.into() as u64,]
+            $($($rest)*)?
+        );
+    };
+    (@arguments $meta:tt $vis:tt $name:ident $raw:ident $ret:tt
+        $signature:tt $parameters:tt $arguments:tt
+    ) => {
+        declare_hostcall!(
+            @result $meta $vis $name $raw $ret $signature $parameters $arguments
+        );
+    };
+
+    (@result [$($meta:tt)*] [$vis:vis] $name:ident $raw:ident ()
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+    ) => {
+        declare_hostcall!(@extern $raw [$($parameters)*]);
+
+        $($meta)*
+        $vis fn $name($($signature)*) -> Result<(), $crate::raw::ErrorCode> {
+            let status = unsafe { $raw($($arguments)*) };
+
+            declare_hostcall!(@status status, ())
+        }
+    };
+    (@result [$($meta:tt)*] [$vis:vis] $name:ident $raw:ident bool
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+    ) => {
+        declare_hostcall!(@extern $raw [$($parameters)* _: *mut u32,]);
+
+        $($meta)*
+        $vis fn $name(
+            $($signature)*
+        ) -> Result<bool, $crate::raw::ErrorCode> {
+            let mut result: u32 = 0;
+
+            let status =
+                unsafe { $raw($($arguments)* &mut result as *mut u32) };
+
+            declare_hostcall!(@status status, result != 0)
+        }
+    };
+    (@result [$($meta:tt)*] [$vis:vis] $name:ident $raw:ident [$tag:ident]
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+    ) => {
+        declare_hostcall!(
+            @extern $raw
+            [$($parameters)* _: *mut $crate::raw::RawHandle, _: *mut u64,]
+        );
+
+        $($meta)*
+        $vis fn $name(
+            $($signature)*
+        ) -> Result<
+            Vec<$crate::raw::Handle<$crate::raw::tags::$tag>>,
+            $crate::raw::ErrorCode,
+        > {
+            let mut handles = $crate::alloc::buffer(0);
+
+            /* NB: the first call only discovers the number of handles, as
+             * nothing fits in an empty buffer.  The second call then fills a
+             * buffer of the right size.
+             */
+            loop {
+                let capacity = handles.len() as u64;
+                let mut length: u64 = capacity;
+
+                let status = unsafe {
+                    $raw(
+                        $($arguments)*
+                        handles.as_mut_ptr() as *mut u64,
+                        &mut length as *mut u64,
+                    )
+                };
+
+                if status != 0 {
+                    return Err(declare_hostcall!(@error status));
+                }
+
+                if length <= capacity {
+                    handles.truncate(length as usize);
+
+                    return Ok(handles
+                        .iter()
+                        .map(|h| declare_hostcall!(@handle *h))
+                        .collect());
+                }
+
+                handles.resize(length as usize, 0u64);
+            }
+        }
+    };
+    (@result [$($meta:tt)*] [$vis:vis] $name:ident $raw:ident
+        ($left:ident, $right:ident)
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+    ) => {
+        declare_hostcall!(
+            @extern $raw
+            [
+                $($parameters)*
+                _: *mut $crate::raw::RawHandle,
+                _: *mut $crate::raw::RawHandle,
+            ]
+        );
+
+        $($meta)*
+        $vis fn $name(
+            $($signature)*
+        ) -> Result<
+            (
+                $crate::raw::Handle<$crate::raw::tags::$left>,
+                $crate::raw::Handle<$crate::raw::tags::$right>,
+            ),
+            $crate::raw::ErrorCode,
+        > {
+            let mut left: u64 = 0;
+            let mut right: u64 = 0;
+
+            let status = unsafe {
+                $raw(
+                    $($arguments)*
+                    &mut left as *mut u64,
+                    &mut right as *mut u64,
+                )
+            };
+
+            declare_hostcall!(
+                @status status,
+                (
+                    declare_hostcall!(@handle left),
+                    declare_hostcall!(@handle right),
+                )
+            )
+        }
+    };
+    (@result [$($meta:tt)*] [$vis:vis] $name:ident $raw:ident $tag:ident
+        [$($signature:tt)*] [$($parameters:tt)*] [$($arguments:tt)*]
+    ) => {
+        declare_hostcall!(
+            @extern $raw [$($parameters)* _: *mut $crate::raw::RawHandle,]
+        );
+
+        $($meta)*
+        $vis fn $name(
+            $($signature)*
+        ) -> Result<
+            $crate::raw::Handle<$crate::raw::tags::$tag>,
+            $crate::raw::ErrorCode,
+        > {
+            let mut result: u64 = 0;
+
+            let status =
+                unsafe { $raw($($arguments)* &mut result as *mut u64) };
+
+            declare_hostcall!(@status status, declare_hostcall!(@handle result))
+        }
+    };
+
+    (@extern $raw:ident [$($parameters:tt)*]) => {
+        #[cfg_attr(
+            feature = "wasi-imports",
+            link(wasm_import_module = "supervisionary:kernel/abi")
+        )]
+        #[cfg_attr(
+            all(
+                feature = "supervisionary-imports",
+                not(feature = "wasi-imports")
+            ),
+            link(wasm_import_module = "supervisionary")
+        )]
+        extern "C" {
+            #[doc = concat!(
+                "Raw ABI binding to the `", stringify!($raw), "` function."
+            )]
+            fn $raw($($parameters)*) -> i32;
+        }
+    };
+
+    (@status $status:ident, $result:expr) => {
+        if $status == 0 {
+            Ok($result)
+        } else {
+            Err(declare_hostcall!(@error $status))
+        }
+    };
+
+    (@error $status:ident) => {
+        <$crate::raw::ErrorCode as ::std::convert::TryFrom<i32>>::try_from(
+            $status,
+        )
+        .unwrap()
+    };
+
+    (@handle $raw:expr) => {
+        $crate::raw::Handle::new($raw as usize, ::std::marker::PhantomData)
+    };
+}
@@ -0,0 +1,81 @@
+//! # Bindings to Supervisionary's hypotheses ABI
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Hypotheses.Intern` function.
+    fn __hypotheses_intern(
+        hypotheses_base: *const RawHandle,
+        hypotheses_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+}
+
+/// Interns the set of hypotheses, `hypotheses`, returning a handle to the
+/// interned set.  Interning the same set of hypotheses twice, in any order,
+/// returns the same handle, so sets of hypotheses can be compared for equality
+/// by comparing their handles.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn hypotheses_intern<T>(
+    hypotheses: Vec<T>,
+) -> Result<Handle<tags::Hypotheses>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let hypotheses: Vec<u64> =
+        hypotheses.into_iter().map(|h| *h.into() as u64).collect();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __hypotheses_intern(
+            hypotheses.as_ptr(),
+            hypotheses.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+declare_hostcall! {
+    /// Returns the interned set of hypotheses pointed-to by `handle`, in
+    /// ascending order.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn hypotheses_resolve(
+        handle: &Hypotheses,
+    ) -> [Term] = __hypotheses_resolve;
+}
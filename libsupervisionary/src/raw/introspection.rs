@@ -0,0 +1,114 @@
+//! # Bindings to Supervisionary's introspection ABI
+//!
+//! Challenges are constructed by the host from the machine state.  To
+//! experiment with challenges that depend upon the guest's own execution, a
+//! research guest may ask the host for a few facts about it: how many times
+//! each host call has been made, the fuel consumed so far, counted as one unit
+//! per host call, and the size of the guest's heap.  The host decides which of
+//! these facts to reveal, and refuses the others with
+//! `ErrorCode::IntrospectionRefused`.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::ErrorCode;
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Introspect.CallCount` function.
+    fn __introspect_call_count(
+        name_base: *const u8,
+        name_length: u64,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Introspect.FuelConsumed` function.
+    fn __introspect_fuel_consumed(result: *mut u64) -> i32;
+    /// Raw ABI binding to the `Introspect.HeapSize` function.
+    fn __introspect_heap_size(result: *mut u64) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the number of times that the guest has made the host call imported
+/// as `name`, e.g. `__term_register_variable`, including this call if `name`
+/// is `__introspect_call_count`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn introspect_call_count<T>(name: T) -> Result<u64, ErrorCode>
+where
+    T: AsRef<str>,
+{
+    let name = name.as_ref();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __introspect_call_count(
+            name.as_ptr(),
+            name.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the fuel consumed by the guest so far, one unit for every host
+/// call made, including this one.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn introspect_fuel_consumed() -> Result<u64, ErrorCode> {
+    let mut result: u64 = 0;
+
+    let status = unsafe { __introspect_fuel_consumed(&mut result as *mut u64) };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the size of the guest's heap, in bytes.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn introspect_heap_size() -> Result<u64, ErrorCode> {
+    let mut result: u64 = 0;
+
+    let status = unsafe { __introspect_heap_size(&mut result as *mut u64) };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,471 @@
+//! # Bindings to Supervisionary's kernel-wide ABI
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::alloc;
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// Kinds of kernel object.
+////////////////////////////////////////////////////////////////////////////////
+
+/* NB: the kinds of kernel object are shared with the kernel through the
+ * `supervisionary-handle` crate, along with their encoding.
+ */
+pub use supervisionary_handle::Kind;
+
+/// The value written to the next-cursor pointer by `Kernel.Enumerate` when
+/// there are no more objects left to enumerate.
+const ENUMERATION_COMPLETE: u64 = u64::MAX;
+
+////////////////////////////////////////////////////////////////////////////////
+// Optional kernel features.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The optional features of the kernel, which must be negotiated before use.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Feature {
+    /// Multi-conclusion sequents, bound in the `experimental` module.
+    Sequents,
+    /// Linear resource budgets on host services, bound in the `budget` module.
+    Budgets,
+    /// The linear arithmetic decision procedure, bound in the `linear_arith`
+    /// module.
+    LinearArith,
+}
+
+impl From<Feature> for u64 {
+    fn from(feature: Feature) -> u64 {
+        match feature {
+            Feature::Sequents => 0,
+            Feature::Budgets => 1,
+            Feature::LinearArith => 2,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Variable orders.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The order in which the kernel lists the variables of a type or term, each
+/// exactly once, when enumerating them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VariableOrder {
+    /// The order in which the variables first occur, reading the type or term
+    /// from left to right.  This is the default.
+    FirstOccurrence,
+    /// Ascending order of name, with variables sharing a name in the order in
+    /// which they first occur.
+    ByName,
+}
+
+impl From<VariableOrder> for u64 {
+    fn from(order: VariableOrder) -> u64 {
+        match order {
+            VariableOrder::FirstOccurrence => 0,
+            VariableOrder::ByName => 1,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Kernel identities.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The identity of the kernel that the program is executing under.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Identity {
+    /// The version of the kernel crate.
+    pub version: String,
+    /// The revision of the source tree that the kernel was built from.
+    pub build_hash: String,
+    /// An identifier for the logic implemented by the kernel.
+    pub logic: String,
+    /// A fingerprint of the kernel's bootstrap theory.
+    pub bootstrap_hash: u64,
+}
+
+impl Identity {
+    /// Parses an identity from the `key=value` lines passed from the kernel.
+    /// Unknown keys are ignored, so that the kernel may add new ones.
+    fn parse(identity: &str) -> Option<Identity> {
+        let mut version = None;
+        let mut build_hash = None;
+        let mut logic = None;
+        let mut bootstrap_hash = None;
+
+        for line in identity.lines() {
+            match line.split_once('=') {
+                Some(("version", value)) => version = Some(value.to_string()),
+                Some(("build", value)) => build_hash = Some(value.to_string()),
+                Some(("logic", value)) => logic = Some(value.to_string()),
+                Some(("bootstrap", value)) => {
+                    bootstrap_hash = u64::from_str_radix(value, 16).ok()
+                }
+                _otherwise => (),
+            }
+        }
+
+        Some(Identity {
+            version: version?,
+            build_hash: build_hash?,
+            logic: logic?,
+            bootstrap_hash: bootstrap_hash?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Kernel.Enumerate` function.
+    fn __kernel_enumerate(
+        kind: u64,
+        cursor: RawHandle,
+        result_base: *mut RawHandle,
+        result_length: *mut u64,
+        next_cursor: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Kernel.Identify` function.
+    fn __kernel_identify(
+        identity_base: *mut u8,
+        identity_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Kernel.ErrorDetail` function.
+    fn __kernel_error_detail(
+        detail_base: *mut u8,
+        detail_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Kernel.Feature.Supported` function.
+    fn __kernel_feature_supported(feature: u64) -> bool;
+    /// Raw ABI binding to the `ShouldAbort` function.
+    fn __should_abort() -> bool;
+    /// Raw ABI binding to the `Kernel.Reset` function.
+    fn __kernel_reset() -> i32;
+    /// Raw ABI binding to the `Kernel.SetVariableOrder` function.
+    fn __kernel_set_variable_order(order: u64) -> i32;
+    /// Raw ABI binding to the `Kernel.ExportProof` function.
+    fn __kernel_export_proof(
+        theorem: RawHandle,
+        article_base: *mut u8,
+        article_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `ErrorDetail.Last` function.
+    fn __error_detail_last(
+        call: *mut u64,
+        code: *mut u64,
+        detail_base: *mut u8,
+        detail_length: *mut u64,
+    ) -> i32;
+}
+
+/// The most recent host call made by the program that failed, as reported by
+/// `error_detail_last`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LastError {
+    /// The host-call number of the call that failed.
+    pub call: u64,
+    /// The error code with which the call failed.
+    pub code: ErrorCode,
+    /// The kernel's explanation of the failure, if it recorded one.
+    pub detail: Option<String>,
+}
+
+/// Returns at most `limit` raw handles of registered kernel objects of kind
+/// `kind`, in ascending order, starting at `cursor`.  Also returns the cursor
+/// to pass to the next call, or `None` if enumeration is complete.  Enumeration
+/// starts by passing a `cursor` of `0`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_enumerate(
+    kind: Kind,
+    cursor: RawHandle,
+    limit: usize,
+) -> Result<(Vec<RawHandle>, Option<RawHandle>), ErrorCode> {
+    let mut handles = alloc::buffer(limit);
+    let mut handle_count: u64 = limit as u64;
+    let mut next_cursor: u64 = ENUMERATION_COMPLETE;
+
+    let status = unsafe {
+        __kernel_enumerate(
+            kind.into(),
+            cursor,
+            handles.as_mut_ptr(),
+            &mut handle_count as *mut u64,
+            &mut next_cursor as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        handles.truncate(handle_count as usize);
+
+        if next_cursor == ENUMERATION_COMPLETE {
+            Ok((handles.to_vec(), None))
+        } else {
+            Ok((handles.to_vec(), Some(next_cursor)))
+        }
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the identity of the kernel that the program is executing under,
+/// for recording in proof certificates and exported proof objects.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_identify() -> Identity {
+    let mut identity: Vec<u8> = Vec::new();
+
+    /* NB: the first call only discovers the length of the identity, as nothing
+     * fits in an empty buffer.  The second call then fills a buffer of the
+     * right size.
+     */
+    loop {
+        let capacity = identity.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __kernel_identify(identity.as_mut_ptr(), &mut length as *mut u64)
+        };
+
+        assert_eq!(status, 0, "Kernel.Identify cannot fail");
+
+        if length <= capacity {
+            identity.truncate(length as usize);
+
+            let identity = String::from_utf8(identity)
+                .expect("Kernel identity is not valid UTF-8");
+
+            return Identity::parse(&identity)
+                .expect("Kernel identity is malformed");
+        }
+
+        identity.resize(length as usize, 0u8);
+    }
+}
+
+/// Returns an explanation of why the host call made immediately before failed,
+/// if the kernel recorded one.  Explanations are only recorded for failures,
+/// such as `ErrorCode::ShapeMismatch`, whose error code alone says little, and
+/// render the mismatching objects, marking where they first diverge.  They are
+/// intended for diagnostics, and are not guaranteed to be stable, or
+/// parseable.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_error_detail() -> Option<String> {
+    let mut detail: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_identify`, the first call only discovers the length
+     * of the explanation.  Neither call disturbs the explanation, as only
+     * other host calls clear it.
+     */
+    loop {
+        let capacity = detail.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __kernel_error_detail(detail.as_mut_ptr(), &mut length as *mut u64)
+        };
+
+        assert_eq!(status, 0, "Kernel.ErrorDetail cannot fail");
+
+        if length == 0 {
+            return None;
+        }
+
+        if length <= capacity {
+            detail.truncate(length as usize);
+
+            return Some(
+                String::from_utf8(detail)
+                    .expect("Kernel error detail is not valid UTF-8"),
+            );
+        }
+
+        detail.resize(length as usize, 0u8);
+    }
+}
+
+/// Returns the most recent host call made by the program that failed, or
+/// `None` if none has.  Unlike `kernel_error_detail`, the failure is reported
+/// however many host calls have succeeded since, and only ever concerns this
+/// program's own host calls, even if the host executes several programs at
+/// once.  Calls that return a boolean never fail.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn error_detail_last() -> Option<LastError> {
+    let mut call: u64 = 0;
+    let mut code: u64 = 0;
+    let mut detail: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_error_detail`, the first call only discovers the
+     * length of the explanation.  Neither call disturbs the failure reported,
+     * as `ErrorDetail.Last` is never itself recorded as failing.
+     */
+    loop {
+        let capacity = detail.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __error_detail_last(
+                &mut call as *mut u64,
+                &mut code as *mut u64,
+                detail.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status == ErrorCode::NoFailureRecorded.into() {
+            return None;
+        }
+
+        assert_eq!(status, 0, "ErrorDetail.Last cannot otherwise fail");
+
+        if length <= capacity {
+            detail.truncate(length as usize);
+
+            let code = ErrorCode::try_from(code as i32)
+                .expect("ErrorDetail.Last reported an unknown error code");
+            let detail = String::from_utf8(detail)
+                .expect("Kernel error detail is not valid UTF-8");
+
+            return Some(LastError {
+                call,
+                code,
+                detail: Some(detail).filter(|detail| !detail.is_empty()),
+            });
+        }
+
+        detail.resize(length as usize, 0u8);
+    }
+}
+
+/// Returns `true` iff the kernel that the program is executing under supports
+/// the optional feature `feature`.  Calls bound to an unsupported feature may
+/// still be imported, but fail with `ErrorCode::NoSuchFunction`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+#[inline]
+pub fn kernel_feature_supported(feature: Feature) -> bool {
+    unsafe { __kernel_feature_supported(feature.into()) }
+}
+
+/// Returns `true` iff the host has asked the program to stop, for example
+/// because the user interrupted it.  Long-running automation should poll this
+/// and wind down promptly once it returns `true`.  Programs using this call are
+/// trusted to do so, and may still make host calls whilst winding down,
+/// whereas programs that do not are stopped at their next host call.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+#[inline]
+pub fn should_abort() -> bool {
+    unsafe { __should_abort() }
+}
+
+/// Resets the kernel, discarding every kernel object registered so far, other
+/// than the kernel's preallocated objects, so that a fresh session may begin.
+/// Handles to discarded objects are never reissued, and are reported as stale
+/// with `ErrorCode::StaleHandle`.  Fails with `ErrorCode::PermissionDenied`
+/// unless the host has enabled resetting, as it is privileged.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_reset() -> Result<(), ErrorCode> {
+    let status = unsafe { __kernel_reset() };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Sets the order in which the kernel lists the type-variables of types and
+/// terms, and the free variables of terms, for the rest of the session.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_set_variable_order(
+    order: VariableOrder,
+) -> Result<(), ErrorCode> {
+    let status = unsafe { __kernel_set_variable_order(order.into()) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the proof trace of the theorem pointed-to by `theorem`, recorded
+/// whilst the host had proof recording enabled, as an OpenTheory article, so
+/// that the theorem may be audited by an external checker.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if `theorem` does not
+/// point-to a registered theorem.
+///
+/// Returns `Err(ErrorCode::NoSuchProofRegistered)` if no proof was recorded for
+/// the theorem, as proof recording was disabled when it was registered.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn kernel_export_proof<T>(theorem: T) -> Result<String, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let theorem = **theorem.as_ref() as u64;
+    let mut article: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_identify`, the first call only discovers the length
+     * of the article, and the second call fills a buffer of the right size.
+     */
+    loop {
+        let capacity = article.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __kernel_export_proof(
+                theorem,
+                article.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            article.truncate(length as usize);
+
+            return Ok(String::from_utf8(article)
+                .expect("Exported proof is not valid UTF-8"));
+        }
+
+        article.resize(length as usize, 0u8);
+    }
+}
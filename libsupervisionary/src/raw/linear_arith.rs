@@ -0,0 +1,127 @@
+//! # Bindings to Supervisionary's linear arithmetic ABI
+//!
+//! The kernel may be built with a decision procedure for linear arithmetic over
+//! the natural numbers, which registers theorems for true goals in the
+//! fragment that it decides without deriving them from the kernel's inference
+//! rules.  The procedure therefore enlarges the kernel's trusted base, and
+//! every theorem that depends on it is *tainted*, which may be checked with
+//! `theorem_is_tainted`.  These bindings are only compiled with the
+//! `linear-arith` feature, and the host must support `Feature::LinearArith`,
+//! which should be checked with `kernel_feature_supported` before use.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `LinearArith.Signature` function.
+    fn __linear_arith_signature(result: *mut RawHandle) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.LinearArith` function.
+    fn __theorem_register_linear_arith(
+        term_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The signature.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The kernel objects making up the signature of the natural numbers, as
+/// interpreted by the decision procedure.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LinearArithSignature {
+    /// The type of the natural numbers, `num`.
+    pub num: Handle<tags::Type>,
+    /// Zero, of type `num`.
+    pub zero: Handle<tags::Constant>,
+    /// The successor function, of type `num → num`.
+    pub successor: Handle<tags::Constant>,
+    /// Addition, of type `num → num → num`.
+    pub addition: Handle<tags::Constant>,
+    /// The `≤` relation, of type `num → num → Prop`.
+    pub less_equal: Handle<tags::Constant>,
+    /// The `<` relation, of type `num → num → Prop`.
+    pub less_than: Handle<tags::Constant>,
+}
+
+/// Returns the signature of the natural numbers decided by the kernel's linear
+/// arithmetic decision procedure, which the kernel registers the first time
+/// that it is requested.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn linear_arith_signature() -> Result<LinearArithSignature, ErrorCode> {
+    let mut result: [u64; 6] = [0; 6];
+
+    let status = unsafe { __linear_arith_signature(result.as_mut_ptr()) };
+
+    if status == 0 {
+        Ok(LinearArithSignature {
+            num: Handle::new(result[0] as usize, PhantomData),
+            zero: Handle::new(result[1] as usize, PhantomData),
+            successor: Handle::new(result[2] as usize, PhantomData),
+            addition: Handle::new(result[3] as usize, PhantomData),
+            less_equal: Handle::new(result[4] as usize, PhantomData),
+            less_than: Handle::new(result[5] as usize, PhantomData),
+        })
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// The decision procedure.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers the theorem `⊢ goal` if `goal` is a true statement of linear
+/// arithmetic over the natural numbers, in the fragment decided by the
+/// kernel's procedure.  The new theorem is tainted.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_linear_arith<T>(
+    goal: T,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_linear_arith(
+            *goal.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,206 @@
+//! # Bindings to Supervisionary's object-metadata ABI
+//!
+//! Prover-space libraries may attach auxiliary data, such as the priority of a
+//! simplification rule, a tag, or a source position, to any kernel object,
+//! rather than maintaining tables of their own indexed by handle.  The kernel
+//! carries the metadata of type-formers, constants, types, terms and theorems
+//! through exported state objects, and plays no part in the logic.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::convert::{TryFrom, TryInto};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Object.Metadata.Set` function.
+    fn __object_metadata_set(
+        handle: RawHandle,
+        key_base: *const u8,
+        key_length: u64,
+        value_base: *const u8,
+        value_length: u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Object.Metadata.Get` function.
+    fn __object_metadata_get(
+        handle: RawHandle,
+        key_base: *const u8,
+        key_length: u64,
+        value_base: *mut u8,
+        value_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Object.Metadata.Enumerate` function.
+    fn __object_metadata_enumerate(
+        handle: RawHandle,
+        keys_base: *mut u8,
+        keys_length: *mut u64,
+    ) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Attaches `value` to the kernel object pointed-to by `handle` under `key`,
+/// replacing any value already attached under `key`.  Handles of any kind of
+/// kernel object may be passed.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_metadata_set<H, T, K, V>(
+    handle: H,
+    key: K,
+    value: V,
+) -> Result<(), ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+    K: AsRef<str>,
+    V: AsRef<[u8]>,
+{
+    let key = key.as_ref();
+    let value = value.as_ref();
+
+    let status = unsafe {
+        __object_metadata_set(
+            **handle.as_ref() as u64,
+            key.as_ptr(),
+            key.len() as u64,
+            value.as_ptr(),
+            value.len() as u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the value attached to the kernel object pointed-to by `handle`
+/// under `key`, or `Err(ErrorCode::NoSuchMetadataKey)` if there is none.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_metadata_get<H, T, K>(
+    handle: H,
+    key: K,
+) -> Result<Vec<u8>, ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+    K: AsRef<str>,
+{
+    let handle = **handle.as_ref() as u64;
+    let key = key.as_ref();
+    let mut value: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_identify`, the first call only discovers the length
+     * of the value, and the second call fills a buffer of the right size.
+     */
+    loop {
+        let capacity = value.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __object_metadata_get(
+                handle,
+                key.as_ptr(),
+                key.len() as u64,
+                value.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            value.truncate(length as usize);
+
+            return Ok(value);
+        }
+
+        value.resize(length as usize, 0u8);
+    }
+}
+
+/// Returns the keys under which values are attached to the kernel object
+/// pointed-to by `handle`, in ascending order.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn object_metadata_enumerate<H, T>(
+    handle: H,
+) -> Result<Vec<String>, ErrorCode>
+where
+    H: AsRef<Handle<T>>,
+    T: tags::IsTag,
+{
+    let handle = **handle.as_ref() as u64;
+    let mut buffer: Vec<u8> = Vec::new();
+
+    loop {
+        let capacity = buffer.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __object_metadata_enumerate(
+                handle,
+                buffer.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            buffer.truncate(length as usize);
+            break;
+        }
+
+        buffer.resize(length as usize, 0u8);
+    }
+
+    /* NB: each key is preceded by its length in bytes, as a little-endian
+     * `u64`, and the kernel only stores valid UTF-8.
+     */
+    let mut keys = Vec::new();
+    let mut rest = buffer.as_slice();
+
+    while !rest.is_empty() {
+        let (length, tail) = rest.split_at(8);
+        let length = u64::from_le_bytes(length.try_into().unwrap()) as usize;
+        let (key, tail) = tail.split_at(length);
+
+        keys.push(String::from_utf8(key.to_vec()).unwrap());
+        rest = tail;
+    }
+
+    Ok(keys)
+}
@@ -1,5 +1,17 @@
 //! # Supervisionary system interface bindings
 //!
+//! Every host call is imported from the `env` module by default, the namespace
+//! that toolchains targeting `wasm32-unknown-unknown` use for undecorated
+//! imports.  Guests built for `wasm32-wasi`, and packaged as WASI components,
+//! instead import host calls from a namespace of their own, so with the
+//! `wasi-imports` feature enabled every host call is imported from the
+//! `supervisionary:kernel/abi` module instead.  Toolchains that place imports
+//! of their own in `env`, such as Emscripten, may instead enable the
+//! `supervisionary-imports` feature, under which every host call is imported
+//! from the dedicated `supervisionary` module.  The names of the host calls are
+//! the same in every namespace, and the host serves all of them, so guest code
+//! does not change between them.
+//!
 //! # Authors
 //!
 //! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
@@ -17,15 +29,39 @@
 
 use std::{
     convert::TryFrom,
-    fmt,
     fmt::{Display, Error as DisplayError, Formatter},
-    marker::PhantomData,
-    ops::Deref,
 };
 
+/* NB: declared first, as the macros of `hostcall` are only in scope in the
+ * modules declared after it.
+ */
+#[macro_use]
+mod hostcall;
+
 pub mod _type;
+pub mod batch;
+#[cfg(feature = "budgets")]
+pub mod budget;
+pub mod capability;
 pub mod constant;
+pub mod deallocation;
+#[cfg(feature = "sequents")]
+pub mod experimental;
+pub mod hypotheses;
+pub mod introspection;
+pub mod kernel;
+#[cfg(feature = "linear-arith")]
+pub mod linear_arith;
+pub mod metadata;
+pub mod progress;
+pub mod proof;
+pub mod proof_object;
+pub mod quotient;
+pub mod scratch;
+pub mod speculate;
+pub mod substitution;
 pub mod term;
+pub mod term_view;
 pub mod theorem;
 pub mod type_former;
 
@@ -54,8 +90,15 @@ pub enum ErrorCode {
     /// exist.
     NoSuchFunction,
     /* Dangling objects. */
+    /// A handle was supplied that did not reference a registered capability.
+    NoSuchCapabilityRegistered,
     /// A handle was supplied that did not reference a registered constant.
     NoSuchConstantRegistered,
+    /// A handle was supplied that did not reference a registered set of
+    /// hypotheses.
+    NoSuchHypothesesRegistered,
+    /// A handle was supplied that did not reference a registered substitution.
+    NoSuchSubstitutionRegistered,
     /// A handle was supplied that did not reference a registered term.
     NoSuchTermRegistered,
     /// A handle was supplied that did not reference a registered theorem.
@@ -73,13 +116,33 @@ pub enum ErrorCode {
     NoSuchTypeRegistered,
     /// A type was expected to be a functional type, but it was not.
     NotAFunctionType,
+    /// A type was expected to be a product type, but it was not.
+    NotAProductType,
+    /// A type was expected to be a set type, i.e. a predicate type of the form
+    /// `A -> Prop`, but it was not.
+    NotASetType,
     /// A type was expected to be a type-combination, but it was not.
     NotATypeCombination,
     /// A type was expected to be a type-variable, but it was not.
     NotATypeVariable,
     /// A type passed to a function as an argument was not well-formed.
     TypeNotWellformed,
+    /// A type was expected to be a quotient type, registered with the
+    /// quotient type package, but it was not.
+    NotAQuotientType,
     /* -- Constant related errors. */
+    /// A constant was expected to be a constructor of a datatype, but it was not.
+    NotAConstructor,
+    /// The constructors of a datatype were marked more than once, or a constant
+    /// was marked as a constructor of more than one datatype.
+    ConstructorsAlreadyMarked,
+    /* -- Substitution related errors. */
+    /// A substitution was expected to be a substitution of types for
+    /// type-variables, but it was not.
+    NotATypeSubstitution,
+    /// A substitution was expected to be a substitution of terms for
+    /// variables, but it was not.
+    NotATermSubstitution,
     /* -- Term related errors. */
     NotAConjunction,
     /// A term passed to a function was expected to be a constant but it was
@@ -97,6 +160,9 @@ pub enum ErrorCode {
     /// A term passed to a function was expected to be an application but it was
     /// not.
     NotAnApplication,
+    /// A term passed to a function was expected to be a conditional but it was
+    /// not.
+    NotAConditional,
     /// A term passed to a function was expected to be an equality but it was
     /// not.
     NotAnEquality,
@@ -109,9 +175,20 @@ pub enum ErrorCode {
     /// A term passed to a function was expected to be a negation but it was
     /// not.
     NotANegation,
+    /// A term passed to a function was expected to be a pair but it was not.
+    NotAPair,
+    /// A term passed to a function was expected to be a set membership but it
+    /// was not.
+    NotAMembership,
+    /// A term passed to a function was expected to be a set comprehension but
+    /// it was not.
+    NotAComprehension,
     /// A term passed to a function as an argument did not have propositional
     /// type.
     NotAProposition,
+    /// A term passed to a function was expected to be a local definition but it
+    /// was not.
+    NotALet,
     /// A term passed to a function was expected to be a variable but it was
     /// not.
     NotAVariable,
@@ -123,6 +200,133 @@ pub enum ErrorCode {
     ShapeMismatch,
     /// A theorem passed to a function as an argument was not well-formed.
     TheoremNotWellformed,
+    /* -- Serialization related errors. */
+    /// A serialized proof object passed to the kernel for import was
+    /// malformed, truncated, or referenced kernel objects that do not exist.
+    MalformedProofObject,
+    /* -- Enumeration related errors. */
+    /// A value passed to a function did not encode a kind of kernel object.
+    NoSuchKind,
+    /* -- Capability related errors. */
+    /// A service was named that is not guarded by any registered challenge.
+    NoSuchChallengeRegistered,
+    /// A theorem presented to discharge a challenge did not prove the challenge
+    /// proposition, without premisses.
+    ChallengeNotDischarged,
+    /// A capability was presented after its expiry policy had lapsed.
+    CapabilityExpired,
+    /// A capability was presented to a service other than the one it was minted
+    /// for.
+    CapabilityServiceMismatch,
+    /// A value passed to a function did not encode an expiry policy.
+    NoSuchExpiryPolicy,
+    /* -- Budget related errors. */
+    /// A service was named for which the host has granted no budget.
+    NoSuchBudgetRegistered,
+    /// A service was used after every copy of its resource assertion had been
+    /// consumed.
+    BudgetExhausted,
+    /* -- Proof-term related errors. */
+    /// A handle was supplied to a function that does not point to any registered
+    /// proof term, or a theorem for which no proof term was recorded.
+    NoSuchProofRegistered,
+    /// Re-applying a recorded inference rule to its recorded arguments did not
+    /// derive the recorded theorem.
+    ProofCheckFailed,
+    /* -- Sequent-related errors. */
+    /// A handle was supplied to a function that does not point to any registered
+    /// sequent.
+    NoSuchSequentRegistered,
+    /// A formula that an inference rule acts upon does not appear on the expected
+    /// side of a sequent.
+    FormulaNotInSequent,
+    /* -- Speculation-related errors. */
+    /// An attempt was made to end a speculative branch when none was in
+    /// progress.
+    NoSpeculationInProgress,
+    /// A handle was supplied that was issued within a speculative branch that
+    /// has since been discarded, and so no longer references any kernel
+    /// object.
+    StaleHandle,
+    /* -- Host-call argument errors. */
+    /// A pointer passed to a host call was null, or pointed outside of the memory
+    /// that the host call reads or writes.
+    InvalidPointer,
+    /// A length passed to a host call, or read by it from memory, described a
+    /// buffer extending outside of memory, or was zero where a non-empty buffer
+    /// is required.
+    InvalidLength,
+    /// A string passed to a host call was not valid UTF-8.
+    InvalidString,
+    /// An integer passed to a host call, such as a handle or a length, could
+    /// not be represented on the host.
+    ArgumentOutOfRange,
+    /* -- Metadata-related errors. */
+    /// A handle was supplied that does not point-to a registered kernel object
+    /// of any kind.
+    NoSuchObjectRegistered,
+    /// No metadata is attached to a kernel object under the key supplied.
+    NoSuchMetadataKey,
+    /* -- Linear arithmetic-related errors. */
+    /// A goal was supplied to the linear arithmetic decision procedure that is
+    /// not in the fragment of arithmetic that it decides.
+    NotInLinearArithFragment,
+    /// The linear arithmetic decision procedure failed to prove a goal, either
+    /// because the goal does not hold of every natural number, or because
+    /// deciding it exceeded the procedure's limits.
+    LinearArithGoalNotProved,
+    /* -- Introspection-related errors. */
+    /// The guest asked to inspect a fact about its own machine state that the
+    /// host's introspection policy does not reveal, or that does not exist.
+    IntrospectionRefused,
+    /* -- Rule policy-related errors. */
+    /// The guest applied an inference rule that the host's rule policy does not
+    /// permit in this session.
+    PermissionDenied,
+    /* -- Retraction-related errors. */
+    /// The theorem has been retracted, along with the axiom or definition that
+    /// it depends upon, and may no longer be used.
+    TheoremRetracted,
+    /* -- Position-related errors. */
+    /// A position did not address a subterm of a term: some index along it was
+    /// not that of a child of the subterm reached so far.
+    NoSuchPosition,
+    /* -- Scratch store-related errors. */
+    /// Nothing is stored in the scratch store under the key supplied.
+    NoSuchScratchKey,
+    /* -- Error detail-related errors. */
+    /// No host call has failed in this session, since it began, so there is no
+    /// failure to report.
+    NoFailureRecorded,
+    /* -- Reference counting-related errors. */
+    /// An attempt was made to free a kernel object that is still in use: the
+    /// guest still holds a reference to it, another registered kernel object
+    /// refers to it, or it is of a kind that is never freed.
+    ObjectInUse,
+    /// An attempt was made to release a reference to a kernel object that the
+    /// guest holds no reference to.
+    ObjectNotRetained,
+    /* -- Type definition-related errors. */
+    /// A type-former was expected to be that of a type defined with the kernel's
+    /// type-definition principle, but is not.
+    NotADefinedType,
+    /* -- Premiss-related errors. */
+    /// An index did not address a premiss of a theorem, as it was not less than
+    /// the number of premisses.
+    NoSuchPremiss,
+    /* -- Symbol table-related errors. */
+    /// A name was supplied that is already registered in the kernel's symbol
+    /// table.
+    NameAlreadyRegistered,
+    /// A name was supplied that is not registered in the kernel's symbol table.
+    NoSuchNameRegistered,
+    /* -- Batch-related errors. */
+    /// A command buffer passed to `Kernel.BatchExecute` was malformed, e.g. as
+    /// it used an unknown opcode, or referred to a result not yet computed.
+    MalformedBatch,
+    /* -- Hypothesis-related errors. */
+    /// No hypothesis in a set of hypotheses matched a pattern.
+    NoMatchingHypothesis,
 }
 
 /// Pretty-printing for error codes.
@@ -168,6 +372,100 @@ impl Display for ErrorCode {
             ErrorCode::TheoremNotWellformed => {
                 write!(f, "TheoremNotWellformed")
             }
+            ErrorCode::MalformedProofObject => {
+                write!(f, "MalformedProofObject")
+            }
+            ErrorCode::NoSuchKind => write!(f, "NoSuchKind"),
+            ErrorCode::NoSuchHypothesesRegistered => {
+                write!(f, "NoSuchHypothesesRegistered")
+            }
+            ErrorCode::NotAConditional => write!(f, "NotAConditional"),
+            ErrorCode::NotALet => write!(f, "NotALet"),
+            ErrorCode::NotAProductType => write!(f, "NotAProductType"),
+            ErrorCode::NotAPair => write!(f, "NotAPair"),
+            ErrorCode::NotASetType => write!(f, "NotASetType"),
+            ErrorCode::NotAMembership => write!(f, "NotAMembership"),
+            ErrorCode::NotAComprehension => write!(f, "NotAComprehension"),
+            ErrorCode::NoSuchCapabilityRegistered => {
+                write!(f, "NoSuchCapabilityRegistered")
+            }
+            ErrorCode::NoSuchChallengeRegistered => {
+                write!(f, "NoSuchChallengeRegistered")
+            }
+            ErrorCode::ChallengeNotDischarged => {
+                write!(f, "ChallengeNotDischarged")
+            }
+            ErrorCode::CapabilityExpired => write!(f, "CapabilityExpired"),
+            ErrorCode::CapabilityServiceMismatch => {
+                write!(f, "CapabilityServiceMismatch")
+            }
+            ErrorCode::NoSuchExpiryPolicy => write!(f, "NoSuchExpiryPolicy"),
+            ErrorCode::NoSuchProofRegistered => {
+                write!(f, "NoSuchProofRegistered")
+            }
+            ErrorCode::ProofCheckFailed => write!(f, "ProofCheckFailed"),
+            ErrorCode::NoSuchSequentRegistered => {
+                write!(f, "NoSuchSequentRegistered")
+            }
+            ErrorCode::FormulaNotInSequent => write!(f, "FormulaNotInSequent"),
+            ErrorCode::NoSpeculationInProgress => {
+                write!(f, "NoSpeculationInProgress")
+            }
+            ErrorCode::InvalidPointer => write!(f, "InvalidPointer"),
+            ErrorCode::InvalidLength => write!(f, "InvalidLength"),
+            ErrorCode::InvalidString => write!(f, "InvalidString"),
+            ErrorCode::NotAConstructor => write!(f, "NotAConstructor"),
+            ErrorCode::ConstructorsAlreadyMarked => {
+                write!(f, "ConstructorsAlreadyMarked")
+            }
+            ErrorCode::NotAQuotientType => write!(f, "NotAQuotientType"),
+            ErrorCode::StaleHandle => write!(f, "StaleHandle"),
+            ErrorCode::NoSuchSubstitutionRegistered => {
+                write!(f, "NoSuchSubstitutionRegistered")
+            }
+            ErrorCode::NotATypeSubstitution => {
+                write!(f, "NotATypeSubstitution")
+            }
+            ErrorCode::NotATermSubstitution => {
+                write!(f, "NotATermSubstitution")
+            }
+            ErrorCode::NoSuchBudgetRegistered => {
+                write!(f, "NoSuchBudgetRegistered")
+            }
+            ErrorCode::BudgetExhausted => write!(f, "BudgetExhausted"),
+            ErrorCode::ArgumentOutOfRange => write!(f, "ArgumentOutOfRange"),
+            ErrorCode::NoSuchObjectRegistered => {
+                write!(f, "NoSuchObjectRegistered")
+            }
+            ErrorCode::NoSuchMetadataKey => write!(f, "NoSuchMetadataKey"),
+            ErrorCode::NotInLinearArithFragment => {
+                write!(f, "NotInLinearArithFragment")
+            }
+            ErrorCode::LinearArithGoalNotProved => {
+                write!(f, "LinearArithGoalNotProved")
+            }
+            ErrorCode::IntrospectionRefused => {
+                write!(f, "IntrospectionRefused")
+            }
+            ErrorCode::PermissionDenied => write!(f, "PermissionDenied"),
+            ErrorCode::TheoremRetracted => write!(f, "TheoremRetracted"),
+            ErrorCode::NoSuchPosition => write!(f, "NoSuchPosition"),
+            ErrorCode::NoSuchScratchKey => write!(f, "NoSuchScratchKey"),
+            ErrorCode::NoFailureRecorded => write!(f, "NoFailureRecorded"),
+            ErrorCode::ObjectInUse => write!(f, "ObjectInUse"),
+            ErrorCode::ObjectNotRetained => write!(f, "ObjectNotRetained"),
+            ErrorCode::NotADefinedType => write!(f, "NotADefinedType"),
+            ErrorCode::NoSuchPremiss => write!(f, "NoSuchPremiss"),
+            ErrorCode::NameAlreadyRegistered => {
+                write!(f, "NameAlreadyRegistered")
+            }
+            ErrorCode::NoSuchNameRegistered => {
+                write!(f, "NoSuchNameRegistered")
+            }
+            ErrorCode::MalformedBatch => write!(f, "MalformedBatch"),
+            ErrorCode::NoMatchingHypothesis => {
+                write!(f, "NoMatchingHypothesis")
+            }
         }
     }
 }
@@ -203,6 +501,58 @@ impl Into<i32> for ErrorCode {
             ErrorCode::TermNotWellformed => 25,
             ErrorCode::ShapeMismatch => 26,
             ErrorCode::TheoremNotWellformed => 27,
+            ErrorCode::MalformedProofObject => 28,
+            ErrorCode::NoSuchKind => 29,
+            ErrorCode::NoSuchHypothesesRegistered => 30,
+            ErrorCode::NotAConditional => 31,
+            ErrorCode::NotALet => 32,
+            ErrorCode::NotAProductType => 33,
+            ErrorCode::NotAPair => 34,
+            ErrorCode::NotASetType => 35,
+            ErrorCode::NotAMembership => 36,
+            ErrorCode::NotAComprehension => 37,
+            ErrorCode::NoSuchCapabilityRegistered => 38,
+            ErrorCode::NoSuchChallengeRegistered => 39,
+            ErrorCode::ChallengeNotDischarged => 40,
+            ErrorCode::CapabilityExpired => 41,
+            ErrorCode::CapabilityServiceMismatch => 42,
+            ErrorCode::NoSuchExpiryPolicy => 43,
+            ErrorCode::NoSuchProofRegistered => 44,
+            ErrorCode::ProofCheckFailed => 45,
+            ErrorCode::NoSuchSequentRegistered => 46,
+            ErrorCode::FormulaNotInSequent => 47,
+            ErrorCode::NoSpeculationInProgress => 48,
+            ErrorCode::InvalidPointer => 49,
+            ErrorCode::InvalidLength => 50,
+            ErrorCode::InvalidString => 51,
+            ErrorCode::NotAConstructor => 52,
+            ErrorCode::ConstructorsAlreadyMarked => 53,
+            ErrorCode::NotAQuotientType => 54,
+            ErrorCode::StaleHandle => 55,
+            ErrorCode::NoSuchSubstitutionRegistered => 56,
+            ErrorCode::NotATypeSubstitution => 57,
+            ErrorCode::NotATermSubstitution => 58,
+            ErrorCode::NoSuchBudgetRegistered => 59,
+            ErrorCode::BudgetExhausted => 60,
+            ErrorCode::ArgumentOutOfRange => 61,
+            ErrorCode::NoSuchObjectRegistered => 62,
+            ErrorCode::NoSuchMetadataKey => 63,
+            ErrorCode::NotInLinearArithFragment => 64,
+            ErrorCode::LinearArithGoalNotProved => 65,
+            ErrorCode::IntrospectionRefused => 66,
+            ErrorCode::PermissionDenied => 67,
+            ErrorCode::TheoremRetracted => 68,
+            ErrorCode::NoSuchPosition => 69,
+            ErrorCode::NoSuchScratchKey => 70,
+            ErrorCode::NoFailureRecorded => 71,
+            ErrorCode::ObjectInUse => 72,
+            ErrorCode::ObjectNotRetained => 73,
+            ErrorCode::NotADefinedType => 74,
+            ErrorCode::NoSuchPremiss => 75,
+            ErrorCode::NameAlreadyRegistered => 76,
+            ErrorCode::NoSuchNameRegistered => 77,
+            ErrorCode::MalformedBatch => 78,
+            ErrorCode::NoMatchingHypothesis => 79,
         }
     }
 }
@@ -239,6 +589,58 @@ impl TryFrom<i32> for ErrorCode {
             25 => Ok(ErrorCode::TermNotWellformed),
             26 => Ok(ErrorCode::ShapeMismatch),
             27 => Ok(ErrorCode::TheoremNotWellformed),
+            28 => Ok(ErrorCode::MalformedProofObject),
+            29 => Ok(ErrorCode::NoSuchKind),
+            30 => Ok(ErrorCode::NoSuchHypothesesRegistered),
+            31 => Ok(ErrorCode::NotAConditional),
+            32 => Ok(ErrorCode::NotALet),
+            33 => Ok(ErrorCode::NotAProductType),
+            34 => Ok(ErrorCode::NotAPair),
+            35 => Ok(ErrorCode::NotASetType),
+            36 => Ok(ErrorCode::NotAMembership),
+            37 => Ok(ErrorCode::NotAComprehension),
+            38 => Ok(ErrorCode::NoSuchCapabilityRegistered),
+            39 => Ok(ErrorCode::NoSuchChallengeRegistered),
+            40 => Ok(ErrorCode::ChallengeNotDischarged),
+            41 => Ok(ErrorCode::CapabilityExpired),
+            42 => Ok(ErrorCode::CapabilityServiceMismatch),
+            43 => Ok(ErrorCode::NoSuchExpiryPolicy),
+            44 => Ok(ErrorCode::NoSuchProofRegistered),
+            45 => Ok(ErrorCode::ProofCheckFailed),
+            46 => Ok(ErrorCode::NoSuchSequentRegistered),
+            47 => Ok(ErrorCode::FormulaNotInSequent),
+            48 => Ok(ErrorCode::NoSpeculationInProgress),
+            49 => Ok(ErrorCode::InvalidPointer),
+            50 => Ok(ErrorCode::InvalidLength),
+            51 => Ok(ErrorCode::InvalidString),
+            52 => Ok(ErrorCode::NotAConstructor),
+            53 => Ok(ErrorCode::ConstructorsAlreadyMarked),
+            54 => Ok(ErrorCode::NotAQuotientType),
+            55 => Ok(ErrorCode::StaleHandle),
+            56 => Ok(ErrorCode::NoSuchSubstitutionRegistered),
+            57 => Ok(ErrorCode::NotATypeSubstitution),
+            58 => Ok(ErrorCode::NotATermSubstitution),
+            59 => Ok(ErrorCode::NoSuchBudgetRegistered),
+            60 => Ok(ErrorCode::BudgetExhausted),
+            61 => Ok(ErrorCode::ArgumentOutOfRange),
+            62 => Ok(ErrorCode::NoSuchObjectRegistered),
+            63 => Ok(ErrorCode::NoSuchMetadataKey),
+            64 => Ok(ErrorCode::NotInLinearArithFragment),
+            65 => Ok(ErrorCode::LinearArithGoalNotProved),
+            66 => Ok(ErrorCode::IntrospectionRefused),
+            67 => Ok(ErrorCode::PermissionDenied),
+            68 => Ok(ErrorCode::TheoremRetracted),
+            69 => Ok(ErrorCode::NoSuchPosition),
+            70 => Ok(ErrorCode::NoSuchScratchKey),
+            71 => Ok(ErrorCode::NoFailureRecorded),
+            72 => Ok(ErrorCode::ObjectInUse),
+            73 => Ok(ErrorCode::ObjectNotRetained),
+            74 => Ok(ErrorCode::NotADefinedType),
+            75 => Ok(ErrorCode::NoSuchPremiss),
+            76 => Ok(ErrorCode::NameAlreadyRegistered),
+            77 => Ok(ErrorCode::NoSuchNameRegistered),
+            78 => Ok(ErrorCode::MalformedBatch),
+            79 => Ok(ErrorCode::NoMatchingHypothesis),
             _otherwise => Err(()),
         }
     }
@@ -248,114 +650,7 @@ impl TryFrom<i32> for ErrorCode {
 // Kernel handles.
 ////////////////////////////////////////////////////////////////////////////////
 
-pub mod tags {
-    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct TypeFormer;
-
-    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Type;
-
-    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Constant;
-
-    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Term;
-
-    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-    pub struct Theorem;
-
-    pub trait IsTag {}
-
-    impl IsTag for TypeFormer {}
-
-    impl IsTag for Type {}
-
-    impl IsTag for Constant {}
-
-    impl IsTag for Term {}
-
-    impl IsTag for Theorem {}
-}
-
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Handle<T>
-where
-    T: tags::IsTag,
-{
-    /// We use the Rust `usize` type as our handle type.  Note that on modern 64-bit
-    /// systems this is implemented as a 64-bit unsigned integer.
-    handle: usize,
-    /// The phantom data binding the tag type, `T`.
-    marker: PhantomData<T>,
-}
-
-impl<T> Handle<T>
-where
-    T: tags::IsTag,
-{
-    /// Creates a new kernel handle from a raw handle and some phantom data
-    /// constraining the handle to be of a particular tag-type.
-    #[inline]
-    pub(crate) const fn new(handle: usize, marker: PhantomData<T>) -> Self {
-        Self { handle, marker }
-    }
-}
-
-impl<T> AsRef<Handle<T>> for Handle<T>
-where
-    T: tags::IsTag,
-{
-    #[inline]
-    fn as_ref(&self) -> &Handle<T> {
-        self
-    }
-}
-
-impl<T> Deref for Handle<T>
-where
-    T: tags::IsTag,
-{
-    type Target = usize;
-
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.handle
-    }
-}
-
-impl<T> From<usize> for Handle<T>
-where
-    T: tags::IsTag,
-{
-    #[inline]
-    fn from(handle: usize) -> Self {
-        Handle {
-            handle,
-            marker: PhantomData,
-        }
-    }
-}
-
-impl Display for Handle<tags::Term> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{} (term handle)", self.handle)
-    }
-}
-
-impl Display for Handle<tags::Constant> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{} (constant handle)", self.handle)
-    }
-}
-
-impl Display for Handle<tags::TypeFormer> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{} (type-former handle)", self.handle)
-    }
-}
-
-impl Display for Handle<tags::Type> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{} (type handle)", self.handle)
-    }
-}
+/* NB: handles, and their tags, are shared with the kernel through the
+ * `supervisionary-handle` crate, so that the two sides cannot drift apart.
+ */
+pub use supervisionary_handle::{tags, Handle};
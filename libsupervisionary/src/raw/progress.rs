@@ -0,0 +1,89 @@
+//! # Bindings to Supervisionary's progress ABI
+//!
+//! Some kernel operations, such as checking a large proof, take long enough
+//! that an interactive guest would like to show how far along they are.  A
+//! guest may register a callback, exported through its function table, that
+//! the kernel invokes as such an operation progresses, with the percentage of
+//! the operation completed.  The kernel is busy while the callback runs, so the
+//! callback must not make host calls of its own: doing so traps.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::ErrorCode;
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Progress.Register` function.
+    fn __progress_register(callback: u32, context: u64) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Progress callbacks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The operation passed to a progress callback reporting the progress of
+/// checking a proof.
+pub const PROGRESS_OPERATION_PROOF_CHECK: u32 = 0;
+/// The operation passed to a progress callback reporting the progress of
+/// importing proof objects.
+pub const PROGRESS_OPERATION_PROOF_IMPORT: u32 = 1;
+/// The operation passed to a progress callback reporting the progress of
+/// importing a state object.
+pub const PROGRESS_OPERATION_STATE_IMPORT: u32 = 2;
+
+/// A callback notified of the progress of long-running kernel operations by
+/// `progress_register`.  Called with the context passed to
+/// `progress_register`, one of the `PROGRESS_OPERATION_*` operations, and the
+/// percentage of the operation completed, once as the operation begins and
+/// then each time that percentage increases.
+pub type ProgressCallback = extern "C" fn(u64, u32, u32);
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Registers `callback` to be notified, with `context`, of the progress of
+/// the long-running kernel operations performed by subsequent host calls,
+/// replacing any callback registered before.  Note that the host invokes the
+/// callback through the guest's function table, which must therefore be
+/// exported, and that the callback must not make host calls.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn progress_register(
+    callback: ProgressCallback,
+    context: u64,
+) -> Result<(), ErrorCode> {
+    let status =
+        unsafe { __progress_register(callback as usize as u32, context) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,344 @@
+//! # Bindings to Supervisionary's proof-term ABI
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::alloc;
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// Inference rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The kernel's inference rules, as recorded in proof terms.  The arguments
+/// recorded for each rule are listed in the order in which they are passed to
+/// the corresponding theorem registration function.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    /// The assumption rule, with the assumed term as argument.
+    Assumption,
+    /// The weakening rule, with the added term and the theorem as arguments.
+    Weaken,
+    /// The reflexivity rule, with the term as argument.
+    Reflexivity,
+    /// The symmetry rule, with the theorem as argument.
+    Symmetry,
+    /// The transitivity rule, with the two theorems as arguments.
+    Transitivity,
+    /// The application congruence rule, with the two theorems as arguments.
+    Application,
+    /// The conditional congruence rule, with the three theorems as arguments.
+    ConditionalCongruence,
+    /// The let congruence rule, with the two theorems as arguments.
+    LetCongruence,
+    /// The first projection axiom, with the two components as arguments.
+    FirstProjection,
+    /// The second projection axiom, with the two components as arguments.
+    SecondProjection,
+    /// The surjective pairing axiom, with the pair as argument.
+    SurjectivePairing,
+    /// The definition of set membership, with the element and set as
+    /// arguments.
+    MembershipDefinition,
+    /// The definition of set comprehension, with the set as argument.
+    ComprehensionDefinition,
+    /// The definition of the empty set, with the element type as argument.
+    EmptySetDefinition,
+    /// The definition of the universal set, with the element type as argument.
+    UniversalSetDefinition,
+    /// The definition of set union, with the two sets as arguments.
+    UnionDefinition,
+    /// The definition of set intersection, with the two sets as arguments.
+    IntersectionDefinition,
+    /// The definition of the subset relation, with the two sets as arguments.
+    SubsetDefinition,
+    /// The lambda congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    Lambda,
+    /// The beta-reduction axiom, with the application as argument.
+    Beta,
+    /// The eta-reduction axiom, with the lambda-abstraction as argument.
+    Eta,
+    /// The iff introduction rule, with the two theorems as arguments.
+    IffIntroduction,
+    /// The iff elimination rule, with the theorem as argument.
+    IffLeftElimination,
+    /// The truth introduction rule, without arguments.
+    TruthIntroduction,
+    /// The falsity elimination rule, with the theorem and the conclusion as
+    /// arguments.
+    FalsityElimination,
+    /// The conjunction introduction rule, with the two theorems as arguments.
+    ConjunctionIntroduction,
+    /// The left conjunction elimination rule, with the theorem as argument.
+    ConjunctionLeftElimination,
+    /// The right conjunction elimination rule, with the theorem as argument.
+    ConjunctionRightElimination,
+    /// The left disjunction introduction rule, with the theorem and the
+    /// introduced disjunct as arguments.
+    DisjunctionLeftIntroduction,
+    /// The right disjunction introduction rule, with the theorem and the
+    /// introduced disjunct as arguments.
+    DisjunctionRightIntroduction,
+    /// The disjunction elimination rule, with the three theorems as arguments.
+    DisjunctionElimination,
+    /// The implication introduction rule, with the theorem and the discharged
+    /// premiss as arguments.
+    ImplicationIntroduction,
+    /// The implication elimination rule, with the two theorems as arguments.
+    ImplicationElimination,
+    /// The substitution rule, with the theorem followed by the name, type, and
+    /// replacement term of every binding of the substitution as arguments.
+    Substitute,
+    /// The type substitution rule, with the theorem followed by the name and
+    /// replacement type of every binding of the substitution as arguments.
+    TypeSubstitute,
+    /// The negation introduction rule, with the theorem and the negated term
+    /// as arguments.
+    NegationIntroduction,
+    /// The negation elimination rule, with the two theorems as arguments.
+    NegationElimination,
+    /// The universal introduction rule, with the name and type of the
+    /// generalised variable and the theorem as arguments.
+    ForallIntroduction,
+    /// The universal elimination rule, with the theorem and the instantiating
+    /// term as arguments.
+    ForallElimination,
+    /// A definitional theorem, with the defined constant, lifted into a term,
+    /// and the definition as arguments.
+    Definition,
+    /// A theorem characterising the abstraction and representation functions
+    /// of a defined type, with the statement of the theorem as argument.
+    TypeDefinition,
+    /// The defining equation of a function defined by well-founded recursion,
+    /// with the statement of the equation as argument.
+    RecursiveDefinition,
+    /// The universal congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    ForallCongruence,
+    /// The existential congruence rule, with the name and type of the bound
+    /// variable and the theorem as arguments.
+    ExistsCongruence,
+    /// The existential introduction rule, with the theorem, the existential
+    /// and the witness as arguments.
+    ExistsIntroduction,
+    /// The existential elimination rule, with the existential theorem, the
+    /// eigenvariable and the theorem discharging the instance as arguments.
+    ExistsElimination,
+    /// A goal proved by the linear arithmetic decision procedure, with the
+    /// goal as argument.
+    LinearArith,
+    /// An axiom postulated by the host, with the statement of the axiom as
+    /// argument.
+    Axiom,
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into an
+/// inference rule.
+impl TryFrom<u64> for Rule {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Rule::Assumption),
+            1 => Ok(Rule::Weaken),
+            2 => Ok(Rule::Reflexivity),
+            3 => Ok(Rule::Symmetry),
+            4 => Ok(Rule::Transitivity),
+            5 => Ok(Rule::Application),
+            6 => Ok(Rule::ConditionalCongruence),
+            7 => Ok(Rule::LetCongruence),
+            8 => Ok(Rule::FirstProjection),
+            9 => Ok(Rule::SecondProjection),
+            10 => Ok(Rule::SurjectivePairing),
+            11 => Ok(Rule::MembershipDefinition),
+            12 => Ok(Rule::ComprehensionDefinition),
+            13 => Ok(Rule::EmptySetDefinition),
+            14 => Ok(Rule::UniversalSetDefinition),
+            15 => Ok(Rule::UnionDefinition),
+            16 => Ok(Rule::IntersectionDefinition),
+            17 => Ok(Rule::SubsetDefinition),
+            18 => Ok(Rule::Lambda),
+            19 => Ok(Rule::Beta),
+            20 => Ok(Rule::Eta),
+            21 => Ok(Rule::IffIntroduction),
+            22 => Ok(Rule::IffLeftElimination),
+            23 => Ok(Rule::TruthIntroduction),
+            24 => Ok(Rule::FalsityElimination),
+            25 => Ok(Rule::ConjunctionIntroduction),
+            26 => Ok(Rule::ConjunctionLeftElimination),
+            27 => Ok(Rule::ConjunctionRightElimination),
+            28 => Ok(Rule::DisjunctionLeftIntroduction),
+            29 => Ok(Rule::DisjunctionRightIntroduction),
+            30 => Ok(Rule::DisjunctionElimination),
+            31 => Ok(Rule::ImplicationIntroduction),
+            32 => Ok(Rule::ImplicationElimination),
+            33 => Ok(Rule::Substitute),
+            34 => Ok(Rule::TypeSubstitute),
+            35 => Ok(Rule::NegationIntroduction),
+            36 => Ok(Rule::NegationElimination),
+            37 => Ok(Rule::ForallElimination),
+            38 => Ok(Rule::Definition),
+            39 => Ok(Rule::ForallIntroduction),
+            40 => Ok(Rule::TypeDefinition),
+            41 => Ok(Rule::RecursiveDefinition),
+            42 => Ok(Rule::ForallCongruence),
+            43 => Ok(Rule::ExistsCongruence),
+            44 => Ok(Rule::LinearArith),
+            45 => Ok(Rule::Axiom),
+            46 => Ok(Rule::ExistsIntroduction),
+            47 => Ok(Rule::ExistsElimination),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Theorem.Proof` function.
+    fn __theorem_proof(
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Proof.Split` function.
+    fn __proof_split(
+        proof_handle: RawHandle,
+        result_rule: *mut u64,
+        result_theorem: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Proof.Split.Arguments` function.
+    fn __proof_split_arguments(
+        proof_handle: RawHandle,
+        arguments_base: *mut u64,
+        arguments_length: *mut u64,
+    ) -> i32;
+}
+
+/// Returns a handle to the proof term recorded for the theorem pointed-to by
+/// `theorem`.  Proof terms are only recorded whilst the host has enabled proof
+/// recording.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_proof<T>(theorem: T) -> Result<Handle<tags::Proof>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_proof(
+            *theorem.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the inference rule recorded in the proof term pointed-to by
+/// `proof`, along with a handle to the theorem that it derived.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn proof_split<T>(
+    proof: T,
+) -> Result<(Rule, Handle<tags::Theorem>), ErrorCode>
+where
+    T: AsRef<Handle<tags::Proof>>,
+{
+    let mut rule: u64 = 0;
+    let mut theorem: u64 = 0;
+
+    let status = unsafe {
+        __proof_split(
+            *proof.as_ref().clone() as u64,
+            &mut rule as *mut u64,
+            &mut theorem as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Rule::try_from(rule).unwrap(),
+            Handle::new(theorem as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the arguments recorded in the proof term pointed-to by `proof`, as
+/// raw machine words: names, or handles, as described by the proof term's
+/// inference rule.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn proof_split_arguments<T>(proof: T) -> Result<Vec<u64>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Proof>>,
+{
+    let proof = *proof.as_ref().clone() as u64;
+    let mut arguments = alloc::buffer(0);
+
+    /* NB: as for `hypotheses_resolve`, the first call only discovers the
+     * number of arguments, and the second fills a buffer of the right size.
+     */
+    loop {
+        let capacity = arguments.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __proof_split_arguments(
+                proof,
+                arguments.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            arguments.truncate(length as usize);
+
+            return Ok(arguments.to_vec());
+        }
+
+        arguments.resize(length as usize, 0u64);
+    }
+}
+
+declare_hostcall! {
+    /// Checks the proof term pointed-to by `proof`, and those of the premisses
+    /// that it depends upon, by re-applying every recorded inference rule.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn proof_check(proof: &Proof) -> () = __proof_check;
+}
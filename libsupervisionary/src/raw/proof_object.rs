@@ -0,0 +1,368 @@
+//! # Bindings to Supervisionary's proof-object ABI
+//!
+//! A proof object is a compact serialization of the sequents of some theorems,
+//! sharing every type and term that they have in common, which may be exported
+//! from one session and imported into another.  Handles are only meaningful
+//! within the session that issued them, so a proof object exported here also
+//! records the handles that its types, terms and theorems had when exported.
+//! Importing it, even into a session that has already registered objects of
+//! its own, yields a *remapping table* from those handles to their imported
+//! counterparts, which `Remap` applies to the handles that a guest retained
+//! across the export, e.g. in a serialized goal.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{collections::BTreeMap, convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `ProofObject.Export` function.
+    fn __proof_object_export(
+        theorem_base: *const RawHandle,
+        theorem_length: u64,
+        result_base: *mut u8,
+        result_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `ProofObject.Import` function.
+    fn __proof_object_import(
+        bytes_base: *const u8,
+        bytes_length: u64,
+        result_base: *mut u64,
+        result_length: *mut u64,
+    ) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Imported proof objects.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The sequent of an exported theorem, as imported.  Importing a proof object
+/// never registers a theorem: the sequent must be re-proved, or re-admitted by
+/// the host, in the importing session.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Sequent {
+    /// The premisses of the sequent.
+    pub premisses: Vec<Handle<tags::Term>>,
+    /// The conclusion of the sequent.
+    pub conclusion: Handle<tags::Term>,
+}
+
+/// The correspondence between the handles that the objects of a proof object
+/// had when exported, and their counterparts after importing it.  Types and
+/// terms map to the handles of the imported types and terms, whereas theorems
+/// map to the position of their sequent amongst those imported.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Remapping {
+    /// The imported handle of every type and term, indexed by its exported
+    /// handle.
+    objects: BTreeMap<usize, usize>,
+    /// The position of the sequent of every theorem, indexed by its exported
+    /// handle.
+    theorems: BTreeMap<usize, usize>,
+}
+
+impl Remapping {
+    /// Returns the imported handle of the type or term whose exported handle
+    /// is `handle`, or `None` if the proof object carried no such type or term.
+    #[inline]
+    pub fn remap<T>(&self, handle: &Handle<T>) -> Option<Handle<T>>
+    where
+        T: tags::IsTag,
+    {
+        self.objects
+            .get(&**handle)
+            .map(|h| Handle::new(*h, PhantomData))
+    }
+
+    /// Returns the position, amongst the imported sequents, of the sequent of
+    /// the theorem whose exported handle is `handle`, or `None` if the proof
+    /// object carried no such theorem.
+    #[inline]
+    pub fn theorem<T>(&self, handle: T) -> Option<usize>
+    where
+        T: AsRef<Handle<tags::Theorem>>,
+    {
+        self.theorems.get(&**handle.as_ref()).cloned()
+    }
+
+    /// Returns `true` iff the remapping table is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty() && self.theorems.is_empty()
+    }
+}
+
+/// The result of importing a proof object: the sequents of its theorems, in
+/// the order that they were exported, and the remapping table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Import {
+    /// The sequents of the exported theorems.
+    pub sequents: Vec<Sequent>,
+    /// The remapping table from exported handles to imported objects.
+    pub remapping: Remapping,
+}
+
+/// Guest-side data holding handles that may need updating after a proof object
+/// is imported.
+pub trait Remap {
+    /// Replaces every handle of a type or term in `self` that `remapping`
+    /// remaps with its imported counterpart.  Handles that `remapping` does not
+    /// mention, including every handle of a theorem, are left untouched.
+    fn remap_with(&mut self, remapping: &Remapping);
+}
+
+impl<T> Remap for Handle<T>
+where
+    T: tags::IsTag,
+{
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        if let Some(handle) = remapping.remap(self) {
+            *self = handle;
+        }
+    }
+}
+
+impl<T> Remap for Option<T>
+where
+    T: Remap,
+{
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        if let Some(value) = self {
+            value.remap_with(remapping);
+        }
+    }
+}
+
+impl<T> Remap for [T]
+where
+    T: Remap,
+{
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        for value in self.iter_mut() {
+            value.remap_with(remapping);
+        }
+    }
+}
+
+impl<T> Remap for Vec<T>
+where
+    T: Remap,
+{
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        self.as_mut_slice().remap_with(remapping);
+    }
+}
+
+impl<T, U> Remap for (T, U)
+where
+    T: Remap,
+    U: Remap,
+{
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        self.0.remap_with(remapping);
+        self.1.remap_with(remapping);
+    }
+}
+
+impl Remap for Sequent {
+    #[inline]
+    fn remap_with(&mut self, remapping: &Remapping) {
+        self.premisses.remap_with(remapping);
+        self.conclusion.remap_with(remapping);
+    }
+}
+
+/// Decodes the words written by `ProofObject.Import`.  The host is trusted to
+/// have written a well-formed result.
+fn decode_import(words: &[u64]) -> Import {
+    let mut words = words.iter().map(|w| *w as usize);
+    let mut next = move || words.next().unwrap();
+
+    let mut sequents = Vec::new();
+
+    for _i in 0..next() {
+        let premisses = (0..next())
+            .map(|_j| Handle::new(next(), PhantomData))
+            .collect();
+        let conclusion = Handle::new(next(), PhantomData);
+
+        sequents.push(Sequent {
+            premisses,
+            conclusion,
+        });
+    }
+
+    let mut remapping = Remapping::default();
+
+    for _i in 0..next() {
+        let old = next();
+        remapping.objects.insert(old, next());
+    }
+
+    for _i in 0..next() {
+        let old = next();
+        remapping.theorems.insert(old, next());
+    }
+
+    Import {
+        sequents,
+        remapping,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Serializes the theorems pointed-to by `theorems` into a proof object,
+/// recording their handles, and the handles of their types and terms, so that
+/// importing it yields a remapping table.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::NoSuchTheoremRegistered)` if any handle in
+/// `theorems` does not point-to a registered theorem.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn proof_object_export<T>(theorems: &[T]) -> Result<Vec<u8>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let theorems: Vec<u64> =
+        theorems.iter().map(|h| **h.as_ref() as u64).collect();
+    let mut bytes: Vec<u8> = Vec::new();
+
+    /* NB: as with `object_metadata_get`, the first call only discovers the
+     * length of the proof object, and the second call fills a buffer of the
+     * right size.
+     */
+    loop {
+        let capacity = bytes.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __proof_object_export(
+                theorems.as_ptr(),
+                theorems.len() as u64,
+                bytes.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            bytes.truncate(length as usize);
+
+            return Ok(bytes);
+        }
+
+        bytes.resize(length as usize, 0u8);
+    }
+}
+
+/// Imports the proof object `bytes`, registering its types and terms, and
+/// returns the sequents of its theorems along with the remapping table.  The
+/// remapping table is empty if the proof object was exported without handles,
+/// e.g. by the host.
+///
+/// # Errors
+///
+/// Returns `Err(ErrorCode::MalformedProofObject)` if `bytes` is not a
+/// well-formed proof object, or whatever error registering one of its types or
+/// terms fails with, e.g. `Err(ErrorCode::NoSuchConstantRegistered)` if a term
+/// refers to a constant that is not registered in this session.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn proof_object_import<B>(bytes: B) -> Result<Import, ErrorCode>
+where
+    B: AsRef<[u8]>,
+{
+    let bytes = bytes.as_ref();
+    let mut words: Vec<u64> = Vec::new();
+
+    /* NB: importing is idempotent, so retrying with a buffer of the right size
+     * yields the same handles.
+     */
+    loop {
+        let capacity = words.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __proof_object_import(
+                bytes.as_ptr(),
+                bytes.len() as u64,
+                words.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            words.truncate(length as usize);
+
+            return Ok(decode_import(&words));
+        }
+
+        words.resize(length as usize, 0u64);
+    }
+}
+
+/// Imports the proof object `bytes`, as `proof_object_import`, and updates the
+/// handles in `references` with the remapping table.
+///
+/// # Errors
+///
+/// Returns the errors that `proof_object_import` does, in which case
+/// `references` is untouched.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn proof_object_import_remapping<B, R>(
+    bytes: B,
+    references: &mut R,
+) -> Result<Import, ErrorCode>
+where
+    B: AsRef<[u8]>,
+    R: Remap + ?Sized,
+{
+    let import = proof_object_import(bytes)?;
+
+    references.remap_with(&import.remapping);
+
+    Ok(import)
+}
@@ -0,0 +1,231 @@
+//! # Bindings to Supervisionary's quotient type ABI
+//!
+//! Quotient types are defined from equivalence relations, `R : σ → σ → Prop`,
+//! with the kernel's type-definition principle, as the type, `σ/R`, of the
+//! equivalence classes of `R`.  Alongside the new type the kernel defines the
+//! function, `[·] : σ → σ/R`, mapping each element to its class, and derives
+//! the theorems needed to reason about the new type through representatives.
+//! Functions respecting the relation can then be lifted to the quotient, and
+//! theorems relating representatives transferred to theorems equating their
+//! classes.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+/// The objects registered by a quotient type: the equivalence relation, the
+/// constant mapping elements to their classes, and the theorems
+/// characterising the classes and the induction rule of the quotient.
+pub type Quotient = (
+    Handle<tags::Term>,
+    Handle<tags::Term>,
+    Handle<tags::Theorem>,
+    Handle<tags::Theorem>,
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Quotient.Register` function.
+    fn __quotient_register(
+        relation_handle: RawHandle,
+        reflexivity_handle: RawHandle,
+        symmetry_handle: RawHandle,
+        transitivity_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Quotient.Split` function.
+    fn __quotient_split(
+        type_handle: RawHandle,
+        result_relation: *mut RawHandle,
+        result_class: *mut RawHandle,
+        result_class_equality: *mut RawHandle,
+        result_induction: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Quotient.Lift` function.
+    fn __quotient_lift(
+        type_handle: RawHandle,
+        function_handle: RawHandle,
+        respect_handle: RawHandle,
+        result_constant: *mut RawHandle,
+        result_equation: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Quotient.Transfer` function.
+    fn __quotient_transfer(
+        type_handle: RawHandle,
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+}
+
+/// Defines the quotient of the type `σ` by the closed equivalence relation,
+/// `R : σ → σ → Prop`, pointed-to by `relation_handle`, returning the new
+/// quotient type, `σ/R`.  The theorems pointed-to by `reflexivity_handle`,
+/// `symmetry_handle` and `transitivity_handle` must have no premisses, and
+/// must state, respectively, `∀x. R x x`, `∀x y. R x y ⟶ R y x` and
+/// `∀x y z. R x y ⟶ R y z ⟶ R x z`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn quotient_register<T, U, V, W>(
+    relation_handle: T,
+    reflexivity_handle: U,
+    symmetry_handle: V,
+    transitivity_handle: W,
+) -> Result<Handle<tags::Type>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Theorem>>,
+    V: Into<Handle<tags::Theorem>>,
+    W: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __quotient_register(
+            *relation_handle.into() as u64,
+            *reflexivity_handle.into() as u64,
+            *symmetry_handle.into() as u64,
+            *transitivity_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the relation, `R`, of the quotient type pointed-to by
+/// `type_handle`, along with the constant, `[·]`, mapping elements to their
+/// classes, the theorem `⊢ ∀x y. R x y = ([x] = [y])`, and the induction rule
+/// `⊢ ∀Q. (∀x. Q [x]) ⟶ (∀a. Q a)`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn quotient_split<T>(type_handle: T) -> Result<Quotient, ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+{
+    let mut result_relation: u64 = 0;
+    let mut result_class: u64 = 0;
+    let mut result_class_equality: u64 = 0;
+    let mut result_induction: u64 = 0;
+
+    let status = unsafe {
+        __quotient_split(
+            *type_handle.into() as u64,
+            &mut result_relation as *mut u64,
+            &mut result_class as *mut u64,
+            &mut result_class_equality as *mut u64,
+            &mut result_induction as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_relation as usize, PhantomData),
+            Handle::new(result_class as usize, PhantomData),
+            Handle::new(result_class_equality as usize, PhantomData),
+            Handle::new(result_induction as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Lifts the closed function, `f : σ → … → σ → σ`, pointed-to by
+/// `function_handle`, to a new constant, `f'`, on the quotient type pointed-to
+/// by `type_handle`, returning the constant and the theorem
+/// `⊢ ∀x₁ … xₙ. f' [x₁] … [xₙ] = [f x₁ … xₙ]`.  The theorem pointed-to by
+/// `respect_handle` must have no premisses, and must state that `f` respects
+/// the relation, `R`, of the quotient:
+/// `∀x₁ … xₙ y₁ … yₙ. R x₁ y₁ ⟶ … ⟶ R xₙ yₙ ⟶ R (f x₁ … xₙ) (f y₁ … yₙ)`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn quotient_lift<T, U, V>(
+    type_handle: T,
+    function_handle: U,
+    respect_handle: V,
+) -> Result<(Handle<tags::Term>, Handle<tags::Theorem>), ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+    U: Into<Handle<tags::Term>>,
+    V: Into<Handle<tags::Theorem>>,
+{
+    let mut result_constant: u64 = 0;
+    let mut result_equation: u64 = 0;
+
+    let status = unsafe {
+        __quotient_lift(
+            *type_handle.into() as u64,
+            *function_handle.into() as u64,
+            *respect_handle.into() as u64,
+            &mut result_constant as *mut u64,
+            &mut result_equation as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_constant as usize, PhantomData),
+            Handle::new(result_equation as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Transfers the theorem `Γ ⊢ R s t`, pointed-to by `theorem_handle`, relating
+/// two elements by the relation of the quotient type pointed-to by
+/// `type_handle`, to the theorem `Γ ⊢ [s] = [t]`, equating their classes.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn quotient_transfer<T, U>(
+    type_handle: T,
+    theorem_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+    U: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __quotient_transfer(
+            *type_handle.into() as u64,
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,151 @@
+//! # Bindings to Supervisionary's scratch-store ABI
+//!
+//! The host keeps a small key-value store for each session, in which a tactic
+//! may stash intermediate encoded data between its phases, such as a goal
+//! awaiting a later phase, rather than attaching it as metadata to an
+//! unrelated kernel object, or keeping it in guest globals that do not survive
+//! the module being reloaded.  The host may also persist the store with the
+//! state objects that it exports.  Like metadata, the scratch store plays no
+//! part in the logic.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::ErrorCode;
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Scratch.Put` function.
+    fn __scratch_put(
+        key_base: *const u8,
+        key_length: u64,
+        value_base: *const u8,
+        value_length: u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Scratch.Get` function.
+    fn __scratch_get(
+        key_base: *const u8,
+        key_length: u64,
+        value_base: *mut u8,
+        value_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Scratch.Delete` function.
+    fn __scratch_delete(key_base: *const u8, key_length: u64) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Safe wrappers.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Stores `value` in the scratch store under `key`, replacing any value
+/// already stored under `key`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn scratch_put<K, V>(key: K, value: V) -> Result<(), ErrorCode>
+where
+    K: AsRef<str>,
+    V: AsRef<[u8]>,
+{
+    let key = key.as_ref();
+    let value = value.as_ref();
+
+    let status = unsafe {
+        __scratch_put(
+            key.as_ptr(),
+            key.len() as u64,
+            value.as_ptr(),
+            value.len() as u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the value stored in the scratch store under `key`, or
+/// `Err(ErrorCode::NoSuchScratchKey)` if there is none.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn scratch_get<K>(key: K) -> Result<Vec<u8>, ErrorCode>
+where
+    K: AsRef<str>,
+{
+    let key = key.as_ref();
+    let mut value: Vec<u8> = Vec::new();
+
+    /* NB: as with `object_metadata_get`, the first call only discovers the
+     * length of the value, and the second call fills a buffer of the right
+     * size.
+     */
+    loop {
+        let capacity = value.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __scratch_get(
+                key.as_ptr(),
+                key.len() as u64,
+                value.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            value.truncate(length as usize);
+
+            return Ok(value);
+        }
+
+        value.resize(length as usize, 0u8);
+    }
+}
+
+/// Removes the value stored in the scratch store under `key`, or returns
+/// `Err(ErrorCode::NoSuchScratchKey)` if there is none.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn scratch_delete<K>(key: K) -> Result<(), ErrorCode>
+where
+    K: AsRef<str>,
+{
+    let key = key.as_ref();
+
+    let status = unsafe { __scratch_delete(key.as_ptr(), key.len() as u64) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
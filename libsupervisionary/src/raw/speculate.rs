@@ -0,0 +1,92 @@
+//! # Bindings to Supervisionary's speculation ABI
+//!
+//! Speculative branches let proof search explore an alternative, registering
+//! kernel objects freely, and then either keep everything registered within
+//! the branch or discard all of it at once.  Handles to discarded objects are
+//! never reissued, so retaining one by mistake is reported as an error rather
+//! than silently referring to some other kernel object.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::ErrorCode;
+use std::convert::TryFrom;
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Speculate.Begin` function.
+    fn __speculate_begin() -> i32;
+    /// Raw ABI binding to the `Speculate.End` function.
+    fn __speculate_end(keep: bool) -> i32;
+}
+
+/// Begins a new speculative branch, nested within any branch already in
+/// progress.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn speculate_begin() -> Result<(), ErrorCode> {
+    let status = unsafe { __speculate_begin() };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Ends the innermost speculative branch in progress, keeping every kernel
+/// object registered within it if `keep` is `true`, and discarding them
+/// otherwise.  Fails with `ErrorCode::NoSpeculationInProgress` if no branch is
+/// in progress.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn speculate_end(keep: bool) -> Result<(), ErrorCode> {
+    let status = unsafe { __speculate_end(keep) };
+
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Runs `branch` within a new speculative branch, keeping every kernel object
+/// registered by it if it succeeds, and discarding them if it fails.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn speculate<F, T, E>(branch: F) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E>,
+    E: From<ErrorCode>,
+{
+    speculate_begin()?;
+
+    let result = branch();
+
+    speculate_end(result.is_ok())?;
+
+    result
+}
@@ -0,0 +1,285 @@
+//! # Bindings to Supervisionary's registered substitution ABI
+//!
+//! A substitution applied to many terms, or types, may be registered with the
+//! kernel once, and then applied by handle, rather than being copied into, and
+//! checked by, the kernel on every application.  Substitutions of types for
+//! type-variables apply to types and to the types within terms, whilst
+//! substitutions of terms for variables apply to terms.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, Name, RawHandle};
+use std::{convert::TryFrom, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Substitution.Register.Type` function.
+    fn __substitution_register_type(
+        domain_base: *const u64,
+        domain_length: u64,
+        range_base: *const u64,
+        range_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Substitution.Register.Term` function.
+    fn __substitution_register_term(
+        domain_base: *const u64,
+        domain_length: u64,
+        type_base: *const u64,
+        type_length: u64,
+        range_base: *const u64,
+        range_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Substitution.Compose` function.
+    fn __substitution_compose(
+        first_handle: RawHandle,
+        second_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Type.Substitute.With` function.
+    fn __type_substitute_with(
+        type_handle: RawHandle,
+        substitution_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Substitute.With` function.
+    fn __term_substitute_with(
+        term_handle: RawHandle,
+        substitution_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Type.Substitute.With` function.
+    fn __term_type_substitute_with(
+        term_handle: RawHandle,
+        substitution_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+}
+
+/// Registers the substitution of types for type-variables, `substitution`,
+/// returning its handle.  Registering an equal substitution again returns the
+/// same handle.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn substitution_register_type<N, T>(
+    substitution: Vec<(N, T)>,
+) -> Result<Handle<tags::Substitution>, ErrorCode>
+where
+    N: Into<Name> + Clone,
+    T: Into<Handle<tags::Type>> + Clone,
+{
+    let mut result: u64 = 0;
+    let (domain, range): (Vec<u64>, Vec<u64>) = substitution
+        .iter()
+        .cloned()
+        .map(|(d, r)| (d.into(), *r.into() as u64))
+        .unzip();
+
+    let status = unsafe {
+        __substitution_register_type(
+            domain.as_ptr(),
+            domain.len() as u64,
+            range.as_ptr(),
+            range.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the substitution of terms for variables, `substitution`, pairing
+/// each variable, given by its name and type, with the term replacing it, and
+/// returning its handle.  Registering an equal substitution again returns the
+/// same handle.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn substitution_register_term<N, S, U>(
+    substitution: Vec<((N, S), U)>,
+) -> Result<Handle<tags::Substitution>, ErrorCode>
+where
+    N: Into<Name> + Clone,
+    S: Into<Handle<tags::Type>> + Clone,
+    U: Into<Handle<tags::Term>> + Clone,
+{
+    let mut result: u64 = 0;
+
+    let mut domain: Vec<u64> = Vec::with_capacity(substitution.len());
+    let mut types: Vec<u64> = Vec::with_capacity(substitution.len());
+    let mut range: Vec<u64> = Vec::with_capacity(substitution.len());
+
+    for ((d, t), r) in substitution.iter() {
+        domain.push(d.clone().into());
+        types.push(*t.clone().into() as u64);
+        range.push(*r.clone().into() as u64);
+    }
+
+    let status = unsafe {
+        __substitution_register_term(
+            domain.as_ptr(),
+            domain.len() as u64,
+            types.as_ptr(),
+            types.len() as u64,
+            range.as_ptr(),
+            range.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the composition of the substitutions of types for type-variables
+/// pointed-to by `first_handle` and `second_handle`, returning its handle.
+/// Applying the composition is equivalent to applying the first substitution,
+/// and then the second, but composing is constant-time, however long the
+/// chain of compositions grows.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn substitution_compose<T, U>(
+    first_handle: T,
+    second_handle: U,
+) -> Result<Handle<tags::Substitution>, ErrorCode>
+where
+    T: Into<Handle<tags::Substitution>>,
+    U: Into<Handle<tags::Substitution>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __substitution_compose(
+            *first_handle.into() as u64,
+            *second_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Instantiates the type pointed-to by `type_handle` with the registered
+/// substitution of types for type-variables pointed-to by
+/// `substitution_handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_substitute_with<T, U>(
+    type_handle: T,
+    substitution_handle: U,
+) -> Result<Handle<tags::Type>, ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+    U: Into<Handle<tags::Substitution>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __type_substitute_with(
+            *type_handle.into() as u64,
+            *substitution_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Substitutes terms for variables in the term pointed-to by `term_handle`
+/// with the registered substitution of terms for variables pointed-to by
+/// `substitution_handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_substitute_with<T, U>(
+    term_handle: T,
+    substitution_handle: U,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Substitution>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_substitute_with(
+            *term_handle.into() as u64,
+            *substitution_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Instantiates the types within the term pointed-to by `term_handle` with the
+/// registered substitution of types for type-variables pointed-to by
+/// `substitution_handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_type_substitute_with<T, U>(
+    term_handle: T,
+    substitution_handle: U,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Substitution>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_type_substitute_with(
+            *term_handle.into() as u64,
+            *substitution_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
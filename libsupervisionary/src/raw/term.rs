@@ -15,57 +15,35 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+use crate::alloc;
 use crate::raw::{tags, ErrorCode, Handle, Name, RawHandle};
-use std::{
-    collections::HashSet, convert::TryFrom, iter::FromIterator,
-    marker::PhantomData,
-};
+use std::{collections::HashSet, convert::TryFrom, marker::PhantomData};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Pre-allocated term-related handles.
 ////////////////////////////////////////////////////////////////////////////////
 
-/// A pre-allocated handle used to refer to the truth term, the truth constant
-/// lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_TRUE: Handle<tags::Term> =
-    Handle::new(19usize, PhantomData);
-/// A pre-allocated handle used to refer to the falsity term, the falsity
-/// constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_FALSE: Handle<tags::Term> =
-    Handle::new(20usize, PhantomData);
-/// A pre-allocated handle used to refer to the negation term, the negation
-/// constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_NEGATION: Handle<tags::Term> =
-    Handle::new(21usize, PhantomData);
-/// A pre-allocated handle used to refer to the conjunction term, the
-/// conjunction constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_CONJUNCTION: Handle<tags::Term> =
-    Handle::new(22usize, PhantomData);
-/// A pre-allocated handle used to refer to the disjunction term, the
-/// disjunction constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_DISJUNCTION: Handle<tags::Term> =
-    Handle::new(23usize, PhantomData);
-/// A pre-allocated handle used to refer to the implication term, the
-/// implication constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_IMPLICATION: Handle<tags::Term> =
-    Handle::new(24usize, PhantomData);
-/// A pre-allocated handle used to refer to the equality term, the equality
-/// constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_EQUALITY: Handle<tags::Term> =
-    Handle::new(25usize, PhantomData);
-/// A pre-allocated handle used to refer to the universal quantifier term, the
-/// universal quantifier constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_FORALL: Handle<tags::Term> =
-    Handle::new(26usize, PhantomData);
-/// A pre-allocated handle used to refer to the existential quantifier term, the
-/// existential quantifier constant lifted into a term.
-pub const PREALLOCATED_HANDLE_TERM_EXISTS: Handle<tags::Term> =
-    Handle::new(27usize, PhantomData);
+/* NB: generated by the build script from `abi-spec/preallocated-handles.txt`,
+ * which is shared with the kernel.
+ */
+include!(concat!(env!("OUT_DIR"), "/preallocated_term.rs"));
+
+/// The parts of a conditional: its condition, and its two branches.
+pub type Conditional =
+    (Handle<tags::Term>, Handle<tags::Term>, Handle<tags::Term>);
 
 ////////////////////////////////////////////////////////////////////////////////
 // ABI bindings.
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
 extern "C" {
     /// Raw ABI binding to the `Term.IsRegistered` function.
     fn __term_is_registered(handle: RawHandle) -> bool;
@@ -165,6 +143,13 @@ extern "C" {
         result_type: *mut RawHandle,
         result_body: *mut RawHandle,
     ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Lambda.Display` function.
+    fn __term_split_lambda_display(
+        term_handle: RawHandle,
+        result_name: *mut Name,
+        result_type: *mut RawHandle,
+        result_body: *mut RawHandle,
+    ) -> i32;
     /// Raw ABI binding to the `Term.Split.Negation` function.
     fn __term_split_negation(
         term_handle: RawHandle,
@@ -269,6 +254,31 @@ extern "C" {
         result_type_base: *mut RawHandle,
         result_type_length: *mut u64,
     ) -> i32;
+    /// Raw ABI binding to the `Term.FreeVariables.Count` function.
+    fn __term_free_variables_count(
+        term_handle: RawHandle,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.FreeVariables.Range` function.
+    fn __term_free_variables_range(
+        term_handle: RawHandle,
+        offset: u64,
+        result_name_base: *mut Name,
+        result_type_base: *mut RawHandle,
+        result_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.TypeVariables.Count` function.
+    fn __term_type_variables_count(
+        term_handle: RawHandle,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.TypeVariables.Range` function.
+    fn __term_type_variables_range(
+        term_handle: RawHandle,
+        offset: u64,
+        result_base: *mut Name,
+        result_length: *mut u64,
+    ) -> i32;
     /// Raw ABI binding to the `Term.Type.Substitution` function.
     fn __term_type_substitution(
         term_handle: RawHandle,
@@ -286,8 +296,150 @@ extern "C" {
         term_handle: RawHandle,
         result: *mut bool,
     ) -> i32;
+    /// Raw ABI binding to the `Term.Register.Conditional` function.
+    fn __term_register_conditional(
+        condition_handle: RawHandle,
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Register.Let` function.
+    fn __term_register_let(
+        function_handle: RawHandle,
+        argument_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Conditional` function.
+    fn __term_split_conditional(
+        term_handle: RawHandle,
+        result_condition: *mut RawHandle,
+        result_left: *mut RawHandle,
+        result_right: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Let` function.
+    fn __term_split_let(
+        term_handle: RawHandle,
+        result_function: *mut RawHandle,
+        result_argument: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Test.Conditional` function.
+    fn __term_test_conditional(
+        term_handle: RawHandle,
+        result: *mut bool,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Test.Let` function.
+    fn __term_test_let(term_handle: RawHandle, result: *mut bool) -> i32;
+    /// Raw ABI binding to the `Term.Register.Pair` function.
+    fn __term_register_pair(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Register.First` function.
+    fn __term_register_first(
+        pair_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Register.Second` function.
+    fn __term_register_second(
+        pair_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Pair` function.
+    fn __term_split_pair(
+        term_handle: RawHandle,
+        result_left: *mut RawHandle,
+        result_right: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Test.Pair` function.
+    fn __term_test_pair(term_handle: RawHandle, result: *mut bool) -> i32;
+    /// Raw ABI binding to the `Term.Register.Membership` function.
+    fn __term_register_membership(
+        element_handle: RawHandle,
+        set_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Register.Comprehension` function.
+    fn __term_register_comprehension(
+        bound_name: Name,
+        type_handle: RawHandle,
+        body_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Membership` function.
+    fn __term_split_membership(
+        term_handle: RawHandle,
+        result_element: *mut RawHandle,
+        result_set: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Split.Comprehension` function.
+    fn __term_split_comprehension(
+        term_handle: RawHandle,
+        result_name: *mut Name,
+        result_type: *mut RawHandle,
+        result_body: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Test.Membership` function.
+    fn __term_test_membership(term_handle: RawHandle, result: *mut bool)
+        -> i32;
+    /// Raw ABI binding to the `Term.Test.Comprehension` function.
+    fn __term_test_comprehension(
+        term_handle: RawHandle,
+        result: *mut bool,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Fold` function.
+    fn __term_fold(
+        term_handle: RawHandle,
+        callback: u32,
+        context: u64,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.Diff` function.
+    fn __term_diff(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result_base: *mut u8,
+        result_length: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.SubtermAt` function.
+    fn __term_subterm_at(
+        term_handle: RawHandle,
+        position_base: *const u64,
+        position_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Term.ReplaceAt` function.
+    fn __term_replace_at(
+        term_handle: RawHandle,
+        position_base: *const u64,
+        position_length: u64,
+        replacement_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Term folds.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tag passed to a fold callback visiting a variable, whose two fields are
+/// the variable's name and a handle to its type.
+pub const FOLD_TAG_VARIABLE: u32 = 0;
+/// The tag passed to a fold callback visiting a constant, whose two fields are
+/// a handle to the constant and a handle to its type.
+pub const FOLD_TAG_CONSTANT: u32 = 1;
+/// The tag passed to a fold callback visiting an application, whose two fields
+/// are the folded results of the function and of its argument.
+pub const FOLD_TAG_APPLICATION: u32 = 2;
+/// The tag passed to a fold callback visiting a lambda-abstraction, whose two
+/// fields are the name of the bound variable and the folded result of the body.
+pub const FOLD_TAG_LAMBDA: u32 = 3;
+
+/// A callback folded over a term by `term_fold`.  Called with the context
+/// passed to `term_fold`, the handle of the subterm being visited, one of the
+/// `FOLD_TAG_*` tags, and the two fields described by that tag.
+pub type FoldCallback = extern "C" fn(u64, RawHandle, u32, u64, u64) -> u64;
+
 #[inline]
 pub fn term_is_registered<T>(handle: T) -> bool
 where
@@ -697,6 +849,43 @@ where
     }
 }
 
+/// Splits the λ-abstraction pointed-to by `term_handle` as
+/// `term_split_lambda`, except that the bound variable is given the name first
+/// chosen for it when the abstraction was registered, rather than the
+/// canonical name under which the kernel stores it, and the body is renamed to
+/// match.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_lambda_display<T>(
+    term_handle: T,
+) -> Result<(Name, Handle<tags::Type>, Handle<tags::Term>), ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_name: u64 = 0;
+    let mut result_type_handle: u64 = 0;
+    let mut result_body_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_lambda_display(
+            *term_handle.into() as u64,
+            &mut result_name as *mut u64,
+            &mut result_type_handle as *mut u64,
+            &mut result_body_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            result_name,
+            Handle::new(result_type_handle as usize, PhantomData),
+            Handle::new(result_body_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
 pub fn term_split_negation<T>(
     term_handle: T,
 ) -> Result<Handle<tags::Term>, ErrorCode>
@@ -1135,8 +1324,8 @@ where
 {
     let size = term_size(&term_handle)?;
 
-    let mut result_domain = vec![0u64; size];
-    let mut result_range = vec![0u64; size];
+    let mut result_domain = alloc::buffer(size);
+    let mut result_range = alloc::buffer(size);
 
     let mut result_domain_size: u64 = 0;
     let mut result_range_size: u64 = 0;
@@ -1159,8 +1348,8 @@ where
 
         let substitute = result_domain
             .iter()
-            .zip(result_range)
-            .map(|(d, r)| (*d, Handle::new(r as usize, PhantomData)))
+            .zip(result_range.iter())
+            .map(|(d, r)| (*d, Handle::new(*r as usize, PhantomData)))
             .collect();
 
         Ok(substitute)
@@ -1169,6 +1358,72 @@ where
     }
 }
 
+/// Returns the number of free variables of the term pointed-to by
+/// `term_handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_free_variables_count<T>(term_handle: T) -> Result<usize, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_free_variables_count(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result as usize)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the names and types of at most `limit` free variables of the term
+/// pointed-to by `term_handle`, starting from the free variable at `offset`,
+/// in the order in which the kernel lists them, set by
+/// `kernel_set_variable_order`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_free_variables_range<T>(
+    term_handle: T,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<(Name, Handle<tags::Type>)>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result_domain = alloc::buffer(limit);
+    let mut result_range = alloc::buffer(limit);
+    let mut result_size: u64 = limit as u64;
+
+    let status = unsafe {
+        __term_free_variables_range(
+            *term_handle.as_ref().clone() as u64,
+            offset as u64,
+            result_domain.as_mut_ptr(),
+            result_range.as_mut_ptr(),
+            &mut result_size as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        result_domain.truncate(result_size as usize);
+        result_range.truncate(result_size as usize);
+
+        Ok(result_domain
+            .iter()
+            .zip(result_range.iter())
+            .map(|(d, r)| (*d, Handle::new(*r as usize, PhantomData)))
+            .collect())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
 pub fn term_free_type_variables<T>(
     term_handle: T,
 ) -> Result<HashSet<Name>, ErrorCode>
@@ -1177,7 +1432,7 @@ where
 {
     let size = term_size(&term_handle)?;
 
-    let mut result = vec![0u64; size];
+    let mut result = alloc::buffer(size);
     let mut result_size: u64 = 0;
 
     let status = unsafe {
@@ -1191,7 +1446,65 @@ where
     if status == 0 {
         result.truncate(result_size as usize);
 
-        Ok(HashSet::from_iter(result))
+        Ok(result.iter().copied().collect())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the number of type-variables of the term pointed-to by
+/// `term_handle`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_type_variables_count<T>(term_handle: T) -> Result<usize, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_type_variables_count(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result as usize)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns at most `limit` type-variables of the term pointed-to by
+/// `term_handle`, starting from the type-variable at `offset`, in the order in
+/// which the kernel lists them, set by `kernel_set_variable_order`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_type_variables_range<T>(
+    term_handle: T,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<Name>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result = alloc::buffer(limit);
+    let mut result_size: u64 = limit as u64;
+
+    let status = unsafe {
+        __term_type_variables_range(
+            *term_handle.as_ref().clone() as u64,
+            offset as u64,
+            result.as_mut_ptr(),
+            &mut result_size as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        result.truncate(result_size as usize);
+
+        Ok(result.to_vec())
     } else {
         Err(ErrorCode::try_from(status).unwrap())
     }
@@ -1243,9 +1556,9 @@ where
 {
     let mut result: u64 = 0;
 
-    let mut domain: Vec<u64> = vec![];
-    let mut types: Vec<u64> = vec![];
-    let mut range: Vec<u64> = vec![];
+    let mut domain = alloc::buffer(0);
+    let mut types = alloc::buffer(0);
+    let mut range = alloc::buffer(0);
 
     for ((d, t), r) in substitution.iter() {
         domain.push(d.clone().into());
@@ -1316,3 +1629,624 @@ where
         Err(ErrorCode::try_from(status).unwrap())
     }
 }
+
+/// Registers the conditional `COND condition left right`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_conditional<C, L, R>(
+    condition_handle: C,
+    left_handle: L,
+    right_handle: R,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    C: Into<Handle<tags::Term>>,
+    L: Into<Handle<tags::Term>>,
+    R: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_conditional(
+            *condition_handle.into() as u64,
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the local definition `LET function argument`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_let<F, A>(
+    function_handle: F,
+    argument_handle: A,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    F: Into<Handle<tags::Term>>,
+    A: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_let(
+            *function_handle.into() as u64,
+            *argument_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Splits a conditional into its condition and its two branches.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_conditional<T>(
+    term_handle: T,
+) -> Result<Conditional, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_condition_handle: u64 = 0;
+    let mut result_left_handle: u64 = 0;
+    let mut result_right_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_conditional(
+            *term_handle.into() as u64,
+            &mut result_condition_handle as *mut u64,
+            &mut result_left_handle as *mut u64,
+            &mut result_right_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_condition_handle as usize, PhantomData),
+            Handle::new(result_left_handle as usize, PhantomData),
+            Handle::new(result_right_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Splits a local definition into its function and its argument.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_let<T>(
+    term_handle: T,
+) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_function_handle: u64 = 0;
+    let mut result_argument_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_let(
+            *term_handle.into() as u64,
+            &mut result_function_handle as *mut u64,
+            &mut result_argument_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_function_handle as usize, PhantomData),
+            Handle::new(result_argument_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Tests whether a term is a conditional.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_test_conditional<T>(term_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: bool = false;
+
+    let status = unsafe {
+        __term_test_conditional(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut bool,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Tests whether a term is a local definition.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_test_let<T>(term_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: bool = false;
+
+    let status = unsafe {
+        __term_test_let(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut bool,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the pair `PAIR left right`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_pair<L, R>(
+    left_handle: L,
+    right_handle: R,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    L: Into<Handle<tags::Term>>,
+    R: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_pair(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the first projection `FST pair`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_first<T>(
+    pair_handle: T,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_first(
+            *pair_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the second projection `SND pair`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_second<T>(
+    pair_handle: T,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_second(
+            *pair_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Splits a pair into its two components.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_pair<T>(
+    term_handle: T,
+) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_left_handle: u64 = 0;
+    let mut result_right_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_pair(
+            *term_handle.into() as u64,
+            &mut result_left_handle as *mut u64,
+            &mut result_right_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_left_handle as usize, PhantomData),
+            Handle::new(result_right_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Tests whether a term is a pair.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_test_pair<T>(term_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: bool = false;
+
+    let status = unsafe {
+        __term_test_pair(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut bool,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the set membership `IN element set`, where `set` is a predicate
+/// over the type of `element`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_membership<T, U>(
+    element_handle: T,
+    set_handle: U,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_membership(
+            *element_handle.into() as u64,
+            *set_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Registers the set comprehension `SETSPEC (λname:tau. body)`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_register_comprehension<N, T, B>(
+    name: N,
+    type_handle: T,
+    body_handle: B,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    N: Into<Name>,
+    T: Into<Handle<tags::Type>>,
+    B: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_register_comprehension(
+            name.into(),
+            *type_handle.into() as u64,
+            *body_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Splits a set membership into its element and set.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_membership<T>(
+    term_handle: T,
+) -> Result<(Handle<tags::Term>, Handle<tags::Term>), ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_element_handle: u64 = 0;
+    let mut result_set_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_membership(
+            *term_handle.into() as u64,
+            &mut result_element_handle as *mut u64,
+            &mut result_set_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            Handle::new(result_element_handle as usize, PhantomData),
+            Handle::new(result_set_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Splits a set comprehension into its bound name, element type, and body.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_split_comprehension<T>(
+    term_handle: T,
+) -> Result<(Name, Handle<tags::Type>, Handle<tags::Term>), ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result_name: u64 = 0;
+    let mut result_type_handle: u64 = 0;
+    let mut result_body_handle: u64 = 0;
+
+    let status = unsafe {
+        __term_split_comprehension(
+            *term_handle.into() as u64,
+            &mut result_name as *mut u64,
+            &mut result_type_handle as *mut u64,
+            &mut result_body_handle as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok((
+            result_name,
+            Handle::new(result_type_handle as usize, PhantomData),
+            Handle::new(result_body_handle as usize, PhantomData),
+        ))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Tests whether a term is a set membership.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_test_membership<T>(term_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: bool = false;
+
+    let status = unsafe {
+        __term_test_membership(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut bool,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Tests whether a term is a set comprehension.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_test_comprehension<T>(term_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: bool = false;
+
+    let status = unsafe {
+        __term_test_comprehension(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut bool,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Folds `callback` over the term pointed-to by `term_handle`, bottom-up, with
+/// the kernel performing the traversal.  The callback is called once per
+/// distinct subterm, children before parents, and its result for
+/// `term_handle` is returned.  Note that the host invokes the callback through
+/// the guest's function table, which must therefore be exported.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_fold<T>(
+    term_handle: T,
+    callback: FoldCallback,
+    context: u64,
+) -> Result<u64, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_fold(
+            *term_handle.as_ref().clone() as u64,
+            callback as usize as u32,
+            context,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns a description of the outermost positions at which the terms
+/// pointed-to by `left_handle` and `right_handle` differ, one per line, or an
+/// empty string if the two terms are alpha-equivalent.  Each line gives the
+/// path to a position, as dot-separated steps `fun` and `arg` into
+/// applications, `type` and `body` into λ-abstractions, `type` into variables
+/// and constants, and argument indices into types, followed by the subterms,
+/// or subtypes, at that position.  The description is intended for
+/// diagnostics, and is not guaranteed to be stable, or parseable.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_diff<L, R>(
+    left_handle: L,
+    right_handle: R,
+) -> Result<String, ErrorCode>
+where
+    L: AsRef<Handle<tags::Term>>,
+    R: AsRef<Handle<tags::Term>>,
+{
+    let mut diff: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_identify`, the first call only discovers the length
+     * of the description.
+     */
+    loop {
+        let capacity = diff.len() as u64;
+        let mut length: u64 = capacity;
+
+        let status = unsafe {
+            __term_diff(
+                *left_handle.as_ref().clone() as u64,
+                *right_handle.as_ref().clone() as u64,
+                diff.as_mut_ptr(),
+                &mut length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if length <= capacity {
+            diff.truncate(length as usize);
+
+            return Ok(String::from_utf8(diff)
+                .expect("Term difference is not valid UTF-8"));
+        }
+
+        diff.resize(length as usize, 0u8);
+    }
+}
+
+/// Returns the subterm of the term pointed-to by `term_handle` at `position`,
+/// the sequence of the indices of the children taken from the root: the
+/// function and argument of an application are its children `0` and `1`, and
+/// the body of a λ-abstraction is its child `0`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_subterm_at<T>(
+    term_handle: T,
+    position: &[usize],
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let position: Vec<u64> =
+        position.iter().map(|index| *index as u64).collect();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_subterm_at(
+            *term_handle.as_ref().clone() as u64,
+            position.as_ptr(),
+            position.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Replaces the subterm of the term pointed-to by `term_handle` at `position`,
+/// as for `term_subterm_at`, with the term pointed-to by `replacement_handle`,
+/// returning the new term.  Variables of the replacement bound by an enclosing
+/// λ-abstraction are captured by it.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn term_replace_at<T, U>(
+    term_handle: T,
+    position: &[usize],
+    replacement_handle: U,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+    U: AsRef<Handle<tags::Term>>,
+{
+    let position: Vec<u64> =
+        position.iter().map(|index| *index as u64).collect();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __term_replace_at(
+            *term_handle.as_ref().clone() as u64,
+            position.as_ptr(),
+            position.len() as u64,
+            *replacement_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -0,0 +1,282 @@
+//! # Bindings to Supervisionary's term-view ABI
+//!
+//! Analyses that visit every node of a large term, such as computing its size
+//! or searching it for a pattern, would otherwise make a host call per node.
+//! Instead, a guest may reserve a region of its memory with the kernel, and
+//! ask the kernel to copy a read-only view of a term into it, which is then
+//! traversed locally.  A view lists each distinct subterm of the term once,
+//! children before parents, with the term itself last.
+//!
+//! The view is a copy: it does not change if kernel objects are registered
+//! afterwards, and the kernel never reads it back.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{tags, ErrorCode, Handle, Name, RawHandle};
+use std::{
+    cell::Cell,
+    convert::{TryFrom, TryInto},
+    marker::PhantomData,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI bindings.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
+extern "C" {
+    /// Raw ABI binding to the `Term.View.Reserve` function.
+    fn __term_view_reserve(base: *mut u8, size: u64) -> i32;
+    /// Raw ABI binding to the `Term.View.Map` function.
+    fn __term_view_map(term_handle: RawHandle, size: *mut u64) -> i32;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Layout.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The tag of a node describing a variable.
+const TAG_VARIABLE: u64 = 0;
+/// The tag of a node describing a constant.
+const TAG_CONSTANT: u64 = 1;
+/// The tag of a node describing an application.
+const TAG_APPLICATION: u64 = 2;
+/// The tag of a node describing a lambda-abstraction.
+const TAG_LAMBDA: u64 = 3;
+
+/// The size, in bytes, of each node of a view: a tag, a handle, and three
+/// fields, each a little-endian 64-bit word.
+const NODE_SIZE: usize = 5 * 8;
+
+/// The capacity, in bytes, of a region when first created.
+const INITIAL_CAPACITY: usize = 4096;
+
+thread_local! {
+    /// The address and size of the region most recently reserved with the
+    /// kernel, or zeroes if none has been.
+    static RESERVED: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Views.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A node of a term view.  Subterms are referred to by their index within the
+/// view, which is always smaller than the index of the node referring to them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TermViewNode {
+    /// A variable, with its name and type.
+    Variable { name: Name, tau: Handle<tags::Type> },
+    /// A constant, at a type.
+    Constant {
+        constant: Handle<tags::Constant>,
+        tau: Handle<tags::Type>,
+    },
+    /// An application of the node at index `left` to the node at index
+    /// `right`.
+    Application { left: usize, right: usize },
+    /// A lambda-abstraction over a variable with `name` and type `tau`, whose
+    /// body is the node at index `body`.
+    Lambda {
+        name: Name,
+        tau: Handle<tags::Type>,
+        body: usize,
+    },
+}
+
+/// A read-only view of a term, borrowed from the region that it was mapped
+/// into.
+#[derive(Clone, Debug)]
+pub struct TermView<'a> {
+    /// The nodes of the view, excluding the leading node count.
+    nodes: &'a [u8],
+}
+
+impl<'a> TermView<'a> {
+    /// Returns the number of nodes of the view, that is the number of distinct
+    /// subterms of the term.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len() / NODE_SIZE
+    }
+
+    /// Returns `true` iff the view has no nodes, which is never the case for a
+    /// view mapped by the kernel.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the index of the node of the term itself.
+    #[inline]
+    pub fn root(&self) -> usize {
+        self.len() - 1
+    }
+
+    /// Returns the `index`th word of the `node`th node.
+    fn word(&self, node: usize, index: usize) -> u64 {
+        let offset = node * NODE_SIZE + index * 8;
+
+        u64::from_le_bytes(self.nodes[offset..offset + 8].try_into().unwrap())
+    }
+
+    /// Returns the handle of the subterm described by the node at `index`, or
+    /// `None` if there is no such node.
+    pub fn handle(&self, index: usize) -> Option<Handle<tags::Term>> {
+        if index < self.len() {
+            Some(Handle::new(self.word(index, 1) as usize, PhantomData))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the node at `index`, or `None` if there is no such node.
+    pub fn node(&self, index: usize) -> Option<TermViewNode> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let fields = [
+            self.word(index, 2),
+            self.word(index, 3),
+            self.word(index, 4),
+        ];
+
+        match self.word(index, 0) {
+            TAG_VARIABLE => Some(TermViewNode::Variable {
+                name: fields[0],
+                tau: Handle::new(fields[1] as usize, PhantomData),
+            }),
+            TAG_CONSTANT => Some(TermViewNode::Constant {
+                constant: Handle::new(fields[0] as usize, PhantomData),
+                tau: Handle::new(fields[1] as usize, PhantomData),
+            }),
+            TAG_APPLICATION => Some(TermViewNode::Application {
+                left: fields[0] as usize,
+                right: fields[1] as usize,
+            }),
+            TAG_LAMBDA => Some(TermViewNode::Lambda {
+                name: fields[0],
+                tau: Handle::new(fields[1] as usize, PhantomData),
+                body: fields[2] as usize,
+            }),
+            _otherwise => None,
+        }
+    }
+}
+
+/// A region of the guest's memory into which the kernel copies term views.
+/// The region grows as needed to fit the views mapped into it.
+#[derive(Debug)]
+pub struct TermViewRegion {
+    /// The memory of the region.
+    buffer: Vec<u8>,
+}
+
+impl TermViewRegion {
+    /// Creates a new, empty, region.
+    #[inline]
+    pub fn new() -> Self {
+        TermViewRegion {
+            buffer: vec![0u8; INITIAL_CAPACITY],
+        }
+    }
+
+    /// Reserves the region with the kernel, unless it is already the region
+    /// most recently reserved.
+    fn reserve(&mut self) -> Result<(), ErrorCode> {
+        let base = self.buffer.as_mut_ptr();
+        let region = (base as usize, self.buffer.len());
+
+        if RESERVED.with(|r| r.get()) == region {
+            return Ok(());
+        }
+
+        let status = unsafe { __term_view_reserve(base, region.1 as u64) };
+
+        if status == 0 {
+            RESERVED.with(|r| r.set(region));
+            Ok(())
+        } else {
+            Err(ErrorCode::try_from(status).unwrap())
+        }
+    }
+
+    /// Copies a view of the term pointed-to by `handle` into the region,
+    /// growing the region first if the view does not fit, and returns the
+    /// view.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn map<T>(&mut self, handle: T) -> Result<TermView<'_>, ErrorCode>
+    where
+        T: AsRef<Handle<tags::Term>>,
+    {
+        let handle = **handle.as_ref() as u64;
+
+        loop {
+            self.reserve()?;
+
+            let mut size: u64 = 0;
+
+            let status =
+                unsafe { __term_view_map(handle, &mut size as *mut u64) };
+
+            if status == 0 {
+                return Ok(TermView {
+                    nodes: &self.buffer[8..size as usize],
+                });
+            }
+
+            match ErrorCode::try_from(status).unwrap() {
+                ErrorCode::InvalidLength => {
+                    /* NB: the kernel returns the size of the view, and the
+                     * grown buffer is reserved again before retrying.
+                     */
+                    self.buffer.resize(size as usize, 0u8);
+                }
+                otherwise => return Err(otherwise),
+            }
+        }
+    }
+}
+
+impl Default for TermViewRegion {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TermViewRegion {
+    fn drop(&mut self) {
+        /* NB: the kernel only writes to the region when a view is mapped, and
+         * a region is always reserved before mapping, so it suffices to forget
+         * that this region was reserved.
+         */
+        RESERVED.with(|r| {
+            if r.get().0 == self.buffer.as_ptr() as usize {
+                r.set((0, 0));
+            }
+        });
+    }
+}
@@ -13,17 +13,105 @@
 //! [Dominic Mulligan]: https://dominic-mulligan.co.uk
 //! [Arm Research]: http://www.arm.com/research
 
+use crate::alloc;
 use crate::raw::{tags, ErrorCode, Handle, Name, RawHandle};
 use std::{convert::TryFrom, marker::PhantomData};
 
+////////////////////////////////////////////////////////////////////////////////
+// Unfolding modes.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Which occurrences of a defined constant are unfolded by
+/// `theorem_register_unfold`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum UnfoldMode {
+    /// Only the leftmost-outermost occurrence.
+    Once,
+    /// Every occurrence, to a fixpoint.
+    All,
+    /// Every occurrence, to a fixpoint, within the subterm at the position, as
+    /// for `term_subterm_at`.
+    Within(Vec<usize>),
+}
+
+impl From<&UnfoldMode> for (u64, Vec<u64>) {
+    fn from(mode: &UnfoldMode) -> (u64, Vec<u64>) {
+        match mode {
+            UnfoldMode::Once => (0, Vec::new()),
+            UnfoldMode::All => (1, Vec::new()),
+            UnfoldMode::Within(position) => {
+                (2, position.iter().map(|index| *index as u64).collect())
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Trust levels.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The trust level of a theorem, recording what the theorem depends upon
+/// besides the kernel's inference rules, ordered from most to least trusted.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TrustLevel {
+    /// The theorem was derived by the kernel's inference rules alone.
+    KernelProved,
+    /// The theorem depends upon an axiom postulated by the host, but upon no
+    /// oracle.
+    AxiomDependent,
+    /// The theorem depends upon an oracle, i.e. is tainted.
+    OracleDependent,
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into a trust
+/// level.
+impl TryFrom<u64> for TrustLevel {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TrustLevel::KernelProved),
+            1 => Ok(TrustLevel::AxiomDependent),
+            2 => Ok(TrustLevel::OracleDependent),
+            _otherwise => Err(()),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // ABI bindings.
 ////////////////////////////////////////////////////////////////////////////////
 
 /* TODO: add bindings for existential inference rules */
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
 extern "C" {
     /// Raw ABI binding to the `Theorem.IsRegistered` function.
     fn __theorem_is_registered(theorem_handle: RawHandle) -> bool;
+    /// Raw ABI binding to the `Theorem.IsTainted` function.
+    fn __theorem_is_tainted(theorem_handle: RawHandle, result: *mut u32) -> i32;
+    /// Raw ABI binding to the `Theorem.TrustLevel` function.
+    fn __theorem_trust_level(
+        theorem_handle: RawHandle,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.AlphaEqual` function.
+    fn __theorem_alpha_equal(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut u32,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.ExistsWithConclusion` function.
+    fn __theorem_exists_with_conclusion(
+        term_handle: RawHandle,
+        result: *mut u32,
+    ) -> i32;
     /// Raw ABI binding to the `Theorem.Size` function.
     fn __theorem_size(theorem_handle: RawHandle, result: *mut u64) -> i32;
     /// Raw ABI binding to the `Theorem.Split.Conclusion` function.
@@ -37,6 +125,18 @@ extern "C" {
         hypotheses_base: *mut RawHandle,
         hypotheses_length: *mut u64,
     ) -> i32;
+    /// Raw ABI binding to the `Theorem.Hypotheses.Count` function.
+    fn __theorem_hypotheses_count(
+        theorem_handle: RawHandle,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Split.Hypotheses.Range` function.
+    fn __theorem_split_hypotheses_range(
+        theorem_handle: RawHandle,
+        offset: u64,
+        hypotheses_base: *mut RawHandle,
+        hypotheses_length: *mut u64,
+    ) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Assumption` function.
     fn __theorem_register_assumption(
         term_handle: RawHandle,
@@ -70,6 +170,76 @@ extern "C" {
         right_handle: RawHandle,
         result: *mut RawHandle,
     ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.ConditionalCongruence`
+    /// function.
+    fn __theorem_register_conditional_congruence(
+        condition_handle: RawHandle,
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.LetCongruence` function.
+    fn __theorem_register_let_congruence(
+        function_handle: RawHandle,
+        argument_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.FirstProjection` function.
+    fn __theorem_register_first_projection(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.SecondProjection` function.
+    fn __theorem_register_second_projection(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.SurjectivePairing` function.
+    fn __theorem_register_surjective_pairing(
+        pair_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.MembershipDefinition` function.
+    fn __theorem_register_membership_definition(
+        element_handle: RawHandle,
+        set_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.ComprehensionDefinition` function.
+    fn __theorem_register_comprehension_definition(
+        set_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.EmptySetDefinition` function.
+    fn __theorem_register_empty_set_definition(
+        type_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.UniversalSetDefinition` function.
+    fn __theorem_register_universal_set_definition(
+        type_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.UnionDefinition` function.
+    fn __theorem_register_union_definition(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.IntersectionDefinition` function.
+    fn __theorem_register_intersection_definition(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.SubsetDefinition` function.
+    fn __theorem_register_subset_definition(
+        left_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Lambda` function.
     fn __theorem_register_lambda(
         name: Name,
@@ -108,11 +278,7 @@ extern "C" {
         result: *mut RawHandle,
     ) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Truth.Introduction` function.
-    fn __theorem_register_truth_introduction(
-        hypotheses_base: *const RawHandle,
-        hypotheses_length: u64,
-        result: *mut RawHandle,
-    ) -> i32;
+    fn __theorem_register_truth_introduction(result: *mut RawHandle) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Falsity.Elimination` function.
     fn __theorem_register_falsity_elimination(
         theorem_handle: RawHandle,
@@ -191,9 +357,9 @@ extern "C" {
     ) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Forall.Introduction` function.
     fn __theorem_register_forall_introduction(
-        theorem_handle: RawHandle,
         name: Name,
         type_handle: RawHandle,
+        theorem_handle: RawHandle,
         result: *mut RawHandle,
     ) -> i32;
     /// Raw ABI binding to the `Theorem.Register.Forall.Elimination` function.
@@ -202,6 +368,75 @@ extern "C" {
         term_handle: RawHandle,
         result: *mut RawHandle,
     ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.Exists.Introduction` function.
+    fn __theorem_register_exists_introduction(
+        theorem_handle: RawHandle,
+        term_handle: RawHandle,
+        witness_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.Exists.Elimination` function.
+    fn __theorem_register_exists_elimination(
+        left_handle: RawHandle,
+        variable_handle: RawHandle,
+        right_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.StatementHash` function.
+    fn __theorem_statement_hash(
+        theorem_handle: RawHandle,
+        result: *mut u64,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.StatementAsTerm` function.
+    fn __theorem_statement_as_term(
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.ForallCongruence` function.
+    fn __theorem_register_forall_congruence(
+        name: Name,
+        type_handle: RawHandle,
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.ExistsCongruence` function.
+    fn __theorem_register_exists_congruence(
+        name: Name,
+        type_handle: RawHandle,
+        theorem_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.Unfold` function.
+    fn __theorem_register_unfold(
+        definition_handle: RawHandle,
+        term_handle: RawHandle,
+        mode: u64,
+        path_base: *const u64,
+        path_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.RewriteAt` function.
+    fn __theorem_register_rewrite_at(
+        equation_handle: RawHandle,
+        term_handle: RawHandle,
+        position_base: *const u64,
+        position_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.RewriteHypothesis` function.
+    fn __theorem_register_rewrite_hypothesis(
+        theorem_handle: RawHandle,
+        index: u64,
+        equation_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `Theorem.Register.AssumptionMatching` function.
+    fn __theorem_register_assumption_matching(
+        hypotheses_base: *const RawHandle,
+        hypotheses_length: u64,
+        pattern_handle: RawHandle,
+        result: *mut RawHandle,
+    ) -> i32;
 }
 
 #[inline]
@@ -212,6 +447,142 @@ where
     unsafe { __theorem_is_registered(*theorem_handle.as_ref().clone() as u64) }
 }
 
+/// Returns `true` iff the theorem pointed-to by `theorem_handle` is tainted,
+/// that is was registered by the kernel's linear arithmetic decision
+/// procedure, or derived from a theorem that was.  Theorems are never tainted
+/// if the host does not support `Feature::LinearArith`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_is_tainted<T>(theorem_handle: T) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u32 = 0;
+
+    let status = unsafe {
+        __theorem_is_tainted(
+            *theorem_handle.as_ref().clone() as u64,
+            &mut result as *mut u32,
+        )
+    };
+
+    if status == 0 {
+        Ok(result != 0)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the trust level of the theorem pointed-to by `theorem_handle`: that
+/// of the inference rule that registered it, or that of its least trusted
+/// premiss, whichever is lower.  Lets prover-space code enforce a trust policy
+/// of its own, e.g. only relying upon theorems proved by the kernel alone.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_trust_level<T>(
+    theorem_handle: T,
+) -> Result<TrustLevel, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_trust_level(
+            *theorem_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(TrustLevel::try_from(result).unwrap())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns `true` iff the theorems pointed-to by `left_handle` and
+/// `right_handle` have the same statement up to alpha-equivalence: the same
+/// conclusion, and the same set of hypotheses.  Saves splitting and comparing
+/// the sequents of theorems, e.g. when caching theorems or detecting
+/// duplicates.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_alpha_equal<T, U>(
+    left_handle: T,
+    right_handle: U,
+) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+    U: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u32 = 0;
+
+    let status = unsafe {
+        __theorem_alpha_equal(
+            *left_handle.as_ref().clone() as u64,
+            *right_handle.as_ref().clone() as u64,
+            &mut result as *mut u32,
+        )
+    };
+
+    if status == 0 {
+        Ok(result != 0)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns `true` iff some registered theorem has the term pointed-to by
+/// `term_handle`, or one alpha-equivalent to it, as its conclusion, whatever
+/// its hypotheses.  Cheap enough to call before searching for a proof of a
+/// goal, to check whether it has already been proved.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_exists_with_conclusion<T>(
+    term_handle: T,
+) -> Result<bool, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let mut result: u32 = 0;
+
+    let status = unsafe {
+        __theorem_exists_with_conclusion(
+            *term_handle.as_ref().clone() as u64,
+            &mut result as *mut u32,
+        )
+    };
+
+    if status == 0 {
+        Ok(result != 0)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+declare_hostcall! {
+    /// Returns the handles of every registered theorem with the term pointed-to
+    /// by `conclusion`, or one alpha-equivalent to it, as its conclusion,
+    /// whatever its hypotheses, in ascending order.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn theorem_find_by_conclusion(
+        conclusion: &Term,
+    ) -> [Theorem] = __theorem_find_by_conclusion;
+
+    /// Returns the handles of every registered theorem whose conclusion is an
+    /// instance of the term pointed-to by `pattern`, up to alpha-equivalence,
+    /// whatever its hypotheses, in ascending order.  Every type-variable of
+    /// `pattern` is a wildcard, standing for any type, though the same type
+    /// wherever it occurs, but nothing else in `pattern` is.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    pub fn theorem_find_matching(
+        pattern: &Term,
+    ) -> [Theorem] = __theorem_find_matching;
+}
+
 pub fn theorem_size<T>(theorem_handle: T) -> Result<usize, ErrorCode>
 where
     T: AsRef<Handle<tags::Theorem>>,
@@ -262,7 +633,7 @@ where
 {
     let theorem_handle = theorem_handle.into();
     let size = theorem_size(&theorem_handle)?;
-    let mut hypotheses = vec![0u64; size];
+    let mut hypotheses = alloc::buffer(size);
     let mut hypothesis_count: u64 = 0;
 
     let status = unsafe {
@@ -285,9 +656,74 @@ where
     }
 }
 
+/// Returns the number of hypotheses of the theorem pointed-to by
+/// `theorem_handle`, so that a buffer of exactly the right size may be
+/// allocated for them.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_hypotheses_count<T>(
+    theorem_handle: T,
+) -> Result<usize, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_hypotheses_count(
+            *theorem_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result as usize)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns at most `limit` hypotheses of the theorem pointed-to by
+/// `theorem_handle`, starting from the hypothesis at `offset`, so that large
+/// sets of hypotheses may be fetched in chunks.  Fewer than `limit` hypotheses
+/// are returned only once the end of the hypotheses is reached.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_split_hypotheses_range<T>(
+    theorem_handle: T,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<Handle<tags::Term>>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+{
+    let mut hypotheses = alloc::buffer(limit);
+    let mut hypothesis_count: u64 = limit as u64;
+
+    let status = unsafe {
+        __theorem_split_hypotheses_range(
+            *theorem_handle.as_ref().clone() as u64,
+            offset as u64,
+            hypotheses.as_mut_ptr(),
+            &mut hypothesis_count as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        hypotheses.truncate(hypothesis_count as usize);
+
+        Ok(hypotheses
+            .iter()
+            .map(|h| Handle::new(*h as usize, PhantomData))
+            .collect())
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
 pub fn theorem_register_assumption<T>(
     term_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Term>>,
 {
@@ -308,10 +744,10 @@ where
 pub fn theorem_register_weaken<T, U>(
     term_handle: T,
     theorem_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Term>>,
-    T: Into<Handle<tags::Theorem>>,
+    U: Into<Handle<tags::Theorem>>,
 {
     let term_handle = *term_handle.into() as u64;
     let theorem_handle = *theorem_handle.into() as u64;
@@ -334,7 +770,7 @@ where
 
 pub fn theorem_register_reflexivity<T>(
     term_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Term>>,
 {
@@ -454,7 +890,7 @@ where
 
 pub fn theorem_register_beta<T>(
     term_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Term>>,
 {
@@ -474,7 +910,7 @@ where
 
 pub fn theorem_register_eta<T>(
     term_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Term>>,
 {
@@ -502,9 +938,9 @@ where
     V: Into<Handle<tags::Term>> + Clone,
 {
     let mut result: u64 = 0;
-    let mut domain: Vec<u64> = vec![];
-    let mut types: Vec<u64> = vec![];
-    let mut range: Vec<u64> = vec![];
+    let mut domain = alloc::buffer(0);
+    let mut types = alloc::buffer(0);
+    let mut range = alloc::buffer(0);
 
     for ((d, t), r) in substitution.iter() {
         domain.push(d.clone().into());
@@ -567,25 +1003,12 @@ where
     }
 }
 
-pub fn theorem_register_truth_introduction<T>(
-    hypotheses: Vec<T>,
-) -> Result<Handle<tags::Term>, ErrorCode>
-where
-    T: Into<Handle<tags::Term>> + Clone,
-{
+pub fn theorem_register_truth_introduction(
+) -> Result<Handle<tags::Theorem>, ErrorCode> {
     let mut result: u64 = 0;
-    let hypotheses: Vec<u64> = hypotheses
-        .iter()
-        .cloned()
-        .map(|h| *(h.into()) as u64)
-        .collect();
 
     let status = unsafe {
-        __theorem_register_truth_introduction(
-            hypotheses.as_ptr(),
-            hypotheses.len() as u64,
-            &mut result as *mut u64,
-        )
+        __theorem_register_truth_introduction(&mut result as *mut u64)
     };
 
     if status == 0 {
@@ -598,7 +1021,7 @@ where
 pub fn theorem_register_falsity_elimination<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -623,7 +1046,7 @@ where
 pub fn theorem_register_conjunction_introduction<T, U>(
     left_handle: T,
     right_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Theorem>>,
@@ -647,7 +1070,7 @@ where
 
 pub fn theorem_register_conjunction_left_elimination<T>(
     theorem_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
 {
@@ -669,7 +1092,7 @@ where
 
 pub fn theorem_register_conjunction_right_elimination<T>(
     theorem_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
 {
@@ -692,7 +1115,7 @@ where
 pub fn theorem_register_disjunction_left_introduction<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -717,7 +1140,7 @@ where
 pub fn theorem_register_disjunction_right_introduction<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -743,7 +1166,7 @@ pub fn theorem_register_disjunction_elimination<T, U, V>(
     left_handle: T,
     mid_handle: U,
     right_handle: V,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Theorem>>,
@@ -770,7 +1193,7 @@ where
 pub fn theorem_negation_introduction<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -795,7 +1218,7 @@ where
 pub fn theorem_register_negation_elimination<T, U>(
     left_handle: T,
     right_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Theorem>>,
@@ -820,7 +1243,7 @@ where
 pub fn theorem_implication_introduction<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -845,7 +1268,7 @@ where
 pub fn theorem_register_implication_elimination<T, U>(
     left_handle: T,
     right_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Theorem>>,
@@ -870,7 +1293,7 @@ where
 pub fn theorem_register_iff_introduction<T, U>(
     left_handle: T,
     right_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Theorem>>,
@@ -894,7 +1317,7 @@ where
 
 pub fn theorem_register_iff_left_elimination<T>(
     theorem_handle: T,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
 {
@@ -918,7 +1341,7 @@ pub fn theorem_forall_introduction<N, T, U>(
     name_handle: N,
     type_handle: T,
     theorem_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     N: Into<Name>,
     T: Into<Handle<tags::Type>>,
@@ -945,7 +1368,7 @@ where
 pub fn theorem_forall_elimination<T, U>(
     theorem_handle: T,
     term_handle: U,
-) -> Result<Handle<tags::Term>, ErrorCode>
+) -> Result<Handle<tags::Theorem>, ErrorCode>
 where
     T: Into<Handle<tags::Theorem>>,
     U: Into<Handle<tags::Term>>,
@@ -966,3 +1389,670 @@ where
         Err(ErrorCode::try_from(status).unwrap())
     }
 }
+
+/// Derives `Γ ⊢ ∃x:τ. ɸ` from the existential `∃x:τ. ɸ`, a witness `t` of type
+/// `τ`, and the theorem `Γ ⊢ ɸ[t/x]`.
+pub fn theorem_exists_introduction<T, U, V>(
+    theorem_handle: T,
+    term_handle: U,
+    witness_handle: V,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Theorem>>,
+    U: Into<Handle<tags::Term>>,
+    V: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_exists_introduction(
+            *theorem_handle.into() as u64,
+            *term_handle.into() as u64,
+            *witness_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `Γ ∪ Δ ⊢ ψ` from the theorems `Γ ⊢ ∃x:τ. ɸ` and
+/// `Δ ∪ {ɸ[v/x]} ⊢ ψ`, where the eigenvariable `v` is free in neither
+/// `∃x:τ. ɸ`, nor `ψ`, nor any premiss in `Γ` or `Δ`.
+pub fn theorem_exists_elimination<T, U, V>(
+    left_handle: T,
+    variable_handle: U,
+    right_handle: V,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Theorem>>,
+    U: Into<Handle<tags::Term>>,
+    V: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_exists_elimination(
+            *left_handle.into() as u64,
+            *variable_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `COND c r s = COND d t u` from the equalities `c = d`, `r = t`, and
+/// `s = u`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_conditional_congruence<C, L, R>(
+    condition_handle: C,
+    left_handle: L,
+    right_handle: R,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    C: Into<Handle<tags::Theorem>>,
+    L: Into<Handle<tags::Theorem>>,
+    R: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_conditional_congruence(
+            *condition_handle.into() as u64,
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `LET f r = LET g s` from the equalities `f = g` and `r = s`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_let_congruence<F, A>(
+    function_handle: F,
+    argument_handle: A,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    F: Into<Handle<tags::Theorem>>,
+    A: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_let_congruence(
+            *function_handle.into() as u64,
+            *argument_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `FST (PAIR r s) = r`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_first_projection<L, R>(
+    left_handle: L,
+    right_handle: R,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    L: Into<Handle<tags::Term>>,
+    R: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_first_projection(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `SND (PAIR r s) = s`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_second_projection<L, R>(
+    left_handle: L,
+    right_handle: R,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    L: Into<Handle<tags::Term>>,
+    R: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_second_projection(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `PAIR (FST p) (SND p) = p`, for `p` of product type.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_surjective_pairing<T>(
+    pair_handle: T,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_surjective_pairing(
+            *pair_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `IN x s = s x`, the definition of set membership.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_membership_definition<T, U>(
+    element_handle: T,
+    set_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_membership_definition(
+            *element_handle.into() as u64,
+            *set_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `SETSPEC s = s`, the definition of set comprehension.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_comprehension_definition<T>(
+    set_handle: T,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_comprehension_definition(
+            *set_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `EMPTY = λx:tau. F`, the definition of the empty set of
+/// elements of type `tau`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_empty_set_definition<T>(
+    type_handle: T,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_empty_set_definition(
+            *type_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `UNIV = λx:tau. T`, the definition of the universal set of
+/// elements of type `tau`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_universal_set_definition<T>(
+    type_handle: T,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Type>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_universal_set_definition(
+            *type_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `UNION s t = λx. s x ∨ t x`, the definition of set union.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_union_definition<T, U>(
+    left_handle: T,
+    right_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_union_definition(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `INTER s t = λx. s x ∧ t x`, the definition of set
+/// intersection.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_intersection_definition<T, U>(
+    left_handle: T,
+    right_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_intersection_definition(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Derives `SUBSET s t = ∀x. s x ⇒ t x`, the definition of the subset
+/// relation.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_subset_definition<T, U>(
+    left_handle: T,
+    right_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: Into<Handle<tags::Term>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_subset_definition(
+            *left_handle.into() as u64,
+            *right_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns a 64-bit hash of the statement of the theorem pointed-to by
+/// `theorem_handle`.  Theorems whose statements are ⍺-equivalent share a hash,
+/// which is stable across runs that name their variables, constants and
+/// type-formers identically.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_statement_hash<T>(theorem_handle: T) -> Result<u64, ErrorCode>
+where
+    T: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_statement_hash(
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(result)
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns a proposition equivalent to the statement of the theorem pointed-to
+/// by `theorem_handle`.  A theorem without premisses is quoted as its
+/// conclusion, and otherwise as an implication from the conjunction of its
+/// premisses to its conclusion.  No theorem is registered.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_statement_as_term<T>(
+    theorem_handle: T,
+) -> Result<Handle<tags::Term>, ErrorCode>
+where
+    T: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_statement_as_term(
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Lifts the equality `Γ ⊢ ɸ = ψ`, pointed-to by `theorem_handle`, under a
+/// universal quantifier binding the variable named `name` of the type
+/// pointed-to by `type_handle`, obtaining `Γ ⊢ (∀x:τ. ɸ) = (∀x:τ. ψ)`.
+/// The variable must not appear free in any premiss, `Γ`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_forall_congruence<N, T, U>(
+    name: N,
+    type_handle: T,
+    theorem_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    N: Into<Name>,
+    T: Into<Handle<tags::Type>>,
+    U: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_forall_congruence(
+            name.into(),
+            *type_handle.into() as u64,
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Lifts the equality `Γ ⊢ ɸ = ψ`, pointed-to by `theorem_handle`, under an
+/// existential quantifier binding the variable named `name` of the type
+/// pointed-to by `type_handle`, obtaining `Γ ⊢ (∃x:τ. ɸ) = (∃x:τ. ψ)`.
+/// The variable must not appear free in any premiss, `Γ`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_exists_congruence<N, T, U>(
+    name: N,
+    type_handle: T,
+    theorem_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    N: Into<Name>,
+    T: Into<Handle<tags::Type>>,
+    U: Into<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_exists_congruence(
+            name.into(),
+            *type_handle.into() as u64,
+            *theorem_handle.into() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Unfolds the definition `Γ ⊢ c = t`, pointed-to by `definition_handle`,
+/// within the term `s`, pointed-to by `term_handle`, obtaining `Γ ⊢ s = s'`,
+/// where `s'` is `s` with the occurrences of `c` selected by `mode` replaced
+/// by `t`.  Unfolding to a fixpoint fails if `t` mentions `c`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_unfold<T, U>(
+    definition_handle: T,
+    term_handle: U,
+    mode: &UnfoldMode,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+    U: AsRef<Handle<tags::Term>>,
+{
+    let (mode, path) = mode.into();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_unfold(
+            *definition_handle.as_ref().clone() as u64,
+            *term_handle.as_ref().clone() as u64,
+            mode,
+            path.as_ptr(),
+            path.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Rewrites the term `t`, pointed-to by `term_handle`, with the equation
+/// `Γ ⊢ l = r`, pointed-to by `equation_handle`, at `position`, where the
+/// subterm of `t` must be `l`, obtaining `Γ ⊢ t = t'`, where `t'` is `t` with
+/// that subterm replaced by `r`.  Positions are as for `term_subterm_at`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn theorem_register_rewrite_at<T, U>(
+    equation_handle: T,
+    term_handle: U,
+    position: &[usize],
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+    U: AsRef<Handle<tags::Term>>,
+{
+    let position: Vec<u64> =
+        position.iter().map(|index| *index as u64).collect();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_rewrite_at(
+            *equation_handle.as_ref().clone() as u64,
+            *term_handle.as_ref().clone() as u64,
+            position.as_ptr(),
+            position.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Rewrites every occurrence of `l` in the premiss `ψ` of the theorem
+/// `Γ ∪ {ψ} ⊢ ɸ`, pointed-to by `theorem_handle`, with the equation
+/// `Δ ⊢ l = r`, pointed-to by `equation_handle`, obtaining
+/// `Γ ∪ Δ ∪ {ψ'} ⊢ ɸ`, where `ψ'` is `ψ` with each occurrence replaced by `r`.
+/// The premiss is the one with index `index` in the list of premisses returned
+/// by `theorem_split_premisses`.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoSuchPremiss` if `index` does not address a premiss of
+/// the theorem, and `ErrorCode::ShapeMismatch` if `l` does not occur in `ψ`.
+pub fn theorem_register_rewrite_hypothesis<T, U>(
+    theorem_handle: T,
+    index: usize,
+    equation_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Theorem>>,
+    U: AsRef<Handle<tags::Theorem>>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_rewrite_hypothesis(
+            *theorem_handle.as_ref().clone() as u64,
+            index as u64,
+            *equation_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Closes a goal by assumption: registers the theorem `Γ ⊢ ψ`, where `Γ` is
+/// `hypotheses` and `ψ` is the first of them alpha-equivalent to the term
+/// pointed-to by `pattern_handle`, or if there is none the first of them that
+/// is an instance of it, with its type-variables standing for any type.  The
+/// hypothesis is found, and the theorem derived, in a single ABI call.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoMatchingHypothesis` if no hypothesis in `hypotheses`
+/// matches `pattern_handle`.
+pub fn theorem_register_assumption_matching<T, U>(
+    hypotheses: Vec<T>,
+    pattern_handle: U,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    T: Into<Handle<tags::Term>>,
+    U: AsRef<Handle<tags::Term>>,
+{
+    let hypotheses: Vec<u64> =
+        hypotheses.into_iter().map(|h| *h.into() as u64).collect();
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __theorem_register_assumption_matching(
+            hypotheses.as_ptr(),
+            hypotheses.len() as u64,
+            *pattern_handle.as_ref().clone() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
@@ -22,17 +22,23 @@ use std::{convert::TryFrom, marker::PhantomData};
 // Pre-allocated type-former handles.
 ////////////////////////////////////////////////////////////////////////////////
 
-/// A pre-allocated handle used to refer to the `Prop` type-former.
-pub const PREALLOCATED_HANDLE_TYPE_FORMER_PROP: Handle<tags::TypeFormer> =
-    Handle::new(0usize, PhantomData);
-/// A pre-allocated handle used to refer to the function-space type-former.
-pub const PREALLOCATED_HANDLE_TYPE_FORMER_ARROW: Handle<tags::TypeFormer> =
-    Handle::new(1usize, PhantomData);
+/* NB: generated by the build script from `abi-spec/preallocated-handles.txt`,
+ * which is shared with the kernel.
+ */
+include!(concat!(env!("OUT_DIR"), "/preallocated_type_former.rs"));
 
 ////////////////////////////////////////////////////////////////////////////////
 // ABI bindings.
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg_attr(
+    feature = "wasi-imports",
+    link(wasm_import_module = "supervisionary:kernel/abi")
+)]
+#[cfg_attr(
+    all(feature = "supervisionary-imports", not(feature = "wasi-imports")),
+    link(wasm_import_module = "supervisionary")
+)]
 extern "C" {
     /// Raw ABI binding to the `TypeFormer.Register` function.
     fn __type_former_register(handle: RawHandle) -> u64;
@@ -40,6 +46,23 @@ extern "C" {
     fn __type_former_is_registered(handle: RawHandle) -> bool;
     /// Raw ABI binding to the `TypeFormer.Resolve` function.
     fn __type_former_resolve(handle: RawHandle, out: *mut u64) -> i32;
+    /// Raw ABI binding to the `TypeFormer.Register.WithMetadata` function.
+    fn __type_former_register_with_metadata(
+        arity: Arity,
+        name_base: *const u8,
+        name_length: u64,
+        documentation_base: *const u8,
+        documentation_length: u64,
+        result: *mut RawHandle,
+    ) -> i32;
+    /// Raw ABI binding to the `TypeFormer.Metadata` function.
+    fn __type_former_metadata(
+        handle: RawHandle,
+        name_base: *mut u8,
+        name_length: *mut u64,
+        documentation_base: *mut u8,
+        documentation_length: *mut u64,
+    ) -> i32;
 }
 
 /// Registers a new type-former with a given `arity`.  Returns the handle to the
@@ -87,3 +110,106 @@ where
         Err(ErrorCode::try_from(result).unwrap())
     }
 }
+
+/// Registers a new type-former with a given `arity`, as `type_former_register`,
+/// attaching a `name` and a `documentation` string to it for use by
+/// pretty-printers and other tooling.  Returns the handle to the new
+/// type-former.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_former_register_with_metadata<T>(
+    arity: T,
+    name: &str,
+    documentation: &str,
+) -> Result<Handle<tags::TypeFormer>, ErrorCode>
+where
+    T: Into<Arity>,
+{
+    let mut result: u64 = 0;
+
+    let status = unsafe {
+        __type_former_register_with_metadata(
+            arity.into(),
+            name.as_ptr(),
+            name.len() as u64,
+            documentation.as_ptr(),
+            documentation.len() as u64,
+            &mut result as *mut u64,
+        )
+    };
+
+    if status == 0 {
+        Ok(Handle::new(result as usize, PhantomData))
+    } else {
+        Err(ErrorCode::try_from(status).unwrap())
+    }
+}
+
+/// Returns the name and documentation string attached to the type-former
+/// pointed-to by `handle` in the kernel's heap, if any.  Both are empty if the
+/// type-former was registered without metadata.
+///
+/// This is an experimental ABI call, which the host must explicitly enable.
+pub fn type_former_metadata<H>(handle: H) -> Result<(String, String), ErrorCode>
+where
+    H: AsRef<Handle<tags::TypeFormer>>,
+{
+    let handle = *handle.as_ref().clone() as u64;
+    let mut name: Vec<u8> = Vec::new();
+    let mut documentation: Vec<u8> = Vec::new();
+
+    /* NB: as with `kernel_identify`, the first call only discovers the lengths
+     * of the strings, and the second call fills buffers of the right size.
+     */
+    loop {
+        let name_capacity = name.len() as u64;
+        let documentation_capacity = documentation.len() as u64;
+        let mut name_length: u64 = name_capacity;
+        let mut documentation_length: u64 = documentation_capacity;
+
+        let status = unsafe {
+            __type_former_metadata(
+                handle,
+                name.as_mut_ptr(),
+                &mut name_length as *mut u64,
+                documentation.as_mut_ptr(),
+                &mut documentation_length as *mut u64,
+            )
+        };
+
+        if status != 0 {
+            return Err(ErrorCode::try_from(status).unwrap());
+        }
+
+        if name_length <= name_capacity
+            && documentation_length <= documentation_capacity
+        {
+            name.truncate(name_length as usize);
+            documentation.truncate(documentation_length as usize);
+
+            /* NB: the kernel only stores valid UTF-8. */
+            return Ok((
+                String::from_utf8(name).unwrap(),
+                String::from_utf8(documentation).unwrap(),
+            ));
+        }
+
+        name.resize(name_length as usize, 0u8);
+        documentation.resize(documentation_length as usize, 0u8);
+    }
+}
+
+declare_hostcall! {
+    /// Returns the handle of the first type-former registered with `name` in
+    /// its metadata, as entered into the kernel's symbol table.
+    ///
+    /// This is an experimental ABI call, which the host must explicitly enable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorCode::NoSuchNameRegistered` if no type-former is
+    /// registered with `name`.
+    pub fn type_former_lookup_by_name(
+        name: &str,
+    ) -> TypeFormer = __type_former_lookup_by_name;
+}
@@ -0,0 +1,296 @@
+//! # Proptest strategies for kernel objects
+//!
+//! Strategies generating random, well-typed, types and terms, for guest
+//! libraries that want to property-test their tactics against the real kernel.
+//! Every strategy first generates a *shape*, a plain description of the object,
+//! and then registers the shape with the kernel through the ABI, yielding a
+//! handle.  Shrinking therefore operates on shapes, with each shrunk shape
+//! registered afresh, which is cheap as the kernel shares structurally-equal
+//! objects.
+//!
+//! Terms are well-typed by construction, so registering a shape never fails
+//! unless the kernel itself is misbehaving, in which case the strategy panics.
+//! Variable names are drawn from a deliberately small range, so that generated
+//! terms frequently shadow and capture variables.
+//!
+//! Strategies are only available with the `proptest` feature enabled.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{
+    _type::{
+        type_register_function, type_register_variable,
+        PREALLOCATED_HANDLE_TYPE_PROP,
+    },
+    tags,
+    term::{
+        term_register_application, term_register_conjunction,
+        term_register_disjunction, term_register_equality,
+        term_register_exists, term_register_forall, term_register_implication,
+        term_register_lambda, term_register_negation, term_register_variable,
+        PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_TRUE,
+    },
+    Handle, Name,
+};
+use proptest::{prelude::*, strategy::BoxedStrategy};
+
+////////////////////////////////////////////////////////////////////////////////
+// Generation parameters.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of distinct variable, and type-variable, names used in generated
+/// objects.
+const NAMES: Name = 4;
+/// The maximum depth of generated types.
+const TYPE_DEPTH: u32 = 3;
+/// The number of nodes that generated types, and terms, aim to stay under.
+const DESIRED_SIZE: u32 = 32;
+/// The number of immediate children of the largest nodes, used to tune the
+/// size of generated objects.
+const BRANCHING: u32 = 2;
+
+/// A strategy generating variable, and type-variable, names.
+fn arb_name() -> impl Strategy<Value = Name> {
+    0..NAMES
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Types.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The shape of a generated type.
+#[derive(Clone, Debug)]
+enum TypeShape {
+    /// The type of propositions.
+    Prop,
+    /// A type-variable.
+    Variable(Name),
+    /// A function type.
+    Function(Box<TypeShape>, Box<TypeShape>),
+}
+
+impl TypeShape {
+    /// Registers the type with the kernel, returning its handle.
+    fn register(&self) -> Handle<tags::Type> {
+        match self {
+            TypeShape::Prop => PREALLOCATED_HANDLE_TYPE_PROP,
+            TypeShape::Variable(name) => type_register_variable(*name),
+            TypeShape::Function(domain, range) => {
+                type_register_function(domain.register(), range.register())
+                    .expect("Kernel rejected a well-formed function type.")
+            }
+        }
+    }
+}
+
+/// A strategy generating type shapes.
+fn arb_type_shape() -> BoxedStrategy<TypeShape> {
+    let leaf = prop_oneof![
+        Just(TypeShape::Prop),
+        arb_name().prop_map(TypeShape::Variable),
+    ];
+
+    leaf.prop_recursive(TYPE_DEPTH, DESIRED_SIZE, BRANCHING, |inner| {
+        (inner.clone(), inner)
+            .prop_map(|(domain, range)| {
+                TypeShape::Function(Box::new(domain), Box::new(range))
+            })
+            .boxed()
+    })
+    .boxed()
+}
+
+/// A strategy generating types built from `Prop`, type-variables and function
+/// types, registered with the kernel.
+pub fn arb_type() -> impl Strategy<Value = Handle<tags::Type>> {
+    arb_type_shape().prop_map(|shape| shape.register())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Terms.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The shape of a generated term.
+#[derive(Clone, Debug)]
+enum TermShape {
+    /// Truth.
+    True,
+    /// Falsity.
+    False,
+    /// A variable of the given type.
+    Variable(Name, TypeShape),
+    /// The application of a lambda-abstraction, binding a variable of the
+    /// given type, to a variable of that same type.
+    Redex(Name, TypeShape, Box<TermShape>, Name),
+    /// A lambda-abstraction.
+    Lambda(Name, TypeShape, Box<TermShape>),
+    /// A negation of a proposition.
+    Negation(Box<TermShape>),
+    /// A conjunction of propositions.
+    Conjunction(Box<TermShape>, Box<TermShape>),
+    /// A disjunction of propositions.
+    Disjunction(Box<TermShape>, Box<TermShape>),
+    /// An implication between propositions.
+    Implication(Box<TermShape>, Box<TermShape>),
+    /// The equality of a term with itself.
+    Reflexive(Box<TermShape>),
+    /// The equality of two variables of the same type.
+    Equality(Name, Name, TypeShape),
+    /// A universal quantification over a proposition.
+    Forall(Name, TypeShape, Box<TermShape>),
+    /// An existential quantification over a proposition.
+    Exists(Name, TypeShape, Box<TermShape>),
+}
+
+impl TermShape {
+    /// Registers the term with the kernel, returning its handle.
+    fn register(&self) -> Handle<tags::Term> {
+        match self {
+            TermShape::True => Ok(PREALLOCATED_HANDLE_TERM_TRUE),
+            TermShape::False => Ok(PREALLOCATED_HANDLE_TERM_FALSE),
+            TermShape::Variable(name, tau) => {
+                term_register_variable(*name, tau.register())
+            }
+            TermShape::Redex(name, tau, body, argument) => {
+                let tau = tau.register();
+                let lambda =
+                    term_register_lambda(*name, tau.clone(), body.register());
+                let argument = term_register_variable(*argument, tau);
+
+                lambda.and_then(|lambda| {
+                    term_register_application(lambda, argument?)
+                })
+            }
+            TermShape::Lambda(name, tau, body) => {
+                term_register_lambda(*name, tau.register(), body.register())
+            }
+            TermShape::Negation(body) => {
+                term_register_negation(body.register())
+            }
+            TermShape::Conjunction(left, right) => {
+                term_register_conjunction(left.register(), right.register())
+            }
+            TermShape::Disjunction(left, right) => {
+                term_register_disjunction(left.register(), right.register())
+            }
+            TermShape::Implication(left, right) => {
+                term_register_implication(left.register(), right.register())
+            }
+            TermShape::Reflexive(body) => {
+                let body = body.register();
+
+                term_register_equality(body.clone(), body)
+            }
+            TermShape::Equality(left, right, tau) => {
+                let tau = tau.register();
+                let left = term_register_variable(*left, tau.clone());
+                let right = term_register_variable(*right, tau);
+
+                left.and_then(|left| term_register_equality(left, right?))
+            }
+            TermShape::Forall(name, tau, body) => {
+                term_register_forall(*name, tau.register(), body.register())
+            }
+            TermShape::Exists(name, tau, body) => {
+                term_register_exists(*name, tau.register(), body.register())
+            }
+        }
+        .expect("Kernel rejected a well-typed term.")
+    }
+}
+
+/// A strategy generating proposition shapes of at most `depth` levels.
+fn arb_proposition_shape(depth: u32) -> BoxedStrategy<TermShape> {
+    let leaf = prop_oneof![
+        Just(TermShape::True),
+        Just(TermShape::False),
+        arb_name().prop_map(|name| TermShape::Variable(name, TypeShape::Prop)),
+        (arb_name(), arb_name(), arb_type_shape()).prop_map(
+            |(left, right, tau)| TermShape::Equality(left, right, tau)
+        ),
+    ];
+
+    leaf.prop_recursive(depth, DESIRED_SIZE, BRANCHING, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(|p| TermShape::Negation(Box::new(p))),
+            (inner.clone(), inner.clone()).prop_map(|(p, q)| {
+                TermShape::Conjunction(Box::new(p), Box::new(q))
+            }),
+            (inner.clone(), inner.clone()).prop_map(|(p, q)| {
+                TermShape::Disjunction(Box::new(p), Box::new(q))
+            }),
+            (inner.clone(), inner.clone()).prop_map(|(p, q)| {
+                TermShape::Implication(Box::new(p), Box::new(q))
+            }),
+            (arb_name(), arb_type_shape(), inner.clone()).prop_map(
+                |(name, tau, p)| TermShape::Forall(name, tau, Box::new(p))
+            ),
+            (arb_name(), arb_type_shape(), inner.clone()).prop_map(
+                |(name, tau, p)| TermShape::Exists(name, tau, Box::new(p))
+            ),
+            (arb_name(), inner, arb_name()).prop_map(|(name, p, argument)| {
+                TermShape::Redex(name, TypeShape::Prop, Box::new(p), argument)
+            }),
+        ]
+        .boxed()
+    })
+    .boxed()
+}
+
+/// A strategy generating term shapes of at most `depth` levels.
+fn arb_term_shape(depth: u32) -> BoxedStrategy<TermShape> {
+    let leaf = prop_oneof![
+        arb_proposition_shape(depth),
+        (arb_name(), arb_type_shape())
+            .prop_map(|(name, tau)| TermShape::Variable(name, tau)),
+    ];
+
+    leaf.prop_recursive(depth, DESIRED_SIZE, BRANCHING, |inner| {
+        prop_oneof![
+            (arb_name(), arb_type_shape(), inner.clone()).prop_map(
+                |(name, tau, body)| TermShape::Lambda(
+                    name,
+                    tau,
+                    Box::new(body)
+                )
+            ),
+            (arb_name(), arb_type_shape(), inner.clone(), arb_name()).prop_map(
+                |(name, tau, body, argument)| {
+                    TermShape::Redex(name, tau, Box::new(body), argument)
+                }
+            ),
+            inner.prop_map(|body| TermShape::Reflexive(Box::new(body))),
+        ]
+        .boxed()
+    })
+    .boxed()
+}
+
+/// A strategy generating propositions, built from truth, falsity, variables,
+/// equalities, the connectives, the quantifiers and beta-redexes, of at most
+/// `depth` levels, registered with the kernel.
+pub fn arb_proposition(
+    depth: u32,
+) -> impl Strategy<Value = Handle<tags::Term>> {
+    arb_proposition_shape(depth).prop_map(|shape| shape.register())
+}
+
+/// A strategy generating well-typed terms, of any type, of at most `depth`
+/// levels, registered with the kernel.  Alongside propositions these include
+/// variables, lambda-abstractions, beta-redexes and equalities.
+pub fn arb_term(depth: u32) -> impl Strategy<Value = Handle<tags::Term>> {
+    arb_term_shape(depth).prop_map(|shape| shape.register())
+}
@@ -0,0 +1,417 @@
+//! # Tactics
+//!
+//! Light automation for prover-space code.  The `auto` tactic searches
+//! backwards from a goal for a proof built from the kernel's introduction and
+//! elimination rules for the propositional connectives, using the hypotheses
+//! that it introduces along the way and a list of *hints*, theorems supplied by
+//! the caller, as its facts.  The search is bounded by a depth, counting the
+//! rules applied along any one branch, so always terminates, though it may
+//! fail to find a proof that exists beyond the bound.
+//!
+//! At each goal the search first looks for a fact concluding the goal, then
+//! tries the introduction rule matching the shape of the goal, and then tries
+//! eliminating each fact in turn: modus ponens with an implication whose
+//! consequent is the goal, contradiction with a negation when the goal is
+//! falsity, and, last of all as the most expensive, case analysis on a
+//! disjunction.  Conjunctions are split into their conjuncts as soon as they
+//! become facts.  Facts are tried smallest first, as measured by the size of
+//! their conclusions, on the heuristic that smaller facts are more likely to
+//! be directly useful, and of the two disjuncts of a disjunctive goal the
+//! smaller is tried first.  Hints are used as they stand, and are never
+//! instantiated, so should be instantiated by the caller beforehand.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::raw::{
+    tags,
+    term::{
+        term_size, term_split_conjunction, term_split_disjunction,
+        term_split_implication, term_split_negation,
+        PREALLOCATED_HANDLE_TERM_FALSE, PREALLOCATED_HANDLE_TERM_TRUE,
+    },
+    theorem::{
+        theorem_implication_introduction, theorem_negation_introduction,
+        theorem_register_assumption, theorem_register_conjunction_introduction,
+        theorem_register_conjunction_left_elimination,
+        theorem_register_conjunction_right_elimination,
+        theorem_register_disjunction_elimination,
+        theorem_register_disjunction_left_introduction,
+        theorem_register_disjunction_right_introduction,
+        theorem_register_falsity_elimination,
+        theorem_register_implication_elimination,
+        theorem_register_negation_elimination,
+        theorem_register_truth_introduction, theorem_register_weaken,
+        theorem_split_conclusion, theorem_split_hypotheses,
+    },
+    ErrorCode, Handle,
+};
+use std::slice;
+
+////////////////////////////////////////////////////////////////////////////////
+// Facts.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The default bound on the depth of the search performed by `auto`.
+pub const AUTO_DEFAULT_DEPTH: usize = 8;
+
+/// A theorem available to the search, along with its conclusion and the size
+/// of its conclusion.
+#[derive(Clone, Debug)]
+struct Fact {
+    /// The theorem itself.
+    theorem: Handle<tags::Theorem>,
+    /// The conclusion of the theorem.
+    conclusion: Handle<tags::Term>,
+    /// The size of the conclusion of the theorem.
+    size: usize,
+}
+
+/// Adds the theorem pointed-to by `theorem` to `facts`, along with both
+/// conjuncts of its conclusion, recursively, if it is a conjunction, keeping
+/// `facts` ordered smallest first.
+fn add_fact(
+    facts: &mut Vec<Fact>,
+    theorem: Handle<tags::Theorem>,
+) -> Result<(), ErrorCode> {
+    let conclusion = theorem_split_conclusion(theorem.clone())?;
+
+    if term_split_conjunction(conclusion.clone()).is_ok() {
+        add_fact(
+            facts,
+            theorem_register_conjunction_left_elimination(theorem.clone())?,
+        )?;
+        add_fact(
+            facts,
+            theorem_register_conjunction_right_elimination(theorem.clone())?,
+        )?;
+    }
+
+    let size = term_size(&conclusion)?;
+
+    facts.push(Fact {
+        theorem,
+        conclusion,
+        size,
+    });
+    facts.sort_by_key(|fact| fact.size);
+
+    Ok(())
+}
+
+/// Returns `facts` extended with the assumption `hypothesis ⊢ hypothesis`.
+fn assume(
+    facts: &[Fact],
+    hypothesis: &Handle<tags::Term>,
+) -> Result<Vec<Fact>, ErrorCode> {
+    let mut facts = facts.to_vec();
+
+    add_fact(&mut facts, theorem_register_assumption(hypothesis.clone())?)?;
+
+    Ok(facts)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Premisses.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Weakens the theorem pointed-to by `theorem` with every proposition of
+/// `hypotheses` that is not already one of its premisses.
+fn weaken_to(
+    theorem: Handle<tags::Theorem>,
+    hypotheses: &[Handle<tags::Term>],
+) -> Result<Handle<tags::Theorem>, ErrorCode> {
+    let premisses = theorem_split_hypotheses(theorem.clone())?;
+
+    hypotheses
+        .iter()
+        .filter(|hypothesis| !premisses.contains(hypothesis))
+        .try_fold(theorem, |theorem, hypothesis| {
+            theorem_register_weaken(hypothesis.clone(), theorem)
+        })
+}
+
+/// Discharges the premiss `hypothesis` of the theorem pointed-to by `theorem`
+/// with `rule`, first weakening the theorem with the premiss if the search
+/// found a proof not using it.
+fn discharge<F>(
+    theorem: Handle<tags::Theorem>,
+    hypothesis: &Handle<tags::Term>,
+    rule: F,
+) -> Result<Handle<tags::Theorem>, ErrorCode>
+where
+    F: FnOnce(
+        Handle<tags::Theorem>,
+        Handle<tags::Term>,
+    ) -> Result<Handle<tags::Theorem>, ErrorCode>,
+{
+    let theorem = weaken_to(theorem, slice::from_ref(hypothesis))?;
+
+    rule(theorem, hypothesis.clone())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Search.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Searches for a proof of the goal pointed-to by `goal` from the kernel's
+/// rules for the propositional connectives, the theorems pointed-to by
+/// `hints`, and the hypotheses introduced along the way, applying at most
+/// `depth` rules along any one branch of the search.  Returns `Ok(Some(thm))`
+/// if a proof is found, where `thm` concludes the goal and has as premisses
+/// those of the hints that the proof uses, and `Ok(None)` if the search is
+/// exhausted without finding one.
+///
+/// # Errors
+///
+/// Returns `ErrorCode::NoSuchTermRegistered` if `goal` does not point-to a
+/// registered term, and `ErrorCode::NoSuchTheoremRegistered` if any handle in
+/// `hints` does not point-to a registered theorem.
+pub fn auto<T>(
+    goal: T,
+    hints: &[Handle<tags::Theorem>],
+    depth: usize,
+) -> Result<Option<Handle<tags::Theorem>>, ErrorCode>
+where
+    T: AsRef<Handle<tags::Term>>,
+{
+    let goal = goal.as_ref().clone();
+    let mut facts = Vec::new();
+
+    /* NB: the search treats an unregistered goal as merely unprovable, so it
+     * is checked up-front.
+     */
+    term_size(&goal)?;
+
+    for hint in hints {
+        add_fact(&mut facts, hint.clone())?;
+    }
+
+    search(&goal, &facts, depth)
+}
+
+/// Searches for a proof of `goal` from `facts`, applying at most `depth`
+/// rules along any one branch.
+fn search(
+    goal: &Handle<tags::Term>,
+    facts: &[Fact],
+    depth: usize,
+) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+    if let Some(fact) = facts.iter().find(|fact| &fact.conclusion == goal) {
+        return Ok(Some(fact.theorem.clone()));
+    }
+
+    if goal == &PREALLOCATED_HANDLE_TERM_TRUE {
+        return theorem_register_truth_introduction().map(Some);
+    }
+
+    if let Some(fact) = facts
+        .iter()
+        .find(|fact| fact.conclusion == PREALLOCATED_HANDLE_TERM_FALSE)
+    {
+        return theorem_register_falsity_elimination(
+            fact.theorem.clone(),
+            goal.clone(),
+        )
+        .map(Some);
+    }
+
+    if depth == 0 {
+        return Ok(None);
+    }
+
+    if let Some(theorem) = introduce(goal, facts, depth - 1)? {
+        return Ok(Some(theorem));
+    }
+
+    eliminate(goal, facts, depth - 1)
+}
+
+/// Tries the introduction rule matching the shape of `goal`, searching for
+/// proofs of its subgoals from `facts` with a bound of `depth`.
+fn introduce(
+    goal: &Handle<tags::Term>,
+    facts: &[Fact],
+    depth: usize,
+) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+    if let Ok((left, right)) = term_split_conjunction(goal.clone()) {
+        if let Some(left) = search(&left, facts, depth)? {
+            if let Some(right) = search(&right, facts, depth)? {
+                return theorem_register_conjunction_introduction(left, right)
+                    .map(Some);
+            }
+        }
+    } else if let Ok((left, right)) = term_split_implication(goal.clone()) {
+        let facts = assume(facts, &left)?;
+
+        if let Some(theorem) = search(&right, &facts, depth)? {
+            return discharge(theorem, &left, |theorem, left| {
+                theorem_implication_introduction(theorem, left)
+            })
+            .map(Some);
+        }
+    } else if let Ok(body) = term_split_negation(goal.clone()) {
+        let facts = assume(facts, &body)?;
+
+        if let Some(theorem) =
+            search(&PREALLOCATED_HANDLE_TERM_FALSE, &facts, depth)?
+        {
+            return discharge(theorem, &body, |theorem, body| {
+                theorem_negation_introduction(theorem, body)
+            })
+            .map(Some);
+        }
+    } else if let Ok((left, right)) = term_split_disjunction(goal.clone()) {
+        let by_left = || match search(&left, facts, depth)? {
+            Some(theorem) => theorem_register_disjunction_left_introduction(
+                theorem,
+                right.clone(),
+            )
+            .map(Some),
+            None => Ok(None),
+        };
+        let by_right = || match search(&right, facts, depth)? {
+            Some(theorem) => theorem_register_disjunction_right_introduction(
+                theorem,
+                left.clone(),
+            )
+            .map(Some),
+            None => Ok(None),
+        };
+
+        return if term_size(&left)? <= term_size(&right)? {
+            match by_left()? {
+                Some(theorem) => Ok(Some(theorem)),
+                None => by_right(),
+            }
+        } else {
+            match by_right()? {
+                Some(theorem) => Ok(Some(theorem)),
+                None => by_left(),
+            }
+        };
+    }
+
+    Ok(None)
+}
+
+/// Tries eliminating each of `facts` in turn to prove `goal`, searching for
+/// proofs of the subgoals from `facts` with a bound of `depth`.
+fn eliminate(
+    goal: &Handle<tags::Term>,
+    facts: &[Fact],
+    depth: usize,
+) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+    for fact in facts {
+        if let Ok((antecedent, consequent)) =
+            term_split_implication(fact.conclusion.clone())
+        {
+            if &consequent == goal {
+                if let Some(minor) = search(&antecedent, facts, depth)? {
+                    return theorem_register_implication_elimination(
+                        fact.theorem.clone(),
+                        minor,
+                    )
+                    .map(Some);
+                }
+            }
+        }
+
+        if goal == &PREALLOCATED_HANDLE_TERM_FALSE {
+            if let Ok(body) = term_split_negation(fact.conclusion.clone()) {
+                if let Some(minor) = search(&body, facts, depth)? {
+                    return theorem_register_negation_elimination(
+                        minor,
+                        fact.theorem.clone(),
+                    )
+                    .map(Some);
+                }
+            }
+        }
+    }
+
+    for (index, fact) in facts.iter().enumerate() {
+        if let Ok((left, right)) =
+            term_split_disjunction(fact.conclusion.clone())
+        {
+            /* NB: the disjunction is dropped from the facts of both cases, as
+             * analysing it again would only repeat the same cases.
+             */
+            let mut remaining = facts.to_vec();
+
+            remaining.remove(index);
+
+            if let Some(theorem) =
+                cases(goal, fact, &left, &right, &remaining, depth)?
+            {
+                return Ok(Some(theorem));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Proves `goal` by case analysis on `disjunction`, with disjuncts `left` and
+/// `right`, searching for a proof of `goal` in each case from `facts` with a
+/// bound of `depth`.
+fn cases(
+    goal: &Handle<tags::Term>,
+    disjunction: &Fact,
+    left: &Handle<tags::Term>,
+    right: &Handle<tags::Term>,
+    facts: &[Fact],
+    depth: usize,
+) -> Result<Option<Handle<tags::Theorem>>, ErrorCode> {
+    let mid = match search(goal, &assume(facts, left)?, depth)? {
+        Some(mid) => weaken_to(mid, slice::from_ref(left))?,
+        None => return Ok(None),
+    };
+    let last = match search(goal, &assume(facts, right)?, depth)? {
+        Some(last) => weaken_to(last, slice::from_ref(right))?,
+        None => return Ok(None),
+    };
+
+    /* NB: the kernel requires the premisses of the disjunction to be exactly
+     * those of each case, less the disjunct that the case discharges, so all
+     * three theorems are weakened to the union of their premisses.  This is
+     * impossible if either disjunct is itself one of those premisses.
+     */
+    let mut premisses = theorem_split_hypotheses(disjunction.theorem.clone())?;
+
+    premisses.extend(
+        theorem_split_hypotheses(mid.clone())?
+            .into_iter()
+            .filter(|premiss| premiss != left),
+    );
+    premisses.extend(
+        theorem_split_hypotheses(last.clone())?
+            .into_iter()
+            .filter(|premiss| premiss != right),
+    );
+    premisses.sort();
+    premisses.dedup();
+
+    if premisses.contains(left) || premisses.contains(right) {
+        return Ok(None);
+    }
+
+    theorem_register_disjunction_elimination(
+        weaken_to(disjunction.theorem.clone(), &premisses)?,
+        weaken_to(mid, &premisses)?,
+        weaken_to(last, &premisses)?,
+    )
+    .map(Some)
+}
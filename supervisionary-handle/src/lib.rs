@@ -0,0 +1,403 @@
+//! # Kernel object handles
+//!
+//! Kernel objects are manipulated only by the kernel, so untrusted
+//! "prover-space" code needs some way of naming the object that should be
+//! manipulated by the kernel.  In Supervisionary, we use *handles* for this
+//! purpose, which are simply machine words suitable for passing across the
+//! kernel/prover-space system call boundary.
+//!
+//! Handles are tagged, at the type level, with the kind of kernel object that
+//! they point-to.  This crate defines the tags and the tagged handles once, for
+//! both the kernel and the `libsupervisionary` bindings used by prover-space,
+//! so that the two sides agree on them.  It does not depend on the standard
+//! library, so that it may be used by guests that do not link it.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+#![no_std]
+
+use core::{
+    convert::TryFrom,
+    fmt,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Handle tags.
+////////////////////////////////////////////////////////////////////////////////
+
+/// This module contains dummy types that are used as type-parameters to the
+/// parameterized `Handle` struct, defined below, which allow us to distinguish
+/// between handles used for different purposes within the kernel.  This, though
+/// handles are really just represented as machine words, allow us to statically
+/// avoid mixing up handles that are assumed to point to e.g. a HOL type, with
+/// those assumed to point to a HOL theorem.
+pub mod tags {
+    /// The handle tag for type-formers.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct TypeFormer;
+
+    /// The handle tag for types.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Type;
+
+    /// The handle tag for constants.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Constant;
+
+    /// The handle tag for terms.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Term;
+
+    /// The handle tag for theorems.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Theorem;
+
+    /// The handle tag for interned sets of hypotheses.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Hypotheses;
+
+    /// The handle tag for capabilities.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Capability;
+
+    /// The handle tag for proof terms.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Proof;
+
+    /// The handle tag for multi-conclusion sequents.  Sequents are only
+    /// registered with the experimental `sequents` feature.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Sequent;
+
+    /// The handle tag for registered substitutions.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Substitution;
+
+    /// This is a trait which will allow us to assert that a particular type
+    /// parameter may indeed be instantiated exclusively with a handle tag.
+    pub trait IsTag {
+        /// The kind of kernel object that handles with this tag point-to.
+        const KIND: super::Kind;
+    }
+
+    impl IsTag for TypeFormer {
+        const KIND: super::Kind = super::Kind::TypeFormer;
+    }
+
+    impl IsTag for Type {
+        const KIND: super::Kind = super::Kind::Type;
+    }
+
+    impl IsTag for Constant {
+        const KIND: super::Kind = super::Kind::Constant;
+    }
+
+    impl IsTag for Term {
+        const KIND: super::Kind = super::Kind::Term;
+    }
+
+    impl IsTag for Theorem {
+        const KIND: super::Kind = super::Kind::Theorem;
+    }
+
+    impl IsTag for Hypotheses {
+        const KIND: super::Kind = super::Kind::Hypotheses;
+    }
+
+    impl IsTag for Capability {
+        const KIND: super::Kind = super::Kind::Capability;
+    }
+
+    impl IsTag for Proof {
+        const KIND: super::Kind = super::Kind::Proof;
+    }
+
+    impl IsTag for Sequent {
+        const KIND: super::Kind = super::Kind::Sequent;
+    }
+
+    impl IsTag for Substitution {
+        const KIND: super::Kind = super::Kind::Substitution;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Kinds of kernel object.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The kinds of kernel object that a handle may point-to, mirroring the handle
+/// tags above.  Unlike the tags, kinds exist at runtime, and are passed across
+/// the ABI boundary when prover-space needs to name a table of kernel objects,
+/// for example when enumerating every registered object of a given kind.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Kind {
+    /// Type-formers.
+    TypeFormer,
+    /// Types.
+    Type,
+    /// Constants.
+    Constant,
+    /// Terms.
+    Term,
+    /// Theorems.
+    Theorem,
+    /// Interned sets of hypotheses.
+    Hypotheses,
+    /// Capabilities.
+    Capability,
+    /// Proof terms.
+    Proof,
+    /// Multi-conclusion sequents.
+    Sequent,
+    /// Registered substitutions.
+    Substitution,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tagged handles.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Kernel handles consist of a machine word, which acts as the handle-proper,
+/// along with some phantom data which binds the `T` type-parameter to the
+/// machine word, and which is used to tag the handle with, using some instance
+/// of the `IsTag` trait.  This allows us to statically distinguish between
+/// handles that e.g. are assumed to point to HOL terms from those that are e.g.
+/// assumed to point to theorems, within the kernel and prover-space.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Handle<T>
+where
+    T: tags::IsTag,
+{
+    /// We use the Rust `usize` type as our handle type.  Note that on modern 64-bit
+    /// systems this is implemented as a 64-bit unsigned integer.
+    handle: usize,
+    /// The phantom data binding the tag type, `T`.
+    marker: PhantomData<T>,
+}
+
+impl<T> Handle<T>
+where
+    T: tags::IsTag,
+{
+    /// Creates a new handle from a raw handle and some phantom data
+    /// constraining the handle to be of a particular tag-type.  As a `const`
+    /// function, this also builds the pre-allocated handles of the kernel and
+    /// of prover-space.
+    #[inline]
+    pub const fn new(handle: usize, marker: PhantomData<T>) -> Self {
+        Self { handle, marker }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Trait implementations.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Handles may be passed wherever a reference to a handle is expected.
+impl<T> AsRef<Handle<T>> for Handle<T>
+where
+    T: tags::IsTag,
+{
+    #[inline]
+    fn as_ref(&self) -> &Handle<T> {
+        self
+    }
+}
+
+/// Dereferencing a `Handle` simply returns its associated machine word.
+impl<T> Deref for Handle<T>
+where
+    T: tags::IsTag,
+{
+    type Target = usize;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+/// Injection from machine words into the `Handle` type.
+impl<T> From<usize> for Handle<T>
+where
+    T: tags::IsTag,
+{
+    #[inline]
+    fn from(handle: usize) -> Self {
+        Handle {
+            handle,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Conversion from a kind into a `u64`, for ABI transport.
+impl From<Kind> for u64 {
+    #[inline]
+    fn from(kind: Kind) -> u64 {
+        match kind {
+            Kind::TypeFormer => 0,
+            Kind::Type => 1,
+            Kind::Constant => 2,
+            Kind::Term => 3,
+            Kind::Theorem => 4,
+            Kind::Hypotheses => 5,
+            Kind::Capability => 6,
+            Kind::Proof => 7,
+            Kind::Sequent => 8,
+            Kind::Substitution => 9,
+        }
+    }
+}
+
+/// Conversion from a `u64`, received across the ABI boundary, into a kind.
+impl TryFrom<u64> for Kind {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Kind::TypeFormer),
+            1 => Ok(Kind::Type),
+            2 => Ok(Kind::Constant),
+            3 => Ok(Kind::Term),
+            4 => Ok(Kind::Theorem),
+            5 => Ok(Kind::Hypotheses),
+            6 => Ok(Kind::Capability),
+            7 => Ok(Kind::Proof),
+            8 => Ok(Kind::Sequent),
+            9 => Ok(Kind::Substitution),
+            _otherwise => Err(()),
+        }
+    }
+}
+
+/// Pretty-printing for term handles.
+impl Display for Handle<tags::Term> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (term handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for constant handles.
+impl Display for Handle<tags::Constant> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (constant handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for type-former handles.
+impl Display for Handle<tags::TypeFormer> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (type-former handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for type handles.
+impl Display for Handle<tags::Type> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (type handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for theorem handles.
+impl Display for Handle<tags::Theorem> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (theorem handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for hypotheses handles.
+impl Display for Handle<tags::Hypotheses> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (hypotheses handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for capability handles.
+impl Display for Handle<tags::Capability> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (capability handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for proof handles.
+impl Display for Handle<tags::Proof> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (proof handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for sequent handles.
+impl Display for Handle<tags::Sequent> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (sequent handle)", self.handle)
+    }
+}
+
+/// Pretty-printing for substitution handles.
+impl Display for Handle<tags::Substitution> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (substitution handle)", self.handle)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{tags, tags::IsTag, Handle, Kind};
+    use core::{convert::TryFrom, marker::PhantomData};
+
+    /// Tests that the encoding of kinds round-trips, that unknown encodings
+    /// are rejected, and that each tag records its kind.
+    #[test]
+    pub fn kind0() {
+        for encoding in 0u64..10 {
+            let kind = Kind::try_from(encoding).unwrap();
+
+            assert_eq!(u64::from(kind), encoding);
+        }
+
+        assert_eq!(Kind::try_from(10u64), Err(()));
+        assert_eq!(tags::Term::KIND, Kind::Term);
+        assert_eq!(tags::Substitution::KIND, Kind::Substitution);
+    }
+
+    /// Tests that handles built by the `const` constructor agree with those
+    /// injected from machine words.
+    #[test]
+    pub fn handle0() {
+        const HANDLE: Handle<tags::Term> = Handle::new(3, PhantomData);
+
+        assert_eq!(HANDLE, Handle::from(3));
+        assert_eq!(*HANDLE, 3);
+    }
+}
@@ -0,0 +1,186 @@
+//! # Tests for the Supervisionary theorem ABI
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use libsupervisionary::raw::{
+    _type::{PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP},
+    term::*,
+    theorem::*,
+    ErrorCode,
+};
+
+fn main() {
+    let p =
+        term_register_variable(0u64, PREALLOCATED_HANDLE_TYPE_PROP).unwrap();
+    let q =
+        term_register_variable(1u64, PREALLOCATED_HANDLE_TYPE_PROP).unwrap();
+    let x =
+        term_register_variable(2u64, PREALLOCATED_HANDLE_TYPE_ALPHA).unwrap();
+    let y =
+        term_register_variable(3u64, PREALLOCATED_HANDLE_TYPE_ALPHA).unwrap();
+
+    /* Assumption and weakening. */
+
+    let assume_p = theorem_register_assumption(p.clone()).unwrap();
+
+    assert!(theorem_is_registered(&assume_p));
+    assert_eq!(theorem_split_conclusion(assume_p.clone()), Ok(p.clone()));
+    assert_eq!(
+        theorem_split_hypotheses(assume_p.clone()),
+        Ok(vec![p.clone()])
+    );
+
+    let weakened =
+        theorem_register_weaken(q.clone(), assume_p.clone()).unwrap();
+    let hypotheses = theorem_split_hypotheses(weakened).unwrap();
+
+    assert_eq!(hypotheses.len(), 2);
+    assert!(hypotheses.contains(&p));
+    assert!(hypotheses.contains(&q));
+
+    assert_eq!(
+        theorem_register_assumption(x.clone()),
+        Err(ErrorCode::NotAProposition)
+    );
+
+    /* Equality. */
+
+    let reflexivity = theorem_register_reflexivity(x.clone()).unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(reflexivity.clone()),
+        term_register_equality(x.clone(), x.clone())
+    );
+    assert_eq!(theorem_split_hypotheses(reflexivity.clone()), Ok(vec![]));
+
+    let x_equals_y = term_register_equality(x.clone(), y.clone()).unwrap();
+    let assume_x_equals_y = theorem_register_assumption(x_equals_y).unwrap();
+    let symmetry =
+        theorem_register_symmetry(assume_x_equals_y.clone()).unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(symmetry.clone()),
+        term_register_equality(y.clone(), x.clone())
+    );
+
+    let transitivity =
+        theorem_register_transitivity(assume_x_equals_y.clone(), symmetry)
+            .unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(transitivity),
+        term_register_equality(x.clone(), x.clone())
+    );
+
+    let lambda =
+        term_register_lambda(2u64, PREALLOCATED_HANDLE_TYPE_ALPHA, x.clone())
+            .unwrap();
+    let redex = term_register_application(lambda, y.clone()).unwrap();
+    let beta = theorem_register_beta(redex.clone()).unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(beta),
+        term_register_equality(redex, y.clone())
+    );
+
+    /* Substitution. */
+
+    let substituted = theorem_register_substitute(
+        assume_p.clone(),
+        vec![((0u64, PREALLOCATED_HANDLE_TYPE_PROP), q.clone())],
+    )
+    .unwrap();
+
+    assert_eq!(theorem_split_conclusion(substituted.clone()), Ok(q.clone()));
+    assert_eq!(theorem_split_hypotheses(substituted), Ok(vec![q.clone()]));
+
+    /* Propositional connectives. */
+
+    let truth = theorem_register_truth_introduction().unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(truth),
+        Ok(PREALLOCATED_HANDLE_TERM_TRUE)
+    );
+
+    let assume_q = theorem_register_assumption(q.clone()).unwrap();
+    let conjunction =
+        theorem_register_conjunction_introduction(assume_p.clone(), assume_q)
+            .unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(conjunction.clone()),
+        term_register_conjunction(p.clone(), q.clone())
+    );
+    assert_eq!(
+        theorem_split_conclusion(
+            theorem_register_conjunction_left_elimination(conjunction.clone())
+                .unwrap()
+        ),
+        Ok(p.clone())
+    );
+    assert_eq!(
+        theorem_split_conclusion(
+            theorem_register_conjunction_right_elimination(conjunction)
+                .unwrap()
+        ),
+        Ok(q.clone())
+    );
+
+    let p_implies_p =
+        theorem_implication_introduction(assume_p.clone(), p.clone()).unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(p_implies_p.clone()),
+        term_register_implication(p.clone(), p.clone())
+    );
+    assert_eq!(theorem_split_hypotheses(p_implies_p.clone()), Ok(vec![]));
+    assert_eq!(
+        theorem_implication_introduction(assume_p.clone(), q.clone()),
+        Err(ErrorCode::ShapeMismatch)
+    );
+
+    let modus_ponens =
+        theorem_register_implication_elimination(p_implies_p, assume_p)
+            .unwrap();
+
+    assert_eq!(theorem_split_conclusion(modus_ponens), Ok(p.clone()));
+
+    /* Quantifiers. */
+
+    let forall = theorem_forall_introduction(
+        2u64,
+        PREALLOCATED_HANDLE_TYPE_ALPHA,
+        reflexivity,
+    )
+    .unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(forall.clone()),
+        term_register_forall(
+            2u64,
+            PREALLOCATED_HANDLE_TYPE_ALPHA,
+            term_register_equality(x.clone(), x).unwrap()
+        )
+    );
+
+    let instance = theorem_forall_elimination(forall, y.clone()).unwrap();
+
+    assert_eq!(
+        theorem_split_conclusion(instance),
+        term_register_equality(y.clone(), y)
+    );
+}
@@ -0,0 +1,228 @@
+//! # Benchmarks of guest memory access
+//!
+//! Host calls taking arrays of handles, or of names, copy them out of the WASM
+//! guest's memory, and those returning arrays copy them back in.  Every access
+//! of the guest's memory goes through WASMI, and pays for the bounds-checking
+//! and borrowing of the memory module, so that copying an array one 8-byte word
+//! at a time costs far more than copying it with a single, contiguous access,
+//! and decoding it in host memory.  These benchmarks compare the two, on their
+//! own, and measure registering large substitutions through the host-call
+//! interface, which copies both the domain and range of the substitution out
+//! of the guest's memory.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use byteorder::{ByteOrder, LittleEndian};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use kernel::handle::{
+    PREALLOCATED_HANDLE_TYPE_ALPHA, PREALLOCATED_HANDLE_TYPE_PROP,
+};
+use std::mem::size_of;
+use wasmi::{
+    memory_units::Pages, Externals, MemoryInstance, MemoryRef, RuntimeArgs,
+    RuntimeValue,
+};
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+/// The index of the `Substitution.Register.Type` host call, as fixed by the
+/// ABI.
+const ABI_SUBSTITUTION_REGISTER_TYPE_INDEX: usize = 1095;
+
+/// The number of pages of the guest's memory allocated for every benchmark,
+/// enough to hold two arrays of the largest length benchmarked.
+const MEMORY_PAGES: usize = 16;
+
+/// The address at which arrays are written to the guest's memory.  Note that
+/// host calls reject the null pointer.
+const BASE: usize = 8;
+
+/// The lengths of the arrays benchmarked.
+const LENGTHS: &[usize] = &[64, 1024, 16384];
+
+////////////////////////////////////////////////////////////////////////////////
+// Guest memory.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Allocates a fresh memory module, as though it were the guest's, and writes
+/// the array `0, 1, ..., length - 1` of 8-byte words into it at `BASE`.
+fn memory(length: usize) -> MemoryRef {
+    let memory = MemoryInstance::alloc(Pages(MEMORY_PAGES), None).unwrap();
+    let mut buffer = vec![0u8; length * size_of::<u64>()];
+
+    for (index, word) in buffer.chunks_exact_mut(size_of::<u64>()).enumerate() {
+        LittleEndian::write_u64(word, index as u64);
+    }
+
+    memory.set(BASE as u32, &buffer).unwrap();
+    memory
+}
+
+/// Reads `length` 8-byte words from `memory`, one word at a time.
+fn read_per_word(memory: &MemoryRef, length: usize) -> Vec<u64> {
+    (0..length)
+        .map(|index| {
+            let word = memory
+                .get((BASE + index * size_of::<u64>()) as u32, size_of::<u64>())
+                .unwrap();
+
+            LittleEndian::read_u64(&word)
+        })
+        .collect()
+}
+
+/// Reads `length` 8-byte words from `memory` with a single access.
+fn read_bulk(memory: &MemoryRef, length: usize) -> Vec<u64> {
+    memory
+        .get(BASE as u32, length * size_of::<u64>())
+        .unwrap()
+        .chunks_exact(size_of::<u64>())
+        .map(LittleEndian::read_u64)
+        .collect()
+}
+
+/// Writes `values` to `memory`, one word at a time.
+fn write_per_word(memory: &MemoryRef, values: &[u64]) {
+    for (index, value) in values.iter().enumerate() {
+        memory
+            .set(
+                (BASE + index * size_of::<u64>()) as u32,
+                &value.to_le_bytes(),
+            )
+            .unwrap();
+    }
+}
+
+/// Writes `values` to `memory` with a single access.
+fn write_bulk(memory: &MemoryRef, values: &[u64]) {
+    let buffer: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+    memory.set(BASE as u32, &buffer).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks.
+////////////////////////////////////////////////////////////////////////////////
+
+fn memory_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("memory/read");
+
+    for length in LENGTHS {
+        let memory = memory(*length);
+
+        group.bench_with_input(
+            BenchmarkId::new("per-word", length),
+            length,
+            |b, length| b.iter(|| read_per_word(&memory, *length)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("bulk", length),
+            length,
+            |b, length| b.iter(|| read_bulk(&memory, *length)),
+        );
+    }
+
+    group.finish();
+}
+
+fn memory_write(c: &mut Criterion) {
+    let mut group = c.benchmark_group("memory/write");
+
+    for length in LENGTHS {
+        let memory = memory(*length);
+        let values: Vec<u64> = (0..*length as u64).collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("per-word", length),
+            &values,
+            |b, values| b.iter(|| write_per_word(&memory, values)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("bulk", length),
+            &values,
+            |b, values| b.iter(|| write_bulk(&memory, values)),
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmarks registering substitutions of `length` type-variables through
+/// the host-call interface.  The domain of the substitution is written to the
+/// guest's memory at `BASE`, and its range immediately after.  Note that
+/// re-registering a substitution returns the existing handle, so that every
+/// iteration after the first is dominated by copying the substitution out of
+/// the guest's memory.
+fn substitution_register_type(c: &mut Criterion) {
+    let mut group = c.benchmark_group("memory/substitution-register-type");
+
+    for length in LENGTHS {
+        let memory = memory(*length);
+        let range: Vec<u64> = (0..*length)
+            .map(|index| {
+                if index % 2 == 0 {
+                    *PREALLOCATED_HANDLE_TYPE_PROP as u64
+                } else {
+                    *PREALLOCATED_HANDLE_TYPE_ALPHA as u64
+                }
+            })
+            .collect();
+        let range_base = BASE + length * size_of::<u64>();
+        let range_buffer: Vec<u8> =
+            range.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+        memory.set(range_base as u32, &range_buffer).unwrap();
+
+        let result_base = range_base + length * size_of::<u64>();
+
+        let mut runtime_state = WasmiRuntimeState::new();
+        runtime_state.set_memory(memory);
+
+        let args = [
+            RuntimeValue::I32(BASE as i32),
+            RuntimeValue::I64(*length as i64),
+            RuntimeValue::I32(range_base as i32),
+            RuntimeValue::I64(*length as i64),
+            RuntimeValue::I32(result_base as i32),
+        ];
+
+        group.bench_with_input(
+            BenchmarkId::new("host-call", length),
+            &args,
+            |b, args| {
+                b.iter(|| {
+                    runtime_state
+                        .invoke_index(
+                            ABI_SUBSTITUTION_REGISTER_TYPE_INDEX,
+                            RuntimeArgs::from(&args[..]),
+                        )
+                        .unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    memory_read,
+    memory_write,
+    substitution_register_type
+);
+criterion_main!(benches);
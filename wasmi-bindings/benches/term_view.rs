@@ -0,0 +1,215 @@
+//! # Benchmarks of term views
+//!
+//! Guests analysing large terms may either walk them node by node, making a
+//! host call to split each node, or ask the kernel to copy a view of the whole
+//! term into a region of their memory reserved in advance, and walk the view
+//! locally.  These benchmarks compare the two on conjunctions of increasing
+//! numbers of distinct variables, measuring the host calls made from the
+//! guest's side of the interface: the per-node walk splits every application
+//! it meets, reading the halves back out of the guest's memory, whereas the
+//! view is mapped with a single host call, read back with a single access, and
+//! decoded.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use byteorder::{ByteOrder, LittleEndian};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use kernel::{
+    handle::{tags, Handle, PREALLOCATED_HANDLE_TYPE_PROP},
+    term_view::term_view_decode,
+};
+use std::{collections::HashSet, mem::size_of};
+use wasmi::{
+    memory_units::Pages, Externals, MemoryInstance, MemoryRef, RuntimeArgs,
+    RuntimeValue,
+};
+use wasmi_bindings::runtime_state::WasmiRuntimeState;
+
+/// The index of the `Term.Split.Application` host call, as fixed by the ABI.
+const ABI_TERM_SPLIT_APPLICATION_INDEX: usize = 33;
+/// The index of the `Term.View.Reserve` host call, as fixed by the ABI.
+const ABI_TERM_VIEW_RESERVE_INDEX: usize = 1108;
+/// The index of the `Term.View.Map` host call, as fixed by the ABI.
+const ABI_TERM_VIEW_MAP_INDEX: usize = 1109;
+
+/// The number of pages of the guest's memory allocated for every benchmark,
+/// enough to hold the view of the largest term benchmarked.
+const MEMORY_PAGES: usize = 16;
+
+/// The size, in bytes, of a page of the guest's memory.
+const PAGE_SIZE: usize = 65536;
+
+/// The address of the results written by host calls.  Note that host calls
+/// reject the null pointer.
+const RESULT: usize = 8;
+
+/// The address of the region reserved for term views.
+const REGION: usize = 64;
+
+/// The numbers of distinct variables in the conjunctions benchmarked.
+const WIDTHS: &[usize] = &[16, 256, 4096];
+
+////////////////////////////////////////////////////////////////////////////////
+// Set-up.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a runtime state over `memory`, and the handle of the conjunction
+/// `x₀ ∧ (x₁ ∧ ... xₙ)` of `width` distinct variables registered in it.
+fn conjunction(
+    memory: MemoryRef,
+    width: usize,
+) -> (WasmiRuntimeState, Handle<tags::Term>) {
+    let mut runtime_state = WasmiRuntimeState::new();
+    runtime_state.set_memory(memory);
+
+    let term = runtime_state.with_kernel(|kernel| {
+        let mut variables: Vec<_> = (0..width as u64)
+            .map(|name| {
+                kernel
+                    .term_register_variable(name, PREALLOCATED_HANDLE_TYPE_PROP)
+                    .unwrap()
+            })
+            .collect();
+
+        let last = variables.pop().unwrap();
+
+        variables.into_iter().rev().fold(last, |conjunction, variable| {
+            kernel
+                .term_register_conjunction(variable, conjunction)
+                .unwrap()
+        })
+    });
+
+    (runtime_state, term)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Walks.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Walks the term pointed-to by `term`, splitting every application that it
+/// meets with a host call, and returns the number of distinct nodes visited.
+fn walk_per_node(
+    runtime_state: &mut WasmiRuntimeState,
+    memory: &MemoryRef,
+    term: &Handle<tags::Term>,
+) -> usize {
+    let mut visited = HashSet::new();
+    let mut work = vec![**term as u64];
+
+    let left = RESULT;
+    let right = RESULT + size_of::<u64>();
+
+    while let Some(node) = work.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        let args = [
+            RuntimeValue::I64(node as i64),
+            RuntimeValue::I32(left as i32),
+            RuntimeValue::I32(right as i32),
+        ];
+
+        let status = runtime_state
+            .invoke_index(
+                ABI_TERM_SPLIT_APPLICATION_INDEX,
+                RuntimeArgs::from(&args[..]),
+            )
+            .unwrap();
+
+        if status == Some(RuntimeValue::I32(0)) {
+            for address in &[left, right] {
+                let word =
+                    memory.get(*address as u32, size_of::<u64>()).unwrap();
+
+                work.push(LittleEndian::read_u64(&word));
+            }
+        }
+    }
+
+    visited.len()
+}
+
+/// Maps a view of the term pointed-to by `term` into the region reserved for
+/// term views, reads it back, and returns the number of nodes that it lists.
+fn walk_view(
+    runtime_state: &mut WasmiRuntimeState,
+    memory: &MemoryRef,
+    term: &Handle<tags::Term>,
+) -> usize {
+    let args = [
+        RuntimeValue::I64(**term as i64),
+        RuntimeValue::I32(RESULT as i32),
+    ];
+
+    let status = runtime_state
+        .invoke_index(ABI_TERM_VIEW_MAP_INDEX, RuntimeArgs::from(&args[..]))
+        .unwrap();
+
+    assert_eq!(status, Some(RuntimeValue::I32(0)));
+
+    let size = LittleEndian::read_u64(
+        &memory.get(RESULT as u32, size_of::<u64>()).unwrap(),
+    );
+    let view = memory.get(REGION as u32, size as usize).unwrap();
+
+    term_view_decode(&view).unwrap().len()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks.
+////////////////////////////////////////////////////////////////////////////////
+
+fn term_walk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("term-view/walk");
+
+    for width in WIDTHS {
+        let memory = MemoryInstance::alloc(Pages(MEMORY_PAGES), None).unwrap();
+        let (mut runtime_state, term) = conjunction(memory.clone(), *width);
+
+        let reserve = [
+            RuntimeValue::I32(REGION as i32),
+            RuntimeValue::I64((MEMORY_PAGES * PAGE_SIZE - REGION) as i64),
+        ];
+
+        runtime_state
+            .invoke_index(
+                ABI_TERM_VIEW_RESERVE_INDEX,
+                RuntimeArgs::from(&reserve[..]),
+            )
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("per-node", width),
+            &term,
+            |b, term| {
+                b.iter(|| walk_per_node(&mut runtime_state, &memory, term))
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("view", width),
+            &term,
+            |b, term| b.iter(|| walk_view(&mut runtime_state, &memory, term)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, term_walk);
+criterion_main!(benches);
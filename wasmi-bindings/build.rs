@@ -0,0 +1,41 @@
+//! # Wasm bindings build script
+//!
+//! Lints the host-call specification, in `src/system_call_numbers.rs`, against
+//! the dispatcher and resolver, in `src/runtime_state.rs`, and the signature
+//! checkers, in `src/type_checking.rs`, failing the build with a list of every
+//! mismatched name or number, and every host call that is never dispatched or
+//! whose signature is never checked.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+#[path = "../abi-spec/host_calls.rs"]
+mod host_calls;
+
+use std::env;
+
+fn main() {
+    let directory =
+        env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+
+    host_calls::load(&directory).unwrap_or_else(|e| {
+        panic!("inconsistent host-call specification:\n{}", e)
+    });
+
+    println!("cargo:rerun-if-changed={}", host_calls::NUMBERS);
+    println!("cargo:rerun-if-changed={}", host_calls::DISPATCHER);
+    println!("cargo:rerun-if-changed={}", host_calls::CHECKERS);
+    println!("cargo:rerun-if-changed=../abi-spec/host_calls.rs");
+}
@@ -0,0 +1,1703 @@
+//! # System call argument validation
+//!
+//! Describes the memory that each system call reads or writes through its
+//! pointer arguments, so that the arguments can be validated before the kernel
+//! is touched.  Null pointers, pointers outside of the guest's memory, and
+//! lengths describing buffers that overflow the guest's memory are reported to
+//! the guest as `ErrorCode::InvalidPointer` or `ErrorCode::InvalidLength`,
+//! rather than as a trap raised part-way through the system call, possibly
+//! after the kernel's state has already changed.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::system_call_numbers::{
+    ABI_BUDGET_REMAINING_INDEX, ABI_BUDGET_RESOURCE_INDEX,
+    ABI_CHALLENGE_CURRENT_INDEX, ABI_CHALLENGE_DISCHARGE_INDEX,
+    ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX, ABI_CONSTANT_DEFINE_INDEX,
+    ABI_CONSTANT_IS_CONSTRUCTOR_INDEX, ABI_CONSTANT_LOOKUP_BY_NAME_INDEX,
+    ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX, ABI_CONSTANT_REGISTER_INDEX,
+    ABI_CONSTANT_REGISTER_NAMED_INDEX, ABI_CONSTANT_RESOLVE_INDEX,
+    ABI_ERROR_DETAIL_LAST_INDEX, ABI_HYPOTHESES_INTERN_INDEX,
+    ABI_HYPOTHESES_RESOLVE_INDEX, ABI_INTROSPECT_CALL_COUNT_INDEX,
+    ABI_INTROSPECT_FUEL_CONSUMED_INDEX, ABI_INTROSPECT_HEAP_SIZE_INDEX,
+    ABI_KERNEL_BATCH_EXECUTE_INDEX, ABI_KERNEL_ENUMERATE_INDEX,
+    ABI_KERNEL_ERROR_DETAIL_INDEX, ABI_KERNEL_EXPORT_PROOF_INDEX,
+    ABI_KERNEL_IDENTIFY_INDEX, ABI_LINEAR_ARITH_SIGNATURE_INDEX,
+    ABI_OBJECT_METADATA_ENUMERATE_INDEX, ABI_OBJECT_METADATA_GET_INDEX,
+    ABI_OBJECT_METADATA_SET_INDEX, ABI_PROOF_OBJECT_EXPORT_INDEX,
+    ABI_PROOF_OBJECT_IMPORT_INDEX, ABI_PROOF_SPLIT_ARGUMENTS_INDEX,
+    ABI_PROOF_SPLIT_INDEX, ABI_QUOTIENT_LIFT_INDEX,
+    ABI_QUOTIENT_REGISTER_INDEX, ABI_QUOTIENT_SPLIT_INDEX,
+    ABI_QUOTIENT_TRANSFER_INDEX, ABI_SCRATCH_DELETE_INDEX,
+    ABI_SCRATCH_GET_INDEX, ABI_SCRATCH_PUT_INDEX,
+    ABI_SEQUENT_REGISTER_AXIOM_INDEX,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_CUT_INDEX,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_THEOREM_INDEX, ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX,
+    ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX, ABI_SEQUENT_SPLIT_PREMISSES_INDEX,
+    ABI_SUBSTITUTION_COMPOSE_INDEX, ABI_SUBSTITUTION_REGISTER_TERM_INDEX,
+    ABI_SUBSTITUTION_REGISTER_TYPE_INDEX, ABI_TERM_DIFF_INDEX,
+    ABI_TERM_FOLD_INDEX, ABI_TERM_FREE_VARIABLES_COUNT_INDEX,
+    ABI_TERM_FREE_VARIABLES_INDEX, ABI_TERM_FREE_VARIABLES_RANGE_INDEX,
+    ABI_TERM_REGISTER_APPLICATION_INDEX, ABI_TERM_REGISTER_COMPREHENSION_INDEX,
+    ABI_TERM_REGISTER_CONDITIONAL_INDEX, ABI_TERM_REGISTER_CONJUNCTION_INDEX,
+    ABI_TERM_REGISTER_CONSTANT_INDEX, ABI_TERM_REGISTER_DISJUNCTION_INDEX,
+    ABI_TERM_REGISTER_EQUALITY_INDEX, ABI_TERM_REGISTER_EXISTS_INDEX,
+    ABI_TERM_REGISTER_FIRST_INDEX, ABI_TERM_REGISTER_FORALL_INDEX,
+    ABI_TERM_REGISTER_IMPLICATION_INDEX, ABI_TERM_REGISTER_LAMBDA_INDEX,
+    ABI_TERM_REGISTER_LET_INDEX, ABI_TERM_REGISTER_MEMBERSHIP_INDEX,
+    ABI_TERM_REGISTER_NEGATION_INDEX, ABI_TERM_REGISTER_PAIR_INDEX,
+    ABI_TERM_REGISTER_SECOND_INDEX, ABI_TERM_REGISTER_VARIABLE_INDEX,
+    ABI_TERM_REPLACE_AT_INDEX, ABI_TERM_SPLIT_APPLICATION_INDEX,
+    ABI_TERM_SPLIT_COMPREHENSION_INDEX, ABI_TERM_SPLIT_CONDITIONAL_INDEX,
+    ABI_TERM_SPLIT_CONJUNCTION_INDEX, ABI_TERM_SPLIT_CONSTANT_INDEX,
+    ABI_TERM_SPLIT_DISJUNCTION_INDEX, ABI_TERM_SPLIT_EQUALITY_INDEX,
+    ABI_TERM_SPLIT_EXISTS_INDEX, ABI_TERM_SPLIT_FORALL_INDEX,
+    ABI_TERM_SPLIT_IMPLICATION_INDEX, ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX,
+    ABI_TERM_SPLIT_LAMBDA_INDEX, ABI_TERM_SPLIT_LET_INDEX,
+    ABI_TERM_SPLIT_MEMBERSHIP_INDEX, ABI_TERM_SPLIT_NEGATION_INDEX,
+    ABI_TERM_SPLIT_PAIR_INDEX, ABI_TERM_SPLIT_VARIABLE_INDEX,
+    ABI_TERM_SUBSTITUTE_INDEX, ABI_TERM_SUBSTITUTE_WITH_INDEX,
+    ABI_TERM_SUBTERM_AT_INDEX, ABI_TERM_TEST_APPLICATION_INDEX,
+    ABI_TERM_TEST_COMPREHENSION_INDEX, ABI_TERM_TEST_CONDITIONAL_INDEX,
+    ABI_TERM_TEST_CONJUNCTION_INDEX, ABI_TERM_TEST_CONSTANT_INDEX,
+    ABI_TERM_TEST_DISJUNCTION_INDEX, ABI_TERM_TEST_EQUALITY_INDEX,
+    ABI_TERM_TEST_EXISTS_INDEX, ABI_TERM_TEST_FORALL_INDEX,
+    ABI_TERM_TEST_IMPLICATION_INDEX, ABI_TERM_TEST_LAMBDA_INDEX,
+    ABI_TERM_TEST_LET_INDEX, ABI_TERM_TEST_MEMBERSHIP_INDEX,
+    ABI_TERM_TEST_NEGATION_INDEX, ABI_TERM_TEST_PAIR_INDEX,
+    ABI_TERM_TEST_VARIABLE_INDEX, ABI_TERM_TYPE_INFER_INDEX,
+    ABI_TERM_TYPE_IS_PROPOSITION_INDEX, ABI_TERM_TYPE_SUBSTITUTE_INDEX,
+    ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX, ABI_TERM_TYPE_VARIABLES_COUNT_INDEX,
+    ABI_TERM_TYPE_VARIABLES_INDEX, ABI_TERM_TYPE_VARIABLES_RANGE_INDEX,
+    ABI_TERM_VIEW_MAP_INDEX, ABI_TERM_VIEW_RESERVE_INDEX,
+    ABI_THEOREM_ALPHA_EQUAL_INDEX, ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX,
+    ABI_THEOREM_FIND_BY_CONCLUSION_INDEX, ABI_THEOREM_FIND_MATCHING_INDEX,
+    ABI_THEOREM_HYPOTHESES_COUNT_INDEX, ABI_THEOREM_IS_TAINTED_INDEX,
+    ABI_THEOREM_PROOF_INDEX, ABI_THEOREM_REGISTER_APPLICATION_INDEX,
+    ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+    ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX,
+    ABI_THEOREM_REGISTER_BETA_INDEX,
+    ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_ETA_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_LAMBDA_INDEX,
+    ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX,
+    ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
+    ABI_THEOREM_REGISTER_REWRITE_AT_INDEX,
+    ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX,
+    ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX,
+    ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
+    ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX,
+    ABI_THEOREM_REGISTER_SYMMETRY_INDEX,
+    ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
+    ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
+    ABI_THEOREM_REGISTER_UNFOLD_INDEX,
+    ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_WEAKEN_INDEX, ABI_THEOREM_SIZE_INDEX,
+    ABI_THEOREM_SPLIT_CONCLUSION_INDEX, ABI_THEOREM_SPLIT_HYPOTHESES_INDEX,
+    ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX,
+    ABI_THEOREM_STATEMENT_AS_TERM_INDEX, ABI_THEOREM_STATEMENT_HASH_INDEX,
+    ABI_THEOREM_TRUST_LEVEL_INDEX, ABI_TYPE_DEFINE_INDEX,
+    ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX, ABI_TYPE_FORMER_METADATA_INDEX,
+    ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX,
+    ABI_TYPE_FORMER_RESOLVE_INDEX, ABI_TYPE_GENERALISE_INDEX,
+    ABI_TYPE_INSTANTIATE_FRESH_INDEX, ABI_TYPE_REGISTER_COMBINATION_INDEX,
+    ABI_TYPE_REGISTER_FUNCTION_INDEX, ABI_TYPE_SIZE_INDEX,
+    ABI_TYPE_SPLIT_COMBINATION_INDEX, ABI_TYPE_SPLIT_FUNCTION_INDEX,
+    ABI_TYPE_SPLIT_VARIABLE_INDEX, ABI_TYPE_SUBSTITUTE_INDEX,
+    ABI_TYPE_SUBSTITUTE_WITH_INDEX, ABI_TYPE_TEST_COMBINATION_INDEX,
+    ABI_TYPE_TEST_FUNCTION_INDEX, ABI_TYPE_TEST_VARIABLE_INDEX,
+    ABI_TYPE_VARIABLES_COUNT_INDEX, ABI_TYPE_VARIABLES_INDEX,
+    ABI_TYPE_VARIABLES_RANGE_INDEX,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Pointer arguments.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The size, in bytes, of a byte in a buffer of bytes.
+const BYTE_SIZE: u64 = 1;
+/// The size, in bytes, of a Boolean written to memory.
+const BOOLEAN_SIZE: u64 = 4;
+/// The size, in bytes, of a machine word, e.g. a handle or a name, in memory.
+const WORD_SIZE: u64 = 8;
+
+/// A pointer argument of a system call, describing the memory read or written
+/// through it.  Arguments are identified by their position in the system
+/// call's signature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PointerArgument {
+    /// A pointer to an array read by the system call, whose length, in
+    /// elements of `element_size` bytes, is the size argument at position
+    /// `length`.  If `non_empty` is `true` then the array must not be empty,
+    /// e.g. as for the domain of a substitution.
+    Input {
+        /// The position of the pointer argument.
+        pointer: usize,
+        /// The position of the argument holding the length of the array.
+        length: usize,
+        /// The size, in bytes, of each element of the array.
+        element_size: u64,
+        /// Whether the array must be non-empty.
+        non_empty: bool,
+    },
+    /// A pointer to a result of at least `size` bytes written by the system
+    /// call.
+    Output {
+        /// The position of the pointer argument.
+        pointer: usize,
+        /// The size, in bytes, of the result.
+        size: u64,
+    },
+    /// A pointer to a result buffer written by the system call, whose capacity,
+    /// in elements of `element_size` bytes, is read from the word pointed-to
+    /// by the argument at position `length_pointer`, which the system call
+    /// then overwrites with the length of the result.
+    Buffer {
+        /// The position of the pointer argument.
+        pointer: usize,
+        /// The position of the argument pointing-to the capacity of the buffer.
+        length_pointer: usize,
+        /// The size, in bytes, of each element of the buffer.
+        element_size: u64,
+    },
+}
+
+/// Returns the pointer arguments of the system call with index `index`.
+/// System calls that take no pointer arguments, or that are unknown, have
+/// none.
+pub(crate) fn pointer_arguments(index: usize) -> &'static [PointerArgument] {
+    match index {
+        ABI_TYPE_FORMER_RESOLVE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TYPE_REGISTER_COMBINATION_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_REGISTER_FUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TYPE_SPLIT_VARIABLE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TYPE_SPLIT_COMBINATION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_SPLIT_FUNCTION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_TEST_VARIABLE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TYPE_TEST_COMBINATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TYPE_TEST_FUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TYPE_SIZE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TYPE_VARIABLES_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_SUBSTITUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_CONSTANT_REGISTER_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_CONSTANT_RESOLVE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_VARIABLE_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_CONSTANT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_REGISTER_APPLICATION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_LAMBDA_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_NEGATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_CONJUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_DISJUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_IMPLICATION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_EQUALITY_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_FORALL_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_EXISTS_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SPLIT_VARIABLE_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_CONSTANT_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_APPLICATION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_LAMBDA_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_NEGATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SPLIT_CONJUNCTION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_DISJUNCTION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_IMPLICATION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_EQUALITY_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_FORALL_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_EXISTS_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TEST_VARIABLE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_CONSTANT_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_APPLICATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_LAMBDA_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_NEGATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_CONJUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_DISJUNCTION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_IMPLICATION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_EQUALITY_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_FORALL_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_EXISTS_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_FREE_VARIABLES_INDEX => &[
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SUBSTITUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 5,
+                length: 6,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 7,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TYPE_VARIABLES_INDEX => &[
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TYPE_SUBSTITUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TYPE_INFER_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_TYPE_IS_PROPOSITION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_THEOREM_SPLIT_CONCLUSION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_SIZE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_SPLIT_HYPOTHESES_INDEX => &[
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_REGISTER_ASSUMPTION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_WEAKEN_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_SYMMETRY_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_BETA_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_ETA_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_APPLICATION_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_LAMBDA_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 5,
+                length: 6,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 7,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 0,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_KERNEL_ENUMERATE_INDEX => &[
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_KERNEL_IDENTIFY_INDEX | ABI_KERNEL_ERROR_DETAIL_INDEX => {
+            &[PointerArgument::Buffer {
+                pointer: 0,
+                length_pointer: 1,
+                element_size: BYTE_SIZE,
+            }]
+        }
+        ABI_HYPOTHESES_INTERN_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_HYPOTHESES_RESOLVE_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_CONDITIONAL_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_LET_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SPLIT_CONDITIONAL_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_LET_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TEST_CONDITIONAL_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_LET_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_TERM_REGISTER_PAIR_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_FIRST_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_SECOND_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SPLIT_PAIR_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TEST_PAIR_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_TERM_REGISTER_MEMBERSHIP_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_REGISTER_COMPREHENSION_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SPLIT_MEMBERSHIP_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SPLIT_COMPREHENSION_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_TEST_MEMBERSHIP_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_TERM_TEST_COMPREHENSION_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_CHALLENGE_DISCHARGE_INDEX => &[PointerArgument::Output {
+            pointer: 4,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_FOLD_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_PROOF_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_PROOF_SPLIT_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_PROOF_SPLIT_ARGUMENTS_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: WORD_SIZE,
+        }],
+        ABI_THEOREM_STATEMENT_HASH_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_STATEMENT_AS_TERM_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_QUOTIENT_REGISTER_INDEX => &[PointerArgument::Output {
+            pointer: 4,
+            size: WORD_SIZE,
+        }],
+        ABI_QUOTIENT_SPLIT_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_QUOTIENT_LIFT_INDEX => &[
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_QUOTIENT_TRANSFER_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_SUBSTITUTION_REGISTER_TYPE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 2,
+                length: 3,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_SUBSTITUTION_REGISTER_TERM_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 2,
+                length: 3,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Input {
+                pointer: 4,
+                length: 5,
+                element_size: WORD_SIZE,
+                non_empty: true,
+            },
+            PointerArgument::Output {
+                pointer: 6,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_SUBSTITUTE_WITH_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_SUBSTITUTE_WITH_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_BUDGET_REMAINING_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_BUDGET_RESOURCE_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_SPLIT_PREMISSES_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_AXIOM_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_THEOREM_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_CUT_INDEX => &[PointerArgument::Output {
+            pointer: 3,
+            size: WORD_SIZE,
+        }],
+        ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_CONSTANT_REGISTER_NAMED_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_CONSTANT_LOOKUP_BY_NAME_INDEX
+        | ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_KERNEL_BATCH_EXECUTE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_FORMER_METADATA_INDEX => &[
+            PointerArgument::Buffer {
+                pointer: 1,
+                length_pointer: 2,
+                element_size: BYTE_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 3,
+                length_pointer: 4,
+                element_size: BYTE_SIZE,
+            },
+        ],
+        ABI_OBJECT_METADATA_SET_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+        ],
+        ABI_OBJECT_METADATA_GET_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Buffer {
+                pointer: 3,
+                length_pointer: 4,
+                element_size: BYTE_SIZE,
+            },
+        ],
+        ABI_OBJECT_METADATA_ENUMERATE_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: BYTE_SIZE,
+        }],
+        ABI_TERM_VIEW_RESERVE_INDEX => &[PointerArgument::Input {
+            pointer: 0,
+            length: 1,
+            element_size: BYTE_SIZE,
+            non_empty: true,
+        }],
+        ABI_TERM_VIEW_MAP_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_LINEAR_ARITH_SIGNATURE_INDEX => &[PointerArgument::Output {
+            pointer: 0,
+            size: 6 * WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_IS_TAINTED_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_THEOREM_HYPOTHESES_COUNT_INDEX
+        | ABI_TYPE_VARIABLES_COUNT_INDEX
+        | ABI_TERM_FREE_VARIABLES_COUNT_INDEX
+        | ABI_TERM_TYPE_VARIABLES_COUNT_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX
+        | ABI_TYPE_VARIABLES_RANGE_INDEX
+        | ABI_TERM_TYPE_VARIABLES_RANGE_INDEX => &[PointerArgument::Buffer {
+            pointer: 2,
+            length_pointer: 3,
+            element_size: WORD_SIZE,
+        }],
+        ABI_TERM_FREE_VARIABLES_RANGE_INDEX => &[
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 4,
+                element_size: WORD_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 3,
+                length_pointer: 4,
+                element_size: WORD_SIZE,
+            },
+        ],
+        ABI_INTROSPECT_CALL_COUNT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_INTROSPECT_FUEL_CONSUMED_INDEX | ABI_INTROSPECT_HEAP_SIZE_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 0,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_DIFF_INDEX => &[PointerArgument::Buffer {
+            pointer: 2,
+            length_pointer: 3,
+            element_size: BYTE_SIZE,
+        }],
+        ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 1,
+                size: BOOLEAN_SIZE,
+            }]
+        }
+        ABI_THEOREM_FIND_BY_CONCLUSION_INDEX
+        | ABI_THEOREM_FIND_MATCHING_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: WORD_SIZE,
+        }],
+        ABI_THEOREM_REGISTER_UNFOLD_INDEX => &[
+            PointerArgument::Input {
+                pointer: 3,
+                length: 4,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_SUBTERM_AT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TERM_REPLACE_AT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_REGISTER_REWRITE_AT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 2,
+                length: 3,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX => {
+            &[PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            }]
+        }
+        ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_SCRATCH_PUT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Input {
+                pointer: 2,
+                length: 3,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+        ],
+        ABI_SCRATCH_GET_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: BYTE_SIZE,
+            },
+        ],
+        ABI_SCRATCH_DELETE_INDEX => &[PointerArgument::Input {
+            pointer: 0,
+            length: 1,
+            element_size: BYTE_SIZE,
+            non_empty: false,
+        }],
+        ABI_PROOF_OBJECT_EXPORT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: BYTE_SIZE,
+            },
+        ],
+        ABI_KERNEL_EXPORT_PROOF_INDEX => &[PointerArgument::Buffer {
+            pointer: 1,
+            length_pointer: 2,
+            element_size: BYTE_SIZE,
+        }],
+        ABI_PROOF_OBJECT_IMPORT_INDEX => &[
+            PointerArgument::Input {
+                pointer: 0,
+                length: 1,
+                element_size: BYTE_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: WORD_SIZE,
+            },
+        ],
+        ABI_ERROR_DETAIL_LAST_INDEX => &[
+            PointerArgument::Output {
+                pointer: 0,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: BYTE_SIZE,
+            },
+        ],
+        ABI_TYPE_GENERALISE_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 4,
+                length_pointer: 5,
+                element_size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_INSTANTIATE_FRESH_INDEX => &[
+            PointerArgument::Input {
+                pointer: 1,
+                length: 2,
+                element_size: WORD_SIZE,
+                non_empty: false,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 4,
+                length_pointer: 5,
+                element_size: WORD_SIZE,
+            },
+        ],
+        ABI_THEOREM_ALPHA_EQUAL_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_CHALLENGE_CURRENT_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_THEOREM_TRUST_LEVEL_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: WORD_SIZE,
+        }],
+        ABI_SUBSTITUTION_COMPOSE_INDEX => &[PointerArgument::Output {
+            pointer: 2,
+            size: WORD_SIZE,
+        }],
+        ABI_CONSTANT_DEFINE_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_TYPE_DEFINE_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 2,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 3,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 4,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Output {
+                pointer: 5,
+                size: WORD_SIZE,
+            },
+        ],
+        ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX => &[PointerArgument::Input {
+            pointer: 1,
+            length: 2,
+            element_size: WORD_SIZE,
+            non_empty: true,
+        }],
+        ABI_CONSTANT_IS_CONSTRUCTOR_INDEX => &[PointerArgument::Output {
+            pointer: 1,
+            size: BOOLEAN_SIZE,
+        }],
+        ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX => &[
+            PointerArgument::Output {
+                pointer: 1,
+                size: WORD_SIZE,
+            },
+            PointerArgument::Buffer {
+                pointer: 2,
+                length_pointer: 3,
+                element_size: WORD_SIZE,
+            },
+        ],
+        _otherwise => &[],
+    }
+}
@@ -0,0 +1,392 @@
+//! # Host-call cost model
+//!
+//! Each host call made by a guest crosses the boundary between the guest and
+//! the host, which is costly relative to the work done by many host calls, and
+//! moves data across it, through the guest's memory.  To guide the design of
+//! the ABI, the runtime state may record, for a run of a guest, the number of
+//! crossings made and the bytes read from, and written to, the guest's memory,
+//! both in total and broken down by host call.
+//!
+//! The sequence of host calls made is also recorded, so that the crossings
+//! that proposed additions to the ABI would save may be estimated, by
+//! simulating two kinds of proposal over the sequence:
+//!
+//! - *batched* calls, that make several consecutive calls to the same host
+//!   call in a single crossing,
+//! - *compound* calls, that make a call to one host call followed by a call to
+//!   another in a single crossing.
+//!
+//! The estimates are upper bounds, as the arguments of a call in a batch, or
+//! of the second call in a compound, may depend upon the results of the call
+//! before, which the guest must then compute without crossing.  Bytes read or
+//! written by host calls that invoke guest callbacks are attributed to the
+//! host calls that the callbacks make, where those make any.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Error as DisplayError, Formatter},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Costs.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The cost of the host calls made to one host call, or to all of them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CallCost {
+    /// The number of crossings made, one per host call.
+    pub crossings: u64,
+    /// The number of bytes read from the guest's memory.
+    pub bytes_read: u64,
+    /// The number of bytes written to the guest's memory.
+    pub bytes_written: u64,
+}
+
+impl CallCost {
+    /// Adds `other` to the cost.
+    #[inline]
+    fn add(&mut self, other: &CallCost) {
+        self.crossings += other.crossings;
+        self.bytes_read += other.bytes_read;
+        self.bytes_written += other.bytes_written;
+    }
+}
+
+impl Display for CallCost {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(
+            f,
+            "{} crossings, {} bytes read, {} bytes written",
+            self.crossings, self.bytes_read, self.bytes_written
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Recording costs.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Records the costs of the host calls made by a guest, by host-call number,
+/// as the runtime state makes them.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CostRecorder {
+    /// The cost of each host call made, by host-call number.
+    costs: HashMap<usize, CallCost>,
+    /// The host calls made, by host-call number, in the order in which they
+    /// were made.
+    sequence: Vec<usize>,
+    /// The host calls in progress, innermost last, to which memory accesses
+    /// are attributed.
+    active: Vec<usize>,
+}
+
+impl CostRecorder {
+    /// Records that the host call numbered `index` has been made, and is in
+    /// progress until `end` is called.
+    pub(crate) fn begin(&mut self, index: usize) {
+        self.costs.entry(index).or_default().crossings += 1;
+        self.sequence.push(index);
+        self.active.push(index);
+    }
+
+    /// Records that the innermost host call in progress has returned.
+    #[inline]
+    pub(crate) fn end(&mut self) {
+        self.active.pop();
+    }
+
+    /// Records that `count` bytes were read from the guest's memory by the
+    /// innermost host call in progress, if any.
+    pub(crate) fn read(&mut self, count: usize) {
+        if let Some(index) = self.active.last() {
+            self.costs.entry(*index).or_default().bytes_read += count as u64;
+        }
+    }
+
+    /// Records that `count` bytes were written to the guest's memory by the
+    /// innermost host call in progress, if any.
+    pub(crate) fn written(&mut self, count: usize) {
+        if let Some(index) = self.active.last() {
+            self.costs.entry(*index).or_default().bytes_written += count as u64;
+        }
+    }
+
+    /// Returns a report of the costs recorded so far, naming each host call
+    /// with `name`.
+    pub(crate) fn report<F>(&self, name: F) -> CostReport
+    where
+        F: Fn(usize) -> String,
+    {
+        let mut names = HashMap::new();
+
+        for index in self.costs.keys() {
+            names.insert(*index, name(*index));
+        }
+
+        CostReport {
+            costs: self.costs.clone(),
+            sequence: self.sequence.clone(),
+            names,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cost reports.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The costs of the host calls made by a guest during a run.
+#[derive(Clone, Debug, Default)]
+pub struct CostReport {
+    /// The cost of each host call made, by host-call number.
+    costs: HashMap<usize, CallCost>,
+    /// The host calls made, by host-call number, in the order in which they
+    /// were made.
+    sequence: Vec<usize>,
+    /// The name of each host call made, by host-call number.
+    names: HashMap<usize, String>,
+}
+
+impl CostReport {
+    /// Returns the total cost of every host call made.
+    pub fn total(&self) -> CallCost {
+        let mut total = CallCost::default();
+
+        for cost in self.costs.values() {
+            total.add(cost);
+        }
+
+        total
+    }
+
+    /// Returns the cost of each host call made, by name, most crossings
+    /// first.
+    pub fn per_call(&self) -> Vec<(&str, CallCost)> {
+        let mut costs: Vec<(&str, CallCost)> = self
+            .costs
+            .iter()
+            .map(|(index, cost)| (self.name(*index), *cost))
+            .collect();
+
+        costs.sort_by(|(left_name, left), (right_name, right)| {
+            right
+                .crossings
+                .cmp(&left.crossings)
+                .then(left_name.cmp(right_name))
+        });
+
+        costs
+    }
+
+    /// Returns the name of the host call numbered `index`.
+    #[inline]
+    fn name(&self, index: usize) -> &str {
+        self.names
+            .get(&index)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// Estimates the crossings that batched and compound host calls would
+    /// have saved, had the guest used them wherever possible, returning the
+    /// `limit` proposals that would have saved the most, most first.
+    pub fn simulate_batching(&self, limit: usize) -> Vec<BatchingEstimate> {
+        let mut batched: HashMap<usize, u64> = HashMap::new();
+        let mut compound: HashMap<(usize, usize), u64> = HashMap::new();
+
+        /* NB: a run of `n` consecutive calls to one host call is made in one
+         * crossing, rather than `n`, by a batched call.
+         */
+        for pair in self.sequence.windows(2) {
+            if pair[0] == pair[1] {
+                *batched.entry(pair[0]).or_insert(0) += 1;
+            }
+        }
+
+        /* NB: each pair of calls that a compound call would make saves one
+         * crossing, but the pairs that it makes cannot overlap, so they are
+         * counted greedily, from the start of the sequence.
+         */
+        let mut pairs: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+        for (position, pair) in self.sequence.windows(2).enumerate() {
+            if pair[0] != pair[1] {
+                pairs.entry((pair[0], pair[1])).or_default().push(position);
+            }
+        }
+
+        for (pair, positions) in pairs {
+            let mut next = 0;
+
+            for position in positions {
+                if position >= next {
+                    *compound.entry(pair).or_insert(0) += 1;
+                    next = position + 2;
+                }
+            }
+        }
+
+        let mut estimates: Vec<BatchingEstimate> = batched
+            .into_iter()
+            .map(|(index, saved)| BatchingEstimate {
+                proposal: BatchingProposal::Batched(
+                    self.name(index).to_string(),
+                ),
+                crossings_saved: saved,
+            })
+            .chain(compound.into_iter().map(|((first, second), saved)| {
+                BatchingEstimate {
+                    proposal: BatchingProposal::Compound(
+                        self.name(first).to_string(),
+                        self.name(second).to_string(),
+                    ),
+                    crossings_saved: saved,
+                }
+            }))
+            .collect();
+
+        estimates.sort_by(|left, right| {
+            right
+                .crossings_saved
+                .cmp(&left.crossings_saved)
+                .then(left.proposal.cmp(&right.proposal))
+        });
+        estimates.truncate(limit);
+
+        estimates
+    }
+}
+
+/// Pretty-printing for cost reports, as the total cost followed by the cost
+/// of each host call made, one per line.
+impl Display for CostReport {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(f, "Host calls made: {}.", self.total())?;
+
+        for (name, cost) in self.per_call() {
+            write!(f, "\n    {}: {}.", name, cost)?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Batching estimates.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A proposed addition to the ABI that would save crossings.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BatchingProposal {
+    /// A batched call, making consecutive calls to the named host call in one
+    /// crossing.
+    Batched(String),
+    /// A compound call, making a call to the first named host call followed
+    /// by a call to the second in one crossing.
+    Compound(String, String),
+}
+
+impl Display for BatchingProposal {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            BatchingProposal::Batched(name) => {
+                write!(f, "batched {}", name)
+            }
+            BatchingProposal::Compound(first, second) => {
+                write!(f, "compound {} then {}", first, second)
+            }
+        }
+    }
+}
+
+/// The number of crossings that a proposed addition to the ABI would have
+/// saved during a run.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BatchingEstimate {
+    /// The proposed addition.
+    pub proposal: BatchingProposal,
+    /// The number of crossings that it would have saved.
+    pub crossings_saved: u64,
+}
+
+impl Display for BatchingEstimate {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(
+            f,
+            "{}: {} crossings saved",
+            self.proposal, self.crossings_saved
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::cost::{BatchingEstimate, BatchingProposal, CostRecorder};
+
+    /// Tests that memory accesses are attributed to the innermost host call in
+    /// progress, and that batched and compound calls are credited with the
+    /// crossings that they would save.
+    #[test]
+    pub fn cost0() {
+        let mut recorder = CostRecorder::default();
+
+        for index in [0, 0, 0, 1, 2, 1, 2, 1, 2, 2] {
+            recorder.begin(index);
+            recorder.read(8);
+            recorder.end();
+        }
+
+        recorder.begin(3);
+        recorder.written(4);
+        recorder.begin(0);
+        recorder.written(8);
+        recorder.end();
+        recorder.end();
+        recorder.written(16);
+
+        let report = recorder.report(|index| format!("c{}", index));
+        let total = report.total();
+
+        assert_eq!(total.crossings, 12);
+        assert_eq!(total.bytes_read, 80);
+        assert_eq!(total.bytes_written, 12);
+        assert_eq!(report.per_call()[0].0, "c0");
+        assert_eq!(report.per_call()[0].1.crossings, 4);
+
+        assert_eq!(
+            report.simulate_batching(2),
+            vec![
+                BatchingEstimate {
+                    proposal: BatchingProposal::Compound(
+                        String::from("c1"),
+                        String::from("c2")
+                    ),
+                    crossings_saved: 3,
+                },
+                BatchingEstimate {
+                    proposal: BatchingProposal::Batched(String::from("c0")),
+                    crossings_saved: 2,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! # Machine-state introspection
+//!
+//! The kernel's challenges are constructed by the host from the machine state,
+//! and the `capability` module leaves open which parts of that state a
+//! challenge may depend upon.  To let research guests experiment with such
+//! state-dependent challenges, the runtime state may reveal a few facts about
+//! the guest's own execution to the guest itself, through the experimental
+//! `Introspect` host calls:
+//!
+//! - the number of times that each host call has been made,
+//! - the fuel consumed by the guest, counted as one unit per host call, as the
+//!   interpreter does not meter the guest's own instructions,
+//! - the size, in bytes, of the guest's heap.
+//!
+//! Which facts are revealed is a policy decided by the host: none are by
+//! default, and asking for a fact that the policy does not reveal fails with
+//! `ErrorCode::IntrospectionRefused`.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    fmt::{Display, Error as DisplayError, Formatter},
+    str::FromStr,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Introspectable facts.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The facts about its own machine state that a guest may be allowed to
+/// inspect.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Introspection {
+    /// The number of times that each host call has been made.
+    CallCounts,
+    /// The fuel consumed by the guest, one unit per host call made.
+    FuelConsumed,
+    /// The size of the guest's heap, in bytes.
+    HeapSize,
+}
+
+impl Introspection {
+    /// Every fact that a guest may be allowed to inspect.
+    pub const ALL: [Introspection; 3] = [
+        Introspection::CallCounts,
+        Introspection::FuelConsumed,
+        Introspection::HeapSize,
+    ];
+}
+
+/// Pretty-printing for introspectable facts, in the form accepted by
+/// `from_str`.
+impl Display for Introspection {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            Introspection::CallCounts => write!(f, "call-counts"),
+            Introspection::FuelConsumed => write!(f, "fuel"),
+            Introspection::HeapSize => write!(f, "heap-size"),
+        }
+    }
+}
+
+/// Parsing of introspectable facts, as named on the command line.
+impl FromStr for Introspection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Introspection::ALL
+            .iter()
+            .find(|fact| fact.to_string() == s)
+            .cloned()
+            .ok_or_else(|| format!("Unknown introspectable fact: {}.", s))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::introspection::Introspection;
+
+    /// Tests that every fact is parsed back from its pretty-printing, and that
+    /// unknown facts are rejected.
+    #[test]
+    pub fn introspection0() {
+        for fact in Introspection::ALL.iter() {
+            assert_eq!(fact.to_string().parse::<Introspection>(), Ok(*fact));
+        }
+
+        assert!("registers".parse::<Introspection>().is_err());
+    }
+}
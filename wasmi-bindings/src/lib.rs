@@ -5,6 +5,13 @@
 //! specific to WASMI, and if we were to implement another binding for e.g.
 //! Wasmtime, we'd need another module to handle it.
 //!
+//! Building with the `canaries` feature makes the binding check every host
+//! call that writes results to the guest's memory: the results must not
+//! overlap, and canaries written around each are checked once the call
+//! returns.  This catches guests passing aliased result pointers, and host
+//! calls writing outside of their results, at the host call responsible, but
+//! slows every such call down considerably, so is meant for debugging builds.
+//!
 //! # Authors
 //!
 //! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
@@ -20,8 +27,13 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+mod argument_validation;
+pub mod cost;
+pub mod introspection;
+pub mod rule_policy;
 pub mod runtime_state;
 mod runtime_trap;
 mod system_call_numbers;
 mod system_interface_types;
+pub mod trace;
 mod type_checking;
@@ -0,0 +1,580 @@
+//! # Rule policies
+//!
+//! For teaching, it is useful to restrict a guest to a few of the kernel's
+//! inference rules, so that an assignment may force a proof to be carried out
+//! "the long way", using only the rules of natural deduction, say, and no
+//! substitution.  A *rule policy* names the inference rules, that is the host
+//! calls producing theorems, that a guest may apply in a session, and the
+//! runtime state refuses every other inference rule with
+//! `ErrorCode::PermissionDenied`.  Besides the `Theorem.Register` and
+//! `Sequent.Register` host calls, the inference rules include every other host
+//! call that mints a theorem, such as `Quotient.Lift`.  Host calls that are not
+//! inference rules are unaffected.  No policy is in force by default, so that
+//! every inference rule is permitted.
+//!
+//! Policies are written as text, one rule per line, naming the host call that
+//! applies it, as in `__theorem_register_conjunction_introduction`.  Blank
+//! lines are ignored, as is everything following a `#` on a line.
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use crate::system_call_numbers::{
+    ABI_CONSTANT_DEFINE_INDEX, ABI_CONSTANT_DEFINE_NAME,
+    ABI_QUOTIENT_LIFT_INDEX, ABI_QUOTIENT_LIFT_NAME,
+    ABI_QUOTIENT_REGISTER_INDEX, ABI_QUOTIENT_REGISTER_NAME,
+    ABI_QUOTIENT_TRANSFER_INDEX, ABI_QUOTIENT_TRANSFER_NAME,
+    ABI_SEQUENT_REGISTER_AXIOM_INDEX, ABI_SEQUENT_REGISTER_AXIOM_NAME,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_NAME,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_NAME,
+    ABI_SEQUENT_REGISTER_CUT_INDEX, ABI_SEQUENT_REGISTER_CUT_NAME,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_NAME,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_NAME,
+    ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_NAME,
+    ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_NAME,
+    ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_NEGATION_LEFT_NAME,
+    ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_NEGATION_RIGHT_NAME,
+    ABI_SEQUENT_REGISTER_THEOREM_INDEX, ABI_SEQUENT_REGISTER_THEOREM_NAME,
+    ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX,
+    ABI_SEQUENT_REGISTER_WEAKEN_LEFT_NAME,
+    ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX,
+    ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_NAME,
+    ABI_THEOREM_REGISTER_APPLICATION_INDEX,
+    ABI_THEOREM_REGISTER_APPLICATION_NAME,
+    ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+    ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX,
+    ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_NAME,
+    ABI_THEOREM_REGISTER_ASSUMPTION_NAME, ABI_THEOREM_REGISTER_BETA_INDEX,
+    ABI_THEOREM_REGISTER_BETA_NAME,
+    ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_NAME,
+    ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_ETA_INDEX, ABI_THEOREM_REGISTER_ETA_NAME,
+    ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_NAME,
+    ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX,
+    ABI_THEOREM_REGISTER_FIRST_PROJECTION_NAME,
+    ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_NAME,
+    ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_IFF_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_LAMBDA_INDEX, ABI_THEOREM_REGISTER_LAMBDA_NAME,
+    ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX,
+    ABI_THEOREM_REGISTER_LET_CONGRUENCE_NAME,
+    ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX,
+    ABI_THEOREM_REGISTER_LINEAR_ARITH_NAME,
+    ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
+    ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_NAME,
+    ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
+    ABI_THEOREM_REGISTER_REFLEXIVITY_NAME,
+    ABI_THEOREM_REGISTER_REWRITE_AT_INDEX,
+    ABI_THEOREM_REGISTER_REWRITE_AT_NAME,
+    ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX,
+    ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_NAME,
+    ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX,
+    ABI_THEOREM_REGISTER_SECOND_PROJECTION_NAME,
+    ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_SUBSET_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
+    ABI_THEOREM_REGISTER_SUBSTITUTE_NAME,
+    ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX,
+    ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_NAME,
+    ABI_THEOREM_REGISTER_SYMMETRY_INDEX, ABI_THEOREM_REGISTER_SYMMETRY_NAME,
+    ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
+    ABI_THEOREM_REGISTER_TRANSITIVITY_NAME,
+    ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX,
+    ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_NAME,
+    ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
+    ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_NAME,
+    ABI_THEOREM_REGISTER_UNFOLD_INDEX, ABI_THEOREM_REGISTER_UNFOLD_NAME,
+    ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_UNION_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX,
+    ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_NAME,
+    ABI_THEOREM_REGISTER_WEAKEN_INDEX, ABI_THEOREM_REGISTER_WEAKEN_NAME,
+    ABI_TYPE_DEFINE_INDEX, ABI_TYPE_DEFINE_NAME,
+};
+use std::{collections::HashSet, str::FromStr};
+
+////////////////////////////////////////////////////////////////////////////////
+// Inference rules.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The host calls that apply inference rules, as the ABI index and name of
+/// each.  A host call belongs here iff it produces a theorem, whatever its
+/// name.
+const RULES: [(usize, &str); 66] = [
+    (
+        ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+        ABI_THEOREM_REGISTER_ASSUMPTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_WEAKEN_INDEX,
+        ABI_THEOREM_REGISTER_WEAKEN_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
+        ABI_THEOREM_REGISTER_REFLEXIVITY_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_SYMMETRY_INDEX,
+        ABI_THEOREM_REGISTER_SYMMETRY_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
+        ABI_THEOREM_REGISTER_TRANSITIVITY_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_BETA_INDEX,
+        ABI_THEOREM_REGISTER_BETA_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_ETA_INDEX,
+        ABI_THEOREM_REGISTER_ETA_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_APPLICATION_INDEX,
+        ABI_THEOREM_REGISTER_APPLICATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_LAMBDA_INDEX,
+        ABI_THEOREM_REGISTER_LAMBDA_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
+        ABI_THEOREM_REGISTER_SUBSTITUTE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
+        ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_IFF_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_FORALL_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
+        ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
+        ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_LET_CONGRUENCE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX,
+        ABI_THEOREM_REGISTER_FIRST_PROJECTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX,
+        ABI_THEOREM_REGISTER_SECOND_PROJECTION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX,
+        ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_UNION_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_SUBSET_DEFINITION_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_AXIOM_INDEX,
+        ABI_SEQUENT_REGISTER_AXIOM_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_THEOREM_INDEX,
+        ABI_SEQUENT_REGISTER_THEOREM_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_WEAKEN_LEFT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_CUT_INDEX,
+        ABI_SEQUENT_REGISTER_CUT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_NEGATION_LEFT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_NEGATION_RIGHT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_NAME,
+    ),
+    (
+        ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_NAME,
+    ),
+    (ABI_QUOTIENT_REGISTER_INDEX, ABI_QUOTIENT_REGISTER_NAME),
+    (ABI_QUOTIENT_LIFT_INDEX, ABI_QUOTIENT_LIFT_NAME),
+    (ABI_QUOTIENT_TRANSFER_INDEX, ABI_QUOTIENT_TRANSFER_NAME),
+    (
+        ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX,
+        ABI_THEOREM_REGISTER_LINEAR_ARITH_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_UNFOLD_INDEX,
+        ABI_THEOREM_REGISTER_UNFOLD_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_REWRITE_AT_INDEX,
+        ABI_THEOREM_REGISTER_REWRITE_AT_NAME,
+    ),
+    (ABI_CONSTANT_DEFINE_INDEX, ABI_CONSTANT_DEFINE_NAME),
+    (ABI_TYPE_DEFINE_INDEX, ABI_TYPE_DEFINE_NAME),
+    (
+        ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX,
+        ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_NAME,
+    ),
+    (
+        ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX,
+        ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_NAME,
+    ),
+];
+
+/// Returns the name of the host call numbered `index`, iff it applies an
+/// inference rule.
+#[inline]
+pub fn rule_name(index: usize) -> Option<&'static str> {
+    RULES
+        .iter()
+        .find(|(rule, _name)| *rule == index)
+        .map(|(_rule, name)| *name)
+}
+
+/// Returns the ABI index of the host call named `name`, iff it applies an
+/// inference rule.
+#[inline]
+pub fn rule_index(name: &str) -> Option<usize> {
+    RULES
+        .iter()
+        .find(|(_rule, rule_name)| *rule_name == name)
+        .map(|(rule, _name)| *rule)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Rule policies.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A whitelist of the inference rules that a guest may apply.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RulePolicy {
+    /// The ABI indices of the host calls applying the permitted inference
+    /// rules.
+    permitted: HashSet<usize>,
+}
+
+impl RulePolicy {
+    /// Constructs a policy permitting none of the inference rules.
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Permits the inference rule applied by the host call numbered `index`.
+    #[inline]
+    pub fn permit(&mut self, index: usize) -> &mut Self {
+        self.permitted.insert(index);
+        self
+    }
+
+    /// Returns `true` iff the policy permits a guest to make the host call
+    /// numbered `index`, which it does if the host call is not an inference
+    /// rule, or if it is one of the permitted rules.
+    #[inline]
+    pub fn permits(&self, index: usize) -> bool {
+        rule_name(index).is_none() || self.permitted.contains(&index)
+    }
+}
+
+/// Parsing of rule policies, as written in a policy file.  Lines naming
+/// anything other than an inference rule are rejected, so that mistakes in a
+/// policy are reported rather than silently ignored.
+impl FromStr for RulePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut policy = RulePolicy::new();
+
+        for (number, line) in s.lines().enumerate() {
+            let rule = line.split('#').next().unwrap_or_default().trim();
+
+            if rule.is_empty() {
+                continue;
+            }
+
+            let index = rule_index(rule).ok_or_else(|| {
+                format!(
+                    "Line {} of rule policy does not name an inference rule: {}.",
+                    number + 1,
+                    rule
+                )
+            })?;
+
+            policy.permit(index);
+        }
+
+        Ok(policy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        rule_policy::RulePolicy,
+        system_call_numbers::{
+            ABI_CONSTANT_DEFINE_INDEX, ABI_QUOTIENT_LIFT_INDEX,
+            ABI_QUOTIENT_TRANSFER_INDEX, ABI_SEQUENT_REGISTER_CUT_INDEX,
+            ABI_TERM_REGISTER_VARIABLE_INDEX,
+            ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+            ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
+            ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX, ABI_TYPE_DEFINE_INDEX,
+        },
+    };
+
+    /// Tests that a parsed policy permits exactly the rules that it names,
+    /// alongside every host call that is not an inference rule, and that lines
+    /// naming anything else are rejected.
+    #[test]
+    pub fn rule_policy0() {
+        let policy = "# Natural deduction only.\n\
+                      __theorem_register_assumption\n\
+                      \n\
+                      __theorem_register_conjunction_introduction # and-I\n"
+            .parse::<RulePolicy>()
+            .unwrap();
+
+        assert!(policy.permits(ABI_THEOREM_REGISTER_ASSUMPTION_INDEX));
+        assert!(
+            policy.permits(ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX)
+        );
+        assert!(!policy.permits(ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX));
+        assert!(!policy.permits(ABI_SEQUENT_REGISTER_CUT_INDEX));
+        assert!(policy.permits(ABI_TERM_REGISTER_VARIABLE_INDEX));
+
+        assert!("__term_register_variable".parse::<RulePolicy>().is_err());
+    }
+
+    /// Tests that host calls producing theorems are inference rules whatever
+    /// their names, so that a policy leaving out `__quotient_lift` rejects it,
+    /// and that a policy may permit them by name.
+    #[test]
+    pub fn rule_policy1() {
+        let policy = "__quotient_transfer\n".parse::<RulePolicy>().unwrap();
+
+        assert!(!policy.permits(ABI_QUOTIENT_LIFT_INDEX));
+        assert!(policy.permits(ABI_QUOTIENT_TRANSFER_INDEX));
+    }
+
+    /// Tests that `__constant_define`, which mints the defining theorem of the
+    /// new constant, is an inference rule, denied by a policy leaving it out.
+    #[test]
+    pub fn rule_policy2() {
+        let policy = "__theorem_register_reflexivity\n"
+            .parse::<RulePolicy>()
+            .unwrap();
+
+        assert!(!policy.permits(ABI_CONSTANT_DEFINE_INDEX));
+        assert!("__constant_define"
+            .parse::<RulePolicy>()
+            .unwrap()
+            .permits(ABI_CONSTANT_DEFINE_INDEX));
+    }
+
+    /// Tests that `__type_define`, which mints the theorems characterising the
+    /// new type, is an inference rule, denied by a policy leaving it out.
+    #[test]
+    pub fn rule_policy3() {
+        let policy = "__constant_define\n".parse::<RulePolicy>().unwrap();
+
+        assert!(!policy.permits(ABI_TYPE_DEFINE_INDEX));
+        assert!("__type_define"
+            .parse::<RulePolicy>()
+            .unwrap()
+            .permits(ABI_TYPE_DEFINE_INDEX));
+    }
+}
@@ -23,57 +23,199 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
-use std::{borrow::Borrow, cell::RefCell, fmt::Debug, mem::size_of};
+use std::{
+    borrow::Borrow,
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet, VecDeque},
+    convert::TryFrom,
+    fmt::Debug,
+    mem::size_of,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use byteorder::{ByteOrder, LittleEndian};
 use log::{error, info};
 use wasmi::{
-    Error as WasmiError, Externals, FuncInstance, FuncRef, MemoryRef,
-    ModuleImportResolver, RuntimeArgs, RuntimeValue, Signature, Trap,
+    memory_units::Bytes, Error as WasmiError, Externals, FuncInstance, FuncRef,
+    GlobalDescriptor, GlobalInstance, GlobalRef, MemoryRef,
+    ModuleImportResolver, NopExternals, RuntimeArgs, RuntimeValue, Signature,
+    TableDescriptor, TableInstance, TableRef, Trap, TrapKind, ValueType,
 };
 
 use kernel::{
+    batch::Batch,
+    capability::{Expiry, Service},
     error_code::ErrorCode as KernelErrorCode,
-    handle::{tags, Handle},
-    name::Name,
-    runtime_state::RuntimeState as KernelRuntimeState,
+    feature::Feature,
+    generalisation::Renaming,
+    handle::{tags, Handle, Kind},
+    identity::Identity,
+    metadata::TypeFormerMetadata,
+    name::{Name, VariableOrder},
+    observer::LongOperation,
+    proof::ProofArgument,
+    runtime_state::{FrozenRuntimeState, RuntimeState as KernelRuntimeState},
+    term::Term,
+    trust::TrustLevel,
+    unfold::UnfoldMode,
 };
 
+#[cfg(feature = "canaries")]
+use std::collections::BTreeMap;
+
+#[cfg(not(feature = "budgets"))]
+use crate::system_call_numbers::is_budget_index;
+#[cfg(not(feature = "linear-arith"))]
+use crate::system_call_numbers::is_linear_arith_index;
+#[cfg(not(feature = "sequents"))]
+use crate::system_call_numbers::is_sequent_index;
 use crate::{
+    argument_validation::{pointer_arguments, PointerArgument},
+    cost::{CostRecorder, CostReport},
+    introspection::Introspection,
+    rule_policy::{rule_name, RulePolicy},
     runtime_trap,
     runtime_trap::RuntimeTrap,
     system_call_numbers::{
-        ABI_CONSTANT_IS_REGISTERED_INDEX, ABI_CONSTANT_IS_REGISTERED_NAME,
-        ABI_CONSTANT_REGISTER_INDEX, ABI_CONSTANT_REGISTER_NAME,
-        ABI_CONSTANT_RESOLVE_INDEX, ABI_CONSTANT_RESOLVE_NAME,
-        ABI_TERM_FREE_VARIABLES_INDEX, ABI_TERM_FREE_VARIABLES_NAME,
-        ABI_TERM_IS_REGISTERED_INDEX, ABI_TERM_IS_REGISTERED_NAME,
-        ABI_TERM_REGISTER_APPLICATION_INDEX,
+        is_experimental_index, is_predicate_index, ABI_BUDGET_REMAINING_INDEX,
+        ABI_BUDGET_REMAINING_NAME, ABI_BUDGET_RESOURCE_INDEX,
+        ABI_BUDGET_RESOURCE_NAME, ABI_CAPABILITY_PRESENT_INDEX,
+        ABI_CAPABILITY_PRESENT_NAME, ABI_CHALLENGE_CURRENT_INDEX,
+        ABI_CHALLENGE_CURRENT_NAME, ABI_CHALLENGE_DISCHARGE_INDEX,
+        ABI_CHALLENGE_DISCHARGE_NAME, ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX,
+        ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_NAME, ABI_CONSTANT_DEFINE_INDEX,
+        ABI_CONSTANT_DEFINE_NAME, ABI_CONSTANT_IS_CONSTRUCTOR_INDEX,
+        ABI_CONSTANT_IS_CONSTRUCTOR_NAME, ABI_CONSTANT_IS_REGISTERED_INDEX,
+        ABI_CONSTANT_IS_REGISTERED_NAME, ABI_CONSTANT_LOOKUP_BY_NAME_INDEX,
+        ABI_CONSTANT_LOOKUP_BY_NAME_NAME, ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX,
+        ABI_CONSTANT_MARK_CONSTRUCTORS_NAME, ABI_CONSTANT_REGISTER_INDEX,
+        ABI_CONSTANT_REGISTER_NAME, ABI_CONSTANT_REGISTER_NAMED_INDEX,
+        ABI_CONSTANT_REGISTER_NAMED_NAME, ABI_CONSTANT_RESOLVE_INDEX,
+        ABI_CONSTANT_RESOLVE_NAME, ABI_ERROR_DETAIL_LAST_INDEX,
+        ABI_ERROR_DETAIL_LAST_NAME, ABI_HOST_TABLE_NAME, ABI_HOST_TABLE_SIZE,
+        ABI_HOST_TABLE_VERSION, ABI_HOST_TABLE_VERSION_NAME,
+        ABI_HYPOTHESES_INTERN_INDEX, ABI_HYPOTHESES_INTERN_NAME,
+        ABI_HYPOTHESES_RESOLVE_INDEX, ABI_HYPOTHESES_RESOLVE_NAME,
+        ABI_INTROSPECT_CALL_COUNT_INDEX, ABI_INTROSPECT_CALL_COUNT_NAME,
+        ABI_INTROSPECT_FUEL_CONSUMED_INDEX, ABI_INTROSPECT_FUEL_CONSUMED_NAME,
+        ABI_INTROSPECT_HEAP_SIZE_INDEX, ABI_INTROSPECT_HEAP_SIZE_NAME,
+        ABI_KERNEL_BATCH_EXECUTE_INDEX, ABI_KERNEL_BATCH_EXECUTE_NAME,
+        ABI_KERNEL_ENUMERATE_INDEX, ABI_KERNEL_ENUMERATE_NAME,
+        ABI_KERNEL_ERROR_DETAIL_INDEX, ABI_KERNEL_ERROR_DETAIL_NAME,
+        ABI_KERNEL_EXPORT_PROOF_INDEX, ABI_KERNEL_EXPORT_PROOF_NAME,
+        ABI_KERNEL_FEATURE_SUPPORTED_INDEX, ABI_KERNEL_FEATURE_SUPPORTED_NAME,
+        ABI_KERNEL_IDENTIFY_INDEX, ABI_KERNEL_IDENTIFY_NAME,
+        ABI_KERNEL_RESET_INDEX, ABI_KERNEL_RESET_NAME,
+        ABI_KERNEL_SET_VARIABLE_ORDER_INDEX,
+        ABI_KERNEL_SET_VARIABLE_ORDER_NAME, ABI_LINEAR_ARITH_SIGNATURE_INDEX,
+        ABI_LINEAR_ARITH_SIGNATURE_NAME, ABI_OBJECT_DEC_REF_INDEX,
+        ABI_OBJECT_DEC_REF_NAME, ABI_OBJECT_DELETE_INDEX,
+        ABI_OBJECT_DELETE_NAME, ABI_OBJECT_INC_REF_INDEX,
+        ABI_OBJECT_INC_REF_NAME, ABI_OBJECT_METADATA_ENUMERATE_INDEX,
+        ABI_OBJECT_METADATA_ENUMERATE_NAME, ABI_OBJECT_METADATA_GET_INDEX,
+        ABI_OBJECT_METADATA_GET_NAME, ABI_OBJECT_METADATA_SET_INDEX,
+        ABI_OBJECT_METADATA_SET_NAME, ABI_PROGRESS_REGISTER_INDEX,
+        ABI_PROGRESS_REGISTER_NAME, ABI_PROOF_CHECK_INDEX,
+        ABI_PROOF_CHECK_NAME, ABI_PROOF_OBJECT_EXPORT_INDEX,
+        ABI_PROOF_OBJECT_EXPORT_NAME, ABI_PROOF_OBJECT_IMPORT_INDEX,
+        ABI_PROOF_OBJECT_IMPORT_NAME, ABI_PROOF_SPLIT_ARGUMENTS_INDEX,
+        ABI_PROOF_SPLIT_ARGUMENTS_NAME, ABI_PROOF_SPLIT_INDEX,
+        ABI_PROOF_SPLIT_NAME, ABI_QUOTIENT_LIFT_INDEX, ABI_QUOTIENT_LIFT_NAME,
+        ABI_QUOTIENT_REGISTER_INDEX, ABI_QUOTIENT_REGISTER_NAME,
+        ABI_QUOTIENT_SPLIT_INDEX, ABI_QUOTIENT_SPLIT_NAME,
+        ABI_QUOTIENT_TRANSFER_INDEX, ABI_QUOTIENT_TRANSFER_NAME,
+        ABI_SCRATCH_DELETE_INDEX, ABI_SCRATCH_DELETE_NAME,
+        ABI_SCRATCH_GET_INDEX, ABI_SCRATCH_GET_NAME, ABI_SCRATCH_PUT_INDEX,
+        ABI_SCRATCH_PUT_NAME, ABI_SEQUENT_IS_REGISTERED_INDEX,
+        ABI_SEQUENT_IS_REGISTERED_NAME, ABI_SEQUENT_REGISTER_AXIOM_INDEX,
+        ABI_SEQUENT_REGISTER_AXIOM_NAME,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_NAME,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_NAME,
+        ABI_SEQUENT_REGISTER_CUT_INDEX, ABI_SEQUENT_REGISTER_CUT_NAME,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_NAME,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_NAME,
+        ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_NAME,
+        ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_NAME,
+        ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_NEGATION_LEFT_NAME,
+        ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_NEGATION_RIGHT_NAME,
+        ABI_SEQUENT_REGISTER_THEOREM_INDEX, ABI_SEQUENT_REGISTER_THEOREM_NAME,
+        ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX,
+        ABI_SEQUENT_REGISTER_WEAKEN_LEFT_NAME,
+        ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX,
+        ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_NAME,
+        ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX,
+        ABI_SEQUENT_SPLIT_CONCLUSIONS_NAME, ABI_SEQUENT_SPLIT_PREMISSES_INDEX,
+        ABI_SEQUENT_SPLIT_PREMISSES_NAME, ABI_SHOULD_ABORT_INDEX,
+        ABI_SHOULD_ABORT_NAME, ABI_SPECULATE_BEGIN_INDEX,
+        ABI_SPECULATE_BEGIN_NAME, ABI_SPECULATE_END_INDEX,
+        ABI_SPECULATE_END_NAME, ABI_SUBSTITUTION_COMPOSE_INDEX,
+        ABI_SUBSTITUTION_COMPOSE_NAME, ABI_SUBSTITUTION_REGISTER_TERM_INDEX,
+        ABI_SUBSTITUTION_REGISTER_TERM_NAME,
+        ABI_SUBSTITUTION_REGISTER_TYPE_INDEX,
+        ABI_SUBSTITUTION_REGISTER_TYPE_NAME, ABI_TERM_DIFF_INDEX,
+        ABI_TERM_DIFF_NAME, ABI_TERM_FOLD_INDEX, ABI_TERM_FOLD_NAME,
+        ABI_TERM_FREE_VARIABLES_COUNT_INDEX,
+        ABI_TERM_FREE_VARIABLES_COUNT_NAME, ABI_TERM_FREE_VARIABLES_INDEX,
+        ABI_TERM_FREE_VARIABLES_NAME, ABI_TERM_FREE_VARIABLES_RANGE_INDEX,
+        ABI_TERM_FREE_VARIABLES_RANGE_NAME, ABI_TERM_IS_REGISTERED_INDEX,
+        ABI_TERM_IS_REGISTERED_NAME, ABI_TERM_REGISTER_APPLICATION_INDEX,
         ABI_TERM_REGISTER_APPLICATION_NAME,
+        ABI_TERM_REGISTER_COMPREHENSION_INDEX,
+        ABI_TERM_REGISTER_COMPREHENSION_NAME,
+        ABI_TERM_REGISTER_CONDITIONAL_INDEX,
+        ABI_TERM_REGISTER_CONDITIONAL_NAME,
         ABI_TERM_REGISTER_CONJUNCTION_INDEX,
         ABI_TERM_REGISTER_CONJUNCTION_NAME, ABI_TERM_REGISTER_CONSTANT_INDEX,
         ABI_TERM_REGISTER_CONSTANT_NAME, ABI_TERM_REGISTER_DISJUNCTION_INDEX,
         ABI_TERM_REGISTER_DISJUNCTION_NAME, ABI_TERM_REGISTER_EQUALITY_INDEX,
         ABI_TERM_REGISTER_EQUALITY_NAME, ABI_TERM_REGISTER_EXISTS_INDEX,
-        ABI_TERM_REGISTER_EXISTS_NAME, ABI_TERM_REGISTER_FORALL_INDEX,
+        ABI_TERM_REGISTER_EXISTS_NAME, ABI_TERM_REGISTER_FIRST_INDEX,
+        ABI_TERM_REGISTER_FIRST_NAME, ABI_TERM_REGISTER_FORALL_INDEX,
         ABI_TERM_REGISTER_FORALL_NAME, ABI_TERM_REGISTER_IMPLICATION_INDEX,
         ABI_TERM_REGISTER_IMPLICATION_NAME, ABI_TERM_REGISTER_LAMBDA_INDEX,
-        ABI_TERM_REGISTER_LAMBDA_NAME, ABI_TERM_REGISTER_NEGATION_INDEX,
-        ABI_TERM_REGISTER_NEGATION_NAME, ABI_TERM_REGISTER_VARIABLE_INDEX,
-        ABI_TERM_REGISTER_VARIABLE_NAME, ABI_TERM_SPLIT_APPLICATION_INDEX,
-        ABI_TERM_SPLIT_APPLICATION_NAME, ABI_TERM_SPLIT_CONJUNCTION_INDEX,
+        ABI_TERM_REGISTER_LAMBDA_NAME, ABI_TERM_REGISTER_LET_INDEX,
+        ABI_TERM_REGISTER_LET_NAME, ABI_TERM_REGISTER_MEMBERSHIP_INDEX,
+        ABI_TERM_REGISTER_MEMBERSHIP_NAME, ABI_TERM_REGISTER_NEGATION_INDEX,
+        ABI_TERM_REGISTER_NEGATION_NAME, ABI_TERM_REGISTER_PAIR_INDEX,
+        ABI_TERM_REGISTER_PAIR_NAME, ABI_TERM_REGISTER_SECOND_INDEX,
+        ABI_TERM_REGISTER_SECOND_NAME, ABI_TERM_REGISTER_VARIABLE_INDEX,
+        ABI_TERM_REGISTER_VARIABLE_NAME, ABI_TERM_REPLACE_AT_INDEX,
+        ABI_TERM_REPLACE_AT_NAME, ABI_TERM_SPLIT_APPLICATION_INDEX,
+        ABI_TERM_SPLIT_APPLICATION_NAME, ABI_TERM_SPLIT_COMPREHENSION_INDEX,
+        ABI_TERM_SPLIT_COMPREHENSION_NAME, ABI_TERM_SPLIT_CONDITIONAL_INDEX,
+        ABI_TERM_SPLIT_CONDITIONAL_NAME, ABI_TERM_SPLIT_CONJUNCTION_INDEX,
         ABI_TERM_SPLIT_CONJUNCTION_NAME, ABI_TERM_SPLIT_CONSTANT_INDEX,
         ABI_TERM_SPLIT_CONSTANT_NAME, ABI_TERM_SPLIT_DISJUNCTION_INDEX,
         ABI_TERM_SPLIT_DISJUNCTION_NAME, ABI_TERM_SPLIT_EQUALITY_INDEX,
         ABI_TERM_SPLIT_EQUALITY_NAME, ABI_TERM_SPLIT_EXISTS_INDEX,
         ABI_TERM_SPLIT_EXISTS_NAME, ABI_TERM_SPLIT_FORALL_INDEX,
         ABI_TERM_SPLIT_FORALL_NAME, ABI_TERM_SPLIT_IMPLICATION_INDEX,
-        ABI_TERM_SPLIT_IMPLICATION_NAME, ABI_TERM_SPLIT_LAMBDA_INDEX,
-        ABI_TERM_SPLIT_LAMBDA_NAME, ABI_TERM_SPLIT_NEGATION_INDEX,
-        ABI_TERM_SPLIT_NEGATION_NAME, ABI_TERM_SPLIT_VARIABLE_INDEX,
+        ABI_TERM_SPLIT_IMPLICATION_NAME, ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX,
+        ABI_TERM_SPLIT_LAMBDA_DISPLAY_NAME, ABI_TERM_SPLIT_LAMBDA_INDEX,
+        ABI_TERM_SPLIT_LAMBDA_NAME, ABI_TERM_SPLIT_LET_INDEX,
+        ABI_TERM_SPLIT_LET_NAME, ABI_TERM_SPLIT_MEMBERSHIP_INDEX,
+        ABI_TERM_SPLIT_MEMBERSHIP_NAME, ABI_TERM_SPLIT_NEGATION_INDEX,
+        ABI_TERM_SPLIT_NEGATION_NAME, ABI_TERM_SPLIT_PAIR_INDEX,
+        ABI_TERM_SPLIT_PAIR_NAME, ABI_TERM_SPLIT_VARIABLE_INDEX,
         ABI_TERM_SPLIT_VARIABLE_NAME, ABI_TERM_SUBSTITUTE_INDEX,
-        ABI_TERM_SUBSTITUTE_NAME, ABI_TERM_TEST_APPLICATION_INDEX,
-        ABI_TERM_TEST_APPLICATION_NAME, ABI_TERM_TEST_CONJUNCTION_INDEX,
+        ABI_TERM_SUBSTITUTE_NAME, ABI_TERM_SUBSTITUTE_WITH_INDEX,
+        ABI_TERM_SUBSTITUTE_WITH_NAME, ABI_TERM_SUBTERM_AT_INDEX,
+        ABI_TERM_SUBTERM_AT_NAME, ABI_TERM_TEST_APPLICATION_INDEX,
+        ABI_TERM_TEST_APPLICATION_NAME, ABI_TERM_TEST_COMPREHENSION_INDEX,
+        ABI_TERM_TEST_COMPREHENSION_NAME, ABI_TERM_TEST_CONDITIONAL_INDEX,
+        ABI_TERM_TEST_CONDITIONAL_NAME, ABI_TERM_TEST_CONJUNCTION_INDEX,
         ABI_TERM_TEST_CONJUNCTION_NAME, ABI_TERM_TEST_CONSTANT_INDEX,
         ABI_TERM_TEST_CONSTANT_NAME, ABI_TERM_TEST_DISJUNCTION_INDEX,
         ABI_TERM_TEST_DISJUNCTION_NAME, ABI_TERM_TEST_EQUALITY_INDEX,
@@ -81,18 +223,41 @@ use crate::{
         ABI_TERM_TEST_EXISTS_NAME, ABI_TERM_TEST_FORALL_INDEX,
         ABI_TERM_TEST_FORALL_NAME, ABI_TERM_TEST_IMPLICATION_INDEX,
         ABI_TERM_TEST_IMPLICATION_NAME, ABI_TERM_TEST_LAMBDA_INDEX,
-        ABI_TERM_TEST_LAMBDA_NAME, ABI_TERM_TEST_NEGATION_INDEX,
-        ABI_TERM_TEST_NEGATION_NAME, ABI_TERM_TEST_VARIABLE_INDEX,
+        ABI_TERM_TEST_LAMBDA_NAME, ABI_TERM_TEST_LET_INDEX,
+        ABI_TERM_TEST_LET_NAME, ABI_TERM_TEST_MEMBERSHIP_INDEX,
+        ABI_TERM_TEST_MEMBERSHIP_NAME, ABI_TERM_TEST_NEGATION_INDEX,
+        ABI_TERM_TEST_NEGATION_NAME, ABI_TERM_TEST_PAIR_INDEX,
+        ABI_TERM_TEST_PAIR_NAME, ABI_TERM_TEST_VARIABLE_INDEX,
         ABI_TERM_TEST_VARIABLE_NAME, ABI_TERM_TYPE_INFER_INDEX,
         ABI_TERM_TYPE_INFER_NAME, ABI_TERM_TYPE_IS_PROPOSITION_INDEX,
         ABI_TERM_TYPE_IS_PROPOSITION_NAME, ABI_TERM_TYPE_SUBSTITUTE_INDEX,
-        ABI_TERM_TYPE_SUBSTITUTE_NAME, ABI_TERM_TYPE_VARIABLES_INDEX,
-        ABI_TERM_TYPE_VARIABLES_NAME, ABI_THEOREM_IS_REGISTERED_INDEX,
-        ABI_THEOREM_IS_REGISTERED_NAME, ABI_THEOREM_REGISTER_APPLICATION_INDEX,
+        ABI_TERM_TYPE_SUBSTITUTE_NAME, ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX,
+        ABI_TERM_TYPE_SUBSTITUTE_WITH_NAME,
+        ABI_TERM_TYPE_VARIABLES_COUNT_INDEX,
+        ABI_TERM_TYPE_VARIABLES_COUNT_NAME, ABI_TERM_TYPE_VARIABLES_INDEX,
+        ABI_TERM_TYPE_VARIABLES_NAME, ABI_TERM_TYPE_VARIABLES_RANGE_INDEX,
+        ABI_TERM_TYPE_VARIABLES_RANGE_NAME, ABI_TERM_VIEW_MAP_INDEX,
+        ABI_TERM_VIEW_MAP_NAME, ABI_TERM_VIEW_RESERVE_INDEX,
+        ABI_TERM_VIEW_RESERVE_NAME, ABI_THEOREM_ALPHA_EQUAL_INDEX,
+        ABI_THEOREM_ALPHA_EQUAL_NAME, ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX,
+        ABI_THEOREM_EXISTS_WITH_CONCLUSION_NAME,
+        ABI_THEOREM_FIND_BY_CONCLUSION_INDEX,
+        ABI_THEOREM_FIND_BY_CONCLUSION_NAME, ABI_THEOREM_FIND_MATCHING_INDEX,
+        ABI_THEOREM_FIND_MATCHING_NAME, ABI_THEOREM_HYPOTHESES_COUNT_INDEX,
+        ABI_THEOREM_HYPOTHESES_COUNT_NAME, ABI_THEOREM_IS_REGISTERED_INDEX,
+        ABI_THEOREM_IS_REGISTERED_NAME, ABI_THEOREM_IS_TAINTED_INDEX,
+        ABI_THEOREM_IS_TAINTED_NAME, ABI_THEOREM_PROOF_INDEX,
+        ABI_THEOREM_PROOF_NAME, ABI_THEOREM_REGISTER_APPLICATION_INDEX,
         ABI_THEOREM_REGISTER_APPLICATION_NAME,
         ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+        ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX,
+        ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_NAME,
         ABI_THEOREM_REGISTER_ASSUMPTION_NAME, ABI_THEOREM_REGISTER_BETA_INDEX,
         ABI_THEOREM_REGISTER_BETA_NAME,
+        ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_NAME,
+        ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_NAME,
         ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
         ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_NAME,
         ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
@@ -105,13 +270,21 @@ use crate::{
         ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_NAME,
         ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
         ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_NAME,
+        ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_NAME,
         ABI_THEOREM_REGISTER_ETA_INDEX, ABI_THEOREM_REGISTER_ETA_NAME,
+        ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_NAME,
         ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
         ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_NAME,
         ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
         ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_NAME,
         ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
         ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_NAME,
+        ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX,
+        ABI_THEOREM_REGISTER_FIRST_PROJECTION_NAME,
+        ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_NAME,
         ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
         ABI_THEOREM_REGISTER_FORALL_ELIMINATION_NAME,
         ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
@@ -124,15 +297,33 @@ use crate::{
         ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME,
         ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
         ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_NAME,
+        ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_NAME,
         ABI_THEOREM_REGISTER_LAMBDA_INDEX, ABI_THEOREM_REGISTER_LAMBDA_NAME,
+        ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX,
+        ABI_THEOREM_REGISTER_LET_CONGRUENCE_NAME,
+        ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX,
+        ABI_THEOREM_REGISTER_LINEAR_ARITH_NAME,
+        ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_NAME,
         ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
         ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_NAME,
         ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
         ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_NAME,
         ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
         ABI_THEOREM_REGISTER_REFLEXIVITY_NAME,
+        ABI_THEOREM_REGISTER_REWRITE_AT_INDEX,
+        ABI_THEOREM_REGISTER_REWRITE_AT_NAME,
+        ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX,
+        ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_NAME,
+        ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX,
+        ABI_THEOREM_REGISTER_SECOND_PROJECTION_NAME,
+        ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_SUBSET_DEFINITION_NAME,
         ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
         ABI_THEOREM_REGISTER_SUBSTITUTE_NAME,
+        ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX,
+        ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_NAME,
         ABI_THEOREM_REGISTER_SYMMETRY_INDEX,
         ABI_THEOREM_REGISTER_SYMMETRY_NAME,
         ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
@@ -141,13 +332,34 @@ use crate::{
         ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_NAME,
         ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
         ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_NAME,
+        ABI_THEOREM_REGISTER_UNFOLD_INDEX, ABI_THEOREM_REGISTER_UNFOLD_NAME,
+        ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_UNION_DEFINITION_NAME,
+        ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX,
+        ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_NAME,
+        ABI_THEOREM_REGISTER_WEAKEN_INDEX, ABI_THEOREM_REGISTER_WEAKEN_NAME,
+        ABI_THEOREM_SIZE_INDEX, ABI_THEOREM_SIZE_NAME,
         ABI_THEOREM_SPLIT_CONCLUSION_INDEX, ABI_THEOREM_SPLIT_CONCLUSION_NAME,
         ABI_THEOREM_SPLIT_HYPOTHESES_INDEX, ABI_THEOREM_SPLIT_HYPOTHESES_NAME,
-        ABI_TYPE_FORMER_IS_REGISTERED_INDEX,
-        ABI_TYPE_FORMER_IS_REGISTERED_NAME, ABI_TYPE_FORMER_REGISTER_INDEX,
-        ABI_TYPE_FORMER_REGISTER_NAME, ABI_TYPE_FORMER_RESOLVE_INDEX,
-        ABI_TYPE_FORMER_RESOLVE_NAME, ABI_TYPE_IS_REGISTERED_INDEX,
-        ABI_TYPE_IS_REGISTERED_NAME, ABI_TYPE_REGISTER_COMBINATION_INDEX,
+        ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX,
+        ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_NAME,
+        ABI_THEOREM_STATEMENT_AS_TERM_INDEX,
+        ABI_THEOREM_STATEMENT_AS_TERM_NAME, ABI_THEOREM_STATEMENT_HASH_INDEX,
+        ABI_THEOREM_STATEMENT_HASH_NAME, ABI_THEOREM_TRUST_LEVEL_INDEX,
+        ABI_THEOREM_TRUST_LEVEL_NAME, ABI_TYPE_DEFINE_INDEX,
+        ABI_TYPE_DEFINE_NAME, ABI_TYPE_FORMER_IS_REGISTERED_INDEX,
+        ABI_TYPE_FORMER_IS_REGISTERED_NAME,
+        ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX,
+        ABI_TYPE_FORMER_LOOKUP_BY_NAME_NAME, ABI_TYPE_FORMER_METADATA_INDEX,
+        ABI_TYPE_FORMER_METADATA_NAME, ABI_TYPE_FORMER_REGISTER_INDEX,
+        ABI_TYPE_FORMER_REGISTER_NAME,
+        ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX,
+        ABI_TYPE_FORMER_REGISTER_WITH_METADATA_NAME,
+        ABI_TYPE_FORMER_RESOLVE_INDEX, ABI_TYPE_FORMER_RESOLVE_NAME,
+        ABI_TYPE_GENERALISE_INDEX, ABI_TYPE_GENERALISE_NAME,
+        ABI_TYPE_INSTANTIATE_FRESH_INDEX, ABI_TYPE_INSTANTIATE_FRESH_NAME,
+        ABI_TYPE_IS_REGISTERED_INDEX, ABI_TYPE_IS_REGISTERED_NAME,
+        ABI_TYPE_REGISTER_COMBINATION_INDEX,
         ABI_TYPE_REGISTER_COMBINATION_NAME, ABI_TYPE_REGISTER_FUNCTION_INDEX,
         ABI_TYPE_REGISTER_FUNCTION_NAME, ABI_TYPE_REGISTER_VARIABLE_INDEX,
         ABI_TYPE_REGISTER_VARIABLE_NAME, ABI_TYPE_SIZE_INDEX,
@@ -155,34 +367,176 @@ use crate::{
         ABI_TYPE_SPLIT_COMBINATION_NAME, ABI_TYPE_SPLIT_FUNCTION_INDEX,
         ABI_TYPE_SPLIT_FUNCTION_NAME, ABI_TYPE_SPLIT_VARIABLE_INDEX,
         ABI_TYPE_SPLIT_VARIABLE_NAME, ABI_TYPE_SUBSTITUTE_INDEX,
-        ABI_TYPE_SUBSTITUTE_NAME, ABI_TYPE_TEST_COMBINATION_INDEX,
+        ABI_TYPE_SUBSTITUTE_NAME, ABI_TYPE_SUBSTITUTE_WITH_INDEX,
+        ABI_TYPE_SUBSTITUTE_WITH_NAME, ABI_TYPE_TEST_COMBINATION_INDEX,
         ABI_TYPE_TEST_COMBINATION_NAME, ABI_TYPE_TEST_FUNCTION_INDEX,
         ABI_TYPE_TEST_FUNCTION_NAME, ABI_TYPE_TEST_VARIABLE_INDEX,
-        ABI_TYPE_TEST_VARIABLE_NAME, ABI_TYPE_VARIABLES_INDEX,
-        ABI_TYPE_VARIABLES_NAME,
+        ABI_TYPE_TEST_VARIABLE_NAME, ABI_TYPE_VARIABLES_COUNT_INDEX,
+        ABI_TYPE_VARIABLES_COUNT_NAME, ABI_TYPE_VARIABLES_INDEX,
+        ABI_TYPE_VARIABLES_NAME, ABI_TYPE_VARIABLES_RANGE_INDEX,
+        ABI_TYPE_VARIABLES_RANGE_NAME,
+    },
+    system_interface_types::{
+        narrow, page, semantic_types, OutOfRange, ENUMERATION_COMPLETE,
+        FOLD_TAG_APPLICATION, FOLD_TAG_CONSTANT, FOLD_TAG_LAMBDA,
+        FOLD_TAG_VARIABLE, PROGRESS_OPERATION_PROOF_CHECK,
+        PROGRESS_OPERATION_PROOF_IMPORT, PROGRESS_OPERATION_STATE_IMPORT,
     },
-    system_interface_types::semantic_types,
+    trace::{TraceArgument, TraceEvent, TraceResult, TraceValue},
     type_checking,
 };
 
+////////////////////////////////////////////////////////////////////////////////
+// Host-call arguments and results.
+////////////////////////////////////////////////////////////////////////////////
+
+/// The parts of a conditional: its condition, and its two branches.
+pub type Conditional =
+    (Handle<tags::Term>, Handle<tags::Term>, Handle<tags::Term>);
+
+/// The objects registered by a quotient type: the equivalence relation, the
+/// constant mapping elements to their classes, and the theorems
+/// characterising the classes and the induction rule of the quotient.
+pub type Quotient = (
+    Handle<tags::Term>,
+    Handle<tags::Term>,
+    Handle<tags::Theorem>,
+    Handle<tags::Theorem>,
+);
+
+/// The objects registered by a type definition: the type-former of the new
+/// type, the abstraction and representation constants, and the two theorems
+/// characterising them.
+pub type TypeDefinition = (
+    Handle<tags::TypeFormer>,
+    Handle<tags::Constant>,
+    Handle<tags::Constant>,
+    Handle<tags::Theorem>,
+    Handle<tags::Theorem>,
+);
+
+/// A term substitution, as read from guest memory: the name and type of each
+/// variable in its domain, paired with the term replacing it.
+pub type TermSubstitution =
+    Vec<((Name, Handle<tags::Type>), Handle<tags::Term>)>;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Errors and traps.
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The most recent host call made by a guest that failed, with an error code
+/// other than `Success`, as reported by the `ErrorDetail.Last` host call.
+/// Unlike the kernel's error detail, which is discarded by the next host call,
+/// the most recent failure is retained until another host call fails, and
+/// belongs to the runtime state of the guest that made the call, so that the
+/// failures of several guests, each executing against a runtime state of its
+/// own, never interfere.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LastError {
+    /// The host-call number of the call that failed.
+    pub call: usize,
+    /// The error code with which the call failed.
+    pub code: KernelErrorCode,
+    /// The kernel's explanation of the failure, if it recorded one.
+    pub detail: Option<String>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // The Wasmi runtime state.
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The memory number of the default memory of the WASM guest program.
+pub const DEFAULT_MEMORY: usize = 0;
+
+/// The number of canary bytes written immediately before, and immediately
+/// after, each result of a host call, with the `canaries` feature.
+#[cfg(feature = "canaries")]
+const CANARY_WIDTH: u64 = 8;
+/// The value of each canary byte.  Note that a stray write of this value goes
+/// unnoticed, so it is chosen to be an unlikely one.
+#[cfg(feature = "canaries")]
+const CANARY_BYTE: u8 = 0xa5;
+
 /// The Wasmi runtime state, which is a thin wrapper around the kernel's own
-/// runtime state, adding a reference to the guest WASM program's memory module,
-/// to enable host functions to read-from and write-to the memory module
-/// directly.
+/// runtime state, adding references to the guest WASM program's memory
+/// modules, to enable host functions to read-from and write-to the memory
+/// modules directly.
+///
+/// Memories are numbered in the order they are registered, and memory `0` is
+/// the default memory.  A host call reads and writes the default memory unless
+/// it has been bound to another memory, with `bind_call_memory`, before the
+/// guest's imports are resolved.
 #[derive(Debug)]
 pub struct WasmiRuntimeState {
     /// The kernel's runtime state.
     kernel: RefCell<KernelRuntimeState>,
-    /// The memory instance of the executing WASM guest program.
-    memory: Option<RefCell<MemoryRef>>,
+    /// The memory instances of the executing WASM guest program, indexed by
+    /// memory number.
+    memories: Vec<RefCell<MemoryRef>>,
+    /// Host calls, by name, that read and write a memory other than the
+    /// default memory.
+    call_memories: HashMap<String, usize>,
+    /// Host calls, by host-call number, that read and write a memory other than
+    /// the default memory.  Filled in from `call_memories` as the guest's
+    /// imports are resolved.
+    resolved_call_memories: RefCell<HashMap<usize, usize>>,
+    /// The memory read and written by the host call currently executing.
+    active_memory: Cell<usize>,
+    /// Whether the guest may import experimental host calls.
+    experimental_enabled: bool,
+    /// The function table of the executing WASM guest program, through which
+    /// guest callbacks are resolved.
+    table: Option<TableRef>,
+    /// The host function table, through which guests may call host calls
+    /// indirectly, if it has been enabled.
+    host_table: Option<TableRef>,
+    /// The names under which the guest imported each host call, and the types
+    /// of the values that they return, by host-call number.
+    call_imports: RefCell<HashMap<usize, (String, Option<ValueType>)>>,
+    /// The host calls made by the guest, in the order in which they returned,
+    /// if tracing is enabled.
+    trace: Option<Vec<TraceEvent>>,
+    /// The most recent host calls made by the guest, oldest first, retained
+    /// so that a trap can be reported along with the calls leading up to it.
+    history: VecDeque<TraceEvent>,
+    /// The number of host calls retained in `history`, or `0` if none are.
+    history_capacity: usize,
+    /// Set by the host, possibly from another thread, to ask the guest to
+    /// stop.
+    abort: Arc<AtomicBool>,
+    /// Whether the guest imported `__should_abort`, and so polls for requests
+    /// to stop itself.
+    cooperative: Cell<bool>,
+    /// The region of the guest's memory reserved for term views, as the memory
+    /// number, base address, and size in bytes of the region, if the guest has
+    /// reserved one.
+    term_view_region: Cell<Option<(usize, semantic_types::Pointer, u64)>>,
+    /// The facts about its own machine state that the guest may inspect
+    /// through the `Introspect` host calls.
+    introspection: HashSet<Introspection>,
+    /// The number of times that the guest has made each host call, by
+    /// host-call number.
+    call_counts: HashMap<usize, u64>,
+    /// The guest callback notified of the progress of long-running kernel
+    /// operations, and the context passed to it, if the guest has registered
+    /// one.  Shared with the kernel's progress observer.
+    progress_callback: Rc<RefCell<Option<(FuncRef, semantic_types::Context)>>>,
+    /// The trap raised by the guest's progress callback, if any, which is
+    /// raised in turn once the host call during which it ran returns.
+    progress_trap: Rc<RefCell<Option<Trap>>>,
+    /// Whether the kernel reports progress to `progress_callback`.
+    progress_observed: bool,
+    /// The inference rules that the guest may apply, or `None` if the guest
+    /// may apply every rule.
+    rule_policy: Option<RulePolicy>,
+    /// The costs of the host calls made by the guest, if they are being
+    /// recorded.
+    cost: RefCell<Option<CostRecorder>>,
+    /// Whether the guest may reset the kernel, through the privileged
+    /// `Kernel.Reset` host call.
+    kernel_reset_enabled: bool,
+    /// The most recent host call made by the guest that failed, if any.
+    last_error: Option<LastError>,
 }
 
 impl Default for WasmiRuntimeState {
@@ -190,7 +544,29 @@ impl Default for WasmiRuntimeState {
     fn default() -> Self {
         Self {
             kernel: RefCell::new(Default::default()),
-            memory: None,
+            memories: Vec::new(),
+            call_memories: HashMap::new(),
+            resolved_call_memories: RefCell::new(HashMap::new()),
+            active_memory: Cell::new(DEFAULT_MEMORY),
+            experimental_enabled: false,
+            table: None,
+            host_table: None,
+            call_imports: RefCell::new(HashMap::new()),
+            trace: None,
+            history: VecDeque::new(),
+            history_capacity: 0,
+            abort: Arc::new(AtomicBool::new(false)),
+            cooperative: Cell::new(false),
+            term_view_region: Cell::new(None),
+            introspection: HashSet::new(),
+            call_counts: HashMap::new(),
+            progress_callback: Rc::new(RefCell::new(None)),
+            progress_trap: Rc::new(RefCell::new(None)),
+            progress_observed: false,
+            rule_policy: None,
+            cost: RefCell::new(None),
+            kernel_reset_enabled: false,
+            last_error: None,
         }
     }
 }
@@ -204,23 +580,316 @@ impl WasmiRuntimeState {
         Default::default()
     }
 
+    /// Constructs a new instance of a `WasmiRuntimeState`, as `new`, whose
+    /// kernel issues handles drawn pseudo-randomly, by a generator seeded with
+    /// `seed`, so that the guest cannot guess the handles of kernel objects
+    /// that it was never given.  Traces recorded from such a kernel only
+    /// replay faithfully against a kernel seeded alike.
+    #[inline]
+    pub fn with_randomised_handles(seed: u64) -> Self {
+        Self {
+            kernel: RefCell::new(KernelRuntimeState::with_randomised_handles(
+                seed,
+            )),
+            ..Default::default()
+        }
+    }
+
+    /// Constructs a new instance of a `WasmiRuntimeState`, as `new`, whose
+    /// kernel is instantiated from the frozen base theory `base`, sharing its
+    /// tables until the guest first writes to them.  Many instances, e.g. one
+    /// for each guest of a pool executing in parallel, may be instantiated
+    /// from the same base, each registering kernel objects privately.
+    #[inline]
+    pub fn from_frozen(base: &FrozenRuntimeState) -> Self {
+        Self {
+            kernel: RefCell::new(base.instantiate()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether the guest may import experimental host calls, which are
+    /// refused by default.  Must be called before the guest's imports are
+    /// resolved to have effect.
+    #[inline]
+    pub fn enable_experimental(&mut self, enabled: bool) -> &mut Self {
+        self.experimental_enabled = enabled;
+        self
+    }
+
+    /// Sets whether the kernel records a proof term for every theorem that the
+    /// guest registers, which it does not by default.  Proof terms may then be
+    /// inspected, and checked, through the experimental proof host calls.
+    #[inline]
+    pub fn enable_proof_recording(&mut self, enabled: bool) -> &mut Self {
+        self.kernel.borrow_mut().set_proof_recording(enabled);
+        self
+    }
+
+    /// Sets whether the kernel logs a warning whenever the guest registers a
+    /// theorem whose statement hash duplicates that of a theorem registered
+    /// earlier, which it does not by default.
+    #[inline]
+    pub fn enable_duplicate_detection(&mut self, enabled: bool) -> &mut Self {
+        self.kernel.borrow_mut().set_duplicate_detection(enabled);
+        self
+    }
+
+    /// Sets whether the kernel defers type-checking terms that the guest
+    /// registers until they are used in a theorem, which it does not by
+    /// default.
+    #[inline]
+    pub fn enable_lazy_validation(&mut self, enabled: bool) -> &mut Self {
+        self.kernel.borrow_mut().set_lazy_validation(enabled);
+        self
+    }
+
+    /// Sets whether every host call made by the guest is recorded in a trace,
+    /// which it is not by default.  Enabling tracing discards any trace
+    /// recorded so far.
+    #[inline]
+    pub fn enable_tracing(&mut self, enabled: bool) -> &mut Self {
+        self.trace = if enabled { Some(Vec::new()) } else { None };
+        self
+    }
+
+    /// Returns the host calls made by the guest since tracing was enabled, in
+    /// the order in which they returned.  Empty if tracing is not enabled.
+    #[inline]
+    pub fn trace(&self) -> &[TraceEvent] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Sets the number of most recent host calls made by the guest that are
+    /// retained, with their arguments, for reporting alongside a trap.  None
+    /// are retained by default, or if `capacity` is `0`.  Changing the
+    /// capacity discards any host calls retained so far.
+    #[inline]
+    pub fn enable_history(&mut self, capacity: usize) -> &mut Self {
+        self.history = VecDeque::with_capacity(capacity);
+        self.history_capacity = capacity;
+        self
+    }
+
+    /// Returns the most recent host calls made by the guest, at most as many
+    /// as the capacity set with `enable_history`, oldest first.
+    #[inline]
+    pub fn history(&self) -> &VecDeque<TraceEvent> {
+        &self.history
+    }
+
+    /// Returns the most recent host call made by the guest that failed, if
+    /// any has.
+    #[inline]
+    pub fn last_error(&self) -> Option<&LastError> {
+        self.last_error.as_ref()
+    }
+
+    /// Sets whether the guest may inspect `fact` about its own machine state
+    /// through the experimental `Introspect` host calls, which it may not by
+    /// default.  See the `introspection` module for the facts available.
+    #[inline]
+    pub fn enable_introspection(
+        &mut self,
+        fact: Introspection,
+        enabled: bool,
+    ) -> &mut Self {
+        if enabled {
+            self.introspection.insert(fact);
+        } else {
+            self.introspection.remove(&fact);
+        }
+        self
+    }
+
+    /// Sets whether the costs of the host calls made by the guest are
+    /// recorded, which they are not by default.  Enabling the recording
+    /// discards any costs recorded so far.  See the `cost` module for the
+    /// costs recorded.
+    #[inline]
+    pub fn enable_cost_model(&mut self, enabled: bool) -> &mut Self {
+        self.cost = RefCell::new(if enabled {
+            Some(CostRecorder::default())
+        } else {
+            None
+        });
+        self
+    }
+
+    /// Returns a report of the costs of the host calls made by the guest since
+    /// recording was enabled, or `None` if it is not.
+    pub fn cost_report(&self) -> Option<CostReport> {
+        let call_imports = self.call_imports.borrow();
+
+        self.cost.borrow().as_ref().map(|cost| {
+            cost.report(|index| {
+                call_imports
+                    .get(&index)
+                    .map(|(name, _return_type)| name.clone())
+                    .unwrap_or_else(|| index.to_string())
+            })
+        })
+    }
+
+    /// Sets whether the guest may reset the kernel, discarding every kernel
+    /// object registered so far, through the privileged `Kernel.Reset` host
+    /// call, which it may not by default.  Without this, the host call may
+    /// still be imported, but fails with `ErrorCode::PermissionDenied`.
+    #[inline]
+    pub fn enable_kernel_reset(&mut self, enabled: bool) -> &mut Self {
+        self.kernel_reset_enabled = enabled;
+        self
+    }
+
+    /// Restricts the inference rules that the guest may apply to those
+    /// permitted by `policy`, or lifts any restriction if `policy` is `None`,
+    /// as it is by default.  See the `rule_policy` module for details.
+    #[inline]
+    pub fn set_rule_policy(&mut self, policy: Option<RulePolicy>) -> &mut Self {
+        self.rule_policy = policy;
+        self
+    }
+
+    /// Returns the flag through which the host asks the guest to stop.  Once
+    /// the flag is set, from any thread, the guest's next host call traps with
+    /// `RuntimeTrap::Aborted`, unless the guest imported `__should_abort`.  A
+    /// guest that did so is trusted to poll it, and to wind down cooperatively
+    /// once it returns `true`, so its host calls are served as usual.
+    #[inline]
+    pub fn abort_flag(&self) -> Arc<AtomicBool> {
+        self.abort.clone()
+    }
+
+    /// Replaces the flag through which the host asks the guest to stop with
+    /// `flag`, so that several guests, each executing against a runtime state
+    /// of its own, may be stopped together.
+    #[inline]
+    pub fn share_abort_flag(&mut self, flag: Arc<AtomicBool>) -> &mut Self {
+        self.abort = flag;
+        self
+    }
+
+    /// Returns `true` iff the host has asked the guest to stop.
+    #[inline]
+    pub fn abort_requested(&self) -> bool {
+        self.abort.load(Ordering::SeqCst)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Challenge-related functionality.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Runs `action` with mutable access to the kernel's runtime state, for
+    /// example to construct the propositions used as challenges.  Note that the
+    /// host is trusted, and so may register kernel objects freely.
+    #[inline]
+    pub fn with_kernel<F, R>(&self, action: F) -> R
+    where
+        F: FnOnce(&mut KernelRuntimeState) -> R,
+    {
+        action(&mut self.kernel.borrow_mut())
+    }
+
+    /// Guards the service `service` with the challenge `proposition`, which
+    /// the guest must prove to mint capabilities for the service, via the
+    /// `Challenge.Discharge` host call.
+    pub fn register_challenge<T>(
+        &mut self,
+        service: Service,
+        proposition: T,
+    ) -> Result<&mut Self, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .challenge_register(service, proposition)?;
+
+        Ok(self)
+    }
+
+    /// Signals that the machine state, from which challenges are constructed,
+    /// has changed, expiring capabilities that are only valid until it does.
+    #[inline]
+    pub fn signal_state_change(&mut self) -> &mut Self {
+        self.kernel.borrow_mut().signal_state_change();
+        self
+    }
+
+    /// Checks that the guest presented the capability `capability` to the
+    /// guarded service `service`, for host calls implementing the service.
+    /// Records a use of the capability if so.
+    #[inline]
+    pub fn check_capability<T>(
+        &self,
+        capability: T,
+        service: Service,
+    ) -> Result<(), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Capability>>,
+    {
+        self.capability_present(capability, service)
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Memory-related functionality.
     ////////////////////////////////////////////////////////////////////////////
 
-    /// Returns `true` iff the WASM guest's memory module has been registered.
+    /// Returns `true` iff the WASM guest's default memory module has been
+    /// registered.
     #[inline]
     pub fn is_memory_registered(&self) -> bool {
-        self.memory.is_some()
+        !self.memories.is_empty()
     }
 
-    /// Registers the WASM guest's memory module with the runtime state.
+    /// Registers the WASM guest's default memory module with the runtime state,
+    /// replacing any default memory module registered previously.
     #[inline]
     pub fn set_memory(&mut self, instance: MemoryRef) -> &mut Self {
-        self.memory = Some(RefCell::new(instance));
+        if self.memories.is_empty() {
+            self.memories.push(RefCell::new(instance));
+        } else {
+            self.memories[DEFAULT_MEMORY] = RefCell::new(instance);
+        }
+
+        self
+    }
+
+    /// Registers an additional memory module of the WASM guest with the
+    /// runtime state, returning its memory number.  Note that the first memory
+    /// module registered becomes the default memory.
+    #[inline]
+    pub fn register_memory(&mut self, instance: MemoryRef) -> usize {
+        self.memories.push(RefCell::new(instance));
+        self.memories.len() - 1
+    }
+
+    /// Binds the host call named `call` to the memory with number `memory`, so
+    /// that the call reads and writes that memory rather than the default one.
+    /// Must be called before the guest's imports are resolved to have effect.
+    #[inline]
+    pub fn bind_call_memory<T>(&mut self, call: T, memory: usize) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.call_memories.insert(call.into(), memory);
         self
     }
 
+    /// Returns the memory module read and written by the host call currently
+    /// executing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(RuntimeTrap::MemoryNotRegistered)` if that memory module
+    /// has not been registered with the runtime state.
+    #[inline]
+    fn memory(&self) -> Result<&RefCell<MemoryRef>, RuntimeTrap> {
+        self.memories
+            .get(self.active_memory.get())
+            .ok_or(RuntimeTrap::MemoryNotRegistered)
+    }
+
     /// Writes a buffer of byte values, `bytes`, to the WASM guest program's
     /// memory starting at the provided `address`.
     ///
@@ -243,16 +912,17 @@ impl WasmiRuntimeState {
 
         info!("Writing {:?} bytes at address {:#x}.", bytes, address);
 
-        let memory = match &self.memory {
-            None => return Err(RuntimeTrap::MemoryNotRegistered),
-            Some(memory) => memory,
-        };
+        let memory = self.memory()?;
 
         memory
             .borrow_mut()
             .set(address.into(), bytes)
             .map_err(|_e| RuntimeTrap::MemoryWriteFailed)?;
 
+        if let Some(cost) = self.cost.borrow_mut().as_mut() {
+            cost.written(bytes.len());
+        }
+
         Ok(())
     }
 
@@ -292,29 +962,25 @@ impl WasmiRuntimeState {
     ///
     /// Returns `Err(RuntimeTrap::MemoryWriteFailed)` if the write to memory at
     /// address, `address`, failed.
-    fn write_u64s<T, U>(
-        &self,
-        address: T,
-        values: Vec<U>,
-    ) -> Result<(), RuntimeTrap>
+    fn write_u64s<T, U>(&self, address: T, values: U) -> Result<(), RuntimeTrap>
     where
         T: Into<semantic_types::Pointer>,
-        U: Into<u64> + Clone,
+        U: IntoIterator,
+        U::Item: Into<u64>,
     {
-        let mut address = address.into();
+        let address = address.into();
 
-        info!(
-            "Writing {} u64 values starting at address {:#x}.",
-            values.len(),
-            address
-        );
+        info!("Writing u64 values starting at address {:#x}.", address);
 
-        for v in values.iter().cloned() {
-            self.write_u64(address, v)?;
-            address += 8;
-        }
+        /* NB: the values are encoded in host memory first, so that they are
+         * written to the guest's memory in one go, rather than one at a time.
+         */
+        let buffer: Vec<u8> = values
+            .into_iter()
+            .flat_map(|v| v.into().to_le_bytes())
+            .collect();
 
-        Ok(())
+        self.write_bytes(address, &buffer)
     }
 
     /// Writes a `bool` value to the WASM guest's memory module at a specified
@@ -385,27 +1051,25 @@ impl WasmiRuntimeState {
     fn write_handles<T, U, V>(
         &self,
         address: T,
-        handles: Vec<U>,
+        handles: U,
     ) -> Result<(), RuntimeTrap>
     where
         T: Into<semantic_types::Pointer>,
-        U: Into<Handle<V>>,
+        U: IntoIterator,
+        U::Item: Borrow<Handle<V>>,
         V: tags::IsTag + Debug,
     {
-        let mut address = address.into();
-
-        info!(
-            "Writing {} handles starting at address {:#x}.",
-            handles.len(),
-            address
-        );
+        let address = address.into();
 
-        for handle in handles {
-            self.write_handle(address, handle)?;
-            address += 8;
-        }
+        info!("Writing handles starting at address {:#x}.", address);
 
-        Ok(())
+        self.write_u64s(
+            address,
+            handles.into_iter().map(|handle| {
+                let handle: &Handle<V> = handle.borrow();
+                **handle as u64
+            }),
+        )
     }
 
     /// Reads a fixed `byte_count` of bytes from the WASM guest's memory module
@@ -432,16 +1096,17 @@ impl WasmiRuntimeState {
 
         info!("Reading {} bytes at address {:#x}.", byte_count, address);
 
-        let memory = match &self.memory {
-            None => return Err(RuntimeTrap::MemoryNotRegistered),
-            Some(memory) => memory,
-        };
+        let memory = self.memory()?;
 
         let bytes = memory
             .borrow()
             .get(address.into(), byte_count.into())
             .map_err(|_e| RuntimeTrap::MemoryReadFailed)?;
 
+        if let Some(cost) = self.cost.borrow_mut().as_mut() {
+            cost.read(byte_count);
+        }
+
         Ok(bytes)
     }
 
@@ -487,23 +1152,27 @@ impl WasmiRuntimeState {
         T: Into<u32>,
         U: Into<usize>,
     {
-        let mut accumulator = Vec::new();
-        let mut address = address.into();
+        let address = address.into();
         let count = count.into();
 
         info!("Reading {} u64 values at address {:#x}.", count, address);
 
-        for _c in 0..count {
-            let handle = self.read_u64(address)?;
-            accumulator.push(handle);
-            address += 8;
-        }
-
-        Ok(accumulator)
+        /* NB: the values are read from the guest's memory in one go, and only
+         * then decoded, in host memory, rather than read one at a time.
+         */
+        let byte_count = count
+            .checked_mul(size_of::<u64>())
+            .ok_or(RuntimeTrap::MemoryReadFailed)?;
+        let buffer = self.read_bytes(address, byte_count)?;
+
+        Ok(buffer
+            .chunks_exact(size_of::<u64>())
+            .map(LittleEndian::read_u64)
+            .collect())
     }
 
-    /// Reads a `Handle` from the WASM guest's memory module at a specified
-    /// `address`.
+    /// Reads multiple `Handle` values, as described by `count`, from the WASM
+    /// guest's memory module at a specified `address`.
     ///
     /// # Errors
     ///
@@ -512,20 +1181,33 @@ impl WasmiRuntimeState {
     ///
     /// Returns `Err(RuntimeTrap::MemoryReadFailed)` if the read from memory at
     /// address, `address`, failed.
-    #[inline]
-    fn read_handle<T, U>(&self, address: U) -> Result<Handle<T>, RuntimeTrap>
+    ///
+    /// Returns `Err(OutOfRange)` if any handle read cannot be represented on
+    /// the host.
+    fn read_handles<T, U, V>(
+        &self,
+        address: U,
+        count: V,
+    ) -> Result<Vec<Handle<T>>, Trap>
     where
         T: tags::IsTag,
         U: Into<u32>,
+        V: Into<usize>,
     {
         let address = address.into();
+        let count = count.into();
 
-        info!("Reading handle at address {:#x}.", address);
+        info!("Reading {} handles at address {:#x}.", count, address);
+
+        let handles = self.read_u64s(address, count)?;
 
-        Ok(Handle::from(self.read_u64(address)? as usize))
+        handles
+            .into_iter()
+            .map(|handle| Ok(Handle::from(narrow(handle)?)))
+            .collect()
     }
 
-    /// Reads multiple `Handle` values, as described by `count`, from the WASM
+    /// Reads a position of a subterm, of `count` child indices, from the WASM
     /// guest's memory module at a specified `address`.
     ///
     /// # Errors
@@ -535,105 +1217,655 @@ impl WasmiRuntimeState {
     ///
     /// Returns `Err(RuntimeTrap::MemoryReadFailed)` if the read from memory at
     /// address, `address`, failed.
-    fn read_handles<T, U, V>(
+    ///
+    /// Returns `Err(OutOfRange)` if any index read cannot be represented on
+    /// the host.
+    fn read_position<T, U>(
         &self,
-        address: U,
-        count: V,
-    ) -> Result<Vec<Handle<T>>, RuntimeTrap>
+        address: T,
+        count: U,
+    ) -> Result<Vec<usize>, Trap>
     where
-        T: tags::IsTag,
-        U: Into<u32>,
-        V: Into<usize>,
+        T: Into<u32>,
+        U: Into<usize>,
     {
-        let mut accumulator = Vec::new();
-        let mut address = address.into();
-        let count = count.into();
-
-        info!("Reading {} handles at address {:#x}.", count, address);
-
-        for _c in 0..count {
-            let handle = self.read_handle(address)?;
-            accumulator.push(handle);
-            address += 8;
-        }
+        let indices = self.read_u64s(address, count)?;
 
-        Ok(accumulator)
+        Ok(indices.into_iter().map(narrow).collect::<Result<_, _>>()?)
     }
 
     ////////////////////////////////////////////////////////////////////////////
-    // Kernel-related functionality.
+    // Argument validation.
     ////////////////////////////////////////////////////////////////////////////
 
-    /// Lifting of the `type_former_resolve` function.
+    /// Checks that the `size` bytes starting at `address` lie within memory of
+    /// `memory_size` bytes.
     #[inline]
-    fn type_former_resolve<T>(&self, handle: T) -> Option<usize>
-    where
-        T: Borrow<Handle<tags::TypeFormer>>,
-    {
-        self.kernel.borrow().type_former_resolve(handle).cloned()
+    fn fits_in_memory(
+        address: u64,
+        size: Option<u64>,
+        memory_size: u64,
+    ) -> bool {
+        size.and_then(|size| address.checked_add(size))
+            .is_some_and(|end| end <= memory_size)
     }
 
-    /// Lifting of the `type_former_is_registered` function.
-    #[inline]
-    fn type_former_is_registered<T>(&self, handle: T) -> bool
-    where
-        T: Borrow<Handle<tags::TypeFormer>>,
-    {
-        self.kernel.borrow().type_former_is_registered(handle)
-    }
+    /// Validates the pointer arguments, `args`, of the system call with index
+    /// `index` against the memory read and written by the system call,
+    /// before the system call touches the kernel.  Validation is skipped if
+    /// that memory has not been registered, as the system call then traps.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(KernelErrorCode::InvalidPointer)` if a pointer argument is
+    /// null, or if the result written through it does not fit in memory.
+    ///
+    /// Returns `Err(KernelErrorCode::InvalidLength)` if a buffer read or
+    /// written by the system call does not fit in memory, or if an array that
+    /// must be non-empty is empty.
+    fn validate_arguments(
+        &self,
+        index: usize,
+        args: &RuntimeArgs,
+    ) -> Result<(), KernelErrorCode> {
+        let arguments = pointer_arguments(index);
 
-    /// Lifting of the `type_former_register` function.
-    #[inline]
-    fn type_former_register<T>(&self, arity: T) -> Handle<tags::TypeFormer>
-    where
-        T: Into<usize> + Clone,
-    {
-        self.kernel.borrow_mut().type_former_register(arity)
-    }
+        if arguments.is_empty() {
+            return Ok(());
+        }
 
-    /// Lifting of the `type_register_variable` function.
-    #[inline]
-    fn type_register_variable<T>(&self, name: T) -> Handle<tags::Type>
-    where
-        T: Into<Name> + Clone,
-    {
-        self.kernel.borrow_mut().type_register_variable(name)
-    }
+        let memory_size = match self.memory() {
+            Ok(memory) => {
+                let Bytes(size) = memory.borrow().current_size().into();
+                size as u64
+            }
+            Err(_e) => return Ok(()),
+        };
 
-    /// Lifting of the `type_register_combination` function.
-    #[inline]
-    fn type_register_combination<T, U>(
-        &self,
-        type_former: T,
-        arguments: Vec<U>,
-    ) -> Result<Handle<tags::Type>, KernelErrorCode>
-    where
-        T: Into<Handle<tags::TypeFormer>> + Clone,
-        U: Into<Handle<tags::Type>> + Clone,
-    {
-        self.kernel.borrow_mut().type_register_combination(
-            type_former.into(),
-            arguments.iter().cloned().map(|a| a.into()).collect(),
-        )
-    }
+        for argument in arguments {
+            match *argument {
+                PointerArgument::Output { pointer, size } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+
+                    if address == 0
+                        || !Self::fits_in_memory(
+                            address as u64,
+                            Some(size),
+                            memory_size,
+                        )
+                    {
+                        error!("Invalid result pointer {:#x} passed as argument {} to system call {}.", address, pointer, index);
+
+                        return Err(KernelErrorCode::InvalidPointer);
+                    }
+                }
+                PointerArgument::Input {
+                    pointer,
+                    length,
+                    element_size,
+                    non_empty,
+                } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+                    let count = args.nth::<semantic_types::Size>(length);
 
-    /// Lifting of the `type_register_function` function.
-    #[inline]
-    fn type_register_function<T>(
-        &self,
-        domain: T,
-        range: T,
-    ) -> Result<Handle<tags::Type>, KernelErrorCode>
-    where
-        T: Into<Handle<tags::Type>>,
-    {
-        self.kernel
-            .borrow_mut()
-            .type_register_function(domain.into(), range.into())
-    }
+                    if non_empty && count == 0 {
+                        error!("Empty array passed as argument {} to system call {}.", pointer, index);
 
-    /// Lifting of the `type_is_registered` function.
-    #[inline]
+                        return Err(KernelErrorCode::InvalidLength);
+                    }
+
+                    if address == 0 && count != 0 {
+                        error!("Null array pointer passed as argument {} to system call {}.", pointer, index);
+
+                        return Err(KernelErrorCode::InvalidPointer);
+                    }
+
+                    if !Self::fits_in_memory(
+                        address as u64,
+                        count.checked_mul(element_size),
+                        memory_size,
+                    ) {
+                        error!("Array of length {} at {:#x}, passed as argument {} to system call {}, overflows memory.", count, address, pointer, index);
+
+                        return Err(KernelErrorCode::InvalidLength);
+                    }
+                }
+                PointerArgument::Buffer {
+                    pointer,
+                    length_pointer,
+                    element_size,
+                } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+                    let length_address =
+                        args.nth::<semantic_types::Pointer>(length_pointer);
+
+                    if length_address == 0
+                        || !Self::fits_in_memory(
+                            length_address as u64,
+                            Some(size_of::<u64>() as u64),
+                            memory_size,
+                        )
+                    {
+                        error!("Invalid length pointer {:#x} passed as argument {} to system call {}.", length_address, length_pointer, index);
+
+                        return Err(KernelErrorCode::InvalidPointer);
+                    }
+
+                    let capacity = self
+                        .read_u64(length_address)
+                        .map_err(|_e| KernelErrorCode::InvalidPointer)?;
+
+                    if address == 0 && capacity != 0 {
+                        error!("Null buffer pointer passed as argument {} to system call {}.", pointer, index);
+
+                        return Err(KernelErrorCode::InvalidPointer);
+                    }
+
+                    if !Self::fits_in_memory(
+                        address as u64,
+                        capacity.checked_mul(element_size),
+                        memory_size,
+                    ) {
+                        error!("Buffer of capacity {} at {:#x}, passed as argument {} to system call {}, overflows memory.", capacity, address, pointer, index);
+
+                        return Err(KernelErrorCode::InvalidLength);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Tracing.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the regions of memory read or written by the system call with
+    /// index `index` through its pointer arguments, `args`, as triples of the
+    /// argument's position, the region's address, and its size in bytes.  The
+    /// arguments must already have been validated.
+    fn argument_regions(
+        &self,
+        index: usize,
+        args: &RuntimeArgs,
+    ) -> Result<Vec<(usize, u64, u64)>, RuntimeTrap> {
+        let mut regions = Vec::new();
+
+        for argument in pointer_arguments(index) {
+            match *argument {
+                PointerArgument::Input {
+                    pointer,
+                    length,
+                    element_size,
+                    non_empty: _,
+                } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+                    let count = args.nth::<semantic_types::Size>(length);
+
+                    regions.push((
+                        pointer,
+                        address as u64,
+                        count * element_size,
+                    ));
+                }
+                PointerArgument::Output { pointer, size } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+
+                    regions.push((pointer, address as u64, size));
+                }
+                PointerArgument::Buffer {
+                    pointer,
+                    length_pointer,
+                    element_size,
+                } => {
+                    let address = args.nth::<semantic_types::Pointer>(pointer);
+                    let length_address =
+                        args.nth::<semantic_types::Pointer>(length_pointer);
+                    let capacity = self.read_u64(length_address)?;
+
+                    regions.push((
+                        length_pointer,
+                        length_address as u64,
+                        size_of::<u64>() as u64,
+                    ));
+                    regions.push((
+                        pointer,
+                        address as u64,
+                        capacity * element_size,
+                    ));
+                }
+            }
+        }
+
+        Ok(regions)
+    }
+
+    /// Reads the contents of each region of memory in `regions`.
+    fn read_regions(
+        &self,
+        regions: &[(usize, u64, u64)],
+    ) -> Result<Vec<Vec<u8>>, RuntimeTrap> {
+        regions
+            .iter()
+            .map(|(_position, address, size)| {
+                self.read_bytes(
+                    *address as semantic_types::Pointer,
+                    *size as usize,
+                )
+            })
+            .collect()
+    }
+
+    /// Makes the system call with index `index`, as `dispatch`, recording
+    /// it in the trace and the history, whichever are enabled.  Pointer arguments are recorded along with the memory
+    /// that the call reads or writes through them, unless they fail
+    /// validation, in which case they are recorded as plain values.
+    fn dispatch_traced(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        let regions = if self.validate_arguments(index, &args).is_ok() {
+            self.argument_regions(index, &args)?
+        } else {
+            Vec::new()
+        };
+
+        let before = self.read_regions(&regions)?;
+        let active_memory = self.active_memory.get();
+
+        let values = args.as_ref();
+        let result = self.dispatch(index, RuntimeArgs::from(values));
+
+        /* NB: host calls that invoke guest callbacks may make further host
+         * calls, which change the active memory.
+         */
+        self.active_memory.set(active_memory);
+
+        let after = match &result {
+            Ok(Some(RuntimeValue::I32(status)))
+                if *status == KernelErrorCode::Success.into() =>
+            {
+                Some(self.read_regions(&regions)?)
+            }
+            _otherwise => None,
+        };
+
+        let mut arguments: Vec<TraceArgument> = values
+            .iter()
+            .map(|value| {
+                TraceArgument::Value(
+                    TraceValue::from_runtime_value(value)
+                        .unwrap_or(TraceValue::I64(0)),
+                )
+            })
+            .collect();
+
+        for (region, (position, _address, _size)) in regions.iter().enumerate()
+        {
+            arguments[*position] = TraceArgument::Memory {
+                before: before[region].clone(),
+                after: after.as_ref().map(|after| after[region].clone()),
+            };
+        }
+
+        let (name, return_type) = self
+            .call_imports
+            .borrow()
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| (index.to_string(), None));
+
+        let result_event = match &result {
+            Ok(Some(value)) => TraceValue::from_runtime_value(value)
+                .map(TraceResult::Value)
+                .unwrap_or(TraceResult::None),
+            Ok(None) => TraceResult::None,
+            Err(_trap) => TraceResult::Trap(return_type),
+        };
+
+        let event = TraceEvent {
+            name,
+            arguments,
+            result: result_event,
+        };
+
+        if self.history_capacity > 0 {
+            if self.history.len() == self.history_capacity {
+                self.history.pop_front();
+            }
+
+            self.history.push_back(event.clone());
+        }
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(event);
+        }
+
+        result
+    }
+
+    /// Makes the system call with index `index`, as `dispatch`, recording it in
+    /// the trace and the history, whichever are enabled.
+    #[inline]
+    fn dispatch_observed(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        if self.trace.is_some() || self.history_capacity > 0 {
+            self.dispatch_traced(index, args)
+        } else {
+            self.dispatch(index, args)
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Output canaries.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Makes the system call with index `index`, as `dispatch_observed`.
+    /// Without the `canaries` feature, the results of the call are written to
+    /// the guest's memory unchecked.
+    #[cfg(not(feature = "canaries"))]
+    #[inline]
+    fn dispatch_guarded(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        self.dispatch_observed(index, args)
+    }
+
+    /// Makes the system call with index `index`, as `dispatch_observed`,
+    /// checking that the results it writes through its pointer arguments,
+    /// `args`, do not overlap, and writing canaries to the guest's memory
+    /// immediately around each result.  The canaries are checked once the call
+    /// returns, catching any write that strays outside of the results, and the
+    /// guest's memory is then restored.  Memory read by the call is never
+    /// overwritten with canaries, and neither is memory belonging to another
+    /// of its results, so that results may abut.  Calls with arguments that
+    /// fail validation are made unchecked, as they write nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(RuntimeTrap::OverlappingOutputs)` if two results of the
+    /// call overlap, without making the call.
+    ///
+    /// Returns `Err(RuntimeTrap::CanaryClobbered)` if the call overwrote any
+    /// canary.
+    #[cfg(feature = "canaries")]
+    fn dispatch_guarded(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        if self.validate_arguments(index, &args).is_err() {
+            return self.dispatch_observed(index, args);
+        }
+
+        let outputs: Vec<usize> = pointer_arguments(index)
+            .iter()
+            .flat_map(|argument| match *argument {
+                PointerArgument::Output { pointer, size: _ } => vec![pointer],
+                PointerArgument::Buffer {
+                    pointer,
+                    length_pointer,
+                    element_size: _,
+                } => vec![pointer, length_pointer],
+                PointerArgument::Input { .. } => Vec::new(),
+            })
+            .collect();
+
+        let regions = self.argument_regions(index, &args)?;
+        let results: Vec<(usize, u64, u64)> = regions
+            .iter()
+            .filter(|(position, _address, size)| {
+                *size > 0 && outputs.contains(position)
+            })
+            .cloned()
+            .collect();
+
+        if results.is_empty() {
+            return self.dispatch_observed(index, args);
+        }
+
+        let name = self
+            .call_imports
+            .borrow()
+            .get(&index)
+            .map(|(name, _return_type)| name.clone())
+            .unwrap_or_else(|| index.to_string());
+
+        for (offset, (left, left_address, left_size)) in
+            results.iter().enumerate()
+        {
+            for (right, right_address, right_size) in &results[offset + 1..] {
+                if left_address < &(right_address + right_size)
+                    && right_address < &(left_address + left_size)
+                {
+                    error!("Results of host call {} overlap: argument {} points to {} bytes at {:#x}, and argument {} to {} bytes at {:#x}.", name, left, left_size, left_address, right, right_size, right_address);
+
+                    return Err(runtime_trap::host_trap(
+                        RuntimeTrap::OverlappingOutputs,
+                    ));
+                }
+            }
+        }
+
+        /* NB: folding calls back into the guest, which is free to read the
+         * memory around the results of the fold, so no canaries are written.
+         */
+        if index == ABI_TERM_FOLD_INDEX {
+            return self.dispatch_observed(index, args);
+        }
+
+        let memory_size = {
+            let Bytes(size) = self.memory()?.borrow().current_size().into();
+            size as u64
+        };
+
+        /* NB: each canary byte is mapped to the result that it guards, for
+         * reporting, and is never a byte read or written by the call itself.
+         */
+        let mut canaries: BTreeMap<u64, usize> = BTreeMap::new();
+
+        for (result, (_position, address, size)) in results.iter().enumerate() {
+            let before = address.saturating_sub(CANARY_WIDTH)..*address;
+            let after = address + size
+                ..(address + size + CANARY_WIDTH).min(memory_size);
+
+            for byte in before.chain(after) {
+                if !regions.iter().any(|(_position, address, size)| {
+                    *address <= byte && byte < address + size
+                }) {
+                    canaries.entry(byte).or_insert(result);
+                }
+            }
+        }
+
+        let mut runs: Vec<(u64, usize)> = Vec::new();
+
+        for byte in canaries.keys() {
+            match runs.last_mut() {
+                Some((start, length)) if *start + *length as u64 == *byte => {
+                    *length += 1
+                }
+                _otherwise => runs.push((*byte, 1)),
+            }
+        }
+
+        let mut saved = Vec::with_capacity(runs.len());
+
+        for (start, length) in runs.iter() {
+            let start = *start as semantic_types::Pointer;
+
+            saved.push(self.read_bytes(start, *length)?);
+            self.write_bytes(start, &vec![CANARY_BYTE; *length])?;
+        }
+
+        let result = self.dispatch_observed(index, args);
+
+        let mut clobbered = None;
+
+        for ((start, length), original) in runs.iter().zip(saved) {
+            let pointer = *start as semantic_types::Pointer;
+            let bytes = self.read_bytes(pointer, *length)?;
+
+            if clobbered.is_none() {
+                clobbered = bytes
+                    .iter()
+                    .position(|byte| *byte != CANARY_BYTE)
+                    .map(|offset| start + offset as u64);
+            }
+
+            self.write_bytes(pointer, &original)?;
+        }
+
+        if let Some(byte) = clobbered {
+            let (position, address, size) = results[canaries[&byte]];
+            let side = if byte < address { "before" } else { "after" };
+
+            error!("Host call {} wrote the byte at {:#x}, {} the result of {} bytes at {:#x} pointed-to by argument {}.", name, byte, side, size, address, position);
+
+            return Err(runtime_trap::host_trap(RuntimeTrap::CanaryClobbered));
+        }
+
+        result
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Kernel-related functionality.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `type_former_resolve` function.
+    #[inline]
+    fn type_former_resolve<T>(&self, handle: T) -> Option<usize>
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        self.kernel.borrow().type_former_resolve(handle).cloned()
+    }
+
+    /// Lifting of the `handle_is_stale` function.
+    #[inline]
+    fn handle_is_stale<T>(&self, handle: &Handle<T>) -> bool
+    where
+        T: tags::IsTag,
+    {
+        self.kernel.borrow().handle_is_stale(handle)
+    }
+
+    /// Lifting of the `type_former_is_registered` function.
+    #[inline]
+    fn type_former_is_registered<T>(&self, handle: T) -> bool
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        self.kernel.borrow().type_former_is_registered(handle)
+    }
+
+    /// Lifting of the `type_former_register` function.
+    #[inline]
+    fn type_former_register<T>(&self, arity: T) -> Handle<tags::TypeFormer>
+    where
+        T: Into<usize> + Clone,
+    {
+        self.kernel.borrow_mut().type_former_register(arity)
+    }
+
+    /// Lifting of the `constant_mark_constructors` function.
+    #[inline]
+    fn constant_mark_constructors<T, U>(
+        &self,
+        former: T,
+        constructors: Vec<U>,
+    ) -> Result<(), KernelErrorCode>
+    where
+        T: Into<Handle<tags::TypeFormer>> + Clone,
+        U: Into<Handle<tags::Constant>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .constant_mark_constructors(former, constructors)
+    }
+
+    /// Lifting of the `constant_is_constructor` function.
+    #[inline]
+    fn constant_is_constructor<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Constant>>,
+    {
+        self.kernel.borrow().constant_is_constructor(handle)
+    }
+
+    /// Lifting of the `constant_constructor_siblings` function.
+    #[inline]
+    fn constant_constructor_siblings<T>(
+        &self,
+        handle: T,
+    ) -> Result<
+        (Handle<tags::TypeFormer>, Vec<Handle<tags::Constant>>),
+        KernelErrorCode,
+    >
+    where
+        T: Borrow<Handle<tags::Constant>>,
+    {
+        self.kernel
+            .borrow()
+            .constant_constructor_siblings(handle)
+            .map(|(former, constructors)| {
+                (former.clone(), constructors.clone())
+            })
+    }
+
+    /// Lifting of the `type_register_variable` function.
+    #[inline]
+    fn type_register_variable<T>(&self, name: T) -> Handle<tags::Type>
+    where
+        T: Into<Name> + Clone,
+    {
+        self.kernel.borrow_mut().type_register_variable(name)
+    }
+
+    /// Lifting of the `type_register_combination` function.
+    #[inline]
+    fn type_register_combination<T, U>(
+        &self,
+        type_former: T,
+        arguments: Vec<U>,
+    ) -> Result<Handle<tags::Type>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::TypeFormer>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+    {
+        self.kernel.borrow_mut().type_register_combination(
+            type_former.into(),
+            arguments.into_iter().map(|a| a.into()).collect(),
+        )
+    }
+
+    /// Lifting of the `type_register_function` function.
+    #[inline]
+    fn type_register_function<T>(
+        &self,
+        domain: T,
+        range: T,
+    ) -> Result<Handle<tags::Type>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Type>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .type_register_function(domain.into(), range.into())
+    }
+
+    /// Lifting of the `type_is_registered` function.
+    #[inline]
     fn type_is_registered<T>(&self, handle: T) -> bool
     where
         T: Borrow<Handle<tags::Type>>,
@@ -650,22 +1882,23 @@ impl WasmiRuntimeState {
         self.kernel.borrow().type_split_variable(handle).map(|n| *n)
     }
 
-    /// Lifting of the `type_split_combination` function.
+    /// Lifting of the `type_split_combination` function.  Rather than copying
+    /// the arguments of the combination out of the kernel, `f` is applied to
+    /// them while the kernel is borrowed.
     #[inline]
-    fn type_split_combination<T>(
+    fn type_split_combination<T, F, R>(
         &self,
         handle: T,
-    ) -> Result<
-        (Handle<tags::TypeFormer>, Vec<Handle<tags::Type>>),
-        KernelErrorCode,
-    >
+        f: F,
+    ) -> Result<R, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Type>>,
+        F: FnOnce(&Handle<tags::TypeFormer>, &[Handle<tags::Type>]) -> R,
     {
         self.kernel
             .borrow()
             .type_split_combination(handle)
-            .map(|(f, a)| (f.clone(), a.clone()))
+            .map(|(former, arguments)| f(former, arguments))
     }
 
     /// Lifting of the `type_split_function` function.
@@ -722,16 +1955,23 @@ impl WasmiRuntimeState {
         self.kernel.borrow().type_size(handle)
     }
 
-    /// Lifting of the `type_variables` function.
+    /// Lifting of the `type_variables` function.  Rather than copying the type
+    /// variables out of the kernel, `f` is applied to them while the kernel is
+    /// borrowed.
     #[inline]
-    fn type_variables<T>(&self, handle: T) -> Result<Vec<Name>, KernelErrorCode>
+    fn type_variables<T, F, R>(
+        &self,
+        handle: T,
+        f: F,
+    ) -> Result<R, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Type>>,
+        F: FnOnce(&[&Name]) -> R,
     {
         self.kernel
-            .borrow_mut()
+            .borrow()
             .type_variables(handle)
-            .map(|v| v.iter().map(|e| **e).collect())
+            .map(|variables| f(&variables))
     }
 
     /// Lifting of the `type_substitute` function.
@@ -749,12 +1989,40 @@ impl WasmiRuntimeState {
         self.kernel.borrow_mut().type_substitute(handle, sigma)
     }
 
-    /// Lifting of the `constant_register` function.
+    /// Lifting of the `type_generalise` function.
     #[inline]
-    fn constant_register<T>(
+    fn type_generalise<T>(
         &self,
         handle: T,
-    ) -> Result<Handle<tags::Constant>, KernelErrorCode>
+        avoid: &[Name],
+    ) -> Result<(Handle<tags::Type>, Vec<Name>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        self.kernel.borrow_mut().type_generalise(handle, avoid)
+    }
+
+    /// Lifting of the `type_instantiate_fresh` function.
+    #[inline]
+    fn type_instantiate_fresh<T>(
+        &self,
+        handle: T,
+        avoid: &[Name],
+    ) -> Result<(Handle<tags::Type>, Renaming), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .type_instantiate_fresh(handle, avoid)
+    }
+
+    /// Lifting of the `constant_register` function.
+    #[inline]
+    fn constant_register<T>(
+        &self,
+        handle: T,
+    ) -> Result<Handle<tags::Constant>, KernelErrorCode>
     where
         T: Into<Handle<tags::Type>> + Clone,
     {
@@ -943,7 +2211,7 @@ impl WasmiRuntimeState {
     ) -> Result<Handle<tags::Term>, KernelErrorCode>
     where
         T: Into<Name> + Clone,
-        U: Into<Handle<tags::Type>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
         V: Into<Handle<tags::Term>> + Clone,
     {
         self.kernel
@@ -961,7 +2229,7 @@ impl WasmiRuntimeState {
     ) -> Result<Handle<tags::Term>, KernelErrorCode>
     where
         T: Into<Name> + Clone,
-        U: Into<Handle<tags::Type>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
         V: Into<Handle<tags::Term>> + Clone,
     {
         self.kernel
@@ -1029,6 +2297,57 @@ impl WasmiRuntimeState {
             .map(|(n, t, b)| (*n, t.clone(), b.clone()))
     }
 
+    /// Lifting of the `term_split_lambda_display` function.
+    #[inline]
+    fn term_split_lambda_display<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Name, Handle<tags::Type>, Handle<tags::Term>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow_mut().term_split_lambda_display(handle)
+    }
+
+    /// Lifting of the `term_diff` function.
+    #[inline]
+    fn term_diff<T>(&self, left: T, right: T) -> Result<String, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_diff(left, right)
+    }
+
+    /// Lifting of the `term_subterm_at` function.
+    #[inline]
+    fn term_subterm_at<T>(
+        &self,
+        handle: T,
+        position: &[usize],
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_subterm_at(handle, position)
+    }
+
+    /// Lifting of the `term_replace_at` function.
+    #[inline]
+    fn term_replace_at<T, U>(
+        &self,
+        handle: T,
+        position: &[usize],
+        replacement: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_replace_at(handle, position, replacement)
+    }
+
     /// Lifting of the `term_split_negation` function.
     #[inline]
     fn term_split_negation<T>(
@@ -1245,34 +2564,42 @@ impl WasmiRuntimeState {
         self.kernel.borrow().term_test_exists(handle)
     }
 
-    /// Lifting of the `term_free_variables` function.
+    /// Lifting of the `term_free_variables` function.  Rather than copying the
+    /// free variables out of the kernel, `f` is applied to them while the
+    /// kernel is borrowed.
     #[inline]
-    fn term_free_variables<T>(
+    fn term_free_variables<T, F, R>(
         &self,
         handle: T,
-    ) -> Result<Vec<(Name, Handle<tags::Type>)>, KernelErrorCode>
+        f: F,
+    ) -> Result<R, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Term>>,
+        F: FnOnce(&[(&Name, &Handle<tags::Type>)]) -> R,
     {
         self.kernel
             .borrow()
             .term_free_variables(handle)
-            .map(|v| v.iter().cloned().map(|(n, t)| (*n, t.clone())).collect())
+            .map(|variables| f(&variables))
     }
 
-    /// Lifting of the `term_type_variables` function.
+    /// Lifting of the `term_type_variables` function.  Rather than copying the
+    /// type variables out of the kernel, `f` is applied to them while the
+    /// kernel is borrowed.
     #[inline]
-    fn term_type_variables<T>(
+    fn term_type_variables<T, F, R>(
         &self,
         handle: T,
-    ) -> Result<Vec<Name>, KernelErrorCode>
+        f: F,
+    ) -> Result<R, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Term>>,
+        F: FnOnce(&[&Name]) -> R,
     {
         self.kernel
             .borrow()
             .term_type_variables(handle)
-            .map(|v| v.iter().cloned().cloned().collect())
+            .map(|variables| f(&variables))
     }
 
     /// Lifting of the `term_substitution` function.
@@ -1285,7 +2612,7 @@ impl WasmiRuntimeState {
     where
         T: Into<Handle<tags::Term>>,
         N: Into<Name> + Clone,
-        V: Into<Handle<tags::Type>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
         V: Into<Handle<tags::Term>> + Clone,
     {
         self.kernel.borrow_mut().substitution(handle, substitution)
@@ -1300,8 +2627,8 @@ impl WasmiRuntimeState {
     ) -> Result<Handle<tags::Term>, KernelErrorCode>
     where
         T: Into<Handle<tags::Term>>,
-        U: Into<Name> + Clone,
-        V: Into<Handle<tags::Type>> + Clone,
+        U: Into<Name> + Clone + Debug,
+        V: Into<Handle<tags::Type>> + Clone + Debug,
     {
         self.kernel
             .borrow_mut()
@@ -1341,6 +2668,136 @@ impl WasmiRuntimeState {
         self.kernel.borrow().theorem_is_registered(handle)
     }
 
+    /// Lifting of the `theorem_is_tainted` function.
+    #[inline]
+    fn theorem_is_tainted<T>(&self, handle: T) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().theorem_is_tainted(handle)
+    }
+
+    /// Lifting of the `theorem_alpha_equal` function.
+    #[inline]
+    fn theorem_alpha_equal<T>(
+        &self,
+        left: T,
+        right: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().theorem_alpha_equal(left, right)
+    }
+
+    /// Lifting of the `theorem_exists_with_conclusion` function.
+    #[inline]
+    fn theorem_exists_with_conclusion<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().theorem_exists_with_conclusion(handle)
+    }
+
+    /// Lifting of the `theorem_find_by_conclusion` function.
+    #[inline]
+    fn theorem_find_by_conclusion<T>(
+        &self,
+        handle: T,
+    ) -> Result<Vec<Handle<tags::Theorem>>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().theorem_find_by_conclusion(handle)
+    }
+
+    /// Lifting of the `theorem_find_matching` function.
+    #[inline]
+    fn theorem_find_matching<T>(
+        &self,
+        pattern: T,
+    ) -> Result<Vec<Handle<tags::Theorem>>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().theorem_find_matching(pattern)
+    }
+
+    /// Lifting of the `theorem_register_unfold` function, for the mode encoded
+    /// as `mode` and `position`.
+    #[inline]
+    fn theorem_register_unfold<T, U>(
+        &self,
+        definition: T,
+        term: U,
+        mode: semantic_types::UnfoldMode,
+        position: Vec<usize>,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        let mode = UnfoldMode::try_from((mode, position))
+            .map_err(|_e| KernelErrorCode::ArgumentOutOfRange)?;
+
+        self.kernel
+            .borrow_mut()
+            .theorem_register_unfold(definition, term, &mode)
+    }
+
+    /// Lifting of the `theorem_register_rewrite_at` function.
+    #[inline]
+    fn theorem_register_rewrite_at<T, U>(
+        &self,
+        equation: T,
+        term: U,
+        position: &[usize],
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_rewrite_at(equation, term, position)
+    }
+
+    /// Lifting of the `theorem_register_rewrite_hypothesis` function.
+    #[inline]
+    fn theorem_register_rewrite_hypothesis<T, U>(
+        &self,
+        handle: T,
+        index: usize,
+        equation: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_rewrite_hypothesis(handle, index, equation)
+    }
+
+    /// Lifting of the `theorem_register_assumption_matching` function.
+    #[inline]
+    fn theorem_register_assumption_matching<T, U>(
+        &self,
+        hypotheses: Vec<T>,
+        pattern: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_assumption_matching(hypotheses, pattern)
+    }
+
     /// Lifting of the `theorem_register_assumption` function.
     #[inline]
     fn theorem_register_assumption<T>(
@@ -1355,6 +2812,22 @@ impl WasmiRuntimeState {
             .theorem_register_assumption(term_handle)
     }
 
+    /// Lifting of the `theorem_register_weaken` function.
+    #[inline]
+    fn theorem_register_weaken<T, U>(
+        &self,
+        term_handle: T,
+        theorem_handle: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Theorem>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_weaken(term_handle, theorem_handle)
+    }
+
     /// Lifting of the `theorem_register_reflexivity` function.
     #[inline]
     fn theorem_register_reflexivity<T>(
@@ -1366,7 +2839,7 @@ impl WasmiRuntimeState {
     {
         self.kernel
             .borrow_mut()
-            .theorem_register_reflexivity(term_handle)
+            .theorem_register_reflexivity::<Handle<tags::Term>, _>(term_handle)
     }
 
     /// Lifting of the `theorem_register_symmetry` function.
@@ -1449,7 +2922,7 @@ impl WasmiRuntimeState {
     ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Type>> + Clone,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
     {
         self.kernel
             .borrow_mut()
@@ -1727,18 +3200,24 @@ impl WasmiRuntimeState {
 
     /// Lifting of the `theorem_register_forall_introduction` function.
     #[inline]
-    fn theorem_register_forall_introduction<T, U>(
+    fn theorem_register_forall_introduction<T, U, V>(
         &self,
-        theorem_handle: T,
-        name: U,
+        name: T,
+        type_handle: U,
+        theorem_handle: V,
     ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
     where
-        T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Name>,
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
     {
         self.kernel
             .borrow_mut()
-            .theorem_register_forall_introduction(theorem_handle, name)
+            .theorem_register_forall_introduction(
+                name,
+                type_handle,
+                theorem_handle,
+            )
     }
 
     /// Lifting of the `theorem_register_forall_elimination` function.
@@ -1750,7 +3229,7 @@ impl WasmiRuntimeState {
     ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Term>>,
+        U: Into<Handle<tags::Term>> + Clone,
     {
         self.kernel
             .borrow_mut()
@@ -1759,34 +3238,46 @@ impl WasmiRuntimeState {
 
     /// Lifting of the `theorem_register_exists_introduction` function.
     #[inline]
-    fn theorem_register_exists_introduction<T, U>(
+    fn theorem_register_exists_introduction<T, U, V>(
         &self,
         theorem_handle: T,
         term_handle: U,
+        witness_handle: V,
     ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Into<Handle<tags::Term>>,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
     {
         self.kernel
             .borrow_mut()
-            .theorem_register_exists_introduction(theorem_handle, term_handle)
+            .theorem_register_exists_introduction(
+                theorem_handle,
+                term_handle,
+                witness_handle,
+            )
     }
 
     /// Lifting of the `theorem_register_exists_elimination` function.
     #[inline]
-    fn theorem_register_exists_elimination<T, U>(
+    fn theorem_register_exists_elimination<T, U, V>(
         &self,
         left_handle: T,
-        right_handle: U,
+        variable_handle: U,
+        right_handle: V,
     ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
-        U: Borrow<Handle<tags::Theorem>>,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
     {
         self.kernel
             .borrow_mut()
-            .theorem_register_exists_elimination(left_handle, right_handle)
+            .theorem_register_exists_elimination(
+                left_handle,
+                variable_handle,
+                right_handle,
+            )
     }
 
     /// Lifting of the `theorem_split_conclusion` function.
@@ -1801,103 +3292,5983 @@ impl WasmiRuntimeState {
         self.kernel.borrow().theorem_split_conclusion(handle)
     }
 
-    /// Lifting of the `theorem_split_hypotheses` function.
+    /// Lifting of the `theorem_split_premisses` function.  Rather than copying
+    /// the premisses out of the kernel, `f` is applied to them while the
+    /// kernel is borrowed.
     #[inline]
-    fn theorem_split_hypotheses<T>(
+    fn theorem_split_hypotheses<T, F, R>(
         &self,
         handle: T,
-    ) -> Result<Vec<Handle<tags::Term>>, KernelErrorCode>
+        f: F,
+    ) -> Result<R, KernelErrorCode>
     where
         T: Borrow<Handle<tags::Theorem>>,
+        F: FnOnce(&[Handle<tags::Term>]) -> R,
     {
-        self.kernel.borrow().theorem_split_hypotheses(handle)
+        self.kernel.borrow().theorem_split_premisses(handle).map(f)
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// ABI binding.
-////////////////////////////////////////////////////////////////////////////////
 
-/// Dispatches on an ABI host-call number, and calls the respective function on
-/// the machine's runtime state.
-impl Externals for WasmiRuntimeState {
-    fn invoke_index(
-        &mut self,
-        index: usize,
-        args: RuntimeArgs,
-    ) -> Result<Option<RuntimeValue>, Trap> {
-        match index {
-            ABI_TYPE_FORMER_RESOLVE_INDEX => {
-                let handle = args.nth::<semantic_types::Handle>(0);
-                let result_address = args.nth::<semantic_types::Pointer>(1);
+    /// Lifting of the `kernel_enumerate` function.
+    #[inline]
+    fn kernel_enumerate(
+        &self,
+        kind: Kind,
+        cursor: usize,
+        limit: usize,
+    ) -> (Vec<usize>, Option<usize>) {
+        self.kernel.borrow().kernel_enumerate(kind, cursor, limit)
+    }
 
-                let arity = match self
-                    .type_former_resolve(&Handle::from(handle as usize))
-                {
-                    None => {
-                        return Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::NoSuchTypeFormerRegistered.into(),
-                        )))
-                    }
-                    Some(arity) => arity,
-                };
+    /// Lifting of the `kernel_identify` function.
+    #[inline]
+    fn kernel_identify(&self) -> Identity {
+        Identity::new()
+    }
 
-                self.write_u64(result_address, arity as u64)?;
+    /// Lifting of the `reset` function, refused with
+    /// `KernelErrorCode::PermissionDenied` unless the host has enabled it.
+    fn kernel_reset(&self) -> Result<(), KernelErrorCode> {
+        if !self.kernel_reset_enabled {
+            error!("Guest refused kernel reset.");
 
-                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
-            }
-            ABI_TYPE_FORMER_IS_REGISTERED_INDEX => {
-                let handle = args.nth::<semantic_types::Handle>(0);
-                let result = self
-                    .type_former_is_registered(&Handle::from(handle as usize));
+            return Err(KernelErrorCode::PermissionDenied);
+        }
 
-                Ok(Some(RuntimeValue::I32(result.into())))
-            }
-            ABI_TYPE_FORMER_REGISTER_INDEX => {
-                let arity = args.nth::<semantic_types::Arity>(0);
-                let result = self.type_former_register(arity as usize);
+        self.kernel.borrow_mut().reset();
 
-                Ok(Some(RuntimeValue::I64(*result as i64)))
-            }
-            ABI_TYPE_REGISTER_VARIABLE_INDEX => {
-                let name = args.nth::<semantic_types::Name>(0);
-                let result = self.type_register_variable(name);
+        Ok(())
+    }
 
-                Ok(Some(RuntimeValue::I64(*result as i64)))
-            }
-            ABI_TYPE_REGISTER_COMBINATION_INDEX => {
-                let former_handle: Handle<tags::TypeFormer> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let argument_base = args.nth::<semantic_types::Pointer>(1);
-                let argument_length = args.nth::<semantic_types::Size>(2);
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+    /// Lifting of the `set_variable_order` function, for the order encoded as
+    /// `order`.
+    #[inline]
+    fn kernel_set_variable_order(
+        &self,
+        order: semantic_types::VariableOrder,
+    ) -> Result<(), KernelErrorCode> {
+        let order = VariableOrder::try_from(order)
+            .map_err(|_e| KernelErrorCode::ArgumentOutOfRange)?;
 
-                let arguments =
-                    self.read_handles(argument_base, argument_length as usize)?;
+        self.kernel.borrow_mut().set_variable_order(order);
 
-                match self.type_register_combination(former_handle, arguments) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+        Ok(())
+    }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
-                }
-            }
-            ABI_TYPE_REGISTER_FUNCTION_INDEX => {
-                let domain_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let range_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+    /// Lifting of the `kernel_error_detail` function.
+    #[inline]
+    fn kernel_error_detail(&self) -> Option<String> {
+        self.kernel.borrow().kernel_error_detail()
+    }
+
+    /// Lifting of the `hypotheses_intern` function.
+    #[inline]
+    fn hypotheses_intern<T>(
+        &self,
+        hyps: Vec<T>,
+    ) -> Result<Handle<tags::Hypotheses>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().hypotheses_intern(hyps)
+    }
+
+    /// Lifting of the `hypotheses_resolve` function.  Rather than copying the
+    /// hypotheses out of the kernel, `f` is applied to them while the kernel
+    /// is borrowed.
+    #[inline]
+    fn hypotheses_resolve<T, F, R>(
+        &self,
+        handle: T,
+        f: F,
+    ) -> Result<R, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Hypotheses>>,
+        F: FnOnce(&[Handle<tags::Term>]) -> R,
+    {
+        self.kernel
+            .borrow()
+            .hypotheses_resolve(handle)
+            .map(|hypotheses| f(hypotheses))
+    }
+
+    /// Lifting of the `term_register_conditional` function.
+    #[inline]
+    fn term_register_conditional<T, U, V>(
+        &self,
+        condition: T,
+        left: U,
+        right: V,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_register_conditional(condition, left, right)
+    }
+
+    /// Lifting of the `term_register_let` function.
+    #[inline]
+    fn term_register_let<T, U>(
+        &self,
+        function: T,
+        argument: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_register_let(function, argument)
+    }
+
+    /// Lifting of the `term_split_conditional` function.
+    #[inline]
+    fn term_split_conditional<T>(
+        &self,
+        handle: T,
+    ) -> Result<Conditional, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow()
+            .term_split_conditional(handle)
+            .map(|(c, l, r)| (c.clone(), l.clone(), r.clone()))
+    }
+
+    /// Lifting of the `term_split_let` function.
+    #[inline]
+    fn term_split_let<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Term>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow()
+            .term_split_let(handle)
+            .map(|(f, a)| (f.clone(), a.clone()))
+    }
+
+    /// Lifting of the `term_test_conditional` function.
+    #[inline]
+    fn term_test_conditional<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_test_conditional(handle)
+    }
+
+    /// Lifting of the `term_test_let` function.
+    #[inline]
+    fn term_test_let<T>(&self, handle: T) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_test_let(handle)
+    }
+
+    /// Lifting of the `theorem_register_conditional_congruence` function.
+    #[inline]
+    fn theorem_register_conditional_congruence<T, U, V>(
+        &self,
+        condition_handle: T,
+        left_handle: U,
+        right_handle: V,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_conditional_congruence(
+                condition_handle,
+                left_handle,
+                right_handle,
+            )
+    }
+
+    /// Lifting of the `theorem_register_let_congruence` function.
+    #[inline]
+    fn theorem_register_let_congruence<T, U>(
+        &self,
+        function_handle: T,
+        argument_handle: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_let_congruence(function_handle, argument_handle)
+    }
+
+    /// Lifting of the `term_register_pair` function.
+    #[inline]
+    fn term_register_pair<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().term_register_pair(left, right)
+    }
+
+    /// Lifting of the `term_register_first` function.
+    #[inline]
+    fn term_register_first<T>(
+        &self,
+        pair: T,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().term_register_first(pair)
+    }
+
+    /// Lifting of the `term_register_second` function.
+    #[inline]
+    fn term_register_second<T>(
+        &self,
+        pair: T,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().term_register_second(pair)
+    }
+
+    /// Lifting of the `term_split_pair` function.
+    #[inline]
+    fn term_split_pair<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Term>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow()
+            .term_split_pair(handle)
+            .map(|(l, r)| (l.clone(), r.clone()))
+    }
+
+    /// Lifting of the `term_test_pair` function.
+    #[inline]
+    fn term_test_pair<T>(&self, handle: T) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_test_pair(handle)
+    }
+
+    /// Lifting of the `theorem_register_first_projection` function.
+    #[inline]
+    fn theorem_register_first_projection<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_first_projection(left, right)
+    }
+
+    /// Lifting of the `theorem_register_second_projection` function.
+    #[inline]
+    fn theorem_register_second_projection<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_second_projection(left, right)
+    }
+
+    /// Lifting of the `theorem_register_surjective_pairing` function.
+    #[inline]
+    fn theorem_register_surjective_pairing<T>(
+        &self,
+        pair: T,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_surjective_pairing(pair)
+    }
+
+    /// Lifting of the `term_register_membership` function.
+    #[inline]
+    fn term_register_membership<T, U>(
+        &self,
+        element: T,
+        set: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_register_membership(element, set)
+    }
+
+    /// Lifting of the `term_register_comprehension` function.
+    #[inline]
+    fn term_register_comprehension<T, U, V>(
+        &self,
+        name: T,
+        tau: U,
+        body: V,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone + Debug,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_register_comprehension(name, tau, body)
+    }
+
+    /// Lifting of the `term_split_membership` function.
+    #[inline]
+    fn term_split_membership<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Term>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow()
+            .term_split_membership(handle)
+            .map(|(e, s)| (e.clone(), s.clone()))
+    }
+
+    /// Lifting of the `term_split_comprehension` function.
+    #[inline]
+    fn term_split_comprehension<T>(
+        &self,
+        handle: T,
+    ) -> Result<(Name, Handle<tags::Type>, Handle<tags::Term>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow()
+            .term_split_comprehension(handle)
+            .map(|(n, t, b)| (*n, t.clone(), b.clone()))
+    }
+
+    /// Lifting of the `term_test_membership` function.
+    #[inline]
+    fn term_test_membership<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_test_membership(handle)
+    }
+
+    /// Lifting of the `term_test_comprehension` function.
+    #[inline]
+    fn term_test_comprehension<T>(
+        &self,
+        handle: T,
+    ) -> Result<bool, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        self.kernel.borrow().term_test_comprehension(handle)
+    }
+
+    /// Lifting of the `theorem_register_membership_definition` function.
+    #[inline]
+    fn theorem_register_membership_definition<T, U>(
+        &self,
+        element: T,
+        set: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_membership_definition(element, set)
+    }
+
+    /// Lifting of the `theorem_register_comprehension_definition` function.
+    #[inline]
+    fn theorem_register_comprehension_definition<T>(
+        &self,
+        set: T,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_comprehension_definition(set)
+    }
+
+    /// Lifting of the `theorem_register_empty_set_definition` function.
+    #[inline]
+    fn theorem_register_empty_set_definition<T>(
+        &self,
+        tau: T,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_empty_set_definition(tau)
+    }
+
+    /// Lifting of the `theorem_register_universal_set_definition` function.
+    #[inline]
+    fn theorem_register_universal_set_definition<T>(
+        &self,
+        tau: T,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_universal_set_definition(tau)
+    }
+
+    /// Lifting of the `theorem_register_union_definition` function.
+    #[inline]
+    fn theorem_register_union_definition<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_union_definition(left, right)
+    }
+
+    /// Lifting of the `theorem_register_intersection_definition` function.
+    #[inline]
+    fn theorem_register_intersection_definition<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_intersection_definition(left, right)
+    }
+
+    /// Lifting of the `theorem_register_subset_definition` function.
+    #[inline]
+    fn theorem_register_subset_definition<T, U>(
+        &self,
+        left: T,
+        right: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+        U: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .theorem_register_subset_definition(left, right)
+    }
+
+    /// Lifting of the `challenge_discharge` function.  The expiry policy is
+    /// decoded from `expiry` and `uses`, as received across the ABI boundary.
+    #[inline]
+    fn challenge_discharge<T>(
+        &self,
+        service: Service,
+        theorem: T,
+        expiry: u64,
+        uses: u64,
+    ) -> Result<Handle<tags::Capability>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        let expiry = Expiry::try_from((expiry, uses))
+            .map_err(|_e| KernelErrorCode::NoSuchExpiryPolicy)?;
+
+        self.kernel
+            .borrow_mut()
+            .challenge_discharge(service, theorem, expiry)
+    }
+
+    /// Lifting of the `challenge_resolve` function.
+    #[inline]
+    fn challenge_current(
+        &self,
+        service: Service,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode> {
+        self.kernel.borrow().challenge_resolve(service).cloned()
+    }
+
+    /// Lifting of the `capability_present` function.
+    #[inline]
+    fn capability_present<T>(
+        &self,
+        capability: T,
+        service: Service,
+    ) -> Result<(), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Capability>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .capability_present(capability, service)
+    }
+
+    /// Lifting of the `theorem_proof` function.
+    #[inline]
+    fn theorem_proof<T>(
+        &self,
+        handle: T,
+    ) -> Result<Handle<tags::Proof>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().theorem_proof(handle)
+    }
+
+    /// Lifting of the `proof_split` function, omitting the arguments of the
+    /// inference rule, which are lifted by `proof_split_arguments`.  The rule
+    /// is encoded as a `u64`, for ABI transport.
+    #[inline]
+    fn proof_split<T>(
+        &self,
+        handle: T,
+    ) -> Result<(u64, Handle<tags::Theorem>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        self.kernel
+            .borrow()
+            .proof_split(handle)
+            .map(|(rule, theorem, _arguments)| (rule.into(), theorem.clone()))
+    }
+
+    /// Lifting of the arguments returned by the `proof_split` function.  Rather
+    /// than copying the arguments out of the kernel, `f` is applied to them
+    /// while the kernel is borrowed.
+    #[inline]
+    fn proof_split_arguments<T, F, R>(
+        &self,
+        handle: T,
+        f: F,
+    ) -> Result<R, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+        F: FnOnce(&[ProofArgument]) -> R,
+    {
+        self.kernel
+            .borrow()
+            .proof_split(handle)
+            .map(|(_rule, _theorem, arguments)| f(arguments))
+    }
+
+    /// Lifting of the `proof_check` function.
+    #[inline]
+    fn proof_check<T>(&self, handle: T) -> Result<(), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Proof>>,
+    {
+        self.kernel.borrow_mut().proof_check(handle)
+    }
+
+    /// Lifting of the `theorem_statement_hash` function.
+    #[inline]
+    fn theorem_statement_hash<T>(
+        &self,
+        handle: T,
+    ) -> Result<u64, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().theorem_statement_hash(handle)
+    }
+
+    /// Lifting of the `theorem_statement_as_term` function.
+    #[inline]
+    fn theorem_statement_as_term<T>(
+        &self,
+        handle: T,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow_mut().theorem_statement_as_term(handle)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Quotient types.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `quotient_register` function, returning the new quotient
+    /// type.
+    #[inline]
+    fn quotient_register<T, U, V, W>(
+        &self,
+        relation: T,
+        reflexivity: U,
+        symmetry: V,
+        transitivity: W,
+    ) -> Result<Handle<tags::Type>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Theorem>>,
+        V: Borrow<Handle<tags::Theorem>>,
+        W: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .quotient_register(relation, reflexivity, symmetry, transitivity)
+            .map(|quotient| quotient.tau().clone())
+    }
+
+    /// Lifting of the `quotient_resolve` function, returning the relation,
+    /// class function, class equality theorem and induction rule of the
+    /// quotient type.
+    #[inline]
+    fn quotient_split<T>(&self, tau: T) -> Result<Quotient, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+    {
+        self.kernel.borrow().quotient_resolve(tau).map(|quotient| {
+            (
+                quotient.relation().clone(),
+                quotient.class().clone(),
+                quotient.class_equality().clone(),
+                quotient.induction().clone(),
+            )
+        })
+    }
+
+    /// Lifting of the `quotient_lift` function, returning the lifted constant
+    /// and its equation.
+    #[inline]
+    fn quotient_lift<T, U, V>(
+        &self,
+        tau: T,
+        function: U,
+        respect: V,
+    ) -> Result<(Handle<tags::Term>, Handle<tags::Theorem>), KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Into<Handle<tags::Term>>,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .quotient_lift(tau, function, respect)
+            .map(|lifted| {
+                (lifted.constant().clone(), lifted.equation().clone())
+            })
+    }
+
+    /// Lifting of the `register_new_definition` function, returning the new
+    /// constant and its definitional theorem.
+    #[inline]
+    fn constant_define<T>(
+        &self,
+        defn: T,
+    ) -> Result<(Handle<tags::Constant>, Handle<tags::Theorem>), KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        let mut kernel = self.kernel.borrow_mut();
+        let (constant, theorem) = kernel.register_new_definition(defn)?;
+        let (constant, _tau) = kernel.term_split_constant(constant)?;
+
+        Ok((constant.clone(), theorem))
+    }
+
+    /// Lifting of the `register_new_type_definition` function, returning the
+    /// type-former of the new type, its abstraction and representation
+    /// constants, and the two theorems characterising them.
+    #[inline]
+    fn type_define<T>(
+        &self,
+        witness: T,
+    ) -> Result<TypeDefinition, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        let mut kernel = self.kernel.borrow_mut();
+        let (tau, abs, rep, abs_rep, rep_abs) =
+            kernel.register_new_type_definition(witness)?;
+        let (former, _arguments) = kernel.type_split_combination(tau)?;
+        let former = former.clone();
+        let (abs, _tau) = kernel.term_split_constant(abs)?;
+        let abs = abs.clone();
+        let (rep, _tau) = kernel.term_split_constant(rep)?;
+
+        Ok((former, abs, rep.clone(), abs_rep, rep_abs))
+    }
+
+    /// Lifting of the `quotient_transfer` function.
+    #[inline]
+    fn quotient_transfer<T, U>(
+        &self,
+        tau: T,
+        handle: U,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow_mut().quotient_transfer(tau, handle)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Registered substitutions.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `substitution_register_type` function.
+    #[inline]
+    fn substitution_register_type<U, V>(
+        &self,
+        sigma: Vec<(U, V)>,
+    ) -> Result<Handle<tags::Substitution>, KernelErrorCode>
+    where
+        U: Into<Name> + Clone + Debug,
+        V: Into<Handle<tags::Type>> + Clone + Debug,
+    {
+        self.kernel.borrow_mut().substitution_register_type(sigma)
+    }
+
+    /// Lifting of the `substitution_register_term` function.
+    #[inline]
+    fn substitution_register_term<N, U, V>(
+        &self,
+        sigma: Vec<((N, U), V)>,
+    ) -> Result<Handle<tags::Substitution>, KernelErrorCode>
+    where
+        N: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().substitution_register_term(sigma)
+    }
+
+    /// Lifting of the `theorem_trust_level` function.
+    #[inline]
+    fn theorem_trust_level<T>(
+        &self,
+        handle: T,
+    ) -> Result<TrustLevel, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().theorem_trust_level(handle)
+    }
+
+    /// Lifting of the `substitution_compose` function.
+    #[inline]
+    fn substitution_compose<T, U>(
+        &self,
+        first: T,
+        second: U,
+    ) -> Result<Handle<tags::Substitution>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Substitution>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        self.kernel.borrow_mut().substitution_compose(first, second)
+    }
+
+    /// Lifting of the `type_substitute_with` function.
+    #[inline]
+    fn type_substitute_with<T, U>(
+        &self,
+        tau: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Type>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Type>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        self.kernel.borrow_mut().type_substitute_with(tau, sigma)
+    }
+
+    /// Lifting of the `substitution_with` function.
+    #[inline]
+    fn term_substitute_with<T, U>(
+        &self,
+        handle: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        self.kernel.borrow_mut().substitution_with(handle, sigma)
+    }
+
+    /// Lifting of the `term_type_substitute_with` function.
+    #[inline]
+    fn term_type_substitute_with<T, U>(
+        &self,
+        handle: T,
+        sigma: U,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+        U: Borrow<Handle<tags::Substitution>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .term_type_substitute_with(handle, sigma)
+    }
+
+    /// Lifting of the `theorem_register_forall_congruence` function.
+    #[inline]
+    fn theorem_register_forall_congruence<T, U, V>(
+        &self,
+        name: T,
+        type_handle: U,
+        theorem_handle: V,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow_mut().theorem_register_forall_congruence(
+            name,
+            type_handle,
+            theorem_handle,
+        )
+    }
+
+    /// Lifting of the `theorem_register_exists_congruence` function.
+    #[inline]
+    fn theorem_register_exists_congruence<T, U, V>(
+        &self,
+        name: T,
+        type_handle: U,
+        theorem_handle: V,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Name> + Clone,
+        U: Into<Handle<tags::Type>> + Clone,
+        V: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow_mut().theorem_register_exists_congruence(
+            name,
+            type_handle,
+            theorem_handle,
+        )
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Resource budgets.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the number of copies of the resource assertion that remain in
+    /// the budget granted to `service`.
+    #[cfg(feature = "budgets")]
+    #[inline]
+    fn budget_remaining(
+        &self,
+        service: Service,
+    ) -> Result<u64, KernelErrorCode> {
+        self.kernel
+            .borrow()
+            .budget_resolve(service)
+            .map(|budget| budget.remaining())
+    }
+
+    /// Returns a handle to the resource proposition asserted by the budget
+    /// granted to `service`.
+    #[cfg(feature = "budgets")]
+    #[inline]
+    fn budget_resource(
+        &self,
+        service: Service,
+    ) -> Result<Handle<tags::Term>, KernelErrorCode> {
+        self.kernel
+            .borrow()
+            .budget_resolve(service)
+            .map(|budget| budget.resource().clone())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Feature negotiation.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` iff the host has asked the guest to stop.
+    #[inline]
+    fn should_abort(&self) -> bool {
+        self.abort_requested()
+    }
+
+    /// Lifting of the `Feature::is_supported` function, for features encoded
+    /// as `feature`.  Features unknown to the kernel are unsupported.
+    #[inline]
+    fn kernel_feature_supported(
+        &self,
+        feature: semantic_types::Feature,
+    ) -> bool {
+        Feature::try_from(feature)
+            .map(|feature| feature.is_supported())
+            .unwrap_or(false)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Sequent-related functionality.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `sequent_is_registered` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_is_registered<T>(&self, handle: T) -> bool
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+    {
+        self.kernel.borrow().sequent_is_registered(handle)
+    }
+
+    /// Lifting of the `sequent_split_premisses` function.  The premisses are
+    /// passed to the continuation `f`, whilst the kernel is borrowed.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_split_premisses<T, F, R>(
+        &self,
+        handle: T,
+        f: F,
+    ) -> Result<R, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        F: FnOnce(&[Handle<tags::Term>]) -> R,
+    {
+        self.kernel.borrow().sequent_split_premisses(handle).map(f)
+    }
+
+    /// Lifting of the `sequent_split_conclusions` function.  The conclusions
+    /// are passed to the continuation `f`, whilst the kernel is borrowed.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_split_conclusions<T, F, R>(
+        &self,
+        handle: T,
+        f: F,
+    ) -> Result<R, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        F: FnOnce(&[Handle<tags::Term>]) -> R,
+    {
+        self.kernel
+            .borrow()
+            .sequent_split_conclusions(handle)
+            .map(f)
+    }
+
+    /// Lifting of the `sequent_register_axiom` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_axiom<T>(
+        &self,
+        term: T,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>>,
+    {
+        self.kernel.borrow_mut().sequent_register_axiom(term)
+    }
+
+    /// Lifting of the `sequent_register_theorem` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_theorem<T>(
+        &self,
+        theorem: T,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow_mut().sequent_register_theorem(theorem)
+    }
+
+    /// Lifting of the `sequent_register_weaken_left` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_weaken_left<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_weaken_left(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_weaken_right` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_weaken_right<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_weaken_right(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_negation_left` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_negation_left<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_negation_left(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_negation_right` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_negation_right<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_negation_right(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_conjunction_left` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_conjunction_left<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_conjunction_left(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_disjunction_right` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_disjunction_right<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_disjunction_right(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_implication_right` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_implication_right<T, U>(
+        &self,
+        sequent: T,
+        term: U,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_implication_right(sequent, term)
+    }
+
+    /// Lifting of the `sequent_register_cut` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_cut<T, U, V>(
+        &self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_cut(left, right, term)
+    }
+
+    /// Lifting of the `sequent_register_conjunction_right` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_conjunction_right<T, U, V>(
+        &self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_conjunction_right(left, right, term)
+    }
+
+    /// Lifting of the `sequent_register_disjunction_left` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_disjunction_left<T, U, V>(
+        &self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_disjunction_left(left, right, term)
+    }
+
+    /// Lifting of the `sequent_register_implication_left` function.
+    #[cfg(feature = "sequents")]
+    #[inline]
+    fn sequent_register_implication_left<T, U, V>(
+        &self,
+        left: T,
+        right: U,
+        term: V,
+    ) -> Result<Handle<tags::Sequent>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Sequent>>,
+        U: Borrow<Handle<tags::Sequent>>,
+        V: Into<Handle<tags::Term>>,
+    {
+        self.kernel
+            .borrow_mut()
+            .sequent_register_implication_left(left, right, term)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Host function table.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Sets whether the host function table is enabled, which it is not by
+    /// default.  The host function table is a function table, imported by
+    /// guests as `__supervisionary_host_table`, through which host calls may
+    /// be called indirectly, rather than imported by name.  Every host call
+    /// that any module imports is also placed in the table, in the slot fixed
+    /// for it by the version of the table's layout, which guests import as
+    /// `__supervisionary_host_table_version`.  This allows the host calls to
+    /// be resolved once, by a side module of a dynamically-linked guest, and
+    /// then called by its clients through slots that a dynamic linker may
+    /// patch.  Must be called before the guest's imports are resolved to have
+    /// effect.
+    pub fn enable_host_table(&mut self, enabled: bool) -> &mut Self {
+        /* NB: allocation only fails if the initial size of the table exceeds
+         * its maximum size, which it does not.
+         */
+        self.host_table = if enabled {
+            TableInstance::alloc(ABI_HOST_TABLE_SIZE, Some(ABI_HOST_TABLE_SIZE))
+                .ok()
+        } else {
+            None
+        };
+
+        self
+    }
+
+    /// Returns the host function table, if it has been enabled.
+    #[inline]
+    pub fn host_table(&self) -> Option<&TableRef> {
+        self.host_table.as_ref()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Callback-related functionality.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Registers the WASM guest's function table with the runtime state,
+    /// allowing host calls to invoke guest callbacks by their table index.
+    #[inline]
+    pub fn register_table(&mut self, table: TableRef) -> &mut Self {
+        self.table = Some(table);
+        self
+    }
+
+    /// Resolves the guest callback at index `callback` of the guest's function
+    /// table, checking that it has the signature `expected`.  The callback is
+    /// described as `purpose` when logging.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(RuntimeTrap::TableNotRegistered)` if the guest's function
+    /// table has not been registered with the runtime state.
+    ///
+    /// Returns `Err(RuntimeTrap::NoSuchCallback)` if `callback` does not index
+    /// a function in the table.
+    ///
+    /// Returns `Err(RuntimeTrap::SignatureFailure)` if the function does not
+    /// have the signature `expected`.
+    fn resolve_callback(
+        &self,
+        callback: semantic_types::TableIndex,
+        expected: Signature,
+        purpose: &str,
+    ) -> Result<FuncRef, RuntimeTrap> {
+        let table =
+            self.table.as_ref().ok_or(RuntimeTrap::TableNotRegistered)?;
+
+        let function = table
+            .get(callback)
+            .ok()
+            .flatten()
+            .ok_or(RuntimeTrap::NoSuchCallback)?;
+
+        if function.signature() != &expected {
+            error!("{} callback {} has the wrong signature.", purpose, callback);
+
+            return Err(RuntimeTrap::SignatureFailure);
+        }
+
+        Ok(function)
+    }
+
+    /// Resolves the guest callback at index `callback` of the guest's function
+    /// table, checking that it has the signature expected of a `Term.Fold`
+    /// callback.  See `resolve_callback`.
+    fn fold_callback(
+        &self,
+        callback: semantic_types::TableIndex,
+    ) -> Result<FuncRef, RuntimeTrap> {
+        let expected = Signature::new(
+            &[
+                ValueType::I64,
+                ValueType::I64,
+                ValueType::I32,
+                ValueType::I64,
+                ValueType::I64,
+            ][..],
+            Some(ValueType::I64),
+        );
+
+        self.resolve_callback(callback, expected, "Term.Fold")
+    }
+
+    /// Registers the guest callback at index `callback` of the guest's
+    /// function table to be notified of the progress of the long-running
+    /// kernel operations performed by the guest's host calls, such as
+    /// `Proof.Check`, replacing any callback registered before.  The callback
+    /// is invoked with `context`, one of the `PROGRESS_OPERATION_*` operations,
+    /// and the percentage of the operation completed, once as the operation
+    /// begins and then each time that percentage increases.
+    ///
+    /// Note that, unlike a `Term.Fold` callback, the kernel is borrowed while
+    /// the callback runs, so the callback may not itself make host calls, and
+    /// traps if it tries to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(trap)` if the callback could not be resolved.
+    fn progress_register(
+        &mut self,
+        callback: semantic_types::TableIndex,
+        context: semantic_types::Context,
+    ) -> Result<(), Trap> {
+        let expected = Signature::new(
+            &[ValueType::I64, ValueType::I32, ValueType::I32][..],
+            None,
+        );
+
+        let function = self
+            .resolve_callback(callback, expected, "Progress")
+            .map_err(runtime_trap::host_trap)?;
+
+        *(*self.progress_callback).borrow_mut() = Some((function, context));
+
+        if self.progress_observed {
+            return Ok(());
+        }
+
+        let registered = self.progress_callback.clone();
+        let trapped = self.progress_trap.clone();
+
+        self.kernel
+            .borrow_mut()
+            .on_progress(move |operation, percent| {
+                if (*trapped).borrow().is_some() {
+                    return;
+                }
+
+                if let Some((function, context)) = &*(*registered).borrow() {
+                    let operation = match operation {
+                        LongOperation::ProofCheck => {
+                            PROGRESS_OPERATION_PROOF_CHECK
+                        }
+                        LongOperation::ProofImport => {
+                            PROGRESS_OPERATION_PROOF_IMPORT
+                        }
+                        LongOperation::StateImport => {
+                            PROGRESS_OPERATION_STATE_IMPORT
+                        }
+                    };
+
+                    /* NB: the kernel is borrowed, so the callback is run with
+                     * no host calls available to it.
+                     */
+                    if let Err(trap) = FuncInstance::invoke(
+                        function,
+                        &[
+                            RuntimeValue::I64(*context as i64),
+                            RuntimeValue::I32(operation as i32),
+                            RuntimeValue::I32(percent as i32),
+                        ],
+                        &mut NopExternals,
+                    ) {
+                        *(*trapped).borrow_mut() = Some(trap);
+                    }
+                }
+            });
+
+        self.progress_observed = true;
+
+        Ok(())
+    }
+
+    /// Folds the guest callback at index `callback` of the guest's function
+    /// table over the term pointed-to by `handle`, bottom-up.  The callback is
+    /// invoked once per distinct subterm, with `context`, the subterm's handle,
+    /// a tag describing its shape, and two fields: the name and type of a
+    /// variable, the constant and type of a constant, the folded results of
+    /// the two halves of an application, or the name and folded body of a
+    /// lambda-abstraction.  Returns the callback's result for `handle` itself.
+    ///
+    /// Note that the kernel is not borrowed while the callback runs, so the
+    /// callback may itself make host calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Err(KernelErrorCode::NoSuchTermRegistered))` if `handle`
+    /// does not point-to a registered term.
+    ///
+    /// Returns `Err(trap)` if the callback could not be resolved, returned a
+    /// value of the wrong type, or trapped.
+    fn term_fold<T>(
+        &mut self,
+        handle: T,
+        callback: semantic_types::TableIndex,
+        context: semantic_types::Context,
+    ) -> Result<Result<u64, KernelErrorCode>, Trap>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        let order = match self.kernel.borrow().term_postorder(handle.borrow()) {
+            Err(e) => return Ok(Err(e)),
+            Ok(order) => order,
+        };
+
+        let function = self
+            .fold_callback(callback)
+            .map_err(runtime_trap::host_trap)?;
+
+        let memory = self.active_memory.get();
+        let mut folded: HashMap<Handle<tags::Term>, u64> = HashMap::new();
+
+        for node in order {
+            let (tag, first, second) =
+                match self.kernel.borrow().resolve_term_handle(&node) {
+                    Err(e) => return Ok(Err(e)),
+                    Ok(Term::Variable { name, tau }) => {
+                        (FOLD_TAG_VARIABLE, *name, **tau as u64)
+                    }
+                    Ok(Term::Constant { constant, tau }) => {
+                        (FOLD_TAG_CONSTANT, **constant as u64, **tau as u64)
+                    }
+                    Ok(Term::Application { left, right }) => {
+                        (FOLD_TAG_APPLICATION, folded[left], folded[right])
+                    }
+                    Ok(Term::Lambda { name, body, .. }) => {
+                        (FOLD_TAG_LAMBDA, *name, folded[body])
+                    }
+                };
+
+            let result = FuncInstance::invoke(
+                &function,
+                &[
+                    RuntimeValue::I64(context as i64),
+                    RuntimeValue::I64(*node as i64),
+                    RuntimeValue::I32(tag as i32),
+                    RuntimeValue::I64(first as i64),
+                    RuntimeValue::I64(second as i64),
+                ],
+                self,
+            );
+
+            /* NB: host calls made by the callback set the active memory. */
+            self.active_memory.set(memory);
+
+            match result {
+                Ok(Some(RuntimeValue::I64(result))) => {
+                    folded.insert(node, result as u64);
+                }
+                Ok(_otherwise) => {
+                    return Err(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    ))
+                }
+                Err(trap) => return Err(trap),
+            }
+        }
+
+        Ok(Ok(folded[handle.borrow()]))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Speculative proof search.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `speculate_begin` function.
+    #[inline]
+    fn speculate_begin(&self) {
+        self.kernel.borrow_mut().speculate_begin()
+    }
+
+    /// Lifting of the `speculate_end` function.
+    #[inline]
+    fn speculate_end(&self, keep: bool) -> Result<(), KernelErrorCode> {
+        self.kernel.borrow_mut().speculate_end(keep)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Type-former metadata.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `type_former_register_with_metadata` function.
+    #[inline]
+    fn type_former_register_with_metadata<T>(
+        &self,
+        arity: T,
+        metadata: TypeFormerMetadata,
+    ) -> Handle<tags::TypeFormer>
+    where
+        T: Into<usize> + Clone,
+    {
+        self.kernel
+            .borrow_mut()
+            .type_former_register_with_metadata(arity, metadata)
+    }
+
+    /// Lifting of the `type_former_lookup_by_name` function.
+    #[inline]
+    fn type_former_lookup_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Handle<tags::TypeFormer>, KernelErrorCode> {
+        self.kernel.borrow().type_former_lookup_by_name(name)
+    }
+
+    /// Lifting of the `constant_register_named` function.
+    #[inline]
+    fn constant_register_named<T>(
+        &self,
+        tau: T,
+        name: String,
+    ) -> Result<Handle<tags::Constant>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Type>> + Clone,
+    {
+        self.kernel.borrow_mut().constant_register_named(tau, name)
+    }
+
+    /// Lifting of the `constant_lookup_by_name` function.
+    #[inline]
+    fn constant_lookup_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Handle<tags::Constant>, KernelErrorCode> {
+        self.kernel.borrow().constant_lookup_by_name(name)
+    }
+
+    /// Lifting of the `batch_execute` function.
+    #[inline]
+    fn batch_execute(
+        &self,
+        batch: &Batch,
+    ) -> Result<Vec<u64>, KernelErrorCode> {
+        self.kernel.borrow_mut().batch_execute(batch)
+    }
+
+    /// Lifting of the `type_former_metadata` function.
+    #[inline]
+    fn type_former_metadata<T>(
+        &self,
+        handle: T,
+    ) -> Result<Option<TypeFormerMetadata>, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::TypeFormer>>,
+    {
+        self.kernel
+            .borrow()
+            .type_former_metadata(handle)
+            .map(|metadata| metadata.cloned())
+    }
+
+    /// Lifting of the `object_metadata_set` function.
+    #[inline]
+    fn object_metadata_set(
+        &self,
+        handle: usize,
+        key: String,
+        value: Vec<u8>,
+    ) -> Result<(), KernelErrorCode> {
+        self.kernel
+            .borrow_mut()
+            .object_metadata_set(handle, key, value)
+    }
+
+    /// Lifting of the `object_metadata_get` function.
+    #[inline]
+    fn object_metadata_get(
+        &self,
+        handle: usize,
+        key: &str,
+    ) -> Result<Vec<u8>, KernelErrorCode> {
+        self.kernel
+            .borrow()
+            .object_metadata_get(handle, key)
+            .map(|value| value.to_vec())
+    }
+
+    /// Lifting of the `object_metadata_keys` function.
+    #[inline]
+    fn object_metadata_keys(
+        &self,
+        handle: usize,
+    ) -> Result<Vec<String>, KernelErrorCode> {
+        self.kernel
+            .borrow()
+            .object_metadata_keys(handle)
+            .map(|keys| keys.into_iter().map(String::from).collect())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Reference counting.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `object_inc_ref` function.
+    #[inline]
+    fn object_inc_ref(&self, handle: usize) -> Result<(), KernelErrorCode> {
+        self.kernel.borrow_mut().object_inc_ref(handle)
+    }
+
+    /// Lifting of the `object_dec_ref` function.
+    #[inline]
+    fn object_dec_ref(&self, handle: usize) -> Result<bool, KernelErrorCode> {
+        self.kernel.borrow_mut().object_dec_ref(handle)
+    }
+
+    /// Lifting of the `object_delete` function.
+    #[inline]
+    fn object_delete(&self, handle: usize) -> Result<(), KernelErrorCode> {
+        self.kernel.borrow_mut().object_delete(handle)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // The scratch store.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `scratch_put` function.
+    #[inline]
+    fn scratch_put(&self, key: String, value: Vec<u8>) {
+        self.kernel.borrow_mut().scratch_put(key, value)
+    }
+
+    /// Lifting of the `scratch_get` function.
+    #[inline]
+    fn scratch_get(&self, key: &str) -> Result<Vec<u8>, KernelErrorCode> {
+        self.kernel
+            .borrow()
+            .scratch_get(key)
+            .map(|value| value.to_vec())
+    }
+
+    /// Lifting of the `scratch_delete` function.
+    #[inline]
+    fn scratch_delete(&self, key: &str) -> Result<(), KernelErrorCode> {
+        self.kernel
+            .borrow_mut()
+            .scratch_delete(key)
+            .map(|_value| ())
+    }
+
+    /// Lifting of the `ErrorDetail.Last` host call, which has no counterpart in
+    /// the kernel.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(KernelErrorCode::NoFailureRecorded)` if no host call has
+    /// failed since the guest began.
+    #[inline]
+    fn error_detail_last(&self) -> Result<LastError, KernelErrorCode> {
+        self.last_error
+            .clone()
+            .ok_or(KernelErrorCode::NoFailureRecorded)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Proof objects.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `theorem_export_remappable` function.
+    #[inline]
+    fn proof_object_export(
+        &self,
+        handles: Vec<Handle<tags::Theorem>>,
+    ) -> Result<Vec<u8>, KernelErrorCode> {
+        self.kernel.borrow().theorem_export_remappable(&handles)
+    }
+
+    /// Lifting of the `theorem_import_remapped` function.  The imported
+    /// sequents and the remapping table are flattened into a sequence of
+    /// words: the number of sequents, then each sequent as its number of
+    /// premisses, its premisses and its conclusion; the number of remapped
+    /// types and terms, then each as its exported and imported handles; and
+    /// the number of remapped theorems, then each as its exported handle and
+    /// the position of its sequent.
+    fn proof_object_import(
+        &self,
+        bytes: &[u8],
+    ) -> Result<Vec<u64>, KernelErrorCode> {
+        let (sequents, remapping) =
+            self.kernel.borrow_mut().theorem_import_remapped(bytes)?;
+
+        let mut words = vec![sequents.len() as u64];
+
+        for sequent in sequents {
+            words.push(sequent.premisses.len() as u64);
+            words.extend(sequent.premisses.iter().map(|h| **h as u64));
+            words.push(*sequent.conclusion as u64);
+        }
+
+        let objects: Vec<(usize, usize)> = remapping.objects().collect();
+
+        words.push(objects.len() as u64);
+        words.extend(
+            objects
+                .into_iter()
+                .flat_map(|(old, new)| vec![old as u64, new as u64]),
+        );
+
+        let theorems: Vec<(usize, usize)> = remapping.theorems().collect();
+
+        words.push(theorems.len() as u64);
+        words.extend(
+            theorems
+                .into_iter()
+                .flat_map(|(old, index)| vec![old as u64, index as u64]),
+        );
+
+        Ok(words)
+    }
+
+    /// Lifting of the `proof_trace_export` function, for a single theorem.
+    #[inline]
+    fn kernel_export_proof<T>(
+        &self,
+        handle: T,
+    ) -> Result<String, KernelErrorCode>
+    where
+        T: Borrow<Handle<tags::Theorem>>,
+    {
+        self.kernel.borrow().proof_trace_export(&[handle])
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Machine-state introspection.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Checks that the host's introspection policy lets the guest inspect
+    /// `fact`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(KernelErrorCode::IntrospectionRefused)` if it does not.
+    #[inline]
+    fn introspection_permitted(
+        &self,
+        fact: Introspection,
+    ) -> Result<(), KernelErrorCode> {
+        if self.introspection.contains(&fact) {
+            Ok(())
+        } else {
+            error!("Guest refused introspection of {}.", fact);
+
+            Err(KernelErrorCode::IntrospectionRefused)
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Rule policies.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Checks that the host's rule policy, if any, lets the guest make the
+    /// host call numbered `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(KernelErrorCode::PermissionDenied)` if the host call
+    /// applies an inference rule that the policy does not permit.
+    fn rule_permitted(&self, index: usize) -> Result<(), KernelErrorCode> {
+        let policy = match &self.rule_policy {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        match rule_name(index) {
+            Some(name) if !policy.permits(index) => {
+                error!("Guest refused inference rule {}.", name);
+
+                Err(KernelErrorCode::PermissionDenied)
+            }
+            _otherwise => Ok(()),
+        }
+    }
+
+    /// Returns the number of times that the guest has made the host call that
+    /// it imported as `name`, including the call being made, if `name` is the
+    /// `Introspect.CallCount` host call itself.  Host calls never imported
+    /// have never been made.
+    fn introspect_call_count(
+        &self,
+        name: &str,
+    ) -> Result<u64, KernelErrorCode> {
+        self.introspection_permitted(Introspection::CallCounts)?;
+
+        Ok(self
+            .call_imports
+            .borrow()
+            .iter()
+            .filter(|(_index, (imported, _tau))| imported == name)
+            .map(|(index, _import)| {
+                self.call_counts.get(index).cloned().unwrap_or(0)
+            })
+            .sum())
+    }
+
+    /// Returns the fuel consumed by the guest, one unit for every host call
+    /// made so far, including the call being made.
+    fn introspect_fuel_consumed(&self) -> Result<u64, KernelErrorCode> {
+        self.introspection_permitted(Introspection::FuelConsumed)?;
+
+        Ok(self.call_counts.values().sum())
+    }
+
+    /// Returns the size, in bytes, of the memory read and written by the host
+    /// call currently executing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(trap)` if that memory has not been registered with the
+    /// runtime state.
+    fn introspect_heap_size(
+        &self,
+    ) -> Result<Result<u64, KernelErrorCode>, RuntimeTrap> {
+        if let Err(e) = self.introspection_permitted(Introspection::HeapSize) {
+            return Ok(Err(e));
+        }
+
+        let Bytes(size) = self.memory()?.borrow().current_size().into();
+
+        Ok(Ok(size as u64))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Term views.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reserves the `size` bytes of the active memory starting at `base` for
+    /// term views, replacing any region reserved before.
+    #[inline]
+    fn term_view_reserve(&self, base: semantic_types::Pointer, size: u64) {
+        self.term_view_region
+            .set(Some((self.active_memory.get(), base, size)));
+    }
+
+    /// Copies a view of the term pointed-to by `handle` into the region
+    /// reserved for term views.  Returns the size of the view in bytes, or
+    /// zero if no view could be produced, alongside the outcome of the copy.
+    /// The view is laid out as described in the kernel's `term_view` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Ok(Err(KernelErrorCode::NoSuchTermRegistered))` if `handle`
+    /// does not point-to a registered term.
+    ///
+    /// Returns `Ok(Err(KernelErrorCode::InvalidPointer))` if no region has
+    /// been reserved in the active memory.
+    ///
+    /// Returns `Ok(Err(KernelErrorCode::InvalidLength))` along with the size
+    /// of the view, so that the guest may reserve a larger region and retry,
+    /// if the view does not fit in the region reserved.
+    ///
+    /// Returns `Err(trap)` if the region could not be written.
+    fn term_view_map<T>(
+        &self,
+        handle: T,
+    ) -> Result<(u64, Result<(), KernelErrorCode>), RuntimeTrap>
+    where
+        T: Borrow<Handle<tags::Term>>,
+    {
+        let view = match self.kernel.borrow().term_view(handle) {
+            Err(e) => return Ok((0, Err(e))),
+            Ok(view) => view,
+        };
+
+        let size = view.len() as u64;
+
+        match self.term_view_region.get() {
+            Some((memory, base, capacity))
+                if memory == self.active_memory.get() =>
+            {
+                if size > capacity {
+                    return Ok((size, Err(KernelErrorCode::InvalidLength)));
+                }
+
+                self.write_bytes(base, &view)?;
+
+                Ok((size, Ok(())))
+            }
+            _otherwise => Ok((size, Err(KernelErrorCode::InvalidPointer))),
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Linear arithmetic.
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Lifting of the `linear_arith_signature` function, returning the handles
+    /// of the type of the natural numbers, and of its zero, successor,
+    /// addition, `≤` and `<` constants, in that order.
+    #[cfg(feature = "linear-arith")]
+    #[inline]
+    fn linear_arith_signature(&self) -> [u64; 6] {
+        let signature = self.kernel.borrow_mut().linear_arith_signature();
+
+        [
+            **signature.num() as u64,
+            **signature.zero() as u64,
+            **signature.successor() as u64,
+            **signature.addition() as u64,
+            **signature.less_equal() as u64,
+            **signature.less_than() as u64,
+        ]
+    }
+
+    /// Lifting of the `theorem_register_linear_arith` function.
+    #[cfg(feature = "linear-arith")]
+    #[inline]
+    fn theorem_register_linear_arith<T>(
+        &self,
+        goal: T,
+    ) -> Result<Handle<tags::Theorem>, KernelErrorCode>
+    where
+        T: Into<Handle<tags::Term>> + Clone,
+    {
+        self.kernel.borrow_mut().theorem_register_linear_arith(goal)
+    }
+
+    /// Reads a UTF-8 string of `byte_count` bytes from the WASM guest's memory
+    /// starting at the provided `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(RuntimeTrap::MemoryNotRegistered)` if the WASM guest's
+    /// memory module has not been registered with the runtime state.
+    ///
+    /// Returns `Err(RuntimeTrap::MemoryReadFailed)` if the read from memory at
+    /// address, `address`, failed.
+    ///
+    /// Returns `Ok(Err(KernelErrorCode::InvalidString))` if the bytes read are
+    /// not valid UTF-8.
+    fn read_string<T, U>(
+        &self,
+        address: T,
+        byte_count: U,
+    ) -> Result<Result<String, KernelErrorCode>, RuntimeTrap>
+    where
+        T: Into<semantic_types::Pointer>,
+        U: Into<usize>,
+    {
+        let bytes = self.read_bytes(address, byte_count)?;
+
+        Ok(String::from_utf8(bytes)
+            .map_err(|_e| KernelErrorCode::InvalidString))
+    }
+
+    /// Writes the string, `string`, to the result buffer at `base`, whose
+    /// capacity in bytes is read from `length_ptr`, following the protocol of
+    /// `Kernel.Identify`: the length of the string is written back to
+    /// `length_ptr`, and nothing is written into the buffer if the string does
+    /// not fit.
+    #[inline]
+    fn write_string_buffer(
+        &self,
+        base: semantic_types::Pointer,
+        length_ptr: semantic_types::Pointer,
+        string: &str,
+    ) -> Result<(), RuntimeTrap> {
+        self.write_bytes_buffer(base, length_ptr, string.as_bytes())
+    }
+
+    /// Writes `bytes` to the result buffer at `base`, whose capacity in bytes
+    /// is read from `length_ptr`, as `write_string_buffer`.
+    fn write_bytes_buffer(
+        &self,
+        base: semantic_types::Pointer,
+        length_ptr: semantic_types::Pointer,
+        bytes: &[u8],
+    ) -> Result<(), RuntimeTrap> {
+        let capacity = self.read_u64(length_ptr)?;
+
+        self.write_u64(length_ptr, bytes.len() as u64)?;
+
+        if bytes.len() as u64 <= capacity {
+            self.write_bytes(base, bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ABI binding.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Dispatches on an ABI host-call number, and calls the respective function on
+/// the machine's runtime state, recording the call if tracing, or the
+/// history, is enabled.
+impl Externals for WasmiRuntimeState {
+    fn invoke_index(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        if self.abort_requested() && !self.cooperative.get() {
+            info!(
+                "Stopping guest at host call {}, as asked by the host.",
+                index
+            );
+
+            return Err(runtime_trap::host_trap(RuntimeTrap::Aborted));
+        }
+
+        *self.call_counts.entry(index).or_insert(0) += 1;
+
+        /* NB: budgets on the kernel's own host calls are keyed by the index of
+         * the host call, and are charged before its arguments are even looked
+         * at, so that a call made beyond its budget has no effect.
+         */
+        #[cfg(feature = "budgets")]
+        if self
+            .kernel
+            .borrow_mut()
+            .budget_charge(index as Service)
+            .is_err()
+        {
+            error!("Host call {} made beyond its budget.", index);
+
+            return Err(runtime_trap::host_trap(RuntimeTrap::BudgetExhausted));
+        }
+
+        /* NB: the kernel's error detail only ever explains the failure of the
+         * host call immediately preceding `Kernel.ErrorDetail`.
+         */
+        if index != ABI_KERNEL_ERROR_DETAIL_INDEX {
+            self.kernel.borrow_mut().kernel_clear_error_detail();
+        }
+
+        self.active_memory.set(
+            self.resolved_call_memories
+                .borrow()
+                .get(&index)
+                .cloned()
+                .unwrap_or(DEFAULT_MEMORY),
+        );
+
+        let result = self.dispatch_guarded(index, args);
+
+        /* NB: the guest's progress callback runs within the kernel, which
+         * cannot be interrupted, so any trap that it raises is raised once the
+         * kernel operation that called it has returned.
+         */
+        if let Some(trap) = (*self.progress_trap).borrow_mut().take() {
+            return Err(trap);
+        }
+
+        result
+    }
+}
+
+impl WasmiRuntimeState {
+    /// Dispatches on an ABI host-call number, and calls the respective function
+    /// on the machine's runtime state, recording the cost of the call if costs
+    /// are being recorded.  The memory read and written by the host call must
+    /// already be active.
+    fn dispatch(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        if let Some(cost) = self.cost.get_mut() {
+            cost.begin(index);
+        }
+
+        let result = self.dispatch_checked(index, args);
+
+        if let Some(cost) = self.cost.get_mut() {
+            cost.end();
+        }
+
+        self.record_failure(index, &result);
+
+        result
+    }
+
+    /// Records the call with index `index` as the most recent failure, if its
+    /// result, `result`, is an error code other than `Success`.  Calls that
+    /// return booleans never fail, and neither does `ErrorDetail.Last`, whose
+    /// own failure would otherwise displace the failure that it reports.
+    fn record_failure(
+        &mut self,
+        index: usize,
+        result: &Result<Option<RuntimeValue>, Trap>,
+    ) {
+        if index == ABI_ERROR_DETAIL_LAST_INDEX || is_predicate_index(index) {
+            return;
+        }
+
+        if let Ok(Some(RuntimeValue::I32(status))) = result {
+            match KernelErrorCode::try_from(*status) {
+                Ok(KernelErrorCode::Success) | Err(()) => (),
+                Ok(code) => {
+                    self.last_error = Some(LastError {
+                        call: index,
+                        code,
+                        detail: self.kernel.borrow().kernel_error_detail(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Makes the system call with index `index`, as `dispatch`, once it has
+    /// been checked against the host's rule policy, and its arguments, `args`,
+    /// have been validated.
+    fn dispatch_checked(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        if let Err(e) = self.rule_permitted(index) {
+            return Ok(Some(RuntimeValue::I32(e.into())));
+        }
+
+        if let Err(e) = self.validate_arguments(index, &args) {
+            return Ok(Some(RuntimeValue::I32(e.into())));
+        }
+
+        let result = self.dispatch_marshalled(index, args);
+
+        Self::catch_out_of_range(index, result)
+    }
+
+    /// Reports a trap, raised by an integer argument passed to the system call
+    /// with index `index` that could not be narrowed for the host, to the
+    /// guest as `ErrorCode::ArgumentOutOfRange`.  Every other `result` of the
+    /// system call is returned unchanged.
+    fn catch_out_of_range(
+        index: usize,
+        result: Result<Option<RuntimeValue>, Trap>,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        match result {
+            Err(trap) if Self::is_out_of_range(&trap) => {
+                error!(
+                    "Argument out of range passed to system call {}.",
+                    index
+                );
+
+                Ok(Some(RuntimeValue::I32(
+                    KernelErrorCode::ArgumentOutOfRange.into(),
+                )))
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    /// Returns `true` iff `trap` was raised by an integer argument, passed to
+    /// a system call, that could not be narrowed for the host.
+    #[inline]
+    fn is_out_of_range(trap: &Trap) -> bool {
+        match trap.kind() {
+            TrapKind::Host(error) => {
+                error.downcast_ref::<OutOfRange>().is_some()
+            }
+            _otherwise => false,
+        }
+    }
+
+    /// Unmarshalls the arguments, `args`, of the system call with index
+    /// `index`, and calls the respective function on the machine's runtime
+    /// state, marshalling its results back.  Integer arguments are narrowed
+    /// with `narrow`, never with an `as` cast, so that no argument is
+    /// silently truncated.  The arguments must already have been validated.
+    fn dispatch_marshalled(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        match index {
+            ABI_TYPE_FORMER_RESOLVE_INDEX => {
+                let handle = args.nth::<semantic_types::Handle>(0);
+                let result_address = args.nth::<semantic_types::Pointer>(1);
+
+                let handle = Handle::from(narrow(handle)?);
+
+                let arity = match self.type_former_resolve(&handle) {
+                    None if self.handle_is_stale(&handle) => {
+                        return Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::StaleHandle.into(),
+                        )))
+                    }
+                    None => {
+                        return Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::NoSuchTypeFormerRegistered.into(),
+                        )))
+                    }
+                    Some(arity) => arity,
+                };
+
+                self.write_u64(result_address, arity as u64)?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_TYPE_FORMER_IS_REGISTERED_INDEX => {
+                let handle = args.nth::<semantic_types::Handle>(0);
+                let result = self
+                    .type_former_is_registered(Handle::from(narrow(handle)?));
+
+                Ok(Some(RuntimeValue::I32(result.into())))
+            }
+            ABI_TYPE_FORMER_REGISTER_INDEX => {
+                let arity = args.nth::<semantic_types::Arity>(0);
+                let result = self.type_former_register(narrow(arity)?);
+
+                Ok(Some(RuntimeValue::I64(*result as i64)))
+            }
+            ABI_TYPE_REGISTER_VARIABLE_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let result = self.type_register_variable(name);
+
+                Ok(Some(RuntimeValue::I64(*result as i64)))
+            }
+            ABI_TYPE_REGISTER_COMBINATION_INDEX => {
+                let former_handle: Handle<tags::TypeFormer> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let argument_base = args.nth::<semantic_types::Pointer>(1);
+                let argument_length = args.nth::<semantic_types::Size>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let arguments =
+                    self.read_handles(argument_base, narrow(argument_length)?)?;
+
+                match self.type_register_combination(former_handle, arguments) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_REGISTER_FUNCTION_INDEX => {
+                let domain_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let range_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
                 match self.type_register_function(domain_handle, range_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_IS_REGISTERED_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                Ok(Some(RuntimeValue::I32(
+                    self.type_is_registered(type_handle).into(),
+                )))
+            }
+            ABI_TYPE_SPLIT_VARIABLE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.type_split_variable(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_u64(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_SPLIT_COMBINATION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let former_result_ptr = args.nth::<semantic_types::Pointer>(1);
+                let arguments_result_ptr =
+                    args.nth::<semantic_types::Pointer>(2);
+                let arguments_length_result_ptr =
+                    args.nth::<semantic_types::Pointer>(3);
+
+                let written = self.type_split_combination(
+                    type_handle,
+                    |former, arguments| {
+                        self.write_handle(former_result_ptr, former.clone())?;
+                        self.write_u64(
+                            arguments_length_result_ptr,
+                            arguments.len() as u64,
+                        )?;
+                        self.write_handles(arguments_result_ptr, arguments)
+                    },
+                );
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_SPLIT_FUNCTION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let domain_result_ptr = args.nth::<semantic_types::Pointer>(1);
+                let range_result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.type_split_function(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((domain_handle, range_handle)) => {
+                        self.write_handle(domain_result_ptr, domain_handle)?;
+                        self.write_handle(range_result_ptr, range_handle)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_TEST_VARIABLE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.type_test_variable(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_TEST_COMBINATION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.type_test_combination(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_TEST_FUNCTION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.type_test_function(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_SIZE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.type_size(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_u64(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_VARIABLES_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let variable_result_ptr =
+                    args.nth::<semantic_types::Pointer>(1);
+                let variable_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.type_variables(type_handle, |result| {
+                    self.write_u64s(
+                        variable_result_ptr,
+                        result.iter().map(|n| **n),
+                    )?;
+                    self.write_u64(variable_len_ptr, result.len() as u64)
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_SUBSTITUTE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
+                let rng_len = args.nth::<semantic_types::Size>(4);
+                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst =
+                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+
+                match self.type_substitute(type_handle, subst) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_CONSTANT_REGISTER_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.constant_register(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_CONSTANT_IS_REGISTERED_INDEX => {
+                let constant_handle: Handle<tags::Constant> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+
+                Ok(Some(RuntimeValue::I32(
+                    self.constant_is_registered(constant_handle).into(),
+                )))
+            }
+            ABI_CONSTANT_RESOLVE_INDEX => {
+                let constant_handle: Handle<tags::Constant> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.constant_resolve(constant_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_IS_REGISTERED_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+
+                let result = self.term_is_registered(term_handle);
+
+                Ok(Some(RuntimeValue::I32(if result { 0 } else { 1 })))
+            }
+            ABI_TERM_REGISTER_VARIABLE_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_variable(name, type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_CONSTANT_INDEX => {
+                let constant_handle: Handle<tags::Constant> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
+                let rng_len = args.nth::<semantic_types::Size>(4);
+                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst =
+                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+
+                match self.term_register_constant(constant_handle, subst) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_APPLICATION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_application(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_LAMBDA_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let body_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_register_lambda(name, type_handle, body_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_NEGATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_register_negation(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_CONJUNCTION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_conjunction(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_DISJUNCTION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_disjunction(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_IMPLICATION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_implication(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_EQUALITY_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_equality(left_handle, right_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_FORALL_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let body_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_register_forall(name, type_handle, body_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_EXISTS_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let body_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_register_exists(name, type_handle, body_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_VARIABLE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_variable(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_type_ptr, tau)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_CONSTANT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_const_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_constant(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((constant, tau)) => {
+                        self.write_handle(result_const_ptr, constant)?;
+                        self.write_handle(result_type_ptr, tau)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_APPLICATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_application(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_LAMBDA_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_lambda(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau, body)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_type_ptr, tau)?;
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_NEGATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_split_negation(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(body) => {
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_CONJUNCTION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_conjunction(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_DISJUNCTION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_disjunction(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_IMPLICATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_implication(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_EQUALITY_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_equality(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_FORALL_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_forall(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau, body)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_type_ptr, tau)?;
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_EXISTS_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_exists(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau, body)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_type_ptr, tau)?;
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_VARIABLE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_variable(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_CONSTANT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_constant(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_APPLICATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_application(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_LAMBDA_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_lambda(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_NEGATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_negation(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_CONJUNCTION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_conjunction(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_DISJUNCTION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_disjunction(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_IMPLICATION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_implication(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_EQUALITY_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_equality(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_FORALL_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_forall(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_EXISTS_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_exists(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_FREE_VARIABLES_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_base_ptr =
+                    args.nth::<semantic_types::Pointer>(1);
+                let result_name_len_ptr =
+                    args.nth::<semantic_types::Pointer>(2);
+                let result_type_base_ptr =
+                    args.nth::<semantic_types::Pointer>(3);
+                let result_type_len_ptr =
+                    args.nth::<semantic_types::Pointer>(4);
+
+                let written = self.term_free_variables(term_handle, |result| {
+                    self.write_u64(result_name_len_ptr, result.len() as u64)?;
+                    self.write_u64s(
+                        result_name_base_ptr,
+                        result.iter().map(|(n, _t)| **n),
+                    )?;
+                    self.write_u64(result_type_len_ptr, result.len() as u64)?;
+                    self.write_handles(
+                        result_type_base_ptr,
+                        result.iter().map(|(_n, t)| *t),
+                    )
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SUBSTITUTE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let type_ptr = args.nth::<semantic_types::Pointer>(3);
+                let type_len = args.nth::<semantic_types::Size>(4);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(5);
+                let rng_len = args.nth::<semantic_types::Size>(6);
+                let result_ptr = args.nth::<semantic_types::Pointer>(7);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let types = self.read_handles(type_ptr, narrow(type_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let substitution: Vec<((Name, Handle<tags::Type>), _)> =
+                    domains.into_iter().zip(types).zip(ranges).collect();
+
+                match self.term_substitute(term_handle, substitution) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_VARIABLES_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.term_type_variables(term_handle, |result| {
+                    self.write_u64(result_len_ptr, result.len() as u64)?;
+                    self.write_u64s(result_base_ptr, result.iter().map(|n| **n))
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_SUBSTITUTE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
+                let rng_len = args.nth::<semantic_types::Size>(4);
+                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst =
+                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+
+                match self.term_type_substitute(term_handle, subst) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_INFER_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_type_infer(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_IS_PROPOSITION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_type_is_proposition(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_IS_REGISTERED_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result = self.theorem_is_registered(theorem_handle);
+
+                Ok(Some(RuntimeValue::I32(result.into())))
+            }
+            ABI_THEOREM_SPLIT_CONCLUSION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_split_conclusion(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_SIZE_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                let size = self
+                    .theorem_split_hypotheses(theorem_handle, |result| {
+                        result.len()
+                    });
+
+                match size {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(size) => {
+                        self.write_u64(result_ptr, size as u64)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_SPLIT_HYPOTHESES_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written =
+                    self.theorem_split_hypotheses(theorem_handle, |result| {
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+                        self.write_handles(result_base_ptr, result)
+                    });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_ASSUMPTION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_assumption(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_WEAKEN_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_weaken(term_handle, theorem_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_reflexivity(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_SYMMETRY_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_symmetry(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self
+                    .theorem_register_transitivity(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_BETA_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_beta(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_ETA_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_eta(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_APPLICATION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self
+                    .theorem_register_application(left_handle, right_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_LAMBDA_INDEX => {
+                let name: Name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_lambda(
+                    name,
+                    type_handle,
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let type_ptr = args.nth::<semantic_types::Pointer>(3);
+                let type_len = args.nth::<semantic_types::Size>(4);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(5);
+                let rng_len = args.nth::<semantic_types::Size>(6);
+                let result_ptr = args.nth::<semantic_types::Pointer>(7);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let types = self.read_handles(type_ptr, narrow(type_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst: Vec<((Name, Handle<tags::Type>), _)> =
+                    domains.into_iter().zip(types).zip(ranges).collect();
+
+                match self.theorem_register_substitute(theorem_handle, subst) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
+                let dom_len = args.nth::<semantic_types::Size>(2);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
+                let rng_len = args.nth::<semantic_types::Size>(4);
+                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst =
+                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+
+                match self
+                    .theorem_register_type_substitute(theorem_handle, subst)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX => {
+                let result_ptr = args.nth::<semantic_types::Pointer>(0);
+
+                match self.theorem_register_truth_introduction() {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_falsity_elimination(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_conjunction_introduction(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_conjunction_left_elimination(
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_conjunction_right_elimination(
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let mid_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_disjunction_elimination(
+                    left_handle,
+                    mid_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_disjunction_left_introduction(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_disjunction_right_introduction(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_implication_introduction(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_implication_elimination(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_iff_introduction(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_iff_left_elimination(theorem_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_negation_introduction(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_negation_elimination(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX => {
+                let name: Name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_forall_introduction(
+                    name,
+                    type_handle,
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_forall_elimination(
+                    theorem_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let witness_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_exists_introduction(
+                    theorem_handle,
+                    term_handle,
+                    witness_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX => {
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let variable_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_exists_elimination(
+                    left_handle,
+                    variable_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_KERNEL_ENUMERATE_INDEX => {
+                let kind = args.nth::<semantic_types::Kind>(0);
+                let cursor = args.nth::<semantic_types::Handle>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+                let next_cursor_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                let kind = match Kind::try_from(kind) {
+                    Err(_e) => {
+                        return Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::NoSuchKind as i32,
+                        )))
+                    }
+                    Ok(kind) => kind,
+                };
+
+                /* NB: the length pointer is read for the capacity of the
+                 * result buffer, and then overwritten with the number of
+                 * handles actually written to it.
+                 */
+                let capacity = self.read_u64(result_len_ptr)?;
+
+                let (handles, next) = self.kernel_enumerate(
+                    kind,
+                    narrow(cursor)?,
+                    narrow(capacity)?,
+                );
+
+                self.write_u64(result_len_ptr, handles.len() as u64)?;
+                self.write_u64s(
+                    result_base_ptr,
+                    handles.iter().map(|h| *h as u64),
+                )?;
+                self.write_u64(
+                    next_cursor_ptr,
+                    next.map_or(ENUMERATION_COMPLETE, |n| n as u64),
+                )?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_KERNEL_IDENTIFY_INDEX => {
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(0);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                let identity = self.kernel_identify().to_string();
+
+                /* NB: as with `Hypotheses.Resolve`, the length pointer holds
+                 * the capacity of the result buffer on entry and the length of
+                 * the identity on exit, and nothing is written to the buffer
+                 * if the identity does not fit.
+                 */
+                let capacity = self.read_u64(result_len_ptr)?;
+
+                self.write_u64(result_len_ptr, identity.len() as u64)?;
+
+                if identity.len() as u64 <= capacity {
+                    self.write_bytes(result_base_ptr, identity.as_bytes())?;
+                }
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_KERNEL_ERROR_DETAIL_INDEX => {
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(0);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                /* NB: a failure that recorded no detail is reported as having
+                 * an empty one.
+                 */
+                let detail = self.kernel_error_detail().unwrap_or_default();
+
+                self.write_string_buffer(
+                    result_base_ptr,
+                    result_len_ptr,
+                    &detail,
+                )?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_HYPOTHESES_INTERN_INDEX => {
+                let hyps_base = args.nth::<semantic_types::Pointer>(0);
+                let hyps_length = args.nth::<semantic_types::Size>(1);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let hyps: Vec<Handle<tags::Term>> =
+                    self.read_handles(hyps_base, narrow(hyps_length)?)?;
+
+                match self.hypotheses_intern(hyps) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_HYPOTHESES_RESOLVE_INDEX => {
+                let hyps_handle: Handle<tags::Hypotheses> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.hypotheses_resolve(
+                    hyps_handle,
+                    |result| -> Result<(), RuntimeTrap> {
+                        /* NB: the length pointer is read for the capacity of the
+                         * result buffer, and then overwritten with the number of
+                         * hypotheses.  If the hypotheses do not fit then nothing is
+                         * written to the buffer, and the caller should try again
+                         * with a larger one.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+
+                        if result.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, result)?;
+                        }
+
+                        Ok(())
+                    },
+                );
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_CONDITIONAL_INDEX => {
+                let condition_handle: Handle<tags::Term> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_register_conditional(
+                    condition_handle,
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_LET_INDEX => {
+                let function_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let argument_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_let(function_handle, argument_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_CONDITIONAL_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_condition_ptr =
+                    args.nth::<semantic_types::Pointer>(1);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_conditional(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((condition, left, right)) => {
+                        self.write_handle(result_condition_ptr, condition)?;
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_LET_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_function_ptr =
+                    args.nth::<semantic_types::Pointer>(1);
+                let result_argument_ptr =
+                    args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_let(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((function, argument)) => {
+                        self.write_handle(result_function_ptr, function)?;
+                        self.write_handle(result_argument_ptr, argument)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_CONDITIONAL_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_conditional(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_LET_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_let(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX => {
+                let condition_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let left_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let right_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_conditional_congruence(
+                    condition_handle,
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX => {
+                let function_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let argument_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_let_congruence(
+                    function_handle,
+                    argument_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_PAIR_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_pair(left_handle, right_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_FIRST_INDEX => {
+                let pair_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_register_first(pair_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_SECOND_INDEX => {
+                let pair_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_register_second(pair_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_PAIR_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_pair(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((left, right)) => {
+                        self.write_handle(result_left_ptr, left)?;
+                        self.write_handle(result_right_ptr, right)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_PAIR_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_pair(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_first_projection(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_second_projection(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX => {
+                let pair_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_surjective_pairing(pair_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_MEMBERSHIP_INDEX => {
+                let element_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let set_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_register_membership(element_handle, set_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_REGISTER_COMPREHENSION_INDEX => {
+                let name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let body_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_register_comprehension(
+                    name,
+                    type_handle,
+                    body_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_MEMBERSHIP_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_element_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_set_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_split_membership(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((element, set)) => {
+                        self.write_handle(result_element_ptr, element)?;
+                        self.write_handle(result_set_ptr, set)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_COMPREHENSION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_tau_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_comprehension(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau, body)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_tau_ptr, tau)?;
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_MEMBERSHIP_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_membership(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TEST_COMPREHENSION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.term_test_comprehension(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX => {
+                let element_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let set_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_membership_definition(
+                    element_handle,
+                    set_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX => {
+                let set_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_comprehension_definition(set_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_empty_set_definition(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self
+                    .theorem_register_universal_set_definition(type_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_union_definition(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_intersection_definition(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.theorem_register_subset_definition(
+                    left_handle,
+                    right_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_CHALLENGE_DISCHARGE_INDEX => {
+                let service = args.nth::<semantic_types::Service>(0);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let expiry = args.nth::<semantic_types::Expiry>(2);
+                let uses = args.nth::<semantic_types::Count>(3);
+                let result_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                match self.challenge_discharge(
+                    service,
+                    theorem_handle,
+                    expiry,
+                    uses,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_CAPABILITY_PRESENT_INDEX => {
+                let capability_handle: Handle<tags::Capability> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let service = args.nth::<semantic_types::Service>(1);
+
+                match self.capability_present(capability_handle, service) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_TERM_FOLD_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let callback = args.nth::<semantic_types::TableIndex>(1);
+                let context = args.nth::<semantic_types::Context>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_fold(term_handle, callback, context)? {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_u64(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_PROOF_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_proof(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_PROOF_SPLIT_INDEX => {
+                let proof_handle: Handle<tags::Proof> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_rule_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_theorem_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.proof_split(proof_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((rule, theorem)) => {
+                        self.write_u64(result_rule_ptr, rule)?;
+                        self.write_handle(result_theorem_ptr, theorem)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_PROOF_SPLIT_ARGUMENTS_INDEX => {
+                let proof_handle: Handle<tags::Proof> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.proof_split_arguments(
+                    proof_handle,
+                    |result| -> Result<(), RuntimeTrap> {
+                        /* NB: as for `Hypotheses.Resolve`, the length pointer
+                         * is read for the capacity of the result buffer, and
+                         * then overwritten with the number of arguments.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+
+                        if result.len() as u64 <= capacity {
+                            self.write_u64s(
+                                result_base_ptr,
+                                result.iter().map(ProofArgument::raw),
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                );
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_PROOF_CHECK_INDEX => {
+                let proof_handle: Handle<tags::Proof> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+
+                match self.proof_check(proof_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_THEOREM_STATEMENT_HASH_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_statement_hash(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_u64(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_STATEMENT_AS_TERM_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_statement_as_term(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_QUOTIENT_REGISTER_INDEX => {
+                let relation_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let reflexivity_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let symmetry_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let transitivity_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(3))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                match self.quotient_register(
+                    relation_handle,
+                    reflexivity_handle,
+                    symmetry_handle,
+                    transitivity_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_QUOTIENT_SPLIT_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let relation_ptr = args.nth::<semantic_types::Pointer>(1);
+                let class_ptr = args.nth::<semantic_types::Pointer>(2);
+                let class_equality_ptr = args.nth::<semantic_types::Pointer>(3);
+                let induction_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                match self.quotient_split(type_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((relation, class, class_equality, induction)) => {
+                        self.write_handle(relation_ptr, relation)?;
+                        self.write_handle(class_ptr, class)?;
+                        self.write_handle(class_equality_ptr, class_equality)?;
+                        self.write_handle(induction_ptr, induction)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_QUOTIENT_LIFT_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let function_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let respect_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let constant_ptr = args.nth::<semantic_types::Pointer>(3);
+                let equation_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                match self.quotient_lift(
+                    type_handle,
+                    function_handle,
+                    respect_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((constant, equation)) => {
+                        self.write_handle(constant_ptr, constant)?;
+                        self.write_handle(equation_ptr, equation)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_QUOTIENT_TRANSFER_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.quotient_transfer(type_handle, theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_SUBSTITUTION_REGISTER_TYPE_INDEX => {
+                let dom_ptr = args.nth::<semantic_types::Pointer>(0);
+                let dom_len = args.nth::<semantic_types::Size>(1);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(2);
+                let rng_len = args.nth::<semantic_types::Size>(3);
+                let result_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let subst: Vec<(Name, Handle<tags::Type>)> =
+                    domains.into_iter().zip(ranges).collect();
+
+                match self.substitution_register_type(subst) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_SUBSTITUTION_REGISTER_TERM_INDEX => {
+                let dom_ptr = args.nth::<semantic_types::Pointer>(0);
+                let dom_len = args.nth::<semantic_types::Size>(1);
+                let type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let type_len = args.nth::<semantic_types::Size>(3);
+                let rng_ptr = args.nth::<semantic_types::Pointer>(4);
+                let rng_len = args.nth::<semantic_types::Size>(5);
+                let result_ptr = args.nth::<semantic_types::Pointer>(6);
+
+                let domains = self.read_u64s(dom_ptr, narrow(dom_len)?)?;
+                let types = self.read_handles(type_ptr, narrow(type_len)?)?;
+                let ranges = self.read_handles(rng_ptr, narrow(rng_len)?)?;
+
+                let substitution: TermSubstitution =
+                    domains.into_iter().zip(types).zip(ranges).collect();
+
+                match self.substitution_register_term(substitution) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_SUBSTITUTE_WITH_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let subst_handle: Handle<tags::Substitution> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.type_substitute_with(type_handle, subst_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SUBSTITUTE_WITH_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let subst_handle: Handle<tags::Substitution> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_substitute_with(term_handle, subst_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let subst_handle: Handle<tags::Substitution> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.term_type_substitute_with(term_handle, subst_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX => {
+                let name: Name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_forall_congruence(
+                    name,
+                    type_handle,
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX => {
+                let name: Name = args.nth::<semantic_types::Name>(0);
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.theorem_register_exists_congruence(
+                    name,
+                    type_handle,
+                    theorem_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "budgets")]
+            ABI_BUDGET_REMAINING_INDEX => {
+                let service = args.nth::<semantic_types::Service>(0);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.budget_remaining(service) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(remaining) => {
+                        self.write_u64(result_ptr, remaining)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "budgets")]
+            ABI_BUDGET_RESOURCE_INDEX => {
+                let service = args.nth::<semantic_types::Service>(0);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.budget_resource(service) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(not(feature = "budgets"))]
+            index if is_budget_index(index) => Ok(Some(RuntimeValue::I32(
+                KernelErrorCode::NoSuchFunction.into(),
+            ))),
+            ABI_KERNEL_FEATURE_SUPPORTED_INDEX => {
+                let feature = args.nth::<semantic_types::Feature>(0);
+                let result = self.kernel_feature_supported(feature);
+
+                Ok(Some(RuntimeValue::I32(result.into())))
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_IS_REGISTERED_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result = self.sequent_is_registered(sequent_handle);
+
+                Ok(Some(RuntimeValue::I32(result.into())))
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_SPLIT_PREMISSES_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.sequent_split_premisses(
+                    sequent_handle,
+                    |result| -> Result<(), RuntimeTrap> {
+                        /* NB: as for `Hypotheses.Resolve`, the length pointer
+                         * is read for the capacity of the result buffer, and
+                         * then overwritten with the number of premisses.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+
+                        if result.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, result)?;
+                        }
+
+                        Ok(())
+                    },
+                );
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let written = self.sequent_split_conclusions(
+                    sequent_handle,
+                    |result| -> Result<(), RuntimeTrap> {
+                        /* NB: as for `Hypotheses.Resolve`, the length pointer
+                         * is read for the capacity of the result buffer, and
+                         * then overwritten with the number of conclusions.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+
+                        if result.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, result)?;
+                        }
+
+                        Ok(())
+                    },
+                );
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_AXIOM_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.sequent_register_axiom(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_THEOREM_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.sequent_register_theorem(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self
+                    .sequent_register_weaken_left(sequent_handle, term_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self
+                    .sequent_register_weaken_right(sequent_handle, term_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_CUT_INDEX => {
+                let left_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.sequent_register_cut(
+                    left_handle,
+                    right_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self
+                    .sequent_register_negation_left(sequent_handle, term_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.sequent_register_negation_right(
+                    sequent_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.sequent_register_conjunction_left(
+                    sequent_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX => {
+                let left_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.sequent_register_conjunction_right(
+                    left_handle,
+                    right_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX => {
+                let left_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.sequent_register_disjunction_left(
+                    left_handle,
+                    right_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.sequent_register_disjunction_right(
+                    sequent_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX => {
+                let left_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Sequent> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.sequent_register_implication_left(
+                    left_handle,
+                    right_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(feature = "sequents")]
+            ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX => {
+                let sequent_handle: Handle<tags::Sequent> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.sequent_register_implication_right(
+                    sequent_handle,
+                    term_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            #[cfg(not(feature = "sequents"))]
+            index if is_sequent_index(index) => Ok(Some(RuntimeValue::I32(
+                KernelErrorCode::NoSuchFunction.into(),
+            ))),
+            ABI_SPECULATE_BEGIN_INDEX => {
+                self.speculate_begin();
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_SPECULATE_END_INDEX => {
+                let keep = args.nth::<semantic_types::Boolean>(0) != 0;
+
+                match self.speculate_end(keep) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX => {
+                let arity = args.nth::<semantic_types::Arity>(0);
+                let name_base = args.nth::<semantic_types::Pointer>(1);
+                let name_length = args.nth::<semantic_types::Size>(2);
+                let documentation_base = args.nth::<semantic_types::Pointer>(3);
+                let documentation_length = args.nth::<semantic_types::Size>(4);
+                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let name =
+                    match self.read_string(name_base, narrow(name_length)?)? {
+                        Ok(name) => name,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+                let documentation = match self.read_string(
+                    documentation_base,
+                    narrow(documentation_length)?,
+                )? {
+                    Ok(documentation) => documentation,
+                    Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                };
+
+                let result = self.type_former_register_with_metadata(
+                    narrow(arity)?,
+                    TypeFormerMetadata::new(name, documentation),
+                );
+
+                self.write_handle(result_ptr, result)?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_CONSTANT_REGISTER_NAMED_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let name_base = args.nth::<semantic_types::Pointer>(1);
+                let name_length = args.nth::<semantic_types::Size>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let name =
+                    match self.read_string(name_base, narrow(name_length)?)? {
+                        Ok(name) => name,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.constant_register_named(type_handle, name) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_CONSTANT_LOOKUP_BY_NAME_INDEX => {
+                let name_base = args.nth::<semantic_types::Pointer>(0);
+                let name_length = args.nth::<semantic_types::Size>(1);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let name =
+                    match self.read_string(name_base, narrow(name_length)?)? {
+                        Ok(name) => name,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.constant_lookup_by_name(&name) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX => {
+                let name_base = args.nth::<semantic_types::Pointer>(0);
+                let name_length = args.nth::<semantic_types::Size>(1);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                let name =
+                    match self.read_string(name_base, narrow(name_length)?)? {
+                        Ok(name) => name,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.type_former_lookup_by_name(&name) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_KERNEL_BATCH_EXECUTE_INDEX => {
+                let commands_base = args.nth::<semantic_types::Pointer>(0);
+                let commands_length = args.nth::<semantic_types::Size>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let commands =
+                    self.read_bytes(commands_base, narrow(commands_length)?)?;
+
+                let batch = match Batch::decode(&commands) {
+                    Ok(batch) => batch,
+                    Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                };
+
+                /* NB: as for `Hypotheses.Resolve`, the length pointer is read
+                 * for the capacity of the result buffer, and then overwritten
+                 * with the number of results.  If the results do not fit then
+                 * nothing is executed, and the caller should try again with a
+                 * larger buffer.
+                 */
+                let capacity = self.read_u64(result_len_ptr)?;
+
+                self.write_u64(result_len_ptr, batch.results() as u64)?;
+
+                if batch.results() as u64 > capacity {
+                    return Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    )));
+                }
+
+                match self.batch_execute(&batch) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(results) => {
+                        self.write_u64s(result_base_ptr, results)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_FORMER_METADATA_INDEX => {
+                let handle: Handle<tags::TypeFormer> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let name_base = args.nth::<semantic_types::Pointer>(1);
+                let name_length_ptr = args.nth::<semantic_types::Pointer>(2);
+                let documentation_base = args.nth::<semantic_types::Pointer>(3);
+                let documentation_length_ptr =
+                    args.nth::<semantic_types::Pointer>(4);
+
+                /* NB: type-formers registered without metadata are reported as
+                 * having an empty name and documentation string.
+                 */
+                match self.type_former_metadata(handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(metadata) => {
+                        let metadata = metadata.unwrap_or_default();
+
+                        self.write_string_buffer(
+                            name_base,
+                            name_length_ptr,
+                            metadata.name(),
+                        )?;
+                        self.write_string_buffer(
+                            documentation_base,
+                            documentation_length_ptr,
+                            metadata.documentation(),
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_OBJECT_METADATA_SET_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
+                let key_base = args.nth::<semantic_types::Pointer>(1);
+                let key_length = args.nth::<semantic_types::Size>(2);
+                let value_base = args.nth::<semantic_types::Pointer>(3);
+                let value_length = args.nth::<semantic_types::Size>(4);
+
+                let key =
+                    match self.read_string(key_base, narrow(key_length)?)? {
+                        Ok(key) => key,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+                let value =
+                    self.read_bytes(value_base, narrow(value_length)?)?;
+
+                match self.object_metadata_set(handle, key, value) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_OBJECT_METADATA_GET_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
+                let key_base = args.nth::<semantic_types::Pointer>(1);
+                let key_length = args.nth::<semantic_types::Size>(2);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(3);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                let key =
+                    match self.read_string(key_base, narrow(key_length)?)? {
+                        Ok(key) => key,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.object_metadata_get(handle, &key) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(value) => {
+                        self.write_bytes_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &value,
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_OBJECT_METADATA_ENUMERATE_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                /* NB: the keys are written into a single buffer, each preceded
+                 * by its length in bytes as a little-endian `u64`.
+                 */
+                match self.object_metadata_keys(handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(keys) => {
+                        let mut buffer = Vec::new();
+
+                        for key in keys.iter() {
+                            buffer.extend((key.len() as u64).to_le_bytes());
+                            buffer.extend(key.as_bytes());
+                        }
+
+                        self.write_bytes_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &buffer,
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_VIEW_RESERVE_INDEX => {
+                let base = args.nth::<semantic_types::Pointer>(0);
+                let size = args.nth::<semantic_types::Size>(1);
+
+                self.term_view_reserve(base, size);
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_TERM_VIEW_MAP_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                /* NB: the size of the view is written even if it does not fit
+                 * in the region reserved, so that the guest can grow it.
+                 */
+                let (size, result) = self.term_view_map(term_handle)?;
+
+                self.write_u64(result_ptr, size)?;
+
+                match result {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            #[cfg(feature = "linear-arith")]
+            ABI_LINEAR_ARITH_SIGNATURE_INDEX => {
+                let result_ptr = args.nth::<semantic_types::Pointer>(0);
+
+                self.write_u64s(result_ptr, self.linear_arith_signature())?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            #[cfg(feature = "linear-arith")]
+            ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_register_linear_arith(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -1907,24 +9278,95 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_IS_REGISTERED_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                Ok(Some(RuntimeValue::I32(
-                    self.type_is_registered(type_handle).into(),
-                )))
+            #[cfg(not(feature = "linear-arith"))]
+            index if is_linear_arith_index(index) => Ok(Some(
+                RuntimeValue::I32(KernelErrorCode::NoSuchFunction.into()),
+            )),
+            ABI_THEOREM_IS_TAINTED_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                match self.theorem_is_tainted(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_THEOREM_HYPOTHESES_COUNT_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                let count =
+                    self.theorem_split_hypotheses(theorem_handle, |result| {
+                        result.len()
+                    });
+
+                match count {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(count) => {
+                        self.write_u64(result_ptr, count as u64)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
             }
-            ABI_TYPE_SPLIT_VARIABLE_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
+            ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let offset = narrow(args.nth::<semantic_types::Size>(1))?;
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                /* NB: as for `Kernel.Enumerate`, the length pointer is read for
+                 * the limit on the number of hypotheses to write, and then
+                 * overwritten with the number actually written.
+                 */
+                let limit = narrow(self.read_u64(result_len_ptr)?)?;
+
+                let written =
+                    self.theorem_split_hypotheses(theorem_handle, |result| {
+                        let result = page(result, offset, limit);
+
+                        self.write_u64(result_len_ptr, result.len() as u64)?;
+                        self.write_handles(result_base_ptr, result)
+                    });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TYPE_VARIABLES_COUNT_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
                 let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.type_split_variable(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_u64(result_ptr, result)?;
+                let count =
+                    self.type_variables(type_handle, |result| result.len());
+
+                match count {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(count) => {
+                        self.write_u64(result_ptr, count as u64)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -1932,25 +9374,110 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_SPLIT_COMBINATION_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let former_result_ptr = args.nth::<semantic_types::Pointer>(1);
-                let arguments_result_ptr =
+            ABI_TYPE_VARIABLES_RANGE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let offset = narrow(args.nth::<semantic_types::Size>(1))?;
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let limit = narrow(self.read_u64(result_len_ptr)?)?;
+
+                let written = self.type_variables(type_handle, |result| {
+                    let result = page(result, offset, limit);
+
+                    self.write_u64(result_len_ptr, result.len() as u64)?;
+                    self.write_u64s(result_base_ptr, result.iter().map(|n| **n))
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_FREE_VARIABLES_COUNT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                let count = self.term_free_variables(term_handle, |result| {
+                    result.len()
+                });
+
+                match count {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(count) => {
+                        self.write_u64(result_ptr, count as u64)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_FREE_VARIABLES_RANGE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let offset = narrow(args.nth::<semantic_types::Size>(1))?;
+                let result_name_base_ptr =
                     args.nth::<semantic_types::Pointer>(2);
-                let arguments_length_result_ptr =
+                let result_type_base_ptr =
                     args.nth::<semantic_types::Pointer>(3);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                /* NB: the name and type buffers share the length pointer, and
+                 * so must each have room for the limit read from it.
+                 */
+                let limit = narrow(self.read_u64(result_len_ptr)?)?;
+
+                let written = self.term_free_variables(term_handle, |result| {
+                    let result = page(result, offset, limit);
+
+                    self.write_u64(result_len_ptr, result.len() as u64)?;
+                    self.write_u64s(
+                        result_name_base_ptr,
+                        result.iter().map(|(n, _t)| **n),
+                    )?;
+                    self.write_handles(
+                        result_type_base_ptr,
+                        result.iter().map(|(_n, t)| *t),
+                    )
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
 
-                match self.type_split_combination(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((former_handle, arguments)) => {
-                        self.write_handle(former_result_ptr, former_handle)?;
-                        self.write_u64(
-                            arguments_length_result_ptr,
-                            arguments.len() as u64,
-                        )?;
-                        self.write_handles(arguments_result_ptr, arguments)?;
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_TYPE_VARIABLES_COUNT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+
+                let count = self.term_type_variables(term_handle, |result| {
+                    result.len()
+                });
+
+                match count {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(count) => {
+                        self.write_u64(result_ptr, count as u64)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -1958,18 +9485,27 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_SPLIT_FUNCTION_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let domain_result_ptr = args.nth::<semantic_types::Pointer>(1);
-                let range_result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_TERM_TYPE_VARIABLES_RANGE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let offset = narrow(args.nth::<semantic_types::Size>(1))?;
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.type_split_function(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((domain_handle, range_handle)) => {
-                        self.write_handle(domain_result_ptr, domain_handle)?;
-                        self.write_handle(range_result_ptr, range_handle)?;
+                let limit = narrow(self.read_u64(result_len_ptr)?)?;
+
+                let written = self.term_type_variables(term_handle, |result| {
+                    let result = page(result, offset, limit);
+
+                    self.write_u64(result_len_ptr, result.len() as u64)?;
+                    self.write_u64s(result_base_ptr, result.iter().map(|n| **n))
+                });
+
+                match written {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(written) => {
+                        written?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -1977,16 +9513,21 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_TEST_VARIABLE_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_INTROSPECT_CALL_COUNT_INDEX => {
+                let name_base = args.nth::<semantic_types::Pointer>(0);
+                let name_length = args.nth::<semantic_types::Size>(1);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.type_test_variable(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                let name =
+                    match self.read_string(name_base, narrow(name_length)?)? {
+                        Ok(name) => name,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.introspect_call_count(&name) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(count) => {
+                        self.write_u64(result_ptr, count)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -1994,16 +9535,13 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_TEST_COMBINATION_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_INTROSPECT_FUEL_CONSUMED_INDEX => {
+                let result_ptr = args.nth::<semantic_types::Pointer>(0);
 
-                match self.type_test_combination(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                match self.introspect_fuel_consumed() {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(fuel) => {
+                        self.write_u64(result_ptr, fuel)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2011,14 +9549,88 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_TEST_FUNCTION_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
+            ABI_INTROSPECT_HEAP_SIZE_INDEX => {
+                let result_ptr = args.nth::<semantic_types::Pointer>(0);
+
+                match self.introspect_heap_size()? {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(size) => {
+                        self.write_u64(result_ptr, size)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_split_lambda_display(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((name, tau, body)) => {
+                        self.write_u64(result_name_ptr, name)?;
+                        self.write_handle(result_type_ptr, tau)?;
+                        self.write_handle(result_body_ptr, body)?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_PROGRESS_REGISTER_INDEX => {
+                let callback = args.nth::<semantic_types::TableIndex>(0);
+                let context = args.nth::<semantic_types::Context>(1);
+
+                self.progress_register(callback, context)?;
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_TERM_DIFF_INDEX => {
+                let left_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.term_diff(left_handle, right_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(diff) => {
+                        self.write_string_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &diff,
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_KERNEL_RESET_INDEX => match self.kernel_reset() {
+                Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                Ok(()) => {
+                    Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+                }
+            },
+            ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
                 let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.type_test_function(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.theorem_exists_with_conclusion(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_bool(result_ptr, result)?;
 
@@ -2028,16 +9640,28 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_SIZE_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_FIND_BY_CONCLUSION_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.type_size(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_u64(result_ptr, result)?;
+                match self.theorem_find_by_conclusion(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(theorems) => {
+                        /* NB: as with `Hypotheses.Resolve`, the length pointer
+                         * holds the capacity of the result buffer on entry and
+                         * the number of theorems on exit, and nothing is
+                         * written to the buffer if they do not fit.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, theorems.len() as u64)?;
+
+                        if theorems.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, &theorems)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2045,19 +9669,23 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_VARIABLES_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let variable_result_ptr =
-                    args.nth::<semantic_types::Pointer>(1);
-                let variable_len_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_FIND_MATCHING_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.type_variables(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_u64s(variable_result_ptr, result.clone())?;
-                        self.write_u64(variable_len_ptr, result.len() as u64)?;
+                match self.theorem_find_matching(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(theorems) => {
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, theorems.len() as u64)?;
+
+                        if theorems.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, &theorems)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2065,24 +9693,28 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TYPE_SUBSTITUTE_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_THEOREM_REGISTER_UNFOLD_INDEX => {
+                let definition_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
-                let rng_len = args.nth::<semantic_types::Size>(4);
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let mode = args.nth::<semantic_types::UnfoldMode>(2);
+                let path_ptr = args.nth::<semantic_types::Pointer>(3);
+                let path_len = args.nth::<semantic_types::Size>(4);
                 let result_ptr = args.nth::<semantic_types::Pointer>(5);
 
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+                let position =
+                    self.read_position(path_ptr, narrow(path_len)?)?;
 
-                let subst =
-                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
-
-                match self.type_substitute(type_handle, subst) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.theorem_register_unfold(
+                    definition_handle,
+                    term_handle,
+                    mode,
+                    position,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2092,14 +9724,19 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_CONSTANT_REGISTER_INDEX => {
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_SUBTERM_AT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let path_ptr = args.nth::<semantic_types::Pointer>(1);
+                let path_len = args.nth::<semantic_types::Size>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.constant_register(type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                let position =
+                    self.read_position(path_ptr, narrow(path_len)?)?;
+
+                match self.term_subterm_at(term_handle, &position) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2109,23 +9746,26 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_CONSTANT_IS_REGISTERED_INDEX => {
-                let constant_handle: Handle<tags::Constant> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_TERM_REPLACE_AT_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let path_ptr = args.nth::<semantic_types::Pointer>(1);
+                let path_len = args.nth::<semantic_types::Size>(2);
+                let replacement_handle: Handle<tags::Term> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(3))?,
                 );
+                let result_ptr = args.nth::<semantic_types::Pointer>(4);
 
-                Ok(Some(RuntimeValue::I32(
-                    self.constant_is_registered(constant_handle).into(),
-                )))
-            }
-            ABI_CONSTANT_RESOLVE_INDEX => {
-                let constant_handle: Handle<tags::Constant> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+                let position =
+                    self.read_position(path_ptr, narrow(path_len)?)?;
 
-                match self.constant_resolve(constant_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.term_replace_at(
+                    term_handle,
+                    &position,
+                    replacement_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2135,24 +9775,51 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_IS_REGISTERED_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_THEOREM_REGISTER_REWRITE_AT_INDEX => {
+                let equation_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
+                let path_ptr = args.nth::<semantic_types::Pointer>(2);
+                let path_len = args.nth::<semantic_types::Size>(3);
+                let result_ptr = args.nth::<semantic_types::Pointer>(4);
+
+                let position =
+                    self.read_position(path_ptr, narrow(path_len)?)?;
+
+                match self.theorem_register_rewrite_at(
+                    equation_handle,
+                    term_handle,
+                    &position,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_handle(result_ptr, result)?;
 
-                let result = self.term_is_registered(term_handle);
-
-                Ok(Some(RuntimeValue::I32(if result { 0 } else { 1 })))
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
             }
-            ABI_TERM_REGISTER_VARIABLE_INDEX => {
-                let name = args.nth::<semantic_types::Name>(0);
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
+            ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+                let index = narrow(args.nth::<semantic_types::Size>(1))?;
+                let equation_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(2))?,
+                );
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.term_register_variable(name, type_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.theorem_register_rewrite_hypothesis(
+                    theorem_handle,
+                    index,
+                    equation_handle,
+                ) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2162,24 +9829,21 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_CONSTANT_INDEX => {
-                let constant_handle: Handle<tags::Constant> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
-                let rng_len = args.nth::<semantic_types::Size>(4);
-                let result_ptr = args.nth::<semantic_types::Pointer>(5);
-
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+            ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX => {
+                let hyps_base = args.nth::<semantic_types::Pointer>(0);
+                let hyps_length = args.nth::<semantic_types::Size>(1);
+                let pattern_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(2),
+                )?);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                let subst =
-                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+                let hyps: Vec<Handle<tags::Term>> =
+                    self.read_handles(hyps_base, narrow(hyps_length)?)?;
 
-                match self.term_register_constant(constant_handle, subst) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self
+                    .theorem_register_assumption_matching(hyps, pattern_handle)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2189,20 +9853,117 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_APPLICATION_INDEX => {
-                let left_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
+            ABI_SCRATCH_PUT_INDEX => {
+                let key_base = args.nth::<semantic_types::Pointer>(0);
+                let key_length = args.nth::<semantic_types::Size>(1);
+                let value_base = args.nth::<semantic_types::Pointer>(2);
+                let value_length = args.nth::<semantic_types::Size>(3);
+
+                let key =
+                    match self.read_string(key_base, narrow(key_length)?)? {
+                        Ok(key) => key,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+                let value =
+                    self.read_bytes(value_base, narrow(value_length)?)?;
+
+                self.scratch_put(key, value);
+
+                Ok(Some(RuntimeValue::I32(KernelErrorCode::Success.into())))
+            }
+            ABI_SCRATCH_GET_INDEX => {
+                let key_base = args.nth::<semantic_types::Pointer>(0);
+                let key_length = args.nth::<semantic_types::Size>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let key =
+                    match self.read_string(key_base, narrow(key_length)?)? {
+                        Ok(key) => key,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.scratch_get(&key) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(value) => {
+                        self.write_bytes_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &value,
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_SCRATCH_DELETE_INDEX => {
+                let key_base = args.nth::<semantic_types::Pointer>(0);
+                let key_length = args.nth::<semantic_types::Size>(1);
+
+                let key =
+                    match self.read_string(key_base, narrow(key_length)?)? {
+                        Ok(key) => key,
+                        Err(e) => return Ok(Some(RuntimeValue::I32(e.into()))),
+                    };
+
+                match self.scratch_delete(&key) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_KERNEL_SET_VARIABLE_ORDER_INDEX => {
+                let order = args.nth::<semantic_types::VariableOrder>(0);
+
+                match self.kernel_set_variable_order(order) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_PROOF_OBJECT_EXPORT_INDEX => {
+                let theorem_base = args.nth::<semantic_types::Pointer>(0);
+                let theorem_length = args.nth::<semantic_types::Size>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                let theorems: Vec<Handle<tags::Theorem>> =
+                    self.read_handles(theorem_base, narrow(theorem_length)?)?;
+
+                match self.proof_object_export(theorems) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(bytes) => {
+                        self.write_bytes_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &bytes,
+                        )?;
+
+                        Ok(Some(RuntimeValue::I32(
+                            KernelErrorCode::Success.into(),
+                        )))
+                    }
+                }
+            }
+            ABI_KERNEL_EXPORT_PROOF_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_register_application(left_handle, right_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                match self.kernel_export_proof(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(article) => {
+                        self.write_string_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &article,
+                        )?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2210,21 +9971,31 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_LAMBDA_INDEX => {
-                let name = args.nth::<semantic_types::Name>(0);
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let body_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(2) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+            ABI_PROOF_OBJECT_IMPORT_INDEX => {
+                let bytes_base = args.nth::<semantic_types::Pointer>(0);
+                let bytes_length = args.nth::<semantic_types::Size>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.term_register_lambda(name, type_handle, body_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                let bytes =
+                    self.read_bytes(bytes_base, narrow(bytes_length)?)?;
+
+                match self.proof_object_import(&bytes) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(words) => {
+                        /* NB: as for `Hypotheses.Resolve`, the length pointer
+                         * is read for the capacity of the result buffer, and
+                         * then overwritten with the number of words.  Importing
+                         * is idempotent, so a guest that retries with a larger
+                         * buffer sees the same handles.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_u64(result_len_ptr, words.len() as u64)?;
+
+                        if words.len() as u64 <= capacity {
+                            self.write_u64s(result_base_ptr, words)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2232,16 +10003,24 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_NEGATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_ERROR_DETAIL_LAST_INDEX => {
+                let call_ptr = args.nth::<semantic_types::Pointer>(0);
+                let code_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.term_register_negation(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                match self.error_detail_last() {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(failure) => {
+                        let code: i32 = failure.code.into();
+
+                        self.write_u64(call_ptr, failure.call as u64)?;
+                        self.write_u64(code_ptr, code as u64)?;
+                        self.write_string_buffer(
+                            result_base_ptr,
+                            result_len_ptr,
+                            &failure.detail.unwrap_or_default(),
+                        )?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2249,20 +10028,35 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_CONJUNCTION_INDEX => {
-                let left_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_TYPE_GENERALISE_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let avoid_ptr = args.nth::<semantic_types::Pointer>(1);
+                let avoid_len = args.nth::<semantic_types::Size>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+                let names_base_ptr = args.nth::<semantic_types::Pointer>(4);
+                let names_len_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let avoid = self.read_u64s(avoid_ptr, narrow(avoid_len)?)?;
+
+                match self.type_generalise(type_handle, &avoid) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((result, names)) => {
+                        /* NB: as for `ProofObject.Import`, the length pointer
+                         * is read for the capacity of the names buffer, and
+                         * then overwritten with the number of names.
+                         * Generalising is deterministic, so a guest that
+                         * retries with a larger buffer sees the same result.
+                         */
+                        let capacity = self.read_u64(names_len_ptr)?;
 
-                match self.term_register_conjunction(left_handle, right_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
                         self.write_handle(result_ptr, result)?;
+                        self.write_u64(names_len_ptr, names.len() as u64)?;
+
+                        if names.len() as u64 <= capacity {
+                            self.write_u64s(names_base_ptr, names)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2270,20 +10064,37 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_DISJUNCTION_INDEX => {
-                let left_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_TYPE_INSTANTIATE_FRESH_INDEX => {
+                let type_handle: Handle<tags::Type> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let avoid_ptr = args.nth::<semantic_types::Pointer>(1);
+                let avoid_len = args.nth::<semantic_types::Size>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+                let renaming_base_ptr = args.nth::<semantic_types::Pointer>(4);
+                let renaming_len_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                let avoid = self.read_u64s(avoid_ptr, narrow(avoid_len)?)?;
+
+                match self.type_instantiate_fresh(type_handle, &avoid) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((result, renaming)) => {
+                        /* NB: the renaming is flattened into the words of its
+                         * pairs, each old name followed by its fresh name, and
+                         * returned as for `Type.Generalise`.
+                         */
+                        let words: Vec<u64> = renaming
+                            .into_iter()
+                            .flat_map(|(old, new)| vec![old, new])
+                            .collect();
+                        let capacity = self.read_u64(renaming_len_ptr)?;
 
-                match self.term_register_disjunction(left_handle, right_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
                         self.write_handle(result_ptr, result)?;
+                        self.write_u64(renaming_len_ptr, words.len() as u64)?;
+
+                        if words.len() as u64 <= capacity {
+                            self.write_u64s(renaming_base_ptr, words)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2291,20 +10102,19 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_IMPLICATION_INDEX => {
-                let left_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
+            ABI_THEOREM_ALPHA_EQUAL_INDEX => {
+                let left: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let right: Handle<tags::Theorem> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(1),
+                )?);
                 let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_register_implication(left_handle, right_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.theorem_alpha_equal(left, right) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                        self.write_bool(result_ptr, result)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2312,17 +10122,12 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_EQUALITY_INDEX => {
-                let left_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_CHALLENGE_CURRENT_INDEX => {
+                let service = args.nth::<semantic_types::Service>(0);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.term_register_equality(left_handle, right_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.challenge_current(service) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2332,21 +10137,48 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_FORALL_INDEX => {
-                let name = args.nth::<semantic_types::Name>(0);
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let body_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(2) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+            ABI_OBJECT_INC_REF_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
 
-                match self.term_register_forall(name, type_handle, body_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                match self.object_inc_ref(handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_OBJECT_DEC_REF_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
+
+                match self.object_dec_ref(handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(_freed) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_OBJECT_DELETE_INDEX => {
+                let handle = narrow(args.nth::<semantic_types::Handle>(0))?;
+
+                match self.object_delete(handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
+                }
+            }
+            ABI_CONSTANT_DEFINE_INDEX => {
+                let term_handle: Handle<tags::Term> = Handle::from(narrow(
+                    args.nth::<semantic_types::Handle>(0),
+                )?);
+                let constant_ptr = args.nth::<semantic_types::Pointer>(1);
+                let theorem_ptr = args.nth::<semantic_types::Pointer>(2);
+
+                match self.constant_define(term_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((constant, theorem)) => {
+                        self.write_handle(constant_ptr, constant)?;
+                        self.write_handle(theorem_ptr, theorem)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2354,19 +10186,17 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_REGISTER_EXISTS_INDEX => {
-                let name = args.nth::<semantic_types::Name>(0);
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
+            ABI_SUBSTITUTION_COMPOSE_INDEX => {
+                let first_handle: Handle<tags::Substitution> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let body_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(2) as usize,
+                let second_handle: Handle<tags::Substitution> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(1))?,
                 );
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_register_exists(name, type_handle, body_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
+                match self.substitution_compose(first_handle, second_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
                     Ok(result) => {
                         self.write_handle(result_ptr, result)?;
 
@@ -2376,18 +10206,16 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_SPLIT_VARIABLE_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_THEOREM_TRUST_LEVEL_INDEX => {
+                let theorem_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.term_split_variable(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((name, tau)) => {
-                        self.write_u64(result_name_ptr, name)?;
-                        self.write_handle(result_type_ptr, tau)?;
+                match self.theorem_trust_level(theorem_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_u64(result_ptr, u64::from(result))?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2395,18 +10223,24 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_SPLIT_CONSTANT_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_TYPE_DEFINE_INDEX => {
+                let witness_handle: Handle<tags::Theorem> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_const_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
-
-                match self.term_split_constant(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((constant, tau)) => {
-                        self.write_handle(result_const_ptr, constant)?;
-                        self.write_handle(result_type_ptr, tau)?;
+                let former_ptr = args.nth::<semantic_types::Pointer>(1);
+                let abs_ptr = args.nth::<semantic_types::Pointer>(2);
+                let rep_ptr = args.nth::<semantic_types::Pointer>(3);
+                let abs_rep_ptr = args.nth::<semantic_types::Pointer>(4);
+                let rep_abs_ptr = args.nth::<semantic_types::Pointer>(5);
+
+                match self.type_define(witness_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((former, abs, rep, abs_rep, rep_abs)) => {
+                        self.write_handle(former_ptr, former)?;
+                        self.write_handle(abs_ptr, abs)?;
+                        self.write_handle(rep_ptr, rep)?;
+                        self.write_handle(abs_rep_ptr, abs_rep)?;
+                        self.write_handle(rep_abs_ptr, rep_abs)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2414,39 +10248,38 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_SPLIT_APPLICATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX => {
+                let former_handle: Handle<tags::TypeFormer> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+                let constructor_base = args.nth::<semantic_types::Pointer>(1);
+                let constructor_length = args.nth::<semantic_types::Size>(2);
 
-                match self.term_split_application(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((left, right)) => {
-                        self.write_handle(result_left_ptr, left)?;
-                        self.write_handle(result_right_ptr, right)?;
+                let constructors: Vec<Handle<tags::Constant>> = self
+                    .read_handles(
+                        constructor_base,
+                        narrow(constructor_length)?,
+                    )?;
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                match self
+                    .constant_mark_constructors(former_handle, constructors)
+                {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(()) => Ok(Some(RuntimeValue::I32(
+                        KernelErrorCode::Success.into(),
+                    ))),
                 }
             }
-            ABI_TERM_SPLIT_LAMBDA_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_CONSTANT_IS_CONSTRUCTOR_INDEX => {
+                let constant_handle: Handle<tags::Constant> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
-                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
+                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.term_split_lambda(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((name, tau, body)) => {
-                        self.write_u64(result_name_ptr, name)?;
-                        self.write_handle(result_type_ptr, tau)?;
-                        self.write_handle(result_body_ptr, body)?;
+                match self.constant_is_constructor(constant_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok(result) => {
+                        self.write_bool(result_ptr, result)?;
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
@@ -2454,2395 +10287,3732 @@ impl Externals for WasmiRuntimeState {
                     }
                 }
             }
-            ABI_TERM_SPLIT_NEGATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
+            ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX => {
+                let constant_handle: Handle<tags::Constant> = Handle::from(
+                    narrow(args.nth::<semantic_types::Handle>(0))?,
                 );
-                let result_body_ptr = args.nth::<semantic_types::Pointer>(1);
+                let former_ptr = args.nth::<semantic_types::Pointer>(1);
+                let result_base_ptr = args.nth::<semantic_types::Pointer>(2);
+                let result_len_ptr = args.nth::<semantic_types::Pointer>(3);
+
+                match self.constant_constructor_siblings(constant_handle) {
+                    Err(e) => Ok(Some(RuntimeValue::I32(e.into()))),
+                    Ok((former, constructors)) => {
+                        /* NB: as with `Hypotheses.Resolve`, the length pointer
+                         * holds the capacity of the result buffer on entry and
+                         * the number of constructors on exit, and nothing is
+                         * written to the buffer if they do not fit.
+                         */
+                        let capacity = self.read_u64(result_len_ptr)?;
+
+                        self.write_handle(former_ptr, former)?;
+                        self.write_u64(
+                            result_len_ptr,
+                            constructors.len() as u64,
+                        )?;
 
-                match self.term_split_negation(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(body) => {
-                        self.write_handle(result_body_ptr, body)?;
+                        if constructors.len() as u64 <= capacity {
+                            self.write_handles(result_base_ptr, constructors)?;
+                        }
 
                         Ok(Some(RuntimeValue::I32(
                             KernelErrorCode::Success.into(),
                         )))
                     }
                 }
-            }
-            ABI_TERM_SPLIT_CONJUNCTION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
+            }
+            ABI_SHOULD_ABORT_INDEX => {
+                let result = self.should_abort();
+
+                Ok(Some(RuntimeValue::I32(result.into())))
+            }
+            _otherwise => {
+                Err(runtime_trap::host_trap(RuntimeTrap::NoSuchFunction))
+            }
+        }
+    }
+}
+
+/// Maps an ABI host-call to its associated host-call number.  Also checks that
+/// the function's signature is as expected, otherwise produces a runtime error
+/// that is reported back to the WASM program.
+impl WasmiRuntimeState {
+    /// Allocates the host function with host-call number `index` for the
+    /// guest import named `field_name`, recording the memory that the host
+    /// call should read and write.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(RuntimeTrap::ExperimentalFunctionDisabled)`, as a trap, if
+    /// `index` is an experimental host call and experimental host calls have
+    /// not been enabled.
+    fn alloc_host_function(
+        &self,
+        field_name: &str,
+        signature: &Signature,
+        index: usize,
+    ) -> Result<FuncRef, WasmiError> {
+        if is_experimental_index(index) && !self.experimental_enabled {
+            error!("Experimental host call {} imported, but experimental host calls are disabled.", field_name);
+
+            return Err(WasmiError::Trap(runtime_trap::host_trap(
+                RuntimeTrap::ExperimentalFunctionDisabled,
+            )));
+        }
+
+        if let Some(memory) = self.call_memories.get(field_name) {
+            self.resolved_call_memories
+                .borrow_mut()
+                .insert(index, *memory);
+        }
+
+        self.call_imports
+            .borrow_mut()
+            .insert(index, (field_name.to_string(), signature.return_type()));
+
+        let function = FuncInstance::alloc_host(signature.clone(), index);
+
+        /* NB: a host call imported more than once, by several modules, occupies
+         * its slot of the host function table as it was most recently imported.
+         */
+        if let Some(table) = &self.host_table {
+            table.set(index as u32, Some(function.clone()))?;
+        }
+
+        Ok(function)
+    }
+}
+
+impl ModuleImportResolver for WasmiRuntimeState {
+    fn resolve_func(
+        &self,
+        field_name: &str,
+        signature: &Signature,
+    ) -> Result<FuncRef, WasmiError> {
+        match field_name {
+            ABI_TYPE_FORMER_RESOLVE_NAME => {
+                if !type_checking::check_type_former_resolve_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_former_resolve.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_RESOLVE_INDEX,
+                )
+            }
+            ABI_TYPE_FORMER_REGISTER_NAME => {
+                if !type_checking::check_type_former_register_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_former_register.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_REGISTER_INDEX,
+                )
+            }
+            ABI_TYPE_FORMER_IS_REGISTERED_NAME => {
+                if !type_checking::check_type_former_is_registered_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_former_is_registered.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_IS_REGISTERED_INDEX,
+                )
+            }
+            ABI_TYPE_IS_REGISTERED_NAME => {
+                if !type_checking::check_type_is_registered_signature(signature)
+                {
+                    error!("Signature check failed when checking __type_is_registered.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_IS_REGISTERED_INDEX,
+                )
+            }
+            ABI_TYPE_REGISTER_VARIABLE_NAME => {
+                if !type_checking::check_type_register_variable_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_register_variable.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_REGISTER_VARIABLE_INDEX,
+                )
+            }
+            ABI_TYPE_REGISTER_COMBINATION_NAME => {
+                if !type_checking::check_type_register_combination_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_register_combination.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_split_conjunction(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((left, right)) => {
-                        self.write_handle(result_left_ptr, left)?;
-                        self.write_handle(result_right_ptr, right)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_REGISTER_COMBINATION_INDEX,
+                )
+            }
+            ABI_TYPE_REGISTER_FUNCTION_NAME => {
+                if !type_checking::check_type_register_function_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_register_function.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_SPLIT_DISJUNCTION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_split_disjunction(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((left, right)) => {
-                        self.write_handle(result_left_ptr, left)?;
-                        self.write_handle(result_right_ptr, right)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_REGISTER_FUNCTION_INDEX,
+                )
+            }
+            ABI_TYPE_SPLIT_VARIABLE_NAME => {
+                if !type_checking::check_type_split_variable_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_split_variable.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_SPLIT_IMPLICATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_split_implication(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((left, right)) => {
-                        self.write_handle(result_left_ptr, left)?;
-                        self.write_handle(result_right_ptr, right)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SPLIT_VARIABLE_INDEX,
+                )
+            }
+            ABI_TYPE_SPLIT_COMBINATION_NAME => {
+                if !type_checking::check_type_split_combination_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_split_combination.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_SPLIT_EQUALITY_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_left_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_right_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.term_split_equality(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((left, right)) => {
-                        self.write_handle(result_left_ptr, left)?;
-                        self.write_handle(result_right_ptr, right)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SPLIT_COMBINATION_INDEX,
+                )
+            }
+            ABI_TYPE_SPLIT_FUNCTION_NAME => {
+                if !type_checking::check_type_split_function_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_split_function.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_SPLIT_FORALL_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
-                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.term_split_forall(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((name, tau, body)) => {
-                        self.write_u64(result_name_ptr, name)?;
-                        self.write_handle(result_type_ptr, tau)?;
-                        self.write_handle(result_body_ptr, body)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SPLIT_FUNCTION_INDEX,
+                )
+            }
+            ABI_TYPE_TEST_VARIABLE_NAME => {
+                if !type_checking::check_type_test_variable_signature(signature)
+                {
+                    error!("Signature check failed when checking __type_test_variable.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_SPLIT_EXISTS_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_name_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_type_ptr = args.nth::<semantic_types::Pointer>(2);
-                let result_body_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.term_split_exists(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok((name, tau, body)) => {
-                        self.write_u64(result_name_ptr, name)?;
-                        self.write_handle(result_type_ptr, tau)?;
-                        self.write_handle(result_body_ptr, body)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_TEST_VARIABLE_INDEX,
+                )
+            }
+            ABI_TYPE_TEST_COMBINATION_NAME => {
+                if !type_checking::check_type_test_combination_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_test_combination.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_TERM_TEST_VARIABLE_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.term_test_variable(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_TEST_COMBINATION_INDEX,
+                )
+            }
+            ABI_TYPE_TEST_FUNCTION_NAME => {
+                if !type_checking::check_type_test_function_signature(signature)
+                {
+                    error!("Signature check failed when checking __type_test_function.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_TEST_FUNCTION_INDEX,
+                )
             }
-            ABI_TERM_TEST_CONSTANT_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TYPE_SIZE_NAME => {
+                if !type_checking::check_type_size_signature(signature) {
+                    error!("Signature check failed when checking __type_size.  Signature: {:?}.", signature);
 
-                match self.term_test_constant(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SIZE_INDEX,
+                )
+            }
+            ABI_TYPE_VARIABLES_NAME => {
+                if !type_checking::check_type_variables_signature(signature) {
+                    error!("Signature check failed when checking __type_variables.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_VARIABLES_INDEX,
+                )
             }
-            ABI_TERM_TEST_APPLICATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TYPE_SUBSTITUTE_NAME => {
+                if !type_checking::check_type_substitute_signature(signature) {
+                    error!("Signature check failed when checking __type_substitute.  Signature: {:?}.", signature);
 
-                match self.term_test_application(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SUBSTITUTE_INDEX,
+                )
+            }
+            ABI_CONSTANT_RESOLVE_NAME => {
+                if !type_checking::check_constant_resolve_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_RESOLVE_INDEX,
+                )
             }
-            ABI_TERM_TEST_LAMBDA_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_CONSTANT_IS_REGISTERED_NAME => {
+                if !type_checking::check_constant_is_registered_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_lambda(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_IS_REGISTERED_INDEX,
+                )
+            }
+            ABI_CONSTANT_REGISTER_NAME => {
+                if !type_checking::check_constant_register_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_REGISTER_INDEX,
+                )
+            }
+            ABI_TERM_IS_REGISTERED_NAME => {
+                if !type_checking::check_term_is_registered_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_IS_REGISTERED_INDEX,
+                )
             }
-            ABI_TERM_TEST_NEGATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_REGISTER_VARIABLE_NAME => {
+                if !type_checking::check_term_register_variable_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_negation(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_VARIABLE_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_CONSTANT_NAME => {
+                if !type_checking::check_term_register_constant_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_CONSTANT_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_APPLICATION_NAME => {
+                if !type_checking::check_term_register_application_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_APPLICATION_INDEX,
+                )
             }
-            ABI_TERM_TEST_CONJUNCTION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_REGISTER_LAMBDA_NAME => {
+                if !type_checking::check_term_register_lambda_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_conjunction(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_LAMBDA_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_NEGATION_NAME => {
+                if !type_checking::check_term_register_negation_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_NEGATION_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_CONJUNCTION_NAME => {
+                if !type_checking::check_term_register_conjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_CONJUNCTION_INDEX,
+                )
             }
-            ABI_TERM_TEST_DISJUNCTION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_REGISTER_DISJUNCTION_NAME => {
+                if !type_checking::check_term_register_disjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_disjunction(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_DISJUNCTION_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_IMPLICATION_NAME => {
+                if !type_checking::check_term_register_implication_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_IMPLICATION_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_EQUALITY_NAME => {
+                if !type_checking::check_term_register_equality_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_EQUALITY_INDEX,
+                )
             }
-            ABI_TERM_TEST_IMPLICATION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_REGISTER_FORALL_NAME => {
+                if !type_checking::check_term_register_forall_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_implication(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_FORALL_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_EXISTS_NAME => {
+                if !type_checking::check_term_register_exists_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_EXISTS_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_VARIABLE_NAME => {
+                if !type_checking::check_term_split_variable_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_VARIABLE_INDEX,
+                )
             }
-            ABI_TERM_TEST_EQUALITY_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_SPLIT_CONSTANT_NAME => {
+                if !type_checking::check_term_split_constant_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_equality(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_CONSTANT_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_APPLICATION_NAME => {
+                if !type_checking::check_term_split_application_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_APPLICATION_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_LAMBDA_NAME => {
+                if !type_checking::check_term_split_lambda_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_LAMBDA_INDEX,
+                )
             }
-            ABI_TERM_TEST_FORALL_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_SPLIT_NEGATION_NAME => {
+                if !type_checking::check_term_split_negation_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_forall(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_NEGATION_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_CONJUNCTION_NAME => {
+                if !type_checking::check_term_split_conjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_CONJUNCTION_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_DISJUNCTION_NAME => {
+                if !type_checking::check_term_split_disjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_DISJUNCTION_INDEX,
+                )
             }
-            ABI_TERM_TEST_EXISTS_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_TERM_SPLIT_IMPLICATION_NAME => {
+                if !type_checking::check_term_split_implication_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_test_exists(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_IMPLICATION_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_EQUALITY_NAME => {
+                if !type_checking::check_term_split_equality_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_EQUALITY_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_FORALL_NAME => {
+                if !type_checking::check_term_split_forall_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_FORALL_INDEX,
+                )
             }
-            ABI_TERM_FREE_VARIABLES_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_name_base_ptr =
-                    args.nth::<semantic_types::Pointer>(1);
-                let result_name_len_ptr =
-                    args.nth::<semantic_types::Pointer>(2);
-                let result_type_base_ptr =
-                    args.nth::<semantic_types::Pointer>(1);
-                let result_type_len_ptr =
-                    args.nth::<semantic_types::Pointer>(2);
+            ABI_TERM_SPLIT_EXISTS_NAME => {
+                if !type_checking::check_term_split_exists_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_free_variables(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        let (names, types): (
-                            Vec<Name>,
-                            Vec<Handle<tags::Type>>,
-                        ) = result.iter().cloned().unzip();
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_EXISTS_INDEX,
+                )
+            }
+            ABI_TERM_TEST_VARIABLE_NAME => {
+                if !type_checking::check_term_test_variable_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        self.write_u64(
-                            result_name_len_ptr,
-                            names.len() as u64,
-                        )?;
-                        self.write_u64s(result_name_base_ptr, names)?;
-                        self.write_u64(
-                            result_type_len_ptr,
-                            types.len() as u64,
-                        )?;
-                        self.write_handles(result_type_base_ptr, types)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_VARIABLE_INDEX,
+                )
+            }
+            ABI_TERM_TEST_CONSTANT_NAME => {
+                if !type_checking::check_term_test_constant_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_CONSTANT_INDEX,
+                )
+            }
+            ABI_TERM_TEST_APPLICATION_NAME => {
+                if !type_checking::check_term_test_application_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_APPLICATION_INDEX,
+                )
             }
-            ABI_TERM_SUBSTITUTE_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let type_ptr = args.nth::<semantic_types::Pointer>(3);
-                let type_len = args.nth::<semantic_types::Size>(4);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(5);
-                let rng_len = args.nth::<semantic_types::Size>(6);
-                let result_ptr = args.nth::<semantic_types::Pointer>(7);
+            ABI_TERM_TEST_LAMBDA_NAME => {
+                if !type_checking::check_term_test_lambda_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let types = self.read_u64s(type_ptr, type_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_LAMBDA_INDEX,
+                )
+            }
+            ABI_TERM_TEST_NEGATION_NAME => {
+                if !type_checking::check_term_test_negation_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let substitution = domains
-                    .iter()
-                    .zip(types)
-                    .zip(ranges)
-                    .map(|((d, t), r)| ((*d, t), r))
-                    .collect();
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_NEGATION_INDEX,
+                )
+            }
+            ABI_TERM_TEST_CONJUNCTION_NAME => {
+                if !type_checking::check_term_test_conjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_substitute(term_handle, substitution) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_CONJUNCTION_INDEX,
+                )
+            }
+            ABI_TERM_TEST_DISJUNCTION_NAME => {
+                if !type_checking::check_term_test_disjunction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_DISJUNCTION_INDEX,
+                )
+            }
+            ABI_TERM_TEST_IMPLICATION_NAME => {
+                if !type_checking::check_term_test_implication_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_IMPLICATION_INDEX,
+                )
             }
-            ABI_TERM_TYPE_VARIABLES_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_TERM_TEST_EQUALITY_NAME => {
+                if !type_checking::check_term_test_equality_signature(signature)
+                {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_type_variables(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_u64(result_len_ptr, result.len() as u64)?;
-                        self.write_u64s(result_base_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_EQUALITY_INDEX,
+                )
+            }
+            ABI_TERM_TEST_FORALL_NAME => {
+                if !type_checking::check_term_test_forall_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_FORALL_INDEX,
+                )
+            }
+            ABI_TERM_TEST_EXISTS_NAME => {
+                if !type_checking::check_term_test_exists_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_EXISTS_INDEX,
+                )
             }
-            ABI_TERM_TYPE_SUBSTITUTE_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
+            ABI_TERM_FREE_VARIABLES_NAME => {
+                if !type_checking::check_term_free_variables_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
-                let rng_len = args.nth::<semantic_types::Size>(4);
-                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_FREE_VARIABLES_INDEX,
+                )
+            }
+            ABI_TERM_SUBSTITUTE_NAME => {
+                if !type_checking::check_term_substitute_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SUBSTITUTE_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_VARIABLES_NAME => {
+                if !type_checking::check_term_type_variables_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let subst =
-                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_VARIABLES_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_SUBSTITUTE_NAME => {
+                if !type_checking::check_term_type_substitute_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_type_substitute(term_handle, subst) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_SUBSTITUTE_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_INFER_NAME => {
+                if !type_checking::check_term_type_infer_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_INFER_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_IS_PROPOSITION_NAME => {
+                if !type_checking::check_term_type_is_proposition_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_IS_PROPOSITION_INDEX,
+                )
             }
-            ABI_TERM_TYPE_INFER_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_IS_REGISTERED_NAME => {
+                if !type_checking::check_theorem_is_registered_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_type_infer(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_IS_REGISTERED_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_ASSUMPTION_NAME => {
+                if !type_checking::check_theorem_register_assumption_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_WEAKEN_NAME => {
+                if !type_checking::check_theorem_register_weaken_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_WEAKEN_INDEX,
+                )
             }
-            ABI_TERM_TYPE_IS_PROPOSITION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_REGISTER_REFLEXIVITY_NAME => {
+                if !type_checking::check_theorem_register_reflexivity_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.term_type_is_proposition(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_bool(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_SYMMETRY_NAME => {
+                if !type_checking::check_theorem_register_symmetry_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_SYMMETRY_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_TRANSITIVITY_NAME => {
+                if !type_checking::check_theorem_register_transitivity_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
+                )
             }
-            ABI_THEOREM_IS_REGISTERED_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result = self.theorem_is_registered(theorem_handle);
+            ABI_THEOREM_REGISTER_APPLICATION_NAME => {
+                if !type_checking::check_theorem_register_application_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                Ok(Some(RuntimeValue::I32(result.into())))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_APPLICATION_INDEX,
+                )
             }
-            ABI_THEOREM_SPLIT_CONCLUSION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_REGISTER_LAMBDA_NAME => {
+                if !type_checking::check_theorem_register_lambda_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_split_conclusion(theorem_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_LAMBDA_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_BETA_NAME => {
+                if !type_checking::check_theorem_register_beta_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_BETA_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_ETA_NAME => {
+                if !type_checking::check_theorem_register_eta_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_ETA_INDEX,
+                )
             }
-            ABI_THEOREM_SPLIT_HYPOTHESES_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_base_ptr = args.nth::<semantic_types::Pointer>(1);
-                let result_len_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_REGISTER_SUBSTITUTE_NAME => {
+                if !type_checking::check_theorem_register_substitute_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_split_hypotheses(theorem_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_u64(result_len_ptr, result.len() as u64)?;
-                        self.write_handles(result_base_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_NAME => {
+                if !type_checking::check_theorem_register_type_substitute_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_truth_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_ASSUMPTION_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_falsity_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_assumption(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_conjunction_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_conjunction_left_elimination_signature(signature) {
+                    return Err(WasmiError::Trap(
+                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
                 }
-            }
-            ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.theorem_register_reflexivity(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_conjunction_right_elimination_signature(signature) {
+                    return Err(WasmiError::Trap(
+                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_disjunction_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_SYMMETRY_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_disjunction_left_introduction_signature(signature) {
+                    return Err(WasmiError::Trap(
+                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+                }
 
-                match self.theorem_register_symmetry(theorem_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_disjunction_right_introduction_signature(signature) {
+                    return Err(WasmiError::Trap(
+                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_implication_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_implication_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self
-                    .theorem_register_transitivity(left_handle, right_handle)
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_IFF_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_iff_introduction_signature(signature)
                 {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
-
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_BETA_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.theorem_register_beta(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_iff_left_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_negation_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_ETA_INDEX => {
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_negation_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_eta(term_handle) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_forall_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_FORALL_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_forall_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_APPLICATION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_NAME => {
+                if !type_checking::check_theorem_register_exists_elimination_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self
-                    .theorem_register_application(left_handle, right_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_NAME => {
+                if !type_checking::check_theorem_register_exists_introduction_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
+                )
+            }
+            ABI_THEOREM_SPLIT_CONCLUSION_NAME => {
+                if !type_checking::check_theorem_split_conclusion_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_SPLIT_CONCLUSION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_LAMBDA_INDEX => {
-                let name: Name = args.nth::<semantic_types::Name>(0);
-                let type_handle: Handle<tags::Type> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(2) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
+            ABI_THEOREM_SIZE_NAME => {
+                if !type_checking::check_theorem_size_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_lambda(
-                    name,
-                    type_handle,
-                    theorem_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_SIZE_INDEX,
+                )
+            }
+            ABI_THEOREM_SPLIT_HYPOTHESES_NAME => {
+                if !type_checking::check_theorem_split_hypotheses_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_SPLIT_HYPOTHESES_INDEX,
+                )
+            }
+            ABI_KERNEL_ENUMERATE_NAME => {
+                if !type_checking::check_kernel_enumerate_signature(signature) {
+                    error!("Signature check failed when checking __kernel_enumerate.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_ENUMERATE_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
-                let rng_len = args.nth::<semantic_types::Size>(4);
-                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+            ABI_KERNEL_IDENTIFY_NAME => {
+                if !type_checking::check_kernel_identify_signature(signature) {
+                    error!("Signature check failed when checking __kernel_identify.  Signature: {:?}.", signature);
 
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let subst =
-                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_IDENTIFY_INDEX,
+                )
+            }
+            ABI_KERNEL_ERROR_DETAIL_NAME => {
+                if !type_checking::check_kernel_error_detail_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __kernel_error_detail.  Signature: {:?}.", signature);
 
-                match self.theorem_register_substitute(theorem_handle, subst) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_ERROR_DETAIL_INDEX,
+                )
+            }
+            ABI_OBJECT_METADATA_SET_NAME => {
+                if !type_checking::check_object_metadata_set_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __object_metadata_set.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_OBJECT_METADATA_SET_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let dom_ptr = args.nth::<semantic_types::Pointer>(1);
-                let dom_len = args.nth::<semantic_types::Size>(2);
-                let rng_ptr = args.nth::<semantic_types::Pointer>(3);
-                let rng_len = args.nth::<semantic_types::Size>(4);
-                let result_ptr = args.nth::<semantic_types::Pointer>(5);
+            ABI_OBJECT_METADATA_GET_NAME => {
+                if !type_checking::check_object_metadata_get_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __object_metadata_get.  Signature: {:?}.", signature);
 
-                let domains = self.read_u64s(dom_ptr, dom_len as usize)?;
-                let ranges = self.read_handles(rng_ptr, rng_len as usize)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                let subst =
-                    domains.iter().zip(ranges).map(|(d, r)| (*d, r)).collect();
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_OBJECT_METADATA_GET_INDEX,
+                )
+            }
+            ABI_OBJECT_METADATA_ENUMERATE_NAME => {
+                if !type_checking::check_object_metadata_enumerate_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __object_metadata_enumerate.  Signature: {:?}.", signature);
 
-                match self
-                    .theorem_register_type_substitute(theorem_handle, subst)
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_OBJECT_METADATA_ENUMERATE_INDEX,
+                )
+            }
+            ABI_TERM_VIEW_RESERVE_NAME => {
+                if !type_checking::check_term_view_reserve_signature(signature)
                 {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_view_reserve.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX => {
-                let result_ptr = args.nth::<semantic_types::Pointer>(0);
 
-                match self.theorem_register_truth_introduction() {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_VIEW_RESERVE_INDEX,
+                )
+            }
+            ABI_TERM_VIEW_MAP_NAME => {
+                if !type_checking::check_term_view_map_signature(signature) {
+                    error!("Signature check failed when checking __term_view_map.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_falsity_elimination(
-                    theorem_handle,
-                    term_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_VIEW_MAP_INDEX,
+                )
+            }
+            ABI_LINEAR_ARITH_SIGNATURE_NAME => {
+                if !type_checking::check_linear_arith_signature_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __linear_arith_signature.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_conjunction_introduction(
-                    left_handle,
-                    right_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_LINEAR_ARITH_SIGNATURE_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_LINEAR_ARITH_NAME => {
+                if !type_checking::check_theorem_register_linear_arith_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __theorem_register_linear_arith.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_THEOREM_IS_TAINTED_NAME => {
+                if !type_checking::check_theorem_is_tainted_signature(signature)
+                {
+                    error!("Signature check failed when checking __theorem_is_tainted.  Signature: {:?}.", signature);
 
-                match self.theorem_register_conjunction_left_elimination(
-                    theorem_handle,
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_IS_TAINTED_INDEX,
+                )
+            }
+            ABI_THEOREM_HYPOTHESES_COUNT_NAME => {
+                if !type_checking::check_theorem_hypotheses_count_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __theorem_hypotheses_count.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
 
-                match self.theorem_register_conjunction_right_elimination(
-                    theorem_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_HYPOTHESES_COUNT_INDEX,
+                )
+            }
+            ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_NAME => {
+                if !type_checking::check_theorem_split_hypotheses_range_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __theorem_split_hypotheses_range.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let mid_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(2) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(3);
 
-                match self.theorem_register_disjunction_elimination(
-                    left_handle,
-                    mid_handle,
-                    right_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX,
+                )
+            }
+            ABI_TYPE_VARIABLES_COUNT_NAME => {
+                if !type_checking::check_type_variables_count_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __type_variables_count.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_disjunction_left_introduction(
-                    theorem_handle,
-                    term_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_VARIABLES_COUNT_INDEX,
+                )
+            }
+            ABI_TYPE_VARIABLES_RANGE_NAME => {
+                if !type_checking::check_type_variables_range_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __type_variables_range.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_disjunction_right_introduction(
-                    theorem_handle,
-                    term_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_VARIABLES_RANGE_INDEX,
+                )
+            }
+            ABI_TERM_FREE_VARIABLES_COUNT_NAME => {
+                if !type_checking::check_term_free_variables_count_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_free_variables_count.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_implication_introduction(
-                    theorem_handle,
-                    term_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_FREE_VARIABLES_COUNT_INDEX,
+                )
+            }
+            ABI_TERM_FREE_VARIABLES_RANGE_NAME => {
+                if !type_checking::check_term_free_variables_range_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_free_variables_range.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_implication_elimination(
-                    left_handle,
-                    right_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_FREE_VARIABLES_RANGE_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_VARIABLES_COUNT_NAME => {
+                if !type_checking::check_term_type_variables_count_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_type_variables_count.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
-            }
-            ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
 
-                match self.theorem_register_iff_introduction(
-                    left_handle,
-                    right_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_VARIABLES_COUNT_INDEX,
+                )
+            }
+            ABI_TERM_TYPE_VARIABLES_RANGE_NAME => {
+                if !type_checking::check_term_type_variables_range_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_type_variables_range.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_VARIABLES_RANGE_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(1);
+            ABI_INTROSPECT_CALL_COUNT_NAME => {
+                if !type_checking::check_introspect_call_count_signature(signature) {
+                    error!("Signature check failed when checking __introspect_call_count.  Signature: {:?}.", signature);
 
-                match self.theorem_register_iff_left_elimination(theorem_handle)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_INTROSPECT_CALL_COUNT_INDEX,
+                )
+            }
+            ABI_INTROSPECT_FUEL_CONSUMED_NAME => {
+                if !type_checking::check_introspect_fuel_consumed_signature(signature) {
+                    error!("Signature check failed when checking __introspect_fuel_consumed.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_INTROSPECT_FUEL_CONSUMED_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_INTROSPECT_HEAP_SIZE_NAME => {
+                if !type_checking::check_introspect_heap_size_signature(signature) {
+                    error!("Signature check failed when checking __introspect_heap_size.  Signature: {:?}.", signature);
 
-                match self.theorem_register_negation_introduction(
-                    theorem_handle,
-                    term_handle,
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_INTROSPECT_HEAP_SIZE_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_LAMBDA_DISPLAY_NAME => {
+                if !type_checking::check_term_split_lambda_display_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    error!("Signature check failed when checking __term_split_lambda_display.  Signature: {:?}.", signature);
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_PROGRESS_REGISTER_NAME => {
+                if !type_checking::check_progress_register_signature(signature)
+                {
+                    error!("Signature check failed when checking __progress_register.  Signature: {:?}.", signature);
 
-                match self.theorem_register_negation_elimination(
-                    left_handle,
-                    right_handle,
-                ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_PROGRESS_REGISTER_INDEX,
+                )
+            }
+            ABI_TERM_DIFF_NAME => {
+                if !type_checking::check_term_diff_signature(signature) {
+                    error!("Signature check failed when checking __term_diff.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_DIFF_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let name: Name = args.nth::<semantic_types::Name>(1);
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_KERNEL_RESET_NAME => {
+                if !type_checking::check_kernel_reset_signature(signature) {
+                    error!("Signature check failed when checking __kernel_reset.  Signature: {:?}.", signature);
 
-                match self
-                    .theorem_register_forall_introduction(theorem_handle, name)
-                {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_RESET_INDEX,
+                )
+            }
+            ABI_THEOREM_EXISTS_WITH_CONCLUSION_NAME => {
+                if !type_checking::check_theorem_exists_with_conclusion_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_FIND_BY_CONCLUSION_NAME => {
+                if !type_checking::check_theorem_find_by_conclusion_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_forall_elimination(
-                    theorem_handle,
-                    term_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_FIND_BY_CONCLUSION_INDEX,
+                )
+            }
+            ABI_THEOREM_FIND_MATCHING_NAME => {
+                if !type_checking::check_theorem_find_matching_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_FIND_MATCHING_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_UNFOLD_NAME => {
+                if !type_checking::check_theorem_register_unfold_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_UNFOLD_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX => {
-                let theorem_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let term_handle: Handle<tags::Term> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_TERM_SUBTERM_AT_NAME => {
+                if !type_checking::check_term_subterm_at_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_exists_introduction(
-                    theorem_handle,
-                    term_handle,
-                ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SUBTERM_AT_INDEX,
+                )
+            }
+            ABI_TERM_REPLACE_AT_NAME => {
+                if !type_checking::check_term_replace_at_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REPLACE_AT_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_REWRITE_AT_NAME => {
+                if !type_checking::check_theorem_register_rewrite_at_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_REWRITE_AT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX => {
-                let left_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(0) as usize,
-                );
-                let right_handle: Handle<tags::Theorem> = Handle::from(
-                    args.nth::<semantic_types::Handle>(1) as usize,
-                );
-                let result_ptr = args.nth::<semantic_types::Pointer>(2);
+            ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_NAME => {
+                if !type_checking::check_theorem_register_rewrite_hypothesis_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                match self.theorem_register_exists_elimination(
-                    left_handle,
-                    right_handle,
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_NAME => {
+                if !type_checking::check_theorem_register_assumption_matching_signature(
+                    signature,
                 ) {
-                    Err(e) => Ok(Some(RuntimeValue::I32(e as i32))),
-                    Ok(result) => {
-                        self.write_handle(result_ptr, result)?;
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-                        Ok(Some(RuntimeValue::I32(
-                            KernelErrorCode::Success.into(),
-                        )))
-                    }
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX,
+                )
+            }
+            ABI_SCRATCH_PUT_NAME => {
+                if !type_checking::check_scratch_put_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SCRATCH_PUT_INDEX,
+                )
             }
-            _otherwise => {
-                Err(runtime_trap::host_trap(RuntimeTrap::NoSuchFunction))
+            ABI_SCRATCH_GET_NAME => {
+                if !type_checking::check_scratch_get_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SCRATCH_GET_INDEX,
+                )
             }
-        }
-    }
-}
+            ABI_SCRATCH_DELETE_NAME => {
+                if !type_checking::check_scratch_delete_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
-/// Maps an ABI host-call to its associated host-call number.  Also checks that
-/// the function's signature is as expected, otherwise produces a runtime error
-/// that is reported back to the WASM program.
-impl ModuleImportResolver for WasmiRuntimeState {
-    fn resolve_func(
-        &self,
-        field_name: &str,
-        signature: &Signature,
-    ) -> Result<FuncRef, WasmiError> {
-        match field_name {
-            ABI_TYPE_FORMER_RESOLVE_NAME => {
-                if !type_checking::check_type_former_resolve_signature(
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SCRATCH_DELETE_INDEX,
+                )
+            }
+            ABI_KERNEL_SET_VARIABLE_ORDER_NAME => {
+                if !type_checking::check_kernel_set_variable_order_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_former_resolve.  Signature: {:?}.", signature);
-
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_FORMER_RESOLVE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_SET_VARIABLE_ORDER_INDEX,
+                )
             }
-            ABI_TYPE_FORMER_REGISTER_NAME => {
-                if !type_checking::check_type_former_register_signature(
+            ABI_PROOF_OBJECT_EXPORT_NAME => {
+                if !type_checking::check_proof_object_export_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_former_register.  Signature: {:?}.", signature);
-
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_FORMER_REGISTER_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_PROOF_OBJECT_EXPORT_INDEX,
+                )
             }
-            ABI_TYPE_FORMER_IS_REGISTERED_NAME => {
-                if !type_checking::check_type_former_is_registered_signature(
+            ABI_KERNEL_EXPORT_PROOF_NAME => {
+                if !type_checking::check_kernel_export_proof_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_former_is_registered.  Signature: {:?}.", signature);
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_EXPORT_PROOF_INDEX,
+                )
+            }
+            ABI_PROOF_OBJECT_IMPORT_NAME => {
+                if !type_checking::check_proof_object_import_signature(
+                    signature,
+                ) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_FORMER_IS_REGISTERED_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_PROOF_OBJECT_IMPORT_INDEX,
+                )
             }
-            ABI_TYPE_IS_REGISTERED_NAME => {
-                if !type_checking::check_type_is_registered_signature(signature)
+            ABI_ERROR_DETAIL_LAST_NAME => {
+                if !type_checking::check_error_detail_last_signature(signature)
                 {
-                    error!("Signature check failed when checking __type_is_registered.  Signature: {:?}.", signature);
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_ERROR_DETAIL_LAST_INDEX,
+                )
+            }
+            ABI_TYPE_GENERALISE_NAME => {
+                if !type_checking::check_type_generalise_signature(signature) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_IS_REGISTERED_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_GENERALISE_INDEX,
+                )
             }
-            ABI_TYPE_REGISTER_VARIABLE_NAME => {
-                if !type_checking::check_type_register_variable_signature(
+            ABI_TYPE_INSTANTIATE_FRESH_NAME => {
+                if !type_checking::check_type_instantiate_fresh_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_register_variable.  Signature: {:?}.", signature);
-
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_REGISTER_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_INSTANTIATE_FRESH_INDEX,
+                )
             }
-            ABI_TYPE_REGISTER_COMBINATION_NAME => {
-                if !type_checking::check_type_register_combination_signature(
+            ABI_THEOREM_ALPHA_EQUAL_NAME => {
+                if !type_checking::check_theorem_alpha_equal_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_register_combination.  Signature: {:?}.", signature);
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_ALPHA_EQUAL_INDEX,
+                )
+            }
+            ABI_CHALLENGE_CURRENT_NAME => {
+                if !type_checking::check_challenge_current_signature(signature)
+                {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_REGISTER_COMBINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CHALLENGE_CURRENT_INDEX,
+                )
             }
-            ABI_TYPE_REGISTER_FUNCTION_NAME => {
-                if !type_checking::check_type_register_function_signature(
+            ABI_OBJECT_INC_REF_NAME => {
+                if !type_checking::check_object_inc_ref_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
-                    error!("Signature check failed when checking __type_register_function.  Signature: {:?}.", signature);
+                    ABI_OBJECT_INC_REF_INDEX,
+                )
+            }
+            ABI_OBJECT_DEC_REF_NAME => {
+                if !type_checking::check_object_dec_ref_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_OBJECT_DEC_REF_INDEX,
+                )
+            }
+            ABI_OBJECT_DELETE_NAME => {
+                if !type_checking::check_object_delete_signature(signature) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_REGISTER_FUNCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_OBJECT_DELETE_INDEX,
+                )
             }
-            ABI_TYPE_SPLIT_VARIABLE_NAME => {
-                if !type_checking::check_type_split_variable_signature(
+            ABI_CONSTANT_DEFINE_NAME => {
+                if !type_checking::check_constant_define_signature(signature) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_DEFINE_INDEX,
+                )
+            }
+            ABI_SUBSTITUTION_COMPOSE_NAME => {
+                if !type_checking::check_substitution_compose_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_split_variable.  Signature: {:?}.", signature);
-
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_SPLIT_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SUBSTITUTION_COMPOSE_INDEX,
+                )
             }
-            ABI_TYPE_SPLIT_COMBINATION_NAME => {
-                if !type_checking::check_type_split_combination_signature(
+            ABI_THEOREM_TRUST_LEVEL_NAME => {
+                if !type_checking::check_theorem_trust_level_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_split_combination.  Signature: {:?}.", signature);
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_TRUST_LEVEL_INDEX,
+                )
+            }
+            ABI_TYPE_DEFINE_NAME => {
+                if !type_checking::check_type_define_signature(signature) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_SPLIT_COMBINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_DEFINE_INDEX,
+                )
             }
-            ABI_TYPE_SPLIT_FUNCTION_NAME => {
-                if !type_checking::check_type_split_function_signature(
+            ABI_TYPE_FORMER_LOOKUP_BY_NAME_NAME => {
+                if !type_checking::check_type_former_lookup_by_name_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_split_function.  Signature: {:?}.", signature);
-
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_SPLIT_FUNCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX,
+                )
             }
-            ABI_TYPE_TEST_VARIABLE_NAME => {
-                if !type_checking::check_type_test_variable_signature(signature)
-                {
-                    error!("Signature check failed when checking __type_test_variable.  Signature: {:?}.", signature);
+            ABI_CONSTANT_REGISTER_NAMED_NAME => {
+                if !type_checking::check_constant_register_named_signature(
+                    signature,
+                ) {
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
 
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_REGISTER_NAMED_INDEX,
+                )
+            }
+            ABI_CONSTANT_LOOKUP_BY_NAME_NAME => {
+                if !type_checking::check_constant_lookup_by_name_signature(
+                    signature,
+                ) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_TEST_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_LOOKUP_BY_NAME_INDEX,
+                )
             }
-            ABI_TYPE_TEST_COMBINATION_NAME => {
-                if !type_checking::check_type_test_combination_signature(
+            ABI_KERNEL_BATCH_EXECUTE_NAME => {
+                if !type_checking::check_kernel_batch_execute_signature(
                     signature,
                 ) {
-                    error!("Signature check failed when checking __type_test_combination.  Signature: {:?}.", signature);
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_BATCH_EXECUTE_INDEX,
+                )
+            }
+            ABI_HYPOTHESES_INTERN_NAME => {
+                if !type_checking::check_hypotheses_intern_signature(signature)
+                {
+                    error!("Signature check failed when checking __hypotheses_intern.  Signature: {:?}.", signature);
 
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_TEST_COMBINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_HYPOTHESES_INTERN_INDEX,
+                )
             }
-            ABI_TYPE_TEST_FUNCTION_NAME => {
-                if !type_checking::check_type_test_function_signature(signature)
+            ABI_HYPOTHESES_RESOLVE_NAME => {
+                if !type_checking::check_hypotheses_resolve_signature(signature)
                 {
-                    error!("Signature check failed when checking __type_test_function.  Signature: {:?}.", signature);
+                    error!("Signature check failed when checking __hypotheses_resolve.  Signature: {:?}.", signature);
 
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_TEST_FUNCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_HYPOTHESES_RESOLVE_INDEX,
+                )
             }
-            ABI_TYPE_SIZE_NAME => {
-                if !type_checking::check_type_size_signature(signature) {
-                    error!("Signature check failed when checking __type_size.  Signature: {:?}.", signature);
+            ABI_TERM_REGISTER_CONDITIONAL_NAME => {
+                if !type_checking::check_term_register_conditional_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __term_register_conditional.  Signature: {:?}.", signature);
 
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_SIZE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_CONDITIONAL_INDEX,
+                )
             }
-            ABI_TYPE_VARIABLES_NAME => {
-                if !type_checking::check_type_variables_signature(signature) {
-                    error!("Signature check failed when checking __type_variables.  Signature: {:?}.", signature);
+            ABI_TERM_REGISTER_LET_NAME => {
+                if !type_checking::check_term_register_let_signature(signature)
+                {
+                    error!("Signature check failed when checking __term_register_let.  Signature: {:?}.", signature);
 
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_VARIABLES_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_LET_INDEX,
+                )
             }
-            ABI_TYPE_SUBSTITUTE_NAME => {
-                if !type_checking::check_type_substitute_signature(signature) {
-                    error!("Signature check failed when checking __type_substitute.  Signature: {:?}.", signature);
+            ABI_TERM_SPLIT_CONDITIONAL_NAME => {
+                if !type_checking::check_term_split_conditional_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __term_split_conditional.  Signature: {:?}.", signature);
 
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TYPE_SUBSTITUTE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_CONDITIONAL_INDEX,
+                )
             }
-            ABI_CONSTANT_RESOLVE_NAME => {
-                if !type_checking::check_constant_resolve_signature(signature) {
+            ABI_TERM_SPLIT_LET_NAME => {
+                if !type_checking::check_term_split_let_signature(signature) {
+                    error!("Signature check failed when checking __term_split_let.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_CONSTANT_RESOLVE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_LET_INDEX,
+                )
             }
-            ABI_CONSTANT_IS_REGISTERED_NAME => {
-                if !type_checking::check_constant_is_registered_signature(
+            ABI_TERM_TEST_CONDITIONAL_NAME => {
+                if !type_checking::check_term_test_conditional_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_test_conditional.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_CONSTANT_IS_REGISTERED_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_CONDITIONAL_INDEX,
+                )
             }
-            ABI_CONSTANT_REGISTER_NAME => {
-                if !type_checking::check_constant_register_signature(signature)
-                {
+            ABI_TERM_TEST_LET_NAME => {
+                if !type_checking::check_term_test_let_signature(signature) {
+                    error!("Signature check failed when checking __term_test_let.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_CONSTANT_REGISTER_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_LET_INDEX,
+                )
             }
-            ABI_TERM_IS_REGISTERED_NAME => {
-                if !type_checking::check_term_is_registered_signature(signature)
-                {
+            ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_NAME => {
+                if !type_checking::check_theorem_register_conditional_congruence_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_conditional_congruence.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_IS_REGISTERED_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_VARIABLE_NAME => {
-                if !type_checking::check_term_register_variable_signature(
+            ABI_THEOREM_REGISTER_LET_CONGRUENCE_NAME => {
+                if !type_checking::check_theorem_register_let_congruence_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_let_congruence.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
+                }
+
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX,
+                )
+            }
+            ABI_TERM_REGISTER_PAIR_NAME => {
+                if !type_checking::check_term_register_pair_signature(signature)
+                {
+                    error!("Signature check failed when checking __term_register_pair.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_PAIR_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_CONSTANT_NAME => {
-                if !type_checking::check_term_register_constant_signature(
+            ABI_TERM_REGISTER_FIRST_NAME => {
+                if !type_checking::check_term_register_first_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_register_first.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_CONSTANT_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_FIRST_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_APPLICATION_NAME => {
-                if !type_checking::check_term_register_application_signature(
+            ABI_TERM_REGISTER_SECOND_NAME => {
+                if !type_checking::check_term_register_second_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_register_second.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_APPLICATION_INDEX,
-                ))
-            }
-            ABI_TERM_REGISTER_LAMBDA_NAME => {
-                if !type_checking::check_term_register_lambda_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_TERM_REGISTER_SECOND_INDEX,
+                )
+            }
+            ABI_TERM_SPLIT_PAIR_NAME => {
+                if !type_checking::check_term_split_pair_signature(signature) {
+                    error!("Signature check failed when checking __term_split_pair.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_LAMBDA_INDEX,
-                ))
-            }
-            ABI_TERM_REGISTER_NEGATION_NAME => {
-                if !type_checking::check_term_register_negation_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_TERM_SPLIT_PAIR_INDEX,
+                )
+            }
+            ABI_TERM_TEST_PAIR_NAME => {
+                if !type_checking::check_term_test_pair_signature(signature) {
+                    error!("Signature check failed when checking __term_test_pair.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_NEGATION_INDEX,
-                ))
-            }
-            ABI_TERM_REGISTER_CONJUNCTION_NAME => {
-                if !type_checking::check_term_register_conjunction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_TERM_TEST_PAIR_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_FIRST_PROJECTION_NAME => {
+                if !type_checking::check_theorem_register_first_projection_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_first_projection.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_CONJUNCTION_INDEX,
-                ))
-            }
-            ABI_TERM_REGISTER_DISJUNCTION_NAME => {
-                if !type_checking::check_term_register_disjunction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_SECOND_PROJECTION_NAME => {
+                if !type_checking::check_theorem_register_second_projection_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_second_projection.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_DISJUNCTION_INDEX,
-                ))
-            }
-            ABI_TERM_REGISTER_IMPLICATION_NAME => {
-                if !type_checking::check_term_register_implication_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_NAME => {
+                if !type_checking::check_theorem_register_surjective_pairing_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_surjective_pairing.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_IMPLICATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_EQUALITY_NAME => {
-                if !type_checking::check_term_register_equality_signature(
+            ABI_TERM_REGISTER_MEMBERSHIP_NAME => {
+                if !type_checking::check_term_register_membership_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_register_membership.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_EQUALITY_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_MEMBERSHIP_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_FORALL_NAME => {
-                if !type_checking::check_term_register_forall_signature(
+            ABI_TERM_REGISTER_COMPREHENSION_NAME => {
+                if !type_checking::check_term_register_comprehension_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_register_comprehension.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_FORALL_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_REGISTER_COMPREHENSION_INDEX,
+                )
             }
-            ABI_TERM_REGISTER_EXISTS_NAME => {
-                if !type_checking::check_term_register_exists_signature(
+            ABI_TERM_SPLIT_MEMBERSHIP_NAME => {
+                if !type_checking::check_term_split_membership_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_split_membership.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_REGISTER_EXISTS_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_MEMBERSHIP_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_VARIABLE_NAME => {
-                if !type_checking::check_term_split_variable_signature(
+            ABI_TERM_SPLIT_COMPREHENSION_NAME => {
+                if !type_checking::check_term_split_comprehension_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_split_comprehension.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SPLIT_COMPREHENSION_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_CONSTANT_NAME => {
-                if !type_checking::check_term_split_constant_signature(
+            ABI_TERM_TEST_MEMBERSHIP_NAME => {
+                if !type_checking::check_term_test_membership_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_test_membership.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_CONSTANT_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_MEMBERSHIP_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_APPLICATION_NAME => {
-                if !type_checking::check_term_split_application_signature(
+            ABI_TERM_TEST_COMPREHENSION_NAME => {
+                if !type_checking::check_term_test_comprehension_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_test_comprehension.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_APPLICATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TEST_COMPREHENSION_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_LAMBDA_NAME => {
-                if !type_checking::check_term_split_lambda_signature(signature)
-                {
+            ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_membership_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_membership_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_LAMBDA_INDEX,
-                ))
-            }
-            ABI_TERM_SPLIT_NEGATION_NAME => {
-                if !type_checking::check_term_split_negation_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_comprehension_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_comprehension_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_NEGATION_INDEX,
-                ))
-            }
-            ABI_TERM_SPLIT_CONJUNCTION_NAME => {
-                if !type_checking::check_term_split_conjunction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_empty_set_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_empty_set_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_CONJUNCTION_INDEX,
-                ))
-            }
-            ABI_TERM_SPLIT_DISJUNCTION_NAME => {
-                if !type_checking::check_term_split_disjunction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_universal_set_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_universal_set_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_DISJUNCTION_INDEX,
-                ))
-            }
-            ABI_TERM_SPLIT_IMPLICATION_NAME => {
-                if !type_checking::check_term_split_implication_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_UNION_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_union_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_union_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_IMPLICATION_INDEX,
-                ))
-            }
-            ABI_TERM_SPLIT_EQUALITY_NAME => {
-                if !type_checking::check_term_split_equality_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX,
+                )
+            }
+            ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_intersection_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_intersection_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_EQUALITY_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_FORALL_NAME => {
-                if !type_checking::check_term_split_forall_signature(signature)
-                {
+            ABI_THEOREM_REGISTER_SUBSET_DEFINITION_NAME => {
+                if !type_checking::check_theorem_register_subset_definition_signature(signature) {
+                    error!("Signature check failed when checking __theorem_register_subset_definition.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_FORALL_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX,
+                )
             }
-            ABI_TERM_SPLIT_EXISTS_NAME => {
-                if !type_checking::check_term_split_exists_signature(signature)
-                {
+            ABI_CHALLENGE_DISCHARGE_NAME => {
+                if !type_checking::check_challenge_discharge_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __challenge_discharge.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SPLIT_EXISTS_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CHALLENGE_DISCHARGE_INDEX,
+                )
             }
-            ABI_TERM_TEST_VARIABLE_NAME => {
-                if !type_checking::check_term_test_variable_signature(signature)
+            ABI_CAPABILITY_PRESENT_NAME => {
+                if !type_checking::check_capability_present_signature(signature)
                 {
+                    error!("Signature check failed when checking __capability_present.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_VARIABLE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CAPABILITY_PRESENT_INDEX,
+                )
             }
-            ABI_TERM_TEST_CONSTANT_NAME => {
-                if !type_checking::check_term_test_constant_signature(signature)
-                {
+            ABI_TERM_FOLD_NAME => {
+                if !type_checking::check_term_fold_signature(signature) {
+                    error!("Signature check failed when checking __term_fold.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_CONSTANT_INDEX,
-                ))
-            }
-            ABI_TERM_TEST_APPLICATION_NAME => {
-                if !type_checking::check_term_test_application_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_TERM_FOLD_INDEX,
+                )
+            }
+            ABI_THEOREM_PROOF_NAME => {
+                if !type_checking::check_theorem_proof_signature(signature) {
+                    error!("Signature check failed when checking __theorem_proof.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_APPLICATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_PROOF_INDEX,
+                )
             }
-            ABI_TERM_TEST_LAMBDA_NAME => {
-                if !type_checking::check_term_test_lambda_signature(signature) {
+            ABI_PROOF_SPLIT_NAME => {
+                if !type_checking::check_proof_split_signature(signature) {
+                    error!("Signature check failed when checking __proof_split.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_LAMBDA_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_PROOF_SPLIT_INDEX,
+                )
             }
-            ABI_TERM_TEST_NEGATION_NAME => {
-                if !type_checking::check_term_test_negation_signature(signature)
-                {
+            ABI_PROOF_SPLIT_ARGUMENTS_NAME => {
+                if !type_checking::check_proof_split_arguments_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __proof_split_arguments.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_NEGATION_INDEX,
-                ))
-            }
-            ABI_TERM_TEST_CONJUNCTION_NAME => {
-                if !type_checking::check_term_test_conjunction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_PROOF_SPLIT_ARGUMENTS_INDEX,
+                )
+            }
+            ABI_PROOF_CHECK_NAME => {
+                if !type_checking::check_proof_check_signature(signature) {
+                    error!("Signature check failed when checking __proof_check.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_CONJUNCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_PROOF_CHECK_INDEX,
+                )
             }
-            ABI_TERM_TEST_DISJUNCTION_NAME => {
-                if !type_checking::check_term_test_disjunction_signature(
+            ABI_THEOREM_STATEMENT_HASH_NAME => {
+                if !type_checking::check_theorem_statement_hash_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __theorem_statement_hash.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_DISJUNCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_STATEMENT_HASH_INDEX,
+                )
             }
-            ABI_TERM_TEST_IMPLICATION_NAME => {
-                if !type_checking::check_term_test_implication_signature(
+            ABI_THEOREM_STATEMENT_AS_TERM_NAME => {
+                if !type_checking::check_theorem_statement_as_term_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __theorem_statement_as_term.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_IMPLICATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_STATEMENT_AS_TERM_INDEX,
+                )
             }
-            ABI_TERM_TEST_EQUALITY_NAME => {
-                if !type_checking::check_term_test_equality_signature(signature)
+            ABI_QUOTIENT_REGISTER_NAME => {
+                if !type_checking::check_quotient_register_signature(signature)
                 {
-                    return Err(WasmiError::Trap(runtime_trap::host_trap(
-                        RuntimeTrap::SignatureFailure,
-                    )));
-                }
+                    error!("Signature check failed when checking __quotient_register.  Signature: {:?}.", signature);
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_EQUALITY_INDEX,
-                ))
-            }
-            ABI_TERM_TEST_FORALL_NAME => {
-                if !type_checking::check_term_test_forall_signature(signature) {
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_FORALL_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_QUOTIENT_REGISTER_INDEX,
+                )
             }
-            ABI_TERM_TEST_EXISTS_NAME => {
-                if !type_checking::check_term_test_exists_signature(signature) {
+            ABI_QUOTIENT_SPLIT_NAME => {
+                if !type_checking::check_quotient_split_signature(signature) {
+                    error!("Signature check failed when checking __quotient_split.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TEST_EXISTS_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_QUOTIENT_SPLIT_INDEX,
+                )
             }
-            ABI_TERM_FREE_VARIABLES_NAME => {
-                if !type_checking::check_term_fv_signature(signature) {
+            ABI_QUOTIENT_LIFT_NAME => {
+                if !type_checking::check_quotient_lift_signature(signature) {
+                    error!("Signature check failed when checking __quotient_lift.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_FREE_VARIABLES_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_QUOTIENT_LIFT_INDEX,
+                )
             }
-            ABI_TERM_SUBSTITUTE_NAME => {
-                if !type_checking::check_term_substitute_signature(signature) {
+            ABI_QUOTIENT_TRANSFER_NAME => {
+                if !type_checking::check_quotient_transfer_signature(signature)
+                {
+                    error!("Signature check failed when checking __quotient_transfer.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_SUBSTITUTE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_QUOTIENT_TRANSFER_INDEX,
+                )
             }
-            ABI_TERM_TYPE_VARIABLES_NAME => {
-                if !type_checking::check_term_type_variables_signature(
+            ABI_SUBSTITUTION_REGISTER_TYPE_NAME => {
+                if !type_checking::check_substitution_register_type_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __substitution_register_type.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TYPE_VARIABLES_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SUBSTITUTION_REGISTER_TYPE_INDEX,
+                )
             }
-            ABI_TERM_TYPE_SUBSTITUTE_NAME => {
-                if !type_checking::check_term_type_substitute_signature(
+            ABI_SUBSTITUTION_REGISTER_TERM_NAME => {
+                if !type_checking::check_substitution_register_term_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __substitution_register_term.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TYPE_SUBSTITUTE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SUBSTITUTION_REGISTER_TERM_INDEX,
+                )
             }
-            ABI_TERM_TYPE_INFER_NAME => {
-                if !type_checking::check_term_type_infer_signature(signature) {
+            ABI_TYPE_SUBSTITUTE_WITH_NAME => {
+                if !type_checking::check_type_substitute_with_signature(
+                    signature,
+                ) {
+                    error!("Signature check failed when checking __type_substitute_with.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TYPE_INFER_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_SUBSTITUTE_WITH_INDEX,
+                )
             }
-            ABI_TERM_TYPE_IS_PROPOSITION_NAME => {
-                if !type_checking::check_term_type_is_proposition_signature(
+            ABI_TERM_SUBSTITUTE_WITH_NAME => {
+                if !type_checking::check_term_substitute_with_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_substitute_with.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_TERM_TYPE_IS_PROPOSITION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_SUBSTITUTE_WITH_INDEX,
+                )
             }
-            ABI_THEOREM_IS_REGISTERED_NAME => {
-                if !type_checking::check_theorem_is_registered_signature(
+            ABI_TERM_TYPE_SUBSTITUTE_WITH_NAME => {
+                if !type_checking::check_term_type_substitute_with_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __term_type_substitute_with.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_IS_REGISTERED_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_ASSUMPTION_NAME => {
-                if !type_checking::check_theorem_register_assumption_signature(
+            ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_NAME => {
+                if !type_checking::check_theorem_register_forall_congruence_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __theorem_register_forall_congruence.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_ASSUMPTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_REFLEXIVITY_NAME => {
-                if !type_checking::check_theorem_register_reflexivity_signature(
+            ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_NAME => {
+                if !type_checking::check_theorem_register_exists_congruence_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __theorem_register_exists_congruence.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_REFLEXIVITY_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_SYMMETRY_NAME => {
-                if !type_checking::check_theorem_register_symmetry_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX,
+                )
+            }
+            ABI_BUDGET_REMAINING_NAME => {
+                if !type_checking::check_budget_remaining_signature(signature) {
+                    error!("Signature check failed when checking __budget_remaining.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_SYMMETRY_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_TRANSITIVITY_NAME => {
-                if !type_checking::check_theorem_register_transitivity_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_BUDGET_REMAINING_INDEX,
+                )
+            }
+            ABI_BUDGET_RESOURCE_NAME => {
+                if !type_checking::check_budget_resource_signature(signature) {
+                    error!("Signature check failed when checking __budget_resource.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_TRANSITIVITY_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_BUDGET_RESOURCE_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_APPLICATION_NAME => {
-                if !type_checking::check_theorem_register_application_signature(
+            ABI_KERNEL_FEATURE_SUPPORTED_NAME => {
+                if !type_checking::check_kernel_feature_supported_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __kernel_feature_supported.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_APPLICATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_KERNEL_FEATURE_SUPPORTED_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_LAMBDA_NAME => {
-                if !type_checking::check_theorem_register_lambda_signature(
+            ABI_SEQUENT_IS_REGISTERED_NAME => {
+                if !type_checking::check_sequent_is_registered_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_is_registered.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_LAMBDA_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_IS_REGISTERED_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_BETA_NAME => {
-                if !type_checking::check_theorem_register_beta_signature(
+            ABI_SEQUENT_SPLIT_PREMISSES_NAME => {
+                if !type_checking::check_sequent_split_premisses_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_split_premisses.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_BETA_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_SPLIT_PREMISSES_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_ETA_NAME => {
-                if !type_checking::check_theorem_register_eta_signature(
+            ABI_SEQUENT_SPLIT_CONCLUSIONS_NAME => {
+                if !type_checking::check_sequent_split_conclusions_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_split_conclusions.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_ETA_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_SUBSTITUTE_NAME => {
-                if !type_checking::check_theorem_register_substitute_signature(
+            ABI_SEQUENT_REGISTER_AXIOM_NAME => {
+                if !type_checking::check_sequent_register_axiom_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_register_axiom.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_SUBSTITUTE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_AXIOM_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_NAME => {
-                if !type_checking::check_theorem_register_type_substitute_signature(
+            ABI_SEQUENT_REGISTER_THEOREM_NAME => {
+                if !type_checking::check_sequent_register_theorem_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_register_theorem.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_TYPE_SUBSTITUTE_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_THEOREM_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_truth_introduction_signature(
+            ABI_SEQUENT_REGISTER_WEAKEN_LEFT_NAME => {
+                if !type_checking::check_sequent_register_weaken_left_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_register_weaken_left.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_TRUTH_INTRODUCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_falsity_elimination_signature(
+            ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_NAME => {
+                if !type_checking::check_sequent_register_weaken_right_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_register_weaken_right.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_FALSITY_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_conjunction_introduction_signature(
+            ABI_SEQUENT_REGISTER_CUT_NAME => {
+                if !type_checking::check_sequent_register_cut_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __sequent_register_cut.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_CONJUNCTION_INTRODUCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_CUT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_conjunction_left_elimination_signature(signature) {
-                    return Err(WasmiError::Trap(
-                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+            ABI_SEQUENT_REGISTER_NEGATION_LEFT_NAME => {
+                if !type_checking::check_sequent_register_negation_left_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_negation_left.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_CONJUNCTION_LEFT_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_conjunction_right_elimination_signature(signature) {
-                    return Err(WasmiError::Trap(
-                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+            ABI_SEQUENT_REGISTER_NEGATION_RIGHT_NAME => {
+                if !type_checking::check_sequent_register_negation_right_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_negation_right.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_CONJUNCTION_RIGHT_ELIMINATION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_disjunction_elimination_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX,
+                )
+            }
+            ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_NAME => {
+                if !type_checking::check_sequent_register_conjunction_left_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_conjunction_left.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_DISJUNCTION_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_disjunction_left_introduction_signature(signature) {
-                    return Err(WasmiError::Trap(
-                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+            ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_NAME => {
+                if !type_checking::check_sequent_register_conjunction_right_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_conjunction_right.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_DISJUNCTION_LEFT_INTRODUCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_disjunction_right_introduction_signature(signature) {
-                    return Err(WasmiError::Trap(
-                        runtime_trap::host_trap(RuntimeTrap::SignatureFailure)));
+            ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_NAME => {
+                if !type_checking::check_sequent_register_disjunction_left_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_disjunction_left.  Signature: {:?}.", signature);
+
+                    return Err(WasmiError::Trap(runtime_trap::host_trap(
+                        RuntimeTrap::SignatureFailure,
+                    )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_DISJUNCTION_RIGHT_INTRODUCTION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_implication_introduction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX,
+                )
+            }
+            ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_NAME => {
+                if !type_checking::check_sequent_register_disjunction_right_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_disjunction_right.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_IMPLICATION_INTRODUCTION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_implication_elimination_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX,
+                )
+            }
+            ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_NAME => {
+                if !type_checking::check_sequent_register_implication_left_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_implication_left.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_IFF_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_iff_introduction_signature(signature)
-                {
+            ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_NAME => {
+                if !type_checking::check_sequent_register_implication_right_signature(signature) {
+                    error!("Signature check failed when checking __sequent_register_implication_right.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_IFF_INTRODUCTION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_iff_left_elimination_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX,
+                )
+            }
+            ABI_SPECULATE_BEGIN_NAME => {
+                if !type_checking::check_speculate_begin_signature(signature) {
+                    error!("Signature check failed when checking __speculate_begin.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_negation_introduction_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SPECULATE_BEGIN_INDEX,
+                )
+            }
+            ABI_SPECULATE_END_NAME => {
+                if !type_checking::check_speculate_end_signature(signature) {
+                    error!("Signature check failed when checking __speculate_end.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_NEGATION_INTRODUCTION_INDEX,
-                ))
-            }
-            ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_negation_elimination_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SPECULATE_END_INDEX,
+                )
+            }
+            ABI_TYPE_FORMER_REGISTER_WITH_METADATA_NAME => {
+                if !type_checking::check_type_former_register_with_metadata_signature(signature) {
+                    error!("Signature check failed when checking __type_former_register_with_metadata.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_NEGATION_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_forall_introduction_signature(
+            ABI_TYPE_FORMER_METADATA_NAME => {
+                if !type_checking::check_type_former_metadata_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __type_former_metadata.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_FORALL_INTRODUCTION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_TYPE_FORMER_METADATA_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_FORALL_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_forall_elimination_signature(
+            ABI_CONSTANT_MARK_CONSTRUCTORS_NAME => {
+                if !type_checking::check_constant_mark_constructors_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __constant_mark_constructors.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_FORALL_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_NAME => {
-                if !type_checking::check_theorem_register_exists_elimination_signature(
+            ABI_CONSTANT_IS_CONSTRUCTOR_NAME => {
+                if !type_checking::check_constant_is_constructor_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __constant_is_constructor.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX,
-                ))
+                self.alloc_host_function(
+                    field_name,
+                    signature,
+                    ABI_CONSTANT_IS_CONSTRUCTOR_INDEX,
+                )
             }
-            ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_NAME => {
-                if !type_checking::check_theorem_register_exists_introduction_signature(
+            ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_NAME => {
+                if !type_checking::check_constant_constructor_siblings_signature(
                     signature,
                 ) {
+                    error!("Signature check failed when checking __constant_constructor_siblings.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_REGISTER_EXISTS_INTRODUCTION_INDEX,
-                ))
-            }
-            ABI_THEOREM_SPLIT_CONCLUSION_NAME => {
-                if !type_checking::check_theorem_split_conclusion_signature(
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX,
+                )
+            }
+            ABI_SHOULD_ABORT_NAME => {
+                if !type_checking::check_should_abort_signature(signature) {
+                    error!("Signature check failed when checking __should_abort.  Signature: {:?}.", signature);
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_SPLIT_CONCLUSION_INDEX,
-                ))
-            }
-            ABI_THEOREM_SPLIT_HYPOTHESES_NAME => {
-                if !type_checking::check_theorem_split_hypotheses_signature(
+                self.cooperative.set(true);
+
+                self.alloc_host_function(
+                    field_name,
                     signature,
-                ) {
+                    ABI_SHOULD_ABORT_INDEX,
+                )
+            }
+            _otherwise => {
+                Err(runtime_trap::host_error(KernelErrorCode::NoSuchFunction))
+            }
+        }
+    }
+
+    fn resolve_global(
+        &self,
+        field_name: &str,
+        descriptor: &GlobalDescriptor,
+    ) -> Result<GlobalRef, WasmiError> {
+        match field_name {
+            ABI_HOST_TABLE_VERSION_NAME => {
+                if self.host_table.is_none() {
+                    error!("Host function table version imported, but the host function table is disabled.");
+
+                    return Err(WasmiError::Instantiation(format!(
+                        "Global {} imported, but the host function table is disabled.",
+                        field_name
+                    )));
+                }
+
+                if descriptor.value_type() != ValueType::I32
+                    || descriptor.is_mutable()
+                {
+                    error!("Type check failed when checking __supervisionary_host_table_version.  Type: {:?}, mutable: {}.", descriptor.value_type(), descriptor.is_mutable());
+
                     return Err(WasmiError::Trap(runtime_trap::host_trap(
                         RuntimeTrap::SignatureFailure,
                     )));
                 }
 
-                Ok(FuncInstance::alloc_host(
-                    signature.clone(),
-                    ABI_THEOREM_SPLIT_HYPOTHESES_INDEX,
+                Ok(GlobalInstance::alloc(
+                    RuntimeValue::I32(ABI_HOST_TABLE_VERSION),
+                    false,
                 ))
             }
-            _otherwise => {
-                Err(runtime_trap::host_error(KernelErrorCode::NoSuchFunction))
+            _otherwise => Err(WasmiError::Instantiation(format!(
+                "Export {} not found.",
+                field_name
+            ))),
+        }
+    }
+
+    fn resolve_table(
+        &self,
+        field_name: &str,
+        _descriptor: &TableDescriptor,
+    ) -> Result<TableRef, WasmiError> {
+        match (field_name, &self.host_table) {
+            (ABI_HOST_TABLE_NAME, Some(table)) => Ok(table.clone()),
+            (ABI_HOST_TABLE_NAME, None) => {
+                error!("Host function table imported, but the host function table is disabled.");
+
+                Err(WasmiError::Instantiation(format!(
+                    "Table {} imported, but the host function table is disabled.",
+                    field_name
+                )))
             }
+            _otherwise => Err(WasmiError::Instantiation(format!(
+                "Export {} not found.",
+                field_name
+            ))),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests.
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        runtime_state::WasmiRuntimeState,
+        runtime_trap::{self, RuntimeTrap},
+        system_call_numbers::ABI_TERM_REGISTER_VARIABLE_INDEX,
+        system_interface_types::{narrow, OutOfRange},
+    };
+    use kernel::error_code::ErrorCode as KernelErrorCode;
+    use std::convert::TryFrom;
+    use wasmi::{RuntimeValue, Trap};
+
+    /// Tests that `narrow` accepts exactly the integers representable as a
+    /// `usize` on the host.
+    #[test]
+    pub fn narrow0() {
+        assert_eq!(narrow(0), Ok(0));
+        assert_eq!(narrow(usize::MAX as u64), Ok(usize::MAX));
+
+        match usize::try_from(u64::MAX) {
+            Ok(max) => assert_eq!(narrow(u64::MAX), Ok(max)),
+            Err(_e) => assert_eq!(narrow(u64::MAX), Err(OutOfRange)),
         }
     }
+
+    /// Tests that an argument which could not be narrowed is reported to the
+    /// guest as `ErrorCode::ArgumentOutOfRange`, and that the results of a
+    /// system call are otherwise returned unchanged.
+    #[test]
+    pub fn catch_out_of_range0() {
+        let argument_out_of_range =
+            RuntimeValue::I32(KernelErrorCode::ArgumentOutOfRange.into());
+        let success = RuntimeValue::I32(KernelErrorCode::Success.into());
+
+        /* NB: every `u64` fits in a `usize` on a 64-bit host, so the trap that
+         * `narrow` raises through `?` is constructed directly.
+         */
+        assert_eq!(
+            WasmiRuntimeState::catch_out_of_range(
+                ABI_TERM_REGISTER_VARIABLE_INDEX,
+                Err(Trap::from(OutOfRange)),
+            )
+            .unwrap(),
+            Some(argument_out_of_range)
+        );
+        assert_eq!(
+            WasmiRuntimeState::catch_out_of_range(
+                ABI_TERM_REGISTER_VARIABLE_INDEX,
+                Ok(Some(success)),
+            )
+            .unwrap(),
+            Some(success)
+        );
+        assert!(WasmiRuntimeState::catch_out_of_range(
+            ABI_TERM_REGISTER_VARIABLE_INDEX,
+            Err(runtime_trap::host_trap(RuntimeTrap::MemoryNotRegistered)),
+        )
+        .is_err());
+    }
 }
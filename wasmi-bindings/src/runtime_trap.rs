@@ -36,8 +36,32 @@ pub enum RuntimeTrap {
     MemoryWriteFailed,
     /// The WASM guest program tried to call a function that does not exist.
     NoSuchFunction,
+    /// The WASM guest program tried to import an experimental host function,
+    /// but experimental host functions have not been enabled.
+    ExperimentalFunctionDisabled,
     /// A type-signature check on a host-function failed.
     SignatureFailure,
+    /// The WASM guest's function table was not registered with the runtime
+    /// state, so guest callbacks cannot be resolved.
+    TableNotRegistered,
+    /// The WASM guest passed a table index that does not refer to a function.
+    NoSuchCallback,
+    /// The host asked the WASM guest program to stop, for example because the
+    /// user interrupted it, and the guest made a host call without first
+    /// acknowledging the request.
+    Aborted,
+    /// The WASM guest program made a host call after every copy of the
+    /// resource assertion budgeted for it had been consumed.
+    #[cfg(feature = "budgets")]
+    BudgetExhausted,
+    /// The WASM guest program made a host call whose results would overlap in
+    /// its memory, as found by the debugging `canaries` feature.
+    #[cfg(feature = "canaries")]
+    OverlappingOutputs,
+    /// A host call wrote to the WASM guest's memory immediately around one of
+    /// its results, as found by the debugging `canaries` feature.
+    #[cfg(feature = "canaries")]
+    CanaryClobbered,
 }
 
 /// Pretty-printing for `RuntimeTrap` values.
@@ -51,6 +75,22 @@ impl Display for RuntimeTrap {
             }
             RuntimeTrap::MemoryReadFailed => write!(f, "MemoryReadFailed"),
             RuntimeTrap::MemoryWriteFailed => write!(f, "MemoryWriteFailed"),
+            RuntimeTrap::ExperimentalFunctionDisabled => {
+                write!(f, "ExperimentalFunctionDisabled")
+            }
+            RuntimeTrap::TableNotRegistered => {
+                write!(f, "TableNotRegistered")
+            }
+            RuntimeTrap::NoSuchCallback => write!(f, "NoSuchCallback"),
+            RuntimeTrap::Aborted => write!(f, "Aborted"),
+            #[cfg(feature = "budgets")]
+            RuntimeTrap::BudgetExhausted => write!(f, "BudgetExhausted"),
+            #[cfg(feature = "canaries")]
+            RuntimeTrap::OverlappingOutputs => {
+                write!(f, "OverlappingOutputs")
+            }
+            #[cfg(feature = "canaries")]
+            RuntimeTrap::CanaryClobbered => write!(f, "CanaryClobbered"),
         }
     }
 }
@@ -2,6 +2,17 @@
 //!
 //! Defines the number (and name) of each Supervisionary system call.
 //!
+//! System call numbers are split into two ranges.  Calls numbered below
+//! `ABI_EXPERIMENTAL_INDEX_LOWER_BOUND` are *stable*, and always available to
+//! guests.  Calls numbered at or above it are *experimental*: their interface
+//! may still change, and guests may only import them if the host explicitly
+//! enables experimental calls.  A call graduates to stable by being given a
+//! number in the stable range.
+//!
+//! Host calls may also be reached through the *host function table*, for the
+//! benefit of dynamically-linked guests, wherein the slot of each call is
+//! fixed by a versioned layout derived from its number.
+//!
 //! # Authors
 //!
 //! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
@@ -17,6 +28,31 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
+/// The lower bound (inclusive) of the experimental system call numbers.  All
+/// system calls numbered below this bound are stable.
+pub(crate) const ABI_EXPERIMENTAL_INDEX_LOWER_BOUND: usize = 1024;
+
+/// Returns `true` iff the system call numbered `index` is experimental.
+#[inline]
+pub(crate) fn is_experimental_index(index: usize) -> bool {
+    index >= ABI_EXPERIMENTAL_INDEX_LOWER_BOUND
+}
+
+/// The version of the layout of the host function table.  In version `1`, the
+/// host call numbered `n` occupies slot `n` of the table, so that a call keeps
+/// its slot for as long as it keeps its number.
+pub(crate) const ABI_HOST_TABLE_VERSION: i32 = 1;
+/// The number of slots in version `1` of the host function table.  Calls
+/// numbered at or above this bound require a new version of the layout.
+pub(crate) const ABI_HOST_TABLE_SIZE: u32 = 2048;
+
+/// The name under which guests import the host function table.
+pub(crate) const ABI_HOST_TABLE_NAME: &str = "__supervisionary_host_table";
+/// The name under which guests import the version of the layout of the host
+/// function table.
+pub(crate) const ABI_HOST_TABLE_VERSION_NAME: &str =
+    "__supervisionary_host_table_version";
+
 /// The name of the `TypeFormer.Resolve` ABI call.
 pub(crate) const ABI_TYPE_FORMER_RESOLVE_NAME: &str = "__type_former_resolve";
 /// The name of the `TypeFormer.IsRegistered` ABI call.
@@ -376,7 +412,7 @@ pub(crate) const ABI_THEOREM_REGISTER_IMPLICATION_ELIMINATION_NAME: &str =
 
 /// The name of the `Theorem.Register.IffIntroduction` ABI call.
 pub(crate) const ABI_THEOREM_REGISTER_IFF_INTRODUCTION_NAME: &str =
-    "__theorem_register_iff_elimination";
+    "__theorem_register_iff_introduction";
 /// The name of the `Theorem.Register.IffLeftElimination` ABI call.
 pub(crate) const ABI_THEOREM_REGISTER_IFF_LEFT_ELIMINATION_NAME: &str =
     "__theorem_register_iff_left_elimination";
@@ -407,6 +443,8 @@ pub(crate) const ABI_THEOREM_SPLIT_HYPOTHESES_NAME: &str =
 /// The name of the `Theorem.Split.Conclusion` ABI call.
 pub(crate) const ABI_THEOREM_SPLIT_CONCLUSION_NAME: &str =
     "__theorem_split_conclusion";
+/// The name of the `Theorem.Size` ABI call.
+pub(crate) const ABI_THEOREM_SIZE_NAME: &str = "__theorem_size";
 
 /// The index of the `Theorem.IsRegistered` ABI call.
 pub(crate) const ABI_THEOREM_IS_REGISTERED_INDEX: usize = 59;
@@ -489,3 +527,797 @@ pub(crate) const ABI_THEOREM_REGISTER_EXISTS_ELIMINATION_INDEX: usize = 88;
 pub(crate) const ABI_THEOREM_SPLIT_HYPOTHESES_INDEX: usize = 89;
 /// The index of the `Theorem.Split.Conclusion` ABI call.
 pub(crate) const ABI_THEOREM_SPLIT_CONCLUSION_INDEX: usize = 90;
+/// The index of the `Theorem.Size` ABI call.
+pub(crate) const ABI_THEOREM_SIZE_INDEX: usize = 91;
+
+/// The name of the `Kernel.Enumerate` ABI call.
+pub(crate) const ABI_KERNEL_ENUMERATE_NAME: &str = "__kernel_enumerate";
+
+/// The index of the `Kernel.Enumerate` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_ENUMERATE_INDEX: usize = 1024;
+
+/// The name of the `Hypotheses.Intern` ABI call.
+pub(crate) const ABI_HYPOTHESES_INTERN_NAME: &str = "__hypotheses_intern";
+/// The name of the `Hypotheses.Resolve` ABI call.
+pub(crate) const ABI_HYPOTHESES_RESOLVE_NAME: &str = "__hypotheses_resolve";
+
+/// The index of the `Hypotheses.Intern` ABI call.  Experimental.
+pub(crate) const ABI_HYPOTHESES_INTERN_INDEX: usize = 1025;
+/// The index of the `Hypotheses.Resolve` ABI call.  Experimental.
+pub(crate) const ABI_HYPOTHESES_RESOLVE_INDEX: usize = 1026;
+
+/// The name of the `Kernel.Identify` ABI call.
+pub(crate) const ABI_KERNEL_IDENTIFY_NAME: &str = "__kernel_identify";
+
+/// The index of the `Kernel.Identify` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_IDENTIFY_INDEX: usize = 1027;
+
+/// The name of the `Term.Register.Conditional` ABI call.
+pub(crate) const ABI_TERM_REGISTER_CONDITIONAL_NAME: &str =
+    "__term_register_conditional";
+/// The name of the `Term.Register.Let` ABI call.
+pub(crate) const ABI_TERM_REGISTER_LET_NAME: &str = "__term_register_let";
+/// The name of the `Term.Split.Conditional` ABI call.
+pub(crate) const ABI_TERM_SPLIT_CONDITIONAL_NAME: &str =
+    "__term_split_conditional";
+/// The name of the `Term.Split.Let` ABI call.
+pub(crate) const ABI_TERM_SPLIT_LET_NAME: &str = "__term_split_let";
+/// The name of the `Term.Test.Conditional` ABI call.
+pub(crate) const ABI_TERM_TEST_CONDITIONAL_NAME: &str =
+    "__term_test_conditional";
+/// The name of the `Term.Test.Let` ABI call.
+pub(crate) const ABI_TERM_TEST_LET_NAME: &str = "__term_test_let";
+/// The name of the `Theorem.Register.ConditionalCongruence` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_NAME: &str =
+    "__theorem_register_conditional_congruence";
+/// The name of the `Theorem.Register.LetCongruence` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_LET_CONGRUENCE_NAME: &str =
+    "__theorem_register_let_congruence";
+
+/// The index of the `Term.Register.Conditional` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_CONDITIONAL_INDEX: usize = 1028;
+/// The index of the `Term.Register.Let` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_LET_INDEX: usize = 1029;
+/// The index of the `Term.Split.Conditional` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_CONDITIONAL_INDEX: usize = 1030;
+/// The index of the `Term.Split.Let` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_LET_INDEX: usize = 1031;
+/// The index of the `Term.Test.Conditional` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TEST_CONDITIONAL_INDEX: usize = 1032;
+/// The index of the `Term.Test.Let` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TEST_LET_INDEX: usize = 1033;
+/// The index of the `Theorem.Register.ConditionalCongruence` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_CONDITIONAL_CONGRUENCE_INDEX: usize =
+    1034;
+/// The index of the `Theorem.Register.LetCongruence` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_LET_CONGRUENCE_INDEX: usize = 1035;
+
+/// The name of the `Term.Register.Pair` ABI call.
+pub(crate) const ABI_TERM_REGISTER_PAIR_NAME: &str = "__term_register_pair";
+/// The name of the `Term.Register.First` ABI call.
+pub(crate) const ABI_TERM_REGISTER_FIRST_NAME: &str = "__term_register_first";
+/// The name of the `Term.Register.Second` ABI call.
+pub(crate) const ABI_TERM_REGISTER_SECOND_NAME: &str = "__term_register_second";
+/// The name of the `Term.Split.Pair` ABI call.
+pub(crate) const ABI_TERM_SPLIT_PAIR_NAME: &str = "__term_split_pair";
+/// The name of the `Term.Test.Pair` ABI call.
+pub(crate) const ABI_TERM_TEST_PAIR_NAME: &str = "__term_test_pair";
+/// The name of the `Theorem.Register.FirstProjection` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_FIRST_PROJECTION_NAME: &str =
+    "__theorem_register_first_projection";
+/// The name of the `Theorem.Register.SecondProjection` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_SECOND_PROJECTION_NAME: &str =
+    "__theorem_register_second_projection";
+/// The name of the `Theorem.Register.SurjectivePairing` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_NAME: &str =
+    "__theorem_register_surjective_pairing";
+
+/// The index of the `Term.Register.Pair` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_PAIR_INDEX: usize = 1036;
+/// The index of the `Term.Register.First` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_FIRST_INDEX: usize = 1037;
+/// The index of the `Term.Register.Second` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_SECOND_INDEX: usize = 1038;
+/// The index of the `Term.Split.Pair` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_PAIR_INDEX: usize = 1039;
+/// The index of the `Term.Test.Pair` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TEST_PAIR_INDEX: usize = 1040;
+/// The index of the `Theorem.Register.FirstProjection` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_FIRST_PROJECTION_INDEX: usize = 1041;
+/// The index of the `Theorem.Register.SecondProjection` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_SECOND_PROJECTION_INDEX: usize = 1042;
+/// The index of the `Theorem.Register.SurjectivePairing` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_SURJECTIVE_PAIRING_INDEX: usize = 1043;
+
+/// The name of the `Term.Register.Membership` ABI call.
+pub(crate) const ABI_TERM_REGISTER_MEMBERSHIP_NAME: &str =
+    "__term_register_membership";
+/// The name of the `Term.Register.Comprehension` ABI call.
+pub(crate) const ABI_TERM_REGISTER_COMPREHENSION_NAME: &str =
+    "__term_register_comprehension";
+/// The name of the `Term.Split.Membership` ABI call.
+pub(crate) const ABI_TERM_SPLIT_MEMBERSHIP_NAME: &str =
+    "__term_split_membership";
+/// The name of the `Term.Split.Comprehension` ABI call.
+pub(crate) const ABI_TERM_SPLIT_COMPREHENSION_NAME: &str =
+    "__term_split_comprehension";
+/// The name of the `Term.Test.Membership` ABI call.
+pub(crate) const ABI_TERM_TEST_MEMBERSHIP_NAME: &str = "__term_test_membership";
+/// The name of the `Term.Test.Comprehension` ABI call.
+pub(crate) const ABI_TERM_TEST_COMPREHENSION_NAME: &str =
+    "__term_test_comprehension";
+/// The name of the `Theorem.Register.MembershipDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_NAME: &str =
+    "__theorem_register_membership_definition";
+/// The name of the `Theorem.Register.ComprehensionDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_NAME: &str =
+    "__theorem_register_comprehension_definition";
+/// The name of the `Theorem.Register.EmptySetDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_NAME: &str =
+    "__theorem_register_empty_set_definition";
+/// The name of the `Theorem.Register.UniversalSetDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_NAME: &str =
+    "__theorem_register_universal_set_definition";
+/// The name of the `Theorem.Register.UnionDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_UNION_DEFINITION_NAME: &str =
+    "__theorem_register_union_definition";
+/// The name of the `Theorem.Register.IntersectionDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_NAME: &str =
+    "__theorem_register_intersection_definition";
+/// The name of the `Theorem.Register.SubsetDefinition` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_SUBSET_DEFINITION_NAME: &str =
+    "__theorem_register_subset_definition";
+
+/// The index of the `Term.Register.Membership` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_MEMBERSHIP_INDEX: usize = 1044;
+/// The index of the `Term.Register.Comprehension` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REGISTER_COMPREHENSION_INDEX: usize = 1045;
+/// The index of the `Term.Split.Membership` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_MEMBERSHIP_INDEX: usize = 1046;
+/// The index of the `Term.Split.Comprehension` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_COMPREHENSION_INDEX: usize = 1047;
+/// The index of the `Term.Test.Membership` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TEST_MEMBERSHIP_INDEX: usize = 1048;
+/// The index of the `Term.Test.Comprehension` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TEST_COMPREHENSION_INDEX: usize = 1049;
+/// The index of the `Theorem.Register.MembershipDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_MEMBERSHIP_DEFINITION_INDEX: usize = 1050;
+/// The index of the `Theorem.Register.ComprehensionDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_COMPREHENSION_DEFINITION_INDEX: usize =
+    1051;
+/// The index of the `Theorem.Register.EmptySetDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_EMPTY_SET_DEFINITION_INDEX: usize = 1052;
+/// The index of the `Theorem.Register.UniversalSetDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_UNIVERSAL_SET_DEFINITION_INDEX: usize =
+    1053;
+/// The index of the `Theorem.Register.UnionDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_UNION_DEFINITION_INDEX: usize = 1054;
+/// The index of the `Theorem.Register.IntersectionDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_INTERSECTION_DEFINITION_INDEX: usize =
+    1055;
+/// The index of the `Theorem.Register.SubsetDefinition` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_SUBSET_DEFINITION_INDEX: usize = 1056;
+
+/// The name of the `Challenge.Discharge` ABI call.
+pub(crate) const ABI_CHALLENGE_DISCHARGE_NAME: &str = "__challenge_discharge";
+/// The name of the `Capability.Present` ABI call.
+pub(crate) const ABI_CAPABILITY_PRESENT_NAME: &str = "__capability_present";
+
+/// The index of the `Challenge.Discharge` ABI call.  Experimental.
+pub(crate) const ABI_CHALLENGE_DISCHARGE_INDEX: usize = 1057;
+/// The index of the `Capability.Present` ABI call.  Experimental.
+pub(crate) const ABI_CAPABILITY_PRESENT_INDEX: usize = 1058;
+
+/// The name of the `Term.Fold` ABI call.
+pub(crate) const ABI_TERM_FOLD_NAME: &str = "__term_fold";
+
+/// The index of the `Term.Fold` ABI call.  Experimental.
+pub(crate) const ABI_TERM_FOLD_INDEX: usize = 1059;
+
+/// The name of the `Theorem.Proof` ABI call.
+pub(crate) const ABI_THEOREM_PROOF_NAME: &str = "__theorem_proof";
+/// The name of the `Proof.Split` ABI call.
+pub(crate) const ABI_PROOF_SPLIT_NAME: &str = "__proof_split";
+/// The name of the `Proof.Split.Arguments` ABI call.
+pub(crate) const ABI_PROOF_SPLIT_ARGUMENTS_NAME: &str =
+    "__proof_split_arguments";
+/// The name of the `Proof.Check` ABI call.
+pub(crate) const ABI_PROOF_CHECK_NAME: &str = "__proof_check";
+
+/// The index of the `Theorem.Proof` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_PROOF_INDEX: usize = 1060;
+/// The index of the `Proof.Split` ABI call.  Experimental.
+pub(crate) const ABI_PROOF_SPLIT_INDEX: usize = 1061;
+/// The index of the `Proof.Split.Arguments` ABI call.  Experimental.
+pub(crate) const ABI_PROOF_SPLIT_ARGUMENTS_INDEX: usize = 1062;
+/// The index of the `Proof.Check` ABI call.  Experimental.
+pub(crate) const ABI_PROOF_CHECK_INDEX: usize = 1063;
+
+/// The name of the `Theorem.StatementHash` ABI call.
+pub(crate) const ABI_THEOREM_STATEMENT_HASH_NAME: &str =
+    "__theorem_statement_hash";
+
+/// The index of the `Theorem.StatementHash` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_STATEMENT_HASH_INDEX: usize = 1064;
+
+/// The name of the `Kernel.Feature.Supported` ABI call.
+pub(crate) const ABI_KERNEL_FEATURE_SUPPORTED_NAME: &str =
+    "__kernel_feature_supported";
+/// The name of the `Sequent.IsRegistered` ABI call.
+pub(crate) const ABI_SEQUENT_IS_REGISTERED_NAME: &str =
+    "__sequent_is_registered";
+/// The name of the `Sequent.Split.Premisses` ABI call.
+pub(crate) const ABI_SEQUENT_SPLIT_PREMISSES_NAME: &str =
+    "__sequent_split_premisses";
+/// The name of the `Sequent.Split.Conclusions` ABI call.
+pub(crate) const ABI_SEQUENT_SPLIT_CONCLUSIONS_NAME: &str =
+    "__sequent_split_conclusions";
+/// The name of the `Sequent.Register.Axiom` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_AXIOM_NAME: &str =
+    "__sequent_register_axiom";
+/// The name of the `Sequent.Register.Theorem` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_THEOREM_NAME: &str =
+    "__sequent_register_theorem";
+/// The name of the `Sequent.Register.WeakenLeft` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_WEAKEN_LEFT_NAME: &str =
+    "__sequent_register_weaken_left";
+/// The name of the `Sequent.Register.WeakenRight` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_NAME: &str =
+    "__sequent_register_weaken_right";
+/// The name of the `Sequent.Register.Cut` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_CUT_NAME: &str = "__sequent_register_cut";
+/// The name of the `Sequent.Register.NegationLeft` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_NEGATION_LEFT_NAME: &str =
+    "__sequent_register_negation_left";
+/// The name of the `Sequent.Register.NegationRight` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_NEGATION_RIGHT_NAME: &str =
+    "__sequent_register_negation_right";
+/// The name of the `Sequent.Register.ConjunctionLeft` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_NAME: &str =
+    "__sequent_register_conjunction_left";
+/// The name of the `Sequent.Register.ConjunctionRight` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_NAME: &str =
+    "__sequent_register_conjunction_right";
+/// The name of the `Sequent.Register.DisjunctionLeft` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_NAME: &str =
+    "__sequent_register_disjunction_left";
+/// The name of the `Sequent.Register.DisjunctionRight` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_NAME: &str =
+    "__sequent_register_disjunction_right";
+/// The name of the `Sequent.Register.ImplicationLeft` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_NAME: &str =
+    "__sequent_register_implication_left";
+/// The name of the `Sequent.Register.ImplicationRight` ABI call.
+pub(crate) const ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_NAME: &str =
+    "__sequent_register_implication_right";
+
+/// The index of the `Kernel.Feature.Supported` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_FEATURE_SUPPORTED_INDEX: usize = 1065;
+/// The index of the `Sequent.IsRegistered` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_IS_REGISTERED_INDEX: usize = 1066;
+/// The index of the `Sequent.Split.Premisses` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_SPLIT_PREMISSES_INDEX: usize = 1067;
+/// The index of the `Sequent.Split.Conclusions` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_SPLIT_CONCLUSIONS_INDEX: usize = 1068;
+/// The index of the `Sequent.Register.Axiom` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_AXIOM_INDEX: usize = 1069;
+/// The index of the `Sequent.Register.Theorem` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_THEOREM_INDEX: usize = 1070;
+/// The index of the `Sequent.Register.WeakenLeft` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_WEAKEN_LEFT_INDEX: usize = 1071;
+/// The index of the `Sequent.Register.WeakenRight` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_WEAKEN_RIGHT_INDEX: usize = 1072;
+/// The index of the `Sequent.Register.Cut` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_CUT_INDEX: usize = 1073;
+/// The index of the `Sequent.Register.NegationLeft` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_NEGATION_LEFT_INDEX: usize = 1074;
+/// The index of the `Sequent.Register.NegationRight` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_NEGATION_RIGHT_INDEX: usize = 1075;
+/// The index of the `Sequent.Register.ConjunctionLeft` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_CONJUNCTION_LEFT_INDEX: usize = 1076;
+/// The index of the `Sequent.Register.ConjunctionRight` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_CONJUNCTION_RIGHT_INDEX: usize = 1077;
+/// The index of the `Sequent.Register.DisjunctionLeft` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_DISJUNCTION_LEFT_INDEX: usize = 1078;
+/// The index of the `Sequent.Register.DisjunctionRight` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_DISJUNCTION_RIGHT_INDEX: usize = 1079;
+/// The index of the `Sequent.Register.ImplicationLeft` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_IMPLICATION_LEFT_INDEX: usize = 1080;
+/// The index of the `Sequent.Register.ImplicationRight` ABI call.  Experimental.
+pub(crate) const ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX: usize = 1081;
+
+/// Returns `true` iff `index` is the index of an ABI call from the experimental
+/// `sequents` feature.  Without the feature, these calls may still be imported,
+/// so that guests can negotiate the feature, but fail if called.
+#[cfg(not(feature = "sequents"))]
+#[inline]
+pub(crate) fn is_sequent_index(index: usize) -> bool {
+    (ABI_SEQUENT_IS_REGISTERED_INDEX
+        ..=ABI_SEQUENT_REGISTER_IMPLICATION_RIGHT_INDEX)
+        .contains(&index)
+}
+
+/// The name of the `Speculate.Begin` ABI call.
+pub(crate) const ABI_SPECULATE_BEGIN_NAME: &str = "__speculate_begin";
+/// The name of the `Speculate.End` ABI call.
+pub(crate) const ABI_SPECULATE_END_NAME: &str = "__speculate_end";
+
+/// The index of the `Speculate.Begin` ABI call.  Experimental.
+pub(crate) const ABI_SPECULATE_BEGIN_INDEX: usize = 1082;
+/// The index of the `Speculate.End` ABI call.  Experimental.
+pub(crate) const ABI_SPECULATE_END_INDEX: usize = 1083;
+
+/// The name of the `TypeFormer.Register.WithMetadata` ABI call.
+pub(crate) const ABI_TYPE_FORMER_REGISTER_WITH_METADATA_NAME: &str =
+    "__type_former_register_with_metadata";
+/// The name of the `TypeFormer.Metadata` ABI call.
+pub(crate) const ABI_TYPE_FORMER_METADATA_NAME: &str = "__type_former_metadata";
+
+/// The index of the `TypeFormer.Register.WithMetadata` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_FORMER_REGISTER_WITH_METADATA_INDEX: usize = 1084;
+/// The index of the `TypeFormer.Metadata` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_FORMER_METADATA_INDEX: usize = 1085;
+
+/// The name of the `Constant.MarkConstructors` ABI call.
+pub(crate) const ABI_CONSTANT_MARK_CONSTRUCTORS_NAME: &str =
+    "__constant_mark_constructors";
+/// The name of the `Constant.IsConstructor` ABI call.
+pub(crate) const ABI_CONSTANT_IS_CONSTRUCTOR_NAME: &str =
+    "__constant_is_constructor";
+/// The name of the `Constant.ConstructorSiblings` ABI call.
+pub(crate) const ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_NAME: &str =
+    "__constant_constructor_siblings";
+
+/// The index of the `Constant.MarkConstructors` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_MARK_CONSTRUCTORS_INDEX: usize = 1086;
+/// The index of the `Constant.IsConstructor` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_IS_CONSTRUCTOR_INDEX: usize = 1087;
+/// The index of the `Constant.ConstructorSiblings` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_CONSTRUCTOR_SIBLINGS_INDEX: usize = 1088;
+
+/// The name of the `ShouldAbort` ABI call.
+pub(crate) const ABI_SHOULD_ABORT_NAME: &str = "__should_abort";
+
+/// The index of the `ShouldAbort` ABI call.  Experimental.
+pub(crate) const ABI_SHOULD_ABORT_INDEX: usize = 1089;
+
+/// The name of the `Theorem.StatementAsTerm` ABI call.
+pub(crate) const ABI_THEOREM_STATEMENT_AS_TERM_NAME: &str =
+    "__theorem_statement_as_term";
+
+/// The index of the `Theorem.StatementAsTerm` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_STATEMENT_AS_TERM_INDEX: usize = 1090;
+
+/// The name of the `Quotient.Register` ABI call.
+pub(crate) const ABI_QUOTIENT_REGISTER_NAME: &str = "__quotient_register";
+/// The name of the `Quotient.Split` ABI call.
+pub(crate) const ABI_QUOTIENT_SPLIT_NAME: &str = "__quotient_split";
+/// The name of the `Quotient.Lift` ABI call.
+pub(crate) const ABI_QUOTIENT_LIFT_NAME: &str = "__quotient_lift";
+/// The name of the `Quotient.Transfer` ABI call.
+pub(crate) const ABI_QUOTIENT_TRANSFER_NAME: &str = "__quotient_transfer";
+
+/// The index of the `Quotient.Register` ABI call.  Experimental.
+pub(crate) const ABI_QUOTIENT_REGISTER_INDEX: usize = 1091;
+/// The index of the `Quotient.Split` ABI call.  Experimental.
+pub(crate) const ABI_QUOTIENT_SPLIT_INDEX: usize = 1092;
+/// The index of the `Quotient.Lift` ABI call.  Experimental.
+pub(crate) const ABI_QUOTIENT_LIFT_INDEX: usize = 1093;
+/// The index of the `Quotient.Transfer` ABI call.  Experimental.
+pub(crate) const ABI_QUOTIENT_TRANSFER_INDEX: usize = 1094;
+
+/// The name of the `Substitution.Register.Type` ABI call.
+pub(crate) const ABI_SUBSTITUTION_REGISTER_TYPE_NAME: &str =
+    "__substitution_register_type";
+/// The name of the `Substitution.Register.Term` ABI call.
+pub(crate) const ABI_SUBSTITUTION_REGISTER_TERM_NAME: &str =
+    "__substitution_register_term";
+/// The name of the `Type.Substitute.With` ABI call.
+pub(crate) const ABI_TYPE_SUBSTITUTE_WITH_NAME: &str = "__type_substitute_with";
+/// The name of the `Term.Substitute.With` ABI call.
+pub(crate) const ABI_TERM_SUBSTITUTE_WITH_NAME: &str = "__term_substitute_with";
+/// The name of the `Term.Type.Substitute.With` ABI call.
+pub(crate) const ABI_TERM_TYPE_SUBSTITUTE_WITH_NAME: &str =
+    "__term_type_substitute_with";
+
+/// The index of the `Substitution.Register.Type` ABI call.  Experimental.
+pub(crate) const ABI_SUBSTITUTION_REGISTER_TYPE_INDEX: usize = 1095;
+/// The index of the `Substitution.Register.Term` ABI call.  Experimental.
+pub(crate) const ABI_SUBSTITUTION_REGISTER_TERM_INDEX: usize = 1096;
+/// The index of the `Type.Substitute.With` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_SUBSTITUTE_WITH_INDEX: usize = 1097;
+/// The index of the `Term.Substitute.With` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SUBSTITUTE_WITH_INDEX: usize = 1098;
+/// The index of the `Term.Type.Substitute.With` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TYPE_SUBSTITUTE_WITH_INDEX: usize = 1099;
+
+/// The name of the `Theorem.Register.ForallCongruence` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_NAME: &str =
+    "__theorem_register_forall_congruence";
+/// The name of the `Theorem.Register.ExistsCongruence` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_NAME: &str =
+    "__theorem_register_exists_congruence";
+
+/// The index of the `Theorem.Register.ForallCongruence` ABI call.
+/// Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_FORALL_CONGRUENCE_INDEX: usize = 1100;
+/// The index of the `Theorem.Register.ExistsCongruence` ABI call.
+/// Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_EXISTS_CONGRUENCE_INDEX: usize = 1101;
+
+/// The name of the `Budget.Remaining` ABI call.
+pub(crate) const ABI_BUDGET_REMAINING_NAME: &str = "__budget_remaining";
+/// The name of the `Budget.Resource` ABI call.
+pub(crate) const ABI_BUDGET_RESOURCE_NAME: &str = "__budget_resource";
+
+/// The index of the `Budget.Remaining` ABI call.  Experimental.
+pub(crate) const ABI_BUDGET_REMAINING_INDEX: usize = 1102;
+/// The index of the `Budget.Resource` ABI call.  Experimental.
+pub(crate) const ABI_BUDGET_RESOURCE_INDEX: usize = 1103;
+
+/// Returns `true` iff `index` is the index of an ABI call from the experimental
+/// `budgets` feature.  As for the `sequents` feature, without the feature
+/// these calls may still be imported, but fail if called.
+#[cfg(not(feature = "budgets"))]
+#[inline]
+pub(crate) fn is_budget_index(index: usize) -> bool {
+    (ABI_BUDGET_REMAINING_INDEX..=ABI_BUDGET_RESOURCE_INDEX).contains(&index)
+}
+
+/// The name of the `Kernel.ErrorDetail` ABI call.
+pub(crate) const ABI_KERNEL_ERROR_DETAIL_NAME: &str = "__kernel_error_detail";
+
+/// The index of the `Kernel.ErrorDetail` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_ERROR_DETAIL_INDEX: usize = 1104;
+
+/// The name of the `Object.Metadata.Set` ABI call.
+pub(crate) const ABI_OBJECT_METADATA_SET_NAME: &str = "__object_metadata_set";
+/// The name of the `Object.Metadata.Get` ABI call.
+pub(crate) const ABI_OBJECT_METADATA_GET_NAME: &str = "__object_metadata_get";
+/// The name of the `Object.Metadata.Enumerate` ABI call.
+pub(crate) const ABI_OBJECT_METADATA_ENUMERATE_NAME: &str =
+    "__object_metadata_enumerate";
+
+/// The index of the `Object.Metadata.Set` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_METADATA_SET_INDEX: usize = 1105;
+/// The index of the `Object.Metadata.Get` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_METADATA_GET_INDEX: usize = 1106;
+/// The index of the `Object.Metadata.Enumerate` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_METADATA_ENUMERATE_INDEX: usize = 1107;
+
+/// The name of the `Term.View.Reserve` ABI call.
+pub(crate) const ABI_TERM_VIEW_RESERVE_NAME: &str = "__term_view_reserve";
+/// The name of the `Term.View.Map` ABI call.
+pub(crate) const ABI_TERM_VIEW_MAP_NAME: &str = "__term_view_map";
+
+/// The index of the `Term.View.Reserve` ABI call.  Experimental.
+pub(crate) const ABI_TERM_VIEW_RESERVE_INDEX: usize = 1108;
+/// The index of the `Term.View.Map` ABI call.  Experimental.
+pub(crate) const ABI_TERM_VIEW_MAP_INDEX: usize = 1109;
+
+/// The name of the `LinearArith.Signature` ABI call.
+pub(crate) const ABI_LINEAR_ARITH_SIGNATURE_NAME: &str =
+    "__linear_arith_signature";
+/// The name of the `Theorem.Register.LinearArith` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_LINEAR_ARITH_NAME: &str =
+    "__theorem_register_linear_arith";
+/// The name of the `Theorem.IsTainted` ABI call.
+pub(crate) const ABI_THEOREM_IS_TAINTED_NAME: &str = "__theorem_is_tainted";
+
+/// The index of the `LinearArith.Signature` ABI call.  Experimental.
+pub(crate) const ABI_LINEAR_ARITH_SIGNATURE_INDEX: usize = 1110;
+/// The index of the `Theorem.Register.LinearArith` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX: usize = 1111;
+/// The index of the `Theorem.IsTainted` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_IS_TAINTED_INDEX: usize = 1112;
+
+/// The name of the `Theorem.Hypotheses.Count` ABI call.
+pub(crate) const ABI_THEOREM_HYPOTHESES_COUNT_NAME: &str =
+    "__theorem_hypotheses_count";
+/// The name of the `Theorem.Split.Hypotheses.Range` ABI call.
+pub(crate) const ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_NAME: &str =
+    "__theorem_split_hypotheses_range";
+/// The name of the `Type.Variables.Count` ABI call.
+pub(crate) const ABI_TYPE_VARIABLES_COUNT_NAME: &str = "__type_variables_count";
+/// The name of the `Type.Variables.Range` ABI call.
+pub(crate) const ABI_TYPE_VARIABLES_RANGE_NAME: &str = "__type_variables_range";
+/// The name of the `Term.FreeVariables.Count` ABI call.
+pub(crate) const ABI_TERM_FREE_VARIABLES_COUNT_NAME: &str =
+    "__term_free_variables_count";
+/// The name of the `Term.FreeVariables.Range` ABI call.
+pub(crate) const ABI_TERM_FREE_VARIABLES_RANGE_NAME: &str =
+    "__term_free_variables_range";
+/// The name of the `Term.TypeVariables.Count` ABI call.
+pub(crate) const ABI_TERM_TYPE_VARIABLES_COUNT_NAME: &str =
+    "__term_type_variables_count";
+/// The name of the `Term.TypeVariables.Range` ABI call.
+pub(crate) const ABI_TERM_TYPE_VARIABLES_RANGE_NAME: &str =
+    "__term_type_variables_range";
+
+/// The index of the `Theorem.Hypotheses.Count` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_HYPOTHESES_COUNT_INDEX: usize = 1113;
+/// The index of the `Theorem.Split.Hypotheses.Range` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_SPLIT_HYPOTHESES_RANGE_INDEX: usize = 1114;
+/// The index of the `Type.Variables.Count` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_VARIABLES_COUNT_INDEX: usize = 1115;
+/// The index of the `Type.Variables.Range` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_VARIABLES_RANGE_INDEX: usize = 1116;
+/// The index of the `Term.FreeVariables.Count` ABI call.  Experimental.
+pub(crate) const ABI_TERM_FREE_VARIABLES_COUNT_INDEX: usize = 1117;
+/// The index of the `Term.FreeVariables.Range` ABI call.  Experimental.
+pub(crate) const ABI_TERM_FREE_VARIABLES_RANGE_INDEX: usize = 1118;
+/// The index of the `Term.TypeVariables.Count` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TYPE_VARIABLES_COUNT_INDEX: usize = 1119;
+/// The index of the `Term.TypeVariables.Range` ABI call.  Experimental.
+pub(crate) const ABI_TERM_TYPE_VARIABLES_RANGE_INDEX: usize = 1120;
+
+/// The name of the `Introspect.CallCount` ABI call.
+pub(crate) const ABI_INTROSPECT_CALL_COUNT_NAME: &str =
+    "__introspect_call_count";
+/// The name of the `Introspect.FuelConsumed` ABI call.
+pub(crate) const ABI_INTROSPECT_FUEL_CONSUMED_NAME: &str =
+    "__introspect_fuel_consumed";
+/// The name of the `Introspect.HeapSize` ABI call.
+pub(crate) const ABI_INTROSPECT_HEAP_SIZE_NAME: &str = "__introspect_heap_size";
+
+/// The index of the `Introspect.CallCount` ABI call.  Experimental.
+pub(crate) const ABI_INTROSPECT_CALL_COUNT_INDEX: usize = 1121;
+/// The index of the `Introspect.FuelConsumed` ABI call.  Experimental.
+pub(crate) const ABI_INTROSPECT_FUEL_CONSUMED_INDEX: usize = 1122;
+/// The index of the `Introspect.HeapSize` ABI call.  Experimental.
+pub(crate) const ABI_INTROSPECT_HEAP_SIZE_INDEX: usize = 1123;
+
+/// The name of the `Term.Split.Lambda.Display` ABI call.
+pub(crate) const ABI_TERM_SPLIT_LAMBDA_DISPLAY_NAME: &str =
+    "__term_split_lambda_display";
+
+/// The index of the `Term.Split.Lambda.Display` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SPLIT_LAMBDA_DISPLAY_INDEX: usize = 1124;
+
+/// The name of the `Progress.Register` ABI call.
+pub(crate) const ABI_PROGRESS_REGISTER_NAME: &str = "__progress_register";
+
+/// The index of the `Progress.Register` ABI call.  Experimental.
+pub(crate) const ABI_PROGRESS_REGISTER_INDEX: usize = 1125;
+
+/// The name of the `Term.Diff` ABI call.
+pub(crate) const ABI_TERM_DIFF_NAME: &str = "__term_diff";
+
+/// The index of the `Term.Diff` ABI call.  Experimental.
+pub(crate) const ABI_TERM_DIFF_INDEX: usize = 1126;
+
+/// The name of the `Kernel.Reset` ABI call.
+pub(crate) const ABI_KERNEL_RESET_NAME: &str = "__kernel_reset";
+
+/// The index of the `Kernel.Reset` ABI call.  Experimental, and privileged.
+pub(crate) const ABI_KERNEL_RESET_INDEX: usize = 1127;
+
+/// The name of the `Theorem.ExistsWithConclusion` ABI call.
+pub(crate) const ABI_THEOREM_EXISTS_WITH_CONCLUSION_NAME: &str =
+    "__theorem_exists_with_conclusion";
+
+/// The index of the `Theorem.ExistsWithConclusion` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_EXISTS_WITH_CONCLUSION_INDEX: usize = 1128;
+
+/// The name of the `Theorem.Register.Unfold` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_UNFOLD_NAME: &str =
+    "__theorem_register_unfold";
+
+/// The index of the `Theorem.Register.Unfold` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_UNFOLD_INDEX: usize = 1129;
+
+/// The name of the `Term.SubtermAt` ABI call.
+pub(crate) const ABI_TERM_SUBTERM_AT_NAME: &str = "__term_subterm_at";
+
+/// The index of the `Term.SubtermAt` ABI call.  Experimental.
+pub(crate) const ABI_TERM_SUBTERM_AT_INDEX: usize = 1130;
+
+/// The name of the `Term.ReplaceAt` ABI call.
+pub(crate) const ABI_TERM_REPLACE_AT_NAME: &str = "__term_replace_at";
+
+/// The index of the `Term.ReplaceAt` ABI call.  Experimental.
+pub(crate) const ABI_TERM_REPLACE_AT_INDEX: usize = 1131;
+
+/// The name of the `Theorem.Register.RewriteAt` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_REWRITE_AT_NAME: &str =
+    "__theorem_register_rewrite_at";
+
+/// The index of the `Theorem.Register.RewriteAt` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_REWRITE_AT_INDEX: usize = 1132;
+
+/// The name of the `Scratch.Put` ABI call.
+pub(crate) const ABI_SCRATCH_PUT_NAME: &str = "__scratch_put";
+
+/// The index of the `Scratch.Put` ABI call.  Experimental.
+pub(crate) const ABI_SCRATCH_PUT_INDEX: usize = 1133;
+
+/// The name of the `Scratch.Get` ABI call.
+pub(crate) const ABI_SCRATCH_GET_NAME: &str = "__scratch_get";
+
+/// The index of the `Scratch.Get` ABI call.  Experimental.
+pub(crate) const ABI_SCRATCH_GET_INDEX: usize = 1134;
+
+/// The name of the `Scratch.Delete` ABI call.
+pub(crate) const ABI_SCRATCH_DELETE_NAME: &str = "__scratch_delete";
+
+/// The index of the `Scratch.Delete` ABI call.  Experimental.
+pub(crate) const ABI_SCRATCH_DELETE_INDEX: usize = 1135;
+
+/// The name of the `Kernel.SetVariableOrder` ABI call.
+pub(crate) const ABI_KERNEL_SET_VARIABLE_ORDER_NAME: &str =
+    "__kernel_set_variable_order";
+
+/// The index of the `Kernel.SetVariableOrder` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_SET_VARIABLE_ORDER_INDEX: usize = 1136;
+
+/// The name of the `ProofObject.Export` ABI call.
+pub(crate) const ABI_PROOF_OBJECT_EXPORT_NAME: &str = "__proof_object_export";
+
+/// The index of the `ProofObject.Export` ABI call.  Experimental.
+pub(crate) const ABI_PROOF_OBJECT_EXPORT_INDEX: usize = 1137;
+
+/// The name of the `ProofObject.Import` ABI call.
+pub(crate) const ABI_PROOF_OBJECT_IMPORT_NAME: &str = "__proof_object_import";
+
+/// The index of the `ProofObject.Import` ABI call.  Experimental.
+pub(crate) const ABI_PROOF_OBJECT_IMPORT_INDEX: usize = 1138;
+
+/// The name of the `ErrorDetail.Last` ABI call.
+pub(crate) const ABI_ERROR_DETAIL_LAST_NAME: &str = "__error_detail_last";
+
+/// The index of the `ErrorDetail.Last` ABI call.  Experimental.
+pub(crate) const ABI_ERROR_DETAIL_LAST_INDEX: usize = 1139;
+
+/// The name of the `Type.Generalise` ABI call.
+pub(crate) const ABI_TYPE_GENERALISE_NAME: &str = "__type_generalise";
+
+/// The index of the `Type.Generalise` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_GENERALISE_INDEX: usize = 1140;
+
+/// The name of the `Type.InstantiateFresh` ABI call.
+pub(crate) const ABI_TYPE_INSTANTIATE_FRESH_NAME: &str =
+    "__type_instantiate_fresh";
+
+/// The index of the `Type.InstantiateFresh` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_INSTANTIATE_FRESH_INDEX: usize = 1141;
+
+/// The name of the `Theorem.AlphaEqual` ABI call.
+pub(crate) const ABI_THEOREM_ALPHA_EQUAL_NAME: &str = "__theorem_alpha_equal";
+
+/// The index of the `Theorem.AlphaEqual` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_ALPHA_EQUAL_INDEX: usize = 1142;
+
+/// The name of the `Challenge.Current` ABI call.
+pub(crate) const ABI_CHALLENGE_CURRENT_NAME: &str = "__challenge_current";
+
+/// The index of the `Challenge.Current` ABI call.  Experimental.
+pub(crate) const ABI_CHALLENGE_CURRENT_INDEX: usize = 1143;
+
+/// The name of the `Object.IncRef` ABI call.
+pub(crate) const ABI_OBJECT_INC_REF_NAME: &str = "__object_inc_ref";
+/// The name of the `Object.DecRef` ABI call.
+pub(crate) const ABI_OBJECT_DEC_REF_NAME: &str = "__object_dec_ref";
+/// The name of the `Object.Delete` ABI call.
+pub(crate) const ABI_OBJECT_DELETE_NAME: &str = "__object_delete";
+
+/// The index of the `Object.IncRef` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_INC_REF_INDEX: usize = 1144;
+/// The index of the `Object.DecRef` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_DEC_REF_INDEX: usize = 1145;
+/// The index of the `Object.Delete` ABI call.  Experimental.
+pub(crate) const ABI_OBJECT_DELETE_INDEX: usize = 1146;
+
+/// The name of the `Constant.Define` ABI call.
+pub(crate) const ABI_CONSTANT_DEFINE_NAME: &str = "__constant_define";
+
+/// The index of the `Constant.Define` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_DEFINE_INDEX: usize = 1147;
+
+/// The name of the `Substitution.Compose` ABI call.
+pub(crate) const ABI_SUBSTITUTION_COMPOSE_NAME: &str = "__substitution_compose";
+
+/// The index of the `Substitution.Compose` ABI call.  Experimental.
+pub(crate) const ABI_SUBSTITUTION_COMPOSE_INDEX: usize = 1148;
+
+/// The name of the `Theorem.TrustLevel` ABI call.
+pub(crate) const ABI_THEOREM_TRUST_LEVEL_NAME: &str = "__theorem_trust_level";
+
+/// The index of the `Theorem.TrustLevel` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_TRUST_LEVEL_INDEX: usize = 1149;
+
+/// The name of the `Type.Define` ABI call.
+pub(crate) const ABI_TYPE_DEFINE_NAME: &str = "__type_define";
+
+/// The index of the `Type.Define` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_DEFINE_INDEX: usize = 1150;
+
+/// The name of the `Theorem.Register.RewriteHypothesis` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_NAME: &str =
+    "__theorem_register_rewrite_hypothesis";
+
+/// The index of the `Theorem.Register.RewriteHypothesis` ABI call.
+/// Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_REWRITE_HYPOTHESIS_INDEX: usize = 1151;
+
+/// The name of the `Constant.RegisterNamed` ABI call.
+pub(crate) const ABI_CONSTANT_REGISTER_NAMED_NAME: &str =
+    "__constant_register_named";
+
+/// The index of the `Constant.RegisterNamed` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_REGISTER_NAMED_INDEX: usize = 1152;
+
+/// The name of the `Constant.LookupByName` ABI call.
+pub(crate) const ABI_CONSTANT_LOOKUP_BY_NAME_NAME: &str =
+    "__constant_lookup_by_name";
+
+/// The index of the `Constant.LookupByName` ABI call.  Experimental.
+pub(crate) const ABI_CONSTANT_LOOKUP_BY_NAME_INDEX: usize = 1153;
+
+/// The name of the `TypeFormer.LookupByName` ABI call.
+pub(crate) const ABI_TYPE_FORMER_LOOKUP_BY_NAME_NAME: &str =
+    "__type_former_lookup_by_name";
+
+/// The index of the `TypeFormer.LookupByName` ABI call.  Experimental.
+pub(crate) const ABI_TYPE_FORMER_LOOKUP_BY_NAME_INDEX: usize = 1154;
+
+/// The name of the `Kernel.BatchExecute` ABI call.
+pub(crate) const ABI_KERNEL_BATCH_EXECUTE_NAME: &str = "__kernel_batch_execute";
+
+/// The index of the `Kernel.BatchExecute` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_BATCH_EXECUTE_INDEX: usize = 1155;
+
+/// The name of the `Theorem.FindByConclusion` ABI call.
+pub(crate) const ABI_THEOREM_FIND_BY_CONCLUSION_NAME: &str =
+    "__theorem_find_by_conclusion";
+/// The name of the `Theorem.FindMatching` ABI call.
+pub(crate) const ABI_THEOREM_FIND_MATCHING_NAME: &str =
+    "__theorem_find_matching";
+
+/// The index of the `Theorem.FindByConclusion` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_FIND_BY_CONCLUSION_INDEX: usize = 1156;
+/// The index of the `Theorem.FindMatching` ABI call.  Experimental.
+pub(crate) const ABI_THEOREM_FIND_MATCHING_INDEX: usize = 1157;
+
+/// The name of the `Theorem.Register.AssumptionMatching` ABI call.
+pub(crate) const ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_NAME: &str =
+    "__theorem_register_assumption_matching";
+
+/// The index of the `Theorem.Register.AssumptionMatching` ABI call.
+/// Experimental.
+pub(crate) const ABI_THEOREM_REGISTER_ASSUMPTION_MATCHING_INDEX: usize = 1158;
+
+/// The name of the `Kernel.ExportProof` ABI call.
+pub(crate) const ABI_KERNEL_EXPORT_PROOF_NAME: &str = "__kernel_export_proof";
+
+/// The index of the `Kernel.ExportProof` ABI call.  Experimental.
+pub(crate) const ABI_KERNEL_EXPORT_PROOF_INDEX: usize = 1159;
+
+/// Returns `true` iff the ABI call numbered `index` returns a boolean, rather
+/// than an error code, as an `i32`.  A `false` result of such a call is not a
+/// failure.
+#[inline]
+pub(crate) fn is_predicate_index(index: usize) -> bool {
+    matches!(
+        index,
+        ABI_TYPE_FORMER_IS_REGISTERED_INDEX
+            | ABI_TYPE_IS_REGISTERED_INDEX
+            | ABI_CONSTANT_IS_REGISTERED_INDEX
+            | ABI_TERM_IS_REGISTERED_INDEX
+            | ABI_THEOREM_IS_REGISTERED_INDEX
+            | ABI_SEQUENT_IS_REGISTERED_INDEX
+            | ABI_KERNEL_FEATURE_SUPPORTED_INDEX
+            | ABI_SHOULD_ABORT_INDEX
+    )
+}
+
+/// Returns `true` iff `index` is the index of an ABI call from the experimental
+/// `linear-arith` feature.  As for the `budgets` feature, without the feature
+/// these calls may still be imported, but fail if called.  Note that
+/// `Theorem.IsTainted` is always available, as theorems are never tainted
+/// without the feature.
+#[cfg(not(feature = "linear-arith"))]
+#[inline]
+pub(crate) fn is_linear_arith_index(index: usize) -> bool {
+    (ABI_LINEAR_ARITH_SIGNATURE_INDEX..=ABI_THEOREM_REGISTER_LINEAR_ARITH_INDEX)
+        .contains(&index)
+}
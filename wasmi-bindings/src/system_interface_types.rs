@@ -18,7 +18,11 @@
 //! [Nick Spinale]: https://nickspinale.com
 //! [Arm Research]: http://www.arm.com/research
 
-use wasmi::ValueType;
+use std::{
+    convert::TryFrom,
+    fmt::{Display, Error as DisplayError, Formatter},
+};
+use wasmi::{HostError, ValueType};
 
 /// Type-synonyms for declaratively describing the intended purpose of WASM
 /// types passed across the ABI boundary.
@@ -30,6 +34,8 @@ pub(crate) mod semantic_types {
     pub type Arity = u64;
     /// A handle to a kernel object.
     pub type Handle = u64;
+    /// An encoding of a kind of kernel object (e.g. terms, or theorems).
+    pub type Kind = u64;
     /// A name of a variable (e.g. a lambda-abstracted variable, or
     /// type-variable).
     pub type Name = u64;
@@ -37,8 +43,94 @@ pub(crate) mod semantic_types {
     /// reading-from and writing-to the guest WASM program heap, assuming the
     /// `wasm32-abi`.
     pub type Size = u64;
+    /// An identifier of a service, guarded by a challenge.
+    pub type Service = u64;
+    /// An encoding of a kind of expiry policy of a capability.
+    pub type Expiry = u64;
+    /// A count of something, e.g. the number of times that a capability may be
+    /// used.
+    pub type Count = u64;
+    /// An index into the guest's function table, used for registering
+    /// callbacks.
+    pub type TableIndex = u32;
+    /// An opaque value supplied by the guest, and passed back to its
+    /// callbacks.
+    pub type Context = u64;
+    /// An encoding of an optional feature of the kernel, negotiated by the
+    /// guest.
+    pub type Feature = u64;
+    /// An encoding of a mode of unfolding a definition.
+    pub type UnfoldMode = u64;
+    /// An encoding of the order in which the variables of types and terms
+    /// are enumerated.
+    pub type VariableOrder = u64;
+    /// A Boolean value, with zero denoting `false` and any other value denoting
+    /// `true`.
+    pub type Boolean = u32;
 }
 
+/// Raised when an integer passed across the ABI boundary, such as a handle or
+/// a length, cannot be represented as a `usize` on the host.  This never
+/// surfaces as a trap: the dispatcher catches it, and reports it to the guest
+/// as `ErrorCode::ArgumentOutOfRange`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct OutOfRange;
+
+/// Pretty-printing for `OutOfRange` values.
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(f, "OutOfRange")
+    }
+}
+
+impl HostError for OutOfRange {}
+
+/// Narrows an integer passed across the ABI boundary to a host `usize`,
+/// rather than silently truncating it with an `as` cast.
+///
+/// # Errors
+///
+/// Returns `Err(OutOfRange)` if `value` does not fit in a `usize`.
+#[inline]
+pub(crate) fn narrow(value: u64) -> Result<usize, OutOfRange> {
+    usize::try_from(value).map_err(|_e| OutOfRange)
+}
+
+/// Returns the at most `limit` elements of `items` starting from the element
+/// at `offset`, as returned by the ranged ABI calls that page through a
+/// variable-length result.  No elements are returned if `offset` lies past the
+/// end of `items`.
+#[inline]
+pub(crate) fn page<T>(items: &[T], offset: usize, limit: usize) -> &[T] {
+    let start = offset.min(items.len());
+    let end = start.saturating_add(limit).min(items.len());
+
+    &items[start..end]
+}
+
+/// The cursor written back by the `Kernel.Enumerate` ABI call once every
+/// registered object of the requested kind has been enumerated.
+pub(crate) const ENUMERATION_COMPLETE: u64 = u64::MAX;
+
+/// The tag passed to a `Term.Fold` callback visiting a variable.
+pub(crate) const FOLD_TAG_VARIABLE: u32 = 0;
+/// The tag passed to a `Term.Fold` callback visiting a constant.
+pub(crate) const FOLD_TAG_CONSTANT: u32 = 1;
+/// The tag passed to a `Term.Fold` callback visiting an application.
+pub(crate) const FOLD_TAG_APPLICATION: u32 = 2;
+/// The tag passed to a `Term.Fold` callback visiting a lambda-abstraction.
+pub(crate) const FOLD_TAG_LAMBDA: u32 = 3;
+
+/// The operation passed to a `Progress` callback reporting the progress of
+/// checking a proof.
+pub(crate) const PROGRESS_OPERATION_PROOF_CHECK: u32 = 0;
+/// The operation passed to a `Progress` callback reporting the progress of
+/// importing proof objects.
+pub(crate) const PROGRESS_OPERATION_PROOF_IMPORT: u32 = 1;
+/// The operation passed to a `Progress` callback reporting the progress of
+/// importing a state object.
+pub(crate) const PROGRESS_OPERATION_STATE_IMPORT: u32 = 2;
+
 /// A type capturing semantic types of the ABI, more descriptive than the base
 /// types of WASM.  Note that the constructors of this type are intended to shadow
 /// the type-synyonyms defined in the `semantic_types` module.
@@ -47,12 +139,30 @@ pub(crate) enum AbiType {
     Handle,
     /// A name (e.g. of a lambda-abstracted variable, or similar).
     Name,
+    /// A kind of kernel object.
+    Kind,
     /// An arity for a type-former.
     Arity,
     /// A pointer into the host WASM program's heap.
     Pointer,
     /// A size (or length) of an object appearing in the WASM program's heap.
     Size,
+    /// An identifier of a service, guarded by a challenge.
+    Service,
+    /// A kind of expiry policy of a capability.
+    Expiry,
+    /// A count of something.
+    Count,
+    /// An index into the guest's function table.
+    TableIndex,
+    /// An opaque value passed back to guest callbacks.
+    Context,
+    /// An optional feature of the kernel.
+    Feature,
+    /// A mode of unfolding a definition.
+    UnfoldMode,
+    /// An order in which variables are enumerated.
+    VariableOrder,
     /// A Boolean value.
     Boolean,
     /// An error code returned from an ABI function.
@@ -68,8 +178,17 @@ impl AbiType {
             AbiType::Handle => tau == &ValueType::I64,
             AbiType::Arity => tau == &ValueType::I64,
             AbiType::Name => tau == &ValueType::I64,
+            AbiType::Kind => tau == &ValueType::I64,
             AbiType::Pointer => tau == &ValueType::I32,
             AbiType::Size => tau == &ValueType::I64,
+            AbiType::Service => tau == &ValueType::I64,
+            AbiType::Expiry => tau == &ValueType::I64,
+            AbiType::Count => tau == &ValueType::I64,
+            AbiType::TableIndex => tau == &ValueType::I32,
+            AbiType::Context => tau == &ValueType::I64,
+            AbiType::Feature => tau == &ValueType::I64,
+            AbiType::UnfoldMode => tau == &ValueType::I64,
+            AbiType::VariableOrder => tau == &ValueType::I64,
             AbiType::ErrorCode => tau == &ValueType::I32,
         }
     }
@@ -0,0 +1,370 @@
+//! # Host-call traces
+//!
+//! A trace records every host call made by a WASM guest program, in the order
+//! in which the calls returned, with enough detail to replay them: the values
+//! of the call's arguments, the contents of the guest memory that the call
+//! reads or writes through its pointer arguments, before and after the call,
+//! and the value that the call returned.  Traces are recorded by the runtime
+//! state once tracing is enabled, and can be written to, and read back from,
+//! a simple line-based text format, one host call per line, e.g.
+//!
+//! ```text
+//! __type_register_combination i64:52 mem:3400000000000000 i64:1 mem:0000000000000000/3500000000000000 => i32:0
+//! ```
+//!
+//! Each argument is either a plain value, `i32:n` or `i64:n`, or a region of
+//! guest memory, `mem:before` or `mem:before/after`, with the contents of the
+//! region given in hexadecimal and an empty region written as `-`.  The
+//! contents after the call are only recorded for calls that succeed.  The
+//! result is a plain value, `none`, or `trap:i32`, `trap:i64` or `trap:none`,
+//! giving the type of the value that the call would have returned, if the
+//! call trapped.
+//!
+//! The same events are also retained, a bounded number at a time, as the
+//! runtime state's history of recent host calls, which is reported when the
+//! guest traps.  For reading, rather than replaying, an event may be rendered
+//! with `DecodedTraceEvent`, which decodes memory regions into the 64-bit
+//! words, typically handles and sizes, that host calls exchange, e.g.
+//!
+//! ```text
+//! __type_register_combination(52, [52], 1, [0] -> [53]) => 0
+//! ```
+//!
+//! # Authors
+//!
+//! [Dominic Mulligan], Systems Research Group, [Arm Research] Cambridge.
+//! [Nick Spinale], Systems Research Group, [Arm Research] Cambridge.
+//!
+//! # Copyright
+//!
+//! Copyright (c) Arm Limited, 2021.  All rights reserved (r).  Please see the
+//! `LICENSE.markdown` file in the *Supervisionary* root directory for licensing
+//! information.
+//!
+//! [Dominic Mulligan]: https://dominic-mulligan.co.uk
+//! [Nick Spinale]: https://nickspinale.com
+//! [Arm Research]: http://www.arm.com/research
+
+use std::{
+    fmt::{Display, Error as DisplayError, Formatter},
+    str::{from_utf8, FromStr},
+};
+use wasmi::{RuntimeValue, ValueType};
+
+////////////////////////////////////////////////////////////////////////////////
+// Traced values.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A plain value passed to, or returned from, a host call.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TraceValue {
+    /// A 32-bit value.
+    I32(i32),
+    /// A 64-bit value.
+    I64(i64),
+}
+
+impl TraceValue {
+    /// Converts a WASMI runtime value into a traced value, if it is an
+    /// integer.  Host calls never pass or return floating-point values.
+    pub(crate) fn from_runtime_value(value: &RuntimeValue) -> Option<Self> {
+        match value {
+            RuntimeValue::I32(value) => Some(TraceValue::I32(*value)),
+            RuntimeValue::I64(value) => Some(TraceValue::I64(*value)),
+            _otherwise => None,
+        }
+    }
+}
+
+impl Display for TraceValue {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            TraceValue::I32(value) => write!(f, "i32:{}", value),
+            TraceValue::I64(value) => write!(f, "i64:{}", value),
+        }
+    }
+}
+
+impl FromStr for TraceValue {
+    type Err = MalformedTraceEvent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = s.strip_prefix("i32:") {
+            value
+                .parse()
+                .map(TraceValue::I32)
+                .map_err(|_e| MalformedTraceEvent)
+        } else if let Some(value) = s.strip_prefix("i64:") {
+            value
+                .parse()
+                .map(TraceValue::I64)
+                .map_err(|_e| MalformedTraceEvent)
+        } else {
+            Err(MalformedTraceEvent)
+        }
+    }
+}
+
+/// An argument of a host call.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TraceArgument {
+    /// A plain value.  Pointer arguments are also recorded as plain values if
+    /// they were rejected by argument validation.
+    Value(TraceValue),
+    /// A pointer to a region of guest memory read or written by the host call.
+    Memory {
+        /// The contents of the region before the call.
+        before: Vec<u8>,
+        /// The contents of the region after the call, if it succeeded.
+        after: Option<Vec<u8>>,
+    },
+}
+
+impl Display for TraceArgument {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            TraceArgument::Value(value) => write!(f, "{}", value),
+            TraceArgument::Memory { before, after } => {
+                write!(f, "mem:{}", encode_bytes(before))?;
+
+                if let Some(after) = after {
+                    write!(f, "/{}", encode_bytes(after))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for TraceArgument {
+    type Err = MalformedTraceEvent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("mem:") {
+            None => s.parse().map(TraceArgument::Value),
+            Some(region) => {
+                let (before, after) = match region.split_once('/') {
+                    None => (region, None),
+                    Some((before, after)) => (before, Some(after)),
+                };
+
+                Ok(TraceArgument::Memory {
+                    before: decode_bytes(before)?,
+                    after: after.map(decode_bytes).transpose()?,
+                })
+            }
+        }
+    }
+}
+
+/// The outcome of a host call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TraceResult {
+    /// The host call returned a value.
+    Value(TraceValue),
+    /// The host call returned nothing.
+    None,
+    /// The host call trapped, rather than returning a value of the given type,
+    /// or nothing.
+    Trap(Option<ValueType>),
+}
+
+impl Display for TraceResult {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        match self {
+            TraceResult::Value(value) => write!(f, "{}", value),
+            TraceResult::None => write!(f, "none"),
+            TraceResult::Trap(Some(ValueType::I64)) => write!(f, "trap:i64"),
+            TraceResult::Trap(Some(_otherwise)) => write!(f, "trap:i32"),
+            TraceResult::Trap(None) => write!(f, "trap:none"),
+        }
+    }
+}
+
+impl FromStr for TraceResult {
+    type Err = MalformedTraceEvent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(TraceResult::None),
+            "trap:i32" => Ok(TraceResult::Trap(Some(ValueType::I32))),
+            "trap:i64" => Ok(TraceResult::Trap(Some(ValueType::I64))),
+            "trap:none" => Ok(TraceResult::Trap(None)),
+            value => value.parse().map(TraceResult::Value),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Trace events.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single host call made by the guest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEvent {
+    /// The name under which the guest imported the host call.
+    pub name: String,
+    /// The arguments of the host call, in order.
+    pub arguments: Vec<TraceArgument>,
+    /// The outcome of the host call.
+    pub result: TraceResult,
+}
+
+impl Display for TraceEvent {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(f, "{}", self.name)?;
+
+        for argument in self.arguments.iter() {
+            write!(f, " {}", argument)?;
+        }
+
+        write!(f, " => {}", self.result)
+    }
+}
+
+impl FromStr for TraceEvent {
+    type Err = MalformedTraceEvent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (call, result) =
+            s.trim().split_once(" => ").ok_or(MalformedTraceEvent)?;
+
+        let mut words = call.split_whitespace();
+        let name = words.next().ok_or(MalformedTraceEvent)?.to_string();
+        let arguments = words.map(str::parse).collect::<Result<_, _>>()?;
+
+        Ok(TraceEvent {
+            name,
+            arguments,
+            result: result.trim().parse()?,
+        })
+    }
+}
+
+/// The error produced when a line of a trace cannot be parsed as a trace
+/// event.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MalformedTraceEvent;
+
+impl Display for MalformedTraceEvent {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(f, "MalformedTraceEvent")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Decoded trace events.
+////////////////////////////////////////////////////////////////////////////////
+
+/// A trace event rendered for reading, rather than replaying: plain values
+/// are written without their type, and memory regions are decoded into
+/// little-endian 64-bit words, falling back to hexadecimal for regions that
+/// are not a whole number of words long.
+#[derive(Clone, Copy, Debug)]
+pub struct DecodedTraceEvent<'a>(pub &'a TraceEvent);
+
+/// Writes the value `value` without its type.
+fn write_decoded_value(
+    f: &mut Formatter,
+    value: &TraceValue,
+) -> Result<(), DisplayError> {
+    match value {
+        TraceValue::I32(value) => write!(f, "{}", value),
+        TraceValue::I64(value) => write!(f, "{}", value),
+    }
+}
+
+/// Writes the memory region `bytes` as a list of little-endian 64-bit words,
+/// or in hexadecimal if it is not a whole number of words long.
+fn write_decoded_region(
+    f: &mut Formatter,
+    bytes: &[u8],
+) -> Result<(), DisplayError> {
+    if !bytes.len().is_multiple_of(8) {
+        return write!(f, "0x{}", encode_bytes(bytes));
+    }
+
+    write!(f, "[")?;
+
+    for (index, word) in bytes.chunks(8).enumerate() {
+        let mut buffer = [0u8; 8];
+        buffer.copy_from_slice(word);
+
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+
+        write!(f, "{}", u64::from_le_bytes(buffer))?;
+    }
+
+    write!(f, "]")
+}
+
+impl<'a> Display for DecodedTraceEvent<'a> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), DisplayError> {
+        write!(f, "{}(", self.0.name)?;
+
+        for (index, argument) in self.0.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            match argument {
+                TraceArgument::Value(value) => write_decoded_value(f, value)?,
+                TraceArgument::Memory { before, after } => {
+                    write_decoded_region(f, before)?;
+
+                    if let Some(after) = after {
+                        write!(f, " -> ")?;
+                        write_decoded_region(f, after)?;
+                    }
+                }
+            }
+        }
+
+        write!(f, ") => ")?;
+
+        match &self.0.result {
+            TraceResult::Value(value) => write_decoded_value(f, value),
+            TraceResult::None => write!(f, "()"),
+            TraceResult::Trap(_return_type) => write!(f, "trap"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Encoding memory regions.
+////////////////////////////////////////////////////////////////////////////////
+
+/// Encodes `bytes` in hexadecimal, or as `-` if there are none.
+fn encode_bytes(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::from("-");
+    }
+
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes bytes encoded by `encode_bytes`.
+fn decode_bytes(encoded: &str) -> Result<Vec<u8>, MalformedTraceEvent> {
+    if encoded == "-" {
+        return Ok(Vec::new());
+    }
+
+    encoded
+        .as_bytes()
+        .chunks(2)
+        .map(|digits| {
+            let digits = match digits {
+                [_high, _low] => from_utf8(digits),
+                _otherwise => return Err(MalformedTraceEvent),
+            };
+
+            digits
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or(MalformedTraceEvent)
+        })
+        .collect()
+}
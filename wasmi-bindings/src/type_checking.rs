@@ -686,9 +686,11 @@ pub(crate) fn check_term_test_exists_signature(signature: &Signature) -> bool {
     )
 }
 
-/// Checks the signature of the `Term.FV` ABI function.
+/// Checks the signature of the `Term.FreeVariables` ABI function.
 #[inline]
-pub(crate) fn check_term_fv_signature(signature: &Signature) -> bool {
+pub(crate) fn check_term_free_variables_signature(
+    signature: &Signature,
+) -> bool {
     check_signature(
         signature,
         &[
@@ -854,6 +856,8 @@ pub(crate) fn check_theorem_register_substitute_signature(
             AbiType::Pointer,
             AbiType::Size,
             AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
         ],
         &Some(AbiType::ErrorCode),
     )
@@ -1133,17 +1137,35 @@ pub(crate) fn check_theorem_register_forall_elimination_signature(
 /// Checks the signature of the `Theorem.Register.ExistsIntroduction` ABI function.
 #[inline]
 pub(crate) fn check_theorem_register_exists_introduction_signature(
-    _signature: &Signature,
+    signature: &Signature,
 ) -> bool {
-    unimplemented!()
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
 }
 
 /// Checks the signature of the `Theorem.Register.ExistsElimination` ABI function.
 #[inline]
 pub(crate) fn check_theorem_register_exists_elimination_signature(
-    _signature: &Signature,
+    signature: &Signature,
 ) -> bool {
-    unimplemented!()
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
 }
 
 /// Checks the signature of the `Theorem.Split.Conclusion` ABI function.
@@ -1158,6 +1180,16 @@ pub(crate) fn check_theorem_split_conclusion_signature(
     )
 }
 
+/// Checks the signature of the `Theorem.Size` ABI function.
+#[inline]
+pub(crate) fn check_theorem_size_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
 /// Checks the signature of the `Theorem.Split.Hypotheses` ABI function.
 #[inline]
 pub(crate) fn check_theorem_split_hypotheses_signature(
@@ -1165,7 +1197,1766 @@ pub(crate) fn check_theorem_split_hypotheses_signature(
 ) -> bool {
     check_signature(
         signature,
-        &[AbiType::Handle, AbiType::Pointer, AbiType::Size],
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.Enumerate` ABI function.
+#[inline]
+pub(crate) fn check_kernel_enumerate_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Kind,
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Hypotheses.Intern` ABI function.
+#[inline]
+pub(crate) fn check_hypotheses_intern_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Hypotheses.Resolve` ABI function.
+#[inline]
+pub(crate) fn check_hypotheses_resolve_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.Identify` ABI function.
+#[inline]
+pub(crate) fn check_kernel_identify_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.ErrorDetail` ABI function.
+#[inline]
+pub(crate) fn check_kernel_error_detail_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Conditional` ABI function.
+#[inline]
+pub(crate) fn check_term_register_conditional_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Let` ABI function.
+#[inline]
+pub(crate) fn check_term_register_let_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Split.Conditional` ABI function.
+#[inline]
+pub(crate) fn check_term_split_conditional_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Split.Let` ABI function.
+#[inline]
+pub(crate) fn check_term_split_let_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Test.Conditional` ABI function.
+#[inline]
+pub(crate) fn check_term_test_conditional_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Test.Let` ABI function.
+#[inline]
+pub(crate) fn check_term_test_let_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.ConditionalCongruence` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_conditional_congruence_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.LetCongruence` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_let_congruence_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Pair` ABI function.
+#[inline]
+pub(crate) fn check_term_register_pair_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.First` ABI function.
+#[inline]
+pub(crate) fn check_term_register_first_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Second` ABI function.
+#[inline]
+pub(crate) fn check_term_register_second_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Split.Pair` ABI function.
+#[inline]
+pub(crate) fn check_term_split_pair_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Test.Pair` ABI function.
+#[inline]
+pub(crate) fn check_term_test_pair_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.FirstProjection` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_first_projection_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.SecondProjection` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_second_projection_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.SurjectivePairing` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_surjective_pairing_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Membership` ABI function.
+#[inline]
+pub(crate) fn check_term_register_membership_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Register.Comprehension` ABI function.
+#[inline]
+pub(crate) fn check_term_register_comprehension_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Name,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Split.Membership` ABI function.
+#[inline]
+pub(crate) fn check_term_split_membership_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Split.Comprehension` ABI function.
+#[inline]
+pub(crate) fn check_term_split_comprehension_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Test.Membership` ABI function.
+#[inline]
+pub(crate) fn check_term_test_membership_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Test.Comprehension` ABI function.
+#[inline]
+pub(crate) fn check_term_test_comprehension_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.MembershipDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_membership_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.ComprehensionDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_comprehension_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.EmptySetDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_empty_set_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.UniversalSetDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_universal_set_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.UnionDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_union_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.IntersectionDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_intersection_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.SubsetDefinition` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_subset_definition_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Challenge.Discharge` ABI function.
+#[inline]
+pub(crate) fn check_challenge_discharge_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Service,
+            AbiType::Handle,
+            AbiType::Expiry,
+            AbiType::Count,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Capability.Present` ABI function.
+#[inline]
+pub(crate) fn check_capability_present_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Service],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Fold` ABI function.
+#[inline]
+pub(crate) fn check_term_fold_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::TableIndex,
+            AbiType::Context,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Proof` ABI function.
+#[inline]
+pub(crate) fn check_theorem_proof_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Proof.Split` ABI function.
+#[inline]
+pub(crate) fn check_proof_split_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Proof.Split.Arguments` ABI function.
+#[inline]
+pub(crate) fn check_proof_split_arguments_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Proof.Check` ABI function.
+#[inline]
+pub(crate) fn check_proof_check_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[AbiType::Handle], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Theorem.StatementHash` ABI function.
+#[inline]
+pub(crate) fn check_theorem_statement_hash_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.StatementAsTerm` ABI function.
+#[inline]
+pub(crate) fn check_theorem_statement_as_term_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Quotient.Register` ABI function.
+#[inline]
+pub(crate) fn check_quotient_register_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Quotient.Split` ABI function.
+#[inline]
+pub(crate) fn check_quotient_split_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Quotient.Lift` ABI function.
+#[inline]
+pub(crate) fn check_quotient_lift_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Quotient.Transfer` ABI function.
+#[inline]
+pub(crate) fn check_quotient_transfer_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Substitution.Register.Type` ABI function.
+#[inline]
+pub(crate) fn check_substitution_register_type_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Substitution.Register.Term` ABI function.
+#[inline]
+pub(crate) fn check_substitution_register_term_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.Substitute.With` ABI function.
+#[inline]
+pub(crate) fn check_type_substitute_with_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Substitute.With` ABI function.
+#[inline]
+pub(crate) fn check_term_substitute_with_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Type.Substitute.With` ABI function.
+#[inline]
+pub(crate) fn check_term_type_substitute_with_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.ForallCongruence` ABI
+/// function.
+#[inline]
+pub(crate) fn check_theorem_register_forall_congruence_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Name,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.ExistsCongruence` ABI
+/// function.
+#[inline]
+pub(crate) fn check_theorem_register_exists_congruence_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Name,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Budget.Remaining` ABI function.
+#[inline]
+pub(crate) fn check_budget_remaining_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Service, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Budget.Resource` ABI function.
+#[inline]
+pub(crate) fn check_budget_resource_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Service, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.Feature.Supported` ABI function.
+#[inline]
+pub(crate) fn check_kernel_feature_supported_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(signature, &[AbiType::Feature], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Sequent.IsRegistered` ABI function.
+#[inline]
+pub(crate) fn check_sequent_is_registered_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(signature, &[AbiType::Handle], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Sequent.Split.Premisses` ABI function.
+#[inline]
+pub(crate) fn check_sequent_split_premisses_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Split.Conclusions` ABI function.
+#[inline]
+pub(crate) fn check_sequent_split_conclusions_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.Axiom` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_axiom_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.Theorem` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_theorem_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.WeakenLeft` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_weaken_left_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.WeakenRight` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_weaken_right_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.Cut` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_cut_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.NegationLeft` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_negation_left_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.NegationRight` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_negation_right_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.ConjunctionLeft` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_conjunction_left_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.ConjunctionRight` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_conjunction_right_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.DisjunctionLeft` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_disjunction_left_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.DisjunctionRight` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_disjunction_right_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.ImplicationLeft` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_implication_left_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Sequent.Register.ImplicationRight` ABI function.
+#[inline]
+pub(crate) fn check_sequent_register_implication_right_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Speculate.Begin` ABI function.
+#[inline]
+pub(crate) fn check_speculate_begin_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Speculate.End` ABI function.
+#[inline]
+pub(crate) fn check_speculate_end_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[AbiType::Boolean], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `TypeFormer.Register.WithMetadata` ABI function.
+#[inline]
+pub(crate) fn check_type_former_register_with_metadata_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Arity,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `TypeFormer.LookupByName` ABI function.
+#[inline]
+pub(crate) fn check_type_former_lookup_by_name_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.RegisterNamed` ABI function.
+#[inline]
+pub(crate) fn check_constant_register_named_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.LookupByName` ABI function.
+#[inline]
+pub(crate) fn check_constant_lookup_by_name_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.BatchExecute` ABI function.
+#[inline]
+pub(crate) fn check_kernel_batch_execute_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `TypeFormer.Metadata` ABI function.
+#[inline]
+pub(crate) fn check_type_former_metadata_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.MarkConstructors` ABI function.
+#[inline]
+pub(crate) fn check_constant_mark_constructors_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Size],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.IsConstructor` ABI function.
+#[inline]
+pub(crate) fn check_constant_is_constructor_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.ConstructorSiblings` ABI function.
+#[inline]
+pub(crate) fn check_constant_constructor_siblings_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `ShouldAbort` ABI function.
+#[inline]
+pub(crate) fn check_should_abort_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[], &Some(AbiType::Boolean))
+}
+
+/// Checks the signature of the `Object.Metadata.Set` ABI function.
+#[inline]
+pub(crate) fn check_object_metadata_set_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Object.Metadata.Get` ABI function.
+#[inline]
+pub(crate) fn check_object_metadata_get_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Object.Metadata.Enumerate` ABI function.
+#[inline]
+pub(crate) fn check_object_metadata_enumerate_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Object.IncRef` ABI function.
+#[inline]
+pub(crate) fn check_object_inc_ref_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[AbiType::Handle], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Object.DecRef` ABI function.
+#[inline]
+pub(crate) fn check_object_dec_ref_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[AbiType::Handle], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Object.Delete` ABI function.
+#[inline]
+pub(crate) fn check_object_delete_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[AbiType::Handle], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Substitution.Compose` ABI function.
+#[inline]
+pub(crate) fn check_substitution_compose_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Constant.Define` ABI function.
+#[inline]
+pub(crate) fn check_constant_define_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.Define` ABI function.
+#[inline]
+pub(crate) fn check_type_define_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.View.Reserve` ABI function.
+#[inline]
+pub(crate) fn check_term_view_reserve_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.View.Map` ABI function.
+#[inline]
+pub(crate) fn check_term_view_map_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `LinearArith.Signature` ABI function.
+#[inline]
+pub(crate) fn check_linear_arith_signature_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(signature, &[AbiType::Pointer], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Theorem.Register.LinearArith` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_linear_arith_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.IsTainted` ABI function.
+#[inline]
+pub(crate) fn check_theorem_is_tainted_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.TrustLevel` ABI function.
+#[inline]
+pub(crate) fn check_theorem_trust_level_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Hypotheses.Count` ABI function.
+#[inline]
+pub(crate) fn check_theorem_hypotheses_count_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Split.Hypotheses.Range` ABI function.
+#[inline]
+pub(crate) fn check_theorem_split_hypotheses_range_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.Variables.Count` ABI function.
+#[inline]
+pub(crate) fn check_type_variables_count_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.Variables.Range` ABI function.
+#[inline]
+pub(crate) fn check_type_variables_range_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.FreeVariables.Count` ABI function.
+#[inline]
+pub(crate) fn check_term_free_variables_count_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.FreeVariables.Range` ABI function.
+#[inline]
+pub(crate) fn check_term_free_variables_range_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.TypeVariables.Count` ABI function.
+#[inline]
+pub(crate) fn check_term_type_variables_count_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.TypeVariables.Range` ABI function.
+#[inline]
+pub(crate) fn check_term_type_variables_range_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Introspect.CallCount` ABI function.
+#[inline]
+pub(crate) fn check_introspect_call_count_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Introspect.FuelConsumed` ABI function.
+#[inline]
+pub(crate) fn check_introspect_fuel_consumed_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(signature, &[AbiType::Pointer], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Introspect.HeapSize` ABI function.
+#[inline]
+pub(crate) fn check_introspect_heap_size_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(signature, &[AbiType::Pointer], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Term.Split.Lambda.Display` ABI function.
+#[inline]
+pub(crate) fn check_term_split_lambda_display_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Progress.Register` ABI function.
+#[inline]
+pub(crate) fn check_progress_register_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::TableIndex, AbiType::Context],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.Diff` ABI function.
+#[inline]
+pub(crate) fn check_term_diff_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.Reset` ABI function.
+#[inline]
+pub(crate) fn check_kernel_reset_signature(signature: &Signature) -> bool {
+    check_signature(signature, &[], &Some(AbiType::ErrorCode))
+}
+
+/// Checks the signature of the `Theorem.ExistsWithConclusion` ABI function.
+#[inline]
+pub(crate) fn check_theorem_exists_with_conclusion_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.FindByConclusion` ABI function.
+#[inline]
+pub(crate) fn check_theorem_find_by_conclusion_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.FindMatching` ABI function.
+#[inline]
+pub(crate) fn check_theorem_find_matching_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.Unfold` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_unfold_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::UnfoldMode,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.SubtermAt` ABI function.
+#[inline]
+pub(crate) fn check_term_subterm_at_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Term.ReplaceAt` ABI function.
+#[inline]
+pub(crate) fn check_term_replace_at_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.RewriteAt` ABI function.
+#[inline]
+pub(crate) fn check_theorem_register_rewrite_at_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.RewriteHypothesis` ABI
+/// function.
+#[inline]
+pub(crate) fn check_theorem_register_rewrite_hypothesis_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Size,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.Register.AssumptionMatching` ABI
+/// function.
+#[inline]
+pub(crate) fn check_theorem_register_assumption_matching_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Handle,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Scratch.Put` ABI function.
+#[inline]
+pub(crate) fn check_scratch_put_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Size,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Scratch.Get` ABI function.
+#[inline]
+pub(crate) fn check_scratch_get_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Scratch.Delete` ABI function.
+#[inline]
+pub(crate) fn check_scratch_delete_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Pointer, AbiType::Size],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.SetVariableOrder` ABI function.
+#[inline]
+pub(crate) fn check_kernel_set_variable_order_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::VariableOrder],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `ProofObject.Export` ABI function.
+#[inline]
+pub(crate) fn check_proof_object_export_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Kernel.ExportProof` ABI function.
+#[inline]
+pub(crate) fn check_kernel_export_proof_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Pointer, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `ProofObject.Import` ABI function.
+#[inline]
+pub(crate) fn check_proof_object_import_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `ErrorDetail.Last` ABI function.
+#[inline]
+pub(crate) fn check_error_detail_last_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.Generalise` ABI function.
+#[inline]
+pub(crate) fn check_type_generalise_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Type.InstantiateFresh` ABI function.
+#[inline]
+pub(crate) fn check_type_instantiate_fresh_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[
+            AbiType::Handle,
+            AbiType::Pointer,
+            AbiType::Size,
+            AbiType::Pointer,
+            AbiType::Pointer,
+            AbiType::Pointer,
+        ],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Theorem.AlphaEqual` ABI function.
+#[inline]
+pub(crate) fn check_theorem_alpha_equal_signature(
+    signature: &Signature,
+) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Handle, AbiType::Handle, AbiType::Pointer],
+        &Some(AbiType::ErrorCode),
+    )
+}
+
+/// Checks the signature of the `Challenge.Current` ABI function.
+#[inline]
+pub(crate) fn check_challenge_current_signature(signature: &Signature) -> bool {
+    check_signature(
+        signature,
+        &[AbiType::Service, AbiType::Pointer],
         &Some(AbiType::ErrorCode),
     )
 }